@@ -65,6 +65,11 @@ export enum LogLevel {
     Warn = 'LogLevelWarn',
 }
 
+export enum OrphanedWorkspacesStrategy {
+    Abort = 'OrphanedWorkspacesStrategyAbort',
+    Ignore = 'OrphanedWorkspacesStrategyIgnore',
+}
+
 export enum Platform {
     Android = 'PlatformAndroid',
     Linux = 'PlatformLinux',
@@ -92,6 +97,11 @@ export enum UserProfile {
     Standard = 'UserProfileStandard',
 }
 
+export enum X509RevocationPolicy {
+    HardFail = 'X509RevocationPolicyHardFail',
+    SoftFail = 'X509RevocationPolicySoftFail',
+}
+
 
 export interface AccountInfo {
     serverAddr: string
@@ -171,6 +181,12 @@ export interface AvailableDevice {
 }
 
 
+export interface AvailableDeviceMultiProtection {
+    totpOpaqueKeyId: string | null
+    ty: AvailableDeviceMultiProtectionType
+}
+
+
 export interface AvailablePendingAsyncEnrollment {
     filePath: string
     submittedOn: number
@@ -190,6 +206,8 @@ export interface ClientConfig {
     withMonitors: boolean
     preventSyncPattern: string | null
     logLevel: LogLevel | null
+    pkiRevocationPolicy: X509RevocationPolicy | null
+    outboundSync: OutboundSyncConfig | null
 }
 
 
@@ -220,6 +238,12 @@ export interface DeviceAccessStrategy {
 }
 
 
+export interface DeviceAlternativeProtection {
+    totpProtection: [string, Uint8Array] | null
+    primaryProtection: DevicePrimaryProtectionStrategy
+}
+
+
 export interface DeviceClaimFinalizeInfo {
     handle: number
 }
@@ -336,6 +360,27 @@ export interface OrganizationInfo {
 }
 
 
+export interface OutboundSyncConfig {
+    delays: OutboundSyncDelays | null
+    overrides: Array<OutboundSyncDelaysOverride>
+    blockDeduplication: boolean
+    blockCompression: boolean
+    contentDefinedChunking: boolean
+}
+
+
+export interface OutboundSyncDelays {
+    quiescenceInMs: number
+    maxDelayInMs: number
+}
+
+
+export interface OutboundSyncDelaysOverride {
+    pattern: string
+    delays: OutboundSyncDelays
+}
+
+
 export interface ServerConfig {
     account: AccountConfig
     cryptpad: CryptPadConfig | null
@@ -349,6 +394,7 @@ export interface ServerOrganizationConfig {
     userProfileOutsiderAllowed: boolean
     activeUsersLimit: ActiveUsersLimit
     realmMinimumArchivingPeriodBeforeDeletion: number
+    pkiCrls: Array<Uint8Array>
 }
 
 
@@ -558,6 +604,7 @@ export interface WorkspaceUserAccessInfo {
     humanHandle: HumanHandle
     currentProfile: UserProfile
     currentRole: RealmRole
+    expiresOn: number | null
 }
 
 
@@ -673,6 +720,10 @@ export interface AccountCreateRegistrationDeviceErrorBadVaultKeyAccess {
     tag: "AccountCreateRegistrationDeviceErrorBadVaultKeyAccess"
     error: string
 }
+export interface AccountCreateRegistrationDeviceErrorClockDrift {
+    tag: "AccountCreateRegistrationDeviceErrorClockDrift"
+    error: string
+}
 export interface AccountCreateRegistrationDeviceErrorInternal {
     tag: "AccountCreateRegistrationDeviceErrorInternal"
     error: string
@@ -711,6 +762,7 @@ export interface AccountCreateRegistrationDeviceErrorTimestampOutOfBallpark {
 }
 export type AccountCreateRegistrationDeviceError =
   | AccountCreateRegistrationDeviceErrorBadVaultKeyAccess
+  | AccountCreateRegistrationDeviceErrorClockDrift
   | AccountCreateRegistrationDeviceErrorInternal
   | AccountCreateRegistrationDeviceErrorLoadDeviceDecryptionFailed
   | AccountCreateRegistrationDeviceErrorLoadDeviceInvalidData
@@ -1007,10 +1059,18 @@ export interface AccountRegisterNewDeviceErrorBadVaultKeyAccess {
     tag: "AccountRegisterNewDeviceErrorBadVaultKeyAccess"
     error: string
 }
+export interface AccountRegisterNewDeviceErrorClockDrift {
+    tag: "AccountRegisterNewDeviceErrorClockDrift"
+    error: string
+}
 export interface AccountRegisterNewDeviceErrorCorruptedRegistrationDevice {
     tag: "AccountRegisterNewDeviceErrorCorruptedRegistrationDevice"
     error: string
 }
+export interface AccountRegisterNewDeviceErrorFido2HmacSecretNotSupported {
+    tag: "AccountRegisterNewDeviceErrorFido2HmacSecretNotSupported"
+    error: string
+}
 export interface AccountRegisterNewDeviceErrorInternal {
     tag: "AccountRegisterNewDeviceErrorInternal"
     error: string
@@ -1045,7 +1105,9 @@ export interface AccountRegisterNewDeviceErrorUnknownRegistrationDevice {
 }
 export type AccountRegisterNewDeviceError =
   | AccountRegisterNewDeviceErrorBadVaultKeyAccess
+  | AccountRegisterNewDeviceErrorClockDrift
   | AccountRegisterNewDeviceErrorCorruptedRegistrationDevice
+  | AccountRegisterNewDeviceErrorFido2HmacSecretNotSupported
   | AccountRegisterNewDeviceErrorInternal
   | AccountRegisterNewDeviceErrorInvalidPath
   | AccountRegisterNewDeviceErrorNoSpaceAvailable
@@ -1134,13 +1196,52 @@ export type AsyncEnrollmentIdentitySystem =
   | AsyncEnrollmentIdentitySystemPKICorrupted
 
 
+// AvailableDeviceMultiProtectionType
+export interface AvailableDeviceMultiProtectionTypeAccountVault {
+    tag: "AvailableDeviceMultiProtectionTypeAccountVault"
+}
+export interface AvailableDeviceMultiProtectionTypeFido2 {
+    tag: "AvailableDeviceMultiProtectionTypeFido2"
+}
+export interface AvailableDeviceMultiProtectionTypeOpenBao {
+    tag: "AvailableDeviceMultiProtectionTypeOpenBao"
+    openbao_preferred_auth_id: string
+    openbao_entity_id: string
+}
+export interface AvailableDeviceMultiProtectionTypePKI {
+    tag: "AvailableDeviceMultiProtectionTypePKI"
+    certificate_ref: X509CertificateReference
+}
+export interface AvailableDeviceMultiProtectionTypePassword {
+    tag: "AvailableDeviceMultiProtectionTypePassword"
+}
+export type AvailableDeviceMultiProtectionType =
+  | AvailableDeviceMultiProtectionTypeAccountVault
+  | AvailableDeviceMultiProtectionTypeFido2
+  | AvailableDeviceMultiProtectionTypeOpenBao
+  | AvailableDeviceMultiProtectionTypePKI
+  | AvailableDeviceMultiProtectionTypePassword
+
+
 // AvailableDeviceType
 export interface AvailableDeviceTypeAccountVault {
     tag: "AvailableDeviceTypeAccountVault"
 }
+export interface AvailableDeviceTypeFido2 {
+    tag: "AvailableDeviceTypeFido2"
+}
 export interface AvailableDeviceTypeKeyring {
     tag: "AvailableDeviceTypeKeyring"
 }
+export interface AvailableDeviceTypeMachineKey {
+    tag: "AvailableDeviceTypeMachineKey"
+    machine_key_file: string
+    machine_key_available: boolean
+}
+export interface AvailableDeviceTypeMulti {
+    tag: "AvailableDeviceTypeMulti"
+    protections: Array<AvailableDeviceMultiProtection>
+}
 export interface AvailableDeviceTypeOpenBao {
     tag: "AvailableDeviceTypeOpenBao"
     openbao_preferred_auth_id: string
@@ -1158,7 +1259,10 @@ export interface AvailableDeviceTypeRecovery {
 }
 export type AvailableDeviceType =
   | AvailableDeviceTypeAccountVault
+  | AvailableDeviceTypeFido2
   | AvailableDeviceTypeKeyring
+  | AvailableDeviceTypeMachineKey
+  | AvailableDeviceTypeMulti
   | AvailableDeviceTypeOpenBao
   | AvailableDeviceTypePKI
   | AvailableDeviceTypePassword
@@ -1222,6 +1326,10 @@ export interface BootstrapOrganizationErrorOrganizationExpired {
     tag: "BootstrapOrganizationErrorOrganizationExpired"
     error: string
 }
+export interface BootstrapOrganizationErrorSaveDeviceFido2HmacSecretNotSupported {
+    tag: "BootstrapOrganizationErrorSaveDeviceFido2HmacSecretNotSupported"
+    error: string
+}
 export interface BootstrapOrganizationErrorSaveDeviceInvalidPath {
     tag: "BootstrapOrganizationErrorSaveDeviceInvalidPath"
     error: string
@@ -1253,6 +1361,7 @@ export type BootstrapOrganizationError =
   | BootstrapOrganizationErrorInvalidToken
   | BootstrapOrganizationErrorOffline
   | BootstrapOrganizationErrorOrganizationExpired
+  | BootstrapOrganizationErrorSaveDeviceFido2HmacSecretNotSupported
   | BootstrapOrganizationErrorSaveDeviceInvalidPath
   | BootstrapOrganizationErrorSaveDeviceNoSpaceAvailable
   | BootstrapOrganizationErrorSaveDeviceRemoteOpaqueKeyUploadFailed
@@ -1288,6 +1397,10 @@ export type CertificateBasedInfoOrigin =
 
 
 // ClaimFinalizeError
+export interface ClaimFinalizeErrorFido2HmacSecretNotSupported {
+    tag: "ClaimFinalizeErrorFido2HmacSecretNotSupported"
+    error: string
+}
 export interface ClaimFinalizeErrorInternal {
     tag: "ClaimFinalizeErrorInternal"
     error: string
@@ -1309,6 +1422,7 @@ export interface ClaimFinalizeErrorRemoteOpaqueKeyUploadOffline {
     error: string
 }
 export type ClaimFinalizeError =
+  | ClaimFinalizeErrorFido2HmacSecretNotSupported
   | ClaimFinalizeErrorInternal
   | ClaimFinalizeErrorInvalidPath
   | ClaimFinalizeErrorNoSpaceAvailable
@@ -1434,6 +1548,10 @@ export interface ClientAcceptAsyncEnrollmentErrorBadSubmitPayload {
     tag: "ClientAcceptAsyncEnrollmentErrorBadSubmitPayload"
     error: string
 }
+export interface ClientAcceptAsyncEnrollmentErrorClockDrift {
+    tag: "ClientAcceptAsyncEnrollmentErrorClockDrift"
+    error: string
+}
 export interface ClientAcceptAsyncEnrollmentErrorEnrollmentNoLongerAvailable {
     tag: "ClientAcceptAsyncEnrollmentErrorEnrollmentNoLongerAvailable"
     error: string
@@ -1490,6 +1608,7 @@ export type ClientAcceptAsyncEnrollmentError =
   | ClientAcceptAsyncEnrollmentErrorActiveUsersLimitReached
   | ClientAcceptAsyncEnrollmentErrorAuthorNotAllowed
   | ClientAcceptAsyncEnrollmentErrorBadSubmitPayload
+  | ClientAcceptAsyncEnrollmentErrorClockDrift
   | ClientAcceptAsyncEnrollmentErrorEnrollmentNoLongerAvailable
   | ClientAcceptAsyncEnrollmentErrorEnrollmentNotFound
   | ClientAcceptAsyncEnrollmentErrorHumanHandleAlreadyTaken
@@ -1538,6 +1657,10 @@ export interface ClientArchiveWorkspaceErrorAuthorNotAllowed {
     tag: "ClientArchiveWorkspaceErrorAuthorNotAllowed"
     error: string
 }
+export interface ClientArchiveWorkspaceErrorClockDrift {
+    tag: "ClientArchiveWorkspaceErrorClockDrift"
+    error: string
+}
 export interface ClientArchiveWorkspaceErrorInternal {
     tag: "ClientArchiveWorkspaceErrorInternal"
     error: string
@@ -1581,6 +1704,7 @@ export interface ClientArchiveWorkspaceErrorWorkspaceNotFound {
 export type ClientArchiveWorkspaceError =
   | ClientArchiveWorkspaceErrorArchivingPeriodTooShort
   | ClientArchiveWorkspaceErrorAuthorNotAllowed
+  | ClientArchiveWorkspaceErrorClockDrift
   | ClientArchiveWorkspaceErrorInternal
   | ClientArchiveWorkspaceErrorInvalidCertificate
   | ClientArchiveWorkspaceErrorInvalidEncryptedRealmName
@@ -1641,6 +1765,10 @@ export type ClientCreateWorkspaceError =
 
 
 // ClientDeleteShamirRecoveryError
+export interface ClientDeleteShamirRecoveryErrorClockDrift {
+    tag: "ClientDeleteShamirRecoveryErrorClockDrift"
+    error: string
+}
 export interface ClientDeleteShamirRecoveryErrorInternal {
     tag: "ClientDeleteShamirRecoveryErrorInternal"
     error: string
@@ -1666,6 +1794,7 @@ export interface ClientDeleteShamirRecoveryErrorTimestampOutOfBallpark {
     ballpark_client_late_offset: number
 }
 export type ClientDeleteShamirRecoveryError =
+  | ClientDeleteShamirRecoveryErrorClockDrift
   | ClientDeleteShamirRecoveryErrorInternal
   | ClientDeleteShamirRecoveryErrorInvalidCertificate
   | ClientDeleteShamirRecoveryErrorOffline
@@ -1689,6 +1818,21 @@ export interface ClientEventClientStopped {
     tag: "ClientEventClientStopped"
     device_id: string
 }
+export interface ClientEventClockDrift {
+    tag: "ClientEventClockDrift"
+    required_timestamp: number
+    client_timestamp: number
+}
+export interface ClientEventDeviceAdded {
+    tag: "ClientEventDeviceAdded"
+    user_id: string
+    device_id: string
+}
+export interface ClientEventDeviceRevoked {
+    tag: "ClientEventDeviceRevoked"
+    user_id: string
+    device_id: string
+}
 export interface ClientEventExpiredOrganization {
     tag: "ClientEventExpiredOrganization"
 }
@@ -1743,9 +1887,49 @@ export interface ClientEventPing {
     tag: "ClientEventPing"
     ping: string
 }
+export interface ClientEventRealmArchivingChanged {
+    tag: "ClientEventRealmArchivingChanged"
+    realm_id: string
+}
+export interface ClientEventRealmRekeyDone {
+    tag: "ClientEventRealmRekeyDone"
+    realm_id: string
+    target_key_index: number
+}
+export interface ClientEventRealmRekeyProgress {
+    tag: "ClientEventRealmRekeyProgress"
+    realm_id: string
+    target_key_index: number
+    processed: number
+    total: number
+}
+export interface ClientEventRealmRenamed {
+    tag: "ClientEventRealmRenamed"
+    realm_id: string
+}
+export interface ClientEventRealmRoleChanged {
+    tag: "ClientEventRealmRoleChanged"
+    realm_id: string
+    user_id: string
+    old_role: RealmRole | null
+    new_role: RealmRole | null
+}
 export interface ClientEventRevokedSelfUser {
     tag: "ClientEventRevokedSelfUser"
 }
+export interface ClientEventSelfShared {
+    tag: "ClientEventSelfShared"
+    realm_id: string
+    role: RealmRole
+}
+export interface ClientEventSelfUnshared {
+    tag: "ClientEventSelfUnshared"
+    realm_id: string
+}
+export interface ClientEventSequesterServiceRevoked {
+    tag: "ClientEventSequesterServiceRevoked"
+    service_id: string
+}
 export interface ClientEventServerConfigChanged {
     tag: "ClientEventServerConfigChanged"
 }
@@ -1757,6 +1941,10 @@ export interface ClientEventServerInvalidResponseStatus {
     tag: "ClientEventServerInvalidResponseStatus"
     status_code: string
 }
+export interface ClientEventShamirRecoveryChanged {
+    tag: "ClientEventShamirRecoveryChanged"
+    user_id: string
+}
 export interface ClientEventTooMuchDriftWithServerClock {
     tag: "ClientEventTooMuchDriftWithServerClock"
     server_timestamp: number
@@ -1764,6 +1952,15 @@ export interface ClientEventTooMuchDriftWithServerClock {
     ballpark_client_early_offset: number
     ballpark_client_late_offset: number
 }
+export interface ClientEventUserProfileUpdated {
+    tag: "ClientEventUserProfileUpdated"
+    user_id: string
+    profile: UserProfile
+}
+export interface ClientEventUserRevoked {
+    tag: "ClientEventUserRevoked"
+    user_id: string
+}
 export interface ClientEventWebClientNotAllowedByOrganization {
     tag: "ClientEventWebClientNotAllowedByOrganization"
 }
@@ -1811,6 +2008,9 @@ export type ClientEvent =
   | ClientEventClientErrorResponse
   | ClientEventClientStarted
   | ClientEventClientStopped
+  | ClientEventClockDrift
+  | ClientEventDeviceAdded
+  | ClientEventDeviceRevoked
   | ClientEventExpiredOrganization
   | ClientEventFrozenSelfUser
   | ClientEventGreetingAttemptCancelled
@@ -1825,11 +2025,22 @@ export type ClientEvent =
   | ClientEventOnline
   | ClientEventOrganizationNotFound
   | ClientEventPing
+  | ClientEventRealmArchivingChanged
+  | ClientEventRealmRekeyDone
+  | ClientEventRealmRekeyProgress
+  | ClientEventRealmRenamed
+  | ClientEventRealmRoleChanged
   | ClientEventRevokedSelfUser
+  | ClientEventSelfShared
+  | ClientEventSelfUnshared
+  | ClientEventSequesterServiceRevoked
   | ClientEventServerConfigChanged
   | ClientEventServerInvalidResponseContent
   | ClientEventServerInvalidResponseStatus
+  | ClientEventShamirRecoveryChanged
   | ClientEventTooMuchDriftWithServerClock
+  | ClientEventUserProfileUpdated
+  | ClientEventUserRevoked
   | ClientEventWebClientNotAllowedByOrganization
   | ClientEventWorkspaceLocallyCreated
   | ClientEventWorkspaceOpsInboundSyncDone
@@ -1842,6 +2053,10 @@ export type ClientEvent =
 
 
 // ClientExportRecoveryDeviceError
+export interface ClientExportRecoveryDeviceErrorClockDrift {
+    tag: "ClientExportRecoveryDeviceErrorClockDrift"
+    error: string
+}
 export interface ClientExportRecoveryDeviceErrorInternal {
     tag: "ClientExportRecoveryDeviceErrorInternal"
     error: string
@@ -1867,6 +2082,7 @@ export interface ClientExportRecoveryDeviceErrorTimestampOutOfBallpark {
     ballpark_client_late_offset: number
 }
 export type ClientExportRecoveryDeviceError =
+  | ClientExportRecoveryDeviceErrorClockDrift
   | ClientExportRecoveryDeviceErrorInternal
   | ClientExportRecoveryDeviceErrorInvalidCertificate
   | ClientExportRecoveryDeviceErrorOffline
@@ -2219,11 +2435,83 @@ export type ClientRejectAsyncEnrollmentError =
   | ClientRejectAsyncEnrollmentErrorOffline
 
 
+// ClientRekeyWorkspaceError
+export interface ClientRekeyWorkspaceErrorAuthorNotAllowed {
+    tag: "ClientRekeyWorkspaceErrorAuthorNotAllowed"
+    error: string
+}
+export interface ClientRekeyWorkspaceErrorClockDrift {
+    tag: "ClientRekeyWorkspaceErrorClockDrift"
+    error: string
+}
+export interface ClientRekeyWorkspaceErrorInternal {
+    tag: "ClientRekeyWorkspaceErrorInternal"
+    error: string
+}
+export interface ClientRekeyWorkspaceErrorInvalidCertificate {
+    tag: "ClientRekeyWorkspaceErrorInvalidCertificate"
+    error: string
+}
+export interface ClientRekeyWorkspaceErrorInvalidKeysBundle {
+    tag: "ClientRekeyWorkspaceErrorInvalidKeysBundle"
+    error: string
+}
+export interface ClientRekeyWorkspaceErrorOffline {
+    tag: "ClientRekeyWorkspaceErrorOffline"
+    error: string
+}
+export interface ClientRekeyWorkspaceErrorRealmArchived {
+    tag: "ClientRekeyWorkspaceErrorRealmArchived"
+    error: string
+}
+export interface ClientRekeyWorkspaceErrorRealmDeleted {
+    tag: "ClientRekeyWorkspaceErrorRealmDeleted"
+    error: string
+}
+export interface ClientRekeyWorkspaceErrorServerBlockstoreUnavailable {
+    tag: "ClientRekeyWorkspaceErrorServerBlockstoreUnavailable"
+    error: string
+}
+export interface ClientRekeyWorkspaceErrorStopped {
+    tag: "ClientRekeyWorkspaceErrorStopped"
+    error: string
+}
+export interface ClientRekeyWorkspaceErrorTimestampOutOfBallpark {
+    tag: "ClientRekeyWorkspaceErrorTimestampOutOfBallpark"
+    error: string
+    server_timestamp: number
+    client_timestamp: number
+    ballpark_client_early_offset: number
+    ballpark_client_late_offset: number
+}
+export interface ClientRekeyWorkspaceErrorUnknownRealm {
+    tag: "ClientRekeyWorkspaceErrorUnknownRealm"
+    error: string
+}
+export type ClientRekeyWorkspaceError =
+  | ClientRekeyWorkspaceErrorAuthorNotAllowed
+  | ClientRekeyWorkspaceErrorClockDrift
+  | ClientRekeyWorkspaceErrorInternal
+  | ClientRekeyWorkspaceErrorInvalidCertificate
+  | ClientRekeyWorkspaceErrorInvalidKeysBundle
+  | ClientRekeyWorkspaceErrorOffline
+  | ClientRekeyWorkspaceErrorRealmArchived
+  | ClientRekeyWorkspaceErrorRealmDeleted
+  | ClientRekeyWorkspaceErrorServerBlockstoreUnavailable
+  | ClientRekeyWorkspaceErrorStopped
+  | ClientRekeyWorkspaceErrorTimestampOutOfBallpark
+  | ClientRekeyWorkspaceErrorUnknownRealm
+
+
 // ClientRenameWorkspaceError
 export interface ClientRenameWorkspaceErrorAuthorNotAllowed {
     tag: "ClientRenameWorkspaceErrorAuthorNotAllowed"
     error: string
 }
+export interface ClientRenameWorkspaceErrorClockDrift {
+    tag: "ClientRenameWorkspaceErrorClockDrift"
+    error: string
+}
 export interface ClientRenameWorkspaceErrorInternal {
     tag: "ClientRenameWorkspaceErrorInternal"
     error: string
@@ -2270,6 +2558,7 @@ export interface ClientRenameWorkspaceErrorWorkspaceNotFound {
 }
 export type ClientRenameWorkspaceError =
   | ClientRenameWorkspaceErrorAuthorNotAllowed
+  | ClientRenameWorkspaceErrorClockDrift
   | ClientRenameWorkspaceErrorInternal
   | ClientRenameWorkspaceErrorInvalidCertificate
   | ClientRenameWorkspaceErrorInvalidEncryptedRealmName
@@ -2287,6 +2576,10 @@ export interface ClientRevokeUserErrorAuthorNotAllowed {
     tag: "ClientRevokeUserErrorAuthorNotAllowed"
     error: string
 }
+export interface ClientRevokeUserErrorClockDrift {
+    tag: "ClientRevokeUserErrorClockDrift"
+    error: string
+}
 export interface ClientRevokeUserErrorInternal {
     tag: "ClientRevokeUserErrorInternal"
     error: string
@@ -2307,6 +2600,11 @@ export interface ClientRevokeUserErrorOffline {
     tag: "ClientRevokeUserErrorOffline"
     error: string
 }
+export interface ClientRevokeUserErrorSoleOwnerOfWorkspaces {
+    tag: "ClientRevokeUserErrorSoleOwnerOfWorkspaces"
+    error: string
+    workspaces: Array<string>
+}
 export interface ClientRevokeUserErrorStopped {
     tag: "ClientRevokeUserErrorStopped"
     error: string
@@ -2325,11 +2623,13 @@ export interface ClientRevokeUserErrorUserNotFound {
 }
 export type ClientRevokeUserError =
   | ClientRevokeUserErrorAuthorNotAllowed
+  | ClientRevokeUserErrorClockDrift
   | ClientRevokeUserErrorInternal
   | ClientRevokeUserErrorInvalidCertificate
   | ClientRevokeUserErrorInvalidKeysBundle
   | ClientRevokeUserErrorNoKey
   | ClientRevokeUserErrorOffline
+  | ClientRevokeUserErrorSoleOwnerOfWorkspaces
   | ClientRevokeUserErrorStopped
   | ClientRevokeUserErrorTimestampOutOfBallpark
   | ClientRevokeUserErrorUserIsSelf
@@ -2341,6 +2641,10 @@ export interface ClientSetupShamirRecoveryErrorAuthorAmongRecipients {
     tag: "ClientSetupShamirRecoveryErrorAuthorAmongRecipients"
     error: string
 }
+export interface ClientSetupShamirRecoveryErrorClockDrift {
+    tag: "ClientSetupShamirRecoveryErrorClockDrift"
+    error: string
+}
 export interface ClientSetupShamirRecoveryErrorInternal {
     tag: "ClientSetupShamirRecoveryErrorInternal"
     error: string
@@ -2387,6 +2691,7 @@ export interface ClientSetupShamirRecoveryErrorTooManyShares {
 }
 export type ClientSetupShamirRecoveryError =
   | ClientSetupShamirRecoveryErrorAuthorAmongRecipients
+  | ClientSetupShamirRecoveryErrorClockDrift
   | ClientSetupShamirRecoveryErrorInternal
   | ClientSetupShamirRecoveryErrorInvalidCertificate
   | ClientSetupShamirRecoveryErrorOffline
@@ -2404,6 +2709,10 @@ export interface ClientShareWorkspaceErrorAuthorNotAllowed {
     tag: "ClientShareWorkspaceErrorAuthorNotAllowed"
     error: string
 }
+export interface ClientShareWorkspaceErrorClockDrift {
+    tag: "ClientShareWorkspaceErrorClockDrift"
+    error: string
+}
 export interface ClientShareWorkspaceErrorInternal {
     tag: "ClientShareWorkspaceErrorInternal"
     error: string
@@ -2458,6 +2767,7 @@ export interface ClientShareWorkspaceErrorWorkspaceNotFound {
 }
 export type ClientShareWorkspaceError =
   | ClientShareWorkspaceErrorAuthorNotAllowed
+  | ClientShareWorkspaceErrorClockDrift
   | ClientShareWorkspaceErrorInternal
   | ClientShareWorkspaceErrorInvalidCertificate
   | ClientShareWorkspaceErrorInvalidKeysBundle
@@ -2649,11 +2959,93 @@ export type ClientTotpSetupStatusError =
   | ClientTotpSetupStatusErrorOffline
 
 
+// ClientTransferWorkspaceOwnershipError
+export interface ClientTransferWorkspaceOwnershipErrorAuthorNotAllowed {
+    tag: "ClientTransferWorkspaceOwnershipErrorAuthorNotAllowed"
+    error: string
+}
+export interface ClientTransferWorkspaceOwnershipErrorClockDrift {
+    tag: "ClientTransferWorkspaceOwnershipErrorClockDrift"
+    error: string
+}
+export interface ClientTransferWorkspaceOwnershipErrorInternal {
+    tag: "ClientTransferWorkspaceOwnershipErrorInternal"
+    error: string
+}
+export interface ClientTransferWorkspaceOwnershipErrorInvalidCertificate {
+    tag: "ClientTransferWorkspaceOwnershipErrorInvalidCertificate"
+    error: string
+}
+export interface ClientTransferWorkspaceOwnershipErrorInvalidKeysBundle {
+    tag: "ClientTransferWorkspaceOwnershipErrorInvalidKeysBundle"
+    error: string
+}
+export interface ClientTransferWorkspaceOwnershipErrorNewOwnerIsOutsider {
+    tag: "ClientTransferWorkspaceOwnershipErrorNewOwnerIsOutsider"
+    error: string
+}
+export interface ClientTransferWorkspaceOwnershipErrorNewOwnerIsSelf {
+    tag: "ClientTransferWorkspaceOwnershipErrorNewOwnerIsSelf"
+    error: string
+}
+export interface ClientTransferWorkspaceOwnershipErrorNewOwnerNotFound {
+    tag: "ClientTransferWorkspaceOwnershipErrorNewOwnerNotFound"
+    error: string
+}
+export interface ClientTransferWorkspaceOwnershipErrorNewOwnerRevoked {
+    tag: "ClientTransferWorkspaceOwnershipErrorNewOwnerRevoked"
+    error: string
+}
+export interface ClientTransferWorkspaceOwnershipErrorOffline {
+    tag: "ClientTransferWorkspaceOwnershipErrorOffline"
+    error: string
+}
+export interface ClientTransferWorkspaceOwnershipErrorRealmDeleted {
+    tag: "ClientTransferWorkspaceOwnershipErrorRealmDeleted"
+    error: string
+}
+export interface ClientTransferWorkspaceOwnershipErrorStopped {
+    tag: "ClientTransferWorkspaceOwnershipErrorStopped"
+    error: string
+}
+export interface ClientTransferWorkspaceOwnershipErrorTimestampOutOfBallpark {
+    tag: "ClientTransferWorkspaceOwnershipErrorTimestampOutOfBallpark"
+    error: string
+    server_timestamp: number
+    client_timestamp: number
+    ballpark_client_early_offset: number
+    ballpark_client_late_offset: number
+}
+export interface ClientTransferWorkspaceOwnershipErrorWorkspaceNotFound {
+    tag: "ClientTransferWorkspaceOwnershipErrorWorkspaceNotFound"
+    error: string
+}
+export type ClientTransferWorkspaceOwnershipError =
+  | ClientTransferWorkspaceOwnershipErrorAuthorNotAllowed
+  | ClientTransferWorkspaceOwnershipErrorClockDrift
+  | ClientTransferWorkspaceOwnershipErrorInternal
+  | ClientTransferWorkspaceOwnershipErrorInvalidCertificate
+  | ClientTransferWorkspaceOwnershipErrorInvalidKeysBundle
+  | ClientTransferWorkspaceOwnershipErrorNewOwnerIsOutsider
+  | ClientTransferWorkspaceOwnershipErrorNewOwnerIsSelf
+  | ClientTransferWorkspaceOwnershipErrorNewOwnerNotFound
+  | ClientTransferWorkspaceOwnershipErrorNewOwnerRevoked
+  | ClientTransferWorkspaceOwnershipErrorOffline
+  | ClientTransferWorkspaceOwnershipErrorRealmDeleted
+  | ClientTransferWorkspaceOwnershipErrorStopped
+  | ClientTransferWorkspaceOwnershipErrorTimestampOutOfBallpark
+  | ClientTransferWorkspaceOwnershipErrorWorkspaceNotFound
+
+
 // ClientUserUpdateProfileError
 export interface ClientUserUpdateProfileErrorAuthorNotAllowed {
     tag: "ClientUserUpdateProfileErrorAuthorNotAllowed"
     error: string
 }
+export interface ClientUserUpdateProfileErrorClockDrift {
+    tag: "ClientUserUpdateProfileErrorClockDrift"
+    error: string
+}
 export interface ClientUserUpdateProfileErrorInternal {
     tag: "ClientUserUpdateProfileErrorInternal"
     error: string
@@ -2666,6 +3058,11 @@ export interface ClientUserUpdateProfileErrorOffline {
     tag: "ClientUserUpdateProfileErrorOffline"
     error: string
 }
+export interface ClientUserUpdateProfileErrorSoleOwnerOfWorkspaces {
+    tag: "ClientUserUpdateProfileErrorSoleOwnerOfWorkspaces"
+    error: string
+    workspaces: Array<string>
+}
 export interface ClientUserUpdateProfileErrorStopped {
     tag: "ClientUserUpdateProfileErrorStopped"
     error: string
@@ -2688,9 +3085,11 @@ export interface ClientUserUpdateProfileErrorUserRevoked {
 }
 export type ClientUserUpdateProfileError =
   | ClientUserUpdateProfileErrorAuthorNotAllowed
+  | ClientUserUpdateProfileErrorClockDrift
   | ClientUserUpdateProfileErrorInternal
   | ClientUserUpdateProfileErrorInvalidCertificate
   | ClientUserUpdateProfileErrorOffline
+  | ClientUserUpdateProfileErrorSoleOwnerOfWorkspaces
   | ClientUserUpdateProfileErrorStopped
   | ClientUserUpdateProfileErrorTimestampOutOfBallpark
   | ClientUserUpdateProfileErrorUserIsSelf
@@ -2703,9 +3102,21 @@ export interface DevicePrimaryProtectionStrategyAccountVault {
     tag: "DevicePrimaryProtectionStrategyAccountVault"
     account_handle: number
 }
+export interface DevicePrimaryProtectionStrategyFido2 {
+    tag: "DevicePrimaryProtectionStrategyFido2"
+    pin: string | null
+}
 export interface DevicePrimaryProtectionStrategyKeyring {
     tag: "DevicePrimaryProtectionStrategyKeyring"
 }
+export interface DevicePrimaryProtectionStrategyMachineKey {
+    tag: "DevicePrimaryProtectionStrategyMachineKey"
+    machine_key_file: string
+}
+export interface DevicePrimaryProtectionStrategyMulti {
+    tag: "DevicePrimaryProtectionStrategyMulti"
+    alternatives: Array<DeviceAlternativeProtection>
+}
 export interface DevicePrimaryProtectionStrategyOpenBao {
     tag: "DevicePrimaryProtectionStrategyOpenBao"
     openbao_server_url: string
@@ -2714,6 +3125,7 @@ export interface DevicePrimaryProtectionStrategyOpenBao {
     openbao_entity_id: string
     openbao_auth_token: string
     openbao_preferred_auth_id: string
+    openbao_reauthentication_method: OpenBaoAuthMethod | null
 }
 export interface DevicePrimaryProtectionStrategyPKI {
     tag: "DevicePrimaryProtectionStrategyPKI"
@@ -2725,7 +3137,10 @@ export interface DevicePrimaryProtectionStrategyPassword {
 }
 export type DevicePrimaryProtectionStrategy =
   | DevicePrimaryProtectionStrategyAccountVault
+  | DevicePrimaryProtectionStrategyFido2
   | DevicePrimaryProtectionStrategyKeyring
+  | DevicePrimaryProtectionStrategyMachineKey
+  | DevicePrimaryProtectionStrategyMulti
   | DevicePrimaryProtectionStrategyOpenBao
   | DevicePrimaryProtectionStrategyPKI
   | DevicePrimaryProtectionStrategyPassword
@@ -2819,6 +3234,10 @@ export interface GreetInProgressErrorCancelled {
     tag: "GreetInProgressErrorCancelled"
     error: string
 }
+export interface GreetInProgressErrorClockDrift {
+    tag: "GreetInProgressErrorClockDrift"
+    error: string
+}
 export interface GreetInProgressErrorCorruptedInviteUserData {
     tag: "GreetInProgressErrorCorruptedInviteUserData"
     error: string
@@ -2886,6 +3305,7 @@ export type GreetInProgressError =
   | GreetInProgressErrorActiveUsersLimitReached
   | GreetInProgressErrorAlreadyDeleted
   | GreetInProgressErrorCancelled
+  | GreetInProgressErrorClockDrift
   | GreetInProgressErrorCorruptedInviteUserData
   | GreetInProgressErrorCorruptedSharedSecretKey
   | GreetInProgressErrorDeviceAlreadyExists
@@ -2903,10 +3323,18 @@ export type GreetInProgressError =
 
 
 // ImportRecoveryDeviceError
+export interface ImportRecoveryDeviceErrorClockDrift {
+    tag: "ImportRecoveryDeviceErrorClockDrift"
+    error: string
+}
 export interface ImportRecoveryDeviceErrorDecryptionFailed {
     tag: "ImportRecoveryDeviceErrorDecryptionFailed"
     error: string
 }
+export interface ImportRecoveryDeviceErrorFido2HmacSecretNotSupported {
+    tag: "ImportRecoveryDeviceErrorFido2HmacSecretNotSupported"
+    error: string
+}
 export interface ImportRecoveryDeviceErrorInternal {
     tag: "ImportRecoveryDeviceErrorInternal"
     error: string
@@ -2956,7 +3384,9 @@ export interface ImportRecoveryDeviceErrorTimestampOutOfBallpark {
     ballpark_client_late_offset: number
 }
 export type ImportRecoveryDeviceError =
+  | ImportRecoveryDeviceErrorClockDrift
   | ImportRecoveryDeviceErrorDecryptionFailed
+  | ImportRecoveryDeviceErrorFido2HmacSecretNotSupported
   | ImportRecoveryDeviceErrorInternal
   | ImportRecoveryDeviceErrorInvalidCertificate
   | ImportRecoveryDeviceErrorInvalidData
@@ -3131,6 +3561,31 @@ export type OpenBaoAuthConfig =
   | OpenBaoAuthConfigOIDCProConnect
 
 
+// OpenBaoAuthMethod
+export interface OpenBaoAuthMethodAppRole {
+    tag: "OpenBaoAuthMethodAppRole"
+    mount_path: string
+    role_id: string
+    secret_id: string
+}
+export interface OpenBaoAuthMethodJwt {
+    tag: "OpenBaoAuthMethodJwt"
+    mount_path: string
+    role: string
+    jwt: string
+}
+export interface OpenBaoAuthMethodUserpass {
+    tag: "OpenBaoAuthMethodUserpass"
+    mount_path: string
+    username: string
+    password: string
+}
+export type OpenBaoAuthMethod =
+  | OpenBaoAuthMethodAppRole
+  | OpenBaoAuthMethodJwt
+  | OpenBaoAuthMethodUserpass
+
+
 // OpenBaoListSelfEmailsError
 export interface OpenBaoListSelfEmailsErrorBadServerResponse {
     tag: "OpenBaoListSelfEmailsErrorBadServerResponse"
@@ -3798,6 +4253,10 @@ export interface SubmitterFinalizeAsyncEnrollmentErrorPKIUnusableX509Certificate
     tag: "SubmitterFinalizeAsyncEnrollmentErrorPKIUnusableX509CertificateReference"
     error: string
 }
+export interface SubmitterFinalizeAsyncEnrollmentErrorSaveDeviceFido2HmacSecretNotSupported {
+    tag: "SubmitterFinalizeAsyncEnrollmentErrorSaveDeviceFido2HmacSecretNotSupported"
+    error: string
+}
 export interface SubmitterFinalizeAsyncEnrollmentErrorSaveDeviceInvalidPath {
     tag: "SubmitterFinalizeAsyncEnrollmentErrorSaveDeviceInvalidPath"
     error: string
@@ -3826,6 +4285,7 @@ export type SubmitterFinalizeAsyncEnrollmentError =
   | SubmitterFinalizeAsyncEnrollmentErrorOpenBaoNoServerResponse
   | SubmitterFinalizeAsyncEnrollmentErrorPKICannotOpenCertificateStore
   | SubmitterFinalizeAsyncEnrollmentErrorPKIUnusableX509CertificateReference
+  | SubmitterFinalizeAsyncEnrollmentErrorSaveDeviceFido2HmacSecretNotSupported
   | SubmitterFinalizeAsyncEnrollmentErrorSaveDeviceInvalidPath
   | SubmitterFinalizeAsyncEnrollmentErrorSaveDeviceRemoteOpaqueKeyUploadFailed
   | SubmitterFinalizeAsyncEnrollmentErrorSaveDeviceRemoteOpaqueKeyUploadOffline
@@ -3963,6 +4423,10 @@ export interface UpdateDeviceErrorDecryptionFailed {
     tag: "UpdateDeviceErrorDecryptionFailed"
     error: string
 }
+export interface UpdateDeviceErrorFido2HmacSecretNotSupported {
+    tag: "UpdateDeviceErrorFido2HmacSecretNotSupported"
+    error: string
+}
 export interface UpdateDeviceErrorInternal {
     tag: "UpdateDeviceErrorInternal"
     error: string
@@ -3993,6 +4457,7 @@ export interface UpdateDeviceErrorTOTPDecryptionFailed {
 }
 export type UpdateDeviceError =
   | UpdateDeviceErrorDecryptionFailed
+  | UpdateDeviceErrorFido2HmacSecretNotSupported
   | UpdateDeviceErrorInternal
   | UpdateDeviceErrorInvalidData
   | UpdateDeviceErrorInvalidPath
@@ -4415,6 +4880,15 @@ export type WorkspaceGeneratePathAddrError =
   | WorkspaceGeneratePathAddrErrorStopped
 
 
+// WorkspaceGetRemoteOnlyEntriesError
+export interface WorkspaceGetRemoteOnlyEntriesErrorInternal {
+    tag: "WorkspaceGetRemoteOnlyEntriesErrorInternal"
+    error: string
+}
+export type WorkspaceGetRemoteOnlyEntriesError =
+  | WorkspaceGetRemoteOnlyEntriesErrorInternal
+
+
 // WorkspaceHistoryEntryStat
 export interface WorkspaceHistoryEntryStatFile {
     tag: "WorkspaceHistoryEntryStatFile"
@@ -4860,6 +5334,55 @@ export type WorkspaceInfoError =
   | WorkspaceInfoErrorInternal
 
 
+// WorkspaceIsEntryRemoteOnlyError
+export interface WorkspaceIsEntryRemoteOnlyErrorEntryNotFound {
+    tag: "WorkspaceIsEntryRemoteOnlyErrorEntryNotFound"
+    error: string
+}
+export interface WorkspaceIsEntryRemoteOnlyErrorInternal {
+    tag: "WorkspaceIsEntryRemoteOnlyErrorInternal"
+    error: string
+}
+export interface WorkspaceIsEntryRemoteOnlyErrorInvalidCertificate {
+    tag: "WorkspaceIsEntryRemoteOnlyErrorInvalidCertificate"
+    error: string
+}
+export interface WorkspaceIsEntryRemoteOnlyErrorInvalidKeysBundle {
+    tag: "WorkspaceIsEntryRemoteOnlyErrorInvalidKeysBundle"
+    error: string
+}
+export interface WorkspaceIsEntryRemoteOnlyErrorInvalidManifest {
+    tag: "WorkspaceIsEntryRemoteOnlyErrorInvalidManifest"
+    error: string
+}
+export interface WorkspaceIsEntryRemoteOnlyErrorNoRealmAccess {
+    tag: "WorkspaceIsEntryRemoteOnlyErrorNoRealmAccess"
+    error: string
+}
+export interface WorkspaceIsEntryRemoteOnlyErrorOffline {
+    tag: "WorkspaceIsEntryRemoteOnlyErrorOffline"
+    error: string
+}
+export interface WorkspaceIsEntryRemoteOnlyErrorRealmDeleted {
+    tag: "WorkspaceIsEntryRemoteOnlyErrorRealmDeleted"
+    error: string
+}
+export interface WorkspaceIsEntryRemoteOnlyErrorStopped {
+    tag: "WorkspaceIsEntryRemoteOnlyErrorStopped"
+    error: string
+}
+export type WorkspaceIsEntryRemoteOnlyError =
+  | WorkspaceIsEntryRemoteOnlyErrorEntryNotFound
+  | WorkspaceIsEntryRemoteOnlyErrorInternal
+  | WorkspaceIsEntryRemoteOnlyErrorInvalidCertificate
+  | WorkspaceIsEntryRemoteOnlyErrorInvalidKeysBundle
+  | WorkspaceIsEntryRemoteOnlyErrorInvalidManifest
+  | WorkspaceIsEntryRemoteOnlyErrorNoRealmAccess
+  | WorkspaceIsEntryRemoteOnlyErrorOffline
+  | WorkspaceIsEntryRemoteOnlyErrorRealmDeleted
+  | WorkspaceIsEntryRemoteOnlyErrorStopped
+
+
 // WorkspaceIsFileContentLocalError
 export interface WorkspaceIsFileContentLocalErrorEntryNotFound {
     tag: "WorkspaceIsFileContentLocalErrorEntryNotFound"
@@ -5135,6 +5658,20 @@ export type WorkspaceRemoveEntryError =
   | WorkspaceRemoveEntryErrorStopped
 
 
+// WorkspaceSetRemoteOnlyEntriesError
+export interface WorkspaceSetRemoteOnlyEntriesErrorInternal {
+    tag: "WorkspaceSetRemoteOnlyEntriesErrorInternal"
+    error: string
+}
+export interface WorkspaceSetRemoteOnlyEntriesErrorStopped {
+    tag: "WorkspaceSetRemoteOnlyEntriesErrorStopped"
+    error: string
+}
+export type WorkspaceSetRemoteOnlyEntriesError =
+  | WorkspaceSetRemoteOnlyEntriesErrorInternal
+  | WorkspaceSetRemoteOnlyEntriesErrorStopped
+
+
 // WorkspaceStatEntryError
 export interface WorkspaceStatEntryErrorEntryNotFound {
     tag: "WorkspaceStatEntryErrorEntryNotFound"
@@ -5632,6 +6169,10 @@ export function clientRejectAsyncEnrollment(
     client: number,
     enrollment_id: string
 ): Promise<Result<null, ClientRejectAsyncEnrollmentError>>
+export function clientRekeyWorkspace(
+    client: number,
+    realm_id: string
+): Promise<Result<null, ClientRekeyWorkspaceError>>
 export function clientRenameWorkspace(
     client: number,
     realm_id: string,
@@ -5639,7 +6180,8 @@ export function clientRenameWorkspace(
 ): Promise<Result<null, ClientRenameWorkspaceError>>
 export function clientRevokeUser(
     client: number,
-    user: string
+    user: string,
+    orphaned_workspaces: OrphanedWorkspacesStrategy
 ): Promise<Result<null, ClientRevokeUserError>>
 export function clientSetupShamirRecovery(
     client_handle: number,
@@ -5650,7 +6192,8 @@ export function clientShareWorkspace(
     client: number,
     realm_id: string,
     recipient: string,
-    role: RealmRole | null
+    role: RealmRole | null,
+    expires_on: number | null
 ): Promise<Result<null, ClientShareWorkspaceError>>
 export function clientStart(
     config: ClientConfig,
@@ -5689,15 +6232,23 @@ export function clientTotpSetupConfirm(
 export function clientTotpSetupStatus(
     client: number
 ): Promise<Result<TOTPSetupStatus, ClientTotpSetupStatusError>>
+export function clientTransferWorkspaceOwnership(
+    client: number,
+    realm_id: string,
+    new_owner: string
+): Promise<Result<null, ClientTransferWorkspaceOwnershipError>>
 export function clientUpdateUserProfile(
     client_handle: number,
     user: string,
-    new_profile: UserProfile
+    new_profile: UserProfile,
+    orphaned_workspaces: OrphanedWorkspacesStrategy
 ): Promise<Result<null, ClientUserUpdateProfileError>>
 export function getDefaultConfigDir(
 ): Promise<string>
 export function getDefaultDataBaseDir(
 ): Promise<string>
+export function getDefaultMachineKeyFile(
+): Promise<string>
 export function getDefaultMountpointBaseDir(
 ): Promise<string>
 export function getPlatform(
@@ -6015,6 +6566,9 @@ export function workspaceGeneratePathAddr(
     workspace: number,
     path: string
 ): Promise<Result<[string, string], WorkspaceGeneratePathAddrError>>
+export function workspaceGetRemoteOnlyEntries(
+    workspace: number
+): Promise<Result<Array<string>, WorkspaceGetRemoteOnlyEntriesError>>
 export function workspaceHistoryFdClose(
     workspace_history: number,
     fd: number
@@ -6081,6 +6635,10 @@ export function workspaceHistoryStop(
 export function workspaceInfo(
     workspace: number
 ): Promise<Result<StartedWorkspaceInfo, WorkspaceInfoError>>
+export function workspaceIsEntryRemoteOnlyById(
+    workspace: number,
+    entry_id: string
+): Promise<Result<boolean, WorkspaceIsEntryRemoteOnlyError>>
 export function workspaceIsFileContentLocal(
     workspace: number,
     path: string
@@ -6132,6 +6690,10 @@ export function workspaceRenameEntryById(
     dst_name: string,
     mode: MoveEntryMode
 ): Promise<Result<null, WorkspaceMoveEntryError>>
+export function workspaceSetRemoteOnlyEntries(
+    workspace: number,
+    entries: Array<string>
+): Promise<Result<null, WorkspaceSetRemoteOnlyEntriesError>>
 export function workspaceStatEntry(
     workspace: number,
     path: string
@@ -295,6 +295,32 @@ fn enum_log_level_rs_to_js(value: libparsec::LogLevel) -> &'static str {
     }
 }
 
+// OrphanedWorkspacesStrategy
+
+#[allow(dead_code)]
+fn enum_orphaned_workspaces_strategy_js_to_rs<'a>(
+    cx: &mut impl Context<'a>,
+    raw_value: &str,
+) -> NeonResult<libparsec::OrphanedWorkspacesStrategy> {
+    match raw_value {
+        "OrphanedWorkspacesStrategyAbort" => Ok(libparsec::OrphanedWorkspacesStrategy::Abort),
+        "OrphanedWorkspacesStrategyIgnore" => Ok(libparsec::OrphanedWorkspacesStrategy::Ignore),
+        _ => cx.throw_range_error(format!(
+            "Invalid value `{raw_value}` for enum OrphanedWorkspacesStrategy"
+        )),
+    }
+}
+
+#[allow(dead_code)]
+fn enum_orphaned_workspaces_strategy_rs_to_js(
+    value: libparsec::OrphanedWorkspacesStrategy,
+) -> &'static str {
+    match value {
+        libparsec::OrphanedWorkspacesStrategy::Abort => "OrphanedWorkspacesStrategyAbort",
+        libparsec::OrphanedWorkspacesStrategy::Ignore => "OrphanedWorkspacesStrategyIgnore",
+    }
+}
+
 // Platform
 
 #[allow(dead_code)]
@@ -399,6 +425,30 @@ fn enum_user_profile_rs_to_js(value: libparsec::UserProfile) -> &'static str {
     }
 }
 
+// X509RevocationPolicy
+
+#[allow(dead_code)]
+fn enum_x509_revocation_policy_js_to_rs<'a>(
+    cx: &mut impl Context<'a>,
+    raw_value: &str,
+) -> NeonResult<libparsec::X509RevocationPolicy> {
+    match raw_value {
+        "X509RevocationPolicyHardFail" => Ok(libparsec::X509RevocationPolicy::HardFail),
+        "X509RevocationPolicySoftFail" => Ok(libparsec::X509RevocationPolicy::SoftFail),
+        _ => cx.throw_range_error(format!(
+            "Invalid value `{raw_value}` for enum X509RevocationPolicy"
+        )),
+    }
+}
+
+#[allow(dead_code)]
+fn enum_x509_revocation_policy_rs_to_js(value: libparsec::X509RevocationPolicy) -> &'static str {
+    match value {
+        libparsec::X509RevocationPolicy::HardFail => "X509RevocationPolicyHardFail",
+        libparsec::X509RevocationPolicy::SoftFail => "X509RevocationPolicySoftFail",
+    }
+}
+
 // AccountInfo
 
 #[allow(dead_code)]
@@ -1294,6 +1344,69 @@ fn struct_available_device_rs_to_js<'a>(
     Ok(js_obj)
 }
 
+// AvailableDeviceMultiProtection
+
+#[allow(dead_code)]
+fn struct_available_device_multi_protection_js_to_rs<'a>(
+    cx: &mut impl Context<'a>,
+    obj: Handle<'a, JsObject>,
+) -> NeonResult<libparsec::AvailableDeviceMultiProtection> {
+    let totp_opaque_key_id = {
+        let js_val: Handle<JsValue> = obj.get(cx, "totpOpaqueKeyId")?;
+        {
+            if js_val.is_a::<JsNull, _>(cx) {
+                None
+            } else {
+                let js_val = js_val.downcast_or_throw::<JsString, _>(cx)?;
+                Some({
+                    let custom_from_rs_string =
+                        |s: String| -> Result<libparsec::TOTPOpaqueKeyID, _> {
+                            libparsec::TOTPOpaqueKeyID::from_hex(s.as_str())
+                                .map_err(|e| e.to_string())
+                        };
+                    match custom_from_rs_string(js_val.value(cx)) {
+                        Ok(val) => val,
+                        Err(err) => return cx.throw_type_error(err),
+                    }
+                })
+            }
+        }
+    };
+    let ty = {
+        let js_val: Handle<JsObject> = obj.get(cx, "ty")?;
+        variant_available_device_multi_protection_type_js_to_rs(cx, js_val)?
+    };
+    Ok(libparsec::AvailableDeviceMultiProtection {
+        totp_opaque_key_id,
+        ty,
+    })
+}
+
+#[allow(dead_code)]
+fn struct_available_device_multi_protection_rs_to_js<'a>(
+    cx: &mut impl Context<'a>,
+    rs_obj: libparsec::AvailableDeviceMultiProtection,
+) -> NeonResult<Handle<'a, JsObject>> {
+    let js_obj = cx.empty_object();
+    let js_totp_opaque_key_id = match rs_obj.totp_opaque_key_id {
+        Some(elem) => JsString::try_new(cx, {
+            let custom_to_rs_string =
+                |x: libparsec::TOTPOpaqueKeyID| -> Result<String, &'static str> { Ok(x.hex()) };
+            match custom_to_rs_string(elem) {
+                Ok(ok) => ok,
+                Err(err) => return cx.throw_type_error(err.to_string()),
+            }
+        })
+        .or_throw(cx)?
+        .as_value(cx),
+        None => JsNull::new(cx).as_value(cx),
+    };
+    js_obj.set(cx, "totpOpaqueKeyId", js_totp_opaque_key_id)?;
+    let js_ty = variant_available_device_multi_protection_type_rs_to_js(cx, rs_obj.ty)?;
+    js_obj.set(cx, "ty", js_ty)?;
+    Ok(js_obj)
+}
+
 // AvailablePendingAsyncEnrollment
 
 #[allow(dead_code)]
@@ -1512,6 +1625,31 @@ fn struct_client_config_js_to_rs<'a>(
             }
         }
     };
+    let pki_revocation_policy = {
+        let js_val: Handle<JsValue> = obj.get(cx, "pkiRevocationPolicy")?;
+        {
+            if js_val.is_a::<JsNull, _>(cx) {
+                None
+            } else {
+                let js_val = js_val.downcast_or_throw::<JsString, _>(cx)?;
+                Some({
+                    let js_string = js_val.value(cx);
+                    enum_x509_revocation_policy_js_to_rs(cx, js_string.as_str())?
+                })
+            }
+        }
+    };
+    let outbound_sync = {
+        let js_val: Handle<JsValue> = obj.get(cx, "outboundSync")?;
+        {
+            if js_val.is_a::<JsNull, _>(cx) {
+                None
+            } else {
+                let js_val = js_val.downcast_or_throw::<JsObject, _>(cx)?;
+                Some(struct_outbound_sync_config_js_to_rs(cx, js_val)?)
+            }
+        }
+    };
     Ok(libparsec::ClientConfig {
         config_dir,
         data_base_dir,
@@ -1520,6 +1658,8 @@ fn struct_client_config_js_to_rs<'a>(
         with_monitors,
         prevent_sync_pattern,
         log_level,
+        pki_revocation_policy,
+        outbound_sync,
     })
 }
 
@@ -1579,6 +1719,18 @@ fn struct_client_config_rs_to_js<'a>(
         None => JsNull::new(cx).as_value(cx),
     };
     js_obj.set(cx, "logLevel", js_log_level)?;
+    let js_pki_revocation_policy = match rs_obj.pki_revocation_policy {
+        Some(elem) => JsString::try_new(cx, enum_x509_revocation_policy_rs_to_js(elem))
+            .or_throw(cx)?
+            .as_value(cx),
+        None => JsNull::new(cx).as_value(cx),
+    };
+    js_obj.set(cx, "pkiRevocationPolicy", js_pki_revocation_policy)?;
+    let js_outbound_sync = match rs_obj.outbound_sync {
+        Some(elem) => struct_outbound_sync_config_rs_to_js(cx, elem)?.as_value(cx),
+        None => JsNull::new(cx).as_value(cx),
+    };
+    js_obj.set(cx, "outboundSync", js_outbound_sync)?;
     Ok(js_obj)
 }
 
@@ -1897,6 +2049,99 @@ fn struct_device_access_strategy_rs_to_js<'a>(
     Ok(js_obj)
 }
 
+// DeviceAlternativeProtection
+
+#[allow(dead_code)]
+fn struct_device_alternative_protection_js_to_rs<'a>(
+    cx: &mut impl Context<'a>,
+    obj: Handle<'a, JsObject>,
+) -> NeonResult<libparsec::DeviceAlternativeProtection> {
+    let totp_protection = {
+        let js_val: Handle<JsValue> = obj.get(cx, "totpProtection")?;
+        {
+            if js_val.is_a::<JsNull, _>(cx) {
+                None
+            } else {
+                let js_val = js_val.downcast_or_throw::<JsArray, _>(cx)?;
+                Some((
+                    {
+                        let js_item: Handle<JsString> = js_val.get(cx, 0)?;
+                        {
+                            let custom_from_rs_string =
+                                |s: String| -> Result<libparsec::TOTPOpaqueKeyID, _> {
+                                    libparsec::TOTPOpaqueKeyID::from_hex(s.as_str())
+                                        .map_err(|e| e.to_string())
+                                };
+                            match custom_from_rs_string(js_item.value(cx)) {
+                                Ok(val) => val,
+                                Err(err) => return cx.throw_type_error(err),
+                            }
+                        }
+                    },
+                    {
+                        let js_item: Handle<JsTypedArray<u8>> = js_val.get(cx, 1)?;
+                        {
+                            #[allow(clippy::unnecessary_mut_passed)]
+                            match js_item.as_slice(cx).try_into() {
+                                Ok(val) => val,
+                                // err can't infer type in some case, because of the previous `try_into`
+                                #[allow(clippy::useless_format)]
+                                Err(err) => return cx.throw_type_error(format!("{}", err)),
+                            }
+                        }
+                    },
+                ))
+            }
+        }
+    };
+    let primary_protection = {
+        let js_val: Handle<JsObject> = obj.get(cx, "primaryProtection")?;
+        variant_device_primary_protection_strategy_js_to_rs(cx, js_val)?
+    };
+    Ok(libparsec::DeviceAlternativeProtection {
+        totp_protection,
+        primary_protection,
+    })
+}
+
+#[allow(dead_code)]
+fn struct_device_alternative_protection_rs_to_js<'a>(
+    cx: &mut impl Context<'a>,
+    rs_obj: libparsec::DeviceAlternativeProtection,
+) -> NeonResult<Handle<'a, JsObject>> {
+    let js_obj = cx.empty_object();
+    let js_totp_protection = match rs_obj.totp_protection {
+        Some(elem) => {
+            let (x0, x1) = elem;
+            let js_array = JsArray::new(cx, 2);
+            let js_value = JsString::try_new(cx, {
+                let custom_to_rs_string =
+                    |x: libparsec::TOTPOpaqueKeyID| -> Result<String, &'static str> { Ok(x.hex()) };
+                match custom_to_rs_string(x0) {
+                    Ok(ok) => ok,
+                    Err(err) => return cx.throw_type_error(err.to_string()),
+                }
+            })
+            .or_throw(cx)?;
+            js_array.set(cx, 0, js_value)?;
+            let js_value = {
+                let rs_buff = { x1 };
+                let js_buff = JsTypedArray::from_slice(cx, rs_buff.as_ref())?;
+                js_buff
+            };
+            js_array.set(cx, 1, js_value)?;
+            js_array
+        }
+        .as_value(cx),
+        None => JsNull::new(cx).as_value(cx),
+    };
+    js_obj.set(cx, "totpProtection", js_totp_protection)?;
+    let js_primary_protection =
+        variant_device_primary_protection_strategy_rs_to_js(cx, rs_obj.primary_protection)?;
+    js_obj.set(cx, "primaryProtection", js_primary_protection)?;
+    Ok(js_obj)
+}
+
 // DeviceClaimFinalizeInfo
 
 #[allow(dead_code)]
@@ -3060,6 +3305,166 @@ fn struct_organization_info_rs_to_js<'a>(
     Ok(js_obj)
 }
 
+// OutboundSyncConfig
+
+#[allow(dead_code)]
+fn struct_outbound_sync_config_js_to_rs<'a>(
+    cx: &mut impl Context<'a>,
+    obj: Handle<'a, JsObject>,
+) -> NeonResult<libparsec::OutboundSyncConfig> {
+    let delays = {
+        let js_val: Handle<JsValue> = obj.get(cx, "delays")?;
+        {
+            if js_val.is_a::<JsNull, _>(cx) {
+                None
+            } else {
+                let js_val = js_val.downcast_or_throw::<JsObject, _>(cx)?;
+                Some(struct_outbound_sync_delays_js_to_rs(cx, js_val)?)
+            }
+        }
+    };
+    let overrides = {
+        let js_val: Handle<JsArray> = obj.get(cx, "overrides")?;
+        {
+            let size = js_val.len(cx);
+            let mut v = Vec::with_capacity(size as usize);
+            for i in 0..size {
+                let js_item: Handle<JsObject> = js_val.get(cx, i)?;
+                v.push(struct_outbound_sync_delays_override_js_to_rs(cx, js_item)?);
+            }
+            v
+        }
+    };
+    let block_deduplication = {
+        let js_val: Handle<JsBoolean> = obj.get(cx, "blockDeduplication")?;
+        js_val.value(cx)
+    };
+    let block_compression = {
+        let js_val: Handle<JsBoolean> = obj.get(cx, "blockCompression")?;
+        js_val.value(cx)
+    };
+    let content_defined_chunking = {
+        let js_val: Handle<JsBoolean> = obj.get(cx, "contentDefinedChunking")?;
+        js_val.value(cx)
+    };
+    Ok(libparsec::OutboundSyncConfig {
+        delays,
+        overrides,
+        block_deduplication,
+        block_compression,
+        content_defined_chunking,
+    })
+}
+
+#[allow(dead_code)]
+fn struct_outbound_sync_config_rs_to_js<'a>(
+    cx: &mut impl Context<'a>,
+    rs_obj: libparsec::OutboundSyncConfig,
+) -> NeonResult<Handle<'a, JsObject>> {
+    let js_obj = cx.empty_object();
+    let js_delays = match rs_obj.delays {
+        Some(elem) => struct_outbound_sync_delays_rs_to_js(cx, elem)?.as_value(cx),
+        None => JsNull::new(cx).as_value(cx),
+    };
+    js_obj.set(cx, "delays", js_delays)?;
+    let js_overrides = {
+        // JsArray::new allocates with `undefined` value, that's why we `set` value
+        let js_array = JsArray::new(cx, rs_obj.overrides.len());
+        for (i, elem) in rs_obj.overrides.into_iter().enumerate() {
+            let js_elem = struct_outbound_sync_delays_override_rs_to_js(cx, elem)?;
+            js_array.set(cx, i as u32, js_elem)?;
+        }
+        js_array
+    };
+    js_obj.set(cx, "overrides", js_overrides)?;
+    let js_block_deduplication = JsBoolean::new(cx, rs_obj.block_deduplication);
+    js_obj.set(cx, "blockDeduplication", js_block_deduplication)?;
+    let js_block_compression = JsBoolean::new(cx, rs_obj.block_compression);
+    js_obj.set(cx, "blockCompression", js_block_compression)?;
+    let js_content_defined_chunking = JsBoolean::new(cx, rs_obj.content_defined_chunking);
+    js_obj.set(cx, "contentDefinedChunking", js_content_defined_chunking)?;
+    Ok(js_obj)
+}
+
+// OutboundSyncDelays
+
+#[allow(dead_code)]
+fn struct_outbound_sync_delays_js_to_rs<'a>(
+    cx: &mut impl Context<'a>,
+    obj: Handle<'a, JsObject>,
+) -> NeonResult<libparsec::OutboundSyncDelays> {
+    let quiescence_in_ms = {
+        let js_val: Handle<JsNumber> = obj.get(cx, "quiescenceInMs")?;
+        {
+            let v = js_val.value(cx);
+            if v < (u32::MIN as f64) || (u32::MAX as f64) < v {
+                cx.throw_type_error("Not an u32 number")?
+            }
+            let v = v as u32;
+            v
+        }
+    };
+    let max_delay_in_ms = {
+        let js_val: Handle<JsNumber> = obj.get(cx, "maxDelayInMs")?;
+        {
+            let v = js_val.value(cx);
+            if v < (u32::MIN as f64) || (u32::MAX as f64) < v {
+                cx.throw_type_error("Not an u32 number")?
+            }
+            let v = v as u32;
+            v
+        }
+    };
+    Ok(libparsec::OutboundSyncDelays {
+        quiescence_in_ms,
+        max_delay_in_ms,
+    })
+}
+
+#[allow(dead_code)]
+fn struct_outbound_sync_delays_rs_to_js<'a>(
+    cx: &mut impl Context<'a>,
+    rs_obj: libparsec::OutboundSyncDelays,
+) -> NeonResult<Handle<'a, JsObject>> {
+    let js_obj = cx.empty_object();
+    let js_quiescence_in_ms = JsNumber::new(cx, rs_obj.quiescence_in_ms as f64);
+    js_obj.set(cx, "quiescenceInMs", js_quiescence_in_ms)?;
+    let js_max_delay_in_ms = JsNumber::new(cx, rs_obj.max_delay_in_ms as f64);
+    js_obj.set(cx, "maxDelayInMs", js_max_delay_in_ms)?;
+    Ok(js_obj)
+}
+
+// OutboundSyncDelaysOverride
+
+#[allow(dead_code)]
+fn struct_outbound_sync_delays_override_js_to_rs<'a>(
+    cx: &mut impl Context<'a>,
+    obj: Handle<'a, JsObject>,
+) -> NeonResult<libparsec::OutboundSyncDelaysOverride> {
+    let pattern = {
+        let js_val: Handle<JsString> = obj.get(cx, "pattern")?;
+        js_val.value(cx)
+    };
+    let delays = {
+        let js_val: Handle<JsObject> = obj.get(cx, "delays")?;
+        struct_outbound_sync_delays_js_to_rs(cx, js_val)?
+    };
+    Ok(libparsec::OutboundSyncDelaysOverride { pattern, delays })
+}
+
+#[allow(dead_code)]
+fn struct_outbound_sync_delays_override_rs_to_js<'a>(
+    cx: &mut impl Context<'a>,
+    rs_obj: libparsec::OutboundSyncDelaysOverride,
+) -> NeonResult<Handle<'a, JsObject>> {
+    let js_obj = cx.empty_object();
+    let js_pattern = JsString::try_new(cx, rs_obj.pattern).or_throw(cx)?;
+    js_obj.set(cx, "pattern", js_pattern)?;
+    let js_delays = struct_outbound_sync_delays_rs_to_js(cx, rs_obj.delays)?;
+    js_obj.set(cx, "delays", js_delays)?;
+    Ok(js_obj)
+}
+
 // ServerConfig
 
 #[allow(dead_code)]
@@ -3186,10 +3591,33 @@ fn struct_server_organization_config_js_to_rs<'a>(
             v
         }
     };
+    let pki_crls = {
+        let js_val: Handle<JsArray> = obj.get(cx, "pkiCrls")?;
+        {
+            let size = js_val.len(cx);
+            let mut v = Vec::with_capacity(size as usize);
+            for i in 0..size {
+                let js_item: Handle<JsTypedArray<u8>> = js_val.get(cx, i)?;
+                v.push({
+                    let custom_from_rs_bytes =
+                        |v: &[u8]| -> Result<libparsec::Bytes, String> { Ok(v.to_vec().into()) };
+                    #[allow(clippy::unnecessary_mut_passed)]
+                    match custom_from_rs_bytes(js_item.as_slice(cx)) {
+                        Ok(val) => val,
+                        // err can't infer type in some case, because of the previous `try_into`
+                        #[allow(clippy::useless_format)]
+                        Err(err) => return cx.throw_type_error(format!("{}", err)),
+                    }
+                });
+            }
+            v
+        }
+    };
     Ok(libparsec::ServerOrganizationConfig {
         user_profile_outsider_allowed,
         active_users_limit,
         realm_minimum_archiving_period_before_deletion,
+        pki_crls,
     })
 }
 
@@ -3214,6 +3642,20 @@ fn struct_server_organization_config_rs_to_js<'a>(
         "realmMinimumArchivingPeriodBeforeDeletion",
         js_realm_minimum_archiving_period_before_deletion,
     )?;
+    let js_pki_crls = {
+        // JsArray::new allocates with `undefined` value, that's why we `set` value
+        let js_array = JsArray::new(cx, rs_obj.pki_crls.len());
+        for (i, elem) in rs_obj.pki_crls.into_iter().enumerate() {
+            let js_elem = {
+                let rs_buff = { elem };
+                let js_buff = JsTypedArray::from_slice(cx, rs_buff.as_ref())?;
+                js_buff
+            };
+            js_array.set(cx, i as u32, js_elem)?;
+        }
+        js_array
+    };
+    js_obj.set(cx, "pkiCrls", js_pki_crls)?;
     Ok(js_obj)
 }
 
@@ -5447,11 +5889,33 @@ fn struct_workspace_user_access_info_js_to_rs<'a>(
             enum_realm_role_js_to_rs(cx, js_string.as_str())?
         }
     };
+    let expires_on = {
+        let js_val: Handle<JsValue> = obj.get(cx, "expiresOn")?;
+        {
+            if js_val.is_a::<JsNull, _>(cx) {
+                None
+            } else {
+                let js_val = js_val.downcast_or_throw::<JsNumber, _>(cx)?;
+                Some({
+                    let v = js_val.value(cx);
+                    let custom_from_rs_f64 = |n: f64| -> Result<_, &'static str> {
+                        libparsec::DateTime::from_timestamp_micros((n * 1_000_000f64) as i64)
+                            .map_err(|_| "Out-of-bound datetime")
+                    };
+                    match custom_from_rs_f64(v) {
+                        Ok(val) => val,
+                        Err(err) => return cx.throw_type_error(err),
+                    }
+                })
+            }
+        }
+    };
     Ok(libparsec::WorkspaceUserAccessInfo {
         user_id,
         human_handle,
         current_profile,
         current_role,
+        expires_on,
     })
 }
 
@@ -5479,6 +5943,20 @@ fn struct_workspace_user_access_info_rs_to_js<'a>(
     let js_current_role =
         JsString::try_new(cx, enum_realm_role_rs_to_js(rs_obj.current_role)).or_throw(cx)?;
     js_obj.set(cx, "currentRole", js_current_role)?;
+    let js_expires_on = match rs_obj.expires_on {
+        Some(elem) => JsNumber::new(cx, {
+            let custom_to_rs_f64 = |dt: libparsec::DateTime| -> Result<f64, &'static str> {
+                Ok((dt.as_timestamp_micros() as f64) / 1_000_000f64)
+            };
+            match custom_to_rs_f64(elem) {
+                Ok(ok) => ok,
+                Err(err) => return cx.throw_type_error(err),
+            }
+        })
+        .as_value(cx),
+        None => JsNull::new(cx).as_value(cx),
+    };
+    js_obj.set(cx, "expiresOn", js_expires_on)?;
     Ok(js_obj)
 }
 
@@ -5926,6 +6404,11 @@ fn variant_account_create_registration_device_error_rs_to_js<'a>(
                     .or_throw(cx)?;
             js_obj.set(cx, "tag", js_tag)?;
         }
+        libparsec::AccountCreateRegistrationDeviceError::ClockDrift { .. } => {
+            let js_tag = JsString::try_new(cx, "AccountCreateRegistrationDeviceErrorClockDrift")
+                .or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+        }
         libparsec::AccountCreateRegistrationDeviceError::Internal { .. } => {
             let js_tag = JsString::try_new(cx, "AccountCreateRegistrationDeviceErrorInternal")
                 .or_throw(cx)?;
@@ -6576,6 +7059,11 @@ fn variant_account_register_new_device_error_rs_to_js<'a>(
                 .or_throw(cx)?;
             js_obj.set(cx, "tag", js_tag)?;
         }
+        libparsec::AccountRegisterNewDeviceError::ClockDrift { .. } => {
+            let js_tag =
+                JsString::try_new(cx, "AccountRegisterNewDeviceErrorClockDrift").or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+        }
         libparsec::AccountRegisterNewDeviceError::CorruptedRegistrationDevice { .. } => {
             let js_tag = JsString::try_new(
                 cx,
@@ -6584,6 +7072,14 @@ fn variant_account_register_new_device_error_rs_to_js<'a>(
             .or_throw(cx)?;
             js_obj.set(cx, "tag", js_tag)?;
         }
+        libparsec::AccountRegisterNewDeviceError::Fido2HmacSecretNotSupported { .. } => {
+            let js_tag = JsString::try_new(
+                cx,
+                "AccountRegisterNewDeviceErrorFido2HmacSecretNotSupported",
+            )
+            .or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+        }
         libparsec::AccountRegisterNewDeviceError::Internal { .. } => {
             let js_tag =
                 JsString::try_new(cx, "AccountRegisterNewDeviceErrorInternal").or_throw(cx)?;
@@ -7125,6 +7621,99 @@ fn variant_async_enrollment_identity_system_rs_to_js<'a>(
     Ok(js_obj)
 }
 
+// AvailableDeviceMultiProtectionType
+
+#[allow(dead_code)]
+fn variant_available_device_multi_protection_type_js_to_rs<'a>(
+    cx: &mut impl Context<'a>,
+    obj: Handle<'a, JsObject>,
+) -> NeonResult<libparsec::AvailableDeviceMultiProtectionType> {
+    let tag = obj.get::<JsString, _, _>(cx, "tag")?.value(cx);
+    match tag.as_str() {
+        "AvailableDeviceMultiProtectionTypeAccountVault" => {
+            Ok(libparsec::AvailableDeviceMultiProtectionType::AccountVault {})
+        }
+        "AvailableDeviceMultiProtectionTypeFido2" => {
+            Ok(libparsec::AvailableDeviceMultiProtectionType::Fido2 {})
+        }
+        "AvailableDeviceMultiProtectionTypeOpenBao" => {
+            let openbao_preferred_auth_id = {
+                let js_val: Handle<JsString> = obj.get(cx, "openbaoPreferredAuthId")?;
+                js_val.value(cx)
+            };
+            let openbao_entity_id = {
+                let js_val: Handle<JsString> = obj.get(cx, "openbaoEntityId")?;
+                js_val.value(cx)
+            };
+            Ok(libparsec::AvailableDeviceMultiProtectionType::OpenBao {
+                openbao_preferred_auth_id,
+                openbao_entity_id,
+            })
+        }
+        "AvailableDeviceMultiProtectionTypePKI" => {
+            let certificate_ref = {
+                let js_val: Handle<JsObject> = obj.get(cx, "certificateRef")?;
+                struct_x509_certificate_reference_js_to_rs(cx, js_val)?
+            };
+            Ok(libparsec::AvailableDeviceMultiProtectionType::PKI { certificate_ref })
+        }
+        "AvailableDeviceMultiProtectionTypePassword" => {
+            Ok(libparsec::AvailableDeviceMultiProtectionType::Password {})
+        }
+        _ => cx.throw_type_error("Object is not a AvailableDeviceMultiProtectionType"),
+    }
+}
+
+#[allow(dead_code)]
+fn variant_available_device_multi_protection_type_rs_to_js<'a>(
+    cx: &mut impl Context<'a>,
+    rs_obj: libparsec::AvailableDeviceMultiProtectionType,
+) -> NeonResult<Handle<'a, JsObject>> {
+    let js_obj = cx.empty_object();
+    match rs_obj {
+        libparsec::AvailableDeviceMultiProtectionType::AccountVault { .. } => {
+            let js_tag = JsString::try_new(cx, "AvailableDeviceMultiProtectionTypeAccountVault")
+                .or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+        }
+        libparsec::AvailableDeviceMultiProtectionType::Fido2 { .. } => {
+            let js_tag =
+                JsString::try_new(cx, "AvailableDeviceMultiProtectionTypeFido2").or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+        }
+        libparsec::AvailableDeviceMultiProtectionType::OpenBao {
+            openbao_preferred_auth_id,
+            openbao_entity_id,
+            ..
+        } => {
+            let js_tag =
+                JsString::try_new(cx, "AvailableDeviceMultiProtectionTypeOpenBao").or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+            let js_openbao_preferred_auth_id =
+                JsString::try_new(cx, openbao_preferred_auth_id).or_throw(cx)?;
+            js_obj.set(cx, "openbaoPreferredAuthId", js_openbao_preferred_auth_id)?;
+            let js_openbao_entity_id = JsString::try_new(cx, openbao_entity_id).or_throw(cx)?;
+            js_obj.set(cx, "openbaoEntityId", js_openbao_entity_id)?;
+        }
+        libparsec::AvailableDeviceMultiProtectionType::PKI {
+            certificate_ref, ..
+        } => {
+            let js_tag =
+                JsString::try_new(cx, "AvailableDeviceMultiProtectionTypePKI").or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+            let js_certificate_ref =
+                struct_x509_certificate_reference_rs_to_js(cx, certificate_ref)?;
+            js_obj.set(cx, "certificateRef", js_certificate_ref)?;
+        }
+        libparsec::AvailableDeviceMultiProtectionType::Password { .. } => {
+            let js_tag =
+                JsString::try_new(cx, "AvailableDeviceMultiProtectionTypePassword").or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+        }
+    }
+    Ok(js_obj)
+}
+
 // AvailableDeviceType
 
 #[allow(dead_code)]
@@ -7135,7 +7724,46 @@ fn variant_available_device_type_js_to_rs<'a>(
     let tag = obj.get::<JsString, _, _>(cx, "tag")?.value(cx);
     match tag.as_str() {
         "AvailableDeviceTypeAccountVault" => Ok(libparsec::AvailableDeviceType::AccountVault {}),
+        "AvailableDeviceTypeFido2" => Ok(libparsec::AvailableDeviceType::Fido2 {}),
         "AvailableDeviceTypeKeyring" => Ok(libparsec::AvailableDeviceType::Keyring {}),
+        "AvailableDeviceTypeMachineKey" => {
+            let machine_key_file = {
+                let js_val: Handle<JsString> = obj.get(cx, "machineKeyFile")?;
+                {
+                    let custom_from_rs_string =
+                        |s: String| -> Result<_, &'static str> { Ok(std::path::PathBuf::from(s)) };
+                    match custom_from_rs_string(js_val.value(cx)) {
+                        Ok(val) => val,
+                        Err(err) => return cx.throw_type_error(err),
+                    }
+                }
+            };
+            let machine_key_available = {
+                let js_val: Handle<JsBoolean> = obj.get(cx, "machineKeyAvailable")?;
+                js_val.value(cx)
+            };
+            Ok(libparsec::AvailableDeviceType::MachineKey {
+                machine_key_file,
+                machine_key_available,
+            })
+        }
+        "AvailableDeviceTypeMulti" => {
+            let protections = {
+                let js_val: Handle<JsArray> = obj.get(cx, "protections")?;
+                {
+                    let size = js_val.len(cx);
+                    let mut v = Vec::with_capacity(size as usize);
+                    for i in 0..size {
+                        let js_item: Handle<JsObject> = js_val.get(cx, i)?;
+                        v.push(struct_available_device_multi_protection_js_to_rs(
+                            cx, js_item,
+                        )?);
+                    }
+                    v
+                }
+            };
+            Ok(libparsec::AvailableDeviceType::Multi { protections })
+        }
         "AvailableDeviceTypeOpenBao" => {
             let openbao_preferred_auth_id = {
                 let js_val: Handle<JsString> = obj.get(cx, "openbaoPreferredAuthId")?;
@@ -7174,10 +7802,51 @@ fn variant_available_device_type_rs_to_js<'a>(
             let js_tag = JsString::try_new(cx, "AvailableDeviceTypeAccountVault").or_throw(cx)?;
             js_obj.set(cx, "tag", js_tag)?;
         }
+        libparsec::AvailableDeviceType::Fido2 { .. } => {
+            let js_tag = JsString::try_new(cx, "AvailableDeviceTypeFido2").or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+        }
         libparsec::AvailableDeviceType::Keyring { .. } => {
             let js_tag = JsString::try_new(cx, "AvailableDeviceTypeKeyring").or_throw(cx)?;
             js_obj.set(cx, "tag", js_tag)?;
         }
+        libparsec::AvailableDeviceType::MachineKey {
+            machine_key_file,
+            machine_key_available,
+            ..
+        } => {
+            let js_tag = JsString::try_new(cx, "AvailableDeviceTypeMachineKey").or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+            let js_machine_key_file = JsString::try_new(cx, {
+                let custom_to_rs_string = |path: std::path::PathBuf| -> Result<_, _> {
+                    path.into_os_string()
+                        .into_string()
+                        .map_err(|_| "Path contains non-utf8 characters")
+                };
+                match custom_to_rs_string(machine_key_file) {
+                    Ok(ok) => ok,
+                    Err(err) => return cx.throw_type_error(err.to_string()),
+                }
+            })
+            .or_throw(cx)?;
+            js_obj.set(cx, "machineKeyFile", js_machine_key_file)?;
+            let js_machine_key_available = JsBoolean::new(cx, machine_key_available);
+            js_obj.set(cx, "machineKeyAvailable", js_machine_key_available)?;
+        }
+        libparsec::AvailableDeviceType::Multi { protections, .. } => {
+            let js_tag = JsString::try_new(cx, "AvailableDeviceTypeMulti").or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+            let js_protections = {
+                // JsArray::new allocates with `undefined` value, that's why we `set` value
+                let js_array = JsArray::new(cx, protections.len());
+                for (i, elem) in protections.into_iter().enumerate() {
+                    let js_elem = struct_available_device_multi_protection_rs_to_js(cx, elem)?;
+                    js_array.set(cx, i as u32, js_elem)?;
+                }
+                js_array
+            };
+            js_obj.set(cx, "protections", js_protections)?;
+        }
         libparsec::AvailableDeviceType::OpenBao {
             openbao_preferred_auth_id,
             openbao_entity_id,
@@ -7412,6 +8081,14 @@ fn variant_bootstrap_organization_error_rs_to_js<'a>(
                 .or_throw(cx)?;
             js_obj.set(cx, "tag", js_tag)?;
         }
+        libparsec::BootstrapOrganizationError::SaveDeviceFido2HmacSecretNotSupported { .. } => {
+            let js_tag = JsString::try_new(
+                cx,
+                "BootstrapOrganizationErrorSaveDeviceFido2HmacSecretNotSupported",
+            )
+            .or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+        }
         libparsec::BootstrapOrganizationError::SaveDeviceInvalidPath { .. } => {
             let js_tag = JsString::try_new(cx, "BootstrapOrganizationErrorSaveDeviceInvalidPath")
                 .or_throw(cx)?;
@@ -7586,6 +8263,11 @@ fn variant_claim_finalize_error_rs_to_js<'a>(
     let js_display = JsString::try_new(cx, &rs_obj.to_string()).or_throw(cx)?;
     js_obj.set(cx, "error", js_display)?;
     match rs_obj {
+        libparsec::ClaimFinalizeError::Fido2HmacSecretNotSupported { .. } => {
+            let js_tag = JsString::try_new(cx, "ClaimFinalizeErrorFido2HmacSecretNotSupported")
+                .or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+        }
         libparsec::ClaimFinalizeError::Internal { .. } => {
             let js_tag = JsString::try_new(cx, "ClaimFinalizeErrorInternal").or_throw(cx)?;
             js_obj.set(cx, "tag", js_tag)?;
@@ -7791,6 +8473,11 @@ fn variant_client_accept_async_enrollment_error_rs_to_js<'a>(
                 .or_throw(cx)?;
             js_obj.set(cx, "tag", js_tag)?;
         }
+        libparsec::ClientAcceptAsyncEnrollmentError::ClockDrift { .. } => {
+            let js_tag =
+                JsString::try_new(cx, "ClientAcceptAsyncEnrollmentErrorClockDrift").or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+        }
         libparsec::ClientAcceptAsyncEnrollmentError::EnrollmentNoLongerAvailable { .. } => {
             let js_tag = JsString::try_new(
                 cx,
@@ -7941,6 +8628,11 @@ fn variant_client_archive_workspace_error_rs_to_js<'a>(
                 .or_throw(cx)?;
             js_obj.set(cx, "tag", js_tag)?;
         }
+        libparsec::ClientArchiveWorkspaceError::ClockDrift { .. } => {
+            let js_tag =
+                JsString::try_new(cx, "ClientArchiveWorkspaceErrorClockDrift").or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+        }
         libparsec::ClientArchiveWorkspaceError::Internal { .. } => {
             let js_tag =
                 JsString::try_new(cx, "ClientArchiveWorkspaceErrorInternal").or_throw(cx)?;
@@ -8109,6 +8801,11 @@ fn variant_client_delete_shamir_recovery_error_rs_to_js<'a>(
     let js_display = JsString::try_new(cx, &rs_obj.to_string()).or_throw(cx)?;
     js_obj.set(cx, "error", js_display)?;
     match rs_obj {
+        libparsec::ClientDeleteShamirRecoveryError::ClockDrift { .. } => {
+            let js_tag =
+                JsString::try_new(cx, "ClientDeleteShamirRecoveryErrorClockDrift").or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+        }
         libparsec::ClientDeleteShamirRecoveryError::Internal { .. } => {
             let js_tag =
                 JsString::try_new(cx, "ClientDeleteShamirRecoveryErrorInternal").or_throw(cx)?;
@@ -8226,6 +8923,94 @@ fn variant_client_event_js_to_rs<'a>(
             };
             Ok(libparsec::ClientEvent::ClientStopped { device_id })
         }
+        "ClientEventClockDrift" => {
+            let required_timestamp = {
+                let js_val: Handle<JsNumber> = obj.get(cx, "requiredTimestamp")?;
+                {
+                    let v = js_val.value(cx);
+                    let custom_from_rs_f64 = |n: f64| -> Result<_, &'static str> {
+                        libparsec::DateTime::from_timestamp_micros((n * 1_000_000f64) as i64)
+                            .map_err(|_| "Out-of-bound datetime")
+                    };
+                    match custom_from_rs_f64(v) {
+                        Ok(val) => val,
+                        Err(err) => return cx.throw_type_error(err),
+                    }
+                }
+            };
+            let client_timestamp = {
+                let js_val: Handle<JsNumber> = obj.get(cx, "clientTimestamp")?;
+                {
+                    let v = js_val.value(cx);
+                    let custom_from_rs_f64 = |n: f64| -> Result<_, &'static str> {
+                        libparsec::DateTime::from_timestamp_micros((n * 1_000_000f64) as i64)
+                            .map_err(|_| "Out-of-bound datetime")
+                    };
+                    match custom_from_rs_f64(v) {
+                        Ok(val) => val,
+                        Err(err) => return cx.throw_type_error(err),
+                    }
+                }
+            };
+            Ok(libparsec::ClientEvent::ClockDrift {
+                required_timestamp,
+                client_timestamp,
+            })
+        }
+        "ClientEventDeviceAdded" => {
+            let user_id = {
+                let js_val: Handle<JsString> = obj.get(cx, "userId")?;
+                {
+                    let custom_from_rs_string = |s: String| -> Result<libparsec::UserID, _> {
+                        libparsec::UserID::from_hex(s.as_str()).map_err(|e| e.to_string())
+                    };
+                    match custom_from_rs_string(js_val.value(cx)) {
+                        Ok(val) => val,
+                        Err(err) => return cx.throw_type_error(err),
+                    }
+                }
+            };
+            let device_id = {
+                let js_val: Handle<JsString> = obj.get(cx, "deviceId")?;
+                {
+                    let custom_from_rs_string = |s: String| -> Result<libparsec::DeviceID, _> {
+                        libparsec::DeviceID::from_hex(s.as_str()).map_err(|e| e.to_string())
+                    };
+                    match custom_from_rs_string(js_val.value(cx)) {
+                        Ok(val) => val,
+                        Err(err) => return cx.throw_type_error(err),
+                    }
+                }
+            };
+            Ok(libparsec::ClientEvent::DeviceAdded { user_id, device_id })
+        }
+        "ClientEventDeviceRevoked" => {
+            let user_id = {
+                let js_val: Handle<JsString> = obj.get(cx, "userId")?;
+                {
+                    let custom_from_rs_string = |s: String| -> Result<libparsec::UserID, _> {
+                        libparsec::UserID::from_hex(s.as_str()).map_err(|e| e.to_string())
+                    };
+                    match custom_from_rs_string(js_val.value(cx)) {
+                        Ok(val) => val,
+                        Err(err) => return cx.throw_type_error(err),
+                    }
+                }
+            };
+            let device_id = {
+                let js_val: Handle<JsString> = obj.get(cx, "deviceId")?;
+                {
+                    let custom_from_rs_string = |s: String| -> Result<libparsec::DeviceID, _> {
+                        libparsec::DeviceID::from_hex(s.as_str()).map_err(|e| e.to_string())
+                    };
+                    match custom_from_rs_string(js_val.value(cx)) {
+                        Ok(val) => val,
+                        Err(err) => return cx.throw_type_error(err),
+                    }
+                }
+            };
+            Ok(libparsec::ClientEvent::DeviceRevoked { user_id, device_id })
+        }
         "ClientEventExpiredOrganization" => Ok(libparsec::ClientEvent::ExpiredOrganization {}),
         "ClientEventFrozenSelfUser" => Ok(libparsec::ClientEvent::FrozenSelfUser {}),
         "ClientEventGreetingAttemptCancelled" => {
@@ -8406,7 +9191,225 @@ fn variant_client_event_js_to_rs<'a>(
             };
             Ok(libparsec::ClientEvent::Ping { ping })
         }
+        "ClientEventRealmArchivingChanged" => {
+            let realm_id = {
+                let js_val: Handle<JsString> = obj.get(cx, "realmId")?;
+                {
+                    let custom_from_rs_string = |s: String| -> Result<libparsec::VlobID, _> {
+                        libparsec::VlobID::from_hex(s.as_str()).map_err(|e| e.to_string())
+                    };
+                    match custom_from_rs_string(js_val.value(cx)) {
+                        Ok(val) => val,
+                        Err(err) => return cx.throw_type_error(err),
+                    }
+                }
+            };
+            Ok(libparsec::ClientEvent::RealmArchivingChanged { realm_id })
+        }
+        "ClientEventRealmRekeyDone" => {
+            let realm_id = {
+                let js_val: Handle<JsString> = obj.get(cx, "realmId")?;
+                {
+                    let custom_from_rs_string = |s: String| -> Result<libparsec::VlobID, _> {
+                        libparsec::VlobID::from_hex(s.as_str()).map_err(|e| e.to_string())
+                    };
+                    match custom_from_rs_string(js_val.value(cx)) {
+                        Ok(val) => val,
+                        Err(err) => return cx.throw_type_error(err),
+                    }
+                }
+            };
+            let target_key_index = {
+                let js_val: Handle<JsBigInt> = obj.get(cx, "targetKeyIndex")?;
+                {
+                    let v = js_val
+                        .to_u64(cx)
+                        .or_else(|_| cx.throw_type_error("Not an u64 number"))?;
+                    v
+                }
+            };
+            Ok(libparsec::ClientEvent::RealmRekeyDone {
+                realm_id,
+                target_key_index,
+            })
+        }
+        "ClientEventRealmRekeyProgress" => {
+            let realm_id = {
+                let js_val: Handle<JsString> = obj.get(cx, "realmId")?;
+                {
+                    let custom_from_rs_string = |s: String| -> Result<libparsec::VlobID, _> {
+                        libparsec::VlobID::from_hex(s.as_str()).map_err(|e| e.to_string())
+                    };
+                    match custom_from_rs_string(js_val.value(cx)) {
+                        Ok(val) => val,
+                        Err(err) => return cx.throw_type_error(err),
+                    }
+                }
+            };
+            let target_key_index = {
+                let js_val: Handle<JsBigInt> = obj.get(cx, "targetKeyIndex")?;
+                {
+                    let v = js_val
+                        .to_u64(cx)
+                        .or_else(|_| cx.throw_type_error("Not an u64 number"))?;
+                    v
+                }
+            };
+            let processed = {
+                let js_val: Handle<JsBigInt> = obj.get(cx, "processed")?;
+                {
+                    let v = js_val
+                        .to_u64(cx)
+                        .or_else(|_| cx.throw_type_error("Not an u64 number"))?;
+                    v
+                }
+            };
+            let total = {
+                let js_val: Handle<JsBigInt> = obj.get(cx, "total")?;
+                {
+                    let v = js_val
+                        .to_u64(cx)
+                        .or_else(|_| cx.throw_type_error("Not an u64 number"))?;
+                    v
+                }
+            };
+            Ok(libparsec::ClientEvent::RealmRekeyProgress {
+                realm_id,
+                target_key_index,
+                processed,
+                total,
+            })
+        }
+        "ClientEventRealmRenamed" => {
+            let realm_id = {
+                let js_val: Handle<JsString> = obj.get(cx, "realmId")?;
+                {
+                    let custom_from_rs_string = |s: String| -> Result<libparsec::VlobID, _> {
+                        libparsec::VlobID::from_hex(s.as_str()).map_err(|e| e.to_string())
+                    };
+                    match custom_from_rs_string(js_val.value(cx)) {
+                        Ok(val) => val,
+                        Err(err) => return cx.throw_type_error(err),
+                    }
+                }
+            };
+            Ok(libparsec::ClientEvent::RealmRenamed { realm_id })
+        }
+        "ClientEventRealmRoleChanged" => {
+            let realm_id = {
+                let js_val: Handle<JsString> = obj.get(cx, "realmId")?;
+                {
+                    let custom_from_rs_string = |s: String| -> Result<libparsec::VlobID, _> {
+                        libparsec::VlobID::from_hex(s.as_str()).map_err(|e| e.to_string())
+                    };
+                    match custom_from_rs_string(js_val.value(cx)) {
+                        Ok(val) => val,
+                        Err(err) => return cx.throw_type_error(err),
+                    }
+                }
+            };
+            let user_id = {
+                let js_val: Handle<JsString> = obj.get(cx, "userId")?;
+                {
+                    let custom_from_rs_string = |s: String| -> Result<libparsec::UserID, _> {
+                        libparsec::UserID::from_hex(s.as_str()).map_err(|e| e.to_string())
+                    };
+                    match custom_from_rs_string(js_val.value(cx)) {
+                        Ok(val) => val,
+                        Err(err) => return cx.throw_type_error(err),
+                    }
+                }
+            };
+            let old_role = {
+                let js_val: Handle<JsValue> = obj.get(cx, "oldRole")?;
+                {
+                    if js_val.is_a::<JsNull, _>(cx) {
+                        None
+                    } else {
+                        let js_val = js_val.downcast_or_throw::<JsString, _>(cx)?;
+                        Some({
+                            let js_string = js_val.value(cx);
+                            enum_realm_role_js_to_rs(cx, js_string.as_str())?
+                        })
+                    }
+                }
+            };
+            let new_role = {
+                let js_val: Handle<JsValue> = obj.get(cx, "newRole")?;
+                {
+                    if js_val.is_a::<JsNull, _>(cx) {
+                        None
+                    } else {
+                        let js_val = js_val.downcast_or_throw::<JsString, _>(cx)?;
+                        Some({
+                            let js_string = js_val.value(cx);
+                            enum_realm_role_js_to_rs(cx, js_string.as_str())?
+                        })
+                    }
+                }
+            };
+            Ok(libparsec::ClientEvent::RealmRoleChanged {
+                realm_id,
+                user_id,
+                old_role,
+                new_role,
+            })
+        }
         "ClientEventRevokedSelfUser" => Ok(libparsec::ClientEvent::RevokedSelfUser {}),
+        "ClientEventSelfShared" => {
+            let realm_id = {
+                let js_val: Handle<JsString> = obj.get(cx, "realmId")?;
+                {
+                    let custom_from_rs_string = |s: String| -> Result<libparsec::VlobID, _> {
+                        libparsec::VlobID::from_hex(s.as_str()).map_err(|e| e.to_string())
+                    };
+                    match custom_from_rs_string(js_val.value(cx)) {
+                        Ok(val) => val,
+                        Err(err) => return cx.throw_type_error(err),
+                    }
+                }
+            };
+            let role = {
+                let js_val: Handle<JsString> = obj.get(cx, "role")?;
+                {
+                    let js_string = js_val.value(cx);
+                    enum_realm_role_js_to_rs(cx, js_string.as_str())?
+                }
+            };
+            Ok(libparsec::ClientEvent::SelfShared { realm_id, role })
+        }
+        "ClientEventSelfUnshared" => {
+            let realm_id = {
+                let js_val: Handle<JsString> = obj.get(cx, "realmId")?;
+                {
+                    let custom_from_rs_string = |s: String| -> Result<libparsec::VlobID, _> {
+                        libparsec::VlobID::from_hex(s.as_str()).map_err(|e| e.to_string())
+                    };
+                    match custom_from_rs_string(js_val.value(cx)) {
+                        Ok(val) => val,
+                        Err(err) => return cx.throw_type_error(err),
+                    }
+                }
+            };
+            Ok(libparsec::ClientEvent::SelfUnshared { realm_id })
+        }
+        "ClientEventSequesterServiceRevoked" => {
+            let service_id = {
+                let js_val: Handle<JsString> = obj.get(cx, "serviceId")?;
+                {
+                    let custom_from_rs_string =
+                        |s: String| -> Result<libparsec::SequesterServiceID, _> {
+                            libparsec::SequesterServiceID::from_hex(s.as_str())
+                                .map_err(|e| e.to_string())
+                        };
+                    match custom_from_rs_string(js_val.value(cx)) {
+                        Ok(val) => val,
+                        Err(err) => return cx.throw_type_error(err),
+                    }
+                }
+            };
+            Ok(libparsec::ClientEvent::SequesterServiceRevoked { service_id })
+        }
         "ClientEventServerConfigChanged" => Ok(libparsec::ClientEvent::ServerConfigChanged {}),
         "ClientEventServerInvalidResponseContent" => {
             let protocol_decode_error = {
@@ -8424,6 +9427,21 @@ fn variant_client_event_js_to_rs<'a>(
             };
             Ok(libparsec::ClientEvent::ServerInvalidResponseStatus { status_code })
         }
+        "ClientEventShamirRecoveryChanged" => {
+            let user_id = {
+                let js_val: Handle<JsString> = obj.get(cx, "userId")?;
+                {
+                    let custom_from_rs_string = |s: String| -> Result<libparsec::UserID, _> {
+                        libparsec::UserID::from_hex(s.as_str()).map_err(|e| e.to_string())
+                    };
+                    match custom_from_rs_string(js_val.value(cx)) {
+                        Ok(val) => val,
+                        Err(err) => return cx.throw_type_error(err),
+                    }
+                }
+            };
+            Ok(libparsec::ClientEvent::ShamirRecoveryChanged { user_id })
+        }
         "ClientEventTooMuchDriftWithServerClock" => {
             let server_timestamp = {
                 let js_val: Handle<JsNumber> = obj.get(cx, "serverTimestamp")?;
@@ -8468,6 +9486,43 @@ fn variant_client_event_js_to_rs<'a>(
                 ballpark_client_late_offset,
             })
         }
+        "ClientEventUserProfileUpdated" => {
+            let user_id = {
+                let js_val: Handle<JsString> = obj.get(cx, "userId")?;
+                {
+                    let custom_from_rs_string = |s: String| -> Result<libparsec::UserID, _> {
+                        libparsec::UserID::from_hex(s.as_str()).map_err(|e| e.to_string())
+                    };
+                    match custom_from_rs_string(js_val.value(cx)) {
+                        Ok(val) => val,
+                        Err(err) => return cx.throw_type_error(err),
+                    }
+                }
+            };
+            let profile = {
+                let js_val: Handle<JsString> = obj.get(cx, "profile")?;
+                {
+                    let js_string = js_val.value(cx);
+                    enum_user_profile_js_to_rs(cx, js_string.as_str())?
+                }
+            };
+            Ok(libparsec::ClientEvent::UserProfileUpdated { user_id, profile })
+        }
+        "ClientEventUserRevoked" => {
+            let user_id = {
+                let js_val: Handle<JsString> = obj.get(cx, "userId")?;
+                {
+                    let custom_from_rs_string = |s: String| -> Result<libparsec::UserID, _> {
+                        libparsec::UserID::from_hex(s.as_str()).map_err(|e| e.to_string())
+                    };
+                    match custom_from_rs_string(js_val.value(cx)) {
+                        Ok(val) => val,
+                        Err(err) => return cx.throw_type_error(err),
+                    }
+                }
+            };
+            Ok(libparsec::ClientEvent::UserRevoked { user_id })
+        }
         "ClientEventWebClientNotAllowedByOrganization" => {
             Ok(libparsec::ClientEvent::WebClientNotAllowedByOrganization {})
         }
@@ -8721,6 +9776,86 @@ fn variant_client_event_rs_to_js<'a>(
             .or_throw(cx)?;
             js_obj.set(cx, "deviceId", js_device_id)?;
         }
+        libparsec::ClientEvent::ClockDrift {
+            required_timestamp,
+            client_timestamp,
+            ..
+        } => {
+            let js_tag = JsString::try_new(cx, "ClientEventClockDrift").or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+            let js_required_timestamp = JsNumber::new(cx, {
+                let custom_to_rs_f64 = |dt: libparsec::DateTime| -> Result<f64, &'static str> {
+                    Ok((dt.as_timestamp_micros() as f64) / 1_000_000f64)
+                };
+                match custom_to_rs_f64(required_timestamp) {
+                    Ok(ok) => ok,
+                    Err(err) => return cx.throw_type_error(err),
+                }
+            });
+            js_obj.set(cx, "requiredTimestamp", js_required_timestamp)?;
+            let js_client_timestamp = JsNumber::new(cx, {
+                let custom_to_rs_f64 = |dt: libparsec::DateTime| -> Result<f64, &'static str> {
+                    Ok((dt.as_timestamp_micros() as f64) / 1_000_000f64)
+                };
+                match custom_to_rs_f64(client_timestamp) {
+                    Ok(ok) => ok,
+                    Err(err) => return cx.throw_type_error(err),
+                }
+            });
+            js_obj.set(cx, "clientTimestamp", js_client_timestamp)?;
+        }
+        libparsec::ClientEvent::DeviceAdded {
+            user_id, device_id, ..
+        } => {
+            let js_tag = JsString::try_new(cx, "ClientEventDeviceAdded").or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+            let js_user_id = JsString::try_new(cx, {
+                let custom_to_rs_string =
+                    |x: libparsec::UserID| -> Result<String, &'static str> { Ok(x.hex()) };
+                match custom_to_rs_string(user_id) {
+                    Ok(ok) => ok,
+                    Err(err) => return cx.throw_type_error(err.to_string()),
+                }
+            })
+            .or_throw(cx)?;
+            js_obj.set(cx, "userId", js_user_id)?;
+            let js_device_id = JsString::try_new(cx, {
+                let custom_to_rs_string =
+                    |x: libparsec::DeviceID| -> Result<String, &'static str> { Ok(x.hex()) };
+                match custom_to_rs_string(device_id) {
+                    Ok(ok) => ok,
+                    Err(err) => return cx.throw_type_error(err.to_string()),
+                }
+            })
+            .or_throw(cx)?;
+            js_obj.set(cx, "deviceId", js_device_id)?;
+        }
+        libparsec::ClientEvent::DeviceRevoked {
+            user_id, device_id, ..
+        } => {
+            let js_tag = JsString::try_new(cx, "ClientEventDeviceRevoked").or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+            let js_user_id = JsString::try_new(cx, {
+                let custom_to_rs_string =
+                    |x: libparsec::UserID| -> Result<String, &'static str> { Ok(x.hex()) };
+                match custom_to_rs_string(user_id) {
+                    Ok(ok) => ok,
+                    Err(err) => return cx.throw_type_error(err.to_string()),
+                }
+            })
+            .or_throw(cx)?;
+            js_obj.set(cx, "userId", js_user_id)?;
+            let js_device_id = JsString::try_new(cx, {
+                let custom_to_rs_string =
+                    |x: libparsec::DeviceID| -> Result<String, &'static str> { Ok(x.hex()) };
+                match custom_to_rs_string(device_id) {
+                    Ok(ok) => ok,
+                    Err(err) => return cx.throw_type_error(err.to_string()),
+                }
+            })
+            .or_throw(cx)?;
+            js_obj.set(cx, "deviceId", js_device_id)?;
+        }
         libparsec::ClientEvent::ExpiredOrganization { .. } => {
             let js_tag = JsString::try_new(cx, "ClientEventExpiredOrganization").or_throw(cx)?;
             js_obj.set(cx, "tag", js_tag)?;
@@ -8908,10 +10043,175 @@ fn variant_client_event_rs_to_js<'a>(
             let js_ping = JsString::try_new(cx, ping).or_throw(cx)?;
             js_obj.set(cx, "ping", js_ping)?;
         }
+        libparsec::ClientEvent::RealmArchivingChanged { realm_id, .. } => {
+            let js_tag = JsString::try_new(cx, "ClientEventRealmArchivingChanged").or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+            let js_realm_id = JsString::try_new(cx, {
+                let custom_to_rs_string =
+                    |x: libparsec::VlobID| -> Result<String, &'static str> { Ok(x.hex()) };
+                match custom_to_rs_string(realm_id) {
+                    Ok(ok) => ok,
+                    Err(err) => return cx.throw_type_error(err.to_string()),
+                }
+            })
+            .or_throw(cx)?;
+            js_obj.set(cx, "realmId", js_realm_id)?;
+        }
+        libparsec::ClientEvent::RealmRekeyDone {
+            realm_id,
+            target_key_index,
+            ..
+        } => {
+            let js_tag = JsString::try_new(cx, "ClientEventRealmRekeyDone").or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+            let js_realm_id = JsString::try_new(cx, {
+                let custom_to_rs_string =
+                    |x: libparsec::VlobID| -> Result<String, &'static str> { Ok(x.hex()) };
+                match custom_to_rs_string(realm_id) {
+                    Ok(ok) => ok,
+                    Err(err) => return cx.throw_type_error(err.to_string()),
+                }
+            })
+            .or_throw(cx)?;
+            js_obj.set(cx, "realmId", js_realm_id)?;
+            let js_target_key_index = JsBigInt::from_u64(cx, target_key_index);
+            js_obj.set(cx, "targetKeyIndex", js_target_key_index)?;
+        }
+        libparsec::ClientEvent::RealmRekeyProgress {
+            realm_id,
+            target_key_index,
+            processed,
+            total,
+            ..
+        } => {
+            let js_tag = JsString::try_new(cx, "ClientEventRealmRekeyProgress").or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+            let js_realm_id = JsString::try_new(cx, {
+                let custom_to_rs_string =
+                    |x: libparsec::VlobID| -> Result<String, &'static str> { Ok(x.hex()) };
+                match custom_to_rs_string(realm_id) {
+                    Ok(ok) => ok,
+                    Err(err) => return cx.throw_type_error(err.to_string()),
+                }
+            })
+            .or_throw(cx)?;
+            js_obj.set(cx, "realmId", js_realm_id)?;
+            let js_target_key_index = JsBigInt::from_u64(cx, target_key_index);
+            js_obj.set(cx, "targetKeyIndex", js_target_key_index)?;
+            let js_processed = JsBigInt::from_u64(cx, processed);
+            js_obj.set(cx, "processed", js_processed)?;
+            let js_total = JsBigInt::from_u64(cx, total);
+            js_obj.set(cx, "total", js_total)?;
+        }
+        libparsec::ClientEvent::RealmRenamed { realm_id, .. } => {
+            let js_tag = JsString::try_new(cx, "ClientEventRealmRenamed").or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+            let js_realm_id = JsString::try_new(cx, {
+                let custom_to_rs_string =
+                    |x: libparsec::VlobID| -> Result<String, &'static str> { Ok(x.hex()) };
+                match custom_to_rs_string(realm_id) {
+                    Ok(ok) => ok,
+                    Err(err) => return cx.throw_type_error(err.to_string()),
+                }
+            })
+            .or_throw(cx)?;
+            js_obj.set(cx, "realmId", js_realm_id)?;
+        }
+        libparsec::ClientEvent::RealmRoleChanged {
+            realm_id,
+            user_id,
+            old_role,
+            new_role,
+            ..
+        } => {
+            let js_tag = JsString::try_new(cx, "ClientEventRealmRoleChanged").or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+            let js_realm_id = JsString::try_new(cx, {
+                let custom_to_rs_string =
+                    |x: libparsec::VlobID| -> Result<String, &'static str> { Ok(x.hex()) };
+                match custom_to_rs_string(realm_id) {
+                    Ok(ok) => ok,
+                    Err(err) => return cx.throw_type_error(err.to_string()),
+                }
+            })
+            .or_throw(cx)?;
+            js_obj.set(cx, "realmId", js_realm_id)?;
+            let js_user_id = JsString::try_new(cx, {
+                let custom_to_rs_string =
+                    |x: libparsec::UserID| -> Result<String, &'static str> { Ok(x.hex()) };
+                match custom_to_rs_string(user_id) {
+                    Ok(ok) => ok,
+                    Err(err) => return cx.throw_type_error(err.to_string()),
+                }
+            })
+            .or_throw(cx)?;
+            js_obj.set(cx, "userId", js_user_id)?;
+            let js_old_role = match old_role {
+                Some(elem) => JsString::try_new(cx, enum_realm_role_rs_to_js(elem))
+                    .or_throw(cx)?
+                    .as_value(cx),
+                None => JsNull::new(cx).as_value(cx),
+            };
+            js_obj.set(cx, "oldRole", js_old_role)?;
+            let js_new_role = match new_role {
+                Some(elem) => JsString::try_new(cx, enum_realm_role_rs_to_js(elem))
+                    .or_throw(cx)?
+                    .as_value(cx),
+                None => JsNull::new(cx).as_value(cx),
+            };
+            js_obj.set(cx, "newRole", js_new_role)?;
+        }
         libparsec::ClientEvent::RevokedSelfUser { .. } => {
             let js_tag = JsString::try_new(cx, "ClientEventRevokedSelfUser").or_throw(cx)?;
             js_obj.set(cx, "tag", js_tag)?;
         }
+        libparsec::ClientEvent::SelfShared { realm_id, role, .. } => {
+            let js_tag = JsString::try_new(cx, "ClientEventSelfShared").or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+            let js_realm_id = JsString::try_new(cx, {
+                let custom_to_rs_string =
+                    |x: libparsec::VlobID| -> Result<String, &'static str> { Ok(x.hex()) };
+                match custom_to_rs_string(realm_id) {
+                    Ok(ok) => ok,
+                    Err(err) => return cx.throw_type_error(err.to_string()),
+                }
+            })
+            .or_throw(cx)?;
+            js_obj.set(cx, "realmId", js_realm_id)?;
+            let js_role = JsString::try_new(cx, enum_realm_role_rs_to_js(role)).or_throw(cx)?;
+            js_obj.set(cx, "role", js_role)?;
+        }
+        libparsec::ClientEvent::SelfUnshared { realm_id, .. } => {
+            let js_tag = JsString::try_new(cx, "ClientEventSelfUnshared").or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+            let js_realm_id = JsString::try_new(cx, {
+                let custom_to_rs_string =
+                    |x: libparsec::VlobID| -> Result<String, &'static str> { Ok(x.hex()) };
+                match custom_to_rs_string(realm_id) {
+                    Ok(ok) => ok,
+                    Err(err) => return cx.throw_type_error(err.to_string()),
+                }
+            })
+            .or_throw(cx)?;
+            js_obj.set(cx, "realmId", js_realm_id)?;
+        }
+        libparsec::ClientEvent::SequesterServiceRevoked { service_id, .. } => {
+            let js_tag =
+                JsString::try_new(cx, "ClientEventSequesterServiceRevoked").or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+            let js_service_id = JsString::try_new(cx, {
+                let custom_to_rs_string =
+                    |x: libparsec::SequesterServiceID| -> Result<String, &'static str> {
+                        Ok(x.hex())
+                    };
+                match custom_to_rs_string(service_id) {
+                    Ok(ok) => ok,
+                    Err(err) => return cx.throw_type_error(err.to_string()),
+                }
+            })
+            .or_throw(cx)?;
+            js_obj.set(cx, "serviceId", js_service_id)?;
+        }
         libparsec::ClientEvent::ServerConfigChanged { .. } => {
             let js_tag = JsString::try_new(cx, "ClientEventServerConfigChanged").or_throw(cx)?;
             js_obj.set(cx, "tag", js_tag)?;
@@ -8934,6 +10234,20 @@ fn variant_client_event_rs_to_js<'a>(
             let js_status_code = JsString::try_new(cx, status_code).or_throw(cx)?;
             js_obj.set(cx, "statusCode", js_status_code)?;
         }
+        libparsec::ClientEvent::ShamirRecoveryChanged { user_id, .. } => {
+            let js_tag = JsString::try_new(cx, "ClientEventShamirRecoveryChanged").or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+            let js_user_id = JsString::try_new(cx, {
+                let custom_to_rs_string =
+                    |x: libparsec::UserID| -> Result<String, &'static str> { Ok(x.hex()) };
+                match custom_to_rs_string(user_id) {
+                    Ok(ok) => ok,
+                    Err(err) => return cx.throw_type_error(err.to_string()),
+                }
+            })
+            .or_throw(cx)?;
+            js_obj.set(cx, "userId", js_user_id)?;
+        }
         libparsec::ClientEvent::TooMuchDriftWithServerClock {
             server_timestamp,
             client_timestamp,
@@ -8977,6 +10291,39 @@ fn variant_client_event_rs_to_js<'a>(
                 js_ballpark_client_late_offset,
             )?;
         }
+        libparsec::ClientEvent::UserProfileUpdated {
+            user_id, profile, ..
+        } => {
+            let js_tag = JsString::try_new(cx, "ClientEventUserProfileUpdated").or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+            let js_user_id = JsString::try_new(cx, {
+                let custom_to_rs_string =
+                    |x: libparsec::UserID| -> Result<String, &'static str> { Ok(x.hex()) };
+                match custom_to_rs_string(user_id) {
+                    Ok(ok) => ok,
+                    Err(err) => return cx.throw_type_error(err.to_string()),
+                }
+            })
+            .or_throw(cx)?;
+            js_obj.set(cx, "userId", js_user_id)?;
+            let js_profile =
+                JsString::try_new(cx, enum_user_profile_rs_to_js(profile)).or_throw(cx)?;
+            js_obj.set(cx, "profile", js_profile)?;
+        }
+        libparsec::ClientEvent::UserRevoked { user_id, .. } => {
+            let js_tag = JsString::try_new(cx, "ClientEventUserRevoked").or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+            let js_user_id = JsString::try_new(cx, {
+                let custom_to_rs_string =
+                    |x: libparsec::UserID| -> Result<String, &'static str> { Ok(x.hex()) };
+                match custom_to_rs_string(user_id) {
+                    Ok(ok) => ok,
+                    Err(err) => return cx.throw_type_error(err.to_string()),
+                }
+            })
+            .or_throw(cx)?;
+            js_obj.set(cx, "userId", js_user_id)?;
+        }
         libparsec::ClientEvent::WebClientNotAllowedByOrganization { .. } => {
             let js_tag = JsString::try_new(cx, "ClientEventWebClientNotAllowedByOrganization")
                 .or_throw(cx)?;
@@ -9180,6 +10527,11 @@ fn variant_client_export_recovery_device_error_rs_to_js<'a>(
     let js_display = JsString::try_new(cx, &rs_obj.to_string()).or_throw(cx)?;
     js_obj.set(cx, "error", js_display)?;
     match rs_obj {
+        libparsec::ClientExportRecoveryDeviceError::ClockDrift { .. } => {
+            let js_tag =
+                JsString::try_new(cx, "ClientExportRecoveryDeviceErrorClockDrift").or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+        }
         libparsec::ClientExportRecoveryDeviceError::Internal { .. } => {
             let js_tag =
                 JsString::try_new(cx, "ClientExportRecoveryDeviceErrorInternal").or_throw(cx)?;
@@ -9809,6 +11161,117 @@ fn variant_client_reject_async_enrollment_error_rs_to_js<'a>(
     Ok(js_obj)
 }
 
+// ClientRekeyWorkspaceError
+
+#[allow(dead_code)]
+fn variant_client_rekey_workspace_error_rs_to_js<'a>(
+    cx: &mut impl Context<'a>,
+    rs_obj: libparsec::ClientRekeyWorkspaceError,
+) -> NeonResult<Handle<'a, JsObject>> {
+    let js_obj = cx.empty_object();
+    let js_display = JsString::try_new(cx, &rs_obj.to_string()).or_throw(cx)?;
+    js_obj.set(cx, "error", js_display)?;
+    match rs_obj {
+        libparsec::ClientRekeyWorkspaceError::AuthorNotAllowed { .. } => {
+            let js_tag =
+                JsString::try_new(cx, "ClientRekeyWorkspaceErrorAuthorNotAllowed").or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+        }
+        libparsec::ClientRekeyWorkspaceError::ClockDrift { .. } => {
+            let js_tag =
+                JsString::try_new(cx, "ClientRekeyWorkspaceErrorClockDrift").or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+        }
+        libparsec::ClientRekeyWorkspaceError::Internal { .. } => {
+            let js_tag = JsString::try_new(cx, "ClientRekeyWorkspaceErrorInternal").or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+        }
+        libparsec::ClientRekeyWorkspaceError::InvalidCertificate { .. } => {
+            let js_tag = JsString::try_new(cx, "ClientRekeyWorkspaceErrorInvalidCertificate")
+                .or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+        }
+        libparsec::ClientRekeyWorkspaceError::InvalidKeysBundle { .. } => {
+            let js_tag =
+                JsString::try_new(cx, "ClientRekeyWorkspaceErrorInvalidKeysBundle").or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+        }
+        libparsec::ClientRekeyWorkspaceError::Offline { .. } => {
+            let js_tag = JsString::try_new(cx, "ClientRekeyWorkspaceErrorOffline").or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+        }
+        libparsec::ClientRekeyWorkspaceError::RealmArchived { .. } => {
+            let js_tag =
+                JsString::try_new(cx, "ClientRekeyWorkspaceErrorRealmArchived").or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+        }
+        libparsec::ClientRekeyWorkspaceError::RealmDeleted { .. } => {
+            let js_tag =
+                JsString::try_new(cx, "ClientRekeyWorkspaceErrorRealmDeleted").or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+        }
+        libparsec::ClientRekeyWorkspaceError::ServerBlockstoreUnavailable { .. } => {
+            let js_tag =
+                JsString::try_new(cx, "ClientRekeyWorkspaceErrorServerBlockstoreUnavailable")
+                    .or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+        }
+        libparsec::ClientRekeyWorkspaceError::Stopped { .. } => {
+            let js_tag = JsString::try_new(cx, "ClientRekeyWorkspaceErrorStopped").or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+        }
+        libparsec::ClientRekeyWorkspaceError::TimestampOutOfBallpark {
+            server_timestamp,
+            client_timestamp,
+            ballpark_client_early_offset,
+            ballpark_client_late_offset,
+            ..
+        } => {
+            let js_tag = JsString::try_new(cx, "ClientRekeyWorkspaceErrorTimestampOutOfBallpark")
+                .or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+            let js_server_timestamp = JsNumber::new(cx, {
+                let custom_to_rs_f64 = |dt: libparsec::DateTime| -> Result<f64, &'static str> {
+                    Ok((dt.as_timestamp_micros() as f64) / 1_000_000f64)
+                };
+                match custom_to_rs_f64(server_timestamp) {
+                    Ok(ok) => ok,
+                    Err(err) => return cx.throw_type_error(err),
+                }
+            });
+            js_obj.set(cx, "serverTimestamp", js_server_timestamp)?;
+            let js_client_timestamp = JsNumber::new(cx, {
+                let custom_to_rs_f64 = |dt: libparsec::DateTime| -> Result<f64, &'static str> {
+                    Ok((dt.as_timestamp_micros() as f64) / 1_000_000f64)
+                };
+                match custom_to_rs_f64(client_timestamp) {
+                    Ok(ok) => ok,
+                    Err(err) => return cx.throw_type_error(err),
+                }
+            });
+            js_obj.set(cx, "clientTimestamp", js_client_timestamp)?;
+            let js_ballpark_client_early_offset = JsNumber::new(cx, ballpark_client_early_offset);
+            js_obj.set(
+                cx,
+                "ballparkClientEarlyOffset",
+                js_ballpark_client_early_offset,
+            )?;
+            let js_ballpark_client_late_offset = JsNumber::new(cx, ballpark_client_late_offset);
+            js_obj.set(
+                cx,
+                "ballparkClientLateOffset",
+                js_ballpark_client_late_offset,
+            )?;
+        }
+        libparsec::ClientRekeyWorkspaceError::UnknownRealm { .. } => {
+            let js_tag =
+                JsString::try_new(cx, "ClientRekeyWorkspaceErrorUnknownRealm").or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+        }
+    }
+    Ok(js_obj)
+}
+
 // ClientRenameWorkspaceError
 
 #[allow(dead_code)]
@@ -9825,6 +11288,11 @@ fn variant_client_rename_workspace_error_rs_to_js<'a>(
                 JsString::try_new(cx, "ClientRenameWorkspaceErrorAuthorNotAllowed").or_throw(cx)?;
             js_obj.set(cx, "tag", js_tag)?;
         }
+        libparsec::ClientRenameWorkspaceError::ClockDrift { .. } => {
+            let js_tag =
+                JsString::try_new(cx, "ClientRenameWorkspaceErrorClockDrift").or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+        }
         libparsec::ClientRenameWorkspaceError::Internal { .. } => {
             let js_tag =
                 JsString::try_new(cx, "ClientRenameWorkspaceErrorInternal").or_throw(cx)?;
@@ -9931,6 +11399,10 @@ fn variant_client_revoke_user_error_rs_to_js<'a>(
                 JsString::try_new(cx, "ClientRevokeUserErrorAuthorNotAllowed").or_throw(cx)?;
             js_obj.set(cx, "tag", js_tag)?;
         }
+        libparsec::ClientRevokeUserError::ClockDrift { .. } => {
+            let js_tag = JsString::try_new(cx, "ClientRevokeUserErrorClockDrift").or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+        }
         libparsec::ClientRevokeUserError::Internal { .. } => {
             let js_tag = JsString::try_new(cx, "ClientRevokeUserErrorInternal").or_throw(cx)?;
             js_obj.set(cx, "tag", js_tag)?;
@@ -9953,6 +11425,29 @@ fn variant_client_revoke_user_error_rs_to_js<'a>(
             let js_tag = JsString::try_new(cx, "ClientRevokeUserErrorOffline").or_throw(cx)?;
             js_obj.set(cx, "tag", js_tag)?;
         }
+        libparsec::ClientRevokeUserError::SoleOwnerOfWorkspaces { workspaces, .. } => {
+            let js_tag =
+                JsString::try_new(cx, "ClientRevokeUserErrorSoleOwnerOfWorkspaces").or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+            let js_workspaces = {
+                // JsArray::new allocates with `undefined` value, that's why we `set` value
+                let js_array = JsArray::new(cx, workspaces.len());
+                for (i, elem) in workspaces.into_iter().enumerate() {
+                    let js_elem = JsString::try_new(cx, {
+                        let custom_to_rs_string =
+                            |x: libparsec::VlobID| -> Result<String, &'static str> { Ok(x.hex()) };
+                        match custom_to_rs_string(elem) {
+                            Ok(ok) => ok,
+                            Err(err) => return cx.throw_type_error(err.to_string()),
+                        }
+                    })
+                    .or_throw(cx)?;
+                    js_array.set(cx, i as u32, js_elem)?;
+                }
+                js_array
+            };
+            js_obj.set(cx, "workspaces", js_workspaces)?;
+        }
         libparsec::ClientRevokeUserError::Stopped { .. } => {
             let js_tag = JsString::try_new(cx, "ClientRevokeUserErrorStopped").or_throw(cx)?;
             js_obj.set(cx, "tag", js_tag)?;
@@ -9991,6 +11486,11 @@ fn variant_client_setup_shamir_recovery_error_rs_to_js<'a>(
                     .or_throw(cx)?;
             js_obj.set(cx, "tag", js_tag)?;
         }
+        libparsec::ClientSetupShamirRecoveryError::ClockDrift { .. } => {
+            let js_tag =
+                JsString::try_new(cx, "ClientSetupShamirRecoveryErrorClockDrift").or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+        }
         libparsec::ClientSetupShamirRecoveryError::Internal { .. } => {
             let js_tag =
                 JsString::try_new(cx, "ClientSetupShamirRecoveryErrorInternal").or_throw(cx)?;
@@ -10106,6 +11606,11 @@ fn variant_client_share_workspace_error_rs_to_js<'a>(
                 JsString::try_new(cx, "ClientShareWorkspaceErrorAuthorNotAllowed").or_throw(cx)?;
             js_obj.set(cx, "tag", js_tag)?;
         }
+        libparsec::ClientShareWorkspaceError::ClockDrift { .. } => {
+            let js_tag =
+                JsString::try_new(cx, "ClientShareWorkspaceErrorClockDrift").or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+        }
         libparsec::ClientShareWorkspaceError::Internal { .. } => {
             let js_tag = JsString::try_new(cx, "ClientShareWorkspaceErrorInternal").or_throw(cx)?;
             js_obj.set(cx, "tag", js_tag)?;
@@ -10496,6 +12001,144 @@ fn variant_client_totp_setup_status_error_rs_to_js<'a>(
     Ok(js_obj)
 }
 
+// ClientTransferWorkspaceOwnershipError
+
+#[allow(dead_code)]
+fn variant_client_transfer_workspace_ownership_error_rs_to_js<'a>(
+    cx: &mut impl Context<'a>,
+    rs_obj: libparsec::ClientTransferWorkspaceOwnershipError,
+) -> NeonResult<Handle<'a, JsObject>> {
+    let js_obj = cx.empty_object();
+    let js_display = JsString::try_new(cx, &rs_obj.to_string()).or_throw(cx)?;
+    js_obj.set(cx, "error", js_display)?;
+    match rs_obj {
+        libparsec::ClientTransferWorkspaceOwnershipError::AuthorNotAllowed { .. } => {
+            let js_tag =
+                JsString::try_new(cx, "ClientTransferWorkspaceOwnershipErrorAuthorNotAllowed")
+                    .or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+        }
+        libparsec::ClientTransferWorkspaceOwnershipError::ClockDrift { .. } => {
+            let js_tag = JsString::try_new(cx, "ClientTransferWorkspaceOwnershipErrorClockDrift")
+                .or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+        }
+        libparsec::ClientTransferWorkspaceOwnershipError::Internal { .. } => {
+            let js_tag = JsString::try_new(cx, "ClientTransferWorkspaceOwnershipErrorInternal")
+                .or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+        }
+        libparsec::ClientTransferWorkspaceOwnershipError::InvalidCertificate { .. } => {
+            let js_tag = JsString::try_new(
+                cx,
+                "ClientTransferWorkspaceOwnershipErrorInvalidCertificate",
+            )
+            .or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+        }
+        libparsec::ClientTransferWorkspaceOwnershipError::InvalidKeysBundle { .. } => {
+            let js_tag =
+                JsString::try_new(cx, "ClientTransferWorkspaceOwnershipErrorInvalidKeysBundle")
+                    .or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+        }
+        libparsec::ClientTransferWorkspaceOwnershipError::NewOwnerIsOutsider { .. } => {
+            let js_tag = JsString::try_new(
+                cx,
+                "ClientTransferWorkspaceOwnershipErrorNewOwnerIsOutsider",
+            )
+            .or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+        }
+        libparsec::ClientTransferWorkspaceOwnershipError::NewOwnerIsSelf { .. } => {
+            let js_tag =
+                JsString::try_new(cx, "ClientTransferWorkspaceOwnershipErrorNewOwnerIsSelf")
+                    .or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+        }
+        libparsec::ClientTransferWorkspaceOwnershipError::NewOwnerNotFound { .. } => {
+            let js_tag =
+                JsString::try_new(cx, "ClientTransferWorkspaceOwnershipErrorNewOwnerNotFound")
+                    .or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+        }
+        libparsec::ClientTransferWorkspaceOwnershipError::NewOwnerRevoked { .. } => {
+            let js_tag =
+                JsString::try_new(cx, "ClientTransferWorkspaceOwnershipErrorNewOwnerRevoked")
+                    .or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+        }
+        libparsec::ClientTransferWorkspaceOwnershipError::Offline { .. } => {
+            let js_tag = JsString::try_new(cx, "ClientTransferWorkspaceOwnershipErrorOffline")
+                .or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+        }
+        libparsec::ClientTransferWorkspaceOwnershipError::RealmDeleted { .. } => {
+            let js_tag = JsString::try_new(cx, "ClientTransferWorkspaceOwnershipErrorRealmDeleted")
+                .or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+        }
+        libparsec::ClientTransferWorkspaceOwnershipError::Stopped { .. } => {
+            let js_tag = JsString::try_new(cx, "ClientTransferWorkspaceOwnershipErrorStopped")
+                .or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+        }
+        libparsec::ClientTransferWorkspaceOwnershipError::TimestampOutOfBallpark {
+            server_timestamp,
+            client_timestamp,
+            ballpark_client_early_offset,
+            ballpark_client_late_offset,
+            ..
+        } => {
+            let js_tag = JsString::try_new(
+                cx,
+                "ClientTransferWorkspaceOwnershipErrorTimestampOutOfBallpark",
+            )
+            .or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+            let js_server_timestamp = JsNumber::new(cx, {
+                let custom_to_rs_f64 = |dt: libparsec::DateTime| -> Result<f64, &'static str> {
+                    Ok((dt.as_timestamp_micros() as f64) / 1_000_000f64)
+                };
+                match custom_to_rs_f64(server_timestamp) {
+                    Ok(ok) => ok,
+                    Err(err) => return cx.throw_type_error(err),
+                }
+            });
+            js_obj.set(cx, "serverTimestamp", js_server_timestamp)?;
+            let js_client_timestamp = JsNumber::new(cx, {
+                let custom_to_rs_f64 = |dt: libparsec::DateTime| -> Result<f64, &'static str> {
+                    Ok((dt.as_timestamp_micros() as f64) / 1_000_000f64)
+                };
+                match custom_to_rs_f64(client_timestamp) {
+                    Ok(ok) => ok,
+                    Err(err) => return cx.throw_type_error(err),
+                }
+            });
+            js_obj.set(cx, "clientTimestamp", js_client_timestamp)?;
+            let js_ballpark_client_early_offset = JsNumber::new(cx, ballpark_client_early_offset);
+            js_obj.set(
+                cx,
+                "ballparkClientEarlyOffset",
+                js_ballpark_client_early_offset,
+            )?;
+            let js_ballpark_client_late_offset = JsNumber::new(cx, ballpark_client_late_offset);
+            js_obj.set(
+                cx,
+                "ballparkClientLateOffset",
+                js_ballpark_client_late_offset,
+            )?;
+        }
+        libparsec::ClientTransferWorkspaceOwnershipError::WorkspaceNotFound { .. } => {
+            let js_tag =
+                JsString::try_new(cx, "ClientTransferWorkspaceOwnershipErrorWorkspaceNotFound")
+                    .or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+        }
+    }
+    Ok(js_obj)
+}
+
 // ClientUserUpdateProfileError
 
 #[allow(dead_code)]
@@ -10512,6 +12155,11 @@ fn variant_client_user_update_profile_error_rs_to_js<'a>(
                 .or_throw(cx)?;
             js_obj.set(cx, "tag", js_tag)?;
         }
+        libparsec::ClientUserUpdateProfileError::ClockDrift { .. } => {
+            let js_tag =
+                JsString::try_new(cx, "ClientUserUpdateProfileErrorClockDrift").or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+        }
         libparsec::ClientUserUpdateProfileError::Internal { .. } => {
             let js_tag =
                 JsString::try_new(cx, "ClientUserUpdateProfileErrorInternal").or_throw(cx)?;
@@ -10527,6 +12175,29 @@ fn variant_client_user_update_profile_error_rs_to_js<'a>(
                 JsString::try_new(cx, "ClientUserUpdateProfileErrorOffline").or_throw(cx)?;
             js_obj.set(cx, "tag", js_tag)?;
         }
+        libparsec::ClientUserUpdateProfileError::SoleOwnerOfWorkspaces { workspaces, .. } => {
+            let js_tag = JsString::try_new(cx, "ClientUserUpdateProfileErrorSoleOwnerOfWorkspaces")
+                .or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+            let js_workspaces = {
+                // JsArray::new allocates with `undefined` value, that's why we `set` value
+                let js_array = JsArray::new(cx, workspaces.len());
+                for (i, elem) in workspaces.into_iter().enumerate() {
+                    let js_elem = JsString::try_new(cx, {
+                        let custom_to_rs_string =
+                            |x: libparsec::VlobID| -> Result<String, &'static str> { Ok(x.hex()) };
+                        match custom_to_rs_string(elem) {
+                            Ok(ok) => ok,
+                            Err(err) => return cx.throw_type_error(err.to_string()),
+                        }
+                    })
+                    .or_throw(cx)?;
+                    js_array.set(cx, i as u32, js_elem)?;
+                }
+                js_array
+            };
+            js_obj.set(cx, "workspaces", js_workspaces)?;
+        }
         libparsec::ClientUserUpdateProfileError::Stopped { .. } => {
             let js_tag =
                 JsString::try_new(cx, "ClientUserUpdateProfileErrorStopped").or_throw(cx)?;
@@ -10580,9 +12251,59 @@ fn variant_device_primary_protection_strategy_js_to_rs<'a>(
             };
             Ok(libparsec::DevicePrimaryProtectionStrategy::AccountVault { account_handle })
         }
+        "DevicePrimaryProtectionStrategyFido2" => {
+            let pin = {
+                let js_val: Handle<JsValue> = obj.get(cx, "pin")?;
+                {
+                    if js_val.is_a::<JsNull, _>(cx) {
+                        None
+                    } else {
+                        let js_val = js_val.downcast_or_throw::<JsString, _>(cx)?;
+                        Some({
+                            let custom_from_rs_string =
+                                |s: String| -> Result<_, String> { Ok(s.into()) };
+                            match custom_from_rs_string(js_val.value(cx)) {
+                                Ok(val) => val,
+                                Err(err) => return cx.throw_type_error(err),
+                            }
+                        })
+                    }
+                }
+            };
+            Ok(libparsec::DevicePrimaryProtectionStrategy::Fido2 { pin })
+        }
         "DevicePrimaryProtectionStrategyKeyring" => {
             Ok(libparsec::DevicePrimaryProtectionStrategy::Keyring)
         }
+        "DevicePrimaryProtectionStrategyMachineKey" => {
+            let machine_key_file = {
+                let js_val: Handle<JsString> = obj.get(cx, "machineKeyFile")?;
+                {
+                    let custom_from_rs_string =
+                        |s: String| -> Result<_, &'static str> { Ok(std::path::PathBuf::from(s)) };
+                    match custom_from_rs_string(js_val.value(cx)) {
+                        Ok(val) => val,
+                        Err(err) => return cx.throw_type_error(err),
+                    }
+                }
+            };
+            Ok(libparsec::DevicePrimaryProtectionStrategy::MachineKey { machine_key_file })
+        }
+        "DevicePrimaryProtectionStrategyMulti" => {
+            let alternatives = {
+                let js_val: Handle<JsArray> = obj.get(cx, "alternatives")?;
+                {
+                    let size = js_val.len(cx);
+                    let mut v = Vec::with_capacity(size as usize);
+                    for i in 0..size {
+                        let js_item: Handle<JsObject> = js_val.get(cx, i)?;
+                        v.push(struct_device_alternative_protection_js_to_rs(cx, js_item)?);
+                    }
+                    v
+                }
+            };
+            Ok(libparsec::DevicePrimaryProtectionStrategy::Multi { alternatives })
+        }
         "DevicePrimaryProtectionStrategyOpenBao" => {
             let openbao_server_url = {
                 let js_val: Handle<JsString> = obj.get(cx, "openbaoServerUrl")?;
@@ -10608,6 +12329,17 @@ fn variant_device_primary_protection_strategy_js_to_rs<'a>(
                 let js_val: Handle<JsString> = obj.get(cx, "openbaoPreferredAuthId")?;
                 js_val.value(cx)
             };
+            let openbao_reauthentication_method = {
+                let js_val: Handle<JsValue> = obj.get(cx, "openbaoReauthenticationMethod")?;
+                {
+                    if js_val.is_a::<JsNull, _>(cx) {
+                        None
+                    } else {
+                        let js_val = js_val.downcast_or_throw::<JsObject, _>(cx)?;
+                        Some(variant_open_bao_auth_method_js_to_rs(cx, js_val)?)
+                    }
+                }
+            };
             Ok(libparsec::DevicePrimaryProtectionStrategy::OpenBao {
                 openbao_server_url,
                 openbao_secret_mount_path,
@@ -10615,6 +12347,7 @@ fn variant_device_primary_protection_strategy_js_to_rs<'a>(
                 openbao_entity_id,
                 openbao_auth_token,
                 openbao_preferred_auth_id,
+                openbao_reauthentication_method,
             })
         }
         "DevicePrimaryProtectionStrategyPKI" => {
@@ -10664,11 +12397,56 @@ fn variant_device_primary_protection_strategy_rs_to_js<'a>(
             let js_account_handle = JsNumber::new(cx, account_handle as f64);
             js_obj.set(cx, "accountHandle", js_account_handle)?;
         }
+        libparsec::DevicePrimaryProtectionStrategy::Fido2 { pin, .. } => {
+            let js_tag =
+                JsString::try_new(cx, "DevicePrimaryProtectionStrategyFido2").or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+            let js_pin = match pin {
+                Some(elem) => JsString::try_new(cx, elem).or_throw(cx)?.as_value(cx),
+                None => JsNull::new(cx).as_value(cx),
+            };
+            js_obj.set(cx, "pin", js_pin)?;
+        }
         libparsec::DevicePrimaryProtectionStrategy::Keyring => {
             let js_tag =
                 JsString::try_new(cx, "DevicePrimaryProtectionStrategyKeyring").or_throw(cx)?;
             js_obj.set(cx, "tag", js_tag)?;
         }
+        libparsec::DevicePrimaryProtectionStrategy::MachineKey {
+            machine_key_file, ..
+        } => {
+            let js_tag =
+                JsString::try_new(cx, "DevicePrimaryProtectionStrategyMachineKey").or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+            let js_machine_key_file = JsString::try_new(cx, {
+                let custom_to_rs_string = |path: std::path::PathBuf| -> Result<_, _> {
+                    path.into_os_string()
+                        .into_string()
+                        .map_err(|_| "Path contains non-utf8 characters")
+                };
+                match custom_to_rs_string(machine_key_file) {
+                    Ok(ok) => ok,
+                    Err(err) => return cx.throw_type_error(err.to_string()),
+                }
+            })
+            .or_throw(cx)?;
+            js_obj.set(cx, "machineKeyFile", js_machine_key_file)?;
+        }
+        libparsec::DevicePrimaryProtectionStrategy::Multi { alternatives, .. } => {
+            let js_tag =
+                JsString::try_new(cx, "DevicePrimaryProtectionStrategyMulti").or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+            let js_alternatives = {
+                // JsArray::new allocates with `undefined` value, that's why we `set` value
+                let js_array = JsArray::new(cx, alternatives.len());
+                for (i, elem) in alternatives.into_iter().enumerate() {
+                    let js_elem = struct_device_alternative_protection_rs_to_js(cx, elem)?;
+                    js_array.set(cx, i as u32, js_elem)?;
+                }
+                js_array
+            };
+            js_obj.set(cx, "alternatives", js_alternatives)?;
+        }
         libparsec::DevicePrimaryProtectionStrategy::OpenBao {
             openbao_server_url,
             openbao_secret_mount_path,
@@ -10676,6 +12454,7 @@ fn variant_device_primary_protection_strategy_rs_to_js<'a>(
             openbao_entity_id,
             openbao_auth_token,
             openbao_preferred_auth_id,
+            openbao_reauthentication_method,
             ..
         } => {
             let js_tag =
@@ -10696,6 +12475,15 @@ fn variant_device_primary_protection_strategy_rs_to_js<'a>(
             let js_openbao_preferred_auth_id =
                 JsString::try_new(cx, openbao_preferred_auth_id).or_throw(cx)?;
             js_obj.set(cx, "openbaoPreferredAuthId", js_openbao_preferred_auth_id)?;
+            let js_openbao_reauthentication_method = match openbao_reauthentication_method {
+                Some(elem) => variant_open_bao_auth_method_rs_to_js(cx, elem)?.as_value(cx),
+                None => JsNull::new(cx).as_value(cx),
+            };
+            js_obj.set(
+                cx,
+                "openbaoReauthenticationMethod",
+                js_openbao_reauthentication_method,
+            )?;
         }
         libparsec::DevicePrimaryProtectionStrategy::PKI {
             pki_private_key_handle,
@@ -11297,6 +13085,10 @@ fn variant_greet_in_progress_error_rs_to_js<'a>(
             let js_tag = JsString::try_new(cx, "GreetInProgressErrorCancelled").or_throw(cx)?;
             js_obj.set(cx, "tag", js_tag)?;
         }
+        libparsec::GreetInProgressError::ClockDrift { .. } => {
+            let js_tag = JsString::try_new(cx, "GreetInProgressErrorClockDrift").or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+        }
         libparsec::GreetInProgressError::CorruptedInviteUserData { .. } => {
             let js_tag = JsString::try_new(cx, "GreetInProgressErrorCorruptedInviteUserData")
                 .or_throw(cx)?;
@@ -11437,11 +13229,22 @@ fn variant_import_recovery_device_error_rs_to_js<'a>(
     let js_display = JsString::try_new(cx, &rs_obj.to_string()).or_throw(cx)?;
     js_obj.set(cx, "error", js_display)?;
     match rs_obj {
+        libparsec::ImportRecoveryDeviceError::ClockDrift { .. } => {
+            let js_tag =
+                JsString::try_new(cx, "ImportRecoveryDeviceErrorClockDrift").or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+        }
         libparsec::ImportRecoveryDeviceError::DecryptionFailed { .. } => {
             let js_tag =
                 JsString::try_new(cx, "ImportRecoveryDeviceErrorDecryptionFailed").or_throw(cx)?;
             js_obj.set(cx, "tag", js_tag)?;
         }
+        libparsec::ImportRecoveryDeviceError::Fido2HmacSecretNotSupported { .. } => {
+            let js_tag =
+                JsString::try_new(cx, "ImportRecoveryDeviceErrorFido2HmacSecretNotSupported")
+                    .or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+        }
         libparsec::ImportRecoveryDeviceError::Internal { .. } => {
             let js_tag = JsString::try_new(cx, "ImportRecoveryDeviceErrorInternal").or_throw(cx)?;
             js_obj.set(cx, "tag", js_tag)?;
@@ -12468,6 +14271,132 @@ fn variant_open_bao_auth_config_rs_to_js<'a>(
     Ok(js_obj)
 }
 
+// OpenBaoAuthMethod
+
+#[allow(dead_code)]
+fn variant_open_bao_auth_method_js_to_rs<'a>(
+    cx: &mut impl Context<'a>,
+    obj: Handle<'a, JsObject>,
+) -> NeonResult<libparsec::OpenBaoAuthMethod> {
+    let tag = obj.get::<JsString, _, _>(cx, "tag")?.value(cx);
+    match tag.as_str() {
+        "OpenBaoAuthMethodAppRole" => {
+            let mount_path = {
+                let js_val: Handle<JsString> = obj.get(cx, "mountPath")?;
+                js_val.value(cx)
+            };
+            let role_id = {
+                let js_val: Handle<JsString> = obj.get(cx, "roleId")?;
+                js_val.value(cx)
+            };
+            let secret_id = {
+                let js_val: Handle<JsString> = obj.get(cx, "secretId")?;
+                js_val.value(cx)
+            };
+            Ok(libparsec::OpenBaoAuthMethod::AppRole {
+                mount_path,
+                role_id,
+                secret_id,
+            })
+        }
+        "OpenBaoAuthMethodJwt" => {
+            let mount_path = {
+                let js_val: Handle<JsString> = obj.get(cx, "mountPath")?;
+                js_val.value(cx)
+            };
+            let role = {
+                let js_val: Handle<JsString> = obj.get(cx, "role")?;
+                js_val.value(cx)
+            };
+            let jwt = {
+                let js_val: Handle<JsString> = obj.get(cx, "jwt")?;
+                js_val.value(cx)
+            };
+            Ok(libparsec::OpenBaoAuthMethod::Jwt {
+                mount_path,
+                role,
+                jwt,
+            })
+        }
+        "OpenBaoAuthMethodUserpass" => {
+            let mount_path = {
+                let js_val: Handle<JsString> = obj.get(cx, "mountPath")?;
+                js_val.value(cx)
+            };
+            let username = {
+                let js_val: Handle<JsString> = obj.get(cx, "username")?;
+                js_val.value(cx)
+            };
+            let password = {
+                let js_val: Handle<JsString> = obj.get(cx, "password")?;
+                js_val.value(cx)
+            };
+            Ok(libparsec::OpenBaoAuthMethod::Userpass {
+                mount_path,
+                username,
+                password,
+            })
+        }
+        _ => cx.throw_type_error("Object is not a OpenBaoAuthMethod"),
+    }
+}
+
+#[allow(dead_code)]
+fn variant_open_bao_auth_method_rs_to_js<'a>(
+    cx: &mut impl Context<'a>,
+    rs_obj: libparsec::OpenBaoAuthMethod,
+) -> NeonResult<Handle<'a, JsObject>> {
+    let js_obj = cx.empty_object();
+    match rs_obj {
+        libparsec::OpenBaoAuthMethod::AppRole {
+            mount_path,
+            role_id,
+            secret_id,
+            ..
+        } => {
+            let js_tag = JsString::try_new(cx, "OpenBaoAuthMethodAppRole").or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+            let js_mount_path = JsString::try_new(cx, mount_path).or_throw(cx)?;
+            js_obj.set(cx, "mountPath", js_mount_path)?;
+            let js_role_id = JsString::try_new(cx, role_id).or_throw(cx)?;
+            js_obj.set(cx, "roleId", js_role_id)?;
+            let js_secret_id = JsString::try_new(cx, secret_id).or_throw(cx)?;
+            js_obj.set(cx, "secretId", js_secret_id)?;
+        }
+        libparsec::OpenBaoAuthMethod::Jwt {
+            mount_path,
+            role,
+            jwt,
+            ..
+        } => {
+            let js_tag = JsString::try_new(cx, "OpenBaoAuthMethodJwt").or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+            let js_mount_path = JsString::try_new(cx, mount_path).or_throw(cx)?;
+            js_obj.set(cx, "mountPath", js_mount_path)?;
+            let js_role = JsString::try_new(cx, role).or_throw(cx)?;
+            js_obj.set(cx, "role", js_role)?;
+            let js_jwt = JsString::try_new(cx, jwt).or_throw(cx)?;
+            js_obj.set(cx, "jwt", js_jwt)?;
+        }
+        libparsec::OpenBaoAuthMethod::Userpass {
+            mount_path,
+            username,
+            password,
+            ..
+        } => {
+            let js_tag = JsString::try_new(cx, "OpenBaoAuthMethodUserpass").or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+            let js_mount_path = JsString::try_new(cx, mount_path).or_throw(cx)?;
+            js_obj.set(cx, "mountPath", js_mount_path)?;
+            let js_username = JsString::try_new(cx, username).or_throw(cx)?;
+            js_obj.set(cx, "username", js_username)?;
+            let js_password = JsString::try_new(cx, password).or_throw(cx)?;
+            js_obj.set(cx, "password", js_password)?;
+        }
+    }
+    Ok(js_obj)
+}
+
 // OpenBaoListSelfEmailsError
 
 #[allow(dead_code)]
@@ -16710,6 +18639,10 @@ fn variant_submitter_finalize_async_enrollment_error_rs_to_js<'a>(
             let js_tag = JsString::try_new(cx, "SubmitterFinalizeAsyncEnrollmentErrorPKIUnusableX509CertificateReference").or_throw(cx)?;
             js_obj.set(cx, "tag", js_tag)?;
         }
+        libparsec::SubmitterFinalizeAsyncEnrollmentError::SaveDeviceFido2HmacSecretNotSupported{  .. } => {
+            let js_tag = JsString::try_new(cx, "SubmitterFinalizeAsyncEnrollmentErrorSaveDeviceFido2HmacSecretNotSupported").or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+        }
         libparsec::SubmitterFinalizeAsyncEnrollmentError::SaveDeviceInvalidPath{  .. } => {
             let js_tag = JsString::try_new(cx, "SubmitterFinalizeAsyncEnrollmentErrorSaveDeviceInvalidPath").or_throw(cx)?;
             js_obj.set(cx, "tag", js_tag)?;
@@ -16965,6 +18898,11 @@ fn variant_update_device_error_rs_to_js<'a>(
             let js_tag = JsString::try_new(cx, "UpdateDeviceErrorDecryptionFailed").or_throw(cx)?;
             js_obj.set(cx, "tag", js_tag)?;
         }
+        libparsec::UpdateDeviceError::Fido2HmacSecretNotSupported { .. } => {
+            let js_tag = JsString::try_new(cx, "UpdateDeviceErrorFido2HmacSecretNotSupported")
+                .or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+        }
         libparsec::UpdateDeviceError::Internal { .. } => {
             let js_tag = JsString::try_new(cx, "UpdateDeviceErrorInternal").or_throw(cx)?;
             js_obj.set(cx, "tag", js_tag)?;
@@ -17563,6 +19501,26 @@ fn variant_workspace_generate_path_addr_error_rs_to_js<'a>(
     Ok(js_obj)
 }
 
+// WorkspaceGetRemoteOnlyEntriesError
+
+#[allow(dead_code)]
+fn variant_workspace_get_remote_only_entries_error_rs_to_js<'a>(
+    cx: &mut impl Context<'a>,
+    rs_obj: libparsec::WorkspaceGetRemoteOnlyEntriesError,
+) -> NeonResult<Handle<'a, JsObject>> {
+    let js_obj = cx.empty_object();
+    let js_display = JsString::try_new(cx, &rs_obj.to_string()).or_throw(cx)?;
+    js_obj.set(cx, "error", js_display)?;
+    match rs_obj {
+        libparsec::WorkspaceGetRemoteOnlyEntriesError::Internal { .. } => {
+            let js_tag =
+                JsString::try_new(cx, "WorkspaceGetRemoteOnlyEntriesErrorInternal").or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+        }
+    }
+    Ok(js_obj)
+}
+
 // WorkspaceHistoryEntryStat
 
 #[allow(dead_code)]
@@ -18569,6 +20527,66 @@ fn variant_workspace_info_error_rs_to_js<'a>(
     Ok(js_obj)
 }
 
+// WorkspaceIsEntryRemoteOnlyError
+
+#[allow(dead_code)]
+fn variant_workspace_is_entry_remote_only_error_rs_to_js<'a>(
+    cx: &mut impl Context<'a>,
+    rs_obj: libparsec::WorkspaceIsEntryRemoteOnlyError,
+) -> NeonResult<Handle<'a, JsObject>> {
+    let js_obj = cx.empty_object();
+    let js_display = JsString::try_new(cx, &rs_obj.to_string()).or_throw(cx)?;
+    js_obj.set(cx, "error", js_display)?;
+    match rs_obj {
+        libparsec::WorkspaceIsEntryRemoteOnlyError::EntryNotFound { .. } => {
+            let js_tag = JsString::try_new(cx, "WorkspaceIsEntryRemoteOnlyErrorEntryNotFound")
+                .or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+        }
+        libparsec::WorkspaceIsEntryRemoteOnlyError::Internal { .. } => {
+            let js_tag =
+                JsString::try_new(cx, "WorkspaceIsEntryRemoteOnlyErrorInternal").or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+        }
+        libparsec::WorkspaceIsEntryRemoteOnlyError::InvalidCertificate { .. } => {
+            let js_tag = JsString::try_new(cx, "WorkspaceIsEntryRemoteOnlyErrorInvalidCertificate")
+                .or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+        }
+        libparsec::WorkspaceIsEntryRemoteOnlyError::InvalidKeysBundle { .. } => {
+            let js_tag = JsString::try_new(cx, "WorkspaceIsEntryRemoteOnlyErrorInvalidKeysBundle")
+                .or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+        }
+        libparsec::WorkspaceIsEntryRemoteOnlyError::InvalidManifest { .. } => {
+            let js_tag = JsString::try_new(cx, "WorkspaceIsEntryRemoteOnlyErrorInvalidManifest")
+                .or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+        }
+        libparsec::WorkspaceIsEntryRemoteOnlyError::NoRealmAccess { .. } => {
+            let js_tag = JsString::try_new(cx, "WorkspaceIsEntryRemoteOnlyErrorNoRealmAccess")
+                .or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+        }
+        libparsec::WorkspaceIsEntryRemoteOnlyError::Offline { .. } => {
+            let js_tag =
+                JsString::try_new(cx, "WorkspaceIsEntryRemoteOnlyErrorOffline").or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+        }
+        libparsec::WorkspaceIsEntryRemoteOnlyError::RealmDeleted { .. } => {
+            let js_tag = JsString::try_new(cx, "WorkspaceIsEntryRemoteOnlyErrorRealmDeleted")
+                .or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+        }
+        libparsec::WorkspaceIsEntryRemoteOnlyError::Stopped { .. } => {
+            let js_tag =
+                JsString::try_new(cx, "WorkspaceIsEntryRemoteOnlyErrorStopped").or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+        }
+    }
+    Ok(js_obj)
+}
+
 // WorkspaceIsFileContentLocalError
 
 #[allow(dead_code)]
@@ -18889,6 +20907,31 @@ fn variant_workspace_remove_entry_error_rs_to_js<'a>(
     Ok(js_obj)
 }
 
+// WorkspaceSetRemoteOnlyEntriesError
+
+#[allow(dead_code)]
+fn variant_workspace_set_remote_only_entries_error_rs_to_js<'a>(
+    cx: &mut impl Context<'a>,
+    rs_obj: libparsec::WorkspaceSetRemoteOnlyEntriesError,
+) -> NeonResult<Handle<'a, JsObject>> {
+    let js_obj = cx.empty_object();
+    let js_display = JsString::try_new(cx, &rs_obj.to_string()).or_throw(cx)?;
+    js_obj.set(cx, "error", js_display)?;
+    match rs_obj {
+        libparsec::WorkspaceSetRemoteOnlyEntriesError::Internal { .. } => {
+            let js_tag =
+                JsString::try_new(cx, "WorkspaceSetRemoteOnlyEntriesErrorInternal").or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+        }
+        libparsec::WorkspaceSetRemoteOnlyEntriesError::Stopped { .. } => {
+            let js_tag =
+                JsString::try_new(cx, "WorkspaceSetRemoteOnlyEntriesErrorStopped").or_throw(cx)?;
+            js_obj.set(cx, "tag", js_tag)?;
+        }
+    }
+    Ok(js_obj)
+}
+
 // WorkspaceStatEntryError
 
 #[allow(dead_code)]
@@ -24060,6 +26103,72 @@ fn client_reject_async_enrollment(mut cx: FunctionContext) -> JsResult<JsPromise
     Ok(promise)
 }
 
+// client_rekey_workspace
+fn client_rekey_workspace(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    crate::init_sentry();
+    let client = {
+        let js_val = cx.argument::<JsNumber>(0)?;
+        {
+            let v = js_val.value(&mut cx);
+            if v < (u32::MIN as f64) || (u32::MAX as f64) < v {
+                cx.throw_type_error("Not an u32 number")?
+            }
+            let v = v as u32;
+            v
+        }
+    };
+    let realm_id = {
+        let js_val = cx.argument::<JsString>(1)?;
+        {
+            let custom_from_rs_string = |s: String| -> Result<libparsec::VlobID, _> {
+                libparsec::VlobID::from_hex(s.as_str()).map_err(|e| e.to_string())
+            };
+            match custom_from_rs_string(js_val.value(&mut cx)) {
+                Ok(val) => val,
+                Err(err) => return cx.throw_type_error(err),
+            }
+        }
+    };
+    let channel = cx.channel();
+    let (deferred, promise) = cx.promise();
+
+    // TODO: Promises are not cancellable in Javascript by default, should we add a custom cancel method ?
+    let _handle = crate::TOKIO_RUNTIME
+        .lock()
+        .expect("Mutex is poisoned")
+        .spawn(async move {
+            let ret = libparsec::client_rekey_workspace(client, realm_id).await;
+
+            deferred.settle_with(&channel, move |mut cx| {
+                let js_ret = match ret {
+                    Ok(ok) => {
+                        let js_obj = JsObject::new(&mut cx);
+                        let js_tag = JsBoolean::new(&mut cx, true);
+                        js_obj.set(&mut cx, "ok", js_tag)?;
+                        let js_value = {
+                            #[allow(clippy::let_unit_value)]
+                            let _ = ok;
+                            JsNull::new(&mut cx)
+                        };
+                        js_obj.set(&mut cx, "value", js_value)?;
+                        js_obj
+                    }
+                    Err(err) => {
+                        let js_obj = cx.empty_object();
+                        let js_tag = JsBoolean::new(&mut cx, false);
+                        js_obj.set(&mut cx, "ok", js_tag)?;
+                        let js_err = variant_client_rekey_workspace_error_rs_to_js(&mut cx, err)?;
+                        js_obj.set(&mut cx, "error", js_err)?;
+                        js_obj
+                    }
+                };
+                Ok(js_ret)
+            });
+        });
+
+    Ok(promise)
+}
+
 // client_rename_workspace
 fn client_rename_workspace(mut cx: FunctionContext) -> JsResult<JsPromise> {
     crate::init_sentry();
@@ -24164,6 +26273,13 @@ fn client_revoke_user(mut cx: FunctionContext) -> JsResult<JsPromise> {
             }
         }
     };
+    let orphaned_workspaces = {
+        let js_val = cx.argument::<JsString>(2)?;
+        {
+            let js_string = js_val.value(&mut cx);
+            enum_orphaned_workspaces_strategy_js_to_rs(&mut cx, js_string.as_str())?
+        }
+    };
     let channel = cx.channel();
     let (deferred, promise) = cx.promise();
 
@@ -24172,7 +26288,7 @@ fn client_revoke_user(mut cx: FunctionContext) -> JsResult<JsPromise> {
         .lock()
         .expect("Mutex is poisoned")
         .spawn(async move {
-            let ret = libparsec::client_revoke_user(client, user).await;
+            let ret = libparsec::client_revoke_user(client, user, orphaned_workspaces).await;
 
             deferred.settle_with(&channel, move |mut cx| {
                 let js_ret = match ret {
@@ -24394,6 +26510,23 @@ fn client_share_workspace(mut cx: FunctionContext) -> JsResult<JsPromise> {
         },
         None => None,
     };
+    let expires_on = match cx.argument_opt(4) {
+        Some(v) => match v.downcast::<JsNumber, _>(&mut cx) {
+            Ok(js_val) => Some({
+                let v = js_val.value(&mut cx);
+                let custom_from_rs_f64 = |n: f64| -> Result<_, &'static str> {
+                    libparsec::DateTime::from_timestamp_micros((n * 1_000_000f64) as i64)
+                        .map_err(|_| "Out-of-bound datetime")
+                };
+                match custom_from_rs_f64(v) {
+                    Ok(val) => val,
+                    Err(err) => return cx.throw_type_error(err),
+                }
+            }),
+            Err(_) => None,
+        },
+        None => None,
+    };
     let channel = cx.channel();
     let (deferred, promise) = cx.promise();
 
@@ -24402,7 +26535,9 @@ fn client_share_workspace(mut cx: FunctionContext) -> JsResult<JsPromise> {
         .lock()
         .expect("Mutex is poisoned")
         .spawn(async move {
-            let ret = libparsec::client_share_workspace(client, realm_id, recipient, role).await;
+            let ret =
+                libparsec::client_share_workspace(client, realm_id, recipient, role, expires_on)
+                    .await;
 
             deferred.settle_with(&channel, move |mut cx| {
                 let js_ret = match ret {
@@ -25028,6 +27163,87 @@ fn client_totp_setup_status(mut cx: FunctionContext) -> JsResult<JsPromise> {
     Ok(promise)
 }
 
+// client_transfer_workspace_ownership
+fn client_transfer_workspace_ownership(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    crate::init_sentry();
+    let client = {
+        let js_val = cx.argument::<JsNumber>(0)?;
+        {
+            let v = js_val.value(&mut cx);
+            if v < (u32::MIN as f64) || (u32::MAX as f64) < v {
+                cx.throw_type_error("Not an u32 number")?
+            }
+            let v = v as u32;
+            v
+        }
+    };
+    let realm_id = {
+        let js_val = cx.argument::<JsString>(1)?;
+        {
+            let custom_from_rs_string = |s: String| -> Result<libparsec::VlobID, _> {
+                libparsec::VlobID::from_hex(s.as_str()).map_err(|e| e.to_string())
+            };
+            match custom_from_rs_string(js_val.value(&mut cx)) {
+                Ok(val) => val,
+                Err(err) => return cx.throw_type_error(err),
+            }
+        }
+    };
+    let new_owner = {
+        let js_val = cx.argument::<JsString>(2)?;
+        {
+            let custom_from_rs_string = |s: String| -> Result<libparsec::UserID, _> {
+                libparsec::UserID::from_hex(s.as_str()).map_err(|e| e.to_string())
+            };
+            match custom_from_rs_string(js_val.value(&mut cx)) {
+                Ok(val) => val,
+                Err(err) => return cx.throw_type_error(err),
+            }
+        }
+    };
+    let channel = cx.channel();
+    let (deferred, promise) = cx.promise();
+
+    // TODO: Promises are not cancellable in Javascript by default, should we add a custom cancel method ?
+    let _handle = crate::TOKIO_RUNTIME
+        .lock()
+        .expect("Mutex is poisoned")
+        .spawn(async move {
+            let ret =
+                libparsec::client_transfer_workspace_ownership(client, realm_id, new_owner).await;
+
+            deferred.settle_with(&channel, move |mut cx| {
+                let js_ret = match ret {
+                    Ok(ok) => {
+                        let js_obj = JsObject::new(&mut cx);
+                        let js_tag = JsBoolean::new(&mut cx, true);
+                        js_obj.set(&mut cx, "ok", js_tag)?;
+                        let js_value = {
+                            #[allow(clippy::let_unit_value)]
+                            let _ = ok;
+                            JsNull::new(&mut cx)
+                        };
+                        js_obj.set(&mut cx, "value", js_value)?;
+                        js_obj
+                    }
+                    Err(err) => {
+                        let js_obj = cx.empty_object();
+                        let js_tag = JsBoolean::new(&mut cx, false);
+                        js_obj.set(&mut cx, "ok", js_tag)?;
+                        let js_err = variant_client_transfer_workspace_ownership_error_rs_to_js(
+                            &mut cx, err,
+                        )?;
+                        js_obj.set(&mut cx, "error", js_err)?;
+                        js_obj
+                    }
+                };
+                Ok(js_ret)
+            });
+        });
+
+    Ok(promise)
+}
+
 // client_update_user_profile
 fn client_update_user_profile(mut cx: FunctionContext) -> JsResult<JsPromise> {
     crate::init_sentry();
@@ -25061,6 +27277,13 @@ fn client_update_user_profile(mut cx: FunctionContext) -> JsResult<JsPromise> {
             enum_user_profile_js_to_rs(&mut cx, js_string.as_str())?
         }
     };
+    let orphaned_workspaces = {
+        let js_val = cx.argument::<JsString>(3)?;
+        {
+            let js_string = js_val.value(&mut cx);
+            enum_orphaned_workspaces_strategy_js_to_rs(&mut cx, js_string.as_str())?
+        }
+    };
     let channel = cx.channel();
     let (deferred, promise) = cx.promise();
 
@@ -25069,7 +27292,13 @@ fn client_update_user_profile(mut cx: FunctionContext) -> JsResult<JsPromise> {
         .lock()
         .expect("Mutex is poisoned")
         .spawn(async move {
-            let ret = libparsec::client_update_user_profile(client_handle, user, new_profile).await;
+            let ret = libparsec::client_update_user_profile(
+                client_handle,
+                user,
+                new_profile,
+                orphaned_workspaces,
+            )
+            .await;
 
             deferred.settle_with(&channel, move |mut cx| {
                 let js_ret = match ret {
@@ -25144,6 +27373,27 @@ fn get_default_data_base_dir(mut cx: FunctionContext) -> JsResult<JsPromise> {
     Ok(promise)
 }
 
+// get_default_machine_key_file
+fn get_default_machine_key_file(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    crate::init_sentry();
+    let ret = libparsec::get_default_machine_key_file();
+    let js_ret = JsString::try_new(&mut cx, {
+        let custom_to_rs_string = |path: std::path::PathBuf| -> Result<_, _> {
+            path.into_os_string()
+                .into_string()
+                .map_err(|_| "Path contains non-utf8 characters")
+        };
+        match custom_to_rs_string(ret) {
+            Ok(ok) => ok,
+            Err(err) => return cx.throw_type_error(err.to_string()),
+        }
+    })
+    .or_throw(&mut cx)?;
+    let (deferred, promise) = cx.promise();
+    deferred.resolve(&mut cx, js_ret);
+    Ok(promise)
+}
+
 // get_default_mountpoint_base_dir
 fn get_default_mountpoint_base_dir(mut cx: FunctionContext) -> JsResult<JsPromise> {
     crate::init_sentry();
@@ -29681,6 +31931,75 @@ fn workspace_generate_path_addr(mut cx: FunctionContext) -> JsResult<JsPromise>
     Ok(promise)
 }
 
+// workspace_get_remote_only_entries
+fn workspace_get_remote_only_entries(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    crate::init_sentry();
+    let workspace = {
+        let js_val = cx.argument::<JsNumber>(0)?;
+        {
+            let v = js_val.value(&mut cx);
+            if v < (u32::MIN as f64) || (u32::MAX as f64) < v {
+                cx.throw_type_error("Not an u32 number")?
+            }
+            let v = v as u32;
+            v
+        }
+    };
+    let channel = cx.channel();
+    let (deferred, promise) = cx.promise();
+
+    // TODO: Promises are not cancellable in Javascript by default, should we add a custom cancel method ?
+    let _handle = crate::TOKIO_RUNTIME
+        .lock()
+        .expect("Mutex is poisoned")
+        .spawn(async move {
+            let ret = libparsec::workspace_get_remote_only_entries(workspace).await;
+
+            deferred.settle_with(&channel, move |mut cx| {
+                let js_ret = match ret {
+                    Ok(ok) => {
+                        let js_obj = JsObject::new(&mut cx);
+                        let js_tag = JsBoolean::new(&mut cx, true);
+                        js_obj.set(&mut cx, "ok", js_tag)?;
+                        let js_value = {
+                            // JsArray::new allocates with `undefined` value, that's why we `set` value
+                            let js_array = JsArray::new(&mut cx, ok.len());
+                            for (i, elem) in ok.into_iter().enumerate() {
+                                let js_elem = JsString::try_new(&mut cx, {
+                                    let custom_to_rs_string =
+                                        |x: libparsec::VlobID| -> Result<String, &'static str> {
+                                            Ok(x.hex())
+                                        };
+                                    match custom_to_rs_string(elem) {
+                                        Ok(ok) => ok,
+                                        Err(err) => return cx.throw_type_error(err.to_string()),
+                                    }
+                                })
+                                .or_throw(&mut cx)?;
+                                js_array.set(&mut cx, i as u32, js_elem)?;
+                            }
+                            js_array
+                        };
+                        js_obj.set(&mut cx, "value", js_value)?;
+                        js_obj
+                    }
+                    Err(err) => {
+                        let js_obj = cx.empty_object();
+                        let js_tag = JsBoolean::new(&mut cx, false);
+                        js_obj.set(&mut cx, "ok", js_tag)?;
+                        let js_err =
+                            variant_workspace_get_remote_only_entries_error_rs_to_js(&mut cx, err)?;
+                        js_obj.set(&mut cx, "error", js_err)?;
+                        js_obj
+                    }
+                };
+                Ok(js_ret)
+            });
+        });
+
+    Ok(promise)
+}
+
 // workspace_history_fd_close
 fn workspace_history_fd_close(mut cx: FunctionContext) -> JsResult<JsPromise> {
     crate::init_sentry();
@@ -30804,6 +33123,69 @@ fn workspace_info(mut cx: FunctionContext) -> JsResult<JsPromise> {
     Ok(promise)
 }
 
+// workspace_is_entry_remote_only_by_id
+fn workspace_is_entry_remote_only_by_id(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    crate::init_sentry();
+    let workspace = {
+        let js_val = cx.argument::<JsNumber>(0)?;
+        {
+            let v = js_val.value(&mut cx);
+            if v < (u32::MIN as f64) || (u32::MAX as f64) < v {
+                cx.throw_type_error("Not an u32 number")?
+            }
+            let v = v as u32;
+            v
+        }
+    };
+    let entry_id = {
+        let js_val = cx.argument::<JsString>(1)?;
+        {
+            let custom_from_rs_string = |s: String| -> Result<libparsec::VlobID, _> {
+                libparsec::VlobID::from_hex(s.as_str()).map_err(|e| e.to_string())
+            };
+            match custom_from_rs_string(js_val.value(&mut cx)) {
+                Ok(val) => val,
+                Err(err) => return cx.throw_type_error(err),
+            }
+        }
+    };
+    let channel = cx.channel();
+    let (deferred, promise) = cx.promise();
+
+    // TODO: Promises are not cancellable in Javascript by default, should we add a custom cancel method ?
+    let _handle = crate::TOKIO_RUNTIME
+        .lock()
+        .expect("Mutex is poisoned")
+        .spawn(async move {
+            let ret = libparsec::workspace_is_entry_remote_only_by_id(workspace, entry_id).await;
+
+            deferred.settle_with(&channel, move |mut cx| {
+                let js_ret = match ret {
+                    Ok(ok) => {
+                        let js_obj = JsObject::new(&mut cx);
+                        let js_tag = JsBoolean::new(&mut cx, true);
+                        js_obj.set(&mut cx, "ok", js_tag)?;
+                        let js_value = JsBoolean::new(&mut cx, ok);
+                        js_obj.set(&mut cx, "value", js_value)?;
+                        js_obj
+                    }
+                    Err(err) => {
+                        let js_obj = cx.empty_object();
+                        let js_tag = JsBoolean::new(&mut cx, false);
+                        js_obj.set(&mut cx, "ok", js_tag)?;
+                        let js_err =
+                            variant_workspace_is_entry_remote_only_error_rs_to_js(&mut cx, err)?;
+                        js_obj.set(&mut cx, "error", js_err)?;
+                        js_obj
+                    }
+                };
+                Ok(js_ret)
+            });
+        });
+
+    Ok(promise)
+}
+
 // workspace_is_file_content_local
 fn workspace_is_file_content_local(mut cx: FunctionContext) -> JsResult<JsPromise> {
     crate::init_sentry();
@@ -31120,10 +33502,241 @@ fn workspace_open_file_and_get_id(mut cx: FunctionContext) -> JsResult<JsPromise
             }
         }
     };
-    let mode = {
-        let js_val = cx.argument::<JsObject>(2)?;
-        struct_open_options_js_to_rs(&mut cx, js_val)?
-    };
+    let mode = {
+        let js_val = cx.argument::<JsObject>(2)?;
+        struct_open_options_js_to_rs(&mut cx, js_val)?
+    };
+    let channel = cx.channel();
+    let (deferred, promise) = cx.promise();
+
+    // TODO: Promises are not cancellable in Javascript by default, should we add a custom cancel method ?
+    let _handle = crate::TOKIO_RUNTIME
+        .lock()
+        .expect("Mutex is poisoned")
+        .spawn(async move {
+            let ret = libparsec::workspace_open_file_and_get_id(workspace, path, mode).await;
+
+            deferred.settle_with(&channel, move |mut cx| {
+                let js_ret = match ret {
+                    Ok(ok) => {
+                        let js_obj = JsObject::new(&mut cx);
+                        let js_tag = JsBoolean::new(&mut cx, true);
+                        js_obj.set(&mut cx, "ok", js_tag)?;
+                        let js_value = {
+                            let (x0, x1) = ok;
+                            let js_array = JsArray::new(&mut cx, 2);
+                            let js_value = JsNumber::new(&mut cx, {
+                                let custom_to_rs_u32 =
+                                    |fd: libparsec::FileDescriptor| -> Result<_, &'static str> {
+                                        Ok(fd.0)
+                                    };
+                                match custom_to_rs_u32(x0) {
+                                    Ok(ok) => ok,
+                                    Err(err) => return cx.throw_type_error(err),
+                                }
+                            }
+                                as f64);
+                            js_array.set(&mut cx, 0, js_value)?;
+                            let js_value = JsString::try_new(&mut cx, {
+                                let custom_to_rs_string =
+                                    |x: libparsec::VlobID| -> Result<String, &'static str> {
+                                        Ok(x.hex())
+                                    };
+                                match custom_to_rs_string(x1) {
+                                    Ok(ok) => ok,
+                                    Err(err) => return cx.throw_type_error(err.to_string()),
+                                }
+                            })
+                            .or_throw(&mut cx)?;
+                            js_array.set(&mut cx, 1, js_value)?;
+                            js_array
+                        };
+                        js_obj.set(&mut cx, "value", js_value)?;
+                        js_obj
+                    }
+                    Err(err) => {
+                        let js_obj = cx.empty_object();
+                        let js_tag = JsBoolean::new(&mut cx, false);
+                        js_obj.set(&mut cx, "ok", js_tag)?;
+                        let js_err = variant_workspace_open_file_error_rs_to_js(&mut cx, err)?;
+                        js_obj.set(&mut cx, "error", js_err)?;
+                        js_obj
+                    }
+                };
+                Ok(js_ret)
+            });
+        });
+
+    Ok(promise)
+}
+
+// workspace_open_file_by_id
+fn workspace_open_file_by_id(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    crate::init_sentry();
+    let workspace = {
+        let js_val = cx.argument::<JsNumber>(0)?;
+        {
+            let v = js_val.value(&mut cx);
+            if v < (u32::MIN as f64) || (u32::MAX as f64) < v {
+                cx.throw_type_error("Not an u32 number")?
+            }
+            let v = v as u32;
+            v
+        }
+    };
+    let entry_id = {
+        let js_val = cx.argument::<JsString>(1)?;
+        {
+            let custom_from_rs_string = |s: String| -> Result<libparsec::VlobID, _> {
+                libparsec::VlobID::from_hex(s.as_str()).map_err(|e| e.to_string())
+            };
+            match custom_from_rs_string(js_val.value(&mut cx)) {
+                Ok(val) => val,
+                Err(err) => return cx.throw_type_error(err),
+            }
+        }
+    };
+    let mode = {
+        let js_val = cx.argument::<JsObject>(2)?;
+        struct_open_options_js_to_rs(&mut cx, js_val)?
+    };
+    let channel = cx.channel();
+    let (deferred, promise) = cx.promise();
+
+    // TODO: Promises are not cancellable in Javascript by default, should we add a custom cancel method ?
+    let _handle = crate::TOKIO_RUNTIME
+        .lock()
+        .expect("Mutex is poisoned")
+        .spawn(async move {
+            let ret = libparsec::workspace_open_file_by_id(workspace, entry_id, mode).await;
+
+            deferred.settle_with(&channel, move |mut cx| {
+                let js_ret = match ret {
+                    Ok(ok) => {
+                        let js_obj = JsObject::new(&mut cx);
+                        let js_tag = JsBoolean::new(&mut cx, true);
+                        js_obj.set(&mut cx, "ok", js_tag)?;
+                        let js_value = JsNumber::new(&mut cx, {
+                            let custom_to_rs_u32 =
+                                |fd: libparsec::FileDescriptor| -> Result<_, &'static str> {
+                                    Ok(fd.0)
+                                };
+                            match custom_to_rs_u32(ok) {
+                                Ok(ok) => ok,
+                                Err(err) => return cx.throw_type_error(err),
+                            }
+                        } as f64);
+                        js_obj.set(&mut cx, "value", js_value)?;
+                        js_obj
+                    }
+                    Err(err) => {
+                        let js_obj = cx.empty_object();
+                        let js_tag = JsBoolean::new(&mut cx, false);
+                        js_obj.set(&mut cx, "ok", js_tag)?;
+                        let js_err = variant_workspace_open_file_error_rs_to_js(&mut cx, err)?;
+                        js_obj.set(&mut cx, "error", js_err)?;
+                        js_obj
+                    }
+                };
+                Ok(js_ret)
+            });
+        });
+
+    Ok(promise)
+}
+
+// workspace_remove_entry
+fn workspace_remove_entry(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    crate::init_sentry();
+    let workspace = {
+        let js_val = cx.argument::<JsNumber>(0)?;
+        {
+            let v = js_val.value(&mut cx);
+            if v < (u32::MIN as f64) || (u32::MAX as f64) < v {
+                cx.throw_type_error("Not an u32 number")?
+            }
+            let v = v as u32;
+            v
+        }
+    };
+    let path = {
+        let js_val = cx.argument::<JsString>(1)?;
+        {
+            let custom_from_rs_string = |s: String| -> Result<_, String> {
+                s.parse::<libparsec::FsPath>().map_err(|e| e.to_string())
+            };
+            match custom_from_rs_string(js_val.value(&mut cx)) {
+                Ok(val) => val,
+                Err(err) => return cx.throw_type_error(err),
+            }
+        }
+    };
+    let channel = cx.channel();
+    let (deferred, promise) = cx.promise();
+
+    // TODO: Promises are not cancellable in Javascript by default, should we add a custom cancel method ?
+    let _handle = crate::TOKIO_RUNTIME
+        .lock()
+        .expect("Mutex is poisoned")
+        .spawn(async move {
+            let ret = libparsec::workspace_remove_entry(workspace, path).await;
+
+            deferred.settle_with(&channel, move |mut cx| {
+                let js_ret = match ret {
+                    Ok(ok) => {
+                        let js_obj = JsObject::new(&mut cx);
+                        let js_tag = JsBoolean::new(&mut cx, true);
+                        js_obj.set(&mut cx, "ok", js_tag)?;
+                        let js_value = {
+                            #[allow(clippy::let_unit_value)]
+                            let _ = ok;
+                            JsNull::new(&mut cx)
+                        };
+                        js_obj.set(&mut cx, "value", js_value)?;
+                        js_obj
+                    }
+                    Err(err) => {
+                        let js_obj = cx.empty_object();
+                        let js_tag = JsBoolean::new(&mut cx, false);
+                        js_obj.set(&mut cx, "ok", js_tag)?;
+                        let js_err = variant_workspace_remove_entry_error_rs_to_js(&mut cx, err)?;
+                        js_obj.set(&mut cx, "error", js_err)?;
+                        js_obj
+                    }
+                };
+                Ok(js_ret)
+            });
+        });
+
+    Ok(promise)
+}
+
+// workspace_remove_file
+fn workspace_remove_file(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    crate::init_sentry();
+    let workspace = {
+        let js_val = cx.argument::<JsNumber>(0)?;
+        {
+            let v = js_val.value(&mut cx);
+            if v < (u32::MIN as f64) || (u32::MAX as f64) < v {
+                cx.throw_type_error("Not an u32 number")?
+            }
+            let v = v as u32;
+            v
+        }
+    };
+    let path = {
+        let js_val = cx.argument::<JsString>(1)?;
+        {
+            let custom_from_rs_string = |s: String| -> Result<_, String> {
+                s.parse::<libparsec::FsPath>().map_err(|e| e.to_string())
+            };
+            match custom_from_rs_string(js_val.value(&mut cx)) {
+                Ok(val) => val,
+                Err(err) => return cx.throw_type_error(err),
+            }
+        }
+    };
     let channel = cx.channel();
     let (deferred, promise) = cx.promise();
 
@@ -31132,7 +33745,7 @@ fn workspace_open_file_and_get_id(mut cx: FunctionContext) -> JsResult<JsPromise
         .lock()
         .expect("Mutex is poisoned")
         .spawn(async move {
-            let ret = libparsec::workspace_open_file_and_get_id(workspace, path, mode).await;
+            let ret = libparsec::workspace_remove_file(workspace, path).await;
 
             deferred.settle_with(&channel, move |mut cx| {
                 let js_ret = match ret {
@@ -31141,33 +33754,9 @@ fn workspace_open_file_and_get_id(mut cx: FunctionContext) -> JsResult<JsPromise
                         let js_tag = JsBoolean::new(&mut cx, true);
                         js_obj.set(&mut cx, "ok", js_tag)?;
                         let js_value = {
-                            let (x0, x1) = ok;
-                            let js_array = JsArray::new(&mut cx, 2);
-                            let js_value = JsNumber::new(&mut cx, {
-                                let custom_to_rs_u32 =
-                                    |fd: libparsec::FileDescriptor| -> Result<_, &'static str> {
-                                        Ok(fd.0)
-                                    };
-                                match custom_to_rs_u32(x0) {
-                                    Ok(ok) => ok,
-                                    Err(err) => return cx.throw_type_error(err),
-                                }
-                            }
-                                as f64);
-                            js_array.set(&mut cx, 0, js_value)?;
-                            let js_value = JsString::try_new(&mut cx, {
-                                let custom_to_rs_string =
-                                    |x: libparsec::VlobID| -> Result<String, &'static str> {
-                                        Ok(x.hex())
-                                    };
-                                match custom_to_rs_string(x1) {
-                                    Ok(ok) => ok,
-                                    Err(err) => return cx.throw_type_error(err.to_string()),
-                                }
-                            })
-                            .or_throw(&mut cx)?;
-                            js_array.set(&mut cx, 1, js_value)?;
-                            js_array
+                            #[allow(clippy::let_unit_value)]
+                            let _ = ok;
+                            JsNull::new(&mut cx)
                         };
                         js_obj.set(&mut cx, "value", js_value)?;
                         js_obj
@@ -31176,82 +33765,7 @@ fn workspace_open_file_and_get_id(mut cx: FunctionContext) -> JsResult<JsPromise
                         let js_obj = cx.empty_object();
                         let js_tag = JsBoolean::new(&mut cx, false);
                         js_obj.set(&mut cx, "ok", js_tag)?;
-                        let js_err = variant_workspace_open_file_error_rs_to_js(&mut cx, err)?;
-                        js_obj.set(&mut cx, "error", js_err)?;
-                        js_obj
-                    }
-                };
-                Ok(js_ret)
-            });
-        });
-
-    Ok(promise)
-}
-
-// workspace_open_file_by_id
-fn workspace_open_file_by_id(mut cx: FunctionContext) -> JsResult<JsPromise> {
-    crate::init_sentry();
-    let workspace = {
-        let js_val = cx.argument::<JsNumber>(0)?;
-        {
-            let v = js_val.value(&mut cx);
-            if v < (u32::MIN as f64) || (u32::MAX as f64) < v {
-                cx.throw_type_error("Not an u32 number")?
-            }
-            let v = v as u32;
-            v
-        }
-    };
-    let entry_id = {
-        let js_val = cx.argument::<JsString>(1)?;
-        {
-            let custom_from_rs_string = |s: String| -> Result<libparsec::VlobID, _> {
-                libparsec::VlobID::from_hex(s.as_str()).map_err(|e| e.to_string())
-            };
-            match custom_from_rs_string(js_val.value(&mut cx)) {
-                Ok(val) => val,
-                Err(err) => return cx.throw_type_error(err),
-            }
-        }
-    };
-    let mode = {
-        let js_val = cx.argument::<JsObject>(2)?;
-        struct_open_options_js_to_rs(&mut cx, js_val)?
-    };
-    let channel = cx.channel();
-    let (deferred, promise) = cx.promise();
-
-    // TODO: Promises are not cancellable in Javascript by default, should we add a custom cancel method ?
-    let _handle = crate::TOKIO_RUNTIME
-        .lock()
-        .expect("Mutex is poisoned")
-        .spawn(async move {
-            let ret = libparsec::workspace_open_file_by_id(workspace, entry_id, mode).await;
-
-            deferred.settle_with(&channel, move |mut cx| {
-                let js_ret = match ret {
-                    Ok(ok) => {
-                        let js_obj = JsObject::new(&mut cx);
-                        let js_tag = JsBoolean::new(&mut cx, true);
-                        js_obj.set(&mut cx, "ok", js_tag)?;
-                        let js_value = JsNumber::new(&mut cx, {
-                            let custom_to_rs_u32 =
-                                |fd: libparsec::FileDescriptor| -> Result<_, &'static str> {
-                                    Ok(fd.0)
-                                };
-                            match custom_to_rs_u32(ok) {
-                                Ok(ok) => ok,
-                                Err(err) => return cx.throw_type_error(err),
-                            }
-                        } as f64);
-                        js_obj.set(&mut cx, "value", js_value)?;
-                        js_obj
-                    }
-                    Err(err) => {
-                        let js_obj = cx.empty_object();
-                        let js_tag = JsBoolean::new(&mut cx, false);
-                        js_obj.set(&mut cx, "ok", js_tag)?;
-                        let js_err = variant_workspace_open_file_error_rs_to_js(&mut cx, err)?;
+                        let js_err = variant_workspace_remove_entry_error_rs_to_js(&mut cx, err)?;
                         js_obj.set(&mut cx, "error", js_err)?;
                         js_obj
                     }
@@ -31263,8 +33777,8 @@ fn workspace_open_file_by_id(mut cx: FunctionContext) -> JsResult<JsPromise> {
     Ok(promise)
 }
 
-// workspace_remove_entry
-fn workspace_remove_entry(mut cx: FunctionContext) -> JsResult<JsPromise> {
+// workspace_remove_folder
+fn workspace_remove_folder(mut cx: FunctionContext) -> JsResult<JsPromise> {
     crate::init_sentry();
     let workspace = {
         let js_val = cx.argument::<JsNumber>(0)?;
@@ -31297,7 +33811,7 @@ fn workspace_remove_entry(mut cx: FunctionContext) -> JsResult<JsPromise> {
         .lock()
         .expect("Mutex is poisoned")
         .spawn(async move {
-            let ret = libparsec::workspace_remove_entry(workspace, path).await;
+            let ret = libparsec::workspace_remove_folder(workspace, path).await;
 
             deferred.settle_with(&channel, move |mut cx| {
                 let js_ret = match ret {
@@ -31329,8 +33843,8 @@ fn workspace_remove_entry(mut cx: FunctionContext) -> JsResult<JsPromise> {
     Ok(promise)
 }
 
-// workspace_remove_file
-fn workspace_remove_file(mut cx: FunctionContext) -> JsResult<JsPromise> {
+// workspace_remove_folder_all
+fn workspace_remove_folder_all(mut cx: FunctionContext) -> JsResult<JsPromise> {
     crate::init_sentry();
     let workspace = {
         let js_val = cx.argument::<JsNumber>(0)?;
@@ -31363,7 +33877,7 @@ fn workspace_remove_file(mut cx: FunctionContext) -> JsResult<JsPromise> {
         .lock()
         .expect("Mutex is poisoned")
         .spawn(async move {
-            let ret = libparsec::workspace_remove_file(workspace, path).await;
+            let ret = libparsec::workspace_remove_folder_all(workspace, path).await;
 
             deferred.settle_with(&channel, move |mut cx| {
                 let js_ret = match ret {
@@ -31395,8 +33909,8 @@ fn workspace_remove_file(mut cx: FunctionContext) -> JsResult<JsPromise> {
     Ok(promise)
 }
 
-// workspace_remove_folder
-fn workspace_remove_folder(mut cx: FunctionContext) -> JsResult<JsPromise> {
+// workspace_rename_entry_by_id
+fn workspace_rename_entry_by_id(mut cx: FunctionContext) -> JsResult<JsPromise> {
     crate::init_sentry();
     let workspace = {
         let js_val = cx.argument::<JsNumber>(0)?;
@@ -31409,11 +33923,11 @@ fn workspace_remove_folder(mut cx: FunctionContext) -> JsResult<JsPromise> {
             v
         }
     };
-    let path = {
+    let src_parent_id = {
         let js_val = cx.argument::<JsString>(1)?;
         {
-            let custom_from_rs_string = |s: String| -> Result<_, String> {
-                s.parse::<libparsec::FsPath>().map_err(|e| e.to_string())
+            let custom_from_rs_string = |s: String| -> Result<libparsec::VlobID, _> {
+                libparsec::VlobID::from_hex(s.as_str()).map_err(|e| e.to_string())
             };
             match custom_from_rs_string(js_val.value(&mut cx)) {
                 Ok(val) => val,
@@ -31421,65 +33935,23 @@ fn workspace_remove_folder(mut cx: FunctionContext) -> JsResult<JsPromise> {
             }
         }
     };
-    let channel = cx.channel();
-    let (deferred, promise) = cx.promise();
-
-    // TODO: Promises are not cancellable in Javascript by default, should we add a custom cancel method ?
-    let _handle = crate::TOKIO_RUNTIME
-        .lock()
-        .expect("Mutex is poisoned")
-        .spawn(async move {
-            let ret = libparsec::workspace_remove_folder(workspace, path).await;
-
-            deferred.settle_with(&channel, move |mut cx| {
-                let js_ret = match ret {
-                    Ok(ok) => {
-                        let js_obj = JsObject::new(&mut cx);
-                        let js_tag = JsBoolean::new(&mut cx, true);
-                        js_obj.set(&mut cx, "ok", js_tag)?;
-                        let js_value = {
-                            #[allow(clippy::let_unit_value)]
-                            let _ = ok;
-                            JsNull::new(&mut cx)
-                        };
-                        js_obj.set(&mut cx, "value", js_value)?;
-                        js_obj
-                    }
-                    Err(err) => {
-                        let js_obj = cx.empty_object();
-                        let js_tag = JsBoolean::new(&mut cx, false);
-                        js_obj.set(&mut cx, "ok", js_tag)?;
-                        let js_err = variant_workspace_remove_entry_error_rs_to_js(&mut cx, err)?;
-                        js_obj.set(&mut cx, "error", js_err)?;
-                        js_obj
-                    }
-                };
-                Ok(js_ret)
-            });
-        });
-
-    Ok(promise)
-}
-
-// workspace_remove_folder_all
-fn workspace_remove_folder_all(mut cx: FunctionContext) -> JsResult<JsPromise> {
-    crate::init_sentry();
-    let workspace = {
-        let js_val = cx.argument::<JsNumber>(0)?;
+    let src_name = {
+        let js_val = cx.argument::<JsString>(2)?;
         {
-            let v = js_val.value(&mut cx);
-            if v < (u32::MIN as f64) || (u32::MAX as f64) < v {
-                cx.throw_type_error("Not an u32 number")?
+            let custom_from_rs_string = |s: String| -> Result<_, _> {
+                s.parse::<libparsec::EntryName>().map_err(|e| e.to_string())
+            };
+            match custom_from_rs_string(js_val.value(&mut cx)) {
+                Ok(val) => val,
+                Err(err) => return cx.throw_type_error(err),
             }
-            let v = v as u32;
-            v
         }
     };
-    let path = {
-        let js_val = cx.argument::<JsString>(1)?;
+    let dst_name = {
+        let js_val = cx.argument::<JsString>(3)?;
         {
-            let custom_from_rs_string = |s: String| -> Result<_, String> {
-                s.parse::<libparsec::FsPath>().map_err(|e| e.to_string())
+            let custom_from_rs_string = |s: String| -> Result<_, _> {
+                s.parse::<libparsec::EntryName>().map_err(|e| e.to_string())
             };
             match custom_from_rs_string(js_val.value(&mut cx)) {
                 Ok(val) => val,
@@ -31487,6 +33959,10 @@ fn workspace_remove_folder_all(mut cx: FunctionContext) -> JsResult<JsPromise> {
             }
         }
     };
+    let mode = {
+        let js_val = cx.argument::<JsObject>(4)?;
+        variant_move_entry_mode_js_to_rs(&mut cx, js_val)?
+    };
     let channel = cx.channel();
     let (deferred, promise) = cx.promise();
 
@@ -31495,7 +33971,14 @@ fn workspace_remove_folder_all(mut cx: FunctionContext) -> JsResult<JsPromise> {
         .lock()
         .expect("Mutex is poisoned")
         .spawn(async move {
-            let ret = libparsec::workspace_remove_folder_all(workspace, path).await;
+            let ret = libparsec::workspace_rename_entry_by_id(
+                workspace,
+                src_parent_id,
+                src_name,
+                dst_name,
+                mode,
+            )
+            .await;
 
             deferred.settle_with(&channel, move |mut cx| {
                 let js_ret = match ret {
@@ -31515,7 +33998,7 @@ fn workspace_remove_folder_all(mut cx: FunctionContext) -> JsResult<JsPromise> {
                         let js_obj = cx.empty_object();
                         let js_tag = JsBoolean::new(&mut cx, false);
                         js_obj.set(&mut cx, "ok", js_tag)?;
-                        let js_err = variant_workspace_remove_entry_error_rs_to_js(&mut cx, err)?;
+                        let js_err = variant_workspace_move_entry_error_rs_to_js(&mut cx, err)?;
                         js_obj.set(&mut cx, "error", js_err)?;
                         js_obj
                     }
@@ -31527,8 +34010,8 @@ fn workspace_remove_folder_all(mut cx: FunctionContext) -> JsResult<JsPromise> {
     Ok(promise)
 }
 
-// workspace_rename_entry_by_id
-fn workspace_rename_entry_by_id(mut cx: FunctionContext) -> JsResult<JsPromise> {
+// workspace_set_remote_only_entries
+fn workspace_set_remote_only_entries(mut cx: FunctionContext) -> JsResult<JsPromise> {
     crate::init_sentry();
     let workspace = {
         let js_val = cx.argument::<JsNumber>(0)?;
@@ -31541,46 +34024,26 @@ fn workspace_rename_entry_by_id(mut cx: FunctionContext) -> JsResult<JsPromise>
             v
         }
     };
-    let src_parent_id = {
-        let js_val = cx.argument::<JsString>(1)?;
-        {
-            let custom_from_rs_string = |s: String| -> Result<libparsec::VlobID, _> {
-                libparsec::VlobID::from_hex(s.as_str()).map_err(|e| e.to_string())
-            };
-            match custom_from_rs_string(js_val.value(&mut cx)) {
-                Ok(val) => val,
-                Err(err) => return cx.throw_type_error(err),
-            }
-        }
-    };
-    let src_name = {
-        let js_val = cx.argument::<JsString>(2)?;
-        {
-            let custom_from_rs_string = |s: String| -> Result<_, _> {
-                s.parse::<libparsec::EntryName>().map_err(|e| e.to_string())
-            };
-            match custom_from_rs_string(js_val.value(&mut cx)) {
-                Ok(val) => val,
-                Err(err) => return cx.throw_type_error(err),
-            }
-        }
-    };
-    let dst_name = {
-        let js_val = cx.argument::<JsString>(3)?;
+    let entries = {
+        let js_val = cx.argument::<JsArray>(1)?;
         {
-            let custom_from_rs_string = |s: String| -> Result<_, _> {
-                s.parse::<libparsec::EntryName>().map_err(|e| e.to_string())
-            };
-            match custom_from_rs_string(js_val.value(&mut cx)) {
-                Ok(val) => val,
-                Err(err) => return cx.throw_type_error(err),
+            let size = js_val.len(&mut cx);
+            let mut v = Vec::with_capacity(size as usize);
+            for i in 0..size {
+                let js_item: Handle<JsString> = js_val.get(&mut cx, i)?;
+                v.push({
+                    let custom_from_rs_string = |s: String| -> Result<libparsec::VlobID, _> {
+                        libparsec::VlobID::from_hex(s.as_str()).map_err(|e| e.to_string())
+                    };
+                    match custom_from_rs_string(js_item.value(&mut cx)) {
+                        Ok(val) => val,
+                        Err(err) => return cx.throw_type_error(err),
+                    }
+                });
             }
+            v
         }
     };
-    let mode = {
-        let js_val = cx.argument::<JsObject>(4)?;
-        variant_move_entry_mode_js_to_rs(&mut cx, js_val)?
-    };
     let channel = cx.channel();
     let (deferred, promise) = cx.promise();
 
@@ -31589,14 +34052,7 @@ fn workspace_rename_entry_by_id(mut cx: FunctionContext) -> JsResult<JsPromise>
         .lock()
         .expect("Mutex is poisoned")
         .spawn(async move {
-            let ret = libparsec::workspace_rename_entry_by_id(
-                workspace,
-                src_parent_id,
-                src_name,
-                dst_name,
-                mode,
-            )
-            .await;
+            let ret = libparsec::workspace_set_remote_only_entries(workspace, entries).await;
 
             deferred.settle_with(&channel, move |mut cx| {
                 let js_ret = match ret {
@@ -31616,7 +34072,8 @@ fn workspace_rename_entry_by_id(mut cx: FunctionContext) -> JsResult<JsPromise>
                         let js_obj = cx.empty_object();
                         let js_tag = JsBoolean::new(&mut cx, false);
                         js_obj.set(&mut cx, "ok", js_tag)?;
-                        let js_err = variant_workspace_move_entry_error_rs_to_js(&mut cx, err)?;
+                        let js_err =
+                            variant_workspace_set_remote_only_entries_error_rs_to_js(&mut cx, err)?;
                         js_obj.set(&mut cx, "error", js_err)?;
                         js_obj
                     }
@@ -32293,6 +34750,7 @@ pub fn register_meths(cx: &mut ModuleContext) -> NeonResult<()> {
         "clientRejectAsyncEnrollment",
         client_reject_async_enrollment,
     )?;
+    cx.export_function("clientRekeyWorkspace", client_rekey_workspace)?;
     cx.export_function("clientRenameWorkspace", client_rename_workspace)?;
     cx.export_function("clientRevokeUser", client_revoke_user)?;
     cx.export_function("clientSetupShamirRecovery", client_setup_shamir_recovery)?;
@@ -32319,9 +34777,14 @@ pub fn register_meths(cx: &mut ModuleContext) -> NeonResult<()> {
     cx.export_function("clientTotpCreateOpaqueKey", client_totp_create_opaque_key)?;
     cx.export_function("clientTotpSetupConfirm", client_totp_setup_confirm)?;
     cx.export_function("clientTotpSetupStatus", client_totp_setup_status)?;
+    cx.export_function(
+        "clientTransferWorkspaceOwnership",
+        client_transfer_workspace_ownership,
+    )?;
     cx.export_function("clientUpdateUserProfile", client_update_user_profile)?;
     cx.export_function("getDefaultConfigDir", get_default_config_dir)?;
     cx.export_function("getDefaultDataBaseDir", get_default_data_base_dir)?;
+    cx.export_function("getDefaultMachineKeyFile", get_default_machine_key_file)?;
     cx.export_function(
         "getDefaultMountpointBaseDir",
         get_default_mountpoint_base_dir,
@@ -32496,6 +34959,10 @@ pub fn register_meths(cx: &mut ModuleContext) -> NeonResult<()> {
     )?;
     cx.export_function("workspaceFdWriteStartEof", workspace_fd_write_start_eof)?;
     cx.export_function("workspaceGeneratePathAddr", workspace_generate_path_addr)?;
+    cx.export_function(
+        "workspaceGetRemoteOnlyEntries",
+        workspace_get_remote_only_entries,
+    )?;
     cx.export_function("workspaceHistoryFdClose", workspace_history_fd_close)?;
     cx.export_function("workspaceHistoryFdRead", workspace_history_fd_read)?;
     cx.export_function("workspaceHistoryFdStat", workspace_history_fd_stat)?;
@@ -32543,6 +35010,10 @@ pub fn register_meths(cx: &mut ModuleContext) -> NeonResult<()> {
     )?;
     cx.export_function("workspaceHistoryStop", workspace_history_stop)?;
     cx.export_function("workspaceInfo", workspace_info)?;
+    cx.export_function(
+        "workspaceIsEntryRemoteOnlyById",
+        workspace_is_entry_remote_only_by_id,
+    )?;
     cx.export_function(
         "workspaceIsFileContentLocal",
         workspace_is_file_content_local,
@@ -32557,6 +35028,10 @@ pub fn register_meths(cx: &mut ModuleContext) -> NeonResult<()> {
     cx.export_function("workspaceRemoveFolder", workspace_remove_folder)?;
     cx.export_function("workspaceRemoveFolderAll", workspace_remove_folder_all)?;
     cx.export_function("workspaceRenameEntryById", workspace_rename_entry_by_id)?;
+    cx.export_function(
+        "workspaceSetRemoteOnlyEntries",
+        workspace_set_remote_only_entries,
+    )?;
     cx.export_function("workspaceStatEntry", workspace_stat_entry)?;
     cx.export_function("workspaceStatEntryById", workspace_stat_entry_by_id)?;
     cx.export_function(
@@ -320,6 +320,9 @@ class AccountCreateRegistrationDeviceError(ErrorVariant):
     class Offline:
         pass
 
+    class ClockDrift:
+        pass
+
     class Internal:
         pass
 
@@ -353,6 +356,9 @@ class AccountRegisterNewDeviceError(ErrorVariant):
     class Offline:
         pass
 
+    class ClockDrift:
+        pass
+
     class Internal:
         pass
 
@@ -371,6 +377,9 @@ class AccountRegisterNewDeviceError(ErrorVariant):
     class RemoteOpaqueKeyUploadFailed:
         pass
 
+    class Fido2HmacSecretNotSupported:
+        pass
+
 
 async def account_register_new_device(
     account: Handle,
@@ -147,6 +147,9 @@ class ClientAcceptAsyncEnrollmentError(ErrorVariant):
     class TimestampOutOfBallpark:
         pass
 
+    class ClockDrift:
+        pass
+
     class Internal:
         pass
 
@@ -344,6 +347,9 @@ class SubmitterFinalizeAsyncEnrollmentError(ErrorVariant):
     class SaveDeviceRemoteOpaqueKeyUploadFailed:
         pass
 
+    class SaveDeviceFido2HmacSecretNotSupported:
+        pass
+
     class Internal:
         pass
 
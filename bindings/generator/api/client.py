@@ -5,6 +5,7 @@ from .addr import ParsecOrganizationAddr
 from .common import (
     U32,
     U64,
+    Bytes,
     DateTime,
     DeviceID,
     DeviceLabel,
@@ -108,6 +109,7 @@ class ServerOrganizationConfig(Structure):
     user_profile_outsider_allowed: bool
     active_users_limit: ActiveUsersLimit
     realm_minimum_archiving_period_before_deletion: U64
+    pki_crls: list[Bytes]
 
 
 class ClientInfo(Structure):
@@ -198,6 +200,11 @@ class DeviceInfo(Structure):
     created_by: DeviceID | None
 
 
+class OrphanedWorkspacesStrategy(Enum):
+    Abort = EnumItemUnit
+    Ignore = EnumItemUnit
+
+
 class ClientRevokeUserError(ErrorVariant):
     class Stopped:
         pass
@@ -214,6 +221,9 @@ class ClientRevokeUserError(ErrorVariant):
     class AuthorNotAllowed:
         pass
 
+    class SoleOwnerOfWorkspaces:
+        workspaces: list[VlobID]
+
     class TimestampOutOfBallpark:
         pass
 
@@ -226,6 +236,9 @@ class ClientRevokeUserError(ErrorVariant):
     class InvalidCertificate:
         pass
 
+    class ClockDrift:
+        pass
+
     class Internal:
         pass
 
@@ -233,6 +246,7 @@ class ClientRevokeUserError(ErrorVariant):
 async def client_revoke_user(
     client: Handle,
     user: UserID,
+    orphaned_workspaces: OrphanedWorkspacesStrategy,
 ) -> Result[None, ClientRevokeUserError]:
     raise NotImplementedError
 
@@ -310,6 +324,7 @@ class WorkspaceUserAccessInfo(Structure):
     human_handle: HumanHandle
     current_profile: UserProfile
     current_role: RealmRole
+    expires_on: DateTime | None
 
 
 class ClientListWorkspaceUsersError(ErrorVariant):
@@ -414,6 +429,9 @@ class ClientRenameWorkspaceError(ErrorVariant):
     class InvalidEncryptedRealmName:
         pass
 
+    class ClockDrift:
+        pass
+
     class Internal:
         pass
 
@@ -466,19 +484,80 @@ class ClientShareWorkspaceError(ErrorVariant):
     class InvalidCertificate:
         pass
 
+    class ClockDrift:
+        pass
+
     class Internal:
         pass
 
 
+# The share expiration is only stored locally: the recipient is unshared once this
+# device is running and online after `expires_on` (see `Client::share_workspace`).
 async def client_share_workspace(
     client: Handle,
     realm_id: VlobID,
     recipient: UserID,
     role: RealmRole | None,
+    expires_on: DateTime | None,
 ) -> Result[None, ClientShareWorkspaceError]:
     raise NotImplementedError
 
 
+class ClientTransferWorkspaceOwnershipError(ErrorVariant):
+    class Stopped:
+        pass
+
+    class NewOwnerIsSelf:
+        pass
+
+    class NewOwnerNotFound:
+        pass
+
+    class NewOwnerRevoked:
+        pass
+
+    class NewOwnerIsOutsider:
+        pass
+
+    class WorkspaceNotFound:
+        pass
+
+    class RealmDeleted:
+        pass
+
+    class AuthorNotAllowed:
+        pass
+
+    class Offline:
+        pass
+
+    class TimestampOutOfBallpark:
+        server_timestamp: DateTime
+        client_timestamp: DateTime
+        ballpark_client_early_offset: float
+        ballpark_client_late_offset: float
+
+    class InvalidKeysBundle:
+        pass
+
+    class InvalidCertificate:
+        pass
+
+    class ClockDrift:
+        pass
+
+    class Internal:
+        pass
+
+
+async def client_transfer_workspace_ownership(
+    client: Handle,
+    realm_id: VlobID,
+    new_owner: UserID,
+) -> Result[None, ClientTransferWorkspaceOwnershipError]:
+    raise NotImplementedError
+
+
 class ClientForgetAllCertificatesError(ErrorVariant):
     class Stopped:
         pass
@@ -521,6 +600,9 @@ class ClientArchiveWorkspaceError(ErrorVariant):
     class InvalidCertificate:
         pass
 
+    class ClockDrift:
+        pass
+
     class Internal:
         pass
 
@@ -541,6 +623,56 @@ async def client_archive_workspace(
     raise NotImplementedError
 
 
+class ClientRekeyWorkspaceError(ErrorVariant):
+    class Stopped:
+        pass
+
+    class Offline:
+        pass
+
+    class UnknownRealm:
+        pass
+
+    class RealmDeleted:
+        pass
+
+    class RealmArchived:
+        pass
+
+    class AuthorNotAllowed:
+        pass
+
+    class ServerBlockstoreUnavailable:
+        pass
+
+    class TimestampOutOfBallpark:
+        server_timestamp: DateTime
+        client_timestamp: DateTime
+        ballpark_client_early_offset: float
+        ballpark_client_late_offset: float
+
+    class InvalidCertificate:
+        pass
+
+    class InvalidKeysBundle:
+        pass
+
+    class ClockDrift:
+        pass
+
+    class Internal:
+        pass
+
+
+# Only the last version of each file and folder is re-encrypted, so this is not
+# a retroactive revocation: the workspace history remains readable with the old keys.
+async def client_rekey_workspace(
+    client: Handle,
+    realm_id: VlobID,
+) -> Result[None, ClientRekeyWorkspaceError]:
+    raise NotImplementedError
+
+
 async def client_forget_all_certificates(
     client: Handle,
 ) -> Result[None, ClientForgetAllCertificatesError]:
@@ -551,10 +683,17 @@ def is_keyring_available() -> bool:
     raise NotImplementedError
 
 
+def get_default_machine_key_file() -> Path:
+    raise NotImplementedError
+
+
 class ImportRecoveryDeviceError(ErrorVariant):
     class NoSpaceAvailable:
         pass
 
+    class ClockDrift:
+        pass
+
     class Internal:
         pass
 
@@ -591,8 +730,14 @@ class ImportRecoveryDeviceError(ErrorVariant):
     class RemoteOpaqueKeyUploadFailed:
         pass
 
+    class Fido2HmacSecretNotSupported:
+        pass
+
 
 class ClientExportRecoveryDeviceError(ErrorVariant):
+    class ClockDrift:
+        pass
+
     class Internal:
         pass
 
@@ -647,6 +792,9 @@ async def client_list_frozen_users(
 
 
 class ClientSetupShamirRecoveryError(ErrorVariant):
+    class ClockDrift:
+        pass
+
     class Internal:
         pass
 
@@ -693,6 +841,9 @@ async def client_setup_shamir_recovery(
 
 
 class ClientDeleteShamirRecoveryError(ErrorVariant):
+    class ClockDrift:
+        pass
+
     class Internal:
         pass
 
@@ -832,12 +983,18 @@ class ClientUserUpdateProfileError(ErrorVariant):
     class UserRevoked:
         pass
 
+    class SoleOwnerOfWorkspaces:
+        workspaces: list[VlobID]
+
     class TimestampOutOfBallpark:
         pass
 
     class InvalidCertificate:
         pass
 
+    class ClockDrift:
+        pass
+
     class Internal:
         pass
 
@@ -846,6 +1003,7 @@ async def client_update_user_profile(
     client_handle: Handle,
     user: UserID,
     new_profile: UserProfile,
+    orphaned_workspaces: OrphanedWorkspacesStrategy,
 ) -> Result[None, ClientUserUpdateProfileError]:
     raise NotImplementedError
 
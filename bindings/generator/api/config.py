@@ -10,6 +10,7 @@ from .common import (
     Ref,
     Result,
     Structure,
+    U32,
     U32BasedType,
     Variant,
     VariantItemUnit,
@@ -46,6 +47,32 @@ class LogLevel(Enum):
     Trace = EnumItemUnit()
 
 
+class X509RevocationPolicy(Enum):
+    SoftFail = EnumItemUnit()
+    HardFail = EnumItemUnit()
+
+
+class OutboundSyncDelays(Structure):
+    quiescence_in_ms: U32
+    max_delay_in_ms: U32
+
+
+class OutboundSyncDelaysOverride(Structure):
+    # Formatted like a `.gitignore` file
+    pattern: str
+    delays: OutboundSyncDelays
+
+
+class OutboundSyncConfig(Structure):
+    delays: OutboundSyncDelays | None
+    overrides: list[OutboundSyncDelaysOverride]
+    block_deduplication: bool
+    # Clients older than Parsec v3.9 cannot read compressed blocks
+    block_compression: bool
+    # Clients older than Parsec v3.9 cannot read files using content-defined chunking
+    content_defined_chunking: bool
+
+
 class ClientConfig(Structure):
     config_dir: Path
     data_base_dir: Path
@@ -54,6 +81,8 @@ class ClientConfig(Structure):
     with_monitors: bool
     prevent_sync_pattern: str | None
     log_level: LogLevel | None
+    pki_revocation_policy: X509RevocationPolicy | None
+    outbound_sync: OutboundSyncConfig | None
 
 
 def get_default_data_base_dir() -> Path:
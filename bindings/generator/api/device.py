@@ -1,5 +1,7 @@
 # Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
 
+from typing import ForwardRef
+
 from .addr import ParsecAddr
 from .common import (
     DateTime,
@@ -22,10 +24,36 @@ from .common import (
     X509CertificateReference,
 )
 from .config import ClientConfig
+from .openbao import OpenBaoAuthMethod
+
+
+class AvailableDeviceMultiProtectionType(Variant):
+    Password = VariantItemUnit
+
+    class PKI:
+        certificate_ref: X509CertificateReference
+
+    AccountVault = VariantItemUnit
+
+    class OpenBao:
+        openbao_preferred_auth_id: str
+        openbao_entity_id: str
+
+    Fido2 = VariantItemUnit
+
+
+class AvailableDeviceMultiProtection(Structure):
+    totp_opaque_key_id: TOTPOpaqueKeyID | None
+    ty: AvailableDeviceMultiProtectionType
 
 
 class AvailableDeviceType(Variant):
     Keyring = VariantItemUnit
+
+    class MachineKey:
+        machine_key_file: Path
+        machine_key_available: bool
+
     Password = VariantItemUnit
     Recovery = VariantItemUnit
 
@@ -38,10 +66,18 @@ class AvailableDeviceType(Variant):
         openbao_preferred_auth_id: str
         openbao_entity_id: str
 
+    Fido2 = VariantItemUnit
+
+    class Multi:
+        protections: list[AvailableDeviceMultiProtection]
+
 
 class DevicePrimaryProtectionStrategy(Variant):
     Keyring = VariantItemUnit()
 
+    class MachineKey:
+        machine_key_file: Path
+
     class Password:
         password: Password
 
@@ -58,6 +94,19 @@ class DevicePrimaryProtectionStrategy(Variant):
         openbao_entity_id: str
         openbao_auth_token: str
         openbao_preferred_auth_id: str
+        openbao_reauthentication_method: OpenBaoAuthMethod | None
+
+    class Fido2:
+        pin: Password | None
+
+    class Multi:
+        # Recursive type (an alternative is itself a primary protection strategy)
+        alternatives: list[ForwardRef("DeviceAlternativeProtection")]
+
+
+class DeviceAlternativeProtection(Structure):
+    totp_protection: tuple[TOTPOpaqueKeyID, SecretKey] | None
+    primary_protection: DevicePrimaryProtectionStrategy
 
 
 class DeviceAccessStrategy(Structure):
@@ -149,6 +198,9 @@ class UpdateDeviceError(ErrorVariant):
     class RemoteOpaqueKeyOperationFailed:
         pass
 
+    class Fido2HmacSecretNotSupported:
+        pass
+
 
 async def update_device_change_authentication(
     config_dir: Ref[Path],
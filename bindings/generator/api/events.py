@@ -11,7 +11,11 @@ from .common import (
     Handle,
     IndexInt,
     InvitationStatus,
+    RealmRole,
+    SequesterServiceID,
     SizeInt,
+    UserID,
+    UserProfile,
     Variant,
     VlobID,
 )
@@ -69,6 +73,56 @@ class ClientEvent(Variant):
         realm_id: VlobID
         entry_id: VlobID
 
+    class UserRevoked:
+        user_id: UserID
+
+    class UserProfileUpdated:
+        user_id: UserID
+        profile: UserProfile
+
+    class DeviceAdded:
+        user_id: UserID
+        device_id: DeviceID
+
+    class DeviceRevoked:
+        user_id: UserID
+        device_id: DeviceID
+
+    class RealmRoleChanged:
+        realm_id: VlobID
+        user_id: UserID
+        old_role: RealmRole | None
+        new_role: RealmRole | None
+
+    class RealmRenamed:
+        realm_id: VlobID
+
+    class RealmArchivingChanged:
+        realm_id: VlobID
+
+    class RealmRekeyProgress:
+        realm_id: VlobID
+        target_key_index: IndexInt
+        processed: IndexInt
+        total: IndexInt
+
+    class RealmRekeyDone:
+        realm_id: VlobID
+        target_key_index: IndexInt
+
+    class SelfShared:
+        realm_id: VlobID
+        role: RealmRole
+
+    class SelfUnshared:
+        realm_id: VlobID
+
+    class SequesterServiceRevoked:
+        service_id: SequesterServiceID
+
+    class ShamirRecoveryChanged:
+        user_id: UserID
+
     class InvitationChanged:
         token: AccessToken
         status: InvitationStatus
@@ -94,6 +148,10 @@ class ClientEvent(Variant):
         ballpark_client_early_offset: float
         ballpark_client_late_offset: float
 
+    class ClockDrift:
+        required_timestamp: DateTime
+        client_timestamp: DateTime
+
     class ExpiredOrganization:
         pass
 
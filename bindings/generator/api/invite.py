@@ -77,6 +77,9 @@ class BootstrapOrganizationError(ErrorVariant):
     class SaveDeviceRemoteOpaqueKeyUploadFailed:
         pass
 
+    class SaveDeviceFido2HmacSecretNotSupported:
+        pass
+
     class SaveDeviceRemoteOpaqueKeyUploadOffline:
         pass
 
@@ -186,6 +189,9 @@ class ClaimFinalizeError(ErrorVariant):
     class RemoteOpaqueKeyUploadFailed:
         pass
 
+    class Fido2HmacSecretNotSupported:
+        pass
+
     class Internal:
         pass
 
@@ -842,6 +848,9 @@ class GreetInProgressError(ErrorVariant):
         ballpark_client_early_offset: float
         ballpark_client_late_offset: float
 
+    class ClockDrift:
+        pass
+
     class Internal:
         pass
 
@@ -1,7 +1,24 @@
 # Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
 
 
-from .common import ErrorVariant, Result
+from .common import ErrorVariant, Result, Variant
+
+
+class OpenBaoAuthMethod(Variant):
+    class AppRole:
+        mount_path: str
+        role_id: str
+        secret_id: str
+
+    class Jwt:
+        mount_path: str
+        role: str
+        jwt: str
+
+    class Userpass:
+        mount_path: str
+        username: str
+        password: str
 
 
 class OpenBaoListSelfEmailsError(ErrorVariant):
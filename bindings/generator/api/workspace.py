@@ -540,6 +540,66 @@ async def workspace_is_file_content_local(
     raise NotImplementedError
 
 
+class WorkspaceSetRemoteOnlyEntriesError(ErrorVariant):
+    class Stopped:
+        pass
+
+    class Internal:
+        pass
+
+
+async def workspace_set_remote_only_entries(
+    workspace: Handle, entries: list[VlobID]
+) -> Result[None, WorkspaceSetRemoteOnlyEntriesError]:
+    raise NotImplementedError
+
+
+class WorkspaceGetRemoteOnlyEntriesError(ErrorVariant):
+    class Internal:
+        pass
+
+
+async def workspace_get_remote_only_entries(
+    workspace: Handle,
+) -> Result[list[VlobID], WorkspaceGetRemoteOnlyEntriesError]:
+    raise NotImplementedError
+
+
+class WorkspaceIsEntryRemoteOnlyError(ErrorVariant):
+    class Offline:
+        pass
+
+    class Stopped:
+        pass
+
+    class EntryNotFound:
+        pass
+
+    class NoRealmAccess:
+        pass
+
+    class RealmDeleted:
+        pass
+
+    class InvalidKeysBundle:
+        pass
+
+    class InvalidCertificate:
+        pass
+
+    class InvalidManifest:
+        pass
+
+    class Internal:
+        pass
+
+
+async def workspace_is_entry_remote_only_by_id(
+    workspace: Handle, entry_id: VlobID
+) -> Result[bool, WorkspaceIsEntryRemoteOnlyError]:
+    raise NotImplementedError
+
+
 class WorkspaceOpenFileError(ErrorVariant):
     class Offline:
         pass
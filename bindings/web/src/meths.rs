@@ -303,6 +303,33 @@ fn enum_log_level_rs_to_js(value: libparsec::LogLevel) -> &'static str {
     }
 }
 
+// OrphanedWorkspacesStrategy
+
+#[allow(dead_code)]
+fn enum_orphaned_workspaces_strategy_js_to_rs(
+    raw_value: &str,
+) -> Result<libparsec::OrphanedWorkspacesStrategy, JsValue> {
+    match raw_value {
+        "OrphanedWorkspacesStrategyAbort" => Ok(libparsec::OrphanedWorkspacesStrategy::Abort),
+        "OrphanedWorkspacesStrategyIgnore" => Ok(libparsec::OrphanedWorkspacesStrategy::Ignore),
+        _ => {
+            let range_error = RangeError::new("Invalid value for enum OrphanedWorkspacesStrategy");
+            range_error.set_cause(&JsValue::from(raw_value));
+            Err(JsValue::from(range_error))
+        }
+    }
+}
+
+#[allow(dead_code)]
+fn enum_orphaned_workspaces_strategy_rs_to_js(
+    value: libparsec::OrphanedWorkspacesStrategy,
+) -> &'static str {
+    match value {
+        libparsec::OrphanedWorkspacesStrategy::Abort => "OrphanedWorkspacesStrategyAbort",
+        libparsec::OrphanedWorkspacesStrategy::Ignore => "OrphanedWorkspacesStrategyIgnore",
+    }
+}
+
 // Platform
 
 #[allow(dead_code)]
@@ -411,6 +438,31 @@ fn enum_user_profile_rs_to_js(value: libparsec::UserProfile) -> &'static str {
     }
 }
 
+// X509RevocationPolicy
+
+#[allow(dead_code)]
+fn enum_x509_revocation_policy_js_to_rs(
+    raw_value: &str,
+) -> Result<libparsec::X509RevocationPolicy, JsValue> {
+    match raw_value {
+        "X509RevocationPolicyHardFail" => Ok(libparsec::X509RevocationPolicy::HardFail),
+        "X509RevocationPolicySoftFail" => Ok(libparsec::X509RevocationPolicy::SoftFail),
+        _ => {
+            let range_error = RangeError::new("Invalid value for enum X509RevocationPolicy");
+            range_error.set_cause(&JsValue::from(raw_value));
+            Err(JsValue::from(range_error))
+        }
+    }
+}
+
+#[allow(dead_code)]
+fn enum_x509_revocation_policy_rs_to_js(value: libparsec::X509RevocationPolicy) -> &'static str {
+    match value {
+        libparsec::X509RevocationPolicy::HardFail => "X509RevocationPolicyHardFail",
+        libparsec::X509RevocationPolicy::SoftFail => "X509RevocationPolicySoftFail",
+    }
+}
+
 // AccountInfo
 
 #[allow(dead_code)]
@@ -1343,6 +1395,67 @@ fn struct_available_device_rs_to_js(
     Ok(js_obj)
 }
 
+// AvailableDeviceMultiProtection
+
+#[allow(dead_code)]
+fn struct_available_device_multi_protection_js_to_rs(
+    obj: JsValue,
+) -> Result<libparsec::AvailableDeviceMultiProtection, JsValue> {
+    let totp_opaque_key_id = {
+        let js_val = Reflect::get(&obj, &"totpOpaqueKeyId".into())?;
+        if js_val.is_null() {
+            None
+        } else {
+            Some(
+                js_val
+                    .dyn_into::<JsString>()
+                    .ok()
+                    .and_then(|s| s.as_string())
+                    .ok_or_else(|| TypeError::new("Not a string"))
+                    .and_then(|x| {
+                        let custom_from_rs_string =
+                            |s: String| -> Result<libparsec::TOTPOpaqueKeyID, _> {
+                                libparsec::TOTPOpaqueKeyID::from_hex(s.as_str())
+                                    .map_err(|e| e.to_string())
+                            };
+                        custom_from_rs_string(x).map_err(|e| TypeError::new(e.as_ref()))
+                    })?,
+            )
+        }
+    };
+    let ty = {
+        let js_val = Reflect::get(&obj, &"ty".into())?;
+        variant_available_device_multi_protection_type_js_to_rs(js_val)?
+    };
+    Ok(libparsec::AvailableDeviceMultiProtection {
+        totp_opaque_key_id,
+        ty,
+    })
+}
+
+#[allow(dead_code)]
+fn struct_available_device_multi_protection_rs_to_js(
+    rs_obj: libparsec::AvailableDeviceMultiProtection,
+) -> Result<JsValue, JsValue> {
+    let js_obj = Object::new().into();
+    let js_totp_opaque_key_id = match rs_obj.totp_opaque_key_id {
+        Some(val) => JsValue::from_str({
+            let custom_to_rs_string =
+                |x: libparsec::TOTPOpaqueKeyID| -> Result<String, &'static str> { Ok(x.hex()) };
+            match custom_to_rs_string(val) {
+                Ok(ok) => ok,
+                Err(err) => return Err(JsValue::from(TypeError::new(&err.to_string()))),
+            }
+            .as_ref()
+        }),
+        None => JsValue::NULL,
+    };
+    Reflect::set(&js_obj, &"totpOpaqueKeyId".into(), &js_totp_opaque_key_id)?;
+    let js_ty = variant_available_device_multi_protection_type_rs_to_js(rs_obj.ty)?;
+    Reflect::set(&js_obj, &"ty".into(), &js_ty)?;
+    Ok(js_obj)
+}
+
 // AvailablePendingAsyncEnrollment
 
 #[allow(dead_code)]
@@ -1579,6 +1692,29 @@ fn struct_client_config_js_to_rs(obj: JsValue) -> Result<libparsec::ClientConfig
             }?)
         }
     };
+    let pki_revocation_policy = {
+        let js_val = Reflect::get(&obj, &"pkiRevocationPolicy".into())?;
+        if js_val.is_null() {
+            None
+        } else {
+            Some({
+                let raw_string = js_val.as_string().ok_or_else(|| {
+                    let type_error = TypeError::new("value is not a string");
+                    type_error.set_cause(&js_val);
+                    JsValue::from(type_error)
+                })?;
+                enum_x509_revocation_policy_js_to_rs(raw_string.as_str())
+            }?)
+        }
+    };
+    let outbound_sync = {
+        let js_val = Reflect::get(&obj, &"outboundSync".into())?;
+        if js_val.is_null() {
+            None
+        } else {
+            Some(struct_outbound_sync_config_js_to_rs(js_val)?)
+        }
+    };
     Ok(libparsec::ClientConfig {
         config_dir,
         data_base_dir,
@@ -1587,6 +1723,8 @@ fn struct_client_config_js_to_rs(obj: JsValue) -> Result<libparsec::ClientConfig
         with_monitors,
         prevent_sync_pattern,
         log_level,
+        pki_revocation_policy,
+        outbound_sync,
     })
 }
 
@@ -1649,6 +1787,20 @@ fn struct_client_config_rs_to_js(rs_obj: libparsec::ClientConfig) -> Result<JsVa
         None => JsValue::NULL,
     };
     Reflect::set(&js_obj, &"logLevel".into(), &js_log_level)?;
+    let js_pki_revocation_policy = match rs_obj.pki_revocation_policy {
+        Some(val) => JsValue::from_str(enum_x509_revocation_policy_rs_to_js(val)),
+        None => JsValue::NULL,
+    };
+    Reflect::set(
+        &js_obj,
+        &"pkiRevocationPolicy".into(),
+        &js_pki_revocation_policy,
+    )?;
+    let js_outbound_sync = match rs_obj.outbound_sync {
+        Some(val) => struct_outbound_sync_config_rs_to_js(val)?,
+        None => JsValue::NULL,
+    };
+    Reflect::set(&js_obj, &"outboundSync".into(), &js_outbound_sync)?;
     Ok(js_obj)
 }
 
@@ -1980,6 +2132,92 @@ fn struct_device_access_strategy_rs_to_js(
     Ok(js_obj)
 }
 
+// DeviceAlternativeProtection
+
+#[allow(dead_code)]
+fn struct_device_alternative_protection_js_to_rs(
+    obj: JsValue,
+) -> Result<libparsec::DeviceAlternativeProtection, JsValue> {
+    let totp_protection = {
+        let js_val = Reflect::get(&obj, &"totpProtection".into())?;
+        if js_val.is_null() {
+            None
+        } else {
+            Some((
+                {
+                    let js_x1 = Reflect::get_u32(&js_val, 0)?;
+                    js_x1
+                        .dyn_into::<JsString>()
+                        .ok()
+                        .and_then(|s| s.as_string())
+                        .ok_or_else(|| TypeError::new("Not a string"))
+                        .and_then(|x| {
+                            let custom_from_rs_string =
+                                |s: String| -> Result<libparsec::TOTPOpaqueKeyID, _> {
+                                    libparsec::TOTPOpaqueKeyID::from_hex(s.as_str())
+                                        .map_err(|e| e.to_string())
+                                };
+                            custom_from_rs_string(x).map_err(|e| TypeError::new(e.as_ref()))
+                        })?
+                },
+                {
+                    let js_x2 = Reflect::get_u32(&js_val, 1)?;
+                    js_x2
+                        .dyn_into::<Uint8Array>()
+                        .map(|x| x.to_vec())
+                        .map_err(|_| TypeError::new("Not a Uint8Array"))
+                        .and_then(|x| {
+                            let xx: &[u8] = &x;
+                            xx.try_into()
+                                .map_err(|_| TypeError::new("Not a valid SecretKey"))
+                        })?
+                },
+            ))
+        }
+    };
+    let primary_protection = {
+        let js_val = Reflect::get(&obj, &"primaryProtection".into())?;
+        variant_device_primary_protection_strategy_js_to_rs(js_val)?
+    };
+    Ok(libparsec::DeviceAlternativeProtection {
+        totp_protection,
+        primary_protection,
+    })
+}
+
+#[allow(dead_code)]
+fn struct_device_alternative_protection_rs_to_js(
+    rs_obj: libparsec::DeviceAlternativeProtection,
+) -> Result<JsValue, JsValue> {
+    let js_obj = Object::new().into();
+    let js_totp_protection = match rs_obj.totp_protection {
+        Some(val) => {
+            let (x1, x2) = val;
+            // Array::new_with_length allocates with `undefined` value, that's why we `set` value
+            let js_array = Array::new_with_length(2);
+            let js_value = JsValue::from_str({
+                let custom_to_rs_string =
+                    |x: libparsec::TOTPOpaqueKeyID| -> Result<String, &'static str> { Ok(x.hex()) };
+                match custom_to_rs_string(x1) {
+                    Ok(ok) => ok,
+                    Err(err) => return Err(JsValue::from(TypeError::new(&err.to_string()))),
+                }
+                .as_ref()
+            });
+            js_array.set(0, js_value);
+            let js_value = JsValue::from(Uint8Array::from(x2.as_ref()));
+            js_array.set(1, js_value);
+            js_array.into()
+        }
+        None => JsValue::NULL,
+    };
+    Reflect::set(&js_obj, &"totpProtection".into(), &js_totp_protection)?;
+    let js_primary_protection =
+        variant_device_primary_protection_strategy_rs_to_js(rs_obj.primary_protection)?;
+    Reflect::set(&js_obj, &"primaryProtection".into(), &js_primary_protection)?;
+    Ok(js_obj)
+}
+
 // DeviceClaimFinalizeInfo
 
 #[allow(dead_code)]
@@ -3200,78 +3438,258 @@ fn struct_organization_info_rs_to_js(
     Ok(js_obj)
 }
 
-// ServerConfig
+// OutboundSyncConfig
 
 #[allow(dead_code)]
-fn struct_server_config_js_to_rs(obj: JsValue) -> Result<libparsec::ServerConfig, JsValue> {
-    let account = {
-        let js_val = Reflect::get(&obj, &"account".into())?;
-        variant_account_config_js_to_rs(js_val)?
-    };
-    let cryptpad = {
-        let js_val = Reflect::get(&obj, &"cryptpad".into())?;
-        if js_val.is_null() {
-            None
-        } else {
-            Some(struct_crypt_pad_config_js_to_rs(js_val)?)
-        }
-    };
-    let organization_bootstrap = {
-        let js_val = Reflect::get(&obj, &"organizationBootstrap".into())?;
-        variant_organization_bootstrap_config_js_to_rs(js_val)?
-    };
-    let openbao = {
-        let js_val = Reflect::get(&obj, &"openbao".into())?;
+fn struct_outbound_sync_config_js_to_rs(
+    obj: JsValue,
+) -> Result<libparsec::OutboundSyncConfig, JsValue> {
+    let delays = {
+        let js_val = Reflect::get(&obj, &"delays".into())?;
         if js_val.is_null() {
             None
         } else {
-            Some(struct_open_bao_config_js_to_rs(js_val)?)
+            Some(struct_outbound_sync_delays_js_to_rs(js_val)?)
         }
     };
-    let advisory_device_file_protection = {
-        let js_val = Reflect::get(&obj, &"advisoryDeviceFileProtection".into())?;
+    let overrides = {
+        let js_val = Reflect::get(&obj, &"overrides".into())?;
         {
             let js_val = js_val
                 .dyn_into::<Array>()
                 .map_err(|_| TypeError::new("Not an array"))?;
             let mut converted = Vec::with_capacity(js_val.length() as usize);
             for x in js_val.iter() {
-                let x_converted = struct_advisory_device_file_protection_js_to_rs(x)?;
+                let x_converted = struct_outbound_sync_delays_override_js_to_rs(x)?;
                 converted.push(x_converted);
             }
             converted
         }
     };
-    Ok(libparsec::ServerConfig {
-        account,
-        cryptpad,
-        organization_bootstrap,
-        openbao,
-        advisory_device_file_protection,
+    let block_deduplication = {
+        let js_val = Reflect::get(&obj, &"blockDeduplication".into())?;
+        js_val
+            .dyn_into::<Boolean>()
+            .map_err(|_| TypeError::new("Not a boolean"))?
+            .value_of()
+    };
+    let block_compression = {
+        let js_val = Reflect::get(&obj, &"blockCompression".into())?;
+        js_val
+            .dyn_into::<Boolean>()
+            .map_err(|_| TypeError::new("Not a boolean"))?
+            .value_of()
+    };
+    let content_defined_chunking = {
+        let js_val = Reflect::get(&obj, &"contentDefinedChunking".into())?;
+        js_val
+            .dyn_into::<Boolean>()
+            .map_err(|_| TypeError::new("Not a boolean"))?
+            .value_of()
+    };
+    Ok(libparsec::OutboundSyncConfig {
+        delays,
+        overrides,
+        block_deduplication,
+        block_compression,
+        content_defined_chunking,
     })
 }
 
 #[allow(dead_code)]
-fn struct_server_config_rs_to_js(rs_obj: libparsec::ServerConfig) -> Result<JsValue, JsValue> {
+fn struct_outbound_sync_config_rs_to_js(
+    rs_obj: libparsec::OutboundSyncConfig,
+) -> Result<JsValue, JsValue> {
     let js_obj = Object::new().into();
-    let js_account = variant_account_config_rs_to_js(rs_obj.account)?;
-    Reflect::set(&js_obj, &"account".into(), &js_account)?;
-    let js_cryptpad = match rs_obj.cryptpad {
-        Some(val) => struct_crypt_pad_config_rs_to_js(val)?,
+    let js_delays = match rs_obj.delays {
+        Some(val) => struct_outbound_sync_delays_rs_to_js(val)?,
         None => JsValue::NULL,
     };
-    Reflect::set(&js_obj, &"cryptpad".into(), &js_cryptpad)?;
-    let js_organization_bootstrap =
-        variant_organization_bootstrap_config_rs_to_js(rs_obj.organization_bootstrap)?;
+    Reflect::set(&js_obj, &"delays".into(), &js_delays)?;
+    let js_overrides = {
+        // Array::new_with_length allocates with `undefined` value, that's why we `set` value
+        let js_array = Array::new_with_length(rs_obj.overrides.len() as u32);
+        for (i, elem) in rs_obj.overrides.into_iter().enumerate() {
+            let js_elem = struct_outbound_sync_delays_override_rs_to_js(elem)?;
+            js_array.set(i as u32, js_elem);
+        }
+        js_array.into()
+    };
+    Reflect::set(&js_obj, &"overrides".into(), &js_overrides)?;
+    let js_block_deduplication = rs_obj.block_deduplication.into();
     Reflect::set(
         &js_obj,
-        &"organizationBootstrap".into(),
-        &js_organization_bootstrap,
+        &"blockDeduplication".into(),
+        &js_block_deduplication,
     )?;
-    let js_openbao = match rs_obj.openbao {
-        Some(val) => struct_open_bao_config_rs_to_js(val)?,
-        None => JsValue::NULL,
-    };
+    let js_block_compression = rs_obj.block_compression.into();
+    Reflect::set(&js_obj, &"blockCompression".into(), &js_block_compression)?;
+    let js_content_defined_chunking = rs_obj.content_defined_chunking.into();
+    Reflect::set(
+        &js_obj,
+        &"contentDefinedChunking".into(),
+        &js_content_defined_chunking,
+    )?;
+    Ok(js_obj)
+}
+
+// OutboundSyncDelays
+
+#[allow(dead_code)]
+fn struct_outbound_sync_delays_js_to_rs(
+    obj: JsValue,
+) -> Result<libparsec::OutboundSyncDelays, JsValue> {
+    let quiescence_in_ms = {
+        let js_val = Reflect::get(&obj, &"quiescenceInMs".into())?;
+        {
+            let v = js_val
+                .dyn_into::<Number>()
+                .map_err(|_| TypeError::new("Not a number"))?
+                .value_of();
+            if v < (u32::MIN as f64) || (u32::MAX as f64) < v {
+                return Err(JsValue::from(TypeError::new("Not an u32 number")));
+            }
+            let v = v as u32;
+            v
+        }
+    };
+    let max_delay_in_ms = {
+        let js_val = Reflect::get(&obj, &"maxDelayInMs".into())?;
+        {
+            let v = js_val
+                .dyn_into::<Number>()
+                .map_err(|_| TypeError::new("Not a number"))?
+                .value_of();
+            if v < (u32::MIN as f64) || (u32::MAX as f64) < v {
+                return Err(JsValue::from(TypeError::new("Not an u32 number")));
+            }
+            let v = v as u32;
+            v
+        }
+    };
+    Ok(libparsec::OutboundSyncDelays {
+        quiescence_in_ms,
+        max_delay_in_ms,
+    })
+}
+
+#[allow(dead_code)]
+fn struct_outbound_sync_delays_rs_to_js(
+    rs_obj: libparsec::OutboundSyncDelays,
+) -> Result<JsValue, JsValue> {
+    let js_obj = Object::new().into();
+    let js_quiescence_in_ms = JsValue::from(rs_obj.quiescence_in_ms);
+    Reflect::set(&js_obj, &"quiescenceInMs".into(), &js_quiescence_in_ms)?;
+    let js_max_delay_in_ms = JsValue::from(rs_obj.max_delay_in_ms);
+    Reflect::set(&js_obj, &"maxDelayInMs".into(), &js_max_delay_in_ms)?;
+    Ok(js_obj)
+}
+
+// OutboundSyncDelaysOverride
+
+#[allow(dead_code)]
+fn struct_outbound_sync_delays_override_js_to_rs(
+    obj: JsValue,
+) -> Result<libparsec::OutboundSyncDelaysOverride, JsValue> {
+    let pattern = {
+        let js_val = Reflect::get(&obj, &"pattern".into())?;
+        js_val
+            .dyn_into::<JsString>()
+            .ok()
+            .and_then(|s| s.as_string())
+            .ok_or_else(|| TypeError::new("Not a string"))?
+    };
+    let delays = {
+        let js_val = Reflect::get(&obj, &"delays".into())?;
+        struct_outbound_sync_delays_js_to_rs(js_val)?
+    };
+    Ok(libparsec::OutboundSyncDelaysOverride { pattern, delays })
+}
+
+#[allow(dead_code)]
+fn struct_outbound_sync_delays_override_rs_to_js(
+    rs_obj: libparsec::OutboundSyncDelaysOverride,
+) -> Result<JsValue, JsValue> {
+    let js_obj = Object::new().into();
+    let js_pattern = JsValue::from_str(rs_obj.pattern.as_ref());
+    Reflect::set(&js_obj, &"pattern".into(), &js_pattern)?;
+    let js_delays = struct_outbound_sync_delays_rs_to_js(rs_obj.delays)?;
+    Reflect::set(&js_obj, &"delays".into(), &js_delays)?;
+    Ok(js_obj)
+}
+
+// ServerConfig
+
+#[allow(dead_code)]
+fn struct_server_config_js_to_rs(obj: JsValue) -> Result<libparsec::ServerConfig, JsValue> {
+    let account = {
+        let js_val = Reflect::get(&obj, &"account".into())?;
+        variant_account_config_js_to_rs(js_val)?
+    };
+    let cryptpad = {
+        let js_val = Reflect::get(&obj, &"cryptpad".into())?;
+        if js_val.is_null() {
+            None
+        } else {
+            Some(struct_crypt_pad_config_js_to_rs(js_val)?)
+        }
+    };
+    let organization_bootstrap = {
+        let js_val = Reflect::get(&obj, &"organizationBootstrap".into())?;
+        variant_organization_bootstrap_config_js_to_rs(js_val)?
+    };
+    let openbao = {
+        let js_val = Reflect::get(&obj, &"openbao".into())?;
+        if js_val.is_null() {
+            None
+        } else {
+            Some(struct_open_bao_config_js_to_rs(js_val)?)
+        }
+    };
+    let advisory_device_file_protection = {
+        let js_val = Reflect::get(&obj, &"advisoryDeviceFileProtection".into())?;
+        {
+            let js_val = js_val
+                .dyn_into::<Array>()
+                .map_err(|_| TypeError::new("Not an array"))?;
+            let mut converted = Vec::with_capacity(js_val.length() as usize);
+            for x in js_val.iter() {
+                let x_converted = struct_advisory_device_file_protection_js_to_rs(x)?;
+                converted.push(x_converted);
+            }
+            converted
+        }
+    };
+    Ok(libparsec::ServerConfig {
+        account,
+        cryptpad,
+        organization_bootstrap,
+        openbao,
+        advisory_device_file_protection,
+    })
+}
+
+#[allow(dead_code)]
+fn struct_server_config_rs_to_js(rs_obj: libparsec::ServerConfig) -> Result<JsValue, JsValue> {
+    let js_obj = Object::new().into();
+    let js_account = variant_account_config_rs_to_js(rs_obj.account)?;
+    Reflect::set(&js_obj, &"account".into(), &js_account)?;
+    let js_cryptpad = match rs_obj.cryptpad {
+        Some(val) => struct_crypt_pad_config_rs_to_js(val)?,
+        None => JsValue::NULL,
+    };
+    Reflect::set(&js_obj, &"cryptpad".into(), &js_cryptpad)?;
+    let js_organization_bootstrap =
+        variant_organization_bootstrap_config_rs_to_js(rs_obj.organization_bootstrap)?;
+    Reflect::set(
+        &js_obj,
+        &"organizationBootstrap".into(),
+        &js_organization_bootstrap,
+    )?;
+    let js_openbao = match rs_obj.openbao {
+        Some(val) => struct_open_bao_config_rs_to_js(val)?,
+        None => JsValue::NULL,
+    };
     Reflect::set(&js_obj, &"openbao".into(), &js_openbao)?;
     let js_advisory_device_file_protection = {
         // Array::new_with_length allocates with `undefined` value, that's why we `set` value
@@ -3319,10 +3737,34 @@ fn struct_server_organization_config_js_to_rs(
             v
         }
     };
+    let pki_crls = {
+        let js_val = Reflect::get(&obj, &"pkiCrls".into())?;
+        {
+            let js_val = js_val
+                .dyn_into::<Array>()
+                .map_err(|_| TypeError::new("Not an array"))?;
+            let mut converted = Vec::with_capacity(js_val.length() as usize);
+            for x in js_val.iter() {
+                let x_converted = x
+                    .dyn_into::<Uint8Array>()
+                    .map(|x| x.to_vec())
+                    .map_err(|_| TypeError::new("Not a Uint8Array"))
+                    .and_then(|x| {
+                        let custom_from_rs_bytes = |v: &[u8]| -> Result<libparsec::Bytes, String> {
+                            Ok(v.to_vec().into())
+                        };
+                        custom_from_rs_bytes(&x).map_err(|e| TypeError::new(e.as_ref()))
+                    })?;
+                converted.push(x_converted);
+            }
+            converted
+        }
+    };
     Ok(libparsec::ServerOrganizationConfig {
         user_profile_outsider_allowed,
         active_users_limit,
         realm_minimum_archiving_period_before_deletion,
+        pki_crls,
     })
 }
 
@@ -3346,6 +3788,16 @@ fn struct_server_organization_config_rs_to_js(
         &"realmMinimumArchivingPeriodBeforeDeletion".into(),
         &js_realm_minimum_archiving_period_before_deletion,
     )?;
+    let js_pki_crls = {
+        // Array::new_with_length allocates with `undefined` value, that's why we `set` value
+        let js_array = Array::new_with_length(rs_obj.pki_crls.len() as u32);
+        for (i, elem) in rs_obj.pki_crls.into_iter().enumerate() {
+            let js_elem = JsValue::from(Uint8Array::from(elem.as_ref()));
+            js_array.set(i as u32, js_elem);
+        }
+        js_array.into()
+    };
+    Reflect::set(&js_obj, &"pkiCrls".into(), &js_pki_crls)?;
     Ok(js_obj)
 }
 
@@ -5709,11 +6161,28 @@ fn struct_workspace_user_access_info_js_to_rs(
             enum_realm_role_js_to_rs(raw_string.as_str())
         }?
     };
+    let expires_on = {
+        let js_val = Reflect::get(&obj, &"expiresOn".into())?;
+        if js_val.is_null() {
+            None
+        } else {
+            Some({
+                let v = js_val.dyn_into::<Number>()?.value_of();
+                let custom_from_rs_f64 = |n: f64| -> Result<_, &'static str> {
+                    libparsec::DateTime::from_timestamp_micros((n * 1_000_000f64) as i64)
+                        .map_err(|_| "Out-of-bound datetime")
+                };
+                let v = custom_from_rs_f64(v).map_err(|e| TypeError::new(e.as_ref()))?;
+                v
+            })
+        }
+    };
     Ok(libparsec::WorkspaceUserAccessInfo {
         user_id,
         human_handle,
         current_profile,
         current_role,
+        expires_on,
     })
 }
 
@@ -5738,6 +6207,20 @@ fn struct_workspace_user_access_info_rs_to_js(
     Reflect::set(&js_obj, &"currentProfile".into(), &js_current_profile)?;
     let js_current_role = JsValue::from_str(enum_realm_role_rs_to_js(rs_obj.current_role));
     Reflect::set(&js_obj, &"currentRole".into(), &js_current_role)?;
+    let js_expires_on = match rs_obj.expires_on {
+        Some(val) => {
+            let custom_to_rs_f64 = |dt: libparsec::DateTime| -> Result<f64, &'static str> {
+                Ok((dt.as_timestamp_micros() as f64) / 1_000_000f64)
+            };
+            let v = match custom_to_rs_f64(val) {
+                Ok(ok) => ok,
+                Err(err) => return Err(JsValue::from(TypeError::new(err.as_ref()))),
+            };
+            JsValue::from(v)
+        }
+        None => JsValue::NULL,
+    };
+    Reflect::set(&js_obj, &"expiresOn".into(), &js_expires_on)?;
     Ok(js_obj)
 }
 
@@ -6231,6 +6714,13 @@ fn variant_account_create_registration_device_error_rs_to_js(
                 &"AccountCreateRegistrationDeviceErrorBadVaultKeyAccess".into(),
             )?;
         }
+        libparsec::AccountCreateRegistrationDeviceError::ClockDrift { .. } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"AccountCreateRegistrationDeviceErrorClockDrift".into(),
+            )?;
+        }
         libparsec::AccountCreateRegistrationDeviceError::Internal { .. } => {
             Reflect::set(
                 &js_obj,
@@ -6922,6 +7412,13 @@ fn variant_account_register_new_device_error_rs_to_js(
                 &"AccountRegisterNewDeviceErrorBadVaultKeyAccess".into(),
             )?;
         }
+        libparsec::AccountRegisterNewDeviceError::ClockDrift { .. } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"AccountRegisterNewDeviceErrorClockDrift".into(),
+            )?;
+        }
         libparsec::AccountRegisterNewDeviceError::CorruptedRegistrationDevice { .. } => {
             Reflect::set(
                 &js_obj,
@@ -6929,6 +7426,13 @@ fn variant_account_register_new_device_error_rs_to_js(
                 &"AccountRegisterNewDeviceErrorCorruptedRegistrationDevice".into(),
             )?;
         }
+        libparsec::AccountRegisterNewDeviceError::Fido2HmacSecretNotSupported { .. } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"AccountRegisterNewDeviceErrorFido2HmacSecretNotSupported".into(),
+            )?;
+        }
         libparsec::AccountRegisterNewDeviceError::Internal { .. } => {
             Reflect::set(
                 &js_obj,
@@ -7542,20 +8046,24 @@ fn variant_async_enrollment_identity_system_rs_to_js(
     Ok(js_obj)
 }
 
-// AvailableDeviceType
+// AvailableDeviceMultiProtectionType
 
 #[allow(dead_code)]
-fn variant_available_device_type_js_to_rs(
+fn variant_available_device_multi_protection_type_js_to_rs(
     obj: JsValue,
-) -> Result<libparsec::AvailableDeviceType, JsValue> {
+) -> Result<libparsec::AvailableDeviceMultiProtectionType, JsValue> {
     let tag = Reflect::get(&obj, &"tag".into())?;
     let tag = tag
         .as_string()
         .ok_or_else(|| JsValue::from(TypeError::new("tag isn't a string")))?;
     match tag.as_str() {
-        "AvailableDeviceTypeAccountVault" => Ok(libparsec::AvailableDeviceType::AccountVault {}),
-        "AvailableDeviceTypeKeyring" => Ok(libparsec::AvailableDeviceType::Keyring {}),
-        "AvailableDeviceTypeOpenBao" => {
+        "AvailableDeviceMultiProtectionTypeAccountVault" => {
+            Ok(libparsec::AvailableDeviceMultiProtectionType::AccountVault {})
+        }
+        "AvailableDeviceMultiProtectionTypeFido2" => {
+            Ok(libparsec::AvailableDeviceMultiProtectionType::Fido2 {})
+        }
+        "AvailableDeviceMultiProtectionTypeOpenBao" => {
             let openbao_preferred_auth_id = {
                 let js_val = Reflect::get(&obj, &"openbaoPreferredAuthId".into())?;
                 js_val
@@ -7572,48 +8080,57 @@ fn variant_available_device_type_js_to_rs(
                     .and_then(|s| s.as_string())
                     .ok_or_else(|| TypeError::new("Not a string"))?
             };
-            Ok(libparsec::AvailableDeviceType::OpenBao {
+            Ok(libparsec::AvailableDeviceMultiProtectionType::OpenBao {
                 openbao_preferred_auth_id,
                 openbao_entity_id,
             })
         }
-        "AvailableDeviceTypePKI" => {
+        "AvailableDeviceMultiProtectionTypePKI" => {
             let certificate_ref = {
                 let js_val = Reflect::get(&obj, &"certificateRef".into())?;
                 struct_x509_certificate_reference_js_to_rs(js_val)?
             };
-            Ok(libparsec::AvailableDeviceType::PKI { certificate_ref })
+            Ok(libparsec::AvailableDeviceMultiProtectionType::PKI { certificate_ref })
+        }
+        "AvailableDeviceMultiProtectionTypePassword" => {
+            Ok(libparsec::AvailableDeviceMultiProtectionType::Password {})
         }
-        "AvailableDeviceTypePassword" => Ok(libparsec::AvailableDeviceType::Password {}),
-        "AvailableDeviceTypeRecovery" => Ok(libparsec::AvailableDeviceType::Recovery {}),
         _ => Err(JsValue::from(TypeError::new(
-            "Object is not a AvailableDeviceType",
+            "Object is not a AvailableDeviceMultiProtectionType",
         ))),
     }
 }
 
 #[allow(dead_code)]
-fn variant_available_device_type_rs_to_js(
-    rs_obj: libparsec::AvailableDeviceType,
+fn variant_available_device_multi_protection_type_rs_to_js(
+    rs_obj: libparsec::AvailableDeviceMultiProtectionType,
 ) -> Result<JsValue, JsValue> {
     let js_obj = Object::new().into();
     match rs_obj {
-        libparsec::AvailableDeviceType::AccountVault { .. } => {
+        libparsec::AvailableDeviceMultiProtectionType::AccountVault { .. } => {
             Reflect::set(
                 &js_obj,
                 &"tag".into(),
-                &"AvailableDeviceTypeAccountVault".into(),
+                &"AvailableDeviceMultiProtectionTypeAccountVault".into(),
             )?;
         }
-        libparsec::AvailableDeviceType::Keyring { .. } => {
-            Reflect::set(&js_obj, &"tag".into(), &"AvailableDeviceTypeKeyring".into())?;
+        libparsec::AvailableDeviceMultiProtectionType::Fido2 { .. } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"AvailableDeviceMultiProtectionTypeFido2".into(),
+            )?;
         }
-        libparsec::AvailableDeviceType::OpenBao {
+        libparsec::AvailableDeviceMultiProtectionType::OpenBao {
             openbao_preferred_auth_id,
             openbao_entity_id,
             ..
         } => {
-            Reflect::set(&js_obj, &"tag".into(), &"AvailableDeviceTypeOpenBao".into())?;
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"AvailableDeviceMultiProtectionTypeOpenBao".into(),
+            )?;
             let js_openbao_preferred_auth_id =
                 JsValue::from_str(openbao_preferred_auth_id.as_ref());
             Reflect::set(
@@ -7624,56 +8141,251 @@ fn variant_available_device_type_rs_to_js(
             let js_openbao_entity_id = JsValue::from_str(openbao_entity_id.as_ref());
             Reflect::set(&js_obj, &"openbaoEntityId".into(), &js_openbao_entity_id)?;
         }
-        libparsec::AvailableDeviceType::PKI {
+        libparsec::AvailableDeviceMultiProtectionType::PKI {
             certificate_ref, ..
         } => {
-            Reflect::set(&js_obj, &"tag".into(), &"AvailableDeviceTypePKI".into())?;
-            let js_certificate_ref = struct_x509_certificate_reference_rs_to_js(certificate_ref)?;
-            Reflect::set(&js_obj, &"certificateRef".into(), &js_certificate_ref)?;
-        }
-        libparsec::AvailableDeviceType::Password { .. } => {
             Reflect::set(
                 &js_obj,
                 &"tag".into(),
-                &"AvailableDeviceTypePassword".into(),
+                &"AvailableDeviceMultiProtectionTypePKI".into(),
             )?;
+            let js_certificate_ref = struct_x509_certificate_reference_rs_to_js(certificate_ref)?;
+            Reflect::set(&js_obj, &"certificateRef".into(), &js_certificate_ref)?;
         }
-        libparsec::AvailableDeviceType::Recovery { .. } => {
+        libparsec::AvailableDeviceMultiProtectionType::Password { .. } => {
             Reflect::set(
                 &js_obj,
                 &"tag".into(),
-                &"AvailableDeviceTypeRecovery".into(),
+                &"AvailableDeviceMultiProtectionTypePassword".into(),
             )?;
         }
     }
     Ok(js_obj)
 }
 
-// AvailablePendingAsyncEnrollmentIdentitySystem
+// AvailableDeviceType
 
 #[allow(dead_code)]
-fn variant_available_pending_async_enrollment_identity_system_js_to_rs(
+fn variant_available_device_type_js_to_rs(
     obj: JsValue,
-) -> Result<libparsec::AvailablePendingAsyncEnrollmentIdentitySystem, JsValue> {
+) -> Result<libparsec::AvailableDeviceType, JsValue> {
     let tag = Reflect::get(&obj, &"tag".into())?;
     let tag = tag
         .as_string()
         .ok_or_else(|| JsValue::from(TypeError::new("tag isn't a string")))?;
     match tag.as_str() {
-        "AvailablePendingAsyncEnrollmentIdentitySystemOpenBao" => {
-            let openbao_entity_id = {
-                let js_val = Reflect::get(&obj, &"openbaoEntityId".into())?;
+        "AvailableDeviceTypeAccountVault" => Ok(libparsec::AvailableDeviceType::AccountVault {}),
+        "AvailableDeviceTypeFido2" => Ok(libparsec::AvailableDeviceType::Fido2 {}),
+        "AvailableDeviceTypeKeyring" => Ok(libparsec::AvailableDeviceType::Keyring {}),
+        "AvailableDeviceTypeMachineKey" => {
+            let machine_key_file = {
+                let js_val = Reflect::get(&obj, &"machineKeyFile".into())?;
                 js_val
                     .dyn_into::<JsString>()
                     .ok()
                     .and_then(|s| s.as_string())
-                    .ok_or_else(|| TypeError::new("Not a string"))?
-            };
-            let openbao_preferred_auth_id = {
-                let js_val = Reflect::get(&obj, &"openbaoPreferredAuthId".into())?;
-                js_val
-                    .dyn_into::<JsString>()
-                    .ok()
+                    .ok_or_else(|| TypeError::new("Not a string"))
+                    .and_then(|x| {
+                        let custom_from_rs_string = |s: String| -> Result<_, &'static str> {
+                            Ok(std::path::PathBuf::from(s))
+                        };
+                        custom_from_rs_string(x).map_err(|e| TypeError::new(e.as_ref()))
+                    })?
+            };
+            let machine_key_available = {
+                let js_val = Reflect::get(&obj, &"machineKeyAvailable".into())?;
+                js_val
+                    .dyn_into::<Boolean>()
+                    .map_err(|_| TypeError::new("Not a boolean"))?
+                    .value_of()
+            };
+            Ok(libparsec::AvailableDeviceType::MachineKey {
+                machine_key_file,
+                machine_key_available,
+            })
+        }
+        "AvailableDeviceTypeMulti" => {
+            let protections = {
+                let js_val = Reflect::get(&obj, &"protections".into())?;
+                {
+                    let js_val = js_val
+                        .dyn_into::<Array>()
+                        .map_err(|_| TypeError::new("Not an array"))?;
+                    let mut converted = Vec::with_capacity(js_val.length() as usize);
+                    for x in js_val.iter() {
+                        let x_converted = struct_available_device_multi_protection_js_to_rs(x)?;
+                        converted.push(x_converted);
+                    }
+                    converted
+                }
+            };
+            Ok(libparsec::AvailableDeviceType::Multi { protections })
+        }
+        "AvailableDeviceTypeOpenBao" => {
+            let openbao_preferred_auth_id = {
+                let js_val = Reflect::get(&obj, &"openbaoPreferredAuthId".into())?;
+                js_val
+                    .dyn_into::<JsString>()
+                    .ok()
+                    .and_then(|s| s.as_string())
+                    .ok_or_else(|| TypeError::new("Not a string"))?
+            };
+            let openbao_entity_id = {
+                let js_val = Reflect::get(&obj, &"openbaoEntityId".into())?;
+                js_val
+                    .dyn_into::<JsString>()
+                    .ok()
+                    .and_then(|s| s.as_string())
+                    .ok_or_else(|| TypeError::new("Not a string"))?
+            };
+            Ok(libparsec::AvailableDeviceType::OpenBao {
+                openbao_preferred_auth_id,
+                openbao_entity_id,
+            })
+        }
+        "AvailableDeviceTypePKI" => {
+            let certificate_ref = {
+                let js_val = Reflect::get(&obj, &"certificateRef".into())?;
+                struct_x509_certificate_reference_js_to_rs(js_val)?
+            };
+            Ok(libparsec::AvailableDeviceType::PKI { certificate_ref })
+        }
+        "AvailableDeviceTypePassword" => Ok(libparsec::AvailableDeviceType::Password {}),
+        "AvailableDeviceTypeRecovery" => Ok(libparsec::AvailableDeviceType::Recovery {}),
+        _ => Err(JsValue::from(TypeError::new(
+            "Object is not a AvailableDeviceType",
+        ))),
+    }
+}
+
+#[allow(dead_code)]
+fn variant_available_device_type_rs_to_js(
+    rs_obj: libparsec::AvailableDeviceType,
+) -> Result<JsValue, JsValue> {
+    let js_obj = Object::new().into();
+    match rs_obj {
+        libparsec::AvailableDeviceType::AccountVault { .. } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"AvailableDeviceTypeAccountVault".into(),
+            )?;
+        }
+        libparsec::AvailableDeviceType::Fido2 { .. } => {
+            Reflect::set(&js_obj, &"tag".into(), &"AvailableDeviceTypeFido2".into())?;
+        }
+        libparsec::AvailableDeviceType::Keyring { .. } => {
+            Reflect::set(&js_obj, &"tag".into(), &"AvailableDeviceTypeKeyring".into())?;
+        }
+        libparsec::AvailableDeviceType::MachineKey {
+            machine_key_file,
+            machine_key_available,
+            ..
+        } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"AvailableDeviceTypeMachineKey".into(),
+            )?;
+            let js_machine_key_file = JsValue::from_str({
+                let custom_to_rs_string = |path: std::path::PathBuf| -> Result<_, _> {
+                    path.into_os_string()
+                        .into_string()
+                        .map_err(|_| "Path contains non-utf8 characters")
+                };
+                match custom_to_rs_string(machine_key_file) {
+                    Ok(ok) => ok,
+                    Err(err) => return Err(JsValue::from(TypeError::new(&err.to_string()))),
+                }
+                .as_ref()
+            });
+            Reflect::set(&js_obj, &"machineKeyFile".into(), &js_machine_key_file)?;
+            let js_machine_key_available = machine_key_available.into();
+            Reflect::set(
+                &js_obj,
+                &"machineKeyAvailable".into(),
+                &js_machine_key_available,
+            )?;
+        }
+        libparsec::AvailableDeviceType::Multi { protections, .. } => {
+            Reflect::set(&js_obj, &"tag".into(), &"AvailableDeviceTypeMulti".into())?;
+            let js_protections = {
+                // Array::new_with_length allocates with `undefined` value, that's why we `set` value
+                let js_array = Array::new_with_length(protections.len() as u32);
+                for (i, elem) in protections.into_iter().enumerate() {
+                    let js_elem = struct_available_device_multi_protection_rs_to_js(elem)?;
+                    js_array.set(i as u32, js_elem);
+                }
+                js_array.into()
+            };
+            Reflect::set(&js_obj, &"protections".into(), &js_protections)?;
+        }
+        libparsec::AvailableDeviceType::OpenBao {
+            openbao_preferred_auth_id,
+            openbao_entity_id,
+            ..
+        } => {
+            Reflect::set(&js_obj, &"tag".into(), &"AvailableDeviceTypeOpenBao".into())?;
+            let js_openbao_preferred_auth_id =
+                JsValue::from_str(openbao_preferred_auth_id.as_ref());
+            Reflect::set(
+                &js_obj,
+                &"openbaoPreferredAuthId".into(),
+                &js_openbao_preferred_auth_id,
+            )?;
+            let js_openbao_entity_id = JsValue::from_str(openbao_entity_id.as_ref());
+            Reflect::set(&js_obj, &"openbaoEntityId".into(), &js_openbao_entity_id)?;
+        }
+        libparsec::AvailableDeviceType::PKI {
+            certificate_ref, ..
+        } => {
+            Reflect::set(&js_obj, &"tag".into(), &"AvailableDeviceTypePKI".into())?;
+            let js_certificate_ref = struct_x509_certificate_reference_rs_to_js(certificate_ref)?;
+            Reflect::set(&js_obj, &"certificateRef".into(), &js_certificate_ref)?;
+        }
+        libparsec::AvailableDeviceType::Password { .. } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"AvailableDeviceTypePassword".into(),
+            )?;
+        }
+        libparsec::AvailableDeviceType::Recovery { .. } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"AvailableDeviceTypeRecovery".into(),
+            )?;
+        }
+    }
+    Ok(js_obj)
+}
+
+// AvailablePendingAsyncEnrollmentIdentitySystem
+
+#[allow(dead_code)]
+fn variant_available_pending_async_enrollment_identity_system_js_to_rs(
+    obj: JsValue,
+) -> Result<libparsec::AvailablePendingAsyncEnrollmentIdentitySystem, JsValue> {
+    let tag = Reflect::get(&obj, &"tag".into())?;
+    let tag = tag
+        .as_string()
+        .ok_or_else(|| JsValue::from(TypeError::new("tag isn't a string")))?;
+    match tag.as_str() {
+        "AvailablePendingAsyncEnrollmentIdentitySystemOpenBao" => {
+            let openbao_entity_id = {
+                let js_val = Reflect::get(&obj, &"openbaoEntityId".into())?;
+                js_val
+                    .dyn_into::<JsString>()
+                    .ok()
+                    .and_then(|s| s.as_string())
+                    .ok_or_else(|| TypeError::new("Not a string"))?
+            };
+            let openbao_preferred_auth_id = {
+                let js_val = Reflect::get(&obj, &"openbaoPreferredAuthId".into())?;
+                js_val
+                    .dyn_into::<JsString>()
+                    .ok()
                     .and_then(|s| s.as_string())
                     .ok_or_else(|| TypeError::new("Not a string"))?
             };
@@ -7888,6 +8600,13 @@ fn variant_bootstrap_organization_error_rs_to_js(
                 &"BootstrapOrganizationErrorOrganizationExpired".into(),
             )?;
         }
+        libparsec::BootstrapOrganizationError::SaveDeviceFido2HmacSecretNotSupported { .. } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"BootstrapOrganizationErrorSaveDeviceFido2HmacSecretNotSupported".into(),
+            )?;
+        }
         libparsec::BootstrapOrganizationError::SaveDeviceInvalidPath { .. } => {
             Reflect::set(
                 &js_obj,
@@ -8067,6 +8786,13 @@ fn variant_claim_finalize_error_rs_to_js(
     let js_display = &rs_obj.to_string();
     Reflect::set(&js_obj, &"error".into(), &js_display.into())?;
     match rs_obj {
+        libparsec::ClaimFinalizeError::Fido2HmacSecretNotSupported { .. } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"ClaimFinalizeErrorFido2HmacSecretNotSupported".into(),
+            )?;
+        }
         libparsec::ClaimFinalizeError::Internal { .. } => {
             Reflect::set(&js_obj, &"tag".into(), &"ClaimFinalizeErrorInternal".into())?;
         }
@@ -8322,6 +9048,13 @@ fn variant_client_accept_async_enrollment_error_rs_to_js(
                 &"ClientAcceptAsyncEnrollmentErrorBadSubmitPayload".into(),
             )?;
         }
+        libparsec::ClientAcceptAsyncEnrollmentError::ClockDrift { .. } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"ClientAcceptAsyncEnrollmentErrorClockDrift".into(),
+            )?;
+        }
         libparsec::ClientAcceptAsyncEnrollmentError::EnrollmentNoLongerAvailable { .. } => {
             Reflect::set(
                 &js_obj,
@@ -8481,6 +9214,13 @@ fn variant_client_archive_workspace_error_rs_to_js(
                 &"ClientArchiveWorkspaceErrorAuthorNotAllowed".into(),
             )?;
         }
+        libparsec::ClientArchiveWorkspaceError::ClockDrift { .. } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"ClientArchiveWorkspaceErrorClockDrift".into(),
+            )?;
+        }
         libparsec::ClientArchiveWorkspaceError::Internal { .. } => {
             Reflect::set(
                 &js_obj,
@@ -8682,6 +9422,13 @@ fn variant_client_delete_shamir_recovery_error_rs_to_js(
     let js_display = &rs_obj.to_string();
     Reflect::set(&js_obj, &"error".into(), &js_display.into())?;
     match rs_obj {
+        libparsec::ClientDeleteShamirRecoveryError::ClockDrift { .. } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"ClientDeleteShamirRecoveryErrorClockDrift".into(),
+            )?;
+        }
         libparsec::ClientDeleteShamirRecoveryError::Internal { .. } => {
             Reflect::set(
                 &js_obj,
@@ -8818,48 +9565,140 @@ fn variant_client_event_js_to_rs(obj: JsValue) -> Result<libparsec::ClientEvent,
             };
             Ok(libparsec::ClientEvent::ClientStopped { device_id })
         }
-        "ClientEventExpiredOrganization" => Ok(libparsec::ClientEvent::ExpiredOrganization {}),
-        "ClientEventFrozenSelfUser" => Ok(libparsec::ClientEvent::FrozenSelfUser {}),
-        "ClientEventGreetingAttemptCancelled" => {
-            let token = {
-                let js_val = Reflect::get(&obj, &"token".into())?;
+        "ClientEventClockDrift" => {
+            let required_timestamp = {
+                let js_val = Reflect::get(&obj, &"requiredTimestamp".into())?;
+                {
+                    let v = js_val.dyn_into::<Number>()?.value_of();
+                    let custom_from_rs_f64 = |n: f64| -> Result<_, &'static str> {
+                        libparsec::DateTime::from_timestamp_micros((n * 1_000_000f64) as i64)
+                            .map_err(|_| "Out-of-bound datetime")
+                    };
+                    let v = custom_from_rs_f64(v).map_err(|e| TypeError::new(e.as_ref()))?;
+                    v
+                }
+            };
+            let client_timestamp = {
+                let js_val = Reflect::get(&obj, &"clientTimestamp".into())?;
+                {
+                    let v = js_val.dyn_into::<Number>()?.value_of();
+                    let custom_from_rs_f64 = |n: f64| -> Result<_, &'static str> {
+                        libparsec::DateTime::from_timestamp_micros((n * 1_000_000f64) as i64)
+                            .map_err(|_| "Out-of-bound datetime")
+                    };
+                    let v = custom_from_rs_f64(v).map_err(|e| TypeError::new(e.as_ref()))?;
+                    v
+                }
+            };
+            Ok(libparsec::ClientEvent::ClockDrift {
+                required_timestamp,
+                client_timestamp,
+            })
+        }
+        "ClientEventDeviceAdded" => {
+            let user_id = {
+                let js_val = Reflect::get(&obj, &"userId".into())?;
                 js_val
                     .dyn_into::<JsString>()
                     .ok()
                     .and_then(|s| s.as_string())
                     .ok_or_else(|| TypeError::new("Not a string"))
                     .and_then(|x| {
-                        let custom_from_rs_string =
-                            |s: String| -> Result<libparsec::AccessToken, _> {
-                                libparsec::AccessToken::from_hex(s.as_str())
-                                    .map_err(|e| e.to_string())
-                            };
+                        let custom_from_rs_string = |s: String| -> Result<libparsec::UserID, _> {
+                            libparsec::UserID::from_hex(s.as_str()).map_err(|e| e.to_string())
+                        };
                         custom_from_rs_string(x).map_err(|e| TypeError::new(e.as_ref()))
                     })?
             };
-            let greeting_attempt = {
-                let js_val = Reflect::get(&obj, &"greetingAttempt".into())?;
+            let device_id = {
+                let js_val = Reflect::get(&obj, &"deviceId".into())?;
                 js_val
                     .dyn_into::<JsString>()
                     .ok()
                     .and_then(|s| s.as_string())
                     .ok_or_else(|| TypeError::new("Not a string"))
                     .and_then(|x| {
-                        let custom_from_rs_string =
-                            |s: String| -> Result<libparsec::GreetingAttemptID, _> {
-                                libparsec::GreetingAttemptID::from_hex(s.as_str())
-                                    .map_err(|e| e.to_string())
-                            };
+                        let custom_from_rs_string = |s: String| -> Result<libparsec::DeviceID, _> {
+                            libparsec::DeviceID::from_hex(s.as_str()).map_err(|e| e.to_string())
+                        };
                         custom_from_rs_string(x).map_err(|e| TypeError::new(e.as_ref()))
                     })?
             };
-            Ok(libparsec::ClientEvent::GreetingAttemptCancelled {
-                token,
-                greeting_attempt,
-            })
+            Ok(libparsec::ClientEvent::DeviceAdded { user_id, device_id })
         }
-        "ClientEventGreetingAttemptJoined" => {
-            let token = {
+        "ClientEventDeviceRevoked" => {
+            let user_id = {
+                let js_val = Reflect::get(&obj, &"userId".into())?;
+                js_val
+                    .dyn_into::<JsString>()
+                    .ok()
+                    .and_then(|s| s.as_string())
+                    .ok_or_else(|| TypeError::new("Not a string"))
+                    .and_then(|x| {
+                        let custom_from_rs_string = |s: String| -> Result<libparsec::UserID, _> {
+                            libparsec::UserID::from_hex(s.as_str()).map_err(|e| e.to_string())
+                        };
+                        custom_from_rs_string(x).map_err(|e| TypeError::new(e.as_ref()))
+                    })?
+            };
+            let device_id = {
+                let js_val = Reflect::get(&obj, &"deviceId".into())?;
+                js_val
+                    .dyn_into::<JsString>()
+                    .ok()
+                    .and_then(|s| s.as_string())
+                    .ok_or_else(|| TypeError::new("Not a string"))
+                    .and_then(|x| {
+                        let custom_from_rs_string = |s: String| -> Result<libparsec::DeviceID, _> {
+                            libparsec::DeviceID::from_hex(s.as_str()).map_err(|e| e.to_string())
+                        };
+                        custom_from_rs_string(x).map_err(|e| TypeError::new(e.as_ref()))
+                    })?
+            };
+            Ok(libparsec::ClientEvent::DeviceRevoked { user_id, device_id })
+        }
+        "ClientEventExpiredOrganization" => Ok(libparsec::ClientEvent::ExpiredOrganization {}),
+        "ClientEventFrozenSelfUser" => Ok(libparsec::ClientEvent::FrozenSelfUser {}),
+        "ClientEventGreetingAttemptCancelled" => {
+            let token = {
+                let js_val = Reflect::get(&obj, &"token".into())?;
+                js_val
+                    .dyn_into::<JsString>()
+                    .ok()
+                    .and_then(|s| s.as_string())
+                    .ok_or_else(|| TypeError::new("Not a string"))
+                    .and_then(|x| {
+                        let custom_from_rs_string =
+                            |s: String| -> Result<libparsec::AccessToken, _> {
+                                libparsec::AccessToken::from_hex(s.as_str())
+                                    .map_err(|e| e.to_string())
+                            };
+                        custom_from_rs_string(x).map_err(|e| TypeError::new(e.as_ref()))
+                    })?
+            };
+            let greeting_attempt = {
+                let js_val = Reflect::get(&obj, &"greetingAttempt".into())?;
+                js_val
+                    .dyn_into::<JsString>()
+                    .ok()
+                    .and_then(|s| s.as_string())
+                    .ok_or_else(|| TypeError::new("Not a string"))
+                    .and_then(|x| {
+                        let custom_from_rs_string =
+                            |s: String| -> Result<libparsec::GreetingAttemptID, _> {
+                                libparsec::GreetingAttemptID::from_hex(s.as_str())
+                                    .map_err(|e| e.to_string())
+                            };
+                        custom_from_rs_string(x).map_err(|e| TypeError::new(e.as_ref()))
+                    })?
+            };
+            Ok(libparsec::ClientEvent::GreetingAttemptCancelled {
+                token,
+                greeting_attempt,
+            })
+        }
+        "ClientEventGreetingAttemptJoined" => {
+            let token = {
                 let js_val = Reflect::get(&obj, &"token".into())?;
                 js_val
                     .dyn_into::<JsString>()
@@ -9038,7 +9877,245 @@ fn variant_client_event_js_to_rs(obj: JsValue) -> Result<libparsec::ClientEvent,
             };
             Ok(libparsec::ClientEvent::Ping { ping })
         }
+        "ClientEventRealmArchivingChanged" => {
+            let realm_id = {
+                let js_val = Reflect::get(&obj, &"realmId".into())?;
+                js_val
+                    .dyn_into::<JsString>()
+                    .ok()
+                    .and_then(|s| s.as_string())
+                    .ok_or_else(|| TypeError::new("Not a string"))
+                    .and_then(|x| {
+                        let custom_from_rs_string = |s: String| -> Result<libparsec::VlobID, _> {
+                            libparsec::VlobID::from_hex(s.as_str()).map_err(|e| e.to_string())
+                        };
+                        custom_from_rs_string(x).map_err(|e| TypeError::new(e.as_ref()))
+                    })?
+            };
+            Ok(libparsec::ClientEvent::RealmArchivingChanged { realm_id })
+        }
+        "ClientEventRealmRekeyDone" => {
+            let realm_id = {
+                let js_val = Reflect::get(&obj, &"realmId".into())?;
+                js_val
+                    .dyn_into::<JsString>()
+                    .ok()
+                    .and_then(|s| s.as_string())
+                    .ok_or_else(|| TypeError::new("Not a string"))
+                    .and_then(|x| {
+                        let custom_from_rs_string = |s: String| -> Result<libparsec::VlobID, _> {
+                            libparsec::VlobID::from_hex(s.as_str()).map_err(|e| e.to_string())
+                        };
+                        custom_from_rs_string(x).map_err(|e| TypeError::new(e.as_ref()))
+                    })?
+            };
+            let target_key_index = {
+                let js_val = Reflect::get(&obj, &"targetKeyIndex".into())?;
+                {
+                    let v = u64::try_from(js_val)
+                        .map_err(|_| TypeError::new("Not a BigInt representing an u64 number"))?;
+                    v
+                }
+            };
+            Ok(libparsec::ClientEvent::RealmRekeyDone {
+                realm_id,
+                target_key_index,
+            })
+        }
+        "ClientEventRealmRekeyProgress" => {
+            let realm_id = {
+                let js_val = Reflect::get(&obj, &"realmId".into())?;
+                js_val
+                    .dyn_into::<JsString>()
+                    .ok()
+                    .and_then(|s| s.as_string())
+                    .ok_or_else(|| TypeError::new("Not a string"))
+                    .and_then(|x| {
+                        let custom_from_rs_string = |s: String| -> Result<libparsec::VlobID, _> {
+                            libparsec::VlobID::from_hex(s.as_str()).map_err(|e| e.to_string())
+                        };
+                        custom_from_rs_string(x).map_err(|e| TypeError::new(e.as_ref()))
+                    })?
+            };
+            let target_key_index = {
+                let js_val = Reflect::get(&obj, &"targetKeyIndex".into())?;
+                {
+                    let v = u64::try_from(js_val)
+                        .map_err(|_| TypeError::new("Not a BigInt representing an u64 number"))?;
+                    v
+                }
+            };
+            let processed = {
+                let js_val = Reflect::get(&obj, &"processed".into())?;
+                {
+                    let v = u64::try_from(js_val)
+                        .map_err(|_| TypeError::new("Not a BigInt representing an u64 number"))?;
+                    v
+                }
+            };
+            let total = {
+                let js_val = Reflect::get(&obj, &"total".into())?;
+                {
+                    let v = u64::try_from(js_val)
+                        .map_err(|_| TypeError::new("Not a BigInt representing an u64 number"))?;
+                    v
+                }
+            };
+            Ok(libparsec::ClientEvent::RealmRekeyProgress {
+                realm_id,
+                target_key_index,
+                processed,
+                total,
+            })
+        }
+        "ClientEventRealmRenamed" => {
+            let realm_id = {
+                let js_val = Reflect::get(&obj, &"realmId".into())?;
+                js_val
+                    .dyn_into::<JsString>()
+                    .ok()
+                    .and_then(|s| s.as_string())
+                    .ok_or_else(|| TypeError::new("Not a string"))
+                    .and_then(|x| {
+                        let custom_from_rs_string = |s: String| -> Result<libparsec::VlobID, _> {
+                            libparsec::VlobID::from_hex(s.as_str()).map_err(|e| e.to_string())
+                        };
+                        custom_from_rs_string(x).map_err(|e| TypeError::new(e.as_ref()))
+                    })?
+            };
+            Ok(libparsec::ClientEvent::RealmRenamed { realm_id })
+        }
+        "ClientEventRealmRoleChanged" => {
+            let realm_id = {
+                let js_val = Reflect::get(&obj, &"realmId".into())?;
+                js_val
+                    .dyn_into::<JsString>()
+                    .ok()
+                    .and_then(|s| s.as_string())
+                    .ok_or_else(|| TypeError::new("Not a string"))
+                    .and_then(|x| {
+                        let custom_from_rs_string = |s: String| -> Result<libparsec::VlobID, _> {
+                            libparsec::VlobID::from_hex(s.as_str()).map_err(|e| e.to_string())
+                        };
+                        custom_from_rs_string(x).map_err(|e| TypeError::new(e.as_ref()))
+                    })?
+            };
+            let user_id = {
+                let js_val = Reflect::get(&obj, &"userId".into())?;
+                js_val
+                    .dyn_into::<JsString>()
+                    .ok()
+                    .and_then(|s| s.as_string())
+                    .ok_or_else(|| TypeError::new("Not a string"))
+                    .and_then(|x| {
+                        let custom_from_rs_string = |s: String| -> Result<libparsec::UserID, _> {
+                            libparsec::UserID::from_hex(s.as_str()).map_err(|e| e.to_string())
+                        };
+                        custom_from_rs_string(x).map_err(|e| TypeError::new(e.as_ref()))
+                    })?
+            };
+            let old_role = {
+                let js_val = Reflect::get(&obj, &"oldRole".into())?;
+                if js_val.is_null() {
+                    None
+                } else {
+                    Some({
+                        let raw_string = js_val.as_string().ok_or_else(|| {
+                            let type_error = TypeError::new("value is not a string");
+                            type_error.set_cause(&js_val);
+                            JsValue::from(type_error)
+                        })?;
+                        enum_realm_role_js_to_rs(raw_string.as_str())
+                    }?)
+                }
+            };
+            let new_role = {
+                let js_val = Reflect::get(&obj, &"newRole".into())?;
+                if js_val.is_null() {
+                    None
+                } else {
+                    Some({
+                        let raw_string = js_val.as_string().ok_or_else(|| {
+                            let type_error = TypeError::new("value is not a string");
+                            type_error.set_cause(&js_val);
+                            JsValue::from(type_error)
+                        })?;
+                        enum_realm_role_js_to_rs(raw_string.as_str())
+                    }?)
+                }
+            };
+            Ok(libparsec::ClientEvent::RealmRoleChanged {
+                realm_id,
+                user_id,
+                old_role,
+                new_role,
+            })
+        }
         "ClientEventRevokedSelfUser" => Ok(libparsec::ClientEvent::RevokedSelfUser {}),
+        "ClientEventSelfShared" => {
+            let realm_id = {
+                let js_val = Reflect::get(&obj, &"realmId".into())?;
+                js_val
+                    .dyn_into::<JsString>()
+                    .ok()
+                    .and_then(|s| s.as_string())
+                    .ok_or_else(|| TypeError::new("Not a string"))
+                    .and_then(|x| {
+                        let custom_from_rs_string = |s: String| -> Result<libparsec::VlobID, _> {
+                            libparsec::VlobID::from_hex(s.as_str()).map_err(|e| e.to_string())
+                        };
+                        custom_from_rs_string(x).map_err(|e| TypeError::new(e.as_ref()))
+                    })?
+            };
+            let role = {
+                let js_val = Reflect::get(&obj, &"role".into())?;
+                {
+                    let raw_string = js_val.as_string().ok_or_else(|| {
+                        let type_error = TypeError::new("value is not a string");
+                        type_error.set_cause(&js_val);
+                        JsValue::from(type_error)
+                    })?;
+                    enum_realm_role_js_to_rs(raw_string.as_str())
+                }?
+            };
+            Ok(libparsec::ClientEvent::SelfShared { realm_id, role })
+        }
+        "ClientEventSelfUnshared" => {
+            let realm_id = {
+                let js_val = Reflect::get(&obj, &"realmId".into())?;
+                js_val
+                    .dyn_into::<JsString>()
+                    .ok()
+                    .and_then(|s| s.as_string())
+                    .ok_or_else(|| TypeError::new("Not a string"))
+                    .and_then(|x| {
+                        let custom_from_rs_string = |s: String| -> Result<libparsec::VlobID, _> {
+                            libparsec::VlobID::from_hex(s.as_str()).map_err(|e| e.to_string())
+                        };
+                        custom_from_rs_string(x).map_err(|e| TypeError::new(e.as_ref()))
+                    })?
+            };
+            Ok(libparsec::ClientEvent::SelfUnshared { realm_id })
+        }
+        "ClientEventSequesterServiceRevoked" => {
+            let service_id = {
+                let js_val = Reflect::get(&obj, &"serviceId".into())?;
+                js_val
+                    .dyn_into::<JsString>()
+                    .ok()
+                    .and_then(|s| s.as_string())
+                    .ok_or_else(|| TypeError::new("Not a string"))
+                    .and_then(|x| {
+                        let custom_from_rs_string =
+                            |s: String| -> Result<libparsec::SequesterServiceID, _> {
+                                libparsec::SequesterServiceID::from_hex(s.as_str())
+                                    .map_err(|e| e.to_string())
+                            };
+                        custom_from_rs_string(x).map_err(|e| TypeError::new(e.as_ref()))
+                    })?
+            };
+            Ok(libparsec::ClientEvent::SequesterServiceRevoked { service_id })
+        }
         "ClientEventServerConfigChanged" => Ok(libparsec::ClientEvent::ServerConfigChanged {}),
         "ClientEventServerInvalidResponseContent" => {
             let protocol_decode_error = {
@@ -9064,6 +10141,23 @@ fn variant_client_event_js_to_rs(obj: JsValue) -> Result<libparsec::ClientEvent,
             };
             Ok(libparsec::ClientEvent::ServerInvalidResponseStatus { status_code })
         }
+        "ClientEventShamirRecoveryChanged" => {
+            let user_id = {
+                let js_val = Reflect::get(&obj, &"userId".into())?;
+                js_val
+                    .dyn_into::<JsString>()
+                    .ok()
+                    .and_then(|s| s.as_string())
+                    .ok_or_else(|| TypeError::new("Not a string"))
+                    .and_then(|x| {
+                        let custom_from_rs_string = |s: String| -> Result<libparsec::UserID, _> {
+                            libparsec::UserID::from_hex(s.as_str()).map_err(|e| e.to_string())
+                        };
+                        custom_from_rs_string(x).map_err(|e| TypeError::new(e.as_ref()))
+                    })?
+            };
+            Ok(libparsec::ClientEvent::ShamirRecoveryChanged { user_id })
+        }
         "ClientEventTooMuchDriftWithServerClock" => {
             let server_timestamp = {
                 let js_val = Reflect::get(&obj, &"serverTimestamp".into())?;
@@ -9104,6 +10198,51 @@ fn variant_client_event_js_to_rs(obj: JsValue) -> Result<libparsec::ClientEvent,
                 ballpark_client_late_offset,
             })
         }
+        "ClientEventUserProfileUpdated" => {
+            let user_id = {
+                let js_val = Reflect::get(&obj, &"userId".into())?;
+                js_val
+                    .dyn_into::<JsString>()
+                    .ok()
+                    .and_then(|s| s.as_string())
+                    .ok_or_else(|| TypeError::new("Not a string"))
+                    .and_then(|x| {
+                        let custom_from_rs_string = |s: String| -> Result<libparsec::UserID, _> {
+                            libparsec::UserID::from_hex(s.as_str()).map_err(|e| e.to_string())
+                        };
+                        custom_from_rs_string(x).map_err(|e| TypeError::new(e.as_ref()))
+                    })?
+            };
+            let profile = {
+                let js_val = Reflect::get(&obj, &"profile".into())?;
+                {
+                    let raw_string = js_val.as_string().ok_or_else(|| {
+                        let type_error = TypeError::new("value is not a string");
+                        type_error.set_cause(&js_val);
+                        JsValue::from(type_error)
+                    })?;
+                    enum_user_profile_js_to_rs(raw_string.as_str())
+                }?
+            };
+            Ok(libparsec::ClientEvent::UserProfileUpdated { user_id, profile })
+        }
+        "ClientEventUserRevoked" => {
+            let user_id = {
+                let js_val = Reflect::get(&obj, &"userId".into())?;
+                js_val
+                    .dyn_into::<JsString>()
+                    .ok()
+                    .and_then(|s| s.as_string())
+                    .ok_or_else(|| TypeError::new("Not a string"))
+                    .and_then(|x| {
+                        let custom_from_rs_string = |s: String| -> Result<libparsec::UserID, _> {
+                            libparsec::UserID::from_hex(s.as_str()).map_err(|e| e.to_string())
+                        };
+                        custom_from_rs_string(x).map_err(|e| TypeError::new(e.as_ref()))
+                    })?
+            };
+            Ok(libparsec::ClientEvent::UserRevoked { user_id })
+        }
         "ClientEventWebClientNotAllowedByOrganization" => {
             Ok(libparsec::ClientEvent::WebClientNotAllowedByOrganization {})
         }
@@ -9379,6 +10518,85 @@ fn variant_client_event_rs_to_js(rs_obj: libparsec::ClientEvent) -> Result<JsVal
             });
             Reflect::set(&js_obj, &"deviceId".into(), &js_device_id)?;
         }
+        libparsec::ClientEvent::ClockDrift {
+            required_timestamp,
+            client_timestamp,
+            ..
+        } => {
+            Reflect::set(&js_obj, &"tag".into(), &"ClientEventClockDrift".into())?;
+            let js_required_timestamp = {
+                let custom_to_rs_f64 = |dt: libparsec::DateTime| -> Result<f64, &'static str> {
+                    Ok((dt.as_timestamp_micros() as f64) / 1_000_000f64)
+                };
+                let v = match custom_to_rs_f64(required_timestamp) {
+                    Ok(ok) => ok,
+                    Err(err) => return Err(JsValue::from(TypeError::new(err.as_ref()))),
+                };
+                JsValue::from(v)
+            };
+            Reflect::set(&js_obj, &"requiredTimestamp".into(), &js_required_timestamp)?;
+            let js_client_timestamp = {
+                let custom_to_rs_f64 = |dt: libparsec::DateTime| -> Result<f64, &'static str> {
+                    Ok((dt.as_timestamp_micros() as f64) / 1_000_000f64)
+                };
+                let v = match custom_to_rs_f64(client_timestamp) {
+                    Ok(ok) => ok,
+                    Err(err) => return Err(JsValue::from(TypeError::new(err.as_ref()))),
+                };
+                JsValue::from(v)
+            };
+            Reflect::set(&js_obj, &"clientTimestamp".into(), &js_client_timestamp)?;
+        }
+        libparsec::ClientEvent::DeviceAdded {
+            user_id, device_id, ..
+        } => {
+            Reflect::set(&js_obj, &"tag".into(), &"ClientEventDeviceAdded".into())?;
+            let js_user_id = JsValue::from_str({
+                let custom_to_rs_string =
+                    |x: libparsec::UserID| -> Result<String, &'static str> { Ok(x.hex()) };
+                match custom_to_rs_string(user_id) {
+                    Ok(ok) => ok,
+                    Err(err) => return Err(JsValue::from(TypeError::new(&err.to_string()))),
+                }
+                .as_ref()
+            });
+            Reflect::set(&js_obj, &"userId".into(), &js_user_id)?;
+            let js_device_id = JsValue::from_str({
+                let custom_to_rs_string =
+                    |x: libparsec::DeviceID| -> Result<String, &'static str> { Ok(x.hex()) };
+                match custom_to_rs_string(device_id) {
+                    Ok(ok) => ok,
+                    Err(err) => return Err(JsValue::from(TypeError::new(&err.to_string()))),
+                }
+                .as_ref()
+            });
+            Reflect::set(&js_obj, &"deviceId".into(), &js_device_id)?;
+        }
+        libparsec::ClientEvent::DeviceRevoked {
+            user_id, device_id, ..
+        } => {
+            Reflect::set(&js_obj, &"tag".into(), &"ClientEventDeviceRevoked".into())?;
+            let js_user_id = JsValue::from_str({
+                let custom_to_rs_string =
+                    |x: libparsec::UserID| -> Result<String, &'static str> { Ok(x.hex()) };
+                match custom_to_rs_string(user_id) {
+                    Ok(ok) => ok,
+                    Err(err) => return Err(JsValue::from(TypeError::new(&err.to_string()))),
+                }
+                .as_ref()
+            });
+            Reflect::set(&js_obj, &"userId".into(), &js_user_id)?;
+            let js_device_id = JsValue::from_str({
+                let custom_to_rs_string =
+                    |x: libparsec::DeviceID| -> Result<String, &'static str> { Ok(x.hex()) };
+                match custom_to_rs_string(device_id) {
+                    Ok(ok) => ok,
+                    Err(err) => return Err(JsValue::from(TypeError::new(&err.to_string()))),
+                }
+                .as_ref()
+            });
+            Reflect::set(&js_obj, &"deviceId".into(), &js_device_id)?;
+        }
         libparsec::ClientEvent::ExpiredOrganization { .. } => {
             Reflect::set(
                 &js_obj,
@@ -9551,48 +10769,216 @@ fn variant_client_event_rs_to_js(rs_obj: libparsec::ClientEvent) -> Result<JsVal
                 &"ClientEventInvitationAlreadyUsedOrDeleted".into(),
             )?;
         }
-        libparsec::ClientEvent::InvitationChanged { token, status, .. } => {
+        libparsec::ClientEvent::InvitationChanged { token, status, .. } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"ClientEventInvitationChanged".into(),
+            )?;
+            let js_token = JsValue::from_str({
+                let custom_to_rs_string =
+                    |x: libparsec::AccessToken| -> Result<String, &'static str> { Ok(x.hex()) };
+                match custom_to_rs_string(token) {
+                    Ok(ok) => ok,
+                    Err(err) => return Err(JsValue::from(TypeError::new(&err.to_string()))),
+                }
+                .as_ref()
+            });
+            Reflect::set(&js_obj, &"token".into(), &js_token)?;
+            let js_status = JsValue::from_str(enum_invitation_status_rs_to_js(status));
+            Reflect::set(&js_obj, &"status".into(), &js_status)?;
+        }
+        libparsec::ClientEvent::MustAcceptTos { .. } => {
+            Reflect::set(&js_obj, &"tag".into(), &"ClientEventMustAcceptTos".into())?;
+        }
+        libparsec::ClientEvent::Offline { .. } => {
+            Reflect::set(&js_obj, &"tag".into(), &"ClientEventOffline".into())?;
+        }
+        libparsec::ClientEvent::Online { .. } => {
+            Reflect::set(&js_obj, &"tag".into(), &"ClientEventOnline".into())?;
+        }
+        libparsec::ClientEvent::OrganizationNotFound { .. } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"ClientEventOrganizationNotFound".into(),
+            )?;
+        }
+        libparsec::ClientEvent::Ping { ping, .. } => {
+            Reflect::set(&js_obj, &"tag".into(), &"ClientEventPing".into())?;
+            let js_ping = JsValue::from_str(ping.as_ref());
+            Reflect::set(&js_obj, &"ping".into(), &js_ping)?;
+        }
+        libparsec::ClientEvent::RealmArchivingChanged { realm_id, .. } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"ClientEventRealmArchivingChanged".into(),
+            )?;
+            let js_realm_id = JsValue::from_str({
+                let custom_to_rs_string =
+                    |x: libparsec::VlobID| -> Result<String, &'static str> { Ok(x.hex()) };
+                match custom_to_rs_string(realm_id) {
+                    Ok(ok) => ok,
+                    Err(err) => return Err(JsValue::from(TypeError::new(&err.to_string()))),
+                }
+                .as_ref()
+            });
+            Reflect::set(&js_obj, &"realmId".into(), &js_realm_id)?;
+        }
+        libparsec::ClientEvent::RealmRekeyDone {
+            realm_id,
+            target_key_index,
+            ..
+        } => {
+            Reflect::set(&js_obj, &"tag".into(), &"ClientEventRealmRekeyDone".into())?;
+            let js_realm_id = JsValue::from_str({
+                let custom_to_rs_string =
+                    |x: libparsec::VlobID| -> Result<String, &'static str> { Ok(x.hex()) };
+                match custom_to_rs_string(realm_id) {
+                    Ok(ok) => ok,
+                    Err(err) => return Err(JsValue::from(TypeError::new(&err.to_string()))),
+                }
+                .as_ref()
+            });
+            Reflect::set(&js_obj, &"realmId".into(), &js_realm_id)?;
+            let js_target_key_index = JsValue::from(target_key_index);
+            Reflect::set(&js_obj, &"targetKeyIndex".into(), &js_target_key_index)?;
+        }
+        libparsec::ClientEvent::RealmRekeyProgress {
+            realm_id,
+            target_key_index,
+            processed,
+            total,
+            ..
+        } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"ClientEventRealmRekeyProgress".into(),
+            )?;
+            let js_realm_id = JsValue::from_str({
+                let custom_to_rs_string =
+                    |x: libparsec::VlobID| -> Result<String, &'static str> { Ok(x.hex()) };
+                match custom_to_rs_string(realm_id) {
+                    Ok(ok) => ok,
+                    Err(err) => return Err(JsValue::from(TypeError::new(&err.to_string()))),
+                }
+                .as_ref()
+            });
+            Reflect::set(&js_obj, &"realmId".into(), &js_realm_id)?;
+            let js_target_key_index = JsValue::from(target_key_index);
+            Reflect::set(&js_obj, &"targetKeyIndex".into(), &js_target_key_index)?;
+            let js_processed = JsValue::from(processed);
+            Reflect::set(&js_obj, &"processed".into(), &js_processed)?;
+            let js_total = JsValue::from(total);
+            Reflect::set(&js_obj, &"total".into(), &js_total)?;
+        }
+        libparsec::ClientEvent::RealmRenamed { realm_id, .. } => {
+            Reflect::set(&js_obj, &"tag".into(), &"ClientEventRealmRenamed".into())?;
+            let js_realm_id = JsValue::from_str({
+                let custom_to_rs_string =
+                    |x: libparsec::VlobID| -> Result<String, &'static str> { Ok(x.hex()) };
+                match custom_to_rs_string(realm_id) {
+                    Ok(ok) => ok,
+                    Err(err) => return Err(JsValue::from(TypeError::new(&err.to_string()))),
+                }
+                .as_ref()
+            });
+            Reflect::set(&js_obj, &"realmId".into(), &js_realm_id)?;
+        }
+        libparsec::ClientEvent::RealmRoleChanged {
+            realm_id,
+            user_id,
+            old_role,
+            new_role,
+            ..
+        } => {
             Reflect::set(
                 &js_obj,
                 &"tag".into(),
-                &"ClientEventInvitationChanged".into(),
+                &"ClientEventRealmRoleChanged".into(),
             )?;
-            let js_token = JsValue::from_str({
+            let js_realm_id = JsValue::from_str({
                 let custom_to_rs_string =
-                    |x: libparsec::AccessToken| -> Result<String, &'static str> { Ok(x.hex()) };
-                match custom_to_rs_string(token) {
+                    |x: libparsec::VlobID| -> Result<String, &'static str> { Ok(x.hex()) };
+                match custom_to_rs_string(realm_id) {
                     Ok(ok) => ok,
                     Err(err) => return Err(JsValue::from(TypeError::new(&err.to_string()))),
                 }
                 .as_ref()
             });
-            Reflect::set(&js_obj, &"token".into(), &js_token)?;
-            let js_status = JsValue::from_str(enum_invitation_status_rs_to_js(status));
-            Reflect::set(&js_obj, &"status".into(), &js_status)?;
+            Reflect::set(&js_obj, &"realmId".into(), &js_realm_id)?;
+            let js_user_id = JsValue::from_str({
+                let custom_to_rs_string =
+                    |x: libparsec::UserID| -> Result<String, &'static str> { Ok(x.hex()) };
+                match custom_to_rs_string(user_id) {
+                    Ok(ok) => ok,
+                    Err(err) => return Err(JsValue::from(TypeError::new(&err.to_string()))),
+                }
+                .as_ref()
+            });
+            Reflect::set(&js_obj, &"userId".into(), &js_user_id)?;
+            let js_old_role = match old_role {
+                Some(val) => JsValue::from_str(enum_realm_role_rs_to_js(val)),
+                None => JsValue::NULL,
+            };
+            Reflect::set(&js_obj, &"oldRole".into(), &js_old_role)?;
+            let js_new_role = match new_role {
+                Some(val) => JsValue::from_str(enum_realm_role_rs_to_js(val)),
+                None => JsValue::NULL,
+            };
+            Reflect::set(&js_obj, &"newRole".into(), &js_new_role)?;
         }
-        libparsec::ClientEvent::MustAcceptTos { .. } => {
-            Reflect::set(&js_obj, &"tag".into(), &"ClientEventMustAcceptTos".into())?;
+        libparsec::ClientEvent::RevokedSelfUser { .. } => {
+            Reflect::set(&js_obj, &"tag".into(), &"ClientEventRevokedSelfUser".into())?;
         }
-        libparsec::ClientEvent::Offline { .. } => {
-            Reflect::set(&js_obj, &"tag".into(), &"ClientEventOffline".into())?;
+        libparsec::ClientEvent::SelfShared { realm_id, role, .. } => {
+            Reflect::set(&js_obj, &"tag".into(), &"ClientEventSelfShared".into())?;
+            let js_realm_id = JsValue::from_str({
+                let custom_to_rs_string =
+                    |x: libparsec::VlobID| -> Result<String, &'static str> { Ok(x.hex()) };
+                match custom_to_rs_string(realm_id) {
+                    Ok(ok) => ok,
+                    Err(err) => return Err(JsValue::from(TypeError::new(&err.to_string()))),
+                }
+                .as_ref()
+            });
+            Reflect::set(&js_obj, &"realmId".into(), &js_realm_id)?;
+            let js_role = JsValue::from_str(enum_realm_role_rs_to_js(role));
+            Reflect::set(&js_obj, &"role".into(), &js_role)?;
         }
-        libparsec::ClientEvent::Online { .. } => {
-            Reflect::set(&js_obj, &"tag".into(), &"ClientEventOnline".into())?;
+        libparsec::ClientEvent::SelfUnshared { realm_id, .. } => {
+            Reflect::set(&js_obj, &"tag".into(), &"ClientEventSelfUnshared".into())?;
+            let js_realm_id = JsValue::from_str({
+                let custom_to_rs_string =
+                    |x: libparsec::VlobID| -> Result<String, &'static str> { Ok(x.hex()) };
+                match custom_to_rs_string(realm_id) {
+                    Ok(ok) => ok,
+                    Err(err) => return Err(JsValue::from(TypeError::new(&err.to_string()))),
+                }
+                .as_ref()
+            });
+            Reflect::set(&js_obj, &"realmId".into(), &js_realm_id)?;
         }
-        libparsec::ClientEvent::OrganizationNotFound { .. } => {
+        libparsec::ClientEvent::SequesterServiceRevoked { service_id, .. } => {
             Reflect::set(
                 &js_obj,
                 &"tag".into(),
-                &"ClientEventOrganizationNotFound".into(),
+                &"ClientEventSequesterServiceRevoked".into(),
             )?;
-        }
-        libparsec::ClientEvent::Ping { ping, .. } => {
-            Reflect::set(&js_obj, &"tag".into(), &"ClientEventPing".into())?;
-            let js_ping = JsValue::from_str(ping.as_ref());
-            Reflect::set(&js_obj, &"ping".into(), &js_ping)?;
-        }
-        libparsec::ClientEvent::RevokedSelfUser { .. } => {
-            Reflect::set(&js_obj, &"tag".into(), &"ClientEventRevokedSelfUser".into())?;
+            let js_service_id = JsValue::from_str({
+                let custom_to_rs_string =
+                    |x: libparsec::SequesterServiceID| -> Result<String, &'static str> {
+                        Ok(x.hex())
+                    };
+                match custom_to_rs_string(service_id) {
+                    Ok(ok) => ok,
+                    Err(err) => return Err(JsValue::from(TypeError::new(&err.to_string()))),
+                }
+                .as_ref()
+            });
+            Reflect::set(&js_obj, &"serviceId".into(), &js_service_id)?;
         }
         libparsec::ClientEvent::ServerConfigChanged { .. } => {
             Reflect::set(
@@ -9626,6 +11012,23 @@ fn variant_client_event_rs_to_js(rs_obj: libparsec::ClientEvent) -> Result<JsVal
             let js_status_code = JsValue::from_str(status_code.as_ref());
             Reflect::set(&js_obj, &"statusCode".into(), &js_status_code)?;
         }
+        libparsec::ClientEvent::ShamirRecoveryChanged { user_id, .. } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"ClientEventShamirRecoveryChanged".into(),
+            )?;
+            let js_user_id = JsValue::from_str({
+                let custom_to_rs_string =
+                    |x: libparsec::UserID| -> Result<String, &'static str> { Ok(x.hex()) };
+                match custom_to_rs_string(user_id) {
+                    Ok(ok) => ok,
+                    Err(err) => return Err(JsValue::from(TypeError::new(&err.to_string()))),
+                }
+                .as_ref()
+            });
+            Reflect::set(&js_obj, &"userId".into(), &js_user_id)?;
+        }
         libparsec::ClientEvent::TooMuchDriftWithServerClock {
             server_timestamp,
             client_timestamp,
@@ -9673,6 +11076,40 @@ fn variant_client_event_rs_to_js(rs_obj: libparsec::ClientEvent) -> Result<JsVal
                 &js_ballpark_client_late_offset,
             )?;
         }
+        libparsec::ClientEvent::UserProfileUpdated {
+            user_id, profile, ..
+        } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"ClientEventUserProfileUpdated".into(),
+            )?;
+            let js_user_id = JsValue::from_str({
+                let custom_to_rs_string =
+                    |x: libparsec::UserID| -> Result<String, &'static str> { Ok(x.hex()) };
+                match custom_to_rs_string(user_id) {
+                    Ok(ok) => ok,
+                    Err(err) => return Err(JsValue::from(TypeError::new(&err.to_string()))),
+                }
+                .as_ref()
+            });
+            Reflect::set(&js_obj, &"userId".into(), &js_user_id)?;
+            let js_profile = JsValue::from_str(enum_user_profile_rs_to_js(profile));
+            Reflect::set(&js_obj, &"profile".into(), &js_profile)?;
+        }
+        libparsec::ClientEvent::UserRevoked { user_id, .. } => {
+            Reflect::set(&js_obj, &"tag".into(), &"ClientEventUserRevoked".into())?;
+            let js_user_id = JsValue::from_str({
+                let custom_to_rs_string =
+                    |x: libparsec::UserID| -> Result<String, &'static str> { Ok(x.hex()) };
+                match custom_to_rs_string(user_id) {
+                    Ok(ok) => ok,
+                    Err(err) => return Err(JsValue::from(TypeError::new(&err.to_string()))),
+                }
+                .as_ref()
+            });
+            Reflect::set(&js_obj, &"userId".into(), &js_user_id)?;
+        }
         libparsec::ClientEvent::WebClientNotAllowedByOrganization { .. } => {
             Reflect::set(
                 &js_obj,
@@ -9893,6 +11330,13 @@ fn variant_client_export_recovery_device_error_rs_to_js(
     let js_display = &rs_obj.to_string();
     Reflect::set(&js_obj, &"error".into(), &js_display.into())?;
     match rs_obj {
+        libparsec::ClientExportRecoveryDeviceError::ClockDrift { .. } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"ClientExportRecoveryDeviceErrorClockDrift".into(),
+            )?;
+        }
         libparsec::ClientExportRecoveryDeviceError::Internal { .. } => {
             Reflect::set(
                 &js_obj,
@@ -10596,7 +12040,145 @@ fn variant_client_reject_async_enrollment_error_rs_to_js(
             Reflect::set(
                 &js_obj,
                 &"tag".into(),
-                &"ClientRejectAsyncEnrollmentErrorOffline".into(),
+                &"ClientRejectAsyncEnrollmentErrorOffline".into(),
+            )?;
+        }
+    }
+    Ok(js_obj)
+}
+
+// ClientRekeyWorkspaceError
+
+#[allow(dead_code)]
+fn variant_client_rekey_workspace_error_rs_to_js(
+    rs_obj: libparsec::ClientRekeyWorkspaceError,
+) -> Result<JsValue, JsValue> {
+    let js_obj = Object::new().into();
+    let js_display = &rs_obj.to_string();
+    Reflect::set(&js_obj, &"error".into(), &js_display.into())?;
+    match rs_obj {
+        libparsec::ClientRekeyWorkspaceError::AuthorNotAllowed { .. } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"ClientRekeyWorkspaceErrorAuthorNotAllowed".into(),
+            )?;
+        }
+        libparsec::ClientRekeyWorkspaceError::ClockDrift { .. } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"ClientRekeyWorkspaceErrorClockDrift".into(),
+            )?;
+        }
+        libparsec::ClientRekeyWorkspaceError::Internal { .. } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"ClientRekeyWorkspaceErrorInternal".into(),
+            )?;
+        }
+        libparsec::ClientRekeyWorkspaceError::InvalidCertificate { .. } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"ClientRekeyWorkspaceErrorInvalidCertificate".into(),
+            )?;
+        }
+        libparsec::ClientRekeyWorkspaceError::InvalidKeysBundle { .. } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"ClientRekeyWorkspaceErrorInvalidKeysBundle".into(),
+            )?;
+        }
+        libparsec::ClientRekeyWorkspaceError::Offline { .. } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"ClientRekeyWorkspaceErrorOffline".into(),
+            )?;
+        }
+        libparsec::ClientRekeyWorkspaceError::RealmArchived { .. } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"ClientRekeyWorkspaceErrorRealmArchived".into(),
+            )?;
+        }
+        libparsec::ClientRekeyWorkspaceError::RealmDeleted { .. } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"ClientRekeyWorkspaceErrorRealmDeleted".into(),
+            )?;
+        }
+        libparsec::ClientRekeyWorkspaceError::ServerBlockstoreUnavailable { .. } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"ClientRekeyWorkspaceErrorServerBlockstoreUnavailable".into(),
+            )?;
+        }
+        libparsec::ClientRekeyWorkspaceError::Stopped { .. } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"ClientRekeyWorkspaceErrorStopped".into(),
+            )?;
+        }
+        libparsec::ClientRekeyWorkspaceError::TimestampOutOfBallpark {
+            server_timestamp,
+            client_timestamp,
+            ballpark_client_early_offset,
+            ballpark_client_late_offset,
+            ..
+        } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"ClientRekeyWorkspaceErrorTimestampOutOfBallpark".into(),
+            )?;
+            let js_server_timestamp = {
+                let custom_to_rs_f64 = |dt: libparsec::DateTime| -> Result<f64, &'static str> {
+                    Ok((dt.as_timestamp_micros() as f64) / 1_000_000f64)
+                };
+                let v = match custom_to_rs_f64(server_timestamp) {
+                    Ok(ok) => ok,
+                    Err(err) => return Err(JsValue::from(TypeError::new(err.as_ref()))),
+                };
+                JsValue::from(v)
+            };
+            Reflect::set(&js_obj, &"serverTimestamp".into(), &js_server_timestamp)?;
+            let js_client_timestamp = {
+                let custom_to_rs_f64 = |dt: libparsec::DateTime| -> Result<f64, &'static str> {
+                    Ok((dt.as_timestamp_micros() as f64) / 1_000_000f64)
+                };
+                let v = match custom_to_rs_f64(client_timestamp) {
+                    Ok(ok) => ok,
+                    Err(err) => return Err(JsValue::from(TypeError::new(err.as_ref()))),
+                };
+                JsValue::from(v)
+            };
+            Reflect::set(&js_obj, &"clientTimestamp".into(), &js_client_timestamp)?;
+            let js_ballpark_client_early_offset = ballpark_client_early_offset.into();
+            Reflect::set(
+                &js_obj,
+                &"ballparkClientEarlyOffset".into(),
+                &js_ballpark_client_early_offset,
+            )?;
+            let js_ballpark_client_late_offset = ballpark_client_late_offset.into();
+            Reflect::set(
+                &js_obj,
+                &"ballparkClientLateOffset".into(),
+                &js_ballpark_client_late_offset,
+            )?;
+        }
+        libparsec::ClientRekeyWorkspaceError::UnknownRealm { .. } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"ClientRekeyWorkspaceErrorUnknownRealm".into(),
             )?;
         }
     }
@@ -10620,6 +12202,13 @@ fn variant_client_rename_workspace_error_rs_to_js(
                 &"ClientRenameWorkspaceErrorAuthorNotAllowed".into(),
             )?;
         }
+        libparsec::ClientRenameWorkspaceError::ClockDrift { .. } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"ClientRenameWorkspaceErrorClockDrift".into(),
+            )?;
+        }
         libparsec::ClientRenameWorkspaceError::Internal { .. } => {
             Reflect::set(
                 &js_obj,
@@ -10751,6 +12340,13 @@ fn variant_client_revoke_user_error_rs_to_js(
                 &"ClientRevokeUserErrorAuthorNotAllowed".into(),
             )?;
         }
+        libparsec::ClientRevokeUserError::ClockDrift { .. } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"ClientRevokeUserErrorClockDrift".into(),
+            )?;
+        }
         libparsec::ClientRevokeUserError::Internal { .. } => {
             Reflect::set(
                 &js_obj,
@@ -10782,6 +12378,33 @@ fn variant_client_revoke_user_error_rs_to_js(
                 &"ClientRevokeUserErrorOffline".into(),
             )?;
         }
+        libparsec::ClientRevokeUserError::SoleOwnerOfWorkspaces { workspaces, .. } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"ClientRevokeUserErrorSoleOwnerOfWorkspaces".into(),
+            )?;
+            let js_workspaces = {
+                // Array::new_with_length allocates with `undefined` value, that's why we `set` value
+                let js_array = Array::new_with_length(workspaces.len() as u32);
+                for (i, elem) in workspaces.into_iter().enumerate() {
+                    let js_elem = JsValue::from_str({
+                        let custom_to_rs_string =
+                            |x: libparsec::VlobID| -> Result<String, &'static str> { Ok(x.hex()) };
+                        match custom_to_rs_string(elem) {
+                            Ok(ok) => ok,
+                            Err(err) => {
+                                return Err(JsValue::from(TypeError::new(&err.to_string())))
+                            }
+                        }
+                        .as_ref()
+                    });
+                    js_array.set(i as u32, js_elem);
+                }
+                js_array.into()
+            };
+            Reflect::set(&js_obj, &"workspaces".into(), &js_workspaces)?;
+        }
         libparsec::ClientRevokeUserError::Stopped { .. } => {
             Reflect::set(
                 &js_obj,
@@ -10831,6 +12454,13 @@ fn variant_client_setup_shamir_recovery_error_rs_to_js(
                 &"ClientSetupShamirRecoveryErrorAuthorAmongRecipients".into(),
             )?;
         }
+        libparsec::ClientSetupShamirRecoveryError::ClockDrift { .. } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"ClientSetupShamirRecoveryErrorClockDrift".into(),
+            )?;
+        }
         libparsec::ClientSetupShamirRecoveryError::Internal { .. } => {
             Reflect::set(
                 &js_obj,
@@ -10962,6 +12592,13 @@ fn variant_client_share_workspace_error_rs_to_js(
                 &"ClientShareWorkspaceErrorAuthorNotAllowed".into(),
             )?;
         }
+        libparsec::ClientShareWorkspaceError::ClockDrift { .. } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"ClientShareWorkspaceErrorClockDrift".into(),
+            )?;
+        }
         libparsec::ClientShareWorkspaceError::Internal { .. } => {
             Reflect::set(
                 &js_obj,
@@ -11403,6 +13040,158 @@ fn variant_client_totp_setup_status_error_rs_to_js(
     Ok(js_obj)
 }
 
+// ClientTransferWorkspaceOwnershipError
+
+#[allow(dead_code)]
+fn variant_client_transfer_workspace_ownership_error_rs_to_js(
+    rs_obj: libparsec::ClientTransferWorkspaceOwnershipError,
+) -> Result<JsValue, JsValue> {
+    let js_obj = Object::new().into();
+    let js_display = &rs_obj.to_string();
+    Reflect::set(&js_obj, &"error".into(), &js_display.into())?;
+    match rs_obj {
+        libparsec::ClientTransferWorkspaceOwnershipError::AuthorNotAllowed { .. } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"ClientTransferWorkspaceOwnershipErrorAuthorNotAllowed".into(),
+            )?;
+        }
+        libparsec::ClientTransferWorkspaceOwnershipError::ClockDrift { .. } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"ClientTransferWorkspaceOwnershipErrorClockDrift".into(),
+            )?;
+        }
+        libparsec::ClientTransferWorkspaceOwnershipError::Internal { .. } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"ClientTransferWorkspaceOwnershipErrorInternal".into(),
+            )?;
+        }
+        libparsec::ClientTransferWorkspaceOwnershipError::InvalidCertificate { .. } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"ClientTransferWorkspaceOwnershipErrorInvalidCertificate".into(),
+            )?;
+        }
+        libparsec::ClientTransferWorkspaceOwnershipError::InvalidKeysBundle { .. } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"ClientTransferWorkspaceOwnershipErrorInvalidKeysBundle".into(),
+            )?;
+        }
+        libparsec::ClientTransferWorkspaceOwnershipError::NewOwnerIsOutsider { .. } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"ClientTransferWorkspaceOwnershipErrorNewOwnerIsOutsider".into(),
+            )?;
+        }
+        libparsec::ClientTransferWorkspaceOwnershipError::NewOwnerIsSelf { .. } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"ClientTransferWorkspaceOwnershipErrorNewOwnerIsSelf".into(),
+            )?;
+        }
+        libparsec::ClientTransferWorkspaceOwnershipError::NewOwnerNotFound { .. } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"ClientTransferWorkspaceOwnershipErrorNewOwnerNotFound".into(),
+            )?;
+        }
+        libparsec::ClientTransferWorkspaceOwnershipError::NewOwnerRevoked { .. } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"ClientTransferWorkspaceOwnershipErrorNewOwnerRevoked".into(),
+            )?;
+        }
+        libparsec::ClientTransferWorkspaceOwnershipError::Offline { .. } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"ClientTransferWorkspaceOwnershipErrorOffline".into(),
+            )?;
+        }
+        libparsec::ClientTransferWorkspaceOwnershipError::RealmDeleted { .. } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"ClientTransferWorkspaceOwnershipErrorRealmDeleted".into(),
+            )?;
+        }
+        libparsec::ClientTransferWorkspaceOwnershipError::Stopped { .. } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"ClientTransferWorkspaceOwnershipErrorStopped".into(),
+            )?;
+        }
+        libparsec::ClientTransferWorkspaceOwnershipError::TimestampOutOfBallpark {
+            server_timestamp,
+            client_timestamp,
+            ballpark_client_early_offset,
+            ballpark_client_late_offset,
+            ..
+        } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"ClientTransferWorkspaceOwnershipErrorTimestampOutOfBallpark".into(),
+            )?;
+            let js_server_timestamp = {
+                let custom_to_rs_f64 = |dt: libparsec::DateTime| -> Result<f64, &'static str> {
+                    Ok((dt.as_timestamp_micros() as f64) / 1_000_000f64)
+                };
+                let v = match custom_to_rs_f64(server_timestamp) {
+                    Ok(ok) => ok,
+                    Err(err) => return Err(JsValue::from(TypeError::new(err.as_ref()))),
+                };
+                JsValue::from(v)
+            };
+            Reflect::set(&js_obj, &"serverTimestamp".into(), &js_server_timestamp)?;
+            let js_client_timestamp = {
+                let custom_to_rs_f64 = |dt: libparsec::DateTime| -> Result<f64, &'static str> {
+                    Ok((dt.as_timestamp_micros() as f64) / 1_000_000f64)
+                };
+                let v = match custom_to_rs_f64(client_timestamp) {
+                    Ok(ok) => ok,
+                    Err(err) => return Err(JsValue::from(TypeError::new(err.as_ref()))),
+                };
+                JsValue::from(v)
+            };
+            Reflect::set(&js_obj, &"clientTimestamp".into(), &js_client_timestamp)?;
+            let js_ballpark_client_early_offset = ballpark_client_early_offset.into();
+            Reflect::set(
+                &js_obj,
+                &"ballparkClientEarlyOffset".into(),
+                &js_ballpark_client_early_offset,
+            )?;
+            let js_ballpark_client_late_offset = ballpark_client_late_offset.into();
+            Reflect::set(
+                &js_obj,
+                &"ballparkClientLateOffset".into(),
+                &js_ballpark_client_late_offset,
+            )?;
+        }
+        libparsec::ClientTransferWorkspaceOwnershipError::WorkspaceNotFound { .. } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"ClientTransferWorkspaceOwnershipErrorWorkspaceNotFound".into(),
+            )?;
+        }
+    }
+    Ok(js_obj)
+}
+
 // ClientUserUpdateProfileError
 
 #[allow(dead_code)]
@@ -11420,6 +13209,13 @@ fn variant_client_user_update_profile_error_rs_to_js(
                 &"ClientUserUpdateProfileErrorAuthorNotAllowed".into(),
             )?;
         }
+        libparsec::ClientUserUpdateProfileError::ClockDrift { .. } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"ClientUserUpdateProfileErrorClockDrift".into(),
+            )?;
+        }
         libparsec::ClientUserUpdateProfileError::Internal { .. } => {
             Reflect::set(
                 &js_obj,
@@ -11441,6 +13237,33 @@ fn variant_client_user_update_profile_error_rs_to_js(
                 &"ClientUserUpdateProfileErrorOffline".into(),
             )?;
         }
+        libparsec::ClientUserUpdateProfileError::SoleOwnerOfWorkspaces { workspaces, .. } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"ClientUserUpdateProfileErrorSoleOwnerOfWorkspaces".into(),
+            )?;
+            let js_workspaces = {
+                // Array::new_with_length allocates with `undefined` value, that's why we `set` value
+                let js_array = Array::new_with_length(workspaces.len() as u32);
+                for (i, elem) in workspaces.into_iter().enumerate() {
+                    let js_elem = JsValue::from_str({
+                        let custom_to_rs_string =
+                            |x: libparsec::VlobID| -> Result<String, &'static str> { Ok(x.hex()) };
+                        match custom_to_rs_string(elem) {
+                            Ok(ok) => ok,
+                            Err(err) => {
+                                return Err(JsValue::from(TypeError::new(&err.to_string())))
+                            }
+                        }
+                        .as_ref()
+                    });
+                    js_array.set(i as u32, js_elem);
+                }
+                js_array.into()
+            };
+            Reflect::set(&js_obj, &"workspaces".into(), &js_workspaces)?;
+        }
         libparsec::ClientUserUpdateProfileError::Stopped { .. } => {
             Reflect::set(
                 &js_obj,
@@ -11508,9 +13331,65 @@ fn variant_device_primary_protection_strategy_js_to_rs(
             };
             Ok(libparsec::DevicePrimaryProtectionStrategy::AccountVault { account_handle })
         }
+        "DevicePrimaryProtectionStrategyFido2" => {
+            let pin = {
+                let js_val = Reflect::get(&obj, &"pin".into())?;
+                if js_val.is_null() {
+                    None
+                } else {
+                    Some(
+                        js_val
+                            .dyn_into::<JsString>()
+                            .ok()
+                            .and_then(|s| s.as_string())
+                            .ok_or_else(|| TypeError::new("Not a string"))
+                            .and_then(|x| {
+                                let custom_from_rs_string =
+                                    |s: String| -> Result<_, String> { Ok(s.into()) };
+                                custom_from_rs_string(x).map_err(|e| TypeError::new(e.as_ref()))
+                            })?,
+                    )
+                }
+            };
+            Ok(libparsec::DevicePrimaryProtectionStrategy::Fido2 { pin })
+        }
         "DevicePrimaryProtectionStrategyKeyring" => {
             Ok(libparsec::DevicePrimaryProtectionStrategy::Keyring)
         }
+        "DevicePrimaryProtectionStrategyMachineKey" => {
+            let machine_key_file = {
+                let js_val = Reflect::get(&obj, &"machineKeyFile".into())?;
+                js_val
+                    .dyn_into::<JsString>()
+                    .ok()
+                    .and_then(|s| s.as_string())
+                    .ok_or_else(|| TypeError::new("Not a string"))
+                    .and_then(|x| {
+                        let custom_from_rs_string = |s: String| -> Result<_, &'static str> {
+                            Ok(std::path::PathBuf::from(s))
+                        };
+                        custom_from_rs_string(x).map_err(|e| TypeError::new(e.as_ref()))
+                    })?
+            };
+            Ok(libparsec::DevicePrimaryProtectionStrategy::MachineKey { machine_key_file })
+        }
+        "DevicePrimaryProtectionStrategyMulti" => {
+            let alternatives = {
+                let js_val = Reflect::get(&obj, &"alternatives".into())?;
+                {
+                    let js_val = js_val
+                        .dyn_into::<Array>()
+                        .map_err(|_| TypeError::new("Not an array"))?;
+                    let mut converted = Vec::with_capacity(js_val.length() as usize);
+                    for x in js_val.iter() {
+                        let x_converted = struct_device_alternative_protection_js_to_rs(x)?;
+                        converted.push(x_converted);
+                    }
+                    converted
+                }
+            };
+            Ok(libparsec::DevicePrimaryProtectionStrategy::Multi { alternatives })
+        }
         "DevicePrimaryProtectionStrategyOpenBao" => {
             let openbao_server_url = {
                 let js_val = Reflect::get(&obj, &"openbaoServerUrl".into())?;
@@ -11560,6 +13439,14 @@ fn variant_device_primary_protection_strategy_js_to_rs(
                     .and_then(|s| s.as_string())
                     .ok_or_else(|| TypeError::new("Not a string"))?
             };
+            let openbao_reauthentication_method = {
+                let js_val = Reflect::get(&obj, &"openbaoReauthenticationMethod".into())?;
+                if js_val.is_null() {
+                    None
+                } else {
+                    Some(variant_open_bao_auth_method_js_to_rs(js_val)?)
+                }
+            };
             Ok(libparsec::DevicePrimaryProtectionStrategy::OpenBao {
                 openbao_server_url,
                 openbao_secret_mount_path,
@@ -11567,6 +13454,7 @@ fn variant_device_primary_protection_strategy_js_to_rs(
                 openbao_entity_id,
                 openbao_auth_token,
                 openbao_preferred_auth_id,
+                openbao_reauthentication_method,
             })
         }
         "DevicePrimaryProtectionStrategyPKI" => {
@@ -11620,17 +13508,68 @@ fn variant_device_primary_protection_strategy_rs_to_js(
             Reflect::set(
                 &js_obj,
                 &"tag".into(),
-                &"DevicePrimaryProtectionStrategyAccountVault".into(),
+                &"DevicePrimaryProtectionStrategyAccountVault".into(),
+            )?;
+            let js_account_handle = JsValue::from(account_handle);
+            Reflect::set(&js_obj, &"accountHandle".into(), &js_account_handle)?;
+        }
+        libparsec::DevicePrimaryProtectionStrategy::Fido2 { pin, .. } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"DevicePrimaryProtectionStrategyFido2".into(),
+            )?;
+            let js_pin = match pin {
+                Some(val) => JsValue::from_str(val.as_ref()),
+                None => JsValue::NULL,
+            };
+            Reflect::set(&js_obj, &"pin".into(), &js_pin)?;
+        }
+        libparsec::DevicePrimaryProtectionStrategy::Keyring => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"DevicePrimaryProtectionStrategyKeyring".into(),
+            )?;
+        }
+        libparsec::DevicePrimaryProtectionStrategy::MachineKey {
+            machine_key_file, ..
+        } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"DevicePrimaryProtectionStrategyMachineKey".into(),
             )?;
-            let js_account_handle = JsValue::from(account_handle);
-            Reflect::set(&js_obj, &"accountHandle".into(), &js_account_handle)?;
+            let js_machine_key_file = JsValue::from_str({
+                let custom_to_rs_string = |path: std::path::PathBuf| -> Result<_, _> {
+                    path.into_os_string()
+                        .into_string()
+                        .map_err(|_| "Path contains non-utf8 characters")
+                };
+                match custom_to_rs_string(machine_key_file) {
+                    Ok(ok) => ok,
+                    Err(err) => return Err(JsValue::from(TypeError::new(&err.to_string()))),
+                }
+                .as_ref()
+            });
+            Reflect::set(&js_obj, &"machineKeyFile".into(), &js_machine_key_file)?;
         }
-        libparsec::DevicePrimaryProtectionStrategy::Keyring => {
+        libparsec::DevicePrimaryProtectionStrategy::Multi { alternatives, .. } => {
             Reflect::set(
                 &js_obj,
                 &"tag".into(),
-                &"DevicePrimaryProtectionStrategyKeyring".into(),
+                &"DevicePrimaryProtectionStrategyMulti".into(),
             )?;
+            let js_alternatives = {
+                // Array::new_with_length allocates with `undefined` value, that's why we `set` value
+                let js_array = Array::new_with_length(alternatives.len() as u32);
+                for (i, elem) in alternatives.into_iter().enumerate() {
+                    let js_elem = struct_device_alternative_protection_rs_to_js(elem)?;
+                    js_array.set(i as u32, js_elem);
+                }
+                js_array.into()
+            };
+            Reflect::set(&js_obj, &"alternatives".into(), &js_alternatives)?;
         }
         libparsec::DevicePrimaryProtectionStrategy::OpenBao {
             openbao_server_url,
@@ -11639,6 +13578,7 @@ fn variant_device_primary_protection_strategy_rs_to_js(
             openbao_entity_id,
             openbao_auth_token,
             openbao_preferred_auth_id,
+            openbao_reauthentication_method,
             ..
         } => {
             Reflect::set(
@@ -11673,6 +13613,15 @@ fn variant_device_primary_protection_strategy_rs_to_js(
                 &"openbaoPreferredAuthId".into(),
                 &js_openbao_preferred_auth_id,
             )?;
+            let js_openbao_reauthentication_method = match openbao_reauthentication_method {
+                Some(val) => variant_open_bao_auth_method_rs_to_js(val)?,
+                None => JsValue::NULL,
+            };
+            Reflect::set(
+                &js_obj,
+                &"openbaoReauthenticationMethod".into(),
+                &js_openbao_reauthentication_method,
+            )?;
         }
         libparsec::DevicePrimaryProtectionStrategy::PKI {
             pki_private_key_handle,
@@ -12335,6 +14284,13 @@ fn variant_greet_in_progress_error_rs_to_js(
                 &"GreetInProgressErrorCancelled".into(),
             )?;
         }
+        libparsec::GreetInProgressError::ClockDrift { .. } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"GreetInProgressErrorClockDrift".into(),
+            )?;
+        }
         libparsec::GreetInProgressError::CorruptedInviteUserData { .. } => {
             Reflect::set(
                 &js_obj,
@@ -12508,6 +14464,13 @@ fn variant_import_recovery_device_error_rs_to_js(
     let js_display = &rs_obj.to_string();
     Reflect::set(&js_obj, &"error".into(), &js_display.into())?;
     match rs_obj {
+        libparsec::ImportRecoveryDeviceError::ClockDrift { .. } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"ImportRecoveryDeviceErrorClockDrift".into(),
+            )?;
+        }
         libparsec::ImportRecoveryDeviceError::DecryptionFailed { .. } => {
             Reflect::set(
                 &js_obj,
@@ -12515,6 +14478,13 @@ fn variant_import_recovery_device_error_rs_to_js(
                 &"ImportRecoveryDeviceErrorDecryptionFailed".into(),
             )?;
         }
+        libparsec::ImportRecoveryDeviceError::Fido2HmacSecretNotSupported { .. } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"ImportRecoveryDeviceErrorFido2HmacSecretNotSupported".into(),
+            )?;
+        }
         libparsec::ImportRecoveryDeviceError::Internal { .. } => {
             Reflect::set(
                 &js_obj,
@@ -13679,6 +15649,168 @@ fn variant_open_bao_auth_config_rs_to_js(
     Ok(js_obj)
 }
 
+// OpenBaoAuthMethod
+
+#[allow(dead_code)]
+fn variant_open_bao_auth_method_js_to_rs(
+    obj: JsValue,
+) -> Result<libparsec::OpenBaoAuthMethod, JsValue> {
+    let tag = Reflect::get(&obj, &"tag".into())?;
+    let tag = tag
+        .as_string()
+        .ok_or_else(|| JsValue::from(TypeError::new("tag isn't a string")))?;
+    match tag.as_str() {
+        "OpenBaoAuthMethodAppRole" => {
+            let mount_path = {
+                let js_val = Reflect::get(&obj, &"mountPath".into())?;
+                js_val
+                    .dyn_into::<JsString>()
+                    .ok()
+                    .and_then(|s| s.as_string())
+                    .ok_or_else(|| TypeError::new("Not a string"))?
+            };
+            let role_id = {
+                let js_val = Reflect::get(&obj, &"roleId".into())?;
+                js_val
+                    .dyn_into::<JsString>()
+                    .ok()
+                    .and_then(|s| s.as_string())
+                    .ok_or_else(|| TypeError::new("Not a string"))?
+            };
+            let secret_id = {
+                let js_val = Reflect::get(&obj, &"secretId".into())?;
+                js_val
+                    .dyn_into::<JsString>()
+                    .ok()
+                    .and_then(|s| s.as_string())
+                    .ok_or_else(|| TypeError::new("Not a string"))?
+            };
+            Ok(libparsec::OpenBaoAuthMethod::AppRole {
+                mount_path,
+                role_id,
+                secret_id,
+            })
+        }
+        "OpenBaoAuthMethodJwt" => {
+            let mount_path = {
+                let js_val = Reflect::get(&obj, &"mountPath".into())?;
+                js_val
+                    .dyn_into::<JsString>()
+                    .ok()
+                    .and_then(|s| s.as_string())
+                    .ok_or_else(|| TypeError::new("Not a string"))?
+            };
+            let role = {
+                let js_val = Reflect::get(&obj, &"role".into())?;
+                js_val
+                    .dyn_into::<JsString>()
+                    .ok()
+                    .and_then(|s| s.as_string())
+                    .ok_or_else(|| TypeError::new("Not a string"))?
+            };
+            let jwt = {
+                let js_val = Reflect::get(&obj, &"jwt".into())?;
+                js_val
+                    .dyn_into::<JsString>()
+                    .ok()
+                    .and_then(|s| s.as_string())
+                    .ok_or_else(|| TypeError::new("Not a string"))?
+            };
+            Ok(libparsec::OpenBaoAuthMethod::Jwt {
+                mount_path,
+                role,
+                jwt,
+            })
+        }
+        "OpenBaoAuthMethodUserpass" => {
+            let mount_path = {
+                let js_val = Reflect::get(&obj, &"mountPath".into())?;
+                js_val
+                    .dyn_into::<JsString>()
+                    .ok()
+                    .and_then(|s| s.as_string())
+                    .ok_or_else(|| TypeError::new("Not a string"))?
+            };
+            let username = {
+                let js_val = Reflect::get(&obj, &"username".into())?;
+                js_val
+                    .dyn_into::<JsString>()
+                    .ok()
+                    .and_then(|s| s.as_string())
+                    .ok_or_else(|| TypeError::new("Not a string"))?
+            };
+            let password = {
+                let js_val = Reflect::get(&obj, &"password".into())?;
+                js_val
+                    .dyn_into::<JsString>()
+                    .ok()
+                    .and_then(|s| s.as_string())
+                    .ok_or_else(|| TypeError::new("Not a string"))?
+            };
+            Ok(libparsec::OpenBaoAuthMethod::Userpass {
+                mount_path,
+                username,
+                password,
+            })
+        }
+        _ => Err(JsValue::from(TypeError::new(
+            "Object is not a OpenBaoAuthMethod",
+        ))),
+    }
+}
+
+#[allow(dead_code)]
+fn variant_open_bao_auth_method_rs_to_js(
+    rs_obj: libparsec::OpenBaoAuthMethod,
+) -> Result<JsValue, JsValue> {
+    let js_obj = Object::new().into();
+    match rs_obj {
+        libparsec::OpenBaoAuthMethod::AppRole {
+            mount_path,
+            role_id,
+            secret_id,
+            ..
+        } => {
+            Reflect::set(&js_obj, &"tag".into(), &"OpenBaoAuthMethodAppRole".into())?;
+            let js_mount_path = JsValue::from_str(mount_path.as_ref());
+            Reflect::set(&js_obj, &"mountPath".into(), &js_mount_path)?;
+            let js_role_id = JsValue::from_str(role_id.as_ref());
+            Reflect::set(&js_obj, &"roleId".into(), &js_role_id)?;
+            let js_secret_id = JsValue::from_str(secret_id.as_ref());
+            Reflect::set(&js_obj, &"secretId".into(), &js_secret_id)?;
+        }
+        libparsec::OpenBaoAuthMethod::Jwt {
+            mount_path,
+            role,
+            jwt,
+            ..
+        } => {
+            Reflect::set(&js_obj, &"tag".into(), &"OpenBaoAuthMethodJwt".into())?;
+            let js_mount_path = JsValue::from_str(mount_path.as_ref());
+            Reflect::set(&js_obj, &"mountPath".into(), &js_mount_path)?;
+            let js_role = JsValue::from_str(role.as_ref());
+            Reflect::set(&js_obj, &"role".into(), &js_role)?;
+            let js_jwt = JsValue::from_str(jwt.as_ref());
+            Reflect::set(&js_obj, &"jwt".into(), &js_jwt)?;
+        }
+        libparsec::OpenBaoAuthMethod::Userpass {
+            mount_path,
+            username,
+            password,
+            ..
+        } => {
+            Reflect::set(&js_obj, &"tag".into(), &"OpenBaoAuthMethodUserpass".into())?;
+            let js_mount_path = JsValue::from_str(mount_path.as_ref());
+            Reflect::set(&js_obj, &"mountPath".into(), &js_mount_path)?;
+            let js_username = JsValue::from_str(username.as_ref());
+            Reflect::set(&js_obj, &"username".into(), &js_username)?;
+            let js_password = JsValue::from_str(password.as_ref());
+            Reflect::set(&js_obj, &"password".into(), &js_password)?;
+        }
+    }
+    Ok(js_obj)
+}
+
 // OpenBaoListSelfEmailsError
 
 #[allow(dead_code)]
@@ -18363,6 +20495,9 @@ fn variant_submitter_finalize_async_enrollment_error_rs_to_js(
         libparsec::SubmitterFinalizeAsyncEnrollmentError::PKIUnusableX509CertificateReference{   .. } => {
             Reflect::set(&js_obj, &"tag".into(), &"SubmitterFinalizeAsyncEnrollmentErrorPKIUnusableX509CertificateReference".into())?;
         }
+        libparsec::SubmitterFinalizeAsyncEnrollmentError::SaveDeviceFido2HmacSecretNotSupported{   .. } => {
+            Reflect::set(&js_obj, &"tag".into(), &"SubmitterFinalizeAsyncEnrollmentErrorSaveDeviceFido2HmacSecretNotSupported".into())?;
+        }
         libparsec::SubmitterFinalizeAsyncEnrollmentError::SaveDeviceInvalidPath{   .. } => {
             Reflect::set(&js_obj, &"tag".into(), &"SubmitterFinalizeAsyncEnrollmentErrorSaveDeviceInvalidPath".into())?;
         }
@@ -18640,6 +20775,13 @@ fn variant_update_device_error_rs_to_js(
                 &"UpdateDeviceErrorDecryptionFailed".into(),
             )?;
         }
+        libparsec::UpdateDeviceError::Fido2HmacSecretNotSupported { .. } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"UpdateDeviceErrorFido2HmacSecretNotSupported".into(),
+            )?;
+        }
         libparsec::UpdateDeviceError::Internal { .. } => {
             Reflect::set(&js_obj, &"tag".into(), &"UpdateDeviceErrorInternal".into())?;
         }
@@ -19407,6 +21549,27 @@ fn variant_workspace_generate_path_addr_error_rs_to_js(
     Ok(js_obj)
 }
 
+// WorkspaceGetRemoteOnlyEntriesError
+
+#[allow(dead_code)]
+fn variant_workspace_get_remote_only_entries_error_rs_to_js(
+    rs_obj: libparsec::WorkspaceGetRemoteOnlyEntriesError,
+) -> Result<JsValue, JsValue> {
+    let js_obj = Object::new().into();
+    let js_display = &rs_obj.to_string();
+    Reflect::set(&js_obj, &"error".into(), &js_display.into())?;
+    match rs_obj {
+        libparsec::WorkspaceGetRemoteOnlyEntriesError::Internal { .. } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"WorkspaceGetRemoteOnlyEntriesErrorInternal".into(),
+            )?;
+        }
+    }
+    Ok(js_obj)
+}
+
 // WorkspaceHistoryEntryStat
 
 #[allow(dead_code)]
@@ -20456,86 +22619,163 @@ fn variant_workspace_history_stat_folder_children_error_rs_to_js(
             Reflect::set(
                 &js_obj,
                 &"tag".into(),
-                &"WorkspaceHistoryStatFolderChildrenErrorInternal".into(),
+                &"WorkspaceHistoryStatFolderChildrenErrorInternal".into(),
+            )?;
+        }
+        libparsec::WorkspaceHistoryStatFolderChildrenError::InvalidCertificate { .. } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"WorkspaceHistoryStatFolderChildrenErrorInvalidCertificate".into(),
+            )?;
+        }
+        libparsec::WorkspaceHistoryStatFolderChildrenError::InvalidHistory { .. } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"WorkspaceHistoryStatFolderChildrenErrorInvalidHistory".into(),
+            )?;
+        }
+        libparsec::WorkspaceHistoryStatFolderChildrenError::InvalidKeysBundle { .. } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"WorkspaceHistoryStatFolderChildrenErrorInvalidKeysBundle".into(),
+            )?;
+        }
+        libparsec::WorkspaceHistoryStatFolderChildrenError::InvalidManifest { .. } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"WorkspaceHistoryStatFolderChildrenErrorInvalidManifest".into(),
+            )?;
+        }
+        libparsec::WorkspaceHistoryStatFolderChildrenError::NoRealmAccess { .. } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"WorkspaceHistoryStatFolderChildrenErrorNoRealmAccess".into(),
+            )?;
+        }
+        libparsec::WorkspaceHistoryStatFolderChildrenError::Offline { .. } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"WorkspaceHistoryStatFolderChildrenErrorOffline".into(),
+            )?;
+        }
+        libparsec::WorkspaceHistoryStatFolderChildrenError::RealmDeleted { .. } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"WorkspaceHistoryStatFolderChildrenErrorRealmDeleted".into(),
+            )?;
+        }
+        libparsec::WorkspaceHistoryStatFolderChildrenError::Stopped { .. } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"WorkspaceHistoryStatFolderChildrenErrorStopped".into(),
+            )?;
+        }
+    }
+    Ok(js_obj)
+}
+
+// WorkspaceInfoError
+
+#[allow(dead_code)]
+fn variant_workspace_info_error_rs_to_js(
+    rs_obj: libparsec::WorkspaceInfoError,
+) -> Result<JsValue, JsValue> {
+    let js_obj = Object::new().into();
+    let js_display = &rs_obj.to_string();
+    Reflect::set(&js_obj, &"error".into(), &js_display.into())?;
+    match rs_obj {
+        libparsec::WorkspaceInfoError::Internal { .. } => {
+            Reflect::set(&js_obj, &"tag".into(), &"WorkspaceInfoErrorInternal".into())?;
+        }
+    }
+    Ok(js_obj)
+}
+
+// WorkspaceIsEntryRemoteOnlyError
+
+#[allow(dead_code)]
+fn variant_workspace_is_entry_remote_only_error_rs_to_js(
+    rs_obj: libparsec::WorkspaceIsEntryRemoteOnlyError,
+) -> Result<JsValue, JsValue> {
+    let js_obj = Object::new().into();
+    let js_display = &rs_obj.to_string();
+    Reflect::set(&js_obj, &"error".into(), &js_display.into())?;
+    match rs_obj {
+        libparsec::WorkspaceIsEntryRemoteOnlyError::EntryNotFound { .. } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"WorkspaceIsEntryRemoteOnlyErrorEntryNotFound".into(),
             )?;
         }
-        libparsec::WorkspaceHistoryStatFolderChildrenError::InvalidCertificate { .. } => {
+        libparsec::WorkspaceIsEntryRemoteOnlyError::Internal { .. } => {
             Reflect::set(
                 &js_obj,
                 &"tag".into(),
-                &"WorkspaceHistoryStatFolderChildrenErrorInvalidCertificate".into(),
+                &"WorkspaceIsEntryRemoteOnlyErrorInternal".into(),
             )?;
         }
-        libparsec::WorkspaceHistoryStatFolderChildrenError::InvalidHistory { .. } => {
+        libparsec::WorkspaceIsEntryRemoteOnlyError::InvalidCertificate { .. } => {
             Reflect::set(
                 &js_obj,
                 &"tag".into(),
-                &"WorkspaceHistoryStatFolderChildrenErrorInvalidHistory".into(),
+                &"WorkspaceIsEntryRemoteOnlyErrorInvalidCertificate".into(),
             )?;
         }
-        libparsec::WorkspaceHistoryStatFolderChildrenError::InvalidKeysBundle { .. } => {
+        libparsec::WorkspaceIsEntryRemoteOnlyError::InvalidKeysBundle { .. } => {
             Reflect::set(
                 &js_obj,
                 &"tag".into(),
-                &"WorkspaceHistoryStatFolderChildrenErrorInvalidKeysBundle".into(),
+                &"WorkspaceIsEntryRemoteOnlyErrorInvalidKeysBundle".into(),
             )?;
         }
-        libparsec::WorkspaceHistoryStatFolderChildrenError::InvalidManifest { .. } => {
+        libparsec::WorkspaceIsEntryRemoteOnlyError::InvalidManifest { .. } => {
             Reflect::set(
                 &js_obj,
                 &"tag".into(),
-                &"WorkspaceHistoryStatFolderChildrenErrorInvalidManifest".into(),
+                &"WorkspaceIsEntryRemoteOnlyErrorInvalidManifest".into(),
             )?;
         }
-        libparsec::WorkspaceHistoryStatFolderChildrenError::NoRealmAccess { .. } => {
+        libparsec::WorkspaceIsEntryRemoteOnlyError::NoRealmAccess { .. } => {
             Reflect::set(
                 &js_obj,
                 &"tag".into(),
-                &"WorkspaceHistoryStatFolderChildrenErrorNoRealmAccess".into(),
+                &"WorkspaceIsEntryRemoteOnlyErrorNoRealmAccess".into(),
             )?;
         }
-        libparsec::WorkspaceHistoryStatFolderChildrenError::Offline { .. } => {
+        libparsec::WorkspaceIsEntryRemoteOnlyError::Offline { .. } => {
             Reflect::set(
                 &js_obj,
                 &"tag".into(),
-                &"WorkspaceHistoryStatFolderChildrenErrorOffline".into(),
+                &"WorkspaceIsEntryRemoteOnlyErrorOffline".into(),
             )?;
         }
-        libparsec::WorkspaceHistoryStatFolderChildrenError::RealmDeleted { .. } => {
+        libparsec::WorkspaceIsEntryRemoteOnlyError::RealmDeleted { .. } => {
             Reflect::set(
                 &js_obj,
                 &"tag".into(),
-                &"WorkspaceHistoryStatFolderChildrenErrorRealmDeleted".into(),
+                &"WorkspaceIsEntryRemoteOnlyErrorRealmDeleted".into(),
             )?;
         }
-        libparsec::WorkspaceHistoryStatFolderChildrenError::Stopped { .. } => {
+        libparsec::WorkspaceIsEntryRemoteOnlyError::Stopped { .. } => {
             Reflect::set(
                 &js_obj,
                 &"tag".into(),
-                &"WorkspaceHistoryStatFolderChildrenErrorStopped".into(),
+                &"WorkspaceIsEntryRemoteOnlyErrorStopped".into(),
             )?;
         }
     }
     Ok(js_obj)
 }
 
-// WorkspaceInfoError
-
-#[allow(dead_code)]
-fn variant_workspace_info_error_rs_to_js(
-    rs_obj: libparsec::WorkspaceInfoError,
-) -> Result<JsValue, JsValue> {
-    let js_obj = Object::new().into();
-    let js_display = &rs_obj.to_string();
-    Reflect::set(&js_obj, &"error".into(), &js_display.into())?;
-    match rs_obj {
-        libparsec::WorkspaceInfoError::Internal { .. } => {
-            Reflect::set(&js_obj, &"tag".into(), &"WorkspaceInfoErrorInternal".into())?;
-        }
-    }
-    Ok(js_obj)
-}
-
 // WorkspaceIsFileContentLocalError
 
 #[allow(dead_code)]
@@ -20963,6 +23203,34 @@ fn variant_workspace_remove_entry_error_rs_to_js(
     Ok(js_obj)
 }
 
+// WorkspaceSetRemoteOnlyEntriesError
+
+#[allow(dead_code)]
+fn variant_workspace_set_remote_only_entries_error_rs_to_js(
+    rs_obj: libparsec::WorkspaceSetRemoteOnlyEntriesError,
+) -> Result<JsValue, JsValue> {
+    let js_obj = Object::new().into();
+    let js_display = &rs_obj.to_string();
+    Reflect::set(&js_obj, &"error".into(), &js_display.into())?;
+    match rs_obj {
+        libparsec::WorkspaceSetRemoteOnlyEntriesError::Internal { .. } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"WorkspaceSetRemoteOnlyEntriesErrorInternal".into(),
+            )?;
+        }
+        libparsec::WorkspaceSetRemoteOnlyEntriesError::Stopped { .. } => {
+            Reflect::set(
+                &js_obj,
+                &"tag".into(),
+                &"WorkspaceSetRemoteOnlyEntriesErrorStopped".into(),
+            )?;
+        }
+    }
+    Ok(js_obj)
+}
+
 // WorkspaceStatEntryError
 
 #[allow(dead_code)]
@@ -24020,6 +26288,40 @@ pub fn clientRejectAsyncEnrollment(client: u32, enrollment_id: String) -> Promis
     }))
 }
 
+// client_rekey_workspace
+#[allow(non_snake_case)]
+#[wasm_bindgen]
+pub fn clientRekeyWorkspace(client: u32, realm_id: String) -> Promise {
+    future_to_promise(libparsec::WithTaskIDFuture::from(async move {
+        let realm_id = {
+            let custom_from_rs_string = |s: String| -> Result<libparsec::VlobID, _> {
+                libparsec::VlobID::from_hex(s.as_str()).map_err(|e| e.to_string())
+            };
+            custom_from_rs_string(realm_id).map_err(|e| TypeError::new(e.as_ref()))
+        }?;
+        let ret = libparsec::client_rekey_workspace(client, realm_id).await;
+        Ok(match ret {
+            Ok(value) => {
+                let js_obj = Object::new().into();
+                Reflect::set(&js_obj, &"ok".into(), &true.into())?;
+                let js_value = {
+                    let _ = value;
+                    JsValue::null()
+                };
+                Reflect::set(&js_obj, &"value".into(), &js_value)?;
+                js_obj
+            }
+            Err(err) => {
+                let js_obj = Object::new().into();
+                Reflect::set(&js_obj, &"ok".into(), &false.into())?;
+                let js_err = variant_client_rekey_workspace_error_rs_to_js(err)?;
+                Reflect::set(&js_obj, &"error".into(), &js_err)?;
+                js_obj
+            }
+        })
+    }))
+}
+
 // client_rename_workspace
 #[allow(non_snake_case)]
 #[wasm_bindgen]
@@ -24063,7 +26365,7 @@ pub fn clientRenameWorkspace(client: u32, realm_id: String, new_name: String) ->
 // client_revoke_user
 #[allow(non_snake_case)]
 #[wasm_bindgen]
-pub fn clientRevokeUser(client: u32, user: String) -> Promise {
+pub fn clientRevokeUser(client: u32, user: String, orphaned_workspaces: String) -> Promise {
     future_to_promise(libparsec::WithTaskIDFuture::from(async move {
         let user = {
             let custom_from_rs_string = |s: String| -> Result<libparsec::UserID, _> {
@@ -24071,7 +26373,9 @@ pub fn clientRevokeUser(client: u32, user: String) -> Promise {
             };
             custom_from_rs_string(user).map_err(|e| TypeError::new(e.as_ref()))
         }?;
-        let ret = libparsec::client_revoke_user(client, user).await;
+        let orphaned_workspaces = enum_orphaned_workspaces_strategy_js_to_rs(&orphaned_workspaces)?;
+
+        let ret = libparsec::client_revoke_user(client, user, orphaned_workspaces).await;
         Ok(match ret {
             Ok(value) => {
                 let js_obj = Object::new().into();
@@ -24195,6 +26499,7 @@ pub fn clientShareWorkspace(
     realm_id: String,
     recipient: String,
     role: Option<String>,
+    expires_on: Option<f64>,
 ) -> Promise {
     future_to_promise(libparsec::WithTaskIDFuture::from(async move {
         let realm_id = {
@@ -24218,7 +26523,23 @@ pub fn clientShareWorkspace(
             None => None,
         };
 
-        let ret = libparsec::client_share_workspace(client, realm_id, recipient, role).await;
+        let expires_on = match expires_on {
+            Some(expires_on) => {
+                let expires_on = {
+                    let custom_from_rs_f64 = |n: f64| -> Result<_, &'static str> {
+                        libparsec::DateTime::from_timestamp_micros((n * 1_000_000f64) as i64)
+                            .map_err(|_| "Out-of-bound datetime")
+                    };
+                    custom_from_rs_f64(expires_on).map_err(|e| TypeError::new(e.as_ref()))
+                }?;
+
+                Some(expires_on)
+            }
+            None => None,
+        };
+
+        let ret =
+            libparsec::client_share_workspace(client, realm_id, recipient, role, expires_on).await;
         Ok(match ret {
             Ok(value) => {
                 let js_obj = Object::new().into();
@@ -24555,10 +26876,59 @@ pub fn clientTotpSetupStatus(client: u32) -> Promise {
     }))
 }
 
+// client_transfer_workspace_ownership
+#[allow(non_snake_case)]
+#[wasm_bindgen]
+pub fn clientTransferWorkspaceOwnership(
+    client: u32,
+    realm_id: String,
+    new_owner: String,
+) -> Promise {
+    future_to_promise(libparsec::WithTaskIDFuture::from(async move {
+        let realm_id = {
+            let custom_from_rs_string = |s: String| -> Result<libparsec::VlobID, _> {
+                libparsec::VlobID::from_hex(s.as_str()).map_err(|e| e.to_string())
+            };
+            custom_from_rs_string(realm_id).map_err(|e| TypeError::new(e.as_ref()))
+        }?;
+        let new_owner = {
+            let custom_from_rs_string = |s: String| -> Result<libparsec::UserID, _> {
+                libparsec::UserID::from_hex(s.as_str()).map_err(|e| e.to_string())
+            };
+            custom_from_rs_string(new_owner).map_err(|e| TypeError::new(e.as_ref()))
+        }?;
+        let ret = libparsec::client_transfer_workspace_ownership(client, realm_id, new_owner).await;
+        Ok(match ret {
+            Ok(value) => {
+                let js_obj = Object::new().into();
+                Reflect::set(&js_obj, &"ok".into(), &true.into())?;
+                let js_value = {
+                    let _ = value;
+                    JsValue::null()
+                };
+                Reflect::set(&js_obj, &"value".into(), &js_value)?;
+                js_obj
+            }
+            Err(err) => {
+                let js_obj = Object::new().into();
+                Reflect::set(&js_obj, &"ok".into(), &false.into())?;
+                let js_err = variant_client_transfer_workspace_ownership_error_rs_to_js(err)?;
+                Reflect::set(&js_obj, &"error".into(), &js_err)?;
+                js_obj
+            }
+        })
+    }))
+}
+
 // client_update_user_profile
 #[allow(non_snake_case)]
 #[wasm_bindgen]
-pub fn clientUpdateUserProfile(client_handle: u32, user: String, new_profile: String) -> Promise {
+pub fn clientUpdateUserProfile(
+    client_handle: u32,
+    user: String,
+    new_profile: String,
+    orphaned_workspaces: String,
+) -> Promise {
     future_to_promise(libparsec::WithTaskIDFuture::from(async move {
         let user = {
             let custom_from_rs_string = |s: String| -> Result<libparsec::UserID, _> {
@@ -24568,7 +26938,15 @@ pub fn clientUpdateUserProfile(client_handle: u32, user: String, new_profile: St
         }?;
         let new_profile = enum_user_profile_js_to_rs(&new_profile)?;
 
-        let ret = libparsec::client_update_user_profile(client_handle, user, new_profile).await;
+        let orphaned_workspaces = enum_orphaned_workspaces_strategy_js_to_rs(&orphaned_workspaces)?;
+
+        let ret = libparsec::client_update_user_profile(
+            client_handle,
+            user,
+            new_profile,
+            orphaned_workspaces,
+        )
+        .await;
         Ok(match ret {
             Ok(value) => {
                 let js_obj = Object::new().into();
@@ -24633,6 +27011,27 @@ pub fn getDefaultDataBaseDir() -> Promise {
     }))
 }
 
+// get_default_machine_key_file
+#[allow(non_snake_case)]
+#[wasm_bindgen]
+pub fn getDefaultMachineKeyFile() -> Promise {
+    future_to_promise(libparsec::WithTaskIDFuture::from(async move {
+        let ret = libparsec::get_default_machine_key_file();
+        Ok(JsValue::from_str({
+            let custom_to_rs_string = |path: std::path::PathBuf| -> Result<_, _> {
+                path.into_os_string()
+                    .into_string()
+                    .map_err(|_| "Path contains non-utf8 characters")
+            };
+            match custom_to_rs_string(ret) {
+                Ok(ok) => ok,
+                Err(err) => return Err(JsValue::from(TypeError::new(&err.to_string()))),
+            }
+            .as_ref()
+        }))
+    }))
+}
+
 // get_default_mountpoint_base_dir
 #[allow(non_snake_case)]
 #[wasm_bindgen]
@@ -27252,6 +29651,51 @@ pub fn workspaceGeneratePathAddr(workspace: u32, path: String) -> Promise {
     }))
 }
 
+// workspace_get_remote_only_entries
+#[allow(non_snake_case)]
+#[wasm_bindgen]
+pub fn workspaceGetRemoteOnlyEntries(workspace: u32) -> Promise {
+    future_to_promise(libparsec::WithTaskIDFuture::from(async move {
+        let ret = libparsec::workspace_get_remote_only_entries(workspace).await;
+        Ok(match ret {
+            Ok(value) => {
+                let js_obj = Object::new().into();
+                Reflect::set(&js_obj, &"ok".into(), &true.into())?;
+                let js_value = {
+                    // Array::new_with_length allocates with `undefined` value, that's why we `set` value
+                    let js_array = Array::new_with_length(value.len() as u32);
+                    for (i, elem) in value.into_iter().enumerate() {
+                        let js_elem = JsValue::from_str({
+                            let custom_to_rs_string =
+                                |x: libparsec::VlobID| -> Result<String, &'static str> {
+                                    Ok(x.hex())
+                                };
+                            match custom_to_rs_string(elem) {
+                                Ok(ok) => ok,
+                                Err(err) => {
+                                    return Err(JsValue::from(TypeError::new(&err.to_string())))
+                                }
+                            }
+                            .as_ref()
+                        });
+                        js_array.set(i as u32, js_elem);
+                    }
+                    js_array.into()
+                };
+                Reflect::set(&js_obj, &"value".into(), &js_value)?;
+                js_obj
+            }
+            Err(err) => {
+                let js_obj = Object::new().into();
+                Reflect::set(&js_obj, &"ok".into(), &false.into())?;
+                let js_err = variant_workspace_get_remote_only_entries_error_rs_to_js(err)?;
+                Reflect::set(&js_obj, &"error".into(), &js_err)?;
+                js_obj
+            }
+        })
+    }))
+}
+
 // workspace_history_fd_close
 #[allow(non_snake_case)]
 #[wasm_bindgen]
@@ -27888,6 +30332,37 @@ pub fn workspaceInfo(workspace: u32) -> Promise {
     }))
 }
 
+// workspace_is_entry_remote_only_by_id
+#[allow(non_snake_case)]
+#[wasm_bindgen]
+pub fn workspaceIsEntryRemoteOnlyById(workspace: u32, entry_id: String) -> Promise {
+    future_to_promise(libparsec::WithTaskIDFuture::from(async move {
+        let entry_id = {
+            let custom_from_rs_string = |s: String| -> Result<libparsec::VlobID, _> {
+                libparsec::VlobID::from_hex(s.as_str()).map_err(|e| e.to_string())
+            };
+            custom_from_rs_string(entry_id).map_err(|e| TypeError::new(e.as_ref()))
+        }?;
+        let ret = libparsec::workspace_is_entry_remote_only_by_id(workspace, entry_id).await;
+        Ok(match ret {
+            Ok(value) => {
+                let js_obj = Object::new().into();
+                Reflect::set(&js_obj, &"ok".into(), &true.into())?;
+                let js_value = value.into();
+                Reflect::set(&js_obj, &"value".into(), &js_value)?;
+                js_obj
+            }
+            Err(err) => {
+                let js_obj = Object::new().into();
+                Reflect::set(&js_obj, &"ok".into(), &false.into())?;
+                let js_err = variant_workspace_is_entry_remote_only_error_rs_to_js(err)?;
+                Reflect::set(&js_obj, &"error".into(), &js_err)?;
+                js_obj
+            }
+        })
+    }))
+}
+
 // workspace_is_file_content_local
 #[allow(non_snake_case)]
 #[wasm_bindgen]
@@ -28352,6 +30827,52 @@ pub fn workspaceRenameEntryById(
     }))
 }
 
+// workspace_set_remote_only_entries
+#[allow(non_snake_case)]
+#[wasm_bindgen]
+pub fn workspaceSetRemoteOnlyEntries(workspace: u32, entries: Vec<String>) -> Promise {
+    future_to_promise(libparsec::WithTaskIDFuture::from(async move {
+        let mut entries_converted = Vec::with_capacity(entries.len());
+        for js_elem in entries.iter() {
+            let rs_elem = js_elem
+                .into()
+                .dyn_into::<JsString>()
+                .ok()
+                .and_then(|s| s.as_string())
+                .ok_or_else(|| TypeError::new("Not a string"))
+                .and_then(|x| {
+                    let custom_from_rs_string = |s: String| -> Result<libparsec::VlobID, _> {
+                        libparsec::VlobID::from_hex(s.as_str()).map_err(|e| e.to_string())
+                    };
+                    custom_from_rs_string(x).map_err(|e| TypeError::new(e.as_ref()))
+                })?;
+            entries_converted.push(rs_elem);
+        }
+        let entries = entries_converted;
+
+        let ret = libparsec::workspace_set_remote_only_entries(workspace, entries).await;
+        Ok(match ret {
+            Ok(value) => {
+                let js_obj = Object::new().into();
+                Reflect::set(&js_obj, &"ok".into(), &true.into())?;
+                let js_value = {
+                    let _ = value;
+                    JsValue::null()
+                };
+                Reflect::set(&js_obj, &"value".into(), &js_value)?;
+                js_obj
+            }
+            Err(err) => {
+                let js_obj = Object::new().into();
+                Reflect::set(&js_obj, &"ok".into(), &false.into())?;
+                let js_err = variant_workspace_set_remote_only_entries_error_rs_to_js(err)?;
+                Reflect::set(&js_obj, &"error".into(), &js_err)?;
+                js_obj
+            }
+        })
+    }))
+}
+
 // workspace_stat_entry
 #[allow(non_snake_case)]
 #[wasm_bindgen]
@@ -0,0 +1,336 @@
+// Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+use anyhow::anyhow;
+use libparsec::{DateTime, EmailAddress, VlobID};
+use libparsec_client::{
+    CertificateAuditLogEntry, CertificateAuditLogEvent, CertificateAuditLogFilter,
+};
+
+use crate::utils::*;
+
+crate::clap_parser_with_shared_opts_builder!(
+    #[with = config_dir, device, password_stdin]
+    pub struct Args {
+        /// Output format (json/csv)
+        #[arg(short, long, default_value_t = Format::Json)]
+        format: Format,
+        /// Only keep the entries concerning (or authored by) the user with this email
+        ///
+        /// If the email has been reused after its previous user got revoked, the most
+        /// recent user is considered.
+        #[arg(long)]
+        user: Option<EmailAddress>,
+        /// Only keep the entries concerning this realm
+        #[arg(long)]
+        realm: Option<VlobID>,
+        /// Ignore everything before this date (e.g: 2024-01-01T00:00:00-00:00)
+        #[arg(long)]
+        since: Option<DateTime>,
+        /// Ignore everything after this date (e.g: 2024-01-01T00:00:00-00:00)
+        #[arg(long)]
+        until: Option<DateTime>,
+    }
+);
+
+#[derive(Clone, Copy)]
+pub enum Format {
+    Json,
+    Csv,
+}
+
+impl std::str::FromStr for Format {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "json" => Ok(Self::Json),
+            "csv" => Ok(Self::Csv),
+            _ => Err("Invalid format"),
+        }
+    }
+}
+
+impl std::fmt::Display for Format {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Json => "json",
+            Self::Csv => "csv",
+        })
+    }
+}
+
+crate::build_main_with_client!(main, certificate_audit);
+
+pub async fn certificate_audit(args: Args, client: &StartedClient) -> anyhow::Result<()> {
+    let Args {
+        format,
+        user,
+        realm,
+        since,
+        until,
+        ..
+    } = args;
+    log::trace!("Building certificate audit log (format={format})");
+
+    // No spinner here: stdout must only contain the json/csv output
+    client.poll_server_for_new_certificates().await?;
+
+    let user = match user {
+        None => None,
+        Some(email) => {
+            let users = client.list_users(false, None, None).await?;
+            // An email can be reused once its user has been revoked, in which case
+            // the most recent user is the one currently owning the email.
+            let user_id = users
+                .iter()
+                .filter(|info| info.human_handle.email() == &email)
+                .max_by_key(|info| info.created_on)
+                .map(|info| info.id)
+                .ok_or(anyhow!("User not found"))?;
+            Some(user_id)
+        }
+    };
+
+    let entries = client
+        .certificate_audit_log(CertificateAuditLogFilter {
+            user,
+            realm,
+            since,
+            until,
+        })
+        .await?;
+    let rows = entries.iter().map(AuditRow::from);
+
+    match format {
+        Format::Json => {
+            let rows: Vec<_> = rows.map(|row| row.to_json()).collect();
+            println!("{}", serde_json::to_string_pretty(&rows)?);
+        }
+        Format::Csv => {
+            println!("{}", AuditRow::CSV_HEADER.join(","));
+            for row in rows {
+                let line: Vec<_> = row.to_csv_fields().iter().map(|x| csv_escape(x)).collect();
+                println!("{}", line.join(","));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Flat representation of an audit log entry, shared between json and csv outputs.
+struct AuditRow {
+    timestamp: String,
+    author_device_id: Option<String>,
+    author_email: Option<String>,
+    event: &'static str,
+    user_id: Option<String>,
+    user_email: Option<String>,
+    realm_id: Option<String>,
+    realm_name: Option<String>,
+    details: String,
+}
+
+impl From<&CertificateAuditLogEntry> for AuditRow {
+    fn from(entry: &CertificateAuditLogEntry) -> Self {
+        let (event, details) = match &entry.event {
+            CertificateAuditLogEvent::UserCreated { profile, .. } => {
+                ("user_created", format!("profile={profile}"))
+            }
+            CertificateAuditLogEvent::UserProfileUpdated { profile, .. } => {
+                ("user_profile_updated", format!("profile={profile}"))
+            }
+            CertificateAuditLogEvent::UserRevoked { .. } => ("user_revoked", String::new()),
+            CertificateAuditLogEvent::DeviceCreated {
+                device_id,
+                device_label,
+                ..
+            } => (
+                "device_created",
+                format!("device_id={} device_label={device_label}", device_id.hex()),
+            ),
+            CertificateAuditLogEvent::DeviceRevoked { device_id, .. } => {
+                ("device_revoked", format!("device_id={}", device_id.hex()))
+            }
+            CertificateAuditLogEvent::GroupCreated { group_id, name } => (
+                "group_created",
+                format!("group_id={} group_name={name}", group_id.hex()),
+            ),
+            CertificateAuditLogEvent::GroupMemberChanged {
+                group_id,
+                group_name,
+                is_member,
+                ..
+            } => (
+                if *is_member {
+                    "group_member_added"
+                } else {
+                    "group_member_removed"
+                },
+                format!("group_id={} group_name={group_name}", group_id.hex()),
+            ),
+            CertificateAuditLogEvent::RealmRoleChanged { role, .. } => (
+                "realm_role_changed",
+                match role {
+                    Some(role) => format!("role={role}"),
+                    None => "role=none".to_string(),
+                },
+            ),
+            CertificateAuditLogEvent::RealmGroupRoleChanged {
+                group_id,
+                group_name,
+                role,
+                ..
+            } => (
+                "realm_group_role_changed",
+                format!(
+                    "group_id={} group_name={group_name} role={}",
+                    group_id.hex(),
+                    match role {
+                        Some(role) => role.to_string(),
+                        None => "none".to_string(),
+                    }
+                ),
+            ),
+            CertificateAuditLogEvent::RealmRenamed { .. } => ("realm_renamed", String::new()),
+            CertificateAuditLogEvent::RealmKeyRotated { key_index, .. } => {
+                ("realm_key_rotated", format!("key_index={key_index}"))
+            }
+            CertificateAuditLogEvent::RealmArchivingChanged { configuration, .. } => (
+                "realm_archiving_changed",
+                match configuration {
+                    libparsec::RealmArchivingConfiguration::Available => {
+                        "configuration=available".to_string()
+                    }
+                    libparsec::RealmArchivingConfiguration::Archived => {
+                        "configuration=archived".to_string()
+                    }
+                    libparsec::RealmArchivingConfiguration::DeletionPlanned { deletion_date } => {
+                        format!("configuration=deletion_planned deletion_date={deletion_date}")
+                    }
+                },
+            ),
+            CertificateAuditLogEvent::SequesterAuthorityCreated => {
+                ("sequester_authority_created", String::new())
+            }
+            CertificateAuditLogEvent::SequesterServiceCreated {
+                service_id,
+                service_label,
+            } => (
+                "sequester_service_created",
+                format!(
+                    "service_id={} service_label={service_label}",
+                    service_id.hex()
+                ),
+            ),
+            CertificateAuditLogEvent::SequesterServiceRevoked {
+                service_id,
+                service_label,
+            } => (
+                "sequester_service_revoked",
+                format!(
+                    "service_id={} service_label={service_label}",
+                    service_id.hex()
+                ),
+            ),
+            CertificateAuditLogEvent::ShamirRecoveryCreated {
+                threshold,
+                recipients,
+                ..
+            } => (
+                "shamir_recovery_created",
+                format!(
+                    "threshold={threshold} recipients={}",
+                    recipients
+                        .iter()
+                        .map(|(recipient, shares)| format!(
+                            "{}:{shares}",
+                            recipient.human_handle.email()
+                        ))
+                        .collect::<Vec<_>>()
+                        .join(";")
+                ),
+            ),
+            CertificateAuditLogEvent::ShamirRecoveryDeleted {
+                setup_timestamp, ..
+            } => (
+                "shamir_recovery_deleted",
+                format!("setup_timestamp={setup_timestamp}"),
+            ),
+        };
+
+        let user = entry.event.user();
+        let realm = entry.event.realm();
+        Self {
+            timestamp: entry.timestamp.to_rfc3339(),
+            author_device_id: entry.author.as_ref().map(|a| a.device_id.hex()),
+            author_email: entry
+                .author
+                .as_ref()
+                .map(|a| a.user.human_handle.email().to_string()),
+            event,
+            user_id: user.map(|u| u.user_id.hex()),
+            user_email: user.map(|u| u.human_handle.email().to_string()),
+            realm_id: realm.map(|r| r.realm_id.hex()),
+            realm_name: realm.and_then(|r| r.name.as_ref().map(|name| name.to_string())),
+            details,
+        }
+    }
+}
+
+impl AuditRow {
+    const CSV_HEADER: [&'static str; 9] = [
+        "timestamp",
+        "author_device_id",
+        "author_email",
+        "event",
+        "user_id",
+        "user_email",
+        "realm_id",
+        "realm_name",
+        "details",
+    ];
+
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "timestamp": self.timestamp,
+            "author_device_id": self.author_device_id,
+            "author_email": self.author_email,
+            "event": self.event,
+            "user_id": self.user_id,
+            "user_email": self.user_email,
+            "realm_id": self.realm_id,
+            "realm_name": self.realm_name,
+            "details": self.details,
+        })
+    }
+
+    fn to_csv_fields(&self) -> [&str; 9] {
+        [
+            &self.timestamp,
+            self.author_device_id.as_deref().unwrap_or_default(),
+            self.author_email.as_deref().unwrap_or_default(),
+            self.event,
+            self.user_id.as_deref().unwrap_or_default(),
+            self.user_email.as_deref().unwrap_or_default(),
+            self.realm_id.as_deref().unwrap_or_default(),
+            self.realm_name.as_deref().unwrap_or_default(),
+            &self.details,
+        ]
+    }
+}
+
+/// Quote the field if needed, and neutralize the values a spreadsheet software
+/// would otherwise evaluate as a formula (e.g. a device label such as `=HYPERLINK(...)`).
+fn csv_escape(field: &str) -> String {
+    let field = if field.starts_with(['=', '+', '-', '@', '\t', '\r']) {
+        format!("'{field}")
+    } else {
+        field.to_string()
+    };
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field
+    }
+}
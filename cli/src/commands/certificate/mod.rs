@@ -1,3 +1,4 @@
+pub mod audit;
 pub mod forget_all_certificates;
 pub mod poll;
 
@@ -14,11 +15,15 @@ pub enum Group {
     /// Note that this scenario is technically similar to a server compromise, so the user
     /// should ensure the rollback is legit before clearing the certificates!
     ForgetAllCertificates(forget_all_certificates::Args),
+    /// Export the history of the organization (users, devices, groups and realms changes)
+    /// as recorded by the certificates, along with the user and realm names involved.
+    Audit(audit::Args),
 }
 
 pub async fn dispatch_command(command: Group) -> anyhow::Result<()> {
     match command {
         Group::Poll(args) => poll::main(args).await,
         Group::ForgetAllCertificates(args) => forget_all_certificates::main(args).await,
+        Group::Audit(args) => audit::main(args).await,
     }
 }
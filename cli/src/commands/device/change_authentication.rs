@@ -13,41 +13,39 @@ crate::clap_parser_with_shared_opts_builder!(
         password: bool,
    #[clap(long, short, action)]
         keyring: bool,
+        /// Protect the device with a key file stored on this machine
+        /// (useful on headless machines where no OS keyring is available)
+   #[clap(long, action)]
+        machine_key: bool,
     }
 );
 
 enum NewAccessStrategyChoice {
     Password,
     Keyring,
+    MachineKey,
 }
 
 pub async fn main(args: Args) -> anyhow::Result<()> {
     let device = load_device_file(&args.config_dir, args.device).await?;
 
-    let new_save_strategy_choice = match (args.password, args.keyring) {
-        (true, false) => NewAccessStrategyChoice::Password,
-        (false, true) => NewAccessStrategyChoice::Keyring,
-        (true, true) => {
+    let new_save_strategy_choice = match (args.password, args.keyring, args.machine_key) {
+        (true, false, false) => NewAccessStrategyChoice::Password,
+        (false, true, false) => NewAccessStrategyChoice::Keyring,
+        (false, false, true) => NewAccessStrategyChoice::MachineKey,
+        (false, false, false) => {
             return Err(anyhow::anyhow!(
-                "Only one of --password and --keyring can be specified"
+                "One of --password, --keyring and --machine-key must be specified"
             ));
         }
-        (false, false) => {
+        _ => {
             return Err(anyhow::anyhow!(
-                "One of --password and --keyring must be specified"
+                "Only one of --password, --keyring and --machine-key can be specified"
             ));
         }
     };
 
-    if device.totp_opaque_key_id.is_some() {
-        // In theory we should support this authentication method here,
-        // however:
-        // - It is cumbersome since it requires a TOTP challenge involving the server.
-        // - In practice it is a niche usage that will most likely only be used in the GUI.
-        return Err(LoadAndUnlockDeviceError::UnsupportedTOTPAuthentication.into());
-    }
-
-    let current_access_strategy = match device.ty {
+    let mut current_access_strategy = match device.ty {
         AvailableDeviceType::Password => {
             let password = read_password(if args.password_stdin {
                 ReadPasswordFrom::Stdin
@@ -78,6 +76,14 @@ pub async fn main(args: Args) -> anyhow::Result<()> {
             primary_protection: DevicePrimaryProtectionStrategy::Keyring,
         },
 
+        AvailableDeviceType::MachineKey {
+            machine_key_file, ..
+        } => DeviceAccessStrategy {
+            key_file: device.key_file_path.clone(),
+            totp_protection: None,
+            primary_protection: DevicePrimaryProtectionStrategy::MachineKey { machine_key_file },
+        },
+
         AvailableDeviceType::AccountVault => {
             // In theory we should support this authentication method here,
             // however:
@@ -91,6 +97,26 @@ pub async fn main(args: Args) -> anyhow::Result<()> {
             return Err(LoadAndUnlockDeviceError::UnsupportedAuthentication(device.ty).into());
         }
 
+        AvailableDeviceType::Fido2 => {
+            let pin = read_fido2_pin(if args.password_stdin {
+                ReadPasswordFrom::Stdin
+            } else {
+                ReadPasswordFrom::Tty {
+                    prompt: "Enter PIN for the FIDO2 authenticator (leave empty if none):",
+                }
+            })?;
+
+            DeviceAccessStrategy {
+                key_file: device.key_file_path.clone(),
+                totp_protection: None,
+                primary_protection: DevicePrimaryProtectionStrategy::Fido2 { pin },
+            }
+        }
+
+        AvailableDeviceType::Multi { .. } => {
+            return Err(LoadAndUnlockDeviceError::UnsupportedAuthentication(device.ty).into());
+        }
+
         AvailableDeviceType::OpenBao { .. } => {
             // In theory we should support this authentication method here,
             // however:
@@ -101,7 +127,10 @@ pub async fn main(args: Args) -> anyhow::Result<()> {
         }
     };
 
-    let new_save_strategy = match new_save_strategy_choice {
+    current_access_strategy.totp_protection =
+        unlock_totp_protection(&args.config_dir, &device, args.password_stdin).await?;
+
+    let mut new_save_strategy = match new_save_strategy_choice {
         NewAccessStrategyChoice::Password => {
             let password = choose_password(if args.password_stdin {
                 ReadPasswordFrom::Stdin
@@ -121,8 +150,18 @@ pub async fn main(args: Args) -> anyhow::Result<()> {
             totp_protection: None,
             primary_protection: DevicePrimaryProtectionStrategy::Keyring,
         },
+
+        NewAccessStrategyChoice::MachineKey => DeviceSaveStrategy {
+            totp_protection: None,
+            primary_protection: DevicePrimaryProtectionStrategy::MachineKey {
+                machine_key_file: libparsec::get_default_machine_key_file(),
+            },
+        },
     };
 
+    // TOTP is only a secondary protection, so keep it as-is while changing the primary one
+    new_save_strategy.totp_protection = current_access_strategy.totp_protection.clone();
+
     libparsec::update_device_change_authentication(
         &args.config_dir,
         current_access_strategy,
@@ -0,0 +1,69 @@
+// Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+use libparsec::{
+    check_device_security, list_available_devices, DeviceProtectionWeakness, PasswordAlgorithm,
+};
+
+use crate::utils::*;
+
+crate::clap_parser_with_shared_opts_builder!(
+    #[with = config_dir]
+    pub struct Args {}
+);
+
+pub async fn main(args: Args) -> anyhow::Result<()> {
+    let config_dir = args.config_dir;
+    log::trace!("Checking devices security under {}", config_dir.display());
+    let devices = list_available_devices(&config_dir).await?;
+    let config_dir_str = config_dir.to_string_lossy();
+
+    if devices.is_empty() {
+        println!("No devices found in {YELLOW}{config_dir_str}{RESET}");
+        return Ok(());
+    }
+
+    let mut weak_count = 0;
+    for device in devices {
+        let device_str = format_single_device(&device);
+        let weaknesses = match check_device_security(&config_dir, device.key_file_path).await {
+            Ok(weaknesses) => weaknesses,
+            Err(err) => {
+                println!("{RED}Cannot check{RESET} {device_str}: {err}");
+                continue;
+            }
+        };
+
+        if weaknesses.is_empty() {
+            println!("{GREEN_CHECKMARK} {device_str}");
+            continue;
+        }
+
+        weak_count += weaknesses.len();
+        println!("{RED}Weak{RESET} {device_str}");
+        for weakness in weaknesses {
+            match weakness {
+                DeviceProtectionWeakness::OutdatedPasswordAlgorithm {
+                    algorithm:
+                        PasswordAlgorithm::Argon2id {
+                            memlimit_kb,
+                            opslimit,
+                            parallelism,
+                            ..
+                        },
+                } => println!(
+                    "  {BULLET_CHAR} Password protection uses outdated Argon2id parameters \
+                    (memlimit: {memlimit_kb}KB, opslimit: {opslimit}, parallelism: {parallelism}), \
+                    unlock the device with its password to upgrade them"
+                ),
+            }
+        }
+    }
+
+    if weak_count == 0 {
+        println!("No weak protection found");
+    } else {
+        println!("Found {RED}{weak_count}{RESET} weak protection(s)");
+    }
+
+    Ok(())
+}
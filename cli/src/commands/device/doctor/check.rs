@@ -0,0 +1,132 @@
+// Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+use std::collections::{hash_map::Entry, HashMap};
+
+use libparsec::{
+    diagnose_device_files, get_server_config, AdvisoryDeviceFileProtection, DeviceFileIssue,
+    ParsecAddr,
+};
+
+use crate::utils::*;
+
+crate::clap_parser_with_shared_opts_builder!(
+    #[with = config_dir]
+    pub struct Args {
+        /// Don't check the device protections against the ones advised by their server
+        #[arg(long)]
+        offline: bool,
+    }
+);
+
+pub async fn main(args: Args) -> anyhow::Result<()> {
+    let Args {
+        offline,
+        config_dir,
+    } = args;
+    log::trace!("Checking device files under {}", config_dir.display());
+
+    let diagnosis = diagnose_device_files(&config_dir).await?;
+    let config_dir_str = config_dir.to_string_lossy();
+
+    if diagnosis.device_files.is_empty() && diagnosis.orphaned_files.is_empty() {
+        println!("No devices found in {YELLOW}{config_dir_str}{RESET}");
+        return Ok(());
+    }
+
+    // `None` if the server cannot be reached
+    let mut advised_per_server: HashMap<ParsecAddr, Option<Vec<AdvisoryDeviceFileProtection>>> =
+        HashMap::new();
+
+    let mut issues_count = 0;
+    for device_file in diagnosis.device_files.iter() {
+        let mut issues = device_file
+            .issues
+            .iter()
+            .map(|issue| match issue {
+                DeviceFileIssue::Corrupted => "Device file is corrupted".to_string(),
+                DeviceFileIssue::Duplicate {
+                    other_key_file,
+                    other_server_addr,
+                } => format!(
+                    "Same device also stored in {} (server URL: {YELLOW}{other_server_addr}{RESET})",
+                    other_key_file.display(),
+                ),
+                DeviceFileIssue::MissingKeyringUserFile { keyring_user } => format!(
+                    "Keyring user file is missing (keyring user: {YELLOW}{keyring_user}{RESET})"
+                ),
+                DeviceFileIssue::MachineKeyUnavailable { machine_key_file } => format!(
+                    "Machine key file {} is missing or has too permissive permissions",
+                    machine_key_file.display()
+                ),
+            })
+            .collect::<Vec<_>>();
+
+        let device_str = match &device_file.device {
+            Some(device) => {
+                if !offline {
+                    let advised = match advised_per_server.entry(device.server_addr.clone()) {
+                        Entry::Occupied(entry) => entry.into_mut(),
+                        Entry::Vacant(entry) => {
+                            let advised = match get_server_config(
+                                &config_dir,
+                                device.server_addr.clone(),
+                            )
+                            .await
+                            {
+                                Ok(config) => Some(config.advisory_device_file_protection),
+                                Err(err) => {
+                                    println!(
+                                        "{YELLOW}Cannot check advised protections{RESET} for server {}: {err}",
+                                        device.server_addr
+                                    );
+                                    None
+                                }
+                            };
+                            entry.insert(advised)
+                        }
+                    };
+                    if let Some(advised) = advised {
+                        if !device_file.follows_advisory_protection(advised) {
+                            issues.push(format!(
+                                "Protection is not among the ones advised by the server ({YELLOW}{}{RESET})",
+                                advised.iter().map(|p| p.as_str()).collect::<Vec<_>>().join(", ")
+                            ));
+                        }
+                    }
+                }
+                format_single_device(device)
+            }
+            None => device_file.key_file_path.display().to_string(),
+        };
+
+        if issues.is_empty() {
+            println!("{GREEN_CHECKMARK} {device_str}");
+            continue;
+        }
+
+        issues_count += issues.len();
+        println!("{RED}Issue{RESET} {device_str}");
+        for issue in issues {
+            println!("  {BULLET_CHAR} {issue}");
+        }
+    }
+
+    for orphaned_file in diagnosis.orphaned_files.iter() {
+        issues_count += 1;
+        println!(
+            "{RED}Issue{RESET} {}\n  {BULLET_CHAR} Leftover of an interrupted device file save",
+            orphaned_file.display()
+        );
+    }
+
+    if issues_count == 0 {
+        println!("No issue found");
+    } else {
+        println!("Found {RED}{issues_count}{RESET} issue(s)");
+        println!(
+            "Corrupted, duplicated and leftover files can be fixed with `device doctor repair`"
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,49 @@
+// Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+use std::path::PathBuf;
+
+use libparsec::export_device_files_backup;
+
+use crate::utils::*;
+
+crate::clap_parser_with_shared_opts_builder!(
+    #[with = config_dir, password_stdin]
+    pub struct Args {
+        /// Path where to save the backup file
+        output: PathBuf,
+    }
+);
+
+pub async fn main(args: Args) -> anyhow::Result<()> {
+    let Args {
+        output,
+        config_dir,
+        password_stdin,
+    } = args;
+    log::trace!(
+        "Exporting device files backup at {} (confdir={})",
+        output.display(),
+        config_dir.display()
+    );
+
+    let password = choose_password(if password_stdin {
+        ReadPasswordFrom::Stdin
+    } else {
+        ReadPasswordFrom::Tty {
+            prompt: "Enter password for the backup file:",
+        }
+    })?;
+
+    let mut handle = start_spinner("Saving device files backup".into());
+
+    let (backup, key_files) = export_device_files_backup(&config_dir, &password).await?;
+    tokio::fs::write(&output, backup).await?;
+
+    handle.stop_with_message(format!(
+        "Backup of {GREEN}{}{RESET} device file(s) saved at {YELLOW}{}{RESET}",
+        key_files.len(),
+        output.display()
+    ));
+
+    Ok(())
+}
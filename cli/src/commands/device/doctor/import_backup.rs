@@ -0,0 +1,66 @@
+// Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+use std::path::PathBuf;
+
+use libparsec::{import_device_files_backup, ImportedDeviceFile};
+
+use crate::utils::*;
+
+crate::clap_parser_with_shared_opts_builder!(
+    #[with = config_dir, password_stdin]
+    pub struct Args {
+        /// Path of the backup file
+        input: PathBuf,
+    }
+);
+
+pub async fn main(args: Args) -> anyhow::Result<()> {
+    let Args {
+        input,
+        config_dir,
+        password_stdin,
+    } = args;
+    log::trace!(
+        "Importing device files backup from {} (confdir={})",
+        input.display(),
+        config_dir.display()
+    );
+
+    let backup = tokio::fs::read(input).await?;
+
+    let password = read_password(if password_stdin {
+        ReadPasswordFrom::Stdin
+    } else {
+        ReadPasswordFrom::Tty {
+            prompt: "Enter password for the backup file:",
+        }
+    })?;
+
+    let outcomes = import_device_files_backup(&config_dir, &backup, &password).await?;
+
+    if outcomes.is_empty() {
+        println!("No device files in the backup");
+        return Ok(());
+    }
+
+    for outcome in outcomes {
+        match outcome {
+            ImportedDeviceFile::Restored { key_file } => {
+                println!("{GREEN_CHECKMARK} Restored {}", key_file.display())
+            }
+            ImportedDeviceFile::AlreadyPresent { key_file } => {
+                println!("{GREEN_CHECKMARK} Already present {}", key_file.display())
+            }
+            ImportedDeviceFile::Conflict {
+                key_file,
+                existing_key_file,
+            } => println!(
+                "{RED}Skipped{RESET} {}: conflicts with existing device file {YELLOW}{}{RESET}",
+                key_file.display(),
+                existing_key_file.display()
+            ),
+        }
+    }
+
+    Ok(())
+}
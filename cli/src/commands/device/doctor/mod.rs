@@ -0,0 +1,29 @@
+pub mod check;
+pub mod export_backup;
+pub mod import_backup;
+pub mod repair;
+
+#[derive(clap::Subcommand)]
+pub enum Group {
+    /// Check the integrity of the local device files
+    ///
+    /// Device files are not decrypted, hence no password is required.
+    Check(check::Args),
+    /// Fix the issues reported by `device doctor check`
+    ///
+    /// Corrupted and duplicated device files are archived, not removed.
+    Repair(repair::Args),
+    /// Save all the local device files into a single password-protected backup file
+    ExportBackup(export_backup::Args),
+    /// Restore the device files from a backup file, existing device files are left untouched
+    ImportBackup(import_backup::Args),
+}
+
+pub async fn dispatch_command(command: Group) -> anyhow::Result<()> {
+    match command {
+        Group::Check(args) => check::main(args).await,
+        Group::Repair(args) => repair::main(args).await,
+        Group::ExportBackup(args) => export_backup::main(args).await,
+        Group::ImportBackup(args) => import_backup::main(args).await,
+    }
+}
@@ -0,0 +1,48 @@
+// Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+use libparsec::{repair_device_files, DeviceFileRepair};
+
+use crate::utils::*;
+
+crate::clap_parser_with_shared_opts_builder!(
+    #[with = config_dir]
+    pub struct Args {}
+);
+
+pub async fn main(args: Args) -> anyhow::Result<()> {
+    let config_dir = args.config_dir;
+    log::trace!("Repairing device files under {}", config_dir.display());
+
+    let repairs = repair_device_files(&config_dir).await?;
+
+    if repairs.is_empty() {
+        println!("Nothing to repair");
+        return Ok(());
+    }
+
+    for repair in repairs {
+        match repair {
+            DeviceFileRepair::OrphanedFileRemoved { path } => println!(
+                "{GREEN_CHECKMARK} Removed leftover file {YELLOW}{}{RESET}",
+                path.display()
+            ),
+            DeviceFileRepair::CorruptedArchived { key_file } => println!(
+                "{GREEN_CHECKMARK} Archived corrupted device file {YELLOW}{}{RESET}",
+                key_file.display()
+            ),
+            DeviceFileRepair::DuplicateArchived {
+                key_file,
+                kept_key_file,
+            } => println!(
+                "{GREEN_CHECKMARK} Archived duplicated device file {YELLOW}{}{RESET} (kept {YELLOW}{}{RESET})",
+                key_file.display(),
+                kept_key_file.display()
+            ),
+            DeviceFileRepair::KeyringUserFileRestored { keyring_user } => println!(
+                "{GREEN_CHECKMARK} Restored keyring user file (keyring user: {YELLOW}{keyring_user}{RESET})"
+            ),
+        }
+    }
+
+    Ok(())
+}
@@ -1,9 +1,13 @@
 pub mod change_authentication;
+pub mod check_security;
+pub mod doctor;
 pub mod export_recovery_device;
 pub mod forget_local;
 pub mod import_recovery_device;
 pub mod list;
 pub mod overwrite_server_url;
+pub mod revoke;
+pub mod totp;
 
 #[derive(clap::Subcommand)]
 pub enum Group {
@@ -14,6 +18,14 @@ pub enum Group {
     List(list::Args),
     /// Change authentication medium for a device
     ChangeAuthentication(change_authentication::Args),
+    /// Report weak protections of the local devices
+    ///
+    /// Outdated password key derivation parameters are upgraded the next time
+    /// the device is unlocked with its password.
+    CheckSecurity(check_security::Args),
+    /// Contains subcommands to check, repair and back up the local device files
+    #[command(subcommand)]
+    Doctor(doctor::Group),
     /// Export recovery device
     ExportRecoveryDevice(export_recovery_device::Args),
     /// Import recovery device
@@ -23,6 +35,14 @@ pub enum Group {
     /// This is only useful if the organization gets migrated to a new server with
     /// a different domain name.
     OverwriteServerURL(overwrite_server_url::Args),
+    /// Revoke a device
+    ///
+    /// The device's user is not revoked, but the workspaces it has access to
+    /// will get their key rotated.
+    Revoke(revoke::Args),
+    /// Contains subcommands related to TOTP (Time-based One-Time Password)
+    #[command(subcommand)]
+    Totp(totp::Group),
 }
 
 pub async fn dispatch_command(command: Group) -> anyhow::Result<()> {
@@ -30,8 +50,12 @@ pub async fn dispatch_command(command: Group) -> anyhow::Result<()> {
         Group::ForgetLocal(args) => forget_local::main(args).await,
         Group::List(args) => list::main(args).await,
         Group::ChangeAuthentication(args) => change_authentication::main(args).await,
+        Group::CheckSecurity(args) => check_security::main(args).await,
+        Group::Doctor(command) => doctor::dispatch_command(command).await,
         Group::ExportRecoveryDevice(args) => export_recovery_device::main(args).await,
         Group::ImportRecoveryDevice(args) => import_recovery_device::main(args).await,
         Group::OverwriteServerURL(args) => overwrite_server_url::main(args).await,
+        Group::Revoke(args) => revoke::main(args).await,
+        Group::Totp(command) => totp::dispatch_command(command).await,
     }
 }
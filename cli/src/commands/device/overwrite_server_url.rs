@@ -20,15 +20,7 @@ crate::clap_parser_with_shared_opts_builder!(
 pub async fn main(args: Args) -> anyhow::Result<()> {
     let device = load_device_file(&args.config_dir, args.device).await?;
 
-    if device.totp_opaque_key_id.is_some() {
-        // In theory we should support this authentication method here,
-        // however:
-        // - It is cumbersome since it requires a TOTP challenge involving the server.
-        // - In practice it is a niche usage that will most likely only be used in the GUI.
-        return Err(LoadAndUnlockDeviceError::UnsupportedTOTPAuthentication.into());
-    }
-
-    let access_strategy = match device.ty {
+    let mut access_strategy = match device.ty {
         AvailableDeviceType::Password => {
             let password = read_password(if args.password_stdin {
                 ReadPasswordFrom::Stdin
@@ -59,6 +51,14 @@ pub async fn main(args: Args) -> anyhow::Result<()> {
             primary_protection: DevicePrimaryProtectionStrategy::Keyring,
         },
 
+        AvailableDeviceType::MachineKey {
+            machine_key_file, ..
+        } => DeviceAccessStrategy {
+            key_file: device.key_file_path.clone(),
+            totp_protection: None,
+            primary_protection: DevicePrimaryProtectionStrategy::MachineKey { machine_key_file },
+        },
+
         AvailableDeviceType::AccountVault => {
             // In theory we should support this authentication method here,
             // however:
@@ -72,6 +72,26 @@ pub async fn main(args: Args) -> anyhow::Result<()> {
             return Err(LoadAndUnlockDeviceError::UnsupportedAuthentication(device.ty).into());
         }
 
+        AvailableDeviceType::Fido2 => {
+            let pin = read_fido2_pin(if args.password_stdin {
+                ReadPasswordFrom::Stdin
+            } else {
+                ReadPasswordFrom::Tty {
+                    prompt: "Enter PIN for the FIDO2 authenticator (leave empty if none):",
+                }
+            })?;
+
+            DeviceAccessStrategy {
+                key_file: device.key_file_path.clone(),
+                totp_protection: None,
+                primary_protection: DevicePrimaryProtectionStrategy::Fido2 { pin },
+            }
+        }
+
+        AvailableDeviceType::Multi { .. } => {
+            return Err(LoadAndUnlockDeviceError::UnsupportedAuthentication(device.ty).into());
+        }
+
         AvailableDeviceType::OpenBao { .. } => {
             // In theory we should support this authentication method here,
             // however:
@@ -82,6 +102,15 @@ pub async fn main(args: Args) -> anyhow::Result<()> {
         }
     };
 
+    // The TOTP opaque key is stored server-side, so the challenge must go to
+    // the server the organization has been migrated to
+    let migrated_device = AvailableDevice {
+        server_addr: args.server_url.clone(),
+        ..device.clone()
+    };
+    access_strategy.totp_protection =
+        unlock_totp_protection(&args.config_dir, &migrated_device, args.password_stdin).await?;
+
     let short_id = &device.device_id.hex()[..3];
     let AvailableDevice {
         organization_id,
@@ -0,0 +1,27 @@
+// Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+use libparsec::DeviceID;
+
+use crate::utils::*;
+
+crate::clap_parser_with_shared_opts_builder!(
+    #[with = config_dir, device, password_stdin]
+    pub struct Args {
+        /// ID of the device to revoke
+        #[arg(value_parser = DeviceID::from_hex)]
+        device_id: DeviceID,
+    }
+);
+
+crate::build_main_with_client!(main, revoke_device);
+
+pub async fn revoke_device(args: Args, client: &StartedClient) -> anyhow::Result<()> {
+    let Args { device_id, .. } = args;
+    poll_server_for_new_certificates(client).await?;
+
+    client.revoke_device(device_id).await?;
+
+    println!("Device {} has been revoked", device_id.hex());
+
+    Ok(())
+}
@@ -0,0 +1,20 @@
+pub mod setup;
+pub mod status;
+
+#[derive(clap::Subcommand)]
+pub enum Group {
+    /// Set up TOTP for the user and protect the device with it
+    ///
+    /// TOTP comes on top of the device's current protection (password or keyring),
+    /// hence a one-time password will be required each time the device is unlocked.
+    Setup(setup::Args),
+    /// Show the TOTP setup status of the user and whether the device is protected by it
+    Status(status::Args),
+}
+
+pub async fn dispatch_command(command: Group) -> anyhow::Result<()> {
+    match command {
+        Group::Setup(args) => setup::main(args).await,
+        Group::Status(args) => status::main(args).await,
+    }
+}
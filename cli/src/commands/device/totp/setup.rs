@@ -0,0 +1,146 @@
+// Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+use libparsec::{
+    AuthenticatedCmds, AvailableDeviceType, ClientConfig, DeviceAccessStrategy,
+    DevicePrimaryProtectionStrategy, DeviceSaveStrategy, ProxyConfig, TOTPSetupStatus,
+};
+use libparsec_totp::TotpSetupConfirmAuthenticatedError;
+
+use crate::utils::*;
+
+crate::clap_parser_with_shared_opts_builder!(
+    #[with = config_dir, device, password_stdin]
+    pub struct Args {}
+);
+
+const OTP_PROMPT: &str = "Enter the one-time password from your authenticator app";
+
+pub async fn main(args: Args) -> anyhow::Result<()> {
+    let Args {
+        config_dir,
+        device,
+        password_stdin,
+    } = args;
+    let read_otp_from = || {
+        if password_stdin {
+            ReadPasswordFrom::Stdin
+        } else {
+            ReadPasswordFrom::Tty { prompt: OTP_PROMPT }
+        }
+    };
+
+    let device = load_device_file(&config_dir, device).await?;
+
+    if device.totp_opaque_key_id.is_some() {
+        return Err(anyhow::anyhow!("Device is already protected by TOTP"));
+    }
+
+    // TOTP cannot be used alone, so it is added on top of the current protection
+    let primary_protection = match device.ty {
+        AvailableDeviceType::Password => {
+            let password = read_password(if password_stdin {
+                ReadPasswordFrom::Stdin
+            } else {
+                ReadPasswordFrom::Tty {
+                    prompt: "Enter password for the device:",
+                }
+            })?;
+            DevicePrimaryProtectionStrategy::Password { password }
+        }
+        AvailableDeviceType::Keyring => DevicePrimaryProtectionStrategy::Keyring,
+        AvailableDeviceType::MachineKey {
+            machine_key_file, ..
+        } => DevicePrimaryProtectionStrategy::MachineKey { machine_key_file },
+        AvailableDeviceType::Fido2 => {
+            let pin = read_fido2_pin(if password_stdin {
+                ReadPasswordFrom::Stdin
+            } else {
+                ReadPasswordFrom::Tty {
+                    prompt: "Enter PIN for the FIDO2 authenticator (leave empty if none):",
+                }
+            })?;
+            DevicePrimaryProtectionStrategy::Fido2 { pin }
+        }
+        AvailableDeviceType::Recovery
+        | AvailableDeviceType::PKI { .. }
+        | AvailableDeviceType::AccountVault
+        | AvailableDeviceType::OpenBao { .. }
+        | AvailableDeviceType::Multi { .. } => {
+            return Err(LoadAndUnlockDeviceError::UnsupportedAuthentication(device.ty).into());
+        }
+    };
+    let access_strategy = DeviceAccessStrategy {
+        key_file: device.key_file_path.clone(),
+        totp_protection: None,
+        primary_protection: primary_protection.clone(),
+    };
+
+    let local_device = libparsec::load_device(
+        &config_dir,
+        &access_strategy.clone().convert_with_side_effects().await?,
+    )
+    .await?;
+    let cmds = AuthenticatedCmds::new(&config_dir, local_device, ProxyConfig::new_from_env()?)?;
+
+    let status = libparsec_totp::totp_setup_status_authenticated(&cmds).await?;
+
+    if let TOTPSetupStatus::Unconfirmed { base32_totp_secret } = &status {
+        let organization_id = &device.organization_id;
+        println!("Add the following TOTP account to your authenticator app:");
+        println!(
+            "{YELLOW}otpauth://totp/parsec.cloud:{organization_id}?secret={base32_totp_secret}&issuer=parsec.cloud{RESET}"
+        );
+        println!("(or enter the secret manually: {YELLOW}{base32_totp_secret}{RESET})");
+
+        loop {
+            let one_time_password = read_one_time_password(read_otp_from())?;
+            match libparsec_totp::totp_setup_confirm_authenticated(&cmds, one_time_password).await {
+                Ok(()) => break,
+                // No point retrying when the input is not interactive
+                Err(TotpSetupConfirmAuthenticatedError::InvalidOneTimePassword)
+                    if !password_stdin =>
+                {
+                    println!("{RED}Invalid one-time password{RESET}, please retry");
+                }
+                Err(err) => return Err(err.into()),
+            }
+        }
+        println!("TOTP {GREEN}set up{RESET}");
+    }
+
+    let (opaque_key_id, opaque_key) = libparsec_totp::totp_create_opaque_key(&cmds).await?;
+
+    if status == TOTPSetupStatus::Confirmed {
+        // The authenticator app has been configured in a previous setup, so make sure
+        // it is still available before requiring it to unlock the device.
+        println!("TOTP is already set up for this user");
+        let one_time_password = read_one_time_password(read_otp_from())?;
+        let config = ClientConfig {
+            config_dir: config_dir.clone(),
+            ..Default::default()
+        };
+        libparsec::totp_fetch_opaque_key(
+            config,
+            device.server_addr.clone(),
+            device.organization_id.clone(),
+            device.user_id,
+            opaque_key_id,
+            one_time_password,
+        )
+        .await?;
+    }
+
+    libparsec::update_device_change_authentication(
+        &config_dir,
+        access_strategy,
+        DeviceSaveStrategy {
+            totp_protection: Some((opaque_key_id, opaque_key)),
+            primary_protection,
+        },
+    )
+    .await?;
+
+    println!("Device is now protected by TOTP");
+
+    Ok(())
+}
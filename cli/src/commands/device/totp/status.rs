@@ -0,0 +1,36 @@
+// Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+use libparsec::TOTPSetupStatus;
+
+use crate::utils::*;
+
+crate::clap_parser_with_shared_opts_builder!(
+    #[with = config_dir, device, password_stdin]
+    pub struct Args {}
+);
+
+pub async fn main(args: Args) -> anyhow::Result<()> {
+    let Args {
+        config_dir,
+        device,
+        password_stdin,
+    } = args;
+
+    let available_device = load_device_file(&config_dir, device.clone()).await?;
+    let (cmds, _) = load_cmds(&config_dir, device, password_stdin).await?;
+
+    let mut handle = start_spinner("Retrieving TOTP setup status".into());
+    let status = libparsec_totp::totp_setup_status_authenticated(&cmds).await?;
+    handle.stop_with_message(match status {
+        TOTPSetupStatus::Confirmed => format!("TOTP {GREEN}set up{RESET}"),
+        TOTPSetupStatus::Unconfirmed { .. } => format!("TOTP {YELLOW}not set up{RESET}"),
+    });
+
+    if available_device.totp_opaque_key_id.is_some() {
+        println!("Device {GREEN}protected{RESET} by TOTP");
+    } else {
+        println!("Device {YELLOW}not protected{RESET} by TOTP");
+    }
+
+    Ok(())
+}
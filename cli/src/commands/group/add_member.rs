@@ -0,0 +1,35 @@
+// Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+use libparsec::{GroupID, UserID};
+
+use crate::utils::*;
+
+crate::clap_parser_with_shared_opts_builder!(
+    #[with = config_dir, device, password_stdin]
+    pub struct Args {
+        /// The group ID
+        #[arg(short, long, value_parser = GroupID::from_hex)]
+        group: GroupID,
+        /// The user ID to add to the group
+        #[arg(short, long, value_parser = UserID::from_hex)]
+        user: UserID,
+    }
+);
+
+crate::build_main_with_client!(main, add_group_member);
+
+pub async fn add_group_member(args: Args, client: &StartedClient) -> anyhow::Result<()> {
+    let Args { group, user, .. } = args;
+    log::trace!("Adding user {user} to group {group}");
+
+    let mut handle = start_spinner("Adding user to group".into());
+
+    poll_server_for_new_certificates(client).await?;
+    client.add_group_member(group, user).await?;
+
+    handle.stop_with_message("User has been added to the group".into());
+
+    client.stop().await;
+
+    Ok(())
+}
@@ -0,0 +1,30 @@
+// Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+use crate::utils::*;
+
+crate::clap_parser_with_shared_opts_builder!(
+    #[with = config_dir, device, password_stdin]
+    pub struct Args {
+        /// Name of the new group
+        name: String,
+    }
+);
+
+crate::build_main_with_client!(main, create_group);
+
+pub async fn create_group(args: Args, client: &StartedClient) -> anyhow::Result<()> {
+    let Args { name, .. } = args;
+    log::trace!("Creating group {name}");
+
+    let mut handle = start_spinner("Creating group".into());
+
+    let id = client.create_group(name).await?.hex();
+
+    handle.stop_with_message(format!(
+        "Group has been created with id: {YELLOW}{id}{RESET}"
+    ));
+
+    client.stop().await;
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+// Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+use crate::utils::*;
+
+crate::clap_parser_with_shared_opts_builder!(
+    #[with = config_dir, device, password_stdin]
+    pub struct Args {
+    }
+);
+
+crate::build_main_with_client!(main, list_groups);
+
+pub async fn list_groups(_args: Args, client: &StartedClient) -> anyhow::Result<()> {
+    log::trace!("Listing groups");
+
+    poll_server_for_new_certificates(client).await?;
+    let groups = client.list_groups().await?;
+
+    if groups.is_empty() {
+        println!("No groups found");
+    } else {
+        let n = groups.len();
+        println!("Found {GREEN}{n}{RESET} group(s):");
+        for info in groups {
+            let id = info.id.hex();
+            let name = info.name;
+            let n = info.members.len();
+            println!("{YELLOW}{id}{RESET} - {name}: {GREEN}{n}{RESET} member(s)");
+            for member in info.members {
+                println!("  {BULLET_CHAR} {member}");
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,25 @@
+pub mod add_member;
+pub mod create;
+pub mod list;
+pub mod remove_member;
+
+#[derive(clap::Subcommand)]
+pub enum Group {
+    /// Add a user to a group
+    AddMember(add_member::Args),
+    /// Create a new group
+    Create(create::Args),
+    /// List groups and their members
+    List(list::Args),
+    /// Remove a user from a group
+    RemoveMember(remove_member::Args),
+}
+
+pub async fn dispatch_command(command: Group) -> anyhow::Result<()> {
+    match command {
+        Group::AddMember(args) => add_member::main(args).await,
+        Group::Create(args) => create::main(args).await,
+        Group::List(args) => list::main(args).await,
+        Group::RemoveMember(args) => remove_member::main(args).await,
+    }
+}
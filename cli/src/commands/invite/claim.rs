@@ -17,7 +17,9 @@ use libparsec::{
     },
     ClientConfig, ParsecInvitationAddr, Url,
 };
-use libparsec_client::{DeviceSaveStrategy, ShamirRecoveryClaimFinalizeCtx};
+use libparsec_client::{
+    DevicePrimaryProtectionStrategy, DeviceSaveStrategy, ShamirRecoveryClaimFinalizeCtx,
+};
 
 use crate::utils::*;
 use dialoguer::{Confirm, FuzzySelect, Input};
@@ -32,12 +34,17 @@ crate::clap_parser_with_shared_opts_builder!(
         /// Use keyring to store the password for the device.
         #[arg(long, default_value_t, conflicts_with = "password_stdin")]
         use_keyring: bool,
+        /// Use a key file stored on this machine to protect the device
+        /// (useful on headless machines where no OS keyring is available).
+        #[arg(long, default_value_t, conflicts_with_all = ["password_stdin", "use_keyring"])]
+        use_machine_key: bool,
     }
 );
 
 enum SaveMode {
     Password { read_from_stdin: bool },
     Keyring,
+    MachineKey,
 }
 
 pub async fn main(args: Args) -> anyhow::Result<()> {
@@ -47,11 +54,14 @@ pub async fn main(args: Args) -> anyhow::Result<()> {
         addr,
         password_stdin,
         use_keyring,
+        use_machine_key,
     } = args;
     let addr = ParsecInvitationAddr::from_any(addr.as_str())?;
     log::trace!("Claiming invitation (addr={addr})");
     let save_mode = if use_keyring {
         SaveMode::Keyring
+    } else if use_machine_key {
+        SaveMode::MachineKey
     } else {
         SaveMode::Password {
             read_from_stdin: password_stdin,
@@ -446,6 +456,12 @@ fn get_save_strategy(save_mode: SaveMode) -> anyhow::Result<DeviceSaveStrategy>
             Ok(DeviceSaveStrategy::new_password(password))
         }
         SaveMode::Keyring => Ok(DeviceSaveStrategy::new_keyring()),
+        SaveMode::MachineKey => Ok(DeviceSaveStrategy {
+            totp_protection: None,
+            primary_protection: DevicePrimaryProtectionStrategy::MachineKey {
+                machine_key_file: libparsec::get_default_machine_key_file(),
+            },
+        }),
     }
 }
 
@@ -1,5 +1,6 @@
 pub mod certificate;
 pub mod device;
+pub mod group;
 pub mod invite;
 pub mod ls;
 pub mod mount_realm_export;
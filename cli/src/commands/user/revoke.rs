@@ -2,6 +2,7 @@
 
 use anyhow::anyhow;
 use libparsec::EmailAddress;
+use libparsec_client::{ClientRevokeUserError, OrphanedWorkspacesStrategy};
 
 use crate::utils::*;
 
@@ -10,20 +11,47 @@ crate::clap_parser_with_shared_opts_builder!(
     pub struct Args {
         /// Email of the user to revoke
         email: EmailAddress,
+        /// Revoke the user even if it is the only owner of some workspaces
+        /// (leaving those workspaces without anybody able to manage them)
+        #[arg(long, default_value_t)]
+        allow_orphaned_workspaces: bool,
     }
 );
 
 crate::build_main_with_client!(main, revoke_user);
 
 pub async fn revoke_user(args: Args, client: &StartedClient) -> anyhow::Result<()> {
-    let Args { email, .. } = args;
+    let Args {
+        email,
+        allow_orphaned_workspaces,
+        ..
+    } = args;
     poll_server_for_new_certificates(client).await?;
     let users = client.list_users(true, None, None).await?;
     let to_revoke = users
         .iter()
         .find(|info| info.human_handle.email() == &email)
         .ok_or(anyhow!("User not found"))?;
-    client.revoke_user(to_revoke.id).await?;
+    let orphaned_workspaces = if allow_orphaned_workspaces {
+        OrphanedWorkspacesStrategy::Ignore
+    } else {
+        OrphanedWorkspacesStrategy::Abort
+    };
+    match client.revoke_user(to_revoke.id, orphaned_workspaces).await {
+        Ok(()) => (),
+        Err(ClientRevokeUserError::SoleOwnerOfWorkspaces { workspaces }) => {
+            let workspaces = workspaces
+                .iter()
+                .map(|id| id.hex())
+                .collect::<Vec<_>>()
+                .join(", ");
+            return Err(anyhow!(
+                "User {email} is the only owner of workspaces {workspaces}, \
+                transfer their ownership first (or use --allow-orphaned-workspaces)"
+            ));
+        }
+        Err(err) => return Err(err.into()),
+    }
 
     println!("User {email} has been revoked");
 
@@ -32,7 +32,12 @@ pub async fn list_users_workspace(args: Args, client: &StartedClient) -> anyhow:
             let role = user.current_role;
             let profile = user.current_profile;
 
-            println!("{BULLET_CHAR} User {YELLOW}{id}{RESET} ({YELLOW}{profile}{RESET}) - {GREEN}{name}{RESET} ({email}) has role {GREEN}{role}{RESET}");
+            let expires = match user.expires_on {
+                Some(expires_on) => format!(" until {YELLOW}{expires_on}{RESET}"),
+                None => String::new(),
+            };
+
+            println!("{BULLET_CHAR} User {YELLOW}{id}{RESET} ({YELLOW}{profile}{RESET}) - {GREEN}{name}{RESET} ({email}) has role {GREEN}{role}{RESET}{expires}");
         }
     }
 
@@ -3,8 +3,12 @@ pub mod create;
 pub mod import;
 pub mod list;
 pub mod list_users;
+pub mod rekey;
+pub mod remote_only;
 pub mod share;
+pub mod share_group;
 pub mod sync;
+pub mod transfer_ownership;
 
 #[derive(clap::Subcommand)]
 pub enum Group {
@@ -18,10 +22,27 @@ pub enum Group {
     List(list::Args),
     /// Import a local file to a remote workspace
     Import(import::Args),
+    /// Re-encrypt the current workspace data with its last key (e.g. after unsharing a user)
+    ///
+    /// This is not a retroactive revocation: only the last version of each file and
+    /// folder is re-encrypted. The previous versions are kept as-is on the server, so
+    /// a user who kept the old keys can still read the workspace history up to the
+    /// last key rotation.
+    Rekey(rekey::Args),
+    /// List or change the workspace entries whose file contents is not kept locally
+    ///
+    /// Files within a remote only entry (i.e. the entry itself, or all its children
+    /// for a folder) are always fetched from the server on read. This only concerns
+    /// the current device.
+    RemoteOnly(remote_only::Args),
     /// Share workspace
     Share(share::Args),
+    /// Share workspace with a group (its members get access to the workspace)
+    ShareGroup(share_group::Args),
     /// Sync workspace data with the server
     Sync(sync::Args),
+    /// Give the owner role to another user (e.g. before leaving the organization)
+    TransferOwnership(transfer_ownership::Args),
 }
 
 pub async fn dispatch_command(command: Group) -> anyhow::Result<()> {
@@ -31,7 +52,11 @@ pub async fn dispatch_command(command: Group) -> anyhow::Result<()> {
         Group::Create(args) => create::main(args).await,
         Group::List(args) => list::main(args).await,
         Group::Import(args) => import::main(args).await,
+        Group::Rekey(args) => rekey::main(args).await,
+        Group::RemoteOnly(args) => remote_only::main(args).await,
         Group::Share(args) => share::main(args).await,
+        Group::ShareGroup(args) => share_group::main(args).await,
         Group::Sync(args) => sync::main(args).await,
+        Group::TransferOwnership(args) => transfer_ownership::main(args).await,
     }
 }
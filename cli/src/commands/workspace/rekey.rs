@@ -0,0 +1,25 @@
+// Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+use crate::utils::*;
+
+crate::clap_parser_with_shared_opts_builder!(
+    #[with = config_dir, device, workspace, password_stdin]
+    pub struct Args {}
+);
+
+crate::build_main_with_client!(main, rekey_workspace);
+
+pub async fn rekey_workspace(args: Args, client: &StartedClient) -> anyhow::Result<()> {
+    let Args { workspace: wid, .. } = args;
+    log::trace!("Re-encrypting workspace {wid} with its last key");
+
+    let mut handle = start_spinner("Re-encrypting current workspace data".into());
+
+    client.rekey_workspace(wid).await?;
+
+    handle.stop_with_message("Current workspace data have been re-encrypted with the last key (previous versions are left as-is)".into());
+
+    client.stop().await;
+
+    Ok(())
+}
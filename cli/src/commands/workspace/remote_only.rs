@@ -0,0 +1,59 @@
+use libparsec::FsPath;
+
+use crate::utils::StartedClient;
+
+crate::clap_parser_with_shared_opts_builder!(
+    #[with = config_dir, device, password_stdin, workspace]
+    pub struct Args {
+        /// Mark a path as remote only (its file contents is no longer kept locally)
+        #[arg(long)]
+        add: Vec<FsPath>,
+        /// Unmark a path previously marked as remote only
+        #[arg(long)]
+        remove: Vec<FsPath>,
+    }
+);
+
+crate::build_main_with_client!(main, workspace_remote_only);
+
+pub async fn workspace_remote_only(args: Args, client: &StartedClient) -> anyhow::Result<()> {
+    let Args {
+        workspace: wid,
+        add,
+        remove,
+        ..
+    } = args;
+
+    log::trace!("workspace_remote_only: {wid} (add: {add:?}, remove: {remove:?})");
+
+    let workspace = client.start_workspace(wid).await?;
+
+    if !add.is_empty() || !remove.is_empty() {
+        let mut entries = workspace.get_remote_only_entries();
+        for path in &add {
+            let entry_id = workspace.stat_entry(path).await?.id();
+            if !entries.contains(&entry_id) {
+                entries.push(entry_id);
+            }
+        }
+        for path in &remove {
+            let entry_id = workspace.stat_entry(path).await?.id();
+            entries.retain(|x| *x != entry_id);
+        }
+        workspace.set_remote_only_entries(&entries).await?;
+    }
+
+    let entries = workspace.get_remote_only_entries();
+    if entries.is_empty() {
+        println!("No remote only entries");
+    } else {
+        for entry_id in entries {
+            println!("{}", entry_id.hex());
+        }
+    }
+
+    drop(workspace);
+    client.stop_workspace(wid).await;
+
+    Ok(())
+}
@@ -1,6 +1,6 @@
 // Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
 
-use libparsec::{RealmRole, UserID};
+use libparsec::{DateTime, RealmRole, UserID};
 
 use crate::utils::*;
 
@@ -13,6 +13,12 @@ crate::clap_parser_with_shared_opts_builder!(
         /// Role (owner/manager/contributor/reader)
         #[arg(short, long)]
         role: RealmRole,
+        /// Automatically unshare the workspace at this date (e.g: 2024-01-01T00:00:00-00:00)
+        ///
+        /// The expiration is only known by this device: the workspace is unshared the
+        /// next time this device is running and online after this date.
+        #[arg(long)]
+        expires: Option<DateTime>,
     }
 );
 
@@ -23,13 +29,16 @@ pub async fn share_workspace(args: Args, client: &StartedClient) -> anyhow::Resu
         workspace: wid,
         user,
         role,
+        expires,
         ..
     } = args;
-    log::trace!("Sharing workspace {wid} to {user} with role {role}");
+    log::trace!("Sharing workspace {wid} to {user} with role {role} (expires: {expires:?})");
 
     let mut handle = start_spinner("Sharing workspace".into());
 
-    client.share_workspace(wid, user, Some(role)).await?;
+    client
+        .share_workspace(wid, user, Some(role), expires)
+        .await?;
 
     handle.stop_with_message("Workspace has been shared".into());
 
@@ -0,0 +1,44 @@
+// Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+use libparsec::{GroupID, RealmRole};
+
+use crate::utils::*;
+
+crate::clap_parser_with_shared_opts_builder!(
+    #[with = config_dir, device, workspace, password_stdin]
+    pub struct Args {
+        /// The group ID to share the workspace with
+        #[arg(short, long, value_parser = GroupID::from_hex)]
+        group: GroupID,
+        /// Role (contributor/reader), unshare the workspace with the group if not provided
+        #[arg(short, long)]
+        role: Option<RealmRole>,
+    }
+);
+
+crate::build_main_with_client!(main, share_workspace_with_group);
+
+pub async fn share_workspace_with_group(args: Args, client: &StartedClient) -> anyhow::Result<()> {
+    let Args {
+        workspace: wid,
+        group,
+        role,
+        ..
+    } = args;
+    log::trace!("Sharing workspace {wid} to group {group} with role {role:?}");
+
+    let mut handle = start_spinner("Sharing workspace with group".into());
+
+    poll_server_for_new_certificates(client).await?;
+    client.share_workspace_with_group(wid, group, role).await?;
+
+    let message = match role {
+        Some(_) => "Workspace has been shared with the group",
+        None => "Workspace has been unshared with the group",
+    };
+    handle.stop_with_message(message.into());
+
+    client.stop().await;
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+// Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+use libparsec::UserID;
+
+use crate::utils::*;
+
+crate::clap_parser_with_shared_opts_builder!(
+    #[with = config_dir, device, workspace, password_stdin]
+    pub struct Args {
+        /// The user ID to give the owner role to
+        #[arg(short, long, value_parser = UserID::from_hex)]
+        user: UserID,
+    }
+);
+
+crate::build_main_with_client!(main, transfer_workspace_ownership);
+
+pub async fn transfer_workspace_ownership(
+    args: Args,
+    client: &StartedClient,
+) -> anyhow::Result<()> {
+    let Args {
+        workspace: wid,
+        user,
+        ..
+    } = args;
+    log::trace!("Transferring ownership of workspace {wid} to {user}");
+
+    let mut handle = start_spinner("Transferring workspace ownership".into());
+
+    client.transfer_workspace_ownership(wid, user).await?;
+
+    handle.stop_with_message("Workspace ownership has been transferred".into());
+
+    Ok(())
+}
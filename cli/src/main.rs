@@ -29,6 +29,9 @@ enum Command {
     /// Contains subcommands related to user
     #[command(subcommand)]
     User(user::Group),
+    /// Contains subcommands related to group
+    #[command(subcommand)]
+    Group(group::Group),
     /// Contains subcommands related to workspace
     #[command(subcommand)]
     Workspace(workspace::Group),
@@ -65,6 +68,7 @@ async fn main() -> anyhow::Result<()> {
         Command::Invite(invitation) => invite::dispatch_command(invitation).await,
         Command::Organization(organization) => organization::dispatch_command(organization).await,
         Command::User(user) => user::dispatch_command(user).await,
+        Command::Group(group) => group::dispatch_command(group).await,
         Command::Server(server) => server::dispatch_command(server).await,
         Command::Workspace(workspace) => workspace::dispatch_command(workspace).await,
         Command::Certificate(certificate) => certificate::dispatch_command(certificate).await,
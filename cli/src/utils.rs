@@ -6,8 +6,9 @@ use anyhow::anyhow;
 use dialoguer::FuzzySelect;
 use libparsec::{
     internal::{Client, EventBus},
-    list_available_devices, AuthenticatedCmds, AvailableDevice, AvailableDeviceType, DeviceLabel,
-    HumanHandle, LocalDevice, Password, ProxyConfig, SASCode, UserProfile,
+    list_available_devices, AuthenticatedCmds, AvailableDevice, AvailableDeviceType, ClientConfig,
+    DeviceLabel, HumanHandle, LocalDevice, Password, ProxyConfig, SASCode, SecretKey,
+    TOTPOpaqueKeyID, TotpFetchOpaqueKeyError, UserProfile,
 };
 use libparsec_client::{DeviceAccessStrategy, DevicePrimaryProtectionStrategy};
 use libparsec_platform_ipc::{
@@ -178,8 +179,8 @@ pub enum LoadAndUnlockDeviceError {
     LoadDevice(LoadDeviceError),
     /// The device file authentication is not supported
     UnsupportedAuthentication(AvailableDeviceType),
-    /// The device file uses TOTP as secondary protection, which is not supported
-    UnsupportedTOTPAuthentication,
+    /// The TOTP opaque key protecting the device file cannot be obtained from the server
+    TOTPFetchOpaqueKey(TotpFetchOpaqueKeyError),
     /// Error while unlocking the device
     UnlockDevice(libparsec::LoadDeviceError),
     /// Internal error
@@ -195,8 +196,8 @@ impl Display for LoadAndUnlockDeviceError {
             LoadAndUnlockDeviceError::UnsupportedAuthentication(ty) => {
                 write!(f, "Unsupported device file authentication `{ty:?}`")
             }
-            LoadAndUnlockDeviceError::UnsupportedTOTPAuthentication => {
-                write!(f, "Unsupported device file authentication `TOTP`")
+            LoadAndUnlockDeviceError::TOTPFetchOpaqueKey(e) => {
+                write!(f, "Cannot unlock the device TOTP protection: {e}")
             }
             LoadAndUnlockDeviceError::UnlockDevice(e) => e.fmt(f),
             LoadAndUnlockDeviceError::Internal(e) => e.fmt(f),
@@ -236,15 +237,7 @@ pub async fn load_and_unlock_device(
 
     log::debug!("Loading device {:?}", device.ty);
 
-    if device.totp_opaque_key_id.is_some() {
-        // In theory we should support this authentication method here,
-        // however:
-        // - It is cumbersome since it requires a TOTP challenge involving the server.
-        // - In practice it is a niche usage that will most likely only be used in the GUI.
-        return Err(LoadAndUnlockDeviceError::UnsupportedTOTPAuthentication);
-    }
-
-    let access_strategy = match device.ty {
+    let mut access_strategy = match device.ty {
         AvailableDeviceType::Password => {
             let password = read_password(if password_stdin {
                 ReadPasswordFrom::Stdin
@@ -272,6 +265,13 @@ pub async fn load_and_unlock_device(
             totp_protection: None,
             primary_protection: DevicePrimaryProtectionStrategy::Keyring,
         },
+        AvailableDeviceType::MachineKey {
+            machine_key_file, ..
+        } => DeviceAccessStrategy {
+            key_file: device.key_file_path.clone(),
+            totp_protection: None,
+            primary_protection: DevicePrimaryProtectionStrategy::MachineKey { machine_key_file },
+        },
         AvailableDeviceType::AccountVault => {
             // In theory we should support this authentication method here,
             // however:
@@ -287,6 +287,32 @@ pub async fn load_and_unlock_device(
                 device.ty,
             ));
         }
+        AvailableDeviceType::Multi { .. } => {
+            // In theory we could use the password protection (if any) here,
+            // however multi-protected devices cannot be created from the CLI for now.
+            return Err(LoadAndUnlockDeviceError::UnsupportedAuthentication(
+                device.ty,
+            ));
+        }
+        AvailableDeviceType::Fido2 => {
+            let pin = read_fido2_pin(if password_stdin {
+                ReadPasswordFrom::Stdin
+            } else {
+                ReadPasswordFrom::Tty {
+                    prompt: "Enter PIN for the FIDO2 authenticator (leave empty if none):",
+                }
+            })?;
+
+            println!("Touch your FIDO2 authenticator to unlock the device");
+
+            DeviceAccessStrategy {
+                key_file: device.key_file_path.clone(),
+                totp_protection: None,
+                primary_protection: DevicePrimaryProtectionStrategy::Fido2 {
+                    operations: Arc::new(libparsec::NativeFido2Operations::new(pin)),
+                },
+            }
+        }
         AvailableDeviceType::OpenBao { .. } => {
             // In theory we should support this authentication method here,
             // however:
@@ -299,11 +325,54 @@ pub async fn load_and_unlock_device(
         }
     };
 
+    // TOTP is a secondary protection, so the one-time password is asked after
+    // the primary protection's password (if any)
+    access_strategy.totp_protection =
+        unlock_totp_protection(config_dir, &device, password_stdin).await?;
+
     let device = libparsec::load_device(config_dir, &access_strategy).await?;
 
     Ok(device)
 }
 
+/// Run the TOTP challenge against the server to obtain the opaque key protecting
+/// the device file, returns `None` if the device is not protected by TOTP.
+pub async fn unlock_totp_protection(
+    config_dir: &Path,
+    device: &AvailableDevice,
+    password_stdin: bool,
+) -> Result<Option<(TOTPOpaqueKeyID, SecretKey)>, LoadAndUnlockDeviceError> {
+    let opaque_key_id = match device.totp_opaque_key_id {
+        Some(opaque_key_id) => opaque_key_id,
+        None => return Ok(None),
+    };
+
+    let one_time_password = read_one_time_password(if password_stdin {
+        ReadPasswordFrom::Stdin
+    } else {
+        ReadPasswordFrom::Tty {
+            prompt: "Enter the one-time password from your authenticator app",
+        }
+    })?;
+
+    let config = ClientConfig {
+        config_dir: config_dir.to_owned(),
+        ..Default::default()
+    };
+    let opaque_key = libparsec::totp_fetch_opaque_key(
+        config,
+        device.server_addr.clone(),
+        device.organization_id.clone(),
+        device.user_id,
+        opaque_key_id,
+        one_time_password,
+    )
+    .await
+    .map_err(LoadAndUnlockDeviceError::TOTPFetchOpaqueKey)?;
+
+    Ok(Some((opaque_key_id, opaque_key)))
+}
+
 pub async fn load_cmds(
     config_dir: &Path,
     device: Option<String>,
@@ -506,6 +575,28 @@ pub fn read_password(read_from: ReadPasswordFrom) -> anyhow::Result<libparsec::P
     .map_err(anyhow::Error::from)
 }
 
+/// The PIN is optional (not all FIDO2 authenticators are protected by one),
+/// hence an empty input means no PIN.
+pub fn read_fido2_pin(read_from: ReadPasswordFrom) -> anyhow::Result<Option<libparsec::Password>> {
+    let pin = read_password(read_from)?;
+    Ok((!pin.is_empty()).then_some(pin))
+}
+
+pub fn read_one_time_password(read_from: ReadPasswordFrom) -> anyhow::Result<String> {
+    let one_time_password = match read_from {
+        ReadPasswordFrom::Stdin => {
+            let mut input = String::new();
+            std::io::stdin().read_line(&mut input)?;
+            input
+        }
+        ReadPasswordFrom::Tty { prompt } => dialoguer::Input::<String>::new()
+            .with_prompt(prompt)
+            .interact_text()?,
+    };
+
+    Ok(one_time_password.trim().to_owned())
+}
+
 pub fn maybe_plural(number: &u8) -> &str {
     if *number == 1 {
         ""
@@ -0,0 +1,59 @@
+use libparsec::{tmp_path, TmpPath};
+
+use crate::{
+    bootstrap_cli_test,
+    testenv_utils::{TestOrganization, DEFAULT_DEVICE_PASSWORD},
+};
+
+#[rstest::rstest]
+#[tokio::test]
+async fn audit_csv(tmp_path: TmpPath) {
+    let (_, TestOrganization { alice, bob, .. }, _) = bootstrap_cli_test(&tmp_path).await.unwrap();
+
+    crate::assert_cmd_success!(
+        with_password = DEFAULT_DEVICE_PASSWORD,
+        "certificate",
+        "audit",
+        "--device",
+        &alice.device_id.hex(),
+        "--format",
+        "csv"
+    )
+    .stdout(predicates::str::starts_with(
+        "timestamp,author_device_id,author_email,event,user_id,user_email,realm_id,realm_name,details",
+    ))
+    .stdout(predicates::str::contains(format!(
+        "user_created,{},{},profile=STANDARD",
+        bob.user_id.hex(),
+        bob.human_handle.email()
+    )));
+}
+
+#[rstest::rstest]
+#[tokio::test]
+async fn audit_json_filter_by_user(tmp_path: TmpPath) {
+    let (_, TestOrganization { alice, bob, .. }, _) = bootstrap_cli_test(&tmp_path).await.unwrap();
+
+    let output = crate::assert_cmd_success!(
+        with_password = DEFAULT_DEVICE_PASSWORD,
+        "certificate",
+        "audit",
+        "--device",
+        &alice.device_id.hex(),
+        "--user",
+        &bob.human_handle.email().to_string()
+    )
+    .get_output()
+    .stdout
+    .clone();
+
+    let entries: Vec<serde_json::Value> = serde_json::from_slice(&output).unwrap();
+    assert!(!entries.is_empty());
+    let bob_user_id = bob.user_id.hex();
+    for entry in entries {
+        assert!(
+            entry["user_id"] == bob_user_id
+                || entry["author_email"] == bob.human_handle.email().to_string()
+        );
+    }
+}
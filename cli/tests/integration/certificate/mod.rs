@@ -1 +1,2 @@
+mod audit;
 mod poll_and_forget;
@@ -0,0 +1,13 @@
+use libparsec::{tmp_path, TmpPath};
+
+use crate::bootstrap_cli_test;
+
+#[rstest::rstest]
+#[tokio::test]
+async fn check_security(tmp_path: TmpPath) {
+    bootstrap_cli_test(&tmp_path).await.unwrap();
+
+    // Devices created by the CLI use the current password algorithm defaults
+    crate::assert_cmd_success!("device", "check-security")
+        .stdout(predicates::str::contains("No weak protection found"));
+}
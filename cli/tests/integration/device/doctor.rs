@@ -0,0 +1,65 @@
+use libparsec::{tmp_path, TmpPath};
+
+use crate::{bootstrap_cli_test, testenv_utils::TestOrganization};
+
+#[rstest::rstest]
+#[tokio::test]
+async fn check_and_repair(tmp_path: TmpPath) {
+    let (_, TestOrganization { alice, .. }, _) = bootstrap_cli_test(&tmp_path).await.unwrap();
+
+    crate::assert_cmd_success!("device", "doctor", "check", "--offline")
+        .stdout(predicates::str::contains("No issue found"));
+
+    // Simulate a device file save interrupted before the rename
+    let devices_dir = tmp_path.join("config/parsec3/libparsec/devices");
+    let leftover = devices_dir.join(format!("{}.keys.tmp", alice.device_id.hex()));
+    std::fs::write(&leftover, b"").unwrap();
+
+    crate::assert_cmd_success!("device", "doctor", "check", "--offline").stdout(
+        predicates::str::contains("Leftover of an interrupted device file save"),
+    );
+
+    crate::assert_cmd_success!("device", "doctor", "repair")
+        .stdout(predicates::str::contains("Removed leftover file"));
+    assert!(!leftover.exists());
+
+    crate::assert_cmd_success!("device", "doctor", "repair")
+        .stdout(predicates::str::contains("Nothing to repair"));
+}
+
+#[rstest::rstest]
+#[tokio::test]
+async fn export_import_backup(tmp_path: TmpPath) {
+    let (_, TestOrganization { alice, .. }, _) = bootstrap_cli_test(&tmp_path).await.unwrap();
+
+    let backup = tmp_path.join("devices.backup");
+    let alice_device_file = tmp_path
+        .join("config/parsec3/libparsec/devices")
+        .join(format!("{}.keys", alice.device_id.hex()));
+
+    crate::assert_cmd_success!(
+        with_password = "P@ssw0rd.",
+        "device",
+        "doctor",
+        "export-backup",
+        &backup.to_string_lossy()
+    )
+    .stdout(predicates::str::contains("device file(s) saved at"));
+    assert!(backup.exists());
+
+    std::fs::remove_file(&alice_device_file).unwrap();
+
+    crate::assert_cmd_success!(
+        with_password = "P@ssw0rd.",
+        "device",
+        "doctor",
+        "import-backup",
+        &backup.to_string_lossy()
+    )
+    .stdout(predicates::str::contains(format!(
+        "Restored {}",
+        alice_device_file.display()
+    )))
+    .stdout(predicates::str::contains("Already present"));
+    assert!(alice_device_file.exists());
+}
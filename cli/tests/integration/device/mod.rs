@@ -1,6 +1,10 @@
 mod change_authentication;
+mod check_security;
+mod doctor;
 mod export_recovery_device;
 mod forget_local;
 mod import_recovery_device;
 mod list;
 mod overwrite_server_url;
+mod revoke;
+mod totp;
@@ -0,0 +1,49 @@
+use libparsec::{tmp_path, TmpPath};
+
+use crate::{
+    bootstrap_cli_test,
+    testenv_utils::{TestOrganization, DEFAULT_DEVICE_PASSWORD},
+};
+
+#[rstest::rstest]
+#[tokio::test]
+async fn revoke_device_ok(tmp_path: TmpPath) {
+    let (
+        _,
+        TestOrganization {
+            alice, other_alice, ..
+        },
+        _,
+    ) = bootstrap_cli_test(&tmp_path).await.unwrap();
+
+    crate::assert_cmd_success!(
+        with_password = DEFAULT_DEVICE_PASSWORD,
+        "device",
+        "revoke",
+        "--device",
+        &alice.device_id.hex(),
+        &other_alice.device_id.hex()
+    )
+    .stdout(predicates::str::contains(format!(
+        "Device {} has been revoked",
+        other_alice.device_id.hex()
+    )));
+}
+
+#[rstest::rstest]
+#[tokio::test]
+async fn revoke_device_self(tmp_path: TmpPath) {
+    let (_, TestOrganization { alice, .. }, _) = bootstrap_cli_test(&tmp_path).await.unwrap();
+
+    crate::assert_cmd_failure!(
+        with_password = DEFAULT_DEVICE_PASSWORD,
+        "device",
+        "revoke",
+        "--device",
+        &alice.device_id.hex(),
+        &alice.device_id.hex()
+    )
+    .stderr(predicates::str::contains(
+        "Error: Cannot revoke the device currently in use",
+    ));
+}
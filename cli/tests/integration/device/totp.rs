@@ -0,0 +1,169 @@
+use std::io::{BufReader, Write};
+
+use hmac::{Hmac, Mac};
+use libparsec::{tmp_path, TmpPath};
+use parsec_cli::utils::{GREEN, RESET, YELLOW};
+
+use crate::{
+    bootstrap_cli_test,
+    testenv_utils::{TestOrganization, DEFAULT_DEVICE_PASSWORD},
+    wait_for,
+};
+
+/// RFC 6238 with the defaults used by the server (SHA1, 30s time step, 6 digits)
+fn compute_one_time_password(base32_totp_secret: &str) -> String {
+    let secret = data_encoding::BASE32_NOPAD
+        .decode(base32_totp_secret.as_bytes())
+        .unwrap();
+    let counter = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+        / 30;
+
+    let mut mac = Hmac::<sha1::Sha1>::new_from_slice(&secret).unwrap();
+    mac.update(&counter.to_be_bytes());
+    let digest = mac.finalize().into_bytes();
+
+    let pointer = (digest[digest.len() - 1] & 0x0F) as usize;
+    let code = u32::from_be_bytes(digest[pointer..pointer + 4].try_into().unwrap()) & 0x7FFF_FFFF;
+    format!("{:06}", code % 1_000_000)
+}
+
+#[rstest::rstest]
+#[tokio::test]
+async fn status_not_set_up(tmp_path: TmpPath) {
+    let (_, TestOrganization { alice, .. }, _) = bootstrap_cli_test(&tmp_path).await.unwrap();
+
+    crate::assert_cmd_success!(
+        with_password = DEFAULT_DEVICE_PASSWORD,
+        "device",
+        "totp",
+        "status",
+        "--device",
+        &alice.device_id.hex()
+    )
+    .stdout(predicates::str::contains(format!(
+        "TOTP {YELLOW}not set up{RESET}"
+    )))
+    .stdout(predicates::str::contains(format!(
+        "Device {YELLOW}not protected{RESET} by TOTP"
+    )));
+}
+
+#[rstest::rstest]
+#[tokio::test]
+async fn setup(tmp_path: TmpPath) {
+    let (_, TestOrganization { alice, .. }, _) = bootstrap_cli_test(&tmp_path).await.unwrap();
+
+    let mut process = crate::std_cmd!(
+        "device",
+        "totp",
+        "setup",
+        "--device",
+        &alice.device_id.hex(),
+        "--password-stdin"
+    )
+    .stdin(std::process::Stdio::piped())
+    .stderr(std::process::Stdio::inherit())
+    .stdout(std::process::Stdio::piped())
+    .spawn()
+    .unwrap();
+
+    let mut stdout = BufReader::new(process.stdout.as_mut().unwrap());
+    let stdin = process.stdin.as_mut().unwrap();
+
+    stdin.write_all(DEFAULT_DEVICE_PASSWORD.as_bytes()).unwrap();
+    stdin.write_all(b"\n").unwrap();
+
+    let mut buf = String::new();
+    wait_for(&mut stdout, &mut buf, "otpauth://totp/");
+    let base32_totp_secret = buf
+        .split_once("secret=")
+        .and_then(|(_, remaining)| remaining.split_once('&'))
+        .map(|(secret, _)| secret.to_owned())
+        .unwrap();
+
+    stdin
+        .write_all(compute_one_time_password(&base32_totp_secret).as_bytes())
+        .unwrap();
+    stdin.write_all(b"\n").unwrap();
+
+    wait_for(&mut stdout, &mut buf, "Device is now protected by TOTP");
+    assert!(process.wait().unwrap().success());
+
+    // Unlocking the device now requires a one-time password
+
+    crate::assert_cmd!(
+        "device",
+        "totp",
+        "status",
+        "--device",
+        &alice.device_id.hex(),
+        "--password-stdin"
+    )
+    .write_stdin(format!(
+        "{DEFAULT_DEVICE_PASSWORD}\n{}\n",
+        compute_one_time_password(&base32_totp_secret)
+    ))
+    .assert()
+    .success()
+    .stdout(predicates::str::contains(format!(
+        "TOTP {GREEN}set up{RESET}"
+    )))
+    .stdout(predicates::str::contains(format!(
+        "Device {GREEN}protected{RESET} by TOTP"
+    )));
+
+    // Changing the primary protection keeps the TOTP protection
+
+    const NEW_DEVICE_PASSWORD: &str = "S3cr3t";
+    crate::assert_cmd!(
+        "device",
+        "change-authentication",
+        "--device",
+        &alice.device_id.hex(),
+        "--password",
+        "--password-stdin"
+    )
+    .write_stdin(format!(
+        "{DEFAULT_DEVICE_PASSWORD}\n{}\n{NEW_DEVICE_PASSWORD}\n",
+        compute_one_time_password(&base32_totp_secret)
+    ))
+    .assert()
+    .success()
+    .stdout(predicates::str::contains(
+        "Device authentication changed successfully",
+    ));
+
+    crate::assert_cmd!(
+        "workspace",
+        "list",
+        "--device",
+        &alice.device_id.hex(),
+        "--password-stdin"
+    )
+    .write_stdin(format!(
+        "{NEW_DEVICE_PASSWORD}\n{}\n",
+        compute_one_time_password(&base32_totp_secret)
+    ))
+    .assert()
+    .success();
+
+    // Invalid one-time password is rejected (done last since failed attempts
+    // cause the server to throttle the following TOTP challenges)
+
+    crate::assert_cmd!(
+        "workspace",
+        "list",
+        "--device",
+        &alice.device_id.hex(),
+        "--password-stdin"
+    )
+    .write_stdin(format!("{NEW_DEVICE_PASSWORD}\nnot-a-code\n"))
+    .assert()
+    .failure()
+    .stderr(predicates::str::contains(
+        "The one-time password is invalid",
+    ));
+}
@@ -0,0 +1,50 @@
+use libparsec::{tmp_path, TmpPath};
+
+use crate::{
+    bootstrap_cli_test,
+    testenv_utils::{TestOrganization, DEFAULT_DEVICE_PASSWORD},
+};
+use parsec_cli::utils::start_client;
+
+#[rstest::rstest]
+#[tokio::test]
+async fn create_group(tmp_path: TmpPath) {
+    let (_, TestOrganization { alice, .. }, _) = bootstrap_cli_test(&tmp_path).await.unwrap();
+
+    crate::assert_cmd_success!(
+        with_password = DEFAULT_DEVICE_PASSWORD,
+        "group",
+        "create",
+        "--device",
+        &alice.device_id.hex(),
+        "Engineering"
+    )
+    .stdout(predicates::str::contains("Group has been created with id"));
+
+    let alice_client = start_client(alice).await.unwrap();
+    alice_client
+        .poll_server_for_new_certificates()
+        .await
+        .unwrap();
+    let groups = alice_client.list_groups().await.unwrap();
+    alice_client.stop().await;
+
+    assert_eq!(groups.len(), 1);
+    assert_eq!(groups[0].name, "Engineering");
+    assert!(groups[0].members.is_empty());
+}
+
+#[rstest::rstest]
+#[tokio::test]
+async fn create_group_not_admin(tmp_path: TmpPath) {
+    let (_, TestOrganization { bob, .. }, _) = bootstrap_cli_test(&tmp_path).await.unwrap();
+
+    crate::assert_cmd_failure!(
+        with_password = DEFAULT_DEVICE_PASSWORD,
+        "group",
+        "create",
+        "--device",
+        &bob.device_id.hex(),
+        "Engineering"
+    );
+}
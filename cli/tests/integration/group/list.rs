@@ -0,0 +1,51 @@
+use libparsec::{tmp_path, TmpPath};
+use predicates::prelude::PredicateBooleanExt;
+
+use crate::{
+    bootstrap_cli_test,
+    testenv_utils::{TestOrganization, DEFAULT_DEVICE_PASSWORD},
+};
+use parsec_cli::utils::{start_client, GREEN, RESET};
+
+#[rstest::rstest]
+#[tokio::test]
+async fn list_groups(tmp_path: TmpPath) {
+    let (_, TestOrganization { alice, bob, .. }, _) = bootstrap_cli_test(&tmp_path).await.unwrap();
+
+    crate::assert_cmd_success!(
+        with_password = DEFAULT_DEVICE_PASSWORD,
+        "group",
+        "list",
+        "--device",
+        &alice.device_id.hex()
+    )
+    .stdout(predicates::str::contains("No groups found"));
+
+    let group_id = {
+        let alice_client = start_client(alice.clone()).await.unwrap();
+        let group_id = alice_client
+            .create_group("Engineering".to_owned())
+            .await
+            .unwrap();
+        alice_client
+            .add_group_member(group_id, bob.user_id)
+            .await
+            .unwrap();
+        alice_client.stop().await;
+        group_id
+    };
+
+    crate::assert_cmd_success!(
+        with_password = DEFAULT_DEVICE_PASSWORD,
+        "group",
+        "list",
+        "--device",
+        &alice.device_id.hex()
+    )
+    .stdout(
+        predicates::str::contains(format!("Found {GREEN}1{RESET} group(s)"))
+            .and(predicates::str::contains(group_id.hex()))
+            .and(predicates::str::contains("Engineering"))
+            .and(predicates::str::contains(bob.user_id.to_string())),
+    );
+}
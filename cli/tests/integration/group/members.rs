@@ -0,0 +1,69 @@
+use libparsec::{tmp_path, TmpPath};
+
+use crate::{
+    bootstrap_cli_test,
+    testenv_utils::{TestOrganization, DEFAULT_DEVICE_PASSWORD},
+};
+use parsec_cli::utils::start_client;
+
+#[rstest::rstest]
+#[tokio::test]
+async fn add_and_remove_member(tmp_path: TmpPath) {
+    let (_, TestOrganization { alice, bob, .. }, _) = bootstrap_cli_test(&tmp_path).await.unwrap();
+
+    let group_id = {
+        let alice_client = start_client(alice.clone()).await.unwrap();
+        let group_id = alice_client
+            .create_group("Engineering".to_owned())
+            .await
+            .unwrap();
+        alice_client.stop().await;
+        group_id
+    };
+
+    crate::assert_cmd_success!(
+        with_password = DEFAULT_DEVICE_PASSWORD,
+        "group",
+        "add-member",
+        "--device",
+        &alice.device_id.hex(),
+        "--group",
+        &group_id.hex(),
+        "--user",
+        &bob.user_id.hex()
+    )
+    .stdout(predicates::str::contains(
+        "User has been added to the group",
+    ));
+
+    let members = {
+        let alice_client = start_client(alice.clone()).await.unwrap();
+        let groups = alice_client.list_groups().await.unwrap();
+        alice_client.stop().await;
+        groups.into_iter().next().unwrap().members
+    };
+    assert_eq!(members, [bob.user_id]);
+
+    crate::assert_cmd_success!(
+        with_password = DEFAULT_DEVICE_PASSWORD,
+        "group",
+        "remove-member",
+        "--device",
+        &alice.device_id.hex(),
+        "--group",
+        &group_id.hex(),
+        "--user",
+        &bob.user_id.hex()
+    )
+    .stdout(predicates::str::contains(
+        "User has been removed from the group",
+    ));
+
+    let members = {
+        let alice_client = start_client(alice).await.unwrap();
+        let groups = alice_client.list_groups().await.unwrap();
+        alice_client.stop().await;
+        groups.into_iter().next().unwrap().members
+    };
+    assert!(members.is_empty());
+}
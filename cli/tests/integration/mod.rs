@@ -3,6 +3,7 @@
 mod certificate;
 mod device;
 mod device_option;
+mod group;
 mod invitations;
 mod ls;
 mod mount_realm_export;
@@ -1,9 +1,10 @@
-use libparsec::{tmp_path, TmpPath};
+use libparsec::{tmp_path, RealmRole, TmpPath};
 
 use crate::{
     bootstrap_cli_test,
     testenv_utils::{TestOrganization, DEFAULT_DEVICE_PASSWORD},
 };
+use parsec_cli::utils::start_client;
 
 #[rstest::rstest]
 #[tokio::test]
@@ -39,3 +40,48 @@ async fn revoke_user_not_found(tmp_path: TmpPath) {
     )
     .stderr(predicates::str::contains("Error: User not found"));
 }
+
+#[rstest::rstest]
+#[tokio::test]
+async fn revoke_user_sole_owner_of_workspaces(tmp_path: TmpPath) {
+    let (_, TestOrganization { alice, bob, .. }, _) = bootstrap_cli_test(&tmp_path).await.unwrap();
+
+    {
+        let bob_client = start_client(bob.clone()).await.unwrap();
+
+        let wid = bob_client
+            .create_workspace("new-workspace".parse().unwrap())
+            .await
+            .unwrap();
+        bob_client.ensure_workspaces_bootstrapped().await.unwrap();
+        bob_client
+            .share_workspace(wid, alice.user_id, Some(RealmRole::Reader), None)
+            .await
+            .unwrap();
+        bob_client.stop().await;
+    }
+
+    crate::assert_cmd_failure!(
+        with_password = DEFAULT_DEVICE_PASSWORD,
+        "user",
+        "revoke",
+        "--device",
+        &alice.device_id.hex(),
+        &bob.human_handle.email().to_string()
+    )
+    .stderr(predicates::str::contains("is the only owner of workspaces"));
+
+    crate::assert_cmd_success!(
+        with_password = DEFAULT_DEVICE_PASSWORD,
+        "user",
+        "revoke",
+        "--device",
+        &alice.device_id.hex(),
+        &bob.human_handle.email().to_string(),
+        "--allow-orphaned-workspaces"
+    )
+    .stdout(predicates::str::contains(format!(
+        "User {} has been revoked",
+        bob.human_handle.email()
+    )));
+}
@@ -24,7 +24,7 @@ async fn workspace_import_file(tmp_path: TmpPath) {
             .unwrap();
         alice_client.ensure_workspaces_bootstrapped().await.unwrap();
         alice_client
-            .share_workspace(wid, bob.user_id, Some(libparsec::RealmRole::Reader))
+            .share_workspace(wid, bob.user_id, Some(libparsec::RealmRole::Reader), None)
             .await
             .unwrap();
 
@@ -93,7 +93,7 @@ async fn workspace_import_file_to_specific_path(tmp_path: TmpPath) {
             .unwrap();
         alice_client.ensure_workspaces_bootstrapped().await.unwrap();
         alice_client
-            .share_workspace(wid, bob.user_id, Some(libparsec::RealmRole::Reader))
+            .share_workspace(wid, bob.user_id, Some(libparsec::RealmRole::Reader), None)
             .await
             .unwrap();
 
@@ -268,7 +268,7 @@ async fn workspace_import_folder(tmp_path: TmpPath) {
             .unwrap();
         alice_client.ensure_workspaces_bootstrapped().await.unwrap();
         alice_client
-            .share_workspace(wid, bob.user_id, Some(libparsec::RealmRole::Reader))
+            .share_workspace(wid, bob.user_id, Some(libparsec::RealmRole::Reader), None)
             .await
             .unwrap();
 
@@ -358,7 +358,7 @@ async fn import_prevent_overwrite(tmp_path: TmpPath) {
             .unwrap();
         alice_client.ensure_workspaces_bootstrapped().await.unwrap();
         alice_client
-            .share_workspace(wid, bob.user_id, Some(libparsec::RealmRole::Reader))
+            .share_workspace(wid, bob.user_id, Some(libparsec::RealmRole::Reader), None)
             .await
             .unwrap();
 
@@ -25,12 +25,12 @@ async fn list_users(tmp_path: TmpPath) {
         .await
         .unwrap();
     client
-        .share_workspace(wid, toto.user_id, Some(RealmRole::Reader))
+        .share_workspace(wid, toto.user_id, Some(RealmRole::Reader), None)
         .await
         .unwrap();
 
     client
-        .share_workspace(wid, bob.user_id, Some(RealmRole::Manager))
+        .share_workspace(wid, bob.user_id, Some(RealmRole::Manager), None)
         .await
         .unwrap();
     client.stop().await;
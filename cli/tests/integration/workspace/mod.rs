@@ -2,5 +2,9 @@ mod archive;
 mod create;
 mod import;
 mod list_users;
+mod rekey;
+mod remote_only;
 mod share;
+mod share_group;
 mod sync;
+mod transfer_ownership;
@@ -0,0 +1,57 @@
+use libparsec::{tmp_path, RealmRole, TmpPath};
+
+use crate::{
+    bootstrap_cli_test,
+    testenv_utils::{TestOrganization, DEFAULT_DEVICE_PASSWORD},
+};
+use parsec_cli::utils::start_client;
+
+#[rstest::rstest]
+#[tokio::test]
+async fn rekey_workspace(tmp_path: TmpPath) {
+    let (_, TestOrganization { alice, bob, .. }, _) = bootstrap_cli_test(&tmp_path).await.unwrap();
+
+    let wid = {
+        let alice_client = start_client(alice.clone()).await.unwrap();
+
+        let wid = alice_client
+            .create_workspace("new-workspace".parse().unwrap())
+            .await
+            .unwrap();
+        alice_client.ensure_workspaces_bootstrapped().await.unwrap();
+        alice_client
+            .share_workspace(wid, bob.user_id, Some(RealmRole::Reader), None)
+            .await
+            .unwrap();
+        alice_client.stop().await;
+
+        wid
+    };
+
+    crate::assert_cmd_success!(
+        with_password = DEFAULT_DEVICE_PASSWORD,
+        "workspace",
+        "rekey",
+        "--device",
+        &alice.device_id.hex(),
+        "--workspace",
+        &wid.hex()
+    )
+    .stdout(predicates::str::contains(
+        "Current workspace data have been re-encrypted with the last key (previous versions are left as-is)",
+    ));
+
+    // Only owners can re-encrypt the workspace
+    crate::assert_cmd_failure!(
+        with_password = DEFAULT_DEVICE_PASSWORD,
+        "workspace",
+        "rekey",
+        "--device",
+        &bob.device_id.hex(),
+        "--workspace",
+        &wid.hex()
+    )
+    .stderr(predicates::str::contains(
+        "Error: Only an owner of the realm can re-encrypt its data",
+    ));
+}
@@ -0,0 +1,76 @@
+use libparsec::{tmp_path, TmpPath};
+
+use crate::{
+    bootstrap_cli_test,
+    testenv_utils::{TestOrganization, DEFAULT_DEVICE_PASSWORD},
+};
+use parsec_cli::utils::start_client;
+
+#[rstest::rstest]
+#[tokio::test]
+async fn remote_only(tmp_path: TmpPath) {
+    let (_, TestOrganization { alice, .. }, _) = bootstrap_cli_test(&tmp_path).await.unwrap();
+
+    let (wid, foo_id) = {
+        let client = start_client(alice.clone()).await.unwrap();
+
+        let wid = client
+            .create_workspace("new-workspace".parse().unwrap())
+            .await
+            .unwrap();
+        client.ensure_workspaces_bootstrapped().await.unwrap();
+
+        let workspace = client.start_workspace(wid).await.unwrap();
+        let foo_id = workspace
+            .create_folder("/foo".parse().unwrap())
+            .await
+            .unwrap();
+        client.stop().await;
+
+        (wid, foo_id)
+    };
+
+    crate::assert_cmd_success!(
+        with_password = DEFAULT_DEVICE_PASSWORD,
+        "workspace",
+        "remote-only",
+        "--device",
+        &alice.device_id.hex(),
+        "--workspace",
+        &wid.hex()
+    )
+    .stdout(predicates::str::contains("No remote only entries"));
+
+    crate::assert_cmd_success!(
+        with_password = DEFAULT_DEVICE_PASSWORD,
+        "workspace",
+        "remote-only",
+        "--device",
+        &alice.device_id.hex(),
+        "--workspace",
+        &wid.hex(),
+        "--add",
+        "/foo"
+    )
+    .stdout(predicates::str::contains(foo_id.hex()));
+
+    {
+        let client = start_client(alice.clone()).await.unwrap();
+        let workspace = client.start_workspace(wid).await.unwrap();
+        assert_eq!(workspace.get_remote_only_entries(), vec![foo_id]);
+        client.stop().await;
+    }
+
+    crate::assert_cmd_success!(
+        with_password = DEFAULT_DEVICE_PASSWORD,
+        "workspace",
+        "remote-only",
+        "--device",
+        &alice.device_id.hex(),
+        "--workspace",
+        &wid.hex(),
+        "--remove",
+        "/foo"
+    )
+    .stdout(predicates::str::contains("No remote only entries"));
+}
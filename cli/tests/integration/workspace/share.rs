@@ -64,3 +64,52 @@ async fn share_workspace(tmp_path: TmpPath) {
         "Missing shared workspace for bob, {workspaces:?}"
     );
 }
+
+#[rstest::rstest]
+#[tokio::test]
+async fn share_workspace_with_expiration(tmp_path: TmpPath) {
+    let (_, TestOrganization { alice, bob, .. }, _) = bootstrap_cli_test(&tmp_path).await.unwrap();
+
+    let wid = {
+        let alice_client = start_client(alice.clone()).await.unwrap();
+
+        let wid = alice_client
+            .create_workspace("new-workspace".parse().unwrap())
+            .await
+            .unwrap();
+
+        alice_client.ensure_workspaces_bootstrapped().await.unwrap();
+        alice_client.stop().await;
+
+        wid
+    };
+
+    crate::assert_cmd_success!(
+        with_password = DEFAULT_DEVICE_PASSWORD,
+        "workspace",
+        "share",
+        "--device",
+        &alice.device_id.hex(),
+        "--workspace",
+        &wid.hex(),
+        "--user",
+        &bob.user_id.hex(),
+        "--role",
+        "reader",
+        "--expires",
+        "2100-01-01T00:00:00Z"
+    )
+    .stdout(predicates::str::contains("Workspace has been shared"));
+
+    log::debug!("The expiration is known by the device that has shared the workspace");
+    let alice_client = start_client(alice).await.unwrap();
+    let users = alice_client.list_workspace_users(wid).await.unwrap();
+    alice_client.stop().await;
+
+    let bob_access = users.iter().find(|u| u.user_id == bob.user_id).unwrap();
+    assert_eq!(bob_access.current_role, RealmRole::Reader);
+    assert_eq!(
+        bob_access.expires_on,
+        Some("2100-01-01T00:00:00Z".parse().unwrap())
+    );
+}
@@ -0,0 +1,80 @@
+use libparsec::{tmp_path, RealmRole, TmpPath};
+
+use crate::{
+    bootstrap_cli_test,
+    testenv_utils::{TestOrganization, DEFAULT_DEVICE_PASSWORD},
+};
+use parsec_cli::utils::start_client;
+
+#[rstest::rstest]
+#[tokio::test]
+async fn share_workspace_with_group(tmp_path: TmpPath) {
+    let (_, TestOrganization { alice, toto, .. }, _) = bootstrap_cli_test(&tmp_path).await.unwrap();
+
+    let (wid, group_id) = {
+        let alice_client = start_client(alice.clone()).await.unwrap();
+
+        let wid = alice_client
+            .create_workspace("new-workspace".parse().unwrap())
+            .await
+            .unwrap();
+        alice_client.ensure_workspaces_bootstrapped().await.unwrap();
+
+        let group_id = alice_client
+            .create_group("Engineering".to_owned())
+            .await
+            .unwrap();
+        alice_client
+            .add_group_member(group_id, toto.user_id)
+            .await
+            .unwrap();
+
+        alice_client.stop().await;
+
+        (wid, group_id)
+    };
+
+    crate::assert_cmd_success!(
+        with_password = DEFAULT_DEVICE_PASSWORD,
+        "workspace",
+        "share-group",
+        "--device",
+        &alice.device_id.hex(),
+        "--workspace",
+        &wid.hex(),
+        "--group",
+        &group_id.hex(),
+        "--role",
+        "contributor"
+    )
+    .stdout(predicates::str::contains(
+        "Workspace has been shared with the group",
+    ));
+
+    let alice_client = start_client(alice.clone()).await.unwrap();
+    let groups = alice_client.list_workspace_groups(wid).await.unwrap();
+    alice_client.stop().await;
+    assert_eq!(groups.len(), 1);
+    assert_eq!(groups[0].group_id, group_id);
+    assert_eq!(groups[0].current_role, RealmRole::Contributor);
+
+    crate::assert_cmd_success!(
+        with_password = DEFAULT_DEVICE_PASSWORD,
+        "workspace",
+        "share-group",
+        "--device",
+        &alice.device_id.hex(),
+        "--workspace",
+        &wid.hex(),
+        "--group",
+        &group_id.hex()
+    )
+    .stdout(predicates::str::contains(
+        "Workspace has been unshared with the group",
+    ));
+
+    let alice_client = start_client(alice).await.unwrap();
+    let groups = alice_client.list_workspace_groups(wid).await.unwrap();
+    alice_client.stop().await;
+    assert!(groups.is_empty());
+}
@@ -27,7 +27,12 @@ async fn setup_workspace(alice: Arc<LocalDevice>, bob: Arc<LocalDevice>) -> Setu
     alice_client.ensure_workspaces_bootstrapped().await.unwrap();
     log::debug!("Share the workspace with bob as a contributor");
     alice_client
-        .share_workspace(wid, bob.user_id, Some(libparsec::RealmRole::Contributor))
+        .share_workspace(
+            wid,
+            bob.user_id,
+            Some(libparsec::RealmRole::Contributor),
+            None,
+        )
         .await
         .unwrap();
 
@@ -0,0 +1,57 @@
+use libparsec::{tmp_path, RealmRole, TmpPath};
+
+use crate::{
+    bootstrap_cli_test,
+    testenv_utils::{TestOrganization, DEFAULT_DEVICE_PASSWORD},
+};
+use parsec_cli::utils::start_client;
+
+#[rstest::rstest]
+#[tokio::test]
+async fn transfer_workspace_ownership(tmp_path: TmpPath) {
+    let (_, TestOrganization { alice, bob, .. }, _) = bootstrap_cli_test(&tmp_path).await.unwrap();
+
+    let wid = {
+        let alice_client = start_client(alice.clone()).await.unwrap();
+
+        let wid = alice_client
+            .create_workspace("new-workspace".parse().unwrap())
+            .await
+            .unwrap();
+        alice_client.ensure_workspaces_bootstrapped().await.unwrap();
+        alice_client
+            .share_workspace(wid, bob.user_id, Some(RealmRole::Reader), None)
+            .await
+            .unwrap();
+        alice_client.stop().await;
+
+        wid
+    };
+
+    crate::assert_cmd_success!(
+        with_password = DEFAULT_DEVICE_PASSWORD,
+        "workspace",
+        "transfer-ownership",
+        "--device",
+        &alice.device_id.hex(),
+        "--workspace",
+        &wid.hex(),
+        "--user",
+        &bob.user_id.hex()
+    )
+    .stdout(predicates::str::contains(
+        "Workspace ownership has been transferred",
+    ));
+
+    let bob_client = start_client(bob).await.unwrap();
+    bob_client.poll_server_for_new_certificates().await.unwrap();
+    bob_client.refresh_workspaces_list().await.unwrap();
+    let workspaces = bob_client.list_workspaces().await;
+
+    assert!(
+        workspaces
+            .iter()
+            .any(|w| w.id == wid && w.self_role == RealmRole::Owner),
+        "Bob should be owner of the workspace, {workspaces:?}"
+    );
+}
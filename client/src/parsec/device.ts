@@ -116,7 +116,10 @@ export async function createDeviceInvitation(sendEmail: boolean): Promise<Result
 export function getPrimaryProtectionTypeForDeviceType(deviceType: AvailableDeviceTypeTag): DevicePrimaryProtectionStrategyTag | undefined {
   return new Map<AvailableDeviceTypeTag, DevicePrimaryProtectionStrategyTag>([
     [AvailableDeviceTypeTag.AccountVault, DevicePrimaryProtectionStrategyTag.AccountVault],
+    [AvailableDeviceTypeTag.Fido2, DevicePrimaryProtectionStrategyTag.Fido2],
     [AvailableDeviceTypeTag.Keyring, DevicePrimaryProtectionStrategyTag.Keyring],
+    [AvailableDeviceTypeTag.MachineKey, DevicePrimaryProtectionStrategyTag.MachineKey],
+    [AvailableDeviceTypeTag.Multi, DevicePrimaryProtectionStrategyTag.Multi],
     [AvailableDeviceTypeTag.OpenBao, DevicePrimaryProtectionStrategyTag.OpenBao],
     [AvailableDeviceTypeTag.PKI, DevicePrimaryProtectionStrategyTag.PKI],
     [AvailableDeviceTypeTag.Password, DevicePrimaryProtectionStrategyTag.Password],
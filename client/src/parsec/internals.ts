@@ -19,5 +19,7 @@ export function getClientConfig(): ClientConfig {
     withMonitors: true,
     preventSyncPattern: null,
     logLevel: null,
+    pkiRevocationPolicy: null,
+    outboundSync: null,
   };
 }
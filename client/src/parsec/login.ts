@@ -272,6 +272,7 @@ export const PrimaryProtectionStrategy = {
       openbaoEntityId: connInfo.userId,
       openbaoAuthToken: connInfo.token,
       openbaoPreferredAuthId: connInfo.provider,
+      openbaoReauthenticationMethod: null,
     };
   },
   useAccountVault(accountHandle: AccountHandle): DevicePrimaryProtectionStrategyAccountVault {
@@ -51,6 +51,8 @@ export async function createOrganization(
     withMonitors: false,
     preventSyncPattern: null,
     logLevel: null,
+    pkiRevocationPolicy: null,
+    outboundSync: null,
   };
   const result = await libparsec.bootstrapOrganization(
     config,
@@ -86,6 +88,8 @@ export async function bootstrapOrganization(
     withMonitors: false,
     preventSyncPattern: null,
     logLevel: null,
+    pkiRevocationPolicy: null,
+    outboundSync: null,
   };
   const result = await libparsec.bootstrapOrganization(
     config,
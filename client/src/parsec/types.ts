@@ -68,6 +68,7 @@ export {
   ListInvitationsErrorTag,
   MountpointToOsPathErrorTag,
   OpenBaoAuthConfigTag,
+  OrphanedWorkspacesStrategy,
   ParsedParsecAddrTag,
   ParseParsecAddrErrorTag,
   PendingAsyncEnrollmentInfoTag,
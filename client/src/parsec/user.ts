@@ -9,6 +9,7 @@ import {
   ClientRevokeUserError,
   ClientUserUpdateProfileError,
   DeviceID,
+  OrphanedWorkspacesStrategy,
   Result,
   UserID,
   UserInfo,
@@ -58,11 +59,17 @@ export async function listUsers(skipRevoked = true, pattern = ''): Promise<Resul
   return generateNoHandleError<ClientListUsersError>();
 }
 
-export async function revokeUser(userId: UserID): Promise<Result<null, ClientRevokeUserError>> {
+// Revocation is aborted by default if it would leave some workspaces without owner
+// (see `ClientRevokeUserErrorTag.SoleOwnerOfWorkspaces`), retry with
+// `OrphanedWorkspacesStrategy.Ignore` once the user has confirmed.
+export async function revokeUser(
+  userId: UserID,
+  orphanedWorkspaces = OrphanedWorkspacesStrategy.Abort,
+): Promise<Result<null, ClientRevokeUserError>> {
   const handle = getConnectionHandle();
 
   if (handle !== null) {
-    return await libparsec.clientRevokeUser(handle, userId);
+    return await libparsec.clientRevokeUser(handle, userId, orphanedWorkspaces);
   }
   return generateNoHandleError<ClientRevokeUserError>();
 }
@@ -76,11 +83,15 @@ export async function getUserInfo(userId: UserID): Promise<Result<UserInfo, Clie
   return generateNoHandleError<ClientGetUserInfoError>();
 }
 
-export async function updateProfile(userId: UserID, profile: UserProfile): Promise<Result<null, ClientUserUpdateProfileError>> {
+export async function updateProfile(
+  userId: UserID,
+  profile: UserProfile,
+  orphanedWorkspaces = OrphanedWorkspacesStrategy.Abort,
+): Promise<Result<null, ClientUserUpdateProfileError>> {
   const handle = getConnectionHandle();
 
   if (handle !== null) {
-    return await libparsec.clientUpdateUserProfile(handle, userId, profile);
+    return await libparsec.clientUpdateUserProfile(handle, userId, profile, orphanedWorkspaces);
   }
   return generateNoHandleError<ClientUserUpdateProfileError>();
 }
@@ -262,15 +262,24 @@ export async function getWorkspaceSharing(
   return generateNoHandleError<ClientListWorkspaceUsersError>();
 }
 
+// Note `expiresOn` is only stored by this device: the user is unshared the next
+// time this device is running and online after this date.
 export async function shareWorkspace(
   workspaceId: WorkspaceID,
   userId: UserID,
   role: WorkspaceRole | null,
+  expiresOn: DateTime | null = null,
 ): Promise<Result<null, ClientShareWorkspaceError>> {
   const handle = getConnectionHandle();
 
   if (handle !== null) {
-    return await libparsec.clientShareWorkspace(handle, workspaceId, userId, role);
+    return await libparsec.clientShareWorkspace(
+      handle,
+      workspaceId,
+      userId,
+      role,
+      expiresOn ? (expiresOn.toSeconds() as any as DateTime) : null,
+    );
   }
   return generateNoHandleError<ClientShareWorkspaceError>();
 }
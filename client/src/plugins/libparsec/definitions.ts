@@ -66,6 +66,11 @@ export enum LogLevel {
     Warn = 'LogLevelWarn',
 }
 
+export enum OrphanedWorkspacesStrategy {
+    Abort = 'OrphanedWorkspacesStrategyAbort',
+    Ignore = 'OrphanedWorkspacesStrategyIgnore',
+}
+
 export enum Platform {
     Android = 'PlatformAndroid',
     Linux = 'PlatformLinux',
@@ -92,6 +97,11 @@ export enum UserProfile {
     Outsider = 'UserProfileOutsider',
     Standard = 'UserProfileStandard',
 }
+
+export enum X509RevocationPolicy {
+    HardFail = 'X509RevocationPolicyHardFail',
+    SoftFail = 'X509RevocationPolicySoftFail',
+}
 export type ParsecAsyncEnrollmentAddrAndRedirectionURL = [
     ParsecAsyncEnrollmentAddr,
     ParsecAsyncEnrollmentRedirectionURL,
@@ -247,6 +257,11 @@ export interface AvailableDevice {
     ty: AvailableDeviceType
 }
 
+export interface AvailableDeviceMultiProtection {
+    totpOpaqueKeyId: TOTPOpaqueKeyID | null
+    ty: AvailableDeviceMultiProtectionType
+}
+
 export interface AvailablePendingAsyncEnrollment {
     filePath: Path
     submittedOn: DateTime
@@ -265,6 +280,8 @@ export interface ClientConfig {
     withMonitors: boolean
     preventSyncPattern: string | null
     logLevel: LogLevel | null
+    pkiRevocationPolicy: X509RevocationPolicy | null
+    outboundSync: OutboundSyncConfig | null
 }
 
 export interface ClientInfo {
@@ -291,6 +308,11 @@ export interface DeviceAccessStrategy {
     primaryProtection: DevicePrimaryProtectionStrategy
 }
 
+export interface DeviceAlternativeProtection {
+    totpProtection: [TOTPOpaqueKeyID, SecretKey] | null
+    primaryProtection: DevicePrimaryProtectionStrategy
+}
+
 export interface DeviceClaimFinalizeInfo {
     handle: Handle
 }
@@ -390,6 +412,24 @@ export interface OrganizationInfo {
     totalMetadataBytes: SizeInt
 }
 
+export interface OutboundSyncConfig {
+    delays: OutboundSyncDelays | null
+    overrides: Array<OutboundSyncDelaysOverride>
+    blockDeduplication: boolean
+    blockCompression: boolean
+    contentDefinedChunking: boolean
+}
+
+export interface OutboundSyncDelays {
+    quiescenceInMs: U32
+    maxDelayInMs: U32
+}
+
+export interface OutboundSyncDelaysOverride {
+    pattern: string
+    delays: OutboundSyncDelays
+}
+
 export interface ServerConfig {
     account: AccountConfig
     cryptpad: CryptPadConfig | null
@@ -402,6 +442,7 @@ export interface ServerOrganizationConfig {
     userProfileOutsiderAllowed: boolean
     activeUsersLimit: ActiveUsersLimit
     realmMinimumArchivingPeriodBeforeDeletion: U64
+    pkiCrls: Array<Bytes>
 }
 
 export interface ShamirRecoveryClaimInProgress1Info {
@@ -583,6 +624,7 @@ export interface WorkspaceUserAccessInfo {
     humanHandle: HumanHandle
     currentProfile: UserProfile
     currentRole: RealmRole
+    expiresOn: DateTime | null
 }
 
 export interface X509CertificateReference {
@@ -716,6 +758,7 @@ export type AccountCreateError =
 // AccountCreateRegistrationDeviceError
 export enum AccountCreateRegistrationDeviceErrorTag {
     BadVaultKeyAccess = 'AccountCreateRegistrationDeviceErrorBadVaultKeyAccess',
+    ClockDrift = 'AccountCreateRegistrationDeviceErrorClockDrift',
     Internal = 'AccountCreateRegistrationDeviceErrorInternal',
     LoadDeviceDecryptionFailed = 'AccountCreateRegistrationDeviceErrorLoadDeviceDecryptionFailed',
     LoadDeviceInvalidData = 'AccountCreateRegistrationDeviceErrorLoadDeviceInvalidData',
@@ -731,6 +774,10 @@ export interface AccountCreateRegistrationDeviceErrorBadVaultKeyAccess {
     tag: AccountCreateRegistrationDeviceErrorTag.BadVaultKeyAccess
     error: string
 }
+export interface AccountCreateRegistrationDeviceErrorClockDrift {
+    tag: AccountCreateRegistrationDeviceErrorTag.ClockDrift
+    error: string
+}
 export interface AccountCreateRegistrationDeviceErrorInternal {
     tag: AccountCreateRegistrationDeviceErrorTag.Internal
     error: string
@@ -769,6 +816,7 @@ export interface AccountCreateRegistrationDeviceErrorTimestampOutOfBallpark {
 }
 export type AccountCreateRegistrationDeviceError =
   | AccountCreateRegistrationDeviceErrorBadVaultKeyAccess
+  | AccountCreateRegistrationDeviceErrorClockDrift
   | AccountCreateRegistrationDeviceErrorInternal
   | AccountCreateRegistrationDeviceErrorLoadDeviceDecryptionFailed
   | AccountCreateRegistrationDeviceErrorLoadDeviceInvalidData
@@ -1134,7 +1182,9 @@ export type AccountRecoverSendValidationEmailError =
 // AccountRegisterNewDeviceError
 export enum AccountRegisterNewDeviceErrorTag {
     BadVaultKeyAccess = 'AccountRegisterNewDeviceErrorBadVaultKeyAccess',
+    ClockDrift = 'AccountRegisterNewDeviceErrorClockDrift',
     CorruptedRegistrationDevice = 'AccountRegisterNewDeviceErrorCorruptedRegistrationDevice',
+    Fido2HmacSecretNotSupported = 'AccountRegisterNewDeviceErrorFido2HmacSecretNotSupported',
     Internal = 'AccountRegisterNewDeviceErrorInternal',
     InvalidPath = 'AccountRegisterNewDeviceErrorInvalidPath',
     NoSpaceAvailable = 'AccountRegisterNewDeviceErrorNoSpaceAvailable',
@@ -1149,10 +1199,18 @@ export interface AccountRegisterNewDeviceErrorBadVaultKeyAccess {
     tag: AccountRegisterNewDeviceErrorTag.BadVaultKeyAccess
     error: string
 }
+export interface AccountRegisterNewDeviceErrorClockDrift {
+    tag: AccountRegisterNewDeviceErrorTag.ClockDrift
+    error: string
+}
 export interface AccountRegisterNewDeviceErrorCorruptedRegistrationDevice {
     tag: AccountRegisterNewDeviceErrorTag.CorruptedRegistrationDevice
     error: string
 }
+export interface AccountRegisterNewDeviceErrorFido2HmacSecretNotSupported {
+    tag: AccountRegisterNewDeviceErrorTag.Fido2HmacSecretNotSupported
+    error: string
+}
 export interface AccountRegisterNewDeviceErrorInternal {
     tag: AccountRegisterNewDeviceErrorTag.Internal
     error: string
@@ -1187,7 +1245,9 @@ export interface AccountRegisterNewDeviceErrorUnknownRegistrationDevice {
 }
 export type AccountRegisterNewDeviceError =
   | AccountRegisterNewDeviceErrorBadVaultKeyAccess
+  | AccountRegisterNewDeviceErrorClockDrift
   | AccountRegisterNewDeviceErrorCorruptedRegistrationDevice
+  | AccountRegisterNewDeviceErrorFido2HmacSecretNotSupported
   | AccountRegisterNewDeviceErrorInternal
   | AccountRegisterNewDeviceErrorInvalidPath
   | AccountRegisterNewDeviceErrorNoSpaceAvailable
@@ -1293,10 +1353,47 @@ export type AsyncEnrollmentIdentitySystem =
   | AsyncEnrollmentIdentitySystemPKI
   | AsyncEnrollmentIdentitySystemPKICorrupted
 
+// AvailableDeviceMultiProtectionType
+export enum AvailableDeviceMultiProtectionTypeTag {
+    AccountVault = 'AvailableDeviceMultiProtectionTypeAccountVault',
+    Fido2 = 'AvailableDeviceMultiProtectionTypeFido2',
+    OpenBao = 'AvailableDeviceMultiProtectionTypeOpenBao',
+    PKI = 'AvailableDeviceMultiProtectionTypePKI',
+    Password = 'AvailableDeviceMultiProtectionTypePassword',
+}
+
+export interface AvailableDeviceMultiProtectionTypeAccountVault {
+    tag: AvailableDeviceMultiProtectionTypeTag.AccountVault
+}
+export interface AvailableDeviceMultiProtectionTypeFido2 {
+    tag: AvailableDeviceMultiProtectionTypeTag.Fido2
+}
+export interface AvailableDeviceMultiProtectionTypeOpenBao {
+    tag: AvailableDeviceMultiProtectionTypeTag.OpenBao
+    openbaoPreferredAuthId: string
+    openbaoEntityId: string
+}
+export interface AvailableDeviceMultiProtectionTypePKI {
+    tag: AvailableDeviceMultiProtectionTypeTag.PKI
+    certificateRef: X509CertificateReference
+}
+export interface AvailableDeviceMultiProtectionTypePassword {
+    tag: AvailableDeviceMultiProtectionTypeTag.Password
+}
+export type AvailableDeviceMultiProtectionType =
+  | AvailableDeviceMultiProtectionTypeAccountVault
+  | AvailableDeviceMultiProtectionTypeFido2
+  | AvailableDeviceMultiProtectionTypeOpenBao
+  | AvailableDeviceMultiProtectionTypePKI
+  | AvailableDeviceMultiProtectionTypePassword
+
 // AvailableDeviceType
 export enum AvailableDeviceTypeTag {
     AccountVault = 'AvailableDeviceTypeAccountVault',
+    Fido2 = 'AvailableDeviceTypeFido2',
     Keyring = 'AvailableDeviceTypeKeyring',
+    MachineKey = 'AvailableDeviceTypeMachineKey',
+    Multi = 'AvailableDeviceTypeMulti',
     OpenBao = 'AvailableDeviceTypeOpenBao',
     PKI = 'AvailableDeviceTypePKI',
     Password = 'AvailableDeviceTypePassword',
@@ -1306,9 +1403,21 @@ export enum AvailableDeviceTypeTag {
 export interface AvailableDeviceTypeAccountVault {
     tag: AvailableDeviceTypeTag.AccountVault
 }
+export interface AvailableDeviceTypeFido2 {
+    tag: AvailableDeviceTypeTag.Fido2
+}
 export interface AvailableDeviceTypeKeyring {
     tag: AvailableDeviceTypeTag.Keyring
 }
+export interface AvailableDeviceTypeMachineKey {
+    tag: AvailableDeviceTypeTag.MachineKey
+    machineKeyFile: Path
+    machineKeyAvailable: boolean
+}
+export interface AvailableDeviceTypeMulti {
+    tag: AvailableDeviceTypeTag.Multi
+    protections: Array<AvailableDeviceMultiProtection>
+}
 export interface AvailableDeviceTypeOpenBao {
     tag: AvailableDeviceTypeTag.OpenBao
     openbaoPreferredAuthId: string
@@ -1326,7 +1435,10 @@ export interface AvailableDeviceTypeRecovery {
 }
 export type AvailableDeviceType =
   | AvailableDeviceTypeAccountVault
+  | AvailableDeviceTypeFido2
   | AvailableDeviceTypeKeyring
+  | AvailableDeviceTypeMachineKey
+  | AvailableDeviceTypeMulti
   | AvailableDeviceTypeOpenBao
   | AvailableDeviceTypePKI
   | AvailableDeviceTypePassword
@@ -1380,6 +1492,7 @@ export enum BootstrapOrganizationErrorTag {
     InvalidToken = 'BootstrapOrganizationErrorInvalidToken',
     Offline = 'BootstrapOrganizationErrorOffline',
     OrganizationExpired = 'BootstrapOrganizationErrorOrganizationExpired',
+    SaveDeviceFido2HmacSecretNotSupported = 'BootstrapOrganizationErrorSaveDeviceFido2HmacSecretNotSupported',
     SaveDeviceInvalidPath = 'BootstrapOrganizationErrorSaveDeviceInvalidPath',
     SaveDeviceNoSpaceAvailable = 'BootstrapOrganizationErrorSaveDeviceNoSpaceAvailable',
     SaveDeviceRemoteOpaqueKeyUploadFailed = 'BootstrapOrganizationErrorSaveDeviceRemoteOpaqueKeyUploadFailed',
@@ -1411,6 +1524,10 @@ export interface BootstrapOrganizationErrorOrganizationExpired {
     tag: BootstrapOrganizationErrorTag.OrganizationExpired
     error: string
 }
+export interface BootstrapOrganizationErrorSaveDeviceFido2HmacSecretNotSupported {
+    tag: BootstrapOrganizationErrorTag.SaveDeviceFido2HmacSecretNotSupported
+    error: string
+}
 export interface BootstrapOrganizationErrorSaveDeviceInvalidPath {
     tag: BootstrapOrganizationErrorTag.SaveDeviceInvalidPath
     error: string
@@ -1442,6 +1559,7 @@ export type BootstrapOrganizationError =
   | BootstrapOrganizationErrorInvalidToken
   | BootstrapOrganizationErrorOffline
   | BootstrapOrganizationErrorOrganizationExpired
+  | BootstrapOrganizationErrorSaveDeviceFido2HmacSecretNotSupported
   | BootstrapOrganizationErrorSaveDeviceInvalidPath
   | BootstrapOrganizationErrorSaveDeviceNoSpaceAvailable
   | BootstrapOrganizationErrorSaveDeviceRemoteOpaqueKeyUploadFailed
@@ -1485,6 +1603,7 @@ export type CertificateBasedInfoOrigin =
 
 // ClaimFinalizeError
 export enum ClaimFinalizeErrorTag {
+    Fido2HmacSecretNotSupported = 'ClaimFinalizeErrorFido2HmacSecretNotSupported',
     Internal = 'ClaimFinalizeErrorInternal',
     InvalidPath = 'ClaimFinalizeErrorInvalidPath',
     NoSpaceAvailable = 'ClaimFinalizeErrorNoSpaceAvailable',
@@ -1492,6 +1611,10 @@ export enum ClaimFinalizeErrorTag {
     RemoteOpaqueKeyUploadOffline = 'ClaimFinalizeErrorRemoteOpaqueKeyUploadOffline',
 }
 
+export interface ClaimFinalizeErrorFido2HmacSecretNotSupported {
+    tag: ClaimFinalizeErrorTag.Fido2HmacSecretNotSupported
+    error: string
+}
 export interface ClaimFinalizeErrorInternal {
     tag: ClaimFinalizeErrorTag.Internal
     error: string
@@ -1513,6 +1636,7 @@ export interface ClaimFinalizeErrorRemoteOpaqueKeyUploadOffline {
     error: string
 }
 export type ClaimFinalizeError =
+  | ClaimFinalizeErrorFido2HmacSecretNotSupported
   | ClaimFinalizeErrorInternal
   | ClaimFinalizeErrorInvalidPath
   | ClaimFinalizeErrorNoSpaceAvailable
@@ -1653,6 +1777,7 @@ export enum ClientAcceptAsyncEnrollmentErrorTag {
     ActiveUsersLimitReached = 'ClientAcceptAsyncEnrollmentErrorActiveUsersLimitReached',
     AuthorNotAllowed = 'ClientAcceptAsyncEnrollmentErrorAuthorNotAllowed',
     BadSubmitPayload = 'ClientAcceptAsyncEnrollmentErrorBadSubmitPayload',
+    ClockDrift = 'ClientAcceptAsyncEnrollmentErrorClockDrift',
     EnrollmentNoLongerAvailable = 'ClientAcceptAsyncEnrollmentErrorEnrollmentNoLongerAvailable',
     EnrollmentNotFound = 'ClientAcceptAsyncEnrollmentErrorEnrollmentNotFound',
     HumanHandleAlreadyTaken = 'ClientAcceptAsyncEnrollmentErrorHumanHandleAlreadyTaken',
@@ -1680,6 +1805,10 @@ export interface ClientAcceptAsyncEnrollmentErrorBadSubmitPayload {
     tag: ClientAcceptAsyncEnrollmentErrorTag.BadSubmitPayload
     error: string
 }
+export interface ClientAcceptAsyncEnrollmentErrorClockDrift {
+    tag: ClientAcceptAsyncEnrollmentErrorTag.ClockDrift
+    error: string
+}
 export interface ClientAcceptAsyncEnrollmentErrorEnrollmentNoLongerAvailable {
     tag: ClientAcceptAsyncEnrollmentErrorTag.EnrollmentNoLongerAvailable
     error: string
@@ -1736,6 +1865,7 @@ export type ClientAcceptAsyncEnrollmentError =
   | ClientAcceptAsyncEnrollmentErrorActiveUsersLimitReached
   | ClientAcceptAsyncEnrollmentErrorAuthorNotAllowed
   | ClientAcceptAsyncEnrollmentErrorBadSubmitPayload
+  | ClientAcceptAsyncEnrollmentErrorClockDrift
   | ClientAcceptAsyncEnrollmentErrorEnrollmentNoLongerAvailable
   | ClientAcceptAsyncEnrollmentErrorEnrollmentNotFound
   | ClientAcceptAsyncEnrollmentErrorHumanHandleAlreadyTaken
@@ -1784,6 +1914,7 @@ export type ClientAcceptTosError =
 export enum ClientArchiveWorkspaceErrorTag {
     ArchivingPeriodTooShort = 'ClientArchiveWorkspaceErrorArchivingPeriodTooShort',
     AuthorNotAllowed = 'ClientArchiveWorkspaceErrorAuthorNotAllowed',
+    ClockDrift = 'ClientArchiveWorkspaceErrorClockDrift',
     Internal = 'ClientArchiveWorkspaceErrorInternal',
     InvalidCertificate = 'ClientArchiveWorkspaceErrorInvalidCertificate',
     InvalidEncryptedRealmName = 'ClientArchiveWorkspaceErrorInvalidEncryptedRealmName',
@@ -1803,6 +1934,10 @@ export interface ClientArchiveWorkspaceErrorAuthorNotAllowed {
     tag: ClientArchiveWorkspaceErrorTag.AuthorNotAllowed
     error: string
 }
+export interface ClientArchiveWorkspaceErrorClockDrift {
+    tag: ClientArchiveWorkspaceErrorTag.ClockDrift
+    error: string
+}
 export interface ClientArchiveWorkspaceErrorInternal {
     tag: ClientArchiveWorkspaceErrorTag.Internal
     error: string
@@ -1846,6 +1981,7 @@ export interface ClientArchiveWorkspaceErrorWorkspaceNotFound {
 export type ClientArchiveWorkspaceError =
   | ClientArchiveWorkspaceErrorArchivingPeriodTooShort
   | ClientArchiveWorkspaceErrorAuthorNotAllowed
+  | ClientArchiveWorkspaceErrorClockDrift
   | ClientArchiveWorkspaceErrorInternal
   | ClientArchiveWorkspaceErrorInvalidCertificate
   | ClientArchiveWorkspaceErrorInvalidEncryptedRealmName
@@ -1918,6 +2054,7 @@ export type ClientCreateWorkspaceError =
 
 // ClientDeleteShamirRecoveryError
 export enum ClientDeleteShamirRecoveryErrorTag {
+    ClockDrift = 'ClientDeleteShamirRecoveryErrorClockDrift',
     Internal = 'ClientDeleteShamirRecoveryErrorInternal',
     InvalidCertificate = 'ClientDeleteShamirRecoveryErrorInvalidCertificate',
     Offline = 'ClientDeleteShamirRecoveryErrorOffline',
@@ -1925,6 +2062,10 @@ export enum ClientDeleteShamirRecoveryErrorTag {
     TimestampOutOfBallpark = 'ClientDeleteShamirRecoveryErrorTimestampOutOfBallpark',
 }
 
+export interface ClientDeleteShamirRecoveryErrorClockDrift {
+    tag: ClientDeleteShamirRecoveryErrorTag.ClockDrift
+    error: string
+}
 export interface ClientDeleteShamirRecoveryErrorInternal {
     tag: ClientDeleteShamirRecoveryErrorTag.Internal
     error: string
@@ -1950,6 +2091,7 @@ export interface ClientDeleteShamirRecoveryErrorTimestampOutOfBallpark {
     ballparkClientLateOffset: number
 }
 export type ClientDeleteShamirRecoveryError =
+  | ClientDeleteShamirRecoveryErrorClockDrift
   | ClientDeleteShamirRecoveryErrorInternal
   | ClientDeleteShamirRecoveryErrorInvalidCertificate
   | ClientDeleteShamirRecoveryErrorOffline
@@ -1962,6 +2104,9 @@ export enum ClientEventTag {
     ClientErrorResponse = 'ClientEventClientErrorResponse',
     ClientStarted = 'ClientEventClientStarted',
     ClientStopped = 'ClientEventClientStopped',
+    ClockDrift = 'ClientEventClockDrift',
+    DeviceAdded = 'ClientEventDeviceAdded',
+    DeviceRevoked = 'ClientEventDeviceRevoked',
     ExpiredOrganization = 'ClientEventExpiredOrganization',
     FrozenSelfUser = 'ClientEventFrozenSelfUser',
     GreetingAttemptCancelled = 'ClientEventGreetingAttemptCancelled',
@@ -1976,11 +2121,22 @@ export enum ClientEventTag {
     Online = 'ClientEventOnline',
     OrganizationNotFound = 'ClientEventOrganizationNotFound',
     Ping = 'ClientEventPing',
+    RealmArchivingChanged = 'ClientEventRealmArchivingChanged',
+    RealmRekeyDone = 'ClientEventRealmRekeyDone',
+    RealmRekeyProgress = 'ClientEventRealmRekeyProgress',
+    RealmRenamed = 'ClientEventRealmRenamed',
+    RealmRoleChanged = 'ClientEventRealmRoleChanged',
     RevokedSelfUser = 'ClientEventRevokedSelfUser',
+    SelfShared = 'ClientEventSelfShared',
+    SelfUnshared = 'ClientEventSelfUnshared',
+    SequesterServiceRevoked = 'ClientEventSequesterServiceRevoked',
     ServerConfigChanged = 'ClientEventServerConfigChanged',
     ServerInvalidResponseContent = 'ClientEventServerInvalidResponseContent',
     ServerInvalidResponseStatus = 'ClientEventServerInvalidResponseStatus',
+    ShamirRecoveryChanged = 'ClientEventShamirRecoveryChanged',
     TooMuchDriftWithServerClock = 'ClientEventTooMuchDriftWithServerClock',
+    UserProfileUpdated = 'ClientEventUserProfileUpdated',
+    UserRevoked = 'ClientEventUserRevoked',
     WebClientNotAllowedByOrganization = 'ClientEventWebClientNotAllowedByOrganization',
     WorkspaceLocallyCreated = 'ClientEventWorkspaceLocallyCreated',
     WorkspaceOpsInboundSyncDone = 'ClientEventWorkspaceOpsInboundSyncDone',
@@ -2007,6 +2163,21 @@ export interface ClientEventClientStopped {
     tag: ClientEventTag.ClientStopped
     deviceId: DeviceID
 }
+export interface ClientEventClockDrift {
+    tag: ClientEventTag.ClockDrift
+    requiredTimestamp: DateTime
+    clientTimestamp: DateTime
+}
+export interface ClientEventDeviceAdded {
+    tag: ClientEventTag.DeviceAdded
+    userId: UserID
+    deviceId: DeviceID
+}
+export interface ClientEventDeviceRevoked {
+    tag: ClientEventTag.DeviceRevoked
+    userId: UserID
+    deviceId: DeviceID
+}
 export interface ClientEventExpiredOrganization {
     tag: ClientEventTag.ExpiredOrganization
 }
@@ -2061,9 +2232,49 @@ export interface ClientEventPing {
     tag: ClientEventTag.Ping
     ping: string
 }
+export interface ClientEventRealmArchivingChanged {
+    tag: ClientEventTag.RealmArchivingChanged
+    realmId: VlobID
+}
+export interface ClientEventRealmRekeyDone {
+    tag: ClientEventTag.RealmRekeyDone
+    realmId: VlobID
+    targetKeyIndex: IndexInt
+}
+export interface ClientEventRealmRekeyProgress {
+    tag: ClientEventTag.RealmRekeyProgress
+    realmId: VlobID
+    targetKeyIndex: IndexInt
+    processed: IndexInt
+    total: IndexInt
+}
+export interface ClientEventRealmRenamed {
+    tag: ClientEventTag.RealmRenamed
+    realmId: VlobID
+}
+export interface ClientEventRealmRoleChanged {
+    tag: ClientEventTag.RealmRoleChanged
+    realmId: VlobID
+    userId: UserID
+    oldRole: RealmRole | null
+    newRole: RealmRole | null
+}
 export interface ClientEventRevokedSelfUser {
     tag: ClientEventTag.RevokedSelfUser
 }
+export interface ClientEventSelfShared {
+    tag: ClientEventTag.SelfShared
+    realmId: VlobID
+    role: RealmRole
+}
+export interface ClientEventSelfUnshared {
+    tag: ClientEventTag.SelfUnshared
+    realmId: VlobID
+}
+export interface ClientEventSequesterServiceRevoked {
+    tag: ClientEventTag.SequesterServiceRevoked
+    serviceId: SequesterServiceID
+}
 export interface ClientEventServerConfigChanged {
     tag: ClientEventTag.ServerConfigChanged
 }
@@ -2075,6 +2286,10 @@ export interface ClientEventServerInvalidResponseStatus {
     tag: ClientEventTag.ServerInvalidResponseStatus
     statusCode: string
 }
+export interface ClientEventShamirRecoveryChanged {
+    tag: ClientEventTag.ShamirRecoveryChanged
+    userId: UserID
+}
 export interface ClientEventTooMuchDriftWithServerClock {
     tag: ClientEventTag.TooMuchDriftWithServerClock
     serverTimestamp: DateTime
@@ -2082,6 +2297,15 @@ export interface ClientEventTooMuchDriftWithServerClock {
     ballparkClientEarlyOffset: number
     ballparkClientLateOffset: number
 }
+export interface ClientEventUserProfileUpdated {
+    tag: ClientEventTag.UserProfileUpdated
+    userId: UserID
+    profile: UserProfile
+}
+export interface ClientEventUserRevoked {
+    tag: ClientEventTag.UserRevoked
+    userId: UserID
+}
 export interface ClientEventWebClientNotAllowedByOrganization {
     tag: ClientEventTag.WebClientNotAllowedByOrganization
 }
@@ -2129,6 +2353,9 @@ export type ClientEvent =
   | ClientEventClientErrorResponse
   | ClientEventClientStarted
   | ClientEventClientStopped
+  | ClientEventClockDrift
+  | ClientEventDeviceAdded
+  | ClientEventDeviceRevoked
   | ClientEventExpiredOrganization
   | ClientEventFrozenSelfUser
   | ClientEventGreetingAttemptCancelled
@@ -2143,11 +2370,22 @@ export type ClientEvent =
   | ClientEventOnline
   | ClientEventOrganizationNotFound
   | ClientEventPing
+  | ClientEventRealmArchivingChanged
+  | ClientEventRealmRekeyDone
+  | ClientEventRealmRekeyProgress
+  | ClientEventRealmRenamed
+  | ClientEventRealmRoleChanged
   | ClientEventRevokedSelfUser
+  | ClientEventSelfShared
+  | ClientEventSelfUnshared
+  | ClientEventSequesterServiceRevoked
   | ClientEventServerConfigChanged
   | ClientEventServerInvalidResponseContent
   | ClientEventServerInvalidResponseStatus
+  | ClientEventShamirRecoveryChanged
   | ClientEventTooMuchDriftWithServerClock
+  | ClientEventUserProfileUpdated
+  | ClientEventUserRevoked
   | ClientEventWebClientNotAllowedByOrganization
   | ClientEventWorkspaceLocallyCreated
   | ClientEventWorkspaceOpsInboundSyncDone
@@ -2160,6 +2398,7 @@ export type ClientEvent =
 
 // ClientExportRecoveryDeviceError
 export enum ClientExportRecoveryDeviceErrorTag {
+    ClockDrift = 'ClientExportRecoveryDeviceErrorClockDrift',
     Internal = 'ClientExportRecoveryDeviceErrorInternal',
     InvalidCertificate = 'ClientExportRecoveryDeviceErrorInvalidCertificate',
     Offline = 'ClientExportRecoveryDeviceErrorOffline',
@@ -2167,6 +2406,10 @@ export enum ClientExportRecoveryDeviceErrorTag {
     TimestampOutOfBallpark = 'ClientExportRecoveryDeviceErrorTimestampOutOfBallpark',
 }
 
+export interface ClientExportRecoveryDeviceErrorClockDrift {
+    tag: ClientExportRecoveryDeviceErrorTag.ClockDrift
+    error: string
+}
 export interface ClientExportRecoveryDeviceErrorInternal {
     tag: ClientExportRecoveryDeviceErrorTag.Internal
     error: string
@@ -2192,6 +2435,7 @@ export interface ClientExportRecoveryDeviceErrorTimestampOutOfBallpark {
     ballparkClientLateOffset: number
 }
 export type ClientExportRecoveryDeviceError =
+  | ClientExportRecoveryDeviceErrorClockDrift
   | ClientExportRecoveryDeviceErrorInternal
   | ClientExportRecoveryDeviceErrorInvalidCertificate
   | ClientExportRecoveryDeviceErrorOffline
@@ -2636,9 +2880,92 @@ export type ClientRejectAsyncEnrollmentError =
   | ClientRejectAsyncEnrollmentErrorInternal
   | ClientRejectAsyncEnrollmentErrorOffline
 
+// ClientRekeyWorkspaceError
+export enum ClientRekeyWorkspaceErrorTag {
+    AuthorNotAllowed = 'ClientRekeyWorkspaceErrorAuthorNotAllowed',
+    ClockDrift = 'ClientRekeyWorkspaceErrorClockDrift',
+    Internal = 'ClientRekeyWorkspaceErrorInternal',
+    InvalidCertificate = 'ClientRekeyWorkspaceErrorInvalidCertificate',
+    InvalidKeysBundle = 'ClientRekeyWorkspaceErrorInvalidKeysBundle',
+    Offline = 'ClientRekeyWorkspaceErrorOffline',
+    RealmArchived = 'ClientRekeyWorkspaceErrorRealmArchived',
+    RealmDeleted = 'ClientRekeyWorkspaceErrorRealmDeleted',
+    ServerBlockstoreUnavailable = 'ClientRekeyWorkspaceErrorServerBlockstoreUnavailable',
+    Stopped = 'ClientRekeyWorkspaceErrorStopped',
+    TimestampOutOfBallpark = 'ClientRekeyWorkspaceErrorTimestampOutOfBallpark',
+    UnknownRealm = 'ClientRekeyWorkspaceErrorUnknownRealm',
+}
+
+export interface ClientRekeyWorkspaceErrorAuthorNotAllowed {
+    tag: ClientRekeyWorkspaceErrorTag.AuthorNotAllowed
+    error: string
+}
+export interface ClientRekeyWorkspaceErrorClockDrift {
+    tag: ClientRekeyWorkspaceErrorTag.ClockDrift
+    error: string
+}
+export interface ClientRekeyWorkspaceErrorInternal {
+    tag: ClientRekeyWorkspaceErrorTag.Internal
+    error: string
+}
+export interface ClientRekeyWorkspaceErrorInvalidCertificate {
+    tag: ClientRekeyWorkspaceErrorTag.InvalidCertificate
+    error: string
+}
+export interface ClientRekeyWorkspaceErrorInvalidKeysBundle {
+    tag: ClientRekeyWorkspaceErrorTag.InvalidKeysBundle
+    error: string
+}
+export interface ClientRekeyWorkspaceErrorOffline {
+    tag: ClientRekeyWorkspaceErrorTag.Offline
+    error: string
+}
+export interface ClientRekeyWorkspaceErrorRealmArchived {
+    tag: ClientRekeyWorkspaceErrorTag.RealmArchived
+    error: string
+}
+export interface ClientRekeyWorkspaceErrorRealmDeleted {
+    tag: ClientRekeyWorkspaceErrorTag.RealmDeleted
+    error: string
+}
+export interface ClientRekeyWorkspaceErrorServerBlockstoreUnavailable {
+    tag: ClientRekeyWorkspaceErrorTag.ServerBlockstoreUnavailable
+    error: string
+}
+export interface ClientRekeyWorkspaceErrorStopped {
+    tag: ClientRekeyWorkspaceErrorTag.Stopped
+    error: string
+}
+export interface ClientRekeyWorkspaceErrorTimestampOutOfBallpark {
+    tag: ClientRekeyWorkspaceErrorTag.TimestampOutOfBallpark
+    error: string
+    serverTimestamp: DateTime
+    clientTimestamp: DateTime
+    ballparkClientEarlyOffset: number
+    ballparkClientLateOffset: number
+}
+export interface ClientRekeyWorkspaceErrorUnknownRealm {
+    tag: ClientRekeyWorkspaceErrorTag.UnknownRealm
+    error: string
+}
+export type ClientRekeyWorkspaceError =
+  | ClientRekeyWorkspaceErrorAuthorNotAllowed
+  | ClientRekeyWorkspaceErrorClockDrift
+  | ClientRekeyWorkspaceErrorInternal
+  | ClientRekeyWorkspaceErrorInvalidCertificate
+  | ClientRekeyWorkspaceErrorInvalidKeysBundle
+  | ClientRekeyWorkspaceErrorOffline
+  | ClientRekeyWorkspaceErrorRealmArchived
+  | ClientRekeyWorkspaceErrorRealmDeleted
+  | ClientRekeyWorkspaceErrorServerBlockstoreUnavailable
+  | ClientRekeyWorkspaceErrorStopped
+  | ClientRekeyWorkspaceErrorTimestampOutOfBallpark
+  | ClientRekeyWorkspaceErrorUnknownRealm
+
 // ClientRenameWorkspaceError
 export enum ClientRenameWorkspaceErrorTag {
     AuthorNotAllowed = 'ClientRenameWorkspaceErrorAuthorNotAllowed',
+    ClockDrift = 'ClientRenameWorkspaceErrorClockDrift',
     Internal = 'ClientRenameWorkspaceErrorInternal',
     InvalidCertificate = 'ClientRenameWorkspaceErrorInvalidCertificate',
     InvalidEncryptedRealmName = 'ClientRenameWorkspaceErrorInvalidEncryptedRealmName',
@@ -2655,6 +2982,10 @@ export interface ClientRenameWorkspaceErrorAuthorNotAllowed {
     tag: ClientRenameWorkspaceErrorTag.AuthorNotAllowed
     error: string
 }
+export interface ClientRenameWorkspaceErrorClockDrift {
+    tag: ClientRenameWorkspaceErrorTag.ClockDrift
+    error: string
+}
 export interface ClientRenameWorkspaceErrorInternal {
     tag: ClientRenameWorkspaceErrorTag.Internal
     error: string
@@ -2701,6 +3032,7 @@ export interface ClientRenameWorkspaceErrorWorkspaceNotFound {
 }
 export type ClientRenameWorkspaceError =
   | ClientRenameWorkspaceErrorAuthorNotAllowed
+  | ClientRenameWorkspaceErrorClockDrift
   | ClientRenameWorkspaceErrorInternal
   | ClientRenameWorkspaceErrorInvalidCertificate
   | ClientRenameWorkspaceErrorInvalidEncryptedRealmName
@@ -2715,11 +3047,13 @@ export type ClientRenameWorkspaceError =
 // ClientRevokeUserError
 export enum ClientRevokeUserErrorTag {
     AuthorNotAllowed = 'ClientRevokeUserErrorAuthorNotAllowed',
+    ClockDrift = 'ClientRevokeUserErrorClockDrift',
     Internal = 'ClientRevokeUserErrorInternal',
     InvalidCertificate = 'ClientRevokeUserErrorInvalidCertificate',
     InvalidKeysBundle = 'ClientRevokeUserErrorInvalidKeysBundle',
     NoKey = 'ClientRevokeUserErrorNoKey',
     Offline = 'ClientRevokeUserErrorOffline',
+    SoleOwnerOfWorkspaces = 'ClientRevokeUserErrorSoleOwnerOfWorkspaces',
     Stopped = 'ClientRevokeUserErrorStopped',
     TimestampOutOfBallpark = 'ClientRevokeUserErrorTimestampOutOfBallpark',
     UserIsSelf = 'ClientRevokeUserErrorUserIsSelf',
@@ -2730,6 +3064,10 @@ export interface ClientRevokeUserErrorAuthorNotAllowed {
     tag: ClientRevokeUserErrorTag.AuthorNotAllowed
     error: string
 }
+export interface ClientRevokeUserErrorClockDrift {
+    tag: ClientRevokeUserErrorTag.ClockDrift
+    error: string
+}
 export interface ClientRevokeUserErrorInternal {
     tag: ClientRevokeUserErrorTag.Internal
     error: string
@@ -2750,6 +3088,11 @@ export interface ClientRevokeUserErrorOffline {
     tag: ClientRevokeUserErrorTag.Offline
     error: string
 }
+export interface ClientRevokeUserErrorSoleOwnerOfWorkspaces {
+    tag: ClientRevokeUserErrorTag.SoleOwnerOfWorkspaces
+    error: string
+    workspaces: Array<VlobID>
+}
 export interface ClientRevokeUserErrorStopped {
     tag: ClientRevokeUserErrorTag.Stopped
     error: string
@@ -2768,11 +3111,13 @@ export interface ClientRevokeUserErrorUserNotFound {
 }
 export type ClientRevokeUserError =
   | ClientRevokeUserErrorAuthorNotAllowed
+  | ClientRevokeUserErrorClockDrift
   | ClientRevokeUserErrorInternal
   | ClientRevokeUserErrorInvalidCertificate
   | ClientRevokeUserErrorInvalidKeysBundle
   | ClientRevokeUserErrorNoKey
   | ClientRevokeUserErrorOffline
+  | ClientRevokeUserErrorSoleOwnerOfWorkspaces
   | ClientRevokeUserErrorStopped
   | ClientRevokeUserErrorTimestampOutOfBallpark
   | ClientRevokeUserErrorUserIsSelf
@@ -2781,6 +3126,7 @@ export type ClientRevokeUserError =
 // ClientSetupShamirRecoveryError
 export enum ClientSetupShamirRecoveryErrorTag {
     AuthorAmongRecipients = 'ClientSetupShamirRecoveryErrorAuthorAmongRecipients',
+    ClockDrift = 'ClientSetupShamirRecoveryErrorClockDrift',
     Internal = 'ClientSetupShamirRecoveryErrorInternal',
     InvalidCertificate = 'ClientSetupShamirRecoveryErrorInvalidCertificate',
     Offline = 'ClientSetupShamirRecoveryErrorOffline',
@@ -2797,6 +3143,10 @@ export interface ClientSetupShamirRecoveryErrorAuthorAmongRecipients {
     tag: ClientSetupShamirRecoveryErrorTag.AuthorAmongRecipients
     error: string
 }
+export interface ClientSetupShamirRecoveryErrorClockDrift {
+    tag: ClientSetupShamirRecoveryErrorTag.ClockDrift
+    error: string
+}
 export interface ClientSetupShamirRecoveryErrorInternal {
     tag: ClientSetupShamirRecoveryErrorTag.Internal
     error: string
@@ -2843,6 +3193,7 @@ export interface ClientSetupShamirRecoveryErrorTooManyShares {
 }
 export type ClientSetupShamirRecoveryError =
   | ClientSetupShamirRecoveryErrorAuthorAmongRecipients
+  | ClientSetupShamirRecoveryErrorClockDrift
   | ClientSetupShamirRecoveryErrorInternal
   | ClientSetupShamirRecoveryErrorInvalidCertificate
   | ClientSetupShamirRecoveryErrorOffline
@@ -2857,6 +3208,7 @@ export type ClientSetupShamirRecoveryError =
 // ClientShareWorkspaceError
 export enum ClientShareWorkspaceErrorTag {
     AuthorNotAllowed = 'ClientShareWorkspaceErrorAuthorNotAllowed',
+    ClockDrift = 'ClientShareWorkspaceErrorClockDrift',
     Internal = 'ClientShareWorkspaceErrorInternal',
     InvalidCertificate = 'ClientShareWorkspaceErrorInvalidCertificate',
     InvalidKeysBundle = 'ClientShareWorkspaceErrorInvalidKeysBundle',
@@ -2875,6 +3227,10 @@ export interface ClientShareWorkspaceErrorAuthorNotAllowed {
     tag: ClientShareWorkspaceErrorTag.AuthorNotAllowed
     error: string
 }
+export interface ClientShareWorkspaceErrorClockDrift {
+    tag: ClientShareWorkspaceErrorTag.ClockDrift
+    error: string
+}
 export interface ClientShareWorkspaceErrorInternal {
     tag: ClientShareWorkspaceErrorTag.Internal
     error: string
@@ -2929,6 +3285,7 @@ export interface ClientShareWorkspaceErrorWorkspaceNotFound {
 }
 export type ClientShareWorkspaceError =
   | ClientShareWorkspaceErrorAuthorNotAllowed
+  | ClientShareWorkspaceErrorClockDrift
   | ClientShareWorkspaceErrorInternal
   | ClientShareWorkspaceErrorInvalidCertificate
   | ClientShareWorkspaceErrorInvalidKeysBundle
@@ -3164,12 +3521,108 @@ export type ClientTotpSetupStatusError =
   | ClientTotpSetupStatusErrorInternal
   | ClientTotpSetupStatusErrorOffline
 
+// ClientTransferWorkspaceOwnershipError
+export enum ClientTransferWorkspaceOwnershipErrorTag {
+    AuthorNotAllowed = 'ClientTransferWorkspaceOwnershipErrorAuthorNotAllowed',
+    ClockDrift = 'ClientTransferWorkspaceOwnershipErrorClockDrift',
+    Internal = 'ClientTransferWorkspaceOwnershipErrorInternal',
+    InvalidCertificate = 'ClientTransferWorkspaceOwnershipErrorInvalidCertificate',
+    InvalidKeysBundle = 'ClientTransferWorkspaceOwnershipErrorInvalidKeysBundle',
+    NewOwnerIsOutsider = 'ClientTransferWorkspaceOwnershipErrorNewOwnerIsOutsider',
+    NewOwnerIsSelf = 'ClientTransferWorkspaceOwnershipErrorNewOwnerIsSelf',
+    NewOwnerNotFound = 'ClientTransferWorkspaceOwnershipErrorNewOwnerNotFound',
+    NewOwnerRevoked = 'ClientTransferWorkspaceOwnershipErrorNewOwnerRevoked',
+    Offline = 'ClientTransferWorkspaceOwnershipErrorOffline',
+    RealmDeleted = 'ClientTransferWorkspaceOwnershipErrorRealmDeleted',
+    Stopped = 'ClientTransferWorkspaceOwnershipErrorStopped',
+    TimestampOutOfBallpark = 'ClientTransferWorkspaceOwnershipErrorTimestampOutOfBallpark',
+    WorkspaceNotFound = 'ClientTransferWorkspaceOwnershipErrorWorkspaceNotFound',
+}
+
+export interface ClientTransferWorkspaceOwnershipErrorAuthorNotAllowed {
+    tag: ClientTransferWorkspaceOwnershipErrorTag.AuthorNotAllowed
+    error: string
+}
+export interface ClientTransferWorkspaceOwnershipErrorClockDrift {
+    tag: ClientTransferWorkspaceOwnershipErrorTag.ClockDrift
+    error: string
+}
+export interface ClientTransferWorkspaceOwnershipErrorInternal {
+    tag: ClientTransferWorkspaceOwnershipErrorTag.Internal
+    error: string
+}
+export interface ClientTransferWorkspaceOwnershipErrorInvalidCertificate {
+    tag: ClientTransferWorkspaceOwnershipErrorTag.InvalidCertificate
+    error: string
+}
+export interface ClientTransferWorkspaceOwnershipErrorInvalidKeysBundle {
+    tag: ClientTransferWorkspaceOwnershipErrorTag.InvalidKeysBundle
+    error: string
+}
+export interface ClientTransferWorkspaceOwnershipErrorNewOwnerIsOutsider {
+    tag: ClientTransferWorkspaceOwnershipErrorTag.NewOwnerIsOutsider
+    error: string
+}
+export interface ClientTransferWorkspaceOwnershipErrorNewOwnerIsSelf {
+    tag: ClientTransferWorkspaceOwnershipErrorTag.NewOwnerIsSelf
+    error: string
+}
+export interface ClientTransferWorkspaceOwnershipErrorNewOwnerNotFound {
+    tag: ClientTransferWorkspaceOwnershipErrorTag.NewOwnerNotFound
+    error: string
+}
+export interface ClientTransferWorkspaceOwnershipErrorNewOwnerRevoked {
+    tag: ClientTransferWorkspaceOwnershipErrorTag.NewOwnerRevoked
+    error: string
+}
+export interface ClientTransferWorkspaceOwnershipErrorOffline {
+    tag: ClientTransferWorkspaceOwnershipErrorTag.Offline
+    error: string
+}
+export interface ClientTransferWorkspaceOwnershipErrorRealmDeleted {
+    tag: ClientTransferWorkspaceOwnershipErrorTag.RealmDeleted
+    error: string
+}
+export interface ClientTransferWorkspaceOwnershipErrorStopped {
+    tag: ClientTransferWorkspaceOwnershipErrorTag.Stopped
+    error: string
+}
+export interface ClientTransferWorkspaceOwnershipErrorTimestampOutOfBallpark {
+    tag: ClientTransferWorkspaceOwnershipErrorTag.TimestampOutOfBallpark
+    error: string
+    serverTimestamp: DateTime
+    clientTimestamp: DateTime
+    ballparkClientEarlyOffset: number
+    ballparkClientLateOffset: number
+}
+export interface ClientTransferWorkspaceOwnershipErrorWorkspaceNotFound {
+    tag: ClientTransferWorkspaceOwnershipErrorTag.WorkspaceNotFound
+    error: string
+}
+export type ClientTransferWorkspaceOwnershipError =
+  | ClientTransferWorkspaceOwnershipErrorAuthorNotAllowed
+  | ClientTransferWorkspaceOwnershipErrorClockDrift
+  | ClientTransferWorkspaceOwnershipErrorInternal
+  | ClientTransferWorkspaceOwnershipErrorInvalidCertificate
+  | ClientTransferWorkspaceOwnershipErrorInvalidKeysBundle
+  | ClientTransferWorkspaceOwnershipErrorNewOwnerIsOutsider
+  | ClientTransferWorkspaceOwnershipErrorNewOwnerIsSelf
+  | ClientTransferWorkspaceOwnershipErrorNewOwnerNotFound
+  | ClientTransferWorkspaceOwnershipErrorNewOwnerRevoked
+  | ClientTransferWorkspaceOwnershipErrorOffline
+  | ClientTransferWorkspaceOwnershipErrorRealmDeleted
+  | ClientTransferWorkspaceOwnershipErrorStopped
+  | ClientTransferWorkspaceOwnershipErrorTimestampOutOfBallpark
+  | ClientTransferWorkspaceOwnershipErrorWorkspaceNotFound
+
 // ClientUserUpdateProfileError
 export enum ClientUserUpdateProfileErrorTag {
     AuthorNotAllowed = 'ClientUserUpdateProfileErrorAuthorNotAllowed',
+    ClockDrift = 'ClientUserUpdateProfileErrorClockDrift',
     Internal = 'ClientUserUpdateProfileErrorInternal',
     InvalidCertificate = 'ClientUserUpdateProfileErrorInvalidCertificate',
     Offline = 'ClientUserUpdateProfileErrorOffline',
+    SoleOwnerOfWorkspaces = 'ClientUserUpdateProfileErrorSoleOwnerOfWorkspaces',
     Stopped = 'ClientUserUpdateProfileErrorStopped',
     TimestampOutOfBallpark = 'ClientUserUpdateProfileErrorTimestampOutOfBallpark',
     UserIsSelf = 'ClientUserUpdateProfileErrorUserIsSelf',
@@ -3181,6 +3634,10 @@ export interface ClientUserUpdateProfileErrorAuthorNotAllowed {
     tag: ClientUserUpdateProfileErrorTag.AuthorNotAllowed
     error: string
 }
+export interface ClientUserUpdateProfileErrorClockDrift {
+    tag: ClientUserUpdateProfileErrorTag.ClockDrift
+    error: string
+}
 export interface ClientUserUpdateProfileErrorInternal {
     tag: ClientUserUpdateProfileErrorTag.Internal
     error: string
@@ -3193,6 +3650,11 @@ export interface ClientUserUpdateProfileErrorOffline {
     tag: ClientUserUpdateProfileErrorTag.Offline
     error: string
 }
+export interface ClientUserUpdateProfileErrorSoleOwnerOfWorkspaces {
+    tag: ClientUserUpdateProfileErrorTag.SoleOwnerOfWorkspaces
+    error: string
+    workspaces: Array<VlobID>
+}
 export interface ClientUserUpdateProfileErrorStopped {
     tag: ClientUserUpdateProfileErrorTag.Stopped
     error: string
@@ -3215,9 +3677,11 @@ export interface ClientUserUpdateProfileErrorUserRevoked {
 }
 export type ClientUserUpdateProfileError =
   | ClientUserUpdateProfileErrorAuthorNotAllowed
+  | ClientUserUpdateProfileErrorClockDrift
   | ClientUserUpdateProfileErrorInternal
   | ClientUserUpdateProfileErrorInvalidCertificate
   | ClientUserUpdateProfileErrorOffline
+  | ClientUserUpdateProfileErrorSoleOwnerOfWorkspaces
   | ClientUserUpdateProfileErrorStopped
   | ClientUserUpdateProfileErrorTimestampOutOfBallpark
   | ClientUserUpdateProfileErrorUserIsSelf
@@ -3227,7 +3691,10 @@ export type ClientUserUpdateProfileError =
 // DevicePrimaryProtectionStrategy
 export enum DevicePrimaryProtectionStrategyTag {
     AccountVault = 'DevicePrimaryProtectionStrategyAccountVault',
+    Fido2 = 'DevicePrimaryProtectionStrategyFido2',
     Keyring = 'DevicePrimaryProtectionStrategyKeyring',
+    MachineKey = 'DevicePrimaryProtectionStrategyMachineKey',
+    Multi = 'DevicePrimaryProtectionStrategyMulti',
     OpenBao = 'DevicePrimaryProtectionStrategyOpenBao',
     PKI = 'DevicePrimaryProtectionStrategyPKI',
     Password = 'DevicePrimaryProtectionStrategyPassword',
@@ -3237,9 +3704,21 @@ export interface DevicePrimaryProtectionStrategyAccountVault {
     tag: DevicePrimaryProtectionStrategyTag.AccountVault
     accountHandle: Handle
 }
+export interface DevicePrimaryProtectionStrategyFido2 {
+    tag: DevicePrimaryProtectionStrategyTag.Fido2
+    pin: Password | null
+}
 export interface DevicePrimaryProtectionStrategyKeyring {
     tag: DevicePrimaryProtectionStrategyTag.Keyring
 }
+export interface DevicePrimaryProtectionStrategyMachineKey {
+    tag: DevicePrimaryProtectionStrategyTag.MachineKey
+    machineKeyFile: Path
+}
+export interface DevicePrimaryProtectionStrategyMulti {
+    tag: DevicePrimaryProtectionStrategyTag.Multi
+    alternatives: Array<DeviceAlternativeProtection>
+}
 export interface DevicePrimaryProtectionStrategyOpenBao {
     tag: DevicePrimaryProtectionStrategyTag.OpenBao
     openbaoServerUrl: string
@@ -3248,6 +3727,7 @@ export interface DevicePrimaryProtectionStrategyOpenBao {
     openbaoEntityId: string
     openbaoAuthToken: string
     openbaoPreferredAuthId: string
+    openbaoReauthenticationMethod: OpenBaoAuthMethod | null
 }
 export interface DevicePrimaryProtectionStrategyPKI {
     tag: DevicePrimaryProtectionStrategyTag.PKI
@@ -3259,7 +3739,10 @@ export interface DevicePrimaryProtectionStrategyPassword {
 }
 export type DevicePrimaryProtectionStrategy =
   | DevicePrimaryProtectionStrategyAccountVault
+  | DevicePrimaryProtectionStrategyFido2
   | DevicePrimaryProtectionStrategyKeyring
+  | DevicePrimaryProtectionStrategyMachineKey
+  | DevicePrimaryProtectionStrategyMulti
   | DevicePrimaryProtectionStrategyOpenBao
   | DevicePrimaryProtectionStrategyPKI
   | DevicePrimaryProtectionStrategyPassword
@@ -3361,6 +3844,7 @@ export enum GreetInProgressErrorTag {
     ActiveUsersLimitReached = 'GreetInProgressErrorActiveUsersLimitReached',
     AlreadyDeleted = 'GreetInProgressErrorAlreadyDeleted',
     Cancelled = 'GreetInProgressErrorCancelled',
+    ClockDrift = 'GreetInProgressErrorClockDrift',
     CorruptedInviteUserData = 'GreetInProgressErrorCorruptedInviteUserData',
     CorruptedSharedSecretKey = 'GreetInProgressErrorCorruptedSharedSecretKey',
     DeviceAlreadyExists = 'GreetInProgressErrorDeviceAlreadyExists',
@@ -3389,6 +3873,10 @@ export interface GreetInProgressErrorCancelled {
     tag: GreetInProgressErrorTag.Cancelled
     error: string
 }
+export interface GreetInProgressErrorClockDrift {
+    tag: GreetInProgressErrorTag.ClockDrift
+    error: string
+}
 export interface GreetInProgressErrorCorruptedInviteUserData {
     tag: GreetInProgressErrorTag.CorruptedInviteUserData
     error: string
@@ -3456,6 +3944,7 @@ export type GreetInProgressError =
   | GreetInProgressErrorActiveUsersLimitReached
   | GreetInProgressErrorAlreadyDeleted
   | GreetInProgressErrorCancelled
+  | GreetInProgressErrorClockDrift
   | GreetInProgressErrorCorruptedInviteUserData
   | GreetInProgressErrorCorruptedSharedSecretKey
   | GreetInProgressErrorDeviceAlreadyExists
@@ -3473,7 +3962,9 @@ export type GreetInProgressError =
 
 // ImportRecoveryDeviceError
 export enum ImportRecoveryDeviceErrorTag {
+    ClockDrift = 'ImportRecoveryDeviceErrorClockDrift',
     DecryptionFailed = 'ImportRecoveryDeviceErrorDecryptionFailed',
+    Fido2HmacSecretNotSupported = 'ImportRecoveryDeviceErrorFido2HmacSecretNotSupported',
     Internal = 'ImportRecoveryDeviceErrorInternal',
     InvalidCertificate = 'ImportRecoveryDeviceErrorInvalidCertificate',
     InvalidData = 'ImportRecoveryDeviceErrorInvalidData',
@@ -3487,10 +3978,18 @@ export enum ImportRecoveryDeviceErrorTag {
     TimestampOutOfBallpark = 'ImportRecoveryDeviceErrorTimestampOutOfBallpark',
 }
 
+export interface ImportRecoveryDeviceErrorClockDrift {
+    tag: ImportRecoveryDeviceErrorTag.ClockDrift
+    error: string
+}
 export interface ImportRecoveryDeviceErrorDecryptionFailed {
     tag: ImportRecoveryDeviceErrorTag.DecryptionFailed
     error: string
 }
+export interface ImportRecoveryDeviceErrorFido2HmacSecretNotSupported {
+    tag: ImportRecoveryDeviceErrorTag.Fido2HmacSecretNotSupported
+    error: string
+}
 export interface ImportRecoveryDeviceErrorInternal {
     tag: ImportRecoveryDeviceErrorTag.Internal
     error: string
@@ -3540,7 +4039,9 @@ export interface ImportRecoveryDeviceErrorTimestampOutOfBallpark {
     ballparkClientLateOffset: number
 }
 export type ImportRecoveryDeviceError =
+  | ImportRecoveryDeviceErrorClockDrift
   | ImportRecoveryDeviceErrorDecryptionFailed
+  | ImportRecoveryDeviceErrorFido2HmacSecretNotSupported
   | ImportRecoveryDeviceErrorInternal
   | ImportRecoveryDeviceErrorInvalidCertificate
   | ImportRecoveryDeviceErrorInvalidData
@@ -3756,6 +4257,36 @@ export type OpenBaoAuthConfig =
   | OpenBaoAuthConfigOIDCHexagone
   | OpenBaoAuthConfigOIDCProConnect
 
+// OpenBaoAuthMethod
+export enum OpenBaoAuthMethodTag {
+    AppRole = 'OpenBaoAuthMethodAppRole',
+    Jwt = 'OpenBaoAuthMethodJwt',
+    Userpass = 'OpenBaoAuthMethodUserpass',
+}
+
+export interface OpenBaoAuthMethodAppRole {
+    tag: OpenBaoAuthMethodTag.AppRole
+    mountPath: string
+    roleId: string
+    secretId: string
+}
+export interface OpenBaoAuthMethodJwt {
+    tag: OpenBaoAuthMethodTag.Jwt
+    mountPath: string
+    role: string
+    jwt: string
+}
+export interface OpenBaoAuthMethodUserpass {
+    tag: OpenBaoAuthMethodTag.Userpass
+    mountPath: string
+    username: string
+    password: string
+}
+export type OpenBaoAuthMethod =
+  | OpenBaoAuthMethodAppRole
+  | OpenBaoAuthMethodJwt
+  | OpenBaoAuthMethodUserpass
+
 // OpenBaoListSelfEmailsError
 export enum OpenBaoListSelfEmailsErrorTag {
     BadServerResponse = 'OpenBaoListSelfEmailsErrorBadServerResponse',
@@ -4510,6 +5041,7 @@ export enum SubmitterFinalizeAsyncEnrollmentErrorTag {
     OpenBaoNoServerResponse = 'SubmitterFinalizeAsyncEnrollmentErrorOpenBaoNoServerResponse',
     PKICannotOpenCertificateStore = 'SubmitterFinalizeAsyncEnrollmentErrorPKICannotOpenCertificateStore',
     PKIUnusableX509CertificateReference = 'SubmitterFinalizeAsyncEnrollmentErrorPKIUnusableX509CertificateReference',
+    SaveDeviceFido2HmacSecretNotSupported = 'SubmitterFinalizeAsyncEnrollmentErrorSaveDeviceFido2HmacSecretNotSupported',
     SaveDeviceInvalidPath = 'SubmitterFinalizeAsyncEnrollmentErrorSaveDeviceInvalidPath',
     SaveDeviceRemoteOpaqueKeyUploadFailed = 'SubmitterFinalizeAsyncEnrollmentErrorSaveDeviceRemoteOpaqueKeyUploadFailed',
     SaveDeviceRemoteOpaqueKeyUploadOffline = 'SubmitterFinalizeAsyncEnrollmentErrorSaveDeviceRemoteOpaqueKeyUploadOffline',
@@ -4575,6 +5107,10 @@ export interface SubmitterFinalizeAsyncEnrollmentErrorPKIUnusableX509Certificate
     tag: SubmitterFinalizeAsyncEnrollmentErrorTag.PKIUnusableX509CertificateReference
     error: string
 }
+export interface SubmitterFinalizeAsyncEnrollmentErrorSaveDeviceFido2HmacSecretNotSupported {
+    tag: SubmitterFinalizeAsyncEnrollmentErrorTag.SaveDeviceFido2HmacSecretNotSupported
+    error: string
+}
 export interface SubmitterFinalizeAsyncEnrollmentErrorSaveDeviceInvalidPath {
     tag: SubmitterFinalizeAsyncEnrollmentErrorTag.SaveDeviceInvalidPath
     error: string
@@ -4603,6 +5139,7 @@ export type SubmitterFinalizeAsyncEnrollmentError =
   | SubmitterFinalizeAsyncEnrollmentErrorOpenBaoNoServerResponse
   | SubmitterFinalizeAsyncEnrollmentErrorPKICannotOpenCertificateStore
   | SubmitterFinalizeAsyncEnrollmentErrorPKIUnusableX509CertificateReference
+  | SubmitterFinalizeAsyncEnrollmentErrorSaveDeviceFido2HmacSecretNotSupported
   | SubmitterFinalizeAsyncEnrollmentErrorSaveDeviceInvalidPath
   | SubmitterFinalizeAsyncEnrollmentErrorSaveDeviceRemoteOpaqueKeyUploadFailed
   | SubmitterFinalizeAsyncEnrollmentErrorSaveDeviceRemoteOpaqueKeyUploadOffline
@@ -4771,6 +5308,7 @@ export type TotpSetupStatusAnonymousError =
 // UpdateDeviceError
 export enum UpdateDeviceErrorTag {
     DecryptionFailed = 'UpdateDeviceErrorDecryptionFailed',
+    Fido2HmacSecretNotSupported = 'UpdateDeviceErrorFido2HmacSecretNotSupported',
     Internal = 'UpdateDeviceErrorInternal',
     InvalidData = 'UpdateDeviceErrorInvalidData',
     InvalidPath = 'UpdateDeviceErrorInvalidPath',
@@ -4784,6 +5322,10 @@ export interface UpdateDeviceErrorDecryptionFailed {
     tag: UpdateDeviceErrorTag.DecryptionFailed
     error: string
 }
+export interface UpdateDeviceErrorFido2HmacSecretNotSupported {
+    tag: UpdateDeviceErrorTag.Fido2HmacSecretNotSupported
+    error: string
+}
 export interface UpdateDeviceErrorInternal {
     tag: UpdateDeviceErrorTag.Internal
     error: string
@@ -4814,6 +5356,7 @@ export interface UpdateDeviceErrorTOTPDecryptionFailed {
 }
 export type UpdateDeviceError =
   | UpdateDeviceErrorDecryptionFailed
+  | UpdateDeviceErrorFido2HmacSecretNotSupported
   | UpdateDeviceErrorInternal
   | UpdateDeviceErrorInvalidData
   | UpdateDeviceErrorInvalidPath
@@ -5334,6 +5877,18 @@ export type WorkspaceGeneratePathAddrError =
   | WorkspaceGeneratePathAddrErrorRealmDeleted
   | WorkspaceGeneratePathAddrErrorStopped
 
+// WorkspaceGetRemoteOnlyEntriesError
+export enum WorkspaceGetRemoteOnlyEntriesErrorTag {
+    Internal = 'WorkspaceGetRemoteOnlyEntriesErrorInternal',
+}
+
+export interface WorkspaceGetRemoteOnlyEntriesErrorInternal {
+    tag: WorkspaceGetRemoteOnlyEntriesErrorTag.Internal
+    error: string
+}
+export type WorkspaceGetRemoteOnlyEntriesError =
+  | WorkspaceGetRemoteOnlyEntriesErrorInternal
+
 // WorkspaceHistoryEntryStat
 export enum WorkspaceHistoryEntryStatTag {
     File = 'WorkspaceHistoryEntryStatFile',
@@ -5880,6 +6435,66 @@ export interface WorkspaceInfoErrorInternal {
 export type WorkspaceInfoError =
   | WorkspaceInfoErrorInternal
 
+// WorkspaceIsEntryRemoteOnlyError
+export enum WorkspaceIsEntryRemoteOnlyErrorTag {
+    EntryNotFound = 'WorkspaceIsEntryRemoteOnlyErrorEntryNotFound',
+    Internal = 'WorkspaceIsEntryRemoteOnlyErrorInternal',
+    InvalidCertificate = 'WorkspaceIsEntryRemoteOnlyErrorInvalidCertificate',
+    InvalidKeysBundle = 'WorkspaceIsEntryRemoteOnlyErrorInvalidKeysBundle',
+    InvalidManifest = 'WorkspaceIsEntryRemoteOnlyErrorInvalidManifest',
+    NoRealmAccess = 'WorkspaceIsEntryRemoteOnlyErrorNoRealmAccess',
+    Offline = 'WorkspaceIsEntryRemoteOnlyErrorOffline',
+    RealmDeleted = 'WorkspaceIsEntryRemoteOnlyErrorRealmDeleted',
+    Stopped = 'WorkspaceIsEntryRemoteOnlyErrorStopped',
+}
+
+export interface WorkspaceIsEntryRemoteOnlyErrorEntryNotFound {
+    tag: WorkspaceIsEntryRemoteOnlyErrorTag.EntryNotFound
+    error: string
+}
+export interface WorkspaceIsEntryRemoteOnlyErrorInternal {
+    tag: WorkspaceIsEntryRemoteOnlyErrorTag.Internal
+    error: string
+}
+export interface WorkspaceIsEntryRemoteOnlyErrorInvalidCertificate {
+    tag: WorkspaceIsEntryRemoteOnlyErrorTag.InvalidCertificate
+    error: string
+}
+export interface WorkspaceIsEntryRemoteOnlyErrorInvalidKeysBundle {
+    tag: WorkspaceIsEntryRemoteOnlyErrorTag.InvalidKeysBundle
+    error: string
+}
+export interface WorkspaceIsEntryRemoteOnlyErrorInvalidManifest {
+    tag: WorkspaceIsEntryRemoteOnlyErrorTag.InvalidManifest
+    error: string
+}
+export interface WorkspaceIsEntryRemoteOnlyErrorNoRealmAccess {
+    tag: WorkspaceIsEntryRemoteOnlyErrorTag.NoRealmAccess
+    error: string
+}
+export interface WorkspaceIsEntryRemoteOnlyErrorOffline {
+    tag: WorkspaceIsEntryRemoteOnlyErrorTag.Offline
+    error: string
+}
+export interface WorkspaceIsEntryRemoteOnlyErrorRealmDeleted {
+    tag: WorkspaceIsEntryRemoteOnlyErrorTag.RealmDeleted
+    error: string
+}
+export interface WorkspaceIsEntryRemoteOnlyErrorStopped {
+    tag: WorkspaceIsEntryRemoteOnlyErrorTag.Stopped
+    error: string
+}
+export type WorkspaceIsEntryRemoteOnlyError =
+  | WorkspaceIsEntryRemoteOnlyErrorEntryNotFound
+  | WorkspaceIsEntryRemoteOnlyErrorInternal
+  | WorkspaceIsEntryRemoteOnlyErrorInvalidCertificate
+  | WorkspaceIsEntryRemoteOnlyErrorInvalidKeysBundle
+  | WorkspaceIsEntryRemoteOnlyErrorInvalidManifest
+  | WorkspaceIsEntryRemoteOnlyErrorNoRealmAccess
+  | WorkspaceIsEntryRemoteOnlyErrorOffline
+  | WorkspaceIsEntryRemoteOnlyErrorRealmDeleted
+  | WorkspaceIsEntryRemoteOnlyErrorStopped
+
 // WorkspaceIsFileContentLocalError
 export enum WorkspaceIsFileContentLocalErrorTag {
     EntryNotFound = 'WorkspaceIsFileContentLocalErrorEntryNotFound',
@@ -6216,6 +6831,24 @@ export type WorkspaceRemoveEntryError =
   | WorkspaceRemoveEntryErrorRealmDeleted
   | WorkspaceRemoveEntryErrorStopped
 
+// WorkspaceSetRemoteOnlyEntriesError
+export enum WorkspaceSetRemoteOnlyEntriesErrorTag {
+    Internal = 'WorkspaceSetRemoteOnlyEntriesErrorInternal',
+    Stopped = 'WorkspaceSetRemoteOnlyEntriesErrorStopped',
+}
+
+export interface WorkspaceSetRemoteOnlyEntriesErrorInternal {
+    tag: WorkspaceSetRemoteOnlyEntriesErrorTag.Internal
+    error: string
+}
+export interface WorkspaceSetRemoteOnlyEntriesErrorStopped {
+    tag: WorkspaceSetRemoteOnlyEntriesErrorTag.Stopped
+    error: string
+}
+export type WorkspaceSetRemoteOnlyEntriesError =
+  | WorkspaceSetRemoteOnlyEntriesErrorInternal
+  | WorkspaceSetRemoteOnlyEntriesErrorStopped
+
 // WorkspaceStatEntryError
 export enum WorkspaceStatEntryErrorTag {
     EntryNotFound = 'WorkspaceStatEntryErrorEntryNotFound',
@@ -6759,6 +7392,10 @@ export interface LibParsecPlugin {
         client: Handle,
         enrollment_id: AsyncEnrollmentID
     ): Promise<Result<null, ClientRejectAsyncEnrollmentError>>
+    clientRekeyWorkspace(
+        client: Handle,
+        realm_id: VlobID
+    ): Promise<Result<null, ClientRekeyWorkspaceError>>
     clientRenameWorkspace(
         client: Handle,
         realm_id: VlobID,
@@ -6766,7 +7403,8 @@ export interface LibParsecPlugin {
     ): Promise<Result<null, ClientRenameWorkspaceError>>
     clientRevokeUser(
         client: Handle,
-        user: UserID
+        user: UserID,
+        orphaned_workspaces: OrphanedWorkspacesStrategy
     ): Promise<Result<null, ClientRevokeUserError>>
     clientSetupShamirRecovery(
         client_handle: Handle,
@@ -6777,7 +7415,8 @@ export interface LibParsecPlugin {
         client: Handle,
         realm_id: VlobID,
         recipient: UserID,
-        role: RealmRole | null
+        role: RealmRole | null,
+        expires_on: DateTime | null
     ): Promise<Result<null, ClientShareWorkspaceError>>
     clientStart(
         config: ClientConfig,
@@ -6816,15 +7455,23 @@ export interface LibParsecPlugin {
     clientTotpSetupStatus(
         client: Handle
     ): Promise<Result<TOTPSetupStatus, ClientTotpSetupStatusError>>
+    clientTransferWorkspaceOwnership(
+        client: Handle,
+        realm_id: VlobID,
+        new_owner: UserID
+    ): Promise<Result<null, ClientTransferWorkspaceOwnershipError>>
     clientUpdateUserProfile(
         client_handle: Handle,
         user: UserID,
-        new_profile: UserProfile
+        new_profile: UserProfile,
+        orphaned_workspaces: OrphanedWorkspacesStrategy
     ): Promise<Result<null, ClientUserUpdateProfileError>>
     getDefaultConfigDir(
     ): Promise<Path>
     getDefaultDataBaseDir(
     ): Promise<Path>
+    getDefaultMachineKeyFile(
+    ): Promise<Path>
     getDefaultMountpointBaseDir(
     ): Promise<Path>
     getPlatform(
@@ -7142,6 +7789,9 @@ export interface LibParsecPlugin {
         workspace: Handle,
         path: FsPath
     ): Promise<Result<ParsecWorkspacePathAddrAndRedirectionURL, WorkspaceGeneratePathAddrError>>
+    workspaceGetRemoteOnlyEntries(
+        workspace: Handle
+    ): Promise<Result<Array<VlobID>, WorkspaceGetRemoteOnlyEntriesError>>
     workspaceHistoryFdClose(
         workspace_history: Handle,
         fd: FileDescriptor
@@ -7208,6 +7858,10 @@ export interface LibParsecPlugin {
     workspaceInfo(
         workspace: Handle
     ): Promise<Result<StartedWorkspaceInfo, WorkspaceInfoError>>
+    workspaceIsEntryRemoteOnlyById(
+        workspace: Handle,
+        entry_id: VlobID
+    ): Promise<Result<boolean, WorkspaceIsEntryRemoteOnlyError>>
     workspaceIsFileContentLocal(
         workspace: Handle,
         path: FsPath
@@ -7259,6 +7913,10 @@ export interface LibParsecPlugin {
         dst_name: EntryName,
         mode: MoveEntryMode
     ): Promise<Result<null, WorkspaceMoveEntryError>>
+    workspaceSetRemoteOnlyEntries(
+        workspace: Handle,
+        entries: Array<VlobID>
+    ): Promise<Result<null, WorkspaceSetRemoteOnlyEntriesError>>
     workspaceStatEntry(
         workspace: Handle,
         path: FsPath
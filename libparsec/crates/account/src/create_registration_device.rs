@@ -2,7 +2,7 @@
 
 use std::sync::Arc;
 
-use libparsec_client::RegisterNewDeviceError;
+use libparsec_client::{ClockDriftError, RegisterNewDeviceError};
 use libparsec_client_connection::{AuthenticatedCmds, ConnectionError};
 use libparsec_types::prelude::*;
 
@@ -15,6 +15,8 @@ pub enum AccountCreateRegistrationDeviceError {
     #[error("Cannot communicate with the server: {0}")]
     Offline(#[from] ConnectionError),
     #[error(transparent)]
+    ClockDrift(#[from] ClockDriftError),
+    #[error(transparent)]
     Internal(#[from] anyhow::Error),
     #[error("Our clock ({client_timestamp}) and the server's one ({server_timestamp}) are too far apart")]
     TimestampOutOfBallpark {
@@ -95,6 +97,9 @@ pub(super) async fn account_create_registration_device(
             ballpark_client_early_offset,
             ballpark_client_late_offset,
         },
+        RegisterNewDeviceError::ClockDrift(err) => {
+            AccountCreateRegistrationDeviceError::ClockDrift(err)
+        }
         RegisterNewDeviceError::Internal(err) => {
             AccountCreateRegistrationDeviceError::Internal(err)
         }
@@ -2,7 +2,7 @@
 
 use std::sync::Arc;
 
-use libparsec_client::RegisterNewDeviceError;
+use libparsec_client::{ClockDriftError, RegisterNewDeviceError};
 use libparsec_client_connection::{AuthenticatedCmds, ConnectionError};
 use libparsec_platform_device_loader::{
     get_default_key_file, save_device, AvailableDevice, DeviceSaveStrategy, RemoteOperationServer,
@@ -23,6 +23,8 @@ pub enum AccountRegisterNewDeviceError {
     #[error("Cannot communicate with the server: {0}")]
     Offline(#[from] ConnectionError),
     #[error(transparent)]
+    ClockDrift(#[from] ClockDriftError),
+    #[error(transparent)]
     Internal(#[from] anyhow::Error),
     #[error("No space available")]
     NoSpaceAvailable,
@@ -51,6 +53,8 @@ pub enum AccountRegisterNewDeviceError {
         server: RemoteOperationServer,
         error: anyhow::Error,
     },
+    #[error("The FIDO2 authenticator doesn't support the hmac-secret extension")]
+    Fido2HmacSecretNotSupported,
 }
 
 pub(super) async fn account_register_new_device(
@@ -135,6 +139,7 @@ pub(super) async fn account_register_new_device(
             ballpark_client_early_offset,
             ballpark_client_late_offset,
         },
+        RegisterNewDeviceError::ClockDrift(err) => AccountRegisterNewDeviceError::ClockDrift(err),
         RegisterNewDeviceError::Internal(err) => AccountRegisterNewDeviceError::Internal(err),
     })?;
 
@@ -161,6 +166,9 @@ pub(super) async fn account_register_new_device(
                 SaveDeviceError::RemoteOpaqueKeyUploadFailed { server, error } => {
                     AccountRegisterNewDeviceError::RemoteOpaqueKeyUploadFailed { server, error }
                 }
+                SaveDeviceError::Fido2HmacSecretNotSupported => {
+                    AccountRegisterNewDeviceError::Fido2HmacSecretNotSupported
+                }
             })?;
 
     Ok(new_available_device)
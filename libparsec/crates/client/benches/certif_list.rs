@@ -0,0 +1,89 @@
+// Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+//! Benchmark the certificates listing operations (heavily used by the GUI) on
+//! an organization with a large number of users and workspaces.
+
+use std::sync::Arc;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use libparsec_client::{
+    CertificateOps, ClientConfig, EventBus, MountpointMountStrategy, OutboundSyncConfig,
+    WorkspaceStorageCacheSize,
+};
+use libparsec_client_connection::{AuthenticatedCmds, ProxyConfig};
+use libparsec_tests_fixtures::prelude::*;
+use libparsec_types::prelude::*;
+
+async fn certificates_ops_factory(env: &TestbedEnv, device: &Arc<LocalDevice>) -> CertificateOps {
+    let config = Arc::new(ClientConfig {
+        config_dir: env.discriminant_dir.clone(),
+        data_base_dir: env.discriminant_dir.clone(),
+        mountpoint_mount_strategy: MountpointMountStrategy::Disabled,
+        workspace_storage_cache_size: WorkspaceStorageCacheSize::Default,
+        proxy: ProxyConfig::default(),
+        outbound_sync: OutboundSyncConfig::default(),
+        pki_revocation_policy: Default::default(),
+        with_monitors: false,
+        prevent_sync_pattern: PreventSyncPattern::empty(),
+    });
+    let cmds = Arc::new(
+        AuthenticatedCmds::new(&config.config_dir, device.clone(), config.proxy.clone()).unwrap(),
+    );
+    CertificateOps::start(config, device.clone(), EventBus::default(), cmds)
+        .await
+        .unwrap()
+}
+
+fn certif_list_bench(c: &mut Criterion) {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap();
+
+    let (scope, ops) = runtime.block_on(async {
+        let scope = TestbedScope::start("largeorg", Run::WithoutServer)
+            .await
+            .unwrap();
+        let alice = scope.env.local_device("alice@dev1");
+        let ops = certificates_ops_factory(&scope.env, &alice).await;
+        (scope, ops)
+    });
+    let workspaces_ids: &Vec<VlobID> = scope.env.template.get_stuff("workspaces_ids");
+
+    c.bench_function("list_users", |b| {
+        b.iter(|| runtime.block_on(ops.list_users(false, None, None)).unwrap())
+    });
+    c.bench_function("list_users_skip_revoked", |b| {
+        b.iter(|| runtime.block_on(ops.list_users(true, None, None)).unwrap())
+    });
+    c.bench_function("list_users_paginated", |b| {
+        b.iter(|| {
+            runtime
+                .block_on(ops.list_users(false, Some(500), Some(50)))
+                .unwrap()
+        })
+    });
+    c.bench_function("list_workspace_users", |b| {
+        b.iter(|| {
+            runtime
+                .block_on(ops.list_workspace_users(workspaces_ids[0]))
+                .unwrap()
+        })
+    });
+    c.bench_function("get_current_self_realms_role", |b| {
+        b.iter(|| {
+            runtime
+                .block_on(ops.get_current_self_realms_role())
+                .unwrap()
+        })
+    });
+
+    runtime.block_on(async {
+        ops.stop().await.unwrap();
+        scope.stop().await;
+    });
+}
+
+criterion_group!(benches, certif_list_bench);
+criterion_main!(benches);
@@ -11,7 +11,12 @@ use super::{
     store::{CertificatesStoreWriteGuard, GetCertificateError, LastShamirRecovery},
     CertificateOps, UpTo,
 };
-use crate::{event_bus::EventInvalidCertificate, EventNewCertificates};
+use crate::{
+    event_bus::EventInvalidCertificate, EventBus, EventDeviceAdded, EventDeviceRevoked,
+    EventNewCertificates, EventRealmArchivingChanged, EventRealmRenamed, EventRealmRoleChanged,
+    EventSelfShared, EventSelfUnshared, EventSequesterServiceRevoked, EventShamirRecoveryChanged,
+    EventUserProfileUpdated, EventUserRevoked,
+};
 
 #[derive(Debug, Clone, thiserror::Error)]
 pub enum InvalidCertificateError {
@@ -69,6 +74,20 @@ pub enum InvalidCertificateError {
         hint: String,
         user_revoked_on: DateTime,
     },
+    #[error("Certificate `{hint}` breaks consistency: it refers to a device that doesn't exist")]
+    NonExistingRelatedDevice { hint: String },
+    #[error("Certificate `{hint}` breaks consistency: it refers to a device that belongs to another user ({device_user_id})")]
+    RelatedDeviceUserMismatch {
+        hint: String,
+        device_user_id: UserID,
+    },
+    #[error("Certificate `{hint}` breaks consistency: it refers to a device that has already been revoked on {device_revoked_on}")]
+    RelatedDeviceAlreadyRevoked {
+        hint: String,
+        device_revoked_on: DateTime,
+    },
+    #[error("Certificate `{hint}` breaks consistency: it refers to a group that doesn't exist")]
+    NonExistingRelatedGroup { hint: String },
     #[error("Certificate `{hint}` breaks consistency: it refers to a sequester service that has already been revoked on {service_revoked_on}")]
     RelatedSequesterServiceAlreadyRevoked {
         hint: String,
@@ -106,6 +125,8 @@ pub enum InvalidCertificateError {
     },
     #[error("Certificate `{hint}` breaks consistency: user has Outsider profile, and hence cannot have Owner/Manager role in the realm")]
     RealmOutsiderCannotBeOwnerOrManager { hint: String },
+    #[error("Certificate `{hint}` breaks consistency: a group can only have Reader/Contributor role in the realm")]
+    RealmGroupCannotBeOwnerOrManager { hint: String },
     #[error("Certificate `{hint}` breaks consistency: author already has a shamir recovery setup")]
     ShamirRecoveryAlreadySetup { hint: String },
     #[error("Certificate `{hint}` breaks consistency: author cannot be among the recipients")]
@@ -194,6 +215,11 @@ pub(super) async fn add_certificates_batch(
     let storage_initially_empty = initial_stored_last_timestamps.is_empty();
     let initial_self_profile = store.get_current_self_profile().await?;
 
+    // Dedicated events are only sent when new certificates are added on top of
+    // the ones we already know about: when the storage is initially empty the
+    // whole history is provided, which is better handled by a single generic event.
+    let mut change_events = vec![];
+
     // If a certificate is invalid we exit without any further validation: the
     // write operation is going to be rolled back.
 
@@ -206,6 +232,16 @@ pub(super) async fn add_certificates_batch(
             .await
             .map_err(send_event_on_invalid_certificate)?;
 
+        if !storage_initially_empty {
+            if let SequesterTopicArcCertificate::SequesterRevokedService(certif) = &cooked {
+                change_events.push(CertificateChangeEvent::SequesterServiceRevoked(
+                    EventSequesterServiceRevoked {
+                        service_id: certif.service_id,
+                    },
+                ));
+            }
+        }
+
         store.add_next_sequester_certificate(cooked, signed).await?;
         new_certificates += 1;
     }
@@ -217,6 +253,10 @@ pub(super) async fn add_certificates_batch(
             .await
             .map_err(send_event_on_invalid_certificate)?;
 
+        if !storage_initially_empty {
+            change_events.extend(CertificateChangeEvent::from_common_certificate(&cooked));
+        }
+
         store.add_next_common_certificate(cooked, signed).await?;
         new_certificates += 1;
     }
@@ -229,6 +269,17 @@ pub(super) async fn add_certificates_batch(
                 .await
                 .map_err(send_event_on_invalid_certificate)?;
 
+            if !storage_initially_empty {
+                // Must be done before adding the certificate given we need the previous role
+                CertificateChangeEvent::from_realm_certificate(
+                    ops,
+                    store,
+                    &cooked,
+                    &mut change_events,
+                )
+                .await?;
+            }
+
             store.add_next_realm_x_certificate(cooked, signed).await?;
             new_certificates += 1;
         }
@@ -290,6 +341,23 @@ pub(super) async fn add_certificates_batch(
             }
         }
 
+        if !storage_initially_empty {
+            let user_id = match &cooked {
+                ShamirRecoveryTopicArcCertificate::ShamirRecoveryBrief(certif) => {
+                    Some(certif.user_id)
+                }
+                ShamirRecoveryTopicArcCertificate::ShamirRecoveryDeletion(certif) => {
+                    Some(certif.setup_to_delete_user_id)
+                }
+                ShamirRecoveryTopicArcCertificate::ShamirRecoveryShare(_) => None,
+            };
+            if let Some(user_id) = user_id {
+                change_events.push(CertificateChangeEvent::ShamirRecoveryChanged(
+                    EventShamirRecoveryChanged { user_id },
+                ));
+            }
+        }
+
         store
             .add_next_shamir_recovery_certificate(cooked, signed)
             .await?;
@@ -329,9 +397,13 @@ pub(super) async fn add_certificates_batch(
         }
     }
 
-    // TODO: sent dedicated events which certificates are added (e.g. share to self,
-    //       realm rename) unless `storage_initially_empty` is true, in which case
-    //       only send a single generic event.
+    // Send the dedicated events (if any, see `change_events` creation) before the
+    // generic one, so that monitors relying on the latter can consider the former
+    // have already been dispatched.
+
+    for change_event in change_events {
+        change_event.send(&ops.event_bus);
+    }
 
     // Finally send an event to notify the monitors that new certificates must now be
     // taken into account.
@@ -374,6 +446,132 @@ pub(super) async fn add_certificates_batch(
     Ok(MaybeRedactedSwitch::NoSwitch { new_certificates })
 }
 
+/// Dedicated event derived from a newly added certificate.
+///
+/// Those events are collected while validating the certificates, but only sent
+/// once the whole batch has been integrated (given an invalid certificate leads
+/// to rolling back the whole batch).
+enum CertificateChangeEvent {
+    UserRevoked(EventUserRevoked),
+    UserProfileUpdated(EventUserProfileUpdated),
+    DeviceAdded(EventDeviceAdded),
+    DeviceRevoked(EventDeviceRevoked),
+    RealmRoleChanged(EventRealmRoleChanged),
+    RealmRenamed(EventRealmRenamed),
+    RealmArchivingChanged(EventRealmArchivingChanged),
+    SelfShared(EventSelfShared),
+    SelfUnshared(EventSelfUnshared),
+    SequesterServiceRevoked(EventSequesterServiceRevoked),
+    ShamirRecoveryChanged(EventShamirRecoveryChanged),
+}
+
+impl CertificateChangeEvent {
+    fn from_common_certificate(cooked: &CommonTopicArcCertificate) -> Option<Self> {
+        match cooked {
+            CommonTopicArcCertificate::Device(certif) => {
+                Some(Self::DeviceAdded(EventDeviceAdded {
+                    user_id: certif.user_id,
+                    device_id: certif.device_id,
+                }))
+            }
+            CommonTopicArcCertificate::UserUpdate(certif) => {
+                Some(Self::UserProfileUpdated(EventUserProfileUpdated {
+                    user_id: certif.user_id,
+                    profile: certif.new_profile,
+                }))
+            }
+            CommonTopicArcCertificate::RevokedUser(certif) => {
+                Some(Self::UserRevoked(EventUserRevoked {
+                    user_id: certif.user_id,
+                }))
+            }
+            CommonTopicArcCertificate::RevokedDevice(certif) => {
+                Some(Self::DeviceRevoked(EventDeviceRevoked {
+                    user_id: certif.user_id,
+                    device_id: certif.device_id,
+                }))
+            }
+            // New user is always provided along with its first device certificate,
+            // hence `DeviceAdded` is enough to notify about it.
+            CommonTopicArcCertificate::User(_)
+            | CommonTopicArcCertificate::Group(_)
+            | CommonTopicArcCertificate::GroupMember(_) => None,
+        }
+    }
+
+    async fn from_realm_certificate(
+        ops: &CertificateOps,
+        store: &mut CertificatesStoreWriteGuard<'_>,
+        cooked: &RealmTopicArcCertificate,
+        change_events: &mut Vec<Self>,
+    ) -> anyhow::Result<()> {
+        match cooked {
+            RealmTopicArcCertificate::RealmRole(certif) => {
+                let old_role = store
+                    .get_last_user_realm_role(UpTo::Current, certif.user_id, certif.realm_id)
+                    .await?
+                    .and_then(|previous| previous.role);
+
+                if certif.user_id == ops.device.user_id {
+                    match (old_role, certif.role) {
+                        (None, Some(role)) => {
+                            change_events.push(Self::SelfShared(EventSelfShared {
+                                realm_id: certif.realm_id,
+                                role,
+                            }));
+                        }
+                        (Some(_), None) => {
+                            change_events.push(Self::SelfUnshared(EventSelfUnshared {
+                                realm_id: certif.realm_id,
+                            }));
+                        }
+                        _ => (),
+                    }
+                }
+
+                change_events.push(Self::RealmRoleChanged(EventRealmRoleChanged {
+                    realm_id: certif.realm_id,
+                    user_id: certif.user_id,
+                    old_role,
+                    new_role: certif.role,
+                }));
+            }
+            RealmTopicArcCertificate::RealmName(certif) => {
+                change_events.push(Self::RealmRenamed(EventRealmRenamed {
+                    realm_id: certif.realm_id,
+                }));
+            }
+            RealmTopicArcCertificate::RealmArchiving(certif) => {
+                change_events.push(Self::RealmArchivingChanged(EventRealmArchivingChanged {
+                    realm_id: certif.realm_id,
+                }));
+            }
+            // Group roles are materialized as realm role certificates for each
+            // group member, and key rotation is not a change visible to the user.
+            RealmTopicArcCertificate::RealmGroupRole(_)
+            | RealmTopicArcCertificate::RealmKeyRotation(_) => (),
+        }
+
+        Ok(())
+    }
+
+    fn send(&self, event_bus: &EventBus) {
+        match self {
+            Self::UserRevoked(event) => event_bus.send(event),
+            Self::UserProfileUpdated(event) => event_bus.send(event),
+            Self::DeviceAdded(event) => event_bus.send(event),
+            Self::DeviceRevoked(event) => event_bus.send(event),
+            Self::RealmRoleChanged(event) => event_bus.send(event),
+            Self::RealmRenamed(event) => event_bus.send(event),
+            Self::RealmArchivingChanged(event) => event_bus.send(event),
+            Self::SelfShared(event) => event_bus.send(event),
+            Self::SelfUnshared(event) => event_bus.send(event),
+            Self::SequesterServiceRevoked(event) => event_bus.send(event),
+            Self::ShamirRecoveryChanged(event) => event_bus.send(event),
+        }
+    }
+}
+
 macro_rules! verify_certificate_signature {
 
     // Entry points
@@ -411,13 +609,30 @@ macro_rules! verify_certificate_signature {
 
     (@internal, Device, $unsecure:ident, $author:expr, $ops:expr, $store:expr) => {
         match $store.get_device_verify_key_and_user_id(UpTo::Timestamp($unsecure.timestamp().to_owned()), $author).await {
-            Ok((author_verify_key, author_user_id)) => $unsecure
-                .verify_signature(&author_verify_key)
-                .map(|(certif, _)| (certif, author_user_id))
-                .map_err(|(unsecure, error)| {
-                    let hint = unsecure.hint();
-                    Box::new(InvalidCertificateError::Corrupted { hint, error })
-                }),
+            Ok((author_verify_key, author_user_id)) => {
+                // A revoked device cannot author anything after its revocation (note
+                // the check on the user being revoked is done later as part of the
+                // consistency checks).
+                match $store.get_revoked_device_certificate(UpTo::Timestamp($unsecure.timestamp().to_owned()), $author).await {
+                    Ok(None) => $unsecure
+                        .verify_signature(&author_verify_key)
+                        .map(|(certif, _)| (certif, author_user_id))
+                        .map_err(|(unsecure, error)| {
+                            let hint = unsecure.hint();
+                            Box::new(InvalidCertificateError::Corrupted { hint, error })
+                        }),
+                    Ok(Some(revoked_certificate)) => {
+                        let what = Box::new(InvalidCertificateError::RevokedAuthor {
+                            hint: $unsecure.hint(),
+                            author_revoked_on: revoked_certificate.timestamp,
+                        });
+                        Err(what)
+                    }
+                    Err(err) => {
+                        return Err(err.into());
+                    }
+                }
+            }
             Err(GetCertificateError::ExistButTooRecent {
                 certificate_timestamp,
                 ..
@@ -533,6 +748,34 @@ async fn validate_common_certificate(
 
             Ok(CommonTopicArcCertificate::RevokedUser(cooked))
         }
+        UnsecureCommonTopicCertificate::RevokedDevice(unsecure) => {
+            let (cooked, author_user_id) =
+                verify_certificate_signature!(Device, unsecure, ops, store)?;
+
+            // 3) The certificate is valid, last check is the consistency with other certificates
+            check_revoked_device_certificate_consistency(ops, store, &cooked, author_user_id)
+                .await?;
+
+            Ok(CommonTopicArcCertificate::RevokedDevice(cooked))
+        }
+        UnsecureCommonTopicCertificate::Group(unsecure) => {
+            let (cooked, author_user_id) =
+                verify_certificate_signature!(Device, unsecure, ops, store)?;
+
+            // 3) The certificate is valid, last check is the consistency with other certificates
+            check_group_certificate_consistency(ops, store, &cooked, author_user_id).await?;
+
+            Ok(CommonTopicArcCertificate::Group(cooked))
+        }
+        UnsecureCommonTopicCertificate::GroupMember(unsecure) => {
+            let (cooked, author_user_id) =
+                verify_certificate_signature!(Device, unsecure, ops, store)?;
+
+            // 3) The certificate is valid, last check is the consistency with other certificates
+            check_group_member_certificate_consistency(ops, store, &cooked, author_user_id).await?;
+
+            Ok(CommonTopicArcCertificate::GroupMember(cooked))
+        }
     }
 }
 
@@ -643,6 +886,27 @@ async fn validate_realm_certificate(
 
             Ok(RealmTopicArcCertificate::RealmArchiving(cooked))
         }
+        UnsecureRealmTopicCertificate::RealmGroupRole(unsecure) => {
+            let (cooked, author_user_id) =
+                verify_certificate_signature!(Device, unsecure, ops, store)?;
+
+            // 3) Ensure the certificate corresponds to the considered realm
+            if cooked.realm_id != realm_id {
+                let hint = format!("{cooked:?}");
+                let error = DataError::UnexpectedRealmID {
+                    expected: realm_id,
+                    got: cooked.realm_id,
+                };
+                let what = Box::new(InvalidCertificateError::Corrupted { hint, error });
+                return Err(CertifAddCertificatesBatchError::InvalidCertificate(what));
+            }
+
+            // 4) The certificate is valid, last check is the consistency with other certificates
+            check_realm_group_role_certificate_consistency(ops, store, &cooked, author_user_id)
+                .await?;
+
+            Ok(RealmTopicArcCertificate::RealmGroupRole(cooked))
+        }
     }
 }
 
@@ -910,6 +1174,25 @@ async fn check_user_not_revoked(
     Ok(())
 }
 
+async fn check_group_exists(
+    store: &mut CertificatesStoreWriteGuard<'_>,
+    up_to: DateTime,
+    group_id: GroupID,
+    mk_hint: impl FnOnce() -> String,
+) -> Result<Arc<GroupCertificate>, CertifAddCertificatesBatchError> {
+    match store
+        .get_group_certificate(UpTo::Timestamp(up_to), group_id)
+        .await?
+    {
+        Some(certif) => Ok(certif),
+        None => {
+            let hint = mk_hint();
+            let what = Box::new(InvalidCertificateError::NonExistingRelatedGroup { hint });
+            Err(CertifAddCertificatesBatchError::InvalidCertificate(what))
+        }
+    }
+}
+
 /// Admin existence has already been checked while fetching it device's verify key,
 /// so what's left is checking it is not revoked and (optionally) it profile
 async fn check_author_not_revoked_and_profile(
@@ -1413,6 +1696,214 @@ async fn check_revoked_user_certificate_consistency(
     Ok(())
 }
 
+async fn check_revoked_device_certificate_consistency(
+    ops: &CertificateOps,
+    store: &mut CertificatesStoreWriteGuard<'_>,
+    cooked: &RevokedDeviceCertificate,
+    author_user_id: UserID,
+) -> Result<(), CertifAddCertificatesBatchError> {
+    let mk_hint = || format!("{cooked:?}");
+
+    // 1) Certificate must be the newest among the ones in common topic.
+    // Note we also reject same timestamp given revoked device certificates is
+    // always created alone.
+
+    // We have already fetched the author's device certificate while validating the
+    // certificate, so `last_stored_timestamp` should never be `None`.
+    // And even if that's the case, the following checks involve fetching stored
+    // certificates and hence will fail.
+    if let Some(last_stored_timestamp) = store.get_last_timestamps().await?.common {
+        if cooked.timestamp <= last_stored_timestamp {
+            let hint = mk_hint();
+            let what = Box::new(InvalidCertificateError::InvalidTimestamp {
+                hint,
+                last_certificate_timestamp: last_stored_timestamp,
+            });
+            return Err(CertifAddCertificatesBatchError::InvalidCertificate(what));
+        }
+    }
+
+    // 2) Check author is not revoked, and has ADMIN profile unless it is revoking
+    // one of its own devices.
+
+    check_author_not_revoked_and_profile(
+        ops,
+        store,
+        cooked.timestamp,
+        author_user_id,
+        author_user_id != cooked.user_id,
+        mk_hint,
+    )
+    .await?;
+
+    // 3) Make sure the user exists
+
+    check_user_exists(store, cooked.timestamp, cooked.user_id, mk_hint).await?;
+
+    // 4) Make sure the user is not already revoked (in which case all its devices
+    // are already unusable)
+
+    check_user_not_revoked(store, cooked.timestamp, cooked.user_id, mk_hint).await?;
+
+    // 5) Make sure the device exists and belongs to the user
+
+    match store
+        .get_device_certificate(UpTo::Timestamp(cooked.timestamp), cooked.device_id)
+        .await
+    {
+        Ok(device_certificate) => {
+            if device_certificate.user_id != cooked.user_id {
+                let hint = mk_hint();
+                let what = Box::new(InvalidCertificateError::RelatedDeviceUserMismatch {
+                    hint,
+                    device_user_id: device_certificate.user_id,
+                });
+                return Err(CertifAddCertificatesBatchError::InvalidCertificate(what));
+            }
+        }
+
+        Err(GetCertificateError::NonExisting | GetCertificateError::ExistButTooRecent { .. }) => {
+            let hint = mk_hint();
+            let what = Box::new(InvalidCertificateError::NonExistingRelatedDevice { hint });
+            return Err(CertifAddCertificatesBatchError::InvalidCertificate(what));
+        }
+
+        // D'oh :/
+        Err(err @ GetCertificateError::Internal(_)) => {
+            return Err(CertifAddCertificatesBatchError::Internal(err.into()));
+        }
+    }
+
+    // 6) Make sure the device is not already revoked
+
+    if let Some(revoked_certificate) = store
+        .get_revoked_device_certificate(UpTo::Timestamp(cooked.timestamp), cooked.device_id)
+        .await?
+    {
+        let hint = mk_hint();
+        let what = Box::new(InvalidCertificateError::RelatedDeviceAlreadyRevoked {
+            hint,
+            device_revoked_on: revoked_certificate.timestamp,
+        });
+        return Err(CertifAddCertificatesBatchError::InvalidCertificate(what));
+    }
+
+    Ok(())
+}
+
+async fn check_group_certificate_consistency(
+    ops: &CertificateOps,
+    store: &mut CertificatesStoreWriteGuard<'_>,
+    cooked: &GroupCertificate,
+    author_user_id: UserID,
+) -> Result<(), CertifAddCertificatesBatchError> {
+    let mk_hint = || format!("{cooked:?}");
+
+    // 1) Certificate must be the newest among the ones in common topic.
+    // Note we also reject same timestamp given group certificate is always
+    // created alone.
+
+    if let Some(last_stored_timestamp) = store.get_last_timestamps().await?.common {
+        if cooked.timestamp <= last_stored_timestamp {
+            let hint = mk_hint();
+            let what = Box::new(InvalidCertificateError::InvalidTimestamp {
+                hint,
+                last_certificate_timestamp: last_stored_timestamp,
+            });
+            return Err(CertifAddCertificatesBatchError::InvalidCertificate(what));
+        }
+    }
+
+    // 2) Check author is not revoked and has ADMIN profile
+
+    check_author_not_revoked_and_profile(
+        ops,
+        store,
+        cooked.timestamp,
+        author_user_id,
+        true,
+        mk_hint,
+    )
+    .await?;
+
+    // 3) Make sure the group doesn't already exist
+
+    if store
+        .get_group_certificate(UpTo::Timestamp(cooked.timestamp), cooked.group_id)
+        .await?
+        .is_some()
+    {
+        let hint = mk_hint();
+        let what = Box::new(InvalidCertificateError::ContentAlreadyExists { hint });
+        return Err(CertifAddCertificatesBatchError::InvalidCertificate(what));
+    }
+
+    Ok(())
+}
+
+async fn check_group_member_certificate_consistency(
+    ops: &CertificateOps,
+    store: &mut CertificatesStoreWriteGuard<'_>,
+    cooked: &GroupMemberCertificate,
+    author_user_id: UserID,
+) -> Result<(), CertifAddCertificatesBatchError> {
+    let mk_hint = || format!("{cooked:?}");
+
+    // 1) Certificate must be the newest among the ones in common topic.
+    // Note we also reject same timestamp given group member certificate is always
+    // created alone.
+
+    if let Some(last_stored_timestamp) = store.get_last_timestamps().await?.common {
+        if cooked.timestamp <= last_stored_timestamp {
+            let hint = mk_hint();
+            let what = Box::new(InvalidCertificateError::InvalidTimestamp {
+                hint,
+                last_certificate_timestamp: last_stored_timestamp,
+            });
+            return Err(CertifAddCertificatesBatchError::InvalidCertificate(what));
+        }
+    }
+
+    // 2) Check author is not revoked and has ADMIN profile
+
+    check_author_not_revoked_and_profile(
+        ops,
+        store,
+        cooked.timestamp,
+        author_user_id,
+        true,
+        mk_hint,
+    )
+    .await?;
+
+    // 3) Make sure the group exists
+
+    check_group_exists(store, cooked.timestamp, cooked.group_id, mk_hint).await?;
+
+    // 4) Make sure the user exists, and is not revoked if it is added to the group
+    // (note there is no point in removing a revoked user from a group given it
+    // will lose access to the realms anyway, but this is still allowed).
+
+    check_user_exists(store, cooked.timestamp, cooked.user_id, mk_hint).await?;
+    if cooked.is_member {
+        check_user_not_revoked(store, cooked.timestamp, cooked.user_id, mk_hint).await?;
+    }
+
+    // 5) Make sure the certificate actually changes the user's membership
+
+    let is_current_member = store
+        .get_group_current_members(UpTo::Timestamp(cooked.timestamp), cooked.group_id)
+        .await?
+        .contains_key(&cooked.user_id);
+    if is_current_member == cooked.is_member {
+        let hint = mk_hint();
+        let what = Box::new(InvalidCertificateError::ContentAlreadyExists { hint });
+        return Err(CertifAddCertificatesBatchError::InvalidCertificate(what));
+    }
+
+    Ok(())
+}
+
 async fn check_realm_role_certificate_consistency(
     store: &mut CertificatesStoreWriteGuard<'_>,
     cooked: &RealmRoleCertificate,
@@ -1542,6 +2033,12 @@ async fn check_realm_role_certificate_consistency(
     let user_certificate =
         check_user_exists(store, cooked.timestamp, cooked.user_id, mk_hint).await?;
 
+    // 3.b) If the role has been given through a group, make sure the group exists
+
+    if let Some(group_id) = cooked.group_id {
+        check_group_exists(store, cooked.timestamp, group_id, mk_hint).await?;
+    }
+
     // 4) Make sure the user is not already revoked if we are giving it a role.
     // (note revoked users *will* be revoked from any realm they are part of once
     // an OWNER notifies the change).
@@ -1841,6 +2338,103 @@ async fn check_realm_archiving_certificate_consistency(
     Ok(())
 }
 
+async fn check_realm_group_role_certificate_consistency(
+    ops: &CertificateOps,
+    store: &mut CertificatesStoreWriteGuard<'_>,
+    cooked: &RealmGroupRoleCertificate,
+    author_user_id: UserID,
+) -> Result<(), CertifAddCertificatesBatchError> {
+    let mk_hint = || format!("{cooked:?}");
+
+    // 1) Certificate must be the newest among the ones in it realm's topic.
+    // Note we also reject same timestamp given realm group role certificate is
+    // always created alone.
+
+    let last_stored_timestamp = store
+        .get_last_timestamps()
+        .await?
+        .realm
+        .get(&cooked.realm_id)
+        .cloned();
+    if let Some(last_stored_timestamp) = last_stored_timestamp {
+        if cooked.timestamp <= last_stored_timestamp {
+            // We already know more recent certificates, hence this certificate
+            // cannot be added without breaking causality !
+            let hint = mk_hint();
+            let what = Box::new(InvalidCertificateError::InvalidTimestamp {
+                hint,
+                last_certificate_timestamp: last_stored_timestamp,
+            });
+            return Err(CertifAddCertificatesBatchError::InvalidCertificate(what));
+        }
+    }
+
+    // 2) Check author's realm role
+
+    let author_current_role = store
+        .get_last_user_realm_role(
+            UpTo::Timestamp(cooked.timestamp),
+            author_user_id,
+            cooked.realm_id,
+        )
+        .await?
+        .and_then(|certif| certif.role);
+    match author_current_role {
+        // Only an Owner can give/remove a role to a group
+        Some(RealmRole::Owner) => (),
+        Some(author_role) => {
+            let hint = mk_hint();
+            let what = Box::new(InvalidCertificateError::RealmAuthorNotOwner { hint, author_role });
+            return Err(CertifAddCertificatesBatchError::InvalidCertificate(what));
+        }
+        None => {
+            let hint = mk_hint();
+            let what = Box::new(InvalidCertificateError::RealmAuthorHasNoRole { hint });
+            return Err(CertifAddCertificatesBatchError::InvalidCertificate(what));
+        }
+    }
+
+    // 3) Make sure the author is not already revoked
+
+    check_author_not_revoked_and_profile(
+        ops,
+        store,
+        cooked.timestamp,
+        author_user_id,
+        false,
+        mk_hint,
+    )
+    .await?;
+
+    // 4) Make sure the group exists
+
+    check_group_exists(store, cooked.timestamp, cooked.group_id, mk_hint).await?;
+
+    // 5) A group can only be given READER/CONTRIBUTOR role: OWNER/MANAGER roles
+    // allow to manage the realm's members, which must stay an individual decision.
+
+    if matches!(cooked.role, Some(RealmRole::Owner | RealmRole::Manager)) {
+        let hint = mk_hint();
+        let what = Box::new(InvalidCertificateError::RealmGroupCannotBeOwnerOrManager { hint });
+        return Err(CertifAddCertificatesBatchError::InvalidCertificate(what));
+    }
+
+    // 6) The certificate must provide a new role
+
+    let group_current_role = store
+        .get_realm_current_groups_roles(UpTo::Timestamp(cooked.timestamp), cooked.realm_id)
+        .await?
+        .get(&cooked.group_id)
+        .and_then(|certif| certif.role);
+    if group_current_role == cooked.role {
+        let hint = mk_hint();
+        let what = Box::new(InvalidCertificateError::ContentAlreadyExists { hint });
+        return Err(CertifAddCertificatesBatchError::InvalidCertificate(what));
+    }
+
+    Ok(())
+}
+
 async fn check_shamir_recovery_brief_certificate_consistency(
     ops: &CertificateOps,
     store: &mut CertificatesStoreWriteGuard<'_>,
@@ -0,0 +1,865 @@
+// Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+use std::{collections::HashMap, num::NonZeroU8};
+
+use libparsec_types::prelude::*;
+
+use super::{
+    realm_keys_bundle::{self, CertifDecryptForRealmError, EncrytionUsage},
+    store::{CertifStoreError, CertificatesStoreReadGuard, GetCertificateQuery},
+    CertificateOps, InvalidCertificateError,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum CertifCertificateAuditLogError {
+    #[error("Component has stopped")]
+    Stopped,
+    #[error(transparent)]
+    InvalidCertificate(#[from] Box<InvalidCertificateError>),
+    #[error(transparent)]
+    Internal(#[from] anyhow::Error),
+}
+
+impl From<CertifStoreError> for CertifCertificateAuditLogError {
+    fn from(value: CertifStoreError) -> Self {
+        match value {
+            CertifStoreError::Stopped => Self::Stopped,
+            CertifStoreError::Internal(err) => err.into(),
+        }
+    }
+}
+
+/// Restrict the entries returned by the audit log, an entry must satisfy all the
+/// criteria to be returned.
+#[derive(Debug, Clone, Default)]
+pub struct CertificateAuditLogFilter {
+    /// Only keep the entries concerning the given user, or authored by it.
+    pub user: Option<UserID>,
+    /// Only keep the entries concerning the given realm.
+    pub realm: Option<VlobID>,
+    /// Only keep the entries that occurred at or after this date.
+    pub since: Option<DateTime>,
+    /// Only keep the entries that occurred at or before this date.
+    pub until: Option<DateTime>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CertificateAuditLogUser {
+    pub user_id: UserID,
+    pub human_handle: HumanHandle,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CertificateAuditLogAuthor {
+    pub device_id: DeviceID,
+    pub user: CertificateAuditLogUser,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CertificateAuditLogRealm {
+    pub realm_id: VlobID,
+    /// Name of the realm at the time of the entry, `None` if the realm had no name yet
+    /// or if its name cannot be decrypted (e.g. we are not part of the realm).
+    pub name: Option<EntryName>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CertificateAuditLogEvent {
+    UserCreated {
+        user: CertificateAuditLogUser,
+        profile: UserProfile,
+    },
+    UserProfileUpdated {
+        user: CertificateAuditLogUser,
+        profile: UserProfile,
+    },
+    UserRevoked {
+        user: CertificateAuditLogUser,
+    },
+    DeviceCreated {
+        user: CertificateAuditLogUser,
+        device_id: DeviceID,
+        device_label: DeviceLabel,
+    },
+    DeviceRevoked {
+        user: CertificateAuditLogUser,
+        device_id: DeviceID,
+    },
+    GroupCreated {
+        group_id: GroupID,
+        name: String,
+    },
+    GroupMemberChanged {
+        group_id: GroupID,
+        group_name: String,
+        user: CertificateAuditLogUser,
+        is_member: bool,
+    },
+    /// `role` is `None` if the user has been unshared
+    RealmRoleChanged {
+        realm: CertificateAuditLogRealm,
+        user: CertificateAuditLogUser,
+        role: Option<RealmRole>,
+    },
+    /// `role` is `None` if the group has been unshared
+    RealmGroupRoleChanged {
+        realm: CertificateAuditLogRealm,
+        group_id: GroupID,
+        group_name: String,
+        role: Option<RealmRole>,
+    },
+    /// `realm.name` is the new name of the realm
+    RealmRenamed {
+        realm: CertificateAuditLogRealm,
+    },
+    RealmKeyRotated {
+        realm: CertificateAuditLogRealm,
+        key_index: IndexInt,
+    },
+    RealmArchivingChanged {
+        realm: CertificateAuditLogRealm,
+        configuration: RealmArchivingConfiguration,
+    },
+    SequesterAuthorityCreated,
+    SequesterServiceCreated {
+        service_id: SequesterServiceID,
+        service_label: String,
+    },
+    SequesterServiceRevoked {
+        service_id: SequesterServiceID,
+        service_label: String,
+    },
+    /// `user` is the user to be recovered, `recipients` are sorted by user ID
+    ShamirRecoveryCreated {
+        user: CertificateAuditLogUser,
+        threshold: NonZeroU8,
+        recipients: Vec<(CertificateAuditLogUser, NonZeroU8)>,
+    },
+    /// `setup_timestamp` identifies the shamir recovery that has been deleted
+    ShamirRecoveryDeleted {
+        user: CertificateAuditLogUser,
+        setup_timestamp: DateTime,
+    },
+}
+
+impl CertificateAuditLogEvent {
+    /// The user the event is about, if any
+    pub fn user(&self) -> Option<&CertificateAuditLogUser> {
+        match self {
+            Self::UserCreated { user, .. }
+            | Self::UserProfileUpdated { user, .. }
+            | Self::UserRevoked { user }
+            | Self::DeviceCreated { user, .. }
+            | Self::DeviceRevoked { user, .. }
+            | Self::GroupMemberChanged { user, .. }
+            | Self::RealmRoleChanged { user, .. }
+            | Self::ShamirRecoveryCreated { user, .. }
+            | Self::ShamirRecoveryDeleted { user, .. } => Some(user),
+            Self::GroupCreated { .. }
+            | Self::RealmGroupRoleChanged { .. }
+            | Self::RealmRenamed { .. }
+            | Self::RealmKeyRotated { .. }
+            | Self::RealmArchivingChanged { .. }
+            | Self::SequesterAuthorityCreated
+            | Self::SequesterServiceCreated { .. }
+            | Self::SequesterServiceRevoked { .. } => None,
+        }
+    }
+
+    /// The realm the event is about, if any
+    pub fn realm(&self) -> Option<&CertificateAuditLogRealm> {
+        match self {
+            Self::RealmRoleChanged { realm, .. }
+            | Self::RealmGroupRoleChanged { realm, .. }
+            | Self::RealmRenamed { realm }
+            | Self::RealmKeyRotated { realm, .. }
+            | Self::RealmArchivingChanged { realm, .. } => Some(realm),
+            Self::UserCreated { .. }
+            | Self::UserProfileUpdated { .. }
+            | Self::UserRevoked { .. }
+            | Self::DeviceCreated { .. }
+            | Self::DeviceRevoked { .. }
+            | Self::GroupCreated { .. }
+            | Self::GroupMemberChanged { .. }
+            | Self::SequesterAuthorityCreated
+            | Self::SequesterServiceCreated { .. }
+            | Self::SequesterServiceRevoked { .. }
+            | Self::ShamirRecoveryCreated { .. }
+            | Self::ShamirRecoveryDeleted { .. } => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CertificateAuditLogEntry {
+    pub timestamp: DateTime,
+    /// `None` if the certificate has been signed by the root key (i.e. it has been
+    /// created during the organization bootstrap) or by the sequester authority.
+    pub author: Option<CertificateAuditLogAuthor>,
+    pub event: CertificateAuditLogEvent,
+}
+
+impl CertificateAuditLogFilter {
+    fn matches(&self, entry: &CertificateAuditLogEntry) -> bool {
+        if let Some(since) = self.since {
+            if entry.timestamp < since {
+                return false;
+            }
+        }
+        if let Some(until) = self.until {
+            if entry.timestamp > until {
+                return false;
+            }
+        }
+        if let Some(user_id) = self.user {
+            let is_author = entry
+                .author
+                .as_ref()
+                .is_some_and(|author| author.user.user_id == user_id);
+            let is_subject = entry
+                .event
+                .user()
+                .is_some_and(|user| user.user_id == user_id);
+            if !is_author && !is_subject {
+                return false;
+            }
+        }
+        if let Some(realm_id) = self.realm {
+            if !entry
+                .event
+                .realm()
+                .is_some_and(|realm| realm.realm_id == realm_id)
+            {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// Load the certificates matching the query, verify their signature and feed them
+/// one by one to the provided block (hence allowing the block to register new
+/// authors for the next certificates).
+macro_rules! for_each_verified_certificate {
+    ($store:ident, $ops:ident, $verify_keys:ident, $query:expr, $certif_type:ident, $author_device:expr, |$certif:ident| $body:block) => {
+        for signed in $store.get_multiple_signed_certificates($query).await? {
+            let unsecure = $certif_type::unsecure_load(signed).map_err(|error| {
+                // No information can be extracted from the binary data...
+                let hint = "<unknown>".into();
+                Box::new(InvalidCertificateError::Corrupted { hint, error })
+            })?;
+            let author_verify_key = match $author_device(unsecure.author()) {
+                None => $ops.device.root_verify_key(),
+                Some(author) => match $verify_keys.get(&author) {
+                    Some((verify_key, _)) => verify_key,
+                    None => {
+                        let hint = unsecure.hint();
+                        return Err(
+                            Box::new(InvalidCertificateError::NonExistingAuthor { hint }).into(),
+                        );
+                    }
+                },
+            };
+            let ($certif, _) =
+                unsecure
+                    .verify_signature(author_verify_key)
+                    .map_err(|(unsecure, error)| {
+                        let hint = unsecure.hint();
+                        Box::new(InvalidCertificateError::Corrupted { hint, error })
+                    })?;
+            $body
+        }
+    };
+}
+
+fn signer_device(signer: CertificateSigner) -> Option<DeviceID> {
+    match signer {
+        CertificateSigner::Root => None,
+        CertificateSigner::User(device_id) => Some(device_id),
+    }
+}
+
+pub(super) async fn certificate_audit_log(
+    ops: &CertificateOps,
+    filter: CertificateAuditLogFilter,
+) -> Result<Vec<CertificateAuditLogEntry>, CertifCertificateAuditLogError> {
+    let mut entries = ops
+        .store
+        .for_read(async |store| load_audit_log(ops, store).await)
+        .await??;
+
+    entries.retain(|entry| filter.matches(entry));
+
+    Ok(entries)
+}
+
+async fn load_audit_log(
+    ops: &CertificateOps,
+    store: &mut CertificatesStoreReadGuard<'_>,
+) -> Result<Vec<CertificateAuditLogEntry>, CertifCertificateAuditLogError> {
+    // 1) Load and verify all the certificates
+    //
+    // Certificates have already been validated when added to the storage, however
+    // we don't want an audit to rely on data that could have been tampered with
+    // afterward, hence their signature is checked again here.
+
+    // Devices come first since they are needed to verify all the other certificates.
+    // Note a device is always created by a previous device (or by the root key), so
+    // verifying them in chronological order is enough.
+    let mut verify_keys: HashMap<DeviceID, (VerifyKey, UserID)> = HashMap::new();
+    let mut devices = vec![];
+    for_each_verified_certificate!(
+        store,
+        ops,
+        verify_keys,
+        GetCertificateQuery::devices_certificates(),
+        DeviceCertificate,
+        signer_device,
+        |certif| {
+            verify_keys.insert(
+                certif.device_id,
+                (certif.verify_key.clone(), certif.user_id),
+            );
+            devices.push(certif);
+        }
+    );
+
+    let mut users = vec![];
+    for_each_verified_certificate!(
+        store,
+        ops,
+        verify_keys,
+        GetCertificateQuery::users_certificates(),
+        UserCertificate,
+        signer_device,
+        |certif| {
+            users.push(certif);
+        }
+    );
+
+    let mut users_updates = vec![];
+    for_each_verified_certificate!(
+        store,
+        ops,
+        verify_keys,
+        GetCertificateQuery::users_update_certificates(),
+        UserUpdateCertificate,
+        Some,
+        |certif| {
+            users_updates.push(certif);
+        }
+    );
+
+    let mut revoked_users = vec![];
+    for_each_verified_certificate!(
+        store,
+        ops,
+        verify_keys,
+        GetCertificateQuery::revoked_users_certificates(),
+        RevokedUserCertificate,
+        Some,
+        |certif| {
+            revoked_users.push(certif);
+        }
+    );
+
+    let mut revoked_devices = vec![];
+    for user in users.iter() {
+        for_each_verified_certificate!(
+            store,
+            ops,
+            verify_keys,
+            GetCertificateQuery::user_revoked_devices_certificates(&user.user_id),
+            RevokedDeviceCertificate,
+            Some,
+            |certif| {
+                revoked_devices.push(certif);
+            }
+        );
+    }
+
+    let mut groups = vec![];
+    for_each_verified_certificate!(
+        store,
+        ops,
+        verify_keys,
+        GetCertificateQuery::groups_certificates(),
+        GroupCertificate,
+        Some,
+        |certif| {
+            groups.push(certif);
+        }
+    );
+
+    let mut groups_members = vec![];
+    for group in groups.iter() {
+        for_each_verified_certificate!(
+            store,
+            ops,
+            verify_keys,
+            GetCertificateQuery::group_members_certificates(&group.group_id),
+            GroupMemberCertificate,
+            Some,
+            |certif| {
+                groups_members.push(certif);
+            }
+        );
+    }
+
+    let mut realms_roles = vec![];
+    for_each_verified_certificate!(
+        store,
+        ops,
+        verify_keys,
+        GetCertificateQuery::realms_roles_certificates(),
+        RealmRoleCertificate,
+        Some,
+        |certif| {
+            realms_roles.push(certif);
+        }
+    );
+
+    let mut realms_key_rotations = vec![];
+    for_each_verified_certificate!(
+        store,
+        ops,
+        verify_keys,
+        GetCertificateQuery::realms_key_rotation_certificates(),
+        RealmKeyRotationCertificate,
+        Some,
+        |certif| {
+            realms_key_rotations.push(certif);
+        }
+    );
+
+    // A realm is always created with a role certificate for its owner
+    let mut realms_ids: Vec<VlobID> = vec![];
+    for certif in realms_roles.iter() {
+        if !realms_ids.contains(&certif.realm_id) {
+            realms_ids.push(certif.realm_id);
+        }
+    }
+
+    let mut realms_names = vec![];
+    let mut realms_archivings = vec![];
+    let mut realms_groups_roles = vec![];
+    for realm_id in realms_ids.iter() {
+        for_each_verified_certificate!(
+            store,
+            ops,
+            verify_keys,
+            GetCertificateQuery::realm_name_certificates(realm_id),
+            RealmNameCertificate,
+            Some,
+            |certif| {
+                realms_names.push(certif);
+            }
+        );
+        for_each_verified_certificate!(
+            store,
+            ops,
+            verify_keys,
+            GetCertificateQuery::realm_archiving_certificates(realm_id),
+            RealmArchivingCertificate,
+            Some,
+            |certif| {
+                realms_archivings.push(certif);
+            }
+        );
+        for_each_verified_certificate!(
+            store,
+            ops,
+            verify_keys,
+            GetCertificateQuery::realm_group_roles_certificates(realm_id),
+            RealmGroupRoleCertificate,
+            Some,
+            |certif| {
+                realms_groups_roles.push(certif);
+            }
+        );
+    }
+
+    // Sequester authority is signed by the root key, and sequester services by the
+    // sequester authority (hence they cannot use `for_each_verified_certificate`).
+    let mut sequester_authority = None;
+    for signed in store
+        .get_multiple_signed_certificates(GetCertificateQuery::sequester_authority_certificate())
+        .await?
+    {
+        let unsecure = SequesterAuthorityCertificate::unsecure_load(signed).map_err(|error| {
+            let hint = "<unknown>".into();
+            Box::new(InvalidCertificateError::Corrupted { hint, error })
+        })?;
+        let (certif, _) = unsecure
+            .verify_signature(ops.device.root_verify_key())
+            .map_err(|(unsecure, error)| {
+                let hint = unsecure.hint();
+                Box::new(InvalidCertificateError::Corrupted { hint, error })
+            })?;
+        sequester_authority = Some(certif);
+    }
+
+    let mut sequester_services = vec![];
+    let mut sequester_revoked_services = vec![];
+    if let Some(authority) = &sequester_authority {
+        for signed in store
+            .get_multiple_signed_certificates(GetCertificateQuery::sequester_service_certificates())
+            .await?
+        {
+            let certif =
+                SequesterServiceCertificate::verify_and_load(&signed, &authority.verify_key_der)
+                    .map_err(|error| {
+                        let hint = "<unknown>".into();
+                        Box::new(InvalidCertificateError::Corrupted { hint, error })
+                    })?;
+            sequester_services.push(certif);
+        }
+        for signed in store
+            .get_multiple_signed_certificates(
+                GetCertificateQuery::sequester_revoked_service_certificates(),
+            )
+            .await?
+        {
+            let certif = SequesterRevokedServiceCertificate::verify_and_load(
+                &signed,
+                &authority.verify_key_der,
+            )
+            .map_err(|error| {
+                let hint = "<unknown>".into();
+                Box::new(InvalidCertificateError::Corrupted { hint, error })
+            })?;
+            sequester_revoked_services.push(certif);
+        }
+    }
+
+    // Shamir recovery share certificates are not part of the audit log: they are
+    // only provided to their recipient and the brief certificate already lists
+    // all the recipients.
+    let mut shamir_recovery_briefs = vec![];
+    for_each_verified_certificate!(
+        store,
+        ops,
+        verify_keys,
+        GetCertificateQuery::shamir_recovery_brief_certificates(),
+        ShamirRecoveryBriefCertificate,
+        Some,
+        |certif| {
+            shamir_recovery_briefs.push(certif);
+        }
+    );
+
+    let mut shamir_recovery_deletions = vec![];
+    for_each_verified_certificate!(
+        store,
+        ops,
+        verify_keys,
+        GetCertificateQuery::shamir_recovery_deletion_certificates(),
+        ShamirRecoveryDeletionCertificate,
+        Some,
+        |certif| {
+            shamir_recovery_deletions.push(certif);
+        }
+    );
+
+    // 2) Resolve human handles and realm names
+
+    let per_user_human_handle: HashMap<UserID, HumanHandle> = users
+        .iter()
+        .map(|certif| (certif.user_id, certif.human_handle.as_ref().to_owned()))
+        .collect();
+    let per_sequester_service_label: HashMap<SequesterServiceID, String> = sequester_services
+        .iter()
+        .map(|certif| (certif.service_id, certif.service_label.clone()))
+        .collect();
+    let per_group_name: HashMap<GroupID, String> = groups
+        .iter()
+        .map(|certif| (certif.group_id, certif.name.clone()))
+        .collect();
+
+    // Names are stored in chronological order for each realm
+    let mut per_realm_names: HashMap<VlobID, Vec<(DateTime, Option<EntryName>)>> = HashMap::new();
+    for certif in realms_names.iter() {
+        let name = decrypt_realm_name(ops, store, certif).await?;
+        per_realm_names
+            .entry(certif.realm_id)
+            .or_default()
+            .push((certif.timestamp, name));
+    }
+
+    let user = |user_id: UserID| -> anyhow::Result<CertificateAuditLogUser> {
+        match per_user_human_handle.get(&user_id) {
+            Some(human_handle) => Ok(CertificateAuditLogUser {
+                user_id,
+                human_handle: human_handle.to_owned(),
+            }),
+            None => Err(anyhow::anyhow!(
+                "Local database contains invalid data: unknown user `{}`",
+                user_id
+            )),
+        }
+    };
+    let author =
+        |device_id: Option<DeviceID>| -> anyhow::Result<Option<CertificateAuditLogAuthor>> {
+            let device_id = match device_id {
+                None => return Ok(None),
+                Some(device_id) => device_id,
+            };
+            match verify_keys.get(&device_id) {
+                Some((_, user_id)) => Ok(Some(CertificateAuditLogAuthor {
+                    device_id,
+                    user: user(*user_id)?,
+                })),
+                None => Err(anyhow::anyhow!(
+                    "Local database contains invalid data: unknown device `{}`",
+                    device_id
+                )),
+            }
+        };
+    let group_name = |group_id: GroupID| -> String {
+        per_group_name.get(&group_id).cloned().unwrap_or_default()
+    };
+    let realm = |realm_id: VlobID, timestamp: DateTime| -> CertificateAuditLogRealm {
+        let name = per_realm_names
+            .get(&realm_id)
+            .and_then(|names| {
+                names
+                    .iter()
+                    .rev()
+                    .find(|(name_timestamp, _)| *name_timestamp <= timestamp)
+            })
+            .and_then(|(_, name)| name.clone());
+        CertificateAuditLogRealm { realm_id, name }
+    };
+
+    // 3) Finally build the entries
+    //
+    // Certificates are ordered by topic first, so they must be sorted to get a
+    // chronological order. Note the sort is stable, hence certificates sharing the
+    // same timestamp (e.g. user and device created during the organization
+    // bootstrap) keep the order in which they have been pushed.
+
+    let mut entries = vec![];
+
+    for certif in users.iter() {
+        entries.push(CertificateAuditLogEntry {
+            timestamp: certif.timestamp,
+            author: author(signer_device(certif.author))?,
+            event: CertificateAuditLogEvent::UserCreated {
+                user: user(certif.user_id)?,
+                profile: certif.profile,
+            },
+        });
+    }
+    for certif in devices.iter() {
+        entries.push(CertificateAuditLogEntry {
+            timestamp: certif.timestamp,
+            author: author(signer_device(certif.author))?,
+            event: CertificateAuditLogEvent::DeviceCreated {
+                user: user(certif.user_id)?,
+                device_id: certif.device_id,
+                device_label: certif.device_label.as_ref().to_owned(),
+            },
+        });
+    }
+    for certif in users_updates.iter() {
+        entries.push(CertificateAuditLogEntry {
+            timestamp: certif.timestamp,
+            author: author(Some(certif.author))?,
+            event: CertificateAuditLogEvent::UserProfileUpdated {
+                user: user(certif.user_id)?,
+                profile: certif.new_profile,
+            },
+        });
+    }
+    for certif in revoked_devices.iter() {
+        entries.push(CertificateAuditLogEntry {
+            timestamp: certif.timestamp,
+            author: author(Some(certif.author))?,
+            event: CertificateAuditLogEvent::DeviceRevoked {
+                user: user(certif.user_id)?,
+                device_id: certif.device_id,
+            },
+        });
+    }
+    for certif in revoked_users.iter() {
+        entries.push(CertificateAuditLogEntry {
+            timestamp: certif.timestamp,
+            author: author(Some(certif.author))?,
+            event: CertificateAuditLogEvent::UserRevoked {
+                user: user(certif.user_id)?,
+            },
+        });
+    }
+    for certif in groups.iter() {
+        entries.push(CertificateAuditLogEntry {
+            timestamp: certif.timestamp,
+            author: author(Some(certif.author))?,
+            event: CertificateAuditLogEvent::GroupCreated {
+                group_id: certif.group_id,
+                name: certif.name.clone(),
+            },
+        });
+    }
+    for certif in groups_members.iter() {
+        entries.push(CertificateAuditLogEntry {
+            timestamp: certif.timestamp,
+            author: author(Some(certif.author))?,
+            event: CertificateAuditLogEvent::GroupMemberChanged {
+                group_id: certif.group_id,
+                group_name: group_name(certif.group_id),
+                user: user(certif.user_id)?,
+                is_member: certif.is_member,
+            },
+        });
+    }
+    for certif in realms_roles.iter() {
+        entries.push(CertificateAuditLogEntry {
+            timestamp: certif.timestamp,
+            author: author(Some(certif.author))?,
+            event: CertificateAuditLogEvent::RealmRoleChanged {
+                realm: realm(certif.realm_id, certif.timestamp),
+                user: user(certif.user_id)?,
+                role: certif.role,
+            },
+        });
+    }
+    for certif in realms_key_rotations.iter() {
+        entries.push(CertificateAuditLogEntry {
+            timestamp: certif.timestamp,
+            author: author(Some(certif.author))?,
+            event: CertificateAuditLogEvent::RealmKeyRotated {
+                realm: realm(certif.realm_id, certif.timestamp),
+                key_index: certif.key_index,
+            },
+        });
+    }
+    for certif in realms_names.iter() {
+        entries.push(CertificateAuditLogEntry {
+            timestamp: certif.timestamp,
+            author: author(Some(certif.author))?,
+            event: CertificateAuditLogEvent::RealmRenamed {
+                realm: realm(certif.realm_id, certif.timestamp),
+            },
+        });
+    }
+    for certif in realms_archivings.iter() {
+        entries.push(CertificateAuditLogEntry {
+            timestamp: certif.timestamp,
+            author: author(Some(certif.author))?,
+            event: CertificateAuditLogEvent::RealmArchivingChanged {
+                realm: realm(certif.realm_id, certif.timestamp),
+                configuration: certif.configuration,
+            },
+        });
+    }
+    for certif in realms_groups_roles.iter() {
+        entries.push(CertificateAuditLogEntry {
+            timestamp: certif.timestamp,
+            author: author(Some(certif.author))?,
+            event: CertificateAuditLogEvent::RealmGroupRoleChanged {
+                realm: realm(certif.realm_id, certif.timestamp),
+                group_id: certif.group_id,
+                group_name: group_name(certif.group_id),
+                role: certif.role,
+            },
+        });
+    }
+
+    if let Some(certif) = &sequester_authority {
+        entries.push(CertificateAuditLogEntry {
+            timestamp: certif.timestamp,
+            author: None,
+            event: CertificateAuditLogEvent::SequesterAuthorityCreated,
+        });
+    }
+    for certif in sequester_services.iter() {
+        entries.push(CertificateAuditLogEntry {
+            timestamp: certif.timestamp,
+            author: None,
+            event: CertificateAuditLogEvent::SequesterServiceCreated {
+                service_id: certif.service_id,
+                service_label: certif.service_label.clone(),
+            },
+        });
+    }
+    for certif in sequester_revoked_services.iter() {
+        entries.push(CertificateAuditLogEntry {
+            timestamp: certif.timestamp,
+            author: None,
+            event: CertificateAuditLogEvent::SequesterServiceRevoked {
+                service_id: certif.service_id,
+                service_label: per_sequester_service_label
+                    .get(&certif.service_id)
+                    .cloned()
+                    .unwrap_or_default(),
+            },
+        });
+    }
+    for certif in shamir_recovery_briefs.iter() {
+        let mut recipients = certif
+            .per_recipient_shares
+            .iter()
+            .map(|(recipient, shares)| Ok((user(*recipient)?, *shares)))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        recipients.sort_by_key(|(recipient, _)| recipient.user_id);
+        entries.push(CertificateAuditLogEntry {
+            timestamp: certif.timestamp,
+            author: author(Some(certif.author))?,
+            event: CertificateAuditLogEvent::ShamirRecoveryCreated {
+                user: user(certif.user_id)?,
+                threshold: certif.threshold,
+                recipients,
+            },
+        });
+    }
+    for certif in shamir_recovery_deletions.iter() {
+        entries.push(CertificateAuditLogEntry {
+            timestamp: certif.timestamp,
+            author: author(Some(certif.author))?,
+            event: CertificateAuditLogEvent::ShamirRecoveryDeleted {
+                user: user(certif.setup_to_delete_user_id)?,
+                setup_timestamp: certif.setup_to_delete_timestamp,
+            },
+        });
+    }
+
+    entries.sort_by_key(|entry| entry.timestamp);
+
+    Ok(entries)
+}
+
+/// Realm name is only provided if it can be decrypted, which is not possible if
+/// we are not part of the realm or if we are offline and don't have its keys bundle.
+async fn decrypt_realm_name(
+    ops: &CertificateOps,
+    store: &mut CertificatesStoreReadGuard<'_>,
+    certif: &RealmNameCertificate,
+) -> Result<Option<EntryName>, CertifCertificateAuditLogError> {
+    let outcome = realm_keys_bundle::decrypt_for_realm(
+        ops,
+        store,
+        EncrytionUsage::RealmRename,
+        certif.realm_id,
+        certif.key_index,
+        &certif.encrypted_name,
+    )
+    .await;
+
+    match outcome {
+        Ok(decrypted) => Ok(std::str::from_utf8(&decrypted)
+            .ok()
+            .and_then(|decrypted_str| decrypted_str.parse().ok())),
+        Err(CertifDecryptForRealmError::Stopped) => Err(CertifCertificateAuditLogError::Stopped),
+        Err(CertifDecryptForRealmError::Internal(err)) => Err(err.into()),
+        Err(
+            CertifDecryptForRealmError::Offline(_)
+            | CertifDecryptForRealmError::NotAllowed
+            | CertifDecryptForRealmError::RealmDeleted
+            | CertifDecryptForRealmError::KeyNotFound
+            | CertifDecryptForRealmError::CorruptedKey
+            | CertifDecryptForRealmError::CorruptedData
+            | CertifDecryptForRealmError::InvalidKeysBundle(_)
+            | CertifDecryptForRealmError::InvalidCertificate(_),
+        ) => Ok(None),
+    }
+}
@@ -44,6 +44,15 @@ pub enum InvalidBlockAccessError {
         block_id: BlockID,
         key_index: IndexInt,
     },
+    #[error("Block access `{block_id}` from manifest `{manifest_id}` version {manifest_version} (in realm `{realm_id}`, create by `{manifest_author}` on {manifest_timestamp}): cannot be decompressed !")]
+    CannotDecompress {
+        realm_id: VlobID,
+        manifest_id: VlobID,
+        manifest_version: VersionInt,
+        manifest_timestamp: DateTime,
+        manifest_author: DeviceID,
+        block_id: BlockID,
+    },
     #[error("Block access `{block_id}` from manifest `{manifest_id}` version {manifest_version} (in realm `{realm_id}`, create by `{manifest_author}` on {manifest_timestamp}): at that time, key index {key_index} didn't exist !")]
     NonExistentKeyIndex {
         realm_id: VlobID,
@@ -201,6 +210,19 @@ pub(super) async fn validate_block(
             CertifForReadWithRequirementsError::Internal(err) => err.into(),
         })??;
 
+    let block = access.decompress_data(block).map_err(|_| {
+        CertifValidateBlockError::InvalidBlockAccess(Box::new(
+            InvalidBlockAccessError::CannotDecompress {
+                realm_id,
+                manifest_id: manifest.id,
+                manifest_version: manifest.version,
+                manifest_timestamp: manifest.timestamp,
+                manifest_author: manifest.author,
+                block_id: access.id,
+            },
+        ))
+    })?;
+
     if block.len() != access.size.get() as usize {
         return Err(CertifValidateBlockError::InvalidBlockAccess(Box::new(
             InvalidBlockAccessError::SizeMismatch {
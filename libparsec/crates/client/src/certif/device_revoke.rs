@@ -0,0 +1,202 @@
+// Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+use libparsec_client_connection::ConnectionError;
+use libparsec_protocol::authenticated_cmds;
+use libparsec_types::prelude::*;
+
+use super::{
+    greater_timestamp,
+    store::{CertifStoreError, GetCertificateError},
+    CertificateBasedActionOutcome, CertificateOps, ClockDriftError, GreaterTimestampOffset,
+    InvalidCertificateError, UpTo,
+};
+use crate::EventTooMuchDriftWithServerClock;
+
+#[derive(Debug, thiserror::Error)]
+pub enum CertifRevokeDeviceError {
+    #[error("Component has stopped")]
+    Stopped,
+    #[error("Cannot communicate with the server: {0}")]
+    Offline(#[from] ConnectionError),
+    #[error("Cannot revoke the device currently in use")]
+    DeviceIsSelf,
+    #[error("Device not found")]
+    DeviceNotFound,
+    #[error("Author not allowed")]
+    AuthorNotAllowed,
+    #[error("Our clock ({client_timestamp}) and the server's one ({server_timestamp}) are too far apart")]
+    TimestampOutOfBallpark {
+        server_timestamp: DateTime,
+        client_timestamp: DateTime,
+        ballpark_client_early_offset: f64,
+        ballpark_client_late_offset: f64,
+    },
+    #[error(transparent)]
+    InvalidCertificate(#[from] Box<InvalidCertificateError>),
+    #[error(transparent)]
+    ClockDrift(#[from] ClockDriftError),
+    #[error(transparent)]
+    Internal(#[from] anyhow::Error),
+}
+
+impl From<CertifStoreError> for CertifRevokeDeviceError {
+    fn from(value: CertifStoreError) -> Self {
+        match value {
+            CertifStoreError::Stopped => Self::Stopped,
+            CertifStoreError::Internal(err) => err.into(),
+        }
+    }
+}
+
+/// Revoke a device, this can be done by the device's user itself (e.g. from one
+/// of its other devices) or by an admin.
+///
+/// Note the realms the device had access to are not re-encrypted here: this is
+/// taken care of by the workspaces needs monitor, which detects the new revoked
+/// device certificate and rotates the realm keys accordingly.
+pub(super) async fn revoke_device(
+    ops: &CertificateOps,
+    device: DeviceID,
+) -> Result<CertificateBasedActionOutcome, CertifRevokeDeviceError> {
+    if ops.device.device_id == device {
+        return Err(CertifRevokeDeviceError::DeviceIsSelf);
+    }
+
+    // The certificate must mention the device's user, which is only known
+    // from the device certificate.
+    let (user, already_revoked) = ops
+        .store
+        .for_read(async |store| {
+            let user_id = match store
+                .get_device_verify_key_and_user_id(UpTo::Current, device)
+                .await
+            {
+                Ok((_, user_id)) => user_id,
+                Err(GetCertificateError::NonExisting) => {
+                    return Err(CertifRevokeDeviceError::DeviceNotFound)
+                }
+                Err(GetCertificateError::ExistButTooRecent { .. }) => {
+                    unreachable!("query up to current")
+                }
+                Err(GetCertificateError::Internal(err)) => {
+                    return Err(CertifRevokeDeviceError::Internal(err))
+                }
+            };
+            let already_revoked = store
+                .get_revoked_device_certificate(UpTo::Current, device)
+                .await?
+                .is_some();
+            Ok((user_id, already_revoked))
+        })
+        .await??;
+
+    if already_revoked {
+        return Ok(CertificateBasedActionOutcome::LocalIdempotent);
+    }
+
+    // Loop is needed to deal with server requiring greater timestamp
+    let mut timestamp = ops.device.now();
+    loop {
+        let outcome = do_server_command(ops, user, device, timestamp).await?;
+
+        match outcome {
+            DoServerCommandOutcome::Done(outcome) => return Ok(outcome),
+            DoServerCommandOutcome::RequireGreaterTimestamp(strictly_greater_than) => {
+                timestamp = greater_timestamp(
+                    &ops.device.time_provider,
+                    GreaterTimestampOffset::User,
+                    strictly_greater_than,
+                )
+                .await
+                .inspect_err(|err| ops.event_bus.send(&err.to_event()))?;
+            }
+        }
+    }
+}
+
+enum DoServerCommandOutcome {
+    Done(CertificateBasedActionOutcome),
+    RequireGreaterTimestamp(DateTime),
+}
+
+async fn do_server_command(
+    ops: &CertificateOps,
+    user: UserID,
+    device: DeviceID,
+    timestamp: DateTime,
+) -> Result<DoServerCommandOutcome, CertifRevokeDeviceError> {
+    // 0) Sanity check to prevent generating and invalid certificate
+
+    if device == ops.device.device_id {
+        return Err(CertifRevokeDeviceError::DeviceIsSelf);
+    }
+
+    // 1) Build revoked device certificate
+
+    let signed_certificate = RevokedDeviceCertificate {
+        author: ops.device.device_id,
+        timestamp,
+        user_id: user,
+        device_id: device,
+    }
+    .dump_and_sign(&ops.device.signing_key);
+
+    // 2) Actually send the command to the server
+
+    use authenticated_cmds::latest::device_revoke::{Rep, Req};
+
+    let req = Req {
+        revoked_device_certificate: signed_certificate.into(),
+    };
+    let rep = ops.cmds.send(req).await?;
+    match rep {
+        Rep::Ok => Ok(DoServerCommandOutcome::Done(
+            CertificateBasedActionOutcome::Uploaded {
+                certificate_timestamp: timestamp,
+            },
+        )),
+        Rep::DeviceAlreadyRevoked {
+            last_common_certificate_timestamp,
+        } => Ok(DoServerCommandOutcome::Done(
+            CertificateBasedActionOutcome::RemoteIdempotent {
+                certificate_timestamp: last_common_certificate_timestamp,
+            },
+        )),
+        Rep::RequireGreaterTimestamp {
+            strictly_greater_than,
+        } => {
+            // The retry is handled by the caller
+            Ok(DoServerCommandOutcome::RequireGreaterTimestamp(
+                strictly_greater_than,
+            ))
+        }
+        Rep::AuthorNotAllowed => Err(CertifRevokeDeviceError::AuthorNotAllowed),
+        Rep::TimestampOutOfBallpark {
+            server_timestamp,
+            client_timestamp,
+            ballpark_client_early_offset,
+            ballpark_client_late_offset,
+            ..
+        } => {
+            let event = EventTooMuchDriftWithServerClock {
+                server_timestamp,
+                ballpark_client_early_offset,
+                ballpark_client_late_offset,
+                client_timestamp,
+            };
+            ops.event_bus.send(&event);
+
+            Err(CertifRevokeDeviceError::TimestampOutOfBallpark {
+                server_timestamp,
+                client_timestamp,
+                ballpark_client_early_offset,
+                ballpark_client_late_offset,
+            })
+        }
+        // The device's user may have been revoked in the meantime
+        Rep::DeviceNotFound => Err(CertifRevokeDeviceError::DeviceNotFound),
+        bad_rep @ (Rep::InvalidCertificate | Rep::UnknownStatus { .. }) => {
+            Err(anyhow::anyhow!("Unexpected server response: {:?}", bad_rep).into())
+        }
+    }
+}
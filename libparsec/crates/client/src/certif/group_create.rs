@@ -0,0 +1,157 @@
+// Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+use libparsec_client_connection::ConnectionError;
+use libparsec_protocol::authenticated_cmds;
+use libparsec_types::prelude::*;
+
+use super::{
+    greater_timestamp, store::CertifStoreError, CertificateBasedActionOutcome, CertificateOps,
+    ClockDriftError, GreaterTimestampOffset, InvalidCertificateError,
+};
+use crate::EventTooMuchDriftWithServerClock;
+
+#[derive(Debug, thiserror::Error)]
+pub enum CertifCreateGroupError {
+    #[error("Component has stopped")]
+    Stopped,
+    #[error("Cannot communicate with the server: {0}")]
+    Offline(#[from] ConnectionError),
+    #[error("Group name cannot be empty")]
+    EmptyName,
+    #[error("Author not allowed")]
+    AuthorNotAllowed,
+    #[error("Our clock ({client_timestamp}) and the server's one ({server_timestamp}) are too far apart")]
+    TimestampOutOfBallpark {
+        server_timestamp: DateTime,
+        client_timestamp: DateTime,
+        ballpark_client_early_offset: f64,
+        ballpark_client_late_offset: f64,
+    },
+    #[error(transparent)]
+    InvalidCertificate(#[from] Box<InvalidCertificateError>),
+    #[error(transparent)]
+    ClockDrift(#[from] ClockDriftError),
+    #[error(transparent)]
+    Internal(#[from] anyhow::Error),
+}
+
+impl From<CertifStoreError> for CertifCreateGroupError {
+    fn from(value: CertifStoreError) -> Self {
+        match value {
+            CertifStoreError::Stopped => Self::Stopped,
+            CertifStoreError::Internal(err) => err.into(),
+        }
+    }
+}
+
+/// Create a new (empty) group of users, only an admin can do that.
+pub(super) async fn create_group(
+    ops: &CertificateOps,
+    name: String,
+) -> Result<(GroupID, CertificateBasedActionOutcome), CertifCreateGroupError> {
+    if name.is_empty() {
+        return Err(CertifCreateGroupError::EmptyName);
+    }
+
+    let group_id = GroupID::default();
+
+    // Loop is needed to deal with server requiring greater timestamp
+    let mut timestamp = ops.device.now();
+    loop {
+        let outcome = do_server_command(ops, group_id, &name, timestamp).await?;
+
+        match outcome {
+            DoServerCommandOutcome::Done(outcome) => return Ok((group_id, outcome)),
+            DoServerCommandOutcome::RequireGreaterTimestamp(strictly_greater_than) => {
+                timestamp = greater_timestamp(
+                    &ops.device.time_provider,
+                    GreaterTimestampOffset::User,
+                    strictly_greater_than,
+                )
+                .await
+                .inspect_err(|err| ops.event_bus.send(&err.to_event()))?;
+            }
+        }
+    }
+}
+
+enum DoServerCommandOutcome {
+    Done(CertificateBasedActionOutcome),
+    RequireGreaterTimestamp(DateTime),
+}
+
+async fn do_server_command(
+    ops: &CertificateOps,
+    group_id: GroupID,
+    name: &str,
+    timestamp: DateTime,
+) -> Result<DoServerCommandOutcome, CertifCreateGroupError> {
+    // 1) Build group certificate
+
+    let signed_certificate = GroupCertificate {
+        author: ops.device.device_id,
+        timestamp,
+        group_id,
+        name: name.to_owned(),
+    }
+    .dump_and_sign(&ops.device.signing_key);
+
+    // 2) Actually send the command to the server
+
+    use authenticated_cmds::latest::group_create::{Rep, Req};
+
+    let req = Req {
+        group_certificate: signed_certificate.into(),
+    };
+    let rep = ops.cmds.send(req).await?;
+    match rep {
+        Rep::Ok => Ok(DoServerCommandOutcome::Done(
+            CertificateBasedActionOutcome::Uploaded {
+                certificate_timestamp: timestamp,
+            },
+        )),
+        // Only possible if a previous attempt reached the server but we didn't
+        // get its response.
+        Rep::GroupAlreadyExists {
+            last_common_certificate_timestamp,
+        } => Ok(DoServerCommandOutcome::Done(
+            CertificateBasedActionOutcome::RemoteIdempotent {
+                certificate_timestamp: last_common_certificate_timestamp,
+            },
+        )),
+        Rep::RequireGreaterTimestamp {
+            strictly_greater_than,
+        } => {
+            // The retry is handled by the caller
+            Ok(DoServerCommandOutcome::RequireGreaterTimestamp(
+                strictly_greater_than,
+            ))
+        }
+        Rep::AuthorNotAllowed => Err(CertifCreateGroupError::AuthorNotAllowed),
+        Rep::TimestampOutOfBallpark {
+            server_timestamp,
+            client_timestamp,
+            ballpark_client_early_offset,
+            ballpark_client_late_offset,
+            ..
+        } => {
+            let event = EventTooMuchDriftWithServerClock {
+                server_timestamp,
+                ballpark_client_early_offset,
+                ballpark_client_late_offset,
+                client_timestamp,
+            };
+            ops.event_bus.send(&event);
+
+            Err(CertifCreateGroupError::TimestampOutOfBallpark {
+                server_timestamp,
+                client_timestamp,
+                ballpark_client_early_offset,
+                ballpark_client_late_offset,
+            })
+        }
+        bad_rep @ (Rep::InvalidCertificate | Rep::UnknownStatus { .. }) => {
+            Err(anyhow::anyhow!("Unexpected server response: {:?}", bad_rep).into())
+        }
+    }
+}
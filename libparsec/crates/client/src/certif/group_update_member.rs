@@ -0,0 +1,186 @@
+// Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+use libparsec_client_connection::ConnectionError;
+use libparsec_protocol::authenticated_cmds;
+use libparsec_types::prelude::*;
+
+use super::{
+    greater_timestamp, store::CertifStoreError, CertificateBasedActionOutcome, CertificateOps,
+    ClockDriftError, GreaterTimestampOffset, InvalidCertificateError, UpTo,
+};
+use crate::EventTooMuchDriftWithServerClock;
+
+#[derive(Debug, thiserror::Error)]
+pub enum CertifUpdateGroupMemberError {
+    #[error("Component has stopped")]
+    Stopped,
+    #[error("Cannot communicate with the server: {0}")]
+    Offline(#[from] ConnectionError),
+    #[error("Group not found")]
+    GroupNotFound,
+    #[error("User not found")]
+    UserNotFound,
+    #[error("User revoked")]
+    UserRevoked,
+    #[error("Author not allowed")]
+    AuthorNotAllowed,
+    #[error("Our clock ({client_timestamp}) and the server's one ({server_timestamp}) are too far apart")]
+    TimestampOutOfBallpark {
+        server_timestamp: DateTime,
+        client_timestamp: DateTime,
+        ballpark_client_early_offset: f64,
+        ballpark_client_late_offset: f64,
+    },
+    #[error(transparent)]
+    InvalidCertificate(#[from] Box<InvalidCertificateError>),
+    #[error(transparent)]
+    ClockDrift(#[from] ClockDriftError),
+    #[error(transparent)]
+    Internal(#[from] anyhow::Error),
+}
+
+impl From<CertifStoreError> for CertifUpdateGroupMemberError {
+    fn from(value: CertifStoreError) -> Self {
+        match value {
+            CertifStoreError::Stopped => Self::Stopped,
+            CertifStoreError::Internal(err) => err.into(),
+        }
+    }
+}
+
+/// Add (`is_member == true`) or remove (`is_member == false`) a user from a group,
+/// only an admin can do that.
+///
+/// Note the realms shared with the group are not updated here: this is taken
+/// care of by the workspaces needs monitor of the realms' owners.
+pub(super) async fn update_group_member(
+    ops: &CertificateOps,
+    group_id: GroupID,
+    user_id: UserID,
+    is_member: bool,
+) -> Result<CertificateBasedActionOutcome, CertifUpdateGroupMemberError> {
+    // Check locally to avoid a useless server round-trip
+    let is_current_member = ops
+        .store
+        .for_read(async |store| {
+            if store
+                .get_group_certificate(UpTo::Current, group_id)
+                .await?
+                .is_none()
+            {
+                return Ok(None);
+            }
+            store
+                .get_group_current_members(UpTo::Current, group_id)
+                .await
+                .map(|members| Some(members.contains_key(&user_id)))
+        })
+        .await??
+        .ok_or(CertifUpdateGroupMemberError::GroupNotFound)?;
+    if is_current_member == is_member {
+        return Ok(CertificateBasedActionOutcome::LocalIdempotent);
+    }
+
+    // Loop is needed to deal with server requiring greater timestamp
+    let mut timestamp = ops.device.now();
+    loop {
+        let outcome = do_server_command(ops, group_id, user_id, is_member, timestamp).await?;
+
+        match outcome {
+            DoServerCommandOutcome::Done(outcome) => return Ok(outcome),
+            DoServerCommandOutcome::RequireGreaterTimestamp(strictly_greater_than) => {
+                timestamp = greater_timestamp(
+                    &ops.device.time_provider,
+                    GreaterTimestampOffset::User,
+                    strictly_greater_than,
+                )
+                .await
+                .inspect_err(|err| ops.event_bus.send(&err.to_event()))?;
+            }
+        }
+    }
+}
+
+enum DoServerCommandOutcome {
+    Done(CertificateBasedActionOutcome),
+    RequireGreaterTimestamp(DateTime),
+}
+
+async fn do_server_command(
+    ops: &CertificateOps,
+    group_id: GroupID,
+    user_id: UserID,
+    is_member: bool,
+    timestamp: DateTime,
+) -> Result<DoServerCommandOutcome, CertifUpdateGroupMemberError> {
+    // 1) Build group member certificate
+
+    let signed_certificate = GroupMemberCertificate {
+        author: ops.device.device_id,
+        timestamp,
+        group_id,
+        user_id,
+        is_member,
+    }
+    .dump_and_sign(&ops.device.signing_key);
+
+    // 2) Actually send the command to the server
+
+    use authenticated_cmds::latest::group_update_member::{Rep, Req};
+
+    let req = Req {
+        group_member_certificate: signed_certificate.into(),
+    };
+    let rep = ops.cmds.send(req).await?;
+    match rep {
+        Rep::Ok => Ok(DoServerCommandOutcome::Done(
+            CertificateBasedActionOutcome::Uploaded {
+                certificate_timestamp: timestamp,
+            },
+        )),
+        Rep::MemberAlreadyUpToDate {
+            last_common_certificate_timestamp,
+        } => Ok(DoServerCommandOutcome::Done(
+            CertificateBasedActionOutcome::RemoteIdempotent {
+                certificate_timestamp: last_common_certificate_timestamp,
+            },
+        )),
+        Rep::RequireGreaterTimestamp {
+            strictly_greater_than,
+        } => {
+            // The retry is handled by the caller
+            Ok(DoServerCommandOutcome::RequireGreaterTimestamp(
+                strictly_greater_than,
+            ))
+        }
+        Rep::AuthorNotAllowed => Err(CertifUpdateGroupMemberError::AuthorNotAllowed),
+        Rep::GroupNotFound => Err(CertifUpdateGroupMemberError::GroupNotFound),
+        Rep::UserNotFound => Err(CertifUpdateGroupMemberError::UserNotFound),
+        Rep::UserRevoked => Err(CertifUpdateGroupMemberError::UserRevoked),
+        Rep::TimestampOutOfBallpark {
+            server_timestamp,
+            client_timestamp,
+            ballpark_client_early_offset,
+            ballpark_client_late_offset,
+            ..
+        } => {
+            let event = EventTooMuchDriftWithServerClock {
+                server_timestamp,
+                ballpark_client_early_offset,
+                ballpark_client_late_offset,
+                client_timestamp,
+            };
+            ops.event_bus.send(&event);
+
+            Err(CertifUpdateGroupMemberError::TimestampOutOfBallpark {
+                server_timestamp,
+                client_timestamp,
+                ballpark_client_early_offset,
+                ballpark_client_late_offset,
+            })
+        }
+        bad_rep @ (Rep::InvalidCertificate | Rep::UnknownStatus { .. }) => {
+            Err(anyhow::anyhow!("Unexpected server response: {:?}", bad_rep).into())
+        }
+    }
+}
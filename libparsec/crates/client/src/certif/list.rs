@@ -14,6 +14,8 @@ pub type CertifGetRealmArchivingConfigurationError = super::store::CertifStoreEr
 pub type CertifListUsersError = super::store::CertifStoreError;
 pub type CertifListUserDevicesError = super::store::CertifStoreError;
 pub type CertifListWorkspaceUsersError = super::store::CertifStoreError;
+pub type CertifListGroupsError = super::store::CertifStoreError;
+pub type CertifListWorkspaceGroupsError = super::store::CertifStoreError;
 
 pub(super) async fn get_current_self_profile(
     ops: &CertificateOps,
@@ -27,33 +29,19 @@ pub(super) async fn get_current_self_profile(
 pub(super) async fn get_current_self_realms_role(
     ops: &CertificateOps,
 ) -> Result<Vec<(VlobID, Option<RealmRole>, DateTime)>, CertifGetCurrentSelfRealmsRoleError> {
-    // TODO: cache !
     let certifs = ops
         .store
         .for_read(async |store| {
             store
-                .get_user_realms_roles(UpTo::Current, ops.device.user_id)
+                .get_user_last_realms_roles(UpTo::Current, ops.device.user_id)
                 .await
         })
         .await??;
 
-    // Replay the history of all changes
-
-    let mut roles = Vec::with_capacity(certifs.len());
-    for certif in certifs {
-        // Note this O(n) operation is fine since we expect `certifs` to be small
-        let maybe_exists = roles
-            .iter()
-            .position(|(realm_id, _, _)| *realm_id == certif.realm_id);
-        match maybe_exists {
-            Some(index) => {
-                roles[index] = (certif.realm_id, certif.role, certif.timestamp);
-            }
-            None => {
-                roles.push((certif.realm_id, certif.role, certif.timestamp));
-            }
-        }
-    }
+    let roles = certifs
+        .into_iter()
+        .map(|certif| (certif.realm_id, certif.role, certif.timestamp))
+        .collect();
 
     Ok(roles)
 }
@@ -375,6 +363,9 @@ pub struct WorkspaceUserAccessInfo {
     pub human_handle: HumanHandle,
     pub current_profile: UserProfile,
     pub current_role: RealmRole,
+    /// Date at which the user will be unshared from the workspace, only known if
+    /// the share expiration has been configured from our device.
+    pub expires_on: Option<DateTime>,
 }
 
 /// List users currently part of the given workspace (i.e. user not revoked
@@ -385,6 +376,8 @@ pub(super) async fn list_workspace_users(
 ) -> Result<Vec<WorkspaceUserAccessInfo>, CertifListWorkspaceUsersError> {
     let mut infos = Vec::new();
 
+    let expirations = ops.store.get_realm_share_expirations(realm_id).await?;
+
     ops.store
         .for_read(async |store| {
             let per_user_certifs = store
@@ -404,6 +397,16 @@ pub(super) async fn list_workspace_users(
                     continue;
                 }
 
+                // An expiration only applies to the role certificate it has been
+                // configured with
+                let expires_on = expirations
+                    .iter()
+                    .find(|expiration| {
+                        expiration.user_id == user_id
+                            && expiration.role_certificate_timestamp == role_certif.timestamp
+                    })
+                    .map(|expiration| expiration.expires_on);
+
                 let user_certif = match store.get_user_certificate(UpTo::Current, user_id).await {
                     Ok(user_certif) => user_certif,
                     // We got the user ID from the certificate store, it is guaranteed to
@@ -429,6 +432,7 @@ pub(super) async fn list_workspace_users(
                     human_handle: user_certif.human_handle.as_ref().to_owned(),
                     current_profile,
                     current_role,
+                    expires_on,
                 };
                 infos.push(user_info);
             }
@@ -439,6 +443,87 @@ pub(super) async fn list_workspace_users(
         .map_err(|err| err.into())
 }
 
+#[derive(Debug, PartialEq, Eq)]
+pub struct GroupInfo {
+    pub id: GroupID,
+    pub name: String,
+    pub created_on: DateTime,
+    pub created_by: DeviceID,
+    /// Users currently part of the group (revoked users included), sorted by ID.
+    pub members: Vec<UserID>,
+}
+
+pub(super) async fn list_groups(
+    ops: &CertificateOps,
+) -> Result<Vec<GroupInfo>, CertifListGroupsError> {
+    ops.store
+        .for_read(async |store| {
+            let certifs = store.get_group_certificates(UpTo::Current).await?;
+
+            let mut infos = Vec::with_capacity(certifs.len());
+            for group_certif in certifs {
+                let mut members: Vec<_> = store
+                    .get_group_current_members(UpTo::Current, group_certif.group_id)
+                    .await?
+                    .into_keys()
+                    .collect();
+                members.sort();
+                infos.push(GroupInfo {
+                    id: group_certif.group_id,
+                    name: group_certif.name.clone(),
+                    created_on: group_certif.timestamp,
+                    created_by: group_certif.author,
+                    members,
+                });
+            }
+
+            Ok(infos)
+        })
+        .await?
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct WorkspaceGroupAccessInfo {
+    pub group_id: GroupID,
+    pub name: String,
+    pub current_role: RealmRole,
+}
+
+/// List groups the given workspace is currently shared with
+pub(super) async fn list_workspace_groups(
+    ops: &CertificateOps,
+    realm_id: VlobID,
+) -> Result<Vec<WorkspaceGroupAccessInfo>, CertifListWorkspaceGroupsError> {
+    ops.store
+        .for_read(async |store| {
+            let per_group_certifs = store
+                .get_realm_current_groups_roles(UpTo::Current, realm_id)
+                .await?;
+
+            let mut infos = Vec::with_capacity(per_group_certifs.len());
+            for (group_id, role_certif) in per_group_certifs {
+                let current_role = role_certif
+                    .role
+                    .expect("unshared group should not be listed");
+                // Group existence has been checked when the role certificate was added
+                let name = store
+                    .get_group_certificate(UpTo::Current, group_id)
+                    .await?
+                    .map(|certif| certif.name.clone())
+                    .unwrap_or_default();
+                infos.push(WorkspaceGroupAccessInfo {
+                    group_id,
+                    name,
+                    current_role,
+                });
+            }
+            infos.sort_by(|a, b| a.name.cmp(&b.name));
+
+            Ok(infos)
+        })
+        .await?
+}
+
 pub(super) async fn get_realm_archiving_configuration(
     ops: &CertificateOps,
     realm_id: VlobID,
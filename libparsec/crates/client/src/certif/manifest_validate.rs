@@ -490,6 +490,31 @@ async fn validate_manifest<M>(
         Err(err) => return Err(CertifValidateManifestError::Internal(err)),
     }
 
+    // 1.3) Check author device is not revoked
+
+    match store
+        .get_revoked_device_certificate(UpTo::Timestamp(timestamp), author.to_owned())
+        .await
+    {
+        // Not revoked at the considered timestamp, as we expected :)
+        Ok(None) => (),
+
+        // Revoked :(
+        Ok(Some(_)) => {
+            let what = Box::new(InvalidManifestError::RevokedAuthor {
+                realm: realm_id,
+                vlob: vlob_id,
+                version,
+                author: author.to_owned(),
+                timestamp,
+            });
+            return Err(CertifValidateManifestError::InvalidManifest(what));
+        }
+
+        // D'oh :/
+        Err(err) => return Err(CertifValidateManifestError::Internal(err)),
+    }
+
     // 2) Actually validate the manifest
 
     let manifest = verify_and_load(
@@ -6,12 +6,18 @@ use libparsec_client_connection::AuthenticatedCmds;
 use libparsec_platform_async::lock::Mutex as AsyncMutex;
 use libparsec_types::prelude::*;
 // Re-expose for convenience
-pub use libparsec_platform_storage::certificates::UpTo;
+pub use libparsec_platform_storage::certificates::{
+    RealmRekeyProgress, RealmShareExpiration, UpTo,
+};
 
 mod add;
+mod audit_log;
 mod block_validate;
+mod device_revoke;
 mod encrypt;
 mod forget_all_certificates;
+mod group_create;
+mod group_update_member;
 mod list;
 mod manifest_validate;
 mod poll;
@@ -20,8 +26,12 @@ mod realm_create;
 mod realm_decrypt_name;
 mod realm_key_rotation;
 mod realm_keys_bundle;
+mod realm_rekey;
 mod realm_rename;
 mod realm_share;
+mod realm_share_expiration;
+mod realm_sole_owner;
+mod realm_update_group_role;
 mod realms_needs;
 mod shamir_recovery_delete;
 mod shamir_recovery_list;
@@ -32,15 +42,24 @@ mod user_update_profile;
 mod workspace_bootstrap;
 
 pub use add::{CertifAddCertificatesBatchError, InvalidCertificateError, MaybeRedactedSwitch};
+pub use audit_log::{
+    CertifCertificateAuditLogError, CertificateAuditLogAuthor, CertificateAuditLogEntry,
+    CertificateAuditLogEvent, CertificateAuditLogFilter, CertificateAuditLogRealm,
+    CertificateAuditLogUser,
+};
 pub use block_validate::{CertifValidateBlockError, InvalidBlockAccessError};
+pub use device_revoke::CertifRevokeDeviceError;
 pub use encrypt::CertifEncryptForSequesterServicesError;
 pub use forget_all_certificates::CertifForgetAllCertificatesError;
+pub use group_create::CertifCreateGroupError;
+pub use group_update_member::CertifUpdateGroupMemberError;
 use libparsec_platform_storage::certificates::PerTopicLastTimestamps;
 pub use list::{
     CertifGetCurrentSelfProfileError, CertifGetCurrentSelfRealmRoleError,
     CertifGetCurrentSelfRealmsRoleError, CertifGetRealmArchivingConfigurationError,
-    CertifGetUserDeviceError, CertifGetUserInfoError, CertifListUserDevicesError,
-    CertifListUsersError, CertifListWorkspaceUsersError, DeviceInfo, UserInfo,
+    CertifGetUserDeviceError, CertifGetUserInfoError, CertifListGroupsError,
+    CertifListUserDevicesError, CertifListUsersError, CertifListWorkspaceGroupsError,
+    CertifListWorkspaceUsersError, DeviceInfo, GroupInfo, UserInfo, WorkspaceGroupAccessInfo,
     WorkspaceUserAccessInfo,
 };
 pub use manifest_validate::{CertifValidateManifestError, InvalidManifestError};
@@ -51,10 +70,17 @@ pub use realm_decrypt_name::{CertifDecryptCurrentRealmNameError, InvalidEncrypte
 pub use realm_key_rotation::CertifRotateRealmKeyError;
 pub use realm_keys_bundle::{
     CertifDecryptForRealmError, CertifEncryptForRealmError, EncrytionUsage, InvalidKeysBundleError,
+    KeysBundleHealingOutcome,
 };
+pub use realm_rekey::{CertifGetRealmRekeyProgressError, CertifRekeyRealmError};
 pub use realm_rename::CertifRenameRealmError;
 pub use realm_share::CertifShareRealmError;
-pub use realms_needs::{CertifGetRealmNeedsError, RealmNeeds};
+pub use realm_share_expiration::{
+    CertifListRealmShareExpirationsError, CertifSetRealmShareExpirationError,
+};
+pub use realm_sole_owner::{CertifListSolelyOwnedRealmsError, OrphanedWorkspacesStrategy};
+pub use realm_update_group_role::CertifUpdateRealmGroupRoleError;
+pub use realms_needs::{CertifGetRealmGroupSharesNeedsError, CertifGetRealmNeedsError, RealmNeeds};
 pub use shamir_recovery_delete::CertifDeleteShamirRecoveryError;
 pub use shamir_recovery_list::{
     CertifGetSelfShamirRecoveryError, CertifGetShamirRecoveryShareDataError,
@@ -68,7 +94,7 @@ pub use user_revoke::CertifRevokeUserError;
 pub use user_update_profile::CertifUpdateUserProfileError;
 pub use workspace_bootstrap::CertifBootstrapWorkspaceError;
 
-use crate::{event_bus::EventBus, ClientConfig};
+use crate::{event_bus::EventBus, ClientConfig, EventClockDrift};
 
 // The following values define an offset to be used when processing
 // a require greater timestamp error. They are different to give priority
@@ -145,20 +171,74 @@ impl From<GreaterTimestampOffset> for i64 {
     }
 }
 
+/// A timestamp required by the server is used as-is as long as it is no further
+/// than this ahead of our own clock.
+///
+/// This is kept well below the server's ballpark (which rejects client timestamps
+/// more than 300 seconds ahead of its own clock) given our clock may itself
+/// be ahead of the server's one.
+/// # microseconds, or 30 seconds
+pub const GREATER_TIMESTAMP_TOLERANCE_US: i64 = 30_000_000;
+
+/// Beyond `GREATER_TIMESTAMP_TOLERANCE_US`, how long we are willing to wait for
+/// our clock to catch up with the timestamp required by the server before giving up.
+/// # microseconds, or 30 seconds
+pub const GREATER_TIMESTAMP_MAX_WAIT_US: i64 = 30_000_000;
+
+/// The server required a timestamp too far ahead of our clock to be reached by
+/// waiting, most likely our clock is lagging behind the server's one.
+///
+/// Unlike `TimestampOutOfBallpark` (where the server rejects the timestamp we
+/// provided), the server's ballpark is unknown here: we only know the timestamp
+/// it required.
+///
+/// Each operation relying on `greater_timestamp` has a `ClockDrift` variant
+/// wrapping this error, so it can be propagated as-is with `?`.
+#[derive(Debug, Clone, thiserror::Error)]
+#[error("Our clock ({client_timestamp}) is too far behind the timestamp required by the server ({required_timestamp})")]
+pub struct ClockDriftError {
+    pub required_timestamp: DateTime,
+    pub client_timestamp: DateTime,
+}
+
+impl ClockDriftError {
+    pub fn to_event(&self) -> EventClockDrift {
+        EventClockDrift {
+            required_timestamp: self.required_timestamp,
+            client_timestamp: self.client_timestamp,
+        }
+    }
+}
+
 /// Return the timestamp to be used when the server requires a greater timestamp.
 ///
 /// The timestamp to be used will be whichever is greater between:
 /// - now (obtained from `time_provider`)
 /// - `strictly_greater_than` (expected by the server) + `offset` (depending on certificate type)
-pub(crate) fn greater_timestamp(
+///
+/// If the latter is too far ahead of our clock (see `GREATER_TIMESTAMP_TOLERANCE_US`),
+/// we first sleep until our clock catches up (up to `GREATER_TIMESTAMP_MAX_WAIT_US`),
+/// otherwise the server would most likely reject our timestamp as out of its ballpark.
+pub(crate) async fn greater_timestamp(
     time_provider: &TimeProvider,
     offset: GreaterTimestampOffset,
     strictly_greater_than: DateTime,
-) -> DateTime {
-    std::cmp::max(
-        time_provider.now(),
-        strictly_greater_than.add_us(offset.into()),
-    )
+) -> Result<DateTime, ClockDriftError> {
+    let required = strictly_greater_than.add_us(offset.into());
+    let now = time_provider.now();
+
+    let to_wait = required - now - Duration::microseconds(GREATER_TIMESTAMP_TOLERANCE_US);
+    if to_wait > Duration::zero() {
+        if to_wait > Duration::microseconds(GREATER_TIMESTAMP_MAX_WAIT_US) {
+            return Err(ClockDriftError {
+                required_timestamp: required,
+                client_timestamp: now,
+            });
+        }
+        time_provider.sleep(to_wait).await;
+    }
+
+    Ok(std::cmp::max(time_provider.now(), required))
 }
 
 #[derive(Debug)]
@@ -443,6 +523,32 @@ impl CertificateOps {
             })?
     }
 
+    /// Encrypt a block with the last known key from the most recent realm keys bundle,
+    /// using a block ID derived from the block's content (see block deduplication).
+    ///
+    /// If `compressed` is `true`, the block is compressed before encryption.
+    ///
+    /// Be aware this function potentially do server accesses (to fetch the keys bundle).
+    pub async fn encrypt_deduplicated_block_for_realm(
+        &self,
+        realm_id: VlobID,
+        data: &[u8],
+        compressed: bool,
+    ) -> Result<(BlockID, Vec<u8>, IndexInt), CertifEncryptForRealmError> {
+        self.store
+            .for_read(async |store| {
+                realm_keys_bundle::encrypt_deduplicated_block_for_realm(
+                    self, store, realm_id, data, compressed,
+                )
+                .await
+            })
+            .await
+            .map_err(|e| match e {
+                CertifStoreError::Stopped => CertifEncryptForRealmError::Stopped,
+                CertifStoreError::Internal(err) => err.context("cannot access storage").into(),
+            })?
+    }
+
     /// Decrypt the data with the key at the given index from the most recent realm keys bundle.
     /// You most likely want to use the `validate_*` methods instead.
     /// This method should only be used to decrypt data not controlled by the server (given in
@@ -522,16 +628,50 @@ impl CertificateOps {
     pub async fn revoke_user(
         &self,
         user: UserID,
+        orphaned_workspaces: OrphanedWorkspacesStrategy,
     ) -> Result<CertificateBasedActionOutcome, CertifRevokeUserError> {
-        user_revoke::revoke_user(self, user).await
+        user_revoke::revoke_user(self, user, orphaned_workspaces).await
+    }
+
+    pub async fn revoke_device(
+        &self,
+        device: DeviceID,
+    ) -> Result<CertificateBasedActionOutcome, CertifRevokeDeviceError> {
+        device_revoke::revoke_device(self, device).await
+    }
+
+    pub async fn create_group(
+        &self,
+        name: String,
+    ) -> Result<(GroupID, CertificateBasedActionOutcome), CertifCreateGroupError> {
+        group_create::create_group(self, name).await
+    }
+
+    pub async fn update_group_member(
+        &self,
+        group_id: GroupID,
+        user_id: UserID,
+        is_member: bool,
+    ) -> Result<CertificateBasedActionOutcome, CertifUpdateGroupMemberError> {
+        group_update_member::update_group_member(self, group_id, user_id, is_member).await
     }
 
     pub async fn user_update_profile(
         &self,
         user_id: UserID,
         new_profile: UserProfile,
+        orphaned_workspaces: OrphanedWorkspacesStrategy,
     ) -> Result<CertificateBasedActionOutcome, CertifUpdateUserProfileError> {
-        user_update_profile::update_profile(self, user_id, new_profile).await
+        user_update_profile::update_profile(self, user_id, new_profile, orphaned_workspaces).await
+    }
+
+    /// Realms in which the given user is the only OWNER while other users still have
+    /// access to it (i.e. realms that would be orphaned if the user is revoked).
+    pub async fn list_solely_owned_realms(
+        &self,
+        user_id: UserID,
+    ) -> Result<Vec<VlobID>, CertifListSolelyOwnedRealmsError> {
+        realm_sole_owner::list_solely_owned_realms(self, user_id).await
     }
 
     /// Returns the timestamp of the uploaded certificate
@@ -550,7 +690,54 @@ impl CertificateOps {
         recipient: UserID,
         role: Option<RealmRole>,
     ) -> Result<CertificateBasedActionOutcome, CertifShareRealmError> {
-        realm_share::share_realm(self, realm_id, recipient, role).await
+        realm_share::share_realm(self, realm_id, recipient, role, None).await
+    }
+
+    /// Same as [`CertificateOps::share_realm`], but the role is marked as granted
+    /// through the given group (so that it can be automatically revoked once the
+    /// user is no longer part of the group).
+    pub async fn share_realm_through_group(
+        &self,
+        realm_id: VlobID,
+        recipient: UserID,
+        role: RealmRole,
+        group_id: GroupID,
+    ) -> Result<CertificateBasedActionOutcome, CertifShareRealmError> {
+        realm_share::share_realm(self, realm_id, recipient, Some(role), Some(group_id)).await
+    }
+
+    pub async fn update_realm_group_role(
+        &self,
+        realm_id: VlobID,
+        group_id: GroupID,
+        role: Option<RealmRole>,
+    ) -> Result<CertificateBasedActionOutcome, CertifUpdateRealmGroupRoleError> {
+        realm_update_group_role::update_realm_group_role(self, realm_id, group_id, role).await
+    }
+
+    /// Configure when the share of the realm with `user_id` should expire (or
+    /// remove the expiration if `expires_on` is `None`).
+    ///
+    /// The expiration is only known by our device: once due, it is processed
+    /// by the workspaces needs monitor (i.e. the user is unshared, then a key
+    /// rotation occurs).
+    pub async fn set_realm_share_expiration(
+        &self,
+        realm_id: VlobID,
+        user_id: UserID,
+        expires_on: Option<DateTime>,
+    ) -> Result<(), CertifSetRealmShareExpirationError> {
+        realm_share_expiration::set_realm_share_expiration(self, realm_id, user_id, expires_on)
+            .await
+    }
+
+    /// Returns the share expiration of the users currently part of the realm
+    /// (expirations already due included).
+    pub async fn list_realm_share_expirations(
+        &self,
+        realm_id: VlobID,
+    ) -> Result<HashMap<UserID, DateTime>, CertifListRealmShareExpirationsError> {
+        realm_share_expiration::list_realm_share_expirations(self, realm_id).await
     }
 
     /// Do a key rotation for the given realm, provided that `target_key_index`
@@ -564,6 +751,44 @@ impl CertificateOps {
         realm_key_rotation::rotate_realm_key_idempotent(self, realm_id, target_key_index).await
     }
 
+    /// Heal the realm's keys bundle if it is corrupted (or contains corrupted keys)
+    /// by recovering the keys from the previous keys bundles, then doing a key
+    /// rotation with them.
+    pub async fn attempt_realm_keys_bundle_healing(
+        &self,
+        realm_id: VlobID,
+    ) -> Result<KeysBundleHealingOutcome, CertifRotateRealmKeyError> {
+        realm_key_rotation::attempt_realm_keys_bundle_healing(self, realm_id).await
+    }
+
+    /// Re-encrypt the data of the realm with its last key, so that the old keys
+    /// are no longer needed to access its current content (only owners are allowed
+    /// to do this).
+    ///
+    /// This is a long running operation: its progress is stored so that it can be
+    /// resumed by calling this method again if interrupted, and is reported with
+    /// `EventRealmRekeyProgress` and `EventRealmRekeyDone` events.
+    pub async fn rekey_realm(&self, realm_id: VlobID) -> Result<(), CertifRekeyRealmError> {
+        realm_rekey::rekey_realm(self, realm_id).await
+    }
+
+    /// Returns `true` if a re-encryption of the realm's data has been started but
+    /// is not completed (or has been outdated by a new key rotation).
+    pub async fn is_realm_rekey_pending(
+        &self,
+        realm_id: VlobID,
+    ) -> Result<bool, CertifGetRealmRekeyProgressError> {
+        realm_rekey::is_realm_rekey_pending(self, realm_id).await
+    }
+
+    /// Returns `None` if no re-encryption of the realm's data has ever been started.
+    pub async fn get_realm_rekey_progress(
+        &self,
+        realm_id: VlobID,
+    ) -> Result<Option<RealmRekeyProgress>, CertifGetRealmRekeyProgressError> {
+        realm_rekey::get_realm_rekey_progress(self, realm_id).await
+    }
+
     /// Returns the needs of a given realm, i.e. if new key rotation (and users
     /// unsharing) is needed.
     pub async fn get_realm_needs(
@@ -573,6 +798,15 @@ impl CertificateOps {
         realms_needs::get_realm_needs(self, realm_id).await
     }
 
+    /// Returns the shares needed for the members of the groups the realm is
+    /// shared with to get their role.
+    pub async fn get_realm_group_shares_needs(
+        &self,
+        realm_id: VlobID,
+    ) -> Result<Vec<(UserID, RealmRole, GroupID)>, CertifGetRealmGroupSharesNeedsError> {
+        realms_needs::get_realm_group_shares_needs(self, realm_id).await
+    }
+
     /// Bootstrap the workspace in an idempotent way, i.e. ensure the realm
     /// exists on the server and that it can be shared with other users.
     ///
@@ -657,6 +891,32 @@ impl CertificateOps {
         list::list_workspace_users(self, realm_id).await
     }
 
+    /// Decode and validate all the certificates we know about into a chronological
+    /// list of entries (e.g. user creation, realm sharing etc.).
+    ///
+    /// Shamir recovery share certificates are not listed given they are only
+    /// provided to their recipient (the brief certificate lists all the recipients).
+    ///
+    /// Be aware this function potentially do server accesses (to fetch the keys
+    /// bundles needed to decrypt the realm names).
+    pub async fn certificate_audit_log(
+        &self,
+        filter: CertificateAuditLogFilter,
+    ) -> Result<Vec<CertificateAuditLogEntry>, CertifCertificateAuditLogError> {
+        audit_log::certificate_audit_log(self, filter).await
+    }
+
+    pub async fn list_groups(&self) -> Result<Vec<GroupInfo>, CertifListGroupsError> {
+        list::list_groups(self).await
+    }
+
+    pub async fn list_workspace_groups(
+        &self,
+        realm_id: VlobID,
+    ) -> Result<Vec<WorkspaceGroupAccessInfo>, CertifListWorkspaceGroupsError> {
+        list::list_workspace_groups(self, realm_id).await
+    }
+
     /// Retrieve the current archiving configuration of a realm from the last
     /// realm archiving certificate. Returns `Available` if no archiving
     /// certificate exists for the realm.
@@ -6,7 +6,7 @@ use libparsec_types::prelude::*;
 
 use super::{
     greater_timestamp, store::CertifStoreError, CertificateBasedActionOutcome, CertificateOps,
-    GreaterTimestampOffset,
+    ClockDriftError, GreaterTimestampOffset,
 };
 use crate::EventTooMuchDriftWithServerClock;
 
@@ -54,6 +54,8 @@ pub enum CertifArchiveRealmError {
         ballpark_client_late_offset: f64,
     },
     #[error(transparent)]
+    ClockDrift(#[from] ClockDriftError),
+    #[error(transparent)]
     Internal(#[from] anyhow::Error),
 }
 
@@ -118,7 +120,9 @@ pub(super) async fn archive_realm(
                     &ops.device.time_provider,
                     GreaterTimestampOffset::Archive,
                     strictly_greater_than,
-                );
+                )
+                .await
+                .inspect_err(|err| ops.event_bus.send(&err.to_event()))?;
                 continue;
             }
             Rep::RealmNotFound => Err(CertifArchiveRealmError::UnknownRealm),
@@ -8,7 +8,9 @@ use super::{
     store::{CertifStoreError, RealmBootstrapState},
     CertificateBasedActionOutcome, CertificateOps,
 };
-use crate::{greater_timestamp, EventTooMuchDriftWithServerClock, GreaterTimestampOffset};
+use crate::{
+    greater_timestamp, ClockDriftError, EventTooMuchDriftWithServerClock, GreaterTimestampOffset,
+};
 
 #[derive(Debug, thiserror::Error)]
 pub enum CertifEnsureRealmCreatedError {
@@ -28,6 +30,8 @@ pub enum CertifEnsureRealmCreatedError {
         ballpark_client_late_offset: f64,
     },
     #[error(transparent)]
+    ClockDrift(#[from] ClockDriftError),
+    #[error(transparent)]
     Internal(#[from] anyhow::Error),
 }
 
@@ -109,7 +113,9 @@ async fn create_realm_idempotent(
                     &ops.device.time_provider,
                     GreaterTimestampOffset::Realm,
                     strictly_greater_than,
-                );
+                )
+                .await
+                .inspect_err(|err| ops.event_bus.send(&err.to_event()))?;
                 continue;
             }
             // A concurrent operation must has modified our profile to OUTSIDER
@@ -13,11 +13,14 @@ use super::{
 };
 use crate::{
     certif::{
-        realm_keys_bundle::{self, GenerateNextKeyBundleForRealmError},
+        realm_keys_bundle::{
+            self, GenerateNextKeyBundleForRealmError, KeysBundleHealingOutcome,
+            RecoverRealmKeysError,
+        },
         CertifPollServerError,
     },
-    greater_timestamp, CertifEncryptForSequesterServicesError, EventTooMuchDriftWithServerClock,
-    GreaterTimestampOffset, InvalidKeysBundleError,
+    greater_timestamp, CertifEncryptForSequesterServicesError, ClockDriftError,
+    EventTooMuchDriftWithServerClock, GreaterTimestampOffset, InvalidKeysBundleError,
 };
 
 #[derive(Debug, thiserror::Error)]
@@ -32,12 +35,11 @@ pub enum CertifRotateRealmKeyError {
     Offline(#[from] ConnectionError),
     #[error("Component has stopped")]
     Stopped,
-    // TODO: This error should be `CurrentKeysBundleCorruptedAndUnrecoverable`, given
-    //       we should attempt a self-healing by recursively getting older keys bundles;
-    //       and only returning this error if our user has reached the last keys bundle
-    //       he has access to without a single one being valid.
-    #[error("Cannot achieve a key rotation if the current keys bundle is corrupted: {0}")]
-    CurrentKeysBundleCorrupted(Box<InvalidKeysBundleError>),
+    /// Self-healing has been attempted by recursively getting older keys bundles,
+    /// but our user has reached the last keys bundle he has access to without
+    /// a single one being valid.
+    #[error("Cannot achieve a key rotation if the current keys bundle is corrupted and unrecoverable: {0}")]
+    CurrentKeysBundleCorruptedAndUnrecoverable(Box<InvalidKeysBundleError>),
     #[error("Our clock ({client_timestamp}) and the server's one ({server_timestamp}) are too far apart")]
     TimestampOutOfBallpark {
         server_timestamp: DateTime,
@@ -48,6 +50,8 @@ pub enum CertifRotateRealmKeyError {
     #[error(transparent)]
     InvalidCertificate(#[from] Box<InvalidCertificateError>),
     #[error(transparent)]
+    ClockDrift(#[from] ClockDriftError),
+    #[error(transparent)]
     Internal(#[from] anyhow::Error),
 }
 
@@ -129,7 +133,9 @@ pub(super) async fn rotate_realm_key_idempotent(
                     &ops.device.time_provider,
                     GreaterTimestampOffset::Realm,
                     strictly_greater_than,
-                );
+                )
+                .await
+                .inspect_err(|err| ops.event_bus.send(&err.to_event()))?;
                 continue;
             }
             Rep::TimestampOutOfBallpark {
@@ -188,6 +194,55 @@ pub(super) async fn rotate_realm_key_idempotent(
     }
 }
 
+/// Heal the realm keys bundle if it is corrupted (or contains corrupted keys).
+///
+/// The keys are recovered from the previous keys bundles our user has access to,
+/// then a key rotation is done so that the new keys bundle contains every
+/// recoverable key.
+pub(super) async fn attempt_realm_keys_bundle_healing(
+    ops: &CertificateOps,
+    realm_id: VlobID,
+) -> Result<KeysBundleHealingOutcome, CertifRotateRealmKeyError> {
+    // 1) Determine if healing is needed (and possible !)
+
+    let (outcome, key_index) = ops
+        .store
+        .for_read(async |store| {
+            realm_keys_bundle::prepare_realm_keys_bundle_healing(ops, store, realm_id)
+                .await
+                .map_err(|err| match err {
+                    RecoverRealmKeysError::Offline(e) => CertifRotateRealmKeyError::Offline(e),
+                    RecoverRealmKeysError::NotAllowed => {
+                        CertifRotateRealmKeyError::AuthorNotAllowed
+                    }
+                    RecoverRealmKeysError::RealmDeleted => CertifRotateRealmKeyError::RealmDeleted,
+                    RecoverRealmKeysError::NoKey => CertifRotateRealmKeyError::UnknownRealm,
+                    RecoverRealmKeysError::Internal(err) => err
+                        .context("Cannot recover realm keys from previous keys bundles")
+                        .into(),
+                })
+        })
+        .await??;
+
+    match outcome {
+        KeysBundleHealingOutcome::NotNeeded | KeysBundleHealingOutcome::NotPossible => {
+            return Ok(outcome)
+        }
+        KeysBundleHealingOutcome::PartialSuccess { .. }
+        | KeysBundleHealingOutcome::TotalSuccess => {}
+    }
+
+    // 2) Do the actual healing with a key rotation
+
+    // Note the keys are recovered once again while generating the next keys bundle,
+    // this is wasteful but healing is an exceptional operation anyway.
+    // If the key rotation turns out to be idempotent, it means another key rotation
+    // has occurred concurrently (which has itself taken care of the healing).
+    rotate_realm_key_idempotent(ops, realm_id, key_index + 1).await?;
+
+    Ok(outcome)
+}
+
 async fn generate_realm_rotate_key_req(
     ops: &CertificateOps,
     realm_id: VlobID,
@@ -216,9 +271,8 @@ async fn generate_realm_rotate_key_req(
                         GenerateNextKeyBundleForRealmError::Internal(err) => {
                             CertifRotateRealmKeyError::Internal(err)
                         }
-                        // TODO: Attempt self-healing here !
-                        GenerateNextKeyBundleForRealmError::InvalidKeysBundle(err) => {
-                            CertifRotateRealmKeyError::CurrentKeysBundleCorrupted(err)
+                        GenerateNextKeyBundleForRealmError::CurrentKeysBundleCorruptedAndUnrecoverable(err) => {
+                            CertifRotateRealmKeyError::CurrentKeysBundleCorruptedAndUnrecoverable(err)
                         }
                     })?;
             let key_index = keys_bundle.key_index();
@@ -6,7 +6,7 @@ use libparsec_client_connection::ConnectionError;
 use libparsec_platform_storage::certificates::{GetCertificateError, UpTo};
 use libparsec_types::prelude::*;
 
-use crate::InvalidCertificateError;
+use crate::{EventRealmKeysBundleCorruptedAndUnrecoverable, InvalidCertificateError};
 
 use super::{
     encrypt::CertifEncryptForUserError, store::CertificatesStoreReadGuard, CertificateOps,
@@ -86,6 +86,12 @@ impl RealmKeys {
         self.keys.len() as IndexInt
     }
 
+    pub fn has_corrupted_keys(&self) -> bool {
+        self.keys
+            .iter()
+            .any(|key| matches!(key, ValidatedKey::Corrupted { .. }))
+    }
+
     /// Return `None` if no valid key exists :(
     pub fn last_valid_key(&self) -> Option<(&KeyDerivation, IndexInt)> {
         self.keys
@@ -261,8 +267,9 @@ async fn load_last_realm_keys_bundle(
     Ok(realm_keys)
 }
 
-// TODO: finish key bundle healing !
-#[allow(dead_code)]
+/// Outcome of a realm keys bundle healing attempt, see
+/// `CertificateOps::attempt_realm_keys_bundle_healing`.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum KeysBundleHealingOutcome {
     /// Current last keys bundle is valid and contains only valid keys.
     NotNeeded,
@@ -277,57 +284,117 @@ pub enum KeysBundleHealingOutcome {
 }
 
 #[derive(Debug, thiserror::Error)]
-pub(super) enum AttemptRealmKeysBundleHealingError {
+pub enum RecoverRealmKeysError {
     #[error("Cannot communicate with the server: {0}")]
     Offline(#[from] ConnectionError),
     #[error("Not allowed to access this realm")]
     NotAllowed,
     #[error("The workspace's realm has been deleted on the server")]
     RealmDeleted,
-    // TODO: finish key bundle healing !
-    #[allow(dead_code)]
     #[error("The realm doesn't have any key yet")]
     NoKey,
     #[error(transparent)]
     Internal(#[from] anyhow::Error),
 }
 
-// /// Recursively load all keys bundle the user has access to in order to recover
-// /// as much keys as possible.
-// /// If the number of keys retrieved doesn't account for the number of key
-// /// rotations, this function complete with dummy zeroized keys.
-// ///
-// /// This method is useful when the last keys bundle is corrupted, or if it contains
-// /// corrupted keys.
-// ///
-// /// Note a user only have access to the keys bundles starting from the one that was
-// /// the current one when he joined the realm. Hence the capacity to recover keys
-// /// depend on the user doing the recovery.
-// ///
-// /// This method is typically used by a monitor.
-// pub(super) async fn attempt_realm_keys_bundle_healing<'a>(
-//     ops: &CertificateOps,
-//     realm_id: VlobID,
-// ) -> Result<KeysBundleHealingOutcome, AttemptRealmKeysBundleHealingError> {
-//     loop {
-//         let outcome = ops.store.for_read(async |store| {
-//             recover_realm_keys_from_previous_bundles(ops, realm_id).await
-//         }).await??;
-
-//         if matches!(outcome, KeysBundleHealingOutcome::NotNeeded | KeysBundleHealingOutcome::NotPossible) {
-//             return Ok(outcome);
-//         }
-
-//     }
-// }
-
-// TODO: finish key bundle healing !
-#[allow(dead_code)]
+enum RecoverRealmKeysOutcome {
+    /// The last keys bundle is valid, and the previous keys bundles don't provide
+    /// a valid version of any of its corrupted keys (if any).
+    NothingToRecover,
+    /// None of the keys bundles we have access to is valid :(
+    Unrecoverable,
+    /// We have done better than the last keys bundle, those keys should be used
+    /// for the next key rotation.
+    Recovered {
+        keys: Vec<KeyDerivation>,
+        still_broken_keys: Vec<IndexInt>,
+    },
+}
+
+/// Check if the last keys bundle of the realm needs healing, and if so recover
+/// the keys from the previous keys bundles.
+///
+/// The returned key index is the one of the last known key rotation, the healing
+/// itself is achieved by doing a key rotation (i.e. with key index + 1) providing
+/// the recovered keys.
+///
+/// A healing that turned out not to be possible is remembered (until the next key
+/// rotation), so that the keys bundles are not fetched again on each call.
+pub(super) async fn prepare_realm_keys_bundle_healing(
+    ops: &CertificateOps,
+    store: &mut CertificatesStoreReadGuard<'_>,
+    realm_id: VlobID,
+) -> Result<(KeysBundleHealingOutcome, IndexInt), RecoverRealmKeysError> {
+    // Key index is needed given the keys bundle may not be usable to obtain it
+    let last_key_index = store
+        .get_realm_key_rotation_certificates(UpTo::Current, realm_id)
+        .await?
+        .len() as IndexInt;
+    if store.is_realm_keys_bundle_healing_known_not_possible(realm_id, last_key_index) {
+        return Ok((KeysBundleHealingOutcome::NotPossible, last_key_index));
+    }
+
+    let key_index = match load_last_realm_keys_bundle(ops, store, realm_id).await {
+        Ok(realm_keys) if !realm_keys.has_corrupted_keys() => {
+            return Ok((KeysBundleHealingOutcome::NotNeeded, realm_keys.key_index()))
+        }
+        Ok(realm_keys) => realm_keys.key_index(),
+        Err(err) => match err {
+            LoadLastKeysBundleError::InvalidKeysBundle(_) => last_key_index,
+            // No key, no problem !
+            LoadLastKeysBundleError::NoKey => return Ok((KeysBundleHealingOutcome::NotNeeded, 0)),
+            LoadLastKeysBundleError::Offline(e) => return Err(RecoverRealmKeysError::Offline(e)),
+            LoadLastKeysBundleError::NotAllowed => return Err(RecoverRealmKeysError::NotAllowed),
+            LoadLastKeysBundleError::RealmDeleted => {
+                return Err(RecoverRealmKeysError::RealmDeleted)
+            }
+            LoadLastKeysBundleError::Internal(err) => return Err(err.into()),
+        },
+    };
+
+    let outcome = match recover_realm_keys_from_previous_bundles(ops, store, realm_id).await? {
+        RecoverRealmKeysOutcome::NothingToRecover => {
+            store.update_cache_for_realm_keys_bundle_healing_not_possible(realm_id, key_index);
+            KeysBundleHealingOutcome::NotPossible
+        }
+        RecoverRealmKeysOutcome::Unrecoverable => {
+            // Only fired once per key index, see above
+            store.update_cache_for_realm_keys_bundle_healing_not_possible(realm_id, key_index);
+            ops.event_bus
+                .send(&EventRealmKeysBundleCorruptedAndUnrecoverable { realm_id });
+            KeysBundleHealingOutcome::NotPossible
+        }
+        RecoverRealmKeysOutcome::Recovered {
+            still_broken_keys, ..
+        } => {
+            if still_broken_keys.is_empty() {
+                KeysBundleHealingOutcome::TotalSuccess
+            } else {
+                KeysBundleHealingOutcome::PartialSuccess { still_broken_keys }
+            }
+        }
+    };
+
+    Ok((outcome, key_index))
+}
+
+/// Recursively load all keys bundle the user has access to in order to recover
+/// as much keys as possible.
+/// If a key cannot be recovered, the corrupted version found in the most recent
+/// keys bundle is kept (or a dummy zeroized key if no keys bundle could be loaded
+/// at all).
+///
+/// This method is useful when the last keys bundle is corrupted, or if it contains
+/// corrupted keys.
+///
+/// Note a user only have access to the keys bundles starting from the one that was
+/// the current one when he joined the realm. Hence the capacity to recover keys
+/// depend on the user doing the recovery.
 async fn recover_realm_keys_from_previous_bundles(
     ops: &CertificateOps,
     store: &mut CertificatesStoreReadGuard<'_>,
     realm_id: VlobID,
-) -> Result<KeysBundleHealingOutcome, AttemptRealmKeysBundleHealingError> {
+) -> Result<RecoverRealmKeysOutcome, RecoverRealmKeysError> {
     // 1) Retrieve all key rotation certificates, we will need them all given
     //    each key has to be validated
 
@@ -336,6 +403,10 @@ async fn recover_realm_keys_from_previous_bundles(
     let key_rotation_certificates = store
         .get_realm_key_rotation_certificates(UpTo::Current, realm_id)
         .await?;
+    let last_key_index = key_rotation_certificates.len() as IndexInt;
+    if last_key_index == 0 {
+        return Err(RecoverRealmKeysError::NoKey);
+    }
 
     // 2) Start with all keys unknown, and then progressively recover them from the
     //    keys bundles.
@@ -343,6 +414,12 @@ async fn recover_realm_keys_from_previous_bundles(
     let mut recovered_keys = (0..key_rotation_certificates.len())
         .map(|_| None)
         .collect::<Vec<_>>();
+    // Keys that are corrupted in all the keys bundles we have access to are kept
+    // as-is in the next keys bundle. Who knows, maybe we are the buggy one and they
+    // are valid after all !
+    let mut corrupted_keys: Vec<Option<KeyDerivation>> = (0..key_rotation_certificates.len())
+        .map(|_| None)
+        .collect::<Vec<_>>();
 
     // At the end we will compare our healing to the last realm keys bundle to determine
     // if we have been able to recover some keys (and in this case we will proceed with
@@ -353,11 +430,14 @@ async fn recover_realm_keys_from_previous_bundles(
         Corrupted,
     }
     let mut last_realm_keys_bundle = FetchLastKeysBundle::NotYet;
+    let mut valid_keys_bundle_found = false;
 
     // Note we progress backward (last certificate first) given 1) it's most likely the
     // last certificate contains most key (if not all !), and 2) we only have access to
     // certificates starting from the one that was the current one when we joined.
     for key_rotation_certificate in key_rotation_certificates.iter().rev() {
+        let is_last_keys_bundle = key_rotation_certificate.key_index == last_key_index;
+
         // 3) Fetch the corresponding keys bundle
 
         let (keys_bundle, keys_bundle_access) = {
@@ -375,10 +455,13 @@ async fn recover_realm_keys_from_previous_bundles(
                     ..
                 } => (keys_bundle, keys_bundle_access),
 
-                Rep::AuthorNotAllowed => {
-                    return Err(AttemptRealmKeysBundleHealingError::NotAllowed)
+                Rep::AuthorNotAllowed => return Err(RecoverRealmKeysError::NotAllowed),
+                Rep::RealmDeleted => return Err(RecoverRealmKeysError::RealmDeleted),
+                // Not having access to the current keys bundle means we are no longer
+                // part of the realm.
+                Rep::AccessNotAvailableForAuthor if is_last_keys_bundle => {
+                    return Err(RecoverRealmKeysError::NotAllowed)
                 }
-                Rep::RealmDeleted => return Err(AttemptRealmKeysBundleHealingError::RealmDeleted),
                 // This key rotation has been done while we were not part of the realm,
                 // so we have no choice but to skip it.
                 // Not we still continue to fetch the previous keys bundle, as we might
@@ -417,22 +500,35 @@ async fn recover_realm_keys_from_previous_bundles(
         match outcome {
             // The keys bundle is valid, we have recovered all the valid keys it contains !
             Ok(realm_keys) => {
-                if matches!(last_realm_keys_bundle, FetchLastKeysBundle::NotYet) {
+                valid_keys_bundle_found = true;
+                if is_last_keys_bundle {
                     last_realm_keys_bundle = FetchLastKeysBundle::Done(realm_keys.clone());
                 }
 
-                for (recovered_key, current_bundle_keys) in
-                    recovered_keys.iter_mut().zip(&realm_keys.keys)
+                for ((recovered_key, corrupted_key), current_bundle_key) in recovered_keys
+                    .iter_mut()
+                    .zip(corrupted_keys.iter_mut())
+                    .zip(&realm_keys.keys)
                 {
-                    if let ValidatedKey::Valid { key, .. } = current_bundle_keys {
-                        *recovered_key = Some(key.to_owned());
+                    match current_bundle_key {
+                        ValidatedKey::Valid { key, .. } => {
+                            *recovered_key = Some(key.to_owned());
+                        }
+                        ValidatedKey::Corrupted {
+                            corrupted_key_dont_use_me,
+                        } => {
+                            // Only keep the most recent version of the corrupted key
+                            if corrupted_key.is_none() {
+                                *corrupted_key = Some(corrupted_key_dont_use_me.to_owned());
+                            }
+                        }
                     }
                 }
             }
             Err(err) => match err {
                 // Keys bundle is itself corrupted, so we cannot recover any key from it :(
                 ValidateKeysBundleError::InvalidKeysBundle(_) => {
-                    if matches!(last_realm_keys_bundle, FetchLastKeysBundle::NotYet) {
+                    if is_last_keys_bundle {
                         last_realm_keys_bundle = FetchLastKeysBundle::Corrupted;
                     }
                 }
@@ -459,15 +555,22 @@ async fn recover_realm_keys_from_previous_bundles(
     //    if we have been able to recover some keys.
 
     let recovery_better_than_last_keys_bundle = match last_realm_keys_bundle {
-        // This realm doesn't have any key yet, so there is nothing to improve
-        FetchLastKeysBundle::NotYet => false,
+        // The last keys bundle is always the first one to be fetched (and we return
+        // early if we don't have access to it).
+        FetchLastKeysBundle::NotYet => unreachable!(),
         // If the last keys bundle is corrupted, indeed we want to replace it !
-        FetchLastKeysBundle::Corrupted => true,
+        // ...provided we have found something to replace it with.
+        FetchLastKeysBundle::Corrupted => {
+            if !valid_keys_bundle_found {
+                return Ok(RecoverRealmKeysOutcome::Unrecoverable);
+            }
+            true
+        }
         FetchLastKeysBundle::Done(realm_keys) => {
             realm_keys
                 .keys
                 .iter()
-                .zip(recovered_keys)
+                .zip(&recovered_keys)
                 .any(|(key, recovered_key)| match (key, recovered_key) {
                     // We have successfully recovered a key that was corrupted in the last keys bundle !
                     (ValidatedKey::Corrupted { .. }, Some(_)) => true,
@@ -480,12 +583,32 @@ async fn recover_realm_keys_from_previous_bundles(
     };
 
     if !recovery_better_than_last_keys_bundle {
-        return Ok(KeysBundleHealingOutcome::NotNeeded);
+        return Ok(RecoverRealmKeysOutcome::NothingToRecover);
     }
 
-    // 7) Final step: do a key rotation with our recovered keys !
+    // 7) Final step: provide the recovered keys, completed with the broken ones
+    //    for the keys we couldn't recover.
+
+    let mut still_broken_keys = vec![];
+    let keys = recovered_keys
+        .into_iter()
+        .zip(corrupted_keys)
+        .zip(1..)
+        .map(
+            |((recovered_key, corrupted_key), key_index)| match recovered_key {
+                Some(key) => key,
+                None => {
+                    still_broken_keys.push(key_index);
+                    corrupted_key.unwrap_or_else(|| KeyDerivation::from([0; KeyDerivation::SIZE]))
+                }
+            },
+        )
+        .collect();
 
-    todo!("do a key rotation with our recovered keys")
+    Ok(RecoverRealmKeysOutcome::Recovered {
+        keys,
+        still_broken_keys,
+    })
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -891,6 +1014,74 @@ pub(super) async fn encrypt_for_realm(
     Ok((encrypted, key_index))
 }
 
+/// Encrypt a block with the last known key, the block ID being derived from the
+/// block's content.
+///
+/// The derivation is keyed with the realm's key, so the server cannot correlate
+/// the ID with the content, while the blocks with the same content in the realm
+/// end up with the same ID (and hence only need to be uploaded once).
+///
+/// Note a key rotation changes the derivation key, hence deduplication only occurs
+/// among the blocks uploaded with the same key.
+///
+/// However the ID is predictable by any member of the realm, hence a block already
+/// existing on the server under this ID must be fetched and validated before being
+/// referenced (otherwise a malicious member could upload junk data under the ID
+/// in order to corrupt the files of the other members).
+///
+/// If `compressed` is `true`, the data gets compressed before encryption. In this
+/// case another derivation key is used: this way a given block ID always refers to
+/// the same cleartext whatever the compressed flag of the block access.
+pub(super) async fn encrypt_deduplicated_block_for_realm(
+    ops: &CertificateOps,
+    store: &mut CertificatesStoreReadGuard<'_>,
+    realm_id: VlobID,
+    data: &[u8],
+    compressed: bool,
+) -> Result<(BlockID, Vec<u8>, IndexInt), CertifEncryptForRealmError> {
+    let realm_keys = load_last_realm_keys_bundle(ops, store, realm_id)
+        .await
+        .map_err(|e| match e {
+            LoadLastKeysBundleError::Offline(e) => CertifEncryptForRealmError::Offline(e),
+            LoadLastKeysBundleError::NotAllowed => CertifEncryptForRealmError::NotAllowed,
+            LoadLastKeysBundleError::RealmDeleted => CertifEncryptForRealmError::RealmDeleted,
+            LoadLastKeysBundleError::NoKey => CertifEncryptForRealmError::NoKey,
+            LoadLastKeysBundleError::InvalidKeysBundle(err) => {
+                CertifEncryptForRealmError::InvalidKeysBundle(err)
+            }
+            LoadLastKeysBundleError::Internal(err) => {
+                err.context("Cannot retrieve realm encryption info").into()
+            }
+        })?;
+
+    let (key_derivation, key_index) = realm_keys
+        .last_valid_key()
+        .ok_or(CertifEncryptForRealmError::NoKey)?;
+
+    let block_id = {
+        let derivation_uuid = if compressed {
+            COMPRESSED_BLOCK_DEDUPLICATION_KEY_DERIVATION_UUID
+        } else {
+            BLOCK_DEDUPLICATION_KEY_DERIVATION_UUID
+        };
+        let mac = key_derivation
+            .derive_secret_key_from_uuid(derivation_uuid)
+            .mac_512(data);
+        BlockID::try_from(&mac[..16]).expect("mac is 64 bytes long")
+    };
+
+    let key = key_derivation
+        .derive_secret_key_from_uuid(EncrytionUsage::Block(block_id).key_derivation_uuid());
+
+    let encrypted = if compressed {
+        key.encrypt(&BlockAccess::compress_data(data))
+    } else {
+        key.encrypt(data)
+    };
+
+    Ok((block_id, encrypted, key_index))
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum CertifDecryptForRealmError {
     /// Stopped is not used by `encrypt_for_realm`, but is convenient anyways given
@@ -962,17 +1153,39 @@ pub enum GenerateNextKeyBundleForRealmError {
     NotAllowed,
     #[error("The workspace's realm has been deleted on the server")]
     RealmDeleted,
-    #[error(transparent)]
-    InvalidKeysBundle(#[from] Box<InvalidKeysBundleError>),
+    #[error(
+        "The current keys bundle is corrupted and cannot be recovered from the previous ones: {0}"
+    )]
+    CurrentKeysBundleCorruptedAndUnrecoverable(Box<InvalidKeysBundleError>),
     #[error(transparent)]
     Internal(#[from] anyhow::Error),
 }
 
+impl From<RecoverRealmKeysError> for GenerateNextKeyBundleForRealmError {
+    fn from(value: RecoverRealmKeysError) -> Self {
+        match value {
+            RecoverRealmKeysError::Offline(e) => Self::Offline(e),
+            RecoverRealmKeysError::NotAllowed => Self::NotAllowed,
+            RecoverRealmKeysError::RealmDeleted => Self::RealmDeleted,
+            // We only try to recover keys once we know there is at least one key
+            // rotation certificate.
+            RecoverRealmKeysError::NoKey => {
+                anyhow::anyhow!("Realm keys disappeared while recovering them").into()
+            }
+            RecoverRealmKeysError::Internal(err) => err.into(),
+        }
+    }
+}
+
 /// This methods retrieves the current keys bundle for a given realm, and appends
 /// to it another key. The resulting couple (keys bundle, keys bundle access) can
 /// then be used to do a key rotation.
 /// Note the realm existence is not checked, and an invalid realm ID will be handled
 /// similarly to a realm with no key yet.
+///
+/// If the current keys bundle is corrupted (or contains corrupted keys), the keys
+/// are recovered from the previous keys bundles, so that the key rotation also
+/// heals the realm keys.
 pub(super) async fn generate_next_keys_bundle_for_realm(
     ops: &CertificateOps,
     store: &mut CertificatesStoreReadGuard<'_>,
@@ -980,11 +1193,47 @@ pub(super) async fn generate_next_keys_bundle_for_realm(
 ) -> Result<(RealmKeysBundle, RealmKeysBundleAccess), GenerateNextKeyBundleForRealmError> {
     let outcome = load_last_realm_keys_bundle(ops, store, realm_id).await;
 
-    let realm_keys = match outcome {
-        Ok(realm_keys) => Some(realm_keys),
+    let mut keys = match outcome {
+        Ok(realm_keys) => {
+            let outcome = if realm_keys.has_corrupted_keys() {
+                recover_realm_keys_from_previous_bundles(ops, store, realm_id).await?
+            } else {
+                RecoverRealmKeysOutcome::NothingToRecover
+            };
+            match outcome {
+                RecoverRealmKeysOutcome::Recovered { keys, .. } => keys,
+                // Note `Unrecoverable` is only possible if the server has provided us
+                // a different keys bundle than the one we have in cache.
+                RecoverRealmKeysOutcome::NothingToRecover
+                | RecoverRealmKeysOutcome::Unrecoverable => realm_keys
+                    .keys
+                    .iter()
+                    .map(|k| match k {
+                        ValidatedKey::Valid { key, .. } => key.to_owned(),
+                        // Note we don't *use* the key here, we only keep it as it is in the next keys bundle.
+                        ValidatedKey::Corrupted {
+                            corrupted_key_dont_use_me,
+                        } => corrupted_key_dont_use_me.to_owned(),
+                    })
+                    .collect(),
+            }
+        }
         Err(err) => match err {
-            LoadLastKeysBundleError::NoKey => None,
+            LoadLastKeysBundleError::NoKey => vec![],
 
+            LoadLastKeysBundleError::InvalidKeysBundle(err) => {
+                match recover_realm_keys_from_previous_bundles(ops, store, realm_id).await? {
+                    RecoverRealmKeysOutcome::Recovered { keys, .. } => keys,
+                    RecoverRealmKeysOutcome::NothingToRecover
+                    | RecoverRealmKeysOutcome::Unrecoverable => {
+                        ops.event_bus
+                            .send(&EventRealmKeysBundleCorruptedAndUnrecoverable { realm_id });
+                        return Err(
+                            GenerateNextKeyBundleForRealmError::CurrentKeysBundleCorruptedAndUnrecoverable(err),
+                        );
+                    }
+                }
+            }
             LoadLastKeysBundleError::Offline(e) => {
                 return Err(GenerateNextKeyBundleForRealmError::Offline(e))
             }
@@ -994,34 +1243,12 @@ pub(super) async fn generate_next_keys_bundle_for_realm(
             LoadLastKeysBundleError::RealmDeleted => {
                 return Err(GenerateNextKeyBundleForRealmError::RealmDeleted)
             }
-            LoadLastKeysBundleError::InvalidKeysBundle(err) => {
-                return Err(GenerateNextKeyBundleForRealmError::InvalidKeysBundle(err))
-            }
             LoadLastKeysBundleError::Internal(err) => {
                 return Err(GenerateNextKeyBundleForRealmError::Internal(err))
             }
         },
     };
-
-    let new_key = KeyDerivation::generate();
-    let keys = match realm_keys {
-        None => vec![new_key],
-        Some(realm_keys) => {
-            let mut keys: Vec<_> = realm_keys
-                .keys
-                .iter()
-                .map(|k| match k {
-                    ValidatedKey::Valid { key, .. } => key.to_owned(),
-                    // Note we don't *use* the key here, we only keep it as it is in the next keys bundle.
-                    ValidatedKey::Corrupted {
-                        corrupted_key_dont_use_me,
-                    } => corrupted_key_dont_use_me.to_owned(),
-                })
-                .collect();
-            keys.push(new_key);
-            keys
-        }
-    };
+    keys.push(KeyDerivation::generate());
 
     let keys_bundle = RealmKeysBundle::new(ops.device.device_id, ops.device.now(), realm_id, keys);
 
@@ -0,0 +1,799 @@
+// Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+//! Re-encryption of the data of a realm with its last key.
+//!
+//! A key rotation only applies to the data uploaded after it, so existing vlobs
+//! and blocks stay encrypted with the old keys (and hence remain readable by
+//! anyone who kept an old keys bundle, e.g. a revoked user).
+//!
+//! The re-key job is run by an owner of the realm and works in passes:
+//! - Each pass lists the vlobs that have changed since the pass checkpoint and
+//!   processes them ordered by ID.
+//! - For each vlob, the blocks (if any) encrypted with an old key are re-uploaded,
+//!   then the manifest is re-uploaded as a new version encrypted with the last key.
+//! - Our own uploads are part of the changes listed by the next pass, so the job
+//!   is completed once a pass has nothing to re-encrypt.
+//!
+//! Progress is stored in the certificates storage after each batch of vlobs, so
+//! the job can be interrupted and resumed at any time. An interrupted job is
+//! resumed by the workspaces process needs monitor (see `is_realm_rekey_pending`).
+//!
+//! Limitations:
+//! - Only the last version of each vlob is re-encrypted: the server keeps the
+//!   previous versions (and the blocks they reference) as-is since they are
+//!   immutable. Hence the old keys cannot be retired: they are still provided
+//!   in the keys bundle so that the workspace history remains readable.
+//! - Re-encrypted blocks get a new ID, so the re-uploaded file manifests differ
+//!   from the previous version only by their block IDs. The merge of a file
+//!   manifest detects this case (see `workspace::merge`) so that a client with
+//!   pending local changes doesn't end up with a conflict.
+
+use std::collections::HashMap;
+
+use libparsec_client_connection::ConnectionError;
+use libparsec_platform_storage::certificates::{PerTopicLastTimestamps, RealmRekeyProgress, UpTo};
+use libparsec_protocol::authenticated_cmds;
+use libparsec_types::prelude::*;
+
+use super::{
+    greater_timestamp, store::CertifStoreError, CertifEncryptForRealmError, CertifPollServerError,
+    CertifValidateBlockError, CertifValidateManifestError, CertificateOps, ClockDriftError,
+    EncrytionUsage, GreaterTimestampOffset, InvalidCertificateError, InvalidKeysBundleError,
+};
+use crate::{EventRealmRekeyDone, EventRealmRekeyProgress};
+
+/// Number of vlobs fetched (and checkpointed) at once, must not exceed the
+/// server limit for `vlob_read_batch`.
+const REKEY_BATCH_SIZE: usize = 100;
+
+#[derive(Debug, thiserror::Error)]
+pub enum CertifRekeyRealmError {
+    #[error("Component has stopped")]
+    Stopped,
+    #[error("Cannot communicate with the server: {0}")]
+    Offline(#[from] ConnectionError),
+    #[error("Unknown realm ID")]
+    UnknownRealm,
+    #[error("The workspace's realm has been deleted on the server")]
+    RealmDeleted,
+    #[error("The workspace's realm has been archived")]
+    RealmArchived,
+    #[error("Only an owner of the realm can re-encrypt its data")]
+    AuthorNotAllowed,
+    #[error("The server's blockstore is not available")]
+    ServerBlockstoreUnavailable,
+    #[error("Our clock ({client_timestamp}) and the server's one ({server_timestamp}) are too far apart")]
+    TimestampOutOfBallpark {
+        server_timestamp: DateTime,
+        client_timestamp: DateTime,
+        ballpark_client_early_offset: f64,
+        ballpark_client_late_offset: f64,
+    },
+    #[error(transparent)]
+    InvalidCertificate(#[from] Box<InvalidCertificateError>),
+    #[error(transparent)]
+    InvalidKeysBundle(#[from] Box<InvalidKeysBundleError>),
+    #[error(transparent)]
+    ClockDrift(#[from] ClockDriftError),
+    #[error(transparent)]
+    Internal(#[from] anyhow::Error),
+}
+
+impl From<CertifStoreError> for CertifRekeyRealmError {
+    fn from(value: CertifStoreError) -> Self {
+        match value {
+            CertifStoreError::Stopped => Self::Stopped,
+            CertifStoreError::Internal(err) => err.into(),
+        }
+    }
+}
+
+impl From<CertifPollServerError> for CertifRekeyRealmError {
+    fn from(value: CertifPollServerError) -> Self {
+        match value {
+            CertifPollServerError::Stopped => Self::Stopped,
+            CertifPollServerError::Offline(e) => Self::Offline(e),
+            CertifPollServerError::InvalidCertificate(err) => Self::InvalidCertificate(err),
+            CertifPollServerError::Internal(err) => err
+                .context("Cannot poll server for new certificates")
+                .into(),
+        }
+    }
+}
+
+impl From<CertifEncryptForRealmError> for CertifRekeyRealmError {
+    fn from(value: CertifEncryptForRealmError) -> Self {
+        match value {
+            CertifEncryptForRealmError::Stopped => Self::Stopped,
+            CertifEncryptForRealmError::Offline(e) => Self::Offline(e),
+            CertifEncryptForRealmError::NotAllowed => Self::AuthorNotAllowed,
+            CertifEncryptForRealmError::RealmDeleted => Self::RealmDeleted,
+            // We always have a key given the realm's last key index has been
+            // obtained from its key rotation certificates.
+            CertifEncryptForRealmError::NoKey => {
+                anyhow::anyhow!("Unexpected missing key for realm encryption").into()
+            }
+            CertifEncryptForRealmError::InvalidKeysBundle(err) => Self::InvalidKeysBundle(err),
+            CertifEncryptForRealmError::Internal(err) => {
+                err.context("Cannot encrypt for realm").into()
+            }
+        }
+    }
+}
+
+pub type CertifGetRealmRekeyProgressError = CertifStoreError;
+
+pub(super) async fn get_realm_rekey_progress(
+    ops: &CertificateOps,
+    realm_id: VlobID,
+) -> Result<Option<RealmRekeyProgress>, CertifGetRealmRekeyProgressError> {
+    ops.store.get_realm_rekey_progress(realm_id).await
+}
+
+/// Return `true` if a re-key job has been started for the realm and is not completed
+/// yet, or if a new key rotation occurred since it has completed.
+pub(super) async fn is_realm_rekey_pending(
+    ops: &CertificateOps,
+    realm_id: VlobID,
+) -> Result<bool, CertifGetRealmRekeyProgressError> {
+    let progress = match ops.store.get_realm_rekey_progress(realm_id).await? {
+        None => return Ok(false),
+        Some(progress) => progress,
+    };
+
+    let last_key_index = ops
+        .store
+        .for_read(async |store| {
+            store
+                .get_realm_last_key_rotation_certificate(UpTo::Current, realm_id)
+                .await
+        })
+        .await?
+        .map_err(CertifStoreError::Internal)?
+        .map(|certif| certif.key_index)
+        .unwrap_or(0);
+
+    Ok(!progress.completed || progress.target_key_index < last_key_index)
+}
+
+pub(super) async fn rekey_realm(
+    ops: &CertificateOps,
+    realm_id: VlobID,
+) -> Result<(), CertifRekeyRealmError> {
+    // 1) Make sure we know about the last key rotation, and that we are allowed
+    //    to re-encrypt the realm
+
+    ops.poll_server_for_new_certificates(None).await?;
+
+    let (self_role, last_key_rotation) = ops
+        .store
+        .for_read(async |store| {
+            let self_role = store.get_self_user_realm_role(realm_id).await?;
+            let last_key_rotation = store
+                .get_realm_last_key_rotation_certificate(UpTo::Current, realm_id)
+                .await?;
+            anyhow::Ok((self_role, last_key_rotation))
+        })
+        .await??;
+
+    match self_role {
+        None => return Err(CertifRekeyRealmError::UnknownRealm),
+        Some(Some(RealmRole::Owner)) => (),
+        Some(_) => return Err(CertifRekeyRealmError::AuthorNotAllowed),
+    }
+
+    // No key rotation means the realm is not bootstrapped, hence it contains no data
+    let target_key_index = match last_key_rotation {
+        None => return Ok(()),
+        Some(certif) => certif.key_index,
+    };
+
+    // 2) Resume the previous re-key job if it was targeting the same key index
+
+    let mut progress = match ops.store.get_realm_rekey_progress(realm_id).await? {
+        Some(progress) if progress.target_key_index == target_key_index => progress,
+        _ => RealmRekeyProgress {
+            realm_id,
+            target_key_index,
+            checkpoint: 0,
+            cursor: None,
+            completed: false,
+        },
+    };
+
+    if progress.completed {
+        return Ok(());
+    }
+
+    // 3) Do the passes until there is nothing left to re-encrypt
+
+    // A block can be referenced by multiple manifests, so keep track of the
+    // blocks already re-encrypted.
+    let mut rekeyed_blocks = HashMap::new();
+
+    loop {
+        let (mut vlobs, current_checkpoint) =
+            poll_changes(ops, realm_id, progress.checkpoint).await?;
+        vlobs.sort();
+
+        // If the pass is resumed, the vlobs that changed while we were interrupted
+        // may have already been skipped, hence the pass checkpoint cannot be moved
+        // forward and another pass must be done.
+        let resumed = progress.cursor.is_some();
+        if let Some(cursor) = progress.cursor {
+            vlobs.retain(|vlob_id| *vlob_id > cursor);
+        }
+
+        let total = vlobs.len() as u64;
+        let mut processed = 0;
+        let mut something_rekeyed = false;
+
+        for batch in vlobs.chunks(REKEY_BATCH_SIZE) {
+            for vlob in read_vlobs(ops, realm_id, batch).await? {
+                if rekey_vlob(ops, realm_id, target_key_index, vlob, &mut rekeyed_blocks).await? {
+                    something_rekeyed = true;
+                }
+            }
+
+            processed += batch.len() as u64;
+            progress.cursor = batch.last().copied();
+            ops.store.set_realm_rekey_progress(&progress).await?;
+
+            ops.event_bus.send(&EventRealmRekeyProgress {
+                realm_id,
+                target_key_index,
+                processed,
+                total,
+            });
+        }
+
+        progress.cursor = None;
+        if !resumed {
+            progress.checkpoint = current_checkpoint;
+            if !something_rekeyed {
+                progress.completed = true;
+            }
+        }
+        ops.store.set_realm_rekey_progress(&progress).await?;
+
+        if progress.completed {
+            ops.event_bus.send(&EventRealmRekeyDone {
+                realm_id,
+                target_key_index,
+            });
+            return Ok(());
+        }
+    }
+}
+
+async fn poll_changes(
+    ops: &CertificateOps,
+    realm_id: VlobID,
+    last_checkpoint: IndexInt,
+) -> Result<(Vec<VlobID>, IndexInt), CertifRekeyRealmError> {
+    use authenticated_cmds::latest::vlob_poll_changes::{Rep, Req};
+
+    let req = Req {
+        realm_id,
+        last_checkpoint,
+    };
+    let rep = ops.cmds.send(req).await?;
+
+    match rep {
+        Rep::Ok {
+            changes,
+            current_checkpoint,
+        } => Ok((
+            changes.into_iter().map(|(vlob_id, _)| vlob_id).collect(),
+            current_checkpoint,
+        )),
+        Rep::AuthorNotAllowed => Err(CertifRekeyRealmError::AuthorNotAllowed),
+        Rep::RealmNotFound => Err(CertifRekeyRealmError::UnknownRealm),
+        Rep::RealmDeleted => Err(CertifRekeyRealmError::RealmDeleted),
+        bad_rep @ Rep::UnknownStatus { .. } => {
+            Err(anyhow::anyhow!("Unexpected server response: {:?}", bad_rep).into())
+        }
+    }
+}
+
+struct RemoteVlob {
+    needed_realm_certificate_timestamp: DateTime,
+    needed_common_certificate_timestamp: DateTime,
+    vlob_id: VlobID,
+    key_index: IndexInt,
+    author: DeviceID,
+    version: VersionInt,
+    timestamp: DateTime,
+    encrypted: Bytes,
+}
+
+async fn read_vlobs(
+    ops: &CertificateOps,
+    realm_id: VlobID,
+    vlobs: &[VlobID],
+) -> Result<Vec<RemoteVlob>, CertifRekeyRealmError> {
+    use authenticated_cmds::latest::vlob_read_batch::{Rep, Req};
+
+    let req = Req {
+        at: None,
+        realm_id,
+        vlobs: vlobs.to_vec(),
+    };
+    let rep = ops.cmds.send(req).await?;
+
+    match rep {
+        Rep::Ok {
+            items,
+            needed_common_certificate_timestamp,
+            needed_realm_certificate_timestamp,
+        } => Ok(items
+            .into_iter()
+            .map(
+                |(vlob_id, key_index, author, version, timestamp, encrypted)| RemoteVlob {
+                    needed_realm_certificate_timestamp,
+                    needed_common_certificate_timestamp,
+                    vlob_id,
+                    key_index,
+                    author,
+                    version,
+                    timestamp,
+                    encrypted,
+                },
+            )
+            .collect()),
+        Rep::AuthorNotAllowed => Err(CertifRekeyRealmError::AuthorNotAllowed),
+        Rep::RealmNotFound => Err(CertifRekeyRealmError::UnknownRealm),
+        Rep::RealmDeleted => Err(CertifRekeyRealmError::RealmDeleted),
+        // Unexpected errors :(
+        bad_rep @ (
+            // Our batches are smaller than the server limit
+            Rep::TooManyElements
+            // Don't know what to do with this status :/
+            | Rep::UnknownStatus { .. }
+        ) => Err(anyhow::anyhow!("Unexpected server response: {:?}", bad_rep).into()),
+    }
+}
+
+/// Return `true` if the vlob (or one of its blocks) has been re-encrypted.
+async fn rekey_vlob(
+    ops: &CertificateOps,
+    realm_id: VlobID,
+    target_key_index: IndexInt,
+    vlob: RemoteVlob,
+    rekeyed_blocks: &mut HashMap<BlockID, BlockID>,
+) -> Result<bool, CertifRekeyRealmError> {
+    // 1) Validate the manifest
+
+    let outcome = if vlob.vlob_id == realm_id {
+        ops.validate_workspace_manifest(
+            vlob.needed_realm_certificate_timestamp,
+            vlob.needed_common_certificate_timestamp,
+            realm_id,
+            vlob.key_index,
+            vlob.author,
+            vlob.version,
+            vlob.timestamp,
+            &vlob.encrypted,
+        )
+        .await
+        .map(ChildManifest::Folder)
+    } else {
+        ops.validate_child_manifest(
+            vlob.needed_realm_certificate_timestamp,
+            vlob.needed_common_certificate_timestamp,
+            realm_id,
+            vlob.key_index,
+            vlob.vlob_id,
+            vlob.author,
+            vlob.version,
+            vlob.timestamp,
+            &vlob.encrypted,
+        )
+        .await
+    };
+
+    let mut manifest = match outcome {
+        Ok(manifest) => manifest,
+        // Nothing we can do about an invalid manifest, it is left as-is
+        Err(CertifValidateManifestError::InvalidManifest(err)) => {
+            log::warn!("Cannot re-encrypt invalid manifest: {err}");
+            return Ok(false);
+        }
+        Err(err) => {
+            return Err(match err {
+                CertifValidateManifestError::Offline(e) => CertifRekeyRealmError::Offline(e),
+                CertifValidateManifestError::Stopped => CertifRekeyRealmError::Stopped,
+                CertifValidateManifestError::NotAllowed => CertifRekeyRealmError::AuthorNotAllowed,
+                CertifValidateManifestError::RealmDeleted => CertifRekeyRealmError::RealmDeleted,
+                CertifValidateManifestError::InvalidCertificate(err) => {
+                    CertifRekeyRealmError::InvalidCertificate(err)
+                }
+                CertifValidateManifestError::InvalidKeysBundle(err) => {
+                    CertifRekeyRealmError::InvalidKeysBundle(err)
+                }
+                CertifValidateManifestError::InvalidManifest(_) => unreachable!(),
+                CertifValidateManifestError::Internal(err) => {
+                    err.context("Cannot validate manifest").into()
+                }
+            })
+        }
+    };
+
+    // 2) Re-encrypt the blocks
+
+    let mut blocks_rekeyed = false;
+    if let ChildManifest::File(file_manifest) = &mut manifest {
+        let mut new_blocks = file_manifest.blocks.clone();
+        for access in new_blocks.iter_mut() {
+            let new_block_id = match rekeyed_blocks.get(&access.id) {
+                Some(new_block_id) => Some(*new_block_id),
+                None => rekey_block(ops, realm_id, target_key_index, file_manifest, access).await?,
+            };
+            if let Some(new_block_id) = new_block_id {
+                rekeyed_blocks.insert(access.id, new_block_id);
+                access.id = new_block_id;
+                blocks_rekeyed = true;
+            }
+        }
+        file_manifest.blocks = new_blocks;
+    }
+
+    if !blocks_rekeyed && vlob.key_index >= target_key_index {
+        return Ok(false);
+    }
+
+    // 3) Upload the manifest as a new version encrypted with the last key
+
+    match &mut manifest {
+        ChildManifest::File(m) => {
+            m.author = ops.device.device_id;
+            m.version += 1;
+        }
+        ChildManifest::Folder(m) => {
+            m.author = ops.device.device_id;
+            m.version += 1;
+        }
+    }
+
+    let mut timestamp = ops.device.now();
+    loop {
+        let signed = match &mut manifest {
+            ChildManifest::File(m) => {
+                m.timestamp = timestamp;
+                m.dump_and_sign(&ops.device.signing_key)
+            }
+            ChildManifest::Folder(m) => {
+                m.timestamp = timestamp;
+                m.dump_and_sign(&ops.device.signing_key)
+            }
+        };
+        let (encrypted, key_index) = ops
+            .encrypt_for_realm(EncrytionUsage::Vlob(vlob.vlob_id), realm_id, &signed)
+            .await?;
+
+        use authenticated_cmds::latest::vlob_update::{Rep, Req};
+
+        let req = Req {
+            key_index,
+            realm_id,
+            vlob_id: vlob.vlob_id,
+            version: vlob.version + 1,
+            timestamp,
+            blob: encrypted.into(),
+        };
+        let rep = ops.cmds.send(req).await?;
+
+        return match rep {
+            Rep::Ok => Ok(true),
+            // The vlob has been concurrently modified, the new version will be
+            // processed by the next pass.
+            Rep::BadVlobVersion => Ok(true),
+            Rep::RequireGreaterTimestamp {
+                strictly_greater_than,
+            } => {
+                timestamp = greater_timestamp(
+                    &ops.device.time_provider,
+                    GreaterTimestampOffset::Manifest,
+                    strictly_greater_than,
+                )
+                .await?;
+                continue;
+            }
+            // A key rotation occurred concurrently, should poll for new certificates and retry
+            Rep::BadKeyIndex {
+                last_realm_certificate_timestamp,
+            } => {
+                let latest_known_timestamps = PerTopicLastTimestamps::new_for_realm(
+                    realm_id,
+                    last_realm_certificate_timestamp,
+                );
+                ops.poll_server_for_new_certificates(Some(&latest_known_timestamps))
+                    .await?;
+                continue;
+            }
+            Rep::AuthorNotAllowed => Err(CertifRekeyRealmError::AuthorNotAllowed),
+            Rep::RealmArchived => Err(CertifRekeyRealmError::RealmArchived),
+            Rep::RealmDeleted => Err(CertifRekeyRealmError::RealmDeleted),
+            Rep::TimestampOutOfBallpark {
+                server_timestamp,
+                client_timestamp,
+                ballpark_client_early_offset,
+                ballpark_client_late_offset,
+            } => Err(CertifRekeyRealmError::TimestampOutOfBallpark {
+                server_timestamp,
+                client_timestamp,
+                ballpark_client_early_offset,
+                ballpark_client_late_offset,
+            }),
+            Rep::SequesterServiceUnavailable { service_id } => {
+                Err(anyhow::anyhow!("Sequester service {service_id} unavailable").into())
+            }
+            Rep::RejectedBySequesterService { service_id, reason } => Err(anyhow::anyhow!(
+                "Rejected by sequester service {service_id} ({reason:?})"
+            )
+            .into()),
+            // Unexpected errors :(
+            bad_rep @ (
+                // The realm and vlob have just been read
+                Rep::RealmNotFound
+                | Rep::VlobNotFound
+                // Don't know what to do with this status :/
+                | Rep::UnknownStatus { .. }
+            ) => Err(anyhow::anyhow!("Unexpected server response: {:?}", bad_rep).into()),
+        };
+    }
+}
+
+/// Return the ID of the re-encrypted block, or `None` if the block doesn't
+/// need to be re-encrypted.
+async fn rekey_block(
+    ops: &CertificateOps,
+    realm_id: VlobID,
+    target_key_index: IndexInt,
+    manifest: &FileManifest,
+    access: &BlockAccess,
+) -> Result<Option<BlockID>, CertifRekeyRealmError> {
+    // 1) Fetch the block
+
+    let (needed_realm_certificate_timestamp, key_index, encrypted) =
+        match fetch_block(ops, realm_id, access.id).await? {
+            Some(fetched) => fetched,
+            // Nothing to re-encrypt
+            None => return Ok(None),
+        };
+
+    if key_index >= target_key_index {
+        return Ok(None);
+    }
+
+    // 2) Decrypt it...
+
+    let outcome = ops
+        .validate_block(
+            needed_realm_certificate_timestamp,
+            realm_id,
+            key_index,
+            manifest,
+            access,
+            &encrypted,
+        )
+        .await;
+    let data = match outcome {
+        Ok(data) => data,
+        // Nothing we can do about an invalid block, it is left as-is
+        Err(CertifValidateBlockError::InvalidBlockAccess(err)) => {
+            log::warn!("Cannot re-encrypt invalid block: {err}");
+            return Ok(None);
+        }
+        Err(err) => return Err(validate_block_error_to_rekey_error(err)),
+    };
+
+    // 3) ...and upload it with the last key
+    //
+    // Blocks are immutable on the server, so the re-encrypted block needs a new ID.
+    // Using a deduplicated block ID makes this idempotent in case the re-key job
+    // is interrupted before the manifest is updated.
+    //
+    // However a deduplicated block ID is predictable by any member of the realm, so
+    // a block already existing under this ID must be checked against our block access
+    // before being reused (see `check_existing_deduplicated_block` in the workspace
+    // outbound sync). If it doesn't match, the block is uploaded under a random ID.
+
+    let outcome = upload_rekeyed_block(ops, realm_id, &data, access.compressed, None).await?;
+    let new_block_id = match outcome {
+        RekeyedBlockUploadOutcome::Uploaded(new_block_id) => new_block_id,
+        RekeyedBlockUploadOutcome::AlreadyExists(new_block_id) => {
+            if check_existing_rekeyed_block(ops, realm_id, manifest, access, new_block_id).await? {
+                new_block_id
+            } else {
+                log::warn!(
+                    "Block `{new_block_id}` already exists on the server but doesn't match block access `{}`, uploading it under a random ID instead",
+                    access.id
+                );
+                let random_block_id = BlockID::default();
+                let outcome = upload_rekeyed_block(
+                    ops,
+                    realm_id,
+                    &data,
+                    access.compressed,
+                    Some(random_block_id),
+                )
+                .await?;
+                match outcome {
+                    RekeyedBlockUploadOutcome::Uploaded(new_block_id) => new_block_id,
+                    // A random ID is not supposed to be already taken
+                    RekeyedBlockUploadOutcome::AlreadyExists(new_block_id) => {
+                        return Err(anyhow::anyhow!(
+                            "Block `{new_block_id}` unexpectedly already exists on the server"
+                        )
+                        .into())
+                    }
+                }
+            }
+        }
+    };
+
+    Ok(Some(new_block_id))
+}
+
+/// Return `None` if the block doesn't exist on the server.
+async fn fetch_block(
+    ops: &CertificateOps,
+    realm_id: VlobID,
+    block_id: BlockID,
+) -> Result<Option<(DateTime, IndexInt, Bytes)>, CertifRekeyRealmError> {
+    use authenticated_cmds::latest::block_read::{Rep, Req};
+
+    let req = Req { block_id, realm_id };
+    let rep = ops.cmds.send(req).await?;
+
+    match rep {
+        Rep::Ok {
+            needed_realm_certificate_timestamp,
+            key_index,
+            block,
+        } => Ok(Some((needed_realm_certificate_timestamp, key_index, block))),
+        Rep::BlockNotFound => Ok(None),
+        Rep::StoreUnavailable => Err(CertifRekeyRealmError::ServerBlockstoreUnavailable),
+        Rep::AuthorNotAllowed => Err(CertifRekeyRealmError::AuthorNotAllowed),
+        Rep::RealmNotFound => Err(CertifRekeyRealmError::UnknownRealm),
+        Rep::RealmDeleted => Err(CertifRekeyRealmError::RealmDeleted),
+        bad_rep @ Rep::UnknownStatus { .. } => {
+            Err(anyhow::anyhow!("Unexpected server response: {:?}", bad_rep).into())
+        }
+    }
+}
+
+/// Check a block already existing on the server under `block_id` contains the
+/// data described by `access`.
+async fn check_existing_rekeyed_block(
+    ops: &CertificateOps,
+    realm_id: VlobID,
+    manifest: &FileManifest,
+    access: &BlockAccess,
+    block_id: BlockID,
+) -> Result<bool, CertifRekeyRealmError> {
+    let (needed_realm_certificate_timestamp, key_index, encrypted) =
+        match fetch_block(ops, realm_id, block_id).await? {
+            Some(fetched) => fetched,
+            // The server has just told us the block exists in the realm
+            None => {
+                return Err(anyhow::anyhow!(
+                    "Unexpected server response: block `{block_id}` not found"
+                )
+                .into())
+            }
+        };
+
+    let mut expected_access = access.clone();
+    expected_access.id = block_id;
+    let outcome = ops
+        .validate_block(
+            needed_realm_certificate_timestamp,
+            realm_id,
+            key_index,
+            manifest,
+            &expected_access,
+            &encrypted,
+        )
+        .await;
+    match outcome {
+        Ok(_) => Ok(true),
+        Err(CertifValidateBlockError::InvalidBlockAccess(_)) => Ok(false),
+        Err(err) => Err(validate_block_error_to_rekey_error(err)),
+    }
+}
+
+enum RekeyedBlockUploadOutcome {
+    Uploaded(BlockID),
+    AlreadyExists(BlockID),
+}
+
+/// Upload the block with the last key of the realm, under `block_id` if provided
+/// or under its deduplicated ID otherwise.
+async fn upload_rekeyed_block(
+    ops: &CertificateOps,
+    realm_id: VlobID,
+    data: &[u8],
+    compressed: bool,
+    block_id: Option<BlockID>,
+) -> Result<RekeyedBlockUploadOutcome, CertifRekeyRealmError> {
+    loop {
+        let (new_block_id, encrypted, key_index) = match block_id {
+            None => {
+                ops.encrypt_deduplicated_block_for_realm(realm_id, data, compressed)
+                    .await?
+            }
+            Some(block_id) => {
+                let compressed_data;
+                let payload = if compressed {
+                    compressed_data = BlockAccess::compress_data(data);
+                    &compressed_data
+                } else {
+                    data
+                };
+                let (encrypted, key_index) = ops
+                    .encrypt_for_realm(EncrytionUsage::Block(block_id), realm_id, payload)
+                    .await?;
+                (block_id, encrypted, key_index)
+            }
+        };
+
+        use authenticated_cmds::latest::block_create::{Rep, Req};
+
+        let req = Req {
+            realm_id,
+            key_index,
+            block_id: new_block_id,
+            block: encrypted.into(),
+        };
+        let rep = ops.cmds.send(req).await?;
+
+        return match rep {
+            Rep::Ok => Ok(RekeyedBlockUploadOutcome::Uploaded(new_block_id)),
+            Rep::BlockAlreadyExists => Ok(RekeyedBlockUploadOutcome::AlreadyExists(new_block_id)),
+            // A key rotation occurred concurrently, should poll for new certificates and retry
+            Rep::BadKeyIndex {
+                last_realm_certificate_timestamp,
+            } => {
+                let latest_known_timestamps = PerTopicLastTimestamps::new_for_realm(
+                    realm_id,
+                    last_realm_certificate_timestamp,
+                );
+                ops.poll_server_for_new_certificates(Some(&latest_known_timestamps))
+                    .await?;
+                continue;
+            }
+            Rep::StoreUnavailable => Err(CertifRekeyRealmError::ServerBlockstoreUnavailable),
+            Rep::AuthorNotAllowed => Err(CertifRekeyRealmError::AuthorNotAllowed),
+            Rep::RealmArchived => Err(CertifRekeyRealmError::RealmArchived),
+            Rep::RealmDeleted => Err(CertifRekeyRealmError::RealmDeleted),
+            // Unexpected errors :(
+            bad_rep @ (
+                // The realm has just been read
+                Rep::RealmNotFound
+                // Don't know what to do with this status :/
+                | Rep::UnknownStatus { .. }
+            ) => Err(anyhow::anyhow!("Unexpected server response: {:?}", bad_rep).into()),
+        };
+    }
+}
+
+fn validate_block_error_to_rekey_error(err: CertifValidateBlockError) -> CertifRekeyRealmError {
+    match err {
+        CertifValidateBlockError::Offline(e) => CertifRekeyRealmError::Offline(e),
+        CertifValidateBlockError::Stopped => CertifRekeyRealmError::Stopped,
+        CertifValidateBlockError::NotAllowed => CertifRekeyRealmError::AuthorNotAllowed,
+        CertifValidateBlockError::RealmDeleted => CertifRekeyRealmError::RealmDeleted,
+        CertifValidateBlockError::InvalidCertificate(err) => {
+            CertifRekeyRealmError::InvalidCertificate(err)
+        }
+        CertifValidateBlockError::InvalidKeysBundle(err) => {
+            CertifRekeyRealmError::InvalidKeysBundle(err)
+        }
+        CertifValidateBlockError::InvalidBlockAccess(err) => {
+            anyhow::anyhow!("Unexpected invalid block access: {err}").into()
+        }
+        CertifValidateBlockError::Internal(err) => err.context("Cannot validate block").into(),
+    }
+}
@@ -11,7 +11,7 @@ use super::{
     UpTo,
 };
 use crate::{
-    certif::CertifPollServerError, greater_timestamp, EncrytionUsage,
+    certif::CertifPollServerError, greater_timestamp, ClockDriftError, EncrytionUsage,
     EventTooMuchDriftWithServerClock, GreaterTimestampOffset,
 };
 
@@ -41,6 +41,8 @@ pub enum CertifRenameRealmError {
     #[error(transparent)]
     InvalidCertificate(#[from] Box<InvalidCertificateError>),
     #[error(transparent)]
+    ClockDrift(#[from] ClockDriftError),
+    #[error(transparent)]
     Internal(#[from] anyhow::Error),
 }
 
@@ -158,7 +160,9 @@ async fn rename_realm_internal(
                     &ops.device.time_provider,
                     GreaterTimestampOffset::Realm,
                     strictly_greater_than,
-                );
+                )
+                .await
+                .inspect_err(|err| ops.event_bus.send(&err.to_event()))?;
                 continue;
             }
             Rep::TimestampOutOfBallpark {
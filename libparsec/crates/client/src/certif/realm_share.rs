@@ -7,8 +7,8 @@ use libparsec_types::prelude::*;
 
 use super::{
     greater_timestamp, store::CertifStoreError, CertifPollServerError,
-    CertificateBasedActionOutcome, CertificateOps, GreaterTimestampOffset, InvalidCertificateError,
-    InvalidKeysBundleError,
+    CertificateBasedActionOutcome, CertificateOps, ClockDriftError, GreaterTimestampOffset,
+    InvalidCertificateError, InvalidKeysBundleError,
 };
 use crate::{
     certif::realm_keys_bundle::EncryptRealmKeysBundleAccessForUserError,
@@ -49,6 +49,8 @@ pub enum CertifShareRealmError {
     #[error(transparent)]
     InvalidCertificate(#[from] Box<InvalidCertificateError>),
     #[error(transparent)]
+    ClockDrift(#[from] ClockDriftError),
+    #[error(transparent)]
     Internal(#[from] anyhow::Error),
 }
 
@@ -66,6 +68,7 @@ pub(super) async fn share_realm(
     realm_id: VlobID,
     recipient: UserID,
     role: Option<RealmRole>,
+    group_id: Option<GroupID>,
 ) -> Result<CertificateBasedActionOutcome, CertifShareRealmError> {
     if ops.device.user_id == recipient {
         return Err(CertifShareRealmError::RecipientIsSelf);
@@ -95,20 +98,22 @@ pub(super) async fn share_realm(
     let mut timestamp = ops.device.now();
     loop {
         let outcome = match role {
-            Some(role) => share_do_server_command(ops, realm_id, recipient, role, timestamp).await,
+            Some(role) => {
+                share_do_server_command(ops, realm_id, recipient, role, group_id, timestamp).await
+            }
             None => unshare_do_server_command(ops, realm_id, recipient, timestamp).await,
         }?;
 
         match outcome {
             DoServerCommandOutcome::Done(outcome) => return Ok(outcome),
             DoServerCommandOutcome::RequireGreaterTimestamp(strictly_greater_than) => {
-                // TODO: handle `strictly_greater_than` out of the client ballpark by
-                // returning an error
                 timestamp = greater_timestamp(
                     &ops.device.time_provider,
                     GreaterTimestampOffset::Realm,
                     strictly_greater_than,
-                );
+                )
+                .await
+                .inspect_err(|err| ops.event_bus.send(&err.to_event()))?;
             }
             DoServerCommandOutcome::MissingKeyRotationCertificate(certificate_timestamp) => {
                 let latest_known_timestamps =
@@ -158,6 +163,7 @@ async fn unshare_do_server_command(
         realm_id,
         user_id: recipient,
         role: None,
+        group_id: None,
     }
     .dump_and_sign(&ops.device.signing_key);
 
@@ -230,6 +236,7 @@ async fn share_do_server_command(
     realm_id: VlobID,
     recipient: UserID,
     role: RealmRole,
+    group_id: Option<GroupID>,
     timestamp: DateTime,
 ) -> Result<DoServerCommandOutcome, CertifShareRealmError> {
     // 0) Sanity check to prevent generating and invalid certificate
@@ -281,6 +288,7 @@ async fn share_do_server_command(
         realm_id,
         user_id: recipient,
         role: Some(role),
+        group_id,
     }
     .dump_and_sign(&ops.device.signing_key);
 
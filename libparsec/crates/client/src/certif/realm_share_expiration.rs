@@ -0,0 +1,112 @@
+// Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+use std::collections::HashMap;
+
+use libparsec_platform_storage::certificates::{RealmShareExpiration, UpTo};
+use libparsec_types::prelude::*;
+
+use super::{store::CertifStoreError, CertificateOps};
+
+#[derive(Debug, thiserror::Error)]
+pub enum CertifSetRealmShareExpirationError {
+    #[error("Component has stopped")]
+    Stopped,
+    #[error("User is not part of the realm")]
+    UserNotInRealm,
+    #[error(transparent)]
+    Internal(#[from] anyhow::Error),
+}
+
+impl From<CertifStoreError> for CertifSetRealmShareExpirationError {
+    fn from(value: CertifStoreError) -> Self {
+        match value {
+            CertifStoreError::Stopped => Self::Stopped,
+            CertifStoreError::Internal(err) => err.into(),
+        }
+    }
+}
+
+/// Configure when the share of the realm with the given user should expire
+/// (or remove the expiration if `expires_on` is `None`).
+///
+/// The expiration is attached to the user's current realm role certificate,
+/// hence any subsequent change of role (e.g. re-sharing by another member) makes
+/// it obsolete.
+///
+/// Note the expiration is only stored in the local certificates storage (the
+/// server and the other devices don't know about it), so the unsharing is done
+/// by this device's workspaces needs monitor once the due date is reached.
+pub(super) async fn set_realm_share_expiration(
+    ops: &CertificateOps,
+    realm_id: VlobID,
+    user_id: UserID,
+    expires_on: Option<DateTime>,
+) -> Result<(), CertifSetRealmShareExpirationError> {
+    let expires_on = match expires_on {
+        Some(expires_on) => expires_on,
+        None => {
+            ops.store
+                .remove_realm_share_expiration(realm_id, user_id)
+                .await?;
+            return Ok(());
+        }
+    };
+
+    let role_certificate_timestamp = ops
+        .store
+        .for_read(async |store| {
+            store
+                .get_last_user_realm_role(UpTo::Current, user_id, realm_id)
+                .await
+        })
+        .await??
+        .and_then(|certif| certif.role.map(|_| certif.timestamp))
+        .ok_or(CertifSetRealmShareExpirationError::UserNotInRealm)?;
+
+    ops.store
+        .set_realm_share_expiration(&RealmShareExpiration {
+            realm_id,
+            user_id,
+            role_certificate_timestamp,
+            expires_on,
+        })
+        .await?;
+
+    Ok(())
+}
+
+pub type CertifListRealmShareExpirationsError = CertifStoreError;
+
+/// Return the share expiration (if any) of each user currently part of the realm.
+///
+/// Note the expirations already due are also returned.
+pub(super) async fn list_realm_share_expirations(
+    ops: &CertificateOps,
+    realm_id: VlobID,
+) -> Result<HashMap<UserID, DateTime>, CertifListRealmShareExpirationsError> {
+    let expirations = ops.store.get_realm_share_expirations(realm_id).await?;
+    if expirations.is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    let per_user_certifs = ops
+        .store
+        .for_read(async |store| {
+            store
+                .get_realm_current_users_roles(UpTo::Current, realm_id)
+                .await
+        })
+        .await??;
+
+    let applicable = expirations
+        .into_iter()
+        .filter_map(|expiration| {
+            // Ignore the expirations made obsolete by a change of role
+            let certif = per_user_certifs.get(&expiration.user_id)?;
+            (certif.timestamp == expiration.role_certificate_timestamp)
+                .then_some((expiration.user_id, expiration.expires_on))
+        })
+        .collect();
+
+    Ok(applicable)
+}
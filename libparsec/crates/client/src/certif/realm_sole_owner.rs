@@ -0,0 +1,82 @@
+// Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+use libparsec_types::prelude::*;
+
+use super::{store::CertificatesStoreReadGuard, CertificateOps, UpTo};
+
+pub type CertifListSolelyOwnedRealmsError = super::store::CertifStoreError;
+
+/// What to do when an operation (i.e. revoking a user or changing its profile to
+/// OUTSIDER) would leave some realms without any OWNER.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrphanedWorkspacesStrategy {
+    /// Don't do the operation, the caller should first have the ownership of the
+    /// concerned workspaces transferred to another user.
+    Abort,
+    /// Do the operation anyway, the concerned workspaces are left without any user
+    /// able to share them or to rotate their keys.
+    Ignore,
+}
+
+/// Realms in which the given user is the only OWNER, while at least one other
+/// user still has access to it (i.e. realms that would become orphaned if the
+/// user loses its OWNER role).
+///
+/// Realms only accessible to the given user (e.g. its user realm, or a workspace
+/// that has never been shared) are not considered.
+pub(super) async fn list_solely_owned_realms(
+    ops: &CertificateOps,
+    user_id: UserID,
+) -> Result<Vec<VlobID>, CertifListSolelyOwnedRealmsError> {
+    ops.store
+        .for_read(async |store| store_list_solely_owned_realms(store, user_id).await)
+        .await?
+        .map_err(|err| err.into())
+}
+
+pub(super) async fn store_list_solely_owned_realms(
+    store: &mut CertificatesStoreReadGuard<'_>,
+    user_id: UserID,
+) -> anyhow::Result<Vec<VlobID>> {
+    let mut realms = vec![];
+
+    let user_roles = store
+        .get_user_last_realms_roles(UpTo::Current, user_id)
+        .await?;
+
+    for user_role in user_roles {
+        if user_role.role != Some(RealmRole::Owner) {
+            continue;
+        }
+
+        let per_user_roles = store
+            .get_realm_current_users_roles(UpTo::Current, user_role.realm_id)
+            .await?;
+
+        let mut has_other_members = false;
+        let mut has_other_owners = false;
+        for (other_user_id, other_role) in per_user_roles {
+            if other_user_id == user_id {
+                continue;
+            }
+            // Revoked users cannot access the realm anymore
+            let maybe_revoked = store
+                .get_revoked_user_certificate(UpTo::Current, other_user_id)
+                .await?;
+            if maybe_revoked.is_some() {
+                continue;
+            }
+            has_other_members = true;
+            if other_role.role == Some(RealmRole::Owner) {
+                has_other_owners = true;
+                break;
+            }
+        }
+
+        if has_other_members && !has_other_owners {
+            realms.push(user_role.realm_id);
+        }
+    }
+
+    Ok(realms)
+}
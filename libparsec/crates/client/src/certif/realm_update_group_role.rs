@@ -0,0 +1,168 @@
+// Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+use libparsec_client_connection::ConnectionError;
+use libparsec_protocol::authenticated_cmds;
+use libparsec_types::prelude::*;
+
+use super::{
+    greater_timestamp, store::CertifStoreError, CertificateBasedActionOutcome, CertificateOps,
+    ClockDriftError, GreaterTimestampOffset, InvalidCertificateError, UpTo,
+};
+use crate::EventTooMuchDriftWithServerClock;
+
+#[derive(Debug, thiserror::Error)]
+pub enum CertifUpdateRealmGroupRoleError {
+    #[error("Component has stopped")]
+    Stopped,
+    #[error("Cannot communicate with the server: {0}")]
+    Offline(#[from] ConnectionError),
+    #[error("Unknown realm ID")]
+    UnknownRealm,
+    #[error("Realm has been deleted")]
+    RealmDeleted,
+    #[error("Group not found")]
+    GroupNotFound,
+    #[error("A group can only have Reader or Contributor role")]
+    RoleNotAllowedForGroup,
+    #[error("Not allowed")]
+    AuthorNotAllowed,
+    #[error("Our clock ({client_timestamp}) and the server's one ({server_timestamp}) are too far apart")]
+    TimestampOutOfBallpark {
+        server_timestamp: DateTime,
+        client_timestamp: DateTime,
+        ballpark_client_early_offset: f64,
+        ballpark_client_late_offset: f64,
+    },
+    #[error(transparent)]
+    InvalidCertificate(#[from] Box<InvalidCertificateError>),
+    #[error(transparent)]
+    ClockDrift(#[from] ClockDriftError),
+    #[error(transparent)]
+    Internal(#[from] anyhow::Error),
+}
+
+impl From<CertifStoreError> for CertifUpdateRealmGroupRoleError {
+    fn from(value: CertifStoreError) -> Self {
+        match value {
+            CertifStoreError::Stopped => Self::Stopped,
+            CertifStoreError::Internal(err) => err.into(),
+        }
+    }
+}
+
+/// Share (`role` is `Some`) or unshare (`role` is `None`) a realm with a group,
+/// only an owner of the realm can do that.
+///
+/// A group can only be given the Reader or Contributor role.
+///
+/// This only records the group's role: the members of the group are given their
+/// own (individual) access to the realm by the workspaces needs monitor of the
+/// realm's owners.
+pub(super) async fn update_realm_group_role(
+    ops: &CertificateOps,
+    realm_id: VlobID,
+    group_id: GroupID,
+    role: Option<RealmRole>,
+) -> Result<CertificateBasedActionOutcome, CertifUpdateRealmGroupRoleError> {
+    if matches!(role, Some(RealmRole::Owner | RealmRole::Manager)) {
+        return Err(CertifUpdateRealmGroupRoleError::RoleNotAllowedForGroup);
+    }
+
+    // Check locally to avoid a useless server round-trip
+    let current_role = ops
+        .store
+        .for_read(async |store| {
+            if store
+                .get_group_certificate(UpTo::Current, group_id)
+                .await?
+                .is_none()
+            {
+                return Ok(None);
+            }
+            store
+                .get_realm_current_groups_roles(UpTo::Current, realm_id)
+                .await
+                .map(|roles| Some(roles.get(&group_id).and_then(|certif| certif.role)))
+        })
+        .await??
+        .ok_or(CertifUpdateRealmGroupRoleError::GroupNotFound)?;
+    if current_role == role {
+        return Ok(CertificateBasedActionOutcome::LocalIdempotent);
+    }
+
+    // Loop is needed to deal with server requiring greater timestamp
+    let mut timestamp = ops.device.now();
+    loop {
+        // 1) Build realm group role certificate
+
+        let signed_certificate = RealmGroupRoleCertificate {
+            author: ops.device.device_id,
+            timestamp,
+            realm_id,
+            group_id,
+            role,
+        }
+        .dump_and_sign(&ops.device.signing_key);
+
+        // 2) Actually send the command to the server
+
+        use authenticated_cmds::latest::realm_update_group_role::{Rep, Req};
+
+        let req = Req {
+            realm_group_role_certificate: signed_certificate.into(),
+        };
+        let rep = ops.cmds.send(req).await?;
+
+        return match rep {
+            Rep::Ok => Ok(CertificateBasedActionOutcome::Uploaded {
+                certificate_timestamp: timestamp,
+            }),
+            Rep::GroupRoleAlreadyGranted {
+                last_realm_certificate_timestamp,
+            } => Ok(CertificateBasedActionOutcome::RemoteIdempotent {
+                certificate_timestamp: last_realm_certificate_timestamp,
+            }),
+            Rep::RequireGreaterTimestamp {
+                strictly_greater_than,
+            } => {
+                timestamp = greater_timestamp(
+                    &ops.device.time_provider,
+                    GreaterTimestampOffset::Realm,
+                    strictly_greater_than,
+                )
+                .await
+                .inspect_err(|err| ops.event_bus.send(&err.to_event()))?;
+                continue;
+            }
+            Rep::RealmNotFound => Err(CertifUpdateRealmGroupRoleError::UnknownRealm),
+            Rep::RealmDeleted => Err(CertifUpdateRealmGroupRoleError::RealmDeleted),
+            Rep::GroupNotFound => Err(CertifUpdateRealmGroupRoleError::GroupNotFound),
+            Rep::AuthorNotAllowed => Err(CertifUpdateRealmGroupRoleError::AuthorNotAllowed),
+            Rep::TimestampOutOfBallpark {
+                server_timestamp,
+                client_timestamp,
+                ballpark_client_early_offset,
+                ballpark_client_late_offset,
+                ..
+            } => {
+                let event = EventTooMuchDriftWithServerClock {
+                    server_timestamp,
+                    ballpark_client_early_offset,
+                    ballpark_client_late_offset,
+                    client_timestamp,
+                };
+                ops.event_bus.send(&event);
+
+                Err(CertifUpdateRealmGroupRoleError::TimestampOutOfBallpark {
+                    server_timestamp,
+                    client_timestamp,
+                    ballpark_client_early_offset,
+                    ballpark_client_late_offset,
+                })
+            }
+            bad_rep @ (Rep::InvalidCertificate | Rep::UnknownStatus { .. }) => {
+                Err(anyhow::anyhow!("Unexpected server response: {:?}", bad_rep).into())
+            }
+        };
+    }
+}
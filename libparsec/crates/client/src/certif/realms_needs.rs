@@ -1,11 +1,14 @@
 // Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
 
-use std::collections::HashSet;
+use std::collections::HashMap;
 
 use libparsec_platform_storage::certificates::UpTo;
 use libparsec_types::prelude::*;
 
-use super::{store::CertifStoreError, CertificateOps};
+use super::{
+    store::{CertifStoreError, CertificatesStoreReadGuard},
+    CertificateOps,
+};
 
 #[derive(Debug, thiserror::Error)]
 pub enum CertifGetRealmNeedsError {
@@ -31,15 +34,19 @@ pub enum RealmNeeds {
     /// - The workspace is no longer shared with an user.
     /// - A new sequester service has been created.
     /// - A sequester service has been revoked.
+    /// - A device of a user part of the workspace has been revoked.
     KeyRotationOnly {
         current_key_index: Option<IndexInt>,
     },
-    /// A user that is part of the workspace has been revoked, this creates a cascade
-    /// of needs: first the workspace needs to be unshared, which is turn means a key
-    /// rotation is needed.
+    /// A user that is part of the workspace has been revoked (or its share has
+    /// expired, or it got its role through a group it is no longer part of), this
+    /// creates a cascade of needs: first the workspace needs to be unshared, which
+    /// is turn means a key rotation is needed.
     UnshareThenKeyRotation {
         current_key_index: Option<IndexInt>,
         revoked_users: Vec<UserID>,
+        expired_users: Vec<UserID>,
+        ungrouped_users: Vec<UserID>,
     },
 }
 
@@ -48,11 +55,14 @@ pub async fn get_realm_needs(
     ops: &CertificateOps,
     realm_id: VlobID,
 ) -> Result<RealmNeeds, CertifGetRealmNeedsError> {
+    let expirations = ops.store.get_realm_share_expirations(realm_id).await?;
+    let now = ops.device.now();
+
     ops.store
         .for_read(async |store| {
-            // TODO: This implementation is not efficient, as it fetches all the roles
-            //       and users certificates. However this should be good enough for now,
-            //       and optimization requires a complex API changes in the storage.
+            // Note the whole realm roles history is needed here (instead of only the
+            // last role of each user provided by the certificates index) since any
+            // unsharing that occurred since the last key rotation must be detected.
 
             let mut needs = RealmNeeds::Nothing;
 
@@ -67,14 +77,15 @@ pub async fn get_realm_needs(
             };
 
             let roles = store.get_realm_roles(UpTo::Current, realm_id).await?;
-            let mut current_users_with_role = HashSet::new();
+            // Users currently part of the workspace, with their current role certificate
+            let mut current_users_with_role = HashMap::new();
             // This loop does two things at once:
             // - Collect all users that are currently part of the workspace (will be used in next step)
             // - Update `needs` if a unshare has occurred since the last key rotation
             for role in roles {
                 match role.role {
                     Some(_) => {
-                        current_users_with_role.insert(role.user_id);
+                        current_users_with_role.insert(role.user_id, role);
                     }
                     None => {
                         current_users_with_role.remove(&role.user_id);
@@ -85,28 +96,68 @@ pub async fn get_realm_needs(
                 }
             }
 
-            for user_id in current_users_with_role {
+            let groups_roles = get_realm_groups_roles_per_user(store, realm_id).await?;
+
+            let mut revoked_users = vec![];
+            let mut expired_users = vec![];
+            let mut ungrouped_users = vec![];
+            for (&user_id, role_certif) in current_users_with_role.iter() {
                 let maybe_revoked = store
                     .get_revoked_user_certificate(UpTo::Current, user_id)
                     .await?;
                 if maybe_revoked.is_some() {
-                    match &mut needs {
-                        RealmNeeds::UnshareThenKeyRotation { revoked_users, .. } => {
-                            revoked_users.push(user_id);
-                        }
-                        _ => {
-                            needs = RealmNeeds::UnshareThenKeyRotation {
-                                current_key_index,
-                                revoked_users: vec![user_id],
-                            };
-                        }
-                    }
+                    revoked_users.push(user_id);
+                    continue;
+                }
+
+                // An expiration only applies to the role certificate it has been
+                // configured with
+                let expired = expirations.iter().any(|expiration| {
+                    expiration.user_id == user_id
+                        && expiration.role_certificate_timestamp == role_certif.timestamp
+                        && expiration.expires_on <= now
+                });
+                if expired {
+                    expired_users.push(user_id);
+                    continue;
+                }
+
+                // A role given through a group is only kept as long as the user
+                // gets a role from one of the groups the realm is shared with
+                // (note a role given individually is never removed this way).
+                if role_certif.group_id.is_some() && !groups_roles.contains_key(&user_id) {
+                    ungrouped_users.push(user_id);
                 }
             }
 
-            // The use of `HashSet` makes our output non-deterministic, so we fix it here
-            if let RealmNeeds::UnshareThenKeyRotation { revoked_users, .. } = &mut needs {
+            if !revoked_users.is_empty() || !expired_users.is_empty() || !ungrouped_users.is_empty()
+            {
+                // The use of `HashMap` makes our output non-deterministic, so we fix it here
                 revoked_users.sort();
+                expired_users.sort();
+                ungrouped_users.sort();
+                needs = RealmNeeds::UnshareThenKeyRotation {
+                    current_key_index,
+                    revoked_users,
+                    expired_users,
+                    ungrouped_users,
+                };
+            }
+
+            // A revoked device used to have access to the realm keys, so we must
+            // rotate them (the user is still part of the realm however).
+            if matches!(needs, RealmNeeds::Nothing) {
+                'users: for user_id in current_users_with_role.keys().copied() {
+                    let revoked_devices = store
+                        .get_user_revoked_devices_certificates(UpTo::Current, user_id)
+                        .await?;
+                    for revoked_device in revoked_devices {
+                        if more_recent_than_last_key_rotation(revoked_device.timestamp) {
+                            needs = RealmNeeds::KeyRotationOnly { current_key_index };
+                            break 'users;
+                        }
+                    }
+                }
             }
 
             if matches!(needs, RealmNeeds::Nothing) {
@@ -137,3 +188,107 @@ pub async fn get_realm_needs(
         })
         .await?
 }
+
+pub type CertifGetRealmGroupSharesNeedsError = CertifGetRealmNeedsError;
+
+/// Return the shares (i.e. user, role and group the role is granted through) to
+/// do so that the members of the groups the realm is shared with have the role
+/// the groups give them.
+///
+/// Only the users without role or with a role previously given through a group
+/// are considered (i.e. a role given individually always has precedence), and
+/// the removal of roles is handled by [`get_realm_needs`] since it requires a
+/// key rotation.
+pub async fn get_realm_group_shares_needs(
+    ops: &CertificateOps,
+    realm_id: VlobID,
+) -> Result<Vec<(UserID, RealmRole, GroupID)>, CertifGetRealmGroupSharesNeedsError> {
+    ops.store
+        .for_read(async |store| {
+            let groups_roles = get_realm_groups_roles_per_user(store, realm_id).await?;
+            if groups_roles.is_empty() {
+                return Ok(vec![]);
+            }
+
+            let current_users_roles = store
+                .get_realm_current_users_roles(UpTo::Current, realm_id)
+                .await?;
+
+            let mut shares = vec![];
+            for (user_id, (role, group_id)) in groups_roles {
+                // We cannot share with ourself (and we are owner anyway)
+                if user_id == ops.device.user_id {
+                    continue;
+                }
+                match current_users_roles.get(&user_id) {
+                    None => (),
+                    // Role given individually, leave it alone
+                    Some(certif) if certif.group_id.is_none() => continue,
+                    Some(certif) if certif.role == Some(role) => continue,
+                    // Role given through a group, but not the expected one
+                    Some(_) => (),
+                }
+                shares.push((user_id, role, group_id));
+            }
+            // The use of `HashMap` makes our output non-deterministic, so we fix it here
+            shares.sort_by_key(|(user_id, _, _)| *user_id);
+
+            Ok(shares)
+        })
+        .await?
+}
+
+fn role_rank(role: RealmRole) -> u8 {
+    match role {
+        RealmRole::Reader => 0,
+        RealmRole::Contributor => 1,
+        RealmRole::Manager => 2,
+        RealmRole::Owner => 3,
+    }
+}
+
+/// For each (non-revoked) user member of a group the realm is shared with, return
+/// the highest role it gets from its groups (along with the group providing it).
+async fn get_realm_groups_roles_per_user(
+    store: &mut CertificatesStoreReadGuard<'_>,
+    realm_id: VlobID,
+) -> anyhow::Result<HashMap<UserID, (RealmRole, GroupID)>> {
+    let groups_roles = store
+        .get_realm_current_groups_roles(UpTo::Current, realm_id)
+        .await?;
+
+    let mut per_user: HashMap<UserID, (RealmRole, GroupID)> = HashMap::new();
+    for (group_id, group_role) in groups_roles {
+        let group_role = group_role
+            .role
+            .expect("unshared group should not be listed");
+        let members = store
+            .get_group_current_members(UpTo::Current, group_id)
+            .await?;
+
+        for user_id in members.into_keys() {
+            if store
+                .get_revoked_user_certificate(UpTo::Current, user_id)
+                .await?
+                .is_some()
+            {
+                continue;
+            }
+
+            // Note there is no need to check the user's profile here: a group can
+            // only have READER/CONTRIBUTOR role, which can be given to an OUTSIDER.
+
+            match per_user.get(&user_id) {
+                // Group ID is used as tie breaker to get a deterministic output
+                Some((role, other_group_id))
+                    if role_rank(*role) > role_rank(group_role)
+                        || (*role == group_role && *other_group_id < group_id) => {}
+                _ => {
+                    per_user.insert(user_id, (group_role, group_id));
+                }
+            }
+        }
+    }
+
+    Ok(per_user)
+}
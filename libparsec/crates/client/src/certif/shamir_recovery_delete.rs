@@ -10,7 +10,7 @@ use crate::{CertificateOps, EventTooMuchDriftWithServerClock};
 use super::{
     greater_timestamp,
     store::{CertifStoreError, LastShamirRecovery},
-    CertificateBasedActionOutcome, GreaterTimestampOffset,
+    CertificateBasedActionOutcome, ClockDriftError, GreaterTimestampOffset,
 };
 
 #[derive(Debug, thiserror::Error)]
@@ -27,6 +27,8 @@ pub enum CertifDeleteShamirRecoveryError {
         ballpark_client_late_offset: f64,
     },
     #[error(transparent)]
+    ClockDrift(#[from] ClockDriftError),
+    #[error(transparent)]
     Internal(#[from] anyhow::Error),
 }
 
@@ -87,7 +89,9 @@ pub(super) async fn delete_shamir_recovery(
                     &ops.device.time_provider,
                     GreaterTimestampOffset::User,
                     strictly_greater_than,
-                );
+                )
+                .await
+                .inspect_err(|err| ops.event_bus.send(&err.to_event()))?;
             }
             DoShamirRecoverySetupOutcome::RemoteIdempotent(last_shamir_certificate_timestamp) => {
                 return Ok(CertificateBasedActionOutcome::RemoteIdempotent {
@@ -10,7 +10,7 @@ use libparsec_types::prelude::*;
 use super::{
     greater_timestamp,
     store::{CertifStoreError, LastShamirRecovery, LastUserExistAndRevokedInfo},
-    CertifPollServerError, GreaterTimestampOffset,
+    CertifPollServerError, ClockDriftError, GreaterTimestampOffset,
 };
 use crate::{CertificateOps, EventTooMuchDriftWithServerClock, InvalidCertificateError};
 
@@ -42,6 +42,8 @@ pub enum CertifSetupShamirRecoveryError {
     #[error(transparent)]
     InvalidCertificate(#[from] Box<InvalidCertificateError>),
     #[error(transparent)]
+    ClockDrift(#[from] ClockDriftError),
+    #[error(transparent)]
     Internal(#[from] anyhow::Error),
 }
 
@@ -108,7 +110,9 @@ pub(super) async fn setup_shamir_recovery(
                     &ops.device.time_provider,
                     GreaterTimestampOffset::User,
                     strictly_greater_than,
-                );
+                )
+                .await
+                .inspect_err(|err| ops.event_bus.send(&err.to_event()))?;
             }
         }
     };
@@ -135,7 +139,9 @@ pub(super) async fn setup_shamir_recovery(
                     &ops.device.time_provider,
                     GreaterTimestampOffset::User,
                     strictly_greater_than,
-                );
+                )
+                .await
+                .inspect_err(|err| ops.event_bus.send(&err.to_event()))?;
             }
             DoShamirRecoverySetupOutcome::RequirePollingCertificates(per_topic_last_timestamps) => {
                 ops.poll_server_for_new_certificates(Some(&per_topic_last_timestamps))
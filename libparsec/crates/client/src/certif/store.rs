@@ -14,7 +14,7 @@
 //! need to prevent concurrent write operations (as it may remove certificates)
 
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     ops::AsyncFnOnce,
     path::Path,
     sync::{Arc, Mutex},
@@ -24,7 +24,8 @@ use libparsec_client_connection::ConnectionError;
 use libparsec_platform_async::lock::{Mutex as AsyncMutex, RwLock};
 pub use libparsec_platform_storage::certificates::UpTo;
 use libparsec_platform_storage::certificates::{
-    CertificatesStorage, CertificatesStorageUpdater, PerTopicLastTimestamps,
+    CertificatesStorage, CertificatesStorageUpdater, PerTopicLastTimestamps, RealmRekeyProgress,
+    RealmShareExpiration,
 };
 pub(super) use libparsec_platform_storage::certificates::{
     GetCertificateError, GetCertificateQuery,
@@ -120,10 +121,15 @@ struct CurrentViewCache {
     // Instead those data are fetched from the server then validated against the
     // certificates.
     pub per_realm_keys: HashMap<VlobID, Arc<RealmKeys>>,
+    /// Key index of the last keys bundle for which a healing has already been
+    /// attempted without success (a new key rotation is needed to change that).
+    pub per_realm_keys_bundle_healing_not_possible: HashMap<VlobID, IndexInt>,
     pub per_realm_bootstrap_state: HashMap<VlobID, RealmBootstrapState>,
     /// If the entry is missing this is a cache miss, if the entry is at `None`
     /// that means the user used to be part of the given realm.
     pub per_realm_self_role: HashMap<VlobID, Option<RealmRole>>,
+    /// Index on the users and realms roles, see [`CertificatesIndex`].
+    pub index: ScalarCache<CertificatesIndex>,
 }
 
 impl CurrentViewCache {
@@ -132,6 +138,107 @@ impl CurrentViewCache {
     }
 }
 
+/// In-memory index of the current view of the certificates most commonly looked up
+/// (e.g. when listing users or the users of a workspace).
+///
+/// The index is built in one go from the storage on first use, then kept up to date
+/// by the write guard each time a new certificate is added. Given it is part of
+/// the [`CurrentViewCache`], it gets cleared along with it if a write operation
+/// fails (hence no partially added certificates can remain in the index).
+#[derive(Debug, Default)]
+struct CertificatesIndex {
+    /// Ordered by timestamp in increasing order (i.e. oldest first)
+    users: Vec<IndexedUser>,
+    per_user_id: HashMap<UserID, usize>,
+    /// Email addresses can be reused once a user is revoked, in such case
+    /// the most recent user is the one referenced here.
+    per_email: HashMap<EmailAddress, UserID>,
+    /// Last role certificate for each user that is or used to be part of the realm.
+    per_realm_users_roles: HashMap<VlobID, HashMap<UserID, Arc<RealmRoleCertificate>>>,
+    per_realm_last_key_rotation: HashMap<VlobID, Arc<RealmKeyRotationCertificate>>,
+}
+
+#[derive(Debug)]
+struct IndexedUser {
+    certificate: Arc<UserCertificate>,
+    last_update: Option<Arc<UserUpdateCertificate>>,
+    revoked: Option<Arc<RevokedUserCertificate>>,
+    /// Ordered by timestamp in increasing order (i.e. oldest first)
+    devices: Vec<Arc<DeviceCertificate>>,
+    /// Last role certificate for each realm the user is or used to be part of,
+    /// ordered by the first time the user got a role in the realm.
+    realms_roles: Vec<Arc<RealmRoleCertificate>>,
+}
+
+impl CertificatesIndex {
+    fn user(&self, user_id: UserID) -> Option<&IndexedUser> {
+        self.per_user_id
+            .get(&user_id)
+            .map(|index| &self.users[*index])
+    }
+
+    fn user_mut(&mut self, user_id: UserID) -> Option<&mut IndexedUser> {
+        self.per_user_id
+            .get(&user_id)
+            .map(|index| &mut self.users[*index])
+    }
+
+    fn add_user(&mut self, certif: Arc<UserCertificate>) {
+        if let MaybeRedacted::Real(human_handle) = &certif.human_handle {
+            self.per_email
+                .insert(human_handle.email().to_owned(), certif.user_id);
+        }
+        self.per_user_id.insert(certif.user_id, self.users.len());
+        self.users.push(IndexedUser {
+            certificate: certif,
+            last_update: None,
+            revoked: None,
+            devices: vec![],
+            realms_roles: vec![],
+        });
+    }
+
+    fn add_device(&mut self, certif: Arc<DeviceCertificate>) {
+        if let Some(user) = self.user_mut(certif.user_id) {
+            user.devices.push(certif);
+        }
+    }
+
+    fn add_user_update(&mut self, certif: Arc<UserUpdateCertificate>) {
+        if let Some(user) = self.user_mut(certif.user_id) {
+            user.last_update = Some(certif);
+        }
+    }
+
+    fn add_revoked_user(&mut self, certif: Arc<RevokedUserCertificate>) {
+        if let Some(user) = self.user_mut(certif.user_id) {
+            user.revoked = Some(certif);
+        }
+    }
+
+    fn add_realm_role(&mut self, certif: Arc<RealmRoleCertificate>) {
+        if let Some(user) = self.user_mut(certif.user_id) {
+            match user
+                .realms_roles
+                .iter_mut()
+                .find(|role| role.realm_id == certif.realm_id)
+            {
+                Some(role) => *role = certif.clone(),
+                None => user.realms_roles.push(certif.clone()),
+            }
+        }
+        self.per_realm_users_roles
+            .entry(certif.realm_id)
+            .or_default()
+            .insert(certif.user_id, certif);
+    }
+
+    fn add_realm_key_rotation(&mut self, certif: Arc<RealmKeyRotationCertificate>) {
+        self.per_realm_last_key_rotation
+            .insert(certif.realm_id, certif);
+    }
+}
+
 #[derive(Debug)]
 pub(super) struct CertificatesStore {
     device: Arc<LocalDevice>,
@@ -174,6 +281,71 @@ impl CertificatesStore {
         Ok(())
     }
 
+    /// Realm rekey progress is not related to the certificates (hence it is not
+    /// protected by `lock`), it is only stored alongside them for convenience.
+    pub async fn get_realm_rekey_progress(
+        &self,
+        realm_id: VlobID,
+    ) -> Result<Option<RealmRekeyProgress>, CertifStoreError> {
+        let mut maybe_storage = self.storage.lock().await;
+        let storage = maybe_storage.as_mut().ok_or(CertifStoreError::Stopped)?;
+        storage
+            .get_realm_rekey_progress(realm_id)
+            .await
+            .map_err(CertifStoreError::Internal)
+    }
+
+    pub async fn set_realm_rekey_progress(
+        &self,
+        progress: &RealmRekeyProgress,
+    ) -> Result<(), CertifStoreError> {
+        let mut maybe_storage = self.storage.lock().await;
+        let storage = maybe_storage.as_mut().ok_or(CertifStoreError::Stopped)?;
+        storage
+            .set_realm_rekey_progress(progress)
+            .await
+            .map_err(CertifStoreError::Internal)
+    }
+
+    /// Similarly to realm rekey progress, share expirations are not certificates
+    /// and hence not protected by `lock`.
+    pub async fn get_realm_share_expirations(
+        &self,
+        realm_id: VlobID,
+    ) -> Result<Vec<RealmShareExpiration>, CertifStoreError> {
+        let mut maybe_storage = self.storage.lock().await;
+        let storage = maybe_storage.as_mut().ok_or(CertifStoreError::Stopped)?;
+        storage
+            .get_realm_share_expirations(realm_id)
+            .await
+            .map_err(CertifStoreError::Internal)
+    }
+
+    pub async fn set_realm_share_expiration(
+        &self,
+        expiration: &RealmShareExpiration,
+    ) -> Result<(), CertifStoreError> {
+        let mut maybe_storage = self.storage.lock().await;
+        let storage = maybe_storage.as_mut().ok_or(CertifStoreError::Stopped)?;
+        storage
+            .set_realm_share_expiration(expiration)
+            .await
+            .map_err(CertifStoreError::Internal)
+    }
+
+    pub async fn remove_realm_share_expiration(
+        &self,
+        realm_id: VlobID,
+        user_id: UserID,
+    ) -> Result<(), CertifStoreError> {
+        let mut maybe_storage = self.storage.lock().await;
+        let storage = maybe_storage.as_mut().ok_or(CertifStoreError::Stopped)?;
+        storage
+            .remove_realm_share_expiration(realm_id, user_id)
+            .await
+            .map_err(CertifStoreError::Internal)
+    }
+
     /// Only used for debugging tests
     #[cfg(any(test, feature = "expose-test-methods"))]
     #[allow(unused)]
@@ -387,7 +559,7 @@ macro_rules! impl_read_methods {
                     .store
                     .current_view_cache
                     .lock()
-                    .expect("Mutex is poisoned !");
+                    .expect("Mutex is poisoned");
                 if let ScalarCache::Present(self_profile) = guard.self_profile {
                     return Ok(self_profile);
                 }
@@ -423,7 +595,7 @@ macro_rules! impl_read_methods {
                     .store
                     .current_view_cache
                     .lock()
-                    .expect("Mutex is poisoned !");
+                    .expect("Mutex is poisoned");
                 if let ScalarCache::Present(last_timestamps) = &guard.per_topic_last_timestamps {
                     return Ok(last_timestamps.to_owned());
                 }
@@ -437,13 +609,158 @@ macro_rules! impl_read_methods {
                 .store
                 .current_view_cache
                 .lock()
-                .expect("Mutex is poisoned !");
+                .expect("Mutex is poisoned");
 
             guard.per_topic_last_timestamps.set(last_timestamps.clone());
 
             Ok(last_timestamps)
         }
 
+        /// Run `cb` against the certificates index, building it first on cache miss.
+        async fn with_index<T>(
+            &mut self,
+            cb: impl FnOnce(&CertificatesIndex) -> T,
+        ) -> anyhow::Result<T> {
+            {
+                let guard = self
+                    .store
+                    .current_view_cache
+                    .lock()
+                    .expect("Mutex is poisoned");
+                if let ScalarCache::Present(index) = &guard.index {
+                    return Ok(cb(index));
+                }
+            }
+
+            // Cache miss !
+
+            let mut index = CertificatesIndex::default();
+
+            // Certificates are returned oldest first, hence the users must be
+            // added first so that the other certificates can refer to them.
+
+            let query = GetCertificateQuery::users_certificates();
+            let items = self
+                .storage
+                .get_multiple_certificates_encrypted(query, UpTo::Current, None, None)
+                .await?;
+            for certif in get_multiple_certificates_from_encrypted(
+                &self.store,
+                items,
+                UserCertificate::unsecure_load,
+                UnsecureUserCertificate::skip_validation,
+            )? {
+                index.add_user(certif);
+            }
+
+            let query = GetCertificateQuery::devices_certificates();
+            let items = self
+                .storage
+                .get_multiple_certificates_encrypted(query, UpTo::Current, None, None)
+                .await?;
+            for certif in get_multiple_certificates_from_encrypted(
+                &self.store,
+                items,
+                DeviceCertificate::unsecure_load,
+                UnsecureDeviceCertificate::skip_validation,
+            )? {
+                index.add_device(certif);
+            }
+
+            let query = GetCertificateQuery::users_update_certificates();
+            let items = self
+                .storage
+                .get_multiple_certificates_encrypted(query, UpTo::Current, None, None)
+                .await?;
+            for certif in get_multiple_certificates_from_encrypted(
+                &self.store,
+                items,
+                UserUpdateCertificate::unsecure_load,
+                UnsecureUserUpdateCertificate::skip_validation,
+            )? {
+                index.add_user_update(certif);
+            }
+
+            let query = GetCertificateQuery::revoked_users_certificates();
+            let items = self
+                .storage
+                .get_multiple_certificates_encrypted(query, UpTo::Current, None, None)
+                .await?;
+            for certif in get_multiple_certificates_from_encrypted(
+                &self.store,
+                items,
+                RevokedUserCertificate::unsecure_load,
+                UnsecureRevokedUserCertificate::skip_validation,
+            )? {
+                index.add_revoked_user(certif);
+            }
+
+            let query = GetCertificateQuery::realms_roles_certificates();
+            let items = self
+                .storage
+                .get_multiple_certificates_encrypted(query, UpTo::Current, None, None)
+                .await?;
+            for certif in get_multiple_certificates_from_encrypted(
+                &self.store,
+                items,
+                RealmRoleCertificate::unsecure_load,
+                UnsecureRealmRoleCertificate::skip_validation,
+            )? {
+                index.add_realm_role(certif);
+            }
+
+            let query = GetCertificateQuery::realms_key_rotation_certificates();
+            let items = self
+                .storage
+                .get_multiple_certificates_encrypted(query, UpTo::Current, None, None)
+                .await?;
+            for certif in get_multiple_certificates_from_encrypted(
+                &self.store,
+                items,
+                RealmKeyRotationCertificate::unsecure_load,
+                UnsecureRealmKeyRotationCertificate::skip_validation,
+            )? {
+                index.add_realm_key_rotation(certif);
+            }
+
+            let outcome = cb(&index);
+
+            self.store
+                .current_view_cache
+                .lock()
+                .expect("Mutex is poisoned !")
+                .index
+                .set(index);
+
+            Ok(outcome)
+        }
+
+        #[allow(unused)]
+        /// Return the certificates matching the query in their signed form, so that they
+        /// can be validated again (certificates are otherwise considered valid once stored).
+        ///
+        /// Certificates are returned ordered by timestamp in increasing order (i.e. oldest first)
+        pub async fn get_multiple_signed_certificates(
+            &mut self,
+            query: GetCertificateQuery<'_>,
+        ) -> anyhow::Result<Vec<Bytes>> {
+            let items = self
+                .storage
+                .get_multiple_certificates_encrypted(query, UpTo::Current, None, None)
+                .await?;
+            items
+                .into_iter()
+                .map(|(_, encrypted)| {
+                    self.store
+                        .device
+                        .local_symkey
+                        .decrypt(&encrypted)
+                        .map(Bytes::from)
+                        .map_err(|e| anyhow::anyhow!("Local database contains invalid data: {}", e))
+                })
+                .collect()
+        }
+
         #[allow(unused)]
         pub async fn get_device_verify_key(
             &mut self,
@@ -466,7 +783,7 @@ macro_rules! impl_read_methods {
                     .store
                     .current_view_cache
                     .lock()
-                    .expect("Mutex is poisoned !");
+                    .expect("Mutex is poisoned");
                 if let Some((verify_key, timestamp, user_id)) = cache.per_device_verify_key_and_user_id.get(&device_id) {
                     if let UpTo::Timestamp(up_to) = up_to {
                         if *timestamp > up_to {
@@ -497,7 +814,7 @@ macro_rules! impl_read_methods {
                     .store
                     .current_view_cache
                     .lock()
-                    .expect("Mutex is poisoned !");
+                    .expect("Mutex is poisoned");
                 cache
                     .per_device_verify_key_and_user_id
                     .insert(device_id, (certif.verify_key.clone(), certif.timestamp, certif.user_id));
@@ -512,6 +829,13 @@ macro_rules! impl_read_methods {
             up_to: UpTo,
             user_id: UserID,
         ) -> Result<Arc<UserCertificate>, GetCertificateError> {
+            if let UpTo::Current = up_to {
+                return self
+                    .with_index(|index| index.user(user_id).map(|user| user.certificate.clone()))
+                    .await?
+                    .ok_or(GetCertificateError::NonExisting);
+            }
+
             let query = GetCertificateQuery::user_certificate(&user_id);
             let (_, encrypted) = self.storage.get_certificate_encrypted(query, up_to).await?;
 
@@ -552,6 +876,20 @@ macro_rules! impl_read_methods {
             offset: Option<u32>,
             limit: Option<u32>,
         ) -> anyhow::Result<Vec<Arc<UserCertificate>>> {
+            if let UpTo::Current = up_to {
+                return self
+                    .with_index(|index| {
+                        index
+                            .users
+                            .iter()
+                            .skip(offset.unwrap_or(0) as usize)
+                            .take(limit.map(|limit| limit as usize).unwrap_or(usize::MAX))
+                            .map(|user| user.certificate.clone())
+                            .collect()
+                    })
+                    .await;
+            }
+
             let query = GetCertificateQuery::users_certificates();
             let items = self
                 .storage
@@ -591,6 +929,17 @@ macro_rules! impl_read_methods {
             up_to: UpTo,
             user_id: UserID,
         ) -> anyhow::Result<Vec<Arc<DeviceCertificate>>> {
+            if let UpTo::Current = up_to {
+                return self
+                    .with_index(|index| {
+                        index
+                            .user(user_id)
+                            .map(|user| user.devices.clone())
+                            .unwrap_or_default()
+                    })
+                    .await;
+            }
+
             let query = GetCertificateQuery::user_devices_certificates(&user_id);
             let items = self
                 .storage
@@ -604,12 +953,185 @@ macro_rules! impl_read_methods {
             )
         }
 
+        #[allow(unused)]
+        pub async fn get_revoked_device_certificate(
+            &mut self,
+            up_to: UpTo,
+            device_id: DeviceID,
+        ) -> anyhow::Result<Option<Arc<RevokedDeviceCertificate>>> {
+            let query = GetCertificateQuery::revoked_device_certificate(&device_id);
+            let outcome = self.storage.get_certificate_encrypted(query, up_to).await;
+
+            let encrypted = match outcome {
+                Ok((_, encrypted)) => encrypted,
+                Err(
+                    GetCertificateError::NonExisting
+                    | GetCertificateError::ExistButTooRecent { .. },
+                ) => return Ok(None),
+                Err(GetCertificateError::Internal(err)) => return Err(err),
+            };
+
+            get_certificate_from_encrypted(
+                self.store,
+                &encrypted,
+                RevokedDeviceCertificate::unsecure_load,
+                UnsecureRevokedDeviceCertificate::skip_validation,
+            )
+            .map(Some)
+        }
+
+        #[allow(unused)]
+        /// Certificates are returned ordered by timestamp in increasing order (i.e. oldest first)
+        pub async fn get_user_revoked_devices_certificates(
+            &mut self,
+            up_to: UpTo,
+            user_id: UserID,
+        ) -> anyhow::Result<Vec<Arc<RevokedDeviceCertificate>>> {
+            let query = GetCertificateQuery::user_revoked_devices_certificates(&user_id);
+            let items = self
+                .storage
+                .get_multiple_certificates_encrypted(query, up_to, None, None)
+                .await?;
+            get_multiple_certificates_from_encrypted(
+                &self.store,
+                items,
+                RevokedDeviceCertificate::unsecure_load,
+                UnsecureRevokedDeviceCertificate::skip_validation,
+            )
+        }
+
+        #[allow(unused)]
+        pub async fn get_group_certificate(
+            &mut self,
+            up_to: UpTo,
+            group_id: GroupID,
+        ) -> anyhow::Result<Option<Arc<GroupCertificate>>> {
+            let query = GetCertificateQuery::group_certificate(&group_id);
+            let outcome = self.storage.get_certificate_encrypted(query, up_to).await;
+
+            let encrypted = match outcome {
+                Ok((_, encrypted)) => encrypted,
+                Err(
+                    GetCertificateError::NonExisting
+                    | GetCertificateError::ExistButTooRecent { .. },
+                ) => return Ok(None),
+                Err(GetCertificateError::Internal(err)) => return Err(err),
+            };
+
+            get_certificate_from_encrypted(
+                self.store,
+                &encrypted,
+                GroupCertificate::unsecure_load,
+                UnsecureGroupCertificate::skip_validation,
+            )
+            .map(Some)
+        }
+
+        #[allow(unused)]
+        /// Certificates are returned ordered by timestamp in increasing order (i.e. oldest first)
+        pub async fn get_group_certificates(
+            &mut self,
+            up_to: UpTo,
+        ) -> anyhow::Result<Vec<Arc<GroupCertificate>>> {
+            let query = GetCertificateQuery::groups_certificates();
+            let items = self
+                .storage
+                .get_multiple_certificates_encrypted(query, up_to, None, None)
+                .await?;
+            get_multiple_certificates_from_encrypted(
+                &self.store,
+                items,
+                GroupCertificate::unsecure_load,
+                UnsecureGroupCertificate::skip_validation,
+            )
+        }
+
+        #[allow(unused)]
+        /// Return all member certificates of the group.
+        ///
+        /// ⚠️ This method returns *all* certificates, including the ones that have been
+        /// superseded !
+        ///
+        /// Certificates are returned ordered by timestamp in increasing order (i.e. oldest first)
+        pub async fn get_group_members_certificates(
+            &mut self,
+            up_to: UpTo,
+            group_id: GroupID,
+        ) -> anyhow::Result<Vec<Arc<GroupMemberCertificate>>> {
+            let query = GetCertificateQuery::group_members_certificates(&group_id);
+            let items = self
+                .storage
+                .get_multiple_certificates_encrypted(query, up_to, None, None)
+                .await?;
+            get_multiple_certificates_from_encrypted(
+                &self.store,
+                items,
+                GroupMemberCertificate::unsecure_load,
+                UnsecureGroupMemberCertificate::skip_validation,
+            )
+        }
+
+        /// For the given group, return the last member certificate of each user still
+        /// part of it.
+        #[allow(unused)]
+        pub async fn get_group_current_members(
+            &mut self,
+            up_to: UpTo,
+            group_id: GroupID,
+        ) -> anyhow::Result<HashMap<UserID, Arc<GroupMemberCertificate>>> {
+            let all_certifs = self.get_group_members_certificates(up_to, group_id).await?;
+            let mut per_user_certif = HashMap::new();
+            for certif in all_certifs {
+                if certif.is_member {
+                    per_user_certif.insert(certif.user_id, certif);
+                } else {
+                    per_user_certif.remove(&certif.user_id);
+                }
+            }
+            Ok(per_user_certif)
+        }
+
+        /// Return the groups the given user is currently a member of.
+        #[allow(unused)]
+        pub async fn get_user_current_groups(
+            &mut self,
+            up_to: UpTo,
+            user_id: UserID,
+        ) -> anyhow::Result<HashSet<GroupID>> {
+            let query = GetCertificateQuery::user_groups_members_certificates(&user_id);
+            let items = self
+                .storage
+                .get_multiple_certificates_encrypted(query, up_to, None, None)
+                .await?;
+            let all_certifs = get_multiple_certificates_from_encrypted(
+                &self.store,
+                items,
+                GroupMemberCertificate::unsecure_load,
+                UnsecureGroupMemberCertificate::skip_validation,
+            )?;
+            let mut groups = HashSet::new();
+            for certif in all_certifs {
+                if certif.is_member {
+                    groups.insert(certif.group_id);
+                } else {
+                    groups.remove(&certif.group_id);
+                }
+            }
+            Ok(groups)
+        }
+
         #[allow(unused)]
         pub async fn get_revoked_user_certificate(
             &mut self,
             up_to: UpTo,
             user_id: UserID,
         ) -> anyhow::Result<Option<Arc<RevokedUserCertificate>>> {
+            if let UpTo::Current = up_to {
+                return self
+                    .with_index(|index| index.user(user_id).and_then(|user| user.revoked.clone()))
+                    .await;
+            }
+
             let query = GetCertificateQuery::revoked_user_certificate(&user_id);
             let outcome = self.storage.get_certificate_encrypted(query, up_to).await;
 
@@ -664,6 +1186,12 @@ macro_rules! impl_read_methods {
             up_to: UpTo,
             user_id: UserID,
         ) -> anyhow::Result<Option<Arc<UserUpdateCertificate>>> {
+            if let UpTo::Current = up_to {
+                return self
+                    .with_index(|index| index.user(user_id).and_then(|user| user.last_update.clone()))
+                    .await;
+            }
+
             let query = GetCertificateQuery::user_update_certificates(&user_id);
             // `get_certificate_encrypted` return the last certificate if multiple are available
             let outcome = self.storage.get_certificate_encrypted(query, up_to).await;
@@ -752,6 +1280,18 @@ macro_rules! impl_read_methods {
             user_id: UserID,
             realm_id: VlobID,
         ) -> anyhow::Result<Option<Arc<RealmRoleCertificate>>> {
+            if let UpTo::Current = up_to {
+                return self
+                    .with_index(|index| {
+                        index
+                            .per_realm_users_roles
+                            .get(&realm_id)
+                            .and_then(|users_roles| users_roles.get(&user_id))
+                            .cloned()
+                    })
+                    .await;
+            }
+
             let query = GetCertificateQuery::realm_role_certificate(&realm_id, &user_id);
             // `get_certificate_encrypted` return the last certificate if multiple are available
             let encrypted = match self.storage.get_certificate_encrypted(query, up_to).await {
@@ -798,8 +1338,53 @@ macro_rules! impl_read_methods {
             )
         }
 
-        // TODO: Provide a `get_user_last_realms_roles` that, for a given user, returns
-        //       the last valid realm role certificate for each of its realms.
+        #[allow(unused)]
+        /// Return the last role certificate for each realm the user is or used to be
+        /// part of (i.e. the certificate's role is `None` if the user has been unshared).
+        ///
+        /// Certificates are ordered by the first time the user got a role in the realm.
+        pub async fn get_user_last_realms_roles(
+            &mut self,
+            up_to: UpTo,
+            user_id: UserID,
+        ) -> anyhow::Result<Vec<Arc<RealmRoleCertificate>>> {
+            if let UpTo::Current = up_to {
+                return self
+                    .with_index(|index| {
+                        index
+                            .user(user_id)
+                            .map(|user| user.realms_roles.clone())
+                            .unwrap_or_default()
+                    })
+                    .await;
+            }
+
+            let all_certifs = self.get_user_realms_roles(up_to, user_id).await?;
+            let mut last_realms_roles: Vec<Arc<RealmRoleCertificate>> = vec![];
+            for certif in all_certifs {
+                match last_realms_roles
+                    .iter_mut()
+                    .find(|role| role.realm_id == certif.realm_id)
+                {
+                    Some(role) => *role = certif,
+                    None => last_realms_roles.push(certif),
+                }
+            }
+            Ok(last_realms_roles)
+        }
+
+        #[allow(unused)]
+        /// Return the ID of the user with the given email address, if any.
+        ///
+        /// If the email address has been used by multiple users (i.e. it has been
+        /// reused after its previous user got revoked), the most recent user is returned.
+        pub async fn get_current_user_id_from_email(
+            &mut self,
+            email: &EmailAddress,
+        ) -> anyhow::Result<Option<UserID>> {
+            self.with_index(|index| index.per_email.get(email).copied())
+                .await
+        }
 
         #[allow(unused)]
         /// Get the current role of our user for a given realm, if any.
@@ -812,7 +1397,7 @@ macro_rules! impl_read_methods {
                     .store
                     .current_view_cache
                     .lock()
-                    .expect("Mutex is poisoned !");
+                    .expect("Mutex is poisoned");
                 if let Some(role) = cache.per_realm_self_role.get(&realm_id) {
                     return Ok(Some(*role));
                 }
@@ -845,7 +1430,7 @@ macro_rules! impl_read_methods {
                     .store
                     .current_view_cache
                     .lock()
-                    .expect("Mutex is poisoned !");
+                    .expect("Mutex is poisoned");
                 cache
                     .per_realm_self_role
                     .insert(realm_id, certif.role);
@@ -865,7 +1450,7 @@ macro_rules! impl_read_methods {
                     .store
                     .current_view_cache
                     .lock()
-                    .expect("Mutex is poisoned !");
+                    .expect("Mutex is poisoned");
 
                 if let Some(state) = guard.per_realm_bootstrap_state.get(&realm_id) {
                     return Ok(*state)
@@ -966,6 +1551,24 @@ macro_rules! impl_read_methods {
             up_to: UpTo,
             realm_id: VlobID,
         ) -> anyhow::Result<HashMap<UserID, Arc<RealmRoleCertificate>>> {
+            if let UpTo::Current = up_to {
+                return self
+                    .with_index(|index| {
+                        index
+                            .per_realm_users_roles
+                            .get(&realm_id)
+                            .map(|users_roles| {
+                                users_roles
+                                    .iter()
+                                    .filter(|(_, certif)| certif.role.is_some())
+                                    .map(|(user_id, certif)| (*user_id, certif.clone()))
+                                    .collect()
+                            })
+                            .unwrap_or_default()
+                    })
+                    .await;
+            }
+
             let all_certifs = self.get_realm_roles(up_to, realm_id).await?;
             let mut per_user_role = HashMap::new();
             for certif in all_certifs {
@@ -981,6 +1584,54 @@ macro_rules! impl_read_methods {
             Ok(per_user_role)
         }
 
+        #[allow(unused)]
+        /// Return all group role certificates of the realm.
+        ///
+        /// ⚠️ This method returns *all* certificates, including the ones that have been
+        /// superseded !
+        ///
+        /// Certificates are returned ordered by timestamp in increasing order (i.e. oldest first)
+        pub async fn get_realm_group_roles(
+            &mut self,
+            up_to: UpTo,
+            realm_id: VlobID,
+        ) -> anyhow::Result<Vec<Arc<RealmGroupRoleCertificate>>> {
+            let query = GetCertificateQuery::realm_group_roles_certificates(&realm_id);
+            let items = self
+                .storage
+                .get_multiple_certificates_encrypted(query, up_to, None, None)
+                .await?;
+            get_multiple_certificates_from_encrypted(
+                &self.store,
+                items,
+                RealmGroupRoleCertificate::unsecure_load,
+                UnsecureRealmGroupRoleCertificate::skip_validation,
+            )
+        }
+
+        /// For the given realm, return the last role certificate for each group still
+        /// part of it.
+        #[allow(unused)]
+        pub async fn get_realm_current_groups_roles(
+            &mut self,
+            up_to: UpTo,
+            realm_id: VlobID,
+        ) -> anyhow::Result<HashMap<GroupID, Arc<RealmGroupRoleCertificate>>> {
+            let all_certifs = self.get_realm_group_roles(up_to, realm_id).await?;
+            let mut per_group_role = HashMap::new();
+            for certif in all_certifs {
+                match certif.role {
+                    Some(_) => {
+                        per_group_role.insert(certif.group_id, certif);
+                    }
+                    None => {
+                        per_group_role.remove(&certif.group_id);
+                    }
+                }
+            }
+            Ok(per_group_role)
+        }
+
         #[allow(unused)]
         pub async fn get_realm_last_name_certificate(
             &mut self,
@@ -1032,6 +1683,12 @@ macro_rules! impl_read_methods {
             up_to: UpTo,
             realm_id: VlobID,
         ) -> anyhow::Result<Option<Arc<RealmKeyRotationCertificate>>> {
+            if let UpTo::Current = up_to {
+                return self
+                    .with_index(|index| index.per_realm_last_key_rotation.get(&realm_id).cloned())
+                    .await;
+            }
+
             let query = GetCertificateQuery::realm_key_rotation_certificates(&realm_id);
             let outcome = self.storage.get_certificate_encrypted(query, up_to).await;
             let encrypted = match outcome {
@@ -1482,7 +2139,7 @@ macro_rules! impl_read_methods {
                 .store
                 .current_view_cache
                 .lock()
-                .expect("Mutex is poisoned !");
+                .expect("Mutex is poisoned");
             guard.per_realm_keys.get(&realm_id).cloned()
         }
 
@@ -1513,6 +2170,37 @@ macro_rules! impl_read_methods {
                 std::collections::hash_map::Entry::Occupied(entry) => entry.get().clone(),
             }
         }
+
+        pub fn is_realm_keys_bundle_healing_known_not_possible(
+            &self,
+            realm_id: VlobID,
+            key_index: IndexInt,
+        ) -> bool {
+            let guard = self
+                .store
+                .current_view_cache
+                .lock()
+                .expect("Mutex is poisoned");
+            guard
+                .per_realm_keys_bundle_healing_not_possible
+                .get(&realm_id)
+                == Some(&key_index)
+        }
+
+        pub fn update_cache_for_realm_keys_bundle_healing_not_possible(
+            &self,
+            realm_id: VlobID,
+            key_index: IndexInt,
+        ) {
+            let mut guard = self
+                .store
+                .current_view_cache
+                .lock()
+                .expect("Mutex is poisoned");
+            guard
+                .per_realm_keys_bundle_healing_not_possible
+                .insert(realm_id, key_index);
+        }
     };
 }
 
@@ -1580,6 +2268,9 @@ impl CertificatesStoreWriteGuard<'_> {
                 if certif.user_id == self.store.device.user_id {
                     cache.self_profile.set(certif.profile);
                 }
+                if let ScalarCache::Present(index) = &mut cache.index {
+                    index.add_user(certif);
+                }
             }
             CommonTopicArcCertificate::Device(certif) => {
                 self.storage.add_certificate(&*certif, encrypted).await?;
@@ -1596,6 +2287,9 @@ impl CertificatesStoreWriteGuard<'_> {
                     certif.device_id,
                     (certif.verify_key.clone(), certif.timestamp, certif.user_id),
                 );
+                if let ScalarCache::Present(index) = &mut cache.index {
+                    index.add_device(certif);
+                }
             }
             CommonTopicArcCertificate::UserUpdate(certif) => {
                 self.storage.add_certificate(&*certif, encrypted).await?;
@@ -1614,12 +2308,54 @@ impl CertificatesStoreWriteGuard<'_> {
                 if certif.user_id == self.store.device.user_id {
                     cache.self_profile.set(certif.new_profile);
                 }
+                if let ScalarCache::Present(index) = &mut cache.index {
+                    index.add_user_update(certif);
+                }
             }
             CommonTopicArcCertificate::RevokedUser(certif) => {
                 self.storage.add_certificate(&*certif, encrypted).await?;
 
                 // Update cache
 
+                let mut cache = self
+                    .store
+                    .current_view_cache
+                    .lock()
+                    .expect("Mutex is poisoned");
+                update_timestamp_cache(&mut cache, certif.timestamp);
+                if let ScalarCache::Present(index) = &mut cache.index {
+                    index.add_revoked_user(certif);
+                }
+            }
+            CommonTopicArcCertificate::RevokedDevice(certif) => {
+                self.storage.add_certificate(&*certif, encrypted).await?;
+
+                // Update cache
+
+                let mut cache = self
+                    .store
+                    .current_view_cache
+                    .lock()
+                    .expect("Mutex is poisoned");
+                update_timestamp_cache(&mut cache, certif.timestamp);
+            }
+            CommonTopicArcCertificate::Group(certif) => {
+                self.storage.add_certificate(&*certif, encrypted).await?;
+
+                // Update cache
+
+                let mut cache = self
+                    .store
+                    .current_view_cache
+                    .lock()
+                    .expect("Mutex is poisoned");
+                update_timestamp_cache(&mut cache, certif.timestamp);
+            }
+            CommonTopicArcCertificate::GroupMember(certif) => {
+                self.storage.add_certificate(&*certif, encrypted).await?;
+
+                // Update cache
+
                 let mut cache = self
                     .store
                     .current_view_cache
@@ -1638,21 +2374,25 @@ impl CertificatesStoreWriteGuard<'_> {
         signed: &[u8],
     ) -> anyhow::Result<()> {
         let encrypted = self.store.device.local_symkey.encrypt(signed);
-        let (realm_id, timestamp, new_keys_bundle) = match certif {
+        let (realm_id, timestamp, new_keys_bundle) = match &certif {
             RealmTopicArcCertificate::RealmRole(certif) => {
-                self.storage.add_certificate(&*certif, encrypted).await?;
+                self.storage.add_certificate(&**certif, encrypted).await?;
                 (certif.realm_id, certif.timestamp, false)
             }
             RealmTopicArcCertificate::RealmName(certif) => {
-                self.storage.add_certificate(&*certif, encrypted).await?;
+                self.storage.add_certificate(&**certif, encrypted).await?;
                 (certif.realm_id, certif.timestamp, false)
             }
             RealmTopicArcCertificate::RealmKeyRotation(certif) => {
-                self.storage.add_certificate(&*certif, encrypted).await?;
+                self.storage.add_certificate(&**certif, encrypted).await?;
                 (certif.realm_id, certif.timestamp, true)
             }
             RealmTopicArcCertificate::RealmArchiving(certif) => {
-                self.storage.add_certificate(&*certif, encrypted).await?;
+                self.storage.add_certificate(&**certif, encrypted).await?;
+                (certif.realm_id, certif.timestamp, false)
+            }
+            RealmTopicArcCertificate::RealmGroupRole(certif) => {
+                self.storage.add_certificate(&**certif, encrypted).await?;
                 (certif.realm_id, certif.timestamp, false)
             }
         };
@@ -1690,6 +2430,21 @@ impl CertificatesStoreWriteGuard<'_> {
         // (i.e. not the very last one).
         if new_keys_bundle {
             guard.per_realm_keys.remove(&realm_id);
+            guard
+                .per_realm_keys_bundle_healing_not_possible
+                .remove(&realm_id);
+        }
+
+        if let ScalarCache::Present(index) = &mut guard.index {
+            match certif {
+                RealmTopicArcCertificate::RealmRole(certif) => index.add_realm_role(certif),
+                RealmTopicArcCertificate::RealmKeyRotation(certif) => {
+                    index.add_realm_key_rotation(certif)
+                }
+                RealmTopicArcCertificate::RealmName(_)
+                | RealmTopicArcCertificate::RealmArchiving(_)
+                | RealmTopicArcCertificate::RealmGroupRole(_) => (),
+            }
         }
 
         Ok(())
@@ -5,8 +5,9 @@ use libparsec_protocol::authenticated_cmds;
 use libparsec_types::prelude::*;
 
 use super::{
-    greater_timestamp, store::CertifStoreError, CertificateBasedActionOutcome, CertificateOps,
-    GreaterTimestampOffset, InvalidCertificateError, InvalidKeysBundleError,
+    greater_timestamp, realm_sole_owner::list_solely_owned_realms, store::CertifStoreError,
+    CertificateBasedActionOutcome, CertificateOps, ClockDriftError, GreaterTimestampOffset,
+    InvalidCertificateError, InvalidKeysBundleError, OrphanedWorkspacesStrategy,
 };
 use crate::EventTooMuchDriftWithServerClock;
 
@@ -22,6 +23,8 @@ pub enum CertifRevokeUserError {
     UserNotFound,
     #[error("Author not allowed")]
     AuthorNotAllowed,
+    #[error("User is the only owner of some workspaces: {workspaces:?}")]
+    SoleOwnerOfWorkspaces { workspaces: Vec<VlobID> },
     #[error("Our clock ({client_timestamp}) and the server's one ({server_timestamp}) are too far apart")]
     TimestampOutOfBallpark {
         server_timestamp: DateTime,
@@ -36,6 +39,8 @@ pub enum CertifRevokeUserError {
     #[error(transparent)]
     InvalidCertificate(#[from] Box<InvalidCertificateError>),
     #[error(transparent)]
+    ClockDrift(#[from] ClockDriftError),
+    #[error(transparent)]
     Internal(#[from] anyhow::Error),
 }
 
@@ -51,11 +56,21 @@ impl From<CertifStoreError> for CertifRevokeUserError {
 pub(super) async fn revoke_user(
     ops: &CertificateOps,
     user: UserID,
+    orphaned_workspaces: OrphanedWorkspacesStrategy,
 ) -> Result<CertificateBasedActionOutcome, CertifRevokeUserError> {
     if ops.device.user_id == user {
         return Err(CertifRevokeUserError::UserIsSelf);
     }
 
+    // A revoked user loses access to all its realms, so make sure nobody is left
+    // behind without an owner.
+    if orphaned_workspaces == OrphanedWorkspacesStrategy::Abort {
+        let workspaces = list_solely_owned_realms(ops, user).await?;
+        if !workspaces.is_empty() {
+            return Err(CertifRevokeUserError::SoleOwnerOfWorkspaces { workspaces });
+        }
+    }
+
     // Loop is needed to deal with server requiring greater timestamp
     let mut timestamp = ops.device.now();
     loop {
@@ -64,13 +79,13 @@ pub(super) async fn revoke_user(
         match outcome {
             DoServerCommandOutcome::Done(outcome) => return Ok(outcome),
             DoServerCommandOutcome::RequireGreaterTimestamp(strictly_greater_than) => {
-                // TODO: #9448 handle `strictly_greater_than` out of the client ballpark by
-                // returning an error
                 timestamp = greater_timestamp(
                     &ops.device.time_provider,
                     GreaterTimestampOffset::User,
                     strictly_greater_than,
-                );
+                )
+                .await
+                .inspect_err(|err| ops.event_bus.send(&err.to_event()))?;
             }
         }
     }
@@ -5,8 +5,9 @@ use libparsec_protocol::authenticated_cmds;
 use libparsec_types::prelude::*;
 
 use super::{
-    greater_timestamp, store::CertifStoreError, CertificateBasedActionOutcome, CertificateOps,
-    GreaterTimestampOffset, InvalidCertificateError,
+    greater_timestamp, realm_sole_owner::list_solely_owned_realms, store::CertifStoreError,
+    CertificateBasedActionOutcome, CertificateOps, ClockDriftError, GreaterTimestampOffset,
+    InvalidCertificateError, OrphanedWorkspacesStrategy,
 };
 use crate::EventTooMuchDriftWithServerClock;
 
@@ -24,6 +25,8 @@ pub enum CertifUpdateUserProfileError {
     UserIsSelf,
     #[error("User revoked")]
     UserRevoked,
+    #[error("User is the only owner of some workspaces: {workspaces:?}")]
+    SoleOwnerOfWorkspaces { workspaces: Vec<VlobID> },
     #[error("Our clock ({client_timestamp}) and the server's one ({server_timestamp}) are too far apart")]
     TimestampOutOfBallpark {
         server_timestamp: DateTime,
@@ -34,6 +37,8 @@ pub enum CertifUpdateUserProfileError {
     #[error(transparent)]
     InvalidCertificate(#[from] Box<InvalidCertificateError>),
     #[error(transparent)]
+    ClockDrift(#[from] ClockDriftError),
+    #[error(transparent)]
     Internal(#[from] anyhow::Error),
 }
 
@@ -50,7 +55,24 @@ pub(super) async fn update_profile(
     ops: &CertificateOps,
     user: UserID,
     new_profile: UserProfile,
+    orphaned_workspaces: OrphanedWorkspacesStrategy,
 ) -> Result<CertificateBasedActionOutcome, CertifUpdateUserProfileError> {
+    if ops.device.user_id == user {
+        return Err(CertifUpdateUserProfileError::UserIsSelf);
+    }
+
+    // An OUTSIDER is not supposed to be OWNER of a shared realm (the server doesn't
+    // enforce it though, see `user_update` command), so make sure nobody is left
+    // behind without a legit owner.
+    if new_profile == UserProfile::Outsider
+        && orphaned_workspaces == OrphanedWorkspacesStrategy::Abort
+    {
+        let workspaces = list_solely_owned_realms(ops, user).await?;
+        if !workspaces.is_empty() {
+            return Err(CertifUpdateUserProfileError::SoleOwnerOfWorkspaces { workspaces });
+        }
+    }
+
     // Loop is needed to deal with server requiring greater timestamp
     let mut timestamp = ops.device.now();
     loop {
@@ -59,13 +81,13 @@ pub(super) async fn update_profile(
         match outcome {
             DoServerCommandOutcome::Done(outcome) => return Ok(outcome),
             DoServerCommandOutcome::RequireGreaterTimestamp(strictly_greater_than) => {
-                // TODO: #9448 handle `strictly_greater_than` out of the client ballpark by
-                // returning an error
                 timestamp = greater_timestamp(
                     &ops.device.time_provider,
                     GreaterTimestampOffset::User,
                     strictly_greater_than,
-                );
+                )
+                .await
+                .inspect_err(|err| ops.event_bus.send(&err.to_event()))?;
             }
         }
     }
@@ -8,7 +8,7 @@ use crate::EventTooMuchDriftWithServerClock;
 use super::{
     store::{CertifStoreError, RealmBootstrapState},
     CertifEnsureRealmCreatedError, CertifPollServerError, CertifRenameRealmError,
-    CertifRotateRealmKeyError, CertificateBasedActionOutcome, CertificateOps,
+    CertifRotateRealmKeyError, CertificateBasedActionOutcome, CertificateOps, ClockDriftError,
     InvalidCertificateError, InvalidKeysBundleError,
 };
 
@@ -36,6 +36,8 @@ pub enum CertifBootstrapWorkspaceError {
     #[error(transparent)]
     InvalidCertificate(#[from] Box<InvalidCertificateError>),
     #[error(transparent)]
+    ClockDrift(#[from] ClockDriftError),
+    #[error(transparent)]
     Internal(#[from] anyhow::Error),
 }
 
@@ -130,6 +132,9 @@ pub(super) async fn bootstrap_workspace(
                         ballpark_client_late_offset,
                     });
                 }
+                CertifEnsureRealmCreatedError::ClockDrift(err) => {
+                    return Err(CertifBootstrapWorkspaceError::ClockDrift(err))
+                }
                 CertifEnsureRealmCreatedError::Internal(err) => {
                     return Err(err.context("Cannot do server-side realm creation").into())
                 }
@@ -170,6 +175,7 @@ pub(super) async fn bootstrap_workspace(
                         ballpark_client_late_offset,
                     });
                 }
+                CertifRotateRealmKeyError::ClockDrift(err) => return Err(CertifBootstrapWorkspaceError::ClockDrift(err)),
                 CertifRotateRealmKeyError::InvalidCertificate(err) => return Err(CertifBootstrapWorkspaceError::InvalidCertificate(err)),
                 CertifRotateRealmKeyError::Internal(err) => return Err(err.context("Cannot do initial server-side key rotation").into()),
                 // Unexpected given we have just made sure the realm was created
@@ -180,7 +186,7 @@ pub(super) async fn bootstrap_workspace(
                 // The fact this new keys bundle is invalid is none of our concern: we
                 // only consider it as proof a key rotation certificate has been concurrently
                 // uploaded and hence this step 2 has been done ¯\_(ツ)_/¯
-                CertifRotateRealmKeyError::CurrentKeysBundleCorrupted(_) => (),
+                CertifRotateRealmKeyError::CurrentKeysBundleCorruptedAndUnrecoverable(_) => (),
             }
         }
 
@@ -250,6 +256,7 @@ pub(super) async fn bootstrap_workspace(
                 // the error: a dedicated monitor should detect the issue, heal the keys
                 // bundle and finally send an event that will re-trigger the bootstrap.
                 CertifRenameRealmError::InvalidKeysBundle(err) => Err(CertifBootstrapWorkspaceError::InvalidKeysBundle(err)),
+                CertifRenameRealmError::ClockDrift(err) => Err(CertifBootstrapWorkspaceError::ClockDrift(err)),
                 CertifRenameRealmError::InvalidCertificate(err) => Err(CertifBootstrapWorkspaceError::InvalidCertificate(err)),
                 // Unexpected given we have just made sure the realm was created
                 CertifRenameRealmError::UnknownRealm => Err(anyhow::anyhow!(
@@ -0,0 +1,43 @@
+// Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+use libparsec_platform_storage::certificates::PerTopicLastTimestamps;
+use libparsec_types::prelude::*;
+
+use crate::certif::{CertifPollServerError, CertificateBasedActionOutcome};
+
+pub type ClientRevokeDeviceError = crate::certif::CertifRevokeDeviceError;
+
+use super::Client;
+
+pub async fn revoke_device(
+    client_ops: &Client,
+    device: DeviceID,
+) -> Result<(), ClientRevokeDeviceError> {
+    let outcome = client_ops.certificates_ops.revoke_device(device).await?;
+
+    let latest_known_timestamps = match outcome {
+        CertificateBasedActionOutcome::LocalIdempotent => return Ok(()),
+        CertificateBasedActionOutcome::Uploaded {
+            certificate_timestamp,
+        }
+        | CertificateBasedActionOutcome::RemoteIdempotent {
+            certificate_timestamp,
+        } => PerTopicLastTimestamps::new_for_common(certificate_timestamp),
+    };
+    client_ops
+        .certificates_ops
+        .poll_server_for_new_certificates(Some(&latest_known_timestamps))
+        .await
+        .map_err(|e| match e {
+            CertifPollServerError::Stopped => ClientRevokeDeviceError::Stopped,
+            CertifPollServerError::Offline(e) => ClientRevokeDeviceError::Offline(e),
+            CertifPollServerError::InvalidCertificate(err) => {
+                ClientRevokeDeviceError::InvalidCertificate(err)
+            }
+            CertifPollServerError::Internal(err) => err
+                .context("Cannot poll server for new certificates")
+                .into(),
+        })?;
+
+    Ok(())
+}
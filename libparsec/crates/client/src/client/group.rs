@@ -0,0 +1,121 @@
+// Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+use libparsec_platform_storage::certificates::PerTopicLastTimestamps;
+use libparsec_types::prelude::*;
+
+use crate::certif::{CertifPollServerError, CertificateBasedActionOutcome};
+
+pub type ClientCreateGroupError = crate::certif::CertifCreateGroupError;
+pub type ClientUpdateGroupMemberError = crate::certif::CertifUpdateGroupMemberError;
+pub type ClientShareWorkspaceWithGroupError = crate::certif::CertifUpdateRealmGroupRoleError;
+
+use super::Client;
+
+pub async fn create_group(
+    client_ops: &Client,
+    name: String,
+) -> Result<GroupID, ClientCreateGroupError> {
+    let (group_id, outcome) = client_ops.certificates_ops.create_group(name).await?;
+
+    let latest_known_timestamps = match outcome {
+        CertificateBasedActionOutcome::LocalIdempotent => return Ok(group_id),
+        CertificateBasedActionOutcome::Uploaded {
+            certificate_timestamp,
+        }
+        | CertificateBasedActionOutcome::RemoteIdempotent {
+            certificate_timestamp,
+        } => PerTopicLastTimestamps::new_for_common(certificate_timestamp),
+    };
+    client_ops
+        .certificates_ops
+        .poll_server_for_new_certificates(Some(&latest_known_timestamps))
+        .await
+        .map_err(|e| match e {
+            CertifPollServerError::Stopped => ClientCreateGroupError::Stopped,
+            CertifPollServerError::Offline(e) => ClientCreateGroupError::Offline(e),
+            CertifPollServerError::InvalidCertificate(err) => {
+                ClientCreateGroupError::InvalidCertificate(err)
+            }
+            CertifPollServerError::Internal(err) => err
+                .context("Cannot poll server for new certificates")
+                .into(),
+        })?;
+
+    Ok(group_id)
+}
+
+pub async fn update_group_member(
+    client_ops: &Client,
+    group_id: GroupID,
+    user_id: UserID,
+    is_member: bool,
+) -> Result<(), ClientUpdateGroupMemberError> {
+    let outcome = client_ops
+        .certificates_ops
+        .update_group_member(group_id, user_id, is_member)
+        .await?;
+
+    let latest_known_timestamps = match outcome {
+        CertificateBasedActionOutcome::LocalIdempotent => return Ok(()),
+        CertificateBasedActionOutcome::Uploaded {
+            certificate_timestamp,
+        }
+        | CertificateBasedActionOutcome::RemoteIdempotent {
+            certificate_timestamp,
+        } => PerTopicLastTimestamps::new_for_common(certificate_timestamp),
+    };
+    client_ops
+        .certificates_ops
+        .poll_server_for_new_certificates(Some(&latest_known_timestamps))
+        .await
+        .map_err(|e| match e {
+            CertifPollServerError::Stopped => ClientUpdateGroupMemberError::Stopped,
+            CertifPollServerError::Offline(e) => ClientUpdateGroupMemberError::Offline(e),
+            CertifPollServerError::InvalidCertificate(err) => {
+                ClientUpdateGroupMemberError::InvalidCertificate(err)
+            }
+            CertifPollServerError::Internal(err) => err
+                .context("Cannot poll server for new certificates")
+                .into(),
+        })?;
+
+    Ok(())
+}
+
+pub async fn share_workspace_with_group(
+    client_ops: &Client,
+    realm_id: VlobID,
+    group_id: GroupID,
+    role: Option<RealmRole>,
+) -> Result<(), ClientShareWorkspaceWithGroupError> {
+    let outcome = client_ops
+        .certificates_ops
+        .update_realm_group_role(realm_id, group_id, role)
+        .await?;
+
+    let latest_known_timestamps = match outcome {
+        CertificateBasedActionOutcome::LocalIdempotent => return Ok(()),
+        CertificateBasedActionOutcome::Uploaded {
+            certificate_timestamp,
+        }
+        | CertificateBasedActionOutcome::RemoteIdempotent {
+            certificate_timestamp,
+        } => PerTopicLastTimestamps::new_for_realm(realm_id, certificate_timestamp),
+    };
+    client_ops
+        .certificates_ops
+        .poll_server_for_new_certificates(Some(&latest_known_timestamps))
+        .await
+        .map_err(|e| match e {
+            CertifPollServerError::Stopped => ClientShareWorkspaceWithGroupError::Stopped,
+            CertifPollServerError::Offline(e) => ClientShareWorkspaceWithGroupError::Offline(e),
+            CertifPollServerError::InvalidCertificate(err) => {
+                ClientShareWorkspaceWithGroupError::InvalidCertificate(err)
+            }
+            CertifPollServerError::Internal(err) => err
+                .context("Cannot poll server for new certificates")
+                .into(),
+        })?;
+
+    Ok(())
+}
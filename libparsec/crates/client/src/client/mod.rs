@@ -2,6 +2,8 @@
 
 #![allow(dead_code)]
 
+mod device_revoke;
+mod group;
 mod list_frozen_users;
 mod organization_info;
 mod pki_get_addr;
@@ -22,6 +24,7 @@ mod workspace_refresh_list;
 mod workspace_rename;
 mod workspace_share;
 mod workspace_start;
+mod workspace_transfer_ownership;
 
 use std::{
     collections::HashMap,
@@ -31,6 +34,9 @@ use std::{
 };
 
 pub use self::{
+    group::{
+        ClientCreateGroupError, ClientShareWorkspaceWithGroupError, ClientUpdateGroupMemberError,
+    },
     list_frozen_users::ClientListFrozenUsersError,
     start_invitation_greet::ClientStartShamirRecoveryInvitationGreetError,
     tos::{ClientAcceptTosError, ClientGetTosError, Tos},
@@ -43,6 +49,7 @@ pub use self::{
     workspace_rename::ClientRenameWorkspaceError,
     workspace_share::ClientShareWorkspaceError,
     workspace_start::ClientStartWorkspaceError,
+    workspace_transfer_ownership::ClientTransferWorkspaceOwnershipError,
 };
 use crate::{
     certif::{CertifPollServerError, CertificateOps},
@@ -70,17 +77,25 @@ pub use recovery_device::{
 
 // Re-exposed for public API
 pub use crate::certif::{
+    CertifCertificateAuditLogError as ClientCertificateAuditLogError,
     CertifForgetAllCertificatesError as ClientForgetAllCertificatesError,
     CertifGetCurrentSelfProfileError as ClientGetCurrentSelfProfileError,
     CertifGetUserDeviceError as ClientGetUserDeviceError,
     CertifGetUserInfoError as ClientGetUserInfoError,
+    CertifListGroupsError as ClientListGroupsError,
     CertifListUserDevicesError as ClientListUserDevicesError,
     CertifListUsersError as ClientListUsersError,
+    CertifListWorkspaceGroupsError as ClientListWorkspaceGroupsError,
     CertifListWorkspaceUsersError as ClientListWorkspaceUsersError,
+    CertifRekeyRealmError as ClientRekeyWorkspaceError,
+    CertifRevokeDeviceError as ClientRevokeDeviceError,
     CertifRevokeUserError as ClientRevokeUserError,
     CertifSetupShamirRecoveryError as ClientSetupShamirRecoveryError,
-    CertifUpdateUserProfileError as ClientUserUpdateProfileError, DeviceInfo,
-    RequestedRealmArchivingConfiguration, UserInfo, WorkspaceUserAccessInfo,
+    CertifUpdateUserProfileError as ClientUserUpdateProfileError, CertificateAuditLogAuthor,
+    CertificateAuditLogEntry, CertificateAuditLogEvent, CertificateAuditLogFilter,
+    CertificateAuditLogRealm, CertificateAuditLogUser, DeviceInfo, GroupInfo,
+    OrphanedWorkspacesStrategy, RequestedRealmArchivingConfiguration, UserInfo,
+    WorkspaceGroupAccessInfo, WorkspaceUserAccessInfo,
 };
 pub use crate::invite::{
     AcceptAsyncEnrollmentError as ClientAcceptAsyncEnrollmentError,
@@ -349,8 +364,22 @@ impl Client {
         self.certificates_ops.get_current_self_profile().await
     }
 
-    pub async fn revoke_user(&self, user: UserID) -> Result<(), ClientRevokeUserError> {
-        user_revoke::revoke_user(self, user).await
+    /// Revoke a user, this function requires to be online.
+    ///
+    /// `orphaned_workspaces` determines what to do if the user is the only OWNER of
+    /// workspaces other users have access to: with `Abort`, nothing is done and the
+    /// concerned workspaces are returned in a `SoleOwnerOfWorkspaces` error (their
+    /// ownership should then be transferred, see `transfer_workspace_ownership`).
+    pub async fn revoke_user(
+        &self,
+        user: UserID,
+        orphaned_workspaces: OrphanedWorkspacesStrategy,
+    ) -> Result<(), ClientRevokeUserError> {
+        user_revoke::revoke_user(self, user, orphaned_workspaces).await
+    }
+
+    pub async fn revoke_device(&self, device: DeviceID) -> Result<(), ClientRevokeDeviceError> {
+        device_revoke::revoke_device(self, device).await
     }
 
     /// Get user info.
@@ -398,6 +427,48 @@ impl Client {
         self.certificates_ops.list_workspace_users(realm_id).await
     }
 
+    /// List all groups.
+    pub async fn list_groups(&self) -> Result<Vec<GroupInfo>, ClientListGroupsError> {
+        self.certificates_ops.list_groups().await
+    }
+
+    /// List all groups currently having access to a given realm.
+    pub async fn list_workspace_groups(
+        &self,
+        realm_id: VlobID,
+    ) -> Result<Vec<WorkspaceGroupAccessInfo>, ClientListWorkspaceGroupsError> {
+        self.certificates_ops.list_workspace_groups(realm_id).await
+    }
+
+    /// Create a new (empty) group, this function requires to be online.
+    pub async fn create_group(&self, name: String) -> Result<GroupID, ClientCreateGroupError> {
+        group::create_group(self, name).await
+    }
+
+    /// Add a user to a group, this function requires to be online.
+    ///
+    /// The user is then given access to the workspaces shared with the group by
+    /// the owners of those workspaces (see `process_workspaces_needs`).
+    pub async fn add_group_member(
+        &self,
+        group_id: GroupID,
+        user_id: UserID,
+    ) -> Result<(), ClientUpdateGroupMemberError> {
+        group::update_group_member(self, group_id, user_id, true).await
+    }
+
+    /// Remove a user from a group, this function requires to be online.
+    ///
+    /// The user's access to the workspaces shared with the group is then revoked
+    /// by the owners of those workspaces (see `process_workspaces_needs`).
+    pub async fn remove_group_member(
+        &self,
+        group_id: GroupID,
+        user_id: UserID,
+    ) -> Result<(), ClientUpdateGroupMemberError> {
+        group::update_group_member(self, group_id, user_id, false).await
+    }
+
     /// List workspaces available to the current user.
     ///
     /// This is done according to the user manifest cache, hence some very
@@ -434,13 +505,54 @@ impl Client {
     ///
     /// If the workspace is not bootstrapped, this function will try to bootstrap
     /// it (this may fail if the current user does not have the OWNER role).
+    ///
+    /// If `expires_on` is provided, the recipient will be automatically unshared
+    /// once it is due.
+    ///
+    /// Note the expiration is only stored in this device's local storage (it is not
+    /// part of the realm role certificate), hence:
+    /// - The unsharing only occurs once this device is running and online after the
+    ///   due date, the recipient keeps its access in the meantime.
+    /// - Other devices (including the other owners of the workspace) don't know about
+    ///   the expiration, and it is lost if this device's local data are removed.
+    /// - The expiration is ignored when unsharing.
     pub async fn share_workspace(
         &self,
         realm_id: VlobID,
         recipient: UserID,
         role: Option<RealmRole>,
+        expires_on: Option<DateTime>,
     ) -> Result<(), ClientShareWorkspaceError> {
-        workspace_share::share_workspace(self, realm_id, recipient, role).await
+        workspace_share::share_workspace(self, realm_id, recipient, role, expires_on).await
+    }
+
+    /// Give the OWNER role on the workspace to another user, this function requires
+    /// to be online and the OWNER role in the workspace.
+    ///
+    /// This is typically used before leaving the organization, or before the
+    /// current user gets revoked (see `revoke_user`). Note our own role is left
+    /// untouched (one cannot change its own role), it is up to the new owner
+    /// to change it if needed.
+    pub async fn transfer_workspace_ownership(
+        &self,
+        realm_id: VlobID,
+        new_owner: UserID,
+    ) -> Result<(), ClientTransferWorkspaceOwnershipError> {
+        workspace_transfer_ownership::transfer_workspace_ownership(self, realm_id, new_owner).await
+    }
+
+    /// Share (or unshare if `role` is `None`) the workspace with a group, this
+    /// function requires to be online and the OWNER role in the workspace.
+    ///
+    /// The members of the group are then individually given access to the workspace
+    /// (see `process_workspaces_needs`).
+    pub async fn share_workspace_with_group(
+        &self,
+        realm_id: VlobID,
+        group_id: GroupID,
+        role: Option<RealmRole>,
+    ) -> Result<(), ClientShareWorkspaceWithGroupError> {
+        group::share_workspace_with_group(self, realm_id, group_id, role).await
     }
 
     /// Archive, unarchive or plan deletion for a workspace, this function requires to be online.
@@ -455,6 +567,25 @@ impl Client {
         workspace_archive::archive_workspace(self, realm_id, configuration).await
     }
 
+    /// Re-encrypt the data of the workspace with its last key, this function requires
+    /// to be online and the OWNER role in the workspace.
+    ///
+    /// This is typically used after a user has been unshared (or revoked), so that
+    /// the keys they may have kept no longer give access to the workspace's current
+    /// content. Progress is reported with `EventRealmRekeyProgress` events.
+    ///
+    /// Once started, the re-encryption is resumed by a monitor if interrupted, and
+    /// done again on each new key rotation.
+    ///
+    /// Note this is not a retroactive revocation: only the last version of each
+    /// file and folder is re-encrypted, the previous versions are kept as-is on the
+    /// server (along with the old keys in the keys bundle, so that the workspace
+    /// history remains readable). Hence someone who kept an old key can still read
+    /// the workspace history up to the last key rotation.
+    pub async fn rekey_workspace(&self, realm_id: VlobID) -> Result<(), ClientRekeyWorkspaceError> {
+        self.certificates_ops.rekey_realm(realm_id).await
+    }
+
     /// Ensure all workspaces are bootstrapped.
     ///
     /// ⚠️ This function doesn't refresh the workspace list to reflect the bootstraps.
@@ -525,6 +656,18 @@ impl Client {
         self.certificates_ops.forget_all_certificates().await
     }
 
+    /// Decode and validate all the certificates we know about into a chronological
+    /// list of entries (e.g. user creation, workspace sharing etc.) suitable for auditing.
+    ///
+    /// Be aware this function potentially do server accesses (to fetch the keys
+    /// bundles needed to decrypt the workspace names).
+    pub async fn certificate_audit_log(
+        &self,
+        filter: CertificateAuditLogFilter,
+    ) -> Result<Vec<CertificateAuditLogEntry>, ClientCertificateAuditLogError> {
+        self.certificates_ops.certificate_audit_log(filter).await
+    }
+
     /// Refresh the workspace list cache by taking into account the certificates that
     /// have been newly fetched.
     ///
@@ -539,9 +682,14 @@ impl Client {
     /// Workspace needs correspond to:
     /// - The workspace has been unshared with someone, a new key rotation is needed
     /// - A user which is part of the workspace has been revoked, unsharing is needed
+    /// - A share configured from this device has expired, unsharing is needed
     ///
     /// This method looks into the local certificates to determine the needs of each workspace
-    /// where the current user has OWNER profile, then proceed to do the requested operations.
+    /// where the current user has OWNER profile, then proceed to do the requested operations
+    /// (on workspaces where the current user has MANAGER profile, only expired shares are
+    /// processed).
+    ///
+    /// Returns the date of the next share expiration, if any.
     ///
     /// ⚠️ This method doesn't poll for the new certificates that have been created by
     /// those operations.
@@ -551,7 +699,7 @@ impl Client {
     /// This method is typically used by a monitor.
     pub(crate) async fn process_workspaces_needs(
         &self,
-    ) -> Result<(), ClientProcessWorkspacesNeedsError> {
+    ) -> Result<Option<DateTime>, ClientProcessWorkspacesNeedsError> {
         workspace_needs::process_workspaces_needs(self).await
     }
 
@@ -687,12 +835,18 @@ impl Client {
         recovery_device::export_recovery_device(self, device_label).await
     }
 
+    /// Change the profile of a user, this function requires to be online.
+    ///
+    /// An OUTSIDER is not supposed to be OWNER of a workspace, hence
+    /// `orphaned_workspaces` is used (similarly to `revoke_user`) when the new
+    /// profile is OUTSIDER.
     pub async fn update_user_profile(
         &self,
         user_id: UserID,
         new_profile: UserProfile,
+        orphaned_workspaces: OrphanedWorkspacesStrategy,
     ) -> Result<(), ClientUserUpdateProfileError> {
-        user_update_profile::update_profile(self, user_id, new_profile).await
+        user_update_profile::update_profile(self, user_id, new_profile, orphaned_workspaces).await
     }
 
     pub async fn organization_info(&self) -> Result<OrganizationInfo, ClientOrganizationInfoError> {
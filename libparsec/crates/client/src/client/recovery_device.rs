@@ -12,7 +12,8 @@ use libparsec_protocol::authenticated_cmds::latest::device_create;
 use libparsec_types::prelude::*;
 
 use crate::{
-    greater_timestamp, CertifPollServerError, GreaterTimestampOffset, InvalidCertificateError,
+    greater_timestamp, CertifPollServerError, ClockDriftError, GreaterTimestampOffset,
+    InvalidCertificateError,
 };
 
 use super::Client;
@@ -71,6 +72,8 @@ pub enum ClientExportRecoveryDeviceError {
     #[error("Cannot communicate with the server: {0}")]
     Offline(#[from] ConnectionError),
     #[error(transparent)]
+    ClockDrift(#[from] ClockDriftError),
+    #[error(transparent)]
     Internal(#[from] anyhow::Error),
 
     #[error("Our clock ({client_timestamp}) and the server's one ({server_timestamp}) are too far apart")]
@@ -91,6 +94,9 @@ impl From<RegisterNewDeviceError> for ClientExportRecoveryDeviceError {
             RegisterNewDeviceError::Internal(error) => {
                 ClientExportRecoveryDeviceError::Internal(error)
             }
+            RegisterNewDeviceError::ClockDrift(err) => {
+                ClientExportRecoveryDeviceError::ClockDrift(err)
+            }
             RegisterNewDeviceError::TimestampOutOfBallpark {
                 server_timestamp,
                 client_timestamp,
@@ -113,6 +119,8 @@ pub enum ImportRecoveryDeviceError {
     #[error("Cannot communicate with the server: {0}")]
     Offline(#[from] ConnectionError),
     #[error(transparent)]
+    ClockDrift(#[from] ClockDriftError),
+    #[error(transparent)]
     Internal(#[from] anyhow::Error),
     #[error("No space available")]
     NoSpaceAvailable,
@@ -150,6 +158,8 @@ pub enum ImportRecoveryDeviceError {
         server: RemoteOperationServer,
         error: anyhow::Error,
     },
+    #[error("The FIDO2 authenticator doesn't support the hmac-secret extension")]
+    Fido2HmacSecretNotSupported,
 }
 
 impl From<LoadRecoveryDeviceError> for ImportRecoveryDeviceError {
@@ -171,6 +181,7 @@ impl From<RegisterNewDeviceError> for ImportRecoveryDeviceError {
         match value {
             RegisterNewDeviceError::Offline(e) => ImportRecoveryDeviceError::Offline(e),
             RegisterNewDeviceError::Internal(error) => ImportRecoveryDeviceError::Internal(error),
+            RegisterNewDeviceError::ClockDrift(err) => ImportRecoveryDeviceError::ClockDrift(err),
             RegisterNewDeviceError::TimestampOutOfBallpark {
                 server_timestamp,
                 client_timestamp,
@@ -200,6 +211,9 @@ impl From<SaveDeviceError> for ImportRecoveryDeviceError {
             SaveDeviceError::RemoteOpaqueKeyUploadFailed { server, error } => {
                 ImportRecoveryDeviceError::RemoteOpaqueKeyUploadFailed { server, error }
             }
+            SaveDeviceError::Fido2HmacSecretNotSupported => {
+                ImportRecoveryDeviceError::Fido2HmacSecretNotSupported
+            }
         }
     }
 }
@@ -221,13 +235,12 @@ pub async fn register_new_device(
         match outcome {
             DeviceInternalsOutcome::Done(timestamp) => return Ok(timestamp),
             DeviceInternalsOutcome::RequireGreaterTimestamp(strictly_greater_than) => {
-                // TODO: handle `strictly_greater_than` out of the client ballpark by
-                // returning an error
                 timestamp = greater_timestamp(
                     &author.time_provider,
                     GreaterTimestampOffset::User,
                     strictly_greater_than,
-                );
+                )
+                .await?;
             }
         }
     }
@@ -275,6 +288,8 @@ pub enum RegisterNewDeviceError {
     #[error("Cannot communicate with the server: {0}")]
     Offline(#[from] ConnectionError),
     #[error(transparent)]
+    ClockDrift(#[from] ClockDriftError),
+    #[error(transparent)]
     Internal(#[from] anyhow::Error),
     #[error("Our clock ({client_timestamp}) and the server's one ({server_timestamp}) are too far apart")]
     TimestampOutOfBallpark {
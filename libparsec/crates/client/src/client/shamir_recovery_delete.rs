@@ -6,7 +6,7 @@ use libparsec_types::prelude::*;
 
 use super::Client;
 use crate::{
-    certif::{CertifPollServerError, InvalidCertificateError},
+    certif::{CertifPollServerError, ClockDriftError, InvalidCertificateError},
     CertifDeleteShamirRecoveryError, CertificateBasedActionOutcome,
 };
 
@@ -26,6 +26,8 @@ pub enum ClientDeleteShamirRecoveryError {
     #[error(transparent)]
     InvalidCertificate(#[from] Box<InvalidCertificateError>),
     #[error(transparent)]
+    ClockDrift(#[from] ClockDriftError),
+    #[error(transparent)]
     Internal(#[from] anyhow::Error),
 }
 
@@ -52,6 +54,9 @@ pub async fn delete_shamir_recovery(
                 ballpark_client_early_offset,
                 ballpark_client_late_offset,
             },
+            CertifDeleteShamirRecoveryError::ClockDrift(err) => {
+                ClientDeleteShamirRecoveryError::ClockDrift(err)
+            }
             CertifDeleteShamirRecoveryError::Internal(error) => error.into(),
         })?;
 
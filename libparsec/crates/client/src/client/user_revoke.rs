@@ -3,14 +3,23 @@
 use libparsec_platform_storage::certificates::PerTopicLastTimestamps;
 use libparsec_types::prelude::*;
 
-use crate::certif::{CertifPollServerError, CertificateBasedActionOutcome};
+use crate::certif::{
+    CertifPollServerError, CertificateBasedActionOutcome, OrphanedWorkspacesStrategy,
+};
 
 pub type ClientRevokeUserError = crate::certif::CertifRevokeUserError;
 
 use super::Client;
 
-pub async fn revoke_user(client_ops: &Client, user: UserID) -> Result<(), ClientRevokeUserError> {
-    let outcome = client_ops.certificates_ops.revoke_user(user).await?;
+pub async fn revoke_user(
+    client_ops: &Client,
+    user: UserID,
+    orphaned_workspaces: OrphanedWorkspacesStrategy,
+) -> Result<(), ClientRevokeUserError> {
+    let outcome = client_ops
+        .certificates_ops
+        .revoke_user(user, orphaned_workspaces)
+        .await?;
 
     let latest_known_timestamps = match outcome {
         CertificateBasedActionOutcome::LocalIdempotent => return Ok(()),
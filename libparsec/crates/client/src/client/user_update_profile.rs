@@ -3,7 +3,9 @@
 use libparsec_platform_storage::certificates::PerTopicLastTimestamps;
 use libparsec_types::prelude::*;
 
-use crate::certif::{CertifPollServerError, CertificateBasedActionOutcome};
+use crate::certif::{
+    CertifPollServerError, CertificateBasedActionOutcome, OrphanedWorkspacesStrategy,
+};
 
 pub type ClientUserUpdateProfileError = crate::certif::CertifUpdateUserProfileError;
 
@@ -13,10 +15,11 @@ pub async fn update_profile(
     client_ops: &Client,
     user_id: UserID,
     new_profile: UserProfile,
+    orphaned_workspaces: OrphanedWorkspacesStrategy,
 ) -> Result<(), ClientUserUpdateProfileError> {
     let outcome = client_ops
         .certificates_ops
-        .user_update_profile(user_id, new_profile)
+        .user_update_profile(user_id, new_profile, orphaned_workspaces)
         .await?;
 
     let latest_known_timestamps = match outcome {
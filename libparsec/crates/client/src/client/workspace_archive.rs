@@ -8,8 +8,9 @@ use super::Client;
 use crate::{
     certif::{
         CertifArchiveRealmError, CertifBootstrapWorkspaceError, CertifPollServerError,
-        CertificateBasedActionOutcome, InvalidCertificateError, InvalidEncryptedRealmNameError,
-        InvalidKeysBundleError, RequestedRealmArchivingConfiguration,
+        CertificateBasedActionOutcome, ClockDriftError, InvalidCertificateError,
+        InvalidEncryptedRealmNameError, InvalidKeysBundleError,
+        RequestedRealmArchivingConfiguration,
     },
     ClientRefreshWorkspacesListError,
 };
@@ -42,6 +43,8 @@ pub enum ClientArchiveWorkspaceError {
     #[error(transparent)]
     InvalidCertificate(#[from] Box<InvalidCertificateError>),
     #[error(transparent)]
+    ClockDrift(#[from] ClockDriftError),
+    #[error(transparent)]
     Internal(#[from] anyhow::Error),
 }
 
@@ -101,6 +104,9 @@ pub async fn archive_workspace(
             CertifBootstrapWorkspaceError::InvalidCertificate(err) => {
                 return Err(ClientArchiveWorkspaceError::InvalidCertificate(err))
             }
+            CertifBootstrapWorkspaceError::ClockDrift(err) => {
+                return Err(ClientArchiveWorkspaceError::ClockDrift(err))
+            }
             CertifBootstrapWorkspaceError::Internal(err) => {
                 return Err(err
                     .context("Cannot ensure workspace is bootstrapped")
@@ -165,6 +171,9 @@ pub async fn archive_workspace(
                 ballpark_client_early_offset,
                 ballpark_client_late_offset,
             },
+            CertifArchiveRealmError::ClockDrift(err) => {
+                ClientArchiveWorkspaceError::ClockDrift(err)
+            }
             CertifArchiveRealmError::Internal(err) => err.context("Cannot archive realm").into(),
         })?;
 
@@ -4,7 +4,7 @@ use libparsec_client_connection::ConnectionError;
 use libparsec_types::prelude::*;
 
 use crate::certif::{
-    CertifBootstrapWorkspaceError, InvalidCertificateError, InvalidKeysBundleError,
+    CertifBootstrapWorkspaceError, ClockDriftError, InvalidCertificateError, InvalidKeysBundleError,
 };
 
 use super::Client;
@@ -29,6 +29,8 @@ pub enum ClientEnsureWorkspacesBootstrappedError {
     #[error(transparent)]
     InvalidCertificate(#[from] Box<InvalidCertificateError>),
     #[error(transparent)]
+    ClockDrift(#[from] ClockDriftError),
+    #[error(transparent)]
     Internal(#[from] anyhow::Error),
 }
 
@@ -94,6 +96,9 @@ pub(crate) async fn ensure_workspaces_bootstrapped(
                 CertifBootstrapWorkspaceError::InvalidCertificate(err) => Err(
                     ClientEnsureWorkspacesBootstrappedError::InvalidCertificate(err),
                 ),
+                CertifBootstrapWorkspaceError::ClockDrift(err) => {
+                    Err(ClientEnsureWorkspacesBootstrappedError::ClockDrift(err))
+                }
                 CertifBootstrapWorkspaceError::Internal(err) => {
                     Err(err.context("Cannot bootstrap workspace").into())
                 }
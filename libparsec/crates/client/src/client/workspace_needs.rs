@@ -5,9 +5,11 @@ use libparsec_types::prelude::*;
 
 use super::Client;
 use crate::{
-    certif::InvalidCertificateError, CertifGetRealmNeedsError, CertifPollServerError,
-    CertifRotateRealmKeyError, CertifShareRealmError, ClientGetCurrentSelfProfileError,
-    EventTooMuchDriftWithServerClock, RealmNeeds,
+    certif::{ClockDriftError, InvalidCertificateError},
+    CertifGetRealmNeedsError, CertifGetRealmRekeyProgressError,
+    CertifListRealmShareExpirationsError, CertifPollServerError, CertifRekeyRealmError,
+    CertifRotateRealmKeyError, CertifSetRealmShareExpirationError, CertifShareRealmError,
+    ClientGetCurrentSelfProfileError, EventTooMuchDriftWithServerClock, RealmNeeds,
 };
 
 #[derive(Debug, thiserror::Error)]
@@ -26,12 +28,16 @@ pub enum ClientProcessWorkspacesNeedsError {
     #[error(transparent)]
     InvalidCertificate(#[from] Box<InvalidCertificateError>),
     #[error(transparent)]
+    ClockDrift(#[from] ClockDriftError),
+    #[error(transparent)]
     Internal(#[from] anyhow::Error),
 }
 
+/// Returns the date of the next share expiration (if any), at which point the
+/// workspaces needs should be processed again.
 pub async fn process_workspaces_needs(
     client: &Client,
-) -> Result<(), ClientProcessWorkspacesNeedsError> {
+) -> Result<Option<DateTime>, ClientProcessWorkspacesNeedsError> {
     // There is a footgun to be aware of here !
     //
     // We should only use the certificate ops to get informations about what workspace
@@ -53,16 +59,37 @@ pub async fn process_workspaces_needs(
             ClientGetCurrentSelfProfileError::Internal(err) => err.into(),
         })?;
 
+    let mut next_share_expiration: Option<DateTime> = None;
     for (realm_id, role, _) in realms {
-        // Only OWNER can rotate keys
-        if role != Some(RealmRole::Owner) {
-            continue;
+        match role {
+            // Only OWNER can rotate keys
+            Some(RealmRole::Owner) => process_workspace_needs(client, realm_id).await?,
+            // MANAGER can configure an expiration when sharing the workspace, so
+            // it must also be able to unshare once it is due (the key rotation
+            // will then be done by an OWNER).
+            Some(RealmRole::Manager) => process_workspace_expired_shares(client, realm_id).await?,
+            _ => continue,
         }
 
-        process_workspace_needs(client, realm_id).await?;
+        let expirations = client
+            .certificates_ops
+            .list_realm_share_expirations(realm_id)
+            .await
+            .map_err(|err| match err {
+                CertifListRealmShareExpirationsError::Stopped => {
+                    ClientProcessWorkspacesNeedsError::Stopped
+                }
+                CertifListRealmShareExpirationsError::Internal(err) => err.into(),
+            })?;
+        let now = client.device.now();
+        next_share_expiration = expirations
+            .into_values()
+            .filter(|expires_on| *expires_on > now)
+            .chain(next_share_expiration)
+            .min();
     }
 
-    Ok(())
+    Ok(next_share_expiration)
 }
 
 async fn process_workspace_needs(
@@ -78,74 +105,22 @@ async fn process_workspace_needs(
             CertifGetRealmNeedsError::Internal(err) => err.into(),
         })?;
 
+    let key_rotation_needed = !matches!(needs, RealmNeeds::Nothing);
+
     if let RealmNeeds::UnshareThenKeyRotation {
         current_key_index,
         revoked_users,
+        expired_users,
+        ungrouped_users,
     } = &needs
     {
-        for user in revoked_users {
-            let outcome = client
-                .certificates_ops
-                .share_realm(realm_id, *user, None)
-                .await;
-            match outcome {
-                Ok(_) => (),
-                Err(err) => match err {
-                    // Valid errors
-
-                    CertifShareRealmError::Stopped => return Err(ClientProcessWorkspacesNeedsError::Stopped),
-                    CertifShareRealmError::Offline(e) => return Err(ClientProcessWorkspacesNeedsError::Offline(e)),
-                    // A concurrent operation has changed our rights to the workspace,
-                    // hence we can no longer process its needs !
-                    CertifShareRealmError::AuthorNotAllowed => return Ok(()),
-                    // Workspace has been deleted, nothing to process
-                    CertifShareRealmError::RealmDeleted => return Ok(()),
-                    CertifShareRealmError::TimestampOutOfBallpark {
-                        server_timestamp,
-                        client_timestamp,
-                        ballpark_client_early_offset,
-                        ballpark_client_late_offset,
-                    } => {
-                        let event = EventTooMuchDriftWithServerClock {
-                            server_timestamp,
-                            ballpark_client_early_offset,
-                            ballpark_client_late_offset,
-                            client_timestamp,
-                        };
-                        client.event_bus.send(&event);
-
-                        return Err(ClientProcessWorkspacesNeedsError::TimestampOutOfBallpark {
-                            server_timestamp,
-                            client_timestamp,
-                            ballpark_client_early_offset,
-                            ballpark_client_late_offset,
-                        });
-                    }
-                    CertifShareRealmError::InvalidKeysBundle(_) => {
-                        // TODO: handle self-healing here !
-                        return Ok(());
-                    }
-                    CertifShareRealmError::InvalidCertificate(err) => return Err(ClientProcessWorkspacesNeedsError::InvalidCertificate(err)),
-                    CertifShareRealmError::Internal(err) => return Err(err.into()),
-
-                    // Invalid errors
-
-                    bad_rep @ (
-                        // If we were revoked, we wouldn't be here anyway !
-                        CertifShareRealmError::RecipientIsSelf
-                        // We got the recipient ID from the certificates !
-                        | CertifShareRealmError::RecipientNotFound
-                        // We got the realm ID from the certificates !
-                        | CertifShareRealmError::RealmNotFound
-                        // Unsharing is allowed on a revoked user (and that's precisely what we are doing here !)
-                        | CertifShareRealmError::RecipientRevoked
-                        // Role is only given when sharing, not unsharing !
-                        | CertifShareRealmError::RoleIncompatibleWithOutsider
-                        // Keys bundle only needed when sharing, not unsharing !
-                        | CertifShareRealmError::NoKey
-                    ) => return Err(anyhow::anyhow!("Unexpected server response: {}", bad_rep).into()),
-                },
-            }
+        let users = revoked_users
+            .iter()
+            .chain(expired_users.iter())
+            .chain(ungrouped_users.iter())
+            .copied();
+        if !unshare_users(client, realm_id, users).await? {
+            return Ok(());
         }
 
         // Given the key rotation encrypt the keys bundle access for each member of the
@@ -174,22 +149,127 @@ async fn process_workspace_needs(
             .certificates_ops
             .rotate_realm_key_idempotent(realm_id, target_key_index)
             .await;
+        if let Err(err) = outcome {
+            return handle_rotate_realm_key_error(client, err);
+        }
+
+        needs = RealmNeeds::Nothing;
+    }
+
+    assert!(matches!(needs, RealmNeeds::Nothing));
+
+    // Members of the groups the realm is shared with must be given their role.
+    if !share_with_groups_members(client, realm_id).await? {
+        return Ok(());
+    }
+
+    // Finally, the realm keys bundle may be corrupted (or contain corrupted keys).
+    // Note a key rotation always heals the keys bundle, hence this is only needed
+    // if no key rotation has been done here.
+    if !key_rotation_needed {
+        let outcome = client
+            .certificates_ops
+            .attempt_realm_keys_bundle_healing(realm_id)
+            .await;
+        if let Err(err) = outcome {
+            return handle_rotate_realm_key_error(client, err);
+        }
+    }
+
+    // Re-encryption of the realm's data is opt-in (see `Client::rekey_workspace`),
+    // but once started it must be resumed if interrupted, and done again on each
+    // new key rotation.
+    resume_realm_rekey(client, realm_id).await
+}
+
+async fn resume_realm_rekey(
+    client: &Client,
+    realm_id: VlobID,
+) -> Result<(), ClientProcessWorkspacesNeedsError> {
+    let pending = client
+        .certificates_ops
+        .is_realm_rekey_pending(realm_id)
+        .await
+        .map_err(|err| match err {
+            CertifGetRealmRekeyProgressError::Stopped => ClientProcessWorkspacesNeedsError::Stopped,
+            CertifGetRealmRekeyProgressError::Internal(err) => err.into(),
+        })?;
+    if !pending {
+        return Ok(());
+    }
+
+    let outcome = client.certificates_ops.rekey_realm(realm_id).await;
+    match outcome {
+        Ok(()) => Ok(()),
+        Err(err) => match err {
+            CertifRekeyRealmError::Stopped => Err(ClientProcessWorkspacesNeedsError::Stopped),
+            CertifRekeyRealmError::Offline(e) => Err(ClientProcessWorkspacesNeedsError::Offline(e)),
+            // A concurrent operation has changed our rights to the workspace,
+            // hence we can no longer process its needs !
+            CertifRekeyRealmError::AuthorNotAllowed | CertifRekeyRealmError::UnknownRealm => Ok(()),
+            // Workspace has been deleted or archived, nothing to process
+            CertifRekeyRealmError::RealmDeleted | CertifRekeyRealmError::RealmArchived => Ok(()),
+            // The job will be resumed on the next call
+            CertifRekeyRealmError::ServerBlockstoreUnavailable => Ok(()),
+            // The keys bundle is corrupted, it is healed first by a key rotation
+            CertifRekeyRealmError::InvalidKeysBundle(_) => Ok(()),
+            CertifRekeyRealmError::TimestampOutOfBallpark {
+                server_timestamp,
+                client_timestamp,
+                ballpark_client_early_offset,
+                ballpark_client_late_offset,
+            } => {
+                let event = EventTooMuchDriftWithServerClock {
+                    server_timestamp,
+                    ballpark_client_early_offset,
+                    ballpark_client_late_offset,
+                    client_timestamp,
+                };
+                client.event_bus.send(&event);
+
+                Err(ClientProcessWorkspacesNeedsError::TimestampOutOfBallpark {
+                    server_timestamp,
+                    client_timestamp,
+                    ballpark_client_early_offset,
+                    ballpark_client_late_offset,
+                })
+            }
+            CertifRekeyRealmError::InvalidCertificate(err) => {
+                Err(ClientProcessWorkspacesNeedsError::InvalidCertificate(err))
+            }
+            CertifRekeyRealmError::ClockDrift(err) => {
+                Err(ClientProcessWorkspacesNeedsError::ClockDrift(err))
+            }
+            CertifRekeyRealmError::Internal(err) => Err(err.into()),
+        },
+    }
+}
+
+/// Returns `false` if the unsharing cannot be done due to a concurrent operation
+/// (i.e. the realm needs can no longer be processed).
+async fn unshare_users(
+    client: &Client,
+    realm_id: VlobID,
+    users: impl Iterator<Item = UserID>,
+) -> Result<bool, ClientProcessWorkspacesNeedsError> {
+    for user in users {
+        let outcome = client
+            .certificates_ops
+            .share_realm(realm_id, user, None)
+            .await;
         match outcome {
             Ok(_) => (),
             Err(err) => match err {
                 // Valid errors
-                CertifRotateRealmKeyError::Offline(e) => {
-                    return Err(ClientProcessWorkspacesNeedsError::Offline(e))
-                }
-                CertifRotateRealmKeyError::Stopped => {
-                    return Err(ClientProcessWorkspacesNeedsError::Stopped)
-                }
+
+                CertifShareRealmError::Stopped => return Err(ClientProcessWorkspacesNeedsError::Stopped),
+                CertifShareRealmError::Offline(e) => return Err(ClientProcessWorkspacesNeedsError::Offline(e)),
                 // A concurrent operation has changed our rights to the workspace,
                 // hence we can no longer process its needs !
-                CertifRotateRealmKeyError::AuthorNotAllowed => return Ok(()),
+                CertifShareRealmError::AuthorNotAllowed => return Ok(false),
                 // Workspace has been deleted, nothing to process
-                CertifRotateRealmKeyError::RealmDeleted => return Ok(()),
-                CertifRotateRealmKeyError::TimestampOutOfBallpark {
+                CertifShareRealmError::RealmDeleted => return Ok(false),
+                CertifShareRealmError::TimestampOutOfBallpark {
                     server_timestamp,
                     client_timestamp,
                     ballpark_client_early_offset,
@@ -210,27 +290,203 @@ async fn process_workspace_needs(
                         ballpark_client_late_offset,
                     });
                 }
-                CertifRotateRealmKeyError::InvalidCertificate(err) => {
-                    return Err(ClientProcessWorkspacesNeedsError::InvalidCertificate(err))
+                CertifShareRealmError::InvalidCertificate(err) => return Err(ClientProcessWorkspacesNeedsError::InvalidCertificate(err)),
+                CertifShareRealmError::ClockDrift(err) => return Err(ClientProcessWorkspacesNeedsError::ClockDrift(err)),
+                CertifShareRealmError::Internal(err) => return Err(err.into()),
+
+                // Invalid errors
+
+                bad_rep @ (
+                    // If we were revoked, we wouldn't be here anyway !
+                    CertifShareRealmError::RecipientIsSelf
+                    // We got the recipient ID from the certificates !
+                    | CertifShareRealmError::RecipientNotFound
+                    // We got the realm ID from the certificates !
+                    | CertifShareRealmError::RealmNotFound
+                    // Unsharing is allowed on a revoked user (and that's precisely what we are doing here !)
+                    | CertifShareRealmError::RecipientRevoked
+                    // Role is only given when sharing, not unsharing !
+                    | CertifShareRealmError::RoleIncompatibleWithOutsider
+                    // Keys bundle only needed when sharing, not unsharing !
+                    | CertifShareRealmError::NoKey
+                    | CertifShareRealmError::InvalidKeysBundle(_)
+                ) => return Err(anyhow::anyhow!("Unexpected server response: {}", bad_rep).into()),
+            },
+        }
+
+        // The user is no longer part of the workspace, so its share expiration
+        // (if any) is obsolete.
+        client
+            .certificates_ops
+            .set_realm_share_expiration(realm_id, user, None)
+            .await
+            .map_err(|err| match err {
+                CertifSetRealmShareExpirationError::Stopped => {
+                    ClientProcessWorkspacesNeedsError::Stopped
                 }
-                CertifRotateRealmKeyError::CurrentKeysBundleCorrupted(_) => {
-                    // TODO: handle self-healing here !
-                    return Ok(());
+                CertifSetRealmShareExpirationError::Internal(err) => err.into(),
+                // Only possible when setting an expiration, not removing it !
+                CertifSetRealmShareExpirationError::UserNotInRealm => unreachable!(),
+            })?;
+    }
+
+    Ok(true)
+}
+
+/// Returns `false` if the sharing cannot be done due to a concurrent operation
+/// (i.e. the realm needs can no longer be processed).
+async fn share_with_groups_members(
+    client: &Client,
+    realm_id: VlobID,
+) -> Result<bool, ClientProcessWorkspacesNeedsError> {
+    let shares = client
+        .certificates_ops
+        .get_realm_group_shares_needs(realm_id)
+        .await
+        .map_err(|err| match err {
+            CertifGetRealmNeedsError::Stopped => ClientProcessWorkspacesNeedsError::Stopped,
+            CertifGetRealmNeedsError::Internal(err) => err.into(),
+        })?;
+
+    for (user, role, group_id) in shares {
+        let outcome = client
+            .certificates_ops
+            .share_realm_through_group(realm_id, user, role, group_id)
+            .await;
+        match outcome {
+            Ok(_) => (),
+            Err(err) => match err {
+                // Valid errors
+
+                CertifShareRealmError::Stopped => return Err(ClientProcessWorkspacesNeedsError::Stopped),
+                CertifShareRealmError::Offline(e) => return Err(ClientProcessWorkspacesNeedsError::Offline(e)),
+                // A concurrent operation has changed our rights to the workspace,
+                // hence we can no longer process its needs !
+                CertifShareRealmError::AuthorNotAllowed => return Ok(false),
+                // Workspace has been deleted, nothing to process
+                CertifShareRealmError::RealmDeleted => return Ok(false),
+                // The workspace is not bootstrapped yet, or its keys bundle is
+                // corrupted: in both cases this will be handled later on.
+                CertifShareRealmError::NoKey
+                | CertifShareRealmError::InvalidKeysBundle(_) => return Ok(false),
+                // A concurrent operation has changed the user, it will be handled
+                // once we get the corresponding certificate.
+                CertifShareRealmError::RecipientRevoked
+                | CertifShareRealmError::RoleIncompatibleWithOutsider => continue,
+                CertifShareRealmError::TimestampOutOfBallpark {
+                    server_timestamp,
+                    client_timestamp,
+                    ballpark_client_early_offset,
+                    ballpark_client_late_offset,
+                } => {
+                    let event = EventTooMuchDriftWithServerClock {
+                        server_timestamp,
+                        ballpark_client_early_offset,
+                        ballpark_client_late_offset,
+                        client_timestamp,
+                    };
+                    client.event_bus.send(&event);
+
+                    return Err(ClientProcessWorkspacesNeedsError::TimestampOutOfBallpark {
+                        server_timestamp,
+                        client_timestamp,
+                        ballpark_client_early_offset,
+                        ballpark_client_late_offset,
+                    });
                 }
-                CertifRotateRealmKeyError::Internal(err) => return Err(err.into()),
+                CertifShareRealmError::InvalidCertificate(err) => return Err(ClientProcessWorkspacesNeedsError::InvalidCertificate(err)),
+                CertifShareRealmError::ClockDrift(err) => return Err(ClientProcessWorkspacesNeedsError::ClockDrift(err)),
+                CertifShareRealmError::Internal(err) => return Err(err.into()),
 
                 // Invalid errors
 
-                // We got the realm ID from the certificates !
-                bad_rep @ CertifRotateRealmKeyError::UnknownRealm => {
-                    return Err(anyhow::anyhow!("Unexpected server response: {}", bad_rep).into())
-                }
+                bad_rep @ (
+                    // We never share with ourself
+                    CertifShareRealmError::RecipientIsSelf
+                    // We got the recipient ID from the certificates !
+                    | CertifShareRealmError::RecipientNotFound
+                    // We got the realm ID from the certificates !
+                    | CertifShareRealmError::RealmNotFound
+                ) => return Err(anyhow::anyhow!("Unexpected server response: {}", bad_rep).into()),
             },
         }
+    }
 
-        needs = RealmNeeds::Nothing;
+    Ok(true)
+}
+
+/// Unlike `process_workspace_needs`, only unshare the users whose share has expired
+/// (typically used when our role doesn't allow us to do a key rotation).
+async fn process_workspace_expired_shares(
+    client: &Client,
+    realm_id: VlobID,
+) -> Result<(), ClientProcessWorkspacesNeedsError> {
+    let needs = client
+        .certificates_ops
+        .get_realm_needs(realm_id)
+        .await
+        .map_err(|err| match err {
+            CertifGetRealmNeedsError::Stopped => ClientProcessWorkspacesNeedsError::Stopped,
+            CertifGetRealmNeedsError::Internal(err) => err.into(),
+        })?;
+
+    if let RealmNeeds::UnshareThenKeyRotation { expired_users, .. } = needs {
+        unshare_users(client, realm_id, expired_users.into_iter()).await?;
     }
 
-    assert!(matches!(needs, RealmNeeds::Nothing));
     Ok(())
 }
+
+fn handle_rotate_realm_key_error(
+    client: &Client,
+    err: CertifRotateRealmKeyError,
+) -> Result<(), ClientProcessWorkspacesNeedsError> {
+    match err {
+        // Valid errors
+        CertifRotateRealmKeyError::Offline(e) => Err(ClientProcessWorkspacesNeedsError::Offline(e)),
+        CertifRotateRealmKeyError::Stopped => Err(ClientProcessWorkspacesNeedsError::Stopped),
+        // A concurrent operation has changed our rights to the workspace,
+        // hence we can no longer process its needs !
+        CertifRotateRealmKeyError::AuthorNotAllowed => Ok(()),
+        // Workspace has been deleted, nothing to process
+        CertifRotateRealmKeyError::RealmDeleted => Ok(()),
+        CertifRotateRealmKeyError::TimestampOutOfBallpark {
+            server_timestamp,
+            client_timestamp,
+            ballpark_client_early_offset,
+            ballpark_client_late_offset,
+        } => {
+            let event = EventTooMuchDriftWithServerClock {
+                server_timestamp,
+                ballpark_client_early_offset,
+                ballpark_client_late_offset,
+                client_timestamp,
+            };
+            client.event_bus.send(&event);
+
+            Err(ClientProcessWorkspacesNeedsError::TimestampOutOfBallpark {
+                server_timestamp,
+                client_timestamp,
+                ballpark_client_early_offset,
+                ballpark_client_late_offset,
+            })
+        }
+        CertifRotateRealmKeyError::InvalidCertificate(err) => {
+            Err(ClientProcessWorkspacesNeedsError::InvalidCertificate(err))
+        }
+        // Self-healing has already been attempted (and the corresponding event
+        // fired), there is nothing more we can do.
+        CertifRotateRealmKeyError::CurrentKeysBundleCorruptedAndUnrecoverable(_) => Ok(()),
+        CertifRotateRealmKeyError::ClockDrift(err) => {
+            Err(ClientProcessWorkspacesNeedsError::ClockDrift(err))
+        }
+        CertifRotateRealmKeyError::Internal(err) => Err(err.into()),
+
+        // Invalid errors
+
+        // We got the realm ID from the certificates !
+        bad_rep @ CertifRotateRealmKeyError::UnknownRealm => {
+            Err(anyhow::anyhow!("Unexpected server response: {}", bad_rep).into())
+        }
+    }
+}
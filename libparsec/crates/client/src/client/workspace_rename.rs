@@ -8,8 +8,8 @@ use super::Client;
 use crate::{
     certif::{
         CertifBootstrapWorkspaceError, CertifPollServerError, CertifRenameRealmError,
-        CertificateBasedActionOutcome, InvalidCertificateError, InvalidEncryptedRealmNameError,
-        InvalidKeysBundleError,
+        CertificateBasedActionOutcome, ClockDriftError, InvalidCertificateError,
+        InvalidEncryptedRealmNameError, InvalidKeysBundleError,
     },
     ClientRefreshWorkspacesListError,
 };
@@ -42,6 +42,8 @@ pub enum ClientRenameWorkspaceError {
     #[error(transparent)]
     InvalidEncryptedRealmName(#[from] Box<InvalidEncryptedRealmNameError>),
     #[error(transparent)]
+    ClockDrift(#[from] ClockDriftError),
+    #[error(transparent)]
     Internal(#[from] anyhow::Error),
 }
 
@@ -94,6 +96,9 @@ pub async fn rename_workspace(
             CertifBootstrapWorkspaceError::InvalidCertificate(err) => {
                 ClientRenameWorkspaceError::InvalidCertificate(err)
             }
+            CertifBootstrapWorkspaceError::ClockDrift(err) => {
+                ClientRenameWorkspaceError::ClockDrift(err)
+            }
             CertifBootstrapWorkspaceError::Internal(err) => err
                 .context("Cannot ensure workspace is bootstrapped")
                 .into(),
@@ -183,6 +188,9 @@ pub async fn rename_workspace(
                 CertifRenameRealmError::InvalidCertificate(err) => {
                     ClientRenameWorkspaceError::InvalidCertificate(err)
                 }
+                CertifRenameRealmError::ClockDrift(err) => {
+                    ClientRenameWorkspaceError::ClockDrift(err)
+                }
                 CertifRenameRealmError::Internal(err) => err.context("Cannot rename realm").into(),
             })?;
 
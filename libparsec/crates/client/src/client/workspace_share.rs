@@ -6,8 +6,9 @@ use libparsec_types::prelude::*;
 
 use super::Client;
 use crate::certif::{
-    CertifBootstrapWorkspaceError, CertifPollServerError, CertifShareRealmError,
-    CertificateBasedActionOutcome, InvalidCertificateError, InvalidKeysBundleError,
+    CertifBootstrapWorkspaceError, CertifPollServerError, CertifSetRealmShareExpirationError,
+    CertifShareRealmError, CertificateBasedActionOutcome, ClockDriftError, InvalidCertificateError,
+    InvalidKeysBundleError,
 };
 
 #[derive(Debug, thiserror::Error)]
@@ -42,6 +43,8 @@ pub enum ClientShareWorkspaceError {
     #[error(transparent)]
     InvalidCertificate(#[from] Box<InvalidCertificateError>),
     #[error(transparent)]
+    ClockDrift(#[from] ClockDriftError),
+    #[error(transparent)]
     Internal(#[from] anyhow::Error),
 }
 
@@ -50,6 +53,7 @@ pub async fn share_workspace(
     realm_id: VlobID,
     recipient: UserID,
     role: Option<RealmRole>,
+    expires_on: Option<DateTime>,
 ) -> Result<(), ClientShareWorkspaceError> {
     // 0) Quick check to filter out invalid realm ID.
     // This is useful given otherwise the next step will create a realm with
@@ -102,6 +106,9 @@ pub async fn share_workspace(
             CertifBootstrapWorkspaceError::InvalidCertificate(err) => {
                 return Err(ClientShareWorkspaceError::InvalidCertificate(err))
             }
+            CertifBootstrapWorkspaceError::ClockDrift(err) => {
+                return Err(ClientShareWorkspaceError::ClockDrift(err))
+            }
             CertifBootstrapWorkspaceError::Internal(err) => {
                 return Err(err
                     .context("Cannot ensure workspace is bootstrapped")
@@ -166,34 +173,58 @@ pub async fn share_workspace(
             },
             CertifShareRealmError::InvalidKeysBundle(err) => ClientShareWorkspaceError::InvalidKeysBundle(err),
             CertifShareRealmError::InvalidCertificate(err) => ClientShareWorkspaceError::InvalidCertificate(err),
+            CertifShareRealmError::ClockDrift(err) => ClientShareWorkspaceError::ClockDrift(err),
             bad_rep @ CertifShareRealmError::NoKey => anyhow::anyhow!("Unexpected server response: {} while we've just made sure the realm was bootstrapped", bad_rep).into(),
             CertifShareRealmError::Internal(err) => err.into(),
         })?;
 
     // 3) Poll the server to fetch back the new realm role certificate
 
-    let latest_known_timestamps = match outcome {
-        CertificateBasedActionOutcome::LocalIdempotent => return Ok(()),
+    match outcome {
+        CertificateBasedActionOutcome::LocalIdempotent => (),
         CertificateBasedActionOutcome::Uploaded {
             certificate_timestamp,
         }
         | CertificateBasedActionOutcome::RemoteIdempotent {
             certificate_timestamp,
-        } => PerTopicLastTimestamps::new_for_realm(realm_id, certificate_timestamp),
-    };
+        } => {
+            let latest_known_timestamps =
+                PerTopicLastTimestamps::new_for_realm(realm_id, certificate_timestamp);
+            client
+                .certificates_ops
+                .poll_server_for_new_certificates(Some(&latest_known_timestamps))
+                .await
+                .map_err(|e| match e {
+                    CertifPollServerError::Stopped => ClientShareWorkspaceError::Stopped,
+                    CertifPollServerError::Offline(e) => ClientShareWorkspaceError::Offline(e),
+                    CertifPollServerError::InvalidCertificate(err) => {
+                        ClientShareWorkspaceError::InvalidCertificate(err)
+                    }
+                    CertifPollServerError::Internal(err) => err
+                        .context("Cannot poll server for new certificates")
+                        .into(),
+                })?;
+        }
+    }
+
+    // 4) Configure the share expiration (now that we know about the new realm
+    //    role certificate it is attached to)
+
+    let expires_on = if role.is_some() { expires_on } else { None };
     client
         .certificates_ops
-        .poll_server_for_new_certificates(Some(&latest_known_timestamps))
+        .set_realm_share_expiration(realm_id, recipient, expires_on)
         .await
         .map_err(|e| match e {
-            CertifPollServerError::Stopped => ClientShareWorkspaceError::Stopped,
-            CertifPollServerError::Offline(e) => ClientShareWorkspaceError::Offline(e),
-            CertifPollServerError::InvalidCertificate(err) => {
-                ClientShareWorkspaceError::InvalidCertificate(err)
+            CertifSetRealmShareExpirationError::Stopped => ClientShareWorkspaceError::Stopped,
+            // A concurrent operation has unshared the recipient in the meantime,
+            // hence there is no share to expire anymore.
+            CertifSetRealmShareExpirationError::UserNotInRealm => {
+                ClientShareWorkspaceError::RecipientNotFound
+            }
+            CertifSetRealmShareExpirationError::Internal(err) => {
+                err.context("Cannot configure share expiration").into()
             }
-            CertifPollServerError::Internal(err) => err
-                .context("Cannot poll server for new certificates")
-                .into(),
         })?;
 
     Ok(())
@@ -0,0 +1,132 @@
+// Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+use libparsec_client_connection::ConnectionError;
+use libparsec_types::prelude::*;
+
+use super::{workspace_share::share_workspace, Client, ClientShareWorkspaceError};
+use crate::certif::{
+    CertifGetCurrentSelfRealmRoleError, ClockDriftError, InvalidCertificateError,
+    InvalidKeysBundleError,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum ClientTransferWorkspaceOwnershipError {
+    #[error("Component has stopped")]
+    Stopped,
+    #[error("Cannot transfer ownership to oneself")]
+    NewOwnerIsSelf,
+    #[error("New owner user not found")]
+    NewOwnerNotFound,
+    #[error("Cannot transfer ownership to a revoked user")]
+    NewOwnerRevoked,
+    #[error("Cannot transfer ownership to a user with profile OUTSIDER")]
+    NewOwnerIsOutsider,
+    #[error("Workspace realm not found")]
+    WorkspaceNotFound,
+    #[error("The workspace's realm has been deleted on the server")]
+    RealmDeleted,
+    #[error("Author not allowed")]
+    AuthorNotAllowed,
+    #[error("Cannot communicate with the server: {0}")]
+    Offline(#[from] ConnectionError),
+    #[error("Our clock ({client_timestamp}) and the server's one ({server_timestamp}) are too far apart")]
+    TimestampOutOfBallpark {
+        server_timestamp: DateTime,
+        client_timestamp: DateTime,
+        ballpark_client_early_offset: f64,
+        ballpark_client_late_offset: f64,
+    },
+    #[error(transparent)]
+    InvalidKeysBundle(#[from] Box<InvalidKeysBundleError>),
+    #[error(transparent)]
+    InvalidCertificate(#[from] Box<InvalidCertificateError>),
+    #[error(transparent)]
+    ClockDrift(#[from] ClockDriftError),
+    #[error(transparent)]
+    Internal(#[from] anyhow::Error),
+}
+
+pub async fn transfer_workspace_ownership(
+    client: &Client,
+    realm_id: VlobID,
+    new_owner: UserID,
+) -> Result<(), ClientTransferWorkspaceOwnershipError> {
+    // 0) Only an OWNER can give the OWNER role, so check this early on to avoid
+    //    bootstrapping a workspace shared with us for nothing.
+    //
+    //    Note we cannot demote ourself afterward (a user cannot change its own
+    //    role), it's up to the new owner to do so if needed.
+
+    let self_role = client
+        .certificates_ops
+        .get_current_self_realm_role(realm_id)
+        .await
+        .map_err(|err| match err {
+            CertifGetCurrentSelfRealmRoleError::Stopped => {
+                ClientTransferWorkspaceOwnershipError::Stopped
+            }
+            CertifGetCurrentSelfRealmRoleError::Internal(err) => err.into(),
+        })?;
+    match self_role {
+        // Workspace not bootstrapped yet, the bootstrap will make us OWNER
+        None => (),
+        Some(Some(RealmRole::Owner)) => (),
+        Some(_) => return Err(ClientTransferWorkspaceOwnershipError::AuthorNotAllowed),
+    }
+
+    // 1) Give the OWNER role to the new owner
+    //
+    //    This also takes care of bootstrapping the workspace if needed, and of
+    //    removing any share expiration configured for the new owner.
+
+    share_workspace(client, realm_id, new_owner, Some(RealmRole::Owner), None)
+        .await
+        .map_err(|err| match err {
+            ClientShareWorkspaceError::Stopped => ClientTransferWorkspaceOwnershipError::Stopped,
+            ClientShareWorkspaceError::RecipientIsSelf => {
+                ClientTransferWorkspaceOwnershipError::NewOwnerIsSelf
+            }
+            ClientShareWorkspaceError::RecipientNotFound => {
+                ClientTransferWorkspaceOwnershipError::NewOwnerNotFound
+            }
+            ClientShareWorkspaceError::WorkspaceNotFound => {
+                ClientTransferWorkspaceOwnershipError::WorkspaceNotFound
+            }
+            ClientShareWorkspaceError::RealmDeleted => {
+                ClientTransferWorkspaceOwnershipError::RealmDeleted
+            }
+            ClientShareWorkspaceError::RecipientRevoked => {
+                ClientTransferWorkspaceOwnershipError::NewOwnerRevoked
+            }
+            ClientShareWorkspaceError::AuthorNotAllowed => {
+                ClientTransferWorkspaceOwnershipError::AuthorNotAllowed
+            }
+            ClientShareWorkspaceError::RoleIncompatibleWithOutsider => {
+                ClientTransferWorkspaceOwnershipError::NewOwnerIsOutsider
+            }
+            ClientShareWorkspaceError::Offline(e) => {
+                ClientTransferWorkspaceOwnershipError::Offline(e)
+            }
+            ClientShareWorkspaceError::TimestampOutOfBallpark {
+                server_timestamp,
+                client_timestamp,
+                ballpark_client_early_offset,
+                ballpark_client_late_offset,
+            } => ClientTransferWorkspaceOwnershipError::TimestampOutOfBallpark {
+                server_timestamp,
+                client_timestamp,
+                ballpark_client_early_offset,
+                ballpark_client_late_offset,
+            },
+            ClientShareWorkspaceError::InvalidKeysBundle(err) => {
+                ClientTransferWorkspaceOwnershipError::InvalidKeysBundle(err)
+            }
+            ClientShareWorkspaceError::InvalidCertificate(err) => {
+                ClientTransferWorkspaceOwnershipError::InvalidCertificate(err)
+            }
+            ClientShareWorkspaceError::ClockDrift(err) => {
+                ClientTransferWorkspaceOwnershipError::ClockDrift(err)
+            }
+            ClientShareWorkspaceError::Internal(err) => err.into(),
+        })
+}
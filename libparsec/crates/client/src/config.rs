@@ -3,6 +3,7 @@
 use std::path::PathBuf;
 
 pub use libparsec_client_connection::ProxyConfig;
+pub use libparsec_platform_pki::X509RevocationPolicy;
 use libparsec_types::prelude::*;
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -26,6 +27,86 @@ impl WorkspaceStorageCacheSize {
     }
 }
 
+/// Delays applied by the outbound sync monitor before uploading a modified entry.
+///
+/// An entry is only synchronized once it hasn't been modified for `quiescence`
+/// and is no longer opened for write. However it will never be delayed for more
+/// than `max_delay` since its first unsynchronized modification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OutboundSyncDelays {
+    pub quiescence: Duration,
+    pub max_delay: Duration,
+}
+
+impl Default for OutboundSyncDelays {
+    fn default() -> Self {
+        // Test delays are 10 times faster than production ones
+        if cfg!(test) {
+            Self {
+                quiescence: Duration::milliseconds(100),
+                max_delay: Duration::seconds(6),
+            }
+        } else {
+            Self {
+                quiescence: Duration::seconds(1),
+                max_delay: Duration::minutes(1),
+            }
+        }
+    }
+}
+
+/// Custom outbound sync delays for the entries whose name matches `pattern`
+/// (typically big files such as `*.pst` or `*.vmdk` that are modified in place
+/// for long periods of time).
+#[derive(Debug, Clone)]
+pub struct OutboundSyncDelaysOverride {
+    pub pattern: PreventSyncPattern,
+    pub delays: OutboundSyncDelays,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct OutboundSyncConfig {
+    pub delays: OutboundSyncDelays,
+    /// Overrides are checked in order, the first matching one is used.
+    pub overrides: Vec<OutboundSyncDelaysOverride>,
+    /// If `true`, the ID of an uploaded block is derived from its content (keyed
+    /// with the realm's key), so identical blocks within a realm are only stored
+    /// once on the server.
+    pub block_deduplication: bool,
+    /// If `true`, new blocks are compressed (zstd) before being encrypted and uploaded.
+    ///
+    /// Note clients older than Parsec v3.9 are not able to read compressed blocks,
+    /// so this should only be enabled once all the clients of the organization are
+    /// up to date.
+    pub block_compression: bool,
+    /// If `true`, the content of modified files is split into blocks using
+    /// content-defined chunking (see `libparsec_types::content_defined_chunk_size`)
+    /// instead of fixed-size blocks, so that inserting data in a file only modifies
+    /// the blocks around the insertion (which makes block deduplication effective).
+    ///
+    /// Note clients older than Parsec v3.9 are not able to read files whose blocks
+    /// are not aligned on the blocksize, so this should only be enabled once all the
+    /// clients of the organization are up to date. Also note a file already using
+    /// content-defined chunking keeps using it regardless of this setting.
+    pub content_defined_chunking: bool,
+}
+
+impl OutboundSyncConfig {
+    pub fn has_overrides(&self) -> bool {
+        !self.overrides.is_empty()
+    }
+
+    pub fn delays_for(&self, name: Option<&EntryName>) -> OutboundSyncDelays {
+        name.and_then(|name| {
+            self.overrides
+                .iter()
+                .find(|item| item.pattern.is_match(name.as_ref()))
+        })
+        .map(|item| item.delays)
+        .unwrap_or(self.delays)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum MountpointMountStrategy {
     Directory {
@@ -50,7 +131,12 @@ pub struct ClientConfig {
     pub workspace_storage_cache_size: WorkspaceStorageCacheSize,
     /// The pattern used to filter out files that should not be synced with the server like temporary files.
     pub prevent_sync_pattern: PreventSyncPattern,
+    /// Configure how long a modified entry should wait before being synchronized.
+    pub outbound_sync: OutboundSyncConfig,
     pub proxy: ProxyConfig,
+    /// How to handle an X509 certificate whose revocation status cannot be
+    /// determined during PKI-based operations (e.g. asynchronous enrollment).
+    pub pki_revocation_policy: X509RevocationPolicy,
     /// If `false`, nothing runs & react in the background, useful for tests
     /// or CLI where the client is started to only perform a single operation.
     pub with_monitors: bool,
@@ -62,6 +148,9 @@ pub struct ServerOrganizationConfig {
     pub active_users_limit: ActiveUsersLimit,
     /// In seconds
     pub realm_minimum_archiving_period_before_deletion: u64,
+    /// CRLs (PEM or DER) to check the X509 certificates against during PKI-based
+    /// operations (e.g. asynchronous enrollment).
+    pub pki_crls: Vec<Bytes>,
 }
 
 // It's easy to provide "good enough" default value so that the config is guaranteed
@@ -74,6 +163,12 @@ impl Default for ServerOrganizationConfig {
             user_profile_outsider_allowed: false,
             active_users_limit: ActiveUsersLimit::NoLimit,
             realm_minimum_archiving_period_before_deletion: 0,
+            pki_crls: vec![],
         }
     }
 }
+
+#[cfg(test)]
+#[path = "../tests/unit/config.rs"]
+#[allow(clippy::unwrap_used)]
+mod tests;
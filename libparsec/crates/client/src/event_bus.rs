@@ -333,6 +333,15 @@ impl_events!(
         ballpark_client_early_offset: Float,
         ballpark_client_late_offset: Float,
     },
+    /// Our clock is lagging too much behind the server's one.
+    ///
+    /// This event is fired when the server requires a timestamp (to keep the
+    /// certificates or vlob versions ordered) that is too far ahead of our clock
+    /// to be reached by waiting (see `certif::greater_timestamp`).
+    ClockDrift {
+        required_timestamp: DateTime,
+        client_timestamp: DateTime,
+    },
 
     // ***********************************************************************
     // Server configuration related events
@@ -349,6 +358,7 @@ impl_events!(
         active_users_limit: ActiveUsersLimit,
         user_profile_outsider_allowed: bool,
         realm_minimum_archiving_period_before_deletion: u64,  // In seconds
+        pki_crls: Vec<Bytes>,
     },
     /// This event is fired by the server config monitor.
     ///
@@ -383,9 +393,9 @@ impl_events!(
     /// This event is used by the workspaces refresh list monitor to trigger a refresh
     /// of the local workspaces list (i.e. the list of workspaces the client considers
     /// the user has access to)
-    // TODO: two types of new certificates events:
-    //       - poll from scratch
-    //       - per-type event: rename, key rotation, share/unshare with self, shamir for self, etc.
+    ///
+    /// Note dedicated events (e.g. `RealmRenamed`, `SelfShared`) are fired before
+    /// this one to detail what has changed, unless `storage_initially_empty` is true.
     NewCertificates {
         // The local certificates storage was empty, hence the server sent all certificates.
         storage_initially_empty: bool,
@@ -402,6 +412,83 @@ impl_events!(
     /// This event is fired by the certificate ops when unsuccessfully trying to
     /// integrate new certificates.
     InvalidCertificate(Box<crate::certif::InvalidCertificateError>),
+    /// This event is fired by the certificate ops when the last keys bundle of a
+    /// realm is corrupted, and none of the previous keys bundles our user has access
+    /// to can be used to heal it.
+    ///
+    /// In this case no key rotation can be done on the realm until another member
+    /// (with access to older keys bundles) heals it.
+    RealmKeysBundleCorruptedAndUnrecoverable { realm_id: VlobID },
+    /// This event is fired by the certificate ops while re-encrypting the data
+    /// of a realm with its last key.
+    ///
+    /// The re-encryption is done in multiple passes (as the realm may change in
+    /// the meantime), `processed` and `total` are the number of vlobs of the
+    /// current pass.
+    RealmRekeyProgress {
+        realm_id: VlobID,
+        target_key_index: IndexInt,
+        processed: u64,
+        total: u64,
+    },
+    /// This event is fired by the certificate ops once all the data of a realm
+    /// are encrypted with the target key.
+    RealmRekeyDone {
+        realm_id: VlobID,
+        target_key_index: IndexInt,
+    },
+    /// This event is fired by the certificate ops when integrating a user revoked
+    /// certificate.
+    ///
+    /// Note the dedicated certificates events (i.e. `UserRevoked`, `UserProfileUpdated`,
+    /// `DeviceAdded` etc.) are only fired for certificates obtained on top of
+    /// already existing ones: when the local storage was initially empty (or if
+    /// it had to be cleared due to a switch from/to redacted certificates), only
+    /// `NewCertificates` is fired.
+    /// In any case, the dedicated events are fired before the related `NewCertificates`.
+    UserRevoked { user_id: UserID },
+    /// This event is fired by the certificate ops when integrating a user update
+    /// certificate.
+    UserProfileUpdated {
+        user_id: UserID,
+        profile: UserProfile,
+    },
+    /// This event is fired by the certificate ops when integrating a device certificate.
+    DeviceAdded { user_id: UserID, device_id: DeviceID },
+    /// This event is fired by the certificate ops when integrating a revoked device
+    /// certificate.
+    DeviceRevoked { user_id: UserID, device_id: DeviceID },
+    /// This event is fired by the certificate ops when integrating a realm role
+    /// certificate (`None` role meaning no access to the realm).
+    RealmRoleChanged {
+        realm_id: VlobID,
+        user_id: UserID,
+        old_role: Option<RealmRole>,
+        new_role: Option<RealmRole>,
+    },
+    /// This event is fired by the certificate ops when integrating a realm name
+    /// certificate.
+    RealmRenamed { realm_id: VlobID },
+    /// This event is fired by the certificate ops when integrating a realm
+    /// archiving certificate.
+    RealmArchivingChanged { realm_id: VlobID },
+    /// This event is fired by the certificate ops when integrating a realm role
+    /// certificate giving our user access to a realm it previously had no access to.
+    ///
+    /// Note `RealmRoleChanged` is also fired for this certificate.
+    SelfShared { realm_id: VlobID, role: RealmRole },
+    /// This event is fired by the certificate ops when integrating a realm role
+    /// certificate removing our user's access to a realm.
+    ///
+    /// Note `RealmRoleChanged` is also fired for this certificate.
+    SelfUnshared { realm_id: VlobID },
+    /// This event is fired by the certificate ops when integrating a sequester
+    /// revoked service certificate.
+    SequesterServiceRevoked { service_id: SequesterServiceID },
+    /// This event is fired by the certificate ops when integrating a shamir recovery
+    /// brief or deletion certificate, `user_id` being the user whose recovery
+    /// setup has changed.
+    ShamirRecoveryChanged { user_id: UserID },
 
     // ***********************************************************************
     // Invitation related events
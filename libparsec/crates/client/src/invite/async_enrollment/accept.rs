@@ -5,7 +5,7 @@ use libparsec_platform_async::PinBoxFutureResult;
 use libparsec_types::prelude::*;
 
 use crate::{
-    greater_timestamp, utils::create_user_and_device_certificates, EventBus,
+    greater_timestamp, utils::create_user_and_device_certificates, ClockDriftError, EventBus,
     EventTooMuchDriftWithServerClock, GreaterTimestampOffset,
 };
 
@@ -44,6 +44,8 @@ pub enum AcceptAsyncEnrollmentError {
         ballpark_client_late_offset: f64,
     },
     #[error(transparent)]
+    ClockDrift(#[from] ClockDriftError),
+    #[error(transparent)]
     Internal(anyhow::Error),
 
     // OpenBao-related errors
@@ -194,7 +196,9 @@ pub(crate) async fn accept_async_enrollment(
                         &author.time_provider,
                         GreaterTimestampOffset::User,
                         strictly_greater_than,
-                    );
+                    )
+                    .await
+                    .inspect_err(|err| event_bus.send(&err.to_event()))?;
                     continue;
                 }
                 Rep::AuthorNotAllowed => Err(AcceptAsyncEnrollmentError::AuthorNotAllowed),
@@ -55,6 +55,10 @@ pub enum SubmitterFinalizeAsyncEnrollmentError {
         server: RemoteOperationServer,
         error: anyhow::Error,
     },
+    #[error(
+        "Cannot save device: the FIDO2 authenticator doesn't support the hmac-secret extension"
+    )]
+    SaveDeviceFido2HmacSecretNotSupported,
     #[error(transparent)]
     Internal(#[from] anyhow::Error),
 
@@ -316,6 +320,9 @@ pub async fn submitter_finalize_async_enrollment(
                 error,
             }
         }
+        SaveDeviceError::Fido2HmacSecretNotSupported => {
+            SubmitterFinalizeAsyncEnrollmentError::SaveDeviceFido2HmacSecretNotSupported
+        }
         SaveDeviceError::Internal(err) => SubmitterFinalizeAsyncEnrollmentError::Internal(err),
     })?;
 
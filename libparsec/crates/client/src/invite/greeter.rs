@@ -14,7 +14,8 @@ use libparsec_types::prelude::*;
 use crate::invite::common::{Throttle, WAIT_PEER_MAX_ATTEMPTS};
 use crate::utils::create_user_and_device_certificates;
 use crate::{
-    greater_timestamp, EventBus, EventTooMuchDriftWithServerClock, GreaterTimestampOffset,
+    greater_timestamp, ClockDriftError, EventBus, EventTooMuchDriftWithServerClock,
+    GreaterTimestampOffset,
 };
 
 /*
@@ -283,6 +284,8 @@ pub enum GreetInProgressError {
     #[error("Operation cancelled")]
     Cancelled,
     #[error(transparent)]
+    ClockDrift(#[from] ClockDriftError),
+    #[error(transparent)]
     Internal(#[from] anyhow::Error),
 }
 
@@ -1385,7 +1388,9 @@ impl UserGreetInProgress4Ctx {
                             &self.device.time_provider,
                             GreaterTimestampOffset::User,
                             strictly_greater_than,
-                        );
+                        )
+                        .await
+                        .inspect_err(|err| self.event_bus.send(&err.to_event()))?;
                         continue;
                     }
                     Rep::ActiveUsersLimitReached => {
@@ -1536,7 +1541,9 @@ impl DeviceGreetInProgress4Ctx {
                             &self.device.time_provider,
                             GreaterTimestampOffset::User,
                             strictly_greater_than,
-                        );
+                        )
+                        .await
+                        .inspect_err(|err| self.event_bus.send(&err.to_event()))?;
                         continue;
                     }
                     Rep::DeviceAlreadyExists => Err(GreetInProgressError::DeviceAlreadyExists),
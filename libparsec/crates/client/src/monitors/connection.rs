@@ -70,6 +70,7 @@ fn dispatch_api_event(event: APIEvent, event_bus: &EventBus) {
             active_users_limit,
             user_profile_outsider_allowed,
             realm_minimum_archiving_period_before_deletion,
+            pki_crls,
             sse_keepalive_seconds: _,
         } => {
             let event = EventServerConfigNotified {
@@ -79,6 +80,7 @@ fn dispatch_api_event(event: APIEvent, event_bus: &EventBus) {
                     realm_minimum_archiving_period_before_deletion
                         .unwrap_or(0)
                         .max(0) as u64,
+                pki_crls: pki_crls.unwrap_or_default(),
             };
             event_bus.send(&event);
         }
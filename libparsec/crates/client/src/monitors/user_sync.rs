@@ -115,7 +115,8 @@ fn task_future_factory(user_ops: Arc<UserOps>, event_bus: EventBus) -> impl Futu
                             log::warn!("Invalid certificate detected: {error}");
                             break;
                         }
-                        error @ UserSyncError::TimestampOutOfBallpark { .. } => {
+                        error @ (UserSyncError::TimestampOutOfBallpark { .. }
+                        | UserSyncError::ClockDrift(_)) => {
                             // Note `UserOps` is responsible for sending the
                             // bad timestamp event on the event bus
                             log::warn!("Client/server clock drift detected: {error:?}");
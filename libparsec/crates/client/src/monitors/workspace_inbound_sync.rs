@@ -331,6 +331,7 @@ async fn inbound_sync_monitor_loop(realm_id: VlobID, mut io: impl InboundSyncMan
                                 | WorkspaceSyncError::InvalidCertificate(_)
                                 | WorkspaceSyncError::Internal(_)
                                 | WorkspaceSyncError::TimestampOutOfBallpark { .. }
+                                | WorkspaceSyncError::ClockDrift(_)
                             ) => {
                                 log::error!("Workspace {realm_id}: stopping due to unexpected error: {err:?}");
                                 return;
@@ -448,6 +449,7 @@ async fn inbound_sync_monitor_loop(realm_id: VlobID, mut io: impl InboundSyncMan
                             | WorkspaceSyncError::InvalidKeysBundle(_)
                             | WorkspaceSyncError::InvalidCertificate(_)
                             | WorkspaceSyncError::TimestampOutOfBallpark { .. }
+                            | WorkspaceSyncError::ClockDrift(_)
                         )
                         => {
                             log::error!("Workspace {realm_id}: stopping due to unexpected error: {err:?}");
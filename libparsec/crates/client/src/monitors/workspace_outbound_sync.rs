@@ -14,6 +14,7 @@ use libparsec_types::prelude::*;
 
 use super::Monitor;
 use crate::{
+    config::{OutboundSyncConfig, OutboundSyncDelays},
     event_bus::{EventBus, EventMonitorCrashed, EventWorkspaceOpsOutboundSyncNeeded},
     workspace::{
         InboundSyncOutcome, OutboundSyncOutcome, WorkspaceGetNeedOutboundSyncEntriesError,
@@ -25,17 +26,10 @@ use crate::{
 const WORKSPACE_OUTBOUND_SYNC_MONITOR_NAME: &str = "workspace_outbound_sync";
 
 // Test constants are 10 times faster than production ones
-#[cfg(test)]
-const MIN_SYNC_WAIT: Duration = Duration::milliseconds(100);
-#[cfg(test)]
-const MAX_SYNC_WAIT: Duration = Duration::seconds(6);
+// (note the sync delays are provided by `ClientConfig::outbound_sync`)
 #[cfg(test)]
 const SERVER_STORE_UNAVAILABLE_WAIT: Duration = Duration::seconds(6);
 
-#[cfg(not(test))]
-const MIN_SYNC_WAIT: Duration = Duration::seconds(1);
-#[cfg(not(test))]
-const MAX_SYNC_WAIT: Duration = Duration::minutes(1);
 #[cfg(not(test))]
 const SERVER_STORE_UNAVAILABLE_WAIT: Duration = Duration::minutes(1);
 
@@ -161,6 +155,34 @@ impl ConfinedEntriesTracker {
     }
 }
 
+/// Retrieve the delays to apply to the entry before syncing it.
+///
+/// This requires the entry's name only if the config contains per-pattern overrides.
+/// The name is only looked up in local data (so this never waits for the server),
+/// and any error occurring while retrieving it makes us fall back to the default delays.
+async fn get_entry_delays(
+    workspace_ops: &WorkspaceOps,
+    config: &OutboundSyncConfig,
+    entry_id: VlobID,
+) -> OutboundSyncDelays {
+    if !config.has_overrides() {
+        return config.delays;
+    }
+    match workspace_ops
+        .get_entry_name_for_outbound_sync(entry_id)
+        .await
+    {
+        Ok(name) => config.delays_for(name.as_ref()),
+        Err(err) => {
+            log::debug!(
+                "Workspace {}: cannot retrieve {entry_id} name, using default sync delays: {err:?}",
+                workspace_ops.realm_id()
+            );
+            config.delays
+        }
+    }
+}
+
 #[derive(Debug)]
 enum IncomingEvent {
     OutboundSyncNeeded { entry_id: VlobID },
@@ -265,6 +287,7 @@ fn task_future_factory(
                                 | WorkspaceSyncError::InvalidKeysBundle(_)
                                 | WorkspaceSyncError::InvalidCertificate(_)
                                 | WorkspaceSyncError::TimestampOutOfBallpark { .. }
+                                | WorkspaceSyncError::ClockDrift(_)
                             )
                             => {
                                 log::error!("Workspace {realm_id}: stopping due to unexpected error: {err:?}");
@@ -399,21 +422,34 @@ fn task_future_factory(
             }
         });
 
+        let config = workspace_ops.config().outbound_sync.clone();
+
         struct DueTime {
             since: DateTime,
             due_time: DateTime,
+            delays: OutboundSyncDelays,
         }
         let (mut to_sync, mut due_time) = {
             let since = device.now();
-            let due_time = since + MIN_SYNC_WAIT;
 
             let outcome = workspace_ops.get_need_outbound_sync(u32::MAX).await;
             log::debug!("Workspace {realm_id}: get need outbound sync, outcome: {outcome:?}");
             let to_sync = match outcome {
-                Ok(to_sync) => to_sync
-                    .into_iter()
-                    .map(|entry_id| (entry_id, DueTime { since, due_time }))
-                    .collect::<HashMap<VlobID, DueTime>>(),
+                Ok(entries) => {
+                    let mut to_sync = HashMap::with_capacity(entries.len());
+                    for entry_id in entries {
+                        let delays = get_entry_delays(&workspace_ops, &config, entry_id).await;
+                        to_sync.insert(
+                            entry_id,
+                            DueTime {
+                                since,
+                                due_time: since + delays.quiescence,
+                                delays,
+                            },
+                        );
+                    }
+                    to_sync
+                }
                 Err(err) => {
                     match err {
                         WorkspaceGetNeedOutboundSyncEntriesError::Stopped => {
@@ -433,11 +469,7 @@ fn task_future_factory(
                 }
             };
 
-            let due_time = if to_sync.is_empty() {
-                None
-            } else {
-                Some(due_time)
-            };
+            let due_time = to_sync.values().map(|time| time.due_time).min();
             (to_sync, due_time)
         };
 
@@ -500,16 +532,30 @@ fn task_future_factory(
                 Action::DueTimeReached => {
                     let now = device.now();
 
+                    // A file still opened for write is likely to be modified again soon
+                    // (e.g. an editor or a compiler rewriting it), so we postpone its sync
+                    // as long as its maximum delay allows it.
+                    //
+                    // Note this check is done on each due entry, so that a file kept opened
+                    // doesn't prevent the other due entries from being synced.
                     let due = to_sync
-                        .iter()
-                        .filter_map(|(entry_id, time)| {
-                            if time.due_time < now {
-                                Some(*entry_id)
-                            } else {
-                                None
+                        .iter_mut()
+                        .filter(|(_, time)| time.due_time < now)
+                        .find_map(|(entry_id, time)| {
+                            if !workspace_ops.is_file_opened_for_write(*entry_id) {
+                                return Some(*entry_id);
                             }
-                        })
-                        .next();
+                            let max_due_time = time.since + time.delays.max_delay;
+                            if max_due_time <= now {
+                                return Some(*entry_id);
+                            }
+                            log::debug!(
+                                "Workspace {realm_id}: {entry_id} is opened for write, postponing its sync"
+                            );
+                            time.due_time =
+                                std::cmp::min(now + time.delays.quiescence, max_due_time);
+                            None
+                        });
                     if let Some(entry_id) = due {
                         log::debug!(
                             "Workspace {realm_id}: sending {entry_id} for sub-task to sync"
@@ -549,21 +595,27 @@ fn task_future_factory(
                             .expect("Mutex is poisoned")
                             .get_confined_entries_after_local_change(entry_id),
                     );
-                    let now = device.now();
                     for entry_id in entries_to_schedule {
-                        let potential_due_time = match to_sync.entry(entry_id) {
-                            std::collections::hash_map::Entry::Vacant(entry) => {
-                                let due_time = now + MIN_SYNC_WAIT;
-                                entry.insert(DueTime {
-                                    since: now,
-                                    due_time,
-                                });
+                        let potential_due_time = match to_sync.get_mut(&entry_id) {
+                            None => {
+                                let delays =
+                                    get_entry_delays(&workspace_ops, &config, entry_id).await;
+                                // Retrieving the delays may have taken some time
+                                let now = device.now();
+                                let due_time = now + delays.quiescence;
+                                to_sync.insert(
+                                    entry_id,
+                                    DueTime {
+                                        since: now,
+                                        due_time,
+                                        delays,
+                                    },
+                                );
                                 due_time
                             }
-                            std::collections::hash_map::Entry::Occupied(mut entry) => {
-                                let e = entry.get_mut();
-                                let new_due_time = now + MIN_SYNC_WAIT;
-                                if new_due_time - e.since < MAX_SYNC_WAIT {
+                            Some(e) => {
+                                let new_due_time = device.now() + e.delays.quiescence;
+                                if new_due_time - e.since < e.delays.max_delay {
                                     e.due_time = new_due_time;
                                 }
                                 e.due_time
@@ -584,3 +636,7 @@ fn task_future_factory(
 
     (task_future, stop_cb)
 }
+
+#[cfg(test)]
+#[path = "../../tests/unit/workspace_outbound_sync_monitor.rs"]
+mod tests;
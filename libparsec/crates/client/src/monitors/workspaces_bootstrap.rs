@@ -84,9 +84,10 @@ fn task_future_factory(event_bus: EventBus, client: Arc<Client>) -> impl Future<
                         return;
                     }
                     Err(
-                        error @ ClientEnsureWorkspacesBootstrappedError::TimestampOutOfBallpark {
+                        error @ (ClientEnsureWorkspacesBootstrappedError::TimestampOutOfBallpark {
                             ..
-                        },
+                        }
+                        | ClientEnsureWorkspacesBootstrappedError::ClockDrift(_)),
                     ) => {
                         // Note ops components are responsible for sending the
                         // bad timestamp event on the event bus
@@ -2,7 +2,8 @@
 
 use std::{future::Future, sync::Arc};
 
-use libparsec_platform_async::{channel, pretend_future_is_send_on_web};
+use libparsec_platform_async::{channel, pretend_future_is_send_on_web, select2_biased};
+use libparsec_types::prelude::*;
 
 use super::Monitor;
 use crate::{
@@ -34,6 +35,7 @@ fn task_future_factory(event_bus: EventBus, client: Arc<Client>) -> impl Future<
     enum Action {
         WorkspaceChanged,
         MissedServerEvents,
+        ShareExpired,
     }
     // Channel starts empty, hence the monitor will stay idle until the connection
     // monitor triggers its initial `EventMissedServerEvents` event
@@ -63,21 +65,35 @@ fn task_future_factory(event_bus: EventBus, client: Arc<Client>) -> impl Future<
     async move {
         let _events_connection_lifetime = events_connection_lifetime;
 
+        // Share expirations are not related to any event, so we must wake up
+        // by ourself once the next one is due.
+        let mut next_share_expiration = None;
+
         loop {
-            let action = match rx.recv_async().await {
-                Ok(action) => action,
-                // Sender has left, time to shutdown !
-                Err(_) => return,
+            let to_sleep = match next_share_expiration {
+                None => Duration::MAX,
+                Some(expires_on) => expires_on - client.device.now(),
             };
+            let action = select2_biased!(
+                outcome = rx.recv_async() => match outcome {
+                    Ok(action) => action,
+                    // Sender has left, time to shutdown !
+                    Err(_) => return,
+                },
+                _ = client.device.time_provider.sleep(to_sleep) => Action::ShareExpired,
+            );
 
             loop {
                 let outcome = match action {
-                    Action::MissedServerEvents | Action::WorkspaceChanged => {
-                        client.process_workspaces_needs().await
-                    }
+                    Action::MissedServerEvents
+                    | Action::WorkspaceChanged
+                    | Action::ShareExpired => client.process_workspaces_needs().await,
                 };
                 match outcome {
-                    Ok(_) => break,
+                    Ok(next) => {
+                        next_share_expiration = next;
+                        break;
+                    }
                     Err(err) => match err {
                         ClientProcessWorkspacesNeedsError::Offline(_) => {
                             event_bus.wait_server_reconnect().await;
@@ -90,6 +106,7 @@ fn task_future_factory(event_bus: EventBus, client: Arc<Client>) -> impl Future<
                         err @ (ClientProcessWorkspacesNeedsError::TimestampOutOfBallpark {
                             ..
                         }
+                        | ClientProcessWorkspacesNeedsError::ClockDrift(_)
                         | ClientProcessWorkspacesNeedsError::InvalidCertificate(_)) => {
                             log::error!("Stopping workspaces process needs monitor due to unexpected outcome: {err}");
                             return;
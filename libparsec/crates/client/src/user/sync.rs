@@ -11,7 +11,7 @@ use crate::{
         CertifEnsureRealmCreatedError, CertifValidateManifestError, InvalidCertificateError,
         InvalidManifestError,
     },
-    greater_timestamp, EventUserOpsOutboundSyncDone, GreaterTimestampOffset,
+    greater_timestamp, ClockDriftError, EventUserOpsOutboundSyncDone, GreaterTimestampOffset,
 };
 
 #[derive(Debug, thiserror::Error)]
@@ -39,6 +39,8 @@ pub enum UserSyncError {
         ballpark_client_late_offset: f64,
     },
     #[error(transparent)]
+    ClockDrift(#[from] ClockDriftError),
+    #[error(transparent)]
     Internal(#[from] anyhow::Error),
 }
 
@@ -110,7 +112,8 @@ async fn upload_manifest(
                         &ops.device.time_provider,
                         GreaterTimestampOffset::Manifest,
                         strictly_greater_than,
-                    );
+                    )
+                    .await?;
                     continue;
                 }
                 // Timeout is about sequester service webhook not being available, no need
@@ -166,7 +169,8 @@ async fn upload_manifest(
                         &ops.device.time_provider,
                         GreaterTimestampOffset::Manifest,
                         strictly_greater_than,
-                    );
+                    )
+                    .await?;
                     continue;
                 }
                 // Timeout is about sequester service webhook not being available, no need
@@ -239,6 +243,7 @@ async fn outbound_sync_inner(ops: &UserOps) -> Result<OutboundSyncOutcome, UserS
                     ballpark_client_early_offset,
                     ballpark_client_late_offset,
                 },
+                CertifEnsureRealmCreatedError::ClockDrift(err) => UserSyncError::ClockDrift(err),
                 CertifEnsureRealmCreatedError::Internal(err) => err
                     .context("Cannot create the workspace on the server")
                     .into(),
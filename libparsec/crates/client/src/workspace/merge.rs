@@ -67,49 +67,18 @@ fn has_file_content_changed_in_local(
     //
     // Here the two non-empty areas in local are expected to each contains a single
     // chunk view corresponding to the same block than in remote.
-
-    // Compare each blocksize area one by one.
-    let mut remote_blocks_iter = base_blocks.iter();
-    for local_blocksize_area in local_blocks.iter() {
-        match local_blocksize_area.len() {
-            // This blocksize area is empty (i.e. it contains only zero-filled data), the
-            // remote manifest should have a hole here (hence nothing to compare here).
-            0 => (),
-            // This blocksize area contains data to compare.
-            1 => {
-                // The blocksize area is made of a single chunk view in local, from there
-                // we can extract the corresponding block access and compare it to the
-                // remote one.
-                // Note that it's possible to have a chunk view referring a block access
-                // but only using part of it (think of truncating a synchronized file),
-                // in this case `ChunkView::get_block_access` works as expected and
-                // return an error (given the chunk view doesn't *correspond* to the block
-                // access but only *uses* it).
-                match (local_blocksize_area[0].get_block_access(), remote_blocks_iter.next()) {
-                    // The local manifest contains local changes in this blocksize area.
-                    (Err(ChunkViewGetBlockAccessError::NotPromotedAsBlock), _)
-                    // The local manifest contains more blocksize areas than the remote.
-                    | (_, None) => {
-                        return true;
-                    }
-                    (Ok(local_block_access), Some(remote_block_access)) => {
-                        // This blocksize area has been modified.
-                        if local_block_access != remote_block_access {
-                            return true;
-                        }
-                    }
-                }
-            }
-            // Multiple chunk views in a given blocksize area indicates there is local
-            // changes (i.e. reshape hasn't been done).
-            _ => {
-                return true;
-            }
-        }
+    //
+    // Also note that, with content-defined chunking, a blocksize area can contain
+    // multiple blocks, and a block spanning over two blocksize areas is represented in
+    // local by a chunk view in each of them (each using part of the block).
+    //
+    // So we retrieve the blocks the local chunk views correspond to, which fails if
+    // there is local changes (i.e. reshape hasn't been done, or a chunk view only uses
+    // part of a block as it is the case when truncating a synchronized file).
+    match LocalFileManifest::get_reshaped_blocks(local_blocks) {
+        Some(local_blocks) => local_blocks.into_iter().ne(base_blocks.iter()),
+        None => true,
     }
-
-    // No changes \o/
-    false
 }
 
 /// Merge a local file manifest with a remote file manifest.
@@ -169,6 +138,8 @@ pub(super) fn merge_local_file_manifest(
                 size: local_base_size,
                 blocksize: local_base_blocksize,
                 blocks: local_base_blocks,
+                // Ignore `content_defined_chunking`: it is determined by the blocks
+                content_defined_chunking: _,
             },
         // `need_sync` has already been checked
         need_sync: _,
@@ -200,7 +171,7 @@ pub(super) fn merge_local_file_manifest(
         // There is local changes in the content, hence we will have a conflict if there is also remote changes !
         let remote_content_changed = remote.size != *local_base_size
             || remote.blocksize != *local_base_blocksize
-            || remote.blocks != *local_base_blocks;
+            || !is_same_blocks_content(&remote.blocks, local_base_blocks);
         if remote_content_changed {
             return MergeLocalFileManifestOutcome::Conflict(remote);
         }
@@ -236,6 +207,23 @@ pub(super) fn merge_local_file_manifest(
     MergeLocalFileManifestOutcome::Merged(merge_in_progress)
 }
 
+/// Blocks only differing by their IDs contain the same data (the digest is computed
+/// on the cleartext data). This is typically the case once a realm's data have been
+/// re-encrypted with its last key (see `certif::realm_rekey`), in which case the
+/// remote change must not cause a conflict with our local changes.
+///
+/// Note the local chunks may still refer to the previous block IDs, which is fine
+/// since the old blocks remain available on the server.
+fn is_same_blocks_content(a: &[BlockAccess], b: &[BlockAccess]) -> bool {
+    a.len() == b.len()
+        && a.iter().zip(b.iter()).all(|(a, b)| {
+            a.offset == b.offset
+                && a.size == b.size
+                && a.digest == b.digest
+                && a.compressed == b.compressed
+        })
+}
+
 /// Merge a local folder manifest with a remote folder manifest.
 /// The local manifest is assumed to be up-to-date with the current prevent sync pattern.
 pub(super) fn merge_local_folder_manifest(
@@ -25,9 +25,10 @@ pub use transactions::{
     WorkspaceCreateFolderError, WorkspaceFdCloseError, WorkspaceFdFlushError, WorkspaceFdReadError,
     WorkspaceFdResizeError, WorkspaceFdStatError, WorkspaceFdWriteError,
     WorkspaceGetNeedInboundSyncEntriesError, WorkspaceGetNeedOutboundSyncEntriesError,
-    WorkspaceIsFileContentLocalError, WorkspaceMoveEntryError, WorkspaceOpenFileError,
-    WorkspaceOpenFolderReaderError, WorkspaceRemoveEntryError, WorkspaceStatEntryError,
-    WorkspaceStatFolderChildrenError, WorkspaceSyncError, WorkspaceWatchEntryOneShotError,
+    WorkspaceIsEntryRemoteOnlyError, WorkspaceIsFileContentLocalError, WorkspaceMoveEntryError,
+    WorkspaceOpenFileError, WorkspaceOpenFolderReaderError, WorkspaceRemoveEntryError,
+    WorkspaceSetRemoteOnlyEntriesError, WorkspaceStatEntryError, WorkspaceStatFolderChildrenError,
+    WorkspaceSyncError, WorkspaceWatchEntryOneShotError,
 };
 
 use self::{store::FileUpdater, transactions::FdWriteStrategy};
@@ -172,7 +173,7 @@ impl WorkspaceOps {
         )
         .await?;
 
-        Ok(Self {
+        let ops = Self {
             config,
             device,
             store,
@@ -189,7 +190,18 @@ impl WorkspaceOps {
                 opened_files: HashMap::new(),
             }),
             entry_watchers: Default::default(),
-        })
+        };
+
+        // The shared prevent sync pattern is normally loaded from the local storage
+        // when starting the store, but it may not be available (e.g. its data have been
+        // evicted from the cache).
+        if !ops.store.is_shared_prevent_sync_pattern_loaded() {
+            if let Err(err) = transactions::refresh_shared_prevent_sync_pattern(&ops).await {
+                log::warn!("Cannot load the shared prevent sync pattern: {}", err);
+            }
+        }
+
+        Ok(ops)
     }
 
     /// Stop the underlying storage (and flush whatever data is not yet on disk)
@@ -257,7 +269,12 @@ impl WorkspaceOps {
         &self,
         entry_id: VlobID,
     ) -> Result<InboundSyncOutcome, WorkspaceSyncError> {
-        transactions::inbound_sync(self, entry_id).await
+        let outcome = transactions::inbound_sync(self, entry_id).await?;
+        if matches!(outcome, InboundSyncOutcome::Updated) {
+            self.refresh_shared_prevent_sync_pattern_if_related(entry_id)
+                .await;
+        }
+        Ok(outcome)
     }
 
     /// Query the server for changes in the workspace since the last checkpoint
@@ -282,7 +299,27 @@ impl WorkspaceOps {
         &self,
         entry_id: VlobID,
     ) -> Result<OutboundSyncOutcome, WorkspaceSyncError> {
-        transactions::outbound_sync(self, entry_id).await
+        let outcome = transactions::outbound_sync(self, entry_id).await?;
+        if matches!(outcome, OutboundSyncOutcome::Done) {
+            self.refresh_shared_prevent_sync_pattern_if_related(entry_id)
+                .await;
+        }
+        Ok(outcome)
+    }
+
+    /// The prevent sync pattern shared among the workspace's members is stored in a
+    /// regular file at the root of the workspace, hence it must be re-applied whenever
+    /// this file (or the root folder, in case the file has been added or removed) is
+    /// synchronized.
+    async fn refresh_shared_prevent_sync_pattern_if_related(&self, entry_id: VlobID) {
+        if !transactions::is_shared_prevent_sync_pattern_related(self, entry_id) {
+            return;
+        }
+        // The sync itself has succeeded, so a failure here is not reported to the
+        // caller (the pattern will be refreshed the next time the file is synced).
+        if let Err(err) = transactions::refresh_shared_prevent_sync_pattern(self).await {
+            log::warn!("Cannot refresh the shared prevent sync pattern: {}", err);
+        }
     }
 
     pub async fn get_need_outbound_sync(
@@ -292,6 +329,20 @@ impl WorkspaceOps {
         transactions::get_need_outbound_sync(self, limit).await
     }
 
+    /// Used by the outbound sync monitor to postpone the sync of a file until
+    /// its last write cursor is closed.
+    pub(crate) fn is_file_opened_for_write(&self, entry_id: VlobID) -> bool {
+        transactions::is_file_opened_for_write(self, entry_id)
+    }
+
+    /// Used by the outbound sync monitor to find the delays to apply to an entry.
+    pub(crate) async fn get_entry_name_for_outbound_sync(
+        &self,
+        entry_id: VlobID,
+    ) -> Result<Option<EntryName>, WorkspaceSyncError> {
+        transactions::get_entry_name_for_outbound_sync(self, entry_id).await
+    }
+
     /*
      * Public interface
      */
@@ -469,6 +520,24 @@ impl WorkspaceOps {
         transactions::is_file_content_local(self, path).await
     }
 
+    pub async fn set_remote_only_entries(
+        &self,
+        entries: &[VlobID],
+    ) -> Result<(), WorkspaceSetRemoteOnlyEntriesError> {
+        transactions::set_remote_only_entries(self, entries).await
+    }
+
+    pub fn get_remote_only_entries(&self) -> Vec<VlobID> {
+        transactions::get_remote_only_entries(self)
+    }
+
+    pub async fn is_entry_remote_only_by_id(
+        &self,
+        entry_id: VlobID,
+    ) -> Result<bool, WorkspaceIsEntryRemoteOnlyError> {
+        transactions::is_entry_remote_only_by_id(self, entry_id).await
+    }
+
     pub async fn open_file(
         &self,
         path: FsPath,
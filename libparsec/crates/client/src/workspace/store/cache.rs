@@ -1,6 +1,9 @@
 // Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
 
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
 
 use libparsec_client_connection::ConnectionError;
 use libparsec_platform_storage::workspace::{PopulateManifestOutcome, UpdateManifestData};
@@ -20,6 +23,9 @@ mod manifest_hash_map {
     pub(crate) struct ManifestsHashMap {
         manifests: HashMap<VlobID, ArcLocalChildManifest>,
         root_manifest_id: VlobID,
+        /// Incremented each time a manifest in the cache changes parent, so that
+        /// data derived from the folder hierarchy knows when it is outdated.
+        reparent_generation: u64,
     }
 
     impl ManifestsHashMap {
@@ -32,6 +38,7 @@ mod manifest_hash_map {
             Self {
                 manifests,
                 root_manifest_id,
+                reparent_generation: 0,
             }
         }
 
@@ -47,6 +54,8 @@ mod manifest_hash_map {
                 .expect("always present");
             self.manifests.clear();
             self.manifests.insert(self.root_manifest_id, root_manifest);
+            // We no longer know the previous parents of the removed manifests
+            self.reparent_generation += 1;
         }
 
         /// Add the manifest in the cache, overwriting whatever value was already present.
@@ -64,7 +73,12 @@ mod manifest_hash_map {
                 }
                 ArcLocalChildManifest::Folder(m) => m.base.id,
             };
-            self.manifests.insert(manifest_id, manifest);
+            let parent = manifest.parent();
+            if let Some(previous) = self.manifests.insert(manifest_id, manifest) {
+                if previous.parent() != parent {
+                    self.reparent_generation += 1;
+                }
+            }
         }
 
         /// If the update lock is not held, we can still have to insert the manifest in
@@ -102,6 +116,10 @@ mod manifest_hash_map {
             }
         }
 
+        pub fn reparent_generation(&self) -> u64 {
+            self.reparent_generation
+        }
+
         pub fn root_manifest(&self) -> &Arc<LocalFolderManifest> {
             match self
                 .manifests
@@ -141,14 +159,32 @@ pub(super) struct CurrentViewCache {
     /// To approximate that, we just keep the last 16 chunks read in memory.
     /// More practical information in this issue: https://github.com/Scille/parsec-cloud/issues/7111
     pub chunks: RoundRobinCache<ChunkID, Bytes, 16>,
+    /// Roots of the subtrees excluded from selective sync (i.e. whose blocks are never
+    /// kept in the local storage). This is a per-device setting, so it is loaded once
+    /// from the local storage and kept in sync with it.
+    pub remote_only_entries: Arc<HashSet<VlobID>>,
+    /// Whether an entry is remote only (i.e. itself or one of its ancestors is in
+    /// `remote_only_entries`), this avoids walking up the folder hierarchy each time.
+    /// Only valid as long as `remote_only_entries` is not modified and the manifests
+    /// are not reparented (see `remote_only_resolved_generation`).
+    pub remote_only_resolved: HashMap<VlobID, bool>,
+    /// `ManifestsHashMap::reparent_generation` at the time `remote_only_resolved`
+    /// has been populated.
+    pub remote_only_resolved_generation: u64,
 }
 
 impl CurrentViewCache {
-    pub fn new(root_manifest: Arc<LocalFolderManifest>) -> Self {
+    pub fn new(
+        root_manifest: Arc<LocalFolderManifest>,
+        remote_only_entries: HashSet<VlobID>,
+    ) -> Self {
         Self {
             manifests: ManifestsHashMap::new(root_manifest),
             lock_update_manifests: PerManifestUpdateLock::new(),
             chunks: Default::default(),
+            remote_only_entries: Arc::new(remote_only_entries),
+            remote_only_resolved: HashMap::new(),
+            remote_only_resolved_generation: 0,
         }
     }
 }
@@ -282,7 +318,7 @@ pub(super) async fn populate_cache_from_local_storage_or_server(
             ArcLocalChildManifest::File(Arc::new(LocalFileManifest::from_remote(manifest)))
         }
         Ok(ChildManifest::Folder(manifest)) => ArcLocalChildManifest::Folder(Arc::new(
-            LocalFolderManifest::from_remote(manifest, &store.prevent_sync_pattern),
+            LocalFolderManifest::from_remote(manifest, &store.get_prevent_sync_pattern()),
         )),
         Err(err) => {
             return Err(match err {
@@ -6,7 +6,8 @@ use libparsec_types::prelude::*;
 use crate::certif::{InvalidCertificateError, InvalidKeysBundleError, InvalidManifestError};
 
 use super::cache::{
-    populate_cache_from_local_storage_or_server, PopulateCacheFromLocalStorageOrServerError,
+    populate_cache_from_local_storage, populate_cache_from_local_storage_or_server,
+    PopulateCacheFromLocalStorageError, PopulateCacheFromLocalStorageOrServerError,
 };
 
 #[derive(Debug, thiserror::Error)]
@@ -31,6 +32,16 @@ pub(crate) enum GetManifestError {
     Internal(#[from] anyhow::Error),
 }
 
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum GetManifestLocalOnlyError {
+    #[error("Component has stopped")]
+    Stopped,
+    #[error("Path doesn't exist")]
+    EntryNotFound,
+    #[error(transparent)]
+    Internal(#[from] anyhow::Error),
+}
+
 pub(super) async fn get_manifest(
     store: &super::WorkspaceStore,
     entry_id: VlobID,
@@ -72,3 +83,31 @@ pub(super) async fn get_manifest(
 
     Ok(manifest)
 }
+
+/// Similar to `get_manifest`, but never fetch the manifest from the server if it
+/// is not available locally.
+pub(super) async fn get_manifest_local_only(
+    store: &super::WorkspaceStore,
+    entry_id: VlobID,
+) -> Result<ArcLocalChildManifest, GetManifestLocalOnlyError> {
+    // Fast path: cache lookup
+    let maybe_found = store
+        .data
+        .with_current_view_cache(|cache| cache.manifests.get(&entry_id).cloned());
+    if let Some(manifest) = maybe_found {
+        return Ok(manifest);
+    }
+
+    // Entry not in the cache, try to fetch it from the local storage...
+    let manifest = populate_cache_from_local_storage(store, entry_id)
+        .await
+        .map_err(|err| match err {
+            PopulateCacheFromLocalStorageError::Stopped => GetManifestLocalOnlyError::Stopped,
+            PopulateCacheFromLocalStorageError::EntryNotFound => {
+                GetManifestLocalOnlyError::EntryNotFound
+            }
+            PopulateCacheFromLocalStorageError::Internal(err) => err.into(),
+        })?;
+
+    Ok(manifest)
+}
@@ -6,11 +6,13 @@ mod folder_updater;
 mod manifest_access;
 mod per_manifest_update_lock;
 mod prevent_sync_pattern;
+mod remote_only;
 mod reparent_updater;
 mod resolve_path;
 mod sync_updater;
 
 use std::{
+    collections::HashSet,
     path::Path,
     sync::{Arc, Mutex},
 };
@@ -30,7 +32,11 @@ use crate::{
 use cache::CurrentViewCache;
 pub(crate) use file_updater::{FileUpdater, ForUpdateFileError};
 pub(crate) use folder_updater::{FolderUpdater, ForUpdateFolderError};
-pub(crate) use manifest_access::GetManifestError;
+pub(crate) use manifest_access::{GetManifestError, GetManifestLocalOnlyError};
+use prevent_sync_pattern::LocalSharedPreventSyncPattern;
+pub(crate) use prevent_sync_pattern::{
+    parse_shared_prevent_sync_pattern, ApplySharedPreventSyncPatternError,
+};
 pub(crate) use reparent_updater::{ForUpdateReparentingError, ReparentingUpdater};
 pub(crate) use resolve_path::{
     PathConfinementPoint, ResolvePathError, RetrievePathFromIDEntry, RetrievePathFromIDError,
@@ -56,6 +62,8 @@ pub(super) type UpdateFolderManifestError = WorkspaceStoreOperationError;
 pub(super) type UpdateFileManifestAndContinueError = WorkspaceStoreOperationError;
 pub(super) type PromoteLocalOnlyChunkToUploadedBlockError = WorkspaceStoreOperationError;
 pub(super) type GetNotUploadedChunkError = WorkspaceStoreOperationError;
+pub(super) type SetUploadedBlockError = WorkspaceStoreOperationError;
+pub(super) type SetRemoteOnlyEntriesError = WorkspaceStoreOperationError;
 
 #[derive(Debug, thiserror::Error)]
 pub(super) enum ReadChunkOrBlockLocalOnlyError {
@@ -151,11 +159,16 @@ mod data {
     }
 
     impl WorkspaceStoreData {
-        pub fn new(storage: WorkspaceStorage, root_manifest: LocalWorkspaceManifest) -> Self {
+        pub fn new(
+            storage: WorkspaceStorage,
+            root_manifest: LocalWorkspaceManifest,
+            remote_only_entries: HashSet<VlobID>,
+        ) -> Self {
             Self {
-                current_view_cache: Mutex::new(CurrentViewCache::new(Arc::new(
-                    root_manifest.into(),
-                ))),
+                current_view_cache: Mutex::new(CurrentViewCache::new(
+                    Arc::new(root_manifest.into()),
+                    remote_only_entries,
+                )),
                 storage: AsyncMutex::new(Some(storage)),
                 #[cfg(debug_assertions)]
                 lock_tracking: Default::default(),
@@ -318,7 +331,16 @@ pub(super) struct WorkspaceStore {
     certificates_ops: Arc<CertificateOps>,
 
     data: data::WorkspaceStoreData,
-    prevent_sync_pattern: PreventSyncPattern,
+    /// Prevent sync pattern provided by the client config
+    local_prevent_sync_pattern: PreventSyncPattern,
+    /// Prevent sync pattern actually in use: the local one merged with the one shared
+    /// among the workspace's members (see `SHARED_PREVENT_SYNC_PATTERN_FILENAME`).
+    prevent_sync_pattern: Mutex<Arc<PreventSyncPattern>>,
+    /// Ensure only a single task at a time applies a new prevent sync pattern
+    apply_prevent_sync_pattern_lock: AsyncMutex<()>,
+    /// `false` if the shared prevent sync pattern couldn't be loaded from the local
+    /// storage on start, in which case it should be refreshed from the server.
+    shared_prevent_sync_pattern_loaded: bool,
 }
 
 impl std::panic::UnwindSafe for WorkspaceStore {}
@@ -377,22 +399,55 @@ impl WorkspaceStore {
         };
 
         // 3) Ensure the prevent sync pattern is applied to the workspace
+        //
+        // The prevent sync pattern is the local one merged with the one shared among
+        // the workspace's members.
+
+        let shared_prevent_sync_pattern =
+            prevent_sync_pattern::load_shared_prevent_sync_pattern_from_local_storage(
+                &mut storage,
+                &device,
+                &root_manifest,
+            )
+            .await?;
+        let (effective_prevent_sync_pattern, shared_prevent_sync_pattern_loaded) =
+            match shared_prevent_sync_pattern {
+                LocalSharedPreventSyncPattern::Missing => (prevent_sync_pattern.clone(), true),
+                LocalSharedPreventSyncPattern::Found(shared) => {
+                    (prevent_sync_pattern.merge(&shared), true)
+                }
+                LocalSharedPreventSyncPattern::NotAvailable => {
+                    (prevent_sync_pattern.clone(), false)
+                }
+            };
+
         prevent_sync_pattern::ensure_prevent_sync_pattern_applied_to_wksp(
             &mut storage,
             device.clone(),
-            prevent_sync_pattern,
+            &effective_prevent_sync_pattern,
         )
         .await?;
 
-        // 4) All set !
+        // 4) Load the selective sync configuration
+
+        let remote_only_entries = storage
+            .get_remote_only_entries()
+            .await?
+            .into_iter()
+            .collect();
+
+        // 5) All set !
 
         Ok(Self {
             realm_id,
             device,
             cmds,
             certificates_ops,
-            data: data::WorkspaceStoreData::new(storage, root_manifest),
-            prevent_sync_pattern: prevent_sync_pattern.clone(),
+            data: data::WorkspaceStoreData::new(storage, root_manifest, remote_only_entries),
+            local_prevent_sync_pattern: prevent_sync_pattern.clone(),
+            prevent_sync_pattern: Mutex::new(Arc::new(effective_prevent_sync_pattern)),
+            apply_prevent_sync_pattern_lock: AsyncMutex::new(()),
+            shared_prevent_sync_pattern_loaded,
         })
     }
 
@@ -435,6 +490,13 @@ impl WorkspaceStore {
         manifest_access::get_manifest(self, entry_id).await
     }
 
+    pub async fn get_manifest_local_only(
+        &self,
+        entry_id: VlobID,
+    ) -> Result<ArcLocalChildManifest, GetManifestLocalOnlyError> {
+        manifest_access::get_manifest_local_only(self, entry_id).await
+    }
+
     /// Don't blindly trust folder manifest's `children` field !
     ///
     /// It may contain invalid data (i.e. referencing a non existing child ID, or a child
@@ -582,6 +644,44 @@ impl WorkspaceStore {
         .await
     }
 
+    pub fn get_prevent_sync_pattern(&self) -> Arc<PreventSyncPattern> {
+        self.prevent_sync_pattern
+            .lock()
+            .expect("Mutex is poisoned")
+            .clone()
+    }
+
+    pub fn is_shared_prevent_sync_pattern_loaded(&self) -> bool {
+        self.shared_prevent_sync_pattern_loaded
+    }
+
+    /// Merge the provided shared prevent sync pattern with the local one, then apply
+    /// the result to the workspace.
+    ///
+    /// Returns `false` if the resulting prevent sync pattern is the one already in use.
+    pub async fn apply_shared_prevent_sync_pattern(
+        &self,
+        shared_prevent_sync_pattern: &PreventSyncPattern,
+    ) -> Result<bool, ApplySharedPreventSyncPatternError> {
+        prevent_sync_pattern::apply_shared_prevent_sync_pattern(self, shared_prevent_sync_pattern)
+            .await
+    }
+
+    pub async fn set_remote_only_entries(
+        &self,
+        entries: &[VlobID],
+    ) -> Result<(), SetRemoteOnlyEntriesError> {
+        remote_only::set_remote_only_entries(self, entries).await
+    }
+
+    pub fn get_remote_only_entries(&self) -> Vec<VlobID> {
+        remote_only::get_remote_only_entries(self)
+    }
+
+    pub async fn is_entry_remote_only(&self, entry_id: VlobID) -> Result<bool, GetManifestError> {
+        remote_only::is_entry_remote_only(self, entry_id).await
+    }
+
     pub async fn is_entry_locked(&self, entry_id: VlobID) -> bool {
         self.data
             .with_current_view_cache(|cache| cache.lock_update_manifests.is_taken(entry_id))
@@ -750,22 +850,35 @@ impl WorkspaceStore {
         // collision (given when a block upload is rejected because the server already
         // contains one with this ID, the client assumes the block data on the server
         // are the one it wanted to upload...).
+        //
+        // Note block deduplication doesn't change this: a deduplicated block ID is
+        // derived from the block content, so the same ID always means the same data.
 
-        // Should both store the data in local storage...
-
-        let encrypted = self.device.local_symkey.encrypt(&data);
-        self.data
-            .with_storage(|maybe_storage| async move {
-                let storage = maybe_storage
-                    .as_mut()
-                    .ok_or_else(|| ReadChunkOrBlockError::Stopped)?;
+        // Should both store the data in local storage (unless the file is part of
+        // a subtree excluded from selective sync)...
 
-                storage
-                    .set_block(access.id, &encrypted, self.device.now())
-                    .await
-                    .map_err(ReadChunkOrBlockError::Internal)
-            })
-            .await?;
+        // Failing to determine if the file is remote only is not a big deal: at worst
+        // the block ends up in the local storage, where it is subject to the usual
+        // cache eviction.
+        let is_remote_only = self
+            .is_entry_remote_only(remote_manifest.id)
+            .await
+            .unwrap_or(false);
+        if !is_remote_only {
+            let encrypted = self.device.local_symkey.encrypt(&data);
+            self.data
+                .with_storage(|maybe_storage| async move {
+                    let storage = maybe_storage
+                        .as_mut()
+                        .ok_or_else(|| ReadChunkOrBlockError::Stopped)?;
+
+                    storage
+                        .set_block(access.id, &encrypted, self.device.now())
+                        .await
+                        .map_err(ReadChunkOrBlockError::Internal)
+                })
+                .await?;
+        }
 
         // ...and update the cache !
 
@@ -830,6 +943,31 @@ impl WorkspaceStore {
             .await
     }
 
+    /// Store in the local cache the data of a block that has just been uploaded.
+    ///
+    /// This is needed when the uploaded block's ID differs from the ID of the chunk
+    /// it originates from (see block deduplication), as in this case the chunk
+    /// cannot simply be promoted.
+    pub async fn set_uploaded_block(
+        &self,
+        block_id: BlockID,
+        data: &[u8],
+    ) -> Result<(), SetUploadedBlockError> {
+        let encrypted = self.device.local_symkey.encrypt(data);
+        self.data
+            .with_storage(|maybe_storage| async move {
+                let storage = maybe_storage
+                    .as_mut()
+                    .ok_or_else(|| SetUploadedBlockError::Stopped)?;
+
+                storage
+                    .set_block(block_id, &encrypted, self.device.now())
+                    .await
+                    .map_err(SetUploadedBlockError::Internal)
+            })
+            .await
+    }
+
     pub async fn get_inbound_need_sync_entries(
         &self,
         limit: u32,
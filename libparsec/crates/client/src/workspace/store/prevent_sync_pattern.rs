@@ -4,6 +4,8 @@ use std::sync::Arc;
 use libparsec_platform_storage::workspace::{UpdateManifestData, WorkspaceStorage};
 use libparsec_types::prelude::*;
 
+use super::ForUpdateFolderError;
+
 #[derive(Debug, thiserror::Error)]
 pub enum ApplyPreventSyncPatternError {
     #[error(transparent)]
@@ -84,3 +86,270 @@ fn decode_folder(
         ))),
     }
 }
+
+/// Parse the content of the shared prevent sync pattern file.
+///
+/// The file uses the glob ignore file format (see
+/// [`PreventSyncPattern::from_glob_ignore_file`]), which is only a subset of the
+/// gitignore syntax:
+/// - Each pattern is matched against the name of the entry (not its path), at
+///   any depth in the workspace.
+/// - Negation (i.e. `!pattern`) is not supported.
+/// - Path separators (e.g. `/build`, `build/` or `doc/*.md`) are not supported.
+///
+/// Given this file is edited by the workspace's members, the invalid or unsupported
+/// lines are ignored one by one (so that a single faulty line doesn't disable the
+/// whole file), and an invalid content (i.e. not UTF-8) is considered empty.
+pub(crate) fn parse_shared_prevent_sync_pattern(content: &[u8]) -> PreventSyncPattern {
+    let content = match std::str::from_utf8(content) {
+        Ok(content) => content,
+        Err(err) => {
+            log::warn!(
+                "Ignoring invalid `{}` file: {}",
+                SHARED_PREVENT_SYNC_PATTERN_FILENAME,
+                err
+            );
+            return PreventSyncPattern::empty();
+        }
+    };
+
+    let mut pattern = PreventSyncPattern::empty();
+    for (line_number, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        // Unsupported gitignore features are ignored rather than being misinterpreted
+        // (e.g. `!foo` would be a pattern matching the `!foo` name).
+        let outcome = if line.starts_with('!') {
+            Err("negation is not supported".to_string())
+        } else if line.contains('/') {
+            Err("path separator is not supported".to_string())
+        } else {
+            PreventSyncPattern::from_glob(line).map_err(|err| err.to_string())
+        };
+
+        match outcome {
+            Ok(line_pattern) => pattern = pattern.merge(&line_pattern),
+            Err(err) => {
+                log::warn!(
+                    "Ignoring invalid line {} in `{}` file: {}",
+                    line_number + 1,
+                    SHARED_PREVENT_SYNC_PATTERN_FILENAME,
+                    err
+                );
+            }
+        }
+    }
+    pattern
+}
+
+pub(super) enum LocalSharedPreventSyncPattern {
+    /// The workspace doesn't have a shared prevent sync pattern file
+    Missing,
+    Found(PreventSyncPattern),
+    /// The shared prevent sync pattern file exists, but its content is not
+    /// available in the local storage
+    NotAvailable,
+}
+
+/// Load the shared prevent sync pattern when starting the workspace: at this point
+/// we only have access to the local storage (and we don't want to depend on the
+/// server anyway).
+pub(super) async fn load_shared_prevent_sync_pattern_from_local_storage(
+    storage: &mut WorkspaceStorage,
+    device: &LocalDevice,
+    root_manifest: &LocalWorkspaceManifest,
+) -> anyhow::Result<LocalSharedPreventSyncPattern> {
+    let name: EntryName = SHARED_PREVENT_SYNC_PATTERN_FILENAME
+        .parse()
+        .expect("valid entry name");
+    let entry_id = match root_manifest.0.children.get(&name) {
+        Some(entry_id) => *entry_id,
+        None => return Ok(LocalSharedPreventSyncPattern::Missing),
+    };
+
+    let manifest = match storage.get_manifest(entry_id).await? {
+        Some(encrypted) => {
+            match LocalFileManifest::decrypt_and_load(&encrypted, &device.local_symkey) {
+                Ok(manifest) => manifest,
+                // We consider that BadSerialization indicate that the manifest is not of file type.
+                Err(DataError::BadSerialization { .. }) => {
+                    return Ok(LocalSharedPreventSyncPattern::Missing)
+                }
+                Err(err) => {
+                    return Err(anyhow::anyhow!(
+                        "Local database contains invalid data: {err}"
+                    ))
+                }
+            }
+        }
+        None => return Ok(LocalSharedPreventSyncPattern::NotAvailable),
+    };
+
+    let (size, chunk_views) =
+        crate::workspace::transactions::prepare_read(&manifest, manifest.size, 0);
+    let mut content = Vec::with_capacity(size as usize);
+    let mut content_size = 0;
+    for chunk_view in chunk_views {
+        let mut maybe_encrypted = storage.get_chunk(chunk_view.id).await?;
+        if maybe_encrypted.is_none() {
+            maybe_encrypted = storage
+                .get_block(chunk_view.id.into(), device.now())
+                .await?;
+        }
+        let encrypted = match maybe_encrypted {
+            Some(encrypted) => encrypted,
+            None => return Ok(LocalSharedPreventSyncPattern::NotAvailable),
+        };
+        let chunk_data = device
+            .local_symkey
+            .decrypt(&encrypted)
+            .map_err(|err| anyhow::anyhow!("Cannot decrypt block from local storage: {}", err))?;
+        chunk_view
+            .copy_between_start_and_stop(&chunk_data, 0, &mut content, &mut content_size)
+            .expect("prepare_read/buf/size are consistent");
+    }
+    // Sparse file, missing data are zeros
+    content.resize(size as usize, 0);
+
+    Ok(LocalSharedPreventSyncPattern::Found(
+        parse_shared_prevent_sync_pattern(&content),
+    ))
+}
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum ApplySharedPreventSyncPatternError {
+    #[error("Component has stopped")]
+    Stopped,
+    #[error(transparent)]
+    Internal(#[from] anyhow::Error),
+}
+
+/// Merge the shared prevent sync pattern with the local one, and apply the result
+/// to the workspace if it has changed.
+///
+/// Unlike `ensure_prevent_sync_pattern_applied_to_wksp`, this is designed to be used
+/// while the workspace is running, hence each folder manifest is modified through
+/// the regular update lock.
+pub(super) async fn apply_shared_prevent_sync_pattern(
+    store: &super::WorkspaceStore,
+    shared_prevent_sync_pattern: &PreventSyncPattern,
+) -> Result<bool, ApplySharedPreventSyncPatternError> {
+    const PAGE_SIZE: u32 = 1000;
+
+    // Concurrent applications would overwrite each other's work
+    let _guard = store.apply_prevent_sync_pattern_lock.lock().await;
+
+    let prevent_sync_pattern = Arc::new(
+        store
+            .local_prevent_sync_pattern
+            .merge(shared_prevent_sync_pattern),
+    );
+    {
+        let mut guard = store
+            .prevent_sync_pattern
+            .lock()
+            .expect("Mutex is poisoned");
+        if **guard == *prevent_sync_pattern {
+            return Ok(false);
+        }
+        // From now on, all the manifests coming from the server or modified
+        // locally will use the new pattern...
+        *guard = prevent_sync_pattern.clone();
+    }
+
+    // ...and the manifests already in the local storage must be updated.
+
+    let pattern_ref = &prevent_sync_pattern;
+    store
+        .data
+        .with_storage(|maybe_storage| async move {
+            let storage = maybe_storage
+                .as_mut()
+                .ok_or(ApplySharedPreventSyncPatternError::Stopped)?;
+            storage
+                .set_prevent_sync_pattern(pattern_ref)
+                .await
+                .map_err(ApplySharedPreventSyncPatternError::Internal)
+        })
+        .await?;
+
+    let mut offset = 0;
+    loop {
+        let manifests = store
+            .data
+            .with_storage(|maybe_storage| async move {
+                let storage = maybe_storage
+                    .as_mut()
+                    .ok_or(ApplySharedPreventSyncPatternError::Stopped)?;
+                storage
+                    .list_manifests(offset, PAGE_SIZE)
+                    .await
+                    .map_err(ApplySharedPreventSyncPatternError::Internal)
+            })
+            .await?;
+
+        for encoded_manifest in &manifests {
+            let entry_id = match decode_folder(encoded_manifest, &store.device.local_symkey) {
+                Ok(Some(folder)) => folder.base.id,
+                Ok(None) => continue,
+                Err(ApplyPreventSyncPatternError::Internal(err)) => return Err(err.into()),
+            };
+
+            let (updater, manifest) = match store.for_update_folder(entry_id).await {
+                Ok(ok) => ok,
+                Err(ForUpdateFolderError::Stopped) => {
+                    return Err(ApplySharedPreventSyncPatternError::Stopped)
+                }
+                // The manifest has been removed from the local storage in the meantime
+                Err(
+                    ForUpdateFolderError::EntryNotFound | ForUpdateFolderError::EntryNotAFolder,
+                ) => continue,
+                Err(err) => {
+                    return Err(anyhow::anyhow!(err)
+                        .context("cannot lock folder for update")
+                        .into())
+                }
+            };
+
+            let new_manifest = manifest.apply_prevent_sync_pattern(pattern_ref, store.device.now());
+            if new_manifest != *manifest {
+                updater
+                    .update_folder_manifest(Arc::new(new_manifest), None)
+                    .await
+                    .map_err(|err| match err {
+                        super::WorkspaceStoreOperationError::Stopped => {
+                            ApplySharedPreventSyncPatternError::Stopped
+                        }
+                        super::WorkspaceStoreOperationError::Internal(err) => {
+                            err.context("cannot update folder manifest").into()
+                        }
+                    })?;
+            }
+        }
+
+        // The manifests list is not filled to the page size,
+        // We consider that another call will result in an empty list, so we can stop here.
+        if manifests.len() < PAGE_SIZE as usize {
+            break;
+        }
+        offset += PAGE_SIZE;
+    }
+
+    store
+        .data
+        .with_storage(|maybe_storage| async move {
+            let storage = maybe_storage
+                .as_mut()
+                .ok_or(ApplySharedPreventSyncPatternError::Stopped)?;
+            storage
+                .mark_prevent_sync_pattern_fully_applied(pattern_ref)
+                .await
+                .map_err(|e| ApplySharedPreventSyncPatternError::Internal(e.into()))
+        })
+        .await?;
+
+    Ok(true)
+}
@@ -0,0 +1,206 @@
+// Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+//! Selective sync: a device can decide that some subtrees of the workspace are
+//! "remote only", in which case the blocks of the files they contain are fetched
+//! from the server on read but never kept in the local storage.
+//!
+//! Note this only concerns the data: manifests are still synchronized (and cached)
+//! as usual so that the folder hierarchy is always browsable.
+
+use std::{collections::HashSet, sync::Arc};
+
+use libparsec_types::prelude::*;
+
+use super::{
+    manifest_access::{get_manifest, get_manifest_local_only},
+    GetManifestError, GetManifestLocalOnlyError, SetRemoteOnlyEntriesError,
+};
+
+/// Blocks already in the local storage that are part of a newly remote only subtree
+/// are removed from it.
+pub(super) async fn set_remote_only_entries(
+    store: &super::WorkspaceStore,
+    entries: &[VlobID],
+) -> Result<(), SetRemoteOnlyEntriesError> {
+    let previous_entries = store
+        .data
+        .with_current_view_cache(|cache| cache.remote_only_entries.clone());
+
+    store
+        .data
+        .with_storage(|maybe_storage| async move {
+            let storage = maybe_storage
+                .as_mut()
+                .ok_or_else(|| SetRemoteOnlyEntriesError::Stopped)?;
+
+            storage
+                .set_remote_only_entries(entries)
+                .await
+                .map_err(SetRemoteOnlyEntriesError::Internal)
+        })
+        .await?;
+
+    let remote_only_entries = Arc::new(entries.iter().copied().collect::<HashSet<_>>());
+    store.data.with_current_view_cache(|cache| {
+        cache.remote_only_entries = remote_only_entries;
+        cache.remote_only_resolved.clear();
+    });
+
+    let new_entries = entries
+        .iter()
+        .copied()
+        .filter(|entry_id| !previous_entries.contains(entry_id));
+    purge_subtrees_blocks(store, new_entries).await
+}
+
+/// Remove from the local storage the blocks of the files within the given subtrees.
+///
+/// Only the manifests available locally are considered: a file whose manifest
+/// has never been fetched cannot have its blocks in the local storage.
+///
+/// Note chunks (i.e. data modified locally but not yet uploaded) are kept: they
+/// are the only copy of this data until the file is synchronized.
+async fn purge_subtrees_blocks(
+    store: &super::WorkspaceStore,
+    roots: impl Iterator<Item = VlobID>,
+) -> Result<(), SetRemoteOnlyEntriesError> {
+    let mut to_visit: Vec<_> = roots.collect();
+    let mut seen = HashSet::new();
+    let mut chunk_ids = vec![];
+    let mut block_ids = vec![];
+    while let Some(entry_id) = to_visit.pop() {
+        // Parenting loop should not occur, but the manifests come from the server
+        // so we'd better not trust them blindly.
+        if !seen.insert(entry_id) {
+            continue;
+        }
+        let manifest = match get_manifest_local_only(store, entry_id).await {
+            Ok(manifest) => manifest,
+            Err(GetManifestLocalOnlyError::EntryNotFound) => continue,
+            Err(GetManifestLocalOnlyError::Stopped) => {
+                return Err(SetRemoteOnlyEntriesError::Stopped)
+            }
+            Err(GetManifestLocalOnlyError::Internal(err)) => {
+                return Err(SetRemoteOnlyEntriesError::Internal(
+                    err.context("cannot get manifest"),
+                ))
+            }
+        };
+        match manifest {
+            ArcLocalChildManifest::Folder(folder) => {
+                to_visit.extend(folder.children.values().copied());
+            }
+            ArcLocalChildManifest::File(file) => {
+                block_ids.extend(file.base.blocks.iter().map(|access| access.id));
+                for chunk_view in file.blocks.iter().flatten() {
+                    chunk_ids.push(chunk_view.id);
+                    if let Some(access) = &chunk_view.access {
+                        block_ids.push(access.id);
+                    }
+                }
+            }
+        }
+    }
+
+    // The in-memory cache is only a read cache, so it doesn't matter if it also
+    // contained not yet uploaded chunks (they are still in the local storage).
+    store.data.with_current_view_cache(|cache| {
+        for chunk_id in chunk_ids.iter() {
+            cache.chunks.remove(chunk_id);
+        }
+    });
+
+    if block_ids.is_empty() {
+        return Ok(());
+    }
+
+    store
+        .data
+        .with_storage(|maybe_storage| async move {
+            let storage = maybe_storage
+                .as_mut()
+                .ok_or_else(|| SetRemoteOnlyEntriesError::Stopped)?;
+
+            storage
+                .remove_blocks(block_ids.into_iter())
+                .await
+                .map_err(SetRemoteOnlyEntriesError::Internal)
+        })
+        .await
+}
+
+pub(super) fn get_remote_only_entries(store: &super::WorkspaceStore) -> Vec<VlobID> {
+    store
+        .data
+        .with_current_view_cache(|cache| cache.remote_only_entries.iter().copied().collect())
+}
+
+/// An entry is remote only if itself or any of its ancestors has been marked as such.
+///
+/// The outcome is cached for the entry and all its ancestors, so only the first
+/// lookup in a given subtree has to walk up the folder hierarchy.
+pub(super) async fn is_entry_remote_only(
+    store: &super::WorkspaceStore,
+    entry_id: VlobID,
+) -> Result<bool, GetManifestError> {
+    let (remote_only_entries, generation) = store.data.with_current_view_cache(|cache| {
+        let generation = cache.manifests.reparent_generation();
+        if cache.remote_only_resolved_generation != generation {
+            cache.remote_only_resolved.clear();
+            cache.remote_only_resolved_generation = generation;
+        }
+        (cache.remote_only_entries.clone(), generation)
+    });
+
+    // Fast path: selective sync is not used on this device
+    if remote_only_entries.is_empty() {
+        return Ok(false);
+    }
+
+    let get_resolved = |entry_id| {
+        store.data.with_current_view_cache(|cache| {
+            if cache.remote_only_resolved_generation == generation {
+                cache.remote_only_resolved.get(&entry_id).copied()
+            } else {
+                None
+            }
+        })
+    };
+
+    let mut current_entry_id = entry_id;
+    let mut visited = vec![];
+    let mut seen = HashSet::new();
+    let is_remote_only = loop {
+        if remote_only_entries.contains(&current_entry_id) {
+            break true;
+        }
+        if let Some(is_remote_only) = get_resolved(current_entry_id) {
+            break is_remote_only;
+        }
+        visited.push(current_entry_id);
+        // Stop once the root is reached. Parenting loop should not occur, but the
+        // manifests come from the server so we'd better not trust them blindly.
+        if current_entry_id == store.realm_id || !seen.insert(current_entry_id) {
+            break false;
+        }
+        let manifest = get_manifest(store, current_entry_id).await?;
+        current_entry_id = manifest.parent();
+    };
+
+    // Only cache the outcome if nothing has changed while we were walking up
+    // the folder hierarchy
+    store.data.with_current_view_cache(|cache| {
+        if cache.manifests.reparent_generation() == generation
+            && cache.remote_only_resolved_generation == generation
+            && Arc::ptr_eq(&cache.remote_only_entries, &remote_only_entries)
+        {
+            for visited_entry_id in visited {
+                cache
+                    .remote_only_resolved
+                    .insert(visited_entry_id, is_remote_only);
+            }
+        }
+    });
+
+    Ok(is_remote_only)
+}
@@ -902,7 +902,6 @@ pub(crate) enum RetrievePathFromIDEntry {
     /// The entry is present in the local storage and has a valid path to reach it.
     Reachable {
         manifest: ArcLocalChildManifest,
-        #[allow(unused)]
         path: FsPath,
         confinement_point: PathConfinementPoint,
         // The chain of entry IDs from the root to the provided entry
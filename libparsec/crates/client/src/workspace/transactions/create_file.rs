@@ -140,7 +140,7 @@ pub(crate) async fn create_file(
     data.insert(child_name, Some(child_id));
     mut_parent_manifest.evolve_children_and_mark_updated(
         data,
-        &ops.config.prevent_sync_pattern,
+        &ops.store.get_prevent_sync_pattern(),
         now,
     );
     let parent_need_sync = mut_parent_manifest.need_sync;
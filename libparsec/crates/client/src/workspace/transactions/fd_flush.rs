@@ -9,7 +9,7 @@ use crate::workspace::{
     OpenedFile, WorkspaceOps, WriteMode,
 };
 
-use super::prepare_reshape;
+use super::{prepare_reshape, use_content_defined_chunking};
 
 #[derive(Debug, thiserror::Error)]
 pub enum WorkspaceFdFlushError {
@@ -128,6 +128,15 @@ async fn reshape(
     ops: &WorkspaceOps,
     opened_file: &mut OpenedFile,
 ) -> Result<(), ReshapeAndFlushError> {
+    // With content-defined chunking, the block boundaries depend on the data that
+    // follows the modification, hence the reshape is only done during sync.
+    if use_content_defined_chunking(
+        &opened_file.manifest,
+        ops.config.outbound_sync.content_defined_chunking,
+    ) {
+        return Ok(());
+    }
+
     let manifest: &mut LocalFileManifest = Arc::make_mut(&mut opened_file.manifest);
     for reshape in prepare_reshape(manifest) {
         // Build the chunk of data resulting of the reshape...
@@ -217,7 +226,7 @@ async fn reshape(
             &buf
         };
         // Commit the changes
-        reshape.commit(buf_ref);
+        reshape.commit(buf_ref, ops.config.outbound_sync.block_compression);
     }
 
     Ok(())
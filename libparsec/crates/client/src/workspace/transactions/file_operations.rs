@@ -3,6 +3,7 @@
 use std::cmp::{max, min};
 use std::collections::HashSet;
 use std::num::NonZeroU64;
+use std::ops::Range;
 
 use libparsec_types::prelude::*;
 
@@ -258,6 +259,8 @@ pub fn prepare_write(
         manifest.blocks[block as usize] = new_chunks;
     }
 
+    discard_chunk_ids_still_in_use(manifest, start_block..stop_block, &mut removed_ids);
+
     // Evolve manifest
     let new_size = max(manifest.size, offset + size);
 
@@ -269,6 +272,21 @@ pub fn prepare_write(
     (write_operations, removed_ids)
 }
 
+/// With content-defined chunking, a block spanning over two block slots is represented
+/// by a chunk view in each of them, hence a chunk ID removed from a block slot might
+/// still be in use in an adjacent one.
+fn discard_chunk_ids_still_in_use(
+    manifest: &LocalFileManifest,
+    blocks: Range<u64>,
+    removed_ids: &mut HashSet<ChunkID>,
+) {
+    let start = blocks.start.saturating_sub(1) as usize;
+    let stop = blocks.end as usize + 1;
+    for chunk_view in manifest.blocks.iter().take(stop).skip(start).flatten() {
+        removed_ids.remove(&chunk_view.id);
+    }
+}
+
 // Prepare resize
 
 fn prepare_truncate(
@@ -340,6 +358,8 @@ fn prepare_truncate(
         manifest.blocks.push(new_chunk_views);
     }
 
+    discard_chunk_ids_still_in_use(manifest, block as u64..block as u64 + 1, &mut removed_ids);
+
     // Update the manifest
     manifest.need_sync = true;
     manifest.size = size;
@@ -440,12 +460,20 @@ impl ReshapeBlockOperation<'_> {
         }
     }
 
-    pub fn commit(self, chunk_data: &[u8]) {
+    /// `compressed` is the value of the flag in the resulting block access, i.e. whether
+    /// the block should be compressed once uploaded.
+    pub fn commit(self, chunk_data: &[u8], compressed: bool) {
+        let set_compressed = |chunk_view: &mut ChunkView| {
+            if let Some(access) = chunk_view.access.as_mut() {
+                access.compressed = compressed;
+            }
+        };
         match self {
             ReshapeBlockOperation::ToPromote { chunk_view } => {
                 chunk_view
                     .promote_as_block(chunk_data)
                     .expect("chunk is block-compatible");
+                set_compressed(chunk_view);
             }
             ReshapeBlockOperation::ToReshape {
                 manifest_chunk_views,
@@ -455,6 +483,7 @@ impl ReshapeBlockOperation<'_> {
                 reshaped_chunk_view
                     .promote_as_block(chunk_data)
                     .expect("chunk is block-compatible");
+                set_compressed(&mut reshaped_chunk_view);
                 manifest_chunk_views.push(reshaped_chunk_view);
             }
         }
@@ -485,3 +514,171 @@ pub fn prepare_reshape(
         .iter_mut()
         .filter_map(ReshapeBlockOperation::try_reshape)
 }
+
+// Prepare content-defined reshape
+
+/// Content-defined chunking is used if enabled in the config, or if the file already
+/// uses it (in which case going back to fixed-size blocks would require to reshape,
+/// and hence re-upload, the whole file).
+pub(crate) fn use_content_defined_chunking(manifest: &LocalFileManifest, enabled: bool) -> bool {
+    if enabled || manifest.base.content_defined_chunking {
+        return true;
+    }
+    // Blocks spanning over two block slots that haven't been synchronized yet
+    let blocksize = u64::from(manifest.blocksize);
+    manifest
+        .blocks
+        .iter()
+        .enumerate()
+        .any(|(block, chunk_views)| {
+            chunk_views.iter().any(|chunk_view| {
+                chunk_view.raw_offset / blocksize != block as u64
+                    || (chunk_view.raw_offset + chunk_view.raw_size.get() - 1) / blocksize
+                        != block as u64
+            })
+        })
+}
+
+/// A content-defined reshape operation, see `prepare_content_defined_reshape`.
+pub(crate) struct ContentDefinedReshape {
+    /// Where the new block starts.
+    pub start: u64,
+    /// The new block ends at most here (i.e. `blocksize` bytes after `start`, or where
+    /// the contiguous data ends).
+    pub stop: u64,
+}
+
+/// With content-defined chunking, the block boundaries depend on the data itself (see
+/// `content_defined_chunk_size`), hence the reshape is done one block at a time:
+/// - The new block starts at the first chunk view that doesn't correspond to a block.
+/// - The data between `start` and `stop` (see `prepare_read`) is used to determine
+///   where the new block ends.
+/// - `commit_content_defined_reshape` then replaces the chunk views by the new block.
+///
+/// Return `None` if the manifest is already reshaped.
+///
+/// Note the chunk views before `start` correspond to blocks, hence the position of
+/// the next reshape is always further in the file, and the chunk views after the new
+/// block are left untouched (so boundaries of the blocks already uploaded are reused).
+pub(crate) fn prepare_content_defined_reshape(
+    manifest: &LocalFileManifest,
+) -> Option<ContentDefinedReshape> {
+    let is_block_part = |chunk_view: &ChunkView, access: &BlockAccess| {
+        chunk_view.raw_offset == access.offset && chunk_view.raw_size == access.size
+    };
+
+    // Find the first chunk view that doesn't correspond to a block (i.e. a block
+    // spanning over two block slots must be represented by two chunk views
+    // corresponding to its beginning and its end)
+    let mut start = None;
+    // Beginning of the current block, and where its next chunk view should start
+    let mut incomplete_block: Option<(u64, u64, &BlockAccess)> = None;
+    for chunk_view in manifest.blocks.iter().flatten() {
+        if let Some((block_start, expected_start, access)) = incomplete_block.take() {
+            match &chunk_view.access {
+                Some(chunk_view_access)
+                    if chunk_view_access == access
+                        && is_block_part(chunk_view, access)
+                        && chunk_view.start == expected_start =>
+                {
+                    if chunk_view.stop.get() != access.offset + access.size.get() {
+                        incomplete_block = Some((block_start, chunk_view.stop.get(), access));
+                    }
+                    continue;
+                }
+                _ => {
+                    start = Some(block_start);
+                    break;
+                }
+            }
+        }
+        match &chunk_view.access {
+            Some(access)
+                if is_block_part(chunk_view, access) && chunk_view.start == access.offset =>
+            {
+                if chunk_view.stop.get() != access.offset + access.size.get() {
+                    incomplete_block = Some((chunk_view.start, chunk_view.stop.get(), access));
+                }
+            }
+            _ => {
+                start = Some(chunk_view.start);
+                break;
+            }
+        }
+    }
+    let start = start.or(incomplete_block.map(|(block_start, _, _)| block_start))?;
+
+    // Find where the contiguous data ends
+    let max_stop = start + u64::from(manifest.blocksize);
+    let mut stop = start;
+    for chunk_view in manifest.blocks.iter().flatten() {
+        if chunk_view.stop.get() <= start {
+            continue;
+        }
+        if chunk_view.start > stop || stop >= max_stop {
+            break;
+        }
+        stop = chunk_view.stop.get();
+    }
+    let stop = min(stop, max_stop);
+
+    Some(ContentDefinedReshape { start, stop })
+}
+
+/// Replace the chunk views between `start` and `start + chunk_data.len()` by a new block.
+///
+/// `compressed` is the value of the flag in the resulting block access, i.e. whether
+/// the block should be compressed once uploaded.
+///
+/// Return the new chunk view (corresponding to the new block, and whose data must be
+/// written), along with a `HashSet` of chunk IDs that must cleaned up from the storage.
+pub(crate) fn commit_content_defined_reshape(
+    manifest: &mut LocalFileManifest,
+    start: u64,
+    chunk_data: &[u8],
+    compressed: bool,
+) -> (ChunkView, HashSet<ChunkID>) {
+    let stop = start + chunk_data.len() as u64;
+    let mut new_chunk_view = ChunkView::new(
+        start,
+        NonZeroU64::new(stop).expect("chunk data is never empty"),
+    );
+    new_chunk_view
+        .promote_as_block(chunk_data)
+        .expect("chunk is block-compatible");
+    if let Some(access) = new_chunk_view.access.as_mut() {
+        access.compressed = compressed;
+    }
+
+    // The new block may span over two block slots
+    let blocksize = u64::from(manifest.blocksize);
+    let start_block = start / blocksize;
+    let stop_block = stop.div_ceil(blocksize);
+    let mut removed_ids = HashSet::new();
+    for block in start_block..stop_block {
+        let block_start = block * blocksize;
+        let sub_start = max(start, block_start);
+        let sub_stop = min(stop, block_start + blocksize);
+        let mut sub_chunk_view = new_chunk_view.clone();
+        sub_chunk_view.start = sub_start;
+        sub_chunk_view.stop =
+            NonZeroU64::new(sub_stop).expect("Sub-stop is always greater than sub-start");
+
+        let block_chunks = manifest
+            .blocks
+            .get_mut(block as usize)
+            .expect("The reshaped data is present in the manifest");
+        let (new_chunks, more_removed_ids) = block_write(
+            block_chunks,
+            sub_stop - sub_start,
+            sub_start,
+            sub_chunk_view,
+        );
+        removed_ids.extend(more_removed_ids);
+        *block_chunks = new_chunks;
+    }
+
+    discard_chunk_ids_still_in_use(manifest, start_block..stop_block, &mut removed_ids);
+
+    (new_chunk_view, removed_ids)
+}
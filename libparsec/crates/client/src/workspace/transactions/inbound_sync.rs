@@ -83,7 +83,7 @@ use libparsec_types::prelude::*;
 use super::super::WorkspaceOps;
 use crate::{
     certif::{
-        CertifValidateManifestError, CertificateOps, InvalidCertificateError,
+        CertifValidateManifestError, CertificateOps, ClockDriftError, InvalidCertificateError,
         InvalidKeysBundleError, InvalidManifestError,
     },
     workspace::{
@@ -134,6 +134,8 @@ pub enum WorkspaceSyncError {
         ballpark_client_late_offset: f64,
     },
     #[error(transparent)]
+    ClockDrift(#[from] ClockDriftError),
+    #[error(transparent)]
     Internal(#[from] anyhow::Error),
 }
 
@@ -409,7 +411,7 @@ async fn merge_manifest_and_update_store(
         (None, ChildManifest::Folder(remote_manifest)) => {
             let local_manifest = Arc::new(LocalFolderManifest::from_remote(
                 remote_manifest,
-                &ops.config.prevent_sync_pattern,
+                &ops.store.get_prevent_sync_pattern(),
             ));
             let parent_id = local_manifest.parent;
             updater
@@ -434,7 +436,7 @@ async fn merge_manifest_and_update_store(
             let merge_outcome = super::super::merge::merge_local_folder_manifest(
                 ops.device.device_id,
                 ops.device.now(),
-                &ops.config.prevent_sync_pattern,
+                &ops.store.get_prevent_sync_pattern(),
                 &local_manifest,
                 remote_manifest,
             );
@@ -531,7 +533,7 @@ async fn handle_conflict_and_update_store(
             remote_manifest.parent,
             ArcLocalChildManifest::Folder(Arc::new(LocalFolderManifest::from_remote(
                 remote_manifest,
-                &ops.config.prevent_sync_pattern,
+                &ops.store.get_prevent_sync_pattern(),
             ))),
         ),
     };
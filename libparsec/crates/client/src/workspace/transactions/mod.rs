@@ -15,6 +15,8 @@ mod open_file;
 mod outbound_sync;
 mod read_folder;
 mod remove_entry;
+mod selective_sync;
+mod shared_prevent_sync_pattern;
 mod stat_entry;
 mod watch_entry;
 
@@ -33,5 +35,7 @@ pub use open_file::*;
 pub use outbound_sync::*;
 pub use read_folder::*;
 pub use remove_entry::*;
+pub use selective_sync::*;
+pub(crate) use shared_prevent_sync_pattern::*;
 pub use stat_entry::*;
 pub use watch_entry::*;
@@ -367,7 +367,7 @@ async fn move_entry_same_parent(
         (dst_child_name, Some(child_id)),
     ]);
 
-    mut_parent.evolve_children_and_mark_updated(data, &ops.config.prevent_sync_pattern, now);
+    mut_parent.evolve_children_and_mark_updated(data, &ops.store.get_prevent_sync_pattern(), now);
 
     parent_updater
         .update_folder_manifest(parent_manifest, None)
@@ -531,12 +531,12 @@ async fn move_entry_different_parents(
 
     mut_src_parent.evolve_children_and_mark_updated(
         src_parent_data,
-        &ops.config.prevent_sync_pattern,
+        &ops.store.get_prevent_sync_pattern(),
         now,
     );
     mut_dst_parent.evolve_children_and_mark_updated(
         dst_parent_data,
-        &ops.config.prevent_sync_pattern,
+        &ops.store.get_prevent_sync_pattern(),
         now,
     );
 
@@ -6,13 +6,15 @@ use libparsec_client_connection::protocol::authenticated_cmds;
 use libparsec_platform_storage::certificates::PerTopicLastTimestamps;
 use libparsec_types::prelude::*;
 
-use super::super::WorkspaceOps;
+use super::super::{WorkspaceOps, WriteMode};
 use super::WorkspaceSyncError;
 use crate::certif::{
     CertifBootstrapWorkspaceError, CertifEncryptForRealmError, CertifPollServerError,
 };
+use crate::server_fetch::{server_fetch_block, ServerFetchBlockError};
 use crate::workspace::store::{
-    ForUpdateSyncError, ForUpdateSyncLocalOnlyError, PathConfinementPoint, ReadChunkOrBlockError,
+    ForUpdateSyncError, ForUpdateSyncLocalOnlyError, GetManifestLocalOnlyError,
+    PathConfinementPoint, ReadChunkOrBlockError, ReadChunkOrBlockLocalOnlyError,
     RetrievePathFromIDEntry, WorkspaceStoreOperationError,
 };
 use crate::{
@@ -30,6 +32,78 @@ pub async fn get_need_outbound_sync(
     ops.store.get_outbound_need_sync_entries(limit).await
 }
 
+/// Return `true` if the entry is a file currently opened with at least one
+/// cursor in write mode.
+///
+/// Note a file with an operation in progress (i.e. its opened file lock is
+/// already taken) is also considered opened for write, as we cannot tell what
+/// the operation is doing.
+pub(crate) fn is_file_opened_for_write(ops: &WorkspaceOps, entry_id: VlobID) -> bool {
+    let opened_file = {
+        let guard = ops.opened_files.lock().expect("Mutex is poisoned");
+        match guard.opened_files.get(&entry_id) {
+            Some(opened_file) => opened_file.clone(),
+            None => return false,
+        }
+    };
+
+    if let Some(opened_file) = opened_file.try_lock() {
+        return opened_file
+            .cursors
+            .iter()
+            .any(|cursor| matches!(cursor.write_mode, WriteMode::Allowed));
+    }
+    true
+}
+
+/// Retrieve the name of the entry, this is used to determine which outbound sync
+/// delays apply to it.
+///
+/// Only local data is used: this is called by the outbound sync monitor each time
+/// an entry gets modified, so it must not wait for the server (especially when
+/// offline). The name is looked up in the parent's `children` field, hence
+/// `None` is returned if the entry is the root or if the entry or its parent are
+/// not present locally.
+pub(crate) async fn get_entry_name_for_outbound_sync(
+    ops: &WorkspaceOps,
+    entry_id: VlobID,
+) -> Result<Option<EntryName>, WorkspaceSyncError> {
+    let get_manifest_local_only = |entry_id: VlobID| async move {
+        match ops.store.get_manifest_local_only(entry_id).await {
+            Ok(manifest) => Ok(Some(manifest)),
+            Err(GetManifestLocalOnlyError::EntryNotFound) => Ok(None),
+            Err(GetManifestLocalOnlyError::Stopped) => Err(WorkspaceSyncError::Stopped),
+            Err(GetManifestLocalOnlyError::Internal(err)) => {
+                Err(err.context("cannot get manifest").into())
+            }
+        }
+    };
+
+    if entry_id == ops.realm_id {
+        return Ok(None);
+    }
+    let parent_id = match get_manifest_local_only(entry_id).await? {
+        Some(manifest) => manifest.parent(),
+        None => return Ok(None),
+    };
+
+    let parent_manifest = if parent_id == ops.realm_id {
+        ops.store.get_root_manifest()
+    } else {
+        match get_manifest_local_only(parent_id).await? {
+            Some(ArcLocalChildManifest::Folder(manifest)) => manifest,
+            Some(ArcLocalChildManifest::File(_)) | None => return Ok(None),
+        }
+    };
+
+    let name = parent_manifest
+        .children
+        .iter()
+        .find(|(_, child_id)| **child_id == entry_id)
+        .map(|(name, _)| name.clone());
+    Ok(name)
+}
+
 pub async fn outbound_sync(
     ops: &WorkspaceOps,
     entry_id: VlobID,
@@ -202,6 +276,9 @@ async fn outbound_sync_child(
                 CertifBootstrapWorkspaceError::InvalidCertificate(err) => {
                     WorkspaceSyncError::InvalidCertificate(err)
                 }
+                CertifBootstrapWorkspaceError::ClockDrift(err) => {
+                    WorkspaceSyncError::ClockDrift(err)
+                }
                 CertifBootstrapWorkspaceError::Internal(err) => {
                     err.context("Cannot bootstrap workspace").into()
                 }
@@ -365,7 +442,7 @@ async fn outbound_sync_folder(
     let local_from_remote = Arc::new(
         LocalFolderManifest::from_remote_with_restored_local_confinement_points(
             remote,
-            &ops.config.prevent_sync_pattern,
+            &ops.store.get_prevent_sync_pattern(),
             local.as_ref(),
             timestamp,
         ),
@@ -499,7 +576,8 @@ async fn upload_manifest<M: RemoteManifest>(
                         &ops.device.time_provider,
                         GreaterTimestampOffset::Manifest,
                         strictly_greater_than,
-                    );
+                    )
+                    .await?;
                     to_upload.update_timestamp(timestamp);
                     continue;
                 }
@@ -564,7 +642,8 @@ async fn upload_manifest<M: RemoteManifest>(
                         &ops.device.time_provider,
                         GreaterTimestampOffset::Manifest,
                         strictly_greater_than,
-                    );
+                    )
+                    .await?;
                     to_upload.update_timestamp(timestamp);
                     continue;
                 }
@@ -641,8 +720,17 @@ async fn reshape_and_upload_blocks(
 
     // 1) Ensure the file is reshaped
 
+    let content_defined_chunking = super::file_operations::use_content_defined_chunking(
+        &manifest,
+        ops.config.outbound_sync.content_defined_chunking,
+    );
     loop {
-        match do_next_reshape_operation(ops, manifest.clone()).await? {
+        let outcome = if content_defined_chunking {
+            do_next_content_defined_reshape_operation(ops, manifest.clone()).await?
+        } else {
+            do_next_reshape_operation(ops, manifest.clone()).await?
+        };
+        match outcome {
             DoNextReshapeOperationOutcome::Done(refreshed_manifest) => {
                 manifest = refreshed_manifest
             }
@@ -655,9 +743,14 @@ async fn reshape_and_upload_blocks(
 
     // 2) Now upload the blocks that are missing on the server
 
-    upload_blocks(ops, &manifest)
-        .await
-        .map(move |_| ReshapeAndUploadBlocksOutcome::Done(manifest))
+    let deduplicated_blocks = upload_blocks(ops, &manifest).await?;
+
+    // 3) Finally update the manifest with the deduplicated block IDs
+
+    if deduplicated_blocks.is_empty() {
+        return Ok(ReshapeAndUploadBlocksOutcome::Done(manifest));
+    }
+    apply_deduplicated_blocks(ops, manifest, deduplicated_blocks).await
 }
 
 enum DoNextReshapeOperationOutcome {
@@ -666,6 +759,32 @@ enum DoNextReshapeOperationOutcome {
     EntryIsBusy,
 }
 
+fn read_chunk_or_block_error_to_sync_error(err: ReadChunkOrBlockError) -> WorkspaceSyncError {
+    match err {
+        ReadChunkOrBlockError::Offline(e) => WorkspaceSyncError::Offline(e),
+        ReadChunkOrBlockError::ServerBlockstoreUnavailable => {
+            WorkspaceSyncError::ServerBlockstoreUnavailable
+        }
+        ReadChunkOrBlockError::Stopped => WorkspaceSyncError::Stopped,
+        // The manifest seems to contain invalid data (or the server is lying to us),
+        // nothing we can do about it so it is treated as an unexpected error
+        ReadChunkOrBlockError::ChunkNotFound => anyhow::anyhow!(
+            "Chunk referenced by the manifest is missing both locally and on the server"
+        )
+        .into(),
+        ReadChunkOrBlockError::NoRealmAccess => WorkspaceSyncError::NotAllowed,
+        ReadChunkOrBlockError::RealmDeleted => WorkspaceSyncError::RealmDeleted,
+        ReadChunkOrBlockError::InvalidBlockAccess(err) => {
+            WorkspaceSyncError::InvalidBlockAccess(err)
+        }
+        ReadChunkOrBlockError::InvalidKeysBundle(err) => WorkspaceSyncError::InvalidKeysBundle(err),
+        ReadChunkOrBlockError::InvalidCertificate(err) => {
+            WorkspaceSyncError::InvalidCertificate(err)
+        }
+        ReadChunkOrBlockError::Internal(err) => err.context("cannot get chunk").into(),
+    }
+}
+
 /// A reshape is done every time the file is closed, hence this function should be a noop
 /// in most case.
 /// However the reshape on file close doesn't download blocks that are missing in local,
@@ -690,42 +809,14 @@ async fn do_next_reshape_operation(
     let mut buf_size = 0;
     let start = reshape.destination().start;
     for chunk_view in reshape.source().iter() {
-        let outcome = ops
+        let chunk_data = ops
             .store
             .get_chunk_or_block(chunk_view, manifest_base)
-            .await;
-        match outcome {
-            Ok(chunk_data) => {
-                chunk_view
-                    .copy_between_start_and_stop(&chunk_data, start, &mut buf, &mut buf_size)
-                    .expect("write on vec cannot fail");
-            }
-            Err(err) => {
-                return Err(match err {
-                    ReadChunkOrBlockError::Offline(e) => WorkspaceSyncError::Offline(e),
-                    ReadChunkOrBlockError::ServerBlockstoreUnavailable => {
-                        WorkspaceSyncError::ServerBlockstoreUnavailable
-                    }
-                    ReadChunkOrBlockError::Stopped => WorkspaceSyncError::Stopped,
-                    // TODO: manifest seems to contain invalid data (or the server is lying to us)
-                    ReadChunkOrBlockError::ChunkNotFound => todo!(
-                        "manifest seems to contain invalid data (or the server is lying to us)"
-                    ),
-                    ReadChunkOrBlockError::NoRealmAccess => WorkspaceSyncError::NotAllowed,
-                    ReadChunkOrBlockError::RealmDeleted => WorkspaceSyncError::RealmDeleted,
-                    ReadChunkOrBlockError::InvalidBlockAccess(err) => {
-                        WorkspaceSyncError::InvalidBlockAccess(err)
-                    }
-                    ReadChunkOrBlockError::InvalidKeysBundle(err) => {
-                        WorkspaceSyncError::InvalidKeysBundle(err)
-                    }
-                    ReadChunkOrBlockError::InvalidCertificate(err) => {
-                        WorkspaceSyncError::InvalidCertificate(err)
-                    }
-                    ReadChunkOrBlockError::Internal(err) => err.context("cannot get chunk").into(),
-                });
-            }
-        }
+            .await
+            .map_err(read_chunk_or_block_error_to_sync_error)?;
+        chunk_view
+            .copy_between_start_and_stop(&chunk_data, start, &mut buf, &mut buf_size)
+            .expect("write on vec cannot fail");
     }
 
     // Sanity check: make sure that the buffer is fully filled
@@ -762,7 +853,7 @@ async fn do_next_reshape_operation(
 
     let to_remove_chunk_ids = reshape.cleanup_ids();
     let new_chunk_id = reshape.destination().id;
-    reshape.commit(&buf);
+    reshape.commit(&buf, ops.config.outbound_sync.block_compression);
     updater
         .update_file_manifest_and_chunks(
             manifest.clone(),
@@ -780,29 +871,144 @@ async fn do_next_reshape_operation(
     Ok(DoNextReshapeOperationOutcome::Done(manifest))
 }
 
-async fn upload_blocks(
+/// Same as `do_next_reshape_operation`, but with content-defined chunking.
+///
+/// Given the reshape is not done on file close in this case, this is where the
+/// modified data gets split into blocks.
+async fn do_next_content_defined_reshape_operation(
     ops: &WorkspaceOps,
-    manifest: &LocalFileManifest,
-) -> Result<(), WorkspaceSyncError> {
-    for (block_index, block) in manifest.blocks.iter().enumerate() {
-        assert!(block.len() == 1); // Sanity check: the manifest is guaranteed to be reshaped
-        let chunk_view = &block[0];
-        let block_access = chunk_view.access.as_ref().expect("already reshaped");
-        let block_id = block_access.id;
-        assert_eq!(block_id, chunk_view.id.into()); // Sanity check
+    mut manifest: Arc<LocalFileManifest>,
+) -> Result<DoNextReshapeOperationOutcome, WorkspaceSyncError> {
+    let original_manifest = manifest.clone();
 
-        // 1) Get back the block's data
+    let reshape = match super::file_operations::prepare_content_defined_reshape(&manifest) {
+        Some(reshape) => reshape,
+        // Reshape is all finished \o/
+        None => return Ok(DoNextReshapeOperationOutcome::AlreadyReshaped),
+    };
 
-        let maybe_data = ops
+    // 1) Read the data following the reshape position...
+
+    let (size, chunk_views) = super::file_operations::prepare_read(
+        &original_manifest,
+        reshape.stop - reshape.start,
+        reshape.start,
+    );
+    let mut buf = Vec::with_capacity(size as usize);
+    let mut buf_size = 0;
+    for chunk_view in chunk_views {
+        let chunk_data = ops
             .store
-            .get_not_uploaded_chunk(chunk_view.id)
+            .get_chunk_or_block(&chunk_view, &original_manifest.base)
             .await
-            .map_err(|err| match err {
-                WorkspaceStoreOperationError::Stopped => WorkspaceSyncError::Stopped,
-                WorkspaceStoreOperationError::Internal(err) => {
-                    err.context("cannot get chunk").into()
+            .map_err(read_chunk_or_block_error_to_sync_error)?;
+        chunk_view
+            .copy_between_start_and_stop(&chunk_data, reshape.start, &mut buf, &mut buf_size)
+            .expect("write on vec cannot fail");
+    }
+
+    // Sanity check: make sure that the buffer is fully filled
+    assert!(buf.len() == (reshape.stop - reshape.start) as usize);
+
+    // ...and determine where the new block ends
+
+    let block_size = content_defined_chunk_size(&buf, original_manifest.blocksize);
+    buf.truncate(block_size);
+
+    // 2) Save the manifest with the new block in the storage
+
+    // Lock back the entry or abort if it has changed in the meantime
+
+    let updater = {
+        let outcome = ops
+            .store
+            .for_update_sync_local_only(original_manifest.base.id)
+            .await;
+        match outcome {
+            Ok((updater, Some(ArcLocalChildManifest::File(refreshed_manifest)))) => {
+                if file_has_changed(&original_manifest, &refreshed_manifest) {
+                    return Ok(DoNextReshapeOperationOutcome::EntryIsBusy);
                 }
-            })?;
+                updater
+            }
+            // Entry has changed type, hence it has been modified and we should retry later
+            Ok((_, None | Some(ArcLocalChildManifest::Folder(_)))) => {
+                return Ok(DoNextReshapeOperationOutcome::EntryIsBusy)
+            }
+            Err(ForUpdateSyncLocalOnlyError::WouldBlock) => {
+                return Ok(DoNextReshapeOperationOutcome::EntryIsBusy)
+            }
+            Err(ForUpdateSyncLocalOnlyError::Stopped) => return Err(WorkspaceSyncError::Stopped),
+            Err(ForUpdateSyncLocalOnlyError::Internal(err)) => {
+                return Err(err.context("cannot access entry in store").into())
+            }
+        }
+    };
+
+    // Do the actual storage update
+
+    let (new_chunk_view, to_remove_chunk_ids) =
+        super::file_operations::commit_content_defined_reshape(
+            Arc::make_mut(&mut manifest),
+            reshape.start,
+            &buf,
+            ops.config.outbound_sync.block_compression,
+        );
+    updater
+        .update_file_manifest_and_chunks(
+            manifest.clone(),
+            [(new_chunk_view.id, buf.as_ref())].into_iter(),
+            to_remove_chunk_ids.into_iter(),
+        )
+        .await
+        .map_err(|err| match err {
+            WorkspaceStoreOperationError::Stopped => WorkspaceSyncError::Stopped,
+            WorkspaceStoreOperationError::Internal(err) => {
+                err.context("cannot update file manifest&chunks").into()
+            }
+        })?;
+
+    Ok(DoNextReshapeOperationOutcome::Done(manifest))
+}
+
+/// A block uploaded with a deduplicated ID (i.e. derived from its content), which
+/// differs from the ID of the chunk it originates from.
+struct DeduplicatedBlock {
+    chunk_id: ChunkID,
+    block_id: BlockID,
+    data: Bytes,
+}
+
+/// Upload the blocks that are not yet on the server.
+///
+/// If block deduplication is enabled, the returned list contains the blocks whose
+/// chunk hasn't been promoted since they have been uploaded under a different ID.
+async fn upload_blocks(
+    ops: &WorkspaceOps,
+    manifest: &LocalFileManifest,
+) -> Result<Vec<DeduplicatedBlock>, WorkspaceSyncError> {
+    let block_deduplication = ops.config.outbound_sync.block_deduplication;
+    let mut deduplicated_blocks = vec![];
+
+    // Sanity check: the manifest is guaranteed to be reshaped
+    let blocks =
+        LocalFileManifest::get_reshaped_blocks(&manifest.blocks).expect("already reshaped");
+
+    for (block_index, block_access) in blocks.iter().enumerate() {
+        let chunk_id = ChunkID::from(*block_access.id);
+
+        // 1) Get back the block's data
+
+        let maybe_data =
+            ops.store
+                .get_not_uploaded_chunk(chunk_id)
+                .await
+                .map_err(|err| match err {
+                    WorkspaceStoreOperationError::Stopped => WorkspaceSyncError::Stopped,
+                    WorkspaceStoreOperationError::Internal(err) => {
+                        err.context("cannot get chunk").into()
+                    }
+                })?;
 
         let data = match maybe_data {
             // Already uploaded, nothing to do
@@ -816,89 +1022,349 @@ async fn upload_blocks(
             realm_id: ops.realm_id,
             entry_id: manifest.base.id,
             block_index: block_index as IndexInt,
-            blocks: manifest.blocks.len() as IndexInt,
+            blocks: blocks.len() as IndexInt,
             blocksize: *manifest.blocksize,
         };
         ops.event_bus.send(&event);
 
-        loop {
-            let (encrypted, key_index) = ops
-                .certificates_ops
-                .encrypt_for_realm(EncrytionUsage::Block(block_id), ops.realm_id(), &data)
-                .await
-                .map_err(|e| match e {
-                    CertifEncryptForRealmError::Stopped => WorkspaceSyncError::Stopped,
-                    CertifEncryptForRealmError::Offline(e) => WorkspaceSyncError::Offline(e),
-                    CertifEncryptForRealmError::NotAllowed => WorkspaceSyncError::NotAllowed,
-                    CertifEncryptForRealmError::RealmDeleted => WorkspaceSyncError::RealmDeleted,
-                    CertifEncryptForRealmError::NoKey => WorkspaceSyncError::NoKey,
-                    CertifEncryptForRealmError::InvalidKeysBundle(err) => {
-                        WorkspaceSyncError::InvalidKeysBundle(err)
-                    }
-                    CertifEncryptForRealmError::Internal(err) => {
-                        err.context("Cannot encrypt manifest for realm").into()
+        let uploaded_block_id = if block_deduplication {
+            match upload_block(ops, block_access, &data, true).await? {
+                (block_id, UploadBlockOutcome::Uploaded | UploadBlockOutcome::KnownLocally) => {
+                    block_id
+                }
+                (block_id, UploadBlockOutcome::AlreadyExists) => {
+                    if check_existing_deduplicated_block(ops, manifest, block_access, block_id)
+                        .await?
+                    {
+                        block_id
+                    } else {
+                        // The block stored under this ID doesn't correspond to our data
+                        // (most likely it has been uploaded by a malicious realm member
+                        // that has predicted the ID), so upload it under the chunk's
+                        // ID instead.
+                        upload_block(ops, block_access, &data, false).await?.0
                     }
-                })?;
+                }
+            }
+        } else {
+            upload_block(ops, block_access, &data, false).await?.0
+        };
 
-            use authenticated_cmds::latest::block_create::{Rep, Req};
-            let req = Req {
-                realm_id: ops.realm_id,
-                key_index,
-                block_id,
-                block: encrypted.into(),
-            };
-            let rep = ops.cmds.send(req).await?;
-            match rep {
-                Rep::Ok
-                // Go idempotent if the block has already been uploaded, as this might
-                // happen when a failure occurs before the local storage is updated
-                | Rep::BlockAlreadyExists => (),
-                Rep::AuthorNotAllowed => return Err(WorkspaceSyncError::NotAllowed),
-                // Nothing we can do if server is not ready to store our data, retry later
-                Rep::StoreUnavailable => return Err(WorkspaceSyncError::ServerBlockstoreUnavailable),
-                    // A key rotation occurred concurrently, should poll for new certificates and retry
-                    Rep::BadKeyIndex { last_realm_certificate_timestamp } => {
-                        let latest_known_timestamps = PerTopicLastTimestamps::new_for_realm(ops.realm_id, last_realm_certificate_timestamp);
-                        ops.certificates_ops
-                            .poll_server_for_new_certificates(Some(&latest_known_timestamps))
-                            .await
-                            .map_err(|err| match err {
-                                CertifPollServerError::Stopped => WorkspaceSyncError::Stopped,
-                                CertifPollServerError::Offline(e) => WorkspaceSyncError::Offline(e),
-                                CertifPollServerError::InvalidCertificate(err) => WorkspaceSyncError::InvalidCertificate(err),
-                                CertifPollServerError::Internal(err) => err.context("Cannot poll server for new certificates").into(),
-                            })?;
-                        continue;
-                    }
+        // 3) Mark the block as uploaded on local storage
 
-                Rep::RealmArchived => return Err(WorkspaceSyncError::RealmArchived),
-                Rep::RealmDeleted => return Err(WorkspaceSyncError::RealmDeleted),
+        // A deduplicated block cannot be promoted given the manifest still refers
+        // to the chunk ID, this is instead done once the manifest is updated.
+        if uploaded_block_id != block_access.id {
+            deduplicated_blocks.push(DeduplicatedBlock {
+                chunk_id,
+                block_id: uploaded_block_id,
+                data,
+            });
+            continue;
+        }
 
-                // Unexpected errors :(
-                bad_rep @ (
-                    // Already checked the realm exists when we called `CertificateOps::encrypt_for_realm`
-                    | Rep::RealmNotFound
-                    // Don't know what to do with this status :/
-                    | Rep::UnknownStatus { .. }
-                ) => {
-                    return Err(anyhow::anyhow!("Unexpected server response: {:?}", bad_rep).into())
+        ops.store
+            .promote_local_only_chunk_to_uploaded_block(chunk_id)
+            .await
+            .map_err(|err| match err {
+                WorkspaceStoreOperationError::Stopped => WorkspaceSyncError::Stopped,
+                WorkspaceStoreOperationError::Internal(err) => {
+                    err.context("cannot promote chunk to uploaded block").into()
                 }
+            })?;
+    }
+
+    Ok(deduplicated_blocks)
+}
+
+enum UploadBlockOutcome {
+    Uploaded,
+    /// The server already has a block with this ID.
+    ///
+    /// Without block deduplication, this means the block has already been uploaded
+    /// (this might happen when a failure occurs before the local storage is updated).
+    AlreadyExists,
+    /// With block deduplication, the block with this ID is already in our local cache
+    /// (i.e. it has already been uploaded or downloaded and validated), hence there is
+    /// no need to upload it.
+    KnownLocally,
+}
+
+/// Encrypt and upload a block.
+///
+/// With block deduplication, the returned block ID is derived from the block's content,
+/// otherwise it is the block access's ID.
+async fn upload_block(
+    ops: &WorkspaceOps,
+    block_access: &BlockAccess,
+    data: &[u8],
+    deduplicated: bool,
+) -> Result<(BlockID, UploadBlockOutcome), WorkspaceSyncError> {
+    // Note that, with block deduplication, compression is done while encrypting
+    // (given the block ID depends on the compressed flag).
+    let compressed_data;
+    let payload: &[u8] = if block_access.compressed && !deduplicated {
+        compressed_data = BlockAccess::compress_data(data);
+        &compressed_data
+    } else {
+        data
+    };
+
+    loop {
+        let outcome = if deduplicated {
+            ops.certificates_ops
+                .encrypt_deduplicated_block_for_realm(ops.realm_id(), data, block_access.compressed)
+                .await
+        } else {
+            ops.certificates_ops
+                .encrypt_for_realm(
+                    EncrytionUsage::Block(block_access.id),
+                    ops.realm_id(),
+                    payload,
+                )
+                .await
+                .map(|(encrypted, key_index)| (block_access.id, encrypted, key_index))
+        };
+        let (block_id, encrypted, key_index) = outcome.map_err(|e| match e {
+            CertifEncryptForRealmError::Stopped => WorkspaceSyncError::Stopped,
+            CertifEncryptForRealmError::Offline(e) => WorkspaceSyncError::Offline(e),
+            CertifEncryptForRealmError::NotAllowed => WorkspaceSyncError::NotAllowed,
+            CertifEncryptForRealmError::RealmDeleted => WorkspaceSyncError::RealmDeleted,
+            CertifEncryptForRealmError::NoKey => WorkspaceSyncError::NoKey,
+            CertifEncryptForRealmError::InvalidKeysBundle(err) => {
+                WorkspaceSyncError::InvalidKeysBundle(err)
+            }
+            CertifEncryptForRealmError::Internal(err) => {
+                err.context("Cannot encrypt manifest for realm").into()
             }
-            break;
+        })?;
+
+        if deduplicated && is_block_known_locally(ops, block_access, block_id, data).await? {
+            return Ok((block_id, UploadBlockOutcome::KnownLocally));
         }
 
-        // 3) Mark the block as uploaded on local storage
+        use authenticated_cmds::latest::block_create::{Rep, Req};
+        let req = Req {
+            realm_id: ops.realm_id,
+            key_index,
+            block_id,
+            block: encrypted.into(),
+        };
+        let rep = ops.cmds.send(req).await?;
+        match rep {
+            Rep::Ok => return Ok((block_id, UploadBlockOutcome::Uploaded)),
+            Rep::BlockAlreadyExists => return Ok((block_id, UploadBlockOutcome::AlreadyExists)),
+            Rep::AuthorNotAllowed => return Err(WorkspaceSyncError::NotAllowed),
+            // Nothing we can do if server is not ready to store our data, retry later
+            Rep::StoreUnavailable => return Err(WorkspaceSyncError::ServerBlockstoreUnavailable),
+            // A key rotation occurred concurrently, should poll for new certificates and retry
+            Rep::BadKeyIndex {
+                last_realm_certificate_timestamp,
+            } => {
+                let latest_known_timestamps = PerTopicLastTimestamps::new_for_realm(
+                    ops.realm_id,
+                    last_realm_certificate_timestamp,
+                );
+                ops.certificates_ops
+                    .poll_server_for_new_certificates(Some(&latest_known_timestamps))
+                    .await
+                    .map_err(|err| match err {
+                        CertifPollServerError::Stopped => WorkspaceSyncError::Stopped,
+                        CertifPollServerError::Offline(e) => WorkspaceSyncError::Offline(e),
+                        CertifPollServerError::InvalidCertificate(err) => {
+                            WorkspaceSyncError::InvalidCertificate(err)
+                        }
+                        CertifPollServerError::Internal(err) => err
+                            .context("Cannot poll server for new certificates")
+                            .into(),
+                    })?;
+                continue;
+            }
+
+            Rep::RealmArchived => return Err(WorkspaceSyncError::RealmArchived),
+            Rep::RealmDeleted => return Err(WorkspaceSyncError::RealmDeleted),
+
+            // Unexpected errors :(
+            bad_rep @ (
+                // Already checked the realm exists when we called `CertificateOps::encrypt_for_realm`
+                Rep::RealmNotFound
+                // Don't know what to do with this status :/
+                | Rep::UnknownStatus { .. }
+            ) => {
+                return Err(anyhow::anyhow!("Unexpected server response: {:?}", bad_rep).into())
+            }
+        }
+    }
+}
+
+/// Check if the block with the deduplicated ID is already in the local cache, in which
+/// case it has already been validated (given it has either been uploaded by us, or
+/// downloaded and checked against a block access).
+async fn is_block_known_locally(
+    ops: &WorkspaceOps,
+    block_access: &BlockAccess,
+    block_id: BlockID,
+    data: &[u8],
+) -> Result<bool, WorkspaceSyncError> {
+    let mut expected_access = block_access.clone();
+    expected_access.id = block_id;
+    let outcome = ops
+        .store
+        .get_chunk_or_block_local_only(&ChunkView::from_block_access(expected_access))
+        .await;
+    match outcome {
+        Ok(cached) => Ok(*cached == *data),
+        Err(ReadChunkOrBlockLocalOnlyError::ChunkNotFound) => Ok(false),
+        Err(ReadChunkOrBlockLocalOnlyError::Stopped) => Err(WorkspaceSyncError::Stopped),
+        Err(ReadChunkOrBlockLocalOnlyError::Internal(err)) => {
+            Err(err.context("cannot get block from local cache").into())
+        }
+    }
+}
 
+/// With block deduplication, the block ID is derived from the block's content with a
+/// key shared by all the realm members. Hence a malicious member can upload junk data
+/// under the ID of a block it expects to be uploaded by others (i.e. poisoning the
+/// files that would refer to it).
+///
+/// So a block already existing on the server must be fetched and checked against the
+/// block access (i.e. decrypted and compared with the size and hash digest) before
+/// being reused.
+///
+/// Return `false` if the existing block doesn't correspond to the block access.
+async fn check_existing_deduplicated_block(
+    ops: &WorkspaceOps,
+    manifest: &LocalFileManifest,
+    block_access: &BlockAccess,
+    block_id: BlockID,
+) -> Result<bool, WorkspaceSyncError> {
+    let mut expected_access = block_access.clone();
+    expected_access.id = block_id;
+    let outcome = server_fetch_block(
+        &ops.cmds,
+        &ops.certificates_ops,
+        ops.realm_id,
+        &manifest.base,
+        &expected_access,
+    )
+    .await;
+    match outcome {
+        Ok(_) => Ok(true),
+        Err(ServerFetchBlockError::InvalidBlockAccess(_)) => Ok(false),
+        Err(err) => Err(match err {
+            ServerFetchBlockError::Stopped => WorkspaceSyncError::Stopped,
+            ServerFetchBlockError::Offline(e) => WorkspaceSyncError::Offline(e),
+            ServerFetchBlockError::RealmDeleted => WorkspaceSyncError::RealmDeleted,
+            ServerFetchBlockError::NoRealmAccess => WorkspaceSyncError::NotAllowed,
+            ServerFetchBlockError::ServerBlockstoreUnavailable => {
+                WorkspaceSyncError::ServerBlockstoreUnavailable
+            }
+            ServerFetchBlockError::InvalidKeysBundle(err) => {
+                WorkspaceSyncError::InvalidKeysBundle(err)
+            }
+            ServerFetchBlockError::InvalidCertificate(err) => {
+                WorkspaceSyncError::InvalidCertificate(err)
+            }
+            // The server has just told us the block exists in the realm
+            err @ (ServerFetchBlockError::RealmNotFound
+            | ServerFetchBlockError::BlockNotFound
+            | ServerFetchBlockError::InvalidBlockAccess(_)) => {
+                anyhow::anyhow!("Unexpected error while fetching existing block: {err}").into()
+            }
+            ServerFetchBlockError::Internal(err) => {
+                err.context("cannot fetch existing block").into()
+            }
+        }),
+    }
+}
+
+/// Replace the chunk IDs by the deduplicated block IDs in the manifest, then
+/// move the chunks' data to the blocks cache.
+///
+/// If the manifest has been modified in the meantime, the sync is aborted and the
+/// chunks are kept as is: they will be uploaded again on the next sync attempt
+/// (which is cheap given the server already has them).
+async fn apply_deduplicated_blocks(
+    ops: &WorkspaceOps,
+    mut manifest: Arc<LocalFileManifest>,
+    deduplicated_blocks: Vec<DeduplicatedBlock>,
+) -> Result<ReshapeAndUploadBlocksOutcome, WorkspaceSyncError> {
+    let original_manifest = manifest.clone();
+
+    // 1) Lock back the entry or abort if it has changed in the meantime
+
+    let updater = {
+        let outcome = ops
+            .store
+            .for_update_sync_local_only(original_manifest.base.id)
+            .await;
+        match outcome {
+            Ok((updater, Some(ArcLocalChildManifest::File(refreshed_manifest)))) => {
+                if file_has_changed(&original_manifest, &refreshed_manifest) {
+                    return Ok(ReshapeAndUploadBlocksOutcome::EntryIsBusy);
+                }
+                updater
+            }
+            // Entry has changed type, hence it has been modified and we should retry later
+            Ok((_, None | Some(ArcLocalChildManifest::Folder(_)))) => {
+                return Ok(ReshapeAndUploadBlocksOutcome::EntryIsBusy)
+            }
+            Err(ForUpdateSyncLocalOnlyError::WouldBlock) => {
+                return Ok(ReshapeAndUploadBlocksOutcome::EntryIsBusy)
+            }
+            Err(ForUpdateSyncLocalOnlyError::Stopped) => return Err(WorkspaceSyncError::Stopped),
+            Err(ForUpdateSyncLocalOnlyError::Internal(err)) => {
+                return Err(err.context("cannot access entry in store").into())
+            }
+        }
+    };
+
+    // 2) Populate the blocks cache with the deduplicated blocks' data
+
+    // Note this is done while holding the update lock: this way the data is
+    // guaranteed to be available once the manifest refers to the new block IDs.
+    for deduplicated_block in deduplicated_blocks.iter() {
         ops.store
-            .promote_local_only_chunk_to_uploaded_block(chunk_view.id)
+            .set_uploaded_block(deduplicated_block.block_id, &deduplicated_block.data)
             .await
             .map_err(|err| match err {
                 WorkspaceStoreOperationError::Stopped => WorkspaceSyncError::Stopped,
                 WorkspaceStoreOperationError::Internal(err) => {
-                    err.context("cannot promote chunk to uploaded block").into()
+                    err.context("cannot store deduplicated block").into()
                 }
             })?;
     }
 
-    Ok(())
+    // 3) Update the manifest and remove the chunks that are no longer used
+
+    let manifest_mut = Arc::make_mut(&mut manifest);
+    for chunk_view in manifest_mut.blocks.iter_mut().flatten() {
+        let found = deduplicated_blocks
+            .iter()
+            .find(|deduplicated_block| deduplicated_block.chunk_id == chunk_view.id);
+        if let Some(deduplicated_block) = found {
+            chunk_view.id = ChunkID::from(*deduplicated_block.block_id);
+            if let Some(access) = chunk_view.access.as_mut() {
+                access.id = deduplicated_block.block_id;
+            }
+        }
+    }
+
+    updater
+        .update_file_manifest_and_chunks(
+            manifest.clone(),
+            std::iter::empty(),
+            deduplicated_blocks
+                .iter()
+                .map(|deduplicated_block| deduplicated_block.chunk_id),
+        )
+        .await
+        .map_err(|err| match err {
+            WorkspaceStoreOperationError::Stopped => WorkspaceSyncError::Stopped,
+            WorkspaceStoreOperationError::Internal(err) => {
+                err.context("cannot update file manifest&chunks").into()
+            }
+        })?;
+
+    Ok(ReshapeAndUploadBlocksOutcome::Done(manifest))
 }
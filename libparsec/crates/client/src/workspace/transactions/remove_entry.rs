@@ -207,7 +207,7 @@ pub(crate) async fn remove_entry(
 
     mut_parent_manifest.evolve_children_and_mark_updated(
         HashMap::from([(child_name, None)]),
-        &ops.config.prevent_sync_pattern,
+        &ops.store.get_prevent_sync_pattern(),
         ops.device.time_provider.now(),
     );
 
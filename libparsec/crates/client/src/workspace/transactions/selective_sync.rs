@@ -0,0 +1,97 @@
+// Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+use libparsec_client_connection::ConnectionError;
+use libparsec_types::prelude::*;
+
+use crate::{
+    certif::{InvalidCertificateError, InvalidKeysBundleError, InvalidManifestError},
+    workspace::{
+        store::{GetManifestError, WorkspaceStoreOperationError},
+        WorkspaceOps,
+    },
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum WorkspaceSetRemoteOnlyEntriesError {
+    #[error("Component has stopped")]
+    Stopped,
+    #[error(transparent)]
+    Internal(#[from] anyhow::Error),
+}
+
+/// Replace the selective sync configuration of this device for the workspace.
+///
+/// Each provided entry is the root of a subtree whose file contents is never
+/// kept in the local storage (i.e. it is always fetched from the server on read).
+/// Note the manifests of those entries are still synchronized as usual.
+///
+/// Blocks already present in the local storage for the newly remote only subtrees
+/// are removed. However data modified locally but not yet synchronized is kept
+/// until it has been uploaded.
+pub async fn set_remote_only_entries(
+    ops: &WorkspaceOps,
+    entries: &[VlobID],
+) -> Result<(), WorkspaceSetRemoteOnlyEntriesError> {
+    ops.store
+        .set_remote_only_entries(entries)
+        .await
+        .map_err(|err| match err {
+            WorkspaceStoreOperationError::Stopped => WorkspaceSetRemoteOnlyEntriesError::Stopped,
+            WorkspaceStoreOperationError::Internal(err) => {
+                err.context("cannot set remote only entries").into()
+            }
+        })
+}
+
+pub fn get_remote_only_entries(ops: &WorkspaceOps) -> Vec<VlobID> {
+    ops.store.get_remote_only_entries()
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum WorkspaceIsEntryRemoteOnlyError {
+    #[error("Cannot communicate with the server: {0}")]
+    Offline(#[from] ConnectionError),
+    #[error("Component has stopped")]
+    Stopped,
+    #[error("Path doesn't exist")]
+    EntryNotFound,
+    #[error("Not allowed to access this realm")]
+    NoRealmAccess,
+    #[error("The workspace's realm has been deleted on the server")]
+    RealmDeleted,
+    #[error(transparent)]
+    InvalidKeysBundle(#[from] Box<InvalidKeysBundleError>),
+    #[error(transparent)]
+    InvalidCertificate(#[from] Box<InvalidCertificateError>),
+    #[error(transparent)]
+    InvalidManifest(#[from] Box<InvalidManifestError>),
+    #[error(transparent)]
+    Internal(#[from] anyhow::Error),
+}
+
+/// Check if the entry is part of a subtree excluded from selective sync.
+pub async fn is_entry_remote_only_by_id(
+    ops: &WorkspaceOps,
+    entry_id: VlobID,
+) -> Result<bool, WorkspaceIsEntryRemoteOnlyError> {
+    ops.store
+        .is_entry_remote_only(entry_id)
+        .await
+        .map_err(|err| match err {
+            GetManifestError::Offline(e) => WorkspaceIsEntryRemoteOnlyError::Offline(e),
+            GetManifestError::Stopped => WorkspaceIsEntryRemoteOnlyError::Stopped,
+            GetManifestError::EntryNotFound => WorkspaceIsEntryRemoteOnlyError::EntryNotFound,
+            GetManifestError::NoRealmAccess => WorkspaceIsEntryRemoteOnlyError::NoRealmAccess,
+            GetManifestError::RealmDeleted => WorkspaceIsEntryRemoteOnlyError::RealmDeleted,
+            GetManifestError::InvalidKeysBundle(err) => {
+                WorkspaceIsEntryRemoteOnlyError::InvalidKeysBundle(err)
+            }
+            GetManifestError::InvalidCertificate(err) => {
+                WorkspaceIsEntryRemoteOnlyError::InvalidCertificate(err)
+            }
+            GetManifestError::InvalidManifest(err) => {
+                WorkspaceIsEntryRemoteOnlyError::InvalidManifest(err)
+            }
+            GetManifestError::Internal(err) => err.context("cannot get manifest").into(),
+        })
+}
@@ -0,0 +1,128 @@
+// Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+use libparsec_types::prelude::*;
+
+use super::WorkspaceSyncError;
+use crate::workspace::{
+    store::{
+        parse_shared_prevent_sync_pattern, ApplySharedPreventSyncPatternError,
+        PathConfinementPoint, ReadChunkOrBlockError, ResolvePathError,
+    },
+    WorkspaceOps,
+};
+
+/// The shared prevent sync pattern file is expected to be small, a bigger file is
+/// most likely a mistake so we'd better not download it.
+const SHARED_PREVENT_SYNC_PATTERN_MAX_SIZE: u64 = 64 * 1024;
+
+pub(crate) fn is_shared_prevent_sync_pattern_related(ops: &WorkspaceOps, entry_id: VlobID) -> bool {
+    if entry_id == ops.realm_id {
+        return true;
+    }
+    let name: EntryName = SHARED_PREVENT_SYNC_PATTERN_FILENAME
+        .parse()
+        .expect("valid entry name");
+    ops.store.get_root_manifest().children.get(&name) == Some(&entry_id)
+}
+
+/// Read the shared prevent sync pattern file (fetching it from the server if needed),
+/// then apply it to the workspace if it has changed.
+///
+/// Returns `true` if a new prevent sync pattern has been applied.
+pub(crate) async fn refresh_shared_prevent_sync_pattern(
+    ops: &WorkspaceOps,
+) -> Result<bool, WorkspaceSyncError> {
+    let path: FsPath = format!("/{}", SHARED_PREVENT_SYNC_PATTERN_FILENAME)
+        .parse()
+        .expect("valid path");
+
+    let outcome = ops.store.resolve_path(&path).await;
+    let shared_prevent_sync_pattern = match outcome {
+        // A confined file is not synchronized, hence it is not shared !
+        Ok((ArcLocalChildManifest::File(manifest), PathConfinementPoint::NotConfined))
+            if manifest.size <= SHARED_PREVENT_SYNC_PATTERN_MAX_SIZE =>
+        {
+            let (size, chunk_views) = super::prepare_read(&manifest, manifest.size, 0);
+            let mut content = Vec::with_capacity(size as usize);
+            let mut content_size = 0;
+            for chunk_view in chunk_views {
+                let chunk_data = ops
+                    .store
+                    .get_chunk_or_block(&chunk_view, &manifest.base)
+                    .await
+                    .map_err(|err| match err {
+                        ReadChunkOrBlockError::Offline(e) => WorkspaceSyncError::Offline(e),
+                        ReadChunkOrBlockError::ServerBlockstoreUnavailable => {
+                            WorkspaceSyncError::ServerBlockstoreUnavailable
+                        }
+                        ReadChunkOrBlockError::Stopped => WorkspaceSyncError::Stopped,
+                        ReadChunkOrBlockError::NoRealmAccess => WorkspaceSyncError::NotAllowed,
+                        ReadChunkOrBlockError::RealmDeleted => WorkspaceSyncError::RealmDeleted,
+                        ReadChunkOrBlockError::InvalidBlockAccess(err) => {
+                            WorkspaceSyncError::InvalidBlockAccess(err)
+                        }
+                        ReadChunkOrBlockError::InvalidCertificate(err) => {
+                            WorkspaceSyncError::InvalidCertificate(err)
+                        }
+                        ReadChunkOrBlockError::InvalidKeysBundle(err) => {
+                            WorkspaceSyncError::InvalidKeysBundle(err)
+                        }
+                        ReadChunkOrBlockError::ChunkNotFound => anyhow::anyhow!(
+                            "Chunk ID {} referenced in local manifest not in local storage !",
+                            chunk_view.id
+                        )
+                        .into(),
+                        ReadChunkOrBlockError::Internal(err) => {
+                            err.context("cannot read chunk").into()
+                        }
+                    })?;
+                chunk_view
+                    .copy_between_start_and_stop(&chunk_data, 0, &mut content, &mut content_size)
+                    .expect("prepare_read/buf/size are consistent");
+            }
+            // Sparse file, missing data are zeros
+            content.resize(size as usize, 0);
+
+            parse_shared_prevent_sync_pattern(&content)
+        }
+
+        Ok((ArcLocalChildManifest::File(_), PathConfinementPoint::NotConfined)) => {
+            log::warn!(
+                "Ignoring `{}` file: bigger than {} bytes",
+                SHARED_PREVENT_SYNC_PATTERN_FILENAME,
+                SHARED_PREVENT_SYNC_PATTERN_MAX_SIZE
+            );
+            PreventSyncPattern::empty()
+        }
+
+        Ok(_) | Err(ResolvePathError::EntryNotFound) => PreventSyncPattern::empty(),
+
+        Err(err) => {
+            return Err(match err {
+                ResolvePathError::Offline(e) => WorkspaceSyncError::Offline(e),
+                ResolvePathError::Stopped => WorkspaceSyncError::Stopped,
+                ResolvePathError::EntryNotFound => unreachable!("handled above"),
+                ResolvePathError::NoRealmAccess => WorkspaceSyncError::NotAllowed,
+                ResolvePathError::RealmDeleted => WorkspaceSyncError::RealmDeleted,
+                ResolvePathError::InvalidKeysBundle(err) => {
+                    WorkspaceSyncError::InvalidKeysBundle(err)
+                }
+                ResolvePathError::InvalidCertificate(err) => {
+                    WorkspaceSyncError::InvalidCertificate(err)
+                }
+                ResolvePathError::InvalidManifest(err) => WorkspaceSyncError::InvalidManifest(err),
+                ResolvePathError::Internal(err) => err.context("cannot resolve path").into(),
+            })
+        }
+    };
+
+    ops.store
+        .apply_shared_prevent_sync_pattern(&shared_prevent_sync_pattern)
+        .await
+        .map_err(|err| match err {
+            ApplySharedPreventSyncPatternError::Stopped => WorkspaceSyncError::Stopped,
+            ApplySharedPreventSyncPatternError::Internal(err) => err
+                .context("cannot apply shared prevent sync pattern")
+                .into(),
+        })
+}
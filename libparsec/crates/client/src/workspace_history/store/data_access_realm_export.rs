@@ -592,8 +592,8 @@ impl RealmExportDataAccess {
             }
         };
 
-        let block: Bytes = match key.decrypt(&encrypted) {
-            Ok(cleartext) => cleartext.into(),
+        let block = match key.decrypt(&encrypted) {
+            Ok(cleartext) => cleartext,
             Err(_) => {
                 return Err(DataAccessFetchBlockError::InvalidBlockAccess(Box::new(
                     InvalidBlockAccessError::CannotDecrypt {
@@ -609,6 +609,22 @@ impl RealmExportDataAccess {
             }
         };
 
+        let block: Bytes = match access.decompress_data(block) {
+            Ok(block) => block.into(),
+            Err(_) => {
+                return Err(DataAccessFetchBlockError::InvalidBlockAccess(Box::new(
+                    InvalidBlockAccessError::CannotDecompress {
+                        realm_id: self.realm_id,
+                        manifest_id: manifest.id,
+                        manifest_version: manifest.version,
+                        manifest_timestamp: manifest.timestamp,
+                        manifest_author: manifest.author,
+                        block_id: access.id,
+                    },
+                )));
+            }
+        };
+
         if block.len() != access.size.get() as usize {
             return Err(DataAccessFetchBlockError::InvalidBlockAccess(Box::new(
                 InvalidBlockAccessError::SizeMismatch {
@@ -0,0 +1,227 @@
+// Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+use std::collections::HashMap;
+
+use libparsec_tests_fixtures::prelude::*;
+use libparsec_types::prelude::*;
+
+use crate::certif::{
+    CertifAddCertificatesBatchError, InvalidCertificateError, MaybeRedactedSwitch,
+};
+
+use super::utils::certificates_ops_factory;
+
+fn revoke_device(
+    author: &LocalDevice,
+    user_id: UserID,
+    device_id: DeviceID,
+    timestamp: DateTime,
+) -> Bytes {
+    RevokedDeviceCertificate {
+        author: author.device_id,
+        timestamp,
+        user_id,
+        device_id,
+    }
+    .dump_and_sign(&author.signing_key)
+    .into()
+}
+
+#[parsec_test(testbed = "minimal")]
+async fn ok(#[values("by_admin", "by_same_user")] kind: &str, env: &TestbedEnv) {
+    env.customize(|builder| {
+        builder.new_user("bob");
+        builder.new_device("bob");
+    })
+    .await;
+    let alice = env.local_device("alice@dev1");
+    let bob1 = env.local_device("bob@dev1");
+    let bob2 = env.local_device("bob@dev2");
+    let ops = certificates_ops_factory(env, &alice).await;
+
+    let author = match kind {
+        "by_admin" => &alice,
+        "by_same_user" => &bob1,
+        unknown => panic!("Unknown kind: {unknown}"),
+    };
+    let mut common_certificates = env.get_common_certificates_signed();
+    common_certificates.push(revoke_device(
+        author,
+        bob2.user_id,
+        bob2.device_id,
+        env.get_last_common_certificate_timestamp().add_us(1),
+    ));
+
+    let switch = ops
+        .add_certificates_batch(&common_certificates, &[], &[], &Default::default())
+        .await
+        .unwrap();
+
+    p_assert_matches!(switch, MaybeRedactedSwitch::NoSwitch { .. });
+}
+
+#[parsec_test(testbed = "minimal")]
+async fn author_not_admin(env: &TestbedEnv) {
+    env.customize(|builder| {
+        builder
+            .new_user("bob")
+            .with_initial_profile(UserProfile::Standard);
+    })
+    .await;
+    let alice = env.local_device("alice@dev1");
+    let bob = env.local_device("bob@dev1");
+    let ops = certificates_ops_factory(env, &alice).await;
+
+    let mut common_certificates = env.get_common_certificates_signed();
+    common_certificates.push(revoke_device(
+        &bob,
+        alice.user_id,
+        alice.device_id,
+        env.get_last_common_certificate_timestamp().add_us(1),
+    ));
+
+    let err = ops
+        .add_certificates_batch(&common_certificates, &[], &[], &Default::default())
+        .await
+        .unwrap_err();
+
+    p_assert_matches!(
+        err,
+        CertifAddCertificatesBatchError::InvalidCertificate(boxed)
+        if matches!(
+            *boxed,
+            InvalidCertificateError::AuthorNotAdmin { author_profile, .. }
+            if author_profile == UserProfile::Standard
+        )
+    );
+}
+
+#[parsec_test(testbed = "minimal")]
+async fn related_device_user_mismatch(env: &TestbedEnv) {
+    env.customize(|builder| {
+        builder.new_user("bob");
+        builder.new_user("mallory");
+    })
+    .await;
+    let alice = env.local_device("alice@dev1");
+    let bob = env.local_device("bob@dev1");
+    let mallory = env.local_device("mallory@dev1");
+    let ops = certificates_ops_factory(env, &alice).await;
+
+    let mut common_certificates = env.get_common_certificates_signed();
+    common_certificates.push(revoke_device(
+        &alice,
+        mallory.user_id,
+        bob.device_id,
+        env.get_last_common_certificate_timestamp().add_us(1),
+    ));
+
+    let err = ops
+        .add_certificates_batch(&common_certificates, &[], &[], &Default::default())
+        .await
+        .unwrap_err();
+
+    p_assert_matches!(
+        err,
+        CertifAddCertificatesBatchError::InvalidCertificate(boxed)
+        if matches!(
+            *boxed,
+            InvalidCertificateError::RelatedDeviceUserMismatch { device_user_id, .. }
+            if device_user_id == bob.user_id
+        )
+    );
+}
+
+#[parsec_test(testbed = "minimal")]
+async fn related_device_already_revoked(env: &TestbedEnv) {
+    env.customize(|builder| {
+        builder.new_user("bob");
+    })
+    .await;
+    let alice = env.local_device("alice@dev1");
+    let bob = env.local_device("bob@dev1");
+    let ops = certificates_ops_factory(env, &alice).await;
+
+    let revoked_on = env.get_last_common_certificate_timestamp().add_us(1);
+    let mut common_certificates = env.get_common_certificates_signed();
+    common_certificates.push(revoke_device(
+        &alice,
+        bob.user_id,
+        bob.device_id,
+        revoked_on,
+    ));
+    common_certificates.push(revoke_device(
+        &alice,
+        bob.user_id,
+        bob.device_id,
+        revoked_on.add_us(1),
+    ));
+
+    let err = ops
+        .add_certificates_batch(&common_certificates, &[], &[], &Default::default())
+        .await
+        .unwrap_err();
+
+    p_assert_matches!(
+        err,
+        CertifAddCertificatesBatchError::InvalidCertificate(boxed)
+        if matches!(
+            *boxed,
+            InvalidCertificateError::RelatedDeviceAlreadyRevoked { device_revoked_on, .. }
+            if device_revoked_on == revoked_on
+        )
+    );
+}
+
+#[parsec_test(testbed = "minimal")]
+async fn signed_by_revoked_device(env: &TestbedEnv) {
+    env.customize(|builder| {
+        builder.new_user("bob");
+    })
+    .await;
+    let alice = env.local_device("alice@dev1");
+    let bob = env.local_device("bob@dev1");
+    let ops = certificates_ops_factory(env, &alice).await;
+
+    let revoked_on = env.get_last_common_certificate_timestamp().add_us(1);
+    let mut common_certificates = env.get_common_certificates_signed();
+    common_certificates.push(revoke_device(
+        &alice,
+        bob.user_id,
+        bob.device_id,
+        revoked_on,
+    ));
+
+    // Bob's revoked device still tries to create a realm
+    let realm_id = VlobID::default();
+    let realm_role: Bytes = RealmRoleCertificate {
+        author: bob.device_id,
+        timestamp: revoked_on.add_us(1),
+        realm_id,
+        user_id: bob.user_id,
+        role: Some(RealmRole::Owner),
+        group_id: None,
+    }
+    .dump_and_sign(&bob.signing_key)
+    .into();
+
+    let err = ops
+        .add_certificates_batch(
+            &common_certificates,
+            &[],
+            &[],
+            &HashMap::from([(realm_id, vec![realm_role])]),
+        )
+        .await
+        .unwrap_err();
+
+    p_assert_matches!(
+        err,
+        CertifAddCertificatesBatchError::InvalidCertificate(boxed)
+        if matches!(
+            *boxed,
+            InvalidCertificateError::RevokedAuthor { author_revoked_on, .. }
+            if author_revoked_on == revoked_on
+        )
+    );
+}
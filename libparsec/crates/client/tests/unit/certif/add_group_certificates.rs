@@ -0,0 +1,486 @@
+// Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+use std::collections::HashMap;
+
+use libparsec_tests_fixtures::prelude::*;
+use libparsec_types::prelude::*;
+
+use crate::certif::{
+    CertifAddCertificatesBatchError, InvalidCertificateError, MaybeRedactedSwitch,
+};
+
+use super::utils::certificates_ops_factory;
+
+fn create_group(author: &LocalDevice, group_id: GroupID, timestamp: DateTime) -> Bytes {
+    GroupCertificate {
+        author: author.device_id,
+        timestamp,
+        group_id,
+        name: "Engineering".to_owned(),
+    }
+    .dump_and_sign(&author.signing_key)
+    .into()
+}
+
+fn update_group_member(
+    author: &LocalDevice,
+    group_id: GroupID,
+    user_id: UserID,
+    is_member: bool,
+    timestamp: DateTime,
+) -> Bytes {
+    GroupMemberCertificate {
+        author: author.device_id,
+        timestamp,
+        group_id,
+        user_id,
+        is_member,
+    }
+    .dump_and_sign(&author.signing_key)
+    .into()
+}
+
+fn give_group_role(
+    author: &LocalDevice,
+    realm_id: VlobID,
+    group_id: GroupID,
+    role: Option<RealmRole>,
+    timestamp: DateTime,
+) -> Bytes {
+    RealmGroupRoleCertificate {
+        author: author.device_id,
+        timestamp,
+        realm_id,
+        group_id,
+        role,
+    }
+    .dump_and_sign(&author.signing_key)
+    .into()
+}
+
+#[parsec_test(testbed = "minimal")]
+async fn ok(env: &TestbedEnv) {
+    env.customize(|builder| {
+        builder.new_user("bob");
+    })
+    .await;
+    let alice = env.local_device("alice@dev1");
+    let bob = env.local_device("bob@dev1");
+    let ops = certificates_ops_factory(env, &alice).await;
+
+    let group_id = GroupID::default();
+    let timestamp = env.get_last_common_certificate_timestamp();
+    let mut common_certificates = env.get_common_certificates_signed();
+    common_certificates.push(create_group(&alice, group_id, timestamp.add_us(1)));
+    common_certificates.push(update_group_member(
+        &alice,
+        group_id,
+        bob.user_id,
+        true,
+        timestamp.add_us(2),
+    ));
+    common_certificates.push(update_group_member(
+        &alice,
+        group_id,
+        bob.user_id,
+        false,
+        timestamp.add_us(3),
+    ));
+
+    let switch = ops
+        .add_certificates_batch(&common_certificates, &[], &[], &Default::default())
+        .await
+        .unwrap();
+
+    p_assert_matches!(switch, MaybeRedactedSwitch::NoSwitch { .. });
+}
+
+#[parsec_test(testbed = "minimal")]
+async fn author_not_admin(#[values("group", "group_member")] kind: &str, env: &TestbedEnv) {
+    env.customize(|builder| {
+        builder
+            .new_user("bob")
+            .with_initial_profile(UserProfile::Standard);
+    })
+    .await;
+    let alice = env.local_device("alice@dev1");
+    let bob = env.local_device("bob@dev1");
+    let ops = certificates_ops_factory(env, &alice).await;
+
+    let group_id = GroupID::default();
+    let timestamp = env.get_last_common_certificate_timestamp();
+    let mut common_certificates = env.get_common_certificates_signed();
+    match kind {
+        "group" => {
+            common_certificates.push(create_group(&bob, group_id, timestamp.add_us(1)));
+        }
+        "group_member" => {
+            common_certificates.push(create_group(&alice, group_id, timestamp.add_us(1)));
+            common_certificates.push(update_group_member(
+                &bob,
+                group_id,
+                bob.user_id,
+                true,
+                timestamp.add_us(2),
+            ));
+        }
+        unknown => panic!("Unknown kind: {unknown}"),
+    }
+
+    let err = ops
+        .add_certificates_batch(&common_certificates, &[], &[], &Default::default())
+        .await
+        .unwrap_err();
+
+    p_assert_matches!(
+        err,
+        CertifAddCertificatesBatchError::InvalidCertificate(boxed)
+        if matches!(
+            *boxed,
+            InvalidCertificateError::AuthorNotAdmin {
+                author_profile: UserProfile::Standard,
+                ..
+            }
+        )
+    );
+}
+
+#[parsec_test(testbed = "minimal")]
+async fn group_already_exists(env: &TestbedEnv) {
+    let alice = env.local_device("alice@dev1");
+    let ops = certificates_ops_factory(env, &alice).await;
+
+    let group_id = GroupID::default();
+    let timestamp = env.get_last_common_certificate_timestamp();
+    let mut common_certificates = env.get_common_certificates_signed();
+    common_certificates.push(create_group(&alice, group_id, timestamp.add_us(1)));
+    common_certificates.push(create_group(&alice, group_id, timestamp.add_us(2)));
+
+    let err = ops
+        .add_certificates_batch(&common_certificates, &[], &[], &Default::default())
+        .await
+        .unwrap_err();
+
+    p_assert_matches!(
+        err,
+        CertifAddCertificatesBatchError::InvalidCertificate(boxed)
+        if matches!(*boxed, InvalidCertificateError::ContentAlreadyExists { .. })
+    );
+}
+
+#[parsec_test(testbed = "minimal")]
+async fn member_of_unknown_group(env: &TestbedEnv) {
+    let alice = env.local_device("alice@dev1");
+    let ops = certificates_ops_factory(env, &alice).await;
+
+    let mut common_certificates = env.get_common_certificates_signed();
+    common_certificates.push(update_group_member(
+        &alice,
+        GroupID::default(),
+        alice.user_id,
+        true,
+        env.get_last_common_certificate_timestamp().add_us(1),
+    ));
+
+    let err = ops
+        .add_certificates_batch(&common_certificates, &[], &[], &Default::default())
+        .await
+        .unwrap_err();
+
+    p_assert_matches!(
+        err,
+        CertifAddCertificatesBatchError::InvalidCertificate(boxed)
+        if matches!(*boxed, InvalidCertificateError::NonExistingRelatedGroup { .. })
+    );
+}
+
+#[parsec_test(testbed = "minimal")]
+async fn member_unchanged(
+    #[values("never_member", "already_member")] kind: &str,
+    env: &TestbedEnv,
+) {
+    let alice = env.local_device("alice@dev1");
+    let ops = certificates_ops_factory(env, &alice).await;
+
+    let group_id = GroupID::default();
+    let timestamp = env.get_last_common_certificate_timestamp();
+    let mut common_certificates = env.get_common_certificates_signed();
+    common_certificates.push(create_group(&alice, group_id, timestamp.add_us(1)));
+    let is_member = match kind {
+        "never_member" => false,
+        "already_member" => {
+            common_certificates.push(update_group_member(
+                &alice,
+                group_id,
+                alice.user_id,
+                true,
+                timestamp.add_us(2),
+            ));
+            true
+        }
+        unknown => panic!("Unknown kind: {unknown}"),
+    };
+    common_certificates.push(update_group_member(
+        &alice,
+        group_id,
+        alice.user_id,
+        is_member,
+        timestamp.add_us(3),
+    ));
+
+    let err = ops
+        .add_certificates_batch(&common_certificates, &[], &[], &Default::default())
+        .await
+        .unwrap_err();
+
+    p_assert_matches!(
+        err,
+        CertifAddCertificatesBatchError::InvalidCertificate(boxed)
+        if matches!(*boxed, InvalidCertificateError::ContentAlreadyExists { .. })
+    );
+}
+
+#[parsec_test(testbed = "minimal")]
+async fn revoked_user_cannot_join_group(env: &TestbedEnv) {
+    env.customize(|builder| {
+        builder.new_user("bob");
+        builder.revoke_user("bob");
+    })
+    .await;
+    let alice = env.local_device("alice@dev1");
+    let bob = env.local_device("bob@dev1");
+    let ops = certificates_ops_factory(env, &alice).await;
+
+    let group_id = GroupID::default();
+    let timestamp = env.get_last_common_certificate_timestamp();
+    let mut common_certificates = env.get_common_certificates_signed();
+    common_certificates.push(create_group(&alice, group_id, timestamp.add_us(1)));
+    common_certificates.push(update_group_member(
+        &alice,
+        group_id,
+        bob.user_id,
+        true,
+        timestamp.add_us(2),
+    ));
+
+    let err = ops
+        .add_certificates_batch(&common_certificates, &[], &[], &Default::default())
+        .await
+        .unwrap_err();
+
+    p_assert_matches!(
+        err,
+        CertifAddCertificatesBatchError::InvalidCertificate(boxed)
+        if matches!(*boxed, InvalidCertificateError::RelatedUserAlreadyRevoked { .. })
+    );
+}
+
+#[parsec_test(testbed = "minimal")]
+async fn realm_group_role(
+    #[values(
+        "ok",
+        "author_not_owner",
+        "unknown_group",
+        "role_unchanged",
+        "owner_role",
+        "manager_role"
+    )]
+    kind: &str,
+    env: &TestbedEnv,
+) {
+    let realm_id = env
+        .customize(|builder| {
+            builder.new_user("bob");
+            let realm_id = builder.new_user_realm("alice").map(|e| e.realm_id);
+            builder.share_realm(realm_id, "bob", RealmRole::Manager);
+            realm_id
+        })
+        .await;
+    let alice = env.local_device("alice@dev1");
+    let bob = env.local_device("bob@dev1");
+    let ops = certificates_ops_factory(env, &alice).await;
+
+    let group_id = GroupID::default();
+    let timestamp = std::cmp::max(
+        env.get_last_common_certificate_timestamp(),
+        env.get_last_realm_certificate_timestamp(realm_id),
+    );
+    let mut common_certificates = env.get_common_certificates_signed();
+    if kind != "unknown_group" {
+        common_certificates.push(create_group(&alice, group_id, timestamp.add_us(1)));
+    }
+    let mut realm_certificates = env.get_realms_certificates_signed();
+    let realm_certificates_for_realm = realm_certificates.get_mut(&realm_id).unwrap();
+    match kind {
+        "ok" | "unknown_group" => {
+            realm_certificates_for_realm.push(give_group_role(
+                &alice,
+                realm_id,
+                group_id,
+                Some(RealmRole::Contributor),
+                timestamp.add_us(2),
+            ));
+        }
+        "author_not_owner" => {
+            realm_certificates_for_realm.push(give_group_role(
+                &bob,
+                realm_id,
+                group_id,
+                Some(RealmRole::Contributor),
+                timestamp.add_us(2),
+            ));
+        }
+        "role_unchanged" => {
+            realm_certificates_for_realm.push(give_group_role(
+                &alice,
+                realm_id,
+                group_id,
+                Some(RealmRole::Contributor),
+                timestamp.add_us(2),
+            ));
+            realm_certificates_for_realm.push(give_group_role(
+                &alice,
+                realm_id,
+                group_id,
+                Some(RealmRole::Contributor),
+                timestamp.add_us(3),
+            ));
+        }
+        "owner_role" | "manager_role" => {
+            let role = if kind == "owner_role" {
+                RealmRole::Owner
+            } else {
+                RealmRole::Manager
+            };
+            realm_certificates_for_realm.push(give_group_role(
+                &alice,
+                realm_id,
+                group_id,
+                Some(role),
+                timestamp.add_us(2),
+            ));
+        }
+        unknown => panic!("Unknown kind: {unknown}"),
+    }
+
+    let outcome = ops
+        .add_certificates_batch(&common_certificates, &[], &[], &realm_certificates)
+        .await;
+
+    match kind {
+        "ok" => {
+            p_assert_matches!(outcome.unwrap(), MaybeRedactedSwitch::NoSwitch { .. });
+        }
+        "author_not_owner" => {
+            p_assert_matches!(
+                outcome.unwrap_err(),
+                CertifAddCertificatesBatchError::InvalidCertificate(boxed)
+                if matches!(
+                    *boxed,
+                    InvalidCertificateError::RealmAuthorNotOwner {
+                        author_role: RealmRole::Manager,
+                        ..
+                    }
+                )
+            );
+        }
+        "unknown_group" => {
+            p_assert_matches!(
+                outcome.unwrap_err(),
+                CertifAddCertificatesBatchError::InvalidCertificate(boxed)
+                if matches!(*boxed, InvalidCertificateError::NonExistingRelatedGroup { .. })
+            );
+        }
+        "role_unchanged" => {
+            p_assert_matches!(
+                outcome.unwrap_err(),
+                CertifAddCertificatesBatchError::InvalidCertificate(boxed)
+                if matches!(*boxed, InvalidCertificateError::ContentAlreadyExists { .. })
+            );
+        }
+        "owner_role" | "manager_role" => {
+            p_assert_matches!(
+                outcome.unwrap_err(),
+                CertifAddCertificatesBatchError::InvalidCertificate(boxed)
+                if matches!(*boxed, InvalidCertificateError::RealmGroupCannotBeOwnerOrManager { .. })
+            );
+        }
+        unknown => panic!("Unknown kind: {unknown}"),
+    }
+}
+
+#[parsec_test(testbed = "minimal")]
+async fn realm_role_with_unknown_group(env: &TestbedEnv) {
+    let realm_id = env
+        .customize(|builder| {
+            builder.new_user("bob");
+            builder.new_user_realm("alice").map(|e| e.realm_id)
+        })
+        .await;
+    let alice = env.local_device("alice@dev1");
+    let bob = env.local_device("bob@dev1");
+    let ops = certificates_ops_factory(env, &alice).await;
+
+    let mut realm_certificates = env.get_realms_certificates_signed();
+    realm_certificates.get_mut(&realm_id).unwrap().push(
+        RealmRoleCertificate {
+            author: alice.device_id,
+            timestamp: env.get_last_realm_certificate_timestamp(realm_id).add_us(1),
+            realm_id,
+            user_id: bob.user_id,
+            role: Some(RealmRole::Reader),
+            group_id: Some(GroupID::default()),
+        }
+        .dump_and_sign(&alice.signing_key)
+        .into(),
+    );
+
+    let err = ops
+        .add_certificates_batch(
+            &env.get_common_certificates_signed(),
+            &[],
+            &[],
+            &realm_certificates,
+        )
+        .await
+        .unwrap_err();
+
+    p_assert_matches!(
+        err,
+        CertifAddCertificatesBatchError::InvalidCertificate(boxed)
+        if matches!(*boxed, InvalidCertificateError::NonExistingRelatedGroup { .. })
+    );
+}
+
+#[parsec_test(testbed = "minimal")]
+async fn realm_group_role_in_unknown_realm(env: &TestbedEnv) {
+    let alice = env.local_device("alice@dev1");
+    let ops = certificates_ops_factory(env, &alice).await;
+
+    let group_id = GroupID::default();
+    let realm_id = VlobID::default();
+    let timestamp = env.get_last_common_certificate_timestamp();
+    let mut common_certificates = env.get_common_certificates_signed();
+    common_certificates.push(create_group(&alice, group_id, timestamp.add_us(1)));
+    let realm_certificates = HashMap::from([(
+        realm_id,
+        vec![give_group_role(
+            &alice,
+            realm_id,
+            group_id,
+            Some(RealmRole::Reader),
+            timestamp.add_us(2),
+        )],
+    )]);
+
+    let err = ops
+        .add_certificates_batch(&common_certificates, &[], &[], &realm_certificates)
+        .await
+        .unwrap_err();
+
+    p_assert_matches!(
+        err,
+        CertifAddCertificatesBatchError::InvalidCertificate(boxed)
+        if matches!(*boxed, InvalidCertificateError::RealmAuthorHasNoRole { .. })
+    );
+}
@@ -5,8 +5,9 @@ use std::collections::HashMap;
 use libparsec_tests_fixtures::prelude::*;
 use libparsec_types::prelude::*;
 
-use crate::certif::{
-    CertifAddCertificatesBatchError, InvalidCertificateError, MaybeRedactedSwitch,
+use crate::{
+    certif::{CertifAddCertificatesBatchError, InvalidCertificateError, MaybeRedactedSwitch},
+    EventNewCertificates, EventRealmRoleChanged, EventSelfShared,
 };
 
 use super::utils::certificates_ops_factory;
@@ -79,6 +80,7 @@ async fn ok_with_existing_certificates(
     let ops = certificates_ops_factory(env, &alice).await;
 
     let (_, certif) = env.get_last_realm_role_certificate("alice", wksp1_id);
+    let mut spy = ops.event_bus.spy.start_expecting();
     let switch = ops
         .add_certificates_batch(
             &[],
@@ -90,6 +92,27 @@ async fn ok_with_existing_certificates(
         .unwrap();
 
     p_assert_matches!(switch, MaybeRedactedSwitch::NoSwitch { .. });
+
+    let (expected_old_role, expected_new_role) = match (last_known_role, new_role) {
+        ("none", "manager") => (None, RealmRole::Manager),
+        ("none", "owner") => (None, RealmRole::Owner),
+        ("reader", "manager") => (Some(RealmRole::Reader), RealmRole::Manager),
+        ("reader", "owner") => (Some(RealmRole::Reader), RealmRole::Owner),
+        unknown => panic!("Unknown kind: {unknown:?}"),
+    };
+    if expected_old_role.is_none() {
+        spy.assert_next(|e: &EventSelfShared| {
+            p_assert_eq!(e.realm_id, wksp1_id);
+            p_assert_eq!(e.role, expected_new_role);
+        });
+    }
+    spy.assert_next(|e: &EventRealmRoleChanged| {
+        p_assert_eq!(e.realm_id, wksp1_id);
+        p_assert_eq!(e.user_id, alice.user_id);
+        p_assert_eq!(e.old_role, expected_old_role);
+        p_assert_eq!(e.new_role, Some(expected_new_role));
+    });
+    spy.assert_next(|_: &EventNewCertificates| {});
 }
 
 #[parsec_test(testbed = "minimal")]
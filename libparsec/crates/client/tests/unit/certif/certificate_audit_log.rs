@@ -0,0 +1,287 @@
+// Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+use libparsec_client_connection::{test_register_send_hook, test_send_hook_realm_get_keys_bundle};
+use libparsec_protocol::authenticated_cmds;
+use libparsec_tests_fixtures::prelude::*;
+use libparsec_types::prelude::*;
+
+use crate::certif::{
+    CertificateAuditLogEvent, CertificateAuditLogFilter, CertificateAuditLogRealm,
+    CertificateAuditLogUser,
+};
+
+use super::utils::certificates_ops_factory;
+
+#[parsec_test(testbed = "minimal")]
+async fn ok(env: &TestbedEnv) {
+    let realm_id = env
+        .customize(|builder| {
+            builder.new_user("bob");
+            let realm_id = builder
+                .new_realm("alice")
+                .then_do_initial_key_rotation()
+                .map(|event| event.realm);
+            builder.rename_realm(realm_id, "wksp1");
+            builder.share_realm(realm_id, "bob", Some(RealmRole::Reader));
+            builder.revoke_user("bob");
+
+            builder.certificates_storage_fetch_certificates("alice@dev1");
+
+            realm_id
+        })
+        .await;
+    let alice = env.local_device("alice@dev1");
+    let bob_user_id: UserID = "bob".parse().unwrap();
+    let ops = certificates_ops_factory(env, &alice).await;
+
+    test_register_send_hook(
+        &env.discriminant_dir,
+        test_send_hook_realm_get_keys_bundle!(env, alice.user_id, realm_id),
+    );
+
+    let entries = ops
+        .certificate_audit_log(CertificateAuditLogFilter::default())
+        .await
+        .unwrap();
+
+    let events: Vec<_> = entries.iter().map(|entry| &entry.event).collect();
+    p_assert_matches!(
+        events.as_slice(),
+        [
+            CertificateAuditLogEvent::UserCreated { user: CertificateAuditLogUser { user_id: user1, .. }, profile: UserProfile::Admin },
+            CertificateAuditLogEvent::DeviceCreated { device_id: device1, .. },
+            CertificateAuditLogEvent::UserCreated { user: CertificateAuditLogUser { user_id: user2, .. }, profile: UserProfile::Standard },
+            CertificateAuditLogEvent::DeviceCreated { user: CertificateAuditLogUser { user_id: user3, .. }, .. },
+            CertificateAuditLogEvent::RealmRoleChanged { realm: CertificateAuditLogRealm { name: None, .. }, role: Some(RealmRole::Owner), .. },
+            CertificateAuditLogEvent::RealmKeyRotated { realm: CertificateAuditLogRealm { name: None, .. }, key_index: 1 },
+            CertificateAuditLogEvent::RealmRenamed { realm: CertificateAuditLogRealm { name: Some(name1), .. } },
+            CertificateAuditLogEvent::RealmRoleChanged { realm: CertificateAuditLogRealm { name: Some(name2), .. }, role: Some(RealmRole::Reader), .. },
+            CertificateAuditLogEvent::UserRevoked { user: CertificateAuditLogUser { user_id: user4, .. } },
+        ]
+        if *user1 == alice.user_id
+            && *device1 == alice.device_id
+            && *user2 == bob_user_id
+            && *user3 == bob_user_id
+            && name1.as_ref() == "wksp1"
+            && name2.as_ref() == "wksp1"
+            && *user4 == bob_user_id
+    );
+
+    // Certificates created during the organization bootstrap are signed by the root key
+    p_assert_eq!(entries[0].author, None);
+    p_assert_eq!(entries[1].author, None);
+    for entry in entries[2..].iter() {
+        let author = entry.author.as_ref().unwrap();
+        p_assert_eq!(author.device_id, alice.device_id);
+        p_assert_eq!(author.user.human_handle, alice.human_handle);
+    }
+
+    // Filter by user (note the realm name keys bundle is now in cache)
+
+    let entries = ops
+        .certificate_audit_log(CertificateAuditLogFilter {
+            user: Some(bob_user_id),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    let events: Vec<_> = entries.iter().map(|entry| &entry.event).collect();
+    p_assert_matches!(
+        events.as_slice(),
+        [
+            CertificateAuditLogEvent::UserCreated { .. },
+            CertificateAuditLogEvent::DeviceCreated { .. },
+            CertificateAuditLogEvent::RealmRoleChanged { .. },
+            CertificateAuditLogEvent::UserRevoked { .. },
+        ]
+    );
+
+    // Filter by realm
+
+    let entries = ops
+        .certificate_audit_log(CertificateAuditLogFilter {
+            realm: Some(realm_id),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    let events: Vec<_> = entries.iter().map(|entry| &entry.event).collect();
+    p_assert_matches!(
+        events.as_slice(),
+        [
+            CertificateAuditLogEvent::RealmRoleChanged { .. },
+            CertificateAuditLogEvent::RealmKeyRotated { .. },
+            CertificateAuditLogEvent::RealmRenamed { .. },
+            CertificateAuditLogEvent::RealmRoleChanged { .. },
+        ]
+    );
+
+    // Filter by time range
+
+    let entries = ops
+        .certificate_audit_log(CertificateAuditLogFilter {
+            since: Some(entries[1].timestamp),
+            until: Some(entries[2].timestamp),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    let events: Vec<_> = entries.iter().map(|entry| &entry.event).collect();
+    p_assert_matches!(
+        events.as_slice(),
+        [
+            CertificateAuditLogEvent::RealmKeyRotated { .. },
+            CertificateAuditLogEvent::RealmRenamed { .. },
+        ]
+    );
+}
+
+#[parsec_test(testbed = "minimal")]
+async fn realm_name_not_decryptable(env: &TestbedEnv) {
+    let realm_id = env
+        .customize(|builder| {
+            let realm_id = builder
+                .new_realm("alice")
+                .then_do_initial_key_rotation()
+                .map(|event| event.realm);
+            builder.rename_realm(realm_id, "wksp1");
+
+            builder.certificates_storage_fetch_certificates("alice@dev1");
+
+            realm_id
+        })
+        .await;
+    let alice = env.local_device("alice@dev1");
+    let ops = certificates_ops_factory(env, &alice).await;
+
+    // Keys bundle is not available, the audit log must still be provided
+    test_register_send_hook(
+        &env.discriminant_dir,
+        |_: authenticated_cmds::latest::realm_get_keys_bundle::Req| {
+            authenticated_cmds::latest::realm_get_keys_bundle::Rep::AuthorNotAllowed
+        },
+    );
+
+    let entries = ops
+        .certificate_audit_log(CertificateAuditLogFilter {
+            realm: Some(realm_id),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+    let events: Vec<_> = entries.iter().map(|entry| &entry.event).collect();
+    p_assert_matches!(
+        events.as_slice(),
+        [
+            CertificateAuditLogEvent::RealmRoleChanged {
+                realm: CertificateAuditLogRealm { name: None, .. },
+                ..
+            },
+            CertificateAuditLogEvent::RealmKeyRotated {
+                realm: CertificateAuditLogRealm { name: None, .. },
+                ..
+            },
+            CertificateAuditLogEvent::RealmRenamed {
+                realm: CertificateAuditLogRealm { name: None, .. }
+            },
+        ]
+    );
+}
+
+#[parsec_test(testbed = "empty")]
+async fn sequester(env: &TestbedEnv) {
+    let service_id = env
+        .customize(|builder| {
+            builder
+                .bootstrap_organization("alice")
+                .and_set_sequestered_organization();
+            let service_id = builder.new_sequester_service().map(|event| event.id);
+            builder.revoke_sequester_service(service_id);
+
+            builder.certificates_storage_fetch_certificates("alice@dev1");
+
+            service_id
+        })
+        .await;
+    let alice = env.local_device("alice@dev1");
+    let ops = certificates_ops_factory(env, &alice).await;
+
+    let entries = ops
+        .certificate_audit_log(CertificateAuditLogFilter::default())
+        .await
+        .unwrap();
+
+    let events: Vec<_> = entries.iter().map(|entry| &entry.event).collect();
+    p_assert_matches!(
+        events.as_slice(),
+        [
+            CertificateAuditLogEvent::UserCreated { .. },
+            CertificateAuditLogEvent::DeviceCreated { .. },
+            CertificateAuditLogEvent::SequesterAuthorityCreated,
+            CertificateAuditLogEvent::SequesterServiceCreated { service_id: id1, service_label: label1 },
+            CertificateAuditLogEvent::SequesterServiceRevoked { service_id: id2, service_label: label2 },
+        ]
+        if *id1 == service_id && *id2 == service_id && label1 == label2
+    );
+
+    // Sequester certificates are not signed by a device
+    for entry in entries[2..].iter() {
+        p_assert_eq!(entry.author, None);
+    }
+}
+
+#[parsec_test(testbed = "minimal")]
+async fn shamir_recovery(env: &TestbedEnv) {
+    env.customize(|builder| {
+        builder.new_user("bob");
+        let recovery_device_id = builder.new_device("bob").map(|e| e.device_id);
+        builder.new_shamir_recovery(
+            "bob",
+            1,
+            [("alice".parse().unwrap(), 2.try_into().unwrap())],
+            recovery_device_id,
+        );
+        builder.delete_shamir_recovery("bob");
+
+        builder.certificates_storage_fetch_certificates("alice@dev1");
+    })
+    .await;
+    let alice = env.local_device("alice@dev1");
+    let bob_user_id: UserID = "bob".parse().unwrap();
+    let ops = certificates_ops_factory(env, &alice).await;
+
+    let entries = ops
+        .certificate_audit_log(CertificateAuditLogFilter {
+            user: Some(bob_user_id),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+    let events: Vec<_> = entries.iter().map(|entry| &entry.event).collect();
+    p_assert_matches!(
+        events.as_slice(),
+        [
+            CertificateAuditLogEvent::UserCreated { .. },
+            CertificateAuditLogEvent::DeviceCreated { .. },
+            CertificateAuditLogEvent::DeviceCreated { .. },
+            CertificateAuditLogEvent::ShamirRecoveryCreated {
+                user: CertificateAuditLogUser { user_id: user1, .. },
+                threshold,
+                recipients,
+            },
+            CertificateAuditLogEvent::ShamirRecoveryDeleted {
+                user: CertificateAuditLogUser { user_id: user2, .. },
+                setup_timestamp,
+            },
+        ]
+        if *user1 == bob_user_id
+            && threshold.get() == 1
+            && recipients.len() == 1
+            && recipients[0].0.user_id == alice.user_id
+            && recipients[0].1.get() == 2
+            && *user2 == bob_user_id
+            && *setup_timestamp == entries[3].timestamp
+    );
+}
@@ -0,0 +1,294 @@
+// Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+use libparsec_tests_fixtures::prelude::*;
+use libparsec_types::prelude::*;
+
+use crate::{
+    certif::MaybeRedactedSwitch, EventDeviceAdded, EventDeviceRevoked, EventNewCertificates,
+    EventRealmRoleChanged, EventSelfShared, EventSelfUnshared, EventSequesterServiceRevoked,
+    EventShamirRecoveryChanged, EventUserProfileUpdated,
+};
+
+use super::utils::certificates_ops_factory;
+
+fn certifs_from_last_event(env: &TestbedEnv) -> Vec<Bytes> {
+    env.template
+        .events
+        .last()
+        .unwrap()
+        .certificates(&env.template)
+        .map(|c| c.signed)
+        .collect()
+}
+
+#[parsec_test(testbed = "minimal")]
+async fn self_shared_and_unshared(#[values("shared", "unshared")] kind: &str, env: &TestbedEnv) {
+    let realm_id = env
+        .customize(|builder| {
+            builder.new_user("bob");
+            let realm_id = builder.new_realm("bob").map(|e| e.realm_id);
+            if kind == "unshared" {
+                builder.share_realm(realm_id, "alice", RealmRole::Reader);
+            }
+
+            builder.certificates_storage_fetch_certificates("alice@dev1");
+
+            match kind {
+                "shared" => {
+                    builder.share_realm(realm_id, "alice", RealmRole::Reader);
+                }
+                "unshared" => {
+                    builder.share_realm(realm_id, "alice", None);
+                }
+                unknown => panic!("Unknown kind: {unknown}"),
+            }
+
+            realm_id
+        })
+        .await;
+    let alice = env.local_device("alice@dev1");
+    let ops = certificates_ops_factory(env, &alice).await;
+
+    let (_, certif) = env.get_last_realm_role_certificate("alice", realm_id);
+    let mut spy = ops.event_bus.spy.start_expecting();
+    let switch = ops
+        .add_certificates_batch(
+            &[],
+            &[],
+            &[],
+            &[(realm_id, vec![certif])].into_iter().collect(),
+        )
+        .await
+        .unwrap();
+
+    p_assert_matches!(switch, MaybeRedactedSwitch::NoSwitch { .. });
+
+    let (expected_old_role, expected_new_role) = match kind {
+        "shared" => {
+            spy.assert_next(|e: &EventSelfShared| {
+                p_assert_eq!(e.realm_id, realm_id);
+                p_assert_eq!(e.role, RealmRole::Reader);
+            });
+            (None, Some(RealmRole::Reader))
+        }
+        "unshared" => {
+            spy.assert_next(|e: &EventSelfUnshared| {
+                p_assert_eq!(e.realm_id, realm_id);
+            });
+            (Some(RealmRole::Reader), None)
+        }
+        unknown => panic!("Unknown kind: {unknown}"),
+    };
+    spy.assert_next(|e: &EventRealmRoleChanged| {
+        p_assert_eq!(e.realm_id, realm_id);
+        p_assert_eq!(e.user_id, alice.user_id);
+        p_assert_eq!(e.old_role, expected_old_role);
+        p_assert_eq!(e.new_role, expected_new_role);
+    });
+    spy.assert_next(|_: &EventNewCertificates| {});
+    spy.assert_no_events();
+}
+
+#[parsec_test(testbed = "minimal")]
+async fn device_added(env: &TestbedEnv) {
+    let device_id = env
+        .customize(|builder| {
+            builder.new_user("bob");
+
+            builder.certificates_storage_fetch_certificates("alice@dev1");
+
+            builder.new_device("bob").map(|e| e.device_id)
+        })
+        .await;
+    let alice = env.local_device("alice@dev1");
+    let bob_user_id: UserID = "bob".parse().unwrap();
+    let ops = certificates_ops_factory(env, &alice).await;
+
+    let certificates = certifs_from_last_event(env);
+    let mut spy = ops.event_bus.spy.start_expecting();
+    ops.add_certificates_batch(&certificates, &[], &[], &Default::default())
+        .await
+        .unwrap();
+
+    spy.assert_next(|e: &EventDeviceAdded| {
+        p_assert_eq!(e.user_id, bob_user_id);
+        p_assert_eq!(e.device_id, device_id);
+    });
+    spy.assert_next(|_: &EventNewCertificates| {});
+    spy.assert_no_events();
+}
+
+#[parsec_test(testbed = "minimal")]
+async fn device_revoked(env: &TestbedEnv) {
+    env.customize(|builder| {
+        builder.new_user("bob");
+        builder.new_device("bob");
+
+        builder.certificates_storage_fetch_certificates("alice@dev1");
+    })
+    .await;
+    let alice = env.local_device("alice@dev1");
+    let bob2 = env.local_device("bob@dev2");
+    let ops = certificates_ops_factory(env, &alice).await;
+
+    let certif: Bytes = RevokedDeviceCertificate {
+        author: alice.device_id,
+        timestamp: env.get_last_common_certificate_timestamp().add_us(1),
+        user_id: bob2.user_id,
+        device_id: bob2.device_id,
+    }
+    .dump_and_sign(&alice.signing_key)
+    .into();
+    let mut spy = ops.event_bus.spy.start_expecting();
+    ops.add_certificates_batch(&[certif], &[], &[], &Default::default())
+        .await
+        .unwrap();
+
+    spy.assert_next(|e: &EventDeviceRevoked| {
+        p_assert_eq!(e.user_id, bob2.user_id);
+        p_assert_eq!(e.device_id, bob2.device_id);
+    });
+    spy.assert_next(|_: &EventNewCertificates| {});
+    spy.assert_no_events();
+}
+
+#[parsec_test(testbed = "minimal")]
+async fn user_profile_updated(env: &TestbedEnv) {
+    env.customize(|builder| {
+        builder.new_user("bob");
+
+        builder.certificates_storage_fetch_certificates("alice@dev1");
+
+        builder.update_user_profile("bob", UserProfile::Admin);
+    })
+    .await;
+    let alice = env.local_device("alice@dev1");
+    let bob_user_id: UserID = "bob".parse().unwrap();
+    let ops = certificates_ops_factory(env, &alice).await;
+
+    let certificates = certifs_from_last_event(env);
+    let mut spy = ops.event_bus.spy.start_expecting();
+    ops.add_certificates_batch(&certificates, &[], &[], &Default::default())
+        .await
+        .unwrap();
+
+    spy.assert_next(|e: &EventUserProfileUpdated| {
+        p_assert_eq!(e.user_id, bob_user_id);
+        p_assert_eq!(e.profile, UserProfile::Admin);
+    });
+    spy.assert_next(|_: &EventNewCertificates| {});
+    spy.assert_no_events();
+}
+
+#[parsec_test(testbed = "empty")]
+async fn sequester_service_revoked(env: &TestbedEnv) {
+    let service_id = env
+        .customize(|builder| {
+            builder
+                .bootstrap_organization("alice")
+                .and_set_sequestered_organization();
+            let service_id = builder.new_sequester_service().map(|event| event.id);
+
+            builder.certificates_storage_fetch_certificates("alice@dev1");
+
+            builder.revoke_sequester_service(service_id);
+
+            service_id
+        })
+        .await;
+    let alice = env.local_device("alice@dev1");
+    let ops = certificates_ops_factory(env, &alice).await;
+
+    let certificates = certifs_from_last_event(env);
+    let mut spy = ops.event_bus.spy.start_expecting();
+    ops.add_certificates_batch(&[], &certificates, &[], &Default::default())
+        .await
+        .unwrap();
+
+    spy.assert_next(|e: &EventSequesterServiceRevoked| {
+        p_assert_eq!(e.service_id, service_id);
+    });
+    spy.assert_next(|_: &EventNewCertificates| {});
+    spy.assert_no_events();
+}
+
+#[parsec_test(testbed = "minimal")]
+async fn shamir_recovery_changed(#[values("created", "deleted")] kind: &str, env: &TestbedEnv) {
+    env.customize(|builder| {
+        builder.new_user("bob");
+        let recovery_device_id = builder.new_device("bob").map(|e| e.device_id);
+        if kind == "deleted" {
+            builder.new_shamir_recovery(
+                "bob",
+                1,
+                [("alice".parse().unwrap(), 1.try_into().unwrap())],
+                recovery_device_id,
+            );
+        }
+
+        builder.certificates_storage_fetch_certificates("alice@dev1");
+
+        match kind {
+            "created" => {
+                builder.new_shamir_recovery(
+                    "bob",
+                    1,
+                    [("alice".parse().unwrap(), 1.try_into().unwrap())],
+                    recovery_device_id,
+                );
+            }
+            "deleted" => {
+                builder.delete_shamir_recovery("bob");
+            }
+            unknown => panic!("Unknown kind: {unknown}"),
+        }
+    })
+    .await;
+    let alice = env.local_device("alice@dev1");
+    let bob_user_id: UserID = "bob".parse().unwrap();
+    let ops = certificates_ops_factory(env, &alice).await;
+
+    let certificates = certifs_from_last_event(env);
+    let mut spy = ops.event_bus.spy.start_expecting();
+    ops.add_certificates_batch(&[], &[], &certificates, &Default::default())
+        .await
+        .unwrap();
+
+    // Only the brief/deletion certificate leads to an event (not the share one)
+    spy.assert_next(|e: &EventShamirRecoveryChanged| {
+        p_assert_eq!(e.user_id, bob_user_id);
+    });
+    spy.assert_next(|_: &EventNewCertificates| {});
+    spy.assert_no_events();
+}
+
+#[parsec_test(testbed = "minimal")]
+async fn no_dedicated_event_when_storage_initially_empty(env: &TestbedEnv) {
+    env.customize(|builder| {
+        builder.new_user("bob");
+        builder.new_device("bob");
+        builder.update_user_profile("bob", UserProfile::Admin);
+        let realm_id = builder.new_realm("bob").map(|e| e.realm_id);
+        builder.share_realm(realm_id, "alice", RealmRole::Reader);
+        builder.share_realm(realm_id, "alice", None);
+        builder.revoke_user("bob");
+    })
+    .await;
+    let alice = env.local_device("alice@dev1");
+    let ops = certificates_ops_factory(env, &alice).await;
+
+    let mut spy = ops.event_bus.spy.start_expecting();
+    ops.add_certificates_batch(
+        &env.get_common_certificates_signed(),
+        &[],
+        &[],
+        &env.get_realms_certificates_signed(),
+    )
+    .await
+    .unwrap();
+
+    spy.assert_next(|e: &EventNewCertificates| {
+        assert!(e.storage_initially_empty);
+    });
+    spy.assert_no_events();
+}
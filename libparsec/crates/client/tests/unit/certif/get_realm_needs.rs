@@ -1,9 +1,11 @@
 // Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
 
+use std::collections::HashMap;
+
 use libparsec_tests_fixtures::prelude::*;
 use libparsec_types::prelude::*;
 
-use crate::certif::{CertifGetRealmNeedsError, RealmNeeds};
+use crate::certif::{CertifGetRealmNeedsError, RealmNeeds, RealmShareExpiration};
 
 use super::utils::certificates_ops_factory;
 
@@ -151,6 +153,35 @@ async fn sequestered_key_rotation_only(
     );
 }
 
+#[parsec_test(testbed = "coolorg")]
+async fn key_rotation_only_after_device_revoked(env: &TestbedEnv) {
+    let wksp1_id: VlobID = *env.template.get_stuff("wksp1_id");
+    let alice = env.local_device("alice@dev1");
+    let bob2 = env.local_device("bob@dev2");
+    let ops = certificates_ops_factory(env, &alice).await;
+
+    // Bob is still part of the realm, but one of his devices (which may have
+    // been able to read the realm) got revoked
+    let revoked_device_certificate: Bytes = RevokedDeviceCertificate {
+        author: alice.device_id,
+        timestamp: alice.time_provider.now(),
+        user_id: bob2.user_id,
+        device_id: bob2.device_id,
+    }
+    .dump_and_sign(&alice.signing_key)
+    .into();
+    ops.add_certificates_batch(&[revoked_device_certificate], &[], &[], &Default::default())
+        .await
+        .unwrap();
+
+    p_assert_eq!(
+        ops.get_realm_needs(wksp1_id).await.unwrap(),
+        RealmNeeds::KeyRotationOnly {
+            current_key_index: Some(1)
+        }
+    );
+}
+
 #[parsec_test(testbed = "coolorg")]
 async fn unshare_then_key_rotation(
     #[values(
@@ -192,11 +223,249 @@ async fn unshare_then_key_rotation(
         ops.get_realm_needs(wksp1_id).await.unwrap(),
         RealmNeeds::UnshareThenKeyRotation {
             revoked_users: expected_revoked_users,
+            expired_users: vec![],
+            ungrouped_users: vec![],
+            current_key_index: Some(1),
+        }
+    );
+}
+
+#[parsec_test(testbed = "coolorg")]
+async fn share_expiration(
+    #[values("expired", "not_yet_expired", "obsolete", "expired_and_revoked")] kind: &str,
+    env: &TestbedEnv,
+) {
+    let wksp1_id: VlobID = *env.template.get_stuff("wksp1_id");
+    if kind == "expired_and_revoked" {
+        env.customize(|builder| {
+            builder.revoke_user("bob");
+            builder.certificates_storage_fetch_certificates("alice@dev1");
+        })
+        .await;
+    }
+
+    let alice = env.local_device("alice@dev1");
+    let ops = certificates_ops_factory(env, &alice).await;
+    let bob_id: UserID = "bob".parse().unwrap();
+
+    let now = alice.now();
+    match kind {
+        "expired" | "expired_and_revoked" => {
+            ops.set_realm_share_expiration(wksp1_id, bob_id, Some(now))
+                .await
+                .unwrap();
+        }
+        "not_yet_expired" => {
+            ops.set_realm_share_expiration(wksp1_id, bob_id, Some(now.add_us(3_600_000_000)))
+                .await
+                .unwrap();
+        }
+        "obsolete" => {
+            // Expiration configured for a previous role of Bob
+            ops.store
+                .set_realm_share_expiration(&RealmShareExpiration {
+                    realm_id: wksp1_id,
+                    user_id: bob_id,
+                    role_certificate_timestamp: "2000-01-01T00:00:00Z".parse().unwrap(),
+                    expires_on: now,
+                })
+                .await
+                .unwrap();
+        }
+        unknown => panic!("Unknown kind: {unknown}"),
+    }
+
+    let expected = match kind {
+        "expired" => RealmNeeds::UnshareThenKeyRotation {
+            revoked_users: vec![],
+            expired_users: vec![bob_id],
+            ungrouped_users: vec![],
+            current_key_index: Some(1),
+        },
+        // A revoked user is unshared anyway
+        "expired_and_revoked" => RealmNeeds::UnshareThenKeyRotation {
+            revoked_users: vec![bob_id],
+            expired_users: vec![],
+            ungrouped_users: vec![],
+            current_key_index: Some(1),
+        },
+        _ => RealmNeeds::Nothing,
+    };
+    p_assert_eq!(ops.get_realm_needs(wksp1_id).await.unwrap(), expected);
+}
+
+#[parsec_test(testbed = "coolorg")]
+async fn unshare_then_key_rotation_after_removed_from_group(env: &TestbedEnv) {
+    let wksp1_id: VlobID = *env.template.get_stuff("wksp1_id");
+    let alice = env.local_device("alice@dev1");
+    let mallory = env.local_device("mallory@dev1");
+    let ops = certificates_ops_factory(env, &alice).await;
+
+    // Mallory got access to the realm through a group, then left the group
+    let group_id = GroupID::default();
+    let now = alice.time_provider.now();
+    let common_certificates: Vec<Bytes> = vec![
+        GroupCertificate {
+            author: alice.device_id,
+            timestamp: now,
+            group_id,
+            name: "Partners".to_owned(),
+        }
+        .dump_and_sign(&alice.signing_key)
+        .into(),
+        GroupMemberCertificate {
+            author: alice.device_id,
+            timestamp: now.add_us(1),
+            group_id,
+            user_id: mallory.user_id,
+            is_member: true,
+        }
+        .dump_and_sign(&alice.signing_key)
+        .into(),
+    ];
+    let realm_certificates: Vec<Bytes> = vec![
+        RealmGroupRoleCertificate {
+            author: alice.device_id,
+            timestamp: now.add_us(2),
+            realm_id: wksp1_id,
+            group_id,
+            role: Some(RealmRole::Reader),
+        }
+        .dump_and_sign(&alice.signing_key)
+        .into(),
+        RealmRoleCertificate {
+            author: alice.device_id,
+            timestamp: now.add_us(3),
+            realm_id: wksp1_id,
+            user_id: mallory.user_id,
+            role: Some(RealmRole::Reader),
+            group_id: Some(group_id),
+        }
+        .dump_and_sign(&alice.signing_key)
+        .into(),
+    ];
+    ops.add_certificates_batch(
+        &common_certificates,
+        &[],
+        &[],
+        &HashMap::from([(wksp1_id, realm_certificates)]),
+    )
+    .await
+    .unwrap();
+
+    p_assert_eq!(
+        ops.get_realm_needs(wksp1_id).await.unwrap(),
+        RealmNeeds::Nothing
+    );
+
+    let removed_certificate: Bytes = GroupMemberCertificate {
+        author: alice.device_id,
+        timestamp: now.add_us(4),
+        group_id,
+        user_id: mallory.user_id,
+        is_member: false,
+    }
+    .dump_and_sign(&alice.signing_key)
+    .into();
+    ops.add_certificates_batch(&[removed_certificate], &[], &[], &Default::default())
+        .await
+        .unwrap();
+
+    p_assert_eq!(
+        ops.get_realm_needs(wksp1_id).await.unwrap(),
+        RealmNeeds::UnshareThenKeyRotation {
             current_key_index: Some(1),
+            revoked_users: vec![],
+            expired_users: vec![],
+            ungrouped_users: vec![mallory.user_id],
         }
     );
 }
 
+#[parsec_test(testbed = "coolorg")]
+async fn group_shares_needs(env: &TestbedEnv) {
+    let wksp1_id: VlobID = *env.template.get_stuff("wksp1_id");
+    let alice = env.local_device("alice@dev1");
+    let bob = env.local_device("bob@dev1");
+    let mallory = env.local_device("mallory@dev1");
+    let ops = certificates_ops_factory(env, &alice).await;
+
+    let group_id = GroupID::default();
+    let now = alice.time_provider.now();
+    let mut common_certificates: Vec<Bytes> = vec![GroupCertificate {
+        author: alice.device_id,
+        timestamp: now,
+        group_id,
+        name: "Everybody".to_owned(),
+    }
+    .dump_and_sign(&alice.signing_key)
+    .into()];
+    for (i, user_id) in [alice.user_id, bob.user_id, mallory.user_id]
+        .into_iter()
+        .enumerate()
+    {
+        common_certificates.push(
+            GroupMemberCertificate {
+                author: alice.device_id,
+                timestamp: now.add_us(1 + i as i64),
+                group_id,
+                user_id,
+                is_member: true,
+            }
+            .dump_and_sign(&alice.signing_key)
+            .into(),
+        );
+    }
+    let group_role_certificate: Bytes = RealmGroupRoleCertificate {
+        author: alice.device_id,
+        timestamp: now.add_us(10),
+        realm_id: wksp1_id,
+        group_id,
+        role: Some(RealmRole::Reader),
+    }
+    .dump_and_sign(&alice.signing_key)
+    .into();
+    ops.add_certificates_batch(
+        &common_certificates,
+        &[],
+        &[],
+        &HashMap::from([(wksp1_id, vec![group_role_certificate])]),
+    )
+    .await
+    .unwrap();
+
+    // - Alice is the author, hence is not concerned
+    // - Bob already has a role given individually, which takes precedence
+    // - Mallory is an Outsider, which is fine given a group is never Owner/Manager
+    p_assert_eq!(
+        ops.get_realm_group_shares_needs(wksp1_id).await.unwrap(),
+        vec![(mallory.user_id, RealmRole::Reader, group_id)]
+    );
+
+    let group_role_certificate: Bytes = RealmGroupRoleCertificate {
+        author: alice.device_id,
+        timestamp: now.add_us(11),
+        realm_id: wksp1_id,
+        group_id,
+        role: Some(RealmRole::Contributor),
+    }
+    .dump_and_sign(&alice.signing_key)
+    .into();
+    ops.add_certificates_batch(
+        &[],
+        &[],
+        &[],
+        &HashMap::from([(wksp1_id, vec![group_role_certificate])]),
+    )
+    .await
+    .unwrap();
+
+    p_assert_eq!(
+        ops.get_realm_group_shares_needs(wksp1_id).await.unwrap(),
+        vec![(mallory.user_id, RealmRole::Contributor, group_id)]
+    );
+}
+
 #[parsec_test(testbed = "minimal")]
 async fn unknown_realm(env: &TestbedEnv) {
     let alice = env.local_device("alice@dev1");
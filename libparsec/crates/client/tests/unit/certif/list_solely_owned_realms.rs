@@ -0,0 +1,78 @@
+// Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+use libparsec_tests_fixtures::prelude::*;
+use libparsec_types::prelude::*;
+
+use super::utils::certificates_ops_factory;
+
+#[parsec_test(testbed = "minimal")]
+async fn ok(env: &TestbedEnv) {
+    let (orphanable_realm_id, bob_realm_id) = env
+        .customize(|builder| {
+            builder.new_user("bob");
+            builder.new_user("mallory");
+
+            // Alice is the only owner, and Bob has access: can be orphaned
+            let orphanable_realm_id = builder.new_realm("alice").map(|event| event.realm_id);
+            builder.share_realm(orphanable_realm_id, "bob", Some(RealmRole::Manager));
+
+            // Alice is the only member: cannot be orphaned
+            builder.new_realm("alice");
+
+            // Bob is also owner: cannot be orphaned
+            let co_owned_realm_id = builder.new_realm("alice").map(|event| event.realm_id);
+            builder.share_realm(co_owned_realm_id, "bob", Some(RealmRole::Owner));
+
+            // Mallory has access but is revoked: cannot be orphaned
+            let revoked_member_realm_id = builder.new_realm("alice").map(|event| event.realm_id);
+            builder.share_realm(revoked_member_realm_id, "mallory", Some(RealmRole::Reader));
+
+            // Alice has been unshared: cannot be orphaned by her
+            let unshared_realm_id = builder.new_realm("bob").map(|event| event.realm_id);
+            builder.share_realm(unshared_realm_id, "alice", Some(RealmRole::Owner));
+            builder.share_realm(unshared_realm_id, "mallory", Some(RealmRole::Reader));
+            builder.share_realm(unshared_realm_id, "alice", None);
+
+            // Bob is the only owner, and Alice has access: can be orphaned by Bob
+            let bob_realm_id = builder.new_realm("bob").map(|event| event.realm_id);
+            builder.share_realm(bob_realm_id, "alice", Some(RealmRole::Contributor));
+
+            builder.revoke_user("mallory");
+
+            builder.certificates_storage_fetch_certificates("alice@dev1");
+
+            (orphanable_realm_id, bob_realm_id)
+        })
+        .await;
+
+    let alice = env.local_device("alice@dev1");
+    let ops = certificates_ops_factory(env, &alice).await;
+
+    p_assert_eq!(
+        ops.list_solely_owned_realms(alice.user_id).await.unwrap(),
+        [orphanable_realm_id]
+    );
+    p_assert_eq!(
+        ops.list_solely_owned_realms("bob".parse().unwrap())
+            .await
+            .unwrap(),
+        [bob_realm_id]
+    );
+}
+
+#[parsec_test(testbed = "minimal")]
+async fn stopped(env: &TestbedEnv) {
+    let alice = env.local_device("alice@dev1");
+    let ops = certificates_ops_factory(env, &alice).await;
+
+    ops.stop().await.unwrap();
+
+    let err = ops
+        .list_solely_owned_realms(alice.user_id)
+        .await
+        .unwrap_err();
+    p_assert_matches!(
+        err,
+        crate::certif::CertifListSolelyOwnedRealmsError::Stopped
+    );
+}
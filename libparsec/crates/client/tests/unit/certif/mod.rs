@@ -2,6 +2,8 @@
 
 mod add_common;
 mod add_device_certificate;
+mod add_device_revoked_certificate;
+mod add_group_certificates;
 mod add_realm_archiving_certificate;
 mod add_realm_key_rotation_certificate;
 mod add_realm_name_certificate;
@@ -17,6 +19,8 @@ mod add_user_revoked_certificate;
 mod add_user_update_certificate;
 mod archive_realm;
 mod bootstrap_workspace;
+mod certificate_audit_log;
+mod certificate_change_events;
 mod decrypt_current_realm_name;
 mod encrypt_for_realm;
 mod encrypt_for_sequester_services;
@@ -29,14 +33,18 @@ mod get_realm_archiving_config;
 mod get_realm_needs;
 mod get_user_device;
 mod get_user_info;
+mod list_solely_owned_realms;
 mod list_user_devices;
 mod list_users;
 mod list_workspace_users;
 mod poll_server_for_new_certificates;
+mod rekey_realm;
 mod rename_realm;
 mod rotate_realm_key_idempotent;
+mod set_realm_share_expiration;
 mod share_realm;
 mod store;
+mod update_realm_group_role;
 mod utils;
 mod validate_block;
 mod validate_child_manifest;
@@ -0,0 +1,229 @@
+// Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+use libparsec_protocol::authenticated_cmds;
+use libparsec_tests_fixtures::prelude::*;
+use libparsec_types::prelude::*;
+
+use crate::{
+    certif::CertificateOps, CertifRekeyRealmError, EventNewCertificates, EventRealmRekeyDone,
+    EventRealmRekeyProgress, RealmRekeyProgress,
+};
+
+use super::utils::certificates_ops_factory;
+
+async fn read_remote_vlob(
+    ops: &CertificateOps,
+    realm_id: VlobID,
+    vlob_id: VlobID,
+) -> (IndexInt, DeviceID, VersionInt) {
+    use authenticated_cmds::latest::vlob_read_batch::{Rep, Req};
+
+    let rep = ops
+        .cmds
+        .send(Req {
+            at: None,
+            realm_id,
+            vlobs: vec![vlob_id],
+        })
+        .await
+        .unwrap();
+    match rep {
+        Rep::Ok { items, .. } => {
+            let (_, key_index, author, version, _, _) = items.into_iter().next().unwrap();
+            (key_index, author, version)
+        }
+        rep => panic!("Unexpected rep: {rep:?}"),
+    }
+}
+
+#[parsec_test(testbed = "coolorg", with_server)]
+async fn ok(env: &TestbedEnv) {
+    let wksp1_id: VlobID = *env.template.get_stuff("wksp1_id");
+    env.customize(|builder| {
+        builder.rotate_key_realm(wksp1_id);
+    })
+    .await;
+
+    let alice = env.local_device("alice@dev1");
+    let ops = certificates_ops_factory(env, &alice).await;
+
+    p_assert_eq!(
+        read_remote_vlob(&ops, wksp1_id, wksp1_id).await,
+        (1, alice.device_id, 1)
+    );
+
+    let mut spy = ops.event_bus.spy.start_expecting();
+
+    ops.rekey_realm(wksp1_id).await.unwrap();
+
+    spy.assert_next(|_: &EventNewCertificates| {});
+    // First pass re-encrypts the workspace manifest...
+    spy.assert_next(|e: &EventRealmRekeyProgress| {
+        p_assert_eq!(e.realm_id, wksp1_id);
+        p_assert_eq!(e.target_key_index, 2);
+        p_assert_eq!(e.processed, 1);
+        p_assert_eq!(e.total, 1);
+    });
+    // ...and second pass checks the manifest we just uploaded
+    spy.assert_next(|e: &EventRealmRekeyProgress| {
+        p_assert_eq!(e.processed, 1);
+        p_assert_eq!(e.total, 1);
+    });
+    spy.assert_next(|e: &EventRealmRekeyDone| {
+        p_assert_eq!(e.realm_id, wksp1_id);
+        p_assert_eq!(e.target_key_index, 2);
+    });
+
+    p_assert_eq!(
+        read_remote_vlob(&ops, wksp1_id, wksp1_id).await,
+        (2, alice.device_id, 2)
+    );
+    p_assert_matches!(
+        ops.get_realm_rekey_progress(wksp1_id).await.unwrap(),
+        Some(RealmRekeyProgress {
+            target_key_index: 2,
+            cursor: None,
+            completed: true,
+            ..
+        })
+    );
+    p_assert_eq!(ops.is_realm_rekey_pending(wksp1_id).await.unwrap(), false);
+
+    // Already completed, so nothing to do
+
+    ops.rekey_realm(wksp1_id).await.unwrap();
+    spy.assert_no_events();
+}
+
+#[parsec_test(testbed = "coolorg", with_server)]
+async fn pending_after_new_key_rotation(env: &TestbedEnv) {
+    let wksp1_id: VlobID = *env.template.get_stuff("wksp1_id");
+    env.customize(|builder| {
+        builder.rotate_key_realm(wksp1_id);
+    })
+    .await;
+
+    let alice = env.local_device("alice@dev1");
+    let ops = certificates_ops_factory(env, &alice).await;
+
+    // Never started, hence not pending
+    p_assert_eq!(ops.is_realm_rekey_pending(wksp1_id).await.unwrap(), false);
+
+    // Completed job targeting a key that is no longer the last one
+    ops.store
+        .set_realm_rekey_progress(&RealmRekeyProgress {
+            realm_id: wksp1_id,
+            target_key_index: 1,
+            checkpoint: 0,
+            cursor: None,
+            completed: true,
+        })
+        .await
+        .unwrap();
+    ops.poll_server_for_new_certificates(None).await.unwrap();
+
+    p_assert_eq!(ops.is_realm_rekey_pending(wksp1_id).await.unwrap(), true);
+
+    ops.rekey_realm(wksp1_id).await.unwrap();
+
+    p_assert_eq!(ops.is_realm_rekey_pending(wksp1_id).await.unwrap(), false);
+}
+
+#[parsec_test(testbed = "coolorg", with_server)]
+async fn nothing_to_rekey(env: &TestbedEnv) {
+    let wksp1_id: VlobID = *env.template.get_stuff("wksp1_id");
+
+    let alice = env.local_device("alice@dev1");
+    let ops = certificates_ops_factory(env, &alice).await;
+
+    p_assert_eq!(ops.get_realm_rekey_progress(wksp1_id).await.unwrap(), None);
+
+    let mut spy = ops.event_bus.spy.start_expecting();
+
+    ops.rekey_realm(wksp1_id).await.unwrap();
+
+    spy.assert_next(|e: &EventRealmRekeyProgress| {
+        p_assert_eq!(e.target_key_index, 1);
+        p_assert_eq!(e.processed, 1);
+        p_assert_eq!(e.total, 1);
+    });
+    spy.assert_next(|e: &EventRealmRekeyDone| {
+        p_assert_eq!(e.target_key_index, 1);
+    });
+
+    // Workspace manifest is left untouched
+    p_assert_eq!(
+        read_remote_vlob(&ops, wksp1_id, wksp1_id).await,
+        (1, alice.device_id, 1)
+    );
+}
+
+#[parsec_test(testbed = "coolorg", with_server)]
+async fn resume_interrupted_pass(env: &TestbedEnv) {
+    let wksp1_id: VlobID = *env.template.get_stuff("wksp1_id");
+    env.customize(|builder| {
+        builder.rotate_key_realm(wksp1_id);
+    })
+    .await;
+
+    let alice = env.local_device("alice@dev1");
+    let ops = certificates_ops_factory(env, &alice).await;
+
+    // Pretend a previous pass has been interrupted after processing the
+    // workspace manifest (which is the only vlob of the realm).
+    ops.store
+        .set_realm_rekey_progress(&RealmRekeyProgress {
+            realm_id: wksp1_id,
+            target_key_index: 2,
+            checkpoint: 0,
+            cursor: Some(wksp1_id),
+            completed: false,
+        })
+        .await
+        .unwrap();
+
+    p_assert_eq!(ops.is_realm_rekey_pending(wksp1_id).await.unwrap(), true);
+
+    let mut spy = ops.event_bus.spy.start_expecting();
+
+    ops.rekey_realm(wksp1_id).await.unwrap();
+
+    spy.assert_next(|_: &EventNewCertificates| {});
+    // The resumed pass has nothing left to process, so the next pass starts
+    // over from the same checkpoint and re-encrypts the workspace manifest...
+    spy.assert_next(|e: &EventRealmRekeyProgress| {
+        p_assert_eq!(e.processed, 1);
+        p_assert_eq!(e.total, 1);
+    });
+    // ...then a last pass checks the manifest we just uploaded
+    spy.assert_next(|e: &EventRealmRekeyProgress| {
+        p_assert_eq!(e.processed, 1);
+        p_assert_eq!(e.total, 1);
+    });
+    spy.assert_next(|_: &EventRealmRekeyDone| {});
+
+    p_assert_eq!(
+        read_remote_vlob(&ops, wksp1_id, wksp1_id).await,
+        (2, alice.device_id, 2)
+    );
+}
+
+#[parsec_test(testbed = "coolorg", with_server)]
+async fn author_not_allowed(env: &TestbedEnv) {
+    let wksp1_id: VlobID = *env.template.get_stuff("wksp1_id");
+
+    let bob = env.local_device("bob@dev1");
+    let ops = certificates_ops_factory(env, &bob).await;
+
+    let err = ops.rekey_realm(wksp1_id).await.unwrap_err();
+    p_assert_matches!(err, CertifRekeyRealmError::AuthorNotAllowed);
+}
+
+#[parsec_test(testbed = "minimal_client_ready", with_server)]
+async fn unknown_realm(env: &TestbedEnv) {
+    let alice = env.local_device("alice@dev1");
+    let ops = certificates_ops_factory(env, &alice).await;
+
+    let err = ops.rekey_realm(VlobID::default()).await.unwrap_err();
+    p_assert_matches!(err, CertifRekeyRealmError::UnknownRealm);
+}
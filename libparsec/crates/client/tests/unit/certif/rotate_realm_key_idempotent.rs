@@ -5,7 +5,10 @@ use libparsec_protocol::authenticated_cmds;
 use libparsec_tests_fixtures::prelude::*;
 use libparsec_types::prelude::*;
 
-use crate::{CertifRotateRealmKeyError, CertificateBasedActionOutcome};
+use crate::{
+    CertifRotateRealmKeyError, CertificateBasedActionOutcome,
+    EventRealmKeysBundleCorruptedAndUnrecoverable, KeysBundleHealingOutcome,
+};
 
 use super::utils::certificates_ops_factory;
 
@@ -121,7 +124,7 @@ async fn stopped(env: &TestbedEnv) {
 }
 
 #[parsec_test(testbed = "minimal_client_ready")]
-async fn current_keys_bundle_corrupted(env: &TestbedEnv) {
+async fn current_keys_bundle_corrupted_and_unrecoverable(env: &TestbedEnv) {
     let wksp1_id: VlobID = *env.template.get_stuff("wksp1_id");
 
     let alice = env.local_device("alice@dev1");
@@ -129,7 +132,24 @@ async fn current_keys_bundle_corrupted(env: &TestbedEnv) {
 
     let keys_bundle = Bytes::from_static(b"<dummy>");
     let keys_bundle_access = env.get_last_realm_keys_bundle_access_for(wksp1_id, alice.user_id);
-    test_register_sequence_of_send_hooks!(&env.discriminant_dir, {
+    test_register_sequence_of_send_hooks!(
+        &env.discriminant_dir,
+        // 1) Load the last keys bundle
+        {
+            let keys_bundle = keys_bundle.clone();
+            let keys_bundle_access = keys_bundle_access.clone();
+            move |req: authenticated_cmds::latest::realm_get_keys_bundle::Req| {
+                p_assert_eq!(req.key_index, 1);
+                p_assert_eq!(req.realm_id, wksp1_id);
+
+                authenticated_cmds::latest::realm_get_keys_bundle::Rep::Ok {
+                    keys_bundle,
+                    keys_bundle_access,
+                }
+            }
+        },
+        // 2) Last keys bundle is corrupted, try to recover from the previous ones
+        //    (but there is none !)
         move |req: authenticated_cmds::latest::realm_get_keys_bundle::Req| {
             p_assert_eq!(req.key_index, 1);
             p_assert_eq!(req.realm_id, wksp1_id);
@@ -138,8 +158,10 @@ async fn current_keys_bundle_corrupted(env: &TestbedEnv) {
                 keys_bundle,
                 keys_bundle_access,
             }
-        }
-    });
+        },
+    );
+
+    let mut spy = ops.event_bus.spy.start_expecting();
 
     let err = ops
         .rotate_realm_key_idempotent(wksp1_id, 2)
@@ -147,6 +169,166 @@ async fn current_keys_bundle_corrupted(env: &TestbedEnv) {
         .unwrap_err();
     p_assert_matches!(
         err,
-        CertifRotateRealmKeyError::CurrentKeysBundleCorrupted { .. },
+        CertifRotateRealmKeyError::CurrentKeysBundleCorruptedAndUnrecoverable { .. },
+    );
+    spy.assert_next(|e: &EventRealmKeysBundleCorruptedAndUnrecoverable| {
+        p_assert_eq!(e.realm_id, wksp1_id);
+    });
+}
+
+#[parsec_test(testbed = "coolorg")]
+async fn current_keys_bundle_corrupted_healed(env: &TestbedEnv) {
+    let wksp1_id: VlobID = *env.template.get_stuff("wksp1_id");
+    env.customize(|builder| {
+        builder.rotate_key_realm(wksp1_id);
+        builder.certificates_storage_fetch_certificates("alice@dev1");
+    })
+    .await;
+
+    let alice = env.local_device("alice@dev1");
+    let ops = certificates_ops_factory(env, &alice).await;
+
+    let dummy_keys_bundle = Bytes::from_static(b"<dummy>");
+    let keys_bundle_access_2 = env.get_keys_bundle_access_for(wksp1_id, alice.user_id, 2);
+    let keys_bundle_1 = env.get_realm_keys_bundle(wksp1_id, 1);
+    let keys_bundle_access_1 = env.get_keys_bundle_access_for(wksp1_id, alice.user_id, 1);
+    let key_1 = env.get_realm_keys(wksp1_id)[0].clone();
+    test_register_sequence_of_send_hooks!(
+        &env.discriminant_dir,
+        // 1) Load the last keys bundle, which is corrupted
+        {
+            let dummy_keys_bundle = dummy_keys_bundle.clone();
+            let keys_bundle_access_2 = keys_bundle_access_2.clone();
+            move |req: authenticated_cmds::latest::realm_get_keys_bundle::Req| {
+                p_assert_eq!(req.key_index, 2);
+                authenticated_cmds::latest::realm_get_keys_bundle::Rep::Ok {
+                    keys_bundle: dummy_keys_bundle,
+                    keys_bundle_access: keys_bundle_access_2,
+                }
+            }
+        },
+        // 2) Recover keys from the previous keys bundles...
+        move |req: authenticated_cmds::latest::realm_get_keys_bundle::Req| {
+            p_assert_eq!(req.key_index, 2);
+            authenticated_cmds::latest::realm_get_keys_bundle::Rep::Ok {
+                keys_bundle: dummy_keys_bundle,
+                keys_bundle_access: keys_bundle_access_2,
+            }
+        },
+        move |req: authenticated_cmds::latest::realm_get_keys_bundle::Req| {
+            p_assert_eq!(req.key_index, 1);
+            authenticated_cmds::latest::realm_get_keys_bundle::Rep::Ok {
+                keys_bundle: keys_bundle_1,
+                keys_bundle_access: keys_bundle_access_1,
+            }
+        },
+        // 3) ...and use them for the key rotation
+        {
+            let alice = alice.clone();
+            move |req: authenticated_cmds::latest::realm_rotate_key::Req| {
+                let keys_bundle_access = RealmKeysBundleAccess::load(
+                    &alice
+                        .private_key
+                        .decrypt_from_self(&req.per_participant_keys_bundle_access[&alice.user_id])
+                        .unwrap(),
+                )
+                .unwrap();
+                let keys_bundle = RealmKeysBundle::unsecure_load(
+                    keys_bundle_access
+                        .keys_bundle_key
+                        .decrypt(&req.keys_bundle)
+                        .unwrap()
+                        .into(),
+                )
+                .unwrap()
+                .verify_signature(&alice.verify_key())
+                .unwrap()
+                .0;
+
+                p_assert_eq!(keys_bundle.key_index(), 3);
+                // Key 1 has been recovered...
+                p_assert_eq!(keys_bundle.keys()[0], key_1);
+                // ...but not key 2, given it was only present in the corrupted keys bundle
+                p_assert_eq!(
+                    keys_bundle.keys()[1],
+                    KeyDerivation::from([0; KeyDerivation::SIZE])
+                );
+
+                authenticated_cmds::latest::realm_rotate_key::Rep::Ok
+            }
+        },
     );
+
+    let outcome = ops.rotate_realm_key_idempotent(wksp1_id, 3).await.unwrap();
+    p_assert_matches!(outcome, CertificateBasedActionOutcome::Uploaded { .. });
+}
+
+#[parsec_test(testbed = "coolorg", with_server)]
+async fn healing_not_needed(env: &TestbedEnv) {
+    let wksp1_id: VlobID = *env.template.get_stuff("wksp1_id");
+
+    let alice = env.local_device("alice@dev1");
+    let ops = certificates_ops_factory(env, &alice).await;
+
+    let outcome = ops
+        .attempt_realm_keys_bundle_healing(wksp1_id)
+        .await
+        .unwrap();
+    p_assert_eq!(outcome, KeysBundleHealingOutcome::NotNeeded);
+}
+
+#[parsec_test(testbed = "minimal_client_ready")]
+async fn healing_not_possible_is_remembered(env: &TestbedEnv) {
+    let wksp1_id: VlobID = *env.template.get_stuff("wksp1_id");
+
+    let alice = env.local_device("alice@dev1");
+    let ops = certificates_ops_factory(env, &alice).await;
+
+    let keys_bundle = Bytes::from_static(b"<dummy>");
+    let keys_bundle_access = env.get_last_realm_keys_bundle_access_for(wksp1_id, alice.user_id);
+    test_register_sequence_of_send_hooks!(
+        &env.discriminant_dir,
+        // 1) Load the last keys bundle
+        {
+            let keys_bundle = keys_bundle.clone();
+            let keys_bundle_access = keys_bundle_access.clone();
+            move |req: authenticated_cmds::latest::realm_get_keys_bundle::Req| {
+                p_assert_eq!(req.key_index, 1);
+                authenticated_cmds::latest::realm_get_keys_bundle::Rep::Ok {
+                    keys_bundle,
+                    keys_bundle_access,
+                }
+            }
+        },
+        // 2) Last keys bundle is corrupted, try to recover from the previous ones
+        //    (but there is none !)
+        move |req: authenticated_cmds::latest::realm_get_keys_bundle::Req| {
+            p_assert_eq!(req.key_index, 1);
+            authenticated_cmds::latest::realm_get_keys_bundle::Rep::Ok {
+                keys_bundle,
+                keys_bundle_access,
+            }
+        },
+    );
+
+    let mut spy = ops.event_bus.spy.start_expecting();
+
+    let outcome = ops
+        .attempt_realm_keys_bundle_healing(wksp1_id)
+        .await
+        .unwrap();
+    p_assert_eq!(outcome, KeysBundleHealingOutcome::NotPossible);
+    spy.assert_next(|e: &EventRealmKeysBundleCorruptedAndUnrecoverable| {
+        p_assert_eq!(e.realm_id, wksp1_id);
+    });
+
+    // Second attempt: the keys bundles are not fetched again (no more send hooks
+    // are registered) and the event is not fired again
+
+    let outcome = ops
+        .attempt_realm_keys_bundle_healing(wksp1_id)
+        .await
+        .unwrap();
+    p_assert_eq!(outcome, KeysBundleHealingOutcome::NotPossible);
+    spy.assert_no_events();
 }
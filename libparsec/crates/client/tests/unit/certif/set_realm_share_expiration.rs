@@ -0,0 +1,102 @@
+// Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+use std::collections::HashMap;
+
+use libparsec_tests_fixtures::prelude::*;
+use libparsec_types::prelude::*;
+
+use crate::certif::{CertifSetRealmShareExpirationError, RealmShareExpiration};
+
+use super::utils::certificates_ops_factory;
+
+#[parsec_test(testbed = "coolorg")]
+async fn ok(env: &TestbedEnv) {
+    let wksp1_id: VlobID = *env.template.get_stuff("wksp1_id");
+
+    let alice = env.local_device("alice@dev1");
+    let ops = certificates_ops_factory(env, &alice).await;
+    let bob_id: UserID = "bob".parse().unwrap();
+
+    p_assert_eq!(
+        ops.list_realm_share_expirations(wksp1_id).await.unwrap(),
+        HashMap::new()
+    );
+
+    let expires_on: DateTime = "2100-01-01T00:00:00Z".parse().unwrap();
+    ops.set_realm_share_expiration(wksp1_id, bob_id, Some(expires_on))
+        .await
+        .unwrap();
+    p_assert_eq!(
+        ops.list_realm_share_expirations(wksp1_id).await.unwrap(),
+        HashMap::from([(bob_id, expires_on)])
+    );
+
+    // Remove the expiration (removing it twice is a no-op)
+
+    ops.set_realm_share_expiration(wksp1_id, bob_id, None)
+        .await
+        .unwrap();
+    ops.set_realm_share_expiration(wksp1_id, bob_id, None)
+        .await
+        .unwrap();
+    p_assert_eq!(
+        ops.list_realm_share_expirations(wksp1_id).await.unwrap(),
+        HashMap::new()
+    );
+}
+
+#[parsec_test(testbed = "coolorg")]
+async fn obsolete_after_role_change(env: &TestbedEnv) {
+    let wksp1_id: VlobID = *env.template.get_stuff("wksp1_id");
+
+    let alice = env.local_device("alice@dev1");
+    let ops = certificates_ops_factory(env, &alice).await;
+    let bob_id: UserID = "bob".parse().unwrap();
+
+    // Expiration configured for a previous role of Bob...
+    ops.store
+        .set_realm_share_expiration(&RealmShareExpiration {
+            realm_id: wksp1_id,
+            user_id: bob_id,
+            role_certificate_timestamp: "2000-01-01T00:00:00Z".parse().unwrap(),
+            expires_on: "2100-01-01T00:00:00Z".parse().unwrap(),
+        })
+        .await
+        .unwrap();
+
+    // ...no longer applies
+    p_assert_eq!(
+        ops.list_realm_share_expirations(wksp1_id).await.unwrap(),
+        HashMap::new()
+    );
+}
+
+#[parsec_test(testbed = "coolorg")]
+async fn user_not_in_realm(#[values("never_shared", "unshared")] kind: &str, env: &TestbedEnv) {
+    let wksp1_id: VlobID = *env.template.get_stuff("wksp1_id");
+    let user_id: UserID = match kind {
+        "never_shared" => "mallory".parse().unwrap(),
+        "unshared" => {
+            env.customize(|builder| {
+                builder.share_realm(wksp1_id, "bob", None);
+                builder.certificates_storage_fetch_certificates("alice@dev1");
+            })
+            .await;
+            "bob".parse().unwrap()
+        }
+        unknown => panic!("Unknown kind: {unknown}"),
+    };
+
+    let alice = env.local_device("alice@dev1");
+    let ops = certificates_ops_factory(env, &alice).await;
+
+    let err = ops
+        .set_realm_share_expiration(
+            wksp1_id,
+            user_id,
+            Some("2100-01-01T00:00:00Z".parse().unwrap()),
+        )
+        .await
+        .unwrap_err();
+    p_assert_matches!(err, CertifSetRealmShareExpirationError::UserNotInRealm);
+}
@@ -979,3 +979,130 @@ async fn get_last_timestamps_shamir(env: &TestbedEnv) {
         }
     );
 }
+
+#[parsec_test(testbed = "minimal")]
+async fn index_cleared_on_write_error(env: &TestbedEnv) {
+    let alice = env.local_device("alice@dev1");
+    let store = certificates_store_factory(env, &alice).await;
+
+    let (alice_certif, alice_signed) = env.get_user_certificate("alice");
+
+    macro_rules! store_get_certifs {
+        ($store:ident) => {
+            $store.for_read(async |store| {
+                store.get_user_certificates(UpTo::Current, None, None).await
+            })
+        };
+    }
+
+    // Populate the index
+
+    let got = store_get_certifs!(store).await.unwrap().unwrap();
+    p_assert_eq!(got, vec![]);
+
+    // Add new certificate, but then fail the write operation
+
+    let outcome = store
+        .for_write(async |store| {
+            store
+                .add_next_common_certificate(
+                    CommonTopicArcCertificate::User(alice_certif),
+                    &alice_signed,
+                )
+                .await
+                .unwrap();
+            // The certificate is now in the index...
+            let got = store
+                .get_user_certificates(UpTo::Current, None, None)
+                .await
+                .unwrap();
+            p_assert_eq!(got.len(), 1);
+            Err::<(), _>("rollback !")
+        })
+        .await
+        .unwrap();
+    p_assert_eq!(outcome, Err("rollback !"));
+
+    // ...but it shouldn't be anymore given the write operation has been rolled back
+
+    let got = store_get_certifs!(store).await.unwrap().unwrap();
+    p_assert_eq!(got, vec![]);
+}
+
+#[parsec_test(testbed = "coolorg")]
+async fn get_user_last_realms_roles(env: &TestbedEnv) {
+    let wksp1_id: VlobID = *env.template.get_stuff("wksp1_id");
+    let wksp2_id = env
+        .customize(|builder| {
+            builder.share_realm(wksp1_id, "bob", None);
+            let wksp2_id = builder
+                .new_realm("alice")
+                .then_do_initial_key_rotation()
+                .map(|e| e.realm);
+            builder.share_realm(wksp2_id, "bob", RealmRole::Contributor);
+            builder.share_realm(wksp2_id, "bob", RealmRole::Manager);
+            builder.certificates_storage_fetch_certificates("bob@dev1");
+            wksp2_id
+        })
+        .await;
+
+    let bob = env.local_device("bob@dev1");
+    let store = certificates_store_factory(env, &bob).await;
+
+    let (wksp2_certif, _) = env.get_last_realm_role_certificate("bob", wksp2_id);
+    let expected = vec![
+        env.get_last_realm_role_certificate("bob", wksp1_id).0,
+        env.get_last_realm_role_certificate("bob", bob.user_realm_id)
+            .0,
+        wksp2_certif.clone(),
+    ];
+
+    // Current view is provided by the index...
+
+    let got = store
+        .for_read(async |store| {
+            store
+                .get_user_last_realms_roles(UpTo::Current, bob.user_id)
+                .await
+        })
+        .await
+        .unwrap()
+        .unwrap();
+    p_assert_eq!(got, expected);
+
+    // ...while past views are provided by the storage
+
+    let got = store
+        .for_read(async |store| {
+            store
+                .get_user_last_realms_roles(UpTo::Timestamp(wksp2_certif.timestamp), bob.user_id)
+                .await
+        })
+        .await
+        .unwrap()
+        .unwrap();
+    p_assert_eq!(got, expected);
+}
+
+#[parsec_test(testbed = "coolorg")]
+async fn get_current_user_id_from_email(env: &TestbedEnv) {
+    let alice = env.local_device("alice@dev1");
+    let bob = env.local_device("bob@dev1");
+    let store = certificates_store_factory(env, &alice).await;
+
+    macro_rules! store_get_user_id {
+        ($store:ident, $email:expr) => {
+            $store.for_read(async |store| store.get_current_user_id_from_email($email).await)
+        };
+    }
+
+    let got = store_get_user_id!(store, bob.human_handle.email())
+        .await
+        .unwrap()
+        .unwrap();
+    p_assert_eq!(got, Some(bob.user_id));
+
+    let unknown: EmailAddress = "zack@example.invalid".parse().unwrap();
+    let got = store_get_user_id!(store, &unknown).await.unwrap().unwrap();
+    p_assert_eq!(got, None);
+}
@@ -0,0 +1,29 @@
+// Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+use libparsec_tests_fixtures::prelude::*;
+use libparsec_types::prelude::*;
+
+use crate::certif::CertifUpdateRealmGroupRoleError;
+
+use super::utils::certificates_ops_factory;
+
+#[parsec_test(testbed = "coolorg")]
+async fn role_not_allowed_for_group(#[values("owner", "manager")] kind: &str, env: &TestbedEnv) {
+    let wksp1_id: VlobID = *env.template.get_stuff("wksp1_id");
+    let alice = env.local_device("alice@dev1");
+    let ops = certificates_ops_factory(env, &alice).await;
+
+    let role = match kind {
+        "owner" => RealmRole::Owner,
+        "manager" => RealmRole::Manager,
+        unknown => panic!("Unknown kind: {unknown}"),
+    };
+
+    // No server request is expected, given the role is checked beforehand
+    let err = ops
+        .update_realm_group_role(wksp1_id, GroupID::default(), Some(role))
+        .await
+        .unwrap_err();
+
+    p_assert_matches!(err, CertifUpdateRealmGroupRoleError::RoleNotAllowedForGroup);
+}
@@ -8,7 +8,7 @@ use libparsec_types::prelude::*;
 
 use crate::{
     certif::{store::CertificatesStore, CertificateOps},
-    ClientConfig, EventBus, MountpointMountStrategy, WorkspaceStorageCacheSize,
+    ClientConfig, EventBus, MountpointMountStrategy, OutboundSyncConfig, WorkspaceStorageCacheSize,
 };
 
 pub(crate) async fn certificates_ops_factory(
@@ -21,6 +21,8 @@ pub(crate) async fn certificates_ops_factory(
         mountpoint_mount_strategy: MountpointMountStrategy::Disabled,
         workspace_storage_cache_size: WorkspaceStorageCacheSize::Default,
         proxy: ProxyConfig::default(),
+        outbound_sync: OutboundSyncConfig::default(),
+        pki_revocation_policy: Default::default(),
         with_monitors: false,
         prevent_sync_pattern: PreventSyncPattern::empty(),
     });
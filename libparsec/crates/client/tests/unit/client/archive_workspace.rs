@@ -14,8 +14,8 @@ use libparsec_types::prelude::*;
 
 use super::utils::client_factory;
 use crate::{
-    ClientArchiveWorkspaceError, EventNewCertificates, EventWorkspacesSelfListChanged,
-    RequestedRealmArchivingConfiguration, WorkspaceInfo,
+    ClientArchiveWorkspaceError, EventNewCertificates, EventRealmArchivingChanged,
+    EventWorkspacesSelfListChanged, RequestedRealmArchivingConfiguration, WorkspaceInfo,
 };
 
 #[parsec_test(testbed = "coolorg")]
@@ -76,6 +76,9 @@ async fn ok(
         .await
         .unwrap();
 
+    spy.assert_next(|event: &EventRealmArchivingChanged| {
+        p_assert_eq!(event.realm_id, wksp1_id);
+    });
     spy.assert_next(|_event: &EventNewCertificates| {});
     spy.assert_next(|_event: &EventWorkspacesSelfListChanged| {});
 
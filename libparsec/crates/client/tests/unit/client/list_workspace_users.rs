@@ -79,6 +79,7 @@ async fn ok(
             human_handle,
             current_profile,
             current_role,
+            expires_on,
         } = alice_entry;
         let (certif, _) = env.get_user_certificate("alice");
         p_assert_eq!(*user_id, certif.user_id);
@@ -91,6 +92,7 @@ async fn ok(
         );
         p_assert_eq!(*current_profile, UserProfile::Admin);
         p_assert_eq!(*current_role, RealmRole::Owner);
+        p_assert_eq!(*expires_on, None);
     }
 
     // Check Bob (if he hasn't been revoked)
@@ -100,6 +102,7 @@ async fn ok(
             human_handle,
             current_profile,
             current_role,
+            expires_on,
         } = bob_entry;
         let (certif, _) = env.get_user_certificate("bob");
         p_assert_eq!(*user_id, certif.user_id);
@@ -120,6 +123,7 @@ async fn ok(
         } else {
             p_assert_eq!(*current_role, RealmRole::Reader);
         }
+        p_assert_eq!(*expires_on, None);
     }
 }
 
@@ -12,6 +12,7 @@ mod organization_info;
 mod process_workspaces_needs;
 mod recovery;
 mod rename_workspace;
+mod revoke_device;
 mod revoke_user;
 mod shamir_recovery_delete;
 mod shamir_recovery_get_self;
@@ -21,6 +22,7 @@ mod share_workspace;
 mod start_shamir_recovery_invitation_greet;
 mod start_workspace;
 mod tos;
+mod transfer_workspace_ownership;
 mod update_user_profile;
 mod utils;
 mod with_monitors;
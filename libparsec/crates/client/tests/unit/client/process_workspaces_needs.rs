@@ -164,7 +164,9 @@ async fn need_unshare_then_key_rotation(env: &TestbedEnv) {
             .unwrap(),
         RealmNeeds::UnshareThenKeyRotation {
             current_key_index: Some(1),
-            revoked_users: vec!["bob".parse().unwrap()]
+            revoked_users: vec!["bob".parse().unwrap()],
+            expired_users: vec![],
+            ungrouped_users: vec![],
         }
     );
 
@@ -313,6 +315,79 @@ async fn multiple_realms_and_needs(env: &TestbedEnv) {
     );
 }
 
+#[parsec_test(testbed = "coolorg", with_server)]
+async fn share_expired(env: &TestbedEnv) {
+    let wksp1_id: VlobID = *env.template.get_stuff("wksp1_id");
+
+    let alice = env.local_device("alice@dev1");
+    let client = client_factory(&env.discriminant_dir, alice.clone()).await;
+    let mallory_id: UserID = "mallory".parse().unwrap();
+    let expires_on: DateTime = "2100-01-01T00:00:00Z".parse().unwrap();
+
+    client
+        .share_workspace(
+            wksp1_id,
+            mallory_id,
+            Some(RealmRole::Reader),
+            Some(expires_on),
+        )
+        .await
+        .unwrap();
+
+    // Not due yet, but we are told when to process the needs again
+
+    p_assert_eq!(
+        client.process_workspaces_needs().await.unwrap(),
+        Some(expires_on)
+    );
+
+    // Now the share is due
+
+    client
+        .certificates_ops
+        .set_realm_share_expiration(wksp1_id, mallory_id, Some(alice.now()))
+        .await
+        .unwrap();
+    p_assert_eq!(
+        client
+            .certificates_ops
+            .get_realm_needs(wksp1_id)
+            .await
+            .unwrap(),
+        RealmNeeds::UnshareThenKeyRotation {
+            current_key_index: Some(1),
+            revoked_users: vec![],
+            expired_users: vec![mallory_id],
+            ungrouped_users: vec![],
+        }
+    );
+
+    p_assert_eq!(client.process_workspaces_needs().await.unwrap(), None);
+
+    client.poll_server_for_new_certificates().await.unwrap();
+
+    let mut users = client
+        .list_workspace_users(wksp1_id)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|e| e.user_id)
+        .collect::<Vec<_>>();
+    users.sort();
+    p_assert_eq!(
+        users,
+        vec!["bob".parse().unwrap(), "alice".parse().unwrap()]
+    );
+    p_assert_eq!(
+        client
+            .certificates_ops
+            .get_realm_needs(wksp1_id)
+            .await
+            .unwrap(),
+        RealmNeeds::Nothing
+    );
+}
+
 #[parsec_test(testbed = "coolorg")]
 async fn offline(env: &TestbedEnv) {
     let wksp1_id: VlobID = *env.template.get_stuff("wksp1_id");
@@ -14,7 +14,8 @@ use libparsec_types::prelude::*;
 
 use super::utils::client_factory;
 use crate::{
-    ClientRenameWorkspaceError, EventNewCertificates, EventWorkspacesSelfListChanged, WorkspaceInfo,
+    ClientRenameWorkspaceError, EventNewCertificates, EventRealmRenamed,
+    EventWorkspacesSelfListChanged, WorkspaceInfo,
 };
 
 #[parsec_test(testbed = "coolorg")]
@@ -68,6 +69,9 @@ async fn ok(env: &TestbedEnv) {
         .unwrap();
 
     // Rename also trigger a local workspace list refresh
+    spy.assert_next(|event: &EventRealmRenamed| {
+        p_assert_eq!(event.realm_id, wksp1_id);
+    });
     spy.assert_next(|_event: &EventNewCertificates| {});
     spy.assert_next(|_event: &EventWorkspacesSelfListChanged| {});
 
@@ -174,6 +178,9 @@ async fn realm_not_bootstrapped_missing_initial_rename(env: &TestbedEnv) {
         .unwrap();
 
     // Rename also trigger a local workspace list refresh
+    spy.assert_next(|event: &EventRealmRenamed| {
+        p_assert_eq!(event.realm_id, wksp1_id);
+    });
     spy.assert_next(|_event: &EventNewCertificates| {});
     spy.assert_next(|_event: &EventWorkspacesSelfListChanged| {});
 
@@ -341,6 +348,9 @@ async fn realm_not_bootstrapped_missing_initial_key_rotation(env: &TestbedEnv) {
     // Some certificates were missing and had to be fetched first...
     spy.assert_next(|_event: &EventNewCertificates| {});
     // ..then the rename also trigger a local workspace list refresh
+    spy.assert_next(|event: &EventRealmRenamed| {
+        p_assert_eq!(event.realm_id, wksp1_id);
+    });
     spy.assert_next(|_event: &EventNewCertificates| {});
     spy.assert_next(|_event: &EventWorkspacesSelfListChanged| {});
 
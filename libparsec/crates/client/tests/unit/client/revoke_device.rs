@@ -0,0 +1,67 @@
+// Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use libparsec_client_connection::test_register_sequence_of_send_hooks;
+use libparsec_protocol::authenticated_cmds;
+use libparsec_tests_fixtures::prelude::*;
+use libparsec_types::prelude::*;
+
+use super::utils::client_factory;
+use crate::ClientRevokeDeviceError;
+
+#[parsec_test(testbed = "coolorg")]
+async fn ok_and_local_idempotent(env: &TestbedEnv) {
+    let alice = env.local_device("alice@dev1");
+    let client = client_factory(&env.discriminant_dir, alice).await;
+
+    // Mock requests to server
+    let new_common_certificates: Arc<Mutex<Vec<Bytes>>> = Arc::default();
+
+    test_register_sequence_of_send_hooks!(
+        &env.discriminant_dir,
+        // 1) Revoke
+        {
+            let new_common_certificates = new_common_certificates.clone();
+            move |req: authenticated_cmds::latest::device_revoke::Req| {
+                new_common_certificates
+                    .lock()
+                    .unwrap()
+                    .push(req.revoked_device_certificate);
+                authenticated_cmds::latest::device_revoke::Rep::Ok
+            }
+        },
+        // 2) Fetch new certificates
+        {
+            let new_common_certificates = new_common_certificates.clone();
+            move |_req: authenticated_cmds::latest::certificate_get::Req| {
+                authenticated_cmds::latest::certificate_get::Rep::Ok {
+                    common_certificates: new_common_certificates.lock().unwrap().clone(),
+                    realm_certificates: HashMap::new(),
+                    sequester_certificates: vec![],
+                    shamir_recovery_certificates: vec![],
+                }
+            }
+        },
+    );
+
+    let bob_device_id = env.local_device("bob@dev1").device_id;
+    client.revoke_device(bob_device_id).await.unwrap();
+
+    // The device is already known to be revoked, so the server is not contacted
+    client.revoke_device(bob_device_id).await.unwrap();
+}
+
+#[parsec_test(testbed = "coolorg")]
+async fn device_is_self(env: &TestbedEnv) {
+    let alice = env.local_device("alice@dev1");
+    let client = client_factory(&env.discriminant_dir, alice.clone()).await;
+
+    p_assert_matches!(
+        client.revoke_device(alice.device_id).await,
+        Err(ClientRevokeDeviceError::DeviceIsSelf)
+    );
+}
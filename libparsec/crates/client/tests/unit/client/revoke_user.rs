@@ -11,7 +11,10 @@ use libparsec_tests_fixtures::prelude::*;
 use libparsec_types::prelude::*;
 
 use super::utils::client_factory;
-use crate::ClientRevokeUserError;
+use crate::{
+    ClientRevokeUserError, EventClockDrift, EventNewCertificates, EventUserRevoked,
+    OrphanedWorkspacesStrategy,
+};
 
 #[parsec_test(testbed = "coolorg")]
 async fn ok(env: &TestbedEnv) {
@@ -48,14 +51,18 @@ async fn ok(env: &TestbedEnv) {
         },
     );
 
-    // let mut spy = client.event_bus.spy.start_expecting();
+    let mut spy = client.event_bus.spy.start_expecting();
 
     let bob_user_id: UserID = "bob".parse().unwrap();
-    client.revoke_user(bob_user_id).await.unwrap();
+    client
+        .revoke_user(bob_user_id, OrphanedWorkspacesStrategy::Abort)
+        .await
+        .unwrap();
 
-    // TODO: check event !
-    // spy.assert_next(|event: &EventUserRevoked| {
-    // });
+    spy.assert_next(|event: &EventUserRevoked| {
+        p_assert_eq!(event.user_id, bob_user_id);
+    });
+    spy.assert_next(|_event: &EventNewCertificates| {});
 
     let users = client.list_users(false, None, None).await.unwrap();
     let bob = users.iter().find(|user| user.id == bob_user_id).unwrap();
@@ -124,13 +131,112 @@ async fn loop_require_greater_timestamp(env: &TestbedEnv) {
     );
 
     let bob_user_id: UserID = "bob".parse().unwrap();
-    client.revoke_user(bob_user_id).await.unwrap();
+    client
+        .revoke_user(bob_user_id, OrphanedWorkspacesStrategy::Abort)
+        .await
+        .unwrap();
 
     let users = client.list_users(false, None, None).await.unwrap();
     let bob = users.iter().find(|user| user.id == bob_user_id).unwrap();
     p_assert_eq!(bob.revoked_by, Some("alice@dev1".parse().unwrap()));
 }
 
+#[parsec_test(testbed = "coolorg")]
+async fn require_greater_timestamp_wait_for_clock(env: &TestbedEnv) {
+    let alice = env.local_device("alice@dev1");
+    let client = client_factory(&env.discriminant_dir, alice.clone()).await;
+
+    // Server requires a timestamp a bit too far ahead of our clock to be used
+    // as-is: we should wait for our clock to catch up before retrying.
+    let strictly_greater_than = alice.time_provider.now().add_us(45_000_000);
+    // Speed up our clock so that the wait doesn't slow down the test
+    alice.time_provider.mock_time_faster(100.);
+
+    let new_common_certificates: Arc<Mutex<Vec<Bytes>>> = Arc::default();
+
+    test_register_sequence_of_send_hooks!(
+        &env.discriminant_dir,
+        // 1) try to Revoke
+        move |_req: authenticated_cmds::latest::user_revoke::Req| {
+            authenticated_cmds::latest::user_revoke::Rep::RequireGreaterTimestamp {
+                strictly_greater_than,
+            }
+        },
+        // 2) re Revoke
+        {
+            let new_common_certificates = new_common_certificates.clone();
+            let alice = alice.clone();
+            move |req: authenticated_cmds::latest::user_revoke::Req| {
+                let certif =
+                    RevokedUserCertificate::unsecure_load(req.revoked_user_certificate.clone())
+                        .unwrap()
+                        .skip_validation(UnsecureSkipValidationReason::Test);
+                assert!(certif.timestamp > strictly_greater_than);
+                // We waited for our clock instead of sending a timestamp far in our future
+                assert!(alice.time_provider.now().add_us(30_000_000) >= strictly_greater_than);
+                new_common_certificates
+                    .lock()
+                    .unwrap()
+                    .push(req.revoked_user_certificate);
+                authenticated_cmds::latest::user_revoke::Rep::Ok
+            }
+        },
+        // 3) Fetch new certificates
+        {
+            let new_common_certificates = new_common_certificates.clone();
+            move |_req: authenticated_cmds::latest::certificate_get::Req| {
+                authenticated_cmds::latest::certificate_get::Rep::Ok {
+                    common_certificates: new_common_certificates.lock().unwrap().clone(),
+                    realm_certificates: HashMap::new(),
+                    sequester_certificates: vec![],
+                    shamir_recovery_certificates: vec![],
+                }
+            }
+        },
+    );
+
+    let bob_user_id: UserID = "bob".parse().unwrap();
+    client
+        .revoke_user(bob_user_id, OrphanedWorkspacesStrategy::Ignore)
+        .await
+        .unwrap();
+}
+
+#[parsec_test(testbed = "coolorg")]
+async fn require_greater_timestamp_clock_drift(env: &TestbedEnv) {
+    let alice = env.local_device("alice@dev1");
+    let client = client_factory(&env.discriminant_dir, alice.clone()).await;
+
+    // Server requires a timestamp way too far ahead of our clock to wait for it
+    let strictly_greater_than = alice.time_provider.now().add_us(3_600_000_000);
+
+    test_register_send_hook(
+        &env.discriminant_dir,
+        move |_req: authenticated_cmds::latest::user_revoke::Req| {
+            authenticated_cmds::latest::user_revoke::Rep::RequireGreaterTimestamp {
+                strictly_greater_than,
+            }
+        },
+    );
+
+    let mut spy = client.event_bus.spy.start_expecting();
+
+    let bob_user_id: UserID = "bob".parse().unwrap();
+    let outcome = client
+        .revoke_user(bob_user_id, OrphanedWorkspacesStrategy::Ignore)
+        .await;
+
+    p_assert_matches!(
+        outcome,
+        Err(ClientRevokeUserError::ClockDrift(err))
+        if err.required_timestamp > strictly_greater_than
+    );
+    spy.assert_next(|event: &EventClockDrift| {
+        assert!(event.required_timestamp > strictly_greater_than);
+        assert!(event.client_timestamp < strictly_greater_than);
+    });
+}
+
 #[parsec_test(testbed = "coolorg")]
 async fn not_allowed(env: &TestbedEnv) {
     let bob = env.local_device("bob@dev1");
@@ -143,10 +249,10 @@ async fn not_allowed(env: &TestbedEnv) {
         },
     );
 
-    let spy = client.event_bus.spy.start_expecting();
+    let mut spy = client.event_bus.spy.start_expecting();
 
     let err = client
-        .revoke_user("alice".parse().unwrap())
+        .revoke_user("alice".parse().unwrap(), OrphanedWorkspacesStrategy::Abort)
         .await
         .unwrap_err();
 
@@ -166,9 +272,12 @@ async fn not_found(env: &TestbedEnv) {
         },
     );
 
-    let spy = client.event_bus.spy.start_expecting();
+    let mut spy = client.event_bus.spy.start_expecting();
 
-    let err = client.revoke_user(UserID::default()).await.unwrap_err();
+    let err = client
+        .revoke_user(UserID::default(), OrphanedWorkspacesStrategy::Abort)
+        .await
+        .unwrap_err();
 
     p_assert_matches!(err, ClientRevokeUserError::UserNotFound);
     spy.assert_no_events();
@@ -179,10 +288,10 @@ async fn offline(env: &TestbedEnv) {
     let alice = env.local_device("alice@dev1");
     let client = client_factory(&env.discriminant_dir, alice).await;
 
-    let spy = client.event_bus.spy.start_expecting();
+    let mut spy = client.event_bus.spy.start_expecting();
 
     let err = client
-        .revoke_user("bob".parse().unwrap())
+        .revoke_user("bob".parse().unwrap(), OrphanedWorkspacesStrategy::Abort)
         .await
         .unwrap_err();
 
@@ -206,13 +315,42 @@ async fn stopped(env: &TestbedEnv) {
         },
     );
 
-    let spy = client.event_bus.spy.start_expecting();
+    let mut spy = client.event_bus.spy.start_expecting();
 
     let err = client
-        .revoke_user("bob".parse().unwrap())
+        .revoke_user("bob".parse().unwrap(), OrphanedWorkspacesStrategy::Ignore)
         .await
         .unwrap_err();
 
     p_assert_matches!(err, ClientRevokeUserError::Stopped);
     spy.assert_no_events();
 }
+
+#[parsec_test(testbed = "coolorg")]
+async fn sole_owner_of_workspaces(env: &TestbedEnv) {
+    let wksp1_id: VlobID = *env.template.get_stuff("wksp1_id");
+    env.customize(|builder| {
+        // Alice is the only owner of wksp1 (which is shared with Bob)
+        builder.update_user_profile("bob", UserProfile::Admin);
+        builder.certificates_storage_fetch_certificates("bob@dev1");
+    })
+    .await;
+
+    let bob = env.local_device("bob@dev1");
+    let client = client_factory(&env.discriminant_dir, bob).await;
+
+    let mut spy = client.event_bus.spy.start_expecting();
+
+    // No server command is expected to be sent
+    let err = client
+        .revoke_user("alice".parse().unwrap(), OrphanedWorkspacesStrategy::Abort)
+        .await
+        .unwrap_err();
+
+    p_assert_matches!(
+        err,
+        ClientRevokeUserError::SoleOwnerOfWorkspaces { workspaces }
+        if workspaces == [wksp1_id]
+    );
+    spy.assert_no_events();
+}
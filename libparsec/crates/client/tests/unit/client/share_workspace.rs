@@ -38,6 +38,7 @@ async fn ok_with_author_having_changed_its_role(
             wksp1_id,
             "mallory".parse().unwrap(),
             Some(RealmRole::Reader),
+            None,
         )
         .await
         .unwrap();
@@ -86,7 +87,12 @@ async fn ok(#[values("author_is_owner", "author_is_manager")] kind: &str, env: &
     // 1) Give initial access
 
     client
-        .share_workspace(wksp1_id, mallory.user_id, Some(RealmRole::Contributor))
+        .share_workspace(
+            wksp1_id,
+            mallory.user_id,
+            Some(RealmRole::Contributor),
+            None,
+        )
         .await
         .unwrap();
 
@@ -98,6 +104,7 @@ async fn ok(#[values("author_is_owner", "author_is_manager")] kind: &str, env: &
             human_handle: mallory.human_handle.clone(),
             current_profile: UserProfile::Standard,
             current_role: RealmRole::Contributor,
+            expires_on: None,
         },
     );
     p_assert_eq!(wksp1_access_info, expected_wksp1_access_info);
@@ -105,7 +112,7 @@ async fn ok(#[values("author_is_owner", "author_is_manager")] kind: &str, env: &
     // Change again
 
     client
-        .share_workspace(wksp1_id, mallory.user_id, Some(RealmRole::Reader))
+        .share_workspace(wksp1_id, mallory.user_id, Some(RealmRole::Reader), None)
         .await
         .unwrap();
 
@@ -119,7 +126,7 @@ async fn ok(#[values("author_is_owner", "author_is_manager")] kind: &str, env: &
     // Finally unshare
 
     client
-        .share_workspace(wksp1_id, mallory.user_id, None)
+        .share_workspace(wksp1_id, mallory.user_id, None, None)
         .await
         .unwrap();
 
@@ -128,6 +135,78 @@ async fn ok(#[values("author_is_owner", "author_is_manager")] kind: &str, env: &
     p_assert_eq!(wksp1_access_info, expected_wksp1_access_info);
 }
 
+#[parsec_test(testbed = "coolorg", with_server)]
+async fn ok_with_expiration(env: &TestbedEnv) {
+    let wksp1_id: VlobID = *env.template.get_stuff("wksp1_id");
+
+    let alice = env.local_device("alice@dev1");
+    let client = client_factory(&env.discriminant_dir, alice).await;
+    let mallory_id: UserID = "mallory".parse().unwrap();
+    let expires_on: DateTime = "2100-01-01T00:00:00Z".parse().unwrap();
+
+    macro_rules! mallory_access {
+        () => {
+            client
+                .list_workspace_users(wksp1_id)
+                .await
+                .unwrap()
+                .into_iter()
+                .find(|info| info.user_id == mallory_id)
+                .map(|info| (info.current_role, info.expires_on))
+        };
+    }
+
+    client
+        .share_workspace(
+            wksp1_id,
+            mallory_id,
+            Some(RealmRole::Reader),
+            Some(expires_on),
+        )
+        .await
+        .unwrap();
+    p_assert_eq!(
+        mallory_access!(),
+        Some((RealmRole::Reader, Some(expires_on)))
+    );
+
+    // Sharing again with the same role allows to remove the expiration
+
+    client
+        .share_workspace(wksp1_id, mallory_id, Some(RealmRole::Reader), None)
+        .await
+        .unwrap();
+    p_assert_eq!(mallory_access!(), Some((RealmRole::Reader, None)));
+
+    client
+        .share_workspace(
+            wksp1_id,
+            mallory_id,
+            Some(RealmRole::Contributor),
+            Some(expires_on),
+        )
+        .await
+        .unwrap();
+    p_assert_eq!(
+        mallory_access!(),
+        Some((RealmRole::Contributor, Some(expires_on)))
+    );
+
+    // Unsharing removes the expiration
+
+    client
+        .share_workspace(wksp1_id, mallory_id, None, None)
+        .await
+        .unwrap();
+    p_assert_eq!(mallory_access!(), None);
+
+    client
+        .share_workspace(wksp1_id, mallory_id, Some(RealmRole::Reader), None)
+        .await
+        .unwrap();
+    p_assert_eq!(mallory_access!(), Some((RealmRole::Reader, None)));
+}
+
 #[parsec_test(testbed = "coolorg", with_server)]
 async fn ok_require_bootstrap_before_share(env: &TestbedEnv) {
     let alice = env.local_device("alice@dev1");
@@ -140,7 +219,7 @@ async fn ok_require_bootstrap_before_share(env: &TestbedEnv) {
         .unwrap();
 
     client
-        .share_workspace(wid, "bob".parse().unwrap(), Some(RealmRole::Reader))
+        .share_workspace(wid, "bob".parse().unwrap(), Some(RealmRole::Reader), None)
         .await
         .unwrap();
 }
@@ -161,7 +240,12 @@ async fn ok_placeholder_workspace(env: &TestbedEnv) {
     let client = client_factory(&env.discriminant_dir, alice).await;
 
     client
-        .share_workspace(wksp2_id, "bob".parse().unwrap(), Some(RealmRole::Reader))
+        .share_workspace(
+            wksp2_id,
+            "bob".parse().unwrap(),
+            Some(RealmRole::Reader),
+            None,
+        )
         .await
         .unwrap();
 }
@@ -174,7 +258,12 @@ async fn to_self(env: &TestbedEnv) {
     let client = client_factory(&env.discriminant_dir, alice).await;
 
     let err = client
-        .share_workspace(wksp1_id, "alice".parse().unwrap(), Some(RealmRole::Reader))
+        .share_workspace(
+            wksp1_id,
+            "alice".parse().unwrap(),
+            Some(RealmRole::Reader),
+            None,
+        )
         .await
         .unwrap_err();
     p_assert_matches!(err, ClientShareWorkspaceError::RecipientIsSelf);
@@ -188,7 +277,12 @@ async fn to_unknown_user(env: &TestbedEnv) {
     let client = client_factory(&env.discriminant_dir, alice).await;
 
     let err = client
-        .share_workspace(wksp1_id, "mike".parse().unwrap(), Some(RealmRole::Reader))
+        .share_workspace(
+            wksp1_id,
+            "mike".parse().unwrap(),
+            Some(RealmRole::Reader),
+            None,
+        )
         .await
         .unwrap_err();
     p_assert_matches!(err, ClientShareWorkspaceError::RecipientNotFound);
@@ -202,7 +296,12 @@ async fn to_unknown_workspace(env: &TestbedEnv) {
     let client = client_factory(&env.discriminant_dir, alice).await;
 
     let err = client
-        .share_workspace(dummy_id, "mallory".parse().unwrap(), Some(RealmRole::Owner))
+        .share_workspace(
+            dummy_id,
+            "mallory".parse().unwrap(),
+            Some(RealmRole::Owner),
+            None,
+        )
         .await
         .unwrap_err();
     p_assert_matches!(err, ClientShareWorkspaceError::WorkspaceNotFound);
@@ -227,6 +326,7 @@ async fn cannot_share_with_revoked_recipient_known_on_client(env: &TestbedEnv) {
             wksp1_id,
             "mallory".parse().unwrap(),
             Some(RealmRole::Reader),
+            None,
         )
         .await
         .unwrap_err();
@@ -250,6 +350,7 @@ async fn cannot_share_with_revoked_recipient_unknown_on_client(env: &TestbedEnv)
             wksp1_id,
             "mallory".parse().unwrap(),
             Some(RealmRole::Reader),
+            None,
         )
         .await
         .unwrap_err();
@@ -283,7 +384,7 @@ async fn can_unshare_with_revoked_recipient(
     let client = client_factory(&env.discriminant_dir, alice.clone()).await;
 
     client
-        .share_workspace(wksp1_id, "bob".parse().unwrap(), None)
+        .share_workspace(wksp1_id, "bob".parse().unwrap(), None, None)
         .await
         .unwrap();
 
@@ -295,6 +396,7 @@ async fn can_unshare_with_revoked_recipient(
             human_handle: alice.human_handle.clone(),
             current_profile: UserProfile::Admin,
             current_role: RealmRole::Owner,
+            expires_on: None,
         }]
     );
 }
@@ -327,6 +429,7 @@ async fn author_not_allowed(#[values("from_client", "from_server")] kind: &str,
             wksp1_id,
             "mallory".parse().unwrap(),
             Some(RealmRole::Reader),
+            None,
         )
         .await
         .unwrap_err();
@@ -345,6 +448,7 @@ async fn role_incompatible_with_outsider(env: &TestbedEnv) {
             wksp1_id,
             "mallory".parse().unwrap(),
             Some(RealmRole::Manager),
+            None,
         )
         .await
         .unwrap_err();
@@ -363,6 +467,7 @@ async fn offline(env: &TestbedEnv) {
             wksp1_id,
             "mallory".parse().unwrap(),
             Some(RealmRole::Reader),
+            None,
         )
         .await
         .unwrap_err();
@@ -385,6 +490,7 @@ async fn timestamp_out_of_ballpark(env: &TestbedEnv) {
             wksp1_id,
             "mallory".parse().unwrap(),
             Some(RealmRole::Reader),
+            None,
         )
         .await
         .unwrap_err();
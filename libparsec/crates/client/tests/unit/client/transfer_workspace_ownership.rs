@@ -0,0 +1,83 @@
+// Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+use libparsec_tests_fixtures::prelude::*;
+use libparsec_types::prelude::*;
+
+use super::utils::client_factory;
+use crate::ClientTransferWorkspaceOwnershipError;
+
+#[parsec_test(testbed = "coolorg", with_server)]
+async fn ok(env: &TestbedEnv) {
+    let wksp1_id: VlobID = *env.template.get_stuff("wksp1_id");
+    let alice = env.local_device("alice@dev1");
+    let bob_user_id: UserID = "bob".parse().unwrap();
+    let client = client_factory(&env.discriminant_dir, alice.clone()).await;
+
+    client
+        .transfer_workspace_ownership(wksp1_id, bob_user_id)
+        .await
+        .unwrap();
+
+    let users = client.list_workspace_users(wksp1_id).await.unwrap();
+    let bob = users
+        .iter()
+        .find(|user| user.user_id == bob_user_id)
+        .unwrap();
+    p_assert_eq!(bob.current_role, RealmRole::Owner);
+
+    // Alice is no longer the only owner of wksp1
+    p_assert_eq!(
+        client
+            .certificates_ops
+            .list_solely_owned_realms(alice.user_id)
+            .await
+            .unwrap(),
+        Vec::<VlobID>::new()
+    );
+}
+
+#[parsec_test(testbed = "coolorg")]
+async fn author_not_allowed(env: &TestbedEnv) {
+    let wksp1_id: VlobID = *env.template.get_stuff("wksp1_id");
+    let bob = env.local_device("bob@dev1");
+    let client = client_factory(&env.discriminant_dir, bob).await;
+
+    // Bob is only Reader, no server command is expected to be sent
+    let err = client
+        .transfer_workspace_ownership(wksp1_id, "alice".parse().unwrap())
+        .await
+        .unwrap_err();
+
+    p_assert_matches!(err, ClientTransferWorkspaceOwnershipError::AuthorNotAllowed);
+}
+
+#[parsec_test(testbed = "coolorg", with_server)]
+async fn new_owner_is_outsider(env: &TestbedEnv) {
+    let wksp1_id: VlobID = *env.template.get_stuff("wksp1_id");
+    let alice = env.local_device("alice@dev1");
+    let client = client_factory(&env.discriminant_dir, alice).await;
+
+    let err = client
+        .transfer_workspace_ownership(wksp1_id, "mallory".parse().unwrap())
+        .await
+        .unwrap_err();
+
+    p_assert_matches!(
+        err,
+        ClientTransferWorkspaceOwnershipError::NewOwnerIsOutsider
+    );
+}
+
+#[parsec_test(testbed = "coolorg")]
+async fn new_owner_is_self(env: &TestbedEnv) {
+    let wksp1_id: VlobID = *env.template.get_stuff("wksp1_id");
+    let alice = env.local_device("alice@dev1");
+    let client = client_factory(&env.discriminant_dir, alice.clone()).await;
+
+    let err = client
+        .transfer_workspace_ownership(wksp1_id, alice.user_id)
+        .await
+        .unwrap_err();
+
+    p_assert_matches!(err, ClientTransferWorkspaceOwnershipError::NewOwnerIsSelf);
+}
@@ -7,12 +7,15 @@ use libparsec_protocol::authenticated_cmds;
 use libparsec_tests_fixtures::prelude::*;
 use libparsec_types::prelude::*;
 
-use crate::{Client, ClientUserUpdateProfileError};
+use crate::{Client, ClientUserUpdateProfileError, OrphanedWorkspacesStrategy};
 
 use super::utils::client_factory;
 
 async fn update_profile_and_check(profile: UserProfile, user_id: UserID, client: &Arc<Client>) {
-    client.update_user_profile(user_id, profile).await.unwrap();
+    client
+        .update_user_profile(user_id, profile, OrphanedWorkspacesStrategy::Abort)
+        .await
+        .unwrap();
 
     let users = client.list_users(false, None, None).await.unwrap();
     let user = users.iter().find(|user| user.id == user_id).unwrap();
@@ -65,9 +68,16 @@ async fn user_revoked(env: &TestbedEnv) {
     let client = client_factory(&env.discriminant_dir, alice).await;
     let bob_user_id: UserID = "bob".parse().unwrap();
 
-    client.revoke_user(bob_user_id).await.unwrap();
+    client
+        .revoke_user(bob_user_id, OrphanedWorkspacesStrategy::Abort)
+        .await
+        .unwrap();
     let err = client
-        .update_user_profile("bob".parse().unwrap(), UserProfile::Outsider)
+        .update_user_profile(
+            "bob".parse().unwrap(),
+            UserProfile::Outsider,
+            OrphanedWorkspacesStrategy::Abort,
+        )
         .await
         .unwrap_err();
 
@@ -80,7 +90,11 @@ async fn self_change_admin(env: &TestbedEnv) {
     let client = client_factory(&env.discriminant_dir, alice).await;
 
     let err = client
-        .update_user_profile("alice".parse().unwrap(), UserProfile::Admin)
+        .update_user_profile(
+            "alice".parse().unwrap(),
+            UserProfile::Admin,
+            OrphanedWorkspacesStrategy::Abort,
+        )
         .await
         .unwrap_err();
 
@@ -93,7 +107,11 @@ async fn not_allowed_standard(env: &TestbedEnv) {
     let client = client_factory(&env.discriminant_dir, bob).await;
 
     let err = client
-        .update_user_profile("alice".parse().unwrap(), UserProfile::Outsider)
+        .update_user_profile(
+            "alice".parse().unwrap(),
+            UserProfile::Outsider,
+            OrphanedWorkspacesStrategy::Ignore,
+        )
         .await
         .unwrap_err();
 
@@ -106,7 +124,11 @@ async fn not_allowed_outsider(env: &TestbedEnv) {
     let client = client_factory(&env.discriminant_dir, mallory).await;
 
     let err = client
-        .update_user_profile("bob".parse().unwrap(), UserProfile::Outsider)
+        .update_user_profile(
+            "bob".parse().unwrap(),
+            UserProfile::Outsider,
+            OrphanedWorkspacesStrategy::Abort,
+        )
         .await
         .unwrap_err();
 
@@ -119,7 +141,11 @@ async fn self_downgrade_outsider(env: &TestbedEnv) {
     let client = client_factory(&env.discriminant_dir, alice).await;
 
     let err = client
-        .update_user_profile("alice".parse().unwrap(), UserProfile::Outsider)
+        .update_user_profile(
+            "alice".parse().unwrap(),
+            UserProfile::Outsider,
+            OrphanedWorkspacesStrategy::Abort,
+        )
         .await
         .unwrap_err();
 
@@ -132,7 +158,11 @@ async fn self_downgrade_standard(env: &TestbedEnv) {
     let client = client_factory(&env.discriminant_dir, alice).await;
 
     let err = client
-        .update_user_profile("alice".parse().unwrap(), UserProfile::Standard)
+        .update_user_profile(
+            "alice".parse().unwrap(),
+            UserProfile::Standard,
+            OrphanedWorkspacesStrategy::Abort,
+        )
         .await
         .unwrap_err();
 
@@ -145,7 +175,11 @@ async fn not_found(env: &TestbedEnv) {
     let client = client_factory(&env.discriminant_dir, alice).await;
 
     let err = client
-        .update_user_profile(UserID::default(), UserProfile::Standard)
+        .update_user_profile(
+            UserID::default(),
+            UserProfile::Standard,
+            OrphanedWorkspacesStrategy::Abort,
+        )
         .await
         .unwrap_err();
 
@@ -160,7 +194,11 @@ async fn offline(env: &TestbedEnv) {
     let spy = client.event_bus.spy.start_expecting();
 
     let err = client
-        .update_user_profile("bob".parse().unwrap(), UserProfile::Admin)
+        .update_user_profile(
+            "bob".parse().unwrap(),
+            UserProfile::Admin,
+            OrphanedWorkspacesStrategy::Abort,
+        )
         .await
         .unwrap_err();
 
@@ -187,7 +225,11 @@ async fn stopped(env: &TestbedEnv) {
     let spy = client.event_bus.spy.start_expecting();
 
     let err = client
-        .update_user_profile("bob".parse().unwrap(), UserProfile::Admin)
+        .update_user_profile(
+            "bob".parse().unwrap(),
+            UserProfile::Admin,
+            OrphanedWorkspacesStrategy::Abort,
+        )
         .await
         .unwrap_err();
 
@@ -238,9 +280,71 @@ async fn server_error(
     );
 
     let err = client
-        .update_user_profile("bob".parse().unwrap(), UserProfile::Admin)
+        .update_user_profile(
+            "bob".parse().unwrap(),
+            UserProfile::Admin,
+            OrphanedWorkspacesStrategy::Abort,
+        )
         .await
         .unwrap_err();
 
     assert(err);
 }
+
+#[parsec_test(testbed = "coolorg")]
+async fn sole_owner_of_workspaces(env: &TestbedEnv) {
+    let wksp1_id: VlobID = *env.template.get_stuff("wksp1_id");
+    env.customize(|builder| {
+        // Alice is the only owner of wksp1 (which is shared with Bob)
+        builder.update_user_profile("bob", UserProfile::Admin);
+        builder.certificates_storage_fetch_certificates("bob@dev1");
+    })
+    .await;
+
+    let bob = env.local_device("bob@dev1");
+    let client = client_factory(&env.discriminant_dir, bob).await;
+
+    // No server command is expected to be sent
+    let err = client
+        .update_user_profile(
+            "alice".parse().unwrap(),
+            UserProfile::Outsider,
+            OrphanedWorkspacesStrategy::Abort,
+        )
+        .await
+        .unwrap_err();
+
+    p_assert_matches!(
+        err,
+        ClientUserUpdateProfileError::SoleOwnerOfWorkspaces { workspaces }
+        if workspaces == [wksp1_id]
+    );
+}
+
+#[parsec_test(testbed = "coolorg", with_server)]
+async fn sole_owner_of_workspaces_ignored(env: &TestbedEnv) {
+    env.customize(|builder| {
+        builder.update_user_profile("bob", UserProfile::Admin);
+        builder.certificates_storage_fetch_certificates("bob@dev1");
+    })
+    .await;
+
+    let bob = env.local_device("bob@dev1");
+    let client = client_factory(&env.discriminant_dir, bob).await;
+
+    client
+        .update_user_profile(
+            "alice".parse().unwrap(),
+            UserProfile::Outsider,
+            OrphanedWorkspacesStrategy::Ignore,
+        )
+        .await
+        .unwrap();
+
+    let users = client.list_users(false, None, None).await.unwrap();
+    let alice = users
+        .iter()
+        .find(|user| user.id == "alice".parse().unwrap())
+        .unwrap();
+    p_assert_eq!(alice.current_profile, UserProfile::Outsider);
+}
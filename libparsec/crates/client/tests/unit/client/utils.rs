@@ -5,7 +5,10 @@ use std::{path::Path, sync::Arc};
 use libparsec_client_connection::ProxyConfig;
 use libparsec_types::prelude::*;
 
-use crate::{Client, ClientConfig, EventBus, MountpointMountStrategy, WorkspaceStorageCacheSize};
+use crate::{
+    Client, ClientConfig, EventBus, MountpointMountStrategy, OutboundSyncConfig,
+    WorkspaceStorageCacheSize,
+};
 
 /// Create a client for the given device WITHOUT monitors (i.e. the client has
 /// no background task reacting to events, which is pretty useful for testing
@@ -21,6 +24,8 @@ pub(crate) async fn client_factory(
         mountpoint_mount_strategy: MountpointMountStrategy::Disabled,
         workspace_storage_cache_size: WorkspaceStorageCacheSize::Default,
         proxy: ProxyConfig::default(),
+        outbound_sync: OutboundSyncConfig::default(),
+        pki_revocation_policy: Default::default(),
         with_monitors: false,
         prevent_sync_pattern: PreventSyncPattern::empty(),
     });
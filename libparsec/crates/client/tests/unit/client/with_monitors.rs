@@ -9,7 +9,7 @@ use crate::{
     workspace::{
         EntryStat, OpenOptions, WorkspaceOpenFileError, WorkspaceOps, WorkspaceStatEntryError,
     },
-    Client, ClientConfig, EventBus, MountpointMountStrategy, WorkspaceInfo,
+    Client, ClientConfig, EventBus, MountpointMountStrategy, OutboundSyncConfig, WorkspaceInfo,
     WorkspaceStorageCacheSize,
 };
 
@@ -26,6 +26,8 @@ async fn multi_devices(env: &TestbedEnv) {
         mountpoint_mount_strategy: MountpointMountStrategy::Disabled,
         workspace_storage_cache_size: WorkspaceStorageCacheSize::Default,
         proxy: libparsec_client_connection::ProxyConfig::default(),
+        outbound_sync: OutboundSyncConfig::default(),
+        pki_revocation_policy: Default::default(),
         with_monitors: true,
         prevent_sync_pattern: PreventSyncPattern::empty(),
     });
@@ -163,6 +165,8 @@ async fn sharing(env: &TestbedEnv) {
         mountpoint_mount_strategy: MountpointMountStrategy::Disabled,
         workspace_storage_cache_size: WorkspaceStorageCacheSize::Default,
         proxy: libparsec_client_connection::ProxyConfig::default(),
+        outbound_sync: OutboundSyncConfig::default(),
+        pki_revocation_policy: Default::default(),
         with_monitors: true,
         prevent_sync_pattern: PreventSyncPattern::empty(),
     });
@@ -185,7 +189,7 @@ async fn sharing(env: &TestbedEnv) {
         .await
         .unwrap();
     alice_client
-        .share_workspace(wid, bob.user_id, Some(RealmRole::Owner))
+        .share_workspace(wid, bob.user_id, Some(RealmRole::Owner), None)
         .await
         .unwrap();
 
@@ -234,7 +238,7 @@ async fn sharing(env: &TestbedEnv) {
     // 3a) Now Bob betrays Alice and unshare the workspace with her...
 
     bob_client
-        .share_workspace(wid, alice.user_id, None)
+        .share_workspace(wid, alice.user_id, None, None)
         .await
         .unwrap();
 
@@ -311,6 +315,8 @@ async fn multi_devices_with_confinement(env: &TestbedEnv) {
         mountpoint_mount_strategy: MountpointMountStrategy::Disabled,
         workspace_storage_cache_size: WorkspaceStorageCacheSize::Default,
         proxy: libparsec_client_connection::ProxyConfig::default(),
+        outbound_sync: OutboundSyncConfig::default(),
+        pki_revocation_policy: Default::default(),
         with_monitors: true,
         prevent_sync_pattern: PreventSyncPattern::from_glob("*.tmp").unwrap(),
     });
@@ -320,6 +326,8 @@ async fn multi_devices_with_confinement(env: &TestbedEnv) {
         mountpoint_mount_strategy: MountpointMountStrategy::Disabled,
         workspace_storage_cache_size: WorkspaceStorageCacheSize::Default,
         proxy: libparsec_client_connection::ProxyConfig::default(),
+        outbound_sync: OutboundSyncConfig::default(),
+        pki_revocation_policy: Default::default(),
         with_monitors: true,
         prevent_sync_pattern: PreventSyncPattern::empty(),
     });
@@ -520,6 +528,8 @@ async fn multi_devices_with_move_out_of_confinement(env: &TestbedEnv) {
         mountpoint_mount_strategy: MountpointMountStrategy::Disabled,
         workspace_storage_cache_size: WorkspaceStorageCacheSize::Default,
         proxy: libparsec_client_connection::ProxyConfig::default(),
+        outbound_sync: OutboundSyncConfig::default(),
+        pki_revocation_policy: Default::default(),
         with_monitors: true,
         prevent_sync_pattern: PreventSyncPattern::from_glob("*.tmp").unwrap(),
     });
@@ -529,6 +539,8 @@ async fn multi_devices_with_move_out_of_confinement(env: &TestbedEnv) {
         mountpoint_mount_strategy: MountpointMountStrategy::Disabled,
         workspace_storage_cache_size: WorkspaceStorageCacheSize::Default,
         proxy: libparsec_client_connection::ProxyConfig::default(),
+        outbound_sync: OutboundSyncConfig::default(),
+        pki_revocation_policy: Default::default(),
         with_monitors: true,
         prevent_sync_pattern: PreventSyncPattern::empty(),
     });
@@ -0,0 +1,57 @@
+// Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+use libparsec_tests_lite::prelude::*;
+use libparsec_types::prelude::*;
+
+use crate::{OutboundSyncConfig, OutboundSyncDelays, OutboundSyncDelaysOverride};
+
+#[test]
+fn outbound_sync_delays_for() {
+    let pst_delays = OutboundSyncDelays {
+        quiescence: Duration::seconds(30),
+        max_delay: Duration::minutes(30),
+    };
+    let vmdk_delays = OutboundSyncDelays {
+        quiescence: Duration::minutes(1),
+        max_delay: Duration::hours(1),
+    };
+    let config = OutboundSyncConfig {
+        overrides: vec![
+            OutboundSyncDelaysOverride {
+                pattern: PreventSyncPattern::from_glob("*.pst").unwrap(),
+                delays: pst_delays,
+            },
+            OutboundSyncDelaysOverride {
+                pattern: PreventSyncPattern::from_multiple_globs(["*.vmdk", "*.pst"].into_iter())
+                    .unwrap(),
+                delays: vmdk_delays,
+            },
+        ],
+        ..Default::default()
+    };
+
+    p_assert_eq!(
+        config.delays_for(Some(&"mails.pst".parse().unwrap())),
+        pst_delays
+    );
+    p_assert_eq!(
+        config.delays_for(Some(&"disk.vmdk".parse().unwrap())),
+        vmdk_delays
+    );
+    p_assert_eq!(
+        config.delays_for(Some(&"notes.txt".parse().unwrap())),
+        OutboundSyncDelays::default()
+    );
+    p_assert_eq!(config.delays_for(None), OutboundSyncDelays::default());
+}
+
+#[test]
+fn outbound_sync_no_overrides() {
+    let config = OutboundSyncConfig::default();
+
+    assert!(!config.has_overrides());
+    p_assert_eq!(
+        config.delays_for(Some(&"mails.pst".parse().unwrap())),
+        config.delays
+    );
+}
@@ -12,7 +12,7 @@ use libparsec_types::prelude::*;
 
 use crate::{
     claimer_retrieve_info, AnyClaimRetrievedInfoCtx, ClientConfig, MountpointMountStrategy,
-    ProxyConfig, WorkspaceStorageCacheSize,
+    OutboundSyncConfig, ProxyConfig, WorkspaceStorageCacheSize,
 };
 
 #[parsec_test(testbed = "minimal")]
@@ -29,6 +29,8 @@ async fn claimer(tmp_path: TmpPath, env: &TestbedEnv) {
         data_base_dir: env.discriminant_dir.clone(),
         workspace_storage_cache_size: WorkspaceStorageCacheSize::Default,
         proxy: ProxyConfig::default(),
+        outbound_sync: OutboundSyncConfig::default(),
+        pki_revocation_policy: Default::default(),
         mountpoint_mount_strategy: MountpointMountStrategy::Disabled,
         with_monitors: false,
         prevent_sync_pattern: PreventSyncPattern::from_regex(r"\.tmp$").unwrap(),
@@ -11,9 +11,10 @@ use libparsec_types::prelude::*;
 
 use crate::{
     claimer_retrieve_info, AnyClaimRetrievedInfoCtx, ClaimerRetrieveInfoError, ClientConfig,
-    MountpointMountStrategy, ProxyConfig, ShamirRecoveryClaimAddShareError,
-    ShamirRecoveryClaimMaybeFinalizeCtx, ShamirRecoveryClaimMaybeRecoverDeviceCtx,
-    ShamirRecoveryClaimPickRecipientError, ShamirRecoveryClaimShare, WorkspaceStorageCacheSize,
+    MountpointMountStrategy, OrphanedWorkspacesStrategy, OutboundSyncConfig, ProxyConfig,
+    ShamirRecoveryClaimAddShareError, ShamirRecoveryClaimMaybeFinalizeCtx,
+    ShamirRecoveryClaimMaybeRecoverDeviceCtx, ShamirRecoveryClaimPickRecipientError,
+    ShamirRecoveryClaimShare, WorkspaceStorageCacheSize,
 };
 
 #[parsec_test(testbed = "shamir", with_server)]
@@ -26,7 +27,10 @@ async fn shamir_full_greeting(tmp_path: TmpPath, env: &TestbedEnv) {
     // Revoke Mallory first
 
     let alice_client = client_factory(&env.discriminant_dir, alice.clone()).await;
-    alice_client.revoke_user(mallory.user_id).await.unwrap();
+    alice_client
+        .revoke_user(mallory.user_id, OrphanedWorkspacesStrategy::Ignore)
+        .await
+        .unwrap();
     let mallory_revoked_on = alice_client
         .list_users(false, None, None)
         .await
@@ -62,6 +66,8 @@ async fn shamir_full_greeting(tmp_path: TmpPath, env: &TestbedEnv) {
         data_base_dir: env.discriminant_dir.clone(),
         workspace_storage_cache_size: WorkspaceStorageCacheSize::Default,
         proxy: ProxyConfig::default(),
+        outbound_sync: OutboundSyncConfig::default(),
+        pki_revocation_policy: Default::default(),
         mountpoint_mount_strategy: MountpointMountStrategy::Disabled,
         with_monitors: false,
         prevent_sync_pattern: PreventSyncPattern::from_regex(r"\.tmp$").unwrap(),
@@ -293,6 +299,8 @@ async fn shamir_invitation_does_not_exist(env: &TestbedEnv) {
         data_base_dir: env.discriminant_dir.clone(),
         workspace_storage_cache_size: WorkspaceStorageCacheSize::Default,
         proxy: ProxyConfig::default(),
+        outbound_sync: OutboundSyncConfig::default(),
+        pki_revocation_policy: Default::default(),
         mountpoint_mount_strategy: MountpointMountStrategy::Disabled,
         with_monitors: false,
         prevent_sync_pattern: PreventSyncPattern::from_regex(r"\.tmp$").unwrap(),
@@ -319,6 +327,8 @@ async fn shamir_invitation_has_been_deleted(env: &TestbedEnv) {
         data_base_dir: env.discriminant_dir.clone(),
         workspace_storage_cache_size: WorkspaceStorageCacheSize::Default,
         proxy: ProxyConfig::default(),
+        outbound_sync: OutboundSyncConfig::default(),
+        pki_revocation_policy: Default::default(),
         mountpoint_mount_strategy: MountpointMountStrategy::Disabled,
         with_monitors: false,
         prevent_sync_pattern: PreventSyncPattern::from_regex(r"\.tmp$").unwrap(),
@@ -361,7 +371,10 @@ async fn unrecoverable_recovery(env: &TestbedEnv) {
     // Revoke Mallory and Bob
 
     let alice_client = client_factory(&env.discriminant_dir, alice.clone()).await;
-    alice_client.revoke_user(mallory.user_id).await.unwrap();
+    alice_client
+        .revoke_user(mallory.user_id, OrphanedWorkspacesStrategy::Ignore)
+        .await
+        .unwrap();
     let mallory_revoked_on = alice_client
         .list_users(false, None, None)
         .await
@@ -370,7 +383,10 @@ async fn unrecoverable_recovery(env: &TestbedEnv) {
         .find(|&u| u.id == mallory.user_id)
         .unwrap()
         .revoked_on;
-    alice_client.revoke_user(bob.user_id).await.unwrap();
+    alice_client
+        .revoke_user(bob.user_id, OrphanedWorkspacesStrategy::Ignore)
+        .await
+        .unwrap();
     let bob_revoked_on = alice_client
         .list_users(false, None, None)
         .await
@@ -406,6 +422,8 @@ async fn unrecoverable_recovery(env: &TestbedEnv) {
         data_base_dir: env.discriminant_dir.clone(),
         workspace_storage_cache_size: WorkspaceStorageCacheSize::Default,
         proxy: ProxyConfig::default(),
+        outbound_sync: OutboundSyncConfig::default(),
+        pki_revocation_policy: Default::default(),
         mountpoint_mount_strategy: MountpointMountStrategy::Disabled,
         with_monitors: false,
         prevent_sync_pattern: PreventSyncPattern::from_regex(r"\.tmp$").unwrap(),
@@ -501,6 +519,8 @@ async fn already_picked_recipient(env: &TestbedEnv) {
         data_base_dir: env.discriminant_dir.clone(),
         workspace_storage_cache_size: WorkspaceStorageCacheSize::Default,
         proxy: ProxyConfig::default(),
+        outbound_sync: OutboundSyncConfig::default(),
+        pki_revocation_policy: Default::default(),
         mountpoint_mount_strategy: MountpointMountStrategy::Disabled,
         with_monitors: false,
         prevent_sync_pattern: PreventSyncPattern::from_regex(r"\.tmp$").unwrap(),
@@ -611,6 +631,8 @@ async fn recipient_not_found(env: &TestbedEnv) {
         data_base_dir: env.discriminant_dir.clone(),
         workspace_storage_cache_size: WorkspaceStorageCacheSize::Default,
         proxy: ProxyConfig::default(),
+        outbound_sync: OutboundSyncConfig::default(),
+        pki_revocation_policy: Default::default(),
         mountpoint_mount_strategy: MountpointMountStrategy::Disabled,
         with_monitors: false,
         prevent_sync_pattern: PreventSyncPattern::from_regex(r"\.tmp$").unwrap(),
@@ -676,6 +698,8 @@ async fn add_share_is_idempotent(env: &TestbedEnv) {
         data_base_dir: env.discriminant_dir.clone(),
         workspace_storage_cache_size: WorkspaceStorageCacheSize::Default,
         proxy: ProxyConfig::default(),
+        outbound_sync: OutboundSyncConfig::default(),
+        pki_revocation_policy: Default::default(),
         mountpoint_mount_strategy: MountpointMountStrategy::Disabled,
         with_monitors: false,
         prevent_sync_pattern: PreventSyncPattern::from_regex(r"\.tmp$").unwrap(),
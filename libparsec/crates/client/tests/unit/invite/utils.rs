@@ -6,7 +6,10 @@ use libparsec_client_connection::ProxyConfig;
 use libparsec_tests_fixtures::prelude::*;
 use libparsec_types::prelude::*;
 
-use crate::{Client, ClientConfig, EventBus, MountpointMountStrategy, WorkspaceStorageCacheSize};
+use crate::{
+    Client, ClientConfig, EventBus, MountpointMountStrategy, OutboundSyncConfig,
+    WorkspaceStorageCacheSize,
+};
 
 /// Create a client for the given device WITHOUT monitors (i.e. the client has
 /// no background task reacting to events, which is pretty useful for testing
@@ -22,6 +25,8 @@ pub(crate) async fn client_factory(
         mountpoint_mount_strategy: MountpointMountStrategy::Disabled,
         workspace_storage_cache_size: WorkspaceStorageCacheSize::Default,
         proxy: ProxyConfig::default(),
+        outbound_sync: OutboundSyncConfig::default(),
+        pki_revocation_policy: Default::default(),
         with_monitors: false,
         prevent_sync_pattern: PreventSyncPattern::empty(),
     });
@@ -34,6 +39,8 @@ pub(crate) fn make_config(env: &TestbedEnv) -> Arc<ClientConfig> {
         data_base_dir: env.discriminant_dir.clone(),
         workspace_storage_cache_size: WorkspaceStorageCacheSize::Default,
         proxy: ProxyConfig::default(),
+        outbound_sync: OutboundSyncConfig::default(),
+        pki_revocation_policy: Default::default(),
         mountpoint_mount_strategy: MountpointMountStrategy::Disabled,
         with_monitors: false,
         prevent_sync_pattern: PreventSyncPattern::from_regex(r"\.tmp$").unwrap(),
@@ -8,7 +8,7 @@ use libparsec_types::prelude::*;
 
 use crate::{
     certif::CertificateOps, user::UserOps, ClientConfig, EventBus, MountpointMountStrategy,
-    WorkspaceStorageCacheSize,
+    OutboundSyncConfig, WorkspaceStorageCacheSize,
 };
 
 pub(crate) async fn user_ops_factory(env: &TestbedEnv, device: &Arc<LocalDevice>) -> UserOps {
@@ -18,6 +18,8 @@ pub(crate) async fn user_ops_factory(env: &TestbedEnv, device: &Arc<LocalDevice>
         mountpoint_mount_strategy: MountpointMountStrategy::Disabled,
         workspace_storage_cache_size: WorkspaceStorageCacheSize::Default,
         proxy: ProxyConfig::default(),
+        outbound_sync: OutboundSyncConfig::default(),
+        pki_revocation_policy: Default::default(),
         with_monitors: false,
         prevent_sync_pattern: PreventSyncPattern::empty(),
     });
@@ -117,7 +117,7 @@ impl Storage {
             for chunk_id in operation.cleanup_ids() {
                 self.clear_chunk_data(chunk_id);
             }
-            operation.commit(&data);
+            operation.commit(&data, false);
         }
     }
 }
@@ -96,6 +96,7 @@ async fn non_placeholder(
                             offset: 0,
                             size: manifest.size.try_into().unwrap(),
                             digest: HashDigest::from_data(block_data),
+                            compressed: false,
                         });
                     });
             }
@@ -376,6 +377,7 @@ async fn non_placeholder(
             size,
             blocksize: _,
             blocks,
+            content_defined_chunking: _,
         } = base;
         p_assert_eq!(*author, "alice@dev1".parse().unwrap());
         p_assert_eq!(*id, conflicted_id);
@@ -433,6 +435,7 @@ async fn non_placeholder(
             size,
             blocksize: _,
             blocks,
+            content_defined_chunking: _,
         } = base;
         p_assert_eq!(*author, "alice@dev1".parse().unwrap());
         p_assert_eq!(*id, conflicted_id);
@@ -34,7 +34,9 @@ async fn no_remote_change(
             digest: HashDigest::from(hex!(
                 "7d486915b914332bb5730fd772223e8b276919e51edca2de0f82c5fc1bce7eb5"
             )),
+            compressed: false,
         }],
+        content_defined_chunking: false,
     };
     let mut local = LocalFileManifest {
         base: remote.clone(),
@@ -67,6 +69,7 @@ async fn no_remote_change(
                 digest: HashDigest::from(hex!(
                     "3d66ba5747c74614850dab7c14cbe7b303ddb1823998491f87a261dcadd978d9"
                 )),
+                compressed: false,
             });
         }
         "same_version_with_local_change" => {
@@ -123,6 +126,7 @@ async fn remote_only_change(
         size: 0,
         blocksize: Blocksize::try_from(512 * 1024).unwrap(),
         blocks: vec![],
+        content_defined_chunking: false,
     };
     let mut local = LocalFileManifest {
         base: remote.clone(),
@@ -150,6 +154,7 @@ async fn remote_only_change(
             size: 0,
             blocksize: Blocksize::try_from(512 * 1024).unwrap(),
             blocks: vec![],
+            content_defined_chunking: false,
         },
         parent: parent_id,
         need_sync: false,
@@ -182,6 +187,7 @@ async fn remote_only_change(
                 digest: HashDigest::from(hex!(
                     "3d66ba5747c74614850dab7c14cbe7b303ddb1823998491f87a261dcadd978d9"
                 )),
+                compressed: false,
             });
 
             expected.base.size = remote.size;
@@ -208,6 +214,7 @@ async fn remote_only_change(
                 digest: HashDigest::from(hex!(
                     "3d66ba5747c74614850dab7c14cbe7b303ddb1823998491f87a261dcadd978d9"
                 )),
+                compressed: false,
             });
             local.size = 10;
             local.blocks.push(vec![ChunkView {
@@ -227,6 +234,7 @@ async fn remote_only_change(
                 digest: HashDigest::from(hex!(
                     "957d1ffaa047479bb2e21416949182b33897fb6bfe674a439dd2b682e327dbe3"
                 )),
+                compressed: false,
             });
 
             expected.base.size = remote.size;
@@ -265,6 +273,7 @@ async fn local_and_remote_changes(
         "size_and_blocks_modified_in_both_with_remote_from_ourself",
         "blocksize_modified_in_both",
         "blocks_modified_in_both",
+        "blocks_rekeyed_in_remote_and_modified_in_local",
         "size_and_blocks_modified_in_both"
     )]
     kind: &str,
@@ -297,7 +306,9 @@ async fn local_and_remote_changes(
             digest: HashDigest::from(hex!(
                 "26d623b082f145d88927a4de50c162b59b2aaa1202ae4415b18e26a67c7a43a7"
             )),
+            compressed: false,
         }],
+        content_defined_chunking: false,
     };
     let mut local = LocalFileManifest {
         base: remote.clone(),
@@ -521,6 +532,9 @@ async fn local_and_remote_changes(
         "blocks_modified_in_both" => {
             let new_block_id = BlockID::from_hex("c4bd6179df134cd49ec50c70d09a1bc7").unwrap();
             remote.blocks[0].id = new_block_id;
+            remote.blocks[0].digest = HashDigest::from(hex!(
+                "076a27c79e5ace2a3d47f9dd2e83e4ff6ea8872b3c2218f66c92b89b55f36560"
+            ));
 
             let new_chunk_id = ChunkID::from_hex("6d06c7044f7d4275b5269aa1b6ae137e").unwrap();
             local.blocks[0][0].id = new_chunk_id;
@@ -528,6 +542,21 @@ async fn local_and_remote_changes(
 
             MergeLocalFileManifestOutcome::Conflict(remote.clone())
         }
+        "blocks_rekeyed_in_remote_and_modified_in_local" => {
+            // Re-encrypting the realm's data only changes the block IDs, hence
+            // the remote content hasn't changed and the local changes are kept.
+            let new_block_id = BlockID::from_hex("c4bd6179df134cd49ec50c70d09a1bc7").unwrap();
+            remote.blocks[0].id = new_block_id;
+
+            let new_chunk_id = ChunkID::from_hex("6d06c7044f7d4275b5269aa1b6ae137e").unwrap();
+            local.blocks[0][0].id = new_chunk_id;
+            local.blocks[0][0].access = None;
+
+            merged.base.blocks.clone_from(&remote.blocks);
+            merged.blocks.clone_from(&local.blocks);
+
+            MergeLocalFileManifestOutcome::Merged(merged)
+        }
         "size_and_blocks_modified_in_both" => {
             remote.size = 5;
             remote.blocks[0].size = NonZeroU64::new(5).unwrap();
@@ -42,6 +42,7 @@ fn non_empty_file_no_changes() {
             digest: HashDigest::from(hex!(
                 "26d623b082f145d88927a4de50c162b59b2aaa1202ae4415b18e26a67c7a43a7"
             )),
+            compressed: false,
         },
         // No block between offset 10 and 20: this blocksize area contains zero-filled data
         BlockAccess {
@@ -51,6 +52,7 @@ fn non_empty_file_no_changes() {
             digest: HashDigest::from(hex!(
                 "64178bc1274c44cc96e7cbdca341f73c6d4c473ecffe4116af72a13246e36532"
             )),
+            compressed: false,
         },
     ];
 
@@ -158,6 +160,7 @@ fn non_empty_base_then_local_full_truncate() {
         digest: HashDigest::from(hex!(
             "26d623b082f145d88927a4de50c162b59b2aaa1202ae4415b18e26a67c7a43a7"
         )),
+        compressed: false,
     }];
 
     let local_size = 0;
@@ -188,6 +191,7 @@ fn non_empty_base_then_local_partial_truncate() {
         digest: HashDigest::from(hex!(
             "26d623b082f145d88927a4de50c162b59b2aaa1202ae4415b18e26a67c7a43a7"
         )),
+        compressed: false,
     }];
 
     let local_size = 2;
@@ -226,6 +230,7 @@ fn non_empty_base_then_local_partial_overwriting() {
         digest: HashDigest::from(hex!(
             "26d623b082f145d88927a4de50c162b59b2aaa1202ae4415b18e26a67c7a43a7"
         )),
+        compressed: false,
     }];
 
     let local_size = base_size;
@@ -277,6 +282,7 @@ fn non_empty_base_then_local_total_overwriting() {
         digest: HashDigest::from(hex!(
             "26d623b082f145d88927a4de50c162b59b2aaa1202ae4415b18e26a67c7a43a7"
         )),
+        compressed: false,
     }];
 
     let local_size = base_size;
@@ -318,6 +324,7 @@ fn non_empty_base_local_block_access_fields_mismatch(
         digest: HashDigest::from(hex!(
             "26d623b082f145d88927a4de50c162b59b2aaa1202ae4415b18e26a67c7a43a7"
         )),
+        compressed: false,
     }];
 
     let mut local_chunk_view_block_access = base_blocks[0].clone();
@@ -359,3 +366,79 @@ fn non_empty_base_local_block_access_fields_mismatch(
         true,
     );
 }
+
+#[parsec_test]
+fn content_defined_chunking(#[values(false, true)] local_change: bool) {
+    let base_size = 25;
+    let base_blocksize = Blocksize::try_from(10).unwrap();
+    // The second block spans over two blocksize areas
+    let base_blocks = vec![
+        BlockAccess {
+            id: BlockID::from_hex("4a621015b4974a64b2e3028c9b3c8178").unwrap(),
+            offset: 0,
+            size: NonZeroU64::new(7).unwrap(),
+            digest: HashDigest::from(hex!(
+                "26d623b082f145d88927a4de50c162b59b2aaa1202ae4415b18e26a67c7a43a7"
+            )),
+            compressed: false,
+        },
+        BlockAccess {
+            id: BlockID::from_hex("35723b4f3f8145bba7910c3c50ab965c").unwrap(),
+            offset: 7,
+            size: NonZeroU64::new(8).unwrap(),
+            digest: HashDigest::from(hex!(
+                "64178bc1274c44cc96e7cbdca341f73c6d4c473ecffe4116af72a13246e36532"
+            )),
+            compressed: false,
+        },
+    ];
+
+    let local_size = base_size;
+    let local_blocksize = base_blocksize;
+    let mut local_blocks = vec![
+        // Blocksize area 0-10
+        vec![
+            ChunkView {
+                id: base_blocks[0].id.into(),
+                start: 0,
+                stop: NonZeroU64::new(7).unwrap(),
+                raw_offset: 0,
+                raw_size: NonZeroU64::new(7).unwrap(),
+                access: Some(base_blocks[0].clone()),
+            },
+            ChunkView {
+                id: base_blocks[1].id.into(),
+                start: 7,
+                stop: NonZeroU64::new(10).unwrap(),
+                raw_offset: 7,
+                raw_size: NonZeroU64::new(8).unwrap(),
+                access: Some(base_blocks[1].clone()),
+            },
+        ],
+        // Blocksize area 10-20
+        vec![ChunkView {
+            id: base_blocks[1].id.into(),
+            start: 10,
+            stop: NonZeroU64::new(15).unwrap(),
+            raw_offset: 7,
+            raw_size: NonZeroU64::new(8).unwrap(),
+            access: Some(base_blocks[1].clone()),
+        }],
+    ];
+    if local_change {
+        // Overwrite the end of the second block
+        local_blocks[1][0] = ChunkView::new(10, NonZeroU64::new(15).unwrap());
+    }
+
+    p_assert_eq!(
+        has_file_content_changed_in_local(
+            base_size,
+            base_blocksize,
+            &base_blocks,
+            local_size,
+            local_blocksize,
+            &local_blocks,
+        ),
+        local_change,
+    );
+}
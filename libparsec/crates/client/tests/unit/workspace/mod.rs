@@ -28,6 +28,8 @@ mod read_folder;
 mod remove_entry;
 mod resolve_path;
 mod retrieve_path_from_id;
+mod selective_sync;
+mod shared_prevent_sync_pattern;
 mod stat_entry;
 mod store;
 mod utils;
@@ -10,15 +10,29 @@ use libparsec_platform_async::prelude::*;
 use libparsec_tests_fixtures::prelude::*;
 use libparsec_types::prelude::*;
 
-use super::utils::workspace_ops_factory;
+use super::utils::{workspace_ops_factory, workspace_ops_with_outbound_sync_config_factory};
 use crate::{
     workspace::{InboundSyncOutcome, OpenOptions, OutboundSyncOutcome},
     EventWorkspaceOpsInboundSyncDone, EventWorkspaceOpsOutboundSyncAborted,
     EventWorkspaceOpsOutboundSyncDone, EventWorkspaceOpsOutboundSyncProgress,
-    EventWorkspaceOpsOutboundSyncStarted, WorkspaceOps,
+    EventWorkspaceOpsOutboundSyncStarted, OutboundSyncConfig, WorkspaceOps,
 };
 
 fn assert_local_and_base_blocks_similar(manifest: &LocalFileManifest) {
+    // With content-defined chunking, a block may be split across multiple
+    // blocksize-aligned spans
+    if manifest.base.content_defined_chunking {
+        let local_blocks = LocalFileManifest::get_reshaped_blocks(&manifest.blocks).unwrap();
+        p_assert_eq!(
+            local_blocks,
+            manifest.base.blocks.iter().collect::<Vec<_>>()
+        );
+        for chunk_view in manifest.blocks.iter().flatten() {
+            p_assert_eq!(chunk_view.id, chunk_view.access.as_ref().unwrap().id.into());
+        }
+        return;
+    }
+
     p_assert_eq!(manifest.blocks.len(), manifest.base.blocks.len());
     for (block_chunks, base_block) in manifest.blocks.iter().zip(manifest.base.blocks.iter()) {
         p_assert_eq!(block_chunks.len(), 1);
@@ -123,6 +137,7 @@ async fn non_placeholder(env: &TestbedEnv) {
                     offset: 0,
                     size: (NEW_DATA.len() as u64).try_into().unwrap(),
                     digest: HashDigest::from_data(NEW_DATA),
+                    compressed: false,
                 });
                 authenticated_cmds::latest::block_create::Rep::Ok {}
             }
@@ -637,3 +652,383 @@ async fn entry_is_busy_after_file_reshaped_and_block_uploaded(
 
 // TODO: test with placeholder folder manifest
 // TODO: test sync with parent field changing and conflict
+
+#[parsec_test(testbed = "minimal_client_ready")]
+async fn block_deduplication(env: &TestbedEnv) {
+    let alice = env.local_device("alice@dev1");
+    let wksp1_id: VlobID = *env.template.get_stuff("wksp1_id");
+    let wksp1_bar_txt_id: VlobID = *env.template.get_stuff("wksp1_bar_txt_id");
+    let wksp1_foo_egg_txt_id: VlobID = *env.template.get_stuff("wksp1_foo_egg_txt_id");
+    let wksp1_ops = workspace_ops_with_outbound_sync_config_factory(
+        &env.discriminant_dir,
+        &alice,
+        wksp1_id,
+        OutboundSyncConfig {
+            block_deduplication: true,
+            ..Default::default()
+        },
+    )
+    .await;
+
+    // Write the same content in two different files
+
+    const NEW_DATA: &[u8] = b"deduplicated data";
+
+    for entry_id in [wksp1_bar_txt_id, wksp1_foo_egg_txt_id] {
+        let options = OpenOptions {
+            read: false,
+            write: true,
+            truncate: true,
+            create: false,
+            create_new: false,
+        };
+        let fd = wksp1_ops.open_file_by_id(entry_id, options).await.unwrap();
+        wksp1_ops.fd_write(fd, 0, NEW_DATA).await.unwrap();
+        wksp1_ops.fd_close(fd).await.unwrap();
+    }
+
+    // 1) Sync the first file, its block ID is derived from the content (and hence
+    // differs from the ID of the local chunk)
+
+    let uploaded_block_id = Arc::new(std::sync::Mutex::new(None));
+    test_register_sequence_of_send_hooks!(
+        &env.discriminant_dir,
+        test_send_hook_realm_get_keys_bundle!(env, alice.user_id, wksp1_id),
+        {
+            let uploaded_block_id = uploaded_block_id.clone();
+            move |req: authenticated_cmds::latest::block_create::Req| {
+                *uploaded_block_id.lock().unwrap() = Some(req.block_id);
+                authenticated_cmds::latest::block_create::Rep::Ok {}
+            }
+        },
+        move |req: authenticated_cmds::latest::vlob_update::Req| {
+            p_assert_eq!(req.vlob_id, wksp1_bar_txt_id);
+            authenticated_cmds::latest::vlob_update::Rep::Ok {}
+        },
+    );
+
+    let local_chunk_id = match wksp1_ops
+        .store
+        .get_manifest(wksp1_bar_txt_id)
+        .await
+        .unwrap()
+    {
+        ArcLocalChildManifest::File(m) => m.blocks[0][0].id,
+        ArcLocalChildManifest::Folder(m) => panic!("Expected file, got {m:?}"),
+    };
+
+    let outcome = wksp1_ops.outbound_sync(wksp1_bar_txt_id).await.unwrap();
+    p_assert_matches!(outcome, OutboundSyncOutcome::Done);
+
+    let manifest = assert_file(&wksp1_ops, wksp1_bar_txt_id, false, 2, NEW_DATA).await;
+    p_assert_eq!(manifest.base.blocks.len(), 1);
+    let block_id = manifest.base.blocks[0].id;
+    p_assert_eq!(Some(block_id), *uploaded_block_id.lock().unwrap());
+    p_assert_ne!(ChunkID::from(block_id), local_chunk_id);
+
+    // 2) Sync the second file, the block is already known so there is no upload
+    // (i.e. no `block_create` command)
+
+    test_register_sequence_of_send_hooks!(
+        &env.discriminant_dir,
+        move |req: authenticated_cmds::latest::vlob_update::Req| {
+            p_assert_eq!(req.vlob_id, wksp1_foo_egg_txt_id);
+            authenticated_cmds::latest::vlob_update::Rep::Ok {}
+        },
+    );
+
+    let outcome = wksp1_ops.outbound_sync(wksp1_foo_egg_txt_id).await.unwrap();
+    p_assert_matches!(outcome, OutboundSyncOutcome::Done);
+
+    let manifest = assert_file(&wksp1_ops, wksp1_foo_egg_txt_id, false, 2, NEW_DATA).await;
+    p_assert_eq!(manifest.base.blocks.len(), 1);
+    p_assert_eq!(manifest.base.blocks[0].id, block_id);
+
+    wksp1_ops.stop().await.unwrap();
+}
+
+#[parsec_test(testbed = "minimal_client_ready")]
+async fn block_deduplication_existing_block_is_checked(
+    #[values("valid", "poisoned")] kind: &str,
+    env: &TestbedEnv,
+) {
+    let alice = env.local_device("alice@dev1");
+    let wksp1_id: VlobID = *env.template.get_stuff("wksp1_id");
+    let wksp1_bar_txt_id: VlobID = *env.template.get_stuff("wksp1_bar_txt_id");
+    let wksp1_ops = workspace_ops_with_outbound_sync_config_factory(
+        &env.discriminant_dir,
+        &alice,
+        wksp1_id,
+        OutboundSyncConfig {
+            block_deduplication: true,
+            ..Default::default()
+        },
+    )
+    .await;
+
+    const NEW_DATA: &[u8] = b"deduplicated data";
+
+    let options = OpenOptions {
+        read: false,
+        write: true,
+        truncate: true,
+        create: false,
+        create_new: false,
+    };
+    let fd = wksp1_ops
+        .open_file_by_id(wksp1_bar_txt_id, options)
+        .await
+        .unwrap();
+    wksp1_ops.fd_write(fd, 0, NEW_DATA).await.unwrap();
+    wksp1_ops.fd_close(fd).await.unwrap();
+
+    let local_chunk_id = match wksp1_ops
+        .store
+        .get_manifest(wksp1_bar_txt_id)
+        .await
+        .unwrap()
+    {
+        ArcLocalChildManifest::File(m) => m.blocks[0][0].id,
+        ArcLocalChildManifest::Folder(m) => panic!("Expected file, got {m:?}"),
+    };
+
+    // The server already has a block with the deduplicated ID (e.g. uploaded by
+    // another member of the realm), so it must be checked before being used.
+
+    let (key_derivation, key_index) = env.get_last_realm_key(wksp1_id);
+    let last_realm_certificate_timestamp = env.get_last_realm_certificate_timestamp(wksp1_id);
+    let deduplicated_block_id = Arc::new(std::sync::Mutex::new(None));
+    let expected_block_id = match kind {
+        "valid" => {
+            test_register_sequence_of_send_hooks!(
+                &env.discriminant_dir,
+                test_send_hook_realm_get_keys_bundle!(env, alice.user_id, wksp1_id),
+                {
+                    let deduplicated_block_id = deduplicated_block_id.clone();
+                    move |req: authenticated_cmds::latest::block_create::Req| {
+                        *deduplicated_block_id.lock().unwrap() = Some(req.block_id);
+                        authenticated_cmds::latest::block_create::Rep::BlockAlreadyExists
+                    }
+                },
+                {
+                    let key_derivation = key_derivation.to_owned();
+                    move |req: authenticated_cmds::latest::block_read::Req| {
+                        let key = key_derivation.derive_secret_key_from_uuid(*req.block_id);
+                        authenticated_cmds::latest::block_read::Rep::Ok {
+                            needed_realm_certificate_timestamp: last_realm_certificate_timestamp,
+                            key_index,
+                            block: key.encrypt(NEW_DATA).into(),
+                        }
+                    }
+                },
+                move |_: authenticated_cmds::latest::vlob_update::Req| {
+                    authenticated_cmds::latest::vlob_update::Rep::Ok {}
+                },
+            );
+            None
+        }
+
+        "poisoned" => {
+            test_register_sequence_of_send_hooks!(
+                &env.discriminant_dir,
+                test_send_hook_realm_get_keys_bundle!(env, alice.user_id, wksp1_id),
+                {
+                    let deduplicated_block_id = deduplicated_block_id.clone();
+                    move |req: authenticated_cmds::latest::block_create::Req| {
+                        *deduplicated_block_id.lock().unwrap() = Some(req.block_id);
+                        authenticated_cmds::latest::block_create::Rep::BlockAlreadyExists
+                    }
+                },
+                {
+                    let key_derivation = key_derivation.to_owned();
+                    move |req: authenticated_cmds::latest::block_read::Req| {
+                        let key = key_derivation.derive_secret_key_from_uuid(*req.block_id);
+                        authenticated_cmds::latest::block_read::Rep::Ok {
+                            needed_realm_certificate_timestamp: last_realm_certificate_timestamp,
+                            key_index,
+                            block: key.encrypt(b"junk data").into(),
+                        }
+                    }
+                },
+                // Poisoned block is ignored, and the data gets uploaded under the
+                // chunk ID instead
+                {
+                    let key_derivation = key_derivation.to_owned();
+                    move |req: authenticated_cmds::latest::block_create::Req| {
+                        p_assert_eq!(ChunkID::from(*req.block_id), local_chunk_id);
+                        let key = key_derivation.derive_secret_key_from_uuid(*req.block_id);
+                        p_assert_eq!(key.decrypt(&req.block).unwrap(), NEW_DATA);
+                        authenticated_cmds::latest::block_create::Rep::Ok {}
+                    }
+                },
+                move |_: authenticated_cmds::latest::vlob_update::Req| {
+                    authenticated_cmds::latest::vlob_update::Rep::Ok {}
+                },
+            );
+            Some(BlockID::from(*local_chunk_id))
+        }
+
+        unknown => panic!("Unknown kind: {unknown}"),
+    };
+
+    let outcome = wksp1_ops.outbound_sync(wksp1_bar_txt_id).await.unwrap();
+    p_assert_matches!(outcome, OutboundSyncOutcome::Done);
+
+    let expected_block_id =
+        expected_block_id.unwrap_or_else(|| deduplicated_block_id.lock().unwrap().unwrap());
+    let manifest = assert_file(&wksp1_ops, wksp1_bar_txt_id, false, 2, NEW_DATA).await;
+    p_assert_eq!(manifest.base.blocks.len(), 1);
+    p_assert_eq!(manifest.base.blocks[0].id, expected_block_id);
+
+    wksp1_ops.stop().await.unwrap();
+}
+
+#[parsec_test(testbed = "minimal_client_ready", with_server)]
+async fn content_defined_chunking(env: &TestbedEnv) {
+    let alice = env.local_device("alice@dev1");
+    let wksp1_id: VlobID = *env.template.get_stuff("wksp1_id");
+    let wksp1_bar_txt_id: VlobID = *env.template.get_stuff("wksp1_bar_txt_id");
+    let wksp1_ops = workspace_ops_with_outbound_sync_config_factory(
+        &env.discriminant_dir,
+        &alice,
+        wksp1_id,
+        OutboundSyncConfig {
+            block_deduplication: true,
+            content_defined_chunking: true,
+            ..Default::default()
+        },
+    )
+    .await;
+
+    // Xorshift64, so that the chunks are stable (see `libparsec_types` chunking tests)
+    let mut state: u64 = 42;
+    let data: Vec<u8> = (0..4096)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state as u8
+        })
+        .collect();
+
+    let write_and_sync = async |content: &[u8], expected_version: VersionInt| {
+        let options = OpenOptions {
+            read: false,
+            write: true,
+            truncate: true,
+            create: false,
+            create_new: false,
+        };
+        let fd = wksp1_ops
+            .open_file_by_id(wksp1_bar_txt_id, options)
+            .await
+            .unwrap();
+        wksp1_ops.fd_write(fd, 0, content).await.unwrap();
+        wksp1_ops.fd_close(fd).await.unwrap();
+
+        let outcome = wksp1_ops.outbound_sync(wksp1_bar_txt_id).await.unwrap();
+        p_assert_matches!(outcome, OutboundSyncOutcome::Done);
+
+        assert_file(
+            &wksp1_ops,
+            wksp1_bar_txt_id,
+            false,
+            expected_version,
+            content,
+        )
+        .await
+    };
+
+    // 1) Blocks are cut according to the content
+
+    let manifest = write_and_sync(&data, 2).await;
+    assert!(manifest.base.content_defined_chunking);
+    let original_blocks = manifest.base.blocks.clone();
+    p_assert_eq!(
+        original_blocks
+            .iter()
+            .map(|block| block.size.get())
+            .collect::<Vec<_>>(),
+        [512, 256, 310, 390, 263, 208, 198, 402, 319, 288, 512, 271, 167]
+    );
+
+    // 2) Insert a byte at the beginning of the file, only the blocks up to the
+    // first content-defined boundary are modified
+
+    let mut shifted = vec![b'x'];
+    shifted.extend_from_slice(&data);
+    let manifest = write_and_sync(&shifted, 3).await;
+    p_assert_eq!(manifest.base.blocks.len(), original_blocks.len());
+    p_assert_ne!(manifest.base.blocks[0].id, original_blocks[0].id);
+    p_assert_ne!(manifest.base.blocks[1].id, original_blocks[1].id);
+    for (block, original_block) in manifest.base.blocks[2..]
+        .iter()
+        .zip(original_blocks[2..].iter())
+    {
+        p_assert_eq!(block.id, original_block.id);
+        p_assert_eq!(block.offset, original_block.offset + 1);
+    }
+
+    wksp1_ops.stop().await.unwrap();
+}
+
+#[parsec_test(testbed = "coolorg", with_server)]
+async fn block_compression(env: &TestbedEnv) {
+    let alice = env.local_device("alice@dev1");
+    let bob = env.local_device("bob@dev1");
+    let wksp1_id: VlobID = *env.template.get_stuff("wksp1_id");
+    let alice_ops = workspace_ops_with_outbound_sync_config_factory(
+        &env.discriminant_dir,
+        &alice,
+        wksp1_id,
+        OutboundSyncConfig {
+            block_compression: true,
+            ..Default::default()
+        },
+    )
+    .await;
+
+    // 1) Alice creates a file with compressible content and syncs it
+
+    let new_data = b"Lorem ipsum dolor sit amet, consectetur adipiscing elit. ".repeat(100);
+
+    let entry_id = alice_ops
+        .create_file("/compressed.txt".parse().unwrap())
+        .await
+        .unwrap();
+    let fd = alice_ops
+        .open_file_by_id(entry_id, OpenOptions::read_write())
+        .await
+        .unwrap();
+    alice_ops.fd_write(fd, 0, &new_data).await.unwrap();
+    alice_ops.fd_close(fd).await.unwrap();
+
+    for entry_id in [entry_id, wksp1_id] {
+        let outcome = alice_ops.outbound_sync(entry_id).await.unwrap();
+        p_assert_matches!(outcome, OutboundSyncOutcome::Done);
+    }
+
+    let manifest = assert_file(&alice_ops, entry_id, false, 1, &new_data).await;
+    p_assert_eq!(manifest.base.blocks.len(), 1);
+    assert!(manifest.base.blocks[0].compressed);
+
+    // 2) Bob fetches the file from the server, hence decompressing its block
+
+    let bob_ops = workspace_ops_factory(&env.discriminant_dir, &bob, wksp1_id).await;
+    bob_ops.refresh_realm_checkpoint().await.unwrap();
+    for entry_id in [wksp1_id, entry_id] {
+        bob_ops.inbound_sync(entry_id).await.unwrap();
+    }
+
+    let fd = bob_ops
+        .open_file("/compressed.txt".parse().unwrap(), OpenOptions::read_only())
+        .await
+        .unwrap();
+    let mut content = Vec::with_capacity(new_data.len());
+    bob_ops
+        .fd_read(fd, 0, new_data.len() as u64, &mut content)
+        .await
+        .unwrap();
+    p_assert_eq!(content, new_data);
+    bob_ops.fd_close(fd).await.unwrap();
+}
@@ -0,0 +1,203 @@
+// Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+use libparsec_tests_fixtures::prelude::*;
+use libparsec_types::prelude::*;
+
+use super::utils::{restart_workspace_ops, workspace_ops_factory};
+use crate::workspace::{MoveEntryMode, OpenOptions, WorkspaceOps};
+
+async fn read_bar_txt(ops: &WorkspaceOps) {
+    let fd = ops
+        .open_file("/bar.txt".parse().unwrap(), OpenOptions::read_only())
+        .await
+        .unwrap();
+    let mut buf = vec![];
+    ops.fd_read(fd, 0, 100, &mut buf).await.unwrap();
+    p_assert_eq!(buf, b"hello world");
+    ops.fd_close(fd).await.unwrap();
+}
+
+#[parsec_test(testbed = "minimal_client_ready")]
+async fn is_entry_remote_only(env: &TestbedEnv) {
+    let wksp1_id: VlobID = *env.template.get_stuff("wksp1_id");
+    let wksp1_bar_txt_id: VlobID = *env.template.get_stuff("wksp1_bar_txt_id");
+    let wksp1_foo_id: VlobID = *env.template.get_stuff("wksp1_foo_id");
+    let wksp1_foo_spam_id: VlobID = *env.template.get_stuff("wksp1_foo_spam_id");
+    let wksp1_foo_egg_txt_id: VlobID = *env.template.get_stuff("wksp1_foo_egg_txt_id");
+
+    let alice = env.local_device("alice@dev1");
+    let ops = workspace_ops_factory(&env.discriminant_dir, &alice, wksp1_id).await;
+
+    // By default nothing is remote only
+
+    p_assert_eq!(ops.get_remote_only_entries(), []);
+    for entry_id in [
+        wksp1_id,
+        wksp1_bar_txt_id,
+        wksp1_foo_id,
+        wksp1_foo_egg_txt_id,
+    ] {
+        assert!(!ops.is_entry_remote_only_by_id(entry_id).await.unwrap());
+    }
+
+    // Marking a folder as remote only also concerns all its children
+
+    ops.set_remote_only_entries(&[wksp1_foo_id]).await.unwrap();
+
+    p_assert_eq!(ops.get_remote_only_entries(), [wksp1_foo_id]);
+    assert!(!ops.is_entry_remote_only_by_id(wksp1_id).await.unwrap());
+    assert!(!ops
+        .is_entry_remote_only_by_id(wksp1_bar_txt_id)
+        .await
+        .unwrap());
+    assert!(ops.is_entry_remote_only_by_id(wksp1_foo_id).await.unwrap());
+    assert!(ops
+        .is_entry_remote_only_by_id(wksp1_foo_spam_id)
+        .await
+        .unwrap());
+    assert!(ops
+        .is_entry_remote_only_by_id(wksp1_foo_egg_txt_id)
+        .await
+        .unwrap());
+
+    // The configuration is kept across restarts
+
+    let ops = restart_workspace_ops(ops).await;
+
+    p_assert_eq!(ops.get_remote_only_entries(), [wksp1_foo_id]);
+    assert!(ops
+        .is_entry_remote_only_by_id(wksp1_foo_egg_txt_id)
+        .await
+        .unwrap());
+
+    // Resetting the configuration
+
+    ops.set_remote_only_entries(&[]).await.unwrap();
+
+    p_assert_eq!(ops.get_remote_only_entries(), []);
+    assert!(!ops
+        .is_entry_remote_only_by_id(wksp1_foo_egg_txt_id)
+        .await
+        .unwrap());
+
+    ops.stop().await.unwrap();
+}
+
+#[parsec_test(testbed = "minimal_client_ready")]
+async fn moved_out_of_remote_only_subtree(env: &TestbedEnv) {
+    let wksp1_id: VlobID = *env.template.get_stuff("wksp1_id");
+    let wksp1_foo_id: VlobID = *env.template.get_stuff("wksp1_foo_id");
+    let wksp1_foo_egg_txt_id: VlobID = *env.template.get_stuff("wksp1_foo_egg_txt_id");
+
+    let alice = env.local_device("alice@dev1");
+    let ops = workspace_ops_factory(&env.discriminant_dir, &alice, wksp1_id).await;
+
+    ops.set_remote_only_entries(&[wksp1_foo_id]).await.unwrap();
+    assert!(ops
+        .is_entry_remote_only_by_id(wksp1_foo_egg_txt_id)
+        .await
+        .unwrap());
+
+    // The outcome is cached, but moving the entry must invalidate it
+
+    ops.move_entry(
+        "/foo/egg.txt".parse().unwrap(),
+        "/egg.txt".parse().unwrap(),
+        MoveEntryMode::NoReplace,
+    )
+    .await
+    .unwrap();
+    assert!(!ops
+        .is_entry_remote_only_by_id(wksp1_foo_egg_txt_id)
+        .await
+        .unwrap());
+
+    ops.move_entry(
+        "/egg.txt".parse().unwrap(),
+        "/foo/egg.txt".parse().unwrap(),
+        MoveEntryMode::NoReplace,
+    )
+    .await
+    .unwrap();
+    assert!(ops
+        .is_entry_remote_only_by_id(wksp1_foo_egg_txt_id)
+        .await
+        .unwrap());
+
+    ops.stop().await.unwrap();
+}
+
+#[parsec_test(testbed = "minimal_client_ready", with_server)]
+async fn remote_only_blocks_not_stored_locally(env: &TestbedEnv) {
+    let wksp1_id: VlobID = *env.template.get_stuff("wksp1_id");
+    let wksp1_bar_txt_id: VlobID = *env.template.get_stuff("wksp1_bar_txt_id");
+
+    env.customize(|builder| {
+        builder.filter_client_storage_events(|event| {
+            !matches!(event, TestbedEvent::WorkspaceCacheStorageFetchBlock(_))
+        });
+    })
+    .await;
+
+    let alice = env.local_device("alice@dev1");
+    let ops = workspace_ops_factory(&env.discriminant_dir, &alice, wksp1_id).await;
+
+    assert!(!ops
+        .is_file_content_local("/bar.txt".parse().unwrap())
+        .await
+        .unwrap());
+
+    // 1) File is remote only: its data is fetched from the server but not stored
+
+    ops.set_remote_only_entries(&[wksp1_bar_txt_id])
+        .await
+        .unwrap();
+    read_bar_txt(&ops).await;
+
+    // Restart to get rid of the in-memory cache
+    let ops = restart_workspace_ops(ops).await;
+
+    assert!(!ops
+        .is_file_content_local("/bar.txt".parse().unwrap())
+        .await
+        .unwrap());
+
+    // 2) File is no longer remote only: its data is stored once fetched
+
+    ops.set_remote_only_entries(&[]).await.unwrap();
+    read_bar_txt(&ops).await;
+
+    let ops = restart_workspace_ops(ops).await;
+
+    assert!(ops
+        .is_file_content_local("/bar.txt".parse().unwrap())
+        .await
+        .unwrap());
+
+    ops.stop().await.unwrap();
+}
+
+#[parsec_test(testbed = "minimal_client_ready")]
+async fn blocks_purged_when_marked_remote_only(env: &TestbedEnv) {
+    let wksp1_id: VlobID = *env.template.get_stuff("wksp1_id");
+
+    let alice = env.local_device("alice@dev1");
+    let ops = workspace_ops_factory(&env.discriminant_dir, &alice, wksp1_id).await;
+
+    assert!(ops
+        .is_file_content_local("/bar.txt".parse().unwrap())
+        .await
+        .unwrap());
+
+    // The whole workspace becomes remote only, hence the file's blocks that were
+    // already in the local storage are removed
+
+    ops.set_remote_only_entries(&[wksp1_id]).await.unwrap();
+
+    assert!(!ops
+        .is_file_content_local("/bar.txt".parse().unwrap())
+        .await
+        .unwrap());
+
+    ops.stop().await.unwrap();
+}
@@ -0,0 +1,177 @@
+// Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+use libparsec_tests_fixtures::prelude::*;
+use libparsec_types::prelude::*;
+
+use super::utils::{restart_workspace_ops, workspace_ops_factory};
+use crate::workspace::{
+    store::parse_shared_prevent_sync_pattern, OpenOptions, OutboundSyncOutcome, WorkspaceOps,
+};
+
+fn is_confined(ops: &WorkspaceOps, entry_id: VlobID) -> bool {
+    let root_manifest = ops.store.get_root_manifest();
+    root_manifest.local_confinement_points.contains(&entry_id)
+}
+
+#[parsec_test(testbed = "minimal_client_ready", with_server)]
+async fn shared_prevent_sync_pattern(env: &TestbedEnv) {
+    let wksp1_id: VlobID = *env.template.get_stuff("wksp1_id");
+
+    let alice = env.local_device("alice@dev1");
+    let ops = workspace_ops_factory(&env.discriminant_dir, &alice, wksp1_id).await;
+
+    let secret_id = ops
+        .create_file("/data.secret".parse().unwrap())
+        .await
+        .unwrap();
+
+    let (fd, ignore_file_id) = ops
+        .open_file_and_get_id(
+            format!("/{}", SHARED_PREVENT_SYNC_PATTERN_FILENAME)
+                .parse()
+                .unwrap(),
+            OpenOptions {
+                read: false,
+                write: true,
+                truncate: false,
+                create: true,
+                create_new: true,
+            },
+        )
+        .await
+        .unwrap();
+    ops.fd_write(fd, 0, b"# Shared ignore file\n*.secret\n")
+        .await
+        .unwrap();
+    ops.fd_close(fd).await.unwrap();
+
+    // The shared pattern is only taken into account once synchronized...
+
+    assert!(!is_confined(&ops, secret_id));
+
+    let outcome = ops.outbound_sync(ignore_file_id).await.unwrap();
+    p_assert_matches!(outcome, OutboundSyncOutcome::Done);
+
+    assert!(is_confined(&ops, secret_id));
+    assert!(ops.store.get_prevent_sync_pattern().is_match("data.secret"));
+    // Local prevent sync pattern is still in use
+    assert!(ops.store.get_prevent_sync_pattern().is_match("data.tmp"));
+
+    // ...and is loaded from the local storage on restart
+
+    let ops = restart_workspace_ops(ops).await;
+
+    assert!(is_confined(&ops, secret_id));
+
+    // Removing the shared ignore file removes the shared pattern
+
+    ops.remove_file(
+        format!("/{}", SHARED_PREVENT_SYNC_PATTERN_FILENAME)
+            .parse()
+            .unwrap(),
+    )
+    .await
+    .unwrap();
+    let outcome = ops.outbound_sync(wksp1_id).await.unwrap();
+    p_assert_matches!(outcome, OutboundSyncOutcome::Done);
+
+    assert!(!is_confined(&ops, secret_id));
+    assert!(!ops.store.get_prevent_sync_pattern().is_match("data.secret"));
+
+    ops.stop().await.unwrap();
+}
+
+#[parsec_test(testbed = "minimal_client_ready", with_server)]
+async fn shared_prevent_sync_pattern_matching_itself(env: &TestbedEnv) {
+    let wksp1_id: VlobID = *env.template.get_stuff("wksp1_id");
+
+    let alice = env.local_device("alice@dev1");
+    let ops = workspace_ops_factory(&env.discriminant_dir, &alice, wksp1_id).await;
+
+    let data_id = ops.create_file("/data.txt".parse().unwrap()).await.unwrap();
+
+    let (fd, ignore_file_id) = ops
+        .open_file_and_get_id(
+            format!("/{}", SHARED_PREVENT_SYNC_PATTERN_FILENAME)
+                .parse()
+                .unwrap(),
+            OpenOptions {
+                read: false,
+                write: true,
+                truncate: false,
+                create: true,
+                create_new: true,
+            },
+        )
+        .await
+        .unwrap();
+    ops.fd_write(fd, 0, b"*\n").await.unwrap();
+    ops.fd_close(fd).await.unwrap();
+
+    let outcome = ops.outbound_sync(ignore_file_id).await.unwrap();
+    p_assert_matches!(outcome, OutboundSyncOutcome::Done);
+
+    // Everything is confined... except the shared ignore file itself
+    assert!(is_confined(&ops, data_id));
+    assert!(!is_confined(&ops, ignore_file_id));
+    assert!(ops
+        .store
+        .get_prevent_sync_pattern()
+        .is_match(SHARED_PREVENT_SYNC_PATTERN_FILENAME));
+
+    // Hence it is still synchronized and can be fixed by any member
+
+    let fd = ops
+        .open_file(
+            format!("/{}", SHARED_PREVENT_SYNC_PATTERN_FILENAME)
+                .parse()
+                .unwrap(),
+            OpenOptions {
+                read: false,
+                write: true,
+                truncate: true,
+                create: false,
+                create_new: false,
+            },
+        )
+        .await
+        .unwrap();
+    ops.fd_write(fd, 0, b"*.secret\n").await.unwrap();
+    ops.fd_close(fd).await.unwrap();
+
+    let outcome = ops.outbound_sync(ignore_file_id).await.unwrap();
+    p_assert_matches!(outcome, OutboundSyncOutcome::Done);
+
+    assert!(!is_confined(&ops, data_id));
+    assert!(!is_confined(&ops, ignore_file_id));
+
+    // Same thing once reloaded from the local storage
+
+    let ops = restart_workspace_ops(ops).await;
+
+    assert!(!is_confined(&ops, data_id));
+    assert!(!is_confined(&ops, ignore_file_id));
+
+    ops.stop().await.unwrap();
+}
+
+#[test]
+fn parse_skips_invalid_and_unsupported_lines() {
+    let pattern = parse_shared_prevent_sync_pattern(
+        b"# Comment\n*.secret\nfooo][?\n!keep.secret\n/build\ndoc/*.md\n*.tmp\n",
+    );
+
+    // Valid lines are kept even if some other lines are not...
+    assert!(pattern.is_match("data.secret"));
+    assert!(pattern.is_match("data.tmp"));
+    // ...which are ignored instead of being taken literally
+    assert!(pattern.is_match("keep.secret"));
+    assert!(!pattern.is_match("!keep.secret"));
+    p_assert_eq!(pattern.0.len(), 2);
+}
+
+#[test]
+fn parse_not_utf8() {
+    let pattern = parse_shared_prevent_sync_pattern(b"*.secret\n\xff\xfe\n");
+    p_assert_eq!(pattern, PreventSyncPattern::empty());
+}
@@ -8,7 +8,7 @@ use libparsec_types::prelude::*;
 use crate::{
     certif::CertificateOps,
     workspace::{LocalUserManifestWorkspaceEntry, WorkspaceExternalInfo, WorkspaceOps},
-    ClientConfig, EventBus, MountpointMountStrategy, WorkspaceStorageCacheSize,
+    ClientConfig, EventBus, MountpointMountStrategy, OutboundSyncConfig, WorkspaceStorageCacheSize,
 };
 
 pub(crate) async fn workspace_ops_factory(
@@ -30,6 +30,39 @@ pub(crate) async fn workspace_ops_with_prevent_sync_pattern_factory(
     device: &Arc<LocalDevice>,
     realm_id: VlobID,
     prevent_sync_pattern: PreventSyncPattern,
+) -> WorkspaceOps {
+    workspace_ops_with_config_factory(
+        discriminant_dir,
+        device,
+        realm_id,
+        prevent_sync_pattern,
+        OutboundSyncConfig::default(),
+    )
+    .await
+}
+
+pub(crate) async fn workspace_ops_with_outbound_sync_config_factory(
+    discriminant_dir: &Path,
+    device: &Arc<LocalDevice>,
+    realm_id: VlobID,
+    outbound_sync: OutboundSyncConfig,
+) -> WorkspaceOps {
+    workspace_ops_with_config_factory(
+        discriminant_dir,
+        device,
+        realm_id,
+        PreventSyncPattern::from_regex(r"\.tmp$").unwrap(),
+        outbound_sync,
+    )
+    .await
+}
+
+async fn workspace_ops_with_config_factory(
+    discriminant_dir: &Path,
+    device: &Arc<LocalDevice>,
+    realm_id: VlobID,
+    prevent_sync_pattern: PreventSyncPattern,
+    outbound_sync: OutboundSyncConfig,
 ) -> WorkspaceOps {
     let config = Arc::new(ClientConfig {
         config_dir: discriminant_dir.to_owned(),
@@ -37,6 +70,8 @@ pub(crate) async fn workspace_ops_with_prevent_sync_pattern_factory(
         mountpoint_mount_strategy: MountpointMountStrategy::Disabled,
         workspace_storage_cache_size: WorkspaceStorageCacheSize::Default,
         proxy: ProxyConfig::default(),
+        outbound_sync,
+        pki_revocation_policy: Default::default(),
         with_monitors: false,
         prevent_sync_pattern,
     });
@@ -3,8 +3,8 @@
 use std::{path::Path, sync::Arc};
 
 use crate::{
-    CertificateOps, ClientConfig, EventBus, MountpointMountStrategy, WorkspaceHistoryOps,
-    WorkspaceStorageCacheSize,
+    CertificateOps, ClientConfig, EventBus, MountpointMountStrategy, OutboundSyncConfig,
+    WorkspaceHistoryOps, WorkspaceStorageCacheSize,
 };
 use libparsec_client_connection::{
     test_register_sequence_of_send_hooks, test_send_hook_realm_get_keys_bundle,
@@ -21,6 +21,8 @@ fn config_factory(env: &TestbedEnv) -> Arc<ClientConfig> {
         mountpoint_mount_strategy: MountpointMountStrategy::Disabled,
         workspace_storage_cache_size: WorkspaceStorageCacheSize::Default,
         proxy: ProxyConfig::default(),
+        outbound_sync: OutboundSyncConfig::default(),
+        pki_revocation_policy: Default::default(),
         with_monitors: false,
         prevent_sync_pattern: PreventSyncPattern::empty(),
     })
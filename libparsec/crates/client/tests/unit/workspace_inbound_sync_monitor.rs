@@ -18,8 +18,8 @@ use crate::workspace::{
     WorkspaceSyncError,
 };
 use crate::{
-    CertificateOps, ClientConfig, EventBus, MountpointMountStrategy, WorkspaceOps,
-    WorkspaceStorageCacheSize,
+    CertificateOps, ClientConfig, EventBus, MountpointMountStrategy, OutboundSyncConfig,
+    WorkspaceOps, WorkspaceStorageCacheSize,
 };
 
 /*
@@ -189,6 +189,8 @@ async fn real_io_provides_a_starting_event(env: &TestbedEnv) {
         mountpoint_mount_strategy: MountpointMountStrategy::Disabled,
         workspace_storage_cache_size: WorkspaceStorageCacheSize::Default,
         proxy: ProxyConfig::default(),
+        outbound_sync: OutboundSyncConfig::default(),
+        pki_revocation_policy: Default::default(),
         with_monitors: false,
         prevent_sync_pattern: PreventSyncPattern::from_regex(r"\.tmp$").unwrap(),
     });
@@ -0,0 +1,105 @@
+// Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+use std::sync::Arc;
+
+use libparsec_tests_fixtures::prelude::*;
+use libparsec_types::prelude::*;
+
+use crate::{
+    workspace::{EntryStat, OpenOptions, WorkspaceOps},
+    Client, ClientConfig, EventBus, MountpointMountStrategy, OutboundSyncConfig,
+    OutboundSyncDelays, WorkspaceStorageCacheSize,
+};
+
+async fn need_sync(workspace: &WorkspaceOps, entry_id: VlobID) -> bool {
+    match workspace.stat_entry_by_id(entry_id).await.unwrap() {
+        EntryStat::File { need_sync, .. } => need_sync,
+        EntryStat::Folder { need_sync, .. } => need_sync,
+    }
+}
+
+async fn wait_for_sync(workspace: &WorkspaceOps, entry_id: VlobID) {
+    // TODO: use event instead of this ugly polling loop !
+    while need_sync(workspace, entry_id).await {
+        libparsec_platform_async::sleep(std::time::Duration::from_millis(50)).await;
+    }
+}
+
+// This test is quite heavy and might be flaky, due to hard-polling for sync status.
+// Do not hesitate to ignore it using `#[ignore]` if it happens to make the CI fail.
+#[parsec_test(testbed = "coolorg", with_server)]
+async fn quiescence_and_opened_for_write(env: &TestbedEnv) {
+    let alice = env.local_device("alice@dev1");
+
+    let quiescence = std::time::Duration::from_millis(300);
+    let max_delay = std::time::Duration::from_secs(3);
+    let config = Arc::new(ClientConfig {
+        config_dir: env.discriminant_dir.clone(),
+        data_base_dir: env.discriminant_dir.clone(),
+        mountpoint_mount_strategy: MountpointMountStrategy::Disabled,
+        workspace_storage_cache_size: WorkspaceStorageCacheSize::Default,
+        proxy: libparsec_client_connection::ProxyConfig::default(),
+        outbound_sync: OutboundSyncConfig {
+            delays: OutboundSyncDelays {
+                quiescence: Duration::milliseconds(quiescence.as_millis() as i64),
+                max_delay: Duration::milliseconds(max_delay.as_millis() as i64),
+            },
+            ..Default::default()
+        },
+        pki_revocation_policy: Default::default(),
+        with_monitors: true,
+        prevent_sync_pattern: PreventSyncPattern::empty(),
+    });
+
+    let client = Client::start(config, EventBus::default(), alice)
+        .await
+        .unwrap();
+    let wid = client
+        .create_workspace("wksp".parse().unwrap())
+        .await
+        .unwrap();
+    let workspace = client.start_workspace(wid).await.unwrap();
+
+    // 1) Two files are modified at the same time, one of them is kept opened for write
+
+    let started = std::time::Instant::now();
+    let opened_id = workspace
+        .create_file("/opened.txt".parse().unwrap())
+        .await
+        .unwrap();
+    let closed_id = workspace
+        .create_file("/closed.txt".parse().unwrap())
+        .await
+        .unwrap();
+    let options = OpenOptions {
+        read: false,
+        write: true,
+        truncate: false,
+        create: false,
+        create_new: false,
+    };
+    let fd = workspace.open_file_by_id(opened_id, options).await.unwrap();
+    workspace.fd_write(fd, 0, b"hello").await.unwrap();
+
+    // Nothing is synchronized until the quiescence delay is elapsed
+
+    assert!(need_sync(&workspace, closed_id).await);
+    assert!(need_sync(&workspace, opened_id).await);
+
+    // 2) The closed file is synchronized once the quiescence delay is elapsed...
+
+    wait_for_sync(&workspace, closed_id).await;
+    assert!(started.elapsed() >= quiescence);
+
+    // ...while the sync of the opened one is postponed...
+
+    assert!(need_sync(&workspace, opened_id).await);
+
+    // 3) ...until its maximum delay is elapsed, even if it is still opened
+
+    wait_for_sync(&workspace, opened_id).await;
+    assert!(started.elapsed() >= max_delay);
+
+    workspace.fd_close(fd).await.unwrap();
+    client.stop().await;
+}
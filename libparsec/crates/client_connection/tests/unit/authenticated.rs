@@ -230,6 +230,7 @@ async fn sse_ok_mocked(env: &TestbedEnv) {
                 user_profile_outsider_allowed: true,
                 sse_keepalive_seconds: Some(30.try_into().unwrap()),
                 realm_minimum_archiving_period_before_deletion: Maybe::Absent,
+                pki_crls: Maybe::Absent,
             }
         ))
     );
@@ -296,6 +297,7 @@ async fn sse_ok_with_server(env: &TestbedEnv) {
                 user_profile_outsider_allowed: true,
                 sse_keepalive_seconds: Some(30.try_into().unwrap()),
                 realm_minimum_archiving_period_before_deletion: Maybe::Present(2592000),
+                pki_crls: Maybe::Absent,
             }
         ))
     );
@@ -647,6 +649,7 @@ async fn sse_last_event_id_with_server(env: &TestbedEnv) {
                 user_profile_outsider_allowed: true,
                 sse_keepalive_seconds: Some(30.try_into().unwrap()),
                 realm_minimum_archiving_period_before_deletion: Maybe::Present(2592000),
+                pki_crls: Maybe::Absent,
             }
         ))
     );
@@ -702,6 +705,7 @@ async fn sse_last_event_id_with_server(env: &TestbedEnv) {
                 user_profile_outsider_allowed: true,
                 sse_keepalive_seconds: Some(30.try_into().unwrap()),
                 realm_minimum_archiving_period_before_deletion: Maybe::Present(2592000),
+                pki_crls: Maybe::Absent,
             }
         ))
     );
@@ -745,6 +749,7 @@ async fn sse_last_event_id_with_server(env: &TestbedEnv) {
                 user_profile_outsider_allowed: true,
                 sse_keepalive_seconds: Some(30.try_into().unwrap()),
                 realm_minimum_archiving_period_before_deletion: Maybe::Present(2592000),
+                pki_crls: Maybe::Absent,
             }
         ))
     );
@@ -24,6 +24,9 @@ pub const ARGON2ID_DEFAULT_MEMLIMIT_KB: u32 = 128 * 1024; // 128 Mo
 pub const ARGON2ID_DEFAULT_OPSLIMIT: u32 = 3;
 // Be careful when changing parallelism: libsodium only supports 1 thread !
 pub const ARGON2ID_DEFAULT_PARALLELISM: u32 = 1;
+// Calibration only increases the number of passes, this upper bound prevents a
+// benchmark run on an overloaded machine from producing an unusable configuration.
+pub const ARGON2ID_CALIBRATION_MAX_OPSLIMIT: u32 = 32;
 
 /// All the configuration needed to derive a key from a password !
 ///
@@ -73,6 +76,59 @@ impl PasswordAlgorithm {
         }
     }
 
+    /// Same as `generate_argon2id`, but the number of passes is increased so that
+    /// deriving a key takes roughly `target_duration` on this machine.
+    ///
+    /// The resulting configuration is never weaker than the defaults. Memory cost is
+    /// left untouched since the configuration may later be used on a less powerful
+    /// machine (e.g. device file copied on a laptop).
+    ///
+    /// ⚠️ Only use this with `TrustedPasswordAlgorithm`, an `UntrustedPasswordAlgorithm`
+    /// must stay indistinguishable from the fake configurations generated by the server.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn generate_argon2id_calibrated(
+        salt_strategy: PasswordAlgorithmSaltStrategy,
+        target_duration: std::time::Duration,
+    ) -> Self {
+        let mut algorithm = Self::generate_argon2id(salt_strategy);
+
+        let started_at = std::time::Instant::now();
+        algorithm
+            .compute_secret_key(&Password::from("calibration".to_string()))
+            .expect("Default configuration is always valid");
+        let elapsed = started_at.elapsed();
+
+        // Argon2id computation time is roughly proportional to its number of passes
+        let ratio = target_duration.as_secs_f64() / elapsed.as_secs_f64().max(f64::EPSILON);
+        let calibrated_opslimit = (f64::from(ARGON2ID_DEFAULT_OPSLIMIT) * ratio).floor();
+
+        match &mut algorithm {
+            Self::Argon2id { opslimit, .. } => {
+                *opslimit = (calibrated_opslimit as u32)
+                    .clamp(ARGON2ID_DEFAULT_OPSLIMIT, ARGON2ID_CALIBRATION_MAX_OPSLIMIT);
+            }
+        }
+
+        algorithm
+    }
+
+    /// Returns `true` if any parameter is weaker than the current defaults (typically
+    /// when the configuration has been generated by an older version of Parsec).
+    pub fn is_weaker_than_default(&self) -> bool {
+        match self {
+            Self::Argon2id {
+                memlimit_kb,
+                opslimit,
+                parallelism,
+                ..
+            } => {
+                *memlimit_kb < ARGON2ID_DEFAULT_MEMLIMIT_KB
+                    || *opslimit < ARGON2ID_DEFAULT_OPSLIMIT
+                    || *parallelism < ARGON2ID_DEFAULT_PARALLELISM
+            }
+        }
+    }
+
     pub fn compute_secret_key(&self, password: &Password) -> Result<SecretKey, CryptoError> {
         let raw: [u8; SecretKey::SIZE] = compute_from_password(self, password)?.into();
         Ok(SecretKey::from(raw))
@@ -184,21 +240,19 @@ impl UntrustedPasswordAlgorithm {
                 opslimit,
                 parallelism,
             } => {
-                if memlimit_kb < ARGON2ID_DEFAULT_MEMLIMIT_KB
-                    || opslimit < ARGON2ID_DEFAULT_OPSLIMIT
-                    || parallelism < ARGON2ID_DEFAULT_PARALLELISM
-                {
-                    return Err(CryptoError::Algorithm("Config too weak".to_string()));
-                }
-
                 let salt = argon2id_derived_salt_from_email(email);
 
-                Ok(PasswordAlgorithm::Argon2id {
+                let algorithm = PasswordAlgorithm::Argon2id {
                     memlimit_kb,
                     opslimit,
                     parallelism,
                     salt,
-                })
+                };
+                if algorithm.is_weaker_than_default() {
+                    return Err(CryptoError::Algorithm("Config too weak".to_string()));
+                }
+
+                Ok(algorithm)
             }
         }
     }
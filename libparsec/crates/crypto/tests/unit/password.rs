@@ -65,6 +65,55 @@ fn argon2id_salt_from_email() {
     );
 }
 
+#[rstest]
+#[case::default(131072, 3, 1, false)]
+#[case::stronger(262144, 10, 1, false)]
+#[case::weak_opslimit(131072, 2, 1, true)]
+#[case::weak_memlimit(65536, 3, 1, true)]
+#[case::weak_parallelism(131072, 3, 0, true)]
+#[cfg_attr(target_arch = "wasm32", platform::test)] // Must be kept last!
+fn argon2id_is_weaker_than_default(
+    #[case] memlimit_kb: u32,
+    #[case] opslimit: u32,
+    #[case] parallelism: u32,
+    #[case] expected: bool,
+) {
+    let algo = PasswordAlgorithm::Argon2id {
+        memlimit_kb,
+        opslimit,
+        parallelism,
+        salt: hex!("58403066db4ea4b72f2527ea4e5977b2"),
+    };
+    p_assert_eq!(algo.is_weaker_than_default(), expected);
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[rstest]
+#[case::never_below_default(std::time::Duration::ZERO, 3)]
+#[case::capped(std::time::Duration::from_secs(3600), 32)]
+fn argon2id_calibrated(
+    #[case] target_duration: std::time::Duration,
+    #[case] expected_opslimit: u32,
+) {
+    let algo = PasswordAlgorithm::generate_argon2id_calibrated(
+        PasswordAlgorithmSaltStrategy::Random,
+        target_duration,
+    );
+    p_assert_eq!(algo.is_weaker_than_default(), false);
+    match algo {
+        PasswordAlgorithm::Argon2id {
+            memlimit_kb,
+            opslimit,
+            parallelism,
+            ..
+        } => {
+            p_assert_eq!(memlimit_kb, 131072);
+            p_assert_eq!(opslimit, expected_opslimit);
+            p_assert_eq!(parallelism, 1);
+        }
+    }
+}
+
 macro_rules! compute_test {
     ($name:ident, $ty:ident) => {
         mod $name {
@@ -0,0 +1,374 @@
+// Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+use reqwest::{StatusCode, Url};
+
+use libparsec_platform_async::{pretend_future_is_send_on_web, PinBoxFutureResult};
+use libparsec_types::prelude::*;
+
+/// Credentials to log in to OpenBao with one of its auth methods.
+#[derive(Clone)]
+pub enum OpenBaoAuthMethod {
+    /// See https://openbao.org/api-docs/auth/approle/#login-with-approle
+    AppRole {
+        mount_path: String,
+        role_id: String,
+        secret_id: String,
+    },
+    /// Exchange a JWT (typically an OIDC ID token obtained by the client from
+    /// its identity provider) for an OpenBao token.
+    ///
+    /// See https://openbao.org/api-docs/auth/jwt/#jwt-login
+    Jwt {
+        mount_path: String,
+        role: String,
+        jwt: String,
+    },
+    /// See https://openbao.org/api-docs/auth/userpass/#login
+    Userpass {
+        mount_path: String,
+        username: String,
+        password: String,
+    },
+}
+
+// Custom Debug implementation to avoid leaking secrets in logs
+impl std::fmt::Debug for OpenBaoAuthMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::AppRole {
+                mount_path,
+                role_id,
+                ..
+            } => f
+                .debug_struct("AppRole")
+                .field("mount_path", mount_path)
+                .field("role_id", role_id)
+                .finish_non_exhaustive(),
+            Self::Jwt {
+                mount_path, role, ..
+            } => f
+                .debug_struct("Jwt")
+                .field("mount_path", mount_path)
+                .field("role", role)
+                .finish_non_exhaustive(),
+            Self::Userpass {
+                mount_path,
+                username,
+                ..
+            } => f
+                .debug_struct("Userpass")
+                .field("mount_path", mount_path)
+                .field("username", username)
+                .finish_non_exhaustive(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct OpenBaoAuthToken {
+    pub token: String,
+    pub entity_id: String,
+    pub renewable: bool,
+    /// Time-to-live granted by the server on login or on last renewal.
+    pub lease_duration: std::time::Duration,
+    /// `None` if the token never expires (i.e. its lease duration is zero).
+    pub expires_on: Option<DateTime>,
+}
+
+impl std::fmt::Debug for OpenBaoAuthToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OpenBaoAuthToken")
+            .field("entity_id", &self.entity_id)
+            .field("renewable", &self.renewable)
+            .field("lease_duration", &self.lease_duration)
+            .field("expires_on", &self.expires_on)
+            .finish_non_exhaustive()
+    }
+}
+
+impl OpenBaoAuthToken {
+    /// The token gets renewed once two thirds of its lease duration have elapsed,
+    /// this leaves plenty of time to re-authenticate if renewal is not possible.
+    pub fn needs_renewal(&self, now: DateTime) -> bool {
+        match self.expires_on {
+            None => false,
+            Some(expires_on) => {
+                let margin = self.lease_duration.as_micros() as i64 / 3;
+                now >= expires_on.add_us(-margin)
+            }
+        }
+    }
+
+    pub fn is_expired(&self, now: DateTime) -> bool {
+        self.expires_on.is_some_and(|expires_on| now >= expires_on)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum OpenBaoLoginError {
+    #[error("Invalid OpenBao server URL: {0}")]
+    BadURL(anyhow::Error),
+    #[error("No response from the OpenBao server: {0}")]
+    NoServerResponse(reqwest::Error),
+    #[error("The OpenBao server rejected the provided credentials")]
+    BadCredentials,
+    #[error("The OpenBao server returned an unexpected response: {0}")]
+    BadServerResponse(anyhow::Error),
+}
+
+pub type OpenBaoRenewError = OpenBaoLoginError;
+
+/// Called to obtain a new auth token once the current one is about to expire
+/// and cannot be renewed (e.g. the token is not renewable or has reached its
+/// max TTL).
+pub trait OpenBaoReauthenticate: std::fmt::Debug + Send + Sync {
+    fn reauthenticate(&self) -> PinBoxFutureResult<OpenBaoAuthToken, OpenBaoLoginError>;
+}
+
+/// Re-authentication by simply logging in again with the same credentials.
+#[derive(Debug)]
+pub(crate) struct OpenBaoLogin {
+    pub client: reqwest::Client,
+    pub openbao_server_url: String,
+    pub method: OpenBaoAuthMethod,
+}
+
+impl OpenBaoReauthenticate for OpenBaoLogin {
+    fn reauthenticate(&self) -> PinBoxFutureResult<OpenBaoAuthToken, OpenBaoLoginError> {
+        let client = self.client.clone();
+        let openbao_server_url = self.openbao_server_url.clone();
+        let method = self.method.clone();
+
+        Box::pin(pretend_future_is_send_on_web(async move {
+            login(&client, &openbao_server_url, &method).await
+        }))
+    }
+}
+
+fn build_url(openbao_server_url: &str, segments: &[&str]) -> Result<Url, OpenBaoLoginError> {
+    let mut url = openbao_server_url
+        .parse::<Url>()
+        .map_err(|err| OpenBaoLoginError::BadURL(err.into()))?;
+    {
+        let mut path = url.path_segments_mut().map_err(|()| {
+            OpenBaoLoginError::BadURL(anyhow::anyhow!("URL should not be a cannot-be-a-base"))
+        })?;
+        path.pop_if_empty();
+        path.push("v1");
+        path.extend(segments);
+    }
+    Ok(url)
+}
+
+pub async fn login(
+    client: &reqwest::Client,
+    openbao_server_url: &str,
+    method: &OpenBaoAuthMethod,
+) -> Result<OpenBaoAuthToken, OpenBaoLoginError> {
+    let (url, body) = match method {
+        OpenBaoAuthMethod::AppRole {
+            mount_path,
+            role_id,
+            secret_id,
+        } => (
+            build_url(openbao_server_url, &["auth", mount_path, "login"])?,
+            serde_json::json!({
+                "role_id": role_id,
+                "secret_id": secret_id,
+            }),
+        ),
+        OpenBaoAuthMethod::Jwt {
+            mount_path,
+            role,
+            jwt,
+        } => (
+            build_url(openbao_server_url, &["auth", mount_path, "login"])?,
+            serde_json::json!({
+                "role": role,
+                "jwt": jwt,
+            }),
+        ),
+        OpenBaoAuthMethod::Userpass {
+            mount_path,
+            username,
+            password,
+        } => (
+            build_url(openbao_server_url, &["auth", mount_path, "login", username])?,
+            serde_json::json!({
+                "password": password,
+            }),
+        ),
+    };
+
+    let rep = client
+        .post(url)
+        .json(&body)
+        .send()
+        .await
+        .map_err(OpenBaoLoginError::NoServerResponse)?;
+
+    match rep.status() {
+        StatusCode::OK => parse_auth_response(rep).await,
+        // Invalid credentials are reported as 400 by AppRole/userpass, and as
+        // 400 or 403 by JWT depending on what is wrong with the token
+        StatusCode::BAD_REQUEST | StatusCode::FORBIDDEN => Err(OpenBaoLoginError::BadCredentials),
+        bad_status => Err(OpenBaoLoginError::BadServerResponse(anyhow::anyhow!(
+            "Login has failed, bad status code: {}",
+            bad_status
+        ))),
+    }
+}
+
+/// See https://openbao.org/api-docs/auth/token/#renew-a-token-self
+pub async fn renew_self(
+    client: &reqwest::Client,
+    openbao_server_url: &str,
+    auth_token: &str,
+) -> Result<OpenBaoAuthToken, OpenBaoRenewError> {
+    let url = build_url(openbao_server_url, &["auth", "token", "renew-self"])?;
+
+    let rep = client
+        .post(url)
+        .header("x-vault-token", auth_token)
+        .json(&serde_json::json!({}))
+        .send()
+        .await
+        .map_err(OpenBaoRenewError::NoServerResponse)?;
+
+    match rep.status() {
+        StatusCode::OK => parse_auth_response(rep).await,
+        // Token is no longer valid (e.g. revoked or already expired)
+        StatusCode::FORBIDDEN => Err(OpenBaoRenewError::BadCredentials),
+        bad_status => Err(OpenBaoRenewError::BadServerResponse(anyhow::anyhow!(
+            "Token renewal has failed, bad status code: {}",
+            bad_status
+        ))),
+    }
+}
+
+/// Retrieve the lease of an existing token (typically obtained by the GUI
+/// through its own login flow), so that it can be renewed before it expires.
+///
+/// See https://openbao.org/api-docs/auth/token/#lookup-a-token-self
+pub async fn lookup_self(
+    client: &reqwest::Client,
+    openbao_server_url: &str,
+    auth_token: &str,
+) -> Result<OpenBaoAuthToken, OpenBaoRenewError> {
+    let url = build_url(openbao_server_url, &["auth", "token", "lookup-self"])?;
+
+    let rep = client
+        .get(url)
+        .header("x-vault-token", auth_token)
+        .send()
+        .await
+        .map_err(OpenBaoRenewError::NoServerResponse)?;
+
+    match rep.status() {
+        StatusCode::OK => (),
+        StatusCode::FORBIDDEN => return Err(OpenBaoRenewError::BadCredentials),
+        bad_status => {
+            return Err(OpenBaoRenewError::BadServerResponse(anyhow::anyhow!(
+                "Token lookup has failed, bad status code: {}",
+                bad_status
+            )))
+        }
+    }
+
+    let now = DateTime::now();
+
+    // See https://openbao.org/api-docs/auth/token/#sample-response-2
+    let rep_json = rep.json::<serde_json::Value>().await.map_err(|err| {
+        OpenBaoRenewError::BadServerResponse(anyhow::anyhow!("Not JSON: {:?}", err))
+    })?;
+
+    let data = &rep_json["data"];
+    let missing_field = |field| {
+        OpenBaoRenewError::BadServerResponse(anyhow::anyhow!(
+            "Missing or invalid `data/{}` field",
+            field
+        ))
+    };
+    let entity_id = data["entity_id"]
+        .as_str()
+        .ok_or_else(|| missing_field("entity_id"))?
+        .to_owned();
+    let renewable = data["renewable"]
+        .as_bool()
+        .ok_or_else(|| missing_field("renewable"))?;
+    // `ttl` is what is left of the lease, while `creation_ttl` is the lease
+    // initially granted (which is what the renewal margin is based on)
+    let ttl = data["ttl"]
+        .as_u64()
+        .map(std::time::Duration::from_secs)
+        .ok_or_else(|| missing_field("ttl"))?;
+    let lease_duration = data["creation_ttl"]
+        .as_u64()
+        .map(std::time::Duration::from_secs)
+        .unwrap_or(ttl);
+
+    let expires_on = if ttl.is_zero() {
+        None
+    } else {
+        Some(now.add_us(ttl.as_micros() as i64))
+    };
+
+    Ok(OpenBaoAuthToken {
+        token: auth_token.to_owned(),
+        entity_id,
+        renewable,
+        lease_duration,
+        expires_on,
+    })
+}
+
+async fn parse_auth_response(
+    rep: reqwest::Response,
+) -> Result<OpenBaoAuthToken, OpenBaoLoginError> {
+    let now = DateTime::now();
+
+    // See https://openbao.org/api-docs/auth/approle/#sample-response-7
+    let rep_json = rep.json::<serde_json::Value>().await.map_err(|err| {
+        OpenBaoLoginError::BadServerResponse(anyhow::anyhow!("Not JSON: {:?}", err))
+    })?;
+
+    // Note this won't panic field is missing: instead a `serde_json::Value` object
+    // representing `undefined` is returned that will itself return another
+    // `undefined` if another field is looked into it.
+    let auth = &rep_json["auth"];
+    let missing_field = |field| {
+        OpenBaoLoginError::BadServerResponse(anyhow::anyhow!(
+            "Missing or invalid `auth/{}` field",
+            field
+        ))
+    };
+    let token = auth["client_token"]
+        .as_str()
+        .ok_or_else(|| missing_field("client_token"))?
+        .to_owned();
+    let entity_id = auth["entity_id"]
+        .as_str()
+        .ok_or_else(|| missing_field("entity_id"))?
+        .to_owned();
+    let renewable = auth["renewable"]
+        .as_bool()
+        .ok_or_else(|| missing_field("renewable"))?;
+    let lease_duration = auth["lease_duration"]
+        .as_u64()
+        .map(std::time::Duration::from_secs)
+        .ok_or_else(|| missing_field("lease_duration"))?;
+
+    let expires_on = if lease_duration.is_zero() {
+        None
+    } else {
+        Some(now.add_us(lease_duration.as_micros() as i64))
+    };
+
+    Ok(OpenBaoAuthToken {
+        token,
+        entity_id,
+        renewable,
+        lease_duration,
+        expires_on,
+    })
+}
@@ -32,7 +32,7 @@ pub async fn list_emails(
     let rep = cmds
         .client
         .get(url)
-        .header("x-vault-token", cmds.openbao_auth_token.clone())
+        .header("x-vault-token", cmds.auth_token().await?)
         .send()
         .await
         .map_err(OpenBaoListEntityEmailsError::NoServerResponse)?;
@@ -1,11 +1,20 @@
 // Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
 
+use std::sync::Arc;
+
+use libparsec_platform_async::lock::Mutex as AsyncMutex;
 use libparsec_types::prelude::*;
 
+mod auth;
 mod identity;
 mod opaque_key;
 mod sign;
 
+pub use auth::{
+    login, lookup_self, renew_self, OpenBaoAuthMethod, OpenBaoAuthToken, OpenBaoLoginError,
+    OpenBaoReauthenticate, OpenBaoRenewError,
+};
+
 #[derive(Debug)]
 pub struct OpenBaoCmds {
     client: reqwest::Client,
@@ -17,7 +26,28 @@ pub struct OpenBaoCmds {
     openbao_secret_mount_path: String,
     openbao_transit_mount_path: String,
     openbao_entity_id: String,
-    openbao_auth_token: String,
+    // Async mutex given the token may have to be renewed (i.e. a request to the
+    // server) before being provided, in which case concurrent operations should
+    // wait for the renewal instead of doing it themselves.
+    openbao_auth: AsyncMutex<OpenBaoAuthState>,
+    reauthenticate: Option<Arc<dyn OpenBaoReauthenticate>>,
+}
+
+#[derive(Debug)]
+struct OpenBaoAuthState {
+    token: OpenBaoAuthToken,
+    /// `false` if the token has been provided as-is (i.e. with `OpenBaoCmds::new`),
+    /// in which case its TTL is looked up before the first request.
+    lease_known: bool,
+    /// Last time the token could neither be renewed nor re-authenticated, used
+    /// to avoid hammering the server (or the re-authentication callback) on
+    /// each request while the current token is still usable.
+    last_refresh_failure: Option<DateTime>,
+}
+
+/// Minimal delay between two attempts at refreshing the token after a failure.
+fn refresh_failure_backoff() -> Duration {
+    Duration::seconds(30)
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -51,6 +81,16 @@ pub enum OpenBaoVerifyError {
     BadServerResponse(anyhow::Error),
 }
 
+impl From<OpenBaoOperationError> for OpenBaoVerifyError {
+    fn from(value: OpenBaoOperationError) -> Self {
+        match value {
+            OpenBaoOperationError::BadURL(err) => Self::BadURL(err),
+            OpenBaoOperationError::NoServerResponse(err) => Self::NoServerResponse(err),
+            OpenBaoOperationError::BadServerResponse(err) => Self::BadServerResponse(err),
+        }
+    }
+}
+
 impl OpenBaoCmds {
     pub fn new(
         client: reqwest::Client,
@@ -60,20 +100,208 @@ impl OpenBaoCmds {
         openbao_entity_id: String,
         openbao_auth_token: String,
     ) -> Self {
+        // Nothing is known about the token's TTL yet, it will be looked up
+        // on the first request
+        let openbao_auth = OpenBaoAuthToken {
+            token: openbao_auth_token,
+            entity_id: openbao_entity_id.clone(),
+            renewable: false,
+            lease_duration: std::time::Duration::ZERO,
+            expires_on: None,
+        };
         Self {
             client,
             openbao_server_url,
             openbao_secret_mount_path,
             openbao_transit_mount_path,
             openbao_entity_id,
-            openbao_auth_token,
+            openbao_auth: AsyncMutex::new(OpenBaoAuthState {
+                token: openbao_auth,
+                lease_known: false,
+                last_refresh_failure: None,
+            }),
+            reauthenticate: None,
+        }
+    }
+
+    /// Unlike with `OpenBaoCmds::new`, the token's lease is already known (e.g.
+    /// the token comes from `auth::login`) so no lookup is needed.
+    pub fn new_with_auth(
+        client: reqwest::Client,
+        openbao_server_url: String,
+        openbao_secret_mount_path: String,
+        openbao_transit_mount_path: String,
+        openbao_auth: OpenBaoAuthToken,
+    ) -> Self {
+        Self {
+            client,
+            openbao_server_url,
+            openbao_secret_mount_path,
+            openbao_transit_mount_path,
+            openbao_entity_id: openbao_auth.entity_id.clone(),
+            openbao_auth: AsyncMutex::new(OpenBaoAuthState {
+                token: openbao_auth,
+                lease_known: true,
+                last_refresh_failure: None,
+            }),
+            reauthenticate: None,
         }
     }
 
+    /// Log in with the given auth method, the same credentials are then used
+    /// to re-authenticate whenever the token cannot be renewed.
+    pub async fn login(
+        client: reqwest::Client,
+        openbao_server_url: String,
+        openbao_secret_mount_path: String,
+        openbao_transit_mount_path: String,
+        method: OpenBaoAuthMethod,
+    ) -> Result<Self, OpenBaoLoginError> {
+        let openbao_auth = auth::login(&client, &openbao_server_url, &method).await?;
+        let reauthenticate = Arc::new(auth::OpenBaoLogin {
+            client: client.clone(),
+            openbao_server_url: openbao_server_url.clone(),
+            method,
+        });
+
+        Ok(Self::new_with_auth(
+            client,
+            openbao_server_url,
+            openbao_secret_mount_path,
+            openbao_transit_mount_path,
+            openbao_auth,
+        )
+        .with_reauthentication(reauthenticate))
+    }
+
+    /// Set the callback used to obtain a new token when the current one is about
+    /// to expire and cannot be renewed.
+    pub fn with_reauthentication(mut self, reauthenticate: Arc<dyn OpenBaoReauthenticate>) -> Self {
+        self.reauthenticate = Some(reauthenticate);
+        self
+    }
+
+    /// Re-authenticate by logging in again with the given auth method.
+    pub fn with_reauthentication_method(self, method: OpenBaoAuthMethod) -> Self {
+        let reauthenticate = Arc::new(auth::OpenBaoLogin {
+            client: self.client.clone(),
+            openbao_server_url: self.openbao_server_url.clone(),
+            method,
+        });
+        self.with_reauthentication(reauthenticate)
+    }
+
     pub fn openbao_entity_id(&self) -> &str {
         &self.openbao_entity_id
     }
 
+    /// Return the current auth token, without trying to renew it.
+    pub async fn current_auth_token(&self) -> OpenBaoAuthToken {
+        self.openbao_auth.lock().await.token.clone()
+    }
+
+    /// Return the auth token to use for the next request, renewing it (or
+    /// re-authenticating) first if it is close to expiration.
+    ///
+    /// Renewal failures are not fatal as long as the current token is not
+    /// expired yet: we will just retry once `refresh_failure_backoff` has elapsed.
+    async fn auth_token(&self) -> Result<String, OpenBaoOperationError> {
+        let mut guard = self.openbao_auth.lock().await;
+        let now = DateTime::now();
+        let recently_failed = guard
+            .last_refresh_failure
+            .is_some_and(|failed_on| now < failed_on + refresh_failure_backoff());
+
+        if !guard.lease_known {
+            if recently_failed {
+                return Ok(guard.token.token.clone());
+            }
+            match auth::lookup_self(&self.client, &self.openbao_server_url, &guard.token.token)
+                .await
+            {
+                Ok(looked_up) => {
+                    guard.token = looked_up;
+                    guard.lease_known = true;
+                }
+                // Odds are the request that follows is going to fail the same way,
+                // so let it report the error
+                Err(err) => {
+                    log::warn!("Cannot lookup OpenBao auth token: {err}");
+                    guard.last_refresh_failure = Some(now);
+                    return Ok(guard.token.token.clone());
+                }
+            }
+        }
+
+        if !guard.token.needs_renewal(now) {
+            return Ok(guard.token.token.clone());
+        }
+
+        if recently_failed && !guard.token.is_expired(now) {
+            return Ok(guard.token.token.clone());
+        }
+
+        if guard.token.renewable {
+            match auth::renew_self(&self.client, &self.openbao_server_url, &guard.token.token).await
+            {
+                Ok(renewed) => {
+                    // The server grants a shorter lease than usual once the token gets
+                    // close to its max TTL, in which case we'd better re-authenticate
+                    let close_to_max_ttl = renewed.needs_renewal(now);
+                    guard.token = renewed;
+                    guard.last_refresh_failure = None;
+                    if !close_to_max_ttl || self.reauthenticate.is_none() {
+                        return Ok(guard.token.token.clone());
+                    }
+                }
+                Err(err) => {
+                    log::warn!("Cannot renew OpenBao auth token: {err}");
+                }
+            }
+        }
+
+        let outcome = match &self.reauthenticate {
+            Some(reauthenticate) => reauthenticate.reauthenticate().await,
+            None => Err(OpenBaoLoginError::BadServerResponse(anyhow::anyhow!(
+                "Token cannot be renewed and no re-authentication is available"
+            ))),
+        };
+        if outcome.is_err() {
+            guard.last_refresh_failure = Some(now);
+        }
+        match outcome {
+            Ok(new) if new.entity_id != self.openbao_entity_id => {
+                guard.last_refresh_failure = Some(now);
+                Err(OpenBaoOperationError::BadServerResponse(anyhow::anyhow!(
+                    "Re-authentication returned a different entity (expected `{}`, got `{}`)",
+                    self.openbao_entity_id,
+                    new.entity_id
+                )))
+            }
+            Ok(new) => {
+                guard.token = new;
+                guard.last_refresh_failure = None;
+                Ok(guard.token.token.clone())
+            }
+            Err(err) if !guard.token.is_expired(now) => {
+                log::warn!("Cannot re-authenticate to OpenBao: {err}");
+                Ok(guard.token.token.clone())
+            }
+            Err(err) => Err(match err {
+                OpenBaoLoginError::BadURL(err) => OpenBaoOperationError::BadURL(err),
+                OpenBaoLoginError::NoServerResponse(err) => {
+                    OpenBaoOperationError::NoServerResponse(err)
+                }
+                err @ (OpenBaoLoginError::BadCredentials
+                | OpenBaoLoginError::BadServerResponse(_)) => {
+                    OpenBaoOperationError::BadServerResponse(anyhow::anyhow!(
+                        "Auth token has expired and re-authentication failed: {err}"
+                    ))
+                }
+            }),
+        }
+    }
+
     pub async fn fetch_opaque_key(
         &self,
         openbao_ciphertext_key_path: &str,
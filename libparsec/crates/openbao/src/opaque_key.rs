@@ -34,7 +34,7 @@ pub async fn fetch_opaque_key(
     let rep = cmds
         .client
         .get(url)
-        .header("x-vault-token", cmds.openbao_auth_token.clone())
+        .header("x-vault-token", cmds.auth_token().await?)
         .send()
         .await
         .map_err(OpenBaoFetchOpaqueKeyError::NoServerResponse)?;
@@ -99,7 +99,7 @@ pub async fn upload_opaque_key(
     let rep = cmds
         .client
         .post(url)
-        .header("x-vault-token", cmds.openbao_auth_token.clone())
+        .header("x-vault-token", cmds.auth_token().await?)
         .json(&serde_json::json!({
             "options": {
                 "cas": 0,  // Prevent overwriting existing secret
@@ -64,7 +64,7 @@ async fn do_create_signing_key(cmds: &OpenBaoCmds, key_name: &str) -> Result<(),
     let rep = cmds
         .client
         .post(url)
-        .header("x-vault-token", cmds.openbao_auth_token.clone())
+        .header("x-vault-token", cmds.auth_token().await?)
         .json(&serde_json::json!({
             "type": "ed25519",
         }))
@@ -110,7 +110,7 @@ async fn do_sign(
     let rep = cmds
         .client
         .post(url)
-        .header("x-vault-token", cmds.openbao_auth_token.clone())
+        .header("x-vault-token", cmds.auth_token().await?)
         .json(&serde_json::json!({
             "input": &BASE64.encode(payload.as_ref()),
         }))
@@ -224,7 +224,7 @@ pub async fn do_verify(
     let rep = cmds
         .client
         .post(url)
-        .header("x-vault-token", cmds.openbao_auth_token.clone())
+        .header("x-vault-token", cmds.auth_token().await?)
         .json(&serde_json::json!({
             "input": &BASE64.encode(payload.as_ref()),
             "signature": signature,
@@ -0,0 +1,574 @@
+// Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+use itertools::Itertools;
+use libparsec_platform_filesystem::{
+    list_files, load_file, remove_file, save_content, ListFilesError, LoadFileError,
+    RemoveFileError, SaveContentError,
+};
+use std::path::{Component, Path, PathBuf};
+
+use libparsec_types::prelude::*;
+
+use super::load::available_device_from_device_file;
+use crate::{
+    archive_device, generate_password_algorithm, get_devices_dir, platform, ArchiveDeviceError,
+    AvailableDevice, AvailableDeviceType, DEVICE_FILE_EXT,
+};
+
+/// Extension of the temporary file used while saving a device file (see `save_content`)
+const TMP_FILE_EXT: &str = "tmp";
+
+/*
+ * Diagnose
+ */
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeviceFileIssue {
+    /// The file cannot be deserialized (typically truncated after a crash or
+    /// a full disk), hence it is ignored when listing the available devices.
+    Corrupted,
+    /// Another device file contains the same device (typically the leftover of
+    /// an authentication change or a server URL overwrite that failed to remove
+    /// the previous file).
+    ///
+    /// Only one of them is considered when listing the available devices.
+    Duplicate {
+        other_key_file: PathBuf,
+        other_server_addr: ParsecAddr,
+    },
+    /// The file storing the keyring user is missing. The device can still be
+    /// loaded, however a new keyring entry is going to be created the next time
+    /// a keyring-protected device is saved.
+    MissingKeyringUserFile { keyring_user: String },
+    /// The machine key file protecting the device is missing or has too
+    /// permissive permissions (see `is_machine_key_available`).
+    MachineKeyUnavailable { machine_key_file: PathBuf },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeviceFileDiagnosis {
+    pub key_file_path: PathBuf,
+    /// `None` if the device file is corrupted
+    pub device: Option<AvailableDevice>,
+    /// `None` if the device file is corrupted or if its protection has no
+    /// advisory equivalent (see `DeviceFile::advisory_protection`)
+    pub protection: Option<AdvisoryDeviceFileProtection>,
+    pub issues: Vec<DeviceFileIssue>,
+}
+
+impl DeviceFileDiagnosis {
+    /// Check the device file protection is among the ones advised by its server
+    /// (see the `advisory_device_file_protection` field of the server config).
+    ///
+    /// Note an empty advice means any protection is fine.
+    pub fn follows_advisory_protection(&self, advised: &[AdvisoryDeviceFileProtection]) -> bool {
+        advised.is_empty()
+            || self
+                .protection
+                .as_ref()
+                .is_some_and(|protection| advised.contains(protection))
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DeviceFilesDiagnosis {
+    pub device_files: Vec<DeviceFileDiagnosis>,
+    /// Temporary files left by interrupted device file saves.
+    pub orphaned_files: Vec<PathBuf>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum DiagnoseDeviceFilesError {
+    #[error("Device storage is not available")]
+    StorageNotAvailable,
+    #[error(transparent)]
+    Internal(anyhow::Error),
+}
+
+impl From<ListFilesError> for DiagnoseDeviceFilesError {
+    fn from(value: ListFilesError) -> Self {
+        match value {
+            ListFilesError::StorageNotAvailable => DiagnoseDeviceFilesError::StorageNotAvailable,
+            ListFilesError::InvalidParent | ListFilesError::Internal(_) => {
+                DiagnoseDeviceFilesError::Internal(value.into())
+            }
+        }
+    }
+}
+
+impl From<LoadFileError> for DiagnoseDeviceFilesError {
+    fn from(value: LoadFileError) -> Self {
+        match value {
+            LoadFileError::StorageNotAvailable => DiagnoseDeviceFilesError::StorageNotAvailable,
+            LoadFileError::NotAFile
+            | LoadFileError::InvalidParent
+            | LoadFileError::InvalidPath
+            | LoadFileError::NotFound
+            | LoadFileError::Internal(_) => DiagnoseDeviceFilesError::Internal(value.into()),
+        }
+    }
+}
+
+/// Check the integrity of the device files in the config dir, without
+/// decrypting them.
+///
+/// Note that, unlike `list_available_devices`, testbed devices are not
+/// considered here.
+pub async fn diagnose_device_files(
+    config_dir: &Path,
+) -> Result<DeviceFilesDiagnosis, DiagnoseDeviceFilesError> {
+    let devices_dir = get_devices_dir(config_dir);
+    let keyring_user = platform::load_keyring_user().await;
+
+    // Sort paths so the report order is deterministic
+    let mut key_files = list_files(&devices_dir, DEVICE_FILE_EXT).await?;
+    key_files.sort();
+
+    let mut device_files = Vec::with_capacity(key_files.len());
+    for key_file in key_files {
+        let file_content = match load_file(&key_file).await {
+            Ok(file_content) => file_content,
+            // The file has been removed in the meantime
+            Err(LoadFileError::NotFound) => continue,
+            Err(err) => return Err(err.into()),
+        };
+
+        let Ok(device_file) = DeviceFile::load(&file_content) else {
+            device_files.push(DeviceFileDiagnosis {
+                key_file_path: key_file,
+                device: None,
+                protection: None,
+                issues: vec![DeviceFileIssue::Corrupted],
+            });
+            continue;
+        };
+
+        let mut issues = vec![];
+        if let (DeviceFile::Keyring(device), None) = (&device_file, &keyring_user) {
+            issues.push(DeviceFileIssue::MissingKeyringUserFile {
+                keyring_user: device.keyring_user.clone(),
+            });
+        }
+
+        let protection = device_file.advisory_protection();
+        let device = available_device_from_device_file(key_file.clone(), device_file);
+
+        if let AvailableDeviceType::MachineKey {
+            machine_key_file,
+            machine_key_available: false,
+        } = &device.ty
+        {
+            issues.push(DeviceFileIssue::MachineKeyUnavailable {
+                machine_key_file: machine_key_file.to_owned(),
+            });
+        }
+
+        device_files.push(DeviceFileDiagnosis {
+            key_file_path: key_file,
+            device: Some(device),
+            protection,
+            issues,
+        });
+    }
+
+    let devices = device_files
+        .iter()
+        .filter_map(|diagnosis| diagnosis.device.clone())
+        .collect_vec();
+    for diagnosis in device_files.iter_mut() {
+        let Some(device) = &diagnosis.device else {
+            continue;
+        };
+        for other in devices.iter() {
+            if other.device_id == device.device_id && other.key_file_path != device.key_file_path {
+                diagnosis.issues.push(DeviceFileIssue::Duplicate {
+                    other_key_file: other.key_file_path.clone(),
+                    other_server_addr: other.server_addr.clone(),
+                });
+            }
+        }
+    }
+
+    // Only consider `<name>.keys.tmp` files, the devices directory is not
+    // supposed to contain other temporary files.
+    let orphaned_files = list_files(&devices_dir, TMP_FILE_EXT)
+        .await?
+        .into_iter()
+        .filter(|path| {
+            path.file_stem()
+                .and_then(|stem| Path::new(stem).extension())
+                .is_some_and(|ext| ext == DEVICE_FILE_EXT)
+        })
+        .collect();
+
+    Ok(DeviceFilesDiagnosis {
+        device_files,
+        orphaned_files,
+    })
+}
+
+/*
+ * Repair
+ */
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeviceFileRepair {
+    OrphanedFileRemoved {
+        path: PathBuf,
+    },
+    /// The device file is archived instead of removed, so it can still be
+    /// inspected.
+    CorruptedArchived {
+        key_file: PathBuf,
+    },
+    /// Among duplicates, the most recently protected device file is kept.
+    DuplicateArchived {
+        key_file: PathBuf,
+        kept_key_file: PathBuf,
+    },
+    KeyringUserFileRestored {
+        keyring_user: String,
+    },
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum RepairDeviceFilesError {
+    #[error("Device storage is not available")]
+    StorageNotAvailable,
+    #[error("No space available")]
+    NoSpaceAvailable,
+    #[error(transparent)]
+    Internal(anyhow::Error),
+}
+
+impl From<DiagnoseDeviceFilesError> for RepairDeviceFilesError {
+    fn from(value: DiagnoseDeviceFilesError) -> Self {
+        match value {
+            DiagnoseDeviceFilesError::StorageNotAvailable => {
+                RepairDeviceFilesError::StorageNotAvailable
+            }
+            DiagnoseDeviceFilesError::Internal(err) => RepairDeviceFilesError::Internal(err),
+        }
+    }
+}
+
+impl From<ArchiveDeviceError> for RepairDeviceFilesError {
+    fn from(value: ArchiveDeviceError) -> Self {
+        match value {
+            ArchiveDeviceError::NoSpaceAvailable => RepairDeviceFilesError::NoSpaceAvailable,
+            ArchiveDeviceError::Internal(err) => RepairDeviceFilesError::Internal(err),
+        }
+    }
+}
+
+impl From<SaveContentError> for RepairDeviceFilesError {
+    fn from(value: SaveContentError) -> Self {
+        match value {
+            SaveContentError::StorageNotAvailable => RepairDeviceFilesError::StorageNotAvailable,
+            SaveContentError::NoSpaceLeft => RepairDeviceFilesError::NoSpaceAvailable,
+            SaveContentError::NotAFile
+            | SaveContentError::InvalidParent
+            | SaveContentError::InvalidPath
+            | SaveContentError::ParentNotFound
+            | SaveContentError::CannotEdit
+            | SaveContentError::Internal(_) => RepairDeviceFilesError::Internal(value.into()),
+        }
+    }
+}
+
+/// Fix the issues reported by `diagnose_device_files` that don't require a
+/// decision from the user.
+///
+/// Note device files are only archived (never removed), and the issues that
+/// cannot be fixed (e.g. unavailable machine key) are left untouched.
+pub async fn repair_device_files(
+    config_dir: &Path,
+) -> Result<Vec<DeviceFileRepair>, RepairDeviceFilesError> {
+    let diagnosis = diagnose_device_files(config_dir).await?;
+    let mut repairs = vec![];
+
+    for path in diagnosis.orphaned_files {
+        log::debug!("Removing orphaned file {}", path.display());
+        match remove_file(&path).await {
+            Ok(()) | Err(RemoveFileError::NotFound) => (),
+            Err(RemoveFileError::StorageNotAvailable) => {
+                return Err(RepairDeviceFilesError::StorageNotAvailable)
+            }
+            Err(
+                err @ (RemoveFileError::InvalidParent
+                | RemoveFileError::InvalidPath
+                | RemoveFileError::Internal(_)),
+            ) => return Err(RepairDeviceFilesError::Internal(err.into())),
+        }
+        repairs.push(DeviceFileRepair::OrphanedFileRemoved { path });
+    }
+
+    for diagnosis in diagnosis.device_files.iter() {
+        if diagnosis.device.is_none() {
+            archive_device(config_dir, &diagnosis.key_file_path).await?;
+            repairs.push(DeviceFileRepair::CorruptedArchived {
+                key_file: diagnosis.key_file_path.clone(),
+            });
+        }
+    }
+
+    // Most recently protected first, so it is the one kept among duplicates
+    let devices = diagnosis
+        .device_files
+        .iter()
+        .filter_map(|diagnosis| diagnosis.device.as_ref())
+        .sorted_by(|a, b| {
+            a.device_id
+                .cmp(&b.device_id)
+                .then(b.protected_on.cmp(&a.protected_on))
+                .then(a.key_file_path.cmp(&b.key_file_path))
+        })
+        .collect_vec();
+    let mut kept: Option<&AvailableDevice> = None;
+    for device in devices.iter().copied() {
+        match kept {
+            Some(kept) if kept.device_id == device.device_id => {
+                archive_device(config_dir, &device.key_file_path).await?;
+                repairs.push(DeviceFileRepair::DuplicateArchived {
+                    key_file: device.key_file_path.clone(),
+                    kept_key_file: kept.key_file_path.clone(),
+                });
+            }
+            _ => kept = Some(device),
+        }
+    }
+
+    // Restore the keyring user of the most recently protected device, so
+    // that it is the one used for the next keyring-protected devices.
+    let keyring_user = diagnosis
+        .device_files
+        .iter()
+        .filter_map(|diagnosis| {
+            let device = diagnosis.device.as_ref()?;
+            diagnosis.issues.iter().find_map(|issue| match issue {
+                DeviceFileIssue::MissingKeyringUserFile { keyring_user } => {
+                    Some((device.protected_on, keyring_user))
+                }
+                _ => None,
+            })
+        })
+        .max_by_key(|(protected_on, _)| *protected_on)
+        .map(|(_, keyring_user)| keyring_user.to_owned());
+    if let Some(keyring_user) = keyring_user {
+        platform::restore_keyring_user(&keyring_user).await?;
+        repairs.push(DeviceFileRepair::KeyringUserFileRestored { keyring_user });
+    }
+
+    Ok(repairs)
+}
+
+/*
+ * Backup
+ */
+
+#[derive(Debug, thiserror::Error)]
+pub enum ExportDeviceFilesBackupError {
+    #[error("Device storage is not available")]
+    StorageNotAvailable,
+    #[error(transparent)]
+    Internal(anyhow::Error),
+}
+
+impl From<DiagnoseDeviceFilesError> for ExportDeviceFilesBackupError {
+    fn from(value: DiagnoseDeviceFilesError) -> Self {
+        match value {
+            DiagnoseDeviceFilesError::StorageNotAvailable => {
+                ExportDeviceFilesBackupError::StorageNotAvailable
+            }
+            DiagnoseDeviceFilesError::Internal(err) => ExportDeviceFilesBackupError::Internal(err),
+        }
+    }
+}
+
+/// Bundle all the device files of the config dir into a single file encrypted
+/// with `password`.
+///
+/// Device files are bundled as-is, so they are still protected by their own
+/// protection once the backup is decrypted.
+///
+/// Returns the serialized backup, along with the device files it contains.
+pub async fn export_device_files_backup(
+    config_dir: &Path,
+    password: &Password,
+) -> Result<(Vec<u8>, Vec<PathBuf>), ExportDeviceFilesBackupError> {
+    let devices_dir = get_devices_dir(config_dir);
+    let key_files = list_files(&devices_dir, DEVICE_FILE_EXT)
+        .await
+        .map_err(DiagnoseDeviceFilesError::from)?;
+
+    let mut entries = Vec::with_capacity(key_files.len());
+    for key_file in key_files.iter() {
+        let content = load_file(key_file)
+            .await
+            .map_err(DiagnoseDeviceFilesError::from)?;
+        let path = key_file
+            .strip_prefix(&devices_dir)
+            .map_err(|err| ExportDeviceFilesBackupError::Internal(err.into()))?
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy())
+            .join("/");
+        entries.push(DeviceFilesBackupEntry { path, content });
+    }
+
+    let algorithm = generate_password_algorithm();
+    let key = algorithm
+        .compute_secret_key(password)
+        .map_err(|err| ExportDeviceFilesBackupError::Internal(err.into()))?;
+
+    let backup = DeviceFilesBackup {
+        created_on: DateTime::now(),
+        ciphertext: DeviceFilesBackup::encrypt_entries(&key, &entries),
+        algorithm,
+    };
+
+    Ok((backup.dump(), key_files))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ImportedDeviceFile {
+    Restored {
+        key_file: PathBuf,
+    },
+    /// The very same device file is already present.
+    AlreadyPresent {
+        key_file: PathBuf,
+    },
+    /// Another device file is already present at this path, or already contains
+    /// this device. It is left untouched and the one from the backup is skipped.
+    Conflict {
+        key_file: PathBuf,
+        existing_key_file: PathBuf,
+    },
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ImportDeviceFilesBackupError {
+    #[error("Device storage is not available")]
+    StorageNotAvailable,
+    #[error("No space available")]
+    NoSpaceAvailable,
+    #[error("Invalid data")]
+    InvalidData,
+    #[error("Decryption failed")]
+    DecryptionFailed,
+    #[error(transparent)]
+    Internal(anyhow::Error),
+}
+
+impl From<DiagnoseDeviceFilesError> for ImportDeviceFilesBackupError {
+    fn from(value: DiagnoseDeviceFilesError) -> Self {
+        match value {
+            DiagnoseDeviceFilesError::StorageNotAvailable => {
+                ImportDeviceFilesBackupError::StorageNotAvailable
+            }
+            DiagnoseDeviceFilesError::Internal(err) => ImportDeviceFilesBackupError::Internal(err),
+        }
+    }
+}
+
+impl From<SaveContentError> for ImportDeviceFilesBackupError {
+    fn from(value: SaveContentError) -> Self {
+        match value {
+            SaveContentError::StorageNotAvailable => {
+                ImportDeviceFilesBackupError::StorageNotAvailable
+            }
+            SaveContentError::NoSpaceLeft => ImportDeviceFilesBackupError::NoSpaceAvailable,
+            SaveContentError::NotAFile
+            | SaveContentError::InvalidParent
+            | SaveContentError::InvalidPath
+            | SaveContentError::ParentNotFound
+            | SaveContentError::CannotEdit
+            | SaveContentError::Internal(_) => ImportDeviceFilesBackupError::Internal(value.into()),
+        }
+    }
+}
+
+/// Restore the device files from a backup created by `export_device_files_backup`.
+///
+/// Existing device files are never overwritten (see `ImportedDeviceFile::Conflict`).
+pub async fn import_device_files_backup(
+    config_dir: &Path,
+    backup: &[u8],
+    password: &Password,
+) -> Result<Vec<ImportedDeviceFile>, ImportDeviceFilesBackupError> {
+    let backup =
+        DeviceFilesBackup::load(backup).map_err(|_| ImportDeviceFilesBackupError::InvalidData)?;
+    let key = backup
+        .algorithm
+        .compute_secret_key(password)
+        .map_err(|_| ImportDeviceFilesBackupError::InvalidData)?;
+    let entries = backup.decrypt_entries(&key).map_err(|err| match err {
+        DataError::Decryption => ImportDeviceFilesBackupError::DecryptionFailed,
+        _ => ImportDeviceFilesBackupError::InvalidData,
+    })?;
+
+    let devices_dir = get_devices_dir(config_dir);
+    let existing_devices = diagnose_device_files(config_dir)
+        .await?
+        .device_files
+        .into_iter()
+        .filter_map(|diagnosis| diagnosis.device)
+        .collect_vec();
+
+    let mut outcomes = Vec::with_capacity(entries.len());
+    for entry in entries {
+        let key_file = backup_entry_key_file(&devices_dir, &entry.path)
+            .ok_or(ImportDeviceFilesBackupError::InvalidData)?;
+
+        match load_file(&key_file).await {
+            Ok(content) => {
+                outcomes.push(if content == entry.content {
+                    ImportedDeviceFile::AlreadyPresent { key_file }
+                } else {
+                    ImportedDeviceFile::Conflict {
+                        existing_key_file: key_file.clone(),
+                        key_file,
+                    }
+                });
+                continue;
+            }
+            Err(LoadFileError::NotFound) => (),
+            Err(err) => return Err(DiagnoseDeviceFilesError::from(err).into()),
+        }
+
+        // Note a corrupted device file is still restored, since the backup
+        // is supposed to reflect the config dir it has been created from.
+        let device_id = DeviceFile::load(&entry.content)
+            .ok()
+            .map(|device_file| available_device_from_device_file(key_file.clone(), device_file))
+            .map(|device| device.device_id);
+        if let Some(existing) = existing_devices
+            .iter()
+            .find(|existing| Some(existing.device_id) == device_id)
+        {
+            outcomes.push(ImportedDeviceFile::Conflict {
+                key_file,
+                existing_key_file: existing.key_file_path.clone(),
+            });
+            continue;
+        }
+
+        log::debug!("Restoring device file {}", key_file.display());
+        save_content(&key_file, &entry.content).await?;
+        outcomes.push(ImportedDeviceFile::Restored { key_file });
+    }
+
+    Ok(outcomes)
+}
+
+/// Backup entry paths are relative to the devices directory, any other path
+/// (e.g. absolute or escaping the devices directory) is rejected.
+fn backup_entry_key_file(devices_dir: &Path, path: &str) -> Option<PathBuf> {
+    let path = Path::new(path);
+    let is_relative = path
+        .components()
+        .all(|component| matches!(component, Component::Normal(_)));
+    let is_device_file = path.extension().is_some_and(|ext| ext == DEVICE_FILE_EXT);
+
+    (is_relative && is_device_file).then(|| devices_dir.join(path))
+}
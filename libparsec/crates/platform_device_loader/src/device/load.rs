@@ -1,6 +1,6 @@
 // Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
 
-use libparsec_platform_filesystem::{load_file, LoadFileError};
+use libparsec_platform_filesystem::{load_file, save_content, LoadFileError};
 
 use std::{
     path::{Path, PathBuf},
@@ -12,9 +12,15 @@ use libparsec_types::prelude::*;
 #[cfg(feature = "test-with-testbed")]
 use crate::testbed;
 use crate::{
-    decrypt_device_file, device::load_ciphertext_key::load_ciphertext_key, AvailableDevice,
-    AvailableDeviceType, DecryptDeviceFileError, DeviceAccessStrategy, LoadCiphertextKeyError,
-    RemoteOperationServer,
+    decrypt_device_file,
+    device::{
+        load_ciphertext_key::{load_ciphertext_key, load_ciphertext_key_from_protection},
+        save::protect_ciphertext_key,
+    },
+    encrypt_device, generate_password_algorithm, AvailableDevice, AvailableDeviceMultiProtection,
+    AvailableDeviceMultiProtectionType, AvailableDeviceType, DecryptDeviceFileError,
+    DeviceAccessStrategy, DevicePrimaryProtectionStrategy, LoadCiphertextKeyError,
+    RemoteOperationServer, SaveDeviceError,
 };
 
 #[derive(Debug, thiserror::Error)]
@@ -153,15 +159,117 @@ pub async fn load_device(
         },
     )?;
 
+    // Upgrading is only possible now that we have the password at hand, note
+    // a failure here is not fatal given the device file is still usable.
+    match maybe_upgrade_password_algorithm(access, &device_file, &ciphertext_key, &device).await {
+        Ok(true) => log::info!(
+            "Outdated password algorithm upgraded in {}",
+            access.key_file.display()
+        ),
+        Ok(false) => (),
+        Err(err) => log::warn!(
+            "Cannot upgrade outdated password algorithm in {}: {err}",
+            access.key_file.display()
+        ),
+    }
+
     Ok(Arc::new(device))
 }
 
+/// Re-protect with the current password algorithm defaults the password protection
+/// (if any) that relies on weaker parameters.
+///
+/// Returns `false` if nothing needed to be upgraded.
+async fn maybe_upgrade_password_algorithm(
+    access: &DeviceAccessStrategy,
+    device_file: &DeviceFile,
+    ciphertext_key: &SecretKey,
+    device: &LocalDevice,
+) -> Result<bool, SaveDeviceError> {
+    let new_device_file = match (device_file, &access.primary_protection) {
+        (DeviceFile::Password(file), DevicePrimaryProtectionStrategy::Password { password }) => {
+            if !file.algorithm.is_weaker_than_default() {
+                return Ok(false);
+            }
+
+            // For password device file, the ciphertext key is directly derived from the password
+            let algorithm = generate_password_algorithm();
+            let key = algorithm
+                .compute_secret_key(password)
+                .map_err(|err| SaveDeviceError::Internal(err.into()))?;
+            let totp_opaque_key = access.totp_protection.as_ref().map(|(_, key)| key);
+
+            DeviceFile::Password(DeviceFilePassword {
+                algorithm,
+                ciphertext: encrypt_device(device, &key, totp_opaque_key),
+                ..file.to_owned()
+            })
+        }
+
+        (DeviceFile::Multi(file), DevicePrimaryProtectionStrategy::Multi { alternatives }) => {
+            let mut file = file.to_owned();
+            let mut upgraded = false;
+
+            for protection in file.protections.iter_mut() {
+                if !matches!(
+                    &protection.primary,
+                    DeviceFileMultiPrimaryProtection::Password { algorithm }
+                        if algorithm.is_weaker_than_default()
+                ) {
+                    continue;
+                }
+
+                // Only the password alternative that unlocks this protection can be
+                // used to re-protect it.
+                for alternative in alternatives {
+                    if !matches!(
+                        alternative.primary_protection,
+                        DevicePrimaryProtectionStrategy::Password { .. }
+                    ) {
+                        continue;
+                    }
+                    if let Some(Ok(_)) =
+                        load_ciphertext_key_from_protection(alternative, protection).await
+                    {
+                        *protection = protect_ciphertext_key(
+                            alternative,
+                            &device.human_handle,
+                            ciphertext_key,
+                        )
+                        .await?;
+                        upgraded = true;
+                        break;
+                    }
+                }
+            }
+
+            if !upgraded {
+                return Ok(false);
+            }
+            DeviceFile::Multi(file)
+        }
+
+        _ => return Ok(false),
+    };
+
+    save_content(&access.key_file, &new_device_file.dump()).await?;
+
+    Ok(true)
+}
+
 fn load_available_device_from_blob(
     path: PathBuf,
     blob: &[u8],
 ) -> Result<AvailableDevice, libparsec_types::RmpDecodeError> {
     let device_file = DeviceFile::load(blob)?;
 
+    Ok(available_device_from_device_file(path, device_file))
+}
+
+pub(super) fn available_device_from_device_file(
+    path: PathBuf,
+    device_file: DeviceFile,
+) -> AvailableDevice {
     let (
         ty,
         created_on,
@@ -251,9 +359,80 @@ fn load_available_device_from_blob(
             device.device_label,
             device.totp_opaque_key_id,
         ),
+        DeviceFile::MachineKey(device) => (
+            AvailableDeviceType::MachineKey {
+                machine_key_available: crate::is_machine_key_available(Path::new(
+                    &device.machine_key_file,
+                )),
+                machine_key_file: device.machine_key_file.into(),
+            },
+            device.created_on,
+            device.protected_on,
+            device.server_url,
+            device.organization_id,
+            device.user_id,
+            device.device_id,
+            device.human_handle,
+            device.device_label,
+            device.totp_opaque_key_id,
+        ),
+        DeviceFile::Fido2(device) => (
+            AvailableDeviceType::Fido2,
+            device.created_on,
+            device.protected_on,
+            device.server_url,
+            device.organization_id,
+            device.user_id,
+            device.device_id,
+            device.human_handle,
+            device.device_label,
+            device.totp_opaque_key_id,
+        ),
+        DeviceFile::Multi(device) => (
+            AvailableDeviceType::Multi {
+                protections: device
+                    .protections
+                    .into_iter()
+                    .map(|protection| AvailableDeviceMultiProtection {
+                        totp_opaque_key_id: protection.totp_opaque_key_id,
+                        ty: match protection.primary {
+                            DeviceFileMultiPrimaryProtection::Password { .. } => {
+                                AvailableDeviceMultiProtectionType::Password
+                            }
+                            DeviceFileMultiPrimaryProtection::PKI {
+                                certificate_ref, ..
+                            } => AvailableDeviceMultiProtectionType::PKI { certificate_ref },
+                            DeviceFileMultiPrimaryProtection::AccountVault { .. } => {
+                                AvailableDeviceMultiProtectionType::AccountVault
+                            }
+                            DeviceFileMultiPrimaryProtection::OpenBao {
+                                openbao_entity_id,
+                                openbao_preferred_auth_id,
+                                ..
+                            } => AvailableDeviceMultiProtectionType::OpenBao {
+                                openbao_entity_id,
+                                openbao_preferred_auth_id,
+                            },
+                            DeviceFileMultiPrimaryProtection::Fido2 { .. } => {
+                                AvailableDeviceMultiProtectionType::Fido2
+                            }
+                        },
+                    })
+                    .collect(),
+            },
+            device.created_on,
+            device.protected_on,
+            device.server_url,
+            device.organization_id,
+            device.user_id,
+            device.device_id,
+            device.human_handle,
+            device.device_label,
+            device.totp_opaque_key_id,
+        ),
     };
 
-    Ok(AvailableDevice {
+    AvailableDevice {
         key_file_path: path,
         created_on,
         protected_on,
@@ -265,5 +444,5 @@ fn load_available_device_from_blob(
         device_label,
         totp_opaque_key_id,
         ty,
-    })
+    }
 }
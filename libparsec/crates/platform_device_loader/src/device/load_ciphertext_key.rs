@@ -1,9 +1,11 @@
 // Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
 
 use crate::{
-    platform, AccountVaultOperationsFetchOpaqueKeyError, DeviceAccessStrategy,
-    DevicePrimaryProtectionStrategy, OpenBaoOperationsFetchOpaqueKeyError,
-    PkiOperationsDecryptOpaqueKeyError, RemoteOperationServer,
+    platform, AccountVaultOperations, AccountVaultOperationsFetchOpaqueKeyError,
+    DeviceAccessStrategy, DeviceAlternativeProtection, DevicePrimaryProtectionStrategy,
+    Fido2DeviceOperations, Fido2OperationsGetHmacSecretError, OpenBaoDeviceOperations,
+    OpenBaoOperationsFetchOpaqueKeyError, PkiDeviceOperations, PkiOperationsDecryptOpaqueKeyError,
+    RemoteOperationServer,
 };
 use libparsec_types::prelude::*;
 
@@ -70,22 +72,12 @@ pub(super) async fn load_ciphertext_key(
                     return Err(LoadCiphertextKeyError::InvalidData);
                 }
 
-                let raw = operations
-                    .decrypt_opaque_key(device.algorithm, device.encrypted_key.as_ref())
-                    .await
-                    .map_err(|err| match err {
-                        PkiOperationsDecryptOpaqueKeyError::UnsupportedAlgorithm => {
-                            LoadCiphertextKeyError::InvalidData
-                        }
-                        PkiOperationsDecryptOpaqueKeyError::DecryptionFailed(_) => {
-                            LoadCiphertextKeyError::DecryptionFailed
-                        }
-                        PkiOperationsDecryptOpaqueKeyError::Internal(e) => {
-                            LoadCiphertextKeyError::Internal(e)
-                        }
-                    })?;
-
-                Ok(raw)
+                pki_decrypt_opaque_key(
+                    operations.as_ref(),
+                    device.algorithm,
+                    device.encrypted_key.as_ref(),
+                )
+                .await
             } else {
                 Err(LoadCiphertextKeyError::InvalidData)
             }
@@ -93,29 +85,7 @@ pub(super) async fn load_ciphertext_key(
 
         DevicePrimaryProtectionStrategy::AccountVault { operations, .. } => {
             if let DeviceFile::AccountVault(device) = device_file {
-                let ciphertext_key = operations
-                    .fetch_opaque_key(device.ciphertext_key_id)
-                    .await
-                    .map_err(|err| match err {
-                        AccountVaultOperationsFetchOpaqueKeyError::BadVaultKeyAccess(_)
-                        | AccountVaultOperationsFetchOpaqueKeyError::UnknownOpaqueKey
-                        | AccountVaultOperationsFetchOpaqueKeyError::CorruptedOpaqueKey => {
-                            LoadCiphertextKeyError::RemoteOpaqueKeyFetchFailed {
-                                server: RemoteOperationServer::ParsecAccount,
-                                error: err.into(),
-                            }
-                        }
-                        AccountVaultOperationsFetchOpaqueKeyError::Offline(_) => {
-                            LoadCiphertextKeyError::RemoteOpaqueKeyFetchOffline {
-                                server: RemoteOperationServer::ParsecAccount,
-                                error: err.into(),
-                            }
-                        }
-                        AccountVaultOperationsFetchOpaqueKeyError::Internal(err) => {
-                            LoadCiphertextKeyError::Internal(err)
-                        }
-                    })?;
-                Ok(ciphertext_key)
+                account_vault_fetch_opaque_key(operations.as_ref(), device.ciphertext_key_id).await
             } else {
                 Err(LoadCiphertextKeyError::InvalidData)
             }
@@ -123,28 +93,255 @@ pub(super) async fn load_ciphertext_key(
 
         DevicePrimaryProtectionStrategy::OpenBao { operations, .. } => {
             if let DeviceFile::OpenBao(device) = device_file {
-                let ciphertext_key = operations
-                    .fetch_opaque_key(device.openbao_ciphertext_key_path.clone())
-                    .await
-                    .map_err(|err| match err {
-                        err @ (OpenBaoOperationsFetchOpaqueKeyError::BadURL(_)
-                        | OpenBaoOperationsFetchOpaqueKeyError::BadServerResponse(_)) => {
-                            LoadCiphertextKeyError::RemoteOpaqueKeyFetchFailed {
-                                server: RemoteOperationServer::OpenBao,
-                                error: err.into(),
-                            }
-                        }
-                        OpenBaoOperationsFetchOpaqueKeyError::NoServerResponse(_) => {
-                            LoadCiphertextKeyError::RemoteOpaqueKeyFetchOffline {
-                                server: RemoteOperationServer::OpenBao,
-                                error: err.into(),
-                            }
+                openbao_fetch_opaque_key(
+                    operations.as_ref(),
+                    device.openbao_ciphertext_key_path.clone(),
+                )
+                .await
+            } else {
+                Err(LoadCiphertextKeyError::InvalidData)
+            }
+        }
+
+        DevicePrimaryProtectionStrategy::MachineKey { machine_key_file } => {
+            if let DeviceFile::MachineKey(_) = device_file {
+                platform::load_machine_key(machine_key_file).await
+            } else {
+                Err(LoadCiphertextKeyError::InvalidData)
+            }
+        }
+
+        DevicePrimaryProtectionStrategy::Fido2 { operations } => {
+            if let DeviceFile::Fido2(device) = device_file {
+                if device.fido2_relying_party_id != operations.relying_party_id() {
+                    return Err(LoadCiphertextKeyError::InvalidData);
+                }
+
+                fido2_get_hmac_secret(
+                    operations.as_ref(),
+                    &device.fido2_credential_id,
+                    &device.fido2_hmac_secret_salt,
+                )
+                .await
+            } else {
+                Err(LoadCiphertextKeyError::InvalidData)
+            }
+        }
+
+        DevicePrimaryProtectionStrategy::Multi { alternatives } => {
+            if let DeviceFile::Multi(device) = device_file {
+                // Try each alternative against each protection of the device file,
+                // the first one that succeeds wins.
+                let mut outcome = Err(LoadCiphertextKeyError::InvalidData);
+                for alternative in alternatives {
+                    for protection in device.protections.iter() {
+                        match load_ciphertext_key_from_protection(alternative, protection).await {
+                            Some(Ok(ciphertext_key)) => return Ok(ciphertext_key),
+                            Some(err @ Err(_)) => outcome = err,
+                            None => (),
                         }
-                    })?;
-                Ok(ciphertext_key)
+                    }
+                }
+                outcome
             } else {
                 Err(LoadCiphertextKeyError::InvalidData)
             }
         }
     }
 }
+
+/// Returns `None` if the alternative doesn't correspond to this protection.
+pub(super) async fn load_ciphertext_key_from_protection(
+    alternative: &DeviceAlternativeProtection,
+    protection: &DeviceFileMultiProtection,
+) -> Option<Result<SecretKey, LoadCiphertextKeyError>> {
+    let totp_opaque_key = match (&alternative.totp_protection, protection.totp_opaque_key_id) {
+        (None, None) => None,
+        (Some((totp_opaque_key_id, totp_opaque_key)), Some(expected_totp_opaque_key_id))
+            if *totp_opaque_key_id == expected_totp_opaque_key_id =>
+        {
+            Some(totp_opaque_key)
+        }
+        _ => return None,
+    };
+
+    let outcome = match &alternative.primary_protection {
+        DevicePrimaryProtectionStrategy::Password { password } => {
+            let DeviceFileMultiPrimaryProtection::Password { algorithm } = &protection.primary
+            else {
+                return None;
+            };
+            algorithm
+                .compute_secret_key(password)
+                .map_err(|_| LoadCiphertextKeyError::InvalidData)
+        }
+
+        DevicePrimaryProtectionStrategy::PKI { operations } => {
+            let DeviceFileMultiPrimaryProtection::PKI {
+                certificate_ref,
+                algorithm,
+                encrypted_key,
+            } = &protection.primary
+            else {
+                return None;
+            };
+            if certificate_ref != operations.certificate_ref() {
+                return None;
+            }
+            pki_decrypt_opaque_key(operations.as_ref(), *algorithm, encrypted_key.as_ref()).await
+        }
+
+        DevicePrimaryProtectionStrategy::AccountVault { operations } => {
+            let DeviceFileMultiPrimaryProtection::AccountVault { opaque_key_id } =
+                &protection.primary
+            else {
+                return None;
+            };
+            account_vault_fetch_opaque_key(operations.as_ref(), *opaque_key_id).await
+        }
+
+        DevicePrimaryProtectionStrategy::OpenBao { operations } => {
+            let DeviceFileMultiPrimaryProtection::OpenBao {
+                openbao_entity_id,
+                openbao_opaque_key_path,
+                ..
+            } = &protection.primary
+            else {
+                return None;
+            };
+            if openbao_entity_id != operations.openbao_entity_id() {
+                return None;
+            }
+            openbao_fetch_opaque_key(operations.as_ref(), openbao_opaque_key_path.clone()).await
+        }
+
+        DevicePrimaryProtectionStrategy::Fido2 { operations } => {
+            let DeviceFileMultiPrimaryProtection::Fido2 {
+                fido2_relying_party_id,
+                fido2_credential_id,
+                fido2_hmac_secret_salt,
+            } = &protection.primary
+            else {
+                return None;
+            };
+            if fido2_relying_party_id != operations.relying_party_id() {
+                return None;
+            }
+            fido2_get_hmac_secret(
+                operations.as_ref(),
+                fido2_credential_id,
+                fido2_hmac_secret_salt,
+            )
+            .await
+        }
+
+        // Cannot be used as alternative
+        DevicePrimaryProtectionStrategy::Keyring
+        | DevicePrimaryProtectionStrategy::MachineKey { .. }
+        | DevicePrimaryProtectionStrategy::Multi { .. } => return None,
+    };
+
+    let outcome = outcome.and_then(|key| {
+        let encrypted_ciphertext_key = match totp_opaque_key {
+            Some(totp_opaque_key) => totp_opaque_key
+                .decrypt(&protection.encrypted_ciphertext_key)
+                .map_err(|_| LoadCiphertextKeyError::DecryptionFailed)?,
+            None => protection.encrypted_ciphertext_key.to_vec(),
+        };
+        let raw = zeroize::Zeroizing::new(
+            key.decrypt(&encrypted_ciphertext_key)
+                .map_err(|_| LoadCiphertextKeyError::DecryptionFailed)?,
+        );
+        SecretKey::try_from(raw.as_slice()).map_err(|_| LoadCiphertextKeyError::InvalidData)
+    });
+
+    Some(outcome)
+}
+
+async fn pki_decrypt_opaque_key(
+    operations: &dyn PkiDeviceOperations,
+    algorithm: PKIEncryptionAlgorithm,
+    encrypted_key: &[u8],
+) -> Result<SecretKey, LoadCiphertextKeyError> {
+    operations
+        .decrypt_opaque_key(algorithm, encrypted_key)
+        .await
+        .map_err(|err| match err {
+            PkiOperationsDecryptOpaqueKeyError::UnsupportedAlgorithm => {
+                LoadCiphertextKeyError::InvalidData
+            }
+            PkiOperationsDecryptOpaqueKeyError::DecryptionFailed(_) => {
+                LoadCiphertextKeyError::DecryptionFailed
+            }
+            PkiOperationsDecryptOpaqueKeyError::Internal(e) => LoadCiphertextKeyError::Internal(e),
+        })
+}
+
+async fn fido2_get_hmac_secret(
+    operations: &dyn Fido2DeviceOperations,
+    credential_id: &[u8],
+    salt: &[u8],
+) -> Result<SecretKey, LoadCiphertextKeyError> {
+    operations
+        .get_hmac_secret(credential_id, salt)
+        .await
+        .map_err(|err| match err {
+            // Typically another security key than the one used to protect the device
+            Fido2OperationsGetHmacSecretError::UnknownCredential => {
+                LoadCiphertextKeyError::DecryptionFailed
+            }
+            Fido2OperationsGetHmacSecretError::Internal(e) => LoadCiphertextKeyError::Internal(e),
+        })
+}
+
+async fn account_vault_fetch_opaque_key(
+    operations: &dyn AccountVaultOperations,
+    opaque_key_id: AccountVaultItemOpaqueKeyID,
+) -> Result<SecretKey, LoadCiphertextKeyError> {
+    operations
+        .fetch_opaque_key(opaque_key_id)
+        .await
+        .map_err(|err| match err {
+            AccountVaultOperationsFetchOpaqueKeyError::BadVaultKeyAccess(_)
+            | AccountVaultOperationsFetchOpaqueKeyError::UnknownOpaqueKey
+            | AccountVaultOperationsFetchOpaqueKeyError::CorruptedOpaqueKey => {
+                LoadCiphertextKeyError::RemoteOpaqueKeyFetchFailed {
+                    server: RemoteOperationServer::ParsecAccount,
+                    error: err.into(),
+                }
+            }
+            AccountVaultOperationsFetchOpaqueKeyError::Offline(_) => {
+                LoadCiphertextKeyError::RemoteOpaqueKeyFetchOffline {
+                    server: RemoteOperationServer::ParsecAccount,
+                    error: err.into(),
+                }
+            }
+            AccountVaultOperationsFetchOpaqueKeyError::Internal(err) => {
+                LoadCiphertextKeyError::Internal(err)
+            }
+        })
+}
+
+async fn openbao_fetch_opaque_key(
+    operations: &dyn OpenBaoDeviceOperations,
+    openbao_opaque_key_path: String,
+) -> Result<SecretKey, LoadCiphertextKeyError> {
+    operations
+        .fetch_opaque_key(openbao_opaque_key_path)
+        .await
+        .map_err(|err| match err {
+            err @ (OpenBaoOperationsFetchOpaqueKeyError::BadURL(_)
+            | OpenBaoOperationsFetchOpaqueKeyError::BadServerResponse(_)) => {
+                LoadCiphertextKeyError::RemoteOpaqueKeyFetchFailed {
+                    server: RemoteOperationServer::OpenBao,
+                    error: err.into(),
+                }
+            }
+            OpenBaoOperationsFetchOpaqueKeyError::NoServerResponse(_) => {
+                LoadCiphertextKeyError::RemoteOpaqueKeyFetchOffline {
+                    server: RemoteOperationServer::OpenBao,
+                    error: err.into(),
+                }
+            }
+        })
+}
@@ -1,19 +1,23 @@
 // Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
 
 mod archive;
+mod doctor;
 mod list;
 mod load;
 mod load_ciphertext_key;
 mod recovery;
 mod remove;
 mod save;
+mod security;
 mod update;
 
 pub use archive::*;
+pub use doctor::*;
 pub use list::*;
 pub use load::*;
 pub(crate) use load_ciphertext_key::*;
 pub use recovery::*;
 pub use remove::*;
 pub use save::*;
+pub use security::*;
 pub use update::*;
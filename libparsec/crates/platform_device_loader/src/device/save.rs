@@ -6,9 +6,12 @@ use libparsec_platform_filesystem::{save_content, SaveContentError};
 use libparsec_types::prelude::*;
 
 use crate::{
-    encrypt_device, platform, AccountVaultOperationsUploadOpaqueKeyError, AvailableDevice,
-    DevicePrimaryProtectionStrategy, DeviceSaveStrategy, OpenBaoOperationsUploadOpaqueKeyError,
-    PkiOperationsEncryptOpaqueKeyError, RemoteOperationServer,
+    encrypt_device, generate_password_algorithm, platform, AccountVaultOperations,
+    AccountVaultOperationsUploadOpaqueKeyError, AvailableDevice, DeviceAlternativeProtection,
+    DevicePrimaryProtectionStrategy, DeviceSaveStrategy, Fido2DeviceOperations,
+    Fido2OperationsMakeCredentialError, OpenBaoDeviceOperations,
+    OpenBaoOperationsUploadOpaqueKeyError, PkiDeviceOperations, PkiOperationsEncryptOpaqueKeyError,
+    RemoteOperationServer,
 };
 
 #[derive(Debug, thiserror::Error)]
@@ -36,6 +39,8 @@ pub enum SaveDeviceError {
         server: RemoteOperationServer,
         error: anyhow::Error,
     },
+    #[error("The FIDO2 authenticator doesn't support the hmac-secret extension")]
+    Fido2HmacSecretNotSupported,
     #[error(transparent)]
     Internal(anyhow::Error),
 }
@@ -65,7 +70,6 @@ pub(crate) async fn save_device(
 ) -> Result<AvailableDevice, SaveDeviceError> {
     let protected_on = device.now();
     let server_addr: ParsecAddr = device.organization_addr.clone().into();
-    let available_device_type = strategy.primary_protection.ty();
 
     let (totp_opaque_key_id, totp_opaque_key) = strategy
         .totp_protection
@@ -88,8 +92,7 @@ pub(crate) async fn save_device(
         }
 
         DevicePrimaryProtectionStrategy::Password { password } => {
-            let key_algo =
-                PasswordAlgorithm::generate_argon2id(PasswordAlgorithmSaltStrategy::Random);
+            let key_algo = generate_password_algorithm();
             let key = key_algo
                 .compute_secret_key(password)
                 .expect("Failed to derive key from password");
@@ -119,23 +122,8 @@ pub(crate) async fn save_device(
             // Generate a random key
             let key = SecretKey::generate();
 
-            // Encrypt the key using the public key related to a certificate from the store
-            let (algorithm, encrypted_key) = operations
-                .encrypt_opaque_key(key.as_ref())
-                .await
-                .map_err(|err| match err {
-                    PkiOperationsEncryptOpaqueKeyError::Internal(e) => SaveDeviceError::Internal(e),
-                })?;
-
-            // May check if we are able to decrypt the encrypted key from the previous step
-            assert_eq!(
-                operations
-                    .decrypt_opaque_key(algorithm, encrypted_key.as_ref())
-                    .await
-                    .map_err(|e| SaveDeviceError::Internal(e.into()))?
-                    .as_ref(),
-                key.as_ref()
-            );
+            let (algorithm, encrypted_key) =
+                pki_encrypt_opaque_key(operations.as_ref(), &key).await?;
 
             // Use the generated key to encrypt the device content
             let ciphertext = encrypt_device(device, &key, totp_opaque_key);
@@ -163,24 +151,8 @@ pub(crate) async fn save_device(
         }
 
         DevicePrimaryProtectionStrategy::AccountVault { operations } => {
-            let (ciphertext_key_id, ciphertext_key) = operations
-                .upload_opaque_key()
-                .await
-                .map_err(|err| match err {
-                    AccountVaultOperationsUploadOpaqueKeyError::BadVaultKeyAccess(_)
-                    | AccountVaultOperationsUploadOpaqueKeyError::BadServerResponse(_) => {
-                        SaveDeviceError::RemoteOpaqueKeyUploadFailed {
-                            server: RemoteOperationServer::ParsecAccount,
-                            error: err.into(),
-                        }
-                    }
-                    AccountVaultOperationsUploadOpaqueKeyError::Offline(_) => {
-                        SaveDeviceError::RemoteOpaqueKeyUploadOffline {
-                            server: RemoteOperationServer::ParsecAccount,
-                            error: err.into(),
-                        }
-                    }
-                })?;
+            let (ciphertext_key_id, ciphertext_key) =
+                account_vault_upload_opaque_key(operations.as_ref()).await?;
 
             let ciphertext = encrypt_device(device, &ciphertext_key, totp_opaque_key);
 
@@ -204,24 +176,8 @@ pub(crate) async fn save_device(
         }
 
         DevicePrimaryProtectionStrategy::OpenBao { operations } => {
-            let (openbao_ciphertext_key_path, ciphertext_key) = operations
-                .upload_opaque_key()
-                .await
-                .map_err(|err| match err {
-                    OpenBaoOperationsUploadOpaqueKeyError::NoServerResponse(_) => {
-                        SaveDeviceError::RemoteOpaqueKeyUploadOffline {
-                            server: RemoteOperationServer::OpenBao,
-                            error: err.into(),
-                        }
-                    }
-                    OpenBaoOperationsUploadOpaqueKeyError::BadURL(_)
-                    | OpenBaoOperationsUploadOpaqueKeyError::BadServerResponse(_) => {
-                        SaveDeviceError::RemoteOpaqueKeyUploadFailed {
-                            server: RemoteOperationServer::OpenBao,
-                            error: err.into(),
-                        }
-                    }
-                })?;
+            let (openbao_ciphertext_key_path, ciphertext_key) =
+                openbao_upload_opaque_key(operations.as_ref()).await?;
 
             let ciphertext = encrypt_device(device, &ciphertext_key, totp_opaque_key);
 
@@ -245,8 +201,102 @@ pub(crate) async fn save_device(
 
             save_content(&key_file, &file_content).await?;
         }
+
+        DevicePrimaryProtectionStrategy::MachineKey { machine_key_file } => {
+            let key = platform::load_or_generate_machine_key(machine_key_file).await?;
+
+            let ciphertext = encrypt_device(device, &key, totp_opaque_key);
+
+            let file_content = DeviceFile::MachineKey(DeviceFileMachineKey {
+                created_on,
+                protected_on,
+                server_url: server_addr.clone(),
+                organization_id: device.organization_id().to_owned(),
+                user_id: device.user_id,
+                device_id: device.device_id,
+                human_handle: device.human_handle.to_owned(),
+                device_label: device.device_label.to_owned(),
+                machine_key_file: machine_key_file.to_string_lossy().into_owned(),
+                ciphertext,
+                totp_opaque_key_id,
+            });
+
+            let file_content = file_content.dump();
+
+            save_content(&key_file, &file_content).await?;
+        }
+
+        DevicePrimaryProtectionStrategy::Fido2 { operations } => {
+            let (fido2_credential_id, fido2_hmac_secret_salt, key) =
+                fido2_make_credential(operations.as_ref(), &device.human_handle).await?;
+
+            let ciphertext = encrypt_device(device, &key, totp_opaque_key);
+
+            let file_content = DeviceFile::Fido2(DeviceFileFido2 {
+                created_on,
+                protected_on,
+                server_url: server_addr.clone(),
+                organization_id: device.organization_id().to_owned(),
+                user_id: device.user_id,
+                device_id: device.device_id,
+                human_handle: device.human_handle.to_owned(),
+                device_label: device.device_label.to_owned(),
+                fido2_relying_party_id: operations.relying_party_id().to_owned(),
+                fido2_credential_id,
+                fido2_hmac_secret_salt,
+                ciphertext,
+                totp_opaque_key_id,
+            });
+
+            let file_content = file_content.dump();
+
+            save_content(&key_file, &file_content).await?;
+        }
+
+        DevicePrimaryProtectionStrategy::Multi { alternatives } => {
+            if alternatives.is_empty() {
+                return Err(SaveDeviceError::Internal(anyhow::anyhow!(
+                    "Multi protection requires at least one alternative"
+                )));
+            }
+
+            // The device is encrypted with a random key, that is itself stored
+            // encrypted once per alternative
+            let ciphertext_key = SecretKey::generate();
+
+            let mut protections = Vec::with_capacity(alternatives.len());
+            for alternative in alternatives {
+                protections.push(
+                    protect_ciphertext_key(alternative, &device.human_handle, &ciphertext_key)
+                        .await?,
+                );
+            }
+
+            let ciphertext = encrypt_device(device, &ciphertext_key, totp_opaque_key);
+
+            let file_content = DeviceFile::Multi(DeviceFileMulti {
+                created_on,
+                protected_on,
+                server_url: server_addr.clone(),
+                organization_id: device.organization_id().to_owned(),
+                user_id: device.user_id,
+                device_id: device.device_id,
+                human_handle: device.human_handle.to_owned(),
+                device_label: device.device_label.to_owned(),
+                protections,
+                ciphertext,
+                totp_opaque_key_id,
+            });
+
+            let file_content = file_content.dump();
+
+            save_content(&key_file, &file_content).await?;
+        }
     }
 
+    // Only retrieved now since the machine key file may have just been created
+    let available_device_type = strategy.primary_protection.ty();
+
     Ok(AvailableDevice {
         key_file_path: key_file,
         server_addr,
@@ -261,3 +311,203 @@ pub(crate) async fn save_device(
         ty: available_device_type,
     })
 }
+
+pub(super) async fn protect_ciphertext_key(
+    alternative: &DeviceAlternativeProtection,
+    human_handle: &HumanHandle,
+    ciphertext_key: &SecretKey,
+) -> Result<DeviceFileMultiProtection, SaveDeviceError> {
+    let (primary, key) = match &alternative.primary_protection {
+        DevicePrimaryProtectionStrategy::Password { password } => {
+            let algorithm = generate_password_algorithm();
+            let key = algorithm
+                .compute_secret_key(password)
+                .expect("Failed to derive key from password");
+
+            (
+                DeviceFileMultiPrimaryProtection::Password { algorithm },
+                key,
+            )
+        }
+
+        DevicePrimaryProtectionStrategy::PKI { operations } => {
+            let key = SecretKey::generate();
+            let (algorithm, encrypted_key) =
+                pki_encrypt_opaque_key(operations.as_ref(), &key).await?;
+
+            (
+                DeviceFileMultiPrimaryProtection::PKI {
+                    certificate_ref: operations.certificate_ref().to_owned(),
+                    algorithm,
+                    encrypted_key,
+                },
+                key,
+            )
+        }
+
+        DevicePrimaryProtectionStrategy::AccountVault { operations } => {
+            let (opaque_key_id, key) = account_vault_upload_opaque_key(operations.as_ref()).await?;
+
+            (
+                DeviceFileMultiPrimaryProtection::AccountVault { opaque_key_id },
+                key,
+            )
+        }
+
+        DevicePrimaryProtectionStrategy::OpenBao { operations } => {
+            let (openbao_opaque_key_path, key) =
+                openbao_upload_opaque_key(operations.as_ref()).await?;
+
+            (
+                DeviceFileMultiPrimaryProtection::OpenBao {
+                    openbao_preferred_auth_id: operations.openbao_preferred_auth_id().to_owned(),
+                    openbao_entity_id: operations.openbao_entity_id().to_owned(),
+                    openbao_opaque_key_path,
+                },
+                key,
+            )
+        }
+
+        DevicePrimaryProtectionStrategy::Fido2 { operations } => {
+            let (fido2_credential_id, fido2_hmac_secret_salt, key) =
+                fido2_make_credential(operations.as_ref(), human_handle).await?;
+
+            (
+                DeviceFileMultiPrimaryProtection::Fido2 {
+                    fido2_relying_party_id: operations.relying_party_id().to_owned(),
+                    fido2_credential_id,
+                    fido2_hmac_secret_salt,
+                },
+                key,
+            )
+        }
+
+        // Keyring stores the ciphertext key itself, so it cannot share it with
+        // other protections. Machine key is meant for headless machines where
+        // there is no point in providing alternatives.
+        DevicePrimaryProtectionStrategy::Keyring
+        | DevicePrimaryProtectionStrategy::MachineKey { .. }
+        | DevicePrimaryProtectionStrategy::Multi { .. } => {
+            return Err(SaveDeviceError::Internal(anyhow::anyhow!(
+                "{:?} protection cannot be used as a multi protection alternative",
+                alternative.primary_protection.ty()
+            )));
+        }
+    };
+
+    let encrypted_ciphertext_key = key.encrypt(ciphertext_key.as_ref());
+    let (totp_opaque_key_id, encrypted_ciphertext_key) = match &alternative.totp_protection {
+        Some((totp_opaque_key_id, totp_opaque_key)) => (
+            Some(*totp_opaque_key_id),
+            totp_opaque_key.encrypt(&encrypted_ciphertext_key),
+        ),
+        None => (None, encrypted_ciphertext_key),
+    };
+
+    Ok(DeviceFileMultiProtection {
+        primary,
+        encrypted_ciphertext_key: encrypted_ciphertext_key.into(),
+        totp_opaque_key_id,
+    })
+}
+
+async fn pki_encrypt_opaque_key(
+    operations: &dyn PkiDeviceOperations,
+    key: &SecretKey,
+) -> Result<(PKIEncryptionAlgorithm, Bytes), SaveDeviceError> {
+    // Encrypt the key using the public key related to a certificate from the store
+    let (algorithm, encrypted_key) =
+        operations
+            .encrypt_opaque_key(key.as_ref())
+            .await
+            .map_err(|err| match err {
+                PkiOperationsEncryptOpaqueKeyError::Internal(e) => SaveDeviceError::Internal(e),
+            })?;
+
+    // May check if we are able to decrypt the encrypted key from the previous step
+    assert_eq!(
+        operations
+            .decrypt_opaque_key(algorithm, encrypted_key.as_ref())
+            .await
+            .map_err(|e| SaveDeviceError::Internal(e.into()))?
+            .as_ref(),
+        key.as_ref()
+    );
+
+    Ok((algorithm, encrypted_key))
+}
+
+/// Returns `(<credential_id>, <hmac_secret_salt>, <opaque_key>)`
+async fn fido2_make_credential(
+    operations: &dyn Fido2DeviceOperations,
+    human_handle: &HumanHandle,
+) -> Result<(Bytes, Bytes, SecretKey), SaveDeviceError> {
+    let credential_id =
+        operations
+            .make_credential(human_handle)
+            .await
+            .map_err(|err| match err {
+                Fido2OperationsMakeCredentialError::HmacSecretNotSupported => {
+                    SaveDeviceError::Fido2HmacSecretNotSupported
+                }
+                Fido2OperationsMakeCredentialError::Internal(e) => SaveDeviceError::Internal(e),
+            })?;
+
+    // The salt is not secret (it is stored in the device file), the actual
+    // secret being the one held by the authenticator.
+    let salt = Bytes::copy_from_slice(SecretKey::generate().as_ref());
+
+    let key = operations
+        .get_hmac_secret(&credential_id, &salt)
+        .await
+        .map_err(|e| SaveDeviceError::Internal(e.into()))?;
+
+    Ok((credential_id, salt, key))
+}
+
+async fn account_vault_upload_opaque_key(
+    operations: &dyn AccountVaultOperations,
+) -> Result<(AccountVaultItemOpaqueKeyID, SecretKey), SaveDeviceError> {
+    operations
+        .upload_opaque_key()
+        .await
+        .map_err(|err| match err {
+            AccountVaultOperationsUploadOpaqueKeyError::BadVaultKeyAccess(_)
+            | AccountVaultOperationsUploadOpaqueKeyError::BadServerResponse(_) => {
+                SaveDeviceError::RemoteOpaqueKeyUploadFailed {
+                    server: RemoteOperationServer::ParsecAccount,
+                    error: err.into(),
+                }
+            }
+            AccountVaultOperationsUploadOpaqueKeyError::Offline(_) => {
+                SaveDeviceError::RemoteOpaqueKeyUploadOffline {
+                    server: RemoteOperationServer::ParsecAccount,
+                    error: err.into(),
+                }
+            }
+        })
+}
+
+/// Returns `(<openbao_opaque_key_path>, <opaque_key>)`
+async fn openbao_upload_opaque_key(
+    operations: &dyn OpenBaoDeviceOperations,
+) -> Result<(String, SecretKey), SaveDeviceError> {
+    operations
+        .upload_opaque_key()
+        .await
+        .map_err(|err| match err {
+            OpenBaoOperationsUploadOpaqueKeyError::NoServerResponse(_) => {
+                SaveDeviceError::RemoteOpaqueKeyUploadOffline {
+                    server: RemoteOperationServer::OpenBao,
+                    error: err.into(),
+                }
+            }
+            OpenBaoOperationsUploadOpaqueKeyError::BadURL(_)
+            | OpenBaoOperationsUploadOpaqueKeyError::BadServerResponse(_) => {
+                SaveDeviceError::RemoteOpaqueKeyUploadFailed {
+                    server: RemoteOperationServer::OpenBao,
+                    error: err.into(),
+                }
+            }
+        })
+}
@@ -0,0 +1,77 @@
+// Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+use libparsec_platform_filesystem::load_file;
+use std::path::{Path, PathBuf};
+
+use libparsec_types::prelude::*;
+
+#[cfg(feature = "test-with-testbed")]
+use crate::testbed;
+use crate::LoadAvailableDeviceError;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeviceProtectionWeakness {
+    /// The password protection uses key derivation parameters weaker than the
+    /// current defaults (typically because the device file has been created by
+    /// an older version of Parsec).
+    ///
+    /// Loading the device with its password is enough to upgrade the parameters.
+    OutdatedPasswordAlgorithm { algorithm: PasswordAlgorithm },
+}
+
+/// Inspect the protections of a device file, without decrypting it.
+///
+/// Note `config_dir` is only used as discriminant for the testbed here
+pub async fn check_device_security(
+    #[cfg_attr(not(feature = "test-with-testbed"), expect(unused_variables))] config_dir: &Path,
+    device_file: PathBuf,
+) -> Result<Vec<DeviceProtectionWeakness>, LoadAvailableDeviceError> {
+    #[cfg(feature = "test-with-testbed")]
+    if let Some(all_available_devices) = testbed::maybe_list_available_devices(config_dir) {
+        // Testbed devices are always protected with the current defaults
+        if all_available_devices
+            .iter()
+            .any(|c_access| c_access.key_file_path == device_file)
+        {
+            return Ok(vec![]);
+        }
+    }
+
+    let file_content = load_file(&device_file).await?;
+    let device_file =
+        DeviceFile::load(&file_content).map_err(|_| LoadAvailableDeviceError::InvalidData)?;
+
+    let password_algorithms = match &device_file {
+        DeviceFile::Password(device) => vec![&device.algorithm],
+        DeviceFile::Multi(device) => device
+            .protections
+            .iter()
+            .filter_map(|protection| match &protection.primary {
+                DeviceFileMultiPrimaryProtection::Password { algorithm } => Some(algorithm),
+                DeviceFileMultiPrimaryProtection::PKI { .. }
+                | DeviceFileMultiPrimaryProtection::AccountVault { .. }
+                | DeviceFileMultiPrimaryProtection::OpenBao { .. }
+                | DeviceFileMultiPrimaryProtection::Fido2 { .. } => None,
+            })
+            .collect(),
+        DeviceFile::Keyring(_)
+        | DeviceFile::Recovery(_)
+        | DeviceFile::PKI(_)
+        | DeviceFile::AccountVault(_)
+        | DeviceFile::OpenBao(_)
+        | DeviceFile::MachineKey(_)
+        | DeviceFile::Fido2(_) => vec![],
+    };
+
+    let weaknesses = password_algorithms
+        .into_iter()
+        .filter(|algorithm| algorithm.is_weaker_than_default())
+        .map(
+            |algorithm| DeviceProtectionWeakness::OutdatedPasswordAlgorithm {
+                algorithm: algorithm.to_owned(),
+            },
+        )
+        .collect();
+
+    Ok(weaknesses)
+}
@@ -43,6 +43,8 @@ pub enum UpdateDeviceError {
         server: RemoteOperationServer,
         error: anyhow::Error,
     },
+    #[error("The FIDO2 authenticator doesn't support the hmac-secret extension")]
+    Fido2HmacSecretNotSupported,
     #[error(transparent)]
     Internal(anyhow::Error),
 }
@@ -56,6 +58,9 @@ impl From<SaveDeviceError> for UpdateDeviceError {
             SaveDeviceError::RemoteOpaqueKeyUploadFailed { server, error } => {
                 UpdateDeviceError::RemoteOpaqueKeyOperationFailed { server, error }
             }
+            SaveDeviceError::Fido2HmacSecretNotSupported => {
+                UpdateDeviceError::Fido2HmacSecretNotSupported
+            }
             SaveDeviceError::Internal(error) => UpdateDeviceError::Internal(error),
             SaveDeviceError::NoSpaceAvailable => UpdateDeviceError::NoSpaceAvailable,
             SaveDeviceError::InvalidPath => UpdateDeviceError::InvalidPath,
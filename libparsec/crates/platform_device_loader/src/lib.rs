@@ -15,6 +15,10 @@ mod platform;
 #[cfg(target_arch = "wasm32")]
 #[path = "web/mod.rs"]
 mod platform;
+
+// Only CTAP2 (i.e. native) is supported for the moment, WebAuthn is not
+#[cfg(not(target_arch = "wasm32"))]
+pub use platform::NativeFido2Operations;
 // Testbed integration is tested in the `libparsec_tests_fixture` crate.
 #[cfg(feature = "test-with-testbed")]
 mod testbed;
@@ -36,6 +40,11 @@ pub(crate) const PENDING_ASYNC_ENROLLMENT_EXT: &str = "pending";
 pub const PARSEC_BASE_CONFIG_DIR: &str = "PARSEC_BASE_CONFIG_DIR";
 pub const PARSEC_BASE_DATA_DIR: &str = "PARSEC_BASE_DATA_DIR";
 pub const PARSEC_BASE_HOME_DIR: &str = "PARSEC_BASE_HOME_DIR";
+/// Target duration (in milliseconds) of the key derivation for new password-protected
+/// device files. If set, the password algorithm is calibrated by running a benchmark
+/// instead of simply using the default parameters (not available on web).
+pub const PARSEC_PASSWORD_ALGORITHM_CALIBRATION_MS: &str =
+    "PARSEC_PASSWORD_ALGORITHM_CALIBRATION_MS";
 
 pub fn get_default_data_base_dir() -> PathBuf {
     platform::get_default_data_base_dir()
@@ -115,6 +124,23 @@ pub fn is_keyring_available() -> bool {
     platform::is_keyring_available()
 }
 
+/// Return the default path of the machine key file (see
+/// `DevicePrimaryProtectionStrategy::MachineKey`).
+///
+/// Note the machine key file is not stored in the config dir, so that copying the
+/// devices folder to another machine doesn't also copy the key protecting them.
+pub fn get_default_machine_key_file() -> PathBuf {
+    get_default_data_base_dir().join("machine.key")
+}
+
+pub fn is_machine_key_available(machine_key_file: &Path) -> bool {
+    platform::is_machine_key_available(machine_key_file)
+}
+
+fn generate_password_algorithm() -> PasswordAlgorithm {
+    platform::generate_password_algorithm()
+}
+
 fn encrypt_device(
     device: &LocalDevice,
     ciphertext_key: &SecretKey,
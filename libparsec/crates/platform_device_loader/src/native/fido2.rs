@@ -0,0 +1,158 @@
+// Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+use ctap_hid_fido2::{
+    fidokey::{
+        AssertionExtension as Gext, CredentialExtension as Mext, GetAssertionArgsBuilder,
+        MakeCredentialArgsBuilder,
+    },
+    verifier, FidoKeyHidFactory, LibCfg,
+};
+use libparsec_crypto::{Password, SecretKey};
+use libparsec_platform_async::PinBoxFutureResult;
+use libparsec_types::prelude::*;
+
+use crate::{
+    Fido2DeviceOperations, Fido2OperationsGetHmacSecretError, Fido2OperationsMakeCredentialError,
+    FIDO2_RELYING_PARTY_ID,
+};
+
+/// CTAP2 status returned when none of the allowed credentials is known by the
+/// authenticator (see CTAP2 specification, "Status codes").
+const CTAP2_ERR_NO_CREDENTIALS: u8 = 0x2E;
+
+/// `ctap-hid-fido2` doesn't expose the CTAP status as a typed error: it is only
+/// provided as part of the error message (e.g. `0x2E CTAP2_ERR_NO_CREDENTIALS`),
+/// hence the status code is looked up in each error of the chain.
+fn is_ctap_status(err: &anyhow::Error, status: u8) -> bool {
+    let code = format!("0x{status:02X}");
+    err.chain().any(|cause| {
+        cause
+            .to_string()
+            .split(|c: char| !c.is_ascii_alphanumeric())
+            .any(|word| word.eq_ignore_ascii_case(&code))
+    })
+}
+
+/// Access a FIDO2 authenticator plugged on the machine (e.g. a YubiKey) using
+/// CTAP2 over USB HID.
+///
+/// The CTAP2 library is blocking (each operation waits for the user to touch
+/// the authenticator), hence the operations are run in a dedicated thread.
+#[derive(Debug)]
+pub struct NativeFido2Operations {
+    /// Only needed if the authenticator is protected by a PIN
+    pin: Option<Password>,
+}
+
+impl NativeFido2Operations {
+    pub fn new(pin: Option<Password>) -> Self {
+        Self { pin }
+    }
+}
+
+impl Fido2DeviceOperations for NativeFido2Operations {
+    fn relying_party_id(&self) -> &str {
+        FIDO2_RELYING_PARTY_ID
+    }
+
+    fn make_credential(
+        &self,
+        _human_handle: &HumanHandle,
+    ) -> PinBoxFutureResult<Bytes, Fido2OperationsMakeCredentialError> {
+        // Note the human handle is not provided to the authenticator: the credential
+        // is not discoverable (its ID is stored in the device file instead), so the
+        // user entity would never be displayed.
+        let pin = self.pin.clone();
+        Box::pin(async move {
+            tokio::task::spawn_blocking(move || {
+                let device = FidoKeyHidFactory::create(&LibCfg::init())
+                    .map_err(Fido2OperationsMakeCredentialError::Internal)?;
+
+                let challenge = verifier::create_challenge();
+                let extensions = [Mext::HmacSecret(Some(true))];
+                let builder = MakeCredentialArgsBuilder::new(FIDO2_RELYING_PARTY_ID, &challenge)
+                    .extensions(&extensions);
+                let args = match &pin {
+                    Some(pin) => builder.pin(pin.as_ref()),
+                    None => builder.without_pin_and_uv(),
+                }
+                .build();
+
+                let attestation = device
+                    .make_credential_with_args(&args)
+                    .map_err(Fido2OperationsMakeCredentialError::Internal)?;
+
+                // An authenticator not supporting the extension silently ignores it
+                let hmac_secret_enabled = attestation
+                    .extensions
+                    .iter()
+                    .any(|ext| matches!(ext, Mext::HmacSecret(Some(true))));
+                if !hmac_secret_enabled {
+                    return Err(Fido2OperationsMakeCredentialError::HmacSecretNotSupported);
+                }
+
+                Ok(Bytes::from(attestation.credential_descriptor.id))
+            })
+            .await
+            .map_err(|err| Fido2OperationsMakeCredentialError::Internal(err.into()))?
+        })
+    }
+
+    fn get_hmac_secret(
+        &self,
+        credential_id: &[u8],
+        salt: &[u8],
+    ) -> PinBoxFutureResult<SecretKey, Fido2OperationsGetHmacSecretError> {
+        let pin = self.pin.clone();
+        let credential_id = credential_id.to_vec();
+        let salt: Result<[u8; 32], _> = salt.try_into();
+        Box::pin(async move {
+            let salt = salt.map_err(|_| {
+                Fido2OperationsGetHmacSecretError::Internal(anyhow::anyhow!(
+                    "hmac-secret salt must be 32 bytes long"
+                ))
+            })?;
+
+            tokio::task::spawn_blocking(move || {
+                let device = FidoKeyHidFactory::create(&LibCfg::init())
+                    .map_err(Fido2OperationsGetHmacSecretError::Internal)?;
+
+                let challenge = verifier::create_challenge();
+                let extensions = [Gext::HmacSecret(Some(salt))];
+                let builder = GetAssertionArgsBuilder::new(FIDO2_RELYING_PARTY_ID, &challenge)
+                    .credential_id(&credential_id)
+                    .extensions(&extensions);
+                let args = match &pin {
+                    Some(pin) => builder.pin(pin.as_ref()),
+                    None => builder.without_pin_and_uv(),
+                }
+                .build();
+
+                let assertions = device.get_assertion_with_args(&args).map_err(|err| {
+                    // Typically another authenticator than the one the credential
+                    // has been created with.
+                    if is_ctap_status(&err, CTAP2_ERR_NO_CREDENTIALS) {
+                        Fido2OperationsGetHmacSecretError::UnknownCredential
+                    } else {
+                        Fido2OperationsGetHmacSecretError::Internal(err)
+                    }
+                })?;
+
+                assertions
+                    .iter()
+                    .flat_map(|assertion| assertion.extensions.iter())
+                    .find_map(|ext| match ext {
+                        Gext::HmacSecret(Some(output)) => Some(SecretKey::from(*output)),
+                        _ => None,
+                    })
+                    .ok_or_else(|| {
+                        Fido2OperationsGetHmacSecretError::Internal(anyhow::anyhow!(
+                            "The authenticator didn't return the hmac-secret output"
+                        ))
+                    })
+            })
+            .await
+            .map_err(|err| Fido2OperationsGetHmacSecretError::Internal(err.into()))?
+        })
+    }
+}
@@ -2,16 +2,21 @@
 
 use keyring::Entry as KeyringEntry;
 use libparsec_platform_async::future::FutureExt as _;
-use libparsec_platform_filesystem::save_content;
+use libparsec_platform_filesystem::{save_content, SaveContentError};
 use std::path::{Path, PathBuf};
 use uuid::Uuid;
 
 use crate::{
     encrypt_device, LoadCiphertextKeyError, LoadDeviceError, SaveDeviceError,
     PARSEC_BASE_CONFIG_DIR, PARSEC_BASE_DATA_DIR, PARSEC_BASE_HOME_DIR,
+    PARSEC_PASSWORD_ALGORITHM_CALIBRATION_MS,
 };
 use libparsec_types::prelude::*;
 
+mod fido2;
+
+pub use fido2::NativeFido2Operations;
+
 const KEYRING_SERVICE: &str = "parsec";
 
 impl From<keyring::Error> for LoadDeviceError {
@@ -35,7 +40,7 @@ pub(crate) async fn save_device_keyring(
     totp_opaque_key_id: Option<TOTPOpaqueKeyID>,
     totp_opaque_key: Option<&SecretKey>,
 ) -> Result<(), SaveDeviceError> {
-    let keyring_user_path = crate::get_default_data_base_dir().join("keyring_user.txt");
+    let keyring_user_path = get_keyring_user_file();
 
     let keyring_info = tokio::fs::read_to_string(&keyring_user_path)
         .map(|keyring_user| {
@@ -80,6 +85,22 @@ pub(crate) async fn save_device_keyring(
     Ok(())
 }
 
+/// The keyring user used for new keyring-protected device files is shared
+/// between all of them, and is stored in this file.
+fn get_keyring_user_file() -> PathBuf {
+    crate::get_default_data_base_dir().join("keyring_user.txt")
+}
+
+pub(super) async fn load_keyring_user() -> Option<String> {
+    tokio::fs::read_to_string(get_keyring_user_file())
+        .await
+        .ok()
+}
+
+pub(super) async fn restore_keyring_user(keyring_user: &str) -> Result<(), SaveContentError> {
+    save_content(&get_keyring_user_file(), keyring_user.as_bytes()).await
+}
+
 async fn generate_keyring_user(
     keyring_user_path: &Path,
 ) -> Result<(SecretKey, String), SaveDeviceError> {
@@ -158,6 +179,169 @@ pub(super) async fn load_ciphertext_key_keyring(
     }
 }
 
+/// The machine key file must be owned by the current user and only be accessible
+/// by it, otherwise it is considered compromised and we refuse to use it.
+#[cfg(unix)]
+fn check_machine_key_permissions(metadata: &std::fs::Metadata) -> Result<(), String> {
+    use std::os::unix::fs::{MetadataExt, PermissionsExt};
+
+    // SAFETY: geteuid is always successful and has no side effect
+    let euid = unsafe { libc::geteuid() };
+    if metadata.uid() != euid {
+        return Err(format!(
+            "file is owned by another user (got uid {}, expected {euid})",
+            metadata.uid()
+        ));
+    }
+
+    let mode = metadata.permissions().mode() & 0o777;
+    if mode & 0o077 != 0 {
+        return Err(format!(
+            "permissions are too permissive (got {mode:o}, expected 600)"
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn check_machine_key_permissions(_metadata: &std::fs::Metadata) -> Result<(), String> {
+    // On Windows, files in the user's data directory are only accessible
+    // to the user by default
+    Ok(())
+}
+
+pub(super) async fn load_or_generate_machine_key(
+    machine_key_file: &Path,
+) -> Result<SecretKey, SaveDeviceError> {
+    let exists = tokio::fs::try_exists(machine_key_file)
+        .await
+        .map_err(|e| SaveDeviceError::Internal(e.into()))?;
+    if exists {
+        return load_machine_key(machine_key_file)
+            .await
+            .map_err(|e| SaveDeviceError::Internal(e.into()));
+    }
+
+    let (passphrase, key) = SecretKey::generate_recovery_passphrase();
+
+    // Note the file is small enough for blocking I/O not to be an issue
+    let outcome = (|| {
+        use std::io::Write;
+        #[cfg(unix)]
+        use std::os::unix::fs::{DirBuilderExt, OpenOptionsExt};
+
+        if let Some(parent) = machine_key_file.parent() {
+            let mut builder = std::fs::DirBuilder::new();
+            builder.recursive(true);
+            #[cfg(unix)]
+            builder.mode(0o700);
+            builder.create(parent)?;
+        }
+
+        let mut options = std::fs::OpenOptions::new();
+        // `create_new` ensures we never overwrite a concurrently created machine key
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        options.mode(0o600);
+        let mut file = options.open(machine_key_file)?;
+        let outcome = file
+            .write_all(passphrase.as_bytes())
+            .and_then(|_| file.sync_all());
+        if outcome.is_err() {
+            // A partial machine key file would prevent any further generation
+            // (and fail to be loaded), so it must not be left behind.
+            drop(file);
+            if let Err(err) = std::fs::remove_file(machine_key_file) {
+                log::warn!(
+                    "Cannot remove partial machine key file {}: {err}",
+                    machine_key_file.display()
+                );
+            }
+        }
+        outcome
+    })();
+
+    match outcome {
+        Ok(()) => Ok(key),
+        Err(err) if err.kind() == std::io::ErrorKind::AlreadyExists => {
+            load_machine_key(machine_key_file)
+                .await
+                .map_err(|e| SaveDeviceError::Internal(e.into()))
+        }
+        Err(err) => Err(SaveDeviceError::Internal(anyhow::anyhow!(
+            "Cannot create machine key file {}: {err}",
+            machine_key_file.display()
+        ))),
+    }
+}
+
+pub(super) async fn load_machine_key(
+    machine_key_file: &Path,
+) -> Result<SecretKey, LoadCiphertextKeyError> {
+    let metadata = tokio::fs::metadata(machine_key_file).await.map_err(|e| {
+        LoadCiphertextKeyError::Internal(anyhow::anyhow!(
+            "Cannot access machine key file {}: {e}",
+            machine_key_file.display()
+        ))
+    })?;
+    check_machine_key_permissions(&metadata).map_err(|e| {
+        LoadCiphertextKeyError::Internal(anyhow::anyhow!(
+            "Invalid machine key file {}: {e}",
+            machine_key_file.display()
+        ))
+    })?;
+
+    let passphrase = tokio::fs::read_to_string(machine_key_file)
+        .await
+        .map_err(|e| {
+            LoadCiphertextKeyError::Internal(anyhow::anyhow!(
+                "Cannot read machine key file {}: {e}",
+                machine_key_file.display()
+            ))
+        })?;
+
+    SecretKey::from_recovery_passphrase(passphrase.trim().into())
+        .map_err(|_| LoadCiphertextKeyError::DecryptionFailed)
+}
+
+pub(super) fn is_machine_key_available(machine_key_file: &Path) -> bool {
+    match std::fs::metadata(machine_key_file) {
+        Ok(metadata) if metadata.is_file() => match check_machine_key_permissions(&metadata) {
+            Ok(()) => true,
+            Err(err) => {
+                log::warn!(
+                    "Machine key file {} is not available: {err}",
+                    machine_key_file.display()
+                );
+                false
+            }
+        },
+        _ => false,
+    }
+}
+
+pub(super) fn generate_password_algorithm() -> PasswordAlgorithm {
+    let target_duration = std::env::var(PARSEC_PASSWORD_ALGORITHM_CALIBRATION_MS)
+        .ok()
+        .and_then(|raw| match raw.parse::<u64>() {
+            Ok(ms) => Some(std::time::Duration::from_millis(ms)),
+            Err(err) => {
+                log::warn!(
+                    "Ignoring invalid {PARSEC_PASSWORD_ALGORITHM_CALIBRATION_MS} value {raw:?}: {err}"
+                );
+                None
+            }
+        });
+
+    match target_duration {
+        Some(target_duration) => PasswordAlgorithm::generate_argon2id_calibrated(
+            PasswordAlgorithmSaltStrategy::Random,
+            target_duration,
+        ),
+        None => PasswordAlgorithm::generate_argon2id(PasswordAlgorithmSaltStrategy::Random),
+    }
+}
+
 pub(super) fn get_default_data_base_dir() -> PathBuf {
     let mut path = if let Ok(data_dir) = std::env::var(PARSEC_BASE_DATA_DIR) {
         PathBuf::from(data_dir)
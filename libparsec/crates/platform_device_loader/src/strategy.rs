@@ -145,6 +145,54 @@ pub enum PkiOperationsDecryptOpaqueKeyError {
     Internal(anyhow::Error),
 }
 
+/*
+ * FIDO2 operations
+ */
+
+/// Relying party the FIDO2 credentials protecting device files are created for.
+pub const FIDO2_RELYING_PARTY_ID: &str = "parsec.cloud";
+
+/// Access to a FIDO2 authenticator (e.g. a YubiKey) supporting the `hmac-secret`
+/// extension.
+///
+/// Given a salt, the authenticator computes `HMAC-SHA-256(<credential secret>, <salt>)`,
+/// the credential secret being generated on credential creation and never leaving
+/// the authenticator. The output is then used as the key protecting the device file.
+///
+/// The actual communication with the authenticator is left to the implementer
+/// (see `NativeFido2Operations` for CTAP2 on native), which also allows testing
+/// with a software authenticator.
+pub trait Fido2DeviceOperations: std::fmt::Debug + Send + Sync {
+    fn relying_party_id(&self) -> &str;
+    /// Create a new credential with the `hmac-secret` extension enabled and
+    /// return its ID.
+    fn make_credential(
+        &self,
+        human_handle: &HumanHandle,
+    ) -> PinBoxFutureResult<Bytes, Fido2OperationsMakeCredentialError>;
+    fn get_hmac_secret(
+        &self,
+        credential_id: &[u8],
+        salt: &[u8],
+    ) -> PinBoxFutureResult<SecretKey, Fido2OperationsGetHmacSecretError>;
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum Fido2OperationsMakeCredentialError {
+    #[error("The authenticator doesn't support the hmac-secret extension")]
+    HmacSecretNotSupported,
+    #[error(transparent)]
+    Internal(anyhow::Error),
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum Fido2OperationsGetHmacSecretError {
+    #[error("The authenticator doesn't know this credential")]
+    UnknownCredential,
+    #[error(transparent)]
+    Internal(anyhow::Error),
+}
+
 /*
  * DeviceSaveStrategy
  */
@@ -164,6 +212,35 @@ pub enum DevicePrimaryProtectionStrategy {
     OpenBao {
         operations: Arc<dyn OpenBaoDeviceOperations>,
     },
+    /// Key stored in a file only accessible by the current user (see
+    /// `get_default_machine_key_file`).
+    ///
+    /// This is typically used on headless machines where no OS keyring is available.
+    MachineKey {
+        machine_key_file: PathBuf,
+    },
+    /// Key obtained from a FIDO2 authenticator's `hmac-secret` extension.
+    Fido2 {
+        operations: Arc<dyn Fido2DeviceOperations>,
+    },
+    /// Any of the alternatives is enough to access the device.
+    ///
+    /// When saving, each alternative becomes a protection in the device file.
+    /// When loading, only the alternatives available to the user need to be
+    /// provided (e.g. only the password if the smartcard is not plugged).
+    ///
+    /// Note keyring, machine key and multi protections cannot be used as alternatives.
+    Multi {
+        alternatives: Vec<DeviceAlternativeProtection>,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub struct DeviceAlternativeProtection {
+    /// Only applies to this alternative (TOTP required whatever the alternative
+    /// is configured with the save/access strategy's `totp_protection` field).
+    pub totp_protection: Option<(TOTPOpaqueKeyID, SecretKey)>,
+    pub primary_protection: DevicePrimaryProtectionStrategy,
 }
 
 impl DevicePrimaryProtectionStrategy {
@@ -179,6 +256,47 @@ impl DevicePrimaryProtectionStrategy {
                 openbao_entity_id: operations.openbao_entity_id().to_owned(),
                 openbao_preferred_auth_id: operations.openbao_preferred_auth_id().to_owned(),
             },
+            Self::MachineKey { machine_key_file } => AvailableDeviceType::MachineKey {
+                machine_key_file: machine_key_file.to_owned(),
+                machine_key_available: crate::is_machine_key_available(machine_key_file),
+            },
+            Self::Fido2 { .. } => AvailableDeviceType::Fido2,
+            Self::Multi { alternatives } => AvailableDeviceType::Multi {
+                protections: alternatives
+                    .iter()
+                    .filter_map(|alternative| {
+                        let ty = match &alternative.primary_protection {
+                            Self::Password { .. } => AvailableDeviceMultiProtectionType::Password,
+                            Self::PKI { operations } => AvailableDeviceMultiProtectionType::PKI {
+                                certificate_ref: operations.certificate_ref().to_owned(),
+                            },
+                            Self::AccountVault { .. } => {
+                                AvailableDeviceMultiProtectionType::AccountVault
+                            }
+                            Self::OpenBao { operations } => {
+                                AvailableDeviceMultiProtectionType::OpenBao {
+                                    openbao_entity_id: operations.openbao_entity_id().to_owned(),
+                                    openbao_preferred_auth_id: operations
+                                        .openbao_preferred_auth_id()
+                                        .to_owned(),
+                                }
+                            }
+                            Self::Fido2 { .. } => AvailableDeviceMultiProtectionType::Fido2,
+                            // Not allowed as alternative (saving will fail anyway)
+                            Self::Keyring | Self::MachineKey { .. } | Self::Multi { .. } => {
+                                return None
+                            }
+                        };
+                        Some(AvailableDeviceMultiProtection {
+                            totp_opaque_key_id: alternative
+                                .totp_protection
+                                .as_ref()
+                                .map(|(id, _)| *id),
+                            ty,
+                        })
+                    })
+                    .collect(),
+            },
         }
     }
 }
@@ -276,6 +394,37 @@ pub enum AvailableDeviceType {
         openbao_entity_id: String,
         openbao_preferred_auth_id: String,
     },
+    MachineKey {
+        machine_key_file: PathBuf,
+        /// Whether the machine key file is present and has strict enough
+        /// permissions to be used.
+        machine_key_available: bool,
+    },
+    Fido2,
+    Multi {
+        protections: Vec<AvailableDeviceMultiProtection>,
+    },
+}
+
+/// A protection among the ones of a multi-protected device.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AvailableDeviceMultiProtection {
+    pub totp_opaque_key_id: Option<TOTPOpaqueKeyID>,
+    pub ty: AvailableDeviceMultiProtectionType,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AvailableDeviceMultiProtectionType {
+    Password,
+    PKI {
+        certificate_ref: X509CertificateReference,
+    },
+    AccountVault,
+    OpenBao {
+        openbao_entity_id: String,
+        openbao_preferred_auth_id: String,
+    },
+    Fido2,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
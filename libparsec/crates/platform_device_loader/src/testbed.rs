@@ -322,20 +322,22 @@ pub(crate) fn maybe_load_device(
                 .lock()
                 .expect("Mutex is poisoned");
 
-            fn check_access_and_save_strategy_match(
-                access: &DeviceAccessStrategy,
-                c_save_strategy: &DeviceSaveStrategy,
+            fn check_totp_protection_match(
+                totp_protection: &Option<(TOTPOpaqueKeyID, SecretKey)>,
+                c_totp_protection: &Option<(TOTPOpaqueKeyID, SecretKey)>,
             ) -> bool {
-                match (&access.totp_protection, &c_save_strategy.totp_protection) {
-                    (None, None) => (),
-                    (Some((_, key)), Some((_, c_key))) if key == c_key => (),
-                    _ => return false,
+                match (totp_protection, c_totp_protection) {
+                    (None, None) => true,
+                    (Some((_, key)), Some((_, c_key))) => key == c_key,
+                    _ => false,
                 }
+            }
 
-                match (
-                    &access.primary_protection,
-                    &c_save_strategy.primary_protection,
-                ) {
+            fn check_primary_protection_match(
+                primary_protection: &DevicePrimaryProtectionStrategy,
+                c_primary_protection: &DevicePrimaryProtectionStrategy,
+            ) -> bool {
+                match (primary_protection, c_primary_protection) {
                     (
                         DevicePrimaryProtectionStrategy::Password { password: pwd, .. },
                         DevicePrimaryProtectionStrategy::Password { password: c_pwd },
@@ -360,6 +362,35 @@ pub(crate) fn maybe_load_device(
                         DevicePrimaryProtectionStrategy::PKI { .. },
                         DevicePrimaryProtectionStrategy::PKI { .. },
                     ) => true,
+                    (
+                        DevicePrimaryProtectionStrategy::MachineKey { machine_key_file },
+                        DevicePrimaryProtectionStrategy::MachineKey {
+                            machine_key_file: c_machine_key_file,
+                        },
+                    ) => machine_key_file == c_machine_key_file,
+                    (
+                        DevicePrimaryProtectionStrategy::Fido2 { operations, .. },
+                        DevicePrimaryProtectionStrategy::Fido2 {
+                            operations: c_operations,
+                        },
+                    ) => operations.relying_party_id() == c_operations.relying_party_id(),
+                    // Any of the provided alternatives is enough
+                    (
+                        DevicePrimaryProtectionStrategy::Multi { alternatives },
+                        DevicePrimaryProtectionStrategy::Multi {
+                            alternatives: c_alternatives,
+                        },
+                    ) => alternatives.iter().any(|alternative| {
+                        c_alternatives.iter().any(|c_alternative| {
+                            check_totp_protection_match(
+                                &alternative.totp_protection,
+                                &c_alternative.totp_protection,
+                            ) && check_primary_protection_match(
+                                &alternative.primary_protection,
+                                &c_alternative.primary_protection,
+                            )
+                        })
+                    }),
                     // Don't use a `_ => None` fallthrough match here to avoid
                     // silent bug whenever a new variant is added :/
                     (
@@ -367,16 +398,35 @@ pub(crate) fn maybe_load_device(
                         | DevicePrimaryProtectionStrategy::PKI { .. }
                         | DevicePrimaryProtectionStrategy::Keyring
                         | DevicePrimaryProtectionStrategy::AccountVault { .. }
-                        | DevicePrimaryProtectionStrategy::OpenBao { .. },
+                        | DevicePrimaryProtectionStrategy::OpenBao { .. }
+                        | DevicePrimaryProtectionStrategy::MachineKey { .. }
+                        | DevicePrimaryProtectionStrategy::Fido2 { .. }
+                        | DevicePrimaryProtectionStrategy::Multi { .. },
                         DevicePrimaryProtectionStrategy::Password { .. }
                         | DevicePrimaryProtectionStrategy::PKI { .. }
                         | DevicePrimaryProtectionStrategy::Keyring
                         | DevicePrimaryProtectionStrategy::AccountVault { .. }
-                        | DevicePrimaryProtectionStrategy::OpenBao { .. },
+                        | DevicePrimaryProtectionStrategy::OpenBao { .. }
+                        | DevicePrimaryProtectionStrategy::MachineKey { .. }
+                        | DevicePrimaryProtectionStrategy::Fido2 { .. }
+                        | DevicePrimaryProtectionStrategy::Multi { .. },
                     ) => false,
                 }
             }
 
+            fn check_access_and_save_strategy_match(
+                access: &DeviceAccessStrategy,
+                c_save_strategy: &DeviceSaveStrategy,
+            ) -> bool {
+                check_totp_protection_match(
+                    &access.totp_protection,
+                    &c_save_strategy.totp_protection,
+                ) && check_primary_protection_match(
+                    &access.primary_protection,
+                    &c_save_strategy.primary_protection,
+                )
+            }
+
             let found =
                 cache
                     .available
@@ -410,7 +460,10 @@ pub(crate) fn maybe_load_device(
                     | (_, DevicePrimaryProtectionStrategy::Keyring)
                     | (_, DevicePrimaryProtectionStrategy::PKI { .. })
                     | (_, DevicePrimaryProtectionStrategy::AccountVault { .. })
-                    | (_, DevicePrimaryProtectionStrategy::OpenBao { .. }) => false,
+                    | (_, DevicePrimaryProtectionStrategy::OpenBao { .. })
+                    | (_, DevicePrimaryProtectionStrategy::MachineKey { .. })
+                    | (_, DevicePrimaryProtectionStrategy::Fido2 { .. })
+                    | (_, DevicePrimaryProtectionStrategy::Multi { .. }) => false,
                 };
                 // We don't try to resolve the path of `key_file` into an absolute one here !
                 // This is because in practice the path is always provided absolute given it
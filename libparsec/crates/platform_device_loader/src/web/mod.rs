@@ -1,6 +1,7 @@
 // Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
 
 use crate::{LoadCiphertextKeyError, SaveDeviceError};
+use libparsec_platform_filesystem::SaveContentError;
 use libparsec_types::prelude::*;
 use std::path::{Path, PathBuf};
 
@@ -26,6 +27,31 @@ pub(super) fn is_keyring_available() -> bool {
     false
 }
 
+pub(super) async fn load_keyring_user() -> Option<String> {
+    None
+}
+
+pub(super) async fn restore_keyring_user(_: &str) -> Result<(), SaveContentError> {
+    panic!("Keyring not supported on Web")
+}
+
+pub(super) async fn load_or_generate_machine_key(_: &Path) -> Result<SecretKey, SaveDeviceError> {
+    panic!("Machine key not supported on Web")
+}
+
+pub(super) async fn load_machine_key(_: &Path) -> Result<SecretKey, LoadCiphertextKeyError> {
+    panic!("Machine key not supported on Web")
+}
+
+pub(super) fn is_machine_key_available(_: &Path) -> bool {
+    false
+}
+
+pub(super) fn generate_password_algorithm() -> PasswordAlgorithm {
+    // Benchmark is not possible since `std::time::Instant` is not available on web
+    PasswordAlgorithm::generate_argon2id(PasswordAlgorithmSaltStrategy::Random)
+}
+
 pub(super) fn get_default_data_base_dir() -> PathBuf {
     PathBuf::from("/")
 }
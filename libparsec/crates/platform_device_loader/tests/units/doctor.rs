@@ -0,0 +1,292 @@
+// Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+// `allow-unwrap-in-test` don't behave as expected, see:
+// https://github.com/rust-lang/rust-clippy/issues/11119
+#![allow(clippy::unwrap_used)]
+
+use std::path::Path;
+
+use crate::{
+    diagnose_device_files, export_device_files_backup, import_device_files_backup,
+    list_available_devices, repair_device_files,
+    tests::utils::{create_device_file, key_is_archived, key_present_in_system},
+    DeviceFileIssue, DeviceFileRepair, ImportDeviceFilesBackupError, ImportedDeviceFile,
+};
+use libparsec_tests_fixtures::prelude::*;
+use libparsec_types::prelude::*;
+
+fn password_device_file(device_id: &str, server_url: &str, protected_on: &str) -> Vec<u8> {
+    DeviceFile::Password(DeviceFilePassword {
+        created_on: "2000-01-01T00:00:00Z".parse().unwrap(),
+        protected_on: protected_on.parse().unwrap(),
+        server_url: server_url.parse().unwrap(),
+        organization_id: "CoolOrg".parse().unwrap(),
+        user_id: "alice".parse().unwrap(),
+        device_id: device_id.parse().unwrap(),
+        human_handle: "Alicey McAliceFace <alice@parsec.invalid>".parse().unwrap(),
+        device_label: "My dev1 machine".parse().unwrap(),
+        algorithm: PasswordAlgorithm::Argon2id {
+            salt: *b"1234567890123456",
+            opslimit: 1,
+            memlimit_kb: 8,
+            parallelism: 1,
+        },
+        ciphertext: b"<ciphertext>".as_ref().into(),
+        totp_opaque_key_id: None,
+    })
+    .dump()
+}
+
+/// Populate the devices dir with a healthy device, two devices sharing the same
+/// device ID (the first one being the older), a corrupted device and an orphaned
+/// temporary file.
+async fn populate_devices_dir(devices_dir: &Path) {
+    create_device_file(
+        &devices_dir.join("bob.keys"),
+        &password_device_file(
+            "bob@dev1",
+            "parsec3://parsec.invalid",
+            "2000-01-01T00:00:00Z",
+        ),
+    )
+    .await;
+    create_device_file(
+        &devices_dir.join("alice_old.keys"),
+        &password_device_file(
+            "alice@dev1",
+            "parsec3://old.parsec.invalid",
+            "2000-01-01T00:00:00Z",
+        ),
+    )
+    .await;
+    create_device_file(
+        &devices_dir.join("alice_new.keys"),
+        &password_device_file(
+            "alice@dev1",
+            "parsec3://new.parsec.invalid",
+            "2000-01-02T00:00:00Z",
+        ),
+    )
+    .await;
+    create_device_file(&devices_dir.join("corrupted.keys"), b"dummy").await;
+    create_device_file(&devices_dir.join("interrupted.keys.tmp"), b"dummy").await;
+    // Not a device file leftover, should be ignored
+    create_device_file(&devices_dir.join("unrelated.tmp"), b"dummy").await;
+}
+
+#[parsec_test]
+async fn diagnose_no_devices(tmp_path: TmpPath) {
+    let diagnosis = diagnose_device_files(&tmp_path).await.unwrap();
+    p_assert_eq!(diagnosis.device_files, []);
+    p_assert_eq!(diagnosis.orphaned_files, []);
+}
+
+#[parsec_test]
+async fn diagnose(tmp_path: TmpPath) {
+    let devices_dir = tmp_path.join("devices");
+    populate_devices_dir(&devices_dir).await;
+
+    let diagnosis = diagnose_device_files(&tmp_path).await.unwrap();
+
+    p_assert_eq!(
+        diagnosis.orphaned_files,
+        [devices_dir.join("interrupted.keys.tmp")]
+    );
+
+    let summary = diagnosis
+        .device_files
+        .iter()
+        .map(|d| {
+            (
+                d.key_file_path.file_name().unwrap().to_str().unwrap(),
+                d.device.as_ref().map(|device| device.device_id),
+                d.issues.clone(),
+            )
+        })
+        .collect::<Vec<_>>();
+    p_assert_eq!(
+        summary,
+        [
+            (
+                "alice_new.keys",
+                Some("alice@dev1".parse().unwrap()),
+                vec![DeviceFileIssue::Duplicate {
+                    other_key_file: devices_dir.join("alice_old.keys"),
+                    other_server_addr: "parsec3://old.parsec.invalid".parse().unwrap(),
+                }]
+            ),
+            (
+                "alice_old.keys",
+                Some("alice@dev1".parse().unwrap()),
+                vec![DeviceFileIssue::Duplicate {
+                    other_key_file: devices_dir.join("alice_new.keys"),
+                    other_server_addr: "parsec3://new.parsec.invalid".parse().unwrap(),
+                }]
+            ),
+            ("bob.keys", Some("bob@dev1".parse().unwrap()), vec![]),
+            ("corrupted.keys", None, vec![DeviceFileIssue::Corrupted]),
+        ]
+    );
+
+    let password = AdvisoryDeviceFileProtection {
+        primary: AdvisoryDeviceFilePrimaryProtection::Password,
+        with_totp: false,
+    };
+    let keyring = AdvisoryDeviceFileProtection {
+        primary: AdvisoryDeviceFilePrimaryProtection::Keyring,
+        with_totp: false,
+    };
+    let bob = &diagnosis.device_files[2];
+    p_assert_eq!(bob.protection, Some(password.clone()));
+    assert!(bob.follows_advisory_protection(&[]));
+    assert!(bob.follows_advisory_protection(&[keyring.clone(), password]));
+    assert!(!bob.follows_advisory_protection(&[keyring.clone()]));
+    let corrupted = &diagnosis.device_files[3];
+    assert!(!corrupted.follows_advisory_protection(&[keyring]));
+}
+
+#[parsec_test]
+async fn repair(tmp_path: TmpPath) {
+    let devices_dir = tmp_path.join("devices");
+    populate_devices_dir(&devices_dir).await;
+
+    let repairs = repair_device_files(&tmp_path).await.unwrap();
+    p_assert_eq!(
+        repairs,
+        [
+            DeviceFileRepair::OrphanedFileRemoved {
+                path: devices_dir.join("interrupted.keys.tmp"),
+            },
+            DeviceFileRepair::CorruptedArchived {
+                key_file: devices_dir.join("corrupted.keys"),
+            },
+            DeviceFileRepair::DuplicateArchived {
+                key_file: devices_dir.join("alice_old.keys"),
+                kept_key_file: devices_dir.join("alice_new.keys"),
+            },
+        ]
+    );
+
+    assert!(!key_present_in_system(&devices_dir.join("interrupted.keys.tmp")).await);
+    assert!(key_present_in_system(&devices_dir.join("unrelated.tmp")).await);
+    assert!(key_is_archived(&devices_dir.join("corrupted.keys")).await);
+    assert!(key_is_archived(&devices_dir.join("alice_old.keys")).await);
+
+    let devices = list_available_devices(&tmp_path).await.unwrap();
+    p_assert_eq!(
+        devices
+            .iter()
+            .map(|device| device.key_file_path.clone())
+            .collect::<Vec<_>>(),
+        [
+            devices_dir.join("alice_new.keys"),
+            devices_dir.join("bob.keys")
+        ]
+    );
+
+    // Nothing left to repair
+    let diagnosis = diagnose_device_files(&tmp_path).await.unwrap();
+    p_assert_eq!(diagnosis.orphaned_files, []);
+    assert!(diagnosis.device_files.iter().all(|d| d.issues.is_empty()));
+    p_assert_eq!(repair_device_files(&tmp_path).await.unwrap(), []);
+}
+
+#[parsec_test]
+async fn export_import_backup(tmp_path: TmpPath) {
+    let src_config_dir = tmp_path.join("src");
+    let src_devices_dir = src_config_dir.join("devices");
+    let alice = password_device_file(
+        "alice@dev1",
+        "parsec3://parsec.invalid",
+        "2000-01-01T00:00:00Z",
+    );
+    let bob = password_device_file(
+        "bob@dev1",
+        "parsec3://parsec.invalid",
+        "2000-01-01T00:00:00Z",
+    );
+    create_device_file(&src_devices_dir.join("alice.keys"), &alice).await;
+    create_device_file(&src_devices_dir.join("sub/bob.keys"), &bob).await;
+
+    let password: Password = "P@ssw0rd.".to_owned().into();
+    let (backup, exported) = export_device_files_backup(&src_config_dir, &password)
+        .await
+        .unwrap();
+    p_assert_eq!(
+        exported,
+        [
+            src_devices_dir.join("alice.keys"),
+            src_devices_dir.join("sub/bob.keys")
+        ]
+    );
+
+    // Bad password or invalid backup
+
+    let dst_config_dir = tmp_path.join("dst");
+    let dst_devices_dir = dst_config_dir.join("devices");
+    p_assert_matches!(
+        import_device_files_backup(&dst_config_dir, &backup, &"dummy".to_owned().into()).await,
+        Err(ImportDeviceFilesBackupError::DecryptionFailed)
+    );
+    p_assert_matches!(
+        import_device_files_backup(&dst_config_dir, b"dummy", &password).await,
+        Err(ImportDeviceFilesBackupError::InvalidData)
+    );
+
+    // Bob is already present under another path, with different content
+
+    let bob_other_path = dst_devices_dir.join("bob.keys");
+    let bob_other = password_device_file(
+        "bob@dev1",
+        "parsec3://new.parsec.invalid",
+        "2000-01-02T00:00:00Z",
+    );
+    create_device_file(&bob_other_path, &bob_other).await;
+
+    let outcomes = import_device_files_backup(&dst_config_dir, &backup, &password)
+        .await
+        .unwrap();
+    p_assert_eq!(
+        outcomes,
+        [
+            ImportedDeviceFile::Restored {
+                key_file: dst_devices_dir.join("alice.keys")
+            },
+            ImportedDeviceFile::Conflict {
+                key_file: dst_devices_dir.join("sub/bob.keys"),
+                existing_key_file: bob_other_path.clone(),
+            },
+        ]
+    );
+    p_assert_eq!(
+        libparsec_platform_filesystem::load_file(&dst_devices_dir.join("alice.keys"))
+            .await
+            .unwrap(),
+        alice
+    );
+    p_assert_eq!(
+        libparsec_platform_filesystem::load_file(&bob_other_path)
+            .await
+            .unwrap(),
+        bob_other
+    );
+    assert!(!key_present_in_system(&dst_devices_dir.join("sub/bob.keys")).await);
+
+    // Importing again is a no-op
+
+    let outcomes = import_device_files_backup(&dst_config_dir, &backup, &password)
+        .await
+        .unwrap();
+    p_assert_eq!(
+        outcomes,
+        [
+            ImportedDeviceFile::AlreadyPresent {
+                key_file: dst_devices_dir.join("alice.keys")
+            },
+            ImportedDeviceFile::Conflict {
+                key_file: dst_devices_dir.join("sub/bob.keys"),
+                existing_key_file: bob_other_path,
+            },
+        ]
+    );
+}
@@ -5,13 +5,19 @@ libparsec_tests_lite::platform::wasm_bindgen_test_configure!(run_in_browser run_
 
 mod archive;
 mod async_enrollment;
+mod doctor;
 mod list;
 mod load;
+mod password_algorithm_upgrade;
 mod recovery;
 mod remove;
 mod save;
 mod save_list;
 mod save_load;
+mod save_load_fido2;
+#[cfg(not(target_arch = "wasm32"))]
+mod save_load_machine_key;
+mod save_load_multi;
 mod update_device_change_authentication;
 mod update_device_overwrite_server_addr;
 mod utils;
@@ -0,0 +1,260 @@
+// Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+use std::path::Path;
+
+use libparsec_platform_filesystem::load_file;
+
+use crate::{
+    check_device_security, encrypt_device, load_device, save_device, DeviceAccessStrategy,
+    DeviceAlternativeProtection, DevicePrimaryProtectionStrategy, DeviceProtectionWeakness,
+    DeviceSaveStrategy,
+};
+use libparsec_tests_fixtures::{tmp_path, TmpPath};
+use libparsec_tests_lite::prelude::*;
+use libparsec_types::prelude::*;
+
+fn generate_device() -> LocalDevice {
+    let url = ParsecOrganizationAddr::from_any(
+        // cspell:disable-next-line
+        "parsec3://test.invalid/Org?p=xCD7SjlysFv3d4mTkRu-ZddRjIZPGraSjUnoOHT9s8rmLA",
+    )
+    .unwrap();
+    LocalDevice::generate_new_device(
+        url,
+        UserProfile::Admin,
+        HumanHandle::from_raw("alice@dev1", "alice").unwrap(),
+        "alice label".parse().unwrap(),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+}
+
+/// Typically what an older version of Parsec would have generated
+fn weak_algorithm() -> PasswordAlgorithm {
+    PasswordAlgorithm::Argon2id {
+        memlimit_kb: 8 * 1024,
+        opslimit: 1,
+        parallelism: 1,
+        salt: [0x11; 16],
+    }
+}
+
+async fn load_device_file(key_file: &Path) -> DeviceFile {
+    DeviceFile::load(&load_file(key_file).await.unwrap()).unwrap()
+}
+
+#[parsec_test]
+async fn password(tmp_path: TmpPath) {
+    let key_file = tmp_path.join("devices/password_file.keys");
+    let device = generate_device();
+    let password: Password = "P@ssw0rd.".to_string().into();
+
+    let key = weak_algorithm().compute_secret_key(&password).unwrap();
+    let original = DeviceFilePassword {
+        created_on: "2000-01-01T00:00:00Z".parse().unwrap(),
+        protected_on: "2000-01-02T00:00:00Z".parse().unwrap(),
+        server_url: device.organization_addr.clone().into(),
+        organization_id: device.organization_id().to_owned(),
+        user_id: device.user_id,
+        device_id: device.device_id,
+        human_handle: device.human_handle.to_owned(),
+        device_label: device.device_label.to_owned(),
+        algorithm: weak_algorithm(),
+        ciphertext: encrypt_device(&device, &key, None),
+        totp_opaque_key_id: None,
+    };
+    crate::tests::utils::create_device_file(
+        &key_file,
+        &DeviceFile::Password(original.clone()).dump(),
+    )
+    .await;
+
+    p_assert_eq!(
+        check_device_security(Path::new(""), key_file.clone())
+            .await
+            .unwrap(),
+        [DeviceProtectionWeakness::OutdatedPasswordAlgorithm {
+            algorithm: weak_algorithm()
+        }]
+    );
+
+    // Bad password doesn't touch the device file
+
+    p_assert_matches!(
+        load_device(
+            Path::new(""),
+            &DeviceAccessStrategy::new_password(key_file.clone(), "dummy".to_string().into())
+        )
+        .await,
+        Err(_)
+    );
+    p_assert_eq!(
+        load_device_file(&key_file).await,
+        DeviceFile::Password(original.clone())
+    );
+
+    // Successful load upgrades the algorithm...
+
+    let access = DeviceAccessStrategy::new_password(key_file.clone(), password);
+    let res = load_device(Path::new(""), &access).await.unwrap();
+    p_assert_eq!(*res, device);
+
+    let upgraded = match load_device_file(&key_file).await {
+        DeviceFile::Password(upgraded) => upgraded,
+        unknown => panic!("Unexpected device file: {unknown:?}"),
+    };
+    p_assert_eq!(upgraded.algorithm.is_weaker_than_default(), false);
+    p_assert_eq!(
+        upgraded,
+        DeviceFilePassword {
+            algorithm: upgraded.algorithm.clone(),
+            ciphertext: upgraded.ciphertext.clone(),
+            ..original
+        }
+    );
+    p_assert_eq!(
+        check_device_security(Path::new(""), key_file.clone())
+            .await
+            .unwrap(),
+        []
+    );
+
+    // ...and the device file is still usable
+
+    let res = load_device(Path::new(""), &access).await.unwrap();
+    p_assert_eq!(*res, device);
+}
+
+#[parsec_test]
+async fn multi(tmp_path: TmpPath) {
+    let key_file = tmp_path.join("devices/multi_file.keys");
+    let device = generate_device();
+    let ciphertext_key = SecretKey::generate();
+
+    let weak_password_protection = |password: &str| {
+        let key = weak_algorithm()
+            .compute_secret_key(&password.to_string().into())
+            .unwrap();
+        DeviceFileMultiProtection {
+            primary: DeviceFileMultiPrimaryProtection::Password {
+                algorithm: weak_algorithm(),
+            },
+            encrypted_ciphertext_key: key.encrypt(ciphertext_key.as_ref()).into(),
+            totp_opaque_key_id: None,
+        }
+    };
+    let original = DeviceFileMulti {
+        created_on: "2000-01-01T00:00:00Z".parse().unwrap(),
+        protected_on: "2000-01-02T00:00:00Z".parse().unwrap(),
+        server_url: device.organization_addr.clone().into(),
+        organization_id: device.organization_id().to_owned(),
+        user_id: device.user_id,
+        device_id: device.device_id,
+        human_handle: device.human_handle.to_owned(),
+        device_label: device.device_label.to_owned(),
+        protections: vec![
+            weak_password_protection("P@ssw0rd."),
+            weak_password_protection("S3cr3t."),
+        ],
+        ciphertext: encrypt_device(&device, &ciphertext_key, None),
+        totp_opaque_key_id: None,
+    };
+    crate::tests::utils::create_device_file(&key_file, &DeviceFile::Multi(original.clone()).dump())
+        .await;
+
+    p_assert_eq!(
+        check_device_security(Path::new(""), key_file.clone())
+            .await
+            .unwrap()
+            .len(),
+        2
+    );
+
+    // Only the protection corresponding to the provided password can be upgraded
+
+    let access = |password: &str| DeviceAccessStrategy {
+        key_file: key_file.clone(),
+        totp_protection: None,
+        primary_protection: DevicePrimaryProtectionStrategy::Multi {
+            alternatives: vec![DeviceAlternativeProtection {
+                totp_protection: None,
+                primary_protection: DevicePrimaryProtectionStrategy::Password {
+                    password: password.to_string().into(),
+                },
+            }],
+        },
+    };
+    let res = load_device(Path::new(""), &access("S3cr3t."))
+        .await
+        .unwrap();
+    p_assert_eq!(*res, device);
+
+    let upgraded = match load_device_file(&key_file).await {
+        DeviceFile::Multi(upgraded) => upgraded,
+        unknown => panic!("Unexpected device file: {unknown:?}"),
+    };
+    p_assert_eq!(upgraded.protections[0], original.protections[0]);
+    p_assert_matches!(
+        &upgraded.protections[1].primary,
+        DeviceFileMultiPrimaryProtection::Password { algorithm } if !algorithm.is_weaker_than_default()
+    );
+    p_assert_eq!(upgraded.ciphertext, original.ciphertext);
+    p_assert_eq!(
+        check_device_security(Path::new(""), key_file.clone())
+            .await
+            .unwrap(),
+        [DeviceProtectionWeakness::OutdatedPasswordAlgorithm {
+            algorithm: weak_algorithm()
+        }]
+    );
+
+    for password in ["P@ssw0rd.", "S3cr3t."] {
+        let res = load_device(Path::new(""), &access(password)).await.unwrap();
+        p_assert_eq!(*res, device);
+    }
+    p_assert_eq!(
+        check_device_security(Path::new(""), key_file.clone())
+            .await
+            .unwrap(),
+        []
+    );
+}
+
+#[parsec_test]
+async fn up_to_date(tmp_path: TmpPath) {
+    let key_file = tmp_path.join("devices/password_file.keys");
+    let device = generate_device();
+    let password: Password = "P@ssw0rd.".to_string().into();
+
+    save_device(
+        &tmp_path,
+        &DeviceSaveStrategy::new_password(password.clone()),
+        &device,
+        key_file.clone(),
+    )
+    .await
+    .unwrap();
+    let original = load_file(&key_file).await.unwrap();
+    p_assert_eq!(
+        check_device_security(Path::new(""), key_file.clone())
+            .await
+            .unwrap(),
+        []
+    );
+
+    let res = load_device(
+        Path::new(""),
+        &DeviceAccessStrategy::new_password(key_file.clone(), password),
+    )
+    .await
+    .unwrap();
+    p_assert_eq!(*res, device);
+
+    // Device file is left untouched
+    p_assert_eq!(load_file(&key_file).await.unwrap(), original);
+}
@@ -0,0 +1,260 @@
+// Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+use std::{path::Path, sync::Arc};
+
+use super::utils::MockedFido2Operations;
+use crate::{
+    list_available_devices, load_available_device, load_device, save_device,
+    AvailableDeviceMultiProtection, AvailableDeviceMultiProtectionType, AvailableDeviceType,
+    DeviceAccessStrategy, DeviceAlternativeProtection, DevicePrimaryProtectionStrategy,
+    DeviceSaveStrategy, LoadDeviceError, SaveDeviceError,
+};
+use libparsec_tests_fixtures::{tmp_path, TmpPath};
+use libparsec_tests_lite::prelude::*;
+use libparsec_types::prelude::*;
+
+fn generate_device() -> LocalDevice {
+    let url = ParsecOrganizationAddr::from_any(
+        // cspell:disable-next-line
+        "parsec3://test.invalid/Org?p=xCD7SjlysFv3d4mTkRu-ZddRjIZPGraSjUnoOHT9s8rmLA",
+    )
+    .unwrap();
+    LocalDevice::generate_new_device(
+        url,
+        UserProfile::Admin,
+        HumanHandle::from_raw("alice@dev1", "alice").unwrap(),
+        "alice label".parse().unwrap(),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+}
+
+fn fido2_access(key_file: &Path, operations: &Arc<MockedFido2Operations>) -> DeviceAccessStrategy {
+    DeviceAccessStrategy {
+        key_file: key_file.to_owned(),
+        totp_protection: None,
+        primary_protection: DevicePrimaryProtectionStrategy::Fido2 {
+            operations: operations.clone(),
+        },
+    }
+}
+
+#[parsec_test]
+async fn save_load(tmp_path: TmpPath) {
+    let key_file = tmp_path.join("devices/fido2_file.keys");
+    let device = generate_device();
+    let authenticator = Arc::new(MockedFido2Operations::new("parsec.cloud"));
+
+    let save_strategy = DeviceSaveStrategy {
+        totp_protection: None,
+        primary_protection: DevicePrimaryProtectionStrategy::Fido2 {
+            operations: authenticator.clone(),
+        },
+    };
+    let available_device = save_device(&tmp_path, &save_strategy, &device, key_file.clone())
+        .await
+        .unwrap();
+    p_assert_eq!(available_device.ty, AvailableDeviceType::Fido2);
+    p_assert_eq!(
+        load_available_device(Path::new(""), key_file.clone())
+            .await
+            .unwrap(),
+        available_device
+    );
+    p_assert_eq!(
+        list_available_devices(&tmp_path).await.unwrap(),
+        [available_device]
+    );
+
+    let res = load_device(Path::new(""), &fido2_access(&key_file, &authenticator))
+        .await
+        .unwrap();
+    p_assert_eq!(*res, device);
+
+    // Another security key doesn't know the credential
+
+    let other_authenticator = Arc::new(MockedFido2Operations::new("parsec.cloud"));
+    p_assert_matches!(
+        load_device(
+            Path::new(""),
+            &fido2_access(&key_file, &other_authenticator)
+        )
+        .await,
+        Err(LoadDeviceError::DecryptionFailed)
+    );
+
+    // Credential is scoped to its relying party
+
+    let other_relying_party = Arc::new(MockedFido2Operations::new("example.com"));
+    p_assert_matches!(
+        load_device(
+            Path::new(""),
+            &fido2_access(&key_file, &other_relying_party)
+        )
+        .await,
+        Err(LoadDeviceError::InvalidData)
+    );
+
+    // Access strategy must match the device file protection
+
+    p_assert_matches!(
+        load_device(
+            Path::new(""),
+            &DeviceAccessStrategy::new_password(key_file.clone(), "P@ssw0rd.".to_string().into())
+        )
+        .await,
+        Err(LoadDeviceError::InvalidData)
+    );
+}
+
+#[parsec_test]
+async fn save_load_with_totp(tmp_path: TmpPath) {
+    let key_file = tmp_path.join("devices/fido2_file.keys");
+    let device = generate_device();
+    let authenticator = Arc::new(MockedFido2Operations::new("parsec.cloud"));
+
+    let totp_opaque_key_id = TOTPOpaqueKeyID::from_hex("8fdb73524fdd495194e877a5fafbe0a1").unwrap();
+    let totp_opaque_key = SecretKey::generate();
+    let save_strategy = DeviceSaveStrategy {
+        totp_protection: Some((totp_opaque_key_id, totp_opaque_key.clone())),
+        primary_protection: DevicePrimaryProtectionStrategy::Fido2 {
+            operations: authenticator.clone(),
+        },
+    };
+    let available_device = save_device(&tmp_path, &save_strategy, &device, key_file.clone())
+        .await
+        .unwrap();
+    p_assert_eq!(
+        available_device.totp_opaque_key_id,
+        Some(totp_opaque_key_id)
+    );
+
+    let access = DeviceAccessStrategy {
+        totp_protection: Some((totp_opaque_key_id, totp_opaque_key)),
+        ..fido2_access(&key_file, &authenticator)
+    };
+    let res = load_device(Path::new(""), &access).await.unwrap();
+    p_assert_eq!(*res, device);
+
+    p_assert_matches!(
+        load_device(
+            Path::new(""),
+            &DeviceAccessStrategy {
+                totp_protection: Some((totp_opaque_key_id, SecretKey::generate())),
+                ..access
+            }
+        )
+        .await,
+        Err(LoadDeviceError::TOTPDecryptionFailed)
+    );
+}
+
+#[parsec_test]
+async fn save_hmac_secret_not_supported(tmp_path: TmpPath) {
+    let key_file = tmp_path.join("devices/fido2_file.keys");
+    let device = generate_device();
+    let authenticator = Arc::new(MockedFido2Operations::new_without_hmac_secret(
+        "parsec.cloud",
+    ));
+
+    let save_strategy = DeviceSaveStrategy {
+        totp_protection: None,
+        primary_protection: DevicePrimaryProtectionStrategy::Fido2 {
+            operations: authenticator,
+        },
+    };
+    p_assert_matches!(
+        save_device(&tmp_path, &save_strategy, &device, key_file).await,
+        Err(SaveDeviceError::Fido2HmacSecretNotSupported)
+    );
+}
+
+#[parsec_test]
+async fn save_load_as_multi_alternative(tmp_path: TmpPath) {
+    let key_file = tmp_path.join("devices/multi_file.keys");
+    let device = generate_device();
+    let authenticator = Arc::new(MockedFido2Operations::new("parsec.cloud"));
+
+    let fido2_alternative = DeviceAlternativeProtection {
+        totp_protection: None,
+        primary_protection: DevicePrimaryProtectionStrategy::Fido2 {
+            operations: authenticator.clone(),
+        },
+    };
+    let password_alternative = DeviceAlternativeProtection {
+        totp_protection: None,
+        primary_protection: DevicePrimaryProtectionStrategy::Password {
+            password: "P@ssw0rd.".to_string().into(),
+        },
+    };
+    let save_strategy = DeviceSaveStrategy {
+        totp_protection: None,
+        primary_protection: DevicePrimaryProtectionStrategy::Multi {
+            alternatives: vec![fido2_alternative.clone(), password_alternative.clone()],
+        },
+    };
+    let available_device = save_device(&tmp_path, &save_strategy, &device, key_file.clone())
+        .await
+        .unwrap();
+    p_assert_eq!(
+        available_device.ty,
+        AvailableDeviceType::Multi {
+            protections: vec![
+                AvailableDeviceMultiProtection {
+                    totp_opaque_key_id: None,
+                    ty: AvailableDeviceMultiProtectionType::Fido2,
+                },
+                AvailableDeviceMultiProtection {
+                    totp_opaque_key_id: None,
+                    ty: AvailableDeviceMultiProtectionType::Password,
+                },
+            ]
+        }
+    );
+    p_assert_eq!(
+        load_available_device(Path::new(""), key_file.clone())
+            .await
+            .unwrap(),
+        available_device
+    );
+
+    let multi_access = |alternative: DeviceAlternativeProtection| DeviceAccessStrategy {
+        key_file: key_file.clone(),
+        totp_protection: None,
+        primary_protection: DevicePrimaryProtectionStrategy::Multi {
+            alternatives: vec![alternative],
+        },
+    };
+
+    // Either the security key...
+
+    let res = load_device(Path::new(""), &multi_access(fido2_alternative))
+        .await
+        .unwrap();
+    p_assert_eq!(*res, device);
+
+    // ...or the password as fallback
+
+    let res = load_device(Path::new(""), &multi_access(password_alternative))
+        .await
+        .unwrap();
+    p_assert_eq!(*res, device);
+
+    // Another security key doesn't know the credential
+
+    let other_authenticator = DeviceAlternativeProtection {
+        totp_protection: None,
+        primary_protection: DevicePrimaryProtectionStrategy::Fido2 {
+            operations: Arc::new(MockedFido2Operations::new("parsec.cloud")),
+        },
+    };
+    p_assert_matches!(
+        load_device(Path::new(""), &multi_access(other_authenticator)).await,
+        Err(LoadDeviceError::DecryptionFailed)
+    );
+}
@@ -0,0 +1,223 @@
+// Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+use std::path::Path;
+
+use crate::{
+    is_machine_key_available, list_available_devices, load_available_device, load_device,
+    save_device, AvailableDeviceType, DeviceAccessStrategy, DevicePrimaryProtectionStrategy,
+    DeviceSaveStrategy, LoadDeviceError,
+};
+use libparsec_tests_fixtures::{tmp_path, TmpPath};
+use libparsec_tests_lite::prelude::*;
+use libparsec_types::prelude::*;
+
+fn generate_device(label: &str) -> LocalDevice {
+    let url = ParsecOrganizationAddr::from_any(
+        // cspell:disable-next-line
+        "parsec3://test.invalid/Org?p=xCD7SjlysFv3d4mTkRu-ZddRjIZPGraSjUnoOHT9s8rmLA",
+    )
+    .unwrap();
+    LocalDevice::generate_new_device(
+        url,
+        UserProfile::Admin,
+        HumanHandle::from_raw("alice@dev1", "alice").unwrap(),
+        label.parse().unwrap(),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+}
+
+fn machine_key_save_strategy(machine_key_file: &Path) -> DeviceSaveStrategy {
+    DeviceSaveStrategy {
+        totp_protection: None,
+        primary_protection: DevicePrimaryProtectionStrategy::MachineKey {
+            machine_key_file: machine_key_file.to_owned(),
+        },
+    }
+}
+
+fn machine_key_access(key_file: &Path, machine_key_file: &Path) -> DeviceAccessStrategy {
+    DeviceAccessStrategy {
+        key_file: key_file.to_owned(),
+        totp_protection: None,
+        primary_protection: DevicePrimaryProtectionStrategy::MachineKey {
+            machine_key_file: machine_key_file.to_owned(),
+        },
+    }
+}
+
+#[parsec_test]
+async fn save_load(tmp_path: TmpPath) {
+    let machine_key_file = tmp_path.join("data/machine.key");
+    let key_file1 = tmp_path.join("devices/device1.keys");
+    let key_file2 = tmp_path.join("devices/device2.keys");
+    let device1 = generate_device("dev1");
+    let device2 = generate_device("dev2");
+
+    p_assert_eq!(is_machine_key_available(&machine_key_file), false);
+
+    // First save generates the machine key...
+
+    let available_device1 = save_device(
+        &tmp_path,
+        &machine_key_save_strategy(&machine_key_file),
+        &device1,
+        key_file1.clone(),
+    )
+    .await
+    .unwrap();
+    let expected_ty = AvailableDeviceType::MachineKey {
+        machine_key_file: machine_key_file.clone(),
+        machine_key_available: true,
+    };
+    p_assert_eq!(available_device1.ty, expected_ty);
+    p_assert_eq!(is_machine_key_available(&machine_key_file), true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+
+        let mode = std::fs::metadata(&machine_key_file)
+            .unwrap()
+            .permissions()
+            .mode();
+        p_assert_eq!(mode & 0o777, 0o600);
+    }
+    let machine_key = std::fs::read(&machine_key_file).unwrap();
+
+    // ...and the following ones reuse it
+
+    let available_device2 = save_device(
+        &tmp_path,
+        &machine_key_save_strategy(&machine_key_file),
+        &device2,
+        key_file2.clone(),
+    )
+    .await
+    .unwrap();
+    p_assert_eq!(available_device2.ty, expected_ty);
+    p_assert_eq!(std::fs::read(&machine_key_file).unwrap(), machine_key);
+
+    p_assert_eq!(
+        load_available_device(Path::new(""), key_file1.clone())
+            .await
+            .unwrap(),
+        available_device1
+    );
+    let mut devices = list_available_devices(&tmp_path).await.unwrap();
+    devices.sort_by(|a, b| a.key_file_path.cmp(&b.key_file_path));
+    p_assert_eq!(
+        devices,
+        [available_device1.clone(), available_device2.clone()]
+    );
+
+    for (key_file, device) in [(&key_file1, &device1), (&key_file2, &device2)] {
+        let res = load_device(
+            Path::new(""),
+            &machine_key_access(key_file, &machine_key_file),
+        )
+        .await
+        .unwrap();
+        p_assert_eq!(*res, *device);
+    }
+
+    // Another machine key cannot decrypt the device
+
+    let other_machine_key_file = tmp_path.join("other/machine.key");
+    save_device(
+        &tmp_path,
+        &machine_key_save_strategy(&other_machine_key_file),
+        &generate_device("dev3"),
+        tmp_path.join("other/device3.keys"),
+    )
+    .await
+    .unwrap();
+    p_assert_matches!(
+        load_device(
+            Path::new(""),
+            &machine_key_access(&key_file1, &other_machine_key_file)
+        )
+        .await,
+        Err(LoadDeviceError::DecryptionFailed)
+    );
+
+    // Missing machine key file
+
+    p_assert_matches!(
+        load_device(
+            Path::new(""),
+            &machine_key_access(&key_file1, &tmp_path.join("dummy/machine.key"))
+        )
+        .await,
+        Err(LoadDeviceError::Internal(_))
+    );
+
+    // Single protection access strategy cannot be used on a machine key device
+
+    p_assert_matches!(
+        load_device(
+            Path::new(""),
+            &DeviceAccessStrategy::new_password(key_file1.clone(), "P@ssw0rd.".to_string().into())
+        )
+        .await,
+        Err(LoadDeviceError::InvalidData)
+    );
+}
+
+#[cfg(unix)]
+#[parsec_test]
+async fn too_permissive_machine_key_file(tmp_path: TmpPath) {
+    use std::os::unix::fs::PermissionsExt;
+
+    let machine_key_file = tmp_path.join("data/machine.key");
+    let key_file = tmp_path.join("devices/device.keys");
+    let device = generate_device("dev1");
+
+    save_device(
+        &tmp_path,
+        &machine_key_save_strategy(&machine_key_file),
+        &device,
+        key_file.clone(),
+    )
+    .await
+    .unwrap();
+
+    std::fs::set_permissions(&machine_key_file, std::fs::Permissions::from_mode(0o644)).unwrap();
+
+    p_assert_eq!(is_machine_key_available(&machine_key_file), false);
+    p_assert_matches!(
+        load_available_device(Path::new(""), key_file.clone())
+            .await
+            .unwrap()
+            .ty,
+        AvailableDeviceType::MachineKey {
+            machine_key_available: false,
+            ..
+        }
+    );
+    p_assert_matches!(
+        load_device(
+            Path::new(""),
+            &machine_key_access(&key_file, &machine_key_file)
+        )
+        .await,
+        Err(LoadDeviceError::Internal(_))
+    );
+
+    // The compromised machine key must not be reused to protect new devices
+
+    p_assert_matches!(
+        save_device(
+            &tmp_path,
+            &machine_key_save_strategy(&machine_key_file),
+            &generate_device("dev2"),
+            tmp_path.join("devices/device2.keys"),
+        )
+        .await,
+        Err(crate::SaveDeviceError::Internal(_))
+    );
+}
@@ -0,0 +1,291 @@
+// Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+use std::{path::Path, sync::Arc};
+
+use super::utils::{MockedOpenBaoOperations, MockedPkiOperations};
+use crate::{
+    load_available_device, load_device, save_device, AvailableDeviceMultiProtection,
+    AvailableDeviceMultiProtectionType, AvailableDeviceType, DeviceAccessStrategy,
+    DeviceAlternativeProtection, DevicePrimaryProtectionStrategy, DeviceSaveStrategy,
+    LoadDeviceError, OpenBaoDeviceOperations, SaveDeviceError,
+};
+use libparsec_tests_fixtures::{tmp_path, TmpPath};
+use libparsec_tests_lite::prelude::*;
+use libparsec_types::prelude::*;
+
+fn generate_device() -> LocalDevice {
+    let url = ParsecOrganizationAddr::from_any(
+        // cspell:disable-next-line
+        "parsec3://test.invalid/Org?p=xCD7SjlysFv3d4mTkRu-ZddRjIZPGraSjUnoOHT9s8rmLA",
+    )
+    .unwrap();
+    LocalDevice::generate_new_device(
+        url,
+        UserProfile::Admin,
+        HumanHandle::from_raw("alice@dev1", "alice").unwrap(),
+        "alice label".parse().unwrap(),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+}
+
+fn password_alternative(password: &str) -> DeviceAlternativeProtection {
+    DeviceAlternativeProtection {
+        totp_protection: None,
+        primary_protection: DevicePrimaryProtectionStrategy::Password {
+            password: password.to_string().into(),
+        },
+    }
+}
+
+fn multi_access(
+    key_file: &Path,
+    alternatives: Vec<DeviceAlternativeProtection>,
+) -> DeviceAccessStrategy {
+    DeviceAccessStrategy {
+        key_file: key_file.to_owned(),
+        totp_protection: None,
+        primary_protection: DevicePrimaryProtectionStrategy::Multi { alternatives },
+    }
+}
+
+#[parsec_test]
+async fn save_load(tmp_path: TmpPath) {
+    let key_file = tmp_path.join("devices/multi_file.keys");
+    let device = generate_device();
+
+    // "Smartcard OR password OR (OpenBao AND TOTP)"
+    let pki = DeviceAlternativeProtection {
+        totp_protection: None,
+        primary_protection: DevicePrimaryProtectionStrategy::PKI {
+            operations: Arc::new(MockedPkiOperations::new(
+                X509CertificateHash::fake_sha256().into(),
+            )),
+        },
+    };
+    let totp_opaque_key_id = TOTPOpaqueKeyID::from_hex("8fdb73524fdd495194e877a5fafbe0a1").unwrap();
+    let openbao_operations = Arc::new(MockedOpenBaoOperations::new(
+        device.human_handle.email().to_owned(),
+    ));
+    let openbao_with_totp = DeviceAlternativeProtection {
+        totp_protection: Some((totp_opaque_key_id, SecretKey::generate())),
+        primary_protection: DevicePrimaryProtectionStrategy::OpenBao {
+            operations: openbao_operations.clone(),
+        },
+    };
+    let save_strategy = DeviceSaveStrategy {
+        totp_protection: None,
+        primary_protection: DevicePrimaryProtectionStrategy::Multi {
+            alternatives: vec![
+                pki.clone(),
+                password_alternative("P@ssw0rd."),
+                openbao_with_totp.clone(),
+            ],
+        },
+    };
+
+    device
+        .time_provider
+        .mock_time_frozen("2000-01-01T00:00:00Z".parse().unwrap());
+    let available_device = save_device(&tmp_path, &save_strategy, &device, key_file.clone())
+        .await
+        .unwrap();
+    device.time_provider.unmock_time();
+
+    let expected_ty = AvailableDeviceType::Multi {
+        protections: vec![
+            AvailableDeviceMultiProtection {
+                totp_opaque_key_id: None,
+                ty: AvailableDeviceMultiProtectionType::PKI {
+                    certificate_ref: X509CertificateHash::fake_sha256().into(),
+                },
+            },
+            AvailableDeviceMultiProtection {
+                totp_opaque_key_id: None,
+                ty: AvailableDeviceMultiProtectionType::Password,
+            },
+            AvailableDeviceMultiProtection {
+                totp_opaque_key_id: Some(totp_opaque_key_id),
+                ty: AvailableDeviceMultiProtectionType::OpenBao {
+                    openbao_entity_id: openbao_operations.openbao_entity_id().to_owned(),
+                    openbao_preferred_auth_id: openbao_operations
+                        .openbao_preferred_auth_id()
+                        .to_owned(),
+                },
+            },
+        ],
+    };
+    p_assert_eq!(available_device.ty, expected_ty);
+    p_assert_eq!(available_device.totp_opaque_key_id, None);
+    p_assert_eq!(
+        load_available_device(Path::new(""), key_file.clone())
+            .await
+            .unwrap(),
+        available_device
+    );
+
+    // Any single alternative is enough to load the device
+
+    for alternatives in [
+        vec![pki],
+        vec![password_alternative("P@ssw0rd.")],
+        vec![openbao_with_totp.clone()],
+        // Invalid alternatives are skipped
+        vec![password_alternative("dummy"), openbao_with_totp.clone()],
+    ] {
+        let res = load_device(Path::new(""), &multi_access(&key_file, alternatives))
+            .await
+            .unwrap();
+        p_assert_eq!(*res, device);
+    }
+
+    // OpenBao protection requires TOTP
+
+    let openbao_without_totp = DeviceAlternativeProtection {
+        totp_protection: None,
+        ..openbao_with_totp.clone()
+    };
+    p_assert_matches!(
+        load_device(
+            Path::new(""),
+            &multi_access(&key_file, vec![openbao_without_totp])
+        )
+        .await,
+        Err(LoadDeviceError::InvalidData)
+    );
+
+    let openbao_with_bad_totp = DeviceAlternativeProtection {
+        totp_protection: Some((totp_opaque_key_id, SecretKey::generate())),
+        ..openbao_with_totp
+    };
+    p_assert_matches!(
+        load_device(
+            Path::new(""),
+            &multi_access(&key_file, vec![openbao_with_bad_totp])
+        )
+        .await,
+        Err(LoadDeviceError::DecryptionFailed)
+    );
+
+    // Bad password
+
+    p_assert_matches!(
+        load_device(
+            Path::new(""),
+            &multi_access(&key_file, vec![password_alternative("dummy")])
+        )
+        .await,
+        Err(LoadDeviceError::DecryptionFailed)
+    );
+
+    // Single protection access strategy cannot be used on a multi-protected device
+
+    p_assert_matches!(
+        load_device(
+            Path::new(""),
+            &DeviceAccessStrategy::new_password(key_file.clone(), "P@ssw0rd.".to_string().into())
+        )
+        .await,
+        Err(LoadDeviceError::InvalidData)
+    );
+}
+
+#[parsec_test]
+async fn save_load_with_totp(tmp_path: TmpPath) {
+    let key_file = tmp_path.join("devices/multi_file.keys");
+    let device = generate_device();
+
+    // TOTP is required whatever the alternative
+    let totp_opaque_key_id = TOTPOpaqueKeyID::from_hex("8fdb73524fdd495194e877a5fafbe0a1").unwrap();
+    let totp_opaque_key = SecretKey::generate();
+    let save_strategy = DeviceSaveStrategy {
+        totp_protection: Some((totp_opaque_key_id, totp_opaque_key.clone())),
+        primary_protection: DevicePrimaryProtectionStrategy::Multi {
+            alternatives: vec![
+                password_alternative("P@ssw0rd."),
+                password_alternative("S3cr3t."),
+            ],
+        },
+    };
+
+    let available_device = save_device(&tmp_path, &save_strategy, &device, key_file.clone())
+        .await
+        .unwrap();
+    p_assert_eq!(
+        available_device.totp_opaque_key_id,
+        Some(totp_opaque_key_id)
+    );
+
+    let access = DeviceAccessStrategy {
+        totp_protection: Some((totp_opaque_key_id, totp_opaque_key)),
+        ..multi_access(&key_file, vec![password_alternative("S3cr3t.")])
+    };
+    let res = load_device(Path::new(""), &access).await.unwrap();
+    p_assert_eq!(*res, device);
+
+    p_assert_matches!(
+        load_device(
+            Path::new(""),
+            &DeviceAccessStrategy {
+                totp_protection: Some((totp_opaque_key_id, SecretKey::generate())),
+                ..access
+            }
+        )
+        .await,
+        Err(LoadDeviceError::TOTPDecryptionFailed)
+    );
+}
+
+#[parsec_test]
+#[case::keyring(DevicePrimaryProtectionStrategy::Keyring)]
+#[case::nested_multi(DevicePrimaryProtectionStrategy::Multi {
+    alternatives: vec![password_alternative("P@ssw0rd.")],
+})]
+async fn save_invalid_alternative(
+    #[case] primary_protection: DevicePrimaryProtectionStrategy,
+    tmp_path: TmpPath,
+) {
+    let key_file = tmp_path.join("devices/multi_file.keys");
+    let device = generate_device();
+
+    let save_strategy = DeviceSaveStrategy {
+        totp_protection: None,
+        primary_protection: DevicePrimaryProtectionStrategy::Multi {
+            alternatives: vec![
+                password_alternative("P@ssw0rd."),
+                DeviceAlternativeProtection {
+                    totp_protection: None,
+                    primary_protection,
+                },
+            ],
+        },
+    };
+
+    p_assert_matches!(
+        save_device(&tmp_path, &save_strategy, &device, key_file).await,
+        Err(SaveDeviceError::Internal(_))
+    );
+}
+
+#[parsec_test]
+async fn save_no_alternative(tmp_path: TmpPath) {
+    let key_file = tmp_path.join("devices/multi_file.keys");
+    let device = generate_device();
+
+    let save_strategy = DeviceSaveStrategy {
+        totp_protection: None,
+        primary_protection: DevicePrimaryProtectionStrategy::Multi {
+            alternatives: vec![],
+        },
+    };
+
+    p_assert_matches!(
+        save_device(&tmp_path, &save_strategy, &device, key_file).await,
+        Err(SaveDeviceError::Internal(_))
+    );
+}
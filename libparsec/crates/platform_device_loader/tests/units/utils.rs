@@ -1,13 +1,14 @@
 // Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
 
-use std::sync::Mutex;
+use std::{collections::HashMap, sync::Mutex};
 
 pub use libparsec_platform_async::PinBoxFutureResult;
 pub use libparsec_types::prelude::*;
 
 pub use crate::{
     AccountVaultOperations, AccountVaultOperationsFetchOpaqueKeyError,
-    AccountVaultOperationsUploadOpaqueKeyError, OpenBaoDeviceOperations,
+    AccountVaultOperationsUploadOpaqueKeyError, Fido2DeviceOperations,
+    Fido2OperationsGetHmacSecretError, Fido2OperationsMakeCredentialError, OpenBaoDeviceOperations,
     OpenBaoOperationsFetchOpaqueKeyError, OpenBaoOperationsUploadOpaqueKeyError,
     PkiDeviceOperations, PkiOperationsDecryptOpaqueKeyError, PkiOperationsEncryptOpaqueKeyError,
 };
@@ -297,3 +298,84 @@ impl OpenBaoDeviceOperations for MockedOpenBaoOperations {
         Box::pin(async move { outcome })
     }
 }
+
+/*
+ * MockedFido2Operations
+ */
+
+/// Software authenticator: credential secrets are kept in memory instead of
+/// a hardware security key.
+#[derive(Debug)]
+pub(super) struct MockedFido2Operations {
+    relying_party_id: String,
+    hmac_secret_supported: bool,
+    credentials: Mutex<HashMap<Bytes, SecretKey>>,
+}
+
+impl MockedFido2Operations {
+    pub fn new(relying_party_id: &str) -> Self {
+        Self {
+            relying_party_id: relying_party_id.to_owned(),
+            hmac_secret_supported: true,
+            credentials: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Authenticator not implementing the `hmac-secret` extension (e.g. U2F-only key)
+    pub fn new_without_hmac_secret(relying_party_id: &str) -> Self {
+        Self {
+            hmac_secret_supported: false,
+            ..Self::new(relying_party_id)
+        }
+    }
+}
+
+impl Fido2DeviceOperations for MockedFido2Operations {
+    fn relying_party_id(&self) -> &str {
+        &self.relying_party_id
+    }
+
+    fn make_credential(
+        &self,
+        _human_handle: &HumanHandle,
+    ) -> PinBoxFutureResult<Bytes, Fido2OperationsMakeCredentialError> {
+        if !self.hmac_secret_supported {
+            return Box::pin(async {
+                Err(Fido2OperationsMakeCredentialError::HmacSecretNotSupported)
+            });
+        }
+
+        let credential_id = Bytes::copy_from_slice(uuid::Uuid::new_v4().as_bytes());
+        self.credentials
+            .lock()
+            .expect("Mutex is poisoned")
+            .insert(credential_id.clone(), SecretKey::generate());
+
+        Box::pin(async move { Ok(credential_id) })
+    }
+
+    fn get_hmac_secret(
+        &self,
+        credential_id: &[u8],
+        salt: &[u8],
+    ) -> PinBoxFutureResult<SecretKey, Fido2OperationsGetHmacSecretError> {
+        let outcome = match self
+            .credentials
+            .lock()
+            .expect("Mutex is poisoned")
+            .get(credential_id)
+        {
+            // The actual hmac-secret extension uses HMAC-SHA-256, any keyed hash
+            // is fine for the mock.
+            Some(credential_secret) => {
+                let raw_key: [u8; 32] = credential_secret.mac_512(salt)[..32]
+                    .try_into()
+                    .expect("Valid size");
+                Ok(SecretKey::from(raw_key))
+            }
+            None => Err(Fido2OperationsGetHmacSecretError::UnknownCredential),
+        };
+
+        Box::pin(async move { outcome })
+    }
+}
@@ -5,7 +5,8 @@
 use std::{path::PathBuf, process::ExitCode, sync::Arc};
 
 use libparsec_client::{
-    Client, ClientConfig, EventBus, MountpointMountStrategy, ProxyConfig, WorkspaceStorageCacheSize,
+    Client, ClientConfig, EventBus, MountpointMountStrategy, OutboundSyncConfig, ProxyConfig,
+    WorkspaceStorageCacheSize,
 };
 use libparsec_platform_mountpoint::Mountpoint;
 use libparsec_tests_fixtures::prelude::*;
@@ -87,6 +88,8 @@ async fn main() -> ExitCode {
                 mountpoint_mount_strategy,
                 workspace_storage_cache_size: WorkspaceStorageCacheSize::Default,
                 proxy: ProxyConfig::default(),
+                outbound_sync: OutboundSyncConfig::default(),
+                pki_revocation_policy: Default::default(),
                 with_monitors: false,
                 prevent_sync_pattern: PreventSyncPattern::empty(),
             });
@@ -9,7 +9,7 @@ use std::{
 };
 
 use libparsec_client::{
-    ClientConfig, MountpointMountStrategy, ProxyConfig, WorkspaceHistoryOps,
+    ClientConfig, MountpointMountStrategy, OutboundSyncConfig, ProxyConfig, WorkspaceHistoryOps,
     WorkspaceHistoryRealmExportDecryptor, WorkspaceStorageCacheSize,
 };
 use libparsec_platform_mountpoint::Mountpoint;
@@ -133,6 +133,8 @@ async fn main() -> ExitCode {
                 mountpoint_mount_strategy,
                 workspace_storage_cache_size: WorkspaceStorageCacheSize::Default,
                 proxy: ProxyConfig::default(),
+                outbound_sync: OutboundSyncConfig::default(),
+                pki_revocation_policy: Default::default(),
                 with_monitors: false,
                 prevent_sync_pattern: PreventSyncPattern::empty(),
             });
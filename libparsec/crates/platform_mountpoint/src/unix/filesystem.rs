@@ -40,6 +40,15 @@ const READ_WRITE_PERMISSIONS: u16 = 0o700;
 /// Read-only permissions for files and folders.
 /// Equivalent to `chmod` flags `all=,u=rx`.
 const READ_ONLY_PERMISSIONS: u16 = 0o500;
+/// Unlike on Windows (see `FILE_ATTRIBUTE_OFFLINE`), there is no standard way to
+/// flag a file as a placeholder, so entries excluded from selective sync (i.e.
+/// whose data is fetched from the server on read) expose this extended attribute.
+const REMOTE_ONLY_XATTR_NAME: &str = "user.parsec.remote_only";
+const REMOTE_ONLY_XATTR_VALUE: &[u8] = b"1";
+#[cfg(target_os = "macos")]
+const NO_XATTR_ERRNO: i32 = libc::ENOATTR;
+#[cfg(not(target_os = "macos"))]
+const NO_XATTR_ERRNO: i32 = libc::ENODATA;
 
 fn os_name_to_entry_name(name: &OsStr) -> EntryNameResult<EntryName> {
     name.to_str()
@@ -1713,6 +1722,66 @@ impl fuser::Filesystem for Filesystem {
         reply.manual().ok();
     }
 
+    fn getxattr(
+        &mut self,
+        _req: &fuser::Request<'_>,
+        ino: u64,
+        name: &OsStr,
+        size: u32,
+        reply: fuser::ReplyXattr,
+    ) {
+        log::debug!("[FUSE] getxattr(ino: {ino:#x?}, name: {name:?}, size: {size})");
+        let reply = reply_on_drop_guard!(reply, fuser::ReplyXattr);
+
+        if name != REMOTE_ONLY_XATTR_NAME {
+            reply.manual().error(NO_XATTR_ERRNO);
+            return;
+        }
+
+        let path = self
+            .inodes
+            .lock()
+            .expect("mutex is poisoned")
+            .get_path_or_panic(ino);
+        let ops = self.ops.clone();
+        self.tokio_handle.spawn(async move {
+            match is_remote_only_from_path(&ops, path).await {
+                Ok(true) => reply_xattr(reply.manual(), size, REMOTE_ONLY_XATTR_VALUE),
+                Ok(false) => reply.manual().error(NO_XATTR_ERRNO),
+                Err(errno) => reply.manual().error(errno),
+            }
+        });
+    }
+
+    fn listxattr(
+        &mut self,
+        _req: &fuser::Request<'_>,
+        ino: u64,
+        size: u32,
+        reply: fuser::ReplyXattr,
+    ) {
+        log::debug!("[FUSE] listxattr(ino: {ino:#x?}, size: {size})");
+        let reply = reply_on_drop_guard!(reply, fuser::ReplyXattr);
+
+        let path = self
+            .inodes
+            .lock()
+            .expect("mutex is poisoned")
+            .get_path_or_panic(ino);
+        let ops = self.ops.clone();
+        self.tokio_handle.spawn(async move {
+            match is_remote_only_from_path(&ops, path).await {
+                // Names are provided as a list of null-terminated strings
+                Ok(true) => {
+                    let names = format!("{REMOTE_ONLY_XATTR_NAME}\0");
+                    reply_xattr(reply.manual(), size, names.as_bytes())
+                }
+                Ok(false) => reply_xattr(reply.manual(), size, b""),
+                Err(errno) => reply.manual().error(errno),
+            }
+        });
+    }
+
     // TODO: Fuser exposes a `lseek` method to support SEEK_HOLE & SEEK_DATA.
     //       This is an optimisation for filesystem that don't store zero blocks
     //       (which Parsec does !).
@@ -1728,6 +1797,38 @@ impl fuser::Filesystem for Filesystem {
     //       would speed up file copy a lot by reusing the same blocks !
 }
 
+/// A zero `size` means the caller only wants to know the size of the data.
+fn reply_xattr(reply: fuser::ReplyXattr, size: u32, data: &[u8]) {
+    let data_len = data.len() as u32;
+    if size == 0 {
+        reply.size(data_len)
+    } else if size < data_len {
+        reply.error(libc::ERANGE)
+    } else {
+        reply.data(data)
+    }
+}
+
+async fn is_remote_only_from_path(ops: &WorkspaceOps, path: FsPath) -> Result<bool, i32> {
+    let entry_id = match ops.stat_entry(&path).await {
+        Ok(stat) => stat.id(),
+        Err(WorkspaceStatEntryError::EntryNotFound) => return Err(libc::ENOENT),
+        Err(WorkspaceStatEntryError::Offline(_)) => return Err(libc::EHOSTUNREACH),
+        Err(WorkspaceStatEntryError::NoRealmAccess) => return Err(libc::EPERM),
+        Err(err) => {
+            log::warn!("FUSE `is_remote_only_from_path` operation cannot complete: {err:?}");
+            return Err(libc::EIO);
+        }
+    };
+
+    // Failing to determine the selective sync status is not a big deal: the
+    // entry is then simply not displayed as a placeholder.
+    Ok(ops
+        .is_entry_remote_only_by_id(entry_id)
+        .await
+        .unwrap_or(false))
+}
+
 async fn getattr_from_path(
     ops: &WorkspaceOps,
     path: FsPath,
@@ -118,9 +118,22 @@ fn parsec_file_stat_to_winfsp_file_info(stat: &FileStat) -> FileInfo {
         .set_allocation_size(stat.size)
 }
 
-fn parsec_entry_stat_to_winfsp_file_info(stat: &EntryStat, is_read_only: bool) -> FileInfo {
+fn parsec_entry_stat_to_winfsp_file_info(
+    stat: &EntryStat,
+    is_read_only: bool,
+    is_remote_only: bool,
+) -> FileInfo {
     // TODO: consider using FILE_ATTRIBUTE_RECALL_ON_DATA_ACCESS/FILE_ATTRIBUTE_RECALL_ON_OPEN ?
     // (see https://docs.microsoft.com/en-us/windows/desktop/fileio/file-attribute-constants)
+    // Entries excluded from selective sync are displayed as placeholders: their data
+    // is not available locally and must be fetched from the server on read.
+    let with_remote_only = |attributes: FileAttributes| {
+        if is_remote_only {
+            attributes | FileAttributes::OFFLINE
+        } else {
+            attributes
+        }
+    };
     match stat {
         EntryStat::File {
             id,
@@ -136,13 +149,13 @@ fn parsec_entry_stat_to_winfsp_file_info(stat: &EntryStat, is_read_only: bool) -
                 // This way, we don't need to deal with the weird semantics of
                 // FILE_ATTRIBUTE_NORMAL which means "no other attributes is set"
                 // Also, this is what the winfsp memfs does.
-                .set_file_attributes(if is_read_only {
+                .set_file_attributes(with_remote_only(if is_read_only {
                     FileAttributes::ARCHIVE
                         | FileAttributes::NOT_CONTENT_INDEXED
                         | FileAttributes::READONLY
                 } else {
                     FileAttributes::ARCHIVE | FileAttributes::NOT_CONTENT_INDEXED
-                })
+                }))
                 .set_creation_time(created)
                 .set_last_access_time(updated)
                 .set_last_write_time(updated)
@@ -164,9 +177,9 @@ fn parsec_entry_stat_to_winfsp_file_info(stat: &EntryStat, is_read_only: bool) -
             let created = filetime_from_utc((*created).into());
             let updated = filetime_from_utc((*updated).into());
             *FileInfo::default()
-                .set_file_attributes(
+                .set_file_attributes(with_remote_only(
                     FileAttributes::DIRECTORY | FileAttributes::NOT_CONTENT_INDEXED,
-                )
+                ))
                 .set_creation_time(created)
                 .set_last_access_time(updated)
                 .set_last_write_time(updated)
@@ -211,6 +224,17 @@ impl ParsecFileSystemInterface {
 }
 
 impl ParsecFileSystemInterface {
+    async fn entry_stat_to_winfsp_file_info(&self, stat: &EntryStat) -> FileInfo {
+        // Failing to determine the selective sync status is not a big deal: the
+        // entry is then simply not displayed as a placeholder.
+        let is_remote_only = self
+            .ops
+            .is_entry_remote_only_by_id(stat.id())
+            .await
+            .unwrap_or(false);
+        parsec_entry_stat_to_winfsp_file_info(stat, self.is_read_only, is_remote_only)
+    }
+
     async fn get_file_info_async(
         &self,
         file_context: &OpenedObj,
@@ -242,10 +266,7 @@ impl ParsecFileSystemInterface {
                     .stat_entry_by_id_ignore_confinement_point(*id)
                     .await;
                 match outcome {
-                    Ok(stat) => Ok(parsec_entry_stat_to_winfsp_file_info(
-                        &stat,
-                        self.is_read_only,
-                    )),
+                    Ok(stat) => Ok(self.entry_stat_to_winfsp_file_info(&stat).await),
                     Err(err) => Err(match err {
                         WorkspaceStatEntryError::EntryNotFound => STATUS_OBJECT_NAME_NOT_FOUND,
                         WorkspaceStatEntryError::Offline(_) => STATUS_HOST_UNREACHABLE,
@@ -311,7 +332,9 @@ impl FileSystemInterface for ParsecFileSystemInterface {
 
         self.tokio_handle.block_on(async move {
             let file_attributes = match self.ops.stat_entry(&path).await {
-                Ok(stat) => parsec_entry_stat_to_winfsp_file_info(&stat, self.is_read_only)
+                Ok(stat) => self
+                    .entry_stat_to_winfsp_file_info(&stat)
+                    .await
                     .file_attributes(),
                 Err(err) => {
                     return Err(match err {
@@ -1108,7 +1131,7 @@ impl FileSystemInterface for ParsecFileSystemInterface {
                     let directory_stat = reader.stat_folder();
 
                     if !add_dir_info(DirInfo::new(
-                        parsec_entry_stat_to_winfsp_file_info(&directory_stat, self.is_read_only),
+                        self.entry_stat_to_winfsp_file_info(&directory_stat).await,
                         u16cstr!("."),
                     )) {
                         return Ok(());
@@ -1142,7 +1165,7 @@ impl FileSystemInterface for ParsecFileSystemInterface {
                         })?;
 
                     if !add_dir_info(DirInfo::new(
-                        parsec_entry_stat_to_winfsp_file_info(&parent_stat, self.is_read_only),
+                        self.entry_stat_to_winfsp_file_info(&parent_stat).await,
                         u16cstr!(".."),
                     )) {
                         return Ok(());
@@ -1206,7 +1229,7 @@ impl FileSystemInterface for ParsecFileSystemInterface {
                 let winified_child_name = winify_entry_name(child_name);
 
                 if !add_dir_info(DirInfo::from_str(
-                    parsec_entry_stat_to_winfsp_file_info(&child_stat, self.is_read_only),
+                    self.entry_stat_to_winfsp_file_info(&child_stat).await,
                     &winified_child_name,
                 )) {
                     break;
@@ -1268,10 +1291,7 @@ impl FileSystemInterface for ParsecFileSystemInterface {
                     }
                 })?;
 
-            Ok(parsec_entry_stat_to_winfsp_file_info(
-                &stat,
-                self.is_read_only,
-            ))
+            Ok(self.entry_stat_to_winfsp_file_info(&stat).await)
         })
     }
 }
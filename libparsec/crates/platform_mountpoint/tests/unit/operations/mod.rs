@@ -24,6 +24,8 @@ mod linux_fusermount;
 mod unix_clean_mountpoint_base_dir;
 #[cfg(target_family = "unix")]
 mod unix_permissions;
+#[cfg(target_family = "unix")]
+mod unix_xattr;
 #[cfg(target_os = "windows")]
 mod windows_drive_mount;
 #[cfg(target_os = "windows")]
@@ -1,8 +1,105 @@
 // Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
 
-// TODO: Not supported but must test the behavior
+use std::{ffi::CString, os::unix::ffi::OsStrExt, path::Path, path::PathBuf, sync::Arc};
 
-// setxattr
-// getxattr
-// listxattr
-// removexattr
+use libparsec_client::{workspace::WorkspaceOps, Client};
+use libparsec_tests_fixtures::prelude::*;
+use libparsec_types::prelude::*;
+
+use super::utils::mount_and_test;
+
+// TODO: setxattr & removexattr are not supported but must test the behavior
+
+const REMOTE_ONLY_XATTR_NAME: &str = "user.parsec.remote_only";
+#[cfg(target_os = "macos")]
+const NO_XATTR_ERRNO: i32 = libc::ENOATTR;
+#[cfg(not(target_os = "macos"))]
+const NO_XATTR_ERRNO: i32 = libc::ENODATA;
+
+fn getxattr(path: &Path, name: &str) -> Result<Vec<u8>, std::io::Error> {
+    let path = CString::new(path.as_os_str().as_bytes()).unwrap();
+    let name = CString::new(name).unwrap();
+    let mut buf = [0u8; 64];
+    // SAFETY: `path` and `name` are valid null-terminated strings, and `buf` is
+    // big enough for the provided size
+    #[cfg(target_os = "macos")]
+    let ret = unsafe {
+        libc::getxattr(
+            path.as_ptr(),
+            name.as_ptr(),
+            buf.as_mut_ptr().cast(),
+            buf.len(),
+            0,
+            0,
+        )
+    };
+    // SAFETY: see above
+    #[cfg(not(target_os = "macos"))]
+    let ret = unsafe {
+        libc::getxattr(
+            path.as_ptr(),
+            name.as_ptr(),
+            buf.as_mut_ptr().cast(),
+            buf.len(),
+        )
+    };
+    if ret < 0 {
+        Err(std::io::Error::last_os_error())
+    } else {
+        Ok(buf[..ret as usize].to_vec())
+    }
+}
+
+fn listxattr(path: &Path) -> Vec<u8> {
+    let path = CString::new(path.as_os_str().as_bytes()).unwrap();
+    let mut buf = [0u8; 64];
+    // SAFETY: `path` is a valid null-terminated string, and `buf` is big enough
+    // for the provided size
+    #[cfg(target_os = "macos")]
+    let ret = unsafe { libc::listxattr(path.as_ptr(), buf.as_mut_ptr().cast(), buf.len(), 0) };
+    // SAFETY: see above
+    #[cfg(not(target_os = "macos"))]
+    let ret = unsafe { libc::listxattr(path.as_ptr(), buf.as_mut_ptr().cast(), buf.len()) };
+    assert!(ret >= 0, "{:?}", std::io::Error::last_os_error());
+    buf[..ret as usize].to_vec()
+}
+
+#[parsec_test(testbed = "minimal_client_ready")]
+async fn remote_only_placeholder(tmp_path: TmpPath, env: &TestbedEnv) {
+    let wksp1_foo_id: VlobID = *env.template.get_stuff("wksp1_foo_id");
+
+    mount_and_test!(
+        env,
+        &tmp_path,
+        |_client: Arc<Client>, wksp1_ops: Arc<WorkspaceOps>, mountpoint_path: PathBuf| async move {
+            wksp1_ops
+                .set_remote_only_entries(&[wksp1_foo_id])
+                .await
+                .unwrap();
+
+            tokio::task::spawn_blocking(move || {
+                // Remote only entry and its children are placeholders...
+                for path in [
+                    mountpoint_path.join("foo"),
+                    mountpoint_path.join("foo/egg.txt"),
+                ] {
+                    p_assert_eq!(getxattr(&path, REMOTE_ONLY_XATTR_NAME).unwrap(), b"1");
+                    p_assert_eq!(
+                        listxattr(&path),
+                        format!("{REMOTE_ONLY_XATTR_NAME}\0").as_bytes()
+                    );
+                }
+
+                // ...but not the other entries
+                let path = mountpoint_path.join("bar.txt");
+                p_assert_matches!(
+                    getxattr(&path, REMOTE_ONLY_XATTR_NAME),
+                    Err(err) if err.raw_os_error() == Some(NO_XATTR_ERRNO)
+                );
+                p_assert_eq!(listxattr(&path), b"");
+            })
+            .await
+            .unwrap();
+        }
+    );
+}
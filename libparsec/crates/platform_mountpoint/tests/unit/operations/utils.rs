@@ -3,7 +3,8 @@
 use std::sync::Arc;
 
 use libparsec_client::{
-    Client, ClientConfig, EventBus, MountpointMountStrategy, ProxyConfig, WorkspaceStorageCacheSize,
+    Client, ClientConfig, EventBus, MountpointMountStrategy, OutboundSyncConfig, ProxyConfig,
+    WorkspaceStorageCacheSize,
 };
 use libparsec_tests_fixtures::prelude::*;
 use libparsec_types::PreventSyncPattern;
@@ -34,6 +35,8 @@ pub async fn start_client_with_mountpoint_base_dir(
         },
         workspace_storage_cache_size: WorkspaceStorageCacheSize::Default,
         proxy: ProxyConfig::default(),
+        outbound_sync: OutboundSyncConfig::default(),
+        pki_revocation_policy: Default::default(),
         with_monitors: false,
         prevent_sync_pattern: PreventSyncPattern::empty(),
     });
@@ -3,7 +3,8 @@
 use std::sync::Arc;
 
 use libparsec_client::{
-    Client, ClientConfig, EventBus, MountpointMountStrategy, ProxyConfig, WorkspaceStorageCacheSize,
+    Client, ClientConfig, EventBus, MountpointMountStrategy, OutboundSyncConfig, ProxyConfig,
+    WorkspaceStorageCacheSize,
 };
 use libparsec_tests_fixtures::prelude::*;
 use libparsec_types::prelude::*;
@@ -22,6 +23,8 @@ async fn mount_on_drive(env: &TestbedEnv) {
         mountpoint_mount_strategy: MountpointMountStrategy::DriveLetter,
         workspace_storage_cache_size: WorkspaceStorageCacheSize::Default,
         proxy: ProxyConfig::default(),
+        outbound_sync: OutboundSyncConfig::default(),
+        pki_revocation_policy: Default::default(),
         with_monitors: false,
         prevent_sync_pattern: PreventSyncPattern::empty(),
     });
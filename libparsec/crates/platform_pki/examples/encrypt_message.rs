@@ -5,8 +5,8 @@ mod utils;
 
 use anyhow::Context;
 use clap::Parser;
-use libparsec_platform_pki::{encrypt_message, PkiSystem};
-use libparsec_types::X509CertificateHash;
+use libparsec_platform_pki::{encrypt_message_with_algorithm, PkiSystem};
+use libparsec_types::{PKIEncryptionAlgorithm, X509CertificateHash};
 
 #[derive(Debug, Parser)]
 struct Args {
@@ -15,6 +15,9 @@ struct Args {
     certificate_hash: X509CertificateHash,
     #[command(flatten)]
     content: utils::ContentOpts,
+    /// The algorithm to use to encrypt the content.
+    #[arg(long, default_value_t = PKIEncryptionAlgorithm::RsaesOaepSha256)]
+    algorithm: PKIEncryptionAlgorithm,
 }
 
 #[tokio::main(flavor = "current_thread")]
@@ -36,7 +39,7 @@ async fn main() -> anyhow::Result<()> {
         .context("Failed to find certificate")?;
 
     let der = cert.get_der().await.context("Failed to get DER")?;
-    let (algo, ciphered) = encrypt_message(der, &data)
+    let (algo, ciphered) = encrypt_message_with_algorithm(der, args.algorithm, &data)
         .await
         .context("Failed to encrypt message")?;
 
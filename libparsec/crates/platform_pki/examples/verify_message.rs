@@ -6,7 +6,7 @@ mod utils;
 use anyhow::Context;
 use bytes::Bytes;
 use clap::Parser;
-use libparsec_platform_pki::{verify_message, PkiSystem};
+use libparsec_platform_pki::{verify_message, PkiSystem, X509RevocationConfig};
 use libparsec_types::{DateTime, PkiSignatureAlgorithm, X509CertificateHash};
 
 #[derive(Debug, Parser)]
@@ -54,6 +54,7 @@ async fn main() -> anyhow::Result<()> {
         path.leaf.as_ref(),
         path.intermediates.iter().map(|c| c.as_ref()),
         &[path.root],
+        &X509RevocationConfig::default(),
         DateTime::now(),
     ) {
         Ok(_) => println!("The message has a correct signature"),
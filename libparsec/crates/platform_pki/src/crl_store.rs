@@ -0,0 +1,232 @@
+// Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::{Path, PathBuf};
+use std::{collections::HashMap, sync::Mutex};
+
+#[cfg(not(target_arch = "wasm32"))]
+use sha2::Digest as _;
+use x509_cert::der::Decode;
+
+use libparsec_types::prelude::*;
+
+use crate::{x509::X509CertificateInformation, X509RevocationConfig, X509RevocationPolicy};
+
+/// Directory containing CRL files (PEM or DER) provided by the administrator,
+/// defaults to `<config_dir>/crl`.
+pub const PARSEC_PKI_CRL_DIR: &str = "PARSEC_PKI_CRL_DIR";
+
+#[cfg(not(target_arch = "wasm32"))]
+const CRL_FETCH_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+
+/// A distribution point that couldn't be reached is not requested again before
+/// this delay (otherwise each verification would wait for the fetch timeout).
+fn crl_fetch_failure_backoff() -> Duration {
+    Duration::minutes(5)
+}
+
+/// Gather the CRLs needed to check the revocation of a certificate trustchain.
+///
+/// CRLs come from:
+/// - The CRLs provided by the caller (typically the ones from the organization config).
+/// - The CRL directory (see `PARSEC_PKI_CRL_DIR`).
+/// - The CRL distribution points listed in the certificates. Fetched CRLs are
+///   cached on disk and reused until outdated, a stale CRL being still preferred
+///   over no CRL at all if the distribution point cannot be reached (e.g. offline).
+#[derive(Debug)]
+pub(crate) struct X509CrlStore {
+    #[cfg(not(target_arch = "wasm32"))]
+    crl_dir: PathBuf,
+    #[cfg(not(target_arch = "wasm32"))]
+    cache_dir: PathBuf,
+    /// Lazily built, then shared by all the fetches.
+    http_client: Mutex<Option<reqwest::Client>>,
+    /// Distribution point URL -> when its last fetch has failed
+    pub(crate) failed_fetches: Mutex<HashMap<String, DateTime>>,
+}
+
+impl X509CrlStore {
+    pub fn new(config_dir: &std::path::Path) -> Self {
+        #[cfg(target_arch = "wasm32")]
+        let _ = config_dir;
+
+        Self {
+            #[cfg(not(target_arch = "wasm32"))]
+            crl_dir: match std::env::var_os(PARSEC_PKI_CRL_DIR) {
+                Some(dir) => dir.into(),
+                None => config_dir.join("crl"),
+            },
+            #[cfg(not(target_arch = "wasm32"))]
+            cache_dir: config_dir.join("crl-cache"),
+            http_client: Mutex::default(),
+            failed_fetches: Mutex::default(),
+        }
+    }
+
+    pub async fn revocation_config<'a>(
+        &self,
+        policy: X509RevocationPolicy,
+        extra_crls: impl Iterator<Item = &'a [u8]>,
+        certificates: impl Iterator<Item = &'a [u8]>,
+    ) -> X509RevocationConfig {
+        let mut config = X509RevocationConfig::new(policy);
+
+        for raw in extra_crls {
+            if let Err(err) = config.add_crls(raw) {
+                log::warn!("Ignoring invalid CRL: {err}");
+            }
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        for raw in load_crl_dir(&self.crl_dir) {
+            if let Err(err) = config.add_crls(&raw) {
+                log::warn!("Ignoring invalid CRL file: {err}");
+            }
+        }
+
+        let mut urls = certificates
+            .filter_map(|der| X509CertificateInformation::load_der(der).ok())
+            .flat_map(|info| info.extensions.crl_distribution_points)
+            .filter(|url| url.starts_with("http://") || url.starts_with("https://"))
+            .collect::<Vec<_>>();
+        urls.sort();
+        urls.dedup();
+
+        for url in urls {
+            let Some(raw) = self.get_distribution_point_crl(&url).await else {
+                continue;
+            };
+            if let Err(err) = config.add_crls(&raw) {
+                log::warn!("Ignoring invalid CRL from {url}: {err}");
+            }
+        }
+
+        config
+    }
+
+    pub(crate) async fn get_distribution_point_crl(&self, url: &str) -> Option<Vec<u8>> {
+        #[cfg(not(target_arch = "wasm32"))]
+        let cache_path = self
+            .cache_dir
+            .join(format!("{:x}.crl", sha2::Sha256::digest(url.as_bytes())));
+        #[cfg(not(target_arch = "wasm32"))]
+        let cached = std::fs::read(&cache_path).ok();
+        #[cfg(target_arch = "wasm32")]
+        let cached: Option<Vec<u8>> = None;
+
+        if let Some(cached) = &cached {
+            if !is_outdated(cached, DateTime::now()) {
+                return Some(cached.clone());
+            }
+        }
+
+        let now = DateTime::now();
+        let recently_failed = self
+            .failed_fetches
+            .lock()
+            .expect("Mutex is poisoned")
+            .get(url)
+            .is_some_and(|failed_on| now < *failed_on + crl_fetch_failure_backoff());
+        if recently_failed {
+            log::debug!("Skipping CRL fetch from {url} given it has recently failed");
+            return cached;
+        }
+
+        match self.fetch_crl(url).await {
+            Ok(raw) => {
+                self.failed_fetches
+                    .lock()
+                    .expect("Mutex is poisoned")
+                    .remove(url);
+                #[cfg(not(target_arch = "wasm32"))]
+                if let Err(err) = std::fs::create_dir_all(&self.cache_dir)
+                    .and_then(|_| std::fs::write(&cache_path, &raw))
+                {
+                    log::warn!("Cannot cache CRL from {url}: {err}");
+                }
+                Some(raw)
+            }
+            Err(err) => {
+                self.failed_fetches
+                    .lock()
+                    .expect("Mutex is poisoned")
+                    .insert(url.to_owned(), now);
+                if cached.is_some() {
+                    log::warn!("Cannot fetch CRL from {url}, using outdated cached one: {err:#}");
+                } else {
+                    log::warn!("Cannot fetch CRL from {url}: {err:#}");
+                }
+                cached
+            }
+        }
+    }
+
+    async fn fetch_crl(&self, url: &str) -> anyhow::Result<Vec<u8>> {
+        let client = {
+            let mut guard = self.http_client.lock().expect("Mutex is poisoned");
+            match &*guard {
+                Some(client) => client.clone(),
+                None => {
+                    let proxy = libparsec_platform_http_proxy::ProxyConfig::new_from_env()?;
+                    let builder = proxy.configure_http_client(reqwest::ClientBuilder::default());
+                    #[cfg(not(target_arch = "wasm32"))]
+                    let builder = builder.timeout(CRL_FETCH_TIMEOUT);
+                    let client = builder.build()?;
+                    *guard = Some(client.clone());
+                    client
+                }
+            }
+        };
+
+        let rep = client.get(url).send().await?.error_for_status()?;
+        Ok(rep.bytes().await?.to_vec())
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn load_crl_dir(dir: &Path) -> Vec<Vec<u8>> {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return vec![],
+        Err(err) => {
+            log::warn!("Cannot read CRL directory {}: {err}", dir.display());
+            return vec![];
+        }
+    };
+
+    let mut paths = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.is_file())
+        .collect::<Vec<_>>();
+    paths.sort();
+
+    paths
+        .into_iter()
+        .filter_map(|path| {
+            std::fs::read(&path)
+                .inspect_err(|err| log::warn!("Cannot read CRL file {}: {err}", path.display()))
+                .ok()
+        })
+        .collect()
+}
+
+/// A CRL without `nextUpdate` never gets outdated.
+/// Note invalid CRLs are considered outdated so that they get fetched again.
+pub(crate) fn is_outdated(raw: &[u8], now: DateTime) -> bool {
+    let ders = match crate::parse_crl_ders(raw) {
+        Ok(ders) => ders,
+        Err(_) => return true,
+    };
+    let now = now
+        .duration_since_unix_epoch()
+        .expect("current time always > EPOCH");
+
+    ders.iter()
+        .any(|der| match x509_cert::crl::CertificateList::from_der(der) {
+            Ok(crl) => crl
+                .tbs_cert_list
+                .next_update
+                .is_some_and(|next_update| next_update.to_unix_duration() <= now),
+            Err(_) => true,
+        })
+}
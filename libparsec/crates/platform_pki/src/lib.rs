@@ -15,10 +15,12 @@ mod shared;
 pub(crate) mod testbed;
 pub mod x509;
 
+mod crl_store;
 mod pki_certificate;
 mod pki_private_key;
 mod pki_system;
 
+pub use crl_store::PARSEC_PKI_CRL_DIR;
 pub use pki_certificate::*;
 pub use pki_private_key::*;
 pub use pki_system::*;
@@ -2,7 +2,10 @@
 
 use libparsec_types::prelude::*;
 
-use crate::{PkiCertificate, UserX509CertificateDetails, UserX509CertificateLoadError};
+use crate::{
+    crl_store::X509CrlStore, PkiCertificate, UserX509CertificateDetails,
+    UserX509CertificateLoadError, X509RevocationConfig, X509RevocationPolicy,
+};
 
 #[derive(Debug, thiserror::Error)]
 pub enum PkiSystemInitError {
@@ -86,6 +89,7 @@ pub struct PkiSystem {
         crate::platform::PlatformPkiSystem,
         crate::testbed::TestbedPkiSystem,
     >,
+    crl_store: X509CrlStore,
 }
 
 impl PkiSystem {
@@ -93,6 +97,8 @@ impl PkiSystem {
         config_dir: &std::path::Path,
         scws_config: Option<PkiScwsConfig>,
     ) -> Result<Self, PkiSystemInitError> {
+        let crl_store = X509CrlStore::new(config_dir);
+
         #[cfg(feature = "test-with-testbed")]
         let platform = {
             if let Some(testbed) = crate::testbed::maybe_init_testbed(config_dir) {
@@ -107,7 +113,27 @@ impl PkiSystem {
         #[cfg(not(feature = "test-with-testbed"))]
         let platform = { crate::platform::PlatformPkiSystem::init(config_dir, scws_config).await? };
 
-        Ok(Self { platform })
+        Ok(Self {
+            platform,
+            crl_store,
+        })
+    }
+
+    /// Gather the CRLs needed to check the revocation of `certificates` (typically
+    /// a peer's certificate along with its intermediate certificates), to be passed
+    /// to [`crate::verify_message`].
+    ///
+    /// `extra_crls` are CRLs (PEM or DER) provided by the caller, typically the
+    /// ones from the organization config.
+    pub async fn revocation_config<'a>(
+        &self,
+        policy: X509RevocationPolicy,
+        extra_crls: impl Iterator<Item = &'a [u8]>,
+        certificates: impl Iterator<Item = &'a [u8]>,
+    ) -> X509RevocationConfig {
+        self.crl_store
+            .revocation_config(policy, extra_crls, certificates)
+            .await
     }
 
     pub async fn open_certificate(
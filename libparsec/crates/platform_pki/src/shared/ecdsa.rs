@@ -0,0 +1,59 @@
+// Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+use libparsec_types::prelude::*;
+
+/// Curve OIDs (DER encoded) as found in PKCS#11 `CKA_EC_PARAMS` attribute.
+const P256_OID_DER: &[u8] = &[0x06, 0x08, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x03, 0x01, 0x07];
+const P384_OID_DER: &[u8] = &[0x06, 0x05, 0x2b, 0x81, 0x04, 0x00, 0x22];
+
+#[cfg_attr(
+    not(all(target_family = "unix", not(target_arch = "wasm32"))),
+    expect(dead_code)
+)]
+pub(crate) fn ecdsa_signature_algorithm_from_curve_oid(
+    oid_der: &[u8],
+) -> Option<PkiSignatureAlgorithm> {
+    match oid_der {
+        P256_OID_DER => Some(PkiSignatureAlgorithm::EcdsaP256Sha256),
+        P384_OID_DER => Some(PkiSignatureAlgorithm::EcdsaP384Sha384),
+        _ => None,
+    }
+}
+
+/// Hash the message with the hash function used by the ECDSA signature algorithm.
+///
+/// Note this panics if `algorithm` is not an ECDSA algorithm.
+#[cfg_attr(target_arch = "wasm32", expect(dead_code))]
+pub(crate) fn ecdsa_hash_message(algorithm: PkiSignatureAlgorithm, message: &[u8]) -> Vec<u8> {
+    use sha2::Digest as _;
+
+    match algorithm {
+        PkiSignatureAlgorithm::EcdsaP256Sha256 => sha2::Sha256::digest(message).to_vec(),
+        PkiSignatureAlgorithm::EcdsaP384Sha384 => sha2::Sha384::digest(message).to_vec(),
+        PkiSignatureAlgorithm::RsassaPssSha256 => unreachable!("Not an ECDSA algorithm"),
+    }
+}
+
+/// PKCS#11 tokens and Windows CNG both output ECDSA signatures in raw form (i.e.
+/// `r || s`, each integer being padded to the curve size), while X509/`webpki`
+/// expects them ASN.1 DER encoded (i.e. `SEQUENCE { r INTEGER, s INTEGER }`).
+///
+/// Note this panics if `algorithm` is not an ECDSA algorithm.
+#[cfg_attr(target_arch = "wasm32", expect(dead_code))]
+pub(crate) fn ecdsa_signature_raw_to_der(
+    algorithm: PkiSignatureAlgorithm,
+    raw: &[u8],
+) -> anyhow::Result<Vec<u8>> {
+    let invalid_signature = |err: p256::ecdsa::Error| {
+        anyhow::anyhow!("Invalid raw ECDSA signature ({} bytes): {err}", raw.len())
+    };
+    match algorithm {
+        PkiSignatureAlgorithm::EcdsaP256Sha256 => p256::ecdsa::Signature::from_slice(raw)
+            .map(|signature| signature.to_der().as_bytes().to_vec())
+            .map_err(invalid_signature),
+        PkiSignatureAlgorithm::EcdsaP384Sha384 => p384::ecdsa::Signature::from_slice(raw)
+            .map(|signature| signature.to_der().as_bytes().to_vec())
+            .map_err(invalid_signature),
+        PkiSignatureAlgorithm::RsassaPssSha256 => unreachable!("Not an ECDSA algorithm"),
+    }
+}
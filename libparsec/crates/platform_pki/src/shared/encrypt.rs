@@ -14,20 +14,97 @@ pub enum EncryptMessageError {
     CannotEncrypt(#[from] rsa::errors::Error),
 }
 
+/// Encrypt a message with the certificate's public key using RSA-OAEP.
+///
+/// Only RSA certificates are supported: EC certificates can be used to sign
+/// (e.g. an async enrollment request) but not to encrypt, hence they cannot
+/// protect a `DeviceFilePKI` (`EncryptMessageError::CannotAcquirePubkey` is
+/// returned in this case).
 pub async fn encrypt_message(
     certificate: X509CertificateDer<'_>,
     message: &[u8],
+) -> Result<(PKIEncryptionAlgorithm, Bytes), EncryptMessageError> {
+    // SHA256 is the most widely supported, hence the default
+    encrypt_message_with_algorithm(
+        certificate,
+        PKIEncryptionAlgorithm::RsaesOaepSha256,
+        message,
+    )
+    .await
+}
+
+/// Similar to `encrypt_message`, but with a custom OAEP hash.
+///
+/// Parsec itself always uses `encrypt_message` (as every PKI backend supports
+/// RSA-OAEP-SHA256), this is meant for interoperability testing (see the
+/// `encrypt_message` example) with certificates whose policy requires a larger hash.
+pub async fn encrypt_message_with_algorithm(
+    certificate: X509CertificateDer<'_>,
+    algorithm: PKIEncryptionAlgorithm,
+    message: &[u8],
 ) -> Result<(PKIEncryptionAlgorithm, Bytes), EncryptMessageError> {
     let pubkey = crate::x509::get_certificate_public_key(certificate.as_ref())?;
     match pubkey {
         crate::x509::PublicKey::Rsa(rsa_public_key) => {
-            use rsa::traits::RandomizedEncryptor;
-
-            let enc_key = rsa::oaep::EncryptingKey::<rsa::sha2::Sha256>::new(rsa_public_key);
-            enc_key
-                .encrypt_with_rng(&mut rsa::rand_core::OsRng, message)
+            rsaes_oaep_encrypt(rsa_public_key, algorithm, message)
                 .map_err(Into::into)
-                .map(|v| (PKIEncryptionAlgorithm::RsaesOaepSha256, Bytes::from(v)))
+                .map(|v| (algorithm, Bytes::from(v)))
+        }
+    }
+}
+
+fn rsaes_oaep_encrypt(
+    public_key: rsa::RsaPublicKey,
+    algorithm: PKIEncryptionAlgorithm,
+    message: &[u8],
+) -> Result<Vec<u8>, rsa::errors::Error> {
+    use rsa::traits::RandomizedEncryptor;
+
+    let mut rng = rsa::rand_core::OsRng;
+    match algorithm {
+        PKIEncryptionAlgorithm::RsaesOaepSha256 => {
+            rsa::oaep::EncryptingKey::<rsa::sha2::Sha256>::new(public_key)
+                .encrypt_with_rng(&mut rng, message)
+        }
+        PKIEncryptionAlgorithm::RsaesOaepSha384 => {
+            rsa::oaep::EncryptingKey::<rsa::sha2::Sha384>::new(public_key)
+                .encrypt_with_rng(&mut rng, message)
+        }
+        PKIEncryptionAlgorithm::RsaesOaepSha512 => {
+            rsa::oaep::EncryptingKey::<rsa::sha2::Sha512>::new(public_key)
+                .encrypt_with_rng(&mut rng, message)
+        }
+    }
+}
+
+/// Decrypt using a private key available in memory (i.e. not stored in a smartcard/OS store).
+#[cfg_attr(
+    not(any(
+        feature = "test-with-testbed",
+        all(target_family = "unix", not(target_arch = "wasm32"))
+    )),
+    expect(dead_code)
+)]
+pub(crate) fn rsaes_oaep_decrypt(
+    private_key: rsa::RsaPrivateKey,
+    algorithm: PKIEncryptionAlgorithm,
+    ciphertext: &[u8],
+) -> Result<Vec<u8>, rsa::errors::Error> {
+    use rsa::traits::RandomizedDecryptor;
+
+    let mut rng = rsa::rand_core::OsRng;
+    match algorithm {
+        PKIEncryptionAlgorithm::RsaesOaepSha256 => {
+            rsa::oaep::DecryptingKey::<rsa::sha2::Sha256>::new(private_key)
+                .decrypt_with_rng(&mut rng, ciphertext)
+        }
+        PKIEncryptionAlgorithm::RsaesOaepSha384 => {
+            rsa::oaep::DecryptingKey::<rsa::sha2::Sha384>::new(private_key)
+                .decrypt_with_rng(&mut rng, ciphertext)
+        }
+        PKIEncryptionAlgorithm::RsaesOaepSha512 => {
+            rsa::oaep::DecryptingKey::<rsa::sha2::Sha512>::new(private_key)
+                .decrypt_with_rng(&mut rng, ciphertext)
         }
     }
 }
@@ -1,9 +1,13 @@
 // Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
 
+mod ecdsa;
 mod encrypt;
 mod info;
+mod revocation;
 mod verify;
 
+pub(crate) use ecdsa::*;
 pub use encrypt::*;
 pub use info::*;
+pub use revocation::*;
 pub use verify::*;
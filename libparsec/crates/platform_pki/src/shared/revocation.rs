@@ -0,0 +1,79 @@
+// Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+use rustls_pki_types::{pem::PemObject, CertificateRevocationListDer};
+
+/// How to handle a certificate whose revocation status cannot be determined
+/// (i.e. no CRL covers it, or the CRL covering it is outdated).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum X509RevocationPolicy {
+    /// Consider the certificate is not revoked.
+    #[default]
+    SoftFail,
+    /// Consider the certificate cannot be trusted.
+    HardFail,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum X509CrlLoadError {
+    #[error("Invalid PEM: {0}")]
+    InvalidPem(rustls_pki_types::pem::Error),
+    #[error("Invalid CRL: {0}")]
+    InvalidCrl(webpki::Error),
+}
+
+/// The CRLs (Certificate Revocation Lists) used to check whether the
+/// certificates of a trustchain have been revoked.
+#[derive(Debug, Default)]
+pub struct X509RevocationConfig {
+    pub policy: X509RevocationPolicy,
+    pub(crate) crls: Vec<webpki::CertRevocationList<'static>>,
+}
+
+impl X509RevocationConfig {
+    pub fn new(policy: X509RevocationPolicy) -> Self {
+        Self {
+            policy,
+            crls: vec![],
+        }
+    }
+
+    /// Add the CRLs contained in `raw`, which can be either a single DER-encoded
+    /// CRL or a PEM file (possibly containing multiple CRLs).
+    pub fn add_crls(&mut self, raw: &[u8]) -> Result<(), X509CrlLoadError> {
+        let mut crls = parse_crls(raw)?;
+        self.crls.append(&mut crls);
+        Ok(())
+    }
+
+    pub fn crls_count(&self) -> usize {
+        self.crls.len()
+    }
+}
+
+/// Split `raw` into DER-encoded CRLs, `raw` being either a single DER-encoded
+/// CRL or a PEM file (possibly containing multiple CRLs).
+pub(crate) fn parse_crl_ders(
+    raw: &[u8],
+) -> Result<Vec<CertificateRevocationListDer<'static>>, X509CrlLoadError> {
+    let ders = CertificateRevocationListDer::pem_slice_iter(raw)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(X509CrlLoadError::InvalidPem)?;
+
+    if ders.is_empty() {
+        // Not a PEM file, hence it should be a single DER CRL
+        Ok(vec![CertificateRevocationListDer::from(raw.to_vec())])
+    } else {
+        Ok(ders)
+    }
+}
+
+fn parse_crls(raw: &[u8]) -> Result<Vec<webpki::CertRevocationList<'static>>, X509CrlLoadError> {
+    parse_crl_ders(raw)?
+        .iter()
+        .map(|der| {
+            webpki::OwnedCertRevocationList::from_der(der)
+                .map(webpki::CertRevocationList::from)
+                .map_err(X509CrlLoadError::InvalidCrl)
+        })
+        .collect()
+}
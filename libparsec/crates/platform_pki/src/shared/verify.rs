@@ -5,12 +5,16 @@ use webpki::KeyUsage;
 
 use libparsec_types::prelude::*;
 
-use crate::{X509CertificateDer, X509TrustAnchor};
+use crate::{X509CertificateDer, X509RevocationConfig, X509RevocationPolicy, X509TrustAnchor};
 
+/// Note revocation is only relevant when verifying a certificate provided by a peer:
+/// when building the validation path of our own certificate, pass an empty
+/// `X509RevocationConfig` since the peer is the one in charge of rejecting it.
 pub(crate) fn verify_certificate<'der>(
     certificate: &'der X509EndCertificate<'der>,
     intermediate_certs: &'der [X509CertificateDer<'der>],
     trusted_roots: &'der [X509TrustAnchor<'_>],
+    revocation: &X509RevocationConfig,
     now: DateTime,
 ) -> Result<webpki::VerifiedPath<'der>, webpki::Error> {
     let time = rustls_pki_types::UnixTime::since_unix_epoch(
@@ -20,16 +24,42 @@ pub(crate) fn verify_certificate<'der>(
         now.duration_since_unix_epoch()
             .expect("current time always > EPOCH"),
     );
+
+    let crls = revocation.crls.iter().collect::<Vec<_>>();
+    let revocation_options = match webpki::RevocationOptionsBuilder::new(&crls) {
+        Ok(builder) => {
+            let (status_policy, expiration_policy) = match revocation.policy {
+                X509RevocationPolicy::SoftFail => (
+                    webpki::UnknownStatusPolicy::Allow,
+                    webpki::ExpirationPolicy::Ignore,
+                ),
+                X509RevocationPolicy::HardFail => (
+                    webpki::UnknownStatusPolicy::Deny,
+                    webpki::ExpirationPolicy::Enforce,
+                ),
+            };
+            Some(
+                builder
+                    .with_depth(webpki::RevocationCheckDepth::Chain)
+                    .with_status_policy(status_policy)
+                    .with_expiration_policy(expiration_policy)
+                    .build(),
+            )
+        }
+        // `RevocationOptionsBuilder` requires a non-empty list of CRLs
+        Err(_) => match revocation.policy {
+            X509RevocationPolicy::SoftFail => None,
+            X509RevocationPolicy::HardFail => return Err(webpki::Error::UnknownRevocationStatus),
+        },
+    };
+
     certificate.verify_for_usage(
         webpki::ALL_VERIFICATION_ALGS,
         trusted_roots,
         intermediate_certs,
         time,
         KeyUsage::client_auth(),
-        // TODO: Build the revocation options from a CRLS
-        // webpki::RevocationOptionsBuilder require a non empty list, for now we provide None
-        // instead
-        None,
+        revocation_options,
         // We do not have additional constrain to reject a valid path.
         None,
     )
@@ -39,6 +69,10 @@ pub(crate) fn verify_certificate<'der>(
 pub enum VerifyMessageError {
     #[error("X509 certificate cannot be trusted: {0}")]
     X509CertificateUntrusted(webpki::Error),
+    #[error("X509 certificate (or one of its issuers) has been revoked")]
+    X509CertificateRevoked,
+    #[error("X509 certificate revocation status cannot be determined: {0}")]
+    X509CertificateRevocationStatusUnknown(webpki::Error),
     #[error("Invalid signature: {0}")]
     InvalidSignature(webpki::Error),
 }
@@ -50,6 +84,7 @@ pub fn verify_message<'a>(
     certificate: &[u8],
     intermediate_certs: impl Iterator<Item = &'a [u8]>,
     trusted_roots: &[X509TrustAnchor<'_>],
+    revocation: &X509RevocationConfig,
     now: DateTime,
 ) -> Result<(), VerifyMessageError> {
     // 1) Verify the certificate trustchain
@@ -64,14 +99,23 @@ pub fn verify_message<'a>(
             .map(X509CertificateDer::from)
             .collect::<Vec<_>>(),
         trusted_roots,
+        revocation,
         now,
     )
-    .map_err(VerifyMessageError::X509CertificateUntrusted)?;
+    .map_err(|err| match err {
+        webpki::Error::CertRevoked => VerifyMessageError::X509CertificateRevoked,
+        webpki::Error::UnknownRevocationStatus | webpki::Error::CrlExpired { .. } => {
+            VerifyMessageError::X509CertificateRevocationStatusUnknown(err)
+        }
+        err => VerifyMessageError::X509CertificateUntrusted(err),
+    })?;
 
     // 2) Verify the message signature
 
     let verifier = match algorithm {
         PkiSignatureAlgorithm::RsassaPssSha256 => webpki::ring::RSA_PSS_2048_8192_SHA256_LEGACY_KEY,
+        PkiSignatureAlgorithm::EcdsaP256Sha256 => webpki::ring::ECDSA_P256_SHA256,
+        PkiSignatureAlgorithm::EcdsaP384Sha384 => webpki::ring::ECDSA_P384_SHA384,
     };
     certificate
         .verify_signature(verifier, message, signature)
@@ -225,6 +225,7 @@ impl TestbedPkiCertificate {
             &cert,
             &intermediate_refs,
             &self.certificates.trust_anchors,
+            &crate::X509RevocationConfig::default(),
             DateTime::now(),
         )
         .unwrap();
@@ -277,18 +278,8 @@ impl TestbedPkiPrivateKey {
         algorithm: PKIEncryptionAlgorithm,
         ciphertext: &[u8],
     ) -> Result<Bytes, PkiPrivateKeyDecryptError> {
-        match algorithm {
-            PKIEncryptionAlgorithm::RsaesOaepSha256 => {
-                use rsa::traits::RandomizedDecryptor;
-
-                let dec_key = rsa::oaep::DecryptingKey::<rsa::sha2::Sha256>::new(
-                    self.entry.private_key.clone(),
-                );
-                let plaintext = dec_key
-                    .decrypt_with_rng(&mut rsa::rand_core::OsRng, ciphertext)
-                    .map_err(|e| PkiPrivateKeyDecryptError::Decrypt(e.into()))?;
-                Ok(Bytes::from(plaintext))
-            }
-        }
+        crate::rsaes_oaep_decrypt(self.entry.private_key.clone(), algorithm, ciphertext)
+            .map(Bytes::from)
+            .map_err(|e| PkiPrivateKeyDecryptError::Decrypt(e.into()))
     }
 }
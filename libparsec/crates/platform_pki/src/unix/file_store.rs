@@ -0,0 +1,278 @@
+// Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use rsa::RsaPrivateKey;
+use rustls_pki_types::{pem::PemObject, PrivateKeyDer};
+
+use libparsec_types::prelude::*;
+
+use super::FilePrivateKey;
+use crate::{X509CertificateDer, X509TrustAnchor};
+
+/// Directory containing the file-based certificates, defaults to `<config_dir>/pki`.
+///
+/// The directory can contain:
+/// - PKCS#12 files (`.p12`/`.pfx`) bundling a certificate, its private key and
+///   optionally its certificate chain.
+/// - PEM/DER certificate files (`.pem`/`.crt`/`.cer`/`.der`). A PEM file can also
+///   contain the certificate's private key, otherwise it is looked for in a
+///   `<name>.key` file next to it.
+///
+/// Certificates without private key are used as intermediates to build the
+/// validation path. They are never trusted as roots, see `PARSEC_PKI_TRUST_ANCHORS_DIR`.
+pub const PARSEC_PKI_FILES_DIR: &str = "PARSEC_PKI_FILES_DIR";
+/// Directory containing the additional trusted roots (on top of the system's ones),
+/// defaults to `<config_dir>/pki-trust-anchors`.
+///
+/// The directory can contain PEM/DER certificate files (`.pem`/`.crt`/`.cer`/`.der`).
+///
+/// Note this is kept separated from `PARSEC_PKI_FILES_DIR` (and the PKCS#11 tokens)
+/// on purpose: otherwise anybody able to drop a file or plug a smartcard would be able
+/// to add its own certificate authority.
+pub const PARSEC_PKI_TRUST_ANCHORS_DIR: &str = "PARSEC_PKI_TRUST_ANCHORS_DIR";
+/// Password used to decrypt the PKCS#12 files, defaults to an empty password.
+pub const PARSEC_PKI_PKCS12_PASSWORD: &str = "PARSEC_PKI_PKCS12_PASSWORD";
+
+pub(super) fn files_dir(config_dir: &Path) -> PathBuf {
+    match std::env::var_os(PARSEC_PKI_FILES_DIR) {
+        Some(dir) => dir.into(),
+        None => config_dir.join("pki"),
+    }
+}
+
+pub(super) fn trust_anchors_dir(config_dir: &Path) -> PathBuf {
+    match std::env::var_os(PARSEC_PKI_TRUST_ANCHORS_DIR) {
+        Some(dir) => dir.into(),
+        None => config_dir.join("pki-trust-anchors"),
+    }
+}
+
+#[derive(Debug)]
+pub(super) struct FileCertificate {
+    /// PKCS#12 friendly name, certificate's common name is used if not provided.
+    pub label: Option<String>,
+    pub der: X509CertificateDer<'static>,
+    pub private_key: Option<Arc<FilePrivateKey>>,
+}
+
+/// Load all the certificates found in the directory.
+///
+/// Invalid files are skipped (with a warning), so that a single broken file
+/// doesn't prevent from using the other certificates.
+pub(super) fn load_certificates(dir: &Path) -> anyhow::Result<Vec<FileCertificate>> {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(err) => {
+            return Err(anyhow::anyhow!(err).context(format!("Cannot read {}", dir.display())))
+        }
+    };
+
+    let mut paths = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.is_file())
+        .collect::<Vec<_>>();
+    // Keep listing order stable
+    paths.sort();
+
+    let mut certificates = vec![];
+    for path in paths {
+        let extension = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(|ext| ext.to_ascii_lowercase());
+        let outcome = match extension.as_deref() {
+            Some("p12" | "pfx") => load_pkcs12(&path),
+            Some("pem" | "crt" | "cer" | "der") => load_pem_or_der(&path),
+            _ => continue,
+        };
+        match outcome {
+            Ok(mut loaded) => certificates.append(&mut loaded),
+            Err(err) => log::warn!("Skipping certificate file {}: {err:#}", path.display()),
+        }
+    }
+
+    Ok(certificates)
+}
+
+/// Load all the trusted roots found in the directory.
+pub(super) fn load_trust_anchors(dir: &Path) -> anyhow::Result<Vec<X509TrustAnchor<'static>>> {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(err) => {
+            return Err(anyhow::anyhow!(err).context(format!("Cannot read {}", dir.display())))
+        }
+    };
+
+    let mut paths = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.is_file())
+        .collect::<Vec<_>>();
+    paths.sort();
+
+    let mut anchors = vec![];
+    for path in paths {
+        let extension = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(|ext| ext.to_ascii_lowercase());
+        if !matches!(extension.as_deref(), Some("pem" | "crt" | "cer" | "der")) {
+            continue;
+        }
+        let outcome = load_pem_or_der(&path).and_then(|certificates| {
+            certificates
+                .into_iter()
+                .map(|certificate| {
+                    webpki::anchor_from_trusted_cert(&certificate.der)
+                        .map(|anchor| anchor.to_owned())
+                        .map_err(|err| anyhow::anyhow!("Invalid root certificate: {err}"))
+                })
+                .collect::<anyhow::Result<Vec<_>>>()
+        });
+        match outcome {
+            Ok(mut loaded) => anchors.append(&mut loaded),
+            Err(err) => log::warn!("Skipping trust anchor file {}: {err:#}", path.display()),
+        }
+    }
+
+    Ok(anchors)
+}
+
+fn load_pkcs12(path: &Path) -> anyhow::Result<Vec<FileCertificate>> {
+    let raw = std::fs::read(path).context("Cannot read file")?;
+    let password = std::env::var(PARSEC_PKI_PKCS12_PASSWORD).unwrap_or_default();
+    let keystore = p12_keystore::KeyStore::from_pkcs12(&raw, &password)
+        .map_err(|err| anyhow::anyhow!("Invalid PKCS#12 file: {err}"))?;
+
+    let mut certificates = vec![];
+    for (alias, entry) in keystore.entries() {
+        match entry {
+            p12_keystore::KeyStoreEntry::PrivateKeyChain(chain) => {
+                let private_key =
+                    load_private_key_der(PrivateKeyDer::Pkcs8(chain.key().to_owned().into()))?;
+                let mut chain = chain.chain().iter();
+                // The leaf certificate is always first
+                let leaf = chain
+                    .next()
+                    .context("Missing certificate for private key")?;
+                certificates.push(FileCertificate {
+                    label: Some(alias.to_owned()),
+                    der: X509CertificateDer::from(leaf.as_der().to_owned()),
+                    private_key: Some(Arc::new(private_key)),
+                });
+                certificates.extend(chain.map(|cert| FileCertificate {
+                    label: None,
+                    der: X509CertificateDer::from(cert.as_der().to_owned()),
+                    private_key: None,
+                }));
+            }
+            p12_keystore::KeyStoreEntry::Certificate(cert) => {
+                certificates.push(FileCertificate {
+                    label: Some(alias.to_owned()),
+                    der: X509CertificateDer::from(cert.as_der().to_owned()),
+                    private_key: None,
+                });
+            }
+        }
+    }
+    Ok(certificates)
+}
+
+fn load_pem_or_der(path: &Path) -> anyhow::Result<Vec<FileCertificate>> {
+    let raw = std::fs::read(path).context("Cannot read file")?;
+
+    let ders = X509CertificateDer::pem_slice_iter(&raw)
+        .collect::<Result<Vec<_>, _>>()
+        .context("Invalid PEM file")?;
+    let ders = if ders.is_empty() {
+        // Not a PEM file, hence it should be a single DER certificate
+        vec![X509CertificateDer::from(raw.clone())]
+    } else {
+        ders
+    };
+
+    // The private key is either stored along with the certificate, or in a sibling file
+    let private_key = match PrivateKeyDer::from_pem_slice(&raw) {
+        Ok(key) => Some(key),
+        Err(_) => {
+            let key_path = path.with_extension("key");
+            match std::fs::read(&key_path) {
+                Ok(raw_key) => match PrivateKeyDer::from_pem_slice(&raw_key) {
+                    Ok(key) => Some(key),
+                    // Not a PEM file, hence it should be a DER private key
+                    Err(_) => Some(
+                        PrivateKeyDer::try_from(raw_key)
+                            .map_err(|err| anyhow::anyhow!("Invalid private key file: {err}"))?,
+                    ),
+                },
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => None,
+                Err(err) => {
+                    return Err(anyhow::anyhow!(err).context("Cannot read private key file"))
+                }
+            }
+        }
+    };
+    let private_key = private_key
+        .map(load_private_key_der)
+        .transpose()?
+        .map(Arc::new);
+
+    let mut ders = ders.into_iter();
+    // The private key (if any) relates to the first certificate, the others
+    // being its certificate chain.
+    let leaf = ders.next().context("No certificate found")?;
+    let mut certificates = vec![FileCertificate {
+        label: None,
+        der: leaf,
+        private_key,
+    }];
+    certificates.extend(ders.map(|der| FileCertificate {
+        label: None,
+        der,
+        private_key: None,
+    }));
+
+    Ok(certificates)
+}
+
+/// RSA, ECDSA P-256 and ECDSA P-384 private keys are supported.
+fn load_private_key_der(key: PrivateKeyDer<'_>) -> anyhow::Result<FilePrivateKey> {
+    match key {
+        PrivateKeyDer::Pkcs1(key) => {
+            use rsa::pkcs1::DecodeRsaPrivateKey;
+            RsaPrivateKey::from_pkcs1_der(key.secret_pkcs1_der())
+                .map(FilePrivateKey::Rsa)
+                .map_err(|err| anyhow::anyhow!("Invalid PKCS#1 private key: {err}"))
+        }
+        PrivateKeyDer::Pkcs8(key) => {
+            // PKCS#8 contains the key's algorithm, so only the right decoder can succeed
+            let der = key.secret_pkcs8_der();
+            if let Ok(key) = <RsaPrivateKey as rsa::pkcs8::DecodePrivateKey>::from_pkcs8_der(der) {
+                return Ok(FilePrivateKey::Rsa(key));
+            }
+            if let Ok(key) =
+                <p256::ecdsa::SigningKey as p256::pkcs8::DecodePrivateKey>::from_pkcs8_der(der)
+            {
+                return Ok(FilePrivateKey::EcdsaP256(key));
+            }
+            <p384::ecdsa::SigningKey as p384::pkcs8::DecodePrivateKey>::from_pkcs8_der(der)
+                .map(FilePrivateKey::EcdsaP384)
+                .map_err(|err| anyhow::anyhow!("Unsupported PKCS#8 private key: {err}"))
+        }
+        PrivateKeyDer::Sec1(key) => {
+            let der = key.secret_sec1_der();
+            if let Ok(key) = p256::SecretKey::from_sec1_der(der) {
+                return Ok(FilePrivateKey::EcdsaP256(key.into()));
+            }
+            p384::SecretKey::from_sec1_der(der)
+                .map(|key| FilePrivateKey::EcdsaP384(key.into()))
+                .map_err(|err| anyhow::anyhow!("Unsupported SEC1 private key: {err}"))
+        }
+        _ => Err(anyhow::anyhow!("Unsupported private key type")),
+    }
+}
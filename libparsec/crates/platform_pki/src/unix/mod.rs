@@ -1,5 +1,7 @@
 // Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
 
+mod file_store;
+mod pkcs11;
 mod pki_certificate;
 mod pki_private_key;
 mod pki_system;
@@ -0,0 +1,291 @@
+// Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+use std::path::{Path, PathBuf};
+
+use cryptoki::{
+    context::{CInitializeArgs, CInitializeFlags, Pkcs11},
+    error::{Error as CryptokiError, RvError},
+    mechanism::{
+        rsa::{PkcsMgfType, PkcsOaepParams, PkcsOaepSource, PkcsPssParams},
+        Mechanism, MechanismType,
+    },
+    object::{Attribute, AttributeType, KeyType, ObjectClass, ObjectHandle},
+    session::{Session, UserType},
+    slot::Slot,
+};
+
+use libparsec_types::prelude::*;
+
+/// Path to the PKCS#11 module (i.e. a dynamic library) to use.
+///
+/// If not set, we fallback on the p11-kit proxy module, which exposes all the
+/// PKCS#11 modules configured on the system (e.g. OpenSC for smartcards).
+pub const PARSEC_PKCS11_MODULE: &str = "PARSEC_PKCS11_MODULE";
+/// PIN used to log into the PKCS#11 tokens.
+///
+/// If not set, we only log into tokens providing their own way of authenticating
+/// the user (e.g. a pinpad reader). Other tokens are then used without login,
+/// which typically means their private keys are not accessible.
+pub const PARSEC_PKCS11_PIN: &str = "PARSEC_PKCS11_PIN";
+
+const P11_KIT_PROXY_CANDIDATES: &[&str] = &[
+    "/usr/lib/x86_64-linux-gnu/p11-kit-proxy.so",
+    "/usr/lib/aarch64-linux-gnu/p11-kit-proxy.so",
+    "/usr/lib64/p11-kit-proxy.so",
+    "/usr/lib/p11-kit-proxy.so",
+];
+
+/// Returns the module to load and whether it has been explicitly configured.
+pub(super) fn pkcs11_module_path() -> Option<(PathBuf, bool)> {
+    if let Some(path) = std::env::var_os(PARSEC_PKCS11_MODULE) {
+        return Some((path.into(), true));
+    }
+    P11_KIT_PROXY_CANDIDATES
+        .iter()
+        .map(Path::new)
+        .find(|path| path.exists())
+        .map(|path| (path.to_owned(), false))
+}
+
+/// A certificate object stored on a PKCS#11 token.
+#[derive(Debug, Clone)]
+pub(super) struct Pkcs11Certificate {
+    pub slot: Slot,
+    /// `CKA_ID`, shared by the certificate and its related private key.
+    pub id: Vec<u8>,
+    pub label: Option<String>,
+    pub der: Vec<u8>,
+    pub has_private_key: bool,
+}
+
+pub(super) struct Pkcs11Module {
+    ctx: Pkcs11,
+    pin: Option<String>,
+}
+
+impl std::fmt::Debug for Pkcs11Module {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Pkcs11Module").finish_non_exhaustive()
+    }
+}
+
+impl Pkcs11Module {
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let ctx = Pkcs11::new(path)
+            .with_context(|| format!("Cannot load PKCS#11 module {}", path.display()))?;
+        match ctx.initialize(CInitializeArgs::new(CInitializeFlags::OS_LOCKING_OK)) {
+            Ok(()) => (),
+            // Module may have already been initialized by another part of the process
+            Err(CryptokiError::Pkcs11(RvError::CryptokiAlreadyInitialized, _)) => (),
+            Err(err) => {
+                return Err(anyhow::anyhow!(err).context("Cannot initialize PKCS#11 module"))
+            }
+        }
+        let pin = std::env::var(PARSEC_PKCS11_PIN).ok();
+
+        Ok(Self { ctx, pin })
+    }
+
+    fn open_session(&self, slot: Slot) -> anyhow::Result<Session> {
+        let session = self
+            .ctx
+            .open_ro_session(slot)
+            .context("Cannot open PKCS#11 session")?;
+
+        let outcome = match &self.pin {
+            Some(pin) => session.login(UserType::User, Some(&pin.as_str().into())),
+            None => {
+                let token_info = self
+                    .ctx
+                    .get_token_info(slot)
+                    .context("Cannot get PKCS#11 token info")?;
+                if !token_info.protected_authentication_path() {
+                    return Ok(session);
+                }
+                // The PIN is provided out of band (e.g. typed on the reader's pinpad)
+                session.login(UserType::User, None)
+            }
+        };
+        match outcome {
+            Ok(()) => (),
+            // Login is shared among all the sessions of a given slot
+            Err(CryptokiError::Pkcs11(RvError::UserAlreadyLoggedIn, _)) => (),
+            Err(err) => return Err(anyhow::anyhow!(err).context("Cannot log into PKCS#11 token")),
+        }
+
+        Ok(session)
+    }
+
+    pub fn list_certificates(&self) -> anyhow::Result<Vec<Pkcs11Certificate>> {
+        let slots = self
+            .ctx
+            .get_slots_with_token()
+            .context("Cannot list PKCS#11 slots")?;
+
+        let mut certificates = vec![];
+        for slot in slots {
+            let session = match self.open_session(slot) {
+                Ok(session) => session,
+                // A single misbehaving token should not prevent from using the others
+                Err(err) => {
+                    log::warn!("Skipping PKCS#11 slot {}: {err:#}", slot.id());
+                    continue;
+                }
+            };
+
+            let private_key_ids = session
+                .find_objects(&[Attribute::Class(ObjectClass::PRIVATE_KEY)])
+                .context("Cannot list PKCS#11 private keys")?
+                .into_iter()
+                .filter_map(|handle| get_id(&session, handle).ok().flatten())
+                .collect::<Vec<_>>();
+
+            let handles = session
+                .find_objects(&[Attribute::Class(ObjectClass::CERTIFICATE)])
+                .context("Cannot list PKCS#11 certificates")?;
+            for handle in handles {
+                let attrs = session
+                    .get_attributes(
+                        handle,
+                        &[
+                            AttributeType::Id,
+                            AttributeType::Label,
+                            AttributeType::Value,
+                        ],
+                    )
+                    .context("Cannot get PKCS#11 certificate attributes")?;
+
+                let mut id = vec![];
+                let mut label = None;
+                let mut der = None;
+                for attr in attrs {
+                    match attr {
+                        Attribute::Id(value) => id = value,
+                        Attribute::Label(value) => {
+                            label = String::from_utf8(value).ok().filter(|x| !x.is_empty())
+                        }
+                        Attribute::Value(value) => der = Some(value),
+                        _ => (),
+                    }
+                }
+                let Some(der) = der else {
+                    continue;
+                };
+
+                let has_private_key = !id.is_empty() && private_key_ids.contains(&id);
+                certificates.push(Pkcs11Certificate {
+                    slot,
+                    id,
+                    label,
+                    der,
+                    has_private_key,
+                });
+            }
+        }
+
+        Ok(certificates)
+    }
+
+    pub fn has_private_key(&self, slot: Slot, id: &[u8]) -> anyhow::Result<bool> {
+        let session = self.open_session(slot)?;
+        find_private_key(&session, id).map(|handle| handle.is_some())
+    }
+
+    /// The signature algorithm depends on the key type: RSASSA-PSS-SHA256 for
+    /// RSA keys, ECDSA with the hash matching the curve size for EC keys.
+    pub fn sign(
+        &self,
+        slot: Slot,
+        id: &[u8],
+        message: &[u8],
+    ) -> anyhow::Result<(PkiSignatureAlgorithm, Vec<u8>)> {
+        let session = self.open_session(slot)?;
+        let key = find_private_key(&session, id)?.context("Private key not found")?;
+
+        let attrs = session
+            .get_attributes(key, &[AttributeType::KeyType, AttributeType::EcParams])
+            .context("Cannot get private key type")?;
+        let key_type = attrs.iter().find_map(|attr| match attr {
+            Attribute::KeyType(key_type) => Some(*key_type),
+            _ => None,
+        });
+        let ec_params = attrs.iter().find_map(|attr| match attr {
+            Attribute::EcParams(params) => Some(params.as_slice()),
+            _ => None,
+        });
+
+        match key_type {
+            Some(KeyType::RSA) => {
+                // Use the hash size as the salt size (i.e. 32 bytes for sha256) as it is
+                // what expects `webpki` when doing the signature verification
+                let mechanism = Mechanism::Sha256RsaPkcsPss(PkcsPssParams {
+                    hash_alg: MechanismType::SHA256,
+                    mgf: PkcsMgfType::MGF1_SHA256,
+                    s_len: 32.into(),
+                });
+                let signature = session.sign(&mechanism, key, message)?;
+                Ok((PkiSignatureAlgorithm::RsassaPssSha256, signature))
+            }
+            Some(KeyType::EC) => {
+                let algorithm = ec_params
+                    .and_then(crate::ecdsa_signature_algorithm_from_curve_oid)
+                    .context("Unsupported elliptic curve")?;
+                // `CKM_ECDSA` doesn't hash the message, and is more widely supported
+                // by tokens than the `CKM_ECDSA_SHA*` mechanisms
+                let hash = crate::ecdsa_hash_message(algorithm, message);
+                let raw_signature = session.sign(&Mechanism::Ecdsa, key, &hash)?;
+                let signature = crate::ecdsa_signature_raw_to_der(algorithm, &raw_signature)?;
+                Ok((algorithm, signature))
+            }
+            key_type => Err(anyhow::anyhow!(
+                "Unsupported private key type: {key_type:?}"
+            )),
+        }
+    }
+
+    pub fn decrypt_rsaes_oaep(
+        &self,
+        slot: Slot,
+        id: &[u8],
+        algorithm: PKIEncryptionAlgorithm,
+        ciphertext: &[u8],
+    ) -> anyhow::Result<Vec<u8>> {
+        let session = self.open_session(slot)?;
+        let key = find_private_key(&session, id)?.context("Private key not found")?;
+
+        let (hash_alg, mgf) = match algorithm {
+            PKIEncryptionAlgorithm::RsaesOaepSha256 => {
+                (MechanismType::SHA256, PkcsMgfType::MGF1_SHA256)
+            }
+            PKIEncryptionAlgorithm::RsaesOaepSha384 => {
+                (MechanismType::SHA384, PkcsMgfType::MGF1_SHA384)
+            }
+            PKIEncryptionAlgorithm::RsaesOaepSha512 => {
+                (MechanismType::SHA512, PkcsMgfType::MGF1_SHA512)
+            }
+        };
+        let mechanism =
+            Mechanism::RsaPkcsOaep(PkcsOaepParams::new(hash_alg, mgf, PkcsOaepSource::empty()));
+        session
+            .decrypt(&mechanism, key, ciphertext)
+            .map_err(anyhow::Error::from)
+    }
+}
+
+fn get_id(session: &Session, handle: ObjectHandle) -> anyhow::Result<Option<Vec<u8>>> {
+    let attrs = session.get_attributes(handle, &[AttributeType::Id])?;
+    Ok(attrs.into_iter().find_map(|attr| match attr {
+        Attribute::Id(id) if !id.is_empty() => Some(id),
+        _ => None,
+    }))
+}
+
+fn find_private_key(session: &Session, id: &[u8]) -> anyhow::Result<Option<ObjectHandle>> {
+    let handles = session
+        .find_objects(&[
+            Attribute::Class(ObjectClass::PRIVATE_KEY),
+            Attribute::Id(id.to_owned()),
+        ])
+        .context("Cannot search PKCS#11 private key")?;
+    Ok(handles.into_iter().next())
+}
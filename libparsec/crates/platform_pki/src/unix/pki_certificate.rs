@@ -1,37 +1,125 @@
 // Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
 
+use std::sync::Arc;
+
+use cryptoki::slot::Slot;
+use sha2::Digest as _;
+
 use libparsec_types::prelude::*;
-use rustls_pki_types::CertificateDer;
 
+use super::{pkcs11::Pkcs11Module, FilePrivateKey, PlatformPkiPrivateKey, PlatformPkiSystem};
 use crate::{
-    PkiCertificateGetDerError, PkiCertificateGetValidationPathError,
+    verify_certificate, PkiCertificateGetDerError, PkiCertificateGetValidationPathError,
     PkiCertificateRequestPrivateKeyError, PkiCertificateToReferenceError, PkiPrivateKey,
-    X509ValidationPathOwned,
+    X509CertificateDer, X509EndCertificate, X509RevocationConfig, X509ValidationPathOwned,
 };
 
+/// Where the certificate (and hence its private key) comes from.
+#[derive(Debug)]
+pub(super) enum CertificateSource {
+    Pkcs11 {
+        module: Arc<Pkcs11Module>,
+        slot: Slot,
+        id: Vec<u8>,
+    },
+    File {
+        private_key: Arc<FilePrivateKey>,
+    },
+}
+
 #[derive(Debug)]
-pub struct PlatformPkiCertificate {}
+pub struct PlatformPkiCertificate {
+    pub(super) system: PlatformPkiSystem,
+    pub(super) der: X509CertificateDer<'static>,
+    pub(super) source: CertificateSource,
+}
 
 impl PlatformPkiCertificate {
-    pub async fn get_der(&self) -> Result<CertificateDer<'static>, PkiCertificateGetDerError> {
-        unimplemented!("platform not supported");
+    pub async fn get_der(&self) -> Result<X509CertificateDer<'static>, PkiCertificateGetDerError> {
+        Ok(self.der.clone())
     }
 
     pub async fn request_private_key(
         &self,
     ) -> Result<PkiPrivateKey, PkiCertificateRequestPrivateKeyError> {
-        unimplemented!("platform not supported");
+        let private_key = match &self.source {
+            CertificateSource::Pkcs11 { module, slot, id } => {
+                // The token may have been modified since the certificate has been opened
+                let found = module
+                    .has_private_key(*slot, id)
+                    .map_err(PkiCertificateRequestPrivateKeyError::Internal)?;
+                if !found {
+                    return Err(PkiCertificateRequestPrivateKeyError::NotFound);
+                }
+                PlatformPkiPrivateKey::Pkcs11 {
+                    module: module.clone(),
+                    slot: *slot,
+                    id: id.clone(),
+                }
+            }
+            CertificateSource::File { private_key } => {
+                PlatformPkiPrivateKey::File(private_key.clone())
+            }
+        };
+
+        Ok(wrap_platform_private_key(private_key))
     }
 
     pub async fn to_reference(
         &self,
     ) -> Result<X509CertificateReference, PkiCertificateToReferenceError> {
-        unimplemented!("platform not supported");
+        // Certificates are only referenced by their hash, as PKCS#11 URIs are not
+        // stable across tokens (e.g. the slot ID can change between two runs).
+        let hash = X509CertificateHash::SHA256(Box::new(sha2::Sha256::digest(&self.der).into()));
+
+        Ok(X509CertificateReference::from(hash))
     }
 
     pub async fn get_validation_path(
         &self,
     ) -> Result<X509ValidationPathOwned, PkiCertificateGetValidationPathError> {
-        unimplemented!("platform not supported")
+        let trust_material = self
+            .system
+            .list_trust_material()
+            .context("Cannot list trusted roots and intermediates certificates")
+            .map_err(PkiCertificateGetValidationPathError::Internal)?;
+        let leaf = self.der.clone();
+        let end_cert = X509EndCertificate::try_from(&leaf)
+            .context("Invalid leaf certificate")
+            .map_err(PkiCertificateGetValidationPathError::Internal)?;
+        let now = DateTime::now();
+        let path = verify_certificate(
+            &end_cert,
+            &trust_material.intermediates,
+            &trust_material.trusted_roots,
+            &X509RevocationConfig::default(),
+            now,
+        )
+        .inspect_err(|e| log::warn!("Failed to verify certificate: {e}"))
+        .map_err(|_| PkiCertificateGetValidationPathError::Untrusted)?;
+
+        let intermediates = path
+            .intermediate_certificates()
+            .map(|cert| cert.der().into_owned())
+            .collect();
+        let root = path.anchor().to_owned();
+
+        Ok(X509ValidationPathOwned {
+            root,
+            intermediates,
+            leaf,
+        })
+    }
+}
+
+#[cfg(not(feature = "test-with-testbed"))]
+fn wrap_platform_private_key(platform: PlatformPkiPrivateKey) -> PkiPrivateKey {
+    PkiPrivateKey { platform }
+}
+
+#[cfg(feature = "test-with-testbed")]
+fn wrap_platform_private_key(platform: PlatformPkiPrivateKey) -> PkiPrivateKey {
+    PkiPrivateKey {
+        platform: crate::testbed::MaybeWithTestbed::WithPlatform(platform),
     }
 }
@@ -1,24 +1,106 @@
 // Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
 
+use std::sync::Arc;
+
+use cryptoki::slot::Slot;
+use rsa::RsaPrivateKey;
+
+use super::pkcs11::Pkcs11Module;
 use crate::{PkiPrivateKeyDecryptError, PkiPrivateKeySignError};
 use libparsec_types::prelude::*;
 
 #[derive(Debug)]
-pub struct PlatformPkiPrivateKey {}
+pub enum PlatformPkiPrivateKey {
+    /// The private key never leaves the token, which does the actual cryptographic operations.
+    Pkcs11 {
+        module: Arc<Pkcs11Module>,
+        slot: Slot,
+        id: Vec<u8>,
+    },
+    File(Arc<FilePrivateKey>),
+}
+
+/// Private key loaded from a PKCS#12/PEM/DER file.
+#[derive(Debug)]
+pub enum FilePrivateKey {
+    Rsa(RsaPrivateKey),
+    EcdsaP256(p256::ecdsa::SigningKey),
+    EcdsaP384(p384::ecdsa::SigningKey),
+}
 
 impl PlatformPkiPrivateKey {
     pub async fn sign(
         &self,
-        _message: &[u8],
+        message: &[u8],
     ) -> Result<(PkiSignatureAlgorithm, Bytes), PkiPrivateKeySignError> {
-        unimplemented!("platform not supported")
+        match self {
+            Self::Pkcs11 { module, slot, id } => module
+                .sign(*slot, id, message)
+                .map(|(algorithm, signature)| (algorithm, Bytes::from(signature)))
+                .map_err(PkiPrivateKeySignError::Sign),
+            Self::File(private_key) => match private_key.as_ref() {
+                FilePrivateKey::Rsa(private_key) => {
+                    use rsa::signature::{RandomizedSigner, SignatureEncoding};
+
+                    // Use the hash size as the salt size (i.e. 32 bytes for sha256) as it is what expects
+                    // `webpki` when doing the signature verification
+                    let signing_key = rsa::pss::SigningKey::<rsa::sha2::Sha256>::new_with_salt_len(
+                        private_key.clone(),
+                        32,
+                    );
+                    let signature = signing_key
+                        .sign_with_rng(&mut rsa::rand_core::OsRng, message)
+                        .to_vec();
+                    Ok((
+                        PkiSignatureAlgorithm::RsassaPssSha256,
+                        Bytes::from(signature),
+                    ))
+                }
+                // Note the message is hashed by the signing key (SHA256 for P-256 and
+                // SHA384 for P-384), and the nonce is deterministic (see RFC 6979).
+                FilePrivateKey::EcdsaP256(signing_key) => {
+                    use p256::ecdsa::signature::Signer;
+
+                    let signature: p256::ecdsa::Signature = signing_key.sign(message);
+                    Ok((
+                        PkiSignatureAlgorithm::EcdsaP256Sha256,
+                        Bytes::from(signature.to_der().as_bytes().to_vec()),
+                    ))
+                }
+                FilePrivateKey::EcdsaP384(signing_key) => {
+                    use p384::ecdsa::signature::Signer;
+
+                    let signature: p384::ecdsa::Signature = signing_key.sign(message);
+                    Ok((
+                        PkiSignatureAlgorithm::EcdsaP384Sha384,
+                        Bytes::from(signature.to_der().as_bytes().to_vec()),
+                    ))
+                }
+            },
+        }
     }
 
     pub async fn decrypt(
         &self,
-        _algorithm: PKIEncryptionAlgorithm,
-        _ciphertext: &[u8],
+        algorithm: PKIEncryptionAlgorithm,
+        ciphertext: &[u8],
     ) -> Result<Bytes, PkiPrivateKeyDecryptError> {
-        unimplemented!("platform not supported")
+        let plaintext = match self {
+            Self::Pkcs11 { module, slot, id } => module
+                .decrypt_rsaes_oaep(*slot, id, algorithm, ciphertext)
+                .map_err(PkiPrivateKeyDecryptError::Decrypt)?,
+            Self::File(private_key) => match private_key.as_ref() {
+                FilePrivateKey::Rsa(private_key) => {
+                    crate::rsaes_oaep_decrypt(private_key.clone(), algorithm, ciphertext)
+                        .map_err(|e| PkiPrivateKeyDecryptError::Decrypt(e.into()))?
+                }
+                // ECDSA keys can only be used to sign
+                FilePrivateKey::EcdsaP256(_) | FilePrivateKey::EcdsaP384(_) => {
+                    return Err(PkiPrivateKeyDecryptError::UnsupportedAlgorithm)
+                }
+            },
+        };
+
+        Ok(Bytes::from(plaintext))
     }
 }
@@ -1,32 +1,232 @@
 // Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
 
+use std::{path::PathBuf, sync::Arc};
+
+use rustls_pki_types::pem::PemObject;
+use sha2::Digest as _;
+
+use libparsec_types::prelude::*;
+
+use super::{
+    file_store::{self, FileCertificate},
+    pkcs11::{self, Pkcs11Certificate, Pkcs11Module},
+    pki_certificate::CertificateSource,
+    PlatformPkiCertificate,
+};
 use crate::{
     AvailablePkiCertificate, PkiCertificate, PkiScwsConfig, PkiSystemInitError,
-    PkiSystemListUserCertificateError, PkiSystemOpenCertificateError,
+    PkiSystemListUserCertificateError, PkiSystemOpenCertificateError, X509CertificateDer,
+    X509TrustAnchor,
 };
-use libparsec_types::prelude::*;
 
-#[derive(Debug)]
-pub struct PlatformPkiSystem {}
+/// Trusted roots installed on the system (the first existing file is used).
+const SYSTEM_CA_BUNDLE_CANDIDATES: &[&str] = &[
+    // Debian/Ubuntu/Arch
+    "/etc/ssl/certs/ca-certificates.crt",
+    // Fedora/RHEL
+    "/etc/pki/tls/certs/ca-bundle.crt",
+    // OpenSUSE
+    "/etc/ssl/ca-bundle.pem",
+];
+
+/// On Linux there is no OS certificate store, instead certificates are looked for:
+/// - On the PKCS#11 tokens (smartcards, HSM etc.) exposed by the configured module.
+/// - As files in a dedicated directory (see `PARSEC_PKI_FILES_DIR`).
+///
+/// Trusted roots are the system's ones, along with the ones explicitly configured
+/// (see `PARSEC_PKI_TRUST_ANCHORS_DIR`).
+#[derive(Debug, Clone)]
+pub struct PlatformPkiSystem {
+    pkcs11: Option<Arc<Pkcs11Module>>,
+    files_dir: PathBuf,
+    trust_anchors_dir: PathBuf,
+}
+
+/// Certificates that are not end-entity ones (i.e. we don't have their private key),
+/// used to build the validation path.
+pub(super) struct TrustMaterial {
+    pub trusted_roots: Vec<X509TrustAnchor<'static>>,
+    pub intermediates: Vec<X509CertificateDer<'static>>,
+}
 
 impl PlatformPkiSystem {
     pub async fn init(
-        _config_dir: &std::path::Path,
+        config_dir: &std::path::Path,
         _scws_config: Option<PkiScwsConfig>,
     ) -> Result<Self, PkiSystemInitError> {
-        Err(PkiSystemInitError::NotAvailable)
+        let pkcs11 = match pkcs11::pkcs11_module_path() {
+            Some((path, explicitly_configured)) => match Pkcs11Module::load(&path) {
+                Ok(module) => Some(Arc::new(module)),
+                Err(err) if explicitly_configured => {
+                    return Err(PkiSystemInitError::Internal(err));
+                }
+                Err(err) => {
+                    log::warn!("Ignoring PKCS#11 module {}: {err:#}", path.display());
+                    None
+                }
+            },
+            None => None,
+        };
+        let files_dir = file_store::files_dir(config_dir);
+        let trust_anchors_dir = file_store::trust_anchors_dir(config_dir);
+
+        if pkcs11.is_none() && !files_dir.is_dir() {
+            return Err(PkiSystemInitError::NotAvailable);
+        }
+
+        Ok(Self {
+            pkcs11,
+            files_dir,
+            trust_anchors_dir,
+        })
     }
 
     pub async fn open_certificate(
         &self,
-        _cert_ref: &X509CertificateReference,
+        cert_ref: &X509CertificateReference,
     ) -> Result<PkiCertificate, PkiSystemOpenCertificateError> {
-        unimplemented!("platform not supported")
+        let (pkcs11_certs, file_certs) = self
+            .list_certificates()
+            .map_err(PkiSystemOpenCertificateError::Internal)?;
+
+        let matches = |der: &[u8]| match &cert_ref.hash {
+            X509CertificateHash::SHA256(data) => {
+                data.as_ref() == sha2::Sha256::digest(der).as_slice()
+            }
+        };
+
+        let pkcs11_found = pkcs11_certs
+            .into_iter()
+            .find(|cert| cert.has_private_key && matches(&cert.der[..]))
+            .map(|cert| {
+                let module = self
+                    .pkcs11
+                    .clone()
+                    .expect("PKCS#11 certificates only come from a loaded module");
+                (
+                    X509CertificateDer::from(cert.der),
+                    CertificateSource::Pkcs11 {
+                        module,
+                        slot: cert.slot,
+                        id: cert.id,
+                    },
+                )
+            });
+        let found = pkcs11_found.or_else(|| {
+            file_certs.into_iter().find_map(|cert| match cert {
+                FileCertificate {
+                    der,
+                    private_key: Some(private_key),
+                    ..
+                } if matches(&der[..]) => Some((der, CertificateSource::File { private_key })),
+                _ => None,
+            })
+        });
+
+        found
+            .map(|(der, source)| {
+                wrap_platform_certificate(PlatformPkiCertificate {
+                    system: self.clone(),
+                    der,
+                    source,
+                })
+            })
+            .ok_or(PkiSystemOpenCertificateError::NotFound)
     }
 
     pub async fn list_user_certificates(
         &self,
     ) -> Result<Vec<AvailablePkiCertificate>, PkiSystemListUserCertificateError> {
-        unimplemented!("platform not supported");
+        let (pkcs11_certs, file_certs) = self
+            .list_certificates()
+            .map_err(PkiSystemListUserCertificateError::Internal)?;
+
+        let pkcs11_certs = pkcs11_certs
+            .into_iter()
+            .filter(|cert| cert.has_private_key)
+            .map(|cert| AvailablePkiCertificate::load_der(cert.label, &cert.der));
+        let file_certs = file_certs
+            .into_iter()
+            .filter(|cert| cert.private_key.is_some())
+            .map(|cert| AvailablePkiCertificate::load_der(cert.label, &cert.der));
+
+        Ok(pkcs11_certs.chain(file_certs).collect())
+    }
+
+    fn list_certificates(&self) -> anyhow::Result<(Vec<Pkcs11Certificate>, Vec<FileCertificate>)> {
+        let pkcs11_certs = match &self.pkcs11 {
+            Some(module) => module.list_certificates()?,
+            None => vec![],
+        };
+        let file_certs = file_store::load_certificates(&self.files_dir)?;
+
+        Ok((pkcs11_certs, file_certs))
+    }
+
+    pub(super) fn list_trust_material(&self) -> anyhow::Result<TrustMaterial> {
+        let (pkcs11_certs, file_certs) = self.list_certificates()?;
+
+        let mut trusted_roots = load_system_trusted_roots();
+        trusted_roots.extend(file_store::load_trust_anchors(&self.trust_anchors_dir)?);
+
+        // Certificates from the tokens and the files directory are only used as
+        // intermediates: they can be provided by anybody able to plug a smartcard or
+        // drop a file, so they must chain up to a trusted root.
+        let intermediates = pkcs11_certs
+            .into_iter()
+            .filter(|cert| !cert.has_private_key)
+            .map(|cert| X509CertificateDer::from(cert.der))
+            .chain(
+                file_certs
+                    .into_iter()
+                    .filter(|cert| cert.private_key.is_none())
+                    .map(|cert| cert.der),
+            )
+            .filter(|der| !is_self_signed(der))
+            .collect();
+
+        Ok(TrustMaterial {
+            trusted_roots,
+            intermediates,
+        })
+    }
+}
+
+fn is_self_signed(der: &[u8]) -> bool {
+    use x509_cert::der::Decode;
+
+    match x509_cert::Certificate::from_der(der) {
+        Ok(cert) => cert.tbs_certificate.issuer == cert.tbs_certificate.subject,
+        Err(_) => false,
+    }
+}
+
+fn load_system_trusted_roots() -> Vec<X509TrustAnchor<'static>> {
+    let Some(bundle) = SYSTEM_CA_BUNDLE_CANDIDATES
+        .iter()
+        .find_map(|path| std::fs::read(path).ok())
+    else {
+        return vec![];
+    };
+
+    X509CertificateDer::pem_slice_iter(&bundle)
+        .filter_map(|der| {
+            let der = der.ok()?;
+            webpki::anchor_from_trusted_cert(&der)
+                .map(|anchor| anchor.to_owned())
+                .ok()
+        })
+        .collect()
+}
+
+#[cfg(not(feature = "test-with-testbed"))]
+fn wrap_platform_certificate(platform: PlatformPkiCertificate) -> PkiCertificate {
+    PkiCertificate { platform }
+}
+
+#[cfg(feature = "test-with-testbed")]
+fn wrap_platform_certificate(platform: PlatformPkiCertificate) -> PkiCertificate {
+    PkiCertificate {
+        platform: crate::testbed::MaybeWithTestbed::WithPlatform(platform),
     }
 }
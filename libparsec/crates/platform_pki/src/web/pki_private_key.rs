@@ -44,6 +44,9 @@ impl PlatformPkiPrivateKey {
     ) -> Result<Bytes, PkiPrivateKeyDecryptError> {
         let scws_algo = match algorithm {
             PKIEncryptionAlgorithm::RsaesOaepSha256 => scwsapi::EncryptionAlgorithm::RsaOaepSha256,
+            PKIEncryptionAlgorithm::RsaesOaepSha384 | PKIEncryptionAlgorithm::RsaesOaepSha512 => {
+                return Err(PkiPrivateKeyDecryptError::UnsupportedAlgorithm)
+            }
         };
         self.0
             .decrypt(scws_algo, ciphertext)
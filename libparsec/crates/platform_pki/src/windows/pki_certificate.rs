@@ -6,7 +6,7 @@ use super::PlatformPkiPrivateKey;
 use crate::{
     verify_certificate, PkiCertificateGetDerError, PkiCertificateGetValidationPathError,
     PkiCertificateRequestPrivateKeyError, PkiCertificateToReferenceError, PkiPrivateKey,
-    X509CertificateDer, X509EndCertificate, X509ValidationPathOwned,
+    X509CertificateDer, X509EndCertificate, X509RevocationConfig, X509ValidationPathOwned,
 };
 
 pub struct PlatformPkiCertificate(schannel::cert_context::CertContext);
@@ -83,9 +83,15 @@ impl PlatformPkiCertificate {
             .context("Invalid leaf certificate")
             .map_err(PkiCertificateGetValidationPathError::Internal)?;
         let now = DateTime::now();
-        let path = verify_certificate(&end_cert, &raw_intermediates, &raw_trusted_roots, now)
-            .inspect_err(|e| log::warn!("Failed to verify certificate: {e}"))
-            .map_err(|_| PkiCertificateGetValidationPathError::Untrusted)?;
+        let path = verify_certificate(
+            &end_cert,
+            &raw_intermediates,
+            &raw_trusted_roots,
+            &X509RevocationConfig::default(),
+            now,
+        )
+        .inspect_err(|e| log::warn!("Failed to verify certificate: {e}"))
+        .map_err(|_| PkiCertificateGetValidationPathError::Untrusted)?;
 
         let intermediates = path
             .intermediate_certificates()
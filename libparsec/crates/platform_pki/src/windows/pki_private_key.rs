@@ -3,8 +3,10 @@
 use schannel::cert_context::PrivateKey as SchannelPKey;
 use sha2::Digest as _;
 use windows_sys::Win32::Security::Cryptography::{
-    self, NCryptDecrypt, NCryptSignHash, BCRYPT_OAEP_PADDING_INFO, BCRYPT_PSS_PADDING_INFO,
-    NCRYPT_PAD_OAEP_FLAG, NCRYPT_PAD_PSS_FLAG, NCRYPT_SHA256_ALGORITHM,
+    self, NCryptDecrypt, NCryptGetProperty, NCryptSignHash, BCRYPT_OAEP_PADDING_INFO,
+    BCRYPT_PSS_PADDING_INFO, NCRYPT_ALGORITHM_PROPERTY, NCRYPT_ECC_CURVE_NAME_PROPERTY,
+    NCRYPT_PAD_OAEP_FLAG, NCRYPT_PAD_PSS_FLAG, NCRYPT_SHA256_ALGORITHM, NCRYPT_SHA384_ALGORITHM,
+    NCRYPT_SHA512_ALGORITHM,
 };
 
 use libparsec_types::prelude::*;
@@ -42,15 +44,71 @@ impl PlatformPkiPrivateKey {
         algorithm: PKIEncryptionAlgorithm,
         ciphertext: &[u8],
     ) -> Result<Bytes, PkiPrivateKeyDecryptError> {
-        if algorithm != PKIEncryptionAlgorithm::RsaesOaepSha256 {
-            return Err(PkiPrivateKeyDecryptError::UnsupportedAlgorithm);
-        }
         match &self.0 {
             SchannelPKey::CryptProv(_crypt) => Err(PkiPrivateKeyDecryptError::Internal(
                 anyhow::anyhow!("Unsupported private key handle type"),
             )),
-            SchannelPKey::NcryptKey(ncrypt) => decrypt_with_ncrypt(ncrypt, ciphertext),
+            SchannelPKey::NcryptKey(ncrypt) => decrypt_with_ncrypt(ncrypt, algorithm, ciphertext),
+        }
+    }
+}
+
+/// Retrieve a string property (e.g. the key algorithm) of the key.
+fn get_ncrypt_string_property(
+    raw_handle: Cryptography::NCRYPT_KEY_HANDLE,
+    property: windows_sys::core::PCWSTR,
+) -> Result<String, std::io::Error> {
+    // SAFETY: The property is a null-terminated UTF-16 string, and the output buffer
+    // is allocated with the size returned by the first call.
+    //
+    // Documentation of the below function:
+    // https://learn.microsoft.com/en-us/windows/win32/api/ncrypt/nf-ncrypt-ncryptgetproperty
+    unsafe {
+        let mut len = 0_u32;
+        let res = NCryptGetProperty(raw_handle, property, std::ptr::null_mut(), 0, &mut len, 0);
+        if res != 0 {
+            return Err(std::io::Error::from_raw_os_error(res));
+        }
+
+        let mut buff = vec![0_u16; (len as usize).div_ceil(2)];
+        let res = NCryptGetProperty(
+            raw_handle,
+            property,
+            buff.as_mut_ptr() as *mut u8,
+            len,
+            &mut len,
+            0,
+        );
+        if res != 0 {
+            return Err(std::io::Error::from_raw_os_error(res));
+        }
+        let end = buff.iter().position(|c| *c == 0).unwrap_or(buff.len());
+        Ok(String::from_utf16_lossy(&buff[..end]))
+    }
+}
+
+/// The signature algorithm depends on the key type: RSASSA-PSS-SHA256 for
+/// RSA keys, ECDSA with the hash matching the curve size for EC keys.
+fn get_ncrypt_signature_algorithm(
+    raw_handle: Cryptography::NCRYPT_KEY_HANDLE,
+) -> Result<PkiSignatureAlgorithm, PkiPrivateKeySignError> {
+    let algorithm = get_ncrypt_string_property(raw_handle, NCRYPT_ALGORITHM_PROPERTY)
+        .map_err(|e| PkiPrivateKeySignError::Internal(e.into()))?;
+    match algorithm.as_str() {
+        "RSA" => Ok(PkiSignatureAlgorithm::RsassaPssSha256),
+        "ECDSA_P256" => Ok(PkiSignatureAlgorithm::EcdsaP256Sha256),
+        "ECDSA_P384" => Ok(PkiSignatureAlgorithm::EcdsaP384Sha384),
+        // Named curve key, the curve is provided as a separate property
+        "ECDSA" => {
+            let curve = get_ncrypt_string_property(raw_handle, NCRYPT_ECC_CURVE_NAME_PROPERTY)
+                .map_err(|e| PkiPrivateKeySignError::Internal(e.into()))?;
+            match curve.as_str() {
+                "nistP256" | "secP256r1" => Ok(PkiSignatureAlgorithm::EcdsaP256Sha256),
+                "nistP384" | "secP384r1" => Ok(PkiSignatureAlgorithm::EcdsaP384Sha384),
+                _ => Err(PkiPrivateKeySignError::UnsupportedAlgorithm),
+            }
         }
+        _ => Err(PkiPrivateKeySignError::UnsupportedAlgorithm),
     }
 }
 
@@ -58,12 +116,37 @@ fn sign_with_ncrypt(
     pkey: &schannel::ncrypt_key::NcryptKey,
     message: &[u8],
 ) -> Result<(PkiSignatureAlgorithm, Bytes), PkiPrivateKeySignError> {
-    const ALGO: PkiSignatureAlgorithm = PkiSignatureAlgorithm::RsassaPssSha256;
-    let hash = sha2::Sha256::digest(message);
     // SAFETY: We retrieve the inner NCRYPT_KEY_HANDLE wrapped by NcryptKey.
     let raw_handle =
         unsafe { schannel::RawPointer::as_ptr(pkey) as Cryptography::NCRYPT_KEY_HANDLE };
 
+    let algorithm = get_ncrypt_signature_algorithm(raw_handle)?;
+    let hash = match algorithm {
+        PkiSignatureAlgorithm::RsassaPssSha256 => sha2::Sha256::digest(message).to_vec(),
+        PkiSignatureAlgorithm::EcdsaP256Sha256 | PkiSignatureAlgorithm::EcdsaP384Sha384 => {
+            crate::ecdsa_hash_message(algorithm, message)
+        }
+    };
+
+    // https://learn.microsoft.com/en-us/windows/win32/api/bcrypt/ns-bcrypt-bcrypt_pss_padding_info
+    let pss_padding_info = BCRYPT_PSS_PADDING_INFO {
+        pszAlgId: NCRYPT_SHA256_ALGORITHM,
+        // This determine the size of the random salt, from WolfSSL it's the convention to use
+        // a salt of the same size of the hash.
+        cbSalt: hash.len() as u32,
+    };
+    let (padding_info_ptr, flags) = match algorithm {
+        // We sign the hash using RSA_PSS_SHA256 algo.
+        PkiSignatureAlgorithm::RsassaPssSha256 => (
+            (&raw const pss_padding_info) as *const std::os::raw::c_void,
+            NCRYPT_PAD_PSS_FLAG,
+        ),
+        // ECDSA has no padding
+        PkiSignatureAlgorithm::EcdsaP256Sha256 | PkiSignatureAlgorithm::EcdsaP384Sha384 => {
+            (std::ptr::null(), 0)
+        }
+    };
+
     // SAFETY: We follow the windows documentation by correctly passing the correct flags according
     // to padding_info type, and the other pointer are either coming from allocated buffer or null
     // pointer with their correct associated sizes.
@@ -72,17 +155,7 @@ fn sign_with_ncrypt(
     // https://learn.microsoft.com/en-us/windows/win32/api/ncrypt/nf-ncrypt-ncryptsignhash
     // Rust doc:
     // https://docs.rs/windows-sys/latest/windows_sys/Win32/Security/Cryptography/fn.NCryptSignHash.html
-    unsafe {
-        // We sign the hash using RSA_PSS_SHA256 algo.
-        let flags = NCRYPT_PAD_PSS_FLAG;
-        // https://learn.microsoft.com/en-us/windows/win32/api/bcrypt/ns-bcrypt-bcrypt_pss_padding_info
-        let padding_info = BCRYPT_PSS_PADDING_INFO {
-            pszAlgId: NCRYPT_SHA256_ALGORITHM,
-            // This determine the size of the random salt, from WolfSSL it's the convention to use
-            // a salt of the same size of the hash.
-            cbSalt: hash.len() as u32,
-        };
-        let padding_info_ptr = (&raw const padding_info) as *const std::os::raw::c_void;
+    let signature = unsafe {
         let mut len = 0_u32;
 
         // 1. Get the size of the resulting signature
@@ -121,12 +194,24 @@ fn sign_with_ncrypt(
                 std::io::Error::from_raw_os_error(res).into(),
             ));
         }
-        Ok((ALGO, buff.into()))
+        buff.truncate(len as usize);
+        buff
+    };
+
+    match algorithm {
+        PkiSignatureAlgorithm::RsassaPssSha256 => Ok((algorithm, signature.into())),
+        // CNG outputs the ECDSA signature in raw form
+        PkiSignatureAlgorithm::EcdsaP256Sha256 | PkiSignatureAlgorithm::EcdsaP384Sha384 => {
+            crate::ecdsa_signature_raw_to_der(algorithm, &signature)
+                .map(|der| (algorithm, der.into()))
+                .map_err(PkiPrivateKeySignError::Internal)
+        }
     }
 }
 
 fn decrypt_with_ncrypt(
     pkey: &schannel::ncrypt_key::NcryptKey,
+    algorithm: PKIEncryptionAlgorithm,
     ciphertext: &[u8],
 ) -> Result<Bytes, PkiPrivateKeyDecryptError> {
     // SAFETY: We retrieve the inner NCRYPT_KEY_HANDLE wrapped by NcryptKey.
@@ -146,7 +231,11 @@ fn decrypt_with_ncrypt(
         let flags = NCRYPT_PAD_OAEP_FLAG;
         // https://learn.microsoft.com/en-us/windows/win32/api/bcrypt/ns-bcrypt-bcrypt_oaep_padding_info
         let padding_info = BCRYPT_OAEP_PADDING_INFO {
-            pszAlgId: NCRYPT_SHA256_ALGORITHM,
+            pszAlgId: match algorithm {
+                PKIEncryptionAlgorithm::RsaesOaepSha256 => NCRYPT_SHA256_ALGORITHM,
+                PKIEncryptionAlgorithm::RsaesOaepSha384 => NCRYPT_SHA384_ALGORITHM,
+                PKIEncryptionAlgorithm::RsaesOaepSha512 => NCRYPT_SHA512_ALGORITHM,
+            },
             pbLabel: std::ptr::null_mut(),
             cbLabel: 0,
         };
@@ -5,10 +5,19 @@ mod subject_alt_names;
 use x509_cert::{
     der::{
         flagset::FlagSet,
-        oid::db::rfc5280::{ID_CE_EXT_KEY_USAGE, ID_CE_KEY_USAGE, ID_CE_SUBJECT_ALT_NAME},
+        oid::db::rfc5280::{
+            ID_CE_CRL_DISTRIBUTION_POINTS, ID_CE_EXT_KEY_USAGE, ID_CE_KEY_USAGE,
+            ID_CE_SUBJECT_ALT_NAME,
+        },
         Decode, Error as DERError, SliceReader,
     },
-    ext::{self, pkix::KeyUsage},
+    ext::{
+        self,
+        pkix::{
+            name::{DistributionPointName, GeneralName},
+            CrlDistributionPoints, KeyUsage,
+        },
+    },
 };
 
 use subject_alt_names::parse_san_octet_string;
@@ -18,6 +27,8 @@ pub use subject_alt_names::SubjectAltName;
 pub struct Extensions {
     pub subject_alt_names: Vec<SubjectAltName>,
     pub key_usage: KeyUsage,
+    /// URLs from which the CRL covering the certificate can be downloaded
+    pub crl_distribution_points: Vec<String>,
 }
 
 impl Default for Extensions {
@@ -25,6 +36,7 @@ impl Default for Extensions {
         Self {
             subject_alt_names: Default::default(),
             key_usage: FlagSet::empty().into(),
+            crl_distribution_points: Default::default(),
         }
     }
 }
@@ -71,6 +83,27 @@ impl TryFrom<Vec<ext::Extension>> for Extensions {
                     // Certificate Additional key usage
                     // https://datatracker.ietf.org/doc/html/rfc5280#section-4.2.1.12
                     ID_CE_EXT_KEY_USAGE => {}
+                    // CRL distribution points
+                    // https://datatracker.ietf.org/doc/html/rfc5280#section-4.2.1.13
+                    ID_CE_CRL_DISTRIBUTION_POINTS => {
+                        let mut reader = SliceReader::new(ext.extn_value.as_bytes())?;
+                        extensions.crl_distribution_points =
+                            CrlDistributionPoints::decode(&mut reader)?
+                                .0
+                                .into_iter()
+                                .filter_map(|dp| match dp.distribution_point {
+                                    Some(DistributionPointName::FullName(names)) => Some(names),
+                                    _ => None,
+                                })
+                                .flatten()
+                                .filter_map(|name| match name {
+                                    GeneralName::UniformResourceIdentifier(uri) => {
+                                        Some(uri.to_string())
+                                    }
+                                    _ => None,
+                                })
+                                .collect();
+                    }
                     _ => {}
                 }
                 Ok(())
@@ -3,9 +3,14 @@
 mod encrypt;
 #[cfg(target_os = "windows")] // TODO: libparsec_platform_pki only supports Windows so far
 mod list;
+mod revocation;
 mod shared;
 mod sign;
 #[cfg(feature = "test-with-testbed")]
 mod testbed;
+#[cfg(all(target_family = "unix", not(target_arch = "wasm32")))]
+mod unix_file_store;
+#[cfg(all(target_family = "unix", not(target_arch = "wasm32")))]
+mod unix_pkcs11;
 mod utils;
 mod x509;
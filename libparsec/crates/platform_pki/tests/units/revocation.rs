@@ -0,0 +1,284 @@
+// Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+#[cfg(not(target_arch = "wasm32"))]
+use libparsec_tests_fixtures::{tmp_path, TmpPath};
+use libparsec_tests_lite::prelude::*;
+use libparsec_types::prelude::*;
+
+use super::utils::{certificates, InstalledCertificates};
+use crate::{
+    verify_certificate, X509CertificateDer, X509CrlLoadError, X509EndCertificate,
+    X509RevocationConfig, X509RevocationPolicy, X509TrustAnchor,
+};
+
+// See `libparsec/crates/platform_pki/test-pki/README.md`
+// Note Bob's certificate is revoked in the Black Mesa CRL.
+const BLACK_MESA_CRL: &[u8] = include_bytes!("../../test-pki/Crl/black_mesa.crl");
+const GLADOS_DEV_TEAM_CRL: &[u8] = include_bytes!("../../test-pki/Crl/glados_dev_team.crl");
+const APERTURE_SCIENCE_CRL: &[u8] = include_bytes!("../../test-pki/Crl/aperture_science.crl");
+
+fn revocation_config(policy: X509RevocationPolicy, crls: &[&[u8]]) -> X509RevocationConfig {
+    let mut config = X509RevocationConfig::new(policy);
+    for crl in crls {
+        config.add_crls(crl).unwrap();
+    }
+    config
+}
+
+fn check(
+    certificate: &X509CertificateDer<'_>,
+    intermediates: &[X509CertificateDer<'_>],
+    trusted_root: X509TrustAnchor<'_>,
+    revocation: &X509RevocationConfig,
+) -> Result<(), webpki::Error> {
+    let end_cert = X509EndCertificate::try_from(certificate).unwrap();
+    verify_certificate(
+        &end_cert,
+        intermediates,
+        &[trusted_root],
+        revocation,
+        DateTime::now(),
+    )
+    .map(|_| ())
+}
+
+#[parsec_test]
+#[case::soft_fail(X509RevocationPolicy::SoftFail)]
+#[case::hard_fail(X509RevocationPolicy::HardFail)]
+fn not_revoked(#[case] policy: X509RevocationPolicy, certificates: &InstalledCertificates) {
+    let revocation = revocation_config(policy, &[BLACK_MESA_CRL]);
+
+    p_assert_matches!(
+        check(
+            &certificates.alice_der_cert(),
+            &[],
+            certificates.black_mesa_trust_anchor(),
+            &revocation,
+        ),
+        Ok(())
+    );
+}
+
+#[parsec_test]
+#[case::soft_fail(X509RevocationPolicy::SoftFail)]
+#[case::hard_fail(X509RevocationPolicy::HardFail)]
+fn revoked(#[case] policy: X509RevocationPolicy, certificates: &InstalledCertificates) {
+    let revocation = revocation_config(policy, &[BLACK_MESA_CRL]);
+
+    p_assert_matches!(
+        check(
+            &certificates.bob_der_cert(),
+            &[],
+            certificates.black_mesa_trust_anchor(),
+            &revocation,
+        ),
+        Err(webpki::Error::CertRevoked)
+    );
+
+    // Certificate trustchain is checked before the signature, so a dummy signature is enough
+    p_assert_matches!(
+        crate::verify_message(
+            b"The cake is a lie!",
+            b"<dummy>",
+            PkiSignatureAlgorithm::RsassaPssSha256,
+            &certificates.bob_der_cert(),
+            [].into_iter(),
+            &[certificates.black_mesa_trust_anchor()],
+            &revocation,
+            DateTime::now(),
+        ),
+        Err(crate::VerifyMessageError::X509CertificateRevoked)
+    );
+}
+
+#[parsec_test]
+fn no_crl_soft_fail(certificates: &InstalledCertificates) {
+    let revocation = X509RevocationConfig::new(X509RevocationPolicy::SoftFail);
+
+    p_assert_matches!(
+        check(
+            &certificates.bob_der_cert(),
+            &[],
+            certificates.black_mesa_trust_anchor(),
+            &revocation,
+        ),
+        Ok(())
+    );
+}
+
+#[parsec_test]
+fn no_crl_hard_fail(certificates: &InstalledCertificates) {
+    let revocation = X509RevocationConfig::new(X509RevocationPolicy::HardFail);
+
+    p_assert_matches!(
+        crate::verify_message(
+            b"The cake is a lie!",
+            b"<dummy>",
+            PkiSignatureAlgorithm::RsassaPssSha256,
+            &certificates.alice_der_cert(),
+            [].into_iter(),
+            &[certificates.black_mesa_trust_anchor()],
+            &revocation,
+            DateTime::now(),
+        ),
+        Err(
+            crate::VerifyMessageError::X509CertificateRevocationStatusUnknown(
+                webpki::Error::UnknownRevocationStatus
+            )
+        )
+    );
+}
+
+#[parsec_test]
+fn intermediate_crl(certificates: &InstalledCertificates) {
+    let intermediates = [certificates.glados_dev_team_der_cert()];
+
+    // All the certificates of the chain are covered by a CRL
+    let revocation = revocation_config(
+        X509RevocationPolicy::HardFail,
+        &[GLADOS_DEV_TEAM_CRL, APERTURE_SCIENCE_CRL],
+    );
+    p_assert_matches!(
+        check(
+            &certificates.mallory_sign_der_cert(),
+            &intermediates,
+            certificates.aperture_science_trust_anchor(),
+            &revocation,
+        ),
+        Ok(())
+    );
+
+    // The leaf certificate is not covered by a CRL (its issuer is the intermediate)
+    let revocation = revocation_config(X509RevocationPolicy::HardFail, &[APERTURE_SCIENCE_CRL]);
+    p_assert_matches!(
+        check(
+            &certificates.mallory_sign_der_cert(),
+            &intermediates,
+            certificates.aperture_science_trust_anchor(),
+            &revocation,
+        ),
+        Err(webpki::Error::UnknownRevocationStatus)
+    );
+    let revocation = revocation_config(X509RevocationPolicy::SoftFail, &[APERTURE_SCIENCE_CRL]);
+    p_assert_matches!(
+        check(
+            &certificates.mallory_sign_der_cert(),
+            &intermediates,
+            certificates.aperture_science_trust_anchor(),
+            &revocation,
+        ),
+        Ok(())
+    );
+}
+
+#[parsec_test]
+fn add_crls() {
+    let mut revocation = X509RevocationConfig::default();
+
+    // PEM file containing multiple CRLs
+    let pem = [BLACK_MESA_CRL, GLADOS_DEV_TEAM_CRL].concat();
+    revocation.add_crls(&pem).unwrap();
+    p_assert_eq!(revocation.crls_count(), 2);
+
+    // DER CRL
+    let der = crate::parse_crl_ders(APERTURE_SCIENCE_CRL)
+        .unwrap()
+        .remove(0);
+    revocation.add_crls(&der).unwrap();
+    p_assert_eq!(revocation.crls_count(), 3);
+
+    p_assert_matches!(
+        revocation.add_crls(b"<dummy>"),
+        Err(X509CrlLoadError::InvalidCrl(_))
+    );
+    p_assert_eq!(revocation.crls_count(), 3);
+}
+
+#[parsec_test]
+fn is_outdated() {
+    // Test CRLs next update is in 2124
+    let now: DateTime = "2026-02-01T00:00:00Z".parse().unwrap();
+    p_assert_eq!(crate::crl_store::is_outdated(BLACK_MESA_CRL, now), false);
+
+    let now: DateTime = "2125-01-01T00:00:00Z".parse().unwrap();
+    p_assert_eq!(crate::crl_store::is_outdated(BLACK_MESA_CRL, now), true);
+
+    p_assert_eq!(crate::crl_store::is_outdated(b"<dummy>", now), true);
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[parsec_test]
+async fn load_crl_dir(tmp_path: TmpPath, certificates: &InstalledCertificates) {
+    let crl_dir = tmp_path.join("crl");
+    std::fs::create_dir(&crl_dir).unwrap();
+    std::fs::write(crl_dir.join("black_mesa.crl"), BLACK_MESA_CRL).unwrap();
+    // Invalid files are ignored
+    std::fs::write(crl_dir.join("dummy.crl"), b"<dummy>").unwrap();
+
+    let store = crate::crl_store::X509CrlStore::new(&tmp_path);
+    let bob = certificates.bob_der_cert();
+    let revocation = store
+        .revocation_config(
+            X509RevocationPolicy::SoftFail,
+            std::iter::empty(),
+            std::iter::once(bob.as_ref()),
+        )
+        .await;
+
+    p_assert_eq!(revocation.crls_count(), 1);
+    p_assert_matches!(
+        check(
+            &bob,
+            &[],
+            certificates.black_mesa_trust_anchor(),
+            &revocation,
+        ),
+        Err(webpki::Error::CertRevoked)
+    );
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[parsec_test]
+async fn extra_crls(tmp_path: TmpPath, certificates: &InstalledCertificates) {
+    let store = crate::crl_store::X509CrlStore::new(&tmp_path);
+    let bob = certificates.bob_der_cert();
+    // Invalid CRLs are ignored
+    let extra_crls: [&[u8]; 2] = [BLACK_MESA_CRL, b"<dummy>"];
+    let revocation = store
+        .revocation_config(
+            X509RevocationPolicy::HardFail,
+            extra_crls.into_iter(),
+            std::iter::once(bob.as_ref()),
+        )
+        .await;
+
+    p_assert_eq!(revocation.policy, X509RevocationPolicy::HardFail);
+    p_assert_eq!(revocation.crls_count(), 1);
+    p_assert_matches!(
+        check(
+            &bob,
+            &[],
+            certificates.black_mesa_trust_anchor(),
+            &revocation,
+        ),
+        Err(webpki::Error::CertRevoked)
+    );
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[parsec_test]
+async fn failed_fetch_is_not_retried_immediately(tmp_path: TmpPath) {
+    let store = crate::crl_store::X509CrlStore::new(&tmp_path);
+    // Nothing listens on the discard port
+    let url = "http://127.0.0.1:9/unreachable.crl";
+
+    p_assert_eq!(store.get_distribution_point_crl(url).await, None);
+    let failed_on = *store.failed_fetches.lock().unwrap().get(url).unwrap();
+
+    // Second attempt is skipped (failure timestamp is left untouched)
+    p_assert_eq!(store.get_distribution_point_crl(url).await, None);
+    p_assert_eq!(
+        *store.failed_fetches.lock().unwrap().get(url).unwrap(),
+        failed_on
+    );
+}
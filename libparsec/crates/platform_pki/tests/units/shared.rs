@@ -7,6 +7,7 @@ use super::utils::{certificates, InstalledCertificates};
 use crate::{
     get_root_x509_certificate_info_from_trustchain, verify_certificate,
     GetRootX509CertificateInfoFromTrustchainError, RootX509CertificateInfo, X509EndCertificate,
+    X509RevocationConfig,
 };
 
 #[rstest]
@@ -18,6 +19,7 @@ fn test_verify_cert_ok(certificates: &InstalledCertificates) {
         &bob_end_entity_cert,
         &[],
         &[certificates.black_mesa_trust_anchor()],
+        &X509RevocationConfig::default(),
         DateTime::now(),
     )
     .unwrap();
@@ -31,7 +33,7 @@ fn test_verify_unknown_issuer(certificates: &InstalledCertificates) {
     let err = verify_certificate(
         &bob_end_entity_cert,
         &[],
-        &[], // No trusted root, so always invalid
+        &[], // No trusted root, &X509RevocationConfig::default(), so always invalid
         DateTime::now(),
     )
     .map(|_| ())
@@ -50,6 +52,7 @@ fn test_verify_with_intermediate(certificates: &InstalledCertificates) {
         &mallory_sign_end_entity_cert,
         &[glados_dev_team_der],
         &[certificates.aperture_science_trust_anchor()],
+        &X509RevocationConfig::default(),
         DateTime::now(),
     )
     .unwrap();
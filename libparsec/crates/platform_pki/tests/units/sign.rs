@@ -4,6 +4,7 @@ use libparsec_tests_lite::prelude::*;
 use libparsec_types::prelude::*;
 
 use super::utils::{certificates, InstalledCertificates};
+use crate::X509RevocationConfig;
 
 #[cfg(target_os = "windows")]
 #[parsec_test]
@@ -25,6 +26,7 @@ async fn sign_and_verify(certificates: &InstalledCertificates) {
         &certificates.alice_der_cert(),
         [].into_iter(),
         &[certificates.black_mesa_trust_anchor()],
+        &X509RevocationConfig::default(),
         now,
     )
     .unwrap();
@@ -55,11 +57,103 @@ async fn verify(certificates: &InstalledCertificates) {
         &certificates.alice_der_cert(),
         [].into_iter(),
         &[certificates.black_mesa_trust_anchor()],
+        &X509RevocationConfig::default(),
         now,
     )
     .unwrap();
 }
 
+#[parsec_test]
+async fn verify_ecdsa(certificates: &InstalledCertificates) {
+    let payload = b"The cake is a lie!";
+    let algo = PkiSignatureAlgorithm::EcdsaP256Sha256;
+    // Pre-generated signature (`openssl dgst -sha256 -sign eve.key`) to ensure test stability
+    let signature = hex!(
+        "3045022100ce9466e84f43ef059bf6cb978b7363990bd29d1e71f82ffc17f757d4c5aa12d402"
+        "20795bea022b659c6a807983a35884f308f3ef5a599804824d0fd9ed6728fb12ef"
+    );
+
+    crate::verify_message(
+        payload.as_ref(),
+        &signature,
+        algo,
+        &certificates.eve_der_cert(),
+        [].into_iter(),
+        &[certificates.black_mesa_trust_anchor()],
+        &X509RevocationConfig::default(),
+        DateTime::now(),
+    )
+    .unwrap();
+
+    // Signature algorithm must match the certificate's key type
+    p_assert_matches!(
+        crate::verify_message(
+            payload.as_ref(),
+            &signature,
+            PkiSignatureAlgorithm::EcdsaP384Sha384,
+            &certificates.eve_der_cert(),
+            [].into_iter(),
+            &[certificates.black_mesa_trust_anchor()],
+            &X509RevocationConfig::default(),
+            DateTime::now(),
+        ),
+        Err(crate::VerifyMessageError::InvalidSignature(_))
+    );
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[parsec_test]
+#[case::no_padding(
+    // r and s both have their most significant bit unset
+    &hex!(
+        "7e9466e84f43ef059bf6cb978b7363990bd29d1e71f82ffc17f757d4c5aa12d4"
+        "795bea022b659c6a807983a35884f308f3ef5a599804824d0fd9ed6728fb12ef"
+    ),
+    &hex!(
+        "304402207e9466e84f43ef059bf6cb978b7363990bd29d1e71f82ffc17f757d4c5aa12d4"
+        "0220795bea022b659c6a807983a35884f308f3ef5a599804824d0fd9ed6728fb12ef"
+    ),
+)]
+#[case::padding(
+    // r has its most significant bit set, hence must be prefixed by a zero
+    &hex!(
+        "ce9466e84f43ef059bf6cb978b7363990bd29d1e71f82ffc17f757d4c5aa12d4"
+        "795bea022b659c6a807983a35884f308f3ef5a599804824d0fd9ed6728fb12ef"
+    ),
+    &hex!(
+        "3045022100ce9466e84f43ef059bf6cb978b7363990bd29d1e71f82ffc17f757d4c5aa12d4"
+        "0220795bea022b659c6a807983a35884f308f3ef5a599804824d0fd9ed6728fb12ef"
+    ),
+)]
+#[case::leading_zeros(
+    // Leading zeros are stripped
+    &hex!(
+        "0000000000000000000000000000000000000000000000000000000000000001"
+        "00000000000000000000000000000000000000000000000000000000000000ff"
+    ),
+    &hex!("3007020101020200ff"),
+)]
+fn ecdsa_signature_raw_to_der(#[case] raw: &[u8], #[case] expected: &[u8]) {
+    p_assert_eq!(
+        crate::ecdsa_signature_raw_to_der(PkiSignatureAlgorithm::EcdsaP256Sha256, raw).unwrap(),
+        expected
+    );
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[parsec_test]
+fn ecdsa_signature_raw_to_der_invalid_size() {
+    let algo = PkiSignatureAlgorithm::EcdsaP256Sha256;
+    p_assert_matches!(crate::ecdsa_signature_raw_to_der(algo, b""), Err(_));
+    p_assert_matches!(crate::ecdsa_signature_raw_to_der(algo, b"abc"), Err(_));
+    // Signature size must match the curve
+    p_assert_matches!(crate::ecdsa_signature_raw_to_der(algo, &[1; 96]), Err(_));
+    p_assert_matches!(
+        crate::ecdsa_signature_raw_to_der(PkiSignatureAlgorithm::EcdsaP384Sha384, &[1; 64]),
+        Err(_)
+    );
+}
+
 // TODO: Support `KeyUsage` field in X509 certificate
 //       see https://github.com/Scille/parsec-cloud/issues/12087
 // #[parsec_test]
@@ -101,6 +195,7 @@ async fn verify_message_ko_outdated_certificate(certificates: &InstalledCertific
             &certificates.alice_der_cert(),
             validation_path.intermediates.iter().map(|c| c.as_ref()),
             &[validation_path.root],
+            &X509RevocationConfig::default(),
             "9999-01-01T00:00:00Z".parse().unwrap(),
         ),
         Err(crate::VerifyMessageError::X509CertificateUntrusted(
@@ -138,6 +233,7 @@ async fn verify_message_ko_different_certificate(certificates: &InstalledCertifi
             &certificates.bob_der_cert(),
             validation_path.intermediates.iter().map(|c| c.as_ref()),
             &[validation_path.root],
+            &X509RevocationConfig::default(),
             DateTime::now(),
         ),
         Err(crate::VerifyMessageError::InvalidSignature(
@@ -174,6 +270,7 @@ async fn verify_message_ko_different_payload(certificates: &InstalledCertificate
             &validation_path.leaf,
             validation_path.intermediates.iter().map(|c| c.as_ref()),
             &[validation_path.root],
+            &X509RevocationConfig::default(),
             DateTime::now(),
         ),
         Err(crate::VerifyMessageError::InvalidSignature(
@@ -4,7 +4,9 @@ use libparsec_tests_fixtures::prelude::*;
 use libparsec_types::prelude::*;
 
 use super::utils::certificates;
-use crate::{encrypt_message, verify_message, PkiSystem, PkiSystemOpenCertificateError};
+use crate::{
+    encrypt_message, verify_message, PkiSystem, PkiSystemOpenCertificateError, X509RevocationConfig,
+};
 
 async fn init_pki(env: &TestbedEnv) -> PkiSystem {
     // We initialize the testbed mocked version of the PKI system, hence we never
@@ -81,6 +83,7 @@ async fn testbed_sign_verify(env: &TestbedEnv) {
         validation_path.leaf.as_ref(),
         validation_path.intermediates.iter().map(|x| x.as_ref()),
         &[validation_path.root],
+        &X509RevocationConfig::default(),
         "2026-06-01T00:00:00Z".parse().unwrap(),
     )
     .unwrap();
@@ -0,0 +1,274 @@
+// Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+use libparsec_tests_fixtures::{tmp_path, TmpPath};
+use libparsec_tests_lite::prelude::*;
+use libparsec_types::prelude::*;
+use rustls_pki_types::{pem::PemObject, PrivateKeyDer};
+
+use super::utils::{certificates, InstalledCertificates};
+use crate::{
+    AvailablePkiCertificate, PkiCertificateGetValidationPathError, PkiSystem,
+    PkiSystemOpenCertificateError, X509RevocationConfig,
+};
+
+const ALICE_PEM: &[u8] = include_bytes!("../../test-pki/Cert/alice.crt");
+const ALICE_KEY_PEM: &[u8] = include_bytes!("../../test-pki/Cert/alice.key");
+const BOB_PEM: &[u8] = include_bytes!("../../test-pki/Cert/bob.crt");
+const EVE_PEM: &[u8] = include_bytes!("../../test-pki/Cert/eve.crt");
+const EVE_KEY_PEM: &[u8] = include_bytes!("../../test-pki/Cert/eve.key");
+const EVE_PFX: &[u8] = include_bytes!("../../test-pki/Cert/eve.pfx");
+const BLACK_MESA_PEM: &[u8] = include_bytes!("../../test-pki/Root/black_mesa.crt");
+
+/// Populate `<config_dir>/pki` with Alice (certificate + private key) and Bob (certificate only),
+/// and `<config_dir>/pki-trust-anchors` with the Black Mesa root certificate.
+async fn init_pki_with_files(tmp_path: &TmpPath) -> PkiSystem {
+    let pki_dir = tmp_path.join("pki");
+    std::fs::create_dir(&pki_dir).unwrap();
+    std::fs::write(pki_dir.join("alice.crt"), ALICE_PEM).unwrap();
+    std::fs::write(pki_dir.join("alice.key"), ALICE_KEY_PEM).unwrap();
+    std::fs::write(pki_dir.join("bob.crt"), BOB_PEM).unwrap();
+    let trust_anchors_dir = tmp_path.join("pki-trust-anchors");
+    std::fs::create_dir(&trust_anchors_dir).unwrap();
+    std::fs::write(trust_anchors_dir.join("black_mesa.crt"), BLACK_MESA_PEM).unwrap();
+
+    PkiSystem::init(tmp_path, None).await.unwrap()
+}
+
+#[parsec_test]
+async fn list_user_certificates(tmp_path: TmpPath, certificates: &InstalledCertificates) {
+    let pki = init_pki_with_files(&tmp_path).await;
+
+    let available = pki.list_user_certificates().await.unwrap();
+
+    // Only Alice is listed since we don't have the private key of the other certificates
+    let references = available
+        .iter()
+        .map(|cert| match cert {
+            AvailablePkiCertificate::Valid { reference, .. } => reference.clone(),
+            AvailablePkiCertificate::Invalid { reference, .. } => reference.clone(),
+        })
+        .collect::<Vec<_>>();
+    p_assert_matches!(references.as_slice(), [reference] if reference.hash == certificates.alice_cert_ref().hash);
+}
+
+#[parsec_test]
+async fn sign_and_verify(tmp_path: TmpPath, certificates: &InstalledCertificates) {
+    let payload = b"The cake is a lie!";
+    let pki = init_pki_with_files(&tmp_path).await;
+
+    let cert = pki
+        .open_certificate(&certificates.alice_cert_ref())
+        .await
+        .unwrap();
+    p_assert_eq!(
+        cert.to_reference().await.unwrap().hash,
+        certificates.alice_cert_ref().hash
+    );
+    let key = cert.request_private_key().await.unwrap();
+    let (algo, signature) = key.sign(payload.as_ref()).await.unwrap();
+
+    crate::verify_message(
+        payload.as_ref(),
+        &signature,
+        algo,
+        &certificates.alice_der_cert(),
+        [].into_iter(),
+        &[certificates.black_mesa_trust_anchor()],
+        &X509RevocationConfig::default(),
+        DateTime::now(),
+    )
+    .unwrap();
+}
+
+#[parsec_test]
+#[case::pem(&[("eve.crt", EVE_PEM), ("eve.key", EVE_KEY_PEM)])]
+#[case::pkcs12(&[("eve.pfx", EVE_PFX)])]
+async fn sign_and_verify_ecdsa(
+    #[case] files: &[(&str, &[u8])],
+    tmp_path: TmpPath,
+    certificates: &InstalledCertificates,
+) {
+    let payload = b"The cake is a lie!";
+    let pki_dir = tmp_path.join("pki");
+    std::fs::create_dir(&pki_dir).unwrap();
+    for (name, content) in files {
+        std::fs::write(pki_dir.join(name), content).unwrap();
+    }
+    let pki = PkiSystem::init(&tmp_path, None).await.unwrap();
+
+    let cert = pki
+        .open_certificate(&certificates.eve_cert_ref())
+        .await
+        .unwrap();
+    let key = cert.request_private_key().await.unwrap();
+    let (algo, signature) = key.sign(payload.as_ref()).await.unwrap();
+    p_assert_eq!(algo, PkiSignatureAlgorithm::EcdsaP256Sha256);
+
+    crate::verify_message(
+        payload.as_ref(),
+        &signature,
+        algo,
+        &certificates.eve_der_cert(),
+        [].into_iter(),
+        &[certificates.black_mesa_trust_anchor()],
+        &X509RevocationConfig::default(),
+        DateTime::now(),
+    )
+    .unwrap();
+
+    // ECDSA keys cannot be used to decrypt
+    p_assert_matches!(
+        key.decrypt(PKIEncryptionAlgorithm::RsaesOaepSha256, b"")
+            .await,
+        Err(crate::PkiPrivateKeyDecryptError::UnsupportedAlgorithm)
+    );
+}
+
+#[parsec_test]
+async fn encrypt_decrypt(tmp_path: TmpPath, certificates: &InstalledCertificates) {
+    let payload = b"The cake is a lie!";
+    let pki = init_pki_with_files(&tmp_path).await;
+
+    let (algo, encrypted_message, cert_ref) =
+        certificates.alice_encrypt_message(payload.as_ref()).await;
+    let cert = pki.open_certificate(&cert_ref).await.unwrap();
+    let key = cert.request_private_key().await.unwrap();
+    let decrypted_message = key.decrypt(algo, &encrypted_message).await.unwrap();
+
+    p_assert_eq!(*decrypted_message, *payload);
+}
+
+#[parsec_test]
+#[case::sha256(PKIEncryptionAlgorithm::RsaesOaepSha256)]
+#[case::sha384(PKIEncryptionAlgorithm::RsaesOaepSha384)]
+#[case::sha512(PKIEncryptionAlgorithm::RsaesOaepSha512)]
+async fn encrypt_decrypt_with_algorithm(
+    #[case] algorithm: PKIEncryptionAlgorithm,
+    tmp_path: TmpPath,
+    certificates: &InstalledCertificates,
+) {
+    let payload = b"The cake is a lie!";
+    let pki = init_pki_with_files(&tmp_path).await;
+
+    let (algo, encrypted_message) = crate::encrypt_message_with_algorithm(
+        certificates.alice_der_cert(),
+        algorithm,
+        payload.as_ref(),
+    )
+    .await
+    .unwrap();
+    p_assert_eq!(algo, algorithm);
+
+    let cert = pki
+        .open_certificate(&certificates.alice_cert_ref())
+        .await
+        .unwrap();
+    let key = cert.request_private_key().await.unwrap();
+    let decrypted_message = key.decrypt(algo, &encrypted_message).await.unwrap();
+    p_assert_eq!(*decrypted_message, *payload);
+
+    // Decrypting with another algorithm fails
+    let other_algorithm = match algorithm {
+        PKIEncryptionAlgorithm::RsaesOaepSha256 => PKIEncryptionAlgorithm::RsaesOaepSha512,
+        _ => PKIEncryptionAlgorithm::RsaesOaepSha256,
+    };
+    p_assert_matches!(
+        key.decrypt(other_algorithm, &encrypted_message).await,
+        Err(crate::PkiPrivateKeyDecryptError::Decrypt(_))
+    );
+}
+
+#[parsec_test]
+async fn get_validation_path(tmp_path: TmpPath, certificates: &InstalledCertificates) {
+    let pki = init_pki_with_files(&tmp_path).await;
+
+    let cert = pki
+        .open_certificate(&certificates.alice_cert_ref())
+        .await
+        .unwrap();
+    let path = cert.get_validation_path().await.unwrap();
+
+    p_assert_eq!(path.leaf, certificates.alice_der_cert());
+    assert!(path.intermediates.is_empty());
+    p_assert_eq!(path.root, certificates.black_mesa_trust_anchor());
+}
+
+#[parsec_test]
+async fn self_signed_certificate_in_files_dir_is_not_trusted(
+    tmp_path: TmpPath,
+    certificates: &InstalledCertificates,
+) {
+    // The root certificate is among the PKI files instead of the trust anchors
+    let pki_dir = tmp_path.join("pki");
+    std::fs::create_dir(&pki_dir).unwrap();
+    std::fs::write(pki_dir.join("alice.crt"), ALICE_PEM).unwrap();
+    std::fs::write(pki_dir.join("alice.key"), ALICE_KEY_PEM).unwrap();
+    std::fs::write(pki_dir.join("black_mesa.crt"), BLACK_MESA_PEM).unwrap();
+    let pki = PkiSystem::init(&tmp_path, None).await.unwrap();
+
+    let cert = pki
+        .open_certificate(&certificates.alice_cert_ref())
+        .await
+        .unwrap();
+    p_assert_matches!(
+        cert.get_validation_path().await,
+        Err(PkiCertificateGetValidationPathError::Untrusted)
+    );
+}
+
+#[parsec_test]
+async fn open_certificate_without_private_key(
+    tmp_path: TmpPath,
+    certificates: &InstalledCertificates,
+) {
+    let pki = init_pki_with_files(&tmp_path).await;
+
+    // Bob's certificate is only used as part of the trust material
+    p_assert_matches!(
+        pki.open_certificate(&certificates.bob_cert_ref()).await,
+        Err(PkiSystemOpenCertificateError::NotFound)
+    );
+}
+
+#[parsec_test]
+async fn pkcs12(tmp_path: TmpPath, certificates: &InstalledCertificates) {
+    let key_der = match PrivateKeyDer::from_pem_slice(ALICE_KEY_PEM).unwrap() {
+        PrivateKeyDer::Pkcs8(key) => key.secret_pkcs8_der().to_vec(),
+        _ => unreachable!(),
+    };
+    let mut keystore = p12_keystore::KeyStore::new();
+    keystore.add_entry(
+        "Alice's certificate",
+        p12_keystore::KeyStoreEntry::PrivateKeyChain(p12_keystore::PrivateKeyChain::new(
+            key_der,
+            b"alice",
+            [
+                p12_keystore::Certificate::from_der(&certificates.alice_der_cert()).unwrap(),
+                p12_keystore::Certificate::from_der(&certificates.black_mesa_der_cert()).unwrap(),
+            ],
+        )),
+    );
+    let pkcs12 = keystore.writer("").write().unwrap();
+
+    let pki_dir = tmp_path.join("pki");
+    std::fs::create_dir(&pki_dir).unwrap();
+    std::fs::write(pki_dir.join("alice.p12"), pkcs12).unwrap();
+    let trust_anchors_dir = tmp_path.join("pki-trust-anchors");
+    std::fs::create_dir(&trust_anchors_dir).unwrap();
+    std::fs::write(trust_anchors_dir.join("black_mesa.crt"), BLACK_MESA_PEM).unwrap();
+    let pki = PkiSystem::init(&tmp_path, None).await.unwrap();
+
+    let available = pki.list_user_certificates().await.unwrap();
+    p_assert_matches!(
+        available.as_slice(),
+        [AvailablePkiCertificate::Valid { friendly_name, reference, .. }]
+        if friendly_name == "Alice's certificate" && reference.hash == certificates.alice_cert_ref().hash
+    );
+
+    let cert = pki
+        .open_certificate(&certificates.alice_cert_ref())
+        .await
+        .unwrap();
+    let path = cert.get_validation_path().await.unwrap();
+    p_assert_eq!(path.root, certificates.black_mesa_trust_anchor());
+}
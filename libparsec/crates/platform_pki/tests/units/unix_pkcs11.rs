@@ -0,0 +1,112 @@
+// Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+// Those tests require a PKCS#11 token containing the test PKI (e.g. with SoftHSM2,
+// see `examples/import_testpki_softhsm2.sh`) and `PARSEC_PKCS11_MODULE` and
+// `PARSEC_PKCS11_PIN` to be configured accordingly, hence they are ignored by default.
+
+use libparsec_tests_fixtures::{tmp_path, TmpPath};
+use libparsec_tests_lite::prelude::*;
+use libparsec_types::prelude::*;
+
+use super::utils::{certificates, InstalledCertificates};
+use crate::{
+    AvailablePkiCertificate, PkiCertificateGetValidationPathError, PkiSystem, X509RevocationConfig,
+};
+
+const BLACK_MESA_PEM: &[u8] = include_bytes!("../../test-pki/Root/black_mesa.crt");
+
+async fn init_pki_with_pkcs11(tmp_path: &TmpPath) -> PkiSystem {
+    assert!(
+        std::env::var_os("PARSEC_PKCS11_MODULE").is_some(),
+        "`PARSEC_PKCS11_MODULE` must point to the PKCS#11 module containing the test PKI"
+    );
+    PkiSystem::init(tmp_path, None).await.unwrap()
+}
+
+#[ignore = "requires a PKCS#11 token containing the test PKI"]
+#[parsec_test]
+async fn list_user_certificates(tmp_path: TmpPath, certificates: &InstalledCertificates) {
+    let pki = init_pki_with_pkcs11(&tmp_path).await;
+
+    let available = pki.list_user_certificates().await.unwrap();
+
+    let hashes = available
+        .iter()
+        .map(|cert| match cert {
+            AvailablePkiCertificate::Valid { reference, .. } => reference.hash.clone(),
+            AvailablePkiCertificate::Invalid { reference, .. } => reference.hash.clone(),
+        })
+        .collect::<Vec<_>>();
+    assert!(hashes.contains(&certificates.alice_cert_ref().hash));
+    // Root certificates are on the token without their private key
+    assert!(!hashes.contains(&certificates.black_mesa_cert_ref().hash));
+}
+
+#[ignore = "requires a PKCS#11 token containing the test PKI"]
+#[parsec_test]
+async fn sign_and_verify(tmp_path: TmpPath, certificates: &InstalledCertificates) {
+    let payload = b"The cake is a lie!";
+    let pki = init_pki_with_pkcs11(&tmp_path).await;
+
+    let cert = pki
+        .open_certificate(&certificates.alice_cert_ref())
+        .await
+        .unwrap();
+    let key = cert.request_private_key().await.unwrap();
+    let (algo, signature) = key.sign(payload.as_ref()).await.unwrap();
+
+    crate::verify_message(
+        payload.as_ref(),
+        &signature,
+        algo,
+        &certificates.alice_der_cert(),
+        [].into_iter(),
+        &[certificates.black_mesa_trust_anchor()],
+        &X509RevocationConfig::default(),
+        DateTime::now(),
+    )
+    .unwrap();
+}
+
+#[ignore = "requires a PKCS#11 token containing the test PKI"]
+#[parsec_test]
+async fn encrypt_decrypt(tmp_path: TmpPath, certificates: &InstalledCertificates) {
+    let payload = b"The cake is a lie!";
+    let pki = init_pki_with_pkcs11(&tmp_path).await;
+
+    let (algo, encrypted_message, cert_ref) =
+        certificates.alice_encrypt_message(payload.as_ref()).await;
+    let cert = pki.open_certificate(&cert_ref).await.unwrap();
+    let key = cert.request_private_key().await.unwrap();
+    let decrypted_message = key.decrypt(algo, &encrypted_message).await.unwrap();
+
+    p_assert_eq!(*decrypted_message, *payload);
+}
+
+#[ignore = "requires a PKCS#11 token containing the test PKI"]
+#[parsec_test]
+async fn root_certificate_on_token_is_not_trusted(
+    tmp_path: TmpPath,
+    certificates: &InstalledCertificates,
+) {
+    let pki = init_pki_with_pkcs11(&tmp_path).await;
+
+    let cert = pki
+        .open_certificate(&certificates.alice_cert_ref())
+        .await
+        .unwrap();
+    p_assert_matches!(
+        cert.get_validation_path().await,
+        Err(PkiCertificateGetValidationPathError::Untrusted)
+    );
+
+    // Once explicitly configured as trust anchor, the root is trusted
+
+    let trust_anchors_dir = tmp_path.join("pki-trust-anchors");
+    std::fs::create_dir(&trust_anchors_dir).unwrap();
+    std::fs::write(trust_anchors_dir.join("black_mesa.crt"), BLACK_MESA_PEM).unwrap();
+
+    let path = cert.get_validation_path().await.unwrap();
+    p_assert_eq!(path.leaf, certificates.alice_der_cert());
+    p_assert_eq!(path.root, certificates.black_mesa_trust_anchor());
+}
@@ -15,12 +15,14 @@ pub(super) const MALLORY_SIGN_SHA256_CERT_HASH: &str =
     "sha256-00e71WWTQGUjt0GQWPqhYzL8a8NPY3qNS/WhVfeXj9Q=";
 pub(super) const MALLORY_ENCRYPT_SHA256_CERT_HASH: &str =
     "sha256-lPHjEH4TmPMO377j8OoL18hnUBEqNy0CfhNoP60+seY=";
+pub(super) const EVE_SHA256_CERT_HASH: &str = "sha256-e85mW+u0OiPUPPAhNAeL2cVX3dzrplGDwYDuvx/UPwc=";
 
 const ALICE_PEM: &[u8] = include_bytes!("../../test-pki/Cert/alice.crt");
 const BOB_PEM: &[u8] = include_bytes!("../../test-pki/Cert/bob.crt");
 const MALLORY_ENCRYPT_PEM: &[u8] = include_bytes!("../../test-pki/Cert/mallory-encrypt.crt");
 const MALLORY_SIGN_PEM: &[u8] = include_bytes!("../../test-pki/Cert/mallory-sign.crt");
 const OLD_BOBY_PEM: &[u8] = include_bytes!("../../test-pki/Cert/old-boby.crt");
+const EVE_PEM: &[u8] = include_bytes!("../../test-pki/Cert/eve.crt");
 const GLADOS_DEV_TEAM_PEM: &[u8] =
     include_bytes!("../../test-pki/Intermediate/glados_dev_team.crt");
 const APERTURE_SCIENCE_PEM: &[u8] = include_bytes!("../../test-pki/Root/aperture_science.crt");
@@ -38,11 +40,22 @@ impl InstalledCertificates {
         Self::make_cert_ref(ALICE_SHA256_CERT_HASH)
     }
 
-    #[expect(dead_code)]
+    #[cfg_attr(
+        not(all(target_family = "unix", not(target_arch = "wasm32"))),
+        expect(dead_code)
+    )]
     pub fn bob_cert_ref(&self) -> X509CertificateReference {
         Self::make_cert_ref(BOB_SHA256_CERT_HASH)
     }
 
+    #[cfg_attr(
+        not(all(target_family = "unix", not(target_arch = "wasm32"))),
+        expect(dead_code)
+    )]
+    pub fn eve_cert_ref(&self) -> X509CertificateReference {
+        Self::make_cert_ref(EVE_SHA256_CERT_HASH)
+    }
+
     #[expect(dead_code)]
     pub fn black_mesa_cert_ref(&self) -> X509CertificateReference {
         Self::make_cert_ref(BLACK_MESA_SHA256_CERT_HASH)
@@ -93,6 +106,11 @@ impl InstalledCertificates {
         load_pem_and_return_der(OLD_BOBY_PEM)
     }
 
+    /// Eve's certificate uses an ECDSA P-256 key (signed by the Black Mesa RSA root).
+    pub fn eve_der_cert(&self) -> X509CertificateDer<'static> {
+        load_pem_and_return_der(EVE_PEM)
+    }
+
     pub fn black_mesa_der_cert(&self) -> X509CertificateDer<'static> {
         load_pem_and_return_der(BLACK_MESA_PEM)
     }
@@ -125,7 +143,7 @@ impl InstalledCertificates {
             .to_owned()
     }
 
-    #[cfg_attr(not(target_os = "windows"), expect(dead_code))]
+    #[cfg_attr(target_arch = "wasm32", expect(dead_code))]
     pub async fn alice_encrypt_message(
         &self,
         payload: &[u8],
@@ -170,12 +188,12 @@ pub(super) async fn initialize_pki_system() -> PkiSystem {
                 ",
                 ALICE_SHA256_CERT_HASH, error
             );
-            // TODO: Explain how to install the test PKI certificates once Linux support is available
-            //       see https://github.com/Scille/parsec-cloud/issues/11848
             #[cfg(not(windows))]
             panic!(
                 "Certificate \x1b[1;31m{}\x1b[0m not found (error: {:?})\n\
                 This probably means the test PKI certificates are not installed correctly\n\
+                tl;dr: copy `alice.crt` and `alice.key` from `libparsec/crates/platform_pki/test-pki/Cert`\n\
+                into a directory and point \x1b[1;35mPARSEC_PKI_FILES_DIR\x1b[0m to it\n\
                 ",
                 ALICE_SHA256_CERT_HASH, error
             );
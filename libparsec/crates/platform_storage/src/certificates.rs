@@ -191,7 +191,15 @@ macro_rules! impl_storable_certificate_topic {
 
 impl_storable_certificate_topic!(
     CommonTopicArcCertificate,
-    [User, Device, RevokedUser, UserUpdate,]
+    [
+        User,
+        Device,
+        RevokedUser,
+        RevokedDevice,
+        UserUpdate,
+        Group,
+        GroupMember,
+    ]
 );
 
 impl_storable_certificate_topic!(
@@ -205,7 +213,13 @@ impl_storable_certificate_topic!(
 
 impl_storable_certificate_topic!(
     RealmTopicArcCertificate,
-    [RealmRole, RealmName, RealmKeyRotation, RealmArchiving]
+    [
+        RealmRole,
+        RealmName,
+        RealmKeyRotation,
+        RealmArchiving,
+        RealmGroupRole
+    ]
 );
 
 impl_storable_certificate_topic!(
@@ -253,6 +267,18 @@ impl StorableCertificate for RevokedUserCertificate {
     }
 }
 
+impl StorableCertificate for RevokedDeviceCertificate {
+    const TYPE: &'static str = "revoked_device_certificate";
+    fn filters(&self) -> (FilterKind<'_>, FilterKind<'_>) {
+        let filter1 = FilterKind::Bytes(self.device_id.as_bytes());
+        let filter2 = FilterKind::Bytes(self.user_id.as_bytes());
+        (filter1, filter2)
+    }
+    fn timestamp(&self) -> DateTime {
+        self.timestamp
+    }
+}
+
 impl StorableCertificate for UserUpdateCertificate {
     const TYPE: &'static str = "user_update_certificate";
     fn filters(&self) -> (FilterKind<'_>, FilterKind<'_>) {
@@ -265,6 +291,30 @@ impl StorableCertificate for UserUpdateCertificate {
     }
 }
 
+impl StorableCertificate for GroupCertificate {
+    const TYPE: &'static str = "group_certificate";
+    fn filters(&self) -> (FilterKind<'_>, FilterKind<'_>) {
+        let filter1 = FilterKind::Bytes(self.group_id.as_bytes());
+        let filter2 = FilterKind::Null;
+        (filter1, filter2)
+    }
+    fn timestamp(&self) -> DateTime {
+        self.timestamp
+    }
+}
+
+impl StorableCertificate for GroupMemberCertificate {
+    const TYPE: &'static str = "group_member_certificate";
+    fn filters(&self) -> (FilterKind<'_>, FilterKind<'_>) {
+        let filter1 = FilterKind::Bytes(self.group_id.as_bytes());
+        let filter2 = FilterKind::Bytes(self.user_id.as_bytes());
+        (filter1, filter2)
+    }
+    fn timestamp(&self) -> DateTime {
+        self.timestamp
+    }
+}
+
 impl StorableCertificate for RealmRoleCertificate {
     const TYPE: &'static str = "realm_role_certificate";
     fn filters(&self) -> (FilterKind<'_>, FilterKind<'_>) {
@@ -313,6 +363,18 @@ impl StorableCertificate for RealmArchivingCertificate {
     }
 }
 
+impl StorableCertificate for RealmGroupRoleCertificate {
+    const TYPE: &'static str = "realm_group_role_certificate";
+    fn filters(&self) -> (FilterKind<'_>, FilterKind<'_>) {
+        let filter1 = FilterKind::Bytes(self.realm_id.as_bytes());
+        let filter2 = FilterKind::Bytes(self.group_id.as_bytes());
+        (filter1, filter2)
+    }
+    fn timestamp(&self) -> DateTime {
+        self.timestamp
+    }
+}
+
 impl StorableCertificate for SequesterAuthorityCertificate {
     const TYPE: &'static str = "sequester_authority_certificate";
     fn filters(&self) -> (FilterKind<'_>, FilterKind<'_>) {
@@ -480,6 +542,35 @@ impl<'a> GetCertificateQuery<'a> {
         }
     }
 
+    pub fn revoked_device_certificate(device_id: &'a DeviceID) -> Self {
+        Self::Filter1 {
+            certificate_type: <RevokedDeviceCertificate as StorableCertificate>::TYPE,
+            filter1: FilterKind::Bytes(device_id.as_bytes()),
+        }
+    }
+
+    /// Get all revoked device certificates for a given user
+    pub fn user_revoked_devices_certificates(user_id: &'a UserID) -> Self {
+        Self::Filter2 {
+            certificate_type: <RevokedDeviceCertificate as StorableCertificate>::TYPE,
+            filter2: FilterKind::Bytes(user_id.as_bytes()),
+        }
+    }
+
+    /// Get all revoked user certificates we know about
+    pub fn revoked_users_certificates() -> Self {
+        Self::NoFilter {
+            certificate_type: <RevokedUserCertificate as StorableCertificate>::TYPE,
+        }
+    }
+
+    /// Get all user update certificates we know about
+    pub fn users_update_certificates() -> Self {
+        Self::NoFilter {
+            certificate_type: <UserUpdateCertificate as StorableCertificate>::TYPE,
+        }
+    }
+
     /// Get all user update certificates for a given user
     pub fn user_update_certificates(user_id: &'a UserID) -> Self {
         Self::Filter1 {
@@ -504,6 +595,13 @@ impl<'a> GetCertificateQuery<'a> {
         }
     }
 
+    /// Get all device certificates we know about
+    pub fn devices_certificates() -> Self {
+        Self::NoFilter {
+            certificate_type: <DeviceCertificate as StorableCertificate>::TYPE,
+        }
+    }
+
     /// Get all device certificates for a given user
     pub fn user_devices_certificates(user_id: &'a UserID) -> Self {
         Self::Filter2 {
@@ -512,6 +610,36 @@ impl<'a> GetCertificateQuery<'a> {
         }
     }
 
+    /// Get all group certificates we know about
+    pub fn groups_certificates() -> Self {
+        Self::NoFilter {
+            certificate_type: <GroupCertificate as StorableCertificate>::TYPE,
+        }
+    }
+
+    pub fn group_certificate(group_id: &'a GroupID) -> Self {
+        Self::Filter1 {
+            certificate_type: <GroupCertificate as StorableCertificate>::TYPE,
+            filter1: FilterKind::Bytes(group_id.as_bytes()),
+        }
+    }
+
+    /// Get all group member certificates for a given group
+    pub fn group_members_certificates(group_id: &'a GroupID) -> Self {
+        Self::Filter1 {
+            certificate_type: <GroupMemberCertificate as StorableCertificate>::TYPE,
+            filter1: FilterKind::Bytes(group_id.as_bytes()),
+        }
+    }
+
+    /// Get all group member certificates for a given user
+    pub fn user_groups_members_certificates(user_id: &'a UserID) -> Self {
+        Self::Filter2 {
+            certificate_type: <GroupMemberCertificate as StorableCertificate>::TYPE,
+            filter2: FilterKind::Bytes(user_id.as_bytes()),
+        }
+    }
+
     pub fn realm_role_certificate(realm_id: &'a VlobID, user_id: &'a UserID) -> Self {
         Self::BothFilters {
             certificate_type: <RealmRoleCertificate as StorableCertificate>::TYPE,
@@ -536,6 +664,13 @@ impl<'a> GetCertificateQuery<'a> {
         }
     }
 
+    /// Get all realm key rotation certificates we know about
+    pub fn realms_key_rotation_certificates() -> Self {
+        Self::NoFilter {
+            certificate_type: <RealmKeyRotationCertificate as StorableCertificate>::TYPE,
+        }
+    }
+
     /// Get all realm name certificates for a given realm
     pub fn realm_key_rotation_certificates(realm_id: &'a VlobID) -> Self {
         Self::Filter1 {
@@ -552,6 +687,13 @@ impl<'a> GetCertificateQuery<'a> {
         }
     }
 
+    /// Get all realm role certificates we know about
+    pub fn realms_roles_certificates() -> Self {
+        Self::NoFilter {
+            certificate_type: <RealmRoleCertificate as StorableCertificate>::TYPE,
+        }
+    }
+
     /// Get all realm role certificates for a given realm
     pub fn realm_role_certificates(realm_id: &'a VlobID) -> Self {
         Self::Filter1 {
@@ -568,6 +710,14 @@ impl<'a> GetCertificateQuery<'a> {
         }
     }
 
+    /// Get all realm group role certificates for a given realm
+    pub fn realm_group_roles_certificates(realm_id: &'a VlobID) -> Self {
+        Self::Filter1 {
+            certificate_type: <RealmGroupRoleCertificate as StorableCertificate>::TYPE,
+            filter1: FilterKind::Bytes(realm_id.as_bytes()),
+        }
+    }
+
     /// Get all shamir recovery brief certificates we know about
     pub fn shamir_recovery_brief_certificates() -> Self {
         Self::NoFilter {
@@ -653,6 +803,36 @@ impl<'a> GetCertificateQuery<'a> {
     }
 }
 
+/// Progress of the re-encryption of a realm's data with its last key.
+///
+/// Data are re-encrypted in passes: each pass processes the vlobs that have
+/// changed since `checkpoint`, ordered by vlob ID so that `cursor` can be
+/// used to resume an interrupted pass.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RealmRekeyProgress {
+    pub realm_id: VlobID,
+    /// Key index all the realm's data should end up encrypted with.
+    pub target_key_index: IndexInt,
+    /// Realm checkpoint the current pass started from.
+    pub checkpoint: IndexInt,
+    /// Last vlob processed by the current pass, `None` if it hasn't started yet.
+    pub cursor: Option<VlobID>,
+    pub completed: bool,
+}
+
+/// Expiration date of a realm share we have granted.
+///
+/// The expiration only applies to the realm role certificate it has been
+/// configured with: any later change of the user's role makes it obsolete.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RealmShareExpiration {
+    pub realm_id: VlobID,
+    pub user_id: UserID,
+    /// Timestamp of the realm role certificate the expiration applies to.
+    pub role_certificate_timestamp: DateTime,
+    pub expires_on: DateTime,
+}
+
 #[derive(Debug)]
 pub struct CertificatesStorage {
     platform: PlatformCertificatesStorage,
@@ -733,6 +913,48 @@ impl CertificatesStorage {
             .await
     }
 
+    /// Return `None` if no re-encryption has ever been started for this realm.
+    pub async fn get_realm_rekey_progress(
+        &mut self,
+        realm_id: VlobID,
+    ) -> anyhow::Result<Option<RealmRekeyProgress>> {
+        self.platform.get_realm_rekey_progress(realm_id).await
+    }
+
+    pub async fn set_realm_rekey_progress(
+        &mut self,
+        progress: &RealmRekeyProgress,
+    ) -> anyhow::Result<()> {
+        self.platform.set_realm_rekey_progress(progress).await
+    }
+
+    /// Share expirations are returned ordered by user ID.
+    pub async fn get_realm_share_expirations(
+        &mut self,
+        realm_id: VlobID,
+    ) -> anyhow::Result<Vec<RealmShareExpiration>> {
+        self.platform.get_realm_share_expirations(realm_id).await
+    }
+
+    /// Overwrite any previous share expiration for this realm and user.
+    pub async fn set_realm_share_expiration(
+        &mut self,
+        expiration: &RealmShareExpiration,
+    ) -> anyhow::Result<()> {
+        self.platform.set_realm_share_expiration(expiration).await
+    }
+
+    /// No-op if there is no share expiration for this realm and user.
+    pub async fn remove_realm_share_expiration(
+        &mut self,
+        realm_id: VlobID,
+        user_id: UserID,
+    ) -> anyhow::Result<()> {
+        self.platform
+            .remove_realm_share_expiration(realm_id, user_id)
+            .await
+    }
+
     /// Only used for debugging tests
     #[cfg(any(test, feature = "expose-test-methods"))]
     pub async fn debug_dump(&mut self) -> anyhow::Result<String> {
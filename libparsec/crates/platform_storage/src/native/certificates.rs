@@ -15,7 +15,7 @@ use libparsec_types::prelude::*;
 use super::model::get_certificates_storage_db_relative_path;
 use crate::certificates::{
     FilterKind, GetCertificateError, GetCertificateQuery, PerTopicLastTimestamps,
-    StorableCertificateTopic, UpTo,
+    RealmRekeyProgress, RealmShareExpiration, StorableCertificateTopic, UpTo,
 };
 
 // `concat!` macro only works with literal (i.e. `concat!("foo", "bar")`), here we support
@@ -761,6 +761,145 @@ impl PlatformCertificatesStorage {
         .await?
     }
 
+    pub async fn get_realm_rekey_progress(
+        &mut self,
+        realm_id: VlobID,
+    ) -> anyhow::Result<Option<RealmRekeyProgress>> {
+        let row = sqlx::query(
+            "SELECT \
+                target_key_index, \
+                checkpoint, \
+                cursor, \
+                completed \
+            FROM realm_rekey \
+            WHERE realm_id = ?1 \
+            ",
+        )
+        .bind(realm_id.as_bytes())
+        .fetch_optional(&mut self.conn)
+        .await?;
+
+        let row = match row {
+            None => return Ok(None),
+            Some(row) => row,
+        };
+
+        let target_key_index = row.try_get::<i64, _>(0)? as IndexInt;
+        let checkpoint = row.try_get::<i64, _>(1)? as IndexInt;
+        let cursor = row
+            .try_get::<Option<&[u8]>, _>(2)?
+            .map(|raw| VlobID::try_from(raw).map_err(|e| anyhow::anyhow!(e)))
+            .transpose()?;
+        let completed = row.try_get::<bool, _>(3)?;
+
+        Ok(Some(RealmRekeyProgress {
+            realm_id,
+            target_key_index,
+            checkpoint,
+            cursor,
+            completed,
+        }))
+    }
+
+    pub async fn set_realm_rekey_progress(
+        &mut self,
+        progress: &RealmRekeyProgress,
+    ) -> anyhow::Result<()> {
+        sqlx::query(
+            "INSERT OR REPLACE INTO realm_rekey( \
+                realm_id, \
+                target_key_index, \
+                checkpoint, \
+                cursor, \
+                completed \
+            ) \
+            VALUES(?1, ?2, ?3, ?4, ?5) \
+            ",
+        )
+        .bind(progress.realm_id.as_bytes())
+        .bind(progress.target_key_index as i64)
+        .bind(progress.checkpoint as i64)
+        .bind(progress.cursor.as_ref().map(|cursor| cursor.as_bytes()))
+        .bind(progress.completed)
+        .execute(&mut self.conn)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn get_realm_share_expirations(
+        &mut self,
+        realm_id: VlobID,
+    ) -> anyhow::Result<Vec<RealmShareExpiration>> {
+        let rows = sqlx::query(
+            "SELECT \
+                user_id, \
+                role_certificate_timestamp, \
+                expires_on \
+            FROM realm_share_expiration \
+            WHERE realm_id = ?1 \
+            ORDER BY user_id \
+            ",
+        )
+        .bind(realm_id.as_bytes())
+        .fetch_all(&mut self.conn)
+        .await?;
+
+        let mut items = Vec::with_capacity(rows.len());
+        for row in rows {
+            let user_id =
+                UserID::try_from(row.try_get::<&[u8], _>(0)?).map_err(|e| anyhow::anyhow!(e))?;
+            let role_certificate_timestamp =
+                DateTime::from_timestamp_micros(row.try_get::<i64, _>(1)?)?;
+            let expires_on = DateTime::from_timestamp_micros(row.try_get::<i64, _>(2)?)?;
+            items.push(RealmShareExpiration {
+                realm_id,
+                user_id,
+                role_certificate_timestamp,
+                expires_on,
+            });
+        }
+        Ok(items)
+    }
+
+    pub async fn set_realm_share_expiration(
+        &mut self,
+        expiration: &RealmShareExpiration,
+    ) -> anyhow::Result<()> {
+        sqlx::query(
+            "INSERT OR REPLACE INTO realm_share_expiration( \
+                realm_id, \
+                user_id, \
+                role_certificate_timestamp, \
+                expires_on \
+            ) \
+            VALUES(?1, ?2, ?3, ?4) \
+            ",
+        )
+        .bind(expiration.realm_id.as_bytes())
+        .bind(expiration.user_id.as_bytes())
+        .bind(expiration.role_certificate_timestamp.as_timestamp_micros())
+        .bind(expiration.expires_on.as_timestamp_micros())
+        .execute(&mut self.conn)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn remove_realm_share_expiration(
+        &mut self,
+        realm_id: VlobID,
+        user_id: UserID,
+    ) -> anyhow::Result<()> {
+        sqlx::query("DELETE FROM realm_share_expiration WHERE realm_id = ?1 AND user_id = ?2")
+            .bind(realm_id.as_bytes())
+            .bind(user_id.as_bytes())
+            .execute(&mut self.conn)
+            .await?;
+
+        Ok(())
+    }
+
     /// Only used for debugging tests
     #[cfg(any(test, feature = "expose-test-methods"))]
     pub async fn debug_dump(&mut self) -> anyhow::Result<String> {
@@ -85,6 +85,11 @@ pub(super) async fn sqlx_initialize_model_if_needed(
     ))
     .execute(&mut *transaction)
     .await?;
+    sqlx::query(std::include_str!(
+        "sql/create-remote-only-entries-table.sql"
+    ))
+    .execute(&mut *transaction)
+    .await?;
     sqlx::query(std::include_str!("sql/create-chunks-table.sql"))
         .execute(&mut *transaction)
         .await?;
@@ -94,6 +99,14 @@ pub(super) async fn sqlx_initialize_model_if_needed(
     sqlx::query(std::include_str!("sql/create-certificates-table.sql"))
         .execute(&mut *transaction)
         .await?;
+    sqlx::query(std::include_str!("sql/create-realm-rekey-table.sql"))
+        .execute(&mut *transaction)
+        .await?;
+    sqlx::query(std::include_str!(
+        "sql/create-realm-share-expiration-table.sql"
+    ))
+    .execute(&mut *transaction)
+    .await?;
 
     // 2) Populate the tables
 
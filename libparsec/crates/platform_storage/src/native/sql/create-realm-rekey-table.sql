@@ -0,0 +1,10 @@
+-- Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+-- Progress of the re-encryption of the realms' data with their last key
+CREATE TABLE IF NOT EXISTS realm_rekey (
+    realm_id BLOB PRIMARY KEY NOT NULL, -- VlobID
+    target_key_index INTEGER NOT NULL,
+    checkpoint INTEGER NOT NULL,
+    cursor BLOB, -- VlobID, NULL if the current pass hasn't started yet
+    completed INTEGER NOT NULL -- Boolean
+) STRICT;
@@ -0,0 +1,11 @@
+-- Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+-- Expiration date of the realm shares we have granted
+CREATE TABLE IF NOT EXISTS realm_share_expiration (
+    realm_id BLOB NOT NULL, -- VlobID
+    user_id BLOB NOT NULL, -- UserID
+    -- Timestamp of the realm role certificate the expiration applies to
+    role_certificate_timestamp INTEGER NOT NULL,
+    expires_on INTEGER NOT NULL,
+    PRIMARY KEY (realm_id, user_id)
+) STRICT;
@@ -0,0 +1,6 @@
+-- Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+-- Selective sync: the entries (and their children) which data should not be kept in local
+CREATE TABLE IF NOT EXISTS remote_only_entries (
+    vlob_id BLOB PRIMARY KEY NOT NULL -- VlobID
+) STRICT;
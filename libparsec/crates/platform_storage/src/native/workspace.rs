@@ -361,6 +361,20 @@ impl PlatformWorkspaceStorage {
         Ok(())
     }
 
+    pub async fn remove_blocks(
+        &mut self,
+        block_ids: impl Iterator<Item = BlockID>,
+    ) -> anyhow::Result<()> {
+        let mut transaction = self.cache_conn.begin().await?;
+
+        for block_id in block_ids {
+            db_remove_chunk(&mut *transaction, block_id.into()).await?;
+        }
+
+        transaction.commit().await?;
+        Ok(())
+    }
+
     /// Only used for debugging tests
     #[cfg(any(test, feature = "expose-test-methods"))]
     pub async fn debug_dump(&mut self) -> anyhow::Result<DebugDump> {
@@ -503,6 +517,39 @@ impl PlatformWorkspaceStorage {
 
         Ok(())
     }
+
+    pub async fn set_remote_only_entries(&mut self, entries: &[VlobID]) -> anyhow::Result<()> {
+        let mut transaction = self.conn.begin().await?;
+
+        sqlx::query("DELETE FROM remote_only_entries")
+            .execute(&mut *transaction)
+            .await?;
+
+        for entry_id in entries {
+            sqlx::query(
+                "INSERT INTO remote_only_entries(vlob_id) VALUES(?1) ON CONFLICT DO NOTHING",
+            )
+            .bind(entry_id.as_bytes())
+            .execute(&mut *transaction)
+            .await?;
+        }
+
+        transaction.commit().await?;
+
+        Ok(())
+    }
+
+    pub async fn get_remote_only_entries(&mut self) -> anyhow::Result<Vec<VlobID>> {
+        let rows = sqlx::query("SELECT vlob_id FROM remote_only_entries")
+            .fetch_all(&mut self.conn)
+            .await?;
+
+        rows.iter()
+            .map(|row| {
+                VlobID::try_from(row.try_get::<&[u8], _>(0)?).map_err(|e| anyhow::anyhow!(e))
+            })
+            .collect()
+    }
 }
 
 async fn db_update_prevent_sync_pattern<T, E>(mut trans: T, pattern: &str) -> anyhow::Result<bool>
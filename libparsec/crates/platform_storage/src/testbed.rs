@@ -136,10 +136,14 @@ pub(crate) async fn maybe_populate_certificate_storage(data_base_dir: &Path, dev
                     AnyArcCertificate::Device(c) => c.timestamp,
                     AnyArcCertificate::UserUpdate(c) => c.timestamp,
                     AnyArcCertificate::RevokedUser(c) => c.timestamp,
+                    AnyArcCertificate::RevokedDevice(c) => c.timestamp,
+                    AnyArcCertificate::Group(c) => c.timestamp,
+                    AnyArcCertificate::GroupMember(c) => c.timestamp,
                     AnyArcCertificate::RealmRole(c) => c.timestamp,
                     AnyArcCertificate::RealmName(c) => c.timestamp,
                     AnyArcCertificate::RealmArchiving(c) => c.timestamp,
                     AnyArcCertificate::RealmKeyRotation(c) => c.timestamp,
+                    AnyArcCertificate::RealmGroupRole(c) => c.timestamp,
                     AnyArcCertificate::ShamirRecoveryBrief(c) => c.timestamp,
                     AnyArcCertificate::ShamirRecoveryShare(c) => c.timestamp,
                     AnyArcCertificate::ShamirRecoveryDeletion(c) => c.timestamp,
@@ -172,6 +176,15 @@ pub(crate) async fn maybe_populate_certificate_storage(data_base_dir: &Path, dev
                             AnyArcCertificate::RevokedUser(certif) => {
                                 updater.add_certificate(certif.deref(), encrypted).await?
                             }
+                            AnyArcCertificate::RevokedDevice(certif) => {
+                                updater.add_certificate(certif.deref(), encrypted).await?
+                            }
+                            AnyArcCertificate::Group(certif) => {
+                                updater.add_certificate(certif.deref(), encrypted).await?
+                            }
+                            AnyArcCertificate::GroupMember(certif) => {
+                                updater.add_certificate(certif.deref(), encrypted).await?
+                            }
 
                             // In theory we should skip the certificates related to realm we are not part of,
                             // but in practice it has no impact since we don't have any operation that crawls
@@ -189,6 +202,9 @@ pub(crate) async fn maybe_populate_certificate_storage(data_base_dir: &Path, dev
                             AnyArcCertificate::RealmArchiving(certif) => {
                                 updater.add_certificate(certif.deref(), encrypted).await?
                             }
+                            AnyArcCertificate::RealmGroupRole(certif) => {
+                                updater.add_certificate(certif.deref(), encrypted).await?
+                            }
 
                             // Just like for realm, we should in theory skip the shamir certificates not meant to us.
                             // We do have operations that crawl the whole shamir certificates, but they are aware of
@@ -8,12 +8,12 @@ use indexed_db::{Factory, OwnedDatabase, Transaction};
 use libparsec_types::prelude::*;
 
 use super::utils::{
-    js_to_rs_bytes, js_to_rs_timestamp, js_to_rs_vlob_id, rs_to_js_filter, rs_to_js_timestamp,
-    with_transaction, CustomErrMarker,
+    js_to_rs_bytes, js_to_rs_timestamp, js_to_rs_u64, js_to_rs_user_id, js_to_rs_vlob_id,
+    rs_to_js_filter, rs_to_js_timestamp, rs_to_js_u64, with_transaction, CustomErrMarker,
 };
 use crate::certificates::{
     FilterKind, GetCertificateError, GetCertificateQuery, PerTopicLastTimestamps,
-    StorableCertificateTopic, UpTo,
+    RealmRekeyProgress, RealmShareExpiration, StorableCertificateTopic, UpTo,
 };
 
 pub(super) fn get_certificates_storage_db_name(
@@ -28,7 +28,9 @@ pub(super) fn get_certificates_storage_db_name(
 }
 
 // Note each database (certificates, workspace etc.) has its own version.
-const DB_VERSION: u32 = 1;
+// - Version 2 introduced the realm rekey store.
+// - Version 3 introduced the realm share expiration store.
+const DB_VERSION: u32 = 3;
 // Store contains: {
 //   certificate_type: string,
 //   filter1: Uint8Array,
@@ -46,9 +48,57 @@ const INDEX_CERTIFICATE_TYPE: &str = "_idx_certificate_type";
 const INDEX_FILTER1: &str = "_idx_filter1";
 const INDEX_FILTER2: &str = "_idx_filter2";
 const INDEX_FILTERS: &str = "_idx_filters";
+// Realm rekey store contains: {
+//   target_key_index: number,
+//   checkpoint: number,
+//   cursor: Uint8Array | null,
+//   completed: boolean
+// }
+// Items are indexed by realm ID (as Uint8Array).
+const REALM_REKEY_STORE: &str = "realm_rekey";
+const REALM_REKEY_TARGET_KEY_INDEX_FIELD: &str = "target_key_index";
+const REALM_REKEY_CHECKPOINT_FIELD: &str = "checkpoint";
+const REALM_REKEY_CURSOR_FIELD: &str = "cursor";
+const REALM_REKEY_COMPLETED_FIELD: &str = "completed";
+// Realm share expiration store contains: {
+//   user_id: Uint8Array,
+//   role_certificate_timestamp: number,
+//   expires_on: number
+// }
+// Items are indexed by `[realm ID, user ID]` (both as Uint8Array).
+const REALM_SHARE_EXPIRATION_STORE: &str = "realm_share_expiration";
+const REALM_SHARE_EXPIRATION_USER_ID_FIELD: &str = "user_id";
+const REALM_SHARE_EXPIRATION_ROLE_CERTIFICATE_TIMESTAMP_FIELD: &str = "role_certificate_timestamp";
+const REALM_SHARE_EXPIRATION_EXPIRES_ON_FIELD: &str = "expires_on";
 
 async fn initialize_database(
     evt: &indexed_db::VersionChangeEvent<Infallible>,
+) -> indexed_db::Result<(), Infallible> {
+    // This is also called when upgrading from a previous version, in which
+    // case only the missing stores must be created.
+    let existing_stores = evt.database().object_store_names();
+
+    if !existing_stores.iter().any(|name| name == STORE) {
+        initialize_certificates_store(evt)?;
+    }
+
+    if !existing_stores.iter().any(|name| name == REALM_REKEY_STORE) {
+        evt.build_object_store(REALM_REKEY_STORE).create()?;
+    }
+
+    if !existing_stores
+        .iter()
+        .any(|name| name == REALM_SHARE_EXPIRATION_STORE)
+    {
+        evt.build_object_store(REALM_SHARE_EXPIRATION_STORE)
+            .create()?;
+    }
+
+    Ok(())
+}
+
+fn initialize_certificates_store(
+    evt: &indexed_db::VersionChangeEvent<Infallible>,
 ) -> indexed_db::Result<(), Infallible> {
     let store = evt.build_object_store(STORE).auto_increment().create()?;
 
@@ -96,6 +146,14 @@ async fn initialize_database(
     Ok(())
 }
 
+fn realm_share_expiration_key(realm_id: VlobID, user_id: UserID) -> JsValue {
+    js_sys::Array::of2(
+        &js_sys::Uint8Array::from(realm_id.as_bytes()),
+        &js_sys::Uint8Array::from(user_id.as_bytes()),
+    )
+    .into()
+}
+
 #[derive(Debug)]
 pub(crate) struct PlatformCertificatesStorageForUpdateGuard<'a> {
     transaction: &'a Transaction<CustomErrMarker>,
@@ -824,6 +882,216 @@ impl PlatformCertificatesStorage {
         .await?
     }
 
+    pub async fn get_realm_rekey_progress(
+        &mut self,
+        realm_id: VlobID,
+    ) -> anyhow::Result<Option<RealmRekeyProgress>> {
+        let realm_id_js: JsValue = js_sys::Uint8Array::from(realm_id.as_bytes()).into();
+
+        with_transaction!(
+            &self.conn,
+            &[REALM_REKEY_STORE],
+            false,
+            async |transaction: Transaction<CustomErrMarker>| {
+                let store = transaction.object_store(REALM_REKEY_STORE)?;
+
+                let obj = match store.get(&realm_id_js).await? {
+                    Some(obj) => obj,
+                    None => return Ok(None),
+                };
+
+                let get_field = |field: &str| {
+                    js_sys::Reflect::get(&obj, &field.into())
+                        .map_err(|e| anyhow::anyhow!("Invalid entry, got {obj:?}: error {e:?}"))
+                };
+                let get_bool_field = |field: &str| {
+                    let raw_js = get_field(field)?;
+                    raw_js
+                        .as_bool()
+                        .ok_or_else(|| anyhow::anyhow!("Invalid boolean, got {raw_js:?}"))
+                };
+
+                let target_key_index =
+                    js_to_rs_u64(get_field(REALM_REKEY_TARGET_KEY_INDEX_FIELD)?)?;
+                let checkpoint = js_to_rs_u64(get_field(REALM_REKEY_CHECKPOINT_FIELD)?)?;
+                let cursor_js = get_field(REALM_REKEY_CURSOR_FIELD)?;
+                let cursor = if cursor_js.is_null() {
+                    None
+                } else {
+                    Some(js_to_rs_vlob_id(cursor_js)?)
+                };
+                let completed = get_bool_field(REALM_REKEY_COMPLETED_FIELD)?;
+
+                Ok(Some(RealmRekeyProgress {
+                    realm_id,
+                    target_key_index,
+                    checkpoint,
+                    cursor,
+                    completed,
+                }))
+            },
+        )
+        .await?
+    }
+
+    pub async fn set_realm_rekey_progress(
+        &mut self,
+        progress: &RealmRekeyProgress,
+    ) -> anyhow::Result<()> {
+        let realm_id_js: JsValue = js_sys::Uint8Array::from(progress.realm_id.as_bytes()).into();
+
+        let obj = js_sys::Object::new();
+        let set_field = |field: &str, value: &JsValue| {
+            js_sys::Reflect::set(&obj, &field.into(), value).expect("target is an object");
+        };
+        set_field(
+            REALM_REKEY_TARGET_KEY_INDEX_FIELD,
+            &rs_to_js_u64(progress.target_key_index)?,
+        );
+        set_field(
+            REALM_REKEY_CHECKPOINT_FIELD,
+            &rs_to_js_u64(progress.checkpoint)?,
+        );
+        set_field(
+            REALM_REKEY_CURSOR_FIELD,
+            &match &progress.cursor {
+                Some(cursor) => js_sys::Uint8Array::from(cursor.as_bytes()).into(),
+                None => JsValue::NULL,
+            },
+        );
+        set_field(REALM_REKEY_COMPLETED_FIELD, &progress.completed.into());
+
+        with_transaction!(
+            &self.conn,
+            &[REALM_REKEY_STORE],
+            true,
+            async |transaction: Transaction<CustomErrMarker>| {
+                let store = transaction.object_store(REALM_REKEY_STORE)?;
+
+                store.put_kv(&realm_id_js, &obj).await?;
+
+                Ok(())
+            },
+        )
+        .await?
+    }
+
+    pub async fn get_realm_share_expirations(
+        &mut self,
+        realm_id: VlobID,
+    ) -> anyhow::Result<Vec<RealmShareExpiration>> {
+        let realm_id_js: JsValue = js_sys::Uint8Array::from(realm_id.as_bytes()).into();
+        // Keys are `[realm ID, user ID]` arrays, and in IndexedDB an array is
+        // greater than its prefixes and lower than any array whose element is
+        // of a "greater" type (arrays being the greatest type).
+        let lower_bound = js_sys::Array::of1(&realm_id_js);
+        let upper_bound = js_sys::Array::of2(&realm_id_js, &js_sys::Array::new());
+
+        with_transaction!(
+            &self.conn,
+            &[REALM_SHARE_EXPIRATION_STORE],
+            false,
+            async |transaction: Transaction<CustomErrMarker>| {
+                let store = transaction.object_store(REALM_SHARE_EXPIRATION_STORE)?;
+
+                let mut cursor = store
+                    .cursor()
+                    .range(JsValue::from(lower_bound)..=JsValue::from(upper_bound))?
+                    .open()
+                    .await?;
+
+                let mut items = vec![];
+                while let Some(obj) = cursor.value() {
+                    let get_field = |field: &str| {
+                        js_sys::Reflect::get(&obj, &field.into())
+                            .map_err(|e| anyhow::anyhow!("Invalid entry, got {obj:?}: error {e:?}"))
+                    };
+
+                    let user_id =
+                        js_to_rs_user_id(get_field(REALM_SHARE_EXPIRATION_USER_ID_FIELD)?)?;
+                    let role_certificate_timestamp = js_to_rs_timestamp(get_field(
+                        REALM_SHARE_EXPIRATION_ROLE_CERTIFICATE_TIMESTAMP_FIELD,
+                    )?)?;
+                    let expires_on =
+                        js_to_rs_timestamp(get_field(REALM_SHARE_EXPIRATION_EXPIRES_ON_FIELD)?)?;
+
+                    items.push(RealmShareExpiration {
+                        realm_id,
+                        user_id,
+                        role_certificate_timestamp,
+                        expires_on,
+                    });
+
+                    cursor.advance(1).await?;
+                }
+
+                // Keys are ordered as bytes, which is consistent with user ID ordering
+                Ok(items)
+            },
+        )
+        .await?
+    }
+
+    pub async fn set_realm_share_expiration(
+        &mut self,
+        expiration: &RealmShareExpiration,
+    ) -> anyhow::Result<()> {
+        let key_js = realm_share_expiration_key(expiration.realm_id, expiration.user_id);
+
+        let obj = js_sys::Object::new();
+        let set_field = |field: &str, value: &JsValue| {
+            js_sys::Reflect::set(&obj, &field.into(), value).expect("target is an object");
+        };
+        set_field(
+            REALM_SHARE_EXPIRATION_USER_ID_FIELD,
+            &js_sys::Uint8Array::from(expiration.user_id.as_bytes()).into(),
+        );
+        set_field(
+            REALM_SHARE_EXPIRATION_ROLE_CERTIFICATE_TIMESTAMP_FIELD,
+            &rs_to_js_timestamp(expiration.role_certificate_timestamp)?,
+        );
+        set_field(
+            REALM_SHARE_EXPIRATION_EXPIRES_ON_FIELD,
+            &rs_to_js_timestamp(expiration.expires_on)?,
+        );
+
+        with_transaction!(
+            &self.conn,
+            &[REALM_SHARE_EXPIRATION_STORE],
+            true,
+            async |transaction: Transaction<CustomErrMarker>| {
+                let store = transaction.object_store(REALM_SHARE_EXPIRATION_STORE)?;
+
+                store.put_kv(&key_js, &obj).await?;
+
+                Ok(())
+            },
+        )
+        .await?
+    }
+
+    pub async fn remove_realm_share_expiration(
+        &mut self,
+        realm_id: VlobID,
+        user_id: UserID,
+    ) -> anyhow::Result<()> {
+        let key_js = realm_share_expiration_key(realm_id, user_id);
+
+        with_transaction!(
+            &self.conn,
+            &[REALM_SHARE_EXPIRATION_STORE],
+            true,
+            async |transaction: Transaction<CustomErrMarker>| {
+                let store = transaction.object_store(REALM_SHARE_EXPIRATION_STORE)?;
+
+                store.delete(&key_js).await?;
+
+                Ok(())
+            },
+        )
+        .await?
+    }
+
     /// Only used for debugging tests
     #[cfg(any(test, feature = "expose-test-methods"))]
     pub async fn debug_dump(&mut self) -> anyhow::Result<String> {
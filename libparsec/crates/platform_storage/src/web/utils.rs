@@ -141,6 +141,10 @@ pub(super) fn js_to_rs_vlob_id(raw_js: JsValue) -> anyhow::Result<VlobID> {
     bytes_based_js_to_rs_conversion!(raw_js, VlobID)
 }
 
+pub(super) fn js_to_rs_user_id(raw_js: JsValue) -> anyhow::Result<UserID> {
+    bytes_based_js_to_rs_conversion!(raw_js, UserID)
+}
+
 #[cfg_attr(not(any(test, feature = "expose-test-methods")), expect(dead_code))]
 pub(super) fn js_to_rs_block_id(raw_js: JsValue) -> anyhow::Result<BlockID> {
     bytes_based_js_to_rs_conversion!(raw_js, BlockID)
@@ -33,7 +33,8 @@ pub(super) fn get_workspace_storage_db_name(
 }
 
 // Note each database (certificates, workspace etc.) has its own version.
-const DB_VERSION: u32 = 1;
+// - Version 2 introduced the remote only entries store.
+const DB_VERSION: u32 = 2;
 // Prevent sync pattern store contains: {pattern: string, fully_applied: boolean}
 const PREVENT_SYNC_PATTERN_STORE: &str = "prevent_sync_pattern";
 // Prevent sync pattern is a singleton, so we use a single key.
@@ -41,6 +42,11 @@ const PREVENT_SYNC_PATTERN_SINGLETON_KEY: u32 = 1;
 const PREVENT_SYNC_PATTERN_PATTERN_FIELD: &str = "pattern";
 const PREVENT_SYNC_PATTERN_FULLY_APPLIED_FIELD: &str = "fully_applied";
 
+// Remote only entries (i.e. selective sync) store contains:
+// - key: Vlob ID (as Uint8Array)
+// - value: true
+const REMOTE_ONLY_ENTRIES_STORE: &str = "remote_only_entries";
+
 // Checkpoint store contains a singleton with just a number
 const CHECKPOINT_STORE: &str = "checkpoint";
 const CHECKPOINT_SINGLETON_KEY: u32 = 1;
@@ -98,6 +104,30 @@ pub(crate) async fn workspace_storage_remove_data(
 
 async fn initialize_database(
     evt: &indexed_db::VersionChangeEvent<Infallible>,
+) -> indexed_db::Result<(), Infallible> {
+    // This is also called when upgrading from a previous version, in which
+    // case only the missing stores must be created.
+    let existing_stores = evt.database().object_store_names();
+
+    if !existing_stores
+        .iter()
+        .any(|name| name == PREVENT_SYNC_PATTERN_STORE)
+    {
+        initialize_v1_stores(evt).await?;
+    }
+
+    if !existing_stores
+        .iter()
+        .any(|name| name == REMOTE_ONLY_ENTRIES_STORE)
+    {
+        evt.build_object_store(REMOTE_ONLY_ENTRIES_STORE).create()?;
+    }
+
+    Ok(())
+}
+
+async fn initialize_v1_stores(
+    evt: &indexed_db::VersionChangeEvent<Infallible>,
 ) -> indexed_db::Result<(), Infallible> {
     // 1) Create the stores
 
@@ -867,6 +897,32 @@ impl PlatformWorkspaceStorage {
         .await?
     }
 
+    pub async fn remove_blocks(
+        &mut self,
+        block_ids: impl Iterator<Item = BlockID>,
+    ) -> anyhow::Result<()> {
+        let block_ids_js = js_sys::Array::new();
+        for block_id in block_ids {
+            block_ids_js.push(&js_sys::Uint8Array::from(block_id.as_bytes()).into());
+        }
+
+        with_transaction!(
+            &self.conn,
+            &[BLOCKS_STORE],
+            true,
+            async |transaction: Transaction<CustomErrMarker>| {
+                let store = transaction.object_store(BLOCKS_STORE)?;
+
+                for block_id_js in block_ids_js.iter() {
+                    store.delete(&block_id_js).await?;
+                }
+
+                Ok(())
+            },
+        )
+        .await?
+    }
+
     pub async fn set_prevent_sync_pattern(
         &mut self,
         pattern: &PreventSyncPattern,
@@ -1060,6 +1116,52 @@ impl PlatformWorkspaceStorage {
         .await?
     }
 
+    pub async fn set_remote_only_entries(&mut self, entries: &[VlobID]) -> anyhow::Result<()> {
+        let entries_js = js_sys::Array::new();
+        for entry_id in entries {
+            entries_js.push(&js_sys::Uint8Array::from(entry_id.as_bytes()).into());
+        }
+
+        with_transaction!(
+            &self.conn,
+            &[REMOTE_ONLY_ENTRIES_STORE],
+            true,
+            async |transaction: Transaction<CustomErrMarker>| {
+                let store = transaction.object_store(REMOTE_ONLY_ENTRIES_STORE)?;
+
+                store.clear().await?;
+                for entry_id_js in entries_js.iter() {
+                    store.put_kv(&entry_id_js, &true.into()).await?;
+                }
+
+                Ok(())
+            },
+        )
+        .await?
+    }
+
+    pub async fn get_remote_only_entries(&mut self) -> anyhow::Result<Vec<VlobID>> {
+        with_transaction!(
+            &self.conn,
+            &[REMOTE_ONLY_ENTRIES_STORE],
+            false,
+            async |transaction: Transaction<CustomErrMarker>| {
+                let store = transaction.object_store(REMOTE_ONLY_ENTRIES_STORE)?;
+
+                let mut cursor = store.cursor().open().await?;
+
+                let mut entries = vec![];
+                while let Some(entry_id_js) = cursor.primary_key() {
+                    entries.push(js_to_rs_vlob_id(entry_id_js)?);
+                    cursor.advance(1).await?;
+                }
+
+                Ok(entries)
+            },
+        )
+        .await?
+    }
+
     /// Only used for debugging tests
     #[cfg(any(test, feature = "expose-test-methods"))]
     pub async fn debug_dump(&mut self) -> anyhow::Result<DebugDump> {
@@ -236,6 +236,14 @@ impl WorkspaceStorage {
         self.platform.promote_chunk_to_block(chunk_id, now).await
     }
 
+    /// Remove blocks from the cache, blocks not present in the cache are ignored.
+    pub async fn remove_blocks(
+        &mut self,
+        block_ids: impl Iterator<Item = BlockID>,
+    ) -> anyhow::Result<()> {
+        self.platform.remove_blocks(block_ids).await
+    }
+
     /// Only used for debugging tests
     #[cfg(any(test, feature = "expose-test-methods"))]
     pub async fn debug_dump(&mut self) -> anyhow::Result<DebugDump> {
@@ -261,6 +269,16 @@ impl WorkspaceStorage {
             .mark_prevent_sync_pattern_fully_applied(pattern)
             .await
     }
+
+    /// Replace the remote only entries (i.e. the roots of the subtrees excluded from
+    /// selective sync) by the provided ones.
+    pub async fn set_remote_only_entries(&mut self, entries: &[VlobID]) -> anyhow::Result<()> {
+        self.platform.set_remote_only_entries(entries).await
+    }
+
+    pub async fn get_remote_only_entries(&mut self) -> anyhow::Result<Vec<VlobID>> {
+        self.platform.get_remote_only_entries().await
+    }
 }
 
 pub async fn workspace_storage_non_speculative_init(
@@ -5,7 +5,8 @@ use std::collections::HashMap;
 use crate::certificates::CertificatesStorageUpdater;
 
 use super::{
-    CertificatesStorage, GetCertificateError, GetCertificateQuery, PerTopicLastTimestamps, UpTo,
+    CertificatesStorage, GetCertificateError, GetCertificateQuery, PerTopicLastTimestamps,
+    RealmRekeyProgress, RealmShareExpiration, UpTo,
 };
 use libparsec_tests_fixtures::prelude::*;
 use libparsec_types::prelude::*;
@@ -126,6 +127,222 @@ async fn for_update_preserves_task_id(env: &TestbedEnv) {
     p_assert_eq!(task_id_from_for_update.unwrap(), task_id);
 }
 
+#[parsec_test(testbed = "minimal")]
+async fn add_and_get_revoked_device_certificate(
+    mut timestamps: TimestampGenerator,
+    env: &TestbedEnv,
+) {
+    let alice = env.local_device("alice@dev1");
+
+    let mut storage = CertificatesStorage::start(&env.discriminant_dir, &alice)
+        .await
+        .unwrap();
+
+    let user_id = alice.user_id;
+    let device1_id = DeviceID::default();
+    let device2_id = DeviceID::default();
+    let t1 = timestamps.next();
+    let t2 = timestamps.next();
+
+    storage
+        .for_update(async |mut updater| {
+            for (timestamp, device_id, encrypted) in [
+                (t1, device1_id, b"revoked_device1"),
+                (t2, device2_id, b"revoked_device2"),
+            ] {
+                updater
+                    .add_certificate(
+                        &RevokedDeviceCertificate {
+                            timestamp,
+                            user_id,
+                            device_id,
+                            // Not meaningful for the test
+                            author: alice.device_id,
+                        },
+                        encrypted.to_vec(),
+                    )
+                    .await
+                    .unwrap();
+            }
+            anyhow::Ok(())
+        })
+        .await
+        .unwrap()
+        .unwrap();
+
+    p_assert_eq!(
+        storage
+            .get_certificate_encrypted(
+                GetCertificateQuery::revoked_device_certificate(&device1_id),
+                UpTo::Current
+            )
+            .await
+            .unwrap(),
+        (t1, b"revoked_device1".to_vec())
+    );
+    p_assert_matches!(
+        storage
+            .get_certificate_encrypted(
+                GetCertificateQuery::revoked_device_certificate(&alice.device_id),
+                UpTo::Current
+            )
+            .await,
+        Err(GetCertificateError::NonExisting)
+    );
+    p_assert_eq!(
+        storage
+            .get_multiple_certificates_encrypted(
+                GetCertificateQuery::user_revoked_devices_certificates(&user_id),
+                UpTo::Current,
+                None,
+                None
+            )
+            .await
+            .unwrap(),
+        vec![
+            (t1, b"revoked_device1".to_vec()),
+            (t2, b"revoked_device2".to_vec())
+        ]
+    );
+    p_assert_eq!(
+        storage.get_last_timestamps().await.unwrap().common,
+        Some(t2)
+    );
+}
+
+#[parsec_test(testbed = "minimal")]
+async fn add_and_get_group_certificates(mut timestamps: TimestampGenerator, env: &TestbedEnv) {
+    let alice = env.local_device("alice@dev1");
+
+    let mut storage = CertificatesStorage::start(&env.discriminant_dir, &alice)
+        .await
+        .unwrap();
+
+    let user_id = alice.user_id;
+    let group1_id = GroupID::default();
+    let group2_id = GroupID::default();
+    let realm_id = VlobID::default();
+    let t1 = timestamps.next();
+    let t2 = timestamps.next();
+    let t3 = timestamps.next();
+    let t4 = timestamps.next();
+
+    storage
+        .for_update(async |mut updater| {
+            for (timestamp, group_id, encrypted) in
+                [(t1, group1_id, b"group1"), (t2, group2_id, b"group2")]
+            {
+                updater
+                    .add_certificate(
+                        &GroupCertificate {
+                            timestamp,
+                            group_id,
+                            name: "Engineering".to_owned(),
+                            // Not meaningful for the test
+                            author: alice.device_id,
+                        },
+                        encrypted.to_vec(),
+                    )
+                    .await
+                    .unwrap();
+            }
+            updater
+                .add_certificate(
+                    &GroupMemberCertificate {
+                        timestamp: t3,
+                        group_id: group2_id,
+                        user_id,
+                        is_member: true,
+                        // Not meaningful for the test
+                        author: alice.device_id,
+                    },
+                    b"group_member".to_vec(),
+                )
+                .await
+                .unwrap();
+            updater
+                .add_certificate(
+                    &RealmGroupRoleCertificate {
+                        timestamp: t4,
+                        realm_id,
+                        group_id: group2_id,
+                        role: Some(RealmRole::Contributor),
+                        // Not meaningful for the test
+                        author: alice.device_id,
+                    },
+                    b"realm_group_role".to_vec(),
+                )
+                .await
+                .unwrap();
+            anyhow::Ok(())
+        })
+        .await
+        .unwrap()
+        .unwrap();
+
+    p_assert_eq!(
+        storage
+            .get_certificate_encrypted(
+                GetCertificateQuery::group_certificate(&group1_id),
+                UpTo::Current
+            )
+            .await
+            .unwrap(),
+        (t1, b"group1".to_vec())
+    );
+    p_assert_eq!(
+        storage
+            .get_multiple_certificates_encrypted(
+                GetCertificateQuery::groups_certificates(),
+                UpTo::Current,
+                None,
+                None
+            )
+            .await
+            .unwrap(),
+        vec![(t1, b"group1".to_vec()), (t2, b"group2".to_vec())]
+    );
+    p_assert_eq!(
+        storage
+            .get_multiple_certificates_encrypted(
+                GetCertificateQuery::group_members_certificates(&group1_id),
+                UpTo::Current,
+                None,
+                None
+            )
+            .await
+            .unwrap(),
+        vec![]
+    );
+    p_assert_eq!(
+        storage
+            .get_multiple_certificates_encrypted(
+                GetCertificateQuery::user_groups_members_certificates(&user_id),
+                UpTo::Current,
+                None,
+                None
+            )
+            .await
+            .unwrap(),
+        vec![(t3, b"group_member".to_vec())]
+    );
+    p_assert_eq!(
+        storage
+            .get_multiple_certificates_encrypted(
+                GetCertificateQuery::realm_group_roles_certificates(&realm_id),
+                UpTo::Current,
+                None,
+                None
+            )
+            .await
+            .unwrap(),
+        vec![(t4, b"realm_group_role".to_vec())]
+    );
+    let last_timestamps = storage.get_last_timestamps().await.unwrap();
+    p_assert_eq!(last_timestamps.common, Some(t3));
+    p_assert_eq!(last_timestamps.realm.get(&realm_id), Some(&t4));
+}
+
 #[parsec_test(testbed = "minimal")]
 async fn get_last_timestamps(mut timestamps: TimestampGenerator, env: &TestbedEnv) {
     let alice = env.local_device("alice@dev1");
@@ -247,6 +464,7 @@ async fn get_last_timestamps(mut timestamps: TimestampGenerator, env: &TestbedEn
                         // Not meaningful for the test
                         author: device_id,
                         role: None,
+                        group_id: None,
                     },
                     b"<encrypted>".to_vec(),
                 )
@@ -623,6 +841,7 @@ async fn add_and_get_certificate(
                         // Not meaningful for the test
                         author: device_id,
                         role: None,
+                        group_id: None,
                     },
                     b"realm_role1".to_vec(),
                 )
@@ -637,6 +856,7 @@ async fn add_and_get_certificate(
                         // Not meaningful for the test
                         author: device_id,
                         role: None,
+                        group_id: None,
                     },
                     b"realm_role2".to_vec(),
                 )
@@ -651,6 +871,7 @@ async fn add_and_get_certificate(
                         // Not meaningful for the test
                         author: device_id,
                         role: None,
+                        group_id: None,
                     },
                     b"other_realm_role".to_vec(),
                 )
@@ -665,6 +886,7 @@ async fn add_and_get_certificate(
                         // Not meaningful for the test
                         author: device_id,
                         role: None,
+                        group_id: None,
                     },
                     b"realm_role_other_user".to_vec(),
                 )
@@ -1230,6 +1452,7 @@ async fn forget_all_certificates(mut timestamps: TimestampGenerator, env: &Testb
                         // Not meaningful for the test
                         author: device_id,
                         role: None,
+                        group_id: None,
                     },
                     b"<encrypted>".to_vec(),
                 )
@@ -1458,6 +1681,168 @@ async fn forget_all_certificates(mut timestamps: TimestampGenerator, env: &Testb
         .unwrap();
 }
 
+#[parsec_test(testbed = "minimal")]
+async fn realm_rekey_progress(env: &TestbedEnv) {
+    let alice = env.local_device("alice@dev1");
+    let realm_id = VlobID::from_hex("aa0000000000000000000000000000aa").unwrap();
+    let other_realm_id = VlobID::from_hex("bb0000000000000000000000000000bb").unwrap();
+
+    let mut storage = CertificatesStorage::start(&env.discriminant_dir, &alice)
+        .await
+        .unwrap();
+
+    p_assert_eq!(
+        storage.get_realm_rekey_progress(realm_id).await.unwrap(),
+        None
+    );
+
+    let mut progress = RealmRekeyProgress {
+        realm_id,
+        target_key_index: 2,
+        checkpoint: 0,
+        cursor: None,
+        completed: false,
+    };
+    storage.set_realm_rekey_progress(&progress).await.unwrap();
+    p_assert_eq!(
+        storage.get_realm_rekey_progress(realm_id).await.unwrap(),
+        Some(progress.clone())
+    );
+
+    // Overwrite the previous progress
+
+    progress.checkpoint = 42;
+    progress.cursor = Some(VlobID::from_hex("cc0000000000000000000000000000cc").unwrap());
+    storage.set_realm_rekey_progress(&progress).await.unwrap();
+    p_assert_eq!(
+        storage.get_realm_rekey_progress(realm_id).await.unwrap(),
+        Some(progress.clone())
+    );
+
+    progress.cursor = None;
+    progress.completed = true;
+    storage.set_realm_rekey_progress(&progress).await.unwrap();
+
+    // Progress is kept across restarts...
+
+    storage.stop().await.unwrap();
+    let mut storage = CertificatesStorage::start(&env.discriminant_dir, &alice)
+        .await
+        .unwrap();
+    p_assert_eq!(
+        storage.get_realm_rekey_progress(realm_id).await.unwrap(),
+        Some(progress)
+    );
+
+    // ...and is per realm
+
+    p_assert_eq!(
+        storage
+            .get_realm_rekey_progress(other_realm_id)
+            .await
+            .unwrap(),
+        None
+    );
+}
+
+#[parsec_test(testbed = "minimal")]
+async fn realm_share_expirations(env: &TestbedEnv) {
+    let alice = env.local_device("alice@dev1");
+    let realm_id = VlobID::from_hex("aa0000000000000000000000000000aa").unwrap();
+    let other_realm_id = VlobID::from_hex("bb0000000000000000000000000000bb").unwrap();
+    let user1 = UserID::from_hex("1100000000000000000000000000000a").unwrap();
+    let user2 = UserID::from_hex("2200000000000000000000000000000b").unwrap();
+
+    let mut storage = CertificatesStorage::start(&env.discriminant_dir, &alice)
+        .await
+        .unwrap();
+
+    p_assert_eq!(
+        storage.get_realm_share_expirations(realm_id).await.unwrap(),
+        vec![]
+    );
+
+    let user2_expiration = RealmShareExpiration {
+        realm_id,
+        user_id: user2,
+        role_certificate_timestamp: "2000-01-01T00:00:00Z".parse().unwrap(),
+        expires_on: "2000-02-01T00:00:00Z".parse().unwrap(),
+    };
+    let mut user1_expiration = RealmShareExpiration {
+        realm_id,
+        user_id: user1,
+        role_certificate_timestamp: "2000-01-02T00:00:00Z".parse().unwrap(),
+        expires_on: "2000-01-03T00:00:00Z".parse().unwrap(),
+    };
+    let other_realm_expiration = RealmShareExpiration {
+        realm_id: other_realm_id,
+        user_id: user1,
+        role_certificate_timestamp: "2000-01-02T00:00:00Z".parse().unwrap(),
+        expires_on: "2000-03-01T00:00:00Z".parse().unwrap(),
+    };
+    for expiration in [
+        &user2_expiration,
+        &user1_expiration,
+        &other_realm_expiration,
+    ] {
+        storage
+            .set_realm_share_expiration(expiration)
+            .await
+            .unwrap();
+    }
+
+    // Expirations are per realm, and ordered by user ID
+    p_assert_eq!(
+        storage.get_realm_share_expirations(realm_id).await.unwrap(),
+        vec![user1_expiration.clone(), user2_expiration.clone()]
+    );
+    p_assert_eq!(
+        storage
+            .get_realm_share_expirations(other_realm_id)
+            .await
+            .unwrap(),
+        vec![other_realm_expiration.clone()]
+    );
+
+    // Overwrite the previous expiration
+
+    user1_expiration.role_certificate_timestamp = "2000-01-04T00:00:00Z".parse().unwrap();
+    user1_expiration.expires_on = "2000-01-05T00:00:00Z".parse().unwrap();
+    storage
+        .set_realm_share_expiration(&user1_expiration)
+        .await
+        .unwrap();
+
+    // Remove an expiration (removing it twice is a no-op)
+
+    storage
+        .remove_realm_share_expiration(realm_id, user2)
+        .await
+        .unwrap();
+    storage
+        .remove_realm_share_expiration(realm_id, user2)
+        .await
+        .unwrap();
+
+    // Expirations are kept across restarts
+
+    storage.stop().await.unwrap();
+    let mut storage = CertificatesStorage::start(&env.discriminant_dir, &alice)
+        .await
+        .unwrap();
+    p_assert_eq!(
+        storage.get_realm_share_expirations(realm_id).await.unwrap(),
+        vec![user1_expiration]
+    );
+    p_assert_eq!(
+        storage
+            .get_realm_share_expirations(other_realm_id)
+            .await
+            .unwrap(),
+        vec![other_realm_expiration]
+    );
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 #[parsec_test]
 async fn start_with_on_disk_db(tmp_path: TmpPath, alice: &Device) {
@@ -911,6 +911,61 @@ async fn get_and_set_block(env: &TestbedEnv) {
     );
 }
 
+#[parsec_test(testbed = "minimal")]
+async fn remove_blocks(env: &TestbedEnv) {
+    let realm_id = VlobID::from_hex("aa0000000000000000000000000000ee").unwrap();
+    let block1_id = BlockID::from_hex("aa0000000000000000000000000000f1").unwrap();
+    let block2_id = BlockID::from_hex("aa0000000000000000000000000000f2").unwrap();
+    let unknown_block_id = BlockID::from_hex("aa0000000000000000000000000000f3").unwrap();
+    let chunk_id = ChunkID::from_hex("aa0000000000000000000000000000f4").unwrap();
+    let alice = env.local_device("alice@dev1");
+
+    let mut workspace_storage =
+        WorkspaceStorage::start(&env.discriminant_dir, &alice, realm_id, u64::MAX)
+            .await
+            .unwrap();
+
+    for block_id in [block1_id, block2_id] {
+        workspace_storage
+            .set_block(
+                block_id,
+                b"<block>".as_ref(),
+                "2000-01-01T00:00:00Z".parse().unwrap(),
+            )
+            .await
+            .unwrap();
+    }
+    workspace_storage
+        .set_chunk(chunk_id, b"<chunk>".as_ref())
+        .await
+        .unwrap();
+
+    // Unknown blocks are ignored, and chunks are not concerned
+    workspace_storage
+        .remove_blocks([block1_id, unknown_block_id, chunk_id.into()].into_iter())
+        .await
+        .unwrap();
+
+    let dump = workspace_storage.debug_dump().await.unwrap();
+    p_assert_eq!(
+        dump,
+        DebugDump {
+            chunks: vec![DebugChunk {
+                id: chunk_id,
+                size: 7,
+                offline: false,
+            }],
+            blocks: vec![DebugBlock {
+                id: block2_id,
+                size: 7,
+                offline: false,
+                accessed_on: "2000-01-01T00:00:00Z".into()
+            }],
+            ..Default::default()
+        }
+    );
+}
+
 #[parsec_test(testbed = "minimal")]
 async fn block_cache_cleanup(env: &TestbedEnv) {
     let realm_id = VlobID::from_hex("aa0000000000000000000000000000ee").unwrap();
@@ -1488,3 +1543,31 @@ async fn remove_data(tmp_path: TmpPath, alice: &Device) {
         .await
         .unwrap();
 }
+
+#[parsec_test(testbed = "minimal")]
+async fn remote_only_entries(env: &TestbedEnv) {
+    let mut workspace = start_workspace(env).await;
+
+    p_assert_eq!(workspace.get_remote_only_entries().await.unwrap(), vec![]);
+
+    let entry1 = VlobID::from_hex("aa0000000000000000000000000000e1").unwrap();
+    let entry2 = VlobID::from_hex("aa0000000000000000000000000000e2").unwrap();
+
+    workspace
+        .set_remote_only_entries(&[entry1, entry2])
+        .await
+        .unwrap();
+    let mut entries = workspace.get_remote_only_entries().await.unwrap();
+    entries.sort();
+    p_assert_eq!(entries, vec![entry1, entry2]);
+
+    // Setting the entries replaces the previous ones
+    workspace.set_remote_only_entries(&[entry2]).await.unwrap();
+    p_assert_eq!(
+        workspace.get_remote_only_entries().await.unwrap(),
+        vec![entry2]
+    );
+
+    workspace.set_remote_only_entries(&[]).await.unwrap();
+    p_assert_eq!(workspace.get_remote_only_entries().await.unwrap(), vec![]);
+}
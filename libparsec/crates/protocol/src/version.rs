@@ -56,7 +56,10 @@ use libparsec_types::prelude::*;
 //   * Add `advisory_device_file_protection` field to `server_config` response
 //   * Add `realm_update_archiving` to authenticated commands.
 //   * Add `realm_minimum_archiving_period_before_deletion` field to `OrganizationConfig` event in `events_listen`
+//   * Add `pki_crls` field to `OrganizationConfig` event in `events_listen`
 //   * Add `send_email` field to async_enrollment_accept req and `email_sent` status to ok rep
+//   * Add `device_revoke` to authenticated commands.
+//   * Add `group_create/group_update_member/realm_update_group_role` to authenticated commands.
 
 pub const API_V1_VERSION: &ApiVersion = &ApiVersion {
     version: 1,
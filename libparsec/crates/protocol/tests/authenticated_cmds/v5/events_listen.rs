@@ -76,6 +76,7 @@ pub fn rep_ok() {
                     user_profile_outsider_allowed: true,
                     sse_keepalive_seconds: Some(30.try_into().unwrap()),
                     realm_minimum_archiving_period_before_deletion: Maybe::Present(2592000),
+                    pki_crls: Maybe::Absent,
                 },
             ),
         ),
@@ -100,6 +101,7 @@ pub fn rep_ok() {
                     user_profile_outsider_allowed: true,
                     sse_keepalive_seconds: Some(30.try_into().unwrap()),
                     realm_minimum_archiving_period_before_deletion: Maybe::Absent,
+                    pki_crls: Maybe::Absent,
                 },
             ),
         ),
@@ -124,6 +126,7 @@ pub fn rep_ok() {
                     user_profile_outsider_allowed: false,
                     sse_keepalive_seconds: Some(30.try_into().unwrap()),
                     realm_minimum_archiving_period_before_deletion: Maybe::Absent,
+                    pki_crls: Maybe::Absent,
                 },
             ),
         ),
@@ -152,6 +155,7 @@ pub fn rep_ok() {
                     user_profile_outsider_allowed: true,
                     sse_keepalive_seconds: Some(30.try_into().unwrap()),
                     realm_minimum_archiving_period_before_deletion: Maybe::Absent,
+                    pki_crls: Maybe::Absent,
                 },
             ),
         ),
@@ -180,6 +184,7 @@ pub fn rep_ok() {
                     user_profile_outsider_allowed: false,
                     sse_keepalive_seconds: Some(30.try_into().unwrap()),
                     realm_minimum_archiving_period_before_deletion: Maybe::Absent,
+                    pki_crls: Maybe::Absent,
                 },
             ),
         ),
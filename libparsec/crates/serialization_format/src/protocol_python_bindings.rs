@@ -913,6 +913,7 @@ fn quote_type_as_fn_getter_conversion(field_path: &TokenStream, ty: &FieldType)
         FieldType::PKIEnrollmentID => quote_rs_to_py_class!(crate::ids::PKIEnrollmentID),
         FieldType::AsyncEnrollmentID => quote_rs_to_py_class!(crate::ids::AsyncEnrollmentID),
         FieldType::SequesterServiceID => quote_rs_to_py_class!(crate::ids::SequesterServiceID),
+        FieldType::GroupID => quote_rs_to_py_class!(crate::ids::GroupID),
         FieldType::AccountAuthMethodID => quote_rs_to_py_class!(crate::ids::AccountAuthMethodID),
         FieldType::AccountVaultItemOpaqueKeyID => {
             quote_rs_to_py_class!(crate::ids::AccountVaultItemOpaqueKeyID)
@@ -1032,6 +1033,7 @@ fn quote_type_as_fn_new_param(ty: &FieldType) -> TokenStream {
         FieldType::PKIEnrollmentID => quote! { crate::ids::PKIEnrollmentID },
         FieldType::AsyncEnrollmentID => quote! { crate::ids::AsyncEnrollmentID },
         FieldType::SequesterServiceID => quote! { crate::ids::SequesterServiceID },
+        FieldType::GroupID => quote! { crate::ids::GroupID },
         FieldType::AccountAuthMethodID => quote! { crate::ids::AccountAuthMethodID },
         FieldType::AccountVaultItemOpaqueKeyID => {
             quote! { crate::ids::AccountVaultItemOpaqueKeyID }
@@ -1185,6 +1187,7 @@ fn internal_quote_field_as_fn_new_conversion(field_name: &Ident, ty: &FieldType)
         | FieldType::PKIEnrollmentID
         | FieldType::AsyncEnrollmentID
         | FieldType::SequesterServiceID
+        | FieldType::GroupID
         | FieldType::AccountAuthMethodID
         | FieldType::AccountVaultItemOpaqueKeyID
         | FieldType::TOTPOpaqueKeyID
@@ -234,6 +234,7 @@ generate_field_type_enum!(
     PKIEnrollmentID => libparsec_types::PKIEnrollmentID,
     AsyncEnrollmentID => libparsec_types::AsyncEnrollmentID,
     SequesterServiceID => libparsec_types::SequesterServiceID,
+    GroupID => libparsec_types::GroupID,
     AccountAuthMethodID => libparsec_types::AccountAuthMethodID,
     AccountVaultItemOpaqueKeyID => libparsec_types::AccountVaultItemOpaqueKeyID,
     TOTPOpaqueKeyID => libparsec_types::TOTPOpaqueKeyID,
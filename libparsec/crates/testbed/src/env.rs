@@ -496,11 +496,15 @@ impl TestbedEnv {
                 AnyArcCertificate::User(_)
                 | AnyArcCertificate::Device(_)
                 | AnyArcCertificate::UserUpdate(_)
-                | AnyArcCertificate::RevokedUser(_) => Some(event.signed.clone()),
+                | AnyArcCertificate::RevokedUser(_)
+                | AnyArcCertificate::RevokedDevice(_)
+                | AnyArcCertificate::Group(_)
+                | AnyArcCertificate::GroupMember(_) => Some(event.signed.clone()),
                 AnyArcCertificate::RealmRole(_)
                 | AnyArcCertificate::RealmName(_)
                 | AnyArcCertificate::RealmKeyRotation(_)
                 | AnyArcCertificate::RealmArchiving(_)
+                | AnyArcCertificate::RealmGroupRole(_)
                 | AnyArcCertificate::ShamirRecoveryBrief(_)
                 | AnyArcCertificate::ShamirRecoveryShare(_)
                 | AnyArcCertificate::ShamirRecoveryDeletion(_)
@@ -549,10 +553,21 @@ impl TestbedEnv {
                         entry.insert(vec![event.signed.clone()]);
                     }
                 },
+                AnyArcCertificate::RealmGroupRole(certif) => match output.entry(certif.realm_id) {
+                    std::collections::hash_map::Entry::Occupied(entry) => {
+                        entry.into_mut().push(event.signed.clone());
+                    }
+                    std::collections::hash_map::Entry::Vacant(entry) => {
+                        entry.insert(vec![event.signed.clone()]);
+                    }
+                },
                 AnyArcCertificate::User(_)
                 | AnyArcCertificate::Device(_)
                 | AnyArcCertificate::UserUpdate(_)
                 | AnyArcCertificate::RevokedUser(_)
+                | AnyArcCertificate::RevokedDevice(_)
+                | AnyArcCertificate::Group(_)
+                | AnyArcCertificate::GroupMember(_)
                 | AnyArcCertificate::ShamirRecoveryBrief(_)
                 | AnyArcCertificate::ShamirRecoveryShare(_)
                 | AnyArcCertificate::ShamirRecoveryDeletion(_)
@@ -575,9 +590,13 @@ impl TestbedEnv {
                 | AnyArcCertificate::Device(_)
                 | AnyArcCertificate::UserUpdate(_)
                 | AnyArcCertificate::RevokedUser(_)
+                | AnyArcCertificate::RevokedDevice(_)
+                | AnyArcCertificate::Group(_)
+                | AnyArcCertificate::GroupMember(_)
                 | AnyArcCertificate::RealmRole(_)
                 | AnyArcCertificate::RealmName(_)
                 | AnyArcCertificate::RealmArchiving(_)
+                | AnyArcCertificate::RealmGroupRole(_)
                 | AnyArcCertificate::ShamirRecoveryBrief(_)
                 | AnyArcCertificate::ShamirRecoveryShare(_)
                 | AnyArcCertificate::ShamirRecoveryDeletion(_)
@@ -597,10 +616,14 @@ impl TestbedEnv {
                 | AnyArcCertificate::Device(_)
                 | AnyArcCertificate::UserUpdate(_)
                 | AnyArcCertificate::RevokedUser(_)
+                | AnyArcCertificate::RevokedDevice(_)
+                | AnyArcCertificate::Group(_)
+                | AnyArcCertificate::GroupMember(_)
                 | AnyArcCertificate::RealmRole(_)
                 | AnyArcCertificate::RealmName(_)
                 | AnyArcCertificate::RealmKeyRotation(_)
                 | AnyArcCertificate::RealmArchiving(_)
+                | AnyArcCertificate::RealmGroupRole(_)
                 | AnyArcCertificate::SequesterAuthority(_)
                 | AnyArcCertificate::SequesterService(_)
                 | AnyArcCertificate::SequesterRevokedService(_) => None,
@@ -640,10 +663,14 @@ impl TestbedEnv {
                 | AnyArcCertificate::Device(_)
                 | AnyArcCertificate::UserUpdate(_)
                 | AnyArcCertificate::RevokedUser(_)
+                | AnyArcCertificate::RevokedDevice(_)
+                | AnyArcCertificate::Group(_)
+                | AnyArcCertificate::GroupMember(_)
                 | AnyArcCertificate::RealmRole(_)
                 | AnyArcCertificate::RealmName(_)
                 | AnyArcCertificate::RealmKeyRotation(_)
                 | AnyArcCertificate::RealmArchiving(_)
+                | AnyArcCertificate::RealmGroupRole(_)
                 | AnyArcCertificate::SequesterAuthority(_)
                 | AnyArcCertificate::SequesterService(_)
                 | AnyArcCertificate::SequesterRevokedService(_) => None,
@@ -717,12 +744,16 @@ impl TestbedEnv {
                 AnyArcCertificate::RealmName(certif) => (certif.realm_id, certif.timestamp),
                 AnyArcCertificate::RealmKeyRotation(certif) => (certif.realm_id, certif.timestamp),
                 AnyArcCertificate::RealmArchiving(certif) => (certif.realm_id, certif.timestamp),
+                AnyArcCertificate::RealmGroupRole(certif) => (certif.realm_id, certif.timestamp),
 
                 // Exhaustive match so that we detect when new certificates are added
                 AnyArcCertificate::User(_)
                 | AnyArcCertificate::Device(_)
                 | AnyArcCertificate::UserUpdate(_)
                 | AnyArcCertificate::RevokedUser(_)
+                | AnyArcCertificate::RevokedDevice(_)
+                | AnyArcCertificate::Group(_)
+                | AnyArcCertificate::GroupMember(_)
                 | AnyArcCertificate::ShamirRecoveryBrief(_)
                 | AnyArcCertificate::ShamirRecoveryShare(_)
                 | AnyArcCertificate::ShamirRecoveryDeletion(_)
@@ -750,12 +781,18 @@ impl TestbedEnv {
                     AnyArcCertificate::RealmArchiving(certif) => {
                         (certif.realm_id, certif.timestamp)
                     }
+                    AnyArcCertificate::RealmGroupRole(certif) => {
+                        (certif.realm_id, certif.timestamp)
+                    }
 
                     // Exhaustive match so that we detect when new certificates are added
                     AnyArcCertificate::User(_)
                     | AnyArcCertificate::Device(_)
                     | AnyArcCertificate::UserUpdate(_)
                     | AnyArcCertificate::RevokedUser(_)
+                    | AnyArcCertificate::RevokedDevice(_)
+                    | AnyArcCertificate::Group(_)
+                    | AnyArcCertificate::GroupMember(_)
                     | AnyArcCertificate::ShamirRecoveryBrief(_)
                     | AnyArcCertificate::ShamirRecoveryShare(_)
                     | AnyArcCertificate::ShamirRecoveryDeletion(_)
@@ -780,11 +817,15 @@ impl TestbedEnv {
                 AnyArcCertificate::Device(certif) => Some(certif.timestamp),
                 AnyArcCertificate::UserUpdate(certif) => Some(certif.timestamp),
                 AnyArcCertificate::RevokedUser(certif) => Some(certif.timestamp),
+                AnyArcCertificate::RevokedDevice(certif) => Some(certif.timestamp),
+                AnyArcCertificate::Group(certif) => Some(certif.timestamp),
+                AnyArcCertificate::GroupMember(certif) => Some(certif.timestamp),
                 // Exhaustive match so that we detect when new certificates are added
                 AnyArcCertificate::RealmRole(_)
                 | AnyArcCertificate::RealmName(_)
                 | AnyArcCertificate::RealmKeyRotation(_)
                 | AnyArcCertificate::RealmArchiving(_)
+                | AnyArcCertificate::RealmGroupRole(_)
                 | AnyArcCertificate::ShamirRecoveryBrief(_)
                 | AnyArcCertificate::ShamirRecoveryShare(_)
                 | AnyArcCertificate::ShamirRecoveryDeletion(_)
@@ -807,10 +848,14 @@ impl TestbedEnv {
                 | AnyArcCertificate::Device(_)
                 | AnyArcCertificate::UserUpdate(_)
                 | AnyArcCertificate::RevokedUser(_)
+                | AnyArcCertificate::RevokedDevice(_)
+                | AnyArcCertificate::Group(_)
+                | AnyArcCertificate::GroupMember(_)
                 | AnyArcCertificate::RealmRole(_)
                 | AnyArcCertificate::RealmName(_)
                 | AnyArcCertificate::RealmKeyRotation(_)
                 | AnyArcCertificate::RealmArchiving(_)
+                | AnyArcCertificate::RealmGroupRole(_)
                 | AnyArcCertificate::ShamirRecoveryBrief(_)
                 | AnyArcCertificate::ShamirRecoveryShare(_)
                 | AnyArcCertificate::ShamirRecoveryDeletion(_) => None,
@@ -854,10 +899,14 @@ impl TestbedEnv {
                 | AnyArcCertificate::Device(_)
                 | AnyArcCertificate::UserUpdate(_)
                 | AnyArcCertificate::RevokedUser(_)
+                | AnyArcCertificate::RevokedDevice(_)
+                | AnyArcCertificate::Group(_)
+                | AnyArcCertificate::GroupMember(_)
                 | AnyArcCertificate::RealmRole(_)
                 | AnyArcCertificate::RealmName(_)
                 | AnyArcCertificate::RealmKeyRotation(_)
                 | AnyArcCertificate::RealmArchiving(_)
+                | AnyArcCertificate::RealmGroupRole(_)
                 | AnyArcCertificate::SequesterAuthority(_)
                 | AnyArcCertificate::SequesterService(_)
                 | AnyArcCertificate::SequesterRevokedService(_) => None,
@@ -23,7 +23,7 @@ enum TemplateState {
 // Templates are generated only once, then copied for fast initialization of testbed envs
 // On top of that we generated them lazily to further improve speed of single-test runs
 // given cargo-nextest relies on that even when running multiple tests.
-static TESTBED_TEMPLATES: [(&str, Mutex<TemplateState>); 8] = [
+static TESTBED_TEMPLATES: [(&str, Mutex<TemplateState>); 9] = [
     (
         "empty",
         Mutex::new(TemplateState::NotGenerated(templates::empty::generate)),
@@ -64,6 +64,10 @@ static TESTBED_TEMPLATES: [(&str, Mutex<TemplateState>); 8] = [
             templates::sequestered::generate,
         )),
     ),
+    (
+        "largeorg",
+        Mutex::new(TemplateState::NotGenerated(templates::largeorg::generate)),
+    ),
 ];
 
 pub fn test_get_template(id: &str) -> Option<Arc<TestbedTemplate>> {
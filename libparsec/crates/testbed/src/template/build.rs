@@ -927,6 +927,7 @@ impl TestbedEventCreateBlockBuilder<'_> {
             offset,
             size,
             digest: HashDigest::from_data(&event.cleartext),
+            compressed: false,
         }
     }
 }
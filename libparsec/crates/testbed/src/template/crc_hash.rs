@@ -466,6 +466,7 @@ impl CrcHash for FileManifest {
             size,
             blocksize,
             blocks,
+            content_defined_chunking,
         } = self;
 
         author.crc_hash(hasher);
@@ -478,6 +479,7 @@ impl CrcHash for FileManifest {
         size.crc_hash(hasher);
         blocksize.crc_hash(hasher);
         blocks.crc_hash(hasher);
+        content_defined_chunking.crc_hash(hasher);
     }
 }
 
@@ -490,12 +492,14 @@ impl CrcHash for BlockAccess {
             offset,
             size,
             digest,
+            compressed,
         } = self;
 
         id.crc_hash(hasher);
         offset.crc_hash(hasher);
         size.crc_hash(hasher);
         digest.crc_hash(hasher);
+        compressed.crc_hash(hasher);
     }
 }
 impl CrcHash for ChunkView {
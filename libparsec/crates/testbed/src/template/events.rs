@@ -1297,6 +1297,7 @@ single_certificate_event!(
             user_id,
             realm_id: e.realm_id,
             role: Some(RealmRole::Owner),
+            group_id: None,
         };
         let signed: Bytes = certif.dump_and_sign(author_signkey).into();
         TestbedTemplateEventCertificate {
@@ -1474,6 +1475,7 @@ impl TestbedEventShareRealm {
                 user_id: self.user,
                 realm_id: self.realm,
                 role: self.role,
+                group_id: None,
             };
             let signed: Bytes = certif.dump_and_sign(author_signkey).into();
             TestbedTemplateEventCertificate {
@@ -2975,6 +2977,7 @@ impl TestbedEventCreateOrUpdateFileManifestVlob {
                 size,
                 blocksize,
                 blocks,
+                content_defined_chunking: false,
             }),
             cache: Arc::default(),
         }
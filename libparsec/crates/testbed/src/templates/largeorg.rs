@@ -0,0 +1,72 @@
+// Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+use std::sync::Arc;
+
+use libparsec_types::prelude::*;
+
+use crate::TestbedTemplate;
+
+const USERS_COUNT: usize = 1000;
+const WORKSPACES_COUNT: usize = 20;
+const WORKSPACE_MEMBERS_COUNT: usize = 100;
+
+/// Largeorg contains:
+/// - 1 admin user `alice` with device `alice@dev1` whose certificates storage is up to date
+/// - 1000 standard users with 2 devices each, 1 user out of 10 being revoked
+/// - 20 workspaces `wksp1` to `wksp20` owned by alice, each bootstrapped and shared
+///   with 100 users as contributors
+///
+/// This organization is meant to be used by benchmarks, see `workspaces_ids` and
+/// `users_ids` stored stuff.
+pub(crate) fn generate() -> Arc<TestbedTemplate> {
+    // If you change something here:
+    // - Update this function's docstring
+
+    let mut builder = TestbedTemplate::from_builder("largeorg");
+
+    // 1) Create users & devices
+
+    builder.bootstrap_organization("alice"); // alice@dev1
+
+    let mut users_ids = Vec::with_capacity(USERS_COUNT);
+    for _ in 0..USERS_COUNT {
+        let user_id = builder.counters.next_user_id();
+        builder.new_user(user_id);
+        builder.new_device(user_id);
+        users_ids.push(user_id);
+    }
+
+    // 2) Create workspaces, each shared with a different (but overlapping) set of users
+
+    let mut workspaces_ids = Vec::with_capacity(WORKSPACES_COUNT);
+    for i in 0..WORKSPACES_COUNT {
+        let realm_id = builder.new_realm("alice").map(|e| e.realm_id);
+        builder.rotate_key_realm(realm_id);
+        let name: EntryName = format!("wksp{}", i + 1).parse().unwrap();
+        builder.rename_realm(realm_id, name);
+        for user_id in users_ids
+            .iter()
+            .cycle()
+            .skip(i * USERS_COUNT / WORKSPACES_COUNT)
+            .take(WORKSPACE_MEMBERS_COUNT)
+        {
+            builder.share_realm(realm_id, *user_id, Some(RealmRole::Contributor));
+        }
+        workspaces_ids.push(realm_id);
+    }
+
+    // 3) Revoke some users (they are kept as part of the workspaces)
+
+    for user_id in users_ids.iter().step_by(10) {
+        builder.revoke_user(*user_id);
+    }
+
+    builder.store_stuff("users_ids", &users_ids);
+    builder.store_stuff("workspaces_ids", &workspaces_ids);
+
+    // 4) Initialize client storage for alice@dev1
+
+    builder.certificates_storage_fetch_certificates("alice@dev1");
+
+    builder.finalize()
+}
@@ -2,6 +2,7 @@
 
 pub(crate) mod coolorg;
 pub(crate) mod empty;
+pub(crate) mod largeorg;
 pub(crate) mod minimal;
 pub(crate) mod minimal_client_ready;
 pub(crate) mod sequestered;
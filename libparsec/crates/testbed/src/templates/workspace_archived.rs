@@ -61,6 +61,7 @@ pub(crate) fn generate() -> Arc<TestbedTemplate> {
                 offset: 0,
                 size: (wksp_archived_block_data.len() as u64).try_into().unwrap(),
                 digest: HashDigest::from_data(wksp_archived_block_data),
+                compressed: false,
             });
         })
         .map(|e| e.manifest.id);
@@ -18,7 +18,7 @@ use crate::data_macros::impl_transparent_data_format_conversion;
 use crate::{self as libparsec_types, IndexInt};
 use crate::{
     serialization::{format_v0_dump, format_vx_load},
-    DataError, DataResult, DateTime, DeviceID, DeviceLabel, HumanHandle, MaybeRedacted,
+    DataError, DataResult, DateTime, DeviceID, DeviceLabel, GroupID, HumanHandle, MaybeRedacted,
     RealmArchivingConfiguration, RealmRole, SequesterServiceID, UserID, UserProfile, VlobID,
 };
 
@@ -381,6 +381,75 @@ impl_transparent_data_format_conversion!(
     user_id,
 );
 
+/*
+ * RevokedDeviceCertificate
+ */
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(
+    into = "RevokedDeviceCertificateData",
+    from = "RevokedDeviceCertificateData"
+)]
+pub struct RevokedDeviceCertificate {
+    pub author: DeviceID,
+    pub timestamp: DateTime,
+
+    pub user_id: UserID,
+    pub device_id: DeviceID,
+}
+
+impl_unsecure_load!(RevokedDeviceCertificate -> DeviceID);
+impl_unsecure_dump!(RevokedDeviceCertificate);
+impl_dump_and_sign!(RevokedDeviceCertificate);
+impl_base_load!(RevokedDeviceCertificate);
+
+impl RevokedDeviceCertificate {
+    /// This structure represents immutable data (as it is created once, signed, and never updated).
+    /// Hence this `check_data_integrity` is only used during deserialization (and also as sanity check
+    /// right before serialization) and not exposed publicly.
+    fn check_data_integrity(&self) -> DataResult<()> {
+        Ok(())
+    }
+
+    pub fn verify_and_load(
+        signed: &[u8],
+        author_verify_key: &VerifyKey,
+        expected_author: DeviceID,
+        expected_device_id: Option<DeviceID>,
+    ) -> DataResult<Self> {
+        let r = Self::base_verify_and_load(signed, author_verify_key)?;
+
+        if r.author != expected_author {
+            return Err(DataError::UnexpectedAuthor {
+                expected: expected_author,
+                got: Some(r.author),
+            });
+        }
+
+        if let Some(expected_device_id) = expected_device_id {
+            if r.device_id != expected_device_id {
+                return Err(DataError::UnexpectedDeviceID {
+                    expected: expected_device_id,
+                    got: r.device_id,
+                });
+            }
+        }
+
+        Ok(r)
+    }
+}
+
+parsec_data!("schema/certif/revoked_device_certificate.json5");
+
+impl_transparent_data_format_conversion!(
+    RevokedDeviceCertificate,
+    RevokedDeviceCertificateData,
+    author,
+    timestamp,
+    user_id,
+    device_id,
+);
+
 /*
  * UserUpdateCertificate
  */
@@ -447,6 +516,141 @@ impl_transparent_data_format_conversion!(
     new_profile,
 );
 
+/*
+ * GroupCertificate
+ */
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(into = "GroupCertificateData", from = "GroupCertificateData")]
+pub struct GroupCertificate {
+    pub author: DeviceID,
+    pub timestamp: DateTime,
+
+    pub group_id: GroupID,
+    pub name: String,
+}
+
+impl_unsecure_load!(GroupCertificate -> DeviceID);
+impl_unsecure_dump!(GroupCertificate);
+impl_dump_and_sign!(GroupCertificate);
+impl_base_load!(GroupCertificate);
+
+impl GroupCertificate {
+    /// This structure represents immutable data (as it is created once, signed, and never updated).
+    /// Hence this `check_data_integrity` is only used during deserialization (and also as sanity check
+    /// right before serialization) and not exposed publicly.
+    fn check_data_integrity(&self) -> DataResult<()> {
+        if self.name.is_empty() {
+            return Err(DataError::DataIntegrity {
+                data_type: std::any::type_name::<Self>(),
+                invariant: "name is not empty",
+            });
+        }
+
+        Ok(())
+    }
+
+    pub fn verify_and_load(
+        signed: &[u8],
+        author_verify_key: &VerifyKey,
+        expected_author: DeviceID,
+    ) -> DataResult<Self> {
+        let r = Self::base_verify_and_load(signed, author_verify_key)?;
+
+        if r.author != expected_author {
+            return Err(DataError::UnexpectedAuthor {
+                expected: expected_author,
+                got: Some(r.author),
+            });
+        }
+
+        Ok(r)
+    }
+}
+
+parsec_data!("schema/certif/group_certificate.json5");
+
+impl_transparent_data_format_conversion!(
+    GroupCertificate,
+    GroupCertificateData,
+    author,
+    timestamp,
+    group_id,
+    name,
+);
+
+/*
+ * GroupMemberCertificate
+ */
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(
+    into = "GroupMemberCertificateData",
+    from = "GroupMemberCertificateData"
+)]
+pub struct GroupMemberCertificate {
+    pub author: DeviceID,
+    pub timestamp: DateTime,
+
+    pub group_id: GroupID,
+    pub user_id: UserID,
+    // Set to false if the user has been removed from the group
+    pub is_member: bool,
+}
+
+impl_unsecure_load!(GroupMemberCertificate -> DeviceID);
+impl_unsecure_dump!(GroupMemberCertificate);
+impl_dump_and_sign!(GroupMemberCertificate);
+impl_base_load!(GroupMemberCertificate);
+
+impl GroupMemberCertificate {
+    /// This structure represents immutable data (as it is created once, signed, and never updated).
+    /// Hence this `check_data_integrity` is only used during deserialization (and also as sanity check
+    /// right before serialization) and not exposed publicly.
+    fn check_data_integrity(&self) -> DataResult<()> {
+        Ok(())
+    }
+
+    pub fn verify_and_load(
+        signed: &[u8],
+        author_verify_key: &VerifyKey,
+        expected_author: DeviceID,
+        expected_user_id: Option<UserID>,
+    ) -> DataResult<Self> {
+        let r = Self::base_verify_and_load(signed, author_verify_key)?;
+
+        if r.author != expected_author {
+            return Err(DataError::UnexpectedAuthor {
+                expected: expected_author,
+                got: Some(r.author),
+            });
+        }
+
+        if let Some(expected_user_id) = expected_user_id {
+            if r.user_id != expected_user_id {
+                return Err(DataError::UnexpectedUserID {
+                    expected: expected_user_id,
+                    got: r.user_id,
+                });
+            }
+        }
+
+        Ok(r)
+    }
+}
+
+parsec_data!("schema/certif/group_member_certificate.json5");
+
+impl_transparent_data_format_conversion!(
+    GroupMemberCertificate,
+    GroupMemberCertificateData,
+    author,
+    timestamp,
+    group_id,
+    user_id,
+    is_member,
+);
+
 /*
  * DeviceCertificate
  */
@@ -568,6 +772,8 @@ pub struct RealmRoleCertificate {
     pub user_id: UserID,
     // Set to None if role removed
     pub role: Option<RealmRole>, // TODO: use a custom type instead
+    // Set if the role has been granted through a group the user is member of
+    pub group_id: Option<GroupID>,
 }
 
 impl_unsecure_load!(RealmRoleCertificate -> DeviceID);
@@ -595,6 +801,7 @@ impl RealmRoleCertificate {
             realm_id,
             user_id: author_user_id,
             role: Some(RealmRole::Owner),
+            group_id: None,
         }
     }
 
@@ -646,6 +853,7 @@ impl_transparent_data_format_conversion!(
     realm_id,
     user_id,
     role,
+    group_id,
 );
 
 /*
@@ -1253,6 +1461,78 @@ impl_transparent_data_format_conversion!(
     share_recipients,
 );
 
+/*
+ * RealmGroupRoleCertificate
+ */
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(
+    into = "RealmGroupRoleCertificateData",
+    from = "RealmGroupRoleCertificateData"
+)]
+pub struct RealmGroupRoleCertificate {
+    pub author: DeviceID,
+    pub timestamp: DateTime,
+
+    pub realm_id: VlobID,
+    pub group_id: GroupID,
+    // Set to None if role removed
+    pub role: Option<RealmRole>,
+}
+
+impl_unsecure_load!(RealmGroupRoleCertificate -> DeviceID);
+impl_unsecure_dump!(RealmGroupRoleCertificate);
+impl_dump_and_sign!(RealmGroupRoleCertificate);
+impl_base_load!(RealmGroupRoleCertificate);
+
+impl RealmGroupRoleCertificate {
+    /// This structure represents immutable data (as it is created once, signed, and never updated).
+    /// Hence this `check_data_integrity` is only used during deserialization (and also as sanity check
+    /// right before serialization) and not exposed publicly.
+    fn check_data_integrity(&self) -> DataResult<()> {
+        Ok(())
+    }
+
+    pub fn verify_and_load(
+        signed: &[u8],
+        author_verify_key: &VerifyKey,
+        expected_author: DeviceID,
+        expected_realm_id: Option<VlobID>,
+    ) -> DataResult<Self> {
+        let r = Self::base_verify_and_load(signed, author_verify_key)?;
+
+        if r.author != expected_author {
+            return Err(DataError::UnexpectedAuthor {
+                expected: expected_author,
+                got: Some(r.author),
+            });
+        }
+
+        if let Some(expected_realm_id) = expected_realm_id {
+            if r.realm_id != expected_realm_id {
+                return Err(DataError::UnexpectedRealmID {
+                    expected: expected_realm_id,
+                    got: r.realm_id,
+                });
+            }
+        }
+
+        Ok(r)
+    }
+}
+
+parsec_data!("schema/certif/realm_group_role_certificate.json5");
+
+impl_transparent_data_format_conversion!(
+    RealmGroupRoleCertificate,
+    RealmGroupRoleCertificateData,
+    author,
+    timestamp,
+    realm_id,
+    group_id,
+    role,
+);
+
 /*
  * AnyCertificate
  */
@@ -1263,7 +1543,11 @@ pub enum AnyArcCertificate {
     Device(Arc<DeviceCertificate>),
     UserUpdate(Arc<UserUpdateCertificate>),
     RevokedUser(Arc<RevokedUserCertificate>),
+    RevokedDevice(Arc<RevokedDeviceCertificate>),
+    Group(Arc<GroupCertificate>),
+    GroupMember(Arc<GroupMemberCertificate>),
     RealmRole(Arc<RealmRoleCertificate>),
+    RealmGroupRole(Arc<RealmGroupRoleCertificate>),
     RealmName(Arc<RealmNameCertificate>),
     RealmArchiving(Arc<RealmArchivingCertificate>),
     RealmKeyRotation(Arc<RealmKeyRotationCertificate>),
@@ -1282,7 +1566,11 @@ pub enum AnyCertificate {
     Device(DeviceCertificate),
     UserUpdate(UserUpdateCertificate),
     RevokedUser(RevokedUserCertificate),
+    RevokedDevice(RevokedDeviceCertificate),
+    Group(GroupCertificate),
+    GroupMember(GroupMemberCertificate),
     RealmRole(RealmRoleCertificate),
+    RealmGroupRole(RealmGroupRoleCertificate),
     RealmName(RealmNameCertificate),
     RealmArchiving(RealmArchivingCertificate),
     RealmKeyRotation(RealmKeyRotationCertificate),
@@ -1300,7 +1588,11 @@ pub enum UnsecureAnyCertificate {
     Device(UnsecureDeviceCertificate),
     UserUpdate(UnsecureUserUpdateCertificate),
     RevokedUser(UnsecureRevokedUserCertificate),
+    RevokedDevice(UnsecureRevokedDeviceCertificate),
+    Group(UnsecureGroupCertificate),
+    GroupMember(UnsecureGroupMemberCertificate),
     RealmRole(UnsecureRealmRoleCertificate),
+    RealmGroupRole(UnsecureRealmGroupRoleCertificate),
     RealmName(UnsecureRealmNameCertificate),
     RealmArchiving(UnsecureRealmArchivingCertificate),
     RealmKeyRotation(UnsecureRealmKeyRotationCertificate),
@@ -1322,7 +1614,11 @@ impl AnyCertificate {
             AnyCertificate::Device(c) => c.check_data_integrity(),
             AnyCertificate::UserUpdate(c) => c.check_data_integrity(),
             AnyCertificate::RevokedUser(c) => c.check_data_integrity(),
+            AnyCertificate::RevokedDevice(c) => c.check_data_integrity(),
+            AnyCertificate::Group(c) => c.check_data_integrity(),
+            AnyCertificate::GroupMember(c) => c.check_data_integrity(),
             AnyCertificate::RealmRole(c) => c.check_data_integrity(),
+            AnyCertificate::RealmGroupRole(c) => c.check_data_integrity(),
             AnyCertificate::RealmName(c) => c.check_data_integrity(),
             AnyCertificate::RealmArchiving(c) => c.check_data_integrity(),
             AnyCertificate::RealmKeyRotation(c) => c.check_data_integrity(),
@@ -1350,15 +1646,36 @@ impl AnyCertificate {
                     unsecure,
                 })
             }
+            AnyCertificate::RevokedDevice(unsecure) => {
+                UnsecureAnyCertificate::RevokedDevice(UnsecureRevokedDeviceCertificate {
+                    signed,
+                    unsecure,
+                })
+            }
             AnyCertificate::UserUpdate(unsecure) => {
                 UnsecureAnyCertificate::UserUpdate(UnsecureUserUpdateCertificate {
                     signed,
                     unsecure,
                 })
             }
+            AnyCertificate::Group(unsecure) => {
+                UnsecureAnyCertificate::Group(UnsecureGroupCertificate { signed, unsecure })
+            }
+            AnyCertificate::GroupMember(unsecure) => {
+                UnsecureAnyCertificate::GroupMember(UnsecureGroupMemberCertificate {
+                    signed,
+                    unsecure,
+                })
+            }
             AnyCertificate::RealmRole(unsecure) => {
                 UnsecureAnyCertificate::RealmRole(UnsecureRealmRoleCertificate { signed, unsecure })
             }
+            AnyCertificate::RealmGroupRole(unsecure) => {
+                UnsecureAnyCertificate::RealmGroupRole(UnsecureRealmGroupRoleCertificate {
+                    signed,
+                    unsecure,
+                })
+            }
             AnyCertificate::RealmName(unsecure) => {
                 UnsecureAnyCertificate::RealmName(UnsecureRealmNameCertificate { signed, unsecure })
             }
@@ -1419,8 +1736,12 @@ impl UnsecureAnyCertificate {
             UnsecureAnyCertificate::User(unsecure) => unsecure.timestamp(),
             UnsecureAnyCertificate::Device(unsecure) => unsecure.timestamp(),
             UnsecureAnyCertificate::RevokedUser(unsecure) => unsecure.timestamp(),
+            UnsecureAnyCertificate::RevokedDevice(unsecure) => unsecure.timestamp(),
             UnsecureAnyCertificate::UserUpdate(unsecure) => unsecure.timestamp(),
+            UnsecureAnyCertificate::Group(unsecure) => unsecure.timestamp(),
+            UnsecureAnyCertificate::GroupMember(unsecure) => unsecure.timestamp(),
             UnsecureAnyCertificate::RealmRole(unsecure) => unsecure.timestamp(),
+            UnsecureAnyCertificate::RealmGroupRole(unsecure) => unsecure.timestamp(),
             UnsecureAnyCertificate::RealmName(unsecure) => unsecure.timestamp(),
             UnsecureAnyCertificate::RealmArchiving(unsecure) => unsecure.timestamp(),
             UnsecureAnyCertificate::RealmKeyRotation(unsecure) => unsecure.timestamp(),
@@ -1436,8 +1757,12 @@ impl UnsecureAnyCertificate {
             UnsecureAnyCertificate::User(unsecure) => unsecure.hint(),
             UnsecureAnyCertificate::Device(unsecure) => unsecure.hint(),
             UnsecureAnyCertificate::RevokedUser(unsecure) => unsecure.hint(),
+            UnsecureAnyCertificate::RevokedDevice(unsecure) => unsecure.hint(),
             UnsecureAnyCertificate::UserUpdate(unsecure) => unsecure.hint(),
+            UnsecureAnyCertificate::Group(unsecure) => unsecure.hint(),
+            UnsecureAnyCertificate::GroupMember(unsecure) => unsecure.hint(),
             UnsecureAnyCertificate::RealmRole(unsecure) => unsecure.hint(),
+            UnsecureAnyCertificate::RealmGroupRole(unsecure) => unsecure.hint(),
             UnsecureAnyCertificate::RealmName(unsecure) => unsecure.hint(),
             UnsecureAnyCertificate::RealmArchiving(unsecure) => unsecure.hint(),
             UnsecureAnyCertificate::RealmKeyRotation(unsecure) => unsecure.hint(),
@@ -1459,6 +1784,9 @@ pub enum CommonTopicArcCertificate {
     Device(Arc<DeviceCertificate>),
     UserUpdate(Arc<UserUpdateCertificate>),
     RevokedUser(Arc<RevokedUserCertificate>),
+    RevokedDevice(Arc<RevokedDeviceCertificate>),
+    Group(Arc<GroupCertificate>),
+    GroupMember(Arc<GroupMemberCertificate>),
 }
 
 #[derive(Debug, Deserialize)]
@@ -1468,6 +1796,9 @@ pub enum CommonTopicCertificate {
     Device(DeviceCertificate),
     UserUpdate(UserUpdateCertificate),
     RevokedUser(RevokedUserCertificate),
+    RevokedDevice(RevokedDeviceCertificate),
+    Group(GroupCertificate),
+    GroupMember(GroupMemberCertificate),
 }
 
 #[derive(Debug)]
@@ -1476,6 +1807,9 @@ pub enum UnsecureCommonTopicCertificate {
     Device(UnsecureDeviceCertificate),
     UserUpdate(UnsecureUserUpdateCertificate),
     RevokedUser(UnsecureRevokedUserCertificate),
+    RevokedDevice(UnsecureRevokedDeviceCertificate),
+    Group(UnsecureGroupCertificate),
+    GroupMember(UnsecureGroupMemberCertificate),
 }
 
 impl_base_load!(CommonTopicCertificate);
@@ -1489,7 +1823,10 @@ impl CommonTopicCertificate {
             CommonTopicCertificate::User(c) => c.check_data_integrity(),
             CommonTopicCertificate::Device(c) => c.check_data_integrity(),
             CommonTopicCertificate::RevokedUser(c) => c.check_data_integrity(),
+            CommonTopicCertificate::RevokedDevice(c) => c.check_data_integrity(),
             CommonTopicCertificate::UserUpdate(c) => c.check_data_integrity(),
+            CommonTopicCertificate::Group(c) => c.check_data_integrity(),
+            CommonTopicCertificate::GroupMember(c) => c.check_data_integrity(),
         }
     }
 
@@ -1511,12 +1848,27 @@ impl CommonTopicCertificate {
                     unsecure,
                 })
             }
+            CommonTopicCertificate::RevokedDevice(unsecure) => {
+                UnsecureCommonTopicCertificate::RevokedDevice(UnsecureRevokedDeviceCertificate {
+                    signed,
+                    unsecure,
+                })
+            }
             CommonTopicCertificate::UserUpdate(unsecure) => {
                 UnsecureCommonTopicCertificate::UserUpdate(UnsecureUserUpdateCertificate {
                     signed,
                     unsecure,
                 })
             }
+            CommonTopicCertificate::Group(unsecure) => {
+                UnsecureCommonTopicCertificate::Group(UnsecureGroupCertificate { signed, unsecure })
+            }
+            CommonTopicCertificate::GroupMember(unsecure) => {
+                UnsecureCommonTopicCertificate::GroupMember(UnsecureGroupMemberCertificate {
+                    signed,
+                    unsecure,
+                })
+            }
         })
     }
 }
@@ -1527,7 +1879,10 @@ impl UnsecureCommonTopicCertificate {
             UnsecureCommonTopicCertificate::User(unsecure) => unsecure.timestamp(),
             UnsecureCommonTopicCertificate::Device(unsecure) => unsecure.timestamp(),
             UnsecureCommonTopicCertificate::RevokedUser(unsecure) => unsecure.timestamp(),
+            UnsecureCommonTopicCertificate::RevokedDevice(unsecure) => unsecure.timestamp(),
             UnsecureCommonTopicCertificate::UserUpdate(unsecure) => unsecure.timestamp(),
+            UnsecureCommonTopicCertificate::Group(unsecure) => unsecure.timestamp(),
+            UnsecureCommonTopicCertificate::GroupMember(unsecure) => unsecure.timestamp(),
         }
     }
 
@@ -1536,7 +1891,10 @@ impl UnsecureCommonTopicCertificate {
             UnsecureCommonTopicCertificate::User(unsecure) => unsecure.hint(),
             UnsecureCommonTopicCertificate::Device(unsecure) => unsecure.hint(),
             UnsecureCommonTopicCertificate::RevokedUser(unsecure) => unsecure.hint(),
+            UnsecureCommonTopicCertificate::RevokedDevice(unsecure) => unsecure.hint(),
             UnsecureCommonTopicCertificate::UserUpdate(unsecure) => unsecure.hint(),
+            UnsecureCommonTopicCertificate::Group(unsecure) => unsecure.hint(),
+            UnsecureCommonTopicCertificate::GroupMember(unsecure) => unsecure.hint(),
         }
     }
 
@@ -1554,9 +1912,18 @@ impl UnsecureCommonTopicCertificate {
             UnsecureCommonTopicCertificate::RevokedUser(unsecure) => {
                 CommonTopicArcCertificate::RevokedUser(Arc::new(unsecure.skip_validation(reason)))
             }
+            UnsecureCommonTopicCertificate::RevokedDevice(unsecure) => {
+                CommonTopicArcCertificate::RevokedDevice(Arc::new(unsecure.skip_validation(reason)))
+            }
             UnsecureCommonTopicCertificate::UserUpdate(unsecure) => {
                 CommonTopicArcCertificate::UserUpdate(Arc::new(unsecure.skip_validation(reason)))
             }
+            UnsecureCommonTopicCertificate::Group(unsecure) => {
+                CommonTopicArcCertificate::Group(Arc::new(unsecure.skip_validation(reason)))
+            }
+            UnsecureCommonTopicCertificate::GroupMember(unsecure) => {
+                CommonTopicArcCertificate::GroupMember(Arc::new(unsecure.skip_validation(reason)))
+            }
         }
     }
 }
@@ -1601,6 +1968,7 @@ impl SequesterTopicCertificate {
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum RealmTopicArcCertificate {
     RealmRole(Arc<RealmRoleCertificate>),
+    RealmGroupRole(Arc<RealmGroupRoleCertificate>),
     RealmName(Arc<RealmNameCertificate>),
     RealmKeyRotation(Arc<RealmKeyRotationCertificate>),
     RealmArchiving(Arc<RealmArchivingCertificate>),
@@ -1610,6 +1978,7 @@ pub enum RealmTopicArcCertificate {
 #[serde(untagged)]
 pub enum RealmTopicCertificate {
     RealmRole(RealmRoleCertificate),
+    RealmGroupRole(RealmGroupRoleCertificate),
     RealmName(RealmNameCertificate),
     RealmKeyRotation(RealmKeyRotationCertificate),
     RealmArchiving(RealmArchivingCertificate),
@@ -1618,6 +1987,7 @@ pub enum RealmTopicCertificate {
 #[derive(Debug)]
 pub enum UnsecureRealmTopicCertificate {
     RealmRole(UnsecureRealmRoleCertificate),
+    RealmGroupRole(UnsecureRealmGroupRoleCertificate),
     RealmName(UnsecureRealmNameCertificate),
     RealmKeyRotation(UnsecureRealmKeyRotationCertificate),
     RealmArchiving(UnsecureRealmArchivingCertificate),
@@ -1632,6 +2002,7 @@ impl RealmTopicCertificate {
     fn check_data_integrity(&self) -> DataResult<()> {
         match self {
             RealmTopicCertificate::RealmRole(c) => c.check_data_integrity(),
+            RealmTopicCertificate::RealmGroupRole(c) => c.check_data_integrity(),
             RealmTopicCertificate::RealmName(c) => c.check_data_integrity(),
             RealmTopicCertificate::RealmKeyRotation(c) => c.check_data_integrity(),
             RealmTopicCertificate::RealmArchiving(c) => c.check_data_integrity(),
@@ -1647,6 +2018,12 @@ impl RealmTopicCertificate {
                     unsecure,
                 })
             }
+            RealmTopicCertificate::RealmGroupRole(unsecure) => {
+                UnsecureRealmTopicCertificate::RealmGroupRole(UnsecureRealmGroupRoleCertificate {
+                    signed,
+                    unsecure,
+                })
+            }
             RealmTopicCertificate::RealmName(unsecure) => {
                 UnsecureRealmTopicCertificate::RealmName(UnsecureRealmNameCertificate {
                     signed,
@@ -1672,6 +2049,7 @@ impl UnsecureRealmTopicCertificate {
     pub fn timestamp(&self) -> &DateTime {
         match self {
             UnsecureRealmTopicCertificate::RealmRole(unsecure) => unsecure.timestamp(),
+            UnsecureRealmTopicCertificate::RealmGroupRole(unsecure) => unsecure.timestamp(),
             UnsecureRealmTopicCertificate::RealmName(unsecure) => unsecure.timestamp(),
             UnsecureRealmTopicCertificate::RealmKeyRotation(unsecure) => unsecure.timestamp(),
             UnsecureRealmTopicCertificate::RealmArchiving(unsecure) => unsecure.timestamp(),
@@ -1681,6 +2059,7 @@ impl UnsecureRealmTopicCertificate {
     pub fn hint(&self) -> String {
         match self {
             UnsecureRealmTopicCertificate::RealmRole(unsecure) => unsecure.hint(),
+            UnsecureRealmTopicCertificate::RealmGroupRole(unsecure) => unsecure.hint(),
             UnsecureRealmTopicCertificate::RealmName(unsecure) => unsecure.hint(),
             UnsecureRealmTopicCertificate::RealmKeyRotation(unsecure) => unsecure.hint(),
             UnsecureRealmTopicCertificate::RealmArchiving(unsecure) => unsecure.hint(),
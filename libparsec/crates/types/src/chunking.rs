@@ -0,0 +1,81 @@
+// Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+//! Content-defined chunking (CDC) of the file content.
+//!
+//! With fixed-size chunking, inserting a single byte at the beginning of a file
+//! shifts all the following data and hence modifies every block. Instead, with
+//! content-defined chunking the block boundaries are determined by the data itself
+//! (using a rolling hash), so they move along with the data and only the blocks
+//! around the modification change.
+//!
+//! This is the FastCDC algorithm (with normalized chunking), see
+//! https://www.usenix.org/conference/atc16/technical-sessions/presentation/xia
+//!
+//! Note the block boundaries must be stable across clients and versions for
+//! block deduplication to work, hence the gear table and the chunk size
+//! parameters must never be changed.
+
+use crate::Blocksize;
+
+/// Random values used by the gear rolling hash, generated with splitmix64.
+const GEAR: [u64; 256] = {
+    let mut table = [0u64; 256];
+    let mut state: u64 = 0;
+    let mut i = 0;
+    while i < table.len() {
+        state = state.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z = state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        table[i] = z ^ (z >> 31);
+        i += 1;
+    }
+    table
+};
+
+/// Return the size of the chunk starting at the beginning of `data`.
+///
+/// `data` is expected to start at a chunk boundary, and to contain at least
+/// `blocksize` bytes unless it is the end of the data to chunk (in which case the
+/// last chunk may be smaller than the minimum chunk size).
+///
+/// The chunk size is between `blocksize / 4` and `blocksize`, with an average of
+/// `blocksize / 2`, so that each chunk fits in a block.
+pub fn content_defined_chunk_size(data: &[u8], blocksize: Blocksize) -> usize {
+    let max_size = blocksize.inner() as usize;
+    let min_size = max_size / 4;
+    let avg_size = max_size / 2;
+
+    if data.len() <= min_size {
+        return data.len();
+    }
+    let end = data.len().min(max_size);
+    let normal_end = end.min(avg_size);
+
+    // Normalized chunking: the mask is harder to satisfy before the average size
+    // and easier after it, which narrows the chunk size distribution.
+    // Note the most significant bits are used given they depend on the last
+    // 64 bytes, while the least significant bits only depend on the last few bytes.
+    let bits = avg_size.ilog2();
+    let hard_mask = !(u64::MAX >> (bits + 1));
+    let easy_mask = !(u64::MAX >> (bits - 1));
+
+    let mut hash: u64 = 0;
+    for (index, byte) in data.iter().enumerate().take(end).skip(min_size) {
+        hash = (hash << 1).wrapping_add(GEAR[*byte as usize]);
+        let mask = if index < normal_end {
+            hard_mask
+        } else {
+            easy_mask
+        };
+        if hash & mask == 0 {
+            return index + 1;
+        }
+    }
+
+    end
+}
+
+#[cfg(test)]
+#[path = "../tests/unit/chunking.rs"]
+mod tests;
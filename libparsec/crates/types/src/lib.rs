@@ -41,6 +41,10 @@ pub const REALM_RENAME_KEY_DERIVATION_UUID: uuid::Uuid =
     uuid::uuid!("00000000-0000-0000-0000-000000000002");
 pub const PATH_URL_KEY_DERIVATION_UUID: uuid::Uuid =
     uuid::uuid!("00000000-0000-0000-0000-000000000003");
+pub const BLOCK_DEDUPLICATION_KEY_DERIVATION_UUID: uuid::Uuid =
+    uuid::uuid!("00000000-0000-0000-0000-000000000004");
+pub const COMPRESSED_BLOCK_DEDUPLICATION_KEY_DERIVATION_UUID: uuid::Uuid =
+    uuid::uuid!("00000000-0000-0000-0000-000000000005");
 
 /// Base32 alphabet used for human facing codes (see `SASCode` & `ValidationCode`)
 /// (Note I/1 and 0/O are skipped to avoid visual confusion)
@@ -57,6 +61,7 @@ mod account;
 mod addr;
 mod async_enrollment;
 mod certif;
+mod chunking;
 pub mod data_macros;
 mod error;
 mod ext_types;
@@ -85,6 +90,7 @@ pub use account::*;
 pub use addr::*;
 pub use async_enrollment::*;
 pub use certif::*;
+pub use chunking::*;
 pub use error::*;
 pub use ext_types::*;
 pub use fs_path::*;
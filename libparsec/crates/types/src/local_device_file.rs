@@ -8,9 +8,9 @@ use libparsec_serialization_format::parsec_data;
 
 use crate::{self as libparsec_types, PKIEncryptionAlgorithm, X509CertificateReference};
 use crate::{
-    impl_transparent_data_format_conversion, AccountVaultItemOpaqueKeyID, DateTime, DeviceID,
-    DeviceLabel, HumanHandle, OrganizationID, ParsecAddr, PasswordAlgorithm, TOTPOpaqueKeyID,
-    UserID,
+    impl_transparent_data_format_conversion, AccountVaultItemOpaqueKeyID, DataError, DataResult,
+    DateTime, DeviceID, DeviceLabel, HumanHandle, OrganizationID, ParsecAddr, PasswordAlgorithm,
+    SecretKey, TOTPOpaqueKeyID, UserID,
 };
 
 /*
@@ -318,6 +318,127 @@ impl_transparent_data_format_conversion!(
     totp_opaque_key_id,
 );
 
+/*
+ * DeviceFileMachineKey
+ */
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(
+    into = "DeviceFileMachineKeyData",
+    try_from = "DeviceFileMachineKeyData"
+)]
+pub struct DeviceFileMachineKey {
+    pub created_on: DateTime,
+    pub protected_on: DateTime,
+    pub server_url: ParsecAddr,
+    pub organization_id: OrganizationID,
+    pub user_id: UserID,
+    pub device_id: DeviceID,
+    pub human_handle: HumanHandle,
+    pub device_label: DeviceLabel,
+    pub machine_key_file: String,
+    pub ciphertext: Bytes,
+    pub totp_opaque_key_id: Option<TOTPOpaqueKeyID>,
+}
+
+parsec_data!("schema/local_device/device_file_machine_key.json5");
+
+impl_transparent_data_format_conversion!(
+    DeviceFileMachineKey,
+    DeviceFileMachineKeyData,
+    created_on,
+    protected_on,
+    server_url,
+    organization_id,
+    user_id,
+    device_id,
+    human_handle,
+    device_label,
+    machine_key_file,
+    ciphertext,
+    totp_opaque_key_id,
+);
+
+/*
+ * DeviceFileFido2
+ */
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(into = "DeviceFileFido2Data", try_from = "DeviceFileFido2Data")]
+pub struct DeviceFileFido2 {
+    pub created_on: DateTime,
+    pub protected_on: DateTime,
+    pub server_url: ParsecAddr,
+    pub organization_id: OrganizationID,
+    pub user_id: UserID,
+    pub device_id: DeviceID,
+    pub human_handle: HumanHandle,
+    pub device_label: DeviceLabel,
+    pub fido2_relying_party_id: String,
+    pub fido2_credential_id: Bytes,
+    pub fido2_hmac_secret_salt: Bytes,
+    pub ciphertext: Bytes,
+    pub totp_opaque_key_id: Option<TOTPOpaqueKeyID>,
+}
+
+parsec_data!("schema/local_device/device_file_fido2.json5");
+
+impl_transparent_data_format_conversion!(
+    DeviceFileFido2,
+    DeviceFileFido2Data,
+    created_on,
+    protected_on,
+    server_url,
+    organization_id,
+    user_id,
+    device_id,
+    human_handle,
+    device_label,
+    fido2_relying_party_id,
+    fido2_credential_id,
+    fido2_hmac_secret_salt,
+    ciphertext,
+    totp_opaque_key_id,
+);
+
+/*
+ * DeviceFileMulti
+ */
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(into = "DeviceFileMultiData", try_from = "DeviceFileMultiData")]
+pub struct DeviceFileMulti {
+    pub created_on: DateTime,
+    pub protected_on: DateTime,
+    pub server_url: ParsecAddr,
+    pub organization_id: OrganizationID,
+    pub user_id: UserID,
+    pub device_id: DeviceID,
+    pub human_handle: HumanHandle,
+    pub device_label: DeviceLabel,
+    pub protections: Vec<DeviceFileMultiProtection>,
+    pub ciphertext: Bytes,
+    pub totp_opaque_key_id: Option<TOTPOpaqueKeyID>,
+}
+
+parsec_data!("schema/local_device/device_file_multi.json5");
+
+impl_transparent_data_format_conversion!(
+    DeviceFileMulti,
+    DeviceFileMultiData,
+    created_on,
+    protected_on,
+    server_url,
+    organization_id,
+    user_id,
+    device_id,
+    human_handle,
+    device_label,
+    protections,
+    ciphertext,
+    totp_opaque_key_id,
+);
+
 /*
  * DeviceFile
  */
@@ -331,6 +452,9 @@ pub enum DeviceFile {
     PKI(DeviceFilePKI),
     AccountVault(DeviceFileAccountVault),
     OpenBao(DeviceFileOpenBao),
+    MachineKey(DeviceFileMachineKey),
+    Fido2(DeviceFileFido2),
+    Multi(DeviceFileMulti),
 }
 
 impl DeviceFile {
@@ -350,6 +474,9 @@ impl DeviceFile {
             DeviceFile::PKI(device) => &device.ciphertext,
             DeviceFile::AccountVault(device) => &device.ciphertext,
             DeviceFile::OpenBao(device) => &device.ciphertext,
+            DeviceFile::MachineKey(device) => &device.ciphertext,
+            DeviceFile::Fido2(device) => &device.ciphertext,
+            DeviceFile::Multi(device) => &device.ciphertext,
         }
     }
 
@@ -361,8 +488,95 @@ impl DeviceFile {
             DeviceFile::PKI(device) => device.created_on,
             DeviceFile::AccountVault(device) => device.created_on,
             DeviceFile::OpenBao(device) => device.created_on,
+            DeviceFile::MachineKey(device) => device.created_on,
+            DeviceFile::Fido2(device) => device.created_on,
+            DeviceFile::Multi(device) => device.created_on,
         }
     }
+
+    /// Return the protection of the device file in the terms of the server's
+    /// advisory configuration, or `None` if it has no such equivalent (e.g.
+    /// recovery or multi-protected device file).
+    pub fn advisory_protection(&self) -> Option<AdvisoryDeviceFileProtection> {
+        let (primary, totp_opaque_key_id) = match self {
+            DeviceFile::Keyring(device) => (
+                AdvisoryDeviceFilePrimaryProtection::Keyring,
+                device.totp_opaque_key_id,
+            ),
+            DeviceFile::Password(device) => (
+                AdvisoryDeviceFilePrimaryProtection::Password,
+                device.totp_opaque_key_id,
+            ),
+            DeviceFile::PKI(device) => (
+                AdvisoryDeviceFilePrimaryProtection::PKI,
+                device.totp_opaque_key_id,
+            ),
+            DeviceFile::AccountVault(device) => (
+                AdvisoryDeviceFilePrimaryProtection::AccountVault,
+                device.totp_opaque_key_id,
+            ),
+            DeviceFile::OpenBao(device) => (
+                AdvisoryDeviceFilePrimaryProtection::OpenBao,
+                device.totp_opaque_key_id,
+            ),
+            DeviceFile::Recovery(_)
+            | DeviceFile::MachineKey(_)
+            | DeviceFile::Fido2(_)
+            | DeviceFile::Multi(_) => return None,
+        };
+        Some(AdvisoryDeviceFileProtection {
+            primary,
+            with_totp: totp_opaque_key_id.is_some(),
+        })
+    }
+}
+
+/*
+ * DeviceFilesBackup
+ */
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(into = "DeviceFilesBackupData", try_from = "DeviceFilesBackupData")]
+pub struct DeviceFilesBackup {
+    pub created_on: DateTime,
+    pub algorithm: PasswordAlgorithm,
+    pub ciphertext: Bytes,
+}
+
+parsec_data!("schema/local_device/device_files_backup.json5");
+
+impl_transparent_data_format_conversion!(
+    DeviceFilesBackup,
+    DeviceFilesBackupData,
+    created_on,
+    algorithm,
+    ciphertext,
+);
+
+impl DeviceFilesBackup {
+    pub fn dump(&self) -> Vec<u8> {
+        rmp_serde::to_vec_named(self).expect("Unreachable")
+    }
+
+    pub fn load(data: &[u8]) -> Result<Self, rmp_serde::decode::Error> {
+        rmp_serde::from_slice(data)
+    }
+
+    pub fn encrypt_entries(key: &SecretKey, entries: &[DeviceFilesBackupEntry]) -> Bytes {
+        // Note the device files are already encrypted, hence no need to zeroize here
+        let cleartext = rmp_serde::to_vec_named(entries).expect("Unreachable");
+        key.encrypt(&cleartext).into()
+    }
+
+    pub fn decrypt_entries(&self, key: &SecretKey) -> DataResult<Vec<DeviceFilesBackupEntry>> {
+        let cleartext = key
+            .decrypt(&self.ciphertext)
+            .map_err(|_| DataError::Decryption)?;
+        rmp_serde::from_slice(&cleartext).map_err(|_| DataError::BadSerialization {
+            format: None,
+            step: "msgpack",
+        })
+    }
 }
 
 #[cfg(test)]
@@ -44,6 +44,10 @@ pub struct LocalFileManifest {
     /// process flatten the chunks into a single one (hence a `LocalFileManifest`
     /// created from a `FileManifest` should only contains block slots made of
     /// a single chunk).
+    ///
+    /// The exception being content-defined chunking (see `FileManifest::content_defined_chunking`),
+    /// where a block slot can contain multiple blocks, and a block spanning over two block
+    /// slots is represented by a chunk view in each of them.
     pub blocks: Vec<Vec<ChunkView>>,
 }
 
@@ -95,6 +99,7 @@ impl LocalFileManifest {
                 blocksize: DEFAULT_BLOCK_SIZE,
                 size: 0,
                 blocks: vec![],
+                content_defined_chunking: false,
             },
             parent,
             need_sync: true,
@@ -110,15 +115,52 @@ impl LocalFileManifest {
     }
 
     pub fn is_reshaped(&self) -> bool {
-        for chunks in self.blocks.iter() {
-            if chunks.is_empty() {
-                continue;
+        Self::get_reshaped_blocks(&self.blocks).is_some()
+    }
+
+    /// Return the blocks the chunk views correspond to, or `None` if some chunk views
+    /// don't entirely correspond to a block (i.e. a reshape is needed).
+    ///
+    /// Note a block is allowed to be represented by two chunk views (each using
+    /// part of it) if it spans over two block slots.
+    pub fn get_reshaped_blocks(blocks: &[Vec<ChunkView>]) -> Option<Vec<&BlockAccess>> {
+        let mut reshaped = Vec::<&BlockAccess>::new();
+        // Set if the last chunk view only covers the beginning of its block
+        let mut expected_next_start = None;
+
+        for chunk_view in blocks.iter().flatten() {
+            let access = chunk_view.access.as_ref()?;
+            if chunk_view.raw_offset != access.offset || chunk_view.raw_size != access.size {
+                return None;
             }
-            if chunks.len() > 1 || !chunks[0].is_block() {
-                return false;
+
+            match expected_next_start {
+                // The chunk view must cover the end of the previous block
+                Some(expected_start) => {
+                    if chunk_view.start != expected_start || reshaped.last() != Some(&access) {
+                        return None;
+                    }
+                }
+                // The chunk view must cover the beginning of a new block
+                None => {
+                    if chunk_view.start != access.offset {
+                        return None;
+                    }
+                    reshaped.push(access);
+                }
             }
+
+            expected_next_start = if chunk_view.stop.get() == access.offset + access.size.get() {
+                None
+            } else {
+                Some(chunk_view.stop.get())
+            };
+        }
+
+        match expected_next_start {
+            Some(_) => None,
+            None => Some(reshaped),
         }
-        true
     }
 
     /// The chunks in a local file manifest should:
@@ -196,19 +238,29 @@ impl LocalFileManifest {
     }
 
     pub fn from_remote(remote: FileManifest) -> Self {
-        let chunk_views: Vec<ChunkView> = remote
-            .blocks
-            .iter()
-            .map(|access| ChunkView::from_block_access(access.to_owned()))
-            .collect();
-
         let mut blocks = vec![];
-        for chunk_view in chunk_views {
-            let block = (chunk_view.start / *remote.blocksize) as usize;
-            while blocks.len() <= block {
-                blocks.push(vec![]);
+        for access in remote.blocks.iter() {
+            let mut chunk_view = ChunkView::from_block_access(access.to_owned());
+            let block_stop = chunk_view.stop;
+            // With content-defined chunking, a block can span over two block slots
+            loop {
+                let block = (chunk_view.start / *remote.blocksize) as usize;
+                let block_slot_stop = (block as u64 + 1) * *remote.blocksize;
+                while blocks.len() <= block {
+                    blocks.push(vec![]);
+                }
+                if block_stop.get() <= block_slot_stop {
+                    blocks[block].push(chunk_view);
+                    break;
+                }
+                let mut next_chunk_view = chunk_view.clone();
+                chunk_view.stop = block_slot_stop
+                    .try_into()
+                    .expect("block slot stop is greater than chunk view start");
+                next_chunk_view.start = block_slot_stop;
+                blocks[block].push(chunk_view);
+                chunk_view = next_chunk_view;
             }
-            blocks[block].push(chunk_view);
         }
 
         let manifest = Self {
@@ -237,28 +289,30 @@ impl LocalFileManifest {
         self.check_data_integrity()
             .expect("Local file manifest content integrity");
 
-        let blocks = self
-            .blocks
-            .iter()
-            // In a local manifest, each blocksize area is represented by a list of chunks.
-            // That list might be empty if it doesn't contain any data (e.g when the file has been resized)
-            // Since remote manifests is composed of a flat list of ordered and reshaped blocks,
-            // empty blocks (i.e lists containing no chunks) are simply filtered out.
-            .filter(|chunks| !chunks.is_empty())
-            // Each blocksize area is expected to contain a single chunk view, reshaped as an uploadable block
-            // (i.e a chunk view with an access). If not, the `NotReshaped` error is returned.
-            .map(|chunks| match chunks.len() {
-                0 => unreachable!(),
-                1 => chunks[0]
-                    .get_block_access()
-                    .map_err(|_| LocalFileManifestToRemoteError::NeedReshape),
-                _ => Err(LocalFileManifestToRemoteError::NeedReshape),
-            })
-            .collect::<Result<Vec<_>, _>>()?
+        // In a local manifest, each blocksize area is represented by a list of chunks.
+        // That list might be empty if it doesn't contain any data (e.g when the file has been resized)
+        // Since remote manifests is composed of a flat list of ordered and reshaped blocks,
+        // empty blocks (i.e lists containing no chunks) are simply skipped.
+        // Each chunk view is expected to correspond to an uploadable block (i.e a chunk view
+        // with an access). If not, the `NotReshaped` error is returned.
+        let blocks: Vec<BlockAccess> = Self::get_reshaped_blocks(&self.blocks)
+            .ok_or(LocalFileManifestToRemoteError::NeedReshape)?
             .into_iter()
             .cloned()
             .collect();
 
+        // Content-defined chunking is only flagged when the blocks are not aligned on
+        // the blocksize, this way older clients can still read the file otherwise.
+        let mut previous_block_span = None;
+        let content_defined_chunking = blocks.iter().any(|block| {
+            let block_span = block.offset / *self.blocksize;
+            let last_block_span = (block.offset + block.size.get() - 1) / *self.blocksize;
+            let share_block_span =
+                previous_block_span.is_some_and(|previous| previous >= block_span);
+            previous_block_span = Some(block_span);
+            share_block_span || last_block_span != block_span
+        });
+
         let manifest = FileManifest::new(
             author,
             timestamp,
@@ -270,6 +324,7 @@ impl LocalFileManifest {
             self.size,
             self.blocksize,
             blocks,
+            content_defined_chunking,
         );
 
         Ok(manifest)
@@ -443,6 +498,7 @@ impl ChunkView {
             offset: self.start,
             size: self.size().try_into().expect("size must be > 0"),
             digest: HashDigest::from_data(data),
+            compressed: false,
         });
 
         Ok(())
@@ -8,10 +8,28 @@ use serde::{Deserialize, Serialize};
 use crate::{
     self as libparsec_types, impl_transparent_data_format_conversion, DataError, DataResult,
     DateTime, DeviceID, EntryName, FolderManifest, PreventSyncPattern, VlobID,
+    SHARED_PREVENT_SYNC_PATTERN_FILENAME,
 };
 
 use super::{impl_local_manifest_dump, impl_local_manifest_load};
 
+/// Check if a child entry should be confined according to the prevent sync pattern.
+///
+/// The shared prevent sync pattern file (located at the root of the workspace) is
+/// never confined: otherwise a pattern matching it (e.g. `*` or `.*`) would stop the
+/// file from being synchronized, hence the pattern from being shared, and any member
+/// could confine the whole workspace for everyone.
+fn is_confined_child(
+    prevent_sync_pattern: &PreventSyncPattern,
+    parent_is_root: bool,
+    name: &EntryName,
+) -> bool {
+    if parent_is_root && name.as_ref() == SHARED_PREVENT_SYNC_PATTERN_FILENAME {
+        return false;
+    }
+    prevent_sync_pattern.is_match(name.as_ref())
+}
+
 /// The `LocalFolderManifest` represents a folder in the client.
 ///
 /// Unlike `FolderManifest`, it is designed to be modified as changes
@@ -195,9 +213,10 @@ impl LocalFolderManifest {
         );
 
         // Deal with additions second
+        let is_root = self.base.is_root();
         for (name, entry_id) in data.into_iter() {
             if let Some(entry_id) = entry_id {
-                if prevent_sync_pattern.is_match(name.as_ref()) {
+                if is_confined_child(prevent_sync_pattern, is_root, &name) {
                     self.local_confinement_points.insert(entry_id);
                 } else {
                     actually_updated = true;
@@ -305,12 +324,13 @@ impl UnconfinedLocalFolderManifest {
         prevent_sync_pattern: &PreventSyncPattern,
     ) -> LocalFolderManifest {
         // Filter out the base entries that matches the prevent sync pattern
+        let is_root = remote.is_root();
         let mut new_children = remote.children.clone();
         let remote_confinement_points: HashSet<_> = remote
             .children
             .iter()
             .filter_map(|(name, entry_id)| {
-                if prevent_sync_pattern.is_match(name.as_ref()) {
+                if is_confined_child(prevent_sync_pattern, is_root, name) {
                     new_children.remove(name);
                     Some(*entry_id)
                 } else {
@@ -350,13 +370,14 @@ impl UnconfinedLocalFolderManifest {
         timestamp: DateTime,
     ) -> LocalFolderManifest {
         // Filter out the base entries that matches the prevent sync pattern
+        let is_root = self.base.is_root();
         let mut new_children = self.children;
         let remote_confinement_points: HashSet<_> = self
             .base
             .children
             .iter()
             .filter_map(|(name, entry_id)| {
-                if prevent_sync_pattern.is_match(name.as_ref()) {
+                if is_confined_child(prevent_sync_pattern, is_root, name) {
                     if new_children.get(name).is_some_and(|x| x == entry_id) {
                         new_children.remove(name);
                     }
@@ -371,7 +392,7 @@ impl UnconfinedLocalFolderManifest {
         let local_confinement_points: HashSet<_> = new_children
             .iter()
             .filter_map(|(name, entry_id)| {
-                if prevent_sync_pattern.is_match(name.as_ref()) {
+                if is_confined_child(prevent_sync_pattern, is_root, name) {
                     Some(*entry_id)
                 } else {
                     None
@@ -434,7 +455,7 @@ impl UnconfinedLocalFolderManifest {
                 // Case 2
                 if !new_entry_ids.contains_key(entry_id)
                     && new_manifest.remote_confinement_points.contains(entry_id)
-                    && prevent_sync_pattern.is_match(name.as_ref())
+                    && is_confined_child(prevent_sync_pattern, is_root, name)
                 {
                     existing_local_confined_entries.insert(name.clone(), Some(*entry_id));
                     new_entry_ids.insert(entry_id, name);
@@ -10,8 +10,9 @@ use libparsec_serialization_format::parsec_data;
 use crate::{
     self as libparsec_types,
     data_macros::impl_transparent_data_format_conversion,
-    serialization::{format_v0_dump, format_vx_load},
-    BlockID, DataError, DataResult, DateTime, DeviceID, EntryName, SizeInt, VersionInt, VlobID,
+    serialization::{block_compress, block_decompress, format_v0_dump, format_vx_load},
+    BlockID, DataError, DataResult, DateTime, DeviceID, EntryName, Maybe, SizeInt, VersionInt,
+    VlobID,
 };
 
 pub const DEFAULT_BLOCK_SIZE: Blocksize = Blocksize(512 * 1024); // 512 KB
@@ -172,6 +173,36 @@ pub struct BlockAccess {
     /// block integrity.
     pub size: NonZeroU64,
     pub digest: HashDigest,
+    /// If `true`, the block data has been compressed (zstd) before being encrypted.
+    ///
+    /// Note size and hash digest always refer to the uncompressed data.
+    ///
+    /// This field has been introduced in Parsec v3.9, hence it is omitted when `false`
+    /// so that uncompressed blocks are serialized exactly as before.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub compressed: bool,
+}
+
+impl BlockAccess {
+    /// Compress the block data, to be done before encryption.
+    ///
+    /// Note zstd falls back to storing raw data for incompressible data, hence the
+    /// overhead is negligible in this case.
+    pub fn compress_data(data: &[u8]) -> Vec<u8> {
+        block_compress(data)
+    }
+
+    /// Retrieve the block data from its decrypted content (i.e. decompress it if needed).
+    ///
+    /// Note decompression fails if the data is bigger than the block size, but the
+    /// actual size and hash digest are not checked here.
+    pub fn decompress_data(&self, decrypted: Vec<u8>) -> DataResult<Vec<u8>> {
+        if self.compressed {
+            block_decompress(&decrypted, self.size.get())
+        } else {
+            Ok(decrypted)
+        }
+    }
 }
 
 /*
@@ -314,8 +345,20 @@ pub struct FileManifest {
     /// - Each block access has an offset aligned on `blocksize`.
     /// - If `blocksize` is updated (typically if the file grows too big, although not
     ///   implemented at the moment), all blocks must be reshaped to match the new blocksize.
+    ///
+    /// The exception being when `content_defined_chunking` is set, in which case
+    /// `blocksize` is only the maximum size of a block.
     pub blocksize: Blocksize,
     pub blocks: Vec<BlockAccess>,
+    /// If `true`, the blocks boundaries have been determined by the content of the file
+    /// (see `content_defined_chunk_size`) instead of being aligned on `blocksize`.
+    ///
+    /// In this case blocks can have any size up to `blocksize`, hence they are allowed
+    /// to share a block span and to span over two block spans.
+    ///
+    /// This field has been introduced in Parsec v3.9, hence it is omitted when `false`
+    /// so that older clients can still read the files using fixed-size blocks.
+    pub content_defined_chunking: bool,
 }
 
 impl_manifest_dump!(FileManifest);
@@ -336,6 +379,7 @@ impl FileManifest {
         size: SizeInt,
         blocksize: Blocksize,
         blocks: Vec<BlockAccess>,
+        content_defined_chunking: bool,
     ) -> Self {
         let manifest = Self {
             author,
@@ -348,6 +392,7 @@ impl FileManifest {
             size,
             blocksize,
             blocks,
+            content_defined_chunking,
         };
         manifest.check_data_integrity().expect("Invalid manifest");
         manifest
@@ -360,6 +405,9 @@ impl FileManifest {
     /// - not share the same block span
     /// - not span over multiple block spans
     ///
+    /// With content-defined chunking, the last two rules are replaced by:
+    /// - not be bigger than the blocksize
+    ///
     /// Note that they do not have to be contiguous.
     /// Those checks have to remain compatible with `LocalFileManifest::check_data_integrity`.
     /// Also, the id and parent id should be different so the manifest does not point to itself.
@@ -391,6 +439,17 @@ impl FileManifest {
             }
             current_offset = block.offset + block.size.get();
 
+            if self.content_defined_chunking {
+                // Check that blocks are not bigger than the blocksize
+                if block.size.get() > self.blocksize.inner() {
+                    return Err(DataError::DataIntegrity {
+                        data_type: std::any::type_name::<Self>(),
+                        invariant: "blocks are not bigger than the blocksize",
+                    });
+                }
+                continue;
+            }
+
             // Check that blocks are not sharing the same block span
             let block_index = block.offset / self.blocksize.inner();
             if current_block_index > block_index {
@@ -435,6 +494,7 @@ impl TryFrom<FileManifestData> for FileManifest {
             size: data.size,
             blocksize: data.blocksize.try_into()?,
             blocks: data.blocks,
+            content_defined_chunking: matches!(data.content_defined_chunking, Maybe::Present(true)),
         })
     }
 }
@@ -453,6 +513,11 @@ impl From<FileManifest> for FileManifestData {
             size: obj.size,
             blocksize: obj.blocksize.into(),
             blocks: obj.blocks,
+            content_defined_chunking: if obj.content_defined_chunking {
+                Maybe::Present(true)
+            } else {
+                Maybe::Absent
+            },
         }
     }
 }
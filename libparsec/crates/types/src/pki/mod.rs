@@ -14,12 +14,16 @@ pub use cert_ref::{
 )]
 pub enum PKIEncryptionAlgorithm {
     RsaesOaepSha256,
+    RsaesOaepSha384,
+    RsaesOaepSha512,
 }
 
 impl From<PKIEncryptionAlgorithm> for &'static str {
     fn from(value: PKIEncryptionAlgorithm) -> Self {
         match value {
             PKIEncryptionAlgorithm::RsaesOaepSha256 => "RSAES-OAEP-SHA256",
+            PKIEncryptionAlgorithm::RsaesOaepSha384 => "RSAES-OAEP-SHA384",
+            PKIEncryptionAlgorithm::RsaesOaepSha512 => "RSAES-OAEP-SHA512",
         }
     }
 }
@@ -30,6 +34,8 @@ impl FromStr for PKIEncryptionAlgorithm {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "RSAES-OAEP-SHA256" => Ok(Self::RsaesOaepSha256),
+            "RSAES-OAEP-SHA384" => Ok(Self::RsaesOaepSha384),
+            "RSAES-OAEP-SHA512" => Ok(Self::RsaesOaepSha512),
             _ => Err("Unknown encryption algorithm"),
         }
     }
@@ -53,12 +59,18 @@ impl Display for PKIEncryptionAlgorithm {
 )]
 pub enum PkiSignatureAlgorithm {
     RsassaPssSha256,
+    /// Note the signature is ASN.1 DER encoded (i.e. not the raw `r || s` form).
+    EcdsaP256Sha256,
+    /// Note the signature is ASN.1 DER encoded (i.e. not the raw `r || s` form).
+    EcdsaP384Sha384,
 }
 
 impl From<PkiSignatureAlgorithm> for &'static str {
     fn from(value: PkiSignatureAlgorithm) -> Self {
         match value {
             PkiSignatureAlgorithm::RsassaPssSha256 => "RSASSA-PSS-SHA256",
+            PkiSignatureAlgorithm::EcdsaP256Sha256 => "ECDSA-P256-SHA256",
+            PkiSignatureAlgorithm::EcdsaP384Sha384 => "ECDSA-P384-SHA384",
         }
     }
 }
@@ -75,6 +87,8 @@ impl FromStr for PkiSignatureAlgorithm {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "RSASSA-PSS-SHA256" => Ok(Self::RsassaPssSha256),
+            "ECDSA-P256-SHA256" => Ok(Self::EcdsaP256Sha256),
+            "ECDSA-P384-SHA384" => Ok(Self::EcdsaP384Sha384),
             _ => Err("Unknown signature algorithm"),
         }
     }
@@ -18,6 +18,15 @@ pub struct PreventSyncPattern(pub Vec<regex::Regex>);
 
 const DEFAULT_PREVENT_SYNC_PATTERN: &str = std::include_str!("default_pattern.ignore");
 
+/// Name of the file (located at the root of the workspace) containing the prevent
+/// sync pattern shared among all the workspace's members.
+///
+/// This is a regular file (i.e. synchronized like any other) using the glob ignore
+/// file format (see [`PreventSyncPattern::from_glob_ignore_file`]). Note this is
+/// not the full gitignore syntax: patterns are matched against entry names, hence
+/// negation (`!pattern`) and path separators (`/`) are not supported.
+pub const SHARED_PREVENT_SYNC_PATTERN_FILENAME: &str = ".parsecignore";
+
 impl Default for PreventSyncPattern {
     fn default() -> Self {
         Self::from_glob_ignore_file(DEFAULT_PREVENT_SYNC_PATTERN)
@@ -84,6 +93,18 @@ impl PreventSyncPattern {
     pub const fn empty() -> Self {
         Self(Vec::new())
     }
+
+    /// Combine two prevent sync patterns: the result matches anything that is matched
+    /// by either of them.
+    pub fn merge(&self, other: &Self) -> Self {
+        let mut regexes = self.0.clone();
+        for regex in &other.0 {
+            if !regexes.iter().any(|r| r.as_str() == regex.as_str()) {
+                regexes.push(regex.clone());
+            }
+        }
+        Self(regexes)
+    }
 }
 
 /// Parse a glob pattern like `*.rs` and convert it to an regex.
@@ -38,4 +38,12 @@ impl<K: PartialEq, V, const L: usize> RoundRobinCache<K, V, L> {
             })
             .map(|(_, value)| value)
     }
+
+    pub fn remove(&mut self, key: &K) {
+        for item in self.items.iter_mut() {
+            if matches!(item, Some((candidate_key, _)) if candidate_key == key) {
+                *item = None;
+            }
+        }
+    }
 }
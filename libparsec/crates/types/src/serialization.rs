@@ -8,6 +8,11 @@ use crate::DataError;
 
 /// Format v0: `0x00` + zstd(msgpack(<data>))
 const FORMAT_V0_VERSION_BYTE: u8 = 0;
+/// Upper bound of the uncompressed data, to protect against decompression bombs.
+///
+/// This is way above the size of any legitimate data (e.g. a folder manifest with
+/// a million children is well below 100MB).
+const FORMAT_V0_MAX_UNCOMPRESSED_SIZE: u64 = 256 * 1024 * 1024;
 
 /// Format v0: msgpack + zstd
 pub(crate) fn format_v0_dump<T>(obj: &T) -> Vec<u8>
@@ -39,10 +44,11 @@ where
     match raw.first() {
         Some(&FORMAT_V0_VERSION_BYTE) => {
             let step1_input = {
-                zstd::stream::decode_all(&raw[1..]).map_err(|_| DataError::BadSerialization {
-                    format: Some(FORMAT_V0_VERSION_BYTE),
-                    step: "zstd",
-                })?
+                zstd::stream::decode_all_bounded(&raw[1..], FORMAT_V0_MAX_UNCOMPRESSED_SIZE)
+                    .map_err(|_| DataError::BadSerialization {
+                        format: Some(FORMAT_V0_VERSION_BYTE),
+                        step: "zstd",
+                    })?
             };
 
             rmp_serde::from_slice(&step1_input).map_err(|_| DataError::BadSerialization {
@@ -57,3 +63,19 @@ where
         }),
     }
 }
+
+/// Block compression: zstd(<data>)
+pub(crate) fn block_compress(data: &[u8]) -> Vec<u8> {
+    let mut output = Vec::new();
+    zstd::stream::copy_encode(data, &mut output, 0).expect("unexpected compression error");
+    output
+}
+
+/// Block decompression, `max_size` being the expected size of the block: the data
+/// comes from the server so it must not be trusted (i.e. decompression bomb).
+pub(crate) fn block_decompress(raw: &[u8], max_size: u64) -> Result<Vec<u8>, DataError> {
+    zstd::stream::decode_all_bounded(raw, max_size).map_err(|_| DataError::BadSerialization {
+        format: None,
+        step: "zstd",
+    })
+}
@@ -78,6 +78,24 @@ fn debug_format(alice: &Device, bob: &Device, timestamp: DateTime) {
         )
     );
 
+    let revoked_device_certificate = RevokedDeviceCertificate {
+        author: alice.device_id,
+        timestamp,
+        user_id: bob.user_id,
+        device_id: bob.device_id,
+    };
+    p_assert_eq!(
+        format!("{:?}", revoked_device_certificate),
+        concat!(
+            "RevokedDeviceCertificate {",
+            " author: DeviceID { nickname: \"alice@dev1\", id: \"de10a11c-ec00-1000-0000-000000000000\" },",
+            " timestamp: DateTime(\"2020-01-01T00:00:00Z\"),",
+            " user_id: UserID { nickname: \"bob\", id: \"808c0010-0000-0000-0000-000000000000\" },",
+            " device_id: DeviceID { nickname: \"bob@dev1\", id: \"de10808c-0010-0000-0000-000000000000\" }",
+            " }",
+        )
+    );
+
     let user_update_certificate = UserUpdateCertificate {
         author: alice.device_id,
         timestamp,
@@ -102,6 +120,7 @@ fn debug_format(alice: &Device, bob: &Device, timestamp: DateTime) {
         user_id: bob.user_id,
         realm_id: VlobID::from_hex("604784450642426b91eb89242f54fa52").unwrap(),
         role: Some(RealmRole::Owner),
+        group_id: None,
     };
     p_assert_eq!(
         format!("{:?}", realm_role_certificate),
@@ -111,7 +130,66 @@ fn debug_format(alice: &Device, bob: &Device, timestamp: DateTime) {
             " timestamp: DateTime(\"2020-01-01T00:00:00Z\"),",
             " realm_id: VlobID(60478445-0642-426b-91eb-89242f54fa52),",
             " user_id: UserID { nickname: \"bob\", id: \"808c0010-0000-0000-0000-000000000000\" },",
-            " role: Some(Owner)",
+            " role: Some(Owner),",
+            " group_id: None",
+            " }",
+        )
+    );
+
+    let realm_group_role_certificate = RealmGroupRoleCertificate {
+        author: alice.device_id,
+        timestamp,
+        realm_id: VlobID::from_hex("604784450642426b91eb89242f54fa52").unwrap(),
+        group_id: GroupID::from_hex("a11ce9a0c0ffee000000000000000000").unwrap(),
+        role: Some(RealmRole::Reader),
+    };
+    p_assert_eq!(
+        format!("{:?}", realm_group_role_certificate),
+        concat!(
+            "RealmGroupRoleCertificate {",
+            " author: DeviceID { nickname: \"alice@dev1\", id: \"de10a11c-ec00-1000-0000-000000000000\" },",
+            " timestamp: DateTime(\"2020-01-01T00:00:00Z\"),",
+            " realm_id: VlobID(60478445-0642-426b-91eb-89242f54fa52),",
+            " group_id: GroupID(a11ce9a0-c0ff-ee00-0000-000000000000),",
+            " role: Some(Reader)",
+            " }",
+        )
+    );
+
+    let group_certificate = GroupCertificate {
+        author: alice.device_id,
+        timestamp,
+        group_id: GroupID::from_hex("a11ce9a0c0ffee000000000000000000").unwrap(),
+        name: "Engineering".to_owned(),
+    };
+    p_assert_eq!(
+        format!("{:?}", group_certificate),
+        concat!(
+            "GroupCertificate {",
+            " author: DeviceID { nickname: \"alice@dev1\", id: \"de10a11c-ec00-1000-0000-000000000000\" },",
+            " timestamp: DateTime(\"2020-01-01T00:00:00Z\"),",
+            " group_id: GroupID(a11ce9a0-c0ff-ee00-0000-000000000000),",
+            " name: \"Engineering\"",
+            " }",
+        )
+    );
+
+    let group_member_certificate = GroupMemberCertificate {
+        author: alice.device_id,
+        timestamp,
+        group_id: GroupID::from_hex("a11ce9a0c0ffee000000000000000000").unwrap(),
+        user_id: bob.user_id,
+        is_member: true,
+    };
+    p_assert_eq!(
+        format!("{:?}", group_member_certificate),
+        concat!(
+            "GroupMemberCertificate {",
+            " author: DeviceID { nickname: \"alice@dev1\", id: \"de10a11c-ec00-1000-0000-000000000000\" },",
+            " timestamp: DateTime(\"2020-01-01T00:00:00Z\"),",
+            " group_id: GroupID(a11ce9a0-c0ff-ee00-0000-000000000000),",
+            " user_id: UserID { nickname: \"bob\", id: \"808c0010-0000-0000-0000-000000000000\" },",
+            " is_member: true",
             " }",
         )
     );
@@ -1032,6 +1110,84 @@ fn serde_revoked_user_certificate(alice: &Device, bob: &Device) {
     );
 }
 
+#[rstest]
+fn serde_revoked_device_certificate(alice: &Device, bob: &Device) {
+    // Generated from Parsec 3.8.2-a.0+dev
+    // Content:
+    //   type: 'revoked_device_certificate'
+    //   author: ext(2, 0xde10a11cec0010000000000000000000)
+    //   timestamp: ext(1, 1638618643208821) i.e. 2021-12-04T12:50:43.208821Z
+    //   user_id: ext(2, 0x808c0010000000000000000000000000)
+    //   device_id: ext(2, 0xde10808c001000000000000000000000)
+    let data = Bytes::from_static(&hex!(
+    "1accb8dc0e65a983b5536db151d2be50efd444856800631a50dd79d2945a69f893356b"
+    "4a7f313fa56cc510efe48f668983fa5ced5e62c36a793266e66237d1020028b52ffd20"
+    "8529040085a474797065d91a7265766f6b65645f6465766963655f6365727469666963"
+    "617465a6617574686f72d802de10a11cec0010000000000000000000a974696d657374"
+    "616d70d7010005d250a2269a75a7757365725f6964d802808c00100000000000000000"
+    "00000000a96465766963655f6964d802de10808c001000000000000000000000"
+    ));
+    let data = Bytes::from(data.as_ref().to_vec());
+
+    let expected = RevokedDeviceCertificate {
+        author: alice.device_id,
+        timestamp: "2021-12-04T11:50:43.208821Z".parse().unwrap(),
+        user_id: bob.user_id,
+        device_id: bob.device_id,
+    };
+
+    let unsecure_certif = RevokedDeviceCertificate::unsecure_load(data.clone()).unwrap();
+    p_assert_eq!(unsecure_certif.author(), alice.device_id);
+    p_assert_eq!(
+        unsecure_certif
+            .verify_signature(&alice.verify_key())
+            .unwrap(),
+        (expected.clone(), data.clone())
+    );
+
+    let unsecure_certif = RevokedDeviceCertificate::unsecure_load(data.clone()).unwrap();
+    p_assert_eq!(
+        unsecure_certif.skip_validation(UnsecureSkipValidationReason::DataFromLocalStorage),
+        expected
+    );
+
+    let certif = RevokedDeviceCertificate::verify_and_load(
+        &data,
+        &alice.verify_key(),
+        alice.device_id,
+        Some(bob.device_id),
+    )
+    .unwrap();
+    p_assert_eq!(certif, expected);
+
+    // Also test serialization round trip
+    let data2 = expected.dump_and_sign(&alice.signing_key);
+    // Note we cannot just compare with `data` due to signature and keys order
+    let certif2 = RevokedDeviceCertificate::verify_and_load(
+        &data2,
+        &alice.verify_key(),
+        alice.device_id,
+        None,
+    )
+    .unwrap();
+    p_assert_eq!(certif2, expected);
+
+    // Test invalid data
+    p_assert_matches!(
+        RevokedDeviceCertificate::verify_and_load(
+            &data,
+            &alice.verify_key(),
+            alice.device_id,
+            Some(alice.device_id)
+        ),
+        Err(DataError::UnexpectedDeviceID { .. })
+    );
+    p_assert_matches!(
+        RevokedDeviceCertificate::unsecure_load(b"dummy".to_vec().into()),
+        Err(DataError::Signature)
+    );
+}
+
 #[rstest]
 fn serde_user_update_certificate(alice: &Device, bob: &Device) {
     // Generated from Parsec 3.0.0-b.12+dev
@@ -1128,6 +1284,7 @@ fn serde_realm_role_certificate(alice: &Device, bob: &Device) {
         realm_id: VlobID::from_hex("4486e7cf02d747bd9126679ba58e0474").unwrap(),
         user_id: bob.user_id,
         role: Some(RealmRole::Owner),
+        group_id: None,
     };
     let certif = RealmRoleCertificate::verify_and_load(
         &data,
@@ -1210,6 +1367,60 @@ fn serde_realm_role_certificate_no_role(alice: &Device, bob: &Device) {
         user_id: bob.user_id,
         role: None,
         // role: Some(RealmRole::Owner),
+        group_id: None,
+    };
+    let certif = RealmRoleCertificate::verify_and_load(
+        &data,
+        &alice.verify_key(),
+        alice.device_id,
+        None,
+        None,
+    )
+    .unwrap();
+    p_assert_eq!(certif, expected);
+
+    // Also test serialization round trip
+    let data2 = expected.dump_and_sign(&alice.signing_key);
+    // Note we cannot just compare with `data` due to signature and keys order
+    let certif2 = RealmRoleCertificate::verify_and_load(
+        &data2,
+        &alice.verify_key(),
+        alice.device_id,
+        None,
+        None,
+    )
+    .unwrap();
+    p_assert_eq!(certif2, expected);
+}
+
+#[rstest]
+fn serde_realm_role_certificate_with_group(alice: &Device, bob: &Device) {
+    // Generated from Parsec 3.8.2-a.0+dev
+    // Content:
+    //   type: 'realm_role_certificate'
+    //   author: ext(2, 0xde10a11cec0010000000000000000000)
+    //   timestamp: ext(1, 1638618643208821) i.e. 2021-12-04T12:50:43.208821Z
+    //   realm_id: ext(2, 0x4486e7cf02d747bd9126679ba58e0474)
+    //   user_id: ext(2, 0x808c0010000000000000000000000000)
+    //   role: 'CONTRIBUTOR'
+    //   group_id: ext(2, 0xa11ce9a0c0ffee000000000000000000)
+    let data = Bytes::from_static(&hex!(
+    "f52c19f98c319a26d7738ccecb74eac5c5c2ffcbdf2a320c1c0acb1a6f2d9525187c3e"
+    "7120d027711f2cc893b6427350b8dd2128f7d268a9793444f1404153080028b52ffd20"
+    "ab59050087a474797065b67265616c6d5f726f6c655f6365727469666963617465a661"
+    "7574686f72d802de10a11cec0010000000000000000000a974696d657374616d70d701"
+    "0005d250a2269a75a87265616c6d5f6964d8024486e7cf02d747bd9126679ba58e0474"
+    "a7757365725f6964d802808c0010000000000000000000000000a4726f6c65ab434f4e"
+    "5452494255544f52a867726f75705f6964d802a11ce9a0c0ffee000000000000000000"
+    ));
+
+    let expected = RealmRoleCertificate {
+        author: alice.device_id,
+        timestamp: "2021-12-04T11:50:43.208821Z".parse().unwrap(),
+        realm_id: VlobID::from_hex("4486e7cf02d747bd9126679ba58e0474").unwrap(),
+        user_id: bob.user_id,
+        role: Some(RealmRole::Contributor),
+        group_id: Some(GroupID::from_hex("a11ce9a0c0ffee000000000000000000").unwrap()),
     };
     let certif = RealmRoleCertificate::verify_and_load(
         &data,
@@ -1235,6 +1446,226 @@ fn serde_realm_role_certificate_no_role(alice: &Device, bob: &Device) {
     p_assert_eq!(certif2, expected);
 }
 
+#[rstest]
+fn serde_realm_group_role_certificate(alice: &Device) {
+    // Generated from Parsec 3.8.2-a.0+dev
+    // Content:
+    //   type: 'realm_group_role_certificate'
+    //   author: ext(2, 0xde10a11cec0010000000000000000000)
+    //   timestamp: ext(1, 1638618643208821) i.e. 2021-12-04T12:50:43.208821Z
+    //   realm_id: ext(2, 0x4486e7cf02d747bd9126679ba58e0474)
+    //   group_id: ext(2, 0xa11ce9a0c0ffee000000000000000000)
+    //   role: 'CONTRIBUTOR'
+    let data = Bytes::from_static(&hex!(
+    "40d0108b5b1d1aecf8562ab1e5493013e23aa734ea45b1f650681015ef574e04c88f96"
+    "152e8da81cf8d36d8b94fc5d51aca9e45a0df881dfacf00c5cf877f9070028b52ffd20"
+    "97b9040086a474797065bc7265616c6d5f67726f75705f726f6c655f63657274696669"
+    "63617465a6617574686f72d802de10a11cec0010000000000000000000a974696d6573"
+    "74616d70d7010005d250a2269a75a87265616c6d5f6964d8024486e7cf02d747bd9126"
+    "679ba58e0474a867726f75705f6964d802a11ce9a0c0ffee000000000000000000a472"
+    "6f6c65ab434f4e5452494255544f52"
+    ));
+
+    let expected = RealmGroupRoleCertificate {
+        author: alice.device_id,
+        timestamp: "2021-12-04T11:50:43.208821Z".parse().unwrap(),
+        realm_id: VlobID::from_hex("4486e7cf02d747bd9126679ba58e0474").unwrap(),
+        group_id: GroupID::from_hex("a11ce9a0c0ffee000000000000000000").unwrap(),
+        role: Some(RealmRole::Contributor),
+    };
+    let certif = RealmGroupRoleCertificate::verify_and_load(
+        &data,
+        &alice.verify_key(),
+        alice.device_id,
+        None,
+    )
+    .unwrap();
+    p_assert_eq!(certif, expected);
+
+    let unsecure_certif = RealmGroupRoleCertificate::unsecure_load(data.clone()).unwrap();
+    p_assert_eq!(unsecure_certif.author(), alice.device_id);
+    p_assert_eq!(
+        unsecure_certif
+            .verify_signature(&alice.verify_key())
+            .unwrap(),
+        (expected.clone(), data.clone())
+    );
+
+    let unsecure_certif = RealmGroupRoleCertificate::unsecure_load(data.clone()).unwrap();
+    p_assert_eq!(
+        unsecure_certif.skip_validation(UnsecureSkipValidationReason::DataFromLocalStorage),
+        expected
+    );
+
+    // Also test serialization round trip
+    let data2 = expected.dump_and_sign(&alice.signing_key);
+    // Note we cannot just compare with `data` due to signature and keys order
+    let certif2 = RealmGroupRoleCertificate::verify_and_load(
+        &data2,
+        &alice.verify_key(),
+        alice.device_id,
+        None,
+    )
+    .unwrap();
+    p_assert_eq!(certif2, expected);
+
+    // Test invalid data
+    p_assert_matches!(
+        RealmGroupRoleCertificate::verify_and_load(
+            &data,
+            &alice.verify_key(),
+            alice.device_id,
+            Some(VlobID::default())
+        ),
+        Err(DataError::UnexpectedRealmID { .. })
+    );
+    p_assert_matches!(
+        RealmGroupRoleCertificate::unsecure_load(b"dummy".to_vec().into()),
+        Err(DataError::Signature)
+    );
+}
+
+#[rstest]
+fn serde_group_certificate(alice: &Device) {
+    // Generated from Parsec 3.8.2-a.0+dev
+    // Content:
+    //   type: 'group_certificate'
+    //   author: ext(2, 0xde10a11cec0010000000000000000000)
+    //   timestamp: ext(1, 1638618643208821) i.e. 2021-12-04T12:50:43.208821Z
+    //   group_id: ext(2, 0xa11ce9a0c0ffee000000000000000000)
+    //   name: 'Engineering'
+    let data = Bytes::from_static(&hex!(
+    "98bc3dd265b0705f859492045e968d9efa8578d76043f73da75a8e895f7397205fc937"
+    "e8500bc3412b861f0b52ba9e8f5d99eb794174bc95869610e74f8bcf020028b52ffd20"
+    "7189030085a474797065b167726f75705f6365727469666963617465a6617574686f72"
+    "d802de10a11cec0010000000000000000000a974696d657374616d70d7010005d250a2"
+    "269a75a867726f75705f6964d802a11ce9a0c0ffee000000000000000000a46e616d65"
+    "ab456e67696e656572696e67"
+    ));
+
+    let expected = GroupCertificate {
+        author: alice.device_id,
+        timestamp: "2021-12-04T11:50:43.208821Z".parse().unwrap(),
+        group_id: GroupID::from_hex("a11ce9a0c0ffee000000000000000000").unwrap(),
+        name: "Engineering".to_owned(),
+    };
+    let certif =
+        GroupCertificate::verify_and_load(&data, &alice.verify_key(), alice.device_id).unwrap();
+    p_assert_eq!(certif, expected);
+
+    let unsecure_certif = GroupCertificate::unsecure_load(data.clone()).unwrap();
+    p_assert_eq!(unsecure_certif.author(), alice.device_id);
+    p_assert_eq!(
+        unsecure_certif
+            .verify_signature(&alice.verify_key())
+            .unwrap(),
+        (expected.clone(), data.clone())
+    );
+
+    // Also test serialization round trip
+    let data2 = expected.dump_and_sign(&alice.signing_key);
+    // Note we cannot just compare with `data` due to signature and keys order
+    let certif2 =
+        GroupCertificate::verify_and_load(&data2, &alice.verify_key(), alice.device_id).unwrap();
+    p_assert_eq!(certif2, expected);
+
+    // Test invalid data
+    p_assert_matches!(
+        GroupCertificate::unsecure_load(b"dummy".to_vec().into()),
+        Err(DataError::Signature)
+    );
+}
+
+#[rstest]
+fn serde_group_certificate_empty_name(alice: &Device) {
+    // Generated from Parsec 3.8.2-a.0+dev
+    // Content:
+    //   type: 'group_certificate'
+    //   author: ext(2, 0xde10a11cec0010000000000000000000)
+    //   timestamp: ext(1, 1638618643208821) i.e. 2021-12-04T12:50:43.208821Z
+    //   group_id: ext(2, 0xa11ce9a0c0ffee000000000000000000)
+    //   name: ''
+    let data = Bytes::from_static(&hex!(
+    "19b4e12a107a487ce9b13fa48ec3ae3dc6b9b20433c4a69c4b86134c329d233f36ebaa"
+    "73a9567c7249b7dae88bdf79a810860381f518a6efbc0af5a565f84f040028b52ffd20"
+    "6631030085a474797065b167726f75705f6365727469666963617465a6617574686f72"
+    "d802de10a11cec0010000000000000000000a974696d657374616d70d7010005d250a2"
+    "269a75a867726f75705f6964d802a11ce9a0c0ffee000000000000000000a46e616d65"
+    "a0"
+    ));
+
+    p_assert_matches!(
+        GroupCertificate::verify_and_load(&data, &alice.verify_key(), alice.device_id),
+        Err(DataError::DataIntegrity { .. })
+    );
+}
+
+#[rstest]
+fn serde_group_member_certificate(alice: &Device, bob: &Device) {
+    // Generated from Parsec 3.8.2-a.0+dev
+    // Content:
+    //   type: 'group_member_certificate'
+    //   author: ext(2, 0xde10a11cec0010000000000000000000)
+    //   timestamp: ext(1, 1638618643208821) i.e. 2021-12-04T12:50:43.208821Z
+    //   group_id: ext(2, 0xa11ce9a0c0ffee000000000000000000)
+    //   user_id: ext(2, 0x808c0010000000000000000000000000)
+    //   is_member: True
+    let data = Bytes::from_static(&hex!(
+    "d3e703932a367d95d5768e28a4ffd2f1bdef60319039c66336e393664590a655d18045"
+    "73020134474ca6322e56ace57f6f0234ae85762f98d59e92d88d433d020028b52ffd20"
+    "8c61040086a474797065b867726f75705f6d656d6265725f6365727469666963617465"
+    "a6617574686f72d802de10a11cec0010000000000000000000a974696d657374616d70"
+    "d7010005d250a2269a75a867726f75705f6964d802a11ce9a0c0ffee00000000000000"
+    "0000a7757365725f6964d802808c0010000000000000000000000000a969735f6d656d"
+    "626572c3"
+    ));
+
+    let expected = GroupMemberCertificate {
+        author: alice.device_id,
+        timestamp: "2021-12-04T11:50:43.208821Z".parse().unwrap(),
+        group_id: GroupID::from_hex("a11ce9a0c0ffee000000000000000000").unwrap(),
+        user_id: bob.user_id,
+        is_member: true,
+    };
+    let certif = GroupMemberCertificate::verify_and_load(
+        &data,
+        &alice.verify_key(),
+        alice.device_id,
+        Some(bob.user_id),
+    )
+    .unwrap();
+    p_assert_eq!(certif, expected);
+
+    let unsecure_certif = GroupMemberCertificate::unsecure_load(data.clone()).unwrap();
+    p_assert_eq!(
+        unsecure_certif.skip_validation(UnsecureSkipValidationReason::DataFromLocalStorage),
+        expected
+    );
+
+    // Also test serialization round trip
+    let data2 = expected.dump_and_sign(&alice.signing_key);
+    // Note we cannot just compare with `data` due to signature and keys order
+    let certif2 =
+        GroupMemberCertificate::verify_and_load(&data2, &alice.verify_key(), alice.device_id, None)
+            .unwrap();
+    p_assert_eq!(certif2, expected);
+
+    // Test invalid data
+    p_assert_matches!(
+        GroupMemberCertificate::verify_and_load(
+            &data,
+            &alice.verify_key(),
+            alice.device_id,
+            Some(alice.user_id)
+        ),
+        Err(DataError::UnexpectedUserID { .. })
+    );
+    p_assert_matches!(
+        GroupMemberCertificate::unsecure_load(b"dummy".to_vec().into()),
+        Err(DataError::Signature)
+    );
+}
+
 #[rstest]
 fn serde_realm_archiving_certificate_available(alice: &Device) {
     // Generated from Parsec 3.0.0-b.12+dev
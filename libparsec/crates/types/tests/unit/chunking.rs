@@ -0,0 +1,91 @@
+// Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+use libparsec_tests_lite::prelude::*;
+
+use crate::Blocksize;
+
+use super::content_defined_chunk_size;
+
+fn pseudo_random_data(size: usize) -> Vec<u8> {
+    // Xorshift64, so that the data (and hence the chunks) are stable
+    let mut state: u64 = 42;
+    (0..size)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state as u8
+        })
+        .collect()
+}
+
+fn chunk_sizes(data: &[u8], blocksize: Blocksize) -> Vec<usize> {
+    let mut sizes = vec![];
+    let mut offset = 0;
+    while offset < data.len() {
+        let size = content_defined_chunk_size(&data[offset..], blocksize);
+        sizes.push(size);
+        offset += size;
+    }
+    sizes
+}
+
+#[test]
+fn small_data() {
+    let blocksize = Blocksize::try_from(512).unwrap();
+    let data = pseudo_random_data(128);
+
+    p_assert_eq!(content_defined_chunk_size(&data, blocksize), 128);
+    p_assert_eq!(content_defined_chunk_size(&data[..1], blocksize), 1);
+    p_assert_eq!(content_defined_chunk_size(b"", blocksize), 0);
+}
+
+#[test]
+fn stable_chunks() {
+    // Chunks must be the same across clients and versions, otherwise
+    // block deduplication won't work !
+    let blocksize = Blocksize::try_from(512).unwrap();
+    let data = pseudo_random_data(4096);
+
+    p_assert_eq!(
+        chunk_sizes(&data, blocksize),
+        [512, 256, 310, 390, 263, 208, 198, 402, 319, 288, 512, 271, 167]
+    );
+}
+
+#[rstest]
+#[case::small(8)]
+#[case::testbed(512)]
+#[case::default(512 * 1024)]
+fn chunk_size_bounds(#[case] blocksize: u64) {
+    let blocksize = Blocksize::try_from(blocksize).unwrap();
+    let data = pseudo_random_data(8 * blocksize.inner() as usize);
+
+    let sizes = chunk_sizes(&data, blocksize);
+    let (last, others) = sizes.split_last().unwrap();
+    for size in others {
+        assert!(
+            (blocksize.inner() / 4..=blocksize.inner()).contains(&(*size as u64)),
+            "{size}"
+        );
+    }
+    assert!(*last as u64 <= blocksize.inner());
+}
+
+#[test]
+fn boundaries_follow_content() {
+    let blocksize = Blocksize::try_from(512).unwrap();
+    let data = pseudo_random_data(4096);
+    let mut shifted = vec![b'x'];
+    shifted.extend_from_slice(&data);
+
+    let original_sizes = chunk_sizes(&data, blocksize);
+    let shifted_sizes = chunk_sizes(&shifted, blocksize);
+
+    // Only the chunks up to the first content-defined boundary are impacted by
+    // the inserted byte (here the first chunk has the maximum size, hence the
+    // second chunk gets the additional byte)
+    p_assert_eq!(shifted_sizes[0], original_sizes[0]);
+    p_assert_eq!(shifted_sizes[1], original_sizes[1] + 1);
+    p_assert_eq!(shifted_sizes[2..], original_sizes[2..]);
+}
@@ -1042,6 +1042,410 @@ fn openbao_protected_device_file(alice: &Device) {
     }
 }
 
+#[rstest]
+fn machine_key_device_file(alice: &Device) {
+    for (raw, expected) in [(
+        // Generated by hand
+        // Content:
+        //   type: 'machine_key'
+        //   created_on: ext(1, 1262304000000000) i.e. 2010-01-01T01:00:00Z
+        //   protected_on: ext(1, 1263081600000000) i.e. 2010-01-10T01:00:00Z
+        //   server_url: 'https://parsec.invalid/'
+        //   organization_id: 'CoolOrg'
+        //   user_id: ext(2, 0xa11cec00100000000000000000000000)
+        //   device_id: ext(2, 0xde10a11cec0010000000000000000000)
+        //   human_handle: [ 'alice@example.com', 'Alicey McAliceFace', ]
+        //   device_label: 'My dev1 machine'
+        //   machine_key_file: '/home/alice/.local/share/parsec3/machine.key'
+        //   ciphertext:
+        //     0x9743c0a4c62a016e8c1afd000197ecdc6a589ccbc5a97e323eb2a0ad4304f2e2a04dc56fcdda1b
+        //     f857ca6ef5c8a63c1485b65d333da166f59395ef12381016d5d7edc934d112b6404d113a0549d87b
+        //     86f673c970dc740e580150c6fafc155c217a8e2ab463f10d32c3a4ea20e500cd5ec88f4e0f20f877
+        //     2cf70b0ffb3d0a6fbd35d29d4676a012f08c9cf8f8c3e36b0af29fa9a7371d785ee06f6b5966567e
+        //     17cdb4c2a9789511b6f469b394668a56ca60b2ab3d4843007075d8cde834054db43751dc39e2dd39
+        //     36750a3244f53778645e7daeb3b1030e7edae8bea4a770ce2cfb465a5b59962fdc574859ba3d5989
+        //     b349501d5d0224f1388bb9a5f68e578319502c4b3b9d8f7f0c065018bc2b3368e3bb96d13cc53e22
+        //     848bee0b86b4f7daa497bdb308cd3d39daf09f198aceb93f385d4984c8ed36e225d0ef40c60431ec
+        //     1791e8082f0039eaaa7d8641af4e2dbc441a36c71f13d4e214440fcbc25374d370d8d5033f4a45c4
+        //     c9cb5fe7483d6eeba308b1efd9a4f335ef80b3e8353462088622afaf25916d495945065e1db44061
+        //     5986e0a1b4c0f8c29f8819c548603a76215e8301508504aa5dbd136233304c75ed6327a4706e9a50
+        //     74e76d9c693a58d0e411eb54a67a910b15e36894c83e8099f4b80027053faf46fde70a8469650d6f
+        //     91560a7dfe1b876d23f22c2668aaefb817cdc47be0f1f8d2a5500014920f51adb2f5a1d9a3e54301
+        //     2c3ed270ff12cd481df5
+        //   totp_opaque_key_id: ext(2, 0x8fdb73524fdd495194e877a5fafbe0a1)
+        hex!(
+            "8ca474797065ab6d616368696e655f6b6579aa637265617465645f6f6ed70100047c0f"
+            "0d84c000ac70726f7465637465645f6f6ed70100047cc41a172000aa7365727665725f"
+            "75726cb768747470733a2f2f7061727365632e696e76616c69642faf6f7267616e697a"
+            "6174696f6e5f6964a7436f6f6c4f7267a7757365725f6964d802a11cec001000000000"
+            "00000000000000a96465766963655f6964d802de10a11cec0010000000000000000000"
+            "ac68756d616e5f68616e646c6592b1616c696365406578616d706c652e636f6db2416c"
+            "69636579204d63416c69636546616365ac6465766963655f6c6162656caf4d79206465"
+            "7631206d616368696e65b06d616368696e655f6b65795f66696c65d92c2f686f6d652f"
+            "616c6963652f2e6c6f63616c2f73686172652f706172736563332f6d616368696e652e"
+            "6b6579aa63697068657274657874c502119743c0a4c62a016e8c1afd000197ecdc6a58"
+            "9ccbc5a97e323eb2a0ad4304f2e2a04dc56fcdda1bf857ca6ef5c8a63c1485b65d333d"
+            "a166f59395ef12381016d5d7edc934d112b6404d113a0549d87b86f673c970dc740e58"
+            "0150c6fafc155c217a8e2ab463f10d32c3a4ea20e500cd5ec88f4e0f20f8772cf70b0f"
+            "fb3d0a6fbd35d29d4676a012f08c9cf8f8c3e36b0af29fa9a7371d785ee06f6b596656"
+            "7e17cdb4c2a9789511b6f469b394668a56ca60b2ab3d4843007075d8cde834054db437"
+            "51dc39e2dd3936750a3244f53778645e7daeb3b1030e7edae8bea4a770ce2cfb465a5b"
+            "59962fdc574859ba3d5989b349501d5d0224f1388bb9a5f68e578319502c4b3b9d8f7f"
+            "0c065018bc2b3368e3bb96d13cc53e22848bee0b86b4f7daa497bdb308cd3d39daf09f"
+            "198aceb93f385d4984c8ed36e225d0ef40c60431ec1791e8082f0039eaaa7d8641af4e"
+            "2dbc441a36c71f13d4e214440fcbc25374d370d8d5033f4a45c4c9cb5fe7483d6eeba3"
+            "08b1efd9a4f335ef80b3e8353462088622afaf25916d495945065e1db440615986e0a1"
+            "b4c0f8c29f8819c548603a76215e8301508504aa5dbd136233304c75ed6327a4706e9a"
+            "5074e76d9c693a58d0e411eb54a67a910b15e36894c83e8099f4b80027053faf46fde7"
+            "0a8469650d6f91560a7dfe1b876d23f22c2668aaefb817cdc47be0f1f8d2a550001492"
+            "0f51adb2f5a1d9a3e543012c3ed270ff12cd481df5b2746f74705f6f70617175655f6b"
+            "65795f6964d8028fdb73524fdd495194e877a5fafbe0a1"
+        )
+        .as_ref(),
+        DeviceFile::MachineKey(DeviceFileMachineKey {
+            ciphertext: hex!(
+                "9743c0a4c62a016e8c1afd000197ecdc6a589ccbc5a97e323eb2a0ad4304f2e2a04dc5"
+                "6fcdda1bf857ca6ef5c8a63c1485b65d333da166f59395ef12381016d5d7edc934d112"
+                "b6404d113a0549d87b86f673c970dc740e580150c6fafc155c217a8e2ab463f10d32c3"
+                "a4ea20e500cd5ec88f4e0f20f8772cf70b0ffb3d0a6fbd35d29d4676a012f08c9cf8f8"
+                "c3e36b0af29fa9a7371d785ee06f6b5966567e17cdb4c2a9789511b6f469b394668a56"
+                "ca60b2ab3d4843007075d8cde834054db43751dc39e2dd3936750a3244f53778645e7d"
+                "aeb3b1030e7edae8bea4a770ce2cfb465a5b59962fdc574859ba3d5989b349501d5d02"
+                "24f1388bb9a5f68e578319502c4b3b9d8f7f0c065018bc2b3368e3bb96d13cc53e2284"
+                "8bee0b86b4f7daa497bdb308cd3d39daf09f198aceb93f385d4984c8ed36e225d0ef40"
+                "c60431ec1791e8082f0039eaaa7d8641af4e2dbc441a36c71f13d4e214440fcbc25374"
+                "d370d8d5033f4a45c4c9cb5fe7483d6eeba308b1efd9a4f335ef80b3e8353462088622"
+                "afaf25916d495945065e1db440615986e0a1b4c0f8c29f8819c548603a76215e830150"
+                "8504aa5dbd136233304c75ed6327a4706e9a5074e76d9c693a58d0e411eb54a67a910b"
+                "15e36894c83e8099f4b80027053faf46fde70a8469650d6f91560a7dfe1b876d23f22c"
+                "2668aaefb817cdc47be0f1f8d2a5500014920f51adb2f5a1d9a3e543012c3ed270ff12"
+                "cd481df5"
+            )
+            .as_ref()
+            .into(),
+            created_on: "2010-01-01T00:00:00Z".parse().unwrap(),
+            protected_on: "2010-01-10T00:00:00Z".parse().unwrap(),
+            server_url: "parsec3://parsec.invalid".parse().unwrap(),
+            organization_id: alice.organization_id().to_owned(),
+            user_id: alice.user_id,
+            device_id: alice.device_id,
+            human_handle: alice.human_handle.clone(),
+            device_label: alice.device_label.clone(),
+            machine_key_file: "/home/alice/.local/share/parsec3/machine.key".to_string(),
+            totp_opaque_key_id: Some(
+                TOTPOpaqueKeyID::from_hex("8fdb73524fdd495194e877a5fafbe0a1").unwrap(),
+            ),
+        }),
+    )] {
+        println!("***expected: {:?}", expected.dump());
+
+        let device = DeviceFile::load(raw).unwrap();
+        p_assert_eq!(device, expected);
+
+        // Also test roundtrip
+
+        let raw2 = device.dump();
+        let device2 = DeviceFile::load(&raw2).unwrap();
+
+        p_assert_eq!(device2, expected);
+    }
+}
+
+#[rstest]
+fn fido2_device_file(alice: &Device) {
+    for (raw, expected) in [(
+        // Generated by hand
+        // Content:
+        //   type: 'fido2'
+        //   created_on: ext(1, 1262304000000000) i.e. 2010-01-01T01:00:00Z
+        //   protected_on: ext(1, 1263081600000000) i.e. 2010-01-10T01:00:00Z
+        //   server_url: 'https://parsec.invalid/'
+        //   organization_id: 'CoolOrg'
+        //   user_id: ext(2, 0xa11cec00100000000000000000000000)
+        //   device_id: ext(2, 0xde10a11cec0010000000000000000000)
+        //   human_handle: [ 'alice@example.com', 'Alicey McAliceFace', ]
+        //   device_label: 'My dev1 machine'
+        //   fido2_relying_party_id: 'parsec.cloud'
+        //   fido2_credential_id: 0xc2e11a6a5f0b3d8a4e7c91f0d5b2a6e3
+        //   fido2_hmac_secret_salt: 0x3b9f5c1e7a2d4086b1e8c7f2a9d05e6134c8b2f7a1e9d3c6058f4b2e7a1c9d30
+        //   ciphertext:
+        //     0x9743c0a4c62a016e8c1afd000197ecdc6a589ccbc5a97e323eb2a0ad4304f2e2a04dc56fcdda1b
+        //     f857ca6ef5c8a63c1485b65d333da166f59395ef12381016d5d7edc934d112b6404d113a0549d87b
+        //     86f673c970dc740e580150c6fafc155c217a8e2ab463f10d32c3a4ea20e500cd5ec88f4e0f20f877
+        //     2cf70b0ffb3d0a6fbd35d29d4676a012f08c9cf8f8c3e36b0af29fa9a7371d785ee06f6b5966567e
+        //     17cdb4c2a9789511b6f469b394668a56ca60b2ab3d4843007075d8cde834054db43751dc39e2dd39
+        //     36750a3244f53778645e7daeb3b1030e7edae8bea4a770ce2cfb465a5b59962fdc574859ba3d5989
+        //     b349501d5d0224f1388bb9a5f68e578319502c4b3b9d8f7f0c065018bc2b3368e3bb96d13cc53e22
+        //     848bee0b86b4f7daa497bdb308cd3d39daf09f198aceb93f385d4984c8ed36e225d0ef40c60431ec
+        //     1791e8082f0039eaaa7d8641af4e2dbc441a36c71f13d4e214440fcbc25374d370d8d5033f4a45c4
+        //     c9cb5fe7483d6eeba308b1efd9a4f335ef80b3e8353462088622afaf25916d495945065e1db44061
+        //     5986e0a1b4c0f8c29f8819c548603a76215e8301508504aa5dbd136233304c75ed6327a4706e9a50
+        //     74e76d9c693a58d0e411eb54a67a910b15e36894c83e8099f4b80027053faf46fde70a8469650d6f
+        //     91560a7dfe1b876d23f22c2668aaefb817cdc47be0f1f8d2a5500014920f51adb2f5a1d9a3e54301
+        //     2c3ed270ff12cd481df5
+        //   totp_opaque_key_id: ext(2, 0x8fdb73524fdd495194e877a5fafbe0a1)
+        hex!(
+            "8ea474797065a56669646f32aa637265617465645f6f6ed70100047c0f0d84c000ac70"
+            "726f7465637465645f6f6ed70100047cc41a172000aa7365727665725f75726cb76874"
+            "7470733a2f2f7061727365632e696e76616c69642faf6f7267616e697a6174696f6e5f"
+            "6964a7436f6f6c4f7267a7757365725f6964d802a11cec001000000000000000000000"
+            "00a96465766963655f6964d802de10a11cec0010000000000000000000ac68756d616e"
+            "5f68616e646c6592b1616c696365406578616d706c652e636f6db2416c69636579204d"
+            "63416c69636546616365ac6465766963655f6c6162656caf4d792064657631206d6163"
+            "68696e65b66669646f325f72656c79696e675f70617274795f6964ac7061727365632e"
+            "636c6f7564b36669646f325f63726564656e7469616c5f6964c410c2e11a6a5f0b3d8a"
+            "4e7c91f0d5b2a6e3b66669646f325f686d61635f7365637265745f73616c74c4203b9f"
+            "5c1e7a2d4086b1e8c7f2a9d05e6134c8b2f7a1e9d3c6058f4b2e7a1c9d30aa63697068"
+            "657274657874c502119743c0a4c62a016e8c1afd000197ecdc6a589ccbc5a97e323eb2"
+            "a0ad4304f2e2a04dc56fcdda1bf857ca6ef5c8a63c1485b65d333da166f59395ef1238"
+            "1016d5d7edc934d112b6404d113a0549d87b86f673c970dc740e580150c6fafc155c21"
+            "7a8e2ab463f10d32c3a4ea20e500cd5ec88f4e0f20f8772cf70b0ffb3d0a6fbd35d29d"
+            "4676a012f08c9cf8f8c3e36b0af29fa9a7371d785ee06f6b5966567e17cdb4c2a97895"
+            "11b6f469b394668a56ca60b2ab3d4843007075d8cde834054db43751dc39e2dd393675"
+            "0a3244f53778645e7daeb3b1030e7edae8bea4a770ce2cfb465a5b59962fdc574859ba"
+            "3d5989b349501d5d0224f1388bb9a5f68e578319502c4b3b9d8f7f0c065018bc2b3368"
+            "e3bb96d13cc53e22848bee0b86b4f7daa497bdb308cd3d39daf09f198aceb93f385d49"
+            "84c8ed36e225d0ef40c60431ec1791e8082f0039eaaa7d8641af4e2dbc441a36c71f13"
+            "d4e214440fcbc25374d370d8d5033f4a45c4c9cb5fe7483d6eeba308b1efd9a4f335ef"
+            "80b3e8353462088622afaf25916d495945065e1db440615986e0a1b4c0f8c29f8819c5"
+            "48603a76215e8301508504aa5dbd136233304c75ed6327a4706e9a5074e76d9c693a58"
+            "d0e411eb54a67a910b15e36894c83e8099f4b80027053faf46fde70a8469650d6f9156"
+            "0a7dfe1b876d23f22c2668aaefb817cdc47be0f1f8d2a5500014920f51adb2f5a1d9a3"
+            "e543012c3ed270ff12cd481df5b2746f74705f6f70617175655f6b65795f6964d8028f"
+            "db73524fdd495194e877a5fafbe0a1"
+        )
+        .as_ref(),
+        DeviceFile::Fido2(DeviceFileFido2 {
+            ciphertext: hex!(
+                "9743c0a4c62a016e8c1afd000197ecdc6a589ccbc5a97e323eb2a0ad4304f2e2a04dc5"
+                "6fcdda1bf857ca6ef5c8a63c1485b65d333da166f59395ef12381016d5d7edc934d112"
+                "b6404d113a0549d87b86f673c970dc740e580150c6fafc155c217a8e2ab463f10d32c3"
+                "a4ea20e500cd5ec88f4e0f20f8772cf70b0ffb3d0a6fbd35d29d4676a012f08c9cf8f8"
+                "c3e36b0af29fa9a7371d785ee06f6b5966567e17cdb4c2a9789511b6f469b394668a56"
+                "ca60b2ab3d4843007075d8cde834054db43751dc39e2dd3936750a3244f53778645e7d"
+                "aeb3b1030e7edae8bea4a770ce2cfb465a5b59962fdc574859ba3d5989b349501d5d02"
+                "24f1388bb9a5f68e578319502c4b3b9d8f7f0c065018bc2b3368e3bb96d13cc53e2284"
+                "8bee0b86b4f7daa497bdb308cd3d39daf09f198aceb93f385d4984c8ed36e225d0ef40"
+                "c60431ec1791e8082f0039eaaa7d8641af4e2dbc441a36c71f13d4e214440fcbc25374"
+                "d370d8d5033f4a45c4c9cb5fe7483d6eeba308b1efd9a4f335ef80b3e8353462088622"
+                "afaf25916d495945065e1db440615986e0a1b4c0f8c29f8819c548603a76215e830150"
+                "8504aa5dbd136233304c75ed6327a4706e9a5074e76d9c693a58d0e411eb54a67a910b"
+                "15e36894c83e8099f4b80027053faf46fde70a8469650d6f91560a7dfe1b876d23f22c"
+                "2668aaefb817cdc47be0f1f8d2a5500014920f51adb2f5a1d9a3e543012c3ed270ff12"
+                "cd481df5"
+            )
+            .as_ref()
+            .into(),
+            created_on: "2010-01-01T00:00:00Z".parse().unwrap(),
+            protected_on: "2010-01-10T00:00:00Z".parse().unwrap(),
+            server_url: "parsec3://parsec.invalid".parse().unwrap(),
+            organization_id: alice.organization_id().to_owned(),
+            user_id: alice.user_id,
+            device_id: alice.device_id,
+            human_handle: alice.human_handle.clone(),
+            device_label: alice.device_label.clone(),
+            fido2_relying_party_id: "parsec.cloud".to_string(),
+            fido2_credential_id: hex!("c2e11a6a5f0b3d8a4e7c91f0d5b2a6e3").as_ref().into(),
+            fido2_hmac_secret_salt: hex!(
+                "3b9f5c1e7a2d4086b1e8c7f2a9d05e6134c8b2f7a1e9d3c6058f4b2e7a1c9d30"
+            )
+            .as_ref()
+            .into(),
+            totp_opaque_key_id: Some(
+                TOTPOpaqueKeyID::from_hex("8fdb73524fdd495194e877a5fafbe0a1").unwrap(),
+            ),
+        }),
+    )] {
+        println!("***expected: {:?}", expected.dump());
+
+        let device = DeviceFile::load(raw).unwrap();
+        p_assert_eq!(device, expected);
+
+        // Also test roundtrip
+
+        let raw2 = device.dump();
+        let device2 = DeviceFile::load(&raw2).unwrap();
+
+        p_assert_eq!(device2, expected);
+    }
+}
+
+#[rstest]
+fn multi_protected_device_file(alice: &Device) {
+    let _password = "P@ssw0rd.";
+
+    for (raw, expected) in [(
+        // Generated by hand (`encrypted_ciphertext_key` fields are dummy values)
+        // Content:
+        //   type: 'multi'
+        //   created_on: ext(1, 1262304000000000) i.e. 2010-01-01T01:00:00Z
+        //   protected_on: ext(1, 1263081600000000) i.e. 2010-01-10T01:00:00Z
+        //   server_url: 'https://parsec.invalid/'
+        //   organization_id: 'CoolOrg'
+        //   user_id: ext(2, 0xa11cec00100000000000000000000000)
+        //   device_id: ext(2, 0xde10a11cec0010000000000000000000)
+        //   human_handle: [ 'alice@example.com', 'Alicey McAliceFace', ]
+        //   device_label: 'My dev1 machine'
+        //   protections: [
+        //     {
+        //       primary: {
+        //         type: 'PASSWORD',
+        //         algorithm: {
+        //           type: 'ARGON2ID',
+        //           memlimit_kb: 8,
+        //           opslimit: 1,
+        //           parallelism: 1,
+        //           salt: 0x2ae6167f0f7472b8565c390df3af4a8b,
+        //         },
+        //       },
+        //       encrypted_ciphertext_key: 0x111111111111111111111111111111111111111111111111111111111111111111111111
+        //         111111111111111111111111111111111111111111111111111111111111111111111111,
+        //       totp_opaque_key_id: None,
+        //     },
+        //     {
+        //       primary: {
+        //         type: 'OPENBAO',
+        //         openbao_preferred_auth_id: 'auth/my_sso',
+        //         openbao_entity_id: '65732d02-bb5f-7ce7-eae4-69067383b61d',
+        //         openbao_opaque_key_path: '65732d02-bb5f-7ce7-eae4-69067383b61d/e89eb9b36b704ff292db320b553fcd32',
+        //       },
+        //       encrypted_ciphertext_key: 0x222222222222222222222222222222222222222222222222222222222222222222222222
+        //         222222222222222222222222222222222222222222222222222222222222222222222222,
+        //       totp_opaque_key_id: ext(2, 0x8fdb73524fdd495194e877a5fafbe0a1),
+        //     },
+        //   ]
+        //   ciphertext:
+        //     0x9743c0a4c62a016e8c1afd000197ecdc6a589ccbc5a97e323eb2a0ad4304f2e2a04dc56fcdda1b
+        //     f857ca6ef5c8a63c1485b65d333da166f59395ef12381016d5d7edc934d112b6404d113a0549d87b
+        //     86f673c970dc740e580150c6fafc155c217a8e2ab463f10d32c3a4ea20e500cd5ec88f4e0f20f877
+        //     2cf70b0ffb3d0a6fbd35d29d4676a012f08c9cf8f8c3e36b0af29fa9a7371d785ee06f6b5966567e
+        //     17cdb4c2a9789511b6f469b394668a56ca60b2ab3d4843007075d8cde834054db43751dc39e2dd39
+        //     36750a3244f53778645e7daeb3b1030e7edae8bea4a770ce2cfb465a5b59962fdc574859ba3d5989
+        //     b349501d5d0224f1388bb9a5f68e578319502c4b3b9d8f7f0c065018bc2b3368e3bb96d13cc53e22
+        //     848bee0b86b4f7daa497bdb308cd3d39daf09f198aceb93f385d4984c8ed36e225d0ef40c60431ec
+        //     1791e8082f0039eaaa7d8641af4e2dbc441a36c71f13d4e214440fcbc25374d370d8d5033f4a45c4
+        //     c9cb5fe7483d6eeba308b1efd9a4f335ef80b3e8353462088622afaf25916d495945065e1db44061
+        //     5986e0a1b4c0f8c29f8819c548603a76215e8301508504aa5dbd136233304c75ed6327a4706e9a50
+        //     74e76d9c693a58d0e411eb54a67a910b15e36894c83e8099f4b80027053faf46fde70a8469650d6f
+        //     91560a7dfe1b876d23f22c2668aaefb817cdc47be0f1f8d2a5500014920f51adb2f5a1d9a3e54301
+        //     2c3ed270ff12cd481df5
+        //   totp_opaque_key_id: None
+        hex!(
+            "8ca474797065a56d756c7469aa637265617465645f6f6ed70100047c0f0d84c000ac70"
+            "726f7465637465645f6f6ed70100047cc41a172000aa7365727665725f75726cb76874"
+            "7470733a2f2f7061727365632e696e76616c69642faf6f7267616e697a6174696f6e5f"
+            "6964a7436f6f6c4f7267a7757365725f6964d802a11cec001000000000000000000000"
+            "00a96465766963655f6964d802de10a11cec0010000000000000000000ac68756d616e"
+            "5f68616e646c6592b1616c696365406578616d706c652e636f6db2416c69636579204d"
+            "63416c69636546616365ac6465766963655f6c6162656caf4d792064657631206d6163"
+            "68696e65ab70726f74656374696f6e739283a77072696d61727982a474797065a85041"
+            "5353574f5244a9616c676f726974686d85a474797065a84152474f4e324944ab6d656d"
+            "6c696d69745f6b6208a86f70736c696d697401ab706172616c6c656c69736d01a47361"
+            "6c74c4102ae6167f0f7472b8565c390df3af4a8bb8656e637279707465645f63697068"
+            "6572746578745f6b6579c4481111111111111111111111111111111111111111111111"
+            "1111111111111111111111111111111111111111111111111111111111111111111111"
+            "1111111111111111111111111111b2746f74705f6f70617175655f6b65795f6964c083"
+            "a77072696d61727984a474797065a74f50454e42414fb96f70656e62616f5f70726566"
+            "65727265645f617574685f6964ab617574682f6d795f73736fb16f70656e62616f5f65"
+            "6e746974795f6964d92436353733326430322d626235662d376365372d656165342d36"
+            "3930363733383362363164b76f70656e62616f5f6f70617175655f6b65795f70617468"
+            "d94536353733326430322d626235662d376365372d656165342d363930363733383362"
+            "3631642f65383965623962333662373034666632393264623332306235353366636433"
+            "32b8656e637279707465645f636970686572746578745f6b6579c44822222222222222"
+            "2222222222222222222222222222222222222222222222222222222222222222222222"
+            "222222222222222222222222222222222222222222222222222222222222b2746f7470"
+            "5f6f70617175655f6b65795f6964d8028fdb73524fdd495194e877a5fafbe0a1aa6369"
+            "7068657274657874c502119743c0a4c62a016e8c1afd000197ecdc6a589ccbc5a97e32"
+            "3eb2a0ad4304f2e2a04dc56fcdda1bf857ca6ef5c8a63c1485b65d333da166f59395ef"
+            "12381016d5d7edc934d112b6404d113a0549d87b86f673c970dc740e580150c6fafc15"
+            "5c217a8e2ab463f10d32c3a4ea20e500cd5ec88f4e0f20f8772cf70b0ffb3d0a6fbd35"
+            "d29d4676a012f08c9cf8f8c3e36b0af29fa9a7371d785ee06f6b5966567e17cdb4c2a9"
+            "789511b6f469b394668a56ca60b2ab3d4843007075d8cde834054db43751dc39e2dd39"
+            "36750a3244f53778645e7daeb3b1030e7edae8bea4a770ce2cfb465a5b59962fdc5748"
+            "59ba3d5989b349501d5d0224f1388bb9a5f68e578319502c4b3b9d8f7f0c065018bc2b"
+            "3368e3bb96d13cc53e22848bee0b86b4f7daa497bdb308cd3d39daf09f198aceb93f38"
+            "5d4984c8ed36e225d0ef40c60431ec1791e8082f0039eaaa7d8641af4e2dbc441a36c7"
+            "1f13d4e214440fcbc25374d370d8d5033f4a45c4c9cb5fe7483d6eeba308b1efd9a4f3"
+            "35ef80b3e8353462088622afaf25916d495945065e1db440615986e0a1b4c0f8c29f88"
+            "19c548603a76215e8301508504aa5dbd136233304c75ed6327a4706e9a5074e76d9c69"
+            "3a58d0e411eb54a67a910b15e36894c83e8099f4b80027053faf46fde70a8469650d6f"
+            "91560a7dfe1b876d23f22c2668aaefb817cdc47be0f1f8d2a5500014920f51adb2f5a1"
+            "d9a3e543012c3ed270ff12cd481df5b2746f74705f6f70617175655f6b65795f6964c0"
+        )
+        .as_ref(),
+        DeviceFile::Multi(DeviceFileMulti {
+            ciphertext: hex!(
+                "9743c0a4c62a016e8c1afd000197ecdc6a589ccbc5a97e323eb2a0ad4304f2e2a04dc5"
+                "6fcdda1bf857ca6ef5c8a63c1485b65d333da166f59395ef12381016d5d7edc934d112"
+                "b6404d113a0549d87b86f673c970dc740e580150c6fafc155c217a8e2ab463f10d32c3"
+                "a4ea20e500cd5ec88f4e0f20f8772cf70b0ffb3d0a6fbd35d29d4676a012f08c9cf8f8"
+                "c3e36b0af29fa9a7371d785ee06f6b5966567e17cdb4c2a9789511b6f469b394668a56"
+                "ca60b2ab3d4843007075d8cde834054db43751dc39e2dd3936750a3244f53778645e7d"
+                "aeb3b1030e7edae8bea4a770ce2cfb465a5b59962fdc574859ba3d5989b349501d5d02"
+                "24f1388bb9a5f68e578319502c4b3b9d8f7f0c065018bc2b3368e3bb96d13cc53e2284"
+                "8bee0b86b4f7daa497bdb308cd3d39daf09f198aceb93f385d4984c8ed36e225d0ef40"
+                "c60431ec1791e8082f0039eaaa7d8641af4e2dbc441a36c71f13d4e214440fcbc25374"
+                "d370d8d5033f4a45c4c9cb5fe7483d6eeba308b1efd9a4f335ef80b3e8353462088622"
+                "afaf25916d495945065e1db440615986e0a1b4c0f8c29f8819c548603a76215e830150"
+                "8504aa5dbd136233304c75ed6327a4706e9a5074e76d9c693a58d0e411eb54a67a910b"
+                "15e36894c83e8099f4b80027053faf46fde70a8469650d6f91560a7dfe1b876d23f22c"
+                "2668aaefb817cdc47be0f1f8d2a5500014920f51adb2f5a1d9a3e543012c3ed270ff12"
+                "cd481df5"
+            )
+            .as_ref()
+            .into(),
+            created_on: "2010-01-01T00:00:00Z".parse().unwrap(),
+            protected_on: "2010-01-10T00:00:00Z".parse().unwrap(),
+            server_url: "parsec3://parsec.invalid".parse().unwrap(),
+            organization_id: alice.organization_id().to_owned(),
+            user_id: alice.user_id,
+            device_id: alice.device_id,
+            human_handle: alice.human_handle.clone(),
+            device_label: alice.device_label.clone(),
+            protections: vec![
+                DeviceFileMultiProtection {
+                    primary: DeviceFileMultiPrimaryProtection::Password {
+                        algorithm: PasswordAlgorithm::Argon2id {
+                            salt: hex!("2ae6167f0f7472b8565c390df3af4a8b"),
+                            opslimit: 1,
+                            memlimit_kb: 8,
+                            parallelism: 1,
+                        },
+                    },
+                    encrypted_ciphertext_key: [0x11; 72].as_ref().into(),
+                    totp_opaque_key_id: None,
+                },
+                DeviceFileMultiProtection {
+                    primary: DeviceFileMultiPrimaryProtection::OpenBao {
+                        openbao_preferred_auth_id: "auth/my_sso".to_string(),
+                        openbao_entity_id: "65732d02-bb5f-7ce7-eae4-69067383b61d".to_string(),
+                        openbao_opaque_key_path:
+                            "65732d02-bb5f-7ce7-eae4-69067383b61d/e89eb9b36b704ff292db320b553fcd32"
+                                .to_string(),
+                    },
+                    encrypted_ciphertext_key: [0x22; 72].as_ref().into(),
+                    totp_opaque_key_id: Some(
+                        TOTPOpaqueKeyID::from_hex("8fdb73524fdd495194e877a5fafbe0a1").unwrap(),
+                    ),
+                },
+            ],
+            totp_opaque_key_id: None,
+        }),
+    )] {
+        println!("***expected: {:?}", expected.dump());
+
+        let device = DeviceFile::load(raw).unwrap();
+        p_assert_eq!(device, expected);
+
+        // Also test roundtrip
+
+        let raw2 = device.dump();
+        let device2 = DeviceFile::load(&raw2).unwrap();
+
+        p_assert_eq!(device2, expected);
+    }
+}
+
 #[rstest]
 fn recovery_device_file(alice: &Device) {
     // Generated from Parsec v3.0.0-b.11+dev
@@ -1119,6 +1523,80 @@ fn recovery_device_file(alice: &Device) {
     p_assert_eq!(device2, expected);
 }
 
+#[rstest]
+fn device_files_backup() {
+    // Generated by hand (`ciphertext` is a dummy value)
+    // Content:
+    //   type: 'device_files_backup'
+    //   created_on: ext(1, 1262304000000000) i.e. 2010-01-01T01:00:00Z
+    //   algorithm: {
+    //     type: 'ARGON2ID',
+    //     memlimit_kb: 8,
+    //     opslimit: 1,
+    //     parallelism: 1,
+    //     salt: 0x2ae6167f0f7472b8565c390df3af4a8b,
+    //   }
+    //   ciphertext: 0x3c0b6a12d0f4e19c7a58b2e6d93f1a07c4e8125b96fd30a7e2c15b8d4f6a9e03
+    let raw: &[u8] = hex!(
+        "84a474797065b36465766963655f66696c65735f6261636b7570aa637265617465645f"
+        "6f6ed70100047c0f0d84c000a9616c676f726974686d85a474797065a84152474f4e32"
+        "4944ab6d656d6c696d69745f6b6208a86f70736c696d697401ab706172616c6c656c69"
+        "736d01a473616c74c4102ae6167f0f7472b8565c390df3af4a8baa6369706865727465"
+        "7874c4203c0b6a12d0f4e19c7a58b2e6d93f1a07c4e8125b96fd30a7e2c15b8d4f6a9e"
+        "03"
+    )
+    .as_ref();
+    let expected = DeviceFilesBackup {
+        created_on: "2010-01-01T00:00:00Z".parse().unwrap(),
+        algorithm: PasswordAlgorithm::Argon2id {
+            salt: hex!("2ae6167f0f7472b8565c390df3af4a8b"),
+            opslimit: 1,
+            memlimit_kb: 8,
+            parallelism: 1,
+        },
+        ciphertext: hex!("3c0b6a12d0f4e19c7a58b2e6d93f1a07c4e8125b96fd30a7e2c15b8d4f6a9e03")
+            .as_ref()
+            .into(),
+    };
+    println!("***expected: {:?}", expected.dump());
+
+    let backup = DeviceFilesBackup::load(raw).unwrap();
+    p_assert_eq!(backup, expected);
+
+    // Also test roundtrip
+
+    let raw2 = backup.dump();
+    let backup2 = DeviceFilesBackup::load(&raw2).unwrap();
+    p_assert_eq!(backup2, expected);
+
+    // A backup is not a device file (and vice versa)
+
+    DeviceFile::load(raw).unwrap_err();
+
+    // Entries encryption
+
+    let key = SecretKey::generate();
+    let entries = vec![
+        DeviceFilesBackupEntry {
+            path: "de10a11cec0010000000000000000000.keys".to_string(),
+            content: b"<device file 1>".as_ref().into(),
+        },
+        DeviceFilesBackupEntry {
+            path: "sub/device2.keys".to_string(),
+            content: b"<device file 2>".as_ref().into(),
+        },
+    ];
+    let backup = DeviceFilesBackup {
+        ciphertext: DeviceFilesBackup::encrypt_entries(&key, &entries),
+        ..expected
+    };
+    p_assert_eq!(backup.decrypt_entries(&key).unwrap(), entries);
+    p_assert_matches!(
+        backup.decrypt_entries(&SecretKey::generate()),
+        Err(DataError::Decryption)
+    );
+}
+
 #[test]
 fn test_advisory_device_file_protection_parse_ok() {
     let cases = [
@@ -109,6 +109,7 @@ fn serde_local_file_manifest_ok(alice: &Device) {
                     )),
                     offset: 0,
                     size: NonZeroU64::try_from(512).unwrap(),
+                    compressed: false,
                 },
                 BlockAccess {
                     id: BlockID::from_hex("d7e3af6a03e1414db0f4682901e9aa4b").unwrap(),
@@ -117,11 +118,13 @@ fn serde_local_file_manifest_ok(alice: &Device) {
                     )),
                     offset: 512,
                     size: NonZeroU64::try_from(188).unwrap(),
+                    compressed: false,
                 },
             ],
             blocksize: Blocksize::try_from(512).unwrap(),
             parent: VlobID::from_hex("07748fbf67a646428427865fd730bf3e").unwrap(),
             size: 700,
+            content_defined_chunking: false,
         },
         blocks: vec![vec![
             ChunkView {
@@ -134,6 +137,7 @@ fn serde_local_file_manifest_ok(alice: &Device) {
                     )),
                     offset: 0,
                     size: NonZeroU64::try_from(512).unwrap(),
+                    compressed: false,
                 }),
                 raw_offset: 0,
                 raw_size: NonZeroU64::new(512).unwrap(),
@@ -293,6 +297,7 @@ fn chunk_promote_as_block() {
         offset: 1,
         size: NonZeroU64::try_from(4).unwrap(),
         digest: HashDigest::from_data(b"<data>"),
+        compressed: false,
     };
 
     let mut block = ChunkView::from_block_access(block_access);
@@ -436,12 +441,14 @@ fn local_file_manifest_is_reshaped(timestamp: DateTime) {
         offset: 1,
         size: NonZeroU64::try_from(4).unwrap(),
         digest: HashDigest::from_data(&[]),
+        compressed: false,
     },
     BlockAccess {
         id: BlockID::default(),
         offset: 513,
         size: NonZeroU64::try_from(4).unwrap(),
         digest: HashDigest::from_data(&[]),
+        compressed: false,
     }
 ]))]
 fn local_file_manifest_from_remote(timestamp: DateTime, #[case] input: (u64, Vec<BlockAccess>)) {
@@ -457,6 +464,7 @@ fn local_file_manifest_from_remote(timestamp: DateTime, #[case] input: (u64, Vec
         size,
         blocksize: Blocksize::try_from(512).unwrap(),
         blocks: blocks.clone(),
+        content_defined_chunking: false,
     };
 
     let lfm = LocalFileManifest::from_remote(fm.clone());
@@ -515,6 +523,68 @@ fn local_file_manifest_to_remote(timestamp: DateTime) {
     p_assert_eq!(fm.size, lfm.size);
     p_assert_eq!(fm.blocksize, lfm.blocksize);
     p_assert_eq!(fm.blocks, vec![block_access]);
+    assert!(!fm.content_defined_chunking);
+}
+
+#[rstest]
+fn local_file_manifest_content_defined_chunking(timestamp: DateTime) {
+    let block = |offset, size| BlockAccess {
+        id: BlockID::default(),
+        offset,
+        size: NonZeroU64::try_from(size).unwrap(),
+        digest: HashDigest::from_data(b""),
+        compressed: false,
+    };
+    // The second block spans over two block spans
+    let blocks = vec![block(0, 300), block(300, 400), block(700, 300)];
+    let fm = FileManifest {
+        author: DeviceID::default(),
+        timestamp,
+        id: VlobID::default(),
+        parent: VlobID::default(),
+        version: 1,
+        created: timestamp,
+        updated: timestamp,
+        size: 1000,
+        blocksize: Blocksize::try_from(512).unwrap(),
+        blocks: blocks.clone(),
+        content_defined_chunking: true,
+    };
+
+    let mut lfm = LocalFileManifest::from_remote(fm.clone());
+
+    let chunk_view = |block: &BlockAccess, start, stop| ChunkView {
+        id: block.id.into(),
+        start,
+        stop: NonZeroU64::try_from(stop).unwrap(),
+        raw_offset: block.offset,
+        raw_size: block.size,
+        access: Some(block.clone()),
+    };
+    p_assert_eq!(
+        lfm.blocks,
+        [
+            vec![
+                chunk_view(&blocks[0], 0, 300),
+                chunk_view(&blocks[1], 300, 512)
+            ],
+            vec![
+                chunk_view(&blocks[1], 512, 700),
+                chunk_view(&blocks[2], 700, 1000)
+            ],
+        ]
+    );
+    assert!(lfm.is_reshaped());
+
+    let to_remote = lfm.to_remote(fm.author, timestamp).unwrap();
+    p_assert_eq!(to_remote.blocks, blocks);
+    assert!(to_remote.content_defined_chunking);
+
+    // Only part of the second block is used (e.g. the file has been truncated)
+    lfm.blocks.pop();
+    lfm.size = 512;
+    assert!(!lfm.is_reshaped());
+    assert!(lfm.to_remote(fm.author, timestamp).is_err());
 }
 
 // TODO: Add integrity tests for:
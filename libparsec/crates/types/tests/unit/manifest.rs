@@ -94,6 +94,7 @@ fn serde_file_manifest_ok_and_invalid_checks(alice: &Device) {
                 digest: HashDigest::from(hex!(
                     "076a27c79e5ace2a3d47f9dd2e83e4ff6ea8872b3c2218f66c92b89b55f36560"
                 )),
+                compressed: false,
             },
             BlockAccess {
                 id: BlockID::from_hex("d7e3af6a03e1414db0f4682901e9aa4b").unwrap(),
@@ -102,8 +103,10 @@ fn serde_file_manifest_ok_and_invalid_checks(alice: &Device) {
                 digest: HashDigest::from(hex!(
                     "e37ce3b00a1f15b3de62029972345420b76313a885c6ccc6e3b5547857b3ecc6"
                 )),
+                compressed: false,
             },
         ],
+        content_defined_chunking: false,
     };
 
     let manifest = ChildManifest::decrypt_verify_and_load(
@@ -725,6 +728,7 @@ fn generate_file_manifest_blocks_not_sorted(
                 digest: HashDigest::from(hex!(
                     "e37ce3b00a1f15b3de62029972345420b76313a885c6ccc6e3b5547857b3ecc6"
                 )),
+                compressed: false,
             },
             BlockAccess {
                 id: BlockID::from_hex("b82954f1138b4d719b7f5bd78915d20f").unwrap(),
@@ -733,8 +737,10 @@ fn generate_file_manifest_blocks_not_sorted(
                 digest: HashDigest::from(hex!(
                     "076a27c79e5ace2a3d47f9dd2e83e4ff6ea8872b3c2218f66c92b89b55f36560"
                 )),
+                compressed: false,
             },
         ],
+        content_defined_chunking: false,
     };
 
     let data = hex!(
@@ -807,6 +813,7 @@ fn generate_file_manifest_blocks_overlapping(
                 digest: HashDigest::from(hex!(
                     "e37ce3b00a1f15b3de62029972345420b76313a885c6ccc6e3b5547857b3ecc6"
                 )),
+                compressed: false,
             },
             BlockAccess {
                 id: BlockID::from_hex("b82954f1138b4d719b7f5bd78915d20f").unwrap(),
@@ -815,8 +822,10 @@ fn generate_file_manifest_blocks_overlapping(
                 digest: HashDigest::from(hex!(
                     "076a27c79e5ace2a3d47f9dd2e83e4ff6ea8872b3c2218f66c92b89b55f36560"
                 )),
+                compressed: false,
             },
         ],
+        content_defined_chunking: false,
     };
 
     let data = hex!(
@@ -889,6 +898,7 @@ fn generate_file_manifest_exceed_file_size(
                 digest: HashDigest::from(hex!(
                     "076a27c79e5ace2a3d47f9dd2e83e4ff6ea8872b3c2218f66c92b89b55f36560"
                 )),
+                compressed: false,
             },
             BlockAccess {
                 id: BlockID::from_hex("d7e3af6a03e1414db0f4682901e9aa4b").unwrap(),
@@ -897,8 +907,10 @@ fn generate_file_manifest_exceed_file_size(
                 digest: HashDigest::from(hex!(
                     "e37ce3b00a1f15b3de62029972345420b76313a885c6ccc6e3b5547857b3ecc6"
                 )),
+                compressed: false,
             },
         ],
+        content_defined_chunking: false,
     };
 
     let data = hex!(
@@ -966,6 +978,7 @@ fn generate_file_manifest_same_block_span(
                 digest: HashDigest::from(hex!(
                     "076a27c79e5ace2a3d47f9dd2e83e4ff6ea8872b3c2218f66c92b89b55f36560"
                 )),
+                compressed: false,
             },
             BlockAccess {
                 id: BlockID::from_hex("d7e3af6a03e1414db0f4682901e9aa4b").unwrap(),
@@ -974,8 +987,10 @@ fn generate_file_manifest_same_block_span(
                 digest: HashDigest::from(hex!(
                     "e37ce3b00a1f15b3de62029972345420b76313a885c6ccc6e3b5547857b3ecc6"
                 )),
+                compressed: false,
             },
         ],
+        content_defined_chunking: false,
     };
 
     let data = hex!(
@@ -1041,7 +1056,9 @@ fn generate_file_manifest_in_between_block_span(
             digest: HashDigest::from(hex!(
                 "076a27c79e5ace2a3d47f9dd2e83e4ff6ea8872b3c2218f66c92b89b55f36560"
             )),
+            compressed: false,
         }],
+        content_defined_chunking: false,
     };
 
     let data = hex!(
@@ -1131,3 +1148,129 @@ fn serde_file_manifest_invalid_blocks(
         encode_and_format_with_70_width(fresh_data)
     );
 }
+
+#[rstest]
+#[case::not_compressed(false)]
+#[case::compressed(true)]
+fn serde_block_access(#[case] compressed: bool) {
+    let access = BlockAccess {
+        id: BlockID::from_hex("b82954f1138b4d719b7f5bd78915d20f").unwrap(),
+        offset: 0,
+        size: NonZeroU64::try_from(512).unwrap(),
+        digest: HashDigest::from(hex!(
+            "076a27c79e5ace2a3d47f9dd2e83e4ff6ea8872b3c2218f66c92b89b55f36560"
+        )),
+        compressed,
+    };
+
+    let raw = rmp_serde::to_vec_named(&access).unwrap();
+    // The field is omitted when not set, so that older clients get the exact same data
+    p_assert_eq!(
+        raw.windows(b"compressed".len())
+            .any(|window| window == b"compressed"),
+        compressed
+    );
+
+    let loaded: BlockAccess = rmp_serde::from_slice(&raw).unwrap();
+    p_assert_eq!(loaded, access);
+}
+
+#[rstest]
+fn block_access_compress_and_decompress_data() {
+    let data = b"Lorem ipsum dolor sit amet, consectetur adipiscing elit. ".repeat(100);
+    let mut access = BlockAccess {
+        id: BlockID::default(),
+        offset: 0,
+        size: NonZeroU64::try_from(data.len() as u64).unwrap(),
+        digest: HashDigest::from_data(&data),
+        compressed: true,
+    };
+
+    let compressed_data = BlockAccess::compress_data(&data);
+    assert!(compressed_data.len() < data.len());
+    p_assert_eq!(
+        access.decompress_data(compressed_data.clone()).unwrap(),
+        data
+    );
+
+    // Not a valid zstd frame
+    p_assert_matches!(
+        access.decompress_data(data.clone()),
+        Err(DataError::BadSerialization { .. })
+    );
+
+    // Decompressed data bigger than the block size (i.e. decompression bomb)
+    let bomb = BlockAccess::compress_data(&vec![0; 100 * data.len()]);
+    p_assert_matches!(
+        access.decompress_data(bomb),
+        Err(DataError::BadSerialization { .. })
+    );
+
+    // Uncompressed block is kept as-is
+    access.compressed = false;
+    p_assert_eq!(access.decompress_data(data.clone()).unwrap(), data);
+    p_assert_eq!(
+        access.decompress_data(compressed_data.clone()).unwrap(),
+        compressed_data
+    );
+}
+
+#[rstest]
+fn serde_file_manifest_content_defined_chunking(alice: &Device) {
+    let key = SecretKey::generate();
+    let now = "2021-12-04T11:50:43.208821Z".parse().unwrap();
+    let block = |offset, size| BlockAccess {
+        id: BlockID::default(),
+        offset,
+        size: NonZeroU64::try_from(size).unwrap(),
+        digest: HashDigest::from_data(b""),
+        compressed: false,
+    };
+    let mut manifest = FileManifest {
+        author: alice.device_id,
+        timestamp: now,
+        id: VlobID::default(),
+        parent: VlobID::default(),
+        version: 42,
+        created: now,
+        updated: now,
+        size: 1000,
+        blocksize: Blocksize::try_from(512).unwrap(),
+        // Blocks sharing a block span, and spanning over two block spans
+        blocks: vec![block(0, 300), block(300, 400), block(700, 300)],
+        content_defined_chunking: true,
+    };
+
+    let data = manifest.dump_sign_and_encrypt(&alice.signing_key, &key);
+    let loaded = ChildManifest::decrypt_verify_and_load(
+        &data,
+        &key,
+        &alice.verify_key(),
+        alice.device_id,
+        now,
+        None,
+        None,
+    )
+    .unwrap();
+    p_assert_eq!(loaded, ChildManifest::File(manifest.clone()));
+
+    // Blocks are still not allowed to be bigger than the blocksize
+    manifest.blocks = vec![block(0, 513)];
+    let data = manifest
+        .dump_sign_and_encrypt_with_data_integrity_checks_disabled(&alice.signing_key, &key);
+    p_assert_eq!(
+        ChildManifest::decrypt_verify_and_load(
+            &data,
+            &key,
+            &alice.verify_key(),
+            alice.device_id,
+            now,
+            None,
+            None,
+        ),
+        Err(DataError::DataIntegrity {
+            data_type: "libparsec_types::manifest::FileManifest",
+            invariant: "blocks are not bigger than the blocksize",
+        })
+    );
+}
@@ -89,3 +89,31 @@ fn serde_cert_ref_skip_unknown_uris() {
 
     serde_test::assert_de_tokens(&expected_cert_ref.compact(), &got_tokens);
 }
+
+#[rstest]
+#[case::rsaes_oaep_sha256("RSAES-OAEP-SHA256", PKIEncryptionAlgorithm::RsaesOaepSha256)]
+#[case::rsaes_oaep_sha384("RSAES-OAEP-SHA384", PKIEncryptionAlgorithm::RsaesOaepSha384)]
+#[case::rsaes_oaep_sha512("RSAES-OAEP-SHA512", PKIEncryptionAlgorithm::RsaesOaepSha512)]
+fn serde_encryption_algorithm(#[case] raw: &'static str, #[case] expected: PKIEncryptionAlgorithm) {
+    serde_test::assert_tokens(&expected, &[Token::Str(raw)]);
+}
+
+#[rstest]
+#[case::rsassa_pss_sha256("RSASSA-PSS-SHA256", PkiSignatureAlgorithm::RsassaPssSha256)]
+#[case::ecdsa_p256_sha256("ECDSA-P256-SHA256", PkiSignatureAlgorithm::EcdsaP256Sha256)]
+#[case::ecdsa_p384_sha384("ECDSA-P384-SHA384", PkiSignatureAlgorithm::EcdsaP384Sha384)]
+fn serde_signature_algorithm(#[case] raw: &'static str, #[case] expected: PkiSignatureAlgorithm) {
+    serde_test::assert_tokens(&expected, &[Token::Str(raw)]);
+}
+
+#[test]
+fn unknown_algorithm() {
+    p_assert_matches!(
+        "RSAES-OAEP-SHA1".parse::<PKIEncryptionAlgorithm>(),
+        Err("Unknown encryption algorithm")
+    );
+    p_assert_matches!(
+        "ECDSA-P521-SHA512".parse::<PkiSignatureAlgorithm>(),
+        Err("Unknown signature algorithm")
+    );
+}
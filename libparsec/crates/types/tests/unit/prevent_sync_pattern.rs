@@ -120,3 +120,20 @@ fn display() {
         r#"PreventSyncPattern([Regex("^foo\\..*$"), Regex("^bar[^/]$")])"#
     );
 }
+
+#[test]
+fn merge() {
+    let a = PreventSyncPattern::from_multiple_globs(["*.rs", "*.py"].into_iter()).unwrap();
+    let b = PreventSyncPattern::from_multiple_globs(["*.py", "*.txt"].into_iter()).unwrap();
+
+    let merged = a.merge(&b);
+
+    // Duplicated patterns are only kept once
+    p_assert_eq!(merged.0.len(), 3);
+    assert!(merged.is_match("file.rs"));
+    assert!(merged.is_match("file.py"));
+    assert!(merged.is_match("file.txt"));
+    assert!(!merged.is_match("file.md"));
+
+    p_assert_eq!(a.merge(&PreventSyncPattern::empty()), a);
+}
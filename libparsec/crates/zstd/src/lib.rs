@@ -5,6 +5,16 @@
 #[cfg(not(use_pure_rust_but_dirty_zstd))]
 pub mod stream {
     pub use zstd::stream::{copy_encode, decode_all};
+
+    /// Same as `decode_all`, but fails as soon as the decompressed data exceeds
+    /// `max_size` (so that a small malicious input cannot exhaust the memory).
+    pub fn decode_all_bounded<R: std::io::Read>(
+        source: R,
+        max_size: u64,
+    ) -> std::io::Result<Vec<u8>> {
+        let decoder = zstd::stream::read::Decoder::new(source)?;
+        crate::read_to_end_bounded(decoder, max_size)
+    }
 }
 
 #[cfg(any(test, use_pure_rust_but_dirty_zstd))]
@@ -12,5 +22,23 @@ mod dirty;
 
 #[cfg(use_pure_rust_but_dirty_zstd)]
 pub mod stream {
-    pub use crate::dirty::{copy_encode, decode_all};
+    pub use crate::dirty::{copy_encode, decode_all, decode_all_bounded};
+}
+
+fn read_to_end_bounded(reader: impl std::io::Read, max_size: u64) -> std::io::Result<Vec<u8>> {
+    use std::io::Read;
+
+    let mut result = Vec::new();
+    // Read one more byte than allowed to detect the overflow
+    reader
+        .take(max_size.saturating_add(1))
+        .read_to_end(&mut result)?;
+    if result.len() as u64 > max_size {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            "decompressed data exceeds the maximum size",
+        ));
+    }
+
+    Ok(result)
 }
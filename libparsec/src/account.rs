@@ -384,6 +384,8 @@ pub enum AccountCreateRegistrationDeviceError {
         ballpark_client_early_offset: f64,
         ballpark_client_late_offset: f64,
     },
+    #[error(transparent)]
+    ClockDrift(#[from] libparsec_client::ClockDriftError),
     /// Note only a subset of load strategies requires server access to
     /// fetch an opaque key that itself protects the ciphertext key
     /// (e.g. account vault).
@@ -449,6 +451,9 @@ impl From<libparsec_account::AccountCreateRegistrationDeviceError>
                 ballpark_client_early_offset,
                 ballpark_client_late_offset,
             },
+            libparsec_account::AccountCreateRegistrationDeviceError::ClockDrift(err) => {
+                AccountCreateRegistrationDeviceError::ClockDrift(err)
+            }
         }
     }
 }
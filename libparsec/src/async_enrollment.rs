@@ -355,6 +355,8 @@ mod strategy {
     impl AcceptFinalizeAsyncEnrollmentIdentityStrategy {
         pub(super) fn convert(
             self,
+            revocation_policy: libparsec_platform_pki::X509RevocationPolicy,
+            organization_crls: Vec<Bytes>,
         ) -> anyhow::Result<Box<dyn AcceptFinalizeAsyncEnrollmentIdentityStrategyTrait>> {
             match self {
                 Self::OpenBao {
@@ -399,6 +401,8 @@ mod strategy {
                     Ok(Box::new(AcceptFinalizeAsyncEnrollmentPKIIdentityStrategy {
                         pki_certificate,
                         pki_private_key,
+                        revocation_policy,
+                        organization_crls,
                     }))
                 }
             }
@@ -590,6 +594,10 @@ mod strategy {
     struct AcceptFinalizeAsyncEnrollmentPKIIdentityStrategy {
         pki_certificate: Arc<libparsec_platform_pki::PkiCertificate>,
         pki_private_key: Arc<libparsec_platform_pki::PkiPrivateKey>,
+        revocation_policy: libparsec_platform_pki::X509RevocationPolicy,
+        /// CRLs provided by the organization config, checked on top of the
+        /// local ones and the certificates' distribution points.
+        organization_crls: Vec<Bytes>,
     }
 
     impl AcceptFinalizeAsyncEnrollmentIdentityStrategyTrait
@@ -607,6 +615,8 @@ mod strategy {
             expected_author: EmailAddress,
         ) -> PinBoxFutureResult<(), AcceptAsyncEnrollmentError> {
             let pki_certificate = self.pki_certificate.clone();
+            let revocation_policy = self.revocation_policy;
+            let organization_crls = self.organization_crls.clone();
             Box::pin(pretend_future_is_send_on_web(async move {
                 let (signature, algorithm, submitter_cert, intermediate_certs) = match payload_signature {
                     protocol::authenticated_cmds::v5::async_enrollment_list::SubmitPayloadSignature::PKI {
@@ -631,7 +641,18 @@ mod strategy {
                         AcceptAsyncEnrollmentError::PKIUnusableX509CertificateReference(err.into())
                     })?;
 
-                // 2. Validate certificate trustchain and payload signature
+                // 2. Validate certificate trustchain (including revocation) and payload signature
+
+                let revocation = crate::pki::get_pki_system()
+                    .await
+                    .map_err(AcceptAsyncEnrollmentError::Internal)?
+                    .revocation_config(
+                        revocation_policy,
+                        organization_crls.iter().map(|x| x.as_ref()),
+                        std::iter::once(submitter_cert.as_ref())
+                            .chain(intermediate_certs.iter().map(|x| x.as_ref())),
+                    )
+                    .await;
 
                 libparsec_platform_pki::verify_message(
                     &payload,
@@ -640,6 +661,7 @@ mod strategy {
                     &submitter_cert,
                     intermediate_certs.iter().map(|x| x.as_ref()),
                     &[validation_path.root],
+                    &revocation,
                     DateTime::now(),
                 )
                 .map_err(|err| AcceptAsyncEnrollmentError::BadSubmitPayload(err.into()))?;
@@ -728,6 +750,8 @@ mod strategy {
             payload_signature: protocol::anonymous_cmds::latest::async_enrollment_info::AcceptPayloadSignature,
         ) -> PinBoxFutureResult<(), SubmitterFinalizeAsyncEnrollmentError> {
             let pki_certificate = self.pki_certificate.clone();
+            let revocation_policy = self.revocation_policy;
+            let organization_crls = self.organization_crls.clone();
             Box::pin(pretend_future_is_send_on_web(async move {
                 let (signature, algorithm, accepter_cert, intermediate_certs) = match payload_signature {
                     protocol::anonymous_cmds::latest::async_enrollment_info::AcceptPayloadSignature::PKI {
@@ -754,7 +778,18 @@ mod strategy {
                         )
                     })?;
 
-                // 2. Validate certificate trustchain and payload signature
+                // 2. Validate certificate trustchain (including revocation) and payload signature
+
+                let revocation = crate::pki::get_pki_system()
+                    .await
+                    .map_err(SubmitterFinalizeAsyncEnrollmentError::Internal)?
+                    .revocation_config(
+                        revocation_policy,
+                        organization_crls.iter().map(|x| x.as_ref()),
+                        std::iter::once(accepter_cert.as_ref())
+                            .chain(intermediate_certs.iter().map(|x| x.as_ref())),
+                    )
+                    .await;
 
                 libparsec_platform_pki::verify_message(
                     &payload,
@@ -763,6 +798,7 @@ mod strategy {
                     &accepter_cert,
                     intermediate_certs.iter().map(|x| x.as_ref()),
                     &[validation_path.root],
+                    &revocation,
                     DateTime::now(),
                 )
                 .map_err(|err| {
@@ -872,7 +908,10 @@ pub async fn client_accept_async_enrollment(
     .map_err(ClientAcceptAsyncEnrollmentError::Internal)?;
 
     let identity_strategy = identity_strategy
-        .convert()
+        .convert(
+            client.config().pki_revocation_policy,
+            client.server_organization_config().pki_crls,
+        )
         .map_err(ClientAcceptAsyncEnrollmentError::Internal)?;
 
     client
@@ -928,7 +967,9 @@ pub async fn submitter_finalize_async_enrollment(
     identity_strategy: AcceptFinalizeAsyncEnrollmentIdentityStrategy,
 ) -> Result<AvailableDevice, SubmitterFinalizeAsyncEnrollmentError> {
     let config: Arc<libparsec_client::ClientConfig> = config.into();
-    let identity_strategy = identity_strategy.convert()?;
+    // The submitter is not part of the organization yet, hence it has no access
+    // to the organization config (and the CRLs it may provide).
+    let identity_strategy = identity_strategy.convert(config.pki_revocation_policy, vec![])?;
     let new_device_save_strategy = new_device_save_strategy.convert_with_side_effects().await?;
 
     libparsec_client::submitter_finalize_async_enrollment(
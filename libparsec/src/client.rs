@@ -9,11 +9,12 @@ pub use libparsec_client::{
     ClientGetSelfShamirRecoveryError, ClientGetTosError, ClientGetUserDeviceError,
     ClientGetUserInfoError, ClientListFrozenUsersError, ClientListShamirRecoveriesForOthersError,
     ClientListUserDevicesError, ClientListUsersError, ClientListWorkspaceUsersError,
-    ClientOrganizationInfoError, ClientRenameWorkspaceError, ClientRevokeUserError,
-    ClientSetupShamirRecoveryError, ClientShareWorkspaceError, ClientUserUpdateProfileError,
-    DeviceInfo, OrganizationInfo, OtherShamirRecoveryInfo, RequestedRealmArchivingConfiguration,
-    SelfShamirRecoveryInfo, ServerOrganizationConfig, Tos, UserInfo, WorkspaceInfo,
-    WorkspaceUserAccessInfo,
+    ClientOrganizationInfoError, ClientRekeyWorkspaceError, ClientRenameWorkspaceError,
+    ClientRevokeUserError, ClientSetupShamirRecoveryError, ClientShareWorkspaceError,
+    ClientTransferWorkspaceOwnershipError, ClientUserUpdateProfileError, DeviceInfo,
+    OrganizationInfo, OrphanedWorkspacesStrategy, OtherShamirRecoveryInfo,
+    RequestedRealmArchivingConfiguration, SelfShamirRecoveryInfo, ServerOrganizationConfig, Tos,
+    UserInfo, WorkspaceInfo, WorkspaceUserAccessInfo,
 };
 pub use libparsec_client_connection::ConnectionError;
 use libparsec_platform_async::event::{Event, EventListener};
@@ -480,10 +481,14 @@ pub async fn client_accept_tos(
  * Revoke user
  */
 
-pub async fn client_revoke_user(client: Handle, user: UserID) -> Result<(), ClientRevokeUserError> {
+pub async fn client_revoke_user(
+    client: Handle,
+    user: UserID,
+    orphaned_workspaces: OrphanedWorkspacesStrategy,
+) -> Result<(), ClientRevokeUserError> {
     let client = borrow_client(client)?;
 
-    client.revoke_user(user).await
+    client.revoke_user(user, orphaned_workspaces).await
 }
 
 /*
@@ -622,10 +627,29 @@ pub async fn client_share_workspace(
     realm_id: VlobID,
     recipient: UserID,
     role: Option<RealmRole>,
+    expires_on: Option<DateTime>,
 ) -> Result<(), ClientShareWorkspaceError> {
     let client = borrow_client(client)?;
 
-    client.share_workspace(realm_id, recipient, role).await
+    client
+        .share_workspace(realm_id, recipient, role, expires_on)
+        .await
+}
+
+/*
+ * Client transfer workspace ownership
+ */
+
+pub async fn client_transfer_workspace_ownership(
+    client: Handle,
+    realm_id: VlobID,
+    new_owner: UserID,
+) -> Result<(), ClientTransferWorkspaceOwnershipError> {
+    let client = borrow_client(client)?;
+
+    client
+        .transfer_workspace_ownership(realm_id, new_owner)
+        .await
 }
 
 /*
@@ -642,6 +666,22 @@ pub async fn client_archive_workspace(
     client.archive_workspace(realm_id, configuration).await
 }
 
+/*
+ * Client rekey workspace
+ *
+ * Only the last version of each file and folder is re-encrypted, so this is not
+ * a retroactive revocation (see `Client::rekey_workspace`).
+ */
+
+pub async fn client_rekey_workspace(
+    client: Handle,
+    realm_id: VlobID,
+) -> Result<(), ClientRekeyWorkspaceError> {
+    let client = borrow_client(client)?;
+
+    client.rekey_workspace(realm_id).await
+}
+
 /*
  * Setup shamir recovery
  */
@@ -698,10 +738,13 @@ pub async fn client_update_user_profile(
     client: Handle,
     user: UserID,
     new_profile: UserProfile,
+    orphaned_workspaces: OrphanedWorkspacesStrategy,
 ) -> Result<(), ClientUserUpdateProfileError> {
     let client = borrow_client(client)?;
 
-    client.update_user_profile(user, new_profile).await
+    client
+        .update_user_profile(user, new_profile, orphaned_workspaces)
+        .await
 }
 
 /*
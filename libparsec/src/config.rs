@@ -5,7 +5,9 @@ use std::{
     sync::Arc,
 };
 
-pub use libparsec_client::{MountpointMountStrategy, ProxyConfig, WorkspaceStorageCacheSize};
+pub use libparsec_client::{
+    MountpointMountStrategy, ProxyConfig, WorkspaceStorageCacheSize, X509RevocationPolicy,
+};
 use libparsec_client_connection::{AnonymousServerCmds, ConnectionError};
 pub use libparsec_platform_device_loader::{
     get_default_config_dir, get_default_data_base_dir, get_default_mountpoint_base_dir,
@@ -29,6 +31,12 @@ pub struct ClientConfig {
     /// The pattern is formatted like a `.gitignore` file.
     pub prevent_sync_pattern: Option<String>,
     pub log_level: Option<LogLevel>,
+    /// How to handle an X509 certificate whose revocation status cannot be determined,
+    /// if not provided soft-fail is used (i.e. the certificate is considered not revoked).
+    pub pki_revocation_policy: Option<X509RevocationPolicy>,
+    /// Configure how long a modified entry should wait before being synchronized,
+    /// if not provided the default delays are used.
+    pub outbound_sync: Option<OutboundSyncConfig>,
 }
 
 impl Default for ClientConfig {
@@ -41,6 +49,8 @@ impl Default for ClientConfig {
             with_monitors: false,
             prevent_sync_pattern: None,
             log_level: None,
+            pki_revocation_policy: None,
+            outbound_sync: None,
         }
     }
 }
@@ -59,6 +69,8 @@ impl From<ClientConfig> for libparsec_client::ClientConfig {
             mountpoint_mount_strategy: config.mountpoint_mount_strategy,
             workspace_storage_cache_size: config.workspace_storage_cache_size,
             proxy: ProxyConfig::default(),
+            outbound_sync: config.outbound_sync.map(Into::into).unwrap_or_default(),
+            pki_revocation_policy: config.pki_revocation_policy.unwrap_or_default(),
             with_monitors: config.with_monitors,
             prevent_sync_pattern: match config.prevent_sync_pattern {
                 Some(custom_glob_ignore) => PreventSyncPattern::from_glob_ignore_file(
@@ -77,6 +89,76 @@ impl From<ClientConfig> for libparsec_client::ClientConfig {
     }
 }
 
+/// Delays applied by the outbound sync monitor before uploading a modified entry,
+/// see [`libparsec_client::OutboundSyncDelays`].
+#[derive(Debug, Clone, Copy)]
+pub struct OutboundSyncDelays {
+    pub quiescence_in_ms: u32,
+    pub max_delay_in_ms: u32,
+}
+
+impl From<OutboundSyncDelays> for libparsec_client::OutboundSyncDelays {
+    fn from(delays: OutboundSyncDelays) -> Self {
+        Self {
+            quiescence: Duration::milliseconds(delays.quiescence_in_ms.into()),
+            max_delay: Duration::milliseconds(delays.max_delay_in_ms.into()),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct OutboundSyncDelaysOverride {
+    /// The pattern is formatted like a `.gitignore` file.
+    pub pattern: String,
+    pub delays: OutboundSyncDelays,
+}
+
+/// Partial outbound sync configuration, converted into the proper configuration
+/// [`libparsec_client::OutboundSyncConfig`] by using default values as fallback.
+#[derive(Debug, Clone, Default)]
+pub struct OutboundSyncConfig {
+    pub delays: Option<OutboundSyncDelays>,
+    /// Overrides are checked in order, the first matching one is used.
+    pub overrides: Vec<OutboundSyncDelaysOverride>,
+    /// See [`libparsec_client::OutboundSyncConfig::block_deduplication`].
+    pub block_deduplication: bool,
+    /// See [`libparsec_client::OutboundSyncConfig::block_compression`].
+    pub block_compression: bool,
+    /// See [`libparsec_client::OutboundSyncConfig::content_defined_chunking`].
+    pub content_defined_chunking: bool,
+}
+
+impl From<OutboundSyncConfig> for libparsec_client::OutboundSyncConfig {
+    fn from(config: OutboundSyncConfig) -> Self {
+        let overrides = config
+            .overrides
+            .into_iter()
+            .filter_map(|item| {
+                match PreventSyncPattern::from_glob_ignore_file(&item.pattern) {
+                    Ok(pattern) => Some(libparsec_client::OutboundSyncDelaysOverride {
+                        pattern,
+                        delays: item.delays.into(),
+                    }),
+                    // Ignore the override if its pattern is invalid
+                    Err(err) => {
+                        log::warn!(
+                            "Invalid outbound sync delays override pattern, ignoring it: {err}"
+                        );
+                        None
+                    }
+                }
+            })
+            .collect();
+        Self {
+            delays: config.delays.map(Into::into).unwrap_or_default(),
+            overrides,
+            block_deduplication: config.block_deduplication,
+            block_compression: config.block_compression,
+            content_defined_chunking: config.content_defined_chunking,
+        }
+    }
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum GetServerConfigError {
     #[error("Cannot communicate with the server: {0}")]
@@ -4,7 +4,8 @@ use std::{path::Path, sync::Arc};
 
 use crate::config::ClientConfig;
 pub use libparsec_platform_device_loader::{
-    ArchiveDeviceError, AvailableDevice, AvailableDeviceType, ListAvailableDeviceError,
+    ArchiveDeviceError, AvailableDevice, AvailableDeviceMultiProtection,
+    AvailableDeviceMultiProtectionType, AvailableDeviceType, ListAvailableDeviceError,
     UpdateDeviceError,
 };
 pub use libparsec_platform_storage::RemoveDeviceDataError;
@@ -17,7 +18,9 @@ mod strategy {
         Account, AccountFetchOpaqueKeyFromVaultError, AccountUploadOpaqueKeyInVaultError,
     };
     use libparsec_crypto::{Password, SecretKey};
-    use libparsec_openbao::{OpenBaoCmds, OpenBaoFetchOpaqueKeyError, OpenBaoUploadOpaqueKeyError};
+    use libparsec_openbao::{
+        OpenBaoAuthMethod, OpenBaoCmds, OpenBaoFetchOpaqueKeyError, OpenBaoUploadOpaqueKeyError,
+    };
     use libparsec_platform_async::{pretend_future_is_send_on_web, PinBoxFutureResult};
     use libparsec_platform_device_loader::{
         AccountVaultOperationsFetchOpaqueKeyError, AccountVaultOperationsUploadOpaqueKeyError,
@@ -236,6 +239,9 @@ mod strategy {
     #[derive(Debug, Clone)]
     pub enum DevicePrimaryProtectionStrategy {
         Keyring,
+        MachineKey {
+            machine_key_file: PathBuf,
+        },
         Password {
             password: Password,
         },
@@ -252,30 +258,95 @@ mod strategy {
             openbao_entity_id: String,
             openbao_auth_token: String,
             openbao_preferred_auth_id: String,
+            /// Used to obtain a new token once `openbao_auth_token` has expired and
+            /// cannot be renewed, if not provided the device operations fail from
+            /// this point on.
+            openbao_reauthentication_method: Option<OpenBaoAuthMethod>,
+        },
+        Fido2 {
+            /// Only needed if the authenticator is protected by a PIN
+            pin: Option<Password>,
+        },
+        /// Any of the alternatives is enough to access the device (see
+        /// `libparsec_platform_device_loader::DevicePrimaryProtectionStrategy::Multi`).
+        Multi {
+            alternatives: Vec<DeviceAlternativeProtection>,
         },
     }
 
-    /*
-     * DeviceSaveStrategy
-     */
-
     #[derive(Debug, Clone)]
-    pub struct DeviceSaveStrategy {
+    pub struct DeviceAlternativeProtection {
         pub totp_protection: Option<(TOTPOpaqueKeyID, SecretKey)>,
         pub primary_protection: DevicePrimaryProtectionStrategy,
     }
 
-    impl DeviceSaveStrategy {
+    fn fido2_primary_protection(
+        pin: Option<Password>,
+    ) -> anyhow::Result<libparsec_platform_device_loader::DevicePrimaryProtectionStrategy> {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            Ok(
+                libparsec_platform_device_loader::DevicePrimaryProtectionStrategy::Fido2 {
+                    operations: Arc::new(
+                        libparsec_platform_device_loader::NativeFido2Operations::new(pin),
+                    ),
+                },
+            )
+        }
+        #[cfg(target_arch = "wasm32")]
+        {
+            let _ = pin;
+            Err(anyhow::anyhow!("FIDO2 is not supported on web"))
+        }
+    }
+
+    impl DevicePrimaryProtectionStrategy {
         /// This method may need to do side-effects (typically to obtain the `Account`
         /// object from its handle).
         /// Hence its funny name, and why we don't just replace it by a `impl From<...> for ...`
-        pub async fn convert_with_side_effects(
+        async fn convert_with_side_effects(
             self,
-        ) -> anyhow::Result<libparsec_platform_device_loader::DeviceSaveStrategy> {
-            let primary_protection = match self.primary_protection {
+        ) -> anyhow::Result<libparsec_platform_device_loader::DevicePrimaryProtectionStrategy>
+        {
+            match self {
+                DevicePrimaryProtectionStrategy::Multi { alternatives } => {
+                    let mut converted = Vec::with_capacity(alternatives.len());
+                    for alternative in alternatives {
+                        converted.push(
+                            libparsec_platform_device_loader::DeviceAlternativeProtection {
+                                totp_protection: alternative.totp_protection,
+                                primary_protection: alternative
+                                    .primary_protection
+                                    .convert_alternative_with_side_effects()
+                                    .await?,
+                            },
+                        );
+                    }
+                    Ok(
+                        libparsec_platform_device_loader::DevicePrimaryProtectionStrategy::Multi {
+                            alternatives: converted,
+                        },
+                    )
+                }
+                single => single.convert_alternative_with_side_effects().await,
+            }
+        }
+
+        /// Convert a single protection (i.e. not a multi protection, given a multi
+        /// protection cannot be used as one of its own alternatives).
+        async fn convert_alternative_with_side_effects(
+            self,
+        ) -> anyhow::Result<libparsec_platform_device_loader::DevicePrimaryProtectionStrategy>
+        {
+            let primary_protection = match self {
                 DevicePrimaryProtectionStrategy::Keyring => {
                     libparsec_platform_device_loader::DevicePrimaryProtectionStrategy::Keyring
                 }
+                DevicePrimaryProtectionStrategy::MachineKey { machine_key_file } => {
+                    libparsec_platform_device_loader::DevicePrimaryProtectionStrategy::MachineKey {
+                        machine_key_file,
+                    }
+                }
                 DevicePrimaryProtectionStrategy::Password { password } => {
                     libparsec_platform_device_loader::DevicePrimaryProtectionStrategy::Password {
                         password,
@@ -323,19 +394,24 @@ mod strategy {
                     openbao_entity_id,
                     openbao_auth_token,
                     openbao_preferred_auth_id,
+                    openbao_reauthentication_method,
                 } => {
                     let client = libparsec_client_connection::build_client_with_proxy(
                         libparsec_client_connection::ProxyConfig::default(),
                     )?;
 
-                    let cmds = Arc::new(OpenBaoCmds::new(
+                    let cmds = OpenBaoCmds::new(
                         client,
                         openbao_server_url,
                         openbao_secret_mount_path,
                         openbao_transit_mount_path,
                         openbao_entity_id,
                         openbao_auth_token,
-                    ));
+                    );
+                    let cmds = Arc::new(match openbao_reauthentication_method {
+                        Some(method) => cmds.with_reauthentication_method(method),
+                        None => cmds,
+                    });
 
                     libparsec_platform_device_loader::DevicePrimaryProtectionStrategy::OpenBao {
                         operations: Arc::new(OpenBaoDeviceOperations {
@@ -344,8 +420,35 @@ mod strategy {
                         }),
                     }
                 }
+                DevicePrimaryProtectionStrategy::Fido2 { pin } => fido2_primary_protection(pin)?,
+                DevicePrimaryProtectionStrategy::Multi { .. } => {
+                    return Err(anyhow::anyhow!("Multi protection cannot be nested"))
+                }
             };
 
+            Ok(primary_protection)
+        }
+    }
+
+    /*
+     * DeviceSaveStrategy
+     */
+
+    #[derive(Debug, Clone)]
+    pub struct DeviceSaveStrategy {
+        pub totp_protection: Option<(TOTPOpaqueKeyID, SecretKey)>,
+        pub primary_protection: DevicePrimaryProtectionStrategy,
+    }
+
+    impl DeviceSaveStrategy {
+        /// This method may need to do side-effects (typically to obtain the `Account`
+        /// object from its handle).
+        /// Hence its funny name, and why we don't just replace it by a `impl From<...> for ...`
+        pub async fn convert_with_side_effects(
+            self,
+        ) -> anyhow::Result<libparsec_platform_device_loader::DeviceSaveStrategy> {
+            let primary_protection = self.primary_protection.convert_with_side_effects().await?;
+
             Ok(libparsec_platform_device_loader::DeviceSaveStrategy {
                 totp_protection: self.totp_protection,
                 primary_protection,
@@ -372,75 +475,7 @@ mod strategy {
         pub async fn convert_with_side_effects(
             self,
         ) -> anyhow::Result<libparsec_platform_device_loader::DeviceAccessStrategy> {
-            let primary_protection = match self.primary_protection {
-                DevicePrimaryProtectionStrategy::Keyring => {
-                    libparsec_platform_device_loader::DevicePrimaryProtectionStrategy::Keyring
-                }
-                DevicePrimaryProtectionStrategy::Password { password } => {
-                    libparsec_platform_device_loader::DevicePrimaryProtectionStrategy::Password {
-                        password,
-                    }
-                }
-                DevicePrimaryProtectionStrategy::PKI {
-                    pki_private_key_handle,
-                } => {
-                    let (pki_certificate, pki_private_key) =
-                        borrow_from_handle(pki_private_key_handle, |x| match x {
-                            HandleItem::PkiPrivateKey {
-                                certificate,
-                                private_key,
-                            } => Some((certificate.clone(), private_key.clone())),
-                            _ => None,
-                        })?;
-                    let certificate_ref = pki_certificate.to_reference().await.map_err(|err| {
-                        anyhow::anyhow!("Cannot get certificate reference: {}", err)
-                    })?;
-
-                    libparsec_platform_device_loader::DevicePrimaryProtectionStrategy::PKI {
-                        operations: Arc::new(PkiOperations {
-                            pki_certificate,
-                            pki_private_key,
-                            certificate_ref,
-                        }),
-                    }
-                }
-                DevicePrimaryProtectionStrategy::AccountVault { account_handle } => {
-                    // Note `borrow_from_handle` does a side-effect here !
-                    let account = borrow_from_handle(account_handle, |x| match x {
-                        HandleItem::Account(account) => Some(account.clone()),
-                        _ => None,
-                    })?;
-
-                    libparsec_platform_device_loader::DevicePrimaryProtectionStrategy::AccountVault {
-                        operations: Arc::new(AccountVaultOperations { account }),
-                    }
-                }
-                DevicePrimaryProtectionStrategy::OpenBao {
-                    openbao_server_url,
-                    openbao_secret_mount_path,
-                    openbao_transit_mount_path,
-                    openbao_entity_id,
-                    openbao_auth_token,
-                    openbao_preferred_auth_id,
-                } => {
-                    let client = libparsec_client_connection::build_client()?;
-                    let cmds = Arc::new(OpenBaoCmds::new(
-                        client,
-                        openbao_server_url,
-                        openbao_secret_mount_path,
-                        openbao_transit_mount_path,
-                        openbao_entity_id,
-                        openbao_auth_token,
-                    ));
-
-                    libparsec_platform_device_loader::DevicePrimaryProtectionStrategy::OpenBao {
-                        operations: Arc::new(OpenBaoDeviceOperations {
-                            cmds,
-                            openbao_preferred_auth_id,
-                        }),
-                    }
-                }
-            };
+            let primary_protection = self.primary_protection.convert_with_side_effects().await?;
 
             Ok(libparsec_platform_device_loader::DeviceAccessStrategy {
                 key_file: self.key_file,
@@ -450,7 +485,10 @@ mod strategy {
         }
     }
 }
-pub use strategy::{DeviceAccessStrategy, DevicePrimaryProtectionStrategy, DeviceSaveStrategy};
+pub use strategy::{
+    DeviceAccessStrategy, DeviceAlternativeProtection, DevicePrimaryProtectionStrategy,
+    DeviceSaveStrategy,
+};
 
 pub async fn list_available_devices(
     config_dir: &Path,
@@ -62,6 +62,58 @@ pub enum ClientEvent {
         entry_id: VlobID,
     },
 
+    // Certificates changes
+    UserRevoked {
+        user_id: UserID,
+    },
+    UserProfileUpdated {
+        user_id: UserID,
+        profile: UserProfile,
+    },
+    DeviceAdded {
+        user_id: UserID,
+        device_id: DeviceID,
+    },
+    DeviceRevoked {
+        user_id: UserID,
+        device_id: DeviceID,
+    },
+    RealmRoleChanged {
+        realm_id: VlobID,
+        user_id: UserID,
+        old_role: Option<RealmRole>,
+        new_role: Option<RealmRole>,
+    },
+    RealmRenamed {
+        realm_id: VlobID,
+    },
+    RealmArchivingChanged {
+        realm_id: VlobID,
+    },
+    RealmRekeyProgress {
+        realm_id: VlobID,
+        target_key_index: IndexInt,
+        processed: IndexInt,
+        total: IndexInt,
+    },
+    RealmRekeyDone {
+        realm_id: VlobID,
+        target_key_index: IndexInt,
+    },
+    SelfShared {
+        realm_id: VlobID,
+        role: RealmRole,
+    },
+    SelfUnshared {
+        realm_id: VlobID,
+    },
+    SequesterServiceRevoked {
+        service_id: SequesterServiceID,
+    },
+    ShamirRecoveryChanged {
+        user_id: UserID,
+    },
+
     InvitationChanged {
         token: AccessToken,
         status: InvitationStatus,
@@ -111,6 +163,10 @@ pub enum ClientEvent {
         ballpark_client_early_offset: Float,
         ballpark_client_late_offset: Float,
     },
+    ClockDrift {
+        required_timestamp: DateTime,
+        client_timestamp: DateTime,
+    },
     InvalidCertificate {
         detail: String,
     },
@@ -147,6 +203,22 @@ pub(crate) struct OnEventCallbackPlugged {
         EventBusConnectionLifetime<libparsec_client::EventWorkspaceOpsOutboundSyncDone>,
     _workspace_ops_inbound_sync_done:
         EventBusConnectionLifetime<libparsec_client::EventWorkspaceOpsInboundSyncDone>,
+    _user_revoked: EventBusConnectionLifetime<libparsec_client::EventUserRevoked>,
+    _user_profile_updated: EventBusConnectionLifetime<libparsec_client::EventUserProfileUpdated>,
+    _device_added: EventBusConnectionLifetime<libparsec_client::EventDeviceAdded>,
+    _device_revoked: EventBusConnectionLifetime<libparsec_client::EventDeviceRevoked>,
+    _realm_role_changed: EventBusConnectionLifetime<libparsec_client::EventRealmRoleChanged>,
+    _realm_renamed: EventBusConnectionLifetime<libparsec_client::EventRealmRenamed>,
+    _realm_archiving_changed:
+        EventBusConnectionLifetime<libparsec_client::EventRealmArchivingChanged>,
+    _realm_rekey_progress: EventBusConnectionLifetime<libparsec_client::EventRealmRekeyProgress>,
+    _realm_rekey_done: EventBusConnectionLifetime<libparsec_client::EventRealmRekeyDone>,
+    _self_shared: EventBusConnectionLifetime<libparsec_client::EventSelfShared>,
+    _self_unshared: EventBusConnectionLifetime<libparsec_client::EventSelfUnshared>,
+    _sequester_service_revoked:
+        EventBusConnectionLifetime<libparsec_client::EventSequesterServiceRevoked>,
+    _shamir_recovery_changed:
+        EventBusConnectionLifetime<libparsec_client::EventShamirRecoveryChanged>,
     _invitation_changed: EventBusConnectionLifetime<libparsec_client::EventInvitationChanged>,
     _async_enrollment_updated:
         EventBusConnectionLifetime<libparsec_client::EventAsyncEnrollmentUpdated>,
@@ -174,6 +246,7 @@ pub(crate) struct OnEventCallbackPlugged {
         EventBusConnectionLifetime<libparsec_client::EventServerInvalidResponseContent>,
     _too_much_drift_with_server_clock:
         EventBusConnectionLifetime<libparsec_client::EventTooMuchDriftWithServerClock>,
+    _clock_drift: EventBusConnectionLifetime<libparsec_client::EventClockDrift>,
     _invalid_certificate: EventBusConnectionLifetime<libparsec_client::EventInvalidCertificate>,
     // _invalid_keys_bundle: EventBusConnectionLifetime<libparsec_client::EventInvalidKeysBundle>,
     // _invalid_manifest: EventBusConnectionLifetime<libparsec_client::EventInvalidManifest>,
@@ -319,6 +392,153 @@ impl OnEventCallbackPlugged {
                 },
             )
         };
+        let user_revoked = {
+            let on_event_callback = on_event_callback.clone();
+            event_bus.connect(move |e: &libparsec_client::EventUserRevoked| {
+                (on_event_callback)(handle, ClientEvent::UserRevoked { user_id: e.user_id });
+            })
+        };
+        let user_profile_updated = {
+            let on_event_callback = on_event_callback.clone();
+            event_bus.connect(move |e: &libparsec_client::EventUserProfileUpdated| {
+                (on_event_callback)(
+                    handle,
+                    ClientEvent::UserProfileUpdated {
+                        user_id: e.user_id,
+                        profile: e.profile,
+                    },
+                );
+            })
+        };
+        let device_added = {
+            let on_event_callback = on_event_callback.clone();
+            event_bus.connect(move |e: &libparsec_client::EventDeviceAdded| {
+                (on_event_callback)(
+                    handle,
+                    ClientEvent::DeviceAdded {
+                        user_id: e.user_id,
+                        device_id: e.device_id,
+                    },
+                );
+            })
+        };
+        let device_revoked = {
+            let on_event_callback = on_event_callback.clone();
+            event_bus.connect(move |e: &libparsec_client::EventDeviceRevoked| {
+                (on_event_callback)(
+                    handle,
+                    ClientEvent::DeviceRevoked {
+                        user_id: e.user_id,
+                        device_id: e.device_id,
+                    },
+                );
+            })
+        };
+        let realm_role_changed = {
+            let on_event_callback = on_event_callback.clone();
+            event_bus.connect(move |e: &libparsec_client::EventRealmRoleChanged| {
+                (on_event_callback)(
+                    handle,
+                    ClientEvent::RealmRoleChanged {
+                        realm_id: e.realm_id,
+                        user_id: e.user_id,
+                        old_role: e.old_role,
+                        new_role: e.new_role,
+                    },
+                );
+            })
+        };
+        let realm_renamed = {
+            let on_event_callback = on_event_callback.clone();
+            event_bus.connect(move |e: &libparsec_client::EventRealmRenamed| {
+                (on_event_callback)(
+                    handle,
+                    ClientEvent::RealmRenamed {
+                        realm_id: e.realm_id,
+                    },
+                );
+            })
+        };
+        let realm_archiving_changed = {
+            let on_event_callback = on_event_callback.clone();
+            event_bus.connect(move |e: &libparsec_client::EventRealmArchivingChanged| {
+                (on_event_callback)(
+                    handle,
+                    ClientEvent::RealmArchivingChanged {
+                        realm_id: e.realm_id,
+                    },
+                );
+            })
+        };
+        let realm_rekey_progress = {
+            let on_event_callback = on_event_callback.clone();
+            event_bus.connect(move |e: &libparsec_client::EventRealmRekeyProgress| {
+                (on_event_callback)(
+                    handle,
+                    ClientEvent::RealmRekeyProgress {
+                        realm_id: e.realm_id,
+                        target_key_index: e.target_key_index,
+                        processed: e.processed,
+                        total: e.total,
+                    },
+                );
+            })
+        };
+        let realm_rekey_done = {
+            let on_event_callback = on_event_callback.clone();
+            event_bus.connect(move |e: &libparsec_client::EventRealmRekeyDone| {
+                (on_event_callback)(
+                    handle,
+                    ClientEvent::RealmRekeyDone {
+                        realm_id: e.realm_id,
+                        target_key_index: e.target_key_index,
+                    },
+                );
+            })
+        };
+        let self_shared = {
+            let on_event_callback = on_event_callback.clone();
+            event_bus.connect(move |e: &libparsec_client::EventSelfShared| {
+                (on_event_callback)(
+                    handle,
+                    ClientEvent::SelfShared {
+                        realm_id: e.realm_id,
+                        role: e.role,
+                    },
+                );
+            })
+        };
+        let self_unshared = {
+            let on_event_callback = on_event_callback.clone();
+            event_bus.connect(move |e: &libparsec_client::EventSelfUnshared| {
+                (on_event_callback)(
+                    handle,
+                    ClientEvent::SelfUnshared {
+                        realm_id: e.realm_id,
+                    },
+                );
+            })
+        };
+        let sequester_service_revoked = {
+            let on_event_callback = on_event_callback.clone();
+            event_bus.connect(move |e: &libparsec_client::EventSequesterServiceRevoked| {
+                (on_event_callback)(
+                    handle,
+                    ClientEvent::SequesterServiceRevoked {
+                        service_id: e.service_id,
+                    },
+                );
+            })
+        };
+        let shamir_recovery_changed = {
+            let on_event_callback = on_event_callback.clone();
+            event_bus.connect(move |e: &libparsec_client::EventShamirRecoveryChanged| {
+                (on_event_callback)(
+                    handle,
+                    ClientEvent::ShamirRecoveryChanged { user_id: e.user_id },
+                );
+            })
+        };
         let invitation_changed = {
             let on_event_callback = on_event_callback.clone();
             event_bus.connect(move |e: &libparsec_client::EventInvitationChanged| {
@@ -484,6 +704,18 @@ impl OnEventCallbackPlugged {
                 },
             )
         };
+        let clock_drift = {
+            let on_event_callback = on_event_callback.clone();
+            event_bus.connect(move |e: &libparsec_client::EventClockDrift| {
+                (on_event_callback)(
+                    handle,
+                    ClientEvent::ClockDrift {
+                        required_timestamp: e.required_timestamp,
+                        client_timestamp: e.client_timestamp,
+                    },
+                );
+            })
+        };
 
         let invalid_certificate = {
             let on_event_callback = on_event_callback.clone();
@@ -524,12 +756,26 @@ impl OnEventCallbackPlugged {
             _workspace_ops_outbound_sync_aborted: workspace_ops_outbound_sync_aborted,
             _workspace_ops_outbound_sync_done: workspace_ops_outbound_sync_done,
             _workspace_ops_inbound_sync_done: workspace_ops_inbound_sync_done,
+            _user_revoked: user_revoked,
+            _user_profile_updated: user_profile_updated,
+            _device_added: device_added,
+            _device_revoked: device_revoked,
+            _realm_role_changed: realm_role_changed,
+            _realm_renamed: realm_renamed,
+            _realm_archiving_changed: realm_archiving_changed,
+            _realm_rekey_progress: realm_rekey_progress,
+            _realm_rekey_done: realm_rekey_done,
+            _self_shared: self_shared,
+            _self_unshared: self_unshared,
+            _sequester_service_revoked: sequester_service_revoked,
+            _shamir_recovery_changed: shamir_recovery_changed,
             _invitation_changed: invitation_changed,
             _async_enrollment_updated: async_enrollment_updated,
             _greeting_attempt_ready: greeting_attempt_ready,
             _greeting_attempt_cancelled: greeting_attempt_cancelled,
             _greeting_attempt_joined: greeting_attempt_joined,
             _too_much_drift_with_server_clock: too_much_drift_with_server_clock,
+            _clock_drift: clock_drift,
             _expired_organization: expired_organization,
             _organization_not_found: organization_not_found,
             _invitation_already_used_or_deleted: invitation_already_used_or_deleted,
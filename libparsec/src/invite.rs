@@ -66,6 +66,10 @@ pub enum BootstrapOrganizationError {
         server: RemoteOperationServer,
         error: anyhow::Error,
     },
+    #[error(
+        "Cannot save device: the FIDO2 authenticator doesn't support the hmac-secret extension"
+    )]
+    SaveDeviceFido2HmacSecretNotSupported,
     #[error(transparent)]
     Internal(#[from] anyhow::Error),
 }
@@ -171,6 +175,9 @@ pub async fn bootstrap_organization(
             SaveDeviceError::RemoteOpaqueKeyUploadFailed { server, error } => {
                 BootstrapOrganizationError::SaveDeviceRemoteOpaqueKeyUploadFailed { server, error }
             }
+            SaveDeviceError::Fido2HmacSecretNotSupported => {
+                BootstrapOrganizationError::SaveDeviceFido2HmacSecretNotSupported
+            }
             SaveDeviceError::Internal(err) => {
                 BootstrapOrganizationError::Internal(err.context("Cannot save device"))
             }
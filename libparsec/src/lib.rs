@@ -35,8 +35,15 @@ pub use handle::Handle;
 pub use invite::*;
 pub use libparsec_client::{ClientExportRecoveryDeviceError, ImportRecoveryDeviceError};
 pub use libparsec_client_connection::*;
+#[cfg(not(target_arch = "wasm32"))]
+pub use libparsec_platform_device_loader::NativeFido2Operations;
 pub use libparsec_platform_device_loader::{
-    get_default_key_file, is_keyring_available, load_device, save_device, LoadDeviceError,
+    check_device_security, diagnose_device_files, export_device_files_backup, get_default_key_file,
+    get_default_machine_key_file, import_device_files_backup, is_keyring_available,
+    is_machine_key_available, load_device, repair_device_files, save_device, DeviceFileDiagnosis,
+    DeviceFileIssue, DeviceFileRepair, DeviceFilesDiagnosis, DeviceProtectionWeakness,
+    DiagnoseDeviceFilesError, ExportDeviceFilesBackupError, ImportDeviceFilesBackupError,
+    ImportedDeviceFile, LoadDeviceError, RepairDeviceFilesError,
 };
 pub use libparsec_platform_storage as storage;
 pub use libparsec_protocol::*;
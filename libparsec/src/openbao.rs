@@ -2,6 +2,7 @@
 
 use std::sync::Arc;
 
+pub use libparsec_openbao::OpenBaoAuthMethod;
 use libparsec_openbao::{OpenBaoCmds, OpenBaoListEntityEmailsError};
 use libparsec_types::prelude::*;
 
@@ -6,9 +6,10 @@ pub use libparsec_client::workspace::{
     EntryStat, FileStat, MoveEntryMode, OpenOptions, WorkspaceCreateFileError,
     WorkspaceCreateFolderError, WorkspaceDecryptPathAddrError, WorkspaceFdCloseError,
     WorkspaceFdFlushError, WorkspaceFdReadError, WorkspaceFdResizeError, WorkspaceFdStatError,
-    WorkspaceFdWriteError, WorkspaceGeneratePathAddrError, WorkspaceIsFileContentLocalError,
-    WorkspaceMoveEntryError, WorkspaceOpenFileError, WorkspaceRemoveEntryError,
-    WorkspaceStatEntryError, WorkspaceStatFolderChildrenError, WorkspaceWatchEntryOneShotError,
+    WorkspaceFdWriteError, WorkspaceGeneratePathAddrError, WorkspaceIsEntryRemoteOnlyError,
+    WorkspaceIsFileContentLocalError, WorkspaceMoveEntryError, WorkspaceOpenFileError,
+    WorkspaceRemoveEntryError, WorkspaceSetRemoteOnlyEntriesError, WorkspaceStatEntryError,
+    WorkspaceStatFolderChildrenError, WorkspaceWatchEntryOneShotError,
 };
 use libparsec_platform_async::event::{Event, EventListener};
 use libparsec_types::prelude::*;
@@ -627,6 +628,49 @@ pub async fn workspace_is_file_content_local(
     workspace.is_file_content_local(path).await
 }
 
+/*
+ * Selective sync
+ */
+
+/// Replace the entries (i.e. roots of subtrees) whose file contents is never kept
+/// in the local storage of this device.
+///
+/// Blocks already in the local storage for the newly remote only subtrees are
+/// removed, while data modified locally is kept until it has been synchronized.
+pub async fn workspace_set_remote_only_entries(
+    workspace: Handle,
+    entries: Vec<VlobID>,
+) -> Result<(), WorkspaceSetRemoteOnlyEntriesError> {
+    let workspace = borrow_workspace(workspace)?;
+
+    workspace.set_remote_only_entries(&entries).await
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum WorkspaceGetRemoteOnlyEntriesError {
+    #[error(transparent)]
+    Internal(#[from] anyhow::Error),
+}
+
+pub async fn workspace_get_remote_only_entries(
+    workspace: Handle,
+) -> Result<Vec<VlobID>, WorkspaceGetRemoteOnlyEntriesError> {
+    let workspace = borrow_workspace(workspace)?;
+
+    Ok(workspace.get_remote_only_entries())
+}
+
+/// Return `true` if the entry is part of a remote only subtree (i.e. itself or
+/// one of its parents has been marked as remote only).
+pub async fn workspace_is_entry_remote_only_by_id(
+    workspace: Handle,
+    entry_id: VlobID,
+) -> Result<bool, WorkspaceIsEntryRemoteOnlyError> {
+    let workspace = borrow_workspace(workspace)?;
+
+    workspace.is_entry_remote_only_by_id(entry_id).await
+}
+
 pub async fn workspace_open_file(
     workspace: Handle,
     path: FsPath,
@@ -4,6 +4,7 @@ from __future__ import annotations
 
 import shlex
 import subprocess
+import tempfile
 from argparse import ArgumentParser
 from base64 import b64encode
 from binascii import unhexlify
@@ -18,6 +19,7 @@ ROOT_DIR = Path(__file__).parent.parent.resolve()
 ROOT_CERT_DIR = Path("Root")
 INTERMEDIATE_CERT_DIR = Path("Intermediate")
 LEAF_CERT_DIR = Path("Cert")
+CRL_DIR = Path("Crl")
 
 SCRIPT_LAST_MODIFICATION = Path(__file__).stat().st_mtime
 
@@ -42,6 +44,26 @@ class RSAKeyAlgorithm(KeyAlgorithm):
         return check_run(["openssl", "genrsa", "-out", path, str(self.length)])
 
 
+@dataclass
+class ECKeyAlgorithm(KeyAlgorithm):
+    curve: str
+    id = "EC"
+
+    def run_openssl_cmd(self, path: Path):
+        return check_run(
+            [
+                "openssl",
+                "genpkey",
+                "-algorithm",
+                "EC",
+                "-pkeyopt",
+                f"ec_paramgen_curve:{self.curve}",
+                "-out",
+                path,
+            ]
+        )
+
+
 def check_run(args: Sequence[str | Path]) -> int:
     print(f"+ {shlex.join(map(str, args))}")
     return subprocess.check_call(args)
@@ -77,6 +99,8 @@ class CertificateConfig:
     extensions: dict[str, str] = field(default_factory=dict)
     signing: list[CertificateConfig] = field(default_factory=list)
     """Certificates that will by signed by this certificate"""
+    revoked: bool = False
+    """Certificate is listed as revoked in the CRL of its signer"""
 
 
 now = datetime.now()
@@ -124,6 +148,7 @@ TRUSTCHAINS = [
                 not_before=not_before,
                 not_after=not_after,
                 extensions={EXTENDED_KEY_USAGE: "clientAuth"},
+                revoked=True,
             ),
             CertificateConfig(
                 name="old-boby",
@@ -133,6 +158,14 @@ TRUSTCHAINS = [
                 not_after=not_before,  # Not a typo, we want old-boby cert to be expired
                 extensions={BASIC_CONSTRAINTS: "CA:FALSE"},
             ),
+            CertificateConfig(
+                name="eve",
+                subject={COMMON_NAME: "Eve"},
+                key_algorithm=ECKeyAlgorithm(curve="P-256"),
+                not_before=not_before,
+                not_after=not_after,
+                extensions={SUBJECT_ALT_NAME: f"{SAN_EMAIL}:eve@black_mesa.corp"},
+            ),
         ],
     ),
     CertificateConfig(
@@ -190,15 +223,25 @@ def main(output_dir: Path) -> None:
         "Certificates:",
         "",
     ]
+    crls_readme_content = [
+        "",
+        "CRLs (in `Crl/`):",
+        "",
+    ]
 
     for chain in TRUSTCHAINS:
-        certifs = create_trustchain(chain, None, output_dir)
+        certifs, crls = create_trustchain(chain, None, output_dir)
         for name, fingerprint in certifs.items():
             hex_fingerprint = fingerprint.hex().upper()
             coded_fingerprint = f"sha256-{b64encode(fingerprint).decode('utf-8')}"
             readme_file_content.append(
                 f"- {name}: SHA256={hex_fingerprint} (aka `{coded_fingerprint}`)"
             )
+        for name, revoked in crls.items():
+            revoked_list = ", ".join(revoked) if revoked else "nothing"
+            crls_readme_content.append(f"- {name}: revokes {revoked_list}")
+
+    readme_file_content += crls_readme_content
 
     readme_file = output_dir / "README.md"
     readme_file.write_text("\n".join(readme_file_content))
@@ -206,7 +249,7 @@ def main(output_dir: Path) -> None:
 
 def create_trustchain(
     chain: CertificateConfig, signer: CertificateConfig | None, output_dir: Path
-) -> dict[str, Sha256Fingerprint]:
+) -> tuple[dict[str, Sha256Fingerprint], dict[str, list[str]]]:
     if signer:
         print(f"Creating trustchain for {chain.name} signed by {signer.name}")
         # Inherit signer subject
@@ -237,14 +280,20 @@ def create_trustchain(
     generate_pkcs12_file(chain, cert_file, key_file, pkcs12_file)
 
     generated = {}
+    crls = {}
 
     fingerprint = get_sha256_fingerprint(cert_file)
     generated[chain.name] = fingerprint
 
     for children in chain.signing:
-        generated |= create_trustchain(children, chain, output_dir)
+        children_generated, children_crls = create_trustchain(children, chain, output_dir)
+        generated |= children_generated
+        crls |= children_crls
 
-    return generated
+    if chain.signing:
+        crls[chain.name] = generate_crl(chain, output_dir)
+
+    return generated, crls
 
 
 def generate_self_signed_cert(chain: CertificateConfig, key_file: Path, cert_file: Path):
@@ -359,6 +408,68 @@ def get_sha256_fingerprint(cert_file: Path) -> Sha256Fingerprint:
     return unhexlify(raw_fingerprint[len(PREFIX) :].strip().decode("utf-8").replace(":", ""))
 
 
+def generate_crl(chain: CertificateConfig, output_dir: Path) -> list[str]:
+    """
+    Generate the CRL of a CA certificate, listing its revoked children.
+    """
+    ca_workdir = output_dir / chain.type.get_path()
+    ca_cert = ca_workdir / (chain.name + ".crt")
+    ca_key = ca_workdir / (chain.name + ".key")
+    crl_file = output_dir / CRL_DIR / (chain.name + ".crl")
+    crl_file.parent.mkdir(parents=True, exist_ok=True)
+
+    revoked = [children for children in chain.signing if children.revoked]
+
+    # `openssl ca` needs a database to keep track of the revoked certificates
+    with tempfile.TemporaryDirectory() as tmpdir:
+        tmpdir = Path(tmpdir)
+        (tmpdir / "index.txt").touch()
+        (tmpdir / "crlnumber").write_text("01\n")
+        config_file = tmpdir / "openssl.cnf"
+        config_file.write_text(
+            "\n".join(
+                [
+                    "[ ca ]",
+                    "default_ca = CA_default",
+                    "[ CA_default ]",
+                    f"database = {tmpdir / 'index.txt'}",
+                    f"crlnumber = {tmpdir / 'crlnumber'}",
+                    "default_md = sha256",
+                ]
+            )
+        )
+        ca_args = ["-config", config_file, "-cert", ca_cert, "-keyfile", ca_key]
+
+        for children in revoked:
+            children_cert = output_dir / children.type.get_path() / (children.name + ".crt")
+            check_run(
+                [
+                    "openssl",
+                    "ca",
+                    *ca_args,
+                    "-revoke",
+                    children_cert,
+                    "-crl_reason",
+                    "keyCompromise",
+                ]
+            )
+
+        check_run(
+            [
+                "openssl",
+                "ca",
+                *ca_args,
+                "-gencrl",
+                "-crldays",
+                str((not_after - now).days),
+                "-out",
+                crl_file,
+            ]
+        )
+
+    return [children.name for children in revoked]
+
+
 def generate_pkcs12_file(
     chain: CertificateConfig, cert_file: Path, key_file: Path, out_file: Path
 ) -> None:
@@ -22,13 +22,17 @@ from parsec._parsec_pyi.async_enrollment import (
 )
 from parsec._parsec_pyi.certif import (
     DeviceCertificate,
+    GroupCertificate,
+    GroupMemberCertificate,
     HashAlgorithm,
     PrivateKeyAlgorithm,
     RealmArchivingCertificate,
     RealmArchivingConfiguration,
+    RealmGroupRoleCertificate,
     RealmKeyRotationCertificate,
     RealmNameCertificate,
     RealmRoleCertificate,
+    RevokedDeviceCertificate,
     RevokedUserCertificate,
     SecretKeyAlgorithm,
     SequesterAuthorityCertificate,
@@ -77,6 +81,7 @@ from parsec._parsec_pyi.ids import (
     DeviceLabel,
     EmailAddress,
     GreetingAttemptID,
+    GroupID,
     HumanHandle,
     OrganizationID,
     PKIEnrollmentID,
@@ -139,13 +144,17 @@ __all__ = [
     "AsyncEnrollmentSubmitPayload",
     # Certif
     "DeviceCertificate",
+    "GroupCertificate",
+    "GroupMemberCertificate",
     "HashAlgorithm",
     "PrivateKeyAlgorithm",
     "RealmArchivingCertificate",
     "RealmArchivingConfiguration",
+    "RealmGroupRoleCertificate",
     "RealmKeyRotationCertificate",
     "RealmNameCertificate",
     "RealmRoleCertificate",
+    "RevokedDeviceCertificate",
     "RevokedUserCertificate",
     "SecretKeyAlgorithm",
     "SequesterAuthorityCertificate",
@@ -193,6 +202,7 @@ __all__ = [
     "EmailAddress",
     "PKIEnrollmentID",
     "GreetingAttemptID",
+    "GroupID",
     "HumanHandle",
     "OrganizationID",
     "SequesterServiceID",
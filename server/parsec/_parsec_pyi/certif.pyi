@@ -13,6 +13,7 @@ from parsec._parsec_pyi.enumerate import DevicePurpose, RealmRole, UserProfile
 from parsec._parsec_pyi.ids import (
     DeviceID,
     DeviceLabel,
+    GroupID,
     HumanHandle,
     SequesterServiceID,
     UserID,
@@ -154,6 +155,96 @@ class RevokedUserCertificate:
         """Raise `ValueError` if invalid"""
         ...
 
+class RevokedDeviceCertificate:
+    def __init__(
+        self, author: DeviceID, timestamp: DateTime, user_id: UserID, device_id: DeviceID
+    ) -> None: ...
+    @property
+    def author(self) -> DeviceID: ...
+    @property
+    def timestamp(self) -> DateTime: ...
+    @property
+    def user_id(self) -> UserID: ...
+    @property
+    def device_id(self) -> DeviceID: ...
+    @classmethod
+    def verify_and_load(
+        cls,
+        signed: bytes,
+        author_verify_key: VerifyKey,
+        expected_author: DeviceID,
+        expected_device: DeviceID | None = None,
+    ) -> RevokedDeviceCertificate:
+        """Raise `ValueError` if invalid"""
+        ...
+    def dump_and_sign(self, author_signkey: SigningKey) -> bytes: ...
+    @classmethod
+    def unsecure_load(cls, signed: bytes) -> RevokedDeviceCertificate:
+        """Raise `ValueError` if invalid"""
+        ...
+
+class GroupCertificate:
+    def __init__(
+        self, author: DeviceID, timestamp: DateTime, group_id: GroupID, name: str
+    ) -> None: ...
+    @property
+    def author(self) -> DeviceID: ...
+    @property
+    def timestamp(self) -> DateTime: ...
+    @property
+    def group_id(self) -> GroupID: ...
+    @property
+    def name(self) -> str: ...
+    @classmethod
+    def verify_and_load(
+        cls,
+        signed: bytes,
+        author_verify_key: VerifyKey,
+        expected_author: DeviceID,
+    ) -> GroupCertificate:
+        """Raise `ValueError` if invalid"""
+        ...
+    def dump_and_sign(self, author_signkey: SigningKey) -> bytes: ...
+    @classmethod
+    def unsecure_load(cls, signed: bytes) -> GroupCertificate:
+        """Raise `ValueError` if invalid"""
+        ...
+
+class GroupMemberCertificate:
+    def __init__(
+        self,
+        author: DeviceID,
+        timestamp: DateTime,
+        group_id: GroupID,
+        user_id: UserID,
+        is_member: bool,
+    ) -> None: ...
+    @property
+    def author(self) -> DeviceID: ...
+    @property
+    def timestamp(self) -> DateTime: ...
+    @property
+    def group_id(self) -> GroupID: ...
+    @property
+    def user_id(self) -> UserID: ...
+    @property
+    def is_member(self) -> bool: ...
+    @classmethod
+    def verify_and_load(
+        cls,
+        signed: bytes,
+        author_verify_key: VerifyKey,
+        expected_author: DeviceID,
+        expected_user: UserID | None = None,
+    ) -> GroupMemberCertificate:
+        """Raise `ValueError` if invalid"""
+        ...
+    def dump_and_sign(self, author_signkey: SigningKey) -> bytes: ...
+    @classmethod
+    def unsecure_load(cls, signed: bytes) -> GroupMemberCertificate:
+        """Raise `ValueError` if invalid"""
+        ...
+
 class UserUpdateCertificate:
     def __init__(
         self,
@@ -194,6 +285,7 @@ class RealmRoleCertificate:
         realm_id: VlobID,
         user_id: UserID,
         role: RealmRole | None,
+        group_id: GroupID | None = None,
     ) -> None: ...
     @property
     def author(self) -> DeviceID: ...
@@ -205,6 +297,8 @@ class RealmRoleCertificate:
     def user_id(self) -> UserID: ...
     @property
     def role(self) -> RealmRole | None: ...
+    @property
+    def group_id(self) -> GroupID | None: ...
     @classmethod
     def verify_and_load(
         cls,
@@ -222,6 +316,41 @@ class RealmRoleCertificate:
         """Raise `ValueError` if invalid"""
         ...
 
+class RealmGroupRoleCertificate:
+    def __init__(
+        self,
+        author: DeviceID,
+        timestamp: DateTime,
+        realm_id: VlobID,
+        group_id: GroupID,
+        role: RealmRole | None,
+    ) -> None: ...
+    @property
+    def author(self) -> DeviceID: ...
+    @property
+    def timestamp(self) -> DateTime: ...
+    @property
+    def realm_id(self) -> VlobID: ...
+    @property
+    def group_id(self) -> GroupID: ...
+    @property
+    def role(self) -> RealmRole | None: ...
+    @classmethod
+    def verify_and_load(
+        cls,
+        signed: bytes,
+        author_verify_key: VerifyKey,
+        expected_author: DeviceID,
+        expected_realm: VlobID | None = None,
+    ) -> RealmGroupRoleCertificate:
+        """Raise `ValueError` if invalid"""
+        ...
+    def dump_and_sign(self, author_signkey: SigningKey) -> bytes: ...
+    @classmethod
+    def unsecure_load(cls, signed: bytes) -> RealmGroupRoleCertificate:
+        """Raise `ValueError` if invalid"""
+        ...
+
 class RealmNameCertificate:
     def __init__(
         self,
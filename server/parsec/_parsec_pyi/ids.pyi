@@ -212,6 +212,27 @@ class SequesterServiceID:
     @property
     def hyphenated(self) -> str: ...
 
+class GroupID:
+    def __lt__(self, other: GroupID) -> bool: ...
+    def __gt__(self, other: GroupID) -> bool: ...
+    def __le__(self, other: GroupID) -> bool: ...
+    def __ge__(self, other: GroupID) -> bool: ...
+    def __hash__(self) -> int: ...
+    @classmethod
+    def from_bytes(cls, bytes: bytes) -> GroupID: ...
+    @classmethod
+    def from_hex(cls, hex: str) -> GroupID: ...
+    @classmethod
+    def new(cls) -> GroupID: ...
+    @property
+    def bytes(self) -> bytes: ...
+    @property
+    def hex(self) -> str: ...
+    @property
+    def int(self) -> int: ...
+    @property
+    def hyphenated(self) -> str: ...
+
 class PKIEnrollmentID:
     @classmethod
     def from_bytes(cls, bytes: bytes) -> PKIEnrollmentID: ...
@@ -9,6 +9,8 @@ from parsec._parsec_pyi.time import DateTime
 
 class PkiSignatureAlgorithm:
     RSASSA_PSS_SHA256: PkiSignatureAlgorithm
+    ECDSA_P256_SHA256: PkiSignatureAlgorithm
+    ECDSA_P384_SHA384: PkiSignatureAlgorithm
 
     @classmethod
     def from_str(cls, value: str) -> PkiSignatureAlgorithm: ...
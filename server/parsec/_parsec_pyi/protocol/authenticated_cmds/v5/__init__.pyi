@@ -12,7 +12,10 @@ from . import (
     block_read,
     certificate_get,
     device_create,
+    device_revoke,
     events_listen,
+    group_create,
+    group_update_member,
     invite_cancel,
     invite_complete,
     invite_greeter_cancel_greeting_attempt,
@@ -32,6 +35,7 @@ from . import (
     realm_share,
     realm_unshare,
     realm_update_archiving,
+    realm_update_group_role,
     shamir_recovery_delete,
     shamir_recovery_setup,
     totp_create_opaque_key,
@@ -59,7 +63,10 @@ class AnyCmdReq:
         | block_read.Req
         | certificate_get.Req
         | device_create.Req
+        | device_revoke.Req
         | events_listen.Req
+        | group_create.Req
+        | group_update_member.Req
         | invite_cancel.Req
         | invite_complete.Req
         | invite_greeter_cancel_greeting_attempt.Req
@@ -79,6 +86,7 @@ class AnyCmdReq:
         | realm_share.Req
         | realm_unshare.Req
         | realm_update_archiving.Req
+        | realm_update_group_role.Req
         | shamir_recovery_delete.Req
         | shamir_recovery_setup.Req
         | totp_create_opaque_key.Req
@@ -103,7 +111,10 @@ __all__ = [
     "block_read",
     "certificate_get",
     "device_create",
+    "device_revoke",
     "events_listen",
+    "group_create",
+    "group_update_member",
     "invite_cancel",
     "invite_complete",
     "invite_greeter_cancel_greeting_attempt",
@@ -123,6 +134,7 @@ __all__ = [
     "realm_share",
     "realm_unshare",
     "realm_update_archiving",
+    "realm_update_group_role",
     "shamir_recovery_delete",
     "shamir_recovery_setup",
     "totp_create_opaque_key",
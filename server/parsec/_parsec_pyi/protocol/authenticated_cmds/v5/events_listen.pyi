@@ -29,10 +29,13 @@ class APIEventOrganizationConfig(APIEvent):
         active_users_limit: ActiveUsersLimit,
         sse_keepalive_seconds: int | None,
         realm_minimum_archiving_period_before_deletion: int | None,
+        pki_crls: list[bytes] | None,
     ) -> None: ...
     @property
     def active_users_limit(self) -> ActiveUsersLimit: ...
     @property
+    def pki_crls(self) -> list[bytes] | None: ...
+    @property
     def realm_minimum_archiving_period_before_deletion(self) -> int | None: ...
     @property
     def sse_keepalive_seconds(self) -> int | None: ...
@@ -422,6 +422,83 @@ async def test_openbao_auth_token_lookup_self(request: Request):
     }
 
 
+def _openbao_auth_response(vault_token: str) -> dict[str, object]:
+    # See https://openbao.org/api-docs/auth/approle/#sample-response-7
+    return {
+        "request_id": str(uuid.uuid4()),
+        "lease_id": "",
+        "renewable": False,
+        "lease_duration": 0,
+        "data": None,
+        "wrap_info": None,
+        "warnings": None,
+        "auth": {
+            "client_token": vault_token,
+            "accessor": "8609694a-cdbc-db9b-d345-e782dbb562ed",
+            "policies": ["default"],
+            "token_policies": ["default"],
+            "metadata": {},
+            "lease_duration": 3600,
+            "renewable": True,
+            "entity_id": _openbao_entity_id_from_vault_token_value(vault_token),
+            "token_type": "service",
+            "orphan": True,
+        },
+    }
+
+
+@testbed_router.post("/testbed/mock/openbao/v1/auth/token/renew-self")
+async def test_openbao_auth_token_renew_self(request: Request):
+    vault_token = request.headers.get("x-vault-token", "")
+    if not _openbao_entity_id_from_vault_token_value(vault_token):
+        return Response(status_code=403)
+
+    return _openbao_auth_response(vault_token)
+
+
+def _openbao_login(
+    auth_mount: str, identity: object, secret: object
+) -> Response | dict[str, object]:
+    if not isinstance(identity, str) or not isinstance(secret, str):
+        return Response("Bad/missing credentials fields", status_code=400)
+
+    # Any credentials are accepted, except the ones with `invalid` as secret
+    if secret == "invalid":
+        return JSONResponse(status_code=400, content={"errors": ["invalid credentials"]})
+
+    # The same credentials always give the same token (hence the same entity ID)
+    vault_token = "s." + hashlib.sha256(f"{auth_mount}:{identity}".encode()).hexdigest()[:24]
+    return _openbao_auth_response(vault_token)
+
+
+@testbed_router.post("/testbed/mock/openbao/v1/auth/{auth_mount}/login")
+async def test_openbao_login(request: Request, auth_mount: str):
+    try:
+        req = await request.json()
+    except ValueError:
+        return Response("Bad JSON body", status_code=400)
+
+    # AppRole and JWT share the same login route, only the payload differs
+    if "role_id" in req:
+        # See https://openbao.org/api-docs/auth/approle/#login-with-approle
+        return _openbao_login(auth_mount, req.get("role_id"), req.get("secret_id"))
+    else:
+        # See https://openbao.org/api-docs/auth/jwt/#jwt-login
+        # (the JWT is considered to identify the user on its own)
+        return _openbao_login(auth_mount, req.get("jwt"), req.get("jwt"))
+
+
+@testbed_router.post("/testbed/mock/openbao/v1/auth/{auth_mount}/login/{username}")
+async def test_openbao_userpass_login(request: Request, auth_mount: str, username: str):
+    try:
+        req = await request.json()
+    except ValueError:
+        return Response("Bad JSON body", status_code=400)
+
+    # See https://openbao.org/api-docs/auth/userpass/#login
+    return _openbao_login(auth_mount, username, req.get("password"))
+
+
 @testbed_router.get("/testbed/mock/openbao/v1/secret/data/{secret_path:path}")
 async def test_openbao_read_secret(secret_path: str, request: Request):
     testbed: TestbedBackend = request.app.state.testbed
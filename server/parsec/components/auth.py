@@ -23,6 +23,7 @@ from parsec.components.events import EventBus
 from parsec.config import BackendConfig
 from parsec.events import (
     Event,
+    EventDeviceRevoked,
     EventOrganizationTosUpdated,
     EventUserRevokedOrFrozen,
     EventUserUnfrozen,
@@ -234,6 +235,9 @@ class BaseAuthComponent:
                     for ((org_id, device_id), v) in self._device_cache.items()
                     if org_id != event.organization_id or v.user_id != event.user_id
                 }
+            # A revoked device must no longer be able to authenticate.
+            case EventDeviceRevoked():
+                self._device_cache.pop((event.organization_id, event.device_id), None)
             # If TOS has changed they must be re-accepted by the users, so we clear the
             # cache given that the TOS acceptance is checked on cache miss.
             case EventOrganizationTosUpdated():
@@ -27,6 +27,7 @@ from parsec.config import AccountConfig, BackendConfig
 from parsec.events import (
     ClientBroadcastableEvent,
     Event,
+    EventDeviceRevoked,
     EventOrganizationConfig,
     EventOrganizationExpired,
     EventOrganizationTosUpdated,
@@ -335,6 +336,14 @@ class BaseEventsComponent:
                     ):
                         registered.cancel_scope.cancel()
 
+            case EventDeviceRevoked():
+                for registered in self._registered_clients.values():
+                    if (
+                        registered.organization_id == event.organization_id
+                        and registered.device_id == event.device_id
+                    ):
+                        registered.cancel_scope.cancel()
+
             case EventOrganizationTosUpdated():
                 # All users in the organization must re-accept the TOS before being
                 # able to communicate with the server again.
@@ -105,7 +105,9 @@ class MemoryAuthComponent(BaseAuthComponent):
             return AuthAuthenticatedAuthBadOutcome.DEVICE_NOT_FOUND
         user_id = device.cooked.user_id
         user = org.users[user_id]
-        if user.is_revoked:
+        # A revoked device is handled the same way as a revoked user: from the
+        # client's point of view, in both cases it can no longer be used.
+        if user.is_revoked or device.is_revoked:
             return AuthAuthenticatedAuthBadOutcome.USER_REVOKED
         if user.is_frozen:
             return AuthAuthenticatedAuthBadOutcome.USER_FROZEN
@@ -26,6 +26,9 @@ from parsec._parsec import (
     EmailAddress,
     GreeterOrClaimer,
     GreetingAttemptID,
+    GroupCertificate,
+    GroupID,
+    GroupMemberCertificate,
     HashDigest,
     HumanHandle,
     InvitationStatus,
@@ -35,10 +38,12 @@ from parsec._parsec import (
     PkiSignatureAlgorithm,
     RealmArchivingCertificate,
     RealmArchivingConfiguration,
+    RealmGroupRoleCertificate,
     RealmKeyRotationCertificate,
     RealmNameCertificate,
     RealmRole,
     RealmRoleCertificate,
+    RevokedDeviceCertificate,
     RevokedUserCertificate,
     SecretKey,
     SequesterAuthorityCertificate,
@@ -71,12 +76,16 @@ type CommonTopicCertificate = (
     | UserUpdateCertificate
     | SequesterServiceCertificate
     | RevokedUserCertificate
+    | RevokedDeviceCertificate
+    | GroupCertificate
+    | GroupMemberCertificate
 )
 type SequesterTopicCertificate = (
     SequesterAuthorityCertificate | SequesterServiceCertificate | SequesterRevokedServiceCertificate
 )
 type RealmTopicCertificate = (
     RealmRoleCertificate
+    | RealmGroupRoleCertificate
     | RealmKeyRotationCertificate
     | RealmNameCertificate
     | RealmArchivingCertificate
@@ -163,6 +172,7 @@ class MemoryOrganization:
     invitations: dict[AccessToken, MemoryInvitation] = field(default_factory=dict)
     greeting_attempts: dict[GreetingAttemptID, MemoryGreetingAttempt] = field(default_factory=dict)
     realms: dict[VlobID, MemoryRealm] = field(default_factory=dict)
+    groups: dict[GroupID, MemoryGroup] = field(default_factory=dict)
     blocks: dict[BlockID, MemoryBlock] = field(default_factory=dict)
     block_store: dict[BlockID, bytes] = field(default_factory=dict, repr=False)
     # The user id is the author of the shamir recovery
@@ -352,6 +362,29 @@ class MemoryOrganization:
                     (device.cooked.timestamp, 1, device.device_certificate, device.cooked)
                 )
 
+            if device.is_revoked:
+                assert device.cooked_revoked is not None
+                assert device.revoked_device_certificate is not None
+                common_certificates_unordered.append(
+                    (
+                        device.cooked_revoked.timestamp,
+                        1,
+                        device.revoked_device_certificate,
+                        device.cooked_revoked,
+                    )
+                )
+
+        for group in self.groups.values():
+            common_certificates_unordered.append(
+                (group.cooked.timestamp, 1, group.group_certificate, group.cooked)
+            )
+            common_certificates_unordered.extend(
+                [
+                    (member.cooked.timestamp, 1, member.group_member_certificate, member.cooked)
+                    for member in group.members
+                ]
+            )
+
         for ts, _, raw, cooked in sorted(common_certificates_unordered, key=lambda x: (x[0], x[1])):
             yield (ts, raw, cooked)
 
@@ -416,6 +449,14 @@ class MemoryOrganization:
             (archiving.cooked.timestamp, archiving.realm_archiving_certificate, archiving.cooked)
             for archiving in realm.archivings
         ]
+        realm_certificates_unordered += [
+            (
+                group_role.cooked.timestamp,
+                group_role.realm_group_role_certificate,
+                group_role.cooked,
+            )
+            for group_role in realm.group_roles
+        ]
 
         yield from sorted(realm_certificates_unordered, key=lambda x: x[0])
 
@@ -559,6 +600,13 @@ class MemoryDevice:
     cooked: DeviceCertificate
     device_certificate: bytes = field(repr=False)
     redacted_device_certificate: bytes = field(repr=False)
+    # None if not yet revoked
+    cooked_revoked: RevokedDeviceCertificate | None = None
+    revoked_device_certificate: bytes | None = field(default=None, repr=False)
+
+    @property
+    def is_revoked(self) -> bool:
+        return self.revoked_device_certificate is not None
 
 
 class MemoryInvitationDeletedReason(Enum):
@@ -811,6 +859,7 @@ class MemoryRealm:
     key_rotations: list[MemoryRealmKeyRotation] = field(default_factory=list)
     renames: list[MemoryRealmRename] = field(default_factory=list)
     archivings: list[MemoryRealmArchiving] = field(default_factory=list)
+    group_roles: list[MemoryRealmGroupRole] = field(default_factory=list)
     last_vlob_timestamp: DateTime | None = None
     vlobs: dict[VlobID, list[MemoryVlobAtom]] = field(default_factory=dict)
     is_deleted: bool = False
@@ -821,6 +870,12 @@ class MemoryRealm:
                 return role.cooked.role
         return None
 
+    def get_current_group_role_for(self, group_id: GroupID) -> RealmRole | None:
+        for group_role in reversed(self.group_roles):
+            if group_role.cooked.group_id == group_id:
+                return group_role.cooked.role
+        return None
+
     @property
     def is_archived_or_deletion_planned(self) -> bool:
         if not self.archivings:
@@ -869,6 +924,31 @@ class MemoryRealmUserRole:
     realm_role_certificate: bytes = field(repr=False)
 
 
+@dataclass(slots=True)
+class MemoryRealmGroupRole:
+    cooked: RealmGroupRoleCertificate
+    realm_group_role_certificate: bytes = field(repr=False)
+
+
+@dataclass(slots=True)
+class MemoryGroup:
+    cooked: GroupCertificate
+    group_certificate: bytes = field(repr=False)
+    members: list[MemoryGroupMember] = field(default_factory=list)
+
+    def is_member(self, user_id: UserID) -> bool:
+        for member in reversed(self.members):
+            if member.cooked.user_id == user_id:
+                return member.cooked.is_member
+        return False
+
+
+@dataclass(slots=True)
+class MemoryGroupMember:
+    cooked: GroupMemberCertificate
+    group_member_certificate: bytes = field(repr=False)
+
+
 @dataclass(slots=True)
 class MemoryRealmUserChange:
     user: UserID
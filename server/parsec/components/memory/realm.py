@@ -9,6 +9,7 @@ from parsec._parsec import (
     DeviceID,
     OrganizationID,
     RealmArchivingCertificate,
+    RealmGroupRoleCertificate,
     RealmKeyRotationCertificate,
     RealmNameCertificate,
     RealmRoleCertificate,
@@ -25,6 +26,7 @@ from parsec.components.memory.datamodel import (
     MemoryOrganization,
     MemoryRealm,
     MemoryRealmArchiving,
+    MemoryRealmGroupRole,
     MemoryRealmKeyRotation,
     MemoryRealmRename,
     MemoryRealmUserRole,
@@ -71,6 +73,8 @@ from parsec.components.realm import (
     RealmUnshareValidateBadOutcome,
     RealmUpdateArchivingStoreBadOutcome,
     RealmUpdateArchivingValidateBadOutcome,
+    RealmUpdateGroupRoleStoreBadOutcome,
+    RealmUpdateGroupRoleValidateBadOutcome,
     RejectedBySequesterService,
     SequesterServiceMismatch,
     SequesterServiceUnavailable,
@@ -80,6 +84,7 @@ from parsec.components.realm import (
     realm_share_validate,
     realm_unshare_validate,
     realm_update_archiving_validate,
+    realm_update_group_role_validate,
 )
 from parsec.components.sequester import SequesterServiceType
 from parsec.events import EventRealmCertificate
@@ -250,6 +255,9 @@ class MemoryRealmComponent(BaseRealmComponent):
             if user.is_revoked:
                 return RealmShareStoreBadOutcome.RECIPIENT_REVOKED
 
+            if certif.group_id is not None and certif.group_id not in org.groups:
+                return RealmShareStoreBadOutcome.GROUP_NOT_FOUND
+
             realm_topic = ("realm", certif.realm_id)
 
             async with org.topics_lock(write=[realm_topic]) as (realm_topic_last_timestamp,):
@@ -444,6 +452,104 @@ class MemoryRealmComponent(BaseRealmComponent):
 
                 return certif
 
+    @override
+    async def update_group_role(
+        self,
+        now: DateTime,
+        organization_id: OrganizationID,
+        author: DeviceID,
+        author_verify_key: VerifyKey,
+        realm_group_role_certificate: bytes,
+    ) -> (
+        RealmGroupRoleCertificate
+        | CertificateBasedActionIdempotentOutcome
+        | RealmUpdateGroupRoleValidateBadOutcome
+        | TimestampOutOfBallpark
+        | RealmUpdateGroupRoleStoreBadOutcome
+        | RequireGreaterTimestamp
+    ):
+        try:
+            org = self._data.organizations[organization_id]
+        except KeyError:
+            return RealmUpdateGroupRoleStoreBadOutcome.ORGANIZATION_NOT_FOUND
+        if org.is_expired:
+            return RealmUpdateGroupRoleStoreBadOutcome.ORGANIZATION_EXPIRED
+
+        async with org.topics_lock(read=["common"]) as (common_topic_last_timestamp,):
+            try:
+                author_device = org.devices[author]
+            except KeyError:
+                return RealmUpdateGroupRoleStoreBadOutcome.AUTHOR_NOT_FOUND
+            author_user_id = author_device.cooked.user_id
+
+            author_user = org.users[author_user_id]
+            if author_user.is_revoked:
+                return RealmUpdateGroupRoleStoreBadOutcome.AUTHOR_REVOKED
+
+            assert author_verify_key == author_device.cooked.verify_key
+            match realm_update_group_role_validate(
+                now=now,
+                expected_author=author,
+                author_verify_key=author_verify_key,
+                realm_group_role_certificate=realm_group_role_certificate,
+            ):
+                case RealmGroupRoleCertificate() as certif:
+                    pass
+                case error:
+                    return error
+
+            try:
+                realm = org.realms[certif.realm_id]
+            except KeyError:
+                return RealmUpdateGroupRoleStoreBadOutcome.REALM_NOT_FOUND
+
+            if realm.is_deleted:
+                return RealmUpdateGroupRoleStoreBadOutcome.REALM_DELETED
+
+            if certif.group_id not in org.groups:
+                return RealmUpdateGroupRoleStoreBadOutcome.GROUP_NOT_FOUND
+
+            realm_topic = ("realm", certif.realm_id)
+
+            async with org.topics_lock(write=[realm_topic]) as (realm_topic_last_timestamp,):
+                # Only an owner can give or remove a role to a group
+                if realm.get_current_role_for(author_user_id) != RealmRole.OWNER:
+                    return RealmUpdateGroupRoleStoreBadOutcome.AUTHOR_NOT_ALLOWED
+
+                if realm.get_current_group_role_for(certif.group_id) == certif.role:
+                    return CertificateBasedActionIdempotentOutcome(
+                        certificate_timestamp=realm_topic_last_timestamp
+                    )
+
+                # Ensure we are not breaking causality by adding a newer timestamp.
+
+                last_certificate = max(common_topic_last_timestamp, realm_topic_last_timestamp)
+                if last_certificate >= certif.timestamp:
+                    return RequireGreaterTimestamp(strictly_greater_than=last_certificate)
+
+                # All checks are good, now we do the actual insertion
+
+                org.per_topic_last_timestamp[realm_topic] = certif.timestamp
+
+                realm.group_roles.append(
+                    MemoryRealmGroupRole(
+                        cooked=certif,
+                        realm_group_role_certificate=realm_group_role_certificate,
+                    )
+                )
+
+                await self._event_bus.send(
+                    EventRealmCertificate(
+                        organization_id=organization_id,
+                        timestamp=certif.timestamp,
+                        realm_id=certif.realm_id,
+                        user_id=author_user_id,
+                        role_removed=False,
+                    )
+                )
+
+                return certif
+
     @override
     async def rename(
         self,
@@ -8,7 +8,10 @@ from parsec._parsec import (
     DeviceCertificate,
     DeviceID,
     EmailAddress,
+    GroupCertificate,
+    GroupMemberCertificate,
     OrganizationID,
+    RevokedDeviceCertificate,
     RevokedUserCertificate,
     UserCertificate,
     UserID,
@@ -22,6 +25,8 @@ from parsec.components.events import EventBus
 from parsec.components.memory.datamodel import (
     MemoryDatamodel,
     MemoryDevice,
+    MemoryGroup,
+    MemoryGroupMember,
     MemoryUser,
     MemoryUserProfileUpdate,
 )
@@ -32,6 +37,8 @@ from parsec.components.user import (
     UserAcceptTosBadOutcome,
     UserCreateDeviceStoreBadOutcome,
     UserCreateDeviceValidateBadOutcome,
+    UserCreateGroupStoreBadOutcome,
+    UserCreateGroupValidateBadOutcome,
     UserCreateUserStoreBadOutcome,
     UserCreateUserValidateBadOutcome,
     UserDump,
@@ -41,17 +48,25 @@ from parsec.components.user import (
     UserInfo,
     UserListActiveUsersBadOutcome,
     UserListFrozenUsersBadOutcome,
+    UserRevokeDeviceStoreBadOutcome,
+    UserRevokeDeviceValidateBadOutcome,
     UserRevokeUserStoreBadOutcome,
     UserRevokeUserValidateBadOutcome,
+    UserUpdateGroupMemberStoreBadOutcome,
+    UserUpdateGroupMemberValidateBadOutcome,
     UserUpdateUserStoreBadOutcome,
     UserUpdateUserValidateBadOutcome,
     user_create_device_validate,
+    user_create_group_validate,
     user_create_user_validate,
+    user_revoke_device_validate,
     user_revoke_user_validate,
+    user_update_group_member_validate,
     user_update_user_validate,
 )
 from parsec.events import (
     EventCommonCertificate,
+    EventDeviceRevoked,
     EventUserRevokedOrFrozen,
     EventUserUnfrozen,
     EventUserUpdated,
@@ -354,6 +369,294 @@ class MemoryUserComponent(BaseUserComponent):
 
                 return certif
 
+    @override
+    async def revoke_device(
+        self,
+        now: DateTime,
+        organization_id: OrganizationID,
+        author: DeviceID,
+        author_verify_key: VerifyKey,
+        revoked_device_certificate: bytes,
+    ) -> (
+        RevokedDeviceCertificate
+        | CertificateBasedActionIdempotentOutcome
+        | UserRevokeDeviceValidateBadOutcome
+        | UserRevokeDeviceStoreBadOutcome
+        | TimestampOutOfBallpark
+        | RequireGreaterTimestamp
+    ):
+        try:
+            org = self._data.organizations[organization_id]
+        except KeyError:
+            return UserRevokeDeviceStoreBadOutcome.ORGANIZATION_NOT_FOUND
+        if org.is_expired:
+            return UserRevokeDeviceStoreBadOutcome.ORGANIZATION_EXPIRED
+
+        async with org.topics_lock(write=["common"]) as (common_topic_last_timestamp,):
+            try:
+                author_device = org.devices[author]
+            except KeyError:
+                return UserRevokeDeviceStoreBadOutcome.AUTHOR_NOT_FOUND
+            author_user_id = author_device.cooked.user_id
+
+            author_user = org.users[author_user_id]
+            if author_user.is_revoked:
+                return UserRevokeDeviceStoreBadOutcome.AUTHOR_REVOKED
+
+            match user_revoke_device_validate(
+                now=now,
+                expected_author_device_id=author,
+                author_verify_key=author_verify_key,
+                revoked_device_certificate=revoked_device_certificate,
+            ):
+                case RevokedDeviceCertificate() as certif:
+                    pass
+                case error:
+                    return error
+
+            # A device can be revoked by its own user (from another device) or by an admin
+            if (
+                author_user_id != certif.user_id
+                and author_user.current_profile != UserProfile.ADMIN
+            ):
+                return UserRevokeDeviceStoreBadOutcome.AUTHOR_NOT_ALLOWED
+
+            try:
+                target_device = org.devices[certif.device_id]
+            except KeyError:
+                return UserRevokeDeviceStoreBadOutcome.DEVICE_NOT_FOUND
+
+            if target_device.cooked.user_id != certif.user_id:
+                return UserRevokeDeviceStoreBadOutcome.USER_MISMATCH
+
+            # Revoking a device of a revoked user makes no sense
+            if org.users[certif.user_id].is_revoked:
+                return UserRevokeDeviceStoreBadOutcome.DEVICE_NOT_FOUND
+
+            if target_device.is_revoked:
+                assert target_device.cooked_revoked is not None
+                return CertificateBasedActionIdempotentOutcome(
+                    certificate_timestamp=target_device.cooked_revoked.timestamp
+                )
+
+            # Ensure we are not breaking causality by adding a newer timestamp.
+            # Given a revoked device is not allowed to modify a realm, we must check timestamp on:
+            # - The common topic
+            # - For each realm the device's user is part of: the realm topic
+            # - For each realm the device's user is part of: the last vlob
+
+            realms_user_is_part_of = [
+                realm
+                for realm in org.realms.values()
+                if realm.get_current_role_for(certif.user_id) is not None
+            ]
+
+            realm_topics_user_is_part_of: list[tuple[Literal["realm"], VlobID]] = [
+                ("realm", realm.realm_id) for realm in realms_user_is_part_of
+            ]
+            async with org.topics_lock(
+                read=realm_topics_user_is_part_of
+            ) as realm_topics_last_timestamp:
+                last_timestamp = max(
+                    (
+                        common_topic_last_timestamp,
+                        *realm_topics_last_timestamp,
+                        *(
+                            ts
+                            for realm in realms_user_is_part_of
+                            if (ts := realm.last_vlob_timestamp) is not None
+                        ),
+                    )
+                )
+
+                if last_timestamp >= certif.timestamp:
+                    return RequireGreaterTimestamp(strictly_greater_than=last_timestamp)
+
+                # All checks are good, now we do the actual insertion
+
+                org.per_topic_last_timestamp["common"] = certif.timestamp
+
+                target_device.revoked_device_certificate = revoked_device_certificate
+                target_device.cooked_revoked = certif
+
+                await self._event_bus.send(
+                    EventCommonCertificate(
+                        organization_id=organization_id,
+                        timestamp=certif.timestamp,
+                    )
+                )
+                await self._event_bus.send(
+                    EventDeviceRevoked(
+                        organization_id=organization_id,
+                        device_id=certif.device_id,
+                    )
+                )
+
+                return certif
+
+    @override
+    async def create_group(
+        self,
+        now: DateTime,
+        organization_id: OrganizationID,
+        author: DeviceID,
+        author_verify_key: VerifyKey,
+        group_certificate: bytes,
+    ) -> (
+        GroupCertificate
+        | CertificateBasedActionIdempotentOutcome
+        | UserCreateGroupValidateBadOutcome
+        | UserCreateGroupStoreBadOutcome
+        | TimestampOutOfBallpark
+        | RequireGreaterTimestamp
+    ):
+        try:
+            org = self._data.organizations[organization_id]
+        except KeyError:
+            return UserCreateGroupStoreBadOutcome.ORGANIZATION_NOT_FOUND
+        if org.is_expired:
+            return UserCreateGroupStoreBadOutcome.ORGANIZATION_EXPIRED
+
+        async with org.topics_lock(write=["common"]) as (common_topic_last_timestamp,):
+            try:
+                author_device = org.devices[author]
+            except KeyError:
+                return UserCreateGroupStoreBadOutcome.AUTHOR_NOT_FOUND
+            author_user = org.users[author_device.cooked.user_id]
+            if author_user.is_revoked:
+                return UserCreateGroupStoreBadOutcome.AUTHOR_REVOKED
+            if author_user.current_profile != UserProfile.ADMIN:
+                return UserCreateGroupStoreBadOutcome.AUTHOR_NOT_ALLOWED
+
+            match user_create_group_validate(
+                now=now,
+                expected_author_device_id=author,
+                author_verify_key=author_verify_key,
+                group_certificate=group_certificate,
+            ):
+                case GroupCertificate() as certif:
+                    pass
+                case error:
+                    return error
+
+            existing_group = org.groups.get(certif.group_id)
+            if existing_group is not None:
+                return CertificateBasedActionIdempotentOutcome(
+                    certificate_timestamp=existing_group.cooked.timestamp
+                )
+
+            # Ensure we are not breaking causality by adding a newer timestamp.
+
+            if common_topic_last_timestamp >= certif.timestamp:
+                return RequireGreaterTimestamp(strictly_greater_than=common_topic_last_timestamp)
+
+            # All checks are good, now we do the actual insertion
+
+            org.per_topic_last_timestamp["common"] = certif.timestamp
+
+            org.groups[certif.group_id] = MemoryGroup(
+                cooked=certif,
+                group_certificate=group_certificate,
+            )
+
+            await self._event_bus.send(
+                EventCommonCertificate(
+                    organization_id=organization_id,
+                    timestamp=certif.timestamp,
+                )
+            )
+
+            return certif
+
+    @override
+    async def update_group_member(
+        self,
+        now: DateTime,
+        organization_id: OrganizationID,
+        author: DeviceID,
+        author_verify_key: VerifyKey,
+        group_member_certificate: bytes,
+    ) -> (
+        GroupMemberCertificate
+        | CertificateBasedActionIdempotentOutcome
+        | UserUpdateGroupMemberValidateBadOutcome
+        | UserUpdateGroupMemberStoreBadOutcome
+        | TimestampOutOfBallpark
+        | RequireGreaterTimestamp
+    ):
+        try:
+            org = self._data.organizations[organization_id]
+        except KeyError:
+            return UserUpdateGroupMemberStoreBadOutcome.ORGANIZATION_NOT_FOUND
+        if org.is_expired:
+            return UserUpdateGroupMemberStoreBadOutcome.ORGANIZATION_EXPIRED
+
+        async with org.topics_lock(write=["common"]) as (common_topic_last_timestamp,):
+            try:
+                author_device = org.devices[author]
+            except KeyError:
+                return UserUpdateGroupMemberStoreBadOutcome.AUTHOR_NOT_FOUND
+            author_user = org.users[author_device.cooked.user_id]
+            if author_user.is_revoked:
+                return UserUpdateGroupMemberStoreBadOutcome.AUTHOR_REVOKED
+            if author_user.current_profile != UserProfile.ADMIN:
+                return UserUpdateGroupMemberStoreBadOutcome.AUTHOR_NOT_ALLOWED
+
+            match user_update_group_member_validate(
+                now=now,
+                expected_author_device_id=author,
+                author_verify_key=author_verify_key,
+                group_member_certificate=group_member_certificate,
+            ):
+                case GroupMemberCertificate() as certif:
+                    pass
+                case error:
+                    return error
+
+            try:
+                group = org.groups[certif.group_id]
+            except KeyError:
+                return UserUpdateGroupMemberStoreBadOutcome.GROUP_NOT_FOUND
+
+            try:
+                user = org.users[certif.user_id]
+            except KeyError:
+                return UserUpdateGroupMemberStoreBadOutcome.USER_NOT_FOUND
+
+            # A revoked user can still be removed from a group
+            if certif.is_member and user.is_revoked:
+                return UserUpdateGroupMemberStoreBadOutcome.USER_REVOKED
+
+            if group.is_member(certif.user_id) == certif.is_member:
+                return CertificateBasedActionIdempotentOutcome(
+                    certificate_timestamp=common_topic_last_timestamp
+                )
+
+            # Ensure we are not breaking causality by adding a newer timestamp.
+
+            if common_topic_last_timestamp >= certif.timestamp:
+                return RequireGreaterTimestamp(strictly_greater_than=common_topic_last_timestamp)
+
+            # All checks are good, now we do the actual insertion
+
+            org.per_topic_last_timestamp["common"] = certif.timestamp
+
+            group.members.append(
+                MemoryGroupMember(
+                    cooked=certif,
+                    group_member_certificate=group_member_certificate,
+                )
+            )
+
+            await self._event_bus.send(
+                EventCommonCertificate(
+                    organization_id=organization_id,
+                    timestamp=certif.timestamp,
+                )
+            )
+
+            return certif
+
     @override
     async def update_user(
         self,
@@ -96,7 +96,8 @@ my_device AS (
     SELECT
         device._id,
         device.verify_key,
-        device.user_
+        device.user_,
+        device.revoked_on
     FROM device
     INNER JOIN my_organization ON device.organization = my_organization._id
     WHERE
@@ -143,6 +144,7 @@ SELECT
     (SELECT is_expired FROM my_organization) AS organization_is_expired,
     (SELECT _id FROM my_device) AS device_internal_id,
     (SELECT verify_key FROM my_device) AS device_verify_key,
+    (SELECT revoked_on FROM my_device) AS device_revoked_on,
     (SELECT user_id FROM my_user) AS user_id,
     (SELECT revoked_on FROM my_user) AS user_revoked_on,
     (SELECT frozen FROM my_user) AS user_is_frozen,
@@ -353,6 +355,16 @@ class PGAuthComponent(BaseAuthComponent):
             case _:
                 assert False, row
 
+        # A revoked device is handled the same way as a revoked user: from the
+        # client's point of view, in both cases it can no longer be used.
+        match row["device_revoked_on"]:
+            case None:
+                pass
+            case DateTime():
+                return AuthAuthenticatedAuthBadOutcome.USER_REVOKED
+            case _:
+                assert False, row
+
         match row["user_is_frozen"]:
             case False:
                 pass
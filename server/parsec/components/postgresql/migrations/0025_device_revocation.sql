@@ -0,0 +1,10 @@
+-- Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+-------------------------------------------------------
+--  Migration
+--
+-- Add device revocation to the `device` table
+-------------------------------------------------------
+ALTER TABLE device ADD COLUMN revoked_on TIMESTAMPTZ;
+ALTER TABLE device ADD COLUMN revoked_device_certificate BYTEA;
+ALTER TABLE device ADD COLUMN revoked_device_certifier INTEGER REFERENCES device (_id);
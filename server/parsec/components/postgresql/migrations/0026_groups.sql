@@ -0,0 +1,41 @@
+-- Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+-------------------------------------------------------
+--  Migration
+--
+-- Add user groups and the realm roles given to them
+-------------------------------------------------------
+CREATE TABLE group_ (
+    _id SERIAL PRIMARY KEY,
+    organization INTEGER REFERENCES organization (_id) NOT NULL,
+    group_id UUID NOT NULL,
+    group_certificate BYTEA NOT NULL,
+    certified_by INTEGER REFERENCES device (_id) NOT NULL,
+    certified_on TIMESTAMPTZ NOT NULL,
+
+    UNIQUE (organization, group_id)
+);
+
+
+CREATE TABLE group_member (
+    _id SERIAL PRIMARY KEY,
+    group_ INTEGER REFERENCES group_ (_id) NOT NULL,
+    user_ INTEGER REFERENCES user_ (_id) NOT NULL,
+    -- FALSE if the user has been removed from the group
+    is_member BOOLEAN NOT NULL,
+    certificate BYTEA NOT NULL,
+    certified_by INTEGER REFERENCES device (_id) NOT NULL,
+    certified_on TIMESTAMPTZ NOT NULL
+);
+
+
+CREATE TABLE realm_group_role (
+    _id SERIAL PRIMARY KEY,
+    realm INTEGER REFERENCES realm (_id) NOT NULL,
+    group_ INTEGER REFERENCES group_ (_id) NOT NULL,
+    -- NULL if access revocation
+    role REALM_ROLE,
+    certificate BYTEA NOT NULL,
+    certified_by INTEGER REFERENCES device (_id) NOT NULL,
+    certified_on TIMESTAMPTZ NOT NULL
+);
@@ -0,0 +1,10 @@
+-- Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+-------------------------------------------------------
+--  Migration
+--
+-- Add ECDSA algorithms to the `PKI_SIGNATURE_ALGORITHM` type
+-------------------------------------------------------
+
+ALTER TYPE PKI_SIGNATURE_ALGORITHM ADD VALUE 'ECDSA_P256_SHA256';
+ALTER TYPE PKI_SIGNATURE_ALGORITHM ADD VALUE 'ECDSA_P384_SHA384';
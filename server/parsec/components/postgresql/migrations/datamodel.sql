@@ -250,6 +250,12 @@ CREATE TABLE device (
     device_certifier INTEGER REFERENCES device (_id),
     created_on TIMESTAMPTZ NOT NULL,
     redacted_device_certificate BYTEA NOT NULL,
+    -- NULL if not yet revoked
+    revoked_on TIMESTAMPTZ,
+    -- NULL if not yet revoked
+    revoked_device_certificate BYTEA,
+    -- NULL if not yet revoked
+    revoked_device_certifier INTEGER REFERENCES device (_id),
 
     UNIQUE (organization, device_id)
 );
@@ -266,6 +272,35 @@ ADD CONSTRAINT fk_user_device_revoked_user_certifier FOREIGN KEY (
 
 ALTER TABLE profile ADD FOREIGN KEY (certified_by) REFERENCES device (_id);
 
+-------------------------------------------------------
+--  Group
+-------------------------------------------------------
+
+
+CREATE TABLE group_ (
+    _id SERIAL PRIMARY KEY,
+    organization INTEGER REFERENCES organization (_id) NOT NULL,
+    group_id UUID NOT NULL,
+    group_certificate BYTEA NOT NULL,
+    certified_by INTEGER REFERENCES device (_id) NOT NULL,
+    certified_on TIMESTAMPTZ NOT NULL,
+
+    UNIQUE (organization, group_id)
+);
+
+
+CREATE TABLE group_member (
+    _id SERIAL PRIMARY KEY,
+    group_ INTEGER REFERENCES group_ (_id) NOT NULL,
+    user_ INTEGER REFERENCES user_ (_id) NOT NULL,
+    -- FALSE if the user has been removed from the group
+    is_member BOOLEAN NOT NULL,
+    certificate BYTEA NOT NULL,
+    certified_by INTEGER REFERENCES device (_id) NOT NULL,
+    certified_on TIMESTAMPTZ NOT NULL
+);
+
+
 -------------------------------------------------------
 --  Shamir recovery
 -------------------------------------------------------
@@ -430,7 +465,9 @@ CREATE TABLE greeting_step (
 --  Async Enrollment
 -------------------------------------------------------
 
-CREATE TYPE PKI_SIGNATURE_ALGORITHM AS ENUM ('RSASSA_PSS_SHA256');
+CREATE TYPE PKI_SIGNATURE_ALGORITHM AS ENUM (
+    'RSASSA_PSS_SHA256', 'ECDSA_P256_SHA256', 'ECDSA_P384_SHA384'
+);
 
 -- Note this table only store leaf and intermediate certificates, since the
 -- root certificates are supposed to be already known (and trusted!) by the
@@ -555,6 +592,18 @@ CREATE TABLE realm_archiving (
 );
 
 
+CREATE TABLE realm_group_role (
+    _id SERIAL PRIMARY KEY,
+    realm INTEGER REFERENCES realm (_id) NOT NULL,
+    group_ INTEGER REFERENCES group_ (_id) NOT NULL,
+    -- NULL if access revocation
+    role REALM_ROLE,
+    certificate BYTEA NOT NULL,
+    certified_by INTEGER REFERENCES device (_id) NOT NULL,
+    certified_on TIMESTAMPTZ NOT NULL
+);
+
+
 -- TODO: Investigate which of those timestamp is really needed
 CREATE TABLE realm_user_change (
     _id SERIAL PRIMARY KEY,
@@ -8,6 +8,7 @@ from parsec._parsec import (
     DeviceID,
     OrganizationID,
     RealmArchivingCertificate,
+    RealmGroupRoleCertificate,
     RealmKeyRotationCertificate,
     RealmNameCertificate,
     RealmRole,
@@ -53,6 +54,7 @@ from parsec.components.postgresql.realm_rotate_key import realm_rotate_key
 from parsec.components.postgresql.realm_share import realm_share
 from parsec.components.postgresql.realm_unshare import realm_unshare
 from parsec.components.postgresql.realm_update_archiving import realm_update_archiving
+from parsec.components.postgresql.realm_update_group_role import realm_update_group_role
 from parsec.components.postgresql.utils import (
     no_transaction,
     transaction,
@@ -94,6 +96,8 @@ from parsec.components.realm import (
     RealmUnshareValidateBadOutcome,
     RealmUpdateArchivingStoreBadOutcome,
     RealmUpdateArchivingValidateBadOutcome,
+    RealmUpdateGroupRoleStoreBadOutcome,
+    RealmUpdateGroupRoleValidateBadOutcome,
     RejectedBySequesterService,
     SequesterServiceMismatch,
     SequesterServiceUnavailable,
@@ -192,6 +196,33 @@ class PGRealmComponent(BaseRealmComponent):
             realm_role_certificate,
         )
 
+    @override
+    @transaction
+    async def update_group_role(
+        self,
+        conn: AsyncpgConnection,
+        now: DateTime,
+        organization_id: OrganizationID,
+        author: DeviceID,
+        author_verify_key: VerifyKey,
+        realm_group_role_certificate: bytes,
+    ) -> (
+        RealmGroupRoleCertificate
+        | CertificateBasedActionIdempotentOutcome
+        | RealmUpdateGroupRoleValidateBadOutcome
+        | TimestampOutOfBallpark
+        | RealmUpdateGroupRoleStoreBadOutcome
+        | RequireGreaterTimestamp
+    ):
+        return await realm_update_group_role(
+            conn,
+            now,
+            organization_id,
+            author,
+            author_verify_key,
+            realm_group_role_certificate,
+        )
+
     @override
     @transaction
     async def rename(
@@ -56,6 +56,15 @@ my_recipient_realm_role AS (
     LIMIT 1
 ),
 
+-- Only relevant if the sharing is done on behalf of a group
+my_group AS (
+    SELECT _id
+    FROM group_
+    WHERE
+        organization = $organization_internal_id
+        AND group_id = $group_id
+),
+
 my_last_keys_bundle AS (
     SELECT
         _id,
@@ -77,6 +86,7 @@ SELECT
     (SELECT current_profile FROM my_recipient_user) AS recipient_current_profile,
     (SELECT is_revoked FROM my_recipient_user) AS recipient_is_revoked,
     (SELECT role FROM my_recipient_realm_role) AS recipient_current_role,
+    (SELECT _id FROM my_group) AS group_internal_id,
     (SELECT _id FROM my_last_keys_bundle) AS last_keys_bundle_internal_id,
     (SELECT key_index FROM my_last_keys_bundle) AS last_key_index,
     (SELECT last_vlob_timestamp FROM my_last_vlob_timestamp) AS last_vlob_timestamp
@@ -206,6 +216,7 @@ async def realm_share(
             organization_internal_id=db_common.organization_internal_id,
             realm_internal_id=db_realm.realm_internal_id,
             recipient_user_id=certif.user_id,
+            group_id=certif.group_id,
         )
     )
     assert row is not None
@@ -228,6 +239,9 @@ async def realm_share(
         case _:
             assert False, row
 
+    if certif.group_id is not None and row["group_internal_id"] is None:
+        return RealmShareStoreBadOutcome.GROUP_NOT_FOUND
+
     match row["recipient_current_profile"]:
         case str() as raw_recipient_current_profile:
             recipient_current_profile = UserProfile.from_str(raw_recipient_current_profile)
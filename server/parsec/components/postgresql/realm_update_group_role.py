@@ -0,0 +1,229 @@
+# Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+from __future__ import annotations
+
+from parsec._parsec import (
+    DateTime,
+    DeviceID,
+    OrganizationID,
+    RealmGroupRoleCertificate,
+    RealmRole,
+    VerifyKey,
+)
+from parsec.ballpark import RequireGreaterTimestamp, TimestampOutOfBallpark
+from parsec.components.postgresql import AsyncpgConnection
+from parsec.components.postgresql.events import send_signal
+from parsec.components.postgresql.queries import (
+    AuthAndLockCommonOnlyBadOutcome,
+    AuthAndLockCommonOnlyData,
+    LockRealmWriteRealmBadOutcome,
+    LockRealmWriteRealmData,
+    auth_and_lock_common_read,
+    lock_realm_write,
+)
+from parsec.components.postgresql.utils import (
+    Q,
+)
+from parsec.components.realm import (
+    CertificateBasedActionIdempotentOutcome,
+    RealmUpdateGroupRoleStoreBadOutcome,
+    RealmUpdateGroupRoleValidateBadOutcome,
+    realm_update_group_role_validate,
+)
+from parsec.events import EventRealmCertificate
+
+_q_get_info_for_checks = Q(
+    """
+WITH my_group AS (
+    SELECT _id
+    FROM group_
+    WHERE
+        organization = $organization_internal_id
+        AND group_id = $group_id
+),
+
+my_group_realm_role AS (
+    SELECT role
+    FROM realm_group_role
+    WHERE
+        group_ = (SELECT my_group._id FROM my_group)
+        AND realm = $realm_internal_id
+    ORDER BY certified_on DESC
+    LIMIT 1
+)
+
+SELECT
+    (SELECT _id FROM my_group) AS group_internal_id,
+    (SELECT role FROM my_group_realm_role) AS group_current_role
+"""
+)
+
+
+_q_update_group_role = Q(
+    """
+WITH new_realm_group_role AS (
+    INSERT INTO realm_group_role (
+        realm,
+        group_,
+        role,
+        certificate,
+        certified_by,
+        certified_on
+    ) VALUES (
+        $realm_internal_id,
+        $group_internal_id,
+        $role,
+        $certificate,
+        $certified_by,
+        $certified_on
+    )
+),
+
+updated_realm_topic AS (
+    UPDATE realm_topic
+    SET last_timestamp = $certified_on
+    WHERE
+        realm = $realm_internal_id
+        -- Sanity check
+        AND last_timestamp < $certified_on
+    RETURNING TRUE
+)
+
+SELECT COALESCE((SELECT * FROM updated_realm_topic), FALSE) AS update_realm_topic_ok
+"""
+)
+
+
+async def realm_update_group_role(
+    conn: AsyncpgConnection,
+    now: DateTime,
+    organization_id: OrganizationID,
+    author: DeviceID,
+    author_verify_key: VerifyKey,
+    realm_group_role_certificate: bytes,
+) -> (
+    RealmGroupRoleCertificate
+    | CertificateBasedActionIdempotentOutcome
+    | RealmUpdateGroupRoleValidateBadOutcome
+    | TimestampOutOfBallpark
+    | RealmUpdateGroupRoleStoreBadOutcome
+    | RequireGreaterTimestamp
+):
+    # 1) Read lock common topic
+
+    match await auth_and_lock_common_read(conn, organization_id, author):
+        case AuthAndLockCommonOnlyData() as db_common:
+            pass
+        case AuthAndLockCommonOnlyBadOutcome.ORGANIZATION_NOT_FOUND:
+            return RealmUpdateGroupRoleStoreBadOutcome.ORGANIZATION_NOT_FOUND
+        case AuthAndLockCommonOnlyBadOutcome.ORGANIZATION_EXPIRED:
+            return RealmUpdateGroupRoleStoreBadOutcome.ORGANIZATION_EXPIRED
+        case AuthAndLockCommonOnlyBadOutcome.AUTHOR_NOT_FOUND:
+            return RealmUpdateGroupRoleStoreBadOutcome.AUTHOR_NOT_FOUND
+        case AuthAndLockCommonOnlyBadOutcome.AUTHOR_REVOKED:
+            return RealmUpdateGroupRoleStoreBadOutcome.AUTHOR_REVOKED
+
+    # 2) Validate certificate
+
+    match realm_update_group_role_validate(
+        now=now,
+        expected_author=author,
+        author_verify_key=author_verify_key,
+        realm_group_role_certificate=realm_group_role_certificate,
+    ):
+        case RealmGroupRoleCertificate() as certif:
+            pass
+        case error:
+            return error
+
+    # 3) Write lock realm topic
+
+    match await lock_realm_write(
+        conn,
+        db_common.organization_internal_id,
+        db_common.user_internal_id,
+        certif.realm_id,
+    ):
+        case LockRealmWriteRealmData() as db_realm:
+            pass
+        case LockRealmWriteRealmBadOutcome.REALM_NOT_FOUND:
+            return RealmUpdateGroupRoleStoreBadOutcome.REALM_NOT_FOUND
+        case LockRealmWriteRealmBadOutcome.REALM_DELETED:
+            return RealmUpdateGroupRoleStoreBadOutcome.REALM_DELETED
+        case LockRealmWriteRealmBadOutcome.USER_NOT_IN_REALM:
+            return RealmUpdateGroupRoleStoreBadOutcome.AUTHOR_NOT_ALLOWED
+
+    # 4) Fetch from database what is needed and do the checks
+
+    row = await conn.fetchrow(
+        *_q_get_info_for_checks(
+            organization_internal_id=db_common.organization_internal_id,
+            realm_internal_id=db_realm.realm_internal_id,
+            group_id=certif.group_id,
+        )
+    )
+    assert row is not None
+
+    match row["group_internal_id"]:
+        case int() as group_internal_id:
+            pass
+        case None:
+            return RealmUpdateGroupRoleStoreBadOutcome.GROUP_NOT_FOUND
+        case _:
+            assert False, row
+
+    # Only an owner can give or remove a role to a group
+    if db_realm.realm_user_current_role != RealmRole.OWNER:
+        return RealmUpdateGroupRoleStoreBadOutcome.AUTHOR_NOT_ALLOWED
+
+    match row["group_current_role"]:
+        case str() as raw_group_current_role:
+            group_current_role = RealmRole.from_str(raw_group_current_role)
+        case None as group_current_role:
+            pass
+        case _:
+            assert False, row
+
+    if group_current_role == certif.role:
+        return CertificateBasedActionIdempotentOutcome(
+            certificate_timestamp=db_realm.last_realm_certificate_timestamp
+        )
+
+    # 5) Ensure we are not breaking causality by adding a newer timestamp.
+
+    last_certificate = max(
+        db_common.last_common_certificate_timestamp,
+        db_realm.last_realm_certificate_timestamp,
+    )
+    if last_certificate >= certif.timestamp:
+        return RequireGreaterTimestamp(strictly_greater_than=last_certificate)
+
+    # 6) All checks are good, now we do the actual insertion
+
+    update_realm_topic_ok = await conn.fetchval(
+        *_q_update_group_role(
+            realm_internal_id=db_realm.realm_internal_id,
+            group_internal_id=group_internal_id,
+            role=None if certif.role is None else certif.role.str,
+            certificate=realm_group_role_certificate,
+            certified_by=db_common.device_internal_id,
+            certified_on=certif.timestamp,
+        )
+    )
+    match update_realm_topic_ok:
+        case True:
+            pass
+        case unknown:
+            assert False, unknown
+
+    await send_signal(
+        conn,
+        EventRealmCertificate(
+            organization_id=organization_id,
+            timestamp=certif.timestamp,
+            realm_id=certif.realm_id,
+            user_id=db_common.user_id,
+            role_removed=False,
+        ),
+    )
+
+    return certif
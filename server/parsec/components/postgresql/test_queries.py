@@ -51,6 +51,18 @@ deleted_profiles AS (
     RETURNING _id
 ),
 
+deleted_groups AS (
+    DELETE FROM group_
+    WHERE organization IN (SELECT * FROM deleted_organizations)
+    RETURNING _id
+),
+
+deleted_group_members AS (
+    DELETE FROM group_member
+    WHERE group_ IN (SELECT * FROM deleted_groups)
+    RETURNING _id
+),
+
 deleted_invitations AS (
     DELETE FROM invitation
     WHERE organization IN (SELECT * FROM deleted_organizations)
@@ -75,6 +87,12 @@ deleted_realm_archiving AS (
     RETURNING _id
 ),
 
+deleted_realm_group_roles AS (
+    DELETE FROM realm_group_role
+    WHERE realm IN (SELECT * FROM deleted_realms)
+    RETURNING _id
+),
+
 deleted_vlob_atoms AS (
     DELETE FROM vlob_atom
     WHERE realm IN (SELECT * FROM deleted_realms)
@@ -318,7 +336,10 @@ new_devices AS (
         device_certifier,
         created_on,
         redacted_device_certificate,
-        device_label
+        device_label,
+        revoked_on,
+        revoked_device_certificate,
+        revoked_device_certifier
     )
     SELECT
         (SELECT new_organization_ids._id FROM new_organization_ids) AS organization,
@@ -332,7 +353,10 @@ new_devices AS (
         device_certifier,
         created_on,
         redacted_device_certificate,
-        device_label
+        device_label,
+        revoked_on,
+        revoked_device_certificate,
+        revoked_device_certifier
     FROM device
     WHERE organization = {q_organization_internal_id("$source_id")}  -- noqa: LT05,LT14
     ORDER BY _id
@@ -374,10 +398,15 @@ patched_device_certifiers AS (
             SELECT _id
             FROM new_devices
             WHERE device_id = {q_device(_id="device.device_certifier", select="device_id")}  -- noqa: LT05,LT14
+        ),
+        revoked_device_certifier = (
+            SELECT _id
+            FROM new_devices
+            WHERE device_id = {q_device(_id="device.revoked_device_certifier", select="device_id")}  -- noqa: LT05,LT14
         )
     WHERE
         organization = (SELECT new_organization_ids._id FROM new_organization_ids)
-        AND device_certifier IS NOT NULL
+        AND (device_certifier IS NOT NULL OR revoked_device_certifier IS NOT NULL)
     RETURNING _id
 ),
 
@@ -410,6 +439,65 @@ new_profiles AS (
     RETURNING _id
 ),
 
+new_groups AS (
+    INSERT INTO group_ (
+        organization,
+        group_id,
+        group_certificate,
+        certified_by,
+        certified_on
+    )
+    SELECT
+        (SELECT new_organization_ids._id FROM new_organization_ids) AS organization,
+        group_id,
+        group_certificate,
+        (
+            SELECT new_devices._id
+            FROM new_devices
+            WHERE new_devices.device_id = {q_device(_id="group_.certified_by", select="device_id")}  -- noqa: LT05,LT14
+        ) AS certified_by,
+        certified_on
+    FROM group_
+    WHERE organization = {q_organization_internal_id("$source_id")}  -- noqa: LT05,LT14
+    ORDER BY _id
+    RETURNING _id, group_id
+),
+
+new_group_members AS (
+    INSERT INTO group_member (
+        group_,
+        user_,
+        is_member,
+        certificate,
+        certified_by,
+        certified_on
+    )
+    SELECT
+        (
+            SELECT new_groups._id
+            FROM new_groups
+            WHERE new_groups.group_id = group_.group_id
+        ) AS group_,
+        (
+            SELECT new_users._id
+            FROM new_users
+            WHERE new_users.user_id = {q_user(_id="group_member.user_", select="user_id")}  -- noqa: LT05,LT14
+        ) AS user_,
+        group_member.is_member,
+        group_member.certificate,
+        (
+            SELECT new_devices._id
+            FROM new_devices
+            WHERE new_devices.device_id = {q_device(_id="group_member.certified_by", select="device_id")}  -- noqa: LT05,LT14
+        ) AS certified_by,
+        group_member.certified_on
+    FROM group_member
+    INNER JOIN group_ ON group_member.group_ = group_._id
+    WHERE group_.organization = {q_organization_internal_id("$source_id")}  -- noqa: LT05,LT14
+    ORDER BY group_member._id
+    RETURNING _id
+),
+
 new_shamir_recovery_setups AS (
     INSERT INTO shamir_recovery_setup (
         organization,
@@ -609,6 +697,42 @@ new_realm_archivings AS (
     RETURNING _id
 ),
 
+new_realm_group_roles AS (
+    INSERT INTO realm_group_role (
+        realm,
+        group_,
+        role,
+        certificate,
+        certified_by,
+        certified_on
+    )
+    SELECT
+        (
+            SELECT new_realms._id
+            FROM new_realms
+            WHERE new_realms.realm_id = realm.realm_id
+        ) AS realm,
+        (
+            SELECT new_groups._id
+            FROM new_groups
+            WHERE new_groups.group_id = group_.group_id
+        ) AS group_,
+        realm_group_role.role,
+        realm_group_role.certificate,
+        (
+            SELECT new_devices._id
+            FROM new_devices
+            WHERE new_devices.device_id = {q_device(_id="realm_group_role.certified_by", select="device_id")}  -- noqa: LT05,LT14
+        ) AS certified_by,
+        realm_group_role.certified_on
+    FROM realm_group_role
+    INNER JOIN realm ON realm_group_role.realm = realm._id
+    INNER JOIN group_ ON realm_group_role.group_ = group_._id
+    WHERE realm.organization = {q_organization_internal_id("$source_id")}  -- noqa: LT05,LT14
+    ORDER BY realm_group_role._id
+    RETURNING _id
+),
+
 new_vlob_atoms AS (
     INSERT INTO vlob_atom (
         realm,
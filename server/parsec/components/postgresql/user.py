@@ -8,7 +8,10 @@ from parsec._parsec import (
     DeviceCertificate,
     DeviceID,
     EmailAddress,
+    GroupCertificate,
+    GroupMemberCertificate,
     OrganizationID,
+    RevokedDeviceCertificate,
     RevokedUserCertificate,
     UserCertificate,
     UserID,
@@ -21,6 +24,7 @@ from parsec.ballpark import RequireGreaterTimestamp, TimestampOutOfBallpark
 from parsec.components.postgresql import AsyncpgConnection, AsyncpgPool
 from parsec.components.postgresql.user_accept_tos import user_accept_tos
 from parsec.components.postgresql.user_create_device import user_create_device
+from parsec.components.postgresql.user_create_group import user_create_group
 from parsec.components.postgresql.user_create_user import user_create_user
 from parsec.components.postgresql.user_freeze_user import user_freeze_user
 from parsec.components.postgresql.user_get_certificates import user_get_certificates
@@ -29,8 +33,10 @@ from parsec.components.postgresql.user_get_user_info import (
 )
 from parsec.components.postgresql.user_list_active_users import user_list_active_users
 from parsec.components.postgresql.user_list_frozen_users import user_list_frozen_users
+from parsec.components.postgresql.user_revoke_device import user_revoke_device
 from parsec.components.postgresql.user_revoke_user import user_revoke_user
 from parsec.components.postgresql.user_test_dump_current_users import user_test_dump_current_users
+from parsec.components.postgresql.user_update_group_member import user_update_group_member
 from parsec.components.postgresql.user_update_user import user_update_user
 from parsec.components.postgresql.utils import (
     Q,
@@ -47,6 +53,8 @@ from parsec.components.user import (
     UserAcceptTosBadOutcome,
     UserCreateDeviceStoreBadOutcome,
     UserCreateDeviceValidateBadOutcome,
+    UserCreateGroupStoreBadOutcome,
+    UserCreateGroupValidateBadOutcome,
     UserCreateUserStoreBadOutcome,
     UserCreateUserValidateBadOutcome,
     UserDump,
@@ -55,8 +63,12 @@ from parsec.components.user import (
     UserInfo,
     UserListActiveUsersBadOutcome,
     UserListFrozenUsersBadOutcome,
+    UserRevokeDeviceStoreBadOutcome,
+    UserRevokeDeviceValidateBadOutcome,
     UserRevokeUserStoreBadOutcome,
     UserRevokeUserValidateBadOutcome,
+    UserUpdateGroupMemberStoreBadOutcome,
+    UserUpdateGroupMemberValidateBadOutcome,
     UserUpdateUserStoreBadOutcome,
     UserUpdateUserValidateBadOutcome,
 )
@@ -319,6 +331,87 @@ class PGUserComponent(BaseUserComponent):
             revoked_user_certificate,
         )
 
+    @override
+    @transaction
+    async def revoke_device(
+        self,
+        conn: AsyncpgConnection,
+        now: DateTime,
+        organization_id: OrganizationID,
+        author: DeviceID,
+        author_verify_key: VerifyKey,
+        revoked_device_certificate: bytes,
+    ) -> (
+        RevokedDeviceCertificate
+        | CertificateBasedActionIdempotentOutcome
+        | UserRevokeDeviceValidateBadOutcome
+        | UserRevokeDeviceStoreBadOutcome
+        | TimestampOutOfBallpark
+        | RequireGreaterTimestamp
+    ):
+        return await user_revoke_device(
+            conn,
+            now,
+            organization_id,
+            author,
+            author_verify_key,
+            revoked_device_certificate,
+        )
+
+    @override
+    @transaction
+    async def create_group(
+        self,
+        conn: AsyncpgConnection,
+        now: DateTime,
+        organization_id: OrganizationID,
+        author: DeviceID,
+        author_verify_key: VerifyKey,
+        group_certificate: bytes,
+    ) -> (
+        GroupCertificate
+        | CertificateBasedActionIdempotentOutcome
+        | UserCreateGroupValidateBadOutcome
+        | UserCreateGroupStoreBadOutcome
+        | TimestampOutOfBallpark
+        | RequireGreaterTimestamp
+    ):
+        return await user_create_group(
+            conn,
+            now,
+            organization_id,
+            author,
+            author_verify_key,
+            group_certificate,
+        )
+
+    @override
+    @transaction
+    async def update_group_member(
+        self,
+        conn: AsyncpgConnection,
+        now: DateTime,
+        organization_id: OrganizationID,
+        author: DeviceID,
+        author_verify_key: VerifyKey,
+        group_member_certificate: bytes,
+    ) -> (
+        GroupMemberCertificate
+        | CertificateBasedActionIdempotentOutcome
+        | UserUpdateGroupMemberValidateBadOutcome
+        | UserUpdateGroupMemberStoreBadOutcome
+        | TimestampOutOfBallpark
+        | RequireGreaterTimestamp
+    ):
+        return await user_update_group_member(
+            conn,
+            now,
+            organization_id,
+            author,
+            author_verify_key,
+            group_member_certificate,
+        )
+
     @override
     @transaction
     async def freeze_user(
@@ -0,0 +1,174 @@
+# Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+from __future__ import annotations
+
+from parsec._parsec import (
+    DateTime,
+    DeviceID,
+    GroupCertificate,
+    OrganizationID,
+    UserProfile,
+    VerifyKey,
+)
+from parsec.ballpark import RequireGreaterTimestamp, TimestampOutOfBallpark
+from parsec.components.postgresql import AsyncpgConnection
+from parsec.components.postgresql.events import send_signal
+from parsec.components.postgresql.queries import (
+    AuthAndLockCommonOnlyBadOutcome,
+    AuthAndLockCommonOnlyData,
+    auth_and_lock_common_write,
+)
+from parsec.components.postgresql.utils import Q
+from parsec.components.realm import CertificateBasedActionIdempotentOutcome
+from parsec.components.user import (
+    UserCreateGroupStoreBadOutcome,
+    UserCreateGroupValidateBadOutcome,
+    user_create_group_validate,
+)
+from parsec.events import EventCommonCertificate
+
+_q_get_existing_group = Q("""
+SELECT certified_on
+FROM group_
+WHERE
+    organization = $organization_internal_id
+    AND group_id = $group_id
+""")
+
+
+_q_create_group = Q("""
+WITH new_group AS (
+    INSERT INTO group_ (
+        organization,
+        group_id,
+        group_certificate,
+        certified_by,
+        certified_on
+    ) VALUES (
+        $organization_internal_id,
+        $group_id,
+        $group_certificate,
+        $certified_by,
+        $certified_on
+    )
+    RETURNING TRUE
+),
+
+updated_common_topic AS (
+    UPDATE common_topic
+    SET last_timestamp = $certified_on
+    WHERE
+        organization = $organization_internal_id
+        -- Sanity check
+        AND last_timestamp < $certified_on
+    RETURNING TRUE
+)
+
+SELECT
+    COALESCE((SELECT * FROM new_group), FALSE) AS insert_group_ok,
+    COALESCE((SELECT * FROM updated_common_topic), FALSE) AS update_common_topic_ok
+""")
+
+
+async def user_create_group(
+    conn: AsyncpgConnection,
+    now: DateTime,
+    organization_id: OrganizationID,
+    author: DeviceID,
+    author_verify_key: VerifyKey,
+    group_certificate: bytes,
+) -> (
+    GroupCertificate
+    | CertificateBasedActionIdempotentOutcome
+    | UserCreateGroupValidateBadOutcome
+    | UserCreateGroupStoreBadOutcome
+    | TimestampOutOfBallpark
+    | RequireGreaterTimestamp
+):
+    # 1) Write lock common topic
+
+    match await auth_and_lock_common_write(conn, organization_id, author):
+        case AuthAndLockCommonOnlyData() as db_common:
+            pass
+        case AuthAndLockCommonOnlyBadOutcome.ORGANIZATION_NOT_FOUND:
+            return UserCreateGroupStoreBadOutcome.ORGANIZATION_NOT_FOUND
+        case AuthAndLockCommonOnlyBadOutcome.ORGANIZATION_EXPIRED:
+            return UserCreateGroupStoreBadOutcome.ORGANIZATION_EXPIRED
+        case AuthAndLockCommonOnlyBadOutcome.AUTHOR_NOT_FOUND:
+            return UserCreateGroupStoreBadOutcome.AUTHOR_NOT_FOUND
+        case AuthAndLockCommonOnlyBadOutcome.AUTHOR_REVOKED:
+            return UserCreateGroupStoreBadOutcome.AUTHOR_REVOKED
+
+    if db_common.user_current_profile != UserProfile.ADMIN:
+        return UserCreateGroupStoreBadOutcome.AUTHOR_NOT_ALLOWED
+
+    # 2) Validate certificate
+
+    match user_create_group_validate(
+        now=now,
+        expected_author_device_id=author,
+        author_verify_key=author_verify_key,
+        group_certificate=group_certificate,
+    ):
+        case GroupCertificate() as certif:
+            pass
+        case error:
+            return error
+
+    # 3) Check the group doesn't already exist
+
+    match await conn.fetchval(
+        *_q_get_existing_group(
+            organization_internal_id=db_common.organization_internal_id,
+            group_id=certif.group_id,
+        )
+    ):
+        case None:
+            pass
+        case DateTime() as existing_group_certified_on:
+            return CertificateBasedActionIdempotentOutcome(
+                certificate_timestamp=existing_group_certified_on
+            )
+        case unknown:
+            assert False, unknown
+
+    # 4) Ensure we are not breaking causality by adding a newer timestamp.
+
+    if db_common.last_common_certificate_timestamp >= certif.timestamp:
+        return RequireGreaterTimestamp(
+            strictly_greater_than=db_common.last_common_certificate_timestamp
+        )
+
+    # 5) All checks are good, now we do the actual insertion
+
+    row = await conn.fetchrow(
+        *_q_create_group(
+            organization_internal_id=db_common.organization_internal_id,
+            group_id=certif.group_id,
+            group_certificate=group_certificate,
+            certified_by=db_common.device_internal_id,
+            certified_on=certif.timestamp,
+        )
+    )
+    assert row is not None
+
+    match row["insert_group_ok"]:
+        case True:
+            pass
+        case _:
+            assert False, row
+
+    match row["update_common_topic_ok"]:
+        case True:
+            pass
+        case _:
+            assert False, row
+
+    await send_signal(
+        conn,
+        EventCommonCertificate(
+            organization_id=organization_id,
+            timestamp=certif.timestamp,
+        ),
+    )
+
+    return certif
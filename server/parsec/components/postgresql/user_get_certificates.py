@@ -64,6 +64,18 @@ sql_fragment_all_common_certificates = """
             AND revoked_on IS NOT NULL
     )
     UNION
+    -- Device revoked certificate
+    (
+        SELECT
+            1 AS priority,
+            revoked_on AS certificate_timestamp,
+            revoked_device_certificate AS certificate
+        FROM device
+        WHERE
+            organization = $organization_internal_id
+            AND revoked_on IS NOT NULL
+    )
+    UNION
     -- User update certificate
     (
         SELECT
@@ -75,6 +87,29 @@ sql_fragment_all_common_certificates = """
         WHERE
             user_.organization = $organization_internal_id
     )
+    UNION
+    -- Group certificate
+    (
+        SELECT
+            1 AS priority,
+            certified_on AS certificate_timestamp,
+            group_certificate AS certificate
+        FROM group_
+        WHERE
+            organization = $organization_internal_id
+    )
+    UNION
+    -- Group member certificate
+    (
+        SELECT
+            1 AS priority,
+            group_member.certified_on AS certificate_timestamp,
+            group_member.certificate
+        FROM group_member
+        INNER JOIN group_ ON group_member.group_ = group_._id
+        WHERE
+            group_.organization = $organization_internal_id
+    )
 """
 
 
@@ -147,6 +182,15 @@ sql_fragment_all_realm_certificates = """
             certificate
         FROM realm_archiving
     )
+    UNION
+    -- Realm group role certificate
+    (
+        SELECT
+            realm,
+            certified_on AS certificate_timestamp,
+            certificate
+        FROM realm_group_role
+    )
 """
 
 
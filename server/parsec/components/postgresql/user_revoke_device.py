@@ -0,0 +1,286 @@
+# Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+from __future__ import annotations
+
+from parsec._parsec import (
+    DateTime,
+    DeviceID,
+    OrganizationID,
+    RevokedDeviceCertificate,
+    UserID,
+    UserProfile,
+    VerifyKey,
+)
+from parsec.ballpark import RequireGreaterTimestamp, TimestampOutOfBallpark
+from parsec.components.postgresql import AsyncpgConnection
+from parsec.components.postgresql.events import send_signal
+from parsec.components.postgresql.queries import (
+    AuthAndLockCommonOnlyBadOutcome,
+    AuthAndLockCommonOnlyData,
+    auth_and_lock_common_write,
+)
+from parsec.components.postgresql.utils import Q
+from parsec.components.realm import CertificateBasedActionIdempotentOutcome
+from parsec.components.user import (
+    UserRevokeDeviceStoreBadOutcome,
+    UserRevokeDeviceValidateBadOutcome,
+    user_revoke_device_validate,
+)
+from parsec.events import (
+    EventCommonCertificate,
+    EventDeviceRevoked,
+)
+
+_q_get_info_for_checks_and_read_lock_realms = Q("""
+WITH my_device AS (
+    SELECT
+        device._id,
+        device.revoked_on AS device_revoked_on,
+        user_._id AS user_internal_id,
+        user_.user_id,
+        (user_.revoked_on IS NOT NULL) AS user_is_revoked
+    FROM device
+    INNER JOIN user_ ON device.user_ = user_._id
+    WHERE
+        device.organization = $organization_internal_id
+        AND device.device_id = $recipient_device_id
+    LIMIT 1
+),
+
+-- Retrieve the last role for each realm the device's user is or used to be part of...
+my_realms_last_roles AS (
+    SELECT DISTINCT ON (realm)
+        realm AS _id,
+        role
+    FROM realm_user_role
+    WHERE user_ = (SELECT my_device.user_internal_id FROM my_device)
+    ORDER BY realm ASC, certified_on DESC
+),
+
+-- ...and only keep the realm the user is still part of
+my_realms AS (
+    SELECT _id
+    FROM my_realms_last_roles
+    WHERE role IS NOT NULL
+),
+
+-- Realms topic lock must occur ASAP
+my_locked_realms_topics AS (
+    SELECT last_timestamp
+    FROM realm_topic
+    WHERE realm IN (SELECT my_realms.* FROM my_realms)
+    -- Read lock
+    FOR SHARE
+),
+
+-- Same full scan on the `vlob_atom` table than for user revocation, see
+-- `user_revoke_user.py` for the rationale.
+my_vlobs_last_timestamp AS (
+    SELECT MAX(created_on) AS last_timestamp
+    FROM vlob_atom
+    WHERE realm IN (SELECT my_realms.* FROM my_realms)
+)
+
+SELECT
+    (SELECT _id FROM my_device) AS recipient_internal_id,
+    (SELECT user_id FROM my_device) AS recipient_user_id,
+    (SELECT user_is_revoked FROM my_device) AS recipient_user_is_revoked,
+    (SELECT device_revoked_on FROM my_device) AS recipient_revoked_on,
+    (SELECT MAX(last_timestamp) FROM my_locked_realms_topics) AS realms_topics_last_timestamp,
+    (SELECT last_timestamp FROM my_vlobs_last_timestamp) AS vlobs_last_timestamp
+""")
+
+
+_q_revoke_device = Q("""
+WITH updated_device AS (
+    UPDATE device SET
+        revoked_device_certificate = $revoked_device_certificate,
+        revoked_device_certifier = $author_internal_id,
+        revoked_on = $revoked_on
+    WHERE
+        _id = $recipient_internal_id
+        -- Sanity checks
+        AND revoked_device_certificate IS NULL
+        AND revoked_device_certifier IS NULL
+        AND revoked_on IS NULL
+    RETURNING TRUE
+),
+
+updated_common_topic AS (
+    UPDATE common_topic
+    SET last_timestamp = $revoked_on
+    WHERE
+        organization = $organization_internal_id
+        -- Sanity check
+        AND last_timestamp < $revoked_on
+    RETURNING TRUE
+)
+
+SELECT
+    COALESCE((SELECT * FROM updated_device), FALSE) AS update_device_ok,
+    COALESCE((SELECT * FROM updated_common_topic), FALSE) AS update_common_topic_ok
+""")
+
+
+async def user_revoke_device(
+    conn: AsyncpgConnection,
+    now: DateTime,
+    organization_id: OrganizationID,
+    author: DeviceID,
+    author_verify_key: VerifyKey,
+    revoked_device_certificate: bytes,
+) -> (
+    RevokedDeviceCertificate
+    | CertificateBasedActionIdempotentOutcome
+    | UserRevokeDeviceValidateBadOutcome
+    | UserRevokeDeviceStoreBadOutcome
+    | TimestampOutOfBallpark
+    | RequireGreaterTimestamp
+):
+    # 1) Write lock common topic
+
+    match await auth_and_lock_common_write(conn, organization_id, author):
+        case AuthAndLockCommonOnlyData() as db_common:
+            pass
+        case AuthAndLockCommonOnlyBadOutcome.ORGANIZATION_NOT_FOUND:
+            return UserRevokeDeviceStoreBadOutcome.ORGANIZATION_NOT_FOUND
+        case AuthAndLockCommonOnlyBadOutcome.ORGANIZATION_EXPIRED:
+            return UserRevokeDeviceStoreBadOutcome.ORGANIZATION_EXPIRED
+        case AuthAndLockCommonOnlyBadOutcome.AUTHOR_NOT_FOUND:
+            return UserRevokeDeviceStoreBadOutcome.AUTHOR_NOT_FOUND
+        case AuthAndLockCommonOnlyBadOutcome.AUTHOR_REVOKED:
+            return UserRevokeDeviceStoreBadOutcome.AUTHOR_REVOKED
+
+    # 2) Validate certificate
+
+    match user_revoke_device_validate(
+        now=now,
+        expected_author_device_id=author,
+        author_verify_key=author_verify_key,
+        revoked_device_certificate=revoked_device_certificate,
+    ):
+        case RevokedDeviceCertificate() as certif:
+            pass
+        case error:
+            return error
+
+    # A device can be revoked by its own user (from another device) or by an admin
+    if db_common.user_id != certif.user_id and db_common.user_current_profile != UserProfile.ADMIN:
+        return UserRevokeDeviceStoreBadOutcome.AUTHOR_NOT_ALLOWED
+
+    # 3) Fetch from database what is needed and do the checks
+
+    row = await conn.fetchrow(
+        *_q_get_info_for_checks_and_read_lock_realms(
+            organization_internal_id=db_common.organization_internal_id,
+            recipient_device_id=certif.device_id,
+        )
+    )
+    assert row is not None
+
+    # 3.1) Check the recipient exists, belongs to the expected user & is not revoked
+
+    match row["recipient_internal_id"]:
+        case int() as recipient_internal_id:
+            pass
+        case None:
+            return UserRevokeDeviceStoreBadOutcome.DEVICE_NOT_FOUND
+        case _:
+            assert False, row
+
+    match row["recipient_user_id"]:
+        case str() as raw_recipient_user_id:
+            if UserID.from_hex(raw_recipient_user_id) != certif.user_id:
+                return UserRevokeDeviceStoreBadOutcome.USER_MISMATCH
+        case _:
+            assert False, row
+
+    match row["recipient_user_is_revoked"]:
+        case False:
+            pass
+        # Revoking a device of a revoked user makes no sense
+        case True:
+            return UserRevokeDeviceStoreBadOutcome.DEVICE_NOT_FOUND
+        case _:
+            assert False, row
+
+    match row["recipient_revoked_on"]:
+        case None:
+            pass
+        case DateTime() as recipient_revoked_on:
+            return CertificateBasedActionIdempotentOutcome(
+                certificate_timestamp=recipient_revoked_on
+            )
+        case _:
+            assert False, row
+
+    # 3.2) Ensure we are not breaking causality by adding a newer timestamp.
+
+    # Given a revoked device is not allowed to modify a realm, we must check timestamp on:
+    # - The common topic
+    # - For each realm the device's user is part of: the realm topic
+    # - For each realm the device's user is part of: the last vlob
+
+    last_timestamp = db_common.last_common_certificate_timestamp
+
+    match row["realms_topics_last_timestamp"]:
+        case DateTime() as realms_topics_last_timestamp:
+            last_timestamp = max(realms_topics_last_timestamp, last_timestamp)
+        # Can be `None` if the device's user is not part of any realm
+        case None:
+            pass
+        case _:
+            assert False, row
+
+    match row["vlobs_last_timestamp"]:
+        case DateTime() as vlobs_last_timestamp:
+            last_timestamp = max(vlobs_last_timestamp, last_timestamp)
+        # Can be `None` if the device's user is part realms which are all empty
+        case None:
+            pass
+        case _:
+            assert False, row
+
+    if certif.timestamp <= last_timestamp:
+        return RequireGreaterTimestamp(strictly_greater_than=last_timestamp)
+
+    # 4) All checks are good, now we do the actual insertion
+
+    row = await conn.fetchrow(
+        *_q_revoke_device(
+            organization_internal_id=db_common.organization_internal_id,
+            revoked_device_certificate=revoked_device_certificate,
+            author_internal_id=db_common.device_internal_id,
+            revoked_on=certif.timestamp,
+            recipient_internal_id=recipient_internal_id,
+        )
+    )
+    assert row is not None
+
+    match row["update_device_ok"]:
+        case True:
+            pass
+        case _:
+            assert False, row
+
+    match row["update_common_topic_ok"]:
+        case True:
+            pass
+        case _:
+            assert False, row
+
+    await send_signal(
+        conn,
+        EventCommonCertificate(
+            organization_id=organization_id,
+            timestamp=certif.timestamp,
+        ),
+    )
+    await send_signal(
+        conn,
+        EventDeviceRevoked(
+            organization_id=organization_id,
+            device_id=certif.device_id,
+        ),
+    )
+
+    return certif
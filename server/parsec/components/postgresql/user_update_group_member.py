@@ -0,0 +1,235 @@
+# Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+from __future__ import annotations
+
+from parsec._parsec import (
+    DateTime,
+    DeviceID,
+    GroupMemberCertificate,
+    OrganizationID,
+    UserProfile,
+    VerifyKey,
+)
+from parsec.ballpark import RequireGreaterTimestamp, TimestampOutOfBallpark
+from parsec.components.postgresql import AsyncpgConnection
+from parsec.components.postgresql.events import send_signal
+from parsec.components.postgresql.queries import (
+    AuthAndLockCommonOnlyBadOutcome,
+    AuthAndLockCommonOnlyData,
+    auth_and_lock_common_write,
+)
+from parsec.components.postgresql.utils import Q
+from parsec.components.realm import CertificateBasedActionIdempotentOutcome
+from parsec.components.user import (
+    UserUpdateGroupMemberStoreBadOutcome,
+    UserUpdateGroupMemberValidateBadOutcome,
+    user_update_group_member_validate,
+)
+from parsec.events import EventCommonCertificate
+
+_q_get_info_for_checks = Q("""
+WITH my_group AS (
+    SELECT _id
+    FROM group_
+    WHERE
+        organization = $organization_internal_id
+        AND group_id = $group_id
+),
+
+my_user AS (
+    SELECT
+        _id,
+        (revoked_on IS NOT NULL) AS is_revoked
+    FROM user_
+    WHERE
+        organization = $organization_internal_id
+        AND user_id = $user_id
+),
+
+my_last_membership AS (
+    SELECT is_member
+    FROM group_member
+    WHERE
+        group_ = (SELECT my_group._id FROM my_group)
+        AND user_ = (SELECT my_user._id FROM my_user)
+    ORDER BY certified_on DESC
+    LIMIT 1
+)
+
+SELECT
+    (SELECT _id FROM my_group) AS group_internal_id,
+    (SELECT _id FROM my_user) AS user_internal_id,
+    (SELECT is_revoked FROM my_user) AS user_is_revoked,
+    COALESCE((SELECT is_member FROM my_last_membership), FALSE) AS user_is_member
+""")
+
+
+_q_update_group_member = Q("""
+WITH new_group_member AS (
+    INSERT INTO group_member (
+        group_,
+        user_,
+        is_member,
+        certificate,
+        certified_by,
+        certified_on
+    ) VALUES (
+        $group_internal_id,
+        $user_internal_id,
+        $is_member,
+        $certificate,
+        $certified_by,
+        $certified_on
+    )
+    RETURNING TRUE
+),
+
+updated_common_topic AS (
+    UPDATE common_topic
+    SET last_timestamp = $certified_on
+    WHERE
+        organization = $organization_internal_id
+        -- Sanity check
+        AND last_timestamp < $certified_on
+    RETURNING TRUE
+)
+
+SELECT
+    COALESCE((SELECT * FROM new_group_member), FALSE) AS insert_group_member_ok,
+    COALESCE((SELECT * FROM updated_common_topic), FALSE) AS update_common_topic_ok
+""")
+
+
+async def user_update_group_member(
+    conn: AsyncpgConnection,
+    now: DateTime,
+    organization_id: OrganizationID,
+    author: DeviceID,
+    author_verify_key: VerifyKey,
+    group_member_certificate: bytes,
+) -> (
+    GroupMemberCertificate
+    | CertificateBasedActionIdempotentOutcome
+    | UserUpdateGroupMemberValidateBadOutcome
+    | UserUpdateGroupMemberStoreBadOutcome
+    | TimestampOutOfBallpark
+    | RequireGreaterTimestamp
+):
+    # 1) Write lock common topic
+
+    match await auth_and_lock_common_write(conn, organization_id, author):
+        case AuthAndLockCommonOnlyData() as db_common:
+            pass
+        case AuthAndLockCommonOnlyBadOutcome.ORGANIZATION_NOT_FOUND:
+            return UserUpdateGroupMemberStoreBadOutcome.ORGANIZATION_NOT_FOUND
+        case AuthAndLockCommonOnlyBadOutcome.ORGANIZATION_EXPIRED:
+            return UserUpdateGroupMemberStoreBadOutcome.ORGANIZATION_EXPIRED
+        case AuthAndLockCommonOnlyBadOutcome.AUTHOR_NOT_FOUND:
+            return UserUpdateGroupMemberStoreBadOutcome.AUTHOR_NOT_FOUND
+        case AuthAndLockCommonOnlyBadOutcome.AUTHOR_REVOKED:
+            return UserUpdateGroupMemberStoreBadOutcome.AUTHOR_REVOKED
+
+    if db_common.user_current_profile != UserProfile.ADMIN:
+        return UserUpdateGroupMemberStoreBadOutcome.AUTHOR_NOT_ALLOWED
+
+    # 2) Validate certificate
+
+    match user_update_group_member_validate(
+        now=now,
+        expected_author_device_id=author,
+        author_verify_key=author_verify_key,
+        group_member_certificate=group_member_certificate,
+    ):
+        case GroupMemberCertificate() as certif:
+            pass
+        case error:
+            return error
+
+    # 3) Fetch from database what is needed and do the checks
+
+    row = await conn.fetchrow(
+        *_q_get_info_for_checks(
+            organization_internal_id=db_common.organization_internal_id,
+            group_id=certif.group_id,
+            user_id=certif.user_id,
+        )
+    )
+    assert row is not None
+
+    match row["group_internal_id"]:
+        case int() as group_internal_id:
+            pass
+        case None:
+            return UserUpdateGroupMemberStoreBadOutcome.GROUP_NOT_FOUND
+        case _:
+            assert False, row
+
+    match row["user_internal_id"]:
+        case int() as user_internal_id:
+            pass
+        case None:
+            return UserUpdateGroupMemberStoreBadOutcome.USER_NOT_FOUND
+        case _:
+            assert False, row
+
+    match row["user_is_revoked"]:
+        case False:
+            pass
+        case True:
+            # A revoked user can still be removed from a group
+            if certif.is_member:
+                return UserUpdateGroupMemberStoreBadOutcome.USER_REVOKED
+        case _:
+            assert False, row
+
+    match row["user_is_member"]:
+        case bool() as user_is_member:
+            if user_is_member == certif.is_member:
+                return CertificateBasedActionIdempotentOutcome(
+                    certificate_timestamp=db_common.last_common_certificate_timestamp
+                )
+        case _:
+            assert False, row
+
+    # 4) Ensure we are not breaking causality by adding a newer timestamp.
+
+    if db_common.last_common_certificate_timestamp >= certif.timestamp:
+        return RequireGreaterTimestamp(
+            strictly_greater_than=db_common.last_common_certificate_timestamp
+        )
+
+    # 5) All checks are good, now we do the actual insertion
+
+    row = await conn.fetchrow(
+        *_q_update_group_member(
+            organization_internal_id=db_common.organization_internal_id,
+            group_internal_id=group_internal_id,
+            user_internal_id=user_internal_id,
+            is_member=certif.is_member,
+            certificate=group_member_certificate,
+            certified_by=db_common.device_internal_id,
+            certified_on=certif.timestamp,
+        )
+    )
+    assert row is not None
+
+    match row["insert_group_member_ok"]:
+        case True:
+            pass
+        case _:
+            assert False, row
+
+    match row["update_common_topic_ok"]:
+        case True:
+            pass
+        case _:
+            assert False, row
+
+    await send_signal(
+        conn,
+        EventCommonCertificate(
+            organization_id=organization_id,
+            timestamp=certif.timestamp,
+        ),
+    )
+
+    return certif
@@ -17,6 +17,7 @@ from parsec._parsec import (
     DateTime,
     DeviceID,
     GreetingAttemptID,
+    GroupID,
     PKIEnrollmentID,
     SequesterServiceID,
     TOTPOpaqueKeyID,
@@ -42,6 +43,7 @@ SqlQueryParam = (
     | DeviceID
     | PKIEnrollmentID
     | GreetingAttemptID
+    | GroupID
     | SequesterServiceID
     | UserID
     | VlobID
@@ -10,6 +10,7 @@ from parsec._parsec import (
     DeviceID,
     OrganizationID,
     RealmArchivingCertificate,
+    RealmGroupRoleCertificate,
     RealmKeyRotationCertificate,
     RealmNameCertificate,
     RealmRole,
@@ -188,6 +189,40 @@ def realm_unshare_validate(
     return data
 
 
+class RealmUpdateGroupRoleValidateBadOutcome(BadOutcomeEnum):
+    INVALID_CERTIFICATE = auto()
+    INVALID_ROLE = auto()
+
+
+def realm_update_group_role_validate(
+    now: DateTime,
+    expected_author: DeviceID,
+    author_verify_key: VerifyKey,
+    realm_group_role_certificate: bytes,
+) -> RealmGroupRoleCertificate | TimestampOutOfBallpark | RealmUpdateGroupRoleValidateBadOutcome:
+    try:
+        data = RealmGroupRoleCertificate.verify_and_load(
+            realm_group_role_certificate,
+            author_verify_key=author_verify_key,
+            expected_author=expected_author,
+        )
+
+    except ValueError:
+        return RealmUpdateGroupRoleValidateBadOutcome.INVALID_CERTIFICATE
+
+    match timestamps_in_the_ballpark(data.timestamp, now):
+        case TimestampOutOfBallpark() as error:
+            return error
+        case _:
+            pass
+
+    # A group can only be given READER/CONTRIBUTOR role
+    if data.role in (RealmRole.OWNER, RealmRole.MANAGER):
+        return RealmUpdateGroupRoleValidateBadOutcome.INVALID_ROLE
+
+    return data
+
+
 class RealmRenameValidateBadOutcome(BadOutcomeEnum):
     INVALID_CERTIFICATE = auto()
 
@@ -266,6 +301,7 @@ class RealmShareStoreBadOutcome(BadOutcomeEnum):
     RECIPIENT_NOT_FOUND = auto()
     RECIPIENT_REVOKED = auto()
     ROLE_INCOMPATIBLE_WITH_OUTSIDER = auto()
+    GROUP_NOT_FOUND = auto()
 
 
 class RealmUnshareStoreBadOutcome(BadOutcomeEnum):
@@ -279,6 +315,17 @@ class RealmUnshareStoreBadOutcome(BadOutcomeEnum):
     RECIPIENT_NOT_FOUND = auto()
 
 
+class RealmUpdateGroupRoleStoreBadOutcome(BadOutcomeEnum):
+    ORGANIZATION_NOT_FOUND = auto()
+    ORGANIZATION_EXPIRED = auto()
+    REALM_NOT_FOUND = auto()
+    REALM_DELETED = auto()
+    AUTHOR_NOT_FOUND = auto()
+    AUTHOR_REVOKED = auto()
+    AUTHOR_NOT_ALLOWED = auto()
+    GROUP_NOT_FOUND = auto()
+
+
 class RealmRenameStoreBadOutcome(BadOutcomeEnum):
     ORGANIZATION_NOT_FOUND = auto()
     ORGANIZATION_EXPIRED = auto()
@@ -595,6 +642,23 @@ class BaseRealmComponent:
     ):
         raise NotImplementedError
 
+    async def update_group_role(
+        self,
+        now: DateTime,
+        organization_id: OrganizationID,
+        author: DeviceID,
+        author_verify_key: VerifyKey,
+        realm_group_role_certificate: bytes,
+    ) -> (
+        RealmGroupRoleCertificate
+        | CertificateBasedActionIdempotentOutcome
+        | RealmUpdateGroupRoleValidateBadOutcome
+        | TimestampOutOfBallpark
+        | RealmUpdateGroupRoleStoreBadOutcome
+        | RequireGreaterTimestamp
+    ):
+        raise NotImplementedError
+
     async def rename(
         self,
         now: DateTime,
@@ -866,6 +930,9 @@ class BaseRealmComponent:
                 return authenticated_cmds.latest.realm_share.RepRecipientRevoked()
             case RealmShareStoreBadOutcome.ROLE_INCOMPATIBLE_WITH_OUTSIDER:
                 return authenticated_cmds.latest.realm_share.RepRoleIncompatibleWithOutsider()
+            # Giving a role through a group that doesn't exist
+            case RealmShareStoreBadOutcome.GROUP_NOT_FOUND:
+                return authenticated_cmds.latest.realm_share.RepInvalidCertificate()
             case RealmShareStoreBadOutcome.ORGANIZATION_NOT_FOUND:
                 client_ctx.organization_not_found_abort()
             case RealmShareStoreBadOutcome.ORGANIZATION_EXPIRED:
@@ -925,6 +992,56 @@ class BaseRealmComponent:
             case RealmUnshareStoreBadOutcome.AUTHOR_REVOKED:
                 client_ctx.author_revoked_abort()
 
+    @api
+    async def api_realm_update_group_role(
+        self,
+        client_ctx: AuthenticatedClientContext,
+        req: authenticated_cmds.latest.realm_update_group_role.Req,
+    ) -> authenticated_cmds.latest.realm_update_group_role.Rep:
+        outcome = await self.update_group_role(
+            now=DateTime.now(),
+            organization_id=client_ctx.organization_id,
+            author=client_ctx.device_id,
+            author_verify_key=client_ctx.device_verify_key,
+            realm_group_role_certificate=req.realm_group_role_certificate,
+        )
+        match outcome:
+            case RealmGroupRoleCertificate():
+                return authenticated_cmds.latest.realm_update_group_role.RepOk()
+            case RequireGreaterTimestamp() as error:
+                return authenticated_cmds.latest.realm_update_group_role.RepRequireGreaterTimestamp(
+                    strictly_greater_than=error.strictly_greater_than
+                )
+            case TimestampOutOfBallpark() as error:
+                return authenticated_cmds.latest.realm_update_group_role.RepTimestampOutOfBallpark(
+                    server_timestamp=error.server_timestamp,
+                    client_timestamp=error.client_timestamp,
+                    ballpark_client_early_offset=error.ballpark_client_early_offset,
+                    ballpark_client_late_offset=error.ballpark_client_late_offset,
+                )
+            case RealmUpdateGroupRoleValidateBadOutcome():
+                return authenticated_cmds.latest.realm_update_group_role.RepInvalidCertificate()
+            case CertificateBasedActionIdempotentOutcome() as error:
+                return authenticated_cmds.latest.realm_update_group_role.RepGroupRoleAlreadyGranted(
+                    last_realm_certificate_timestamp=error.certificate_timestamp,
+                )
+            case RealmUpdateGroupRoleStoreBadOutcome.REALM_NOT_FOUND:
+                return authenticated_cmds.latest.realm_update_group_role.RepRealmNotFound()
+            case RealmUpdateGroupRoleStoreBadOutcome.REALM_DELETED:
+                return authenticated_cmds.latest.realm_update_group_role.RepRealmDeleted()
+            case RealmUpdateGroupRoleStoreBadOutcome.AUTHOR_NOT_ALLOWED:
+                return authenticated_cmds.latest.realm_update_group_role.RepAuthorNotAllowed()
+            case RealmUpdateGroupRoleStoreBadOutcome.GROUP_NOT_FOUND:
+                return authenticated_cmds.latest.realm_update_group_role.RepGroupNotFound()
+            case RealmUpdateGroupRoleStoreBadOutcome.ORGANIZATION_NOT_FOUND:
+                client_ctx.organization_not_found_abort()
+            case RealmUpdateGroupRoleStoreBadOutcome.ORGANIZATION_EXPIRED:
+                client_ctx.organization_expired_abort()
+            case RealmUpdateGroupRoleStoreBadOutcome.AUTHOR_NOT_FOUND:
+                client_ctx.author_not_found_abort()
+            case RealmUpdateGroupRoleStoreBadOutcome.AUTHOR_REVOKED:
+                client_ctx.author_revoked_abort()
+
     @api
     async def api_realm_rename(
         self,
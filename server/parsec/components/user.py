@@ -11,8 +11,11 @@ from parsec._parsec import (
     DeviceCertificate,
     DeviceID,
     EmailAddress,
+    GroupCertificate,
+    GroupMemberCertificate,
     HumanHandle,
     OrganizationID,
+    RevokedDeviceCertificate,
     RevokedUserCertificate,
     UserCertificate,
     UserID,
@@ -230,6 +233,97 @@ def user_revoke_user_validate(
     return data
 
 
+class UserRevokeDeviceValidateBadOutcome(BadOutcomeEnum):
+    INVALID_CERTIFICATE = auto()
+    CANNOT_SELF_REVOKE = auto()
+
+
+def user_revoke_device_validate(
+    now: DateTime,
+    expected_author_device_id: DeviceID,
+    author_verify_key: VerifyKey,
+    revoked_device_certificate: bytes,
+) -> RevokedDeviceCertificate | TimestampOutOfBallpark | UserRevokeDeviceValidateBadOutcome:
+    try:
+        data = RevokedDeviceCertificate.verify_and_load(
+            signed=revoked_device_certificate,
+            author_verify_key=author_verify_key,
+            expected_author=expected_author_device_id,
+        )
+
+    except ValueError:
+        return UserRevokeDeviceValidateBadOutcome.INVALID_CERTIFICATE
+
+    match timestamps_in_the_ballpark(data.timestamp, now):
+        case TimestampOutOfBallpark() as error:
+            return error
+        case _:
+            pass
+
+    if expected_author_device_id == data.device_id:
+        return UserRevokeDeviceValidateBadOutcome.CANNOT_SELF_REVOKE
+
+    return data
+
+
+class UserCreateGroupValidateBadOutcome(BadOutcomeEnum):
+    INVALID_CERTIFICATE = auto()
+
+
+def user_create_group_validate(
+    now: DateTime,
+    expected_author_device_id: DeviceID,
+    author_verify_key: VerifyKey,
+    group_certificate: bytes,
+) -> GroupCertificate | TimestampOutOfBallpark | UserCreateGroupValidateBadOutcome:
+    try:
+        data = GroupCertificate.verify_and_load(
+            signed=group_certificate,
+            author_verify_key=author_verify_key,
+            expected_author=expected_author_device_id,
+        )
+
+    except ValueError:
+        return UserCreateGroupValidateBadOutcome.INVALID_CERTIFICATE
+
+    match timestamps_in_the_ballpark(data.timestamp, now):
+        case TimestampOutOfBallpark() as error:
+            return error
+        case _:
+            pass
+
+    return data
+
+
+class UserUpdateGroupMemberValidateBadOutcome(BadOutcomeEnum):
+    INVALID_CERTIFICATE = auto()
+
+
+def user_update_group_member_validate(
+    now: DateTime,
+    expected_author_device_id: DeviceID,
+    author_verify_key: VerifyKey,
+    group_member_certificate: bytes,
+) -> GroupMemberCertificate | TimestampOutOfBallpark | UserUpdateGroupMemberValidateBadOutcome:
+    try:
+        data = GroupMemberCertificate.verify_and_load(
+            signed=group_member_certificate,
+            author_verify_key=author_verify_key,
+            expected_author=expected_author_device_id,
+        )
+
+    except ValueError:
+        return UserUpdateGroupMemberValidateBadOutcome.INVALID_CERTIFICATE
+
+    match timestamps_in_the_ballpark(data.timestamp, now):
+        case TimestampOutOfBallpark() as error:
+            return error
+        case _:
+            pass
+
+    return data
+
+
 class UserUpdateUserValidateBadOutcome(BadOutcomeEnum):
     INVALID_CERTIFICATE = auto()
     INVALID_USER_PROFILE = auto()
@@ -293,6 +387,35 @@ class UserRevokeUserStoreBadOutcome(BadOutcomeEnum):
     USER_NOT_FOUND = auto()
 
 
+class UserRevokeDeviceStoreBadOutcome(BadOutcomeEnum):
+    ORGANIZATION_NOT_FOUND = auto()
+    ORGANIZATION_EXPIRED = auto()
+    AUTHOR_NOT_FOUND = auto()
+    AUTHOR_REVOKED = auto()
+    AUTHOR_NOT_ALLOWED = auto()
+    DEVICE_NOT_FOUND = auto()
+    USER_MISMATCH = auto()
+
+
+class UserCreateGroupStoreBadOutcome(BadOutcomeEnum):
+    ORGANIZATION_NOT_FOUND = auto()
+    ORGANIZATION_EXPIRED = auto()
+    AUTHOR_NOT_FOUND = auto()
+    AUTHOR_REVOKED = auto()
+    AUTHOR_NOT_ALLOWED = auto()
+
+
+class UserUpdateGroupMemberStoreBadOutcome(BadOutcomeEnum):
+    ORGANIZATION_NOT_FOUND = auto()
+    ORGANIZATION_EXPIRED = auto()
+    AUTHOR_NOT_FOUND = auto()
+    AUTHOR_REVOKED = auto()
+    AUTHOR_NOT_ALLOWED = auto()
+    GROUP_NOT_FOUND = auto()
+    USER_NOT_FOUND = auto()
+    USER_REVOKED = auto()
+
+
 class UserUpdateUserStoreBadOutcome(BadOutcomeEnum):
     ORGANIZATION_NOT_FOUND = auto()
     ORGANIZATION_EXPIRED = auto()
@@ -427,6 +550,57 @@ class BaseUserComponent:
     ):
         raise NotImplementedError
 
+    async def revoke_device(
+        self,
+        now: DateTime,
+        organization_id: OrganizationID,
+        author: DeviceID,
+        author_verify_key: VerifyKey,
+        revoked_device_certificate: bytes,
+    ) -> (
+        RevokedDeviceCertificate
+        | CertificateBasedActionIdempotentOutcome
+        | UserRevokeDeviceValidateBadOutcome
+        | UserRevokeDeviceStoreBadOutcome
+        | TimestampOutOfBallpark
+        | RequireGreaterTimestamp
+    ):
+        raise NotImplementedError
+
+    async def create_group(
+        self,
+        now: DateTime,
+        organization_id: OrganizationID,
+        author: DeviceID,
+        author_verify_key: VerifyKey,
+        group_certificate: bytes,
+    ) -> (
+        GroupCertificate
+        | CertificateBasedActionIdempotentOutcome
+        | UserCreateGroupValidateBadOutcome
+        | UserCreateGroupStoreBadOutcome
+        | TimestampOutOfBallpark
+        | RequireGreaterTimestamp
+    ):
+        raise NotImplementedError
+
+    async def update_group_member(
+        self,
+        now: DateTime,
+        organization_id: OrganizationID,
+        author: DeviceID,
+        author_verify_key: VerifyKey,
+        group_member_certificate: bytes,
+    ) -> (
+        GroupMemberCertificate
+        | CertificateBasedActionIdempotentOutcome
+        | UserUpdateGroupMemberValidateBadOutcome
+        | UserUpdateGroupMemberStoreBadOutcome
+        | TimestampOutOfBallpark
+        | RequireGreaterTimestamp
+    ):
+        raise NotImplementedError
+
     async def update_user(
         self,
         now: DateTime,
@@ -703,6 +877,149 @@ class BaseUserComponent:
             case UserRevokeUserStoreBadOutcome.AUTHOR_REVOKED:
                 client_ctx.author_revoked_abort()
 
+    @api
+    async def api_device_revoke(
+        self,
+        client_ctx: AuthenticatedClientContext,
+        req: authenticated_cmds.latest.device_revoke.Req,
+    ) -> authenticated_cmds.latest.device_revoke.Rep:
+        outcome = await self.revoke_device(
+            now=DateTime.now(),
+            organization_id=client_ctx.organization_id,
+            author=client_ctx.device_id,
+            author_verify_key=client_ctx.device_verify_key,
+            revoked_device_certificate=req.revoked_device_certificate,
+        )
+        match outcome:
+            case RevokedDeviceCertificate():
+                return authenticated_cmds.latest.device_revoke.RepOk()
+            case CertificateBasedActionIdempotentOutcome() as error:
+                return authenticated_cmds.latest.device_revoke.RepDeviceAlreadyRevoked(
+                    last_common_certificate_timestamp=error.certificate_timestamp
+                )
+            case UserRevokeDeviceStoreBadOutcome.DEVICE_NOT_FOUND:
+                return authenticated_cmds.latest.device_revoke.RepDeviceNotFound()
+            case UserRevokeDeviceStoreBadOutcome.AUTHOR_NOT_ALLOWED:
+                return authenticated_cmds.latest.device_revoke.RepAuthorNotAllowed()
+            case (
+                UserRevokeDeviceValidateBadOutcome()
+                | UserRevokeDeviceStoreBadOutcome.USER_MISMATCH
+            ):
+                return authenticated_cmds.latest.device_revoke.RepInvalidCertificate()
+            case TimestampOutOfBallpark() as error:
+                return authenticated_cmds.latest.device_revoke.RepTimestampOutOfBallpark(
+                    server_timestamp=error.server_timestamp,
+                    client_timestamp=error.client_timestamp,
+                    ballpark_client_early_offset=error.ballpark_client_early_offset,
+                    ballpark_client_late_offset=error.ballpark_client_late_offset,
+                )
+            case RequireGreaterTimestamp() as error:
+                return authenticated_cmds.latest.device_revoke.RepRequireGreaterTimestamp(
+                    strictly_greater_than=error.strictly_greater_than
+                )
+            case UserRevokeDeviceStoreBadOutcome.ORGANIZATION_NOT_FOUND:
+                client_ctx.organization_not_found_abort()
+            case UserRevokeDeviceStoreBadOutcome.ORGANIZATION_EXPIRED:
+                client_ctx.organization_expired_abort()
+            case UserRevokeDeviceStoreBadOutcome.AUTHOR_NOT_FOUND:
+                client_ctx.author_not_found_abort()
+            case UserRevokeDeviceStoreBadOutcome.AUTHOR_REVOKED:
+                client_ctx.author_revoked_abort()
+
+    @api
+    async def api_group_create(
+        self,
+        client_ctx: AuthenticatedClientContext,
+        req: authenticated_cmds.latest.group_create.Req,
+    ) -> authenticated_cmds.latest.group_create.Rep:
+        outcome = await self.create_group(
+            now=DateTime.now(),
+            organization_id=client_ctx.organization_id,
+            author=client_ctx.device_id,
+            author_verify_key=client_ctx.device_verify_key,
+            group_certificate=req.group_certificate,
+        )
+        match outcome:
+            case GroupCertificate():
+                return authenticated_cmds.latest.group_create.RepOk()
+            case CertificateBasedActionIdempotentOutcome() as error:
+                return authenticated_cmds.latest.group_create.RepGroupAlreadyExists(
+                    last_common_certificate_timestamp=error.certificate_timestamp
+                )
+            case UserCreateGroupStoreBadOutcome.AUTHOR_NOT_ALLOWED:
+                return authenticated_cmds.latest.group_create.RepAuthorNotAllowed()
+            case UserCreateGroupValidateBadOutcome():
+                return authenticated_cmds.latest.group_create.RepInvalidCertificate()
+            case TimestampOutOfBallpark() as error:
+                return authenticated_cmds.latest.group_create.RepTimestampOutOfBallpark(
+                    server_timestamp=error.server_timestamp,
+                    client_timestamp=error.client_timestamp,
+                    ballpark_client_early_offset=error.ballpark_client_early_offset,
+                    ballpark_client_late_offset=error.ballpark_client_late_offset,
+                )
+            case RequireGreaterTimestamp() as error:
+                return authenticated_cmds.latest.group_create.RepRequireGreaterTimestamp(
+                    strictly_greater_than=error.strictly_greater_than
+                )
+            case UserCreateGroupStoreBadOutcome.ORGANIZATION_NOT_FOUND:
+                client_ctx.organization_not_found_abort()
+            case UserCreateGroupStoreBadOutcome.ORGANIZATION_EXPIRED:
+                client_ctx.organization_expired_abort()
+            case UserCreateGroupStoreBadOutcome.AUTHOR_NOT_FOUND:
+                client_ctx.author_not_found_abort()
+            case UserCreateGroupStoreBadOutcome.AUTHOR_REVOKED:
+                client_ctx.author_revoked_abort()
+
+    @api
+    async def api_group_update_member(
+        self,
+        client_ctx: AuthenticatedClientContext,
+        req: authenticated_cmds.latest.group_update_member.Req,
+    ) -> authenticated_cmds.latest.group_update_member.Rep:
+        outcome = await self.update_group_member(
+            now=DateTime.now(),
+            organization_id=client_ctx.organization_id,
+            author=client_ctx.device_id,
+            author_verify_key=client_ctx.device_verify_key,
+            group_member_certificate=req.group_member_certificate,
+        )
+        match outcome:
+            case GroupMemberCertificate():
+                return authenticated_cmds.latest.group_update_member.RepOk()
+            case CertificateBasedActionIdempotentOutcome() as error:
+                return authenticated_cmds.latest.group_update_member.RepMemberAlreadyUpToDate(
+                    last_common_certificate_timestamp=error.certificate_timestamp
+                )
+            case UserUpdateGroupMemberStoreBadOutcome.AUTHOR_NOT_ALLOWED:
+                return authenticated_cmds.latest.group_update_member.RepAuthorNotAllowed()
+            case UserUpdateGroupMemberStoreBadOutcome.GROUP_NOT_FOUND:
+                return authenticated_cmds.latest.group_update_member.RepGroupNotFound()
+            case UserUpdateGroupMemberStoreBadOutcome.USER_NOT_FOUND:
+                return authenticated_cmds.latest.group_update_member.RepUserNotFound()
+            case UserUpdateGroupMemberStoreBadOutcome.USER_REVOKED:
+                return authenticated_cmds.latest.group_update_member.RepUserRevoked()
+            case UserUpdateGroupMemberValidateBadOutcome():
+                return authenticated_cmds.latest.group_update_member.RepInvalidCertificate()
+            case TimestampOutOfBallpark() as error:
+                return authenticated_cmds.latest.group_update_member.RepTimestampOutOfBallpark(
+                    server_timestamp=error.server_timestamp,
+                    client_timestamp=error.client_timestamp,
+                    ballpark_client_early_offset=error.ballpark_client_early_offset,
+                    ballpark_client_late_offset=error.ballpark_client_late_offset,
+                )
+            case RequireGreaterTimestamp() as error:
+                return authenticated_cmds.latest.group_update_member.RepRequireGreaterTimestamp(
+                    strictly_greater_than=error.strictly_greater_than
+                )
+            case UserUpdateGroupMemberStoreBadOutcome.ORGANIZATION_NOT_FOUND:
+                client_ctx.organization_not_found_abort()
+            case UserUpdateGroupMemberStoreBadOutcome.ORGANIZATION_EXPIRED:
+                client_ctx.organization_expired_abort()
+            case UserUpdateGroupMemberStoreBadOutcome.AUTHOR_NOT_FOUND:
+                client_ctx.author_not_found_abort()
+            case UserUpdateGroupMemberStoreBadOutcome.AUTHOR_REVOKED:
+                client_ctx.author_revoked_abort()
+
     @api
     async def api_tos_accept(
         self, client_ctx: AuthenticatedClientContext, req: tos_cmds.latest.tos_accept.Req
@@ -476,6 +476,8 @@ class EventOrganizationConfig(BaseModel):
                 active_users_limit=self.active_users_limit,
                 sse_keepalive_seconds=sse_keepalive,
                 realm_minimum_archiving_period_before_deletion=self.realm_minimum_archiving_period_before_deletion,
+                # The server doesn't store organization CRLs yet
+                pki_crls=None,
             ),
             None,
         )
@@ -526,6 +528,22 @@ class EventUserRevokedOrFrozen(BaseModel):
     user_id: UserIDField
 
 
+class EventDeviceRevoked(BaseModel):
+    """
+    This event is only used internally and never broadcasted to users.
+
+    It is used for two things:
+    - Closing the SSE connections to a revoked device.
+    - Updating the auth system's cache.
+    """
+
+    model_config = ConfigDict(arbitrary_types_allowed=True, strict=True)
+    type: Literal["DEVICE_REVOKED"] = "DEVICE_REVOKED"
+    event_id: UUID = Field(default_factory=uuid4)
+    organization_id: OrganizationIDField
+    device_id: DeviceIDField
+
+
 class EventUserUnfrozen(BaseModel):
     """
     This event is only used internally and never broadcasted to users.
@@ -573,6 +591,7 @@ type Event = (
     | EventOrganizationExpired
     | EventOrganizationTosUpdated
     | EventUserRevokedOrFrozen
+    | EventDeviceRevoked
     | EventUserUnfrozen
     | EventUserUpdated
 )
@@ -18,7 +18,7 @@ use libparsec_types::{CertificateSigner, IndexInt, UnsecureSkipValidationReason}
 use crate::{
     crypto::{PublicKey, SequesterPublicKeyDer, SequesterVerifyKeyDer, SigningKey, VerifyKey},
     enumerate::{DevicePurpose, RealmRole, UserProfile},
-    ids::{DeviceID, DeviceLabel, HumanHandle, SequesterServiceID, UserID, VlobID},
+    ids::{DeviceID, DeviceLabel, GroupID, HumanHandle, SequesterServiceID, UserID, VlobID},
     time::DateTime,
 };
 
@@ -439,6 +439,260 @@ impl RevokedUserCertificate {
     }
 }
 
+crate::binding_utils::gen_py_wrapper_class!(
+    RevokedDeviceCertificate,
+    Arc<libparsec_types::RevokedDeviceCertificate>,
+    __repr__,
+    __copy__,
+    __deepcopy__,
+    __richcmp__ eq,
+);
+
+#[pymethods]
+impl RevokedDeviceCertificate {
+    #[new]
+    #[pyo3(signature = (author, timestamp, user_id, device_id))]
+    fn new(
+        author: DeviceID,
+        timestamp: DateTime,
+        user_id: UserID,
+        device_id: DeviceID,
+    ) -> PyResult<Self> {
+        Ok(Self(Arc::new(libparsec_types::RevokedDeviceCertificate {
+            author: author.0,
+            timestamp: timestamp.0,
+            user_id: user_id.0,
+            device_id: device_id.0,
+        })))
+    }
+
+    #[classmethod]
+    #[pyo3(signature = (signed, author_verify_key, expected_author, expected_device=None))]
+    fn verify_and_load(
+        _cls: &Bound<'_, PyType>,
+        signed: &[u8],
+        author_verify_key: &VerifyKey,
+        expected_author: DeviceID,
+        expected_device: Option<DeviceID>,
+    ) -> PyResult<Self> {
+        libparsec_types::RevokedDeviceCertificate::verify_and_load(
+            signed,
+            &author_verify_key.0,
+            expected_author.0,
+            expected_device.map(|x| x.0),
+        )
+        .map_err(|e| PyValueError::new_err(e.to_string()))
+        .map(|x| Self(Arc::new(x)))
+    }
+
+    fn dump_and_sign<'py>(
+        &self,
+        author_signkey: &SigningKey,
+        py: Python<'py>,
+    ) -> Bound<'py, PyBytes> {
+        PyBytes::new(py, &self.0.dump_and_sign(&author_signkey.0))
+    }
+
+    #[classmethod]
+    fn unsecure_load(_cls: &Bound<'_, PyType>, signed: &[u8]) -> PyResult<Self> {
+        libparsec_types::RevokedDeviceCertificate::unsecure_load(signed.to_vec().into())
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+            .map(|u| u.skip_validation(UnsecureSkipValidationReason::DataFromLocalStorage))
+            .map(|x| Self(Arc::new(x)))
+    }
+
+    #[getter]
+    fn author(&self) -> DeviceID {
+        DeviceID(self.0.author)
+    }
+
+    #[getter]
+    fn timestamp(&self) -> DateTime {
+        DateTime(self.0.timestamp)
+    }
+
+    #[getter]
+    fn user_id(&self) -> UserID {
+        UserID(self.0.user_id)
+    }
+
+    #[getter]
+    fn device_id(&self) -> DeviceID {
+        DeviceID(self.0.device_id)
+    }
+}
+
+crate::binding_utils::gen_py_wrapper_class!(
+    GroupCertificate,
+    Arc<libparsec_types::GroupCertificate>,
+    __repr__,
+    __copy__,
+    __deepcopy__,
+    __richcmp__ eq,
+);
+
+#[pymethods]
+impl GroupCertificate {
+    #[new]
+    #[pyo3(signature = (author, timestamp, group_id, name))]
+    fn new(
+        author: DeviceID,
+        timestamp: DateTime,
+        group_id: GroupID,
+        name: String,
+    ) -> PyResult<Self> {
+        Ok(Self(Arc::new(libparsec_types::GroupCertificate {
+            author: author.0,
+            timestamp: timestamp.0,
+            group_id: group_id.0,
+            name,
+        })))
+    }
+
+    #[classmethod]
+    #[pyo3(signature = (signed, author_verify_key, expected_author))]
+    fn verify_and_load(
+        _cls: &Bound<'_, PyType>,
+        signed: &[u8],
+        author_verify_key: &VerifyKey,
+        expected_author: DeviceID,
+    ) -> PyResult<Self> {
+        libparsec_types::GroupCertificate::verify_and_load(
+            signed,
+            &author_verify_key.0,
+            expected_author.0,
+        )
+        .map_err(|e| PyValueError::new_err(e.to_string()))
+        .map(|x| Self(Arc::new(x)))
+    }
+
+    fn dump_and_sign<'py>(
+        &self,
+        author_signkey: &SigningKey,
+        py: Python<'py>,
+    ) -> Bound<'py, PyBytes> {
+        PyBytes::new(py, &self.0.dump_and_sign(&author_signkey.0))
+    }
+
+    #[classmethod]
+    fn unsecure_load(_cls: &Bound<'_, PyType>, signed: &[u8]) -> PyResult<Self> {
+        libparsec_types::GroupCertificate::unsecure_load(signed.to_vec().into())
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+            .map(|u| u.skip_validation(UnsecureSkipValidationReason::DataFromLocalStorage))
+            .map(|x| Self(Arc::new(x)))
+    }
+
+    #[getter]
+    fn author(&self) -> DeviceID {
+        DeviceID(self.0.author)
+    }
+
+    #[getter]
+    fn timestamp(&self) -> DateTime {
+        DateTime(self.0.timestamp)
+    }
+
+    #[getter]
+    fn group_id(&self) -> GroupID {
+        GroupID(self.0.group_id)
+    }
+
+    #[getter]
+    fn name(&self) -> &str {
+        &self.0.name
+    }
+}
+
+crate::binding_utils::gen_py_wrapper_class!(
+    GroupMemberCertificate,
+    Arc<libparsec_types::GroupMemberCertificate>,
+    __repr__,
+    __copy__,
+    __deepcopy__,
+    __richcmp__ eq,
+);
+
+#[pymethods]
+impl GroupMemberCertificate {
+    #[new]
+    #[pyo3(signature = (author, timestamp, group_id, user_id, is_member))]
+    fn new(
+        author: DeviceID,
+        timestamp: DateTime,
+        group_id: GroupID,
+        user_id: UserID,
+        is_member: bool,
+    ) -> PyResult<Self> {
+        Ok(Self(Arc::new(libparsec_types::GroupMemberCertificate {
+            author: author.0,
+            timestamp: timestamp.0,
+            group_id: group_id.0,
+            user_id: user_id.0,
+            is_member,
+        })))
+    }
+
+    #[classmethod]
+    #[pyo3(signature = (signed, author_verify_key, expected_author, expected_user=None))]
+    fn verify_and_load(
+        _cls: &Bound<'_, PyType>,
+        signed: &[u8],
+        author_verify_key: &VerifyKey,
+        expected_author: DeviceID,
+        expected_user: Option<UserID>,
+    ) -> PyResult<Self> {
+        libparsec_types::GroupMemberCertificate::verify_and_load(
+            signed,
+            &author_verify_key.0,
+            expected_author.0,
+            expected_user.map(|x| x.0),
+        )
+        .map_err(|e| PyValueError::new_err(e.to_string()))
+        .map(|x| Self(Arc::new(x)))
+    }
+
+    fn dump_and_sign<'py>(
+        &self,
+        author_signkey: &SigningKey,
+        py: Python<'py>,
+    ) -> Bound<'py, PyBytes> {
+        PyBytes::new(py, &self.0.dump_and_sign(&author_signkey.0))
+    }
+
+    #[classmethod]
+    fn unsecure_load(_cls: &Bound<'_, PyType>, signed: &[u8]) -> PyResult<Self> {
+        libparsec_types::GroupMemberCertificate::unsecure_load(signed.to_vec().into())
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+            .map(|u| u.skip_validation(UnsecureSkipValidationReason::DataFromLocalStorage))
+            .map(|x| Self(Arc::new(x)))
+    }
+
+    #[getter]
+    fn author(&self) -> DeviceID {
+        DeviceID(self.0.author)
+    }
+
+    #[getter]
+    fn timestamp(&self) -> DateTime {
+        DateTime(self.0.timestamp)
+    }
+
+    #[getter]
+    fn group_id(&self) -> GroupID {
+        GroupID(self.0.group_id)
+    }
+
+    #[getter]
+    fn user_id(&self) -> UserID {
+        UserID(self.0.user_id)
+    }
+
+    #[getter]
+    fn is_member(&self) -> bool {
+        self.0.is_member
+    }
+}
+
 crate::binding_utils::gen_py_wrapper_class!(
     UserUpdateCertificate,
     Arc<libparsec_types::UserUpdateCertificate>,
@@ -534,13 +788,14 @@ crate::binding_utils::gen_py_wrapper_class!(
 #[pymethods]
 impl RealmRoleCertificate {
     #[new]
-    #[pyo3(signature = (author, timestamp, realm_id, user_id, role))]
+    #[pyo3(signature = (author, timestamp, realm_id, user_id, role, group_id=None))]
     fn new(
         author: DeviceID,
         timestamp: DateTime,
         realm_id: VlobID,
         user_id: UserID,
         role: Option<RealmRole>,
+        group_id: Option<GroupID>,
     ) -> PyResult<Self> {
         Ok(Self(Arc::new(libparsec_types::RealmRoleCertificate {
             timestamp: timestamp.0,
@@ -548,6 +803,7 @@ impl RealmRoleCertificate {
             realm_id: realm_id.0,
             user_id: user_id.0,
             role: role.map(|x| x.0),
+            group_id: group_id.map(|x| x.0),
         })))
     }
 
@@ -612,6 +868,101 @@ impl RealmRoleCertificate {
     fn role(&self) -> Option<&'static PyObject> {
         self.0.role.map(RealmRole::convert)
     }
+
+    #[getter]
+    fn group_id(&self) -> Option<GroupID> {
+        self.0.group_id.map(GroupID)
+    }
+}
+
+crate::binding_utils::gen_py_wrapper_class!(
+    RealmGroupRoleCertificate,
+    Arc<libparsec_types::RealmGroupRoleCertificate>,
+    __repr__,
+    __copy__,
+    __deepcopy__,
+    __richcmp__ eq,
+);
+
+#[pymethods]
+impl RealmGroupRoleCertificate {
+    #[new]
+    #[pyo3(signature = (author, timestamp, realm_id, group_id, role))]
+    fn new(
+        author: DeviceID,
+        timestamp: DateTime,
+        realm_id: VlobID,
+        group_id: GroupID,
+        role: Option<RealmRole>,
+    ) -> PyResult<Self> {
+        Ok(Self(Arc::new(libparsec_types::RealmGroupRoleCertificate {
+            author: author.0,
+            timestamp: timestamp.0,
+            realm_id: realm_id.0,
+            group_id: group_id.0,
+            role: role.map(|x| x.0),
+        })))
+    }
+
+    #[classmethod]
+    #[pyo3(signature = (signed, author_verify_key, expected_author, expected_realm=None))]
+    fn verify_and_load(
+        _cls: &Bound<'_, PyType>,
+        signed: &[u8],
+        author_verify_key: &VerifyKey,
+        expected_author: DeviceID,
+        expected_realm: Option<VlobID>,
+    ) -> PyResult<Self> {
+        libparsec_types::RealmGroupRoleCertificate::verify_and_load(
+            signed,
+            &author_verify_key.0,
+            expected_author.0,
+            expected_realm.map(|x| x.0),
+        )
+        .map_err(|e| PyValueError::new_err(e.to_string()))
+        .map(|x| Self(Arc::new(x)))
+    }
+
+    fn dump_and_sign<'py>(
+        &self,
+        author_signkey: &SigningKey,
+        py: Python<'py>,
+    ) -> Bound<'py, PyBytes> {
+        PyBytes::new(py, &self.0.dump_and_sign(&author_signkey.0))
+    }
+
+    #[classmethod]
+    fn unsecure_load(_cls: &Bound<'_, PyType>, signed: &[u8]) -> PyResult<Self> {
+        libparsec_types::RealmGroupRoleCertificate::unsecure_load(signed.to_vec().into())
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+            .map(|u| u.skip_validation(UnsecureSkipValidationReason::DataFromLocalStorage))
+            .map(|x| Self(Arc::new(x)))
+    }
+
+    #[getter]
+    fn author(&self) -> DeviceID {
+        self.0.author.into()
+    }
+
+    #[getter]
+    fn timestamp(&self) -> DateTime {
+        self.0.timestamp.into()
+    }
+
+    #[getter]
+    fn realm_id(&self) -> VlobID {
+        self.0.realm_id.into()
+    }
+
+    #[getter]
+    fn group_id(&self) -> GroupID {
+        GroupID(self.0.group_id)
+    }
+
+    #[getter]
+    fn role(&self) -> Option<&'static PyObject> {
+        self.0.role.map(RealmRole::convert)
+    }
 }
 
 crate::binding_utils::gen_py_wrapper_class!(
@@ -58,6 +58,7 @@ impl BlockAccess {
             size: NonZeroU64::try_from(size)
                 .map_err(|_| PyValueError::new_err("Invalid `size` field"))?,
             digest: digest.0,
+            compressed: false,
         }))
     }
 
@@ -152,6 +153,7 @@ impl FileManifest {
             blocksize: libparsec_types::Blocksize::try_from(blocksize)
                 .map_err(|_| PyValueError::new_err("Invalid `blocksize` field"))?,
             blocks: blocks.into_iter().map(|b| b.0).collect(),
+            content_defined_chunking: false,
         }))
     }
 
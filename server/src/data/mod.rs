@@ -21,10 +21,14 @@ pub(crate) fn add_mod(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<PrivateKeyAlgorithm>()?;
     m.add_class::<UserCertificate>()?;
     m.add_class::<RevokedUserCertificate>()?;
+    m.add_class::<RevokedDeviceCertificate>()?;
+    m.add_class::<GroupCertificate>()?;
+    m.add_class::<GroupMemberCertificate>()?;
     m.add_class::<UserUpdateCertificate>()?;
     m.add_class::<SigningKeyAlgorithm>()?;
     m.add_class::<DeviceCertificate>()?;
     m.add_class::<RealmRoleCertificate>()?;
+    m.add_class::<RealmGroupRoleCertificate>()?;
     m.add_class::<RealmNameCertificate>()?;
     m.add_class::<RealmArchivingCertificate>()?;
     m.add_class::<RealmArchivingConfiguration>()?;
@@ -18,6 +18,16 @@ crate::binding_utils::gen_py_wrapper_class_for_enum!(
         rsassa_pss_sha256,
         libparsec_types::PkiSignatureAlgorithm::RsassaPssSha256
     ],
+    [
+        "ECDSA_P256_SHA256",
+        ecdsa_p256_sha256,
+        libparsec_types::PkiSignatureAlgorithm::EcdsaP256Sha256
+    ],
+    [
+        "ECDSA_P384_SHA384",
+        ecdsa_p384_sha384,
+        libparsec_types::PkiSignatureAlgorithm::EcdsaP384Sha384
+    ],
 );
 
 #[pyclass]
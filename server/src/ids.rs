@@ -118,6 +118,18 @@ crate::binding_utils::gen_py_wrapper_class_for_id!(
 );
 gen_uuid!(SequesterServiceID);
 
+crate::binding_utils::gen_py_wrapper_class_for_id!(
+    GroupID,
+    libparsec_types::GroupID,
+    __repr__,
+    __copy__,
+    __deepcopy__,
+    __str__,
+    __richcmp__ ord,
+    __hash__,
+);
+gen_uuid!(GroupID);
+
 crate::binding_utils::gen_py_wrapper_class_for_id!(
     PKIEnrollmentID,
     libparsec_types::PKIEnrollmentID,
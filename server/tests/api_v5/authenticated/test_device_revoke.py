@@ -0,0 +1,366 @@
+# Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+from collections.abc import Awaitable, Callable
+
+import pytest
+
+from parsec._parsec import (
+    DateTime,
+    DeviceID,
+    RevokedDeviceCertificate,
+    RevokedUserCertificate,
+    UserProfile,
+    authenticated_cmds,
+)
+from parsec.events import EventDeviceRevoked
+from tests.common import (
+    Backend,
+    CoolorgRpcClients,
+    HttpCommonErrorsTester,
+    RpcTransportError,
+    bob_becomes_admin_and_changes_alice,
+)
+
+
+@pytest.mark.parametrize("kind", ("as_admin", "as_same_user"))
+async def test_authenticated_device_revoke_ok(
+    coolorg: CoolorgRpcClients, backend: Backend, kind: str
+) -> None:
+    match kind:
+        case "as_admin":
+            author = coolorg.alice
+            to_revoke = coolorg.bob.device_id
+        case "as_same_user":
+            author = coolorg.bob
+            to_revoke = DeviceID.test_from_nickname("bob@dev2")
+        case unknown:
+            assert False, unknown
+
+    certif = RevokedDeviceCertificate(
+        author=author.device_id,
+        timestamp=DateTime.now(),
+        user_id=coolorg.bob.user_id,
+        device_id=to_revoke,
+    )
+    raw_certif = certif.dump_and_sign(author.signing_key)
+
+    expected_topics = await backend.organization.test_dump_topics(coolorg.organization_id)
+    expected_topics.common = certif.timestamp
+
+    with backend.event_bus.spy() as spy:
+        rep = await author.device_revoke(revoked_device_certificate=raw_certif)
+        assert rep == authenticated_cmds.latest.device_revoke.RepOk()
+
+        await spy.wait_event_occurred(
+            EventDeviceRevoked(
+                organization_id=coolorg.organization_id,
+                device_id=to_revoke,
+            )
+        )
+
+    topics = await backend.organization.test_dump_topics(coolorg.organization_id)
+    assert topics == expected_topics
+
+    # The revoked device certificate is now part of the common topic
+    rep = await author.certificate_get(
+        common_after=None,
+        sequester_after=None,
+        shamir_recovery_after=None,
+        realm_after={},
+    )
+    assert isinstance(rep, authenticated_cmds.latest.certificate_get.RepOk)
+    assert rep.common_certificates[-1] == raw_certif
+
+
+async def test_authenticated_device_revoke_revoked_device_cannot_connect(
+    coolorg: CoolorgRpcClients,
+) -> None:
+    certif = RevokedDeviceCertificate(
+        author=coolorg.alice.device_id,
+        timestamp=DateTime.now(),
+        user_id=coolorg.bob.user_id,
+        device_id=coolorg.bob.device_id,
+    )
+    rep = await coolorg.alice.device_revoke(
+        revoked_device_certificate=certif.dump_and_sign(coolorg.alice.signing_key)
+    )
+    assert rep == authenticated_cmds.latest.device_revoke.RepOk()
+
+    with pytest.raises(RpcTransportError) as raised:
+        await coolorg.bob.ping(ping="hello")
+    assert raised.value.rep.status_code == 461
+
+
+async def test_authenticated_device_revoke_disconnect_sse(
+    coolorg: CoolorgRpcClients,
+) -> None:
+    certif = RevokedDeviceCertificate(
+        author=coolorg.alice.device_id,
+        timestamp=DateTime.now(),
+        user_id=coolorg.bob.user_id,
+        device_id=coolorg.bob.device_id,
+    )
+
+    async with coolorg.bob.events_listen() as bob_sse:
+        # 1) Bob starts listening SSE
+        rep = await bob_sse.next_event()  # Server always starts by returning a `ServerConfig` event
+
+        # 2) Then Alice revokes Bob's device
+
+        rep = await coolorg.alice.device_revoke(
+            revoked_device_certificate=certif.dump_and_sign(coolorg.alice.signing_key)
+        )
+        assert rep == authenticated_cmds.latest.device_revoke.RepOk()
+
+        # 3) Hence Bob gets disconnected...
+
+        with pytest.raises(StopAsyncIteration):
+            # Loop given the server might have send us some events before the revocation
+            while True:
+                await bob_sse.next_event()
+
+    # 4) ...and cannot reconnect !
+
+    async with coolorg.bob.raw_sse_connection() as rep:
+        assert rep.status_code == 461
+
+
+@pytest.mark.parametrize(
+    "kind",
+    (
+        "as_outsider",
+        "as_standard",
+        "no_longer_allowed",
+    ),
+)
+async def test_authenticated_device_revoke_author_not_allowed(
+    coolorg: CoolorgRpcClients,
+    backend: Backend,
+    kind: str,
+) -> None:
+    now = DateTime.now()
+    match kind:
+        case "as_outsider":
+            author = coolorg.mallory
+            certif = RevokedDeviceCertificate(
+                author=coolorg.mallory.device_id,
+                timestamp=now,
+                user_id=coolorg.alice.user_id,
+                device_id=coolorg.alice.device_id,
+            )
+
+        case "as_standard":
+            author = coolorg.bob
+            certif = RevokedDeviceCertificate(
+                author=coolorg.bob.device_id,
+                timestamp=now,
+                user_id=coolorg.alice.user_id,
+                device_id=coolorg.alice.device_id,
+            )
+
+        case "no_longer_allowed":
+            await bob_becomes_admin_and_changes_alice(
+                coolorg=coolorg, backend=backend, new_alice_profile=UserProfile.STANDARD
+            )
+            author = coolorg.alice
+            certif = RevokedDeviceCertificate(
+                author=coolorg.alice.device_id,
+                timestamp=DateTime.now(),
+                user_id=coolorg.bob.user_id,
+                device_id=coolorg.bob.device_id,
+            )
+
+        case unknown:
+            assert False, unknown
+
+    rep = await author.device_revoke(
+        revoked_device_certificate=certif.dump_and_sign(author.signing_key)
+    )
+    assert rep == authenticated_cmds.latest.device_revoke.RepAuthorNotAllowed()
+
+
+@pytest.mark.parametrize("kind", ("unknown_device", "user_revoked"))
+async def test_authenticated_device_revoke_device_not_found(
+    coolorg: CoolorgRpcClients, kind: str
+) -> None:
+    match kind:
+        case "unknown_device":
+            user_id = coolorg.bob.user_id
+            device_id = DeviceID.new()
+
+        case "user_revoked":
+            await coolorg.alice.user_revoke(
+                revoked_user_certificate=RevokedUserCertificate(
+                    author=coolorg.alice.device_id,
+                    timestamp=DateTime.now(),
+                    user_id=coolorg.bob.user_id,
+                ).dump_and_sign(coolorg.alice.signing_key)
+            )
+            user_id = coolorg.bob.user_id
+            device_id = coolorg.bob.device_id
+
+        case unknown:
+            assert False, unknown
+
+    certif = RevokedDeviceCertificate(
+        author=coolorg.alice.device_id,
+        timestamp=DateTime.now(),
+        user_id=user_id,
+        device_id=device_id,
+    )
+    rep = await coolorg.alice.device_revoke(
+        revoked_device_certificate=certif.dump_and_sign(coolorg.alice.signing_key)
+    )
+    assert rep == authenticated_cmds.latest.device_revoke.RepDeviceNotFound()
+
+
+async def test_authenticated_device_revoke_device_already_revoked(
+    coolorg: CoolorgRpcClients, backend: Backend
+) -> None:
+    t1 = DateTime.now()
+    certif1 = RevokedDeviceCertificate(
+        author=coolorg.alice.device_id,
+        timestamp=t1,
+        user_id=coolorg.bob.user_id,
+        device_id=coolorg.bob.device_id,
+    )
+
+    outcome = await backend.user.revoke_device(
+        now=t1,
+        organization_id=coolorg.organization_id,
+        author=coolorg.alice.device_id,
+        author_verify_key=coolorg.alice.signing_key.verify_key,
+        revoked_device_certificate=certif1.dump_and_sign(coolorg.alice.signing_key),
+    )
+    assert isinstance(outcome, RevokedDeviceCertificate)
+
+    t2 = DateTime.now()
+    certif2 = RevokedDeviceCertificate(
+        author=coolorg.alice.device_id,
+        timestamp=t2,
+        user_id=coolorg.bob.user_id,
+        device_id=coolorg.bob.device_id,
+    )
+
+    rep = await coolorg.alice.device_revoke(
+        revoked_device_certificate=certif2.dump_and_sign(coolorg.alice.signing_key)
+    )
+    assert rep == authenticated_cmds.latest.device_revoke.RepDeviceAlreadyRevoked(
+        last_common_certificate_timestamp=t1
+    )
+
+
+@pytest.mark.parametrize(
+    "kind",
+    (
+        "dummy_certificate",
+        "self_revoke",
+        "user_mismatch",
+        "author_device_mismatch",
+    ),
+)
+async def test_authenticated_device_revoke_invalid_certificate(
+    coolorg: CoolorgRpcClients,
+    kind: str,
+) -> None:
+    now = DateTime.now()
+
+    match kind:
+        case "dummy_certificate":
+            certif = b"<dummy>"
+        case "self_revoke":
+            certif = RevokedDeviceCertificate(
+                author=coolorg.alice.device_id,
+                timestamp=now,
+                user_id=coolorg.alice.user_id,
+                device_id=coolorg.alice.device_id,
+            ).dump_and_sign(coolorg.alice.signing_key)
+        case "user_mismatch":
+            certif = RevokedDeviceCertificate(
+                author=coolorg.alice.device_id,
+                timestamp=now,
+                user_id=coolorg.mallory.user_id,
+                device_id=coolorg.bob.device_id,
+            ).dump_and_sign(coolorg.alice.signing_key)
+        case "author_device_mismatch":
+            certif = RevokedDeviceCertificate(
+                author=DeviceID.test_from_nickname("alice@dev2"),
+                timestamp=now,
+                user_id=coolorg.bob.user_id,
+                device_id=coolorg.bob.device_id,
+            ).dump_and_sign(coolorg.alice.signing_key)
+        case unknown:
+            assert False, unknown
+
+    rep = await coolorg.alice.device_revoke(revoked_device_certificate=certif)
+    assert rep == authenticated_cmds.latest.device_revoke.RepInvalidCertificate()
+
+
+async def test_authenticated_device_revoke_timestamp_out_of_ballpark(
+    coolorg: CoolorgRpcClients,
+) -> None:
+    t0 = DateTime.now().subtract(seconds=3600)
+    certif = RevokedDeviceCertificate(
+        author=coolorg.alice.device_id,
+        timestamp=t0,
+        user_id=coolorg.bob.user_id,
+        device_id=coolorg.bob.device_id,
+    ).dump_and_sign(coolorg.alice.signing_key)
+
+    rep = await coolorg.alice.device_revoke(revoked_device_certificate=certif)
+    assert isinstance(rep, authenticated_cmds.latest.device_revoke.RepTimestampOutOfBallpark)
+    assert rep.ballpark_client_early_offset == 300.0
+    assert rep.ballpark_client_late_offset == 320.0
+    assert rep.client_timestamp == t0
+
+
+@pytest.mark.parametrize("timestamp_kind", ("same_timestamp", "previous_timestamp"))
+async def test_authenticated_device_revoke_require_greater_timestamp(
+    coolorg: CoolorgRpcClients,
+    timestamp_kind: str,
+    alice_generated_data: Callable[[DateTime], Awaitable[None]],
+) -> None:
+    # 1) Alice creates some data (e.g. certificate, vlob) with a given timestamp
+
+    now = DateTime.now()
+    match timestamp_kind:
+        case "same_timestamp":
+            device_revoke_timestamp = now
+        case "previous_timestamp":
+            device_revoke_timestamp = now.subtract(seconds=1)
+        case unknown:
+            assert False, unknown
+
+    await alice_generated_data(now)
+
+    # 2) Alice revokes her other device, but at a timestamp that would make her
+    #    previous data invalid !
+
+    certif = RevokedDeviceCertificate(
+        author=coolorg.alice.device_id,
+        timestamp=device_revoke_timestamp,
+        user_id=coolorg.alice.user_id,
+        device_id=DeviceID.test_from_nickname("alice@dev2"),
+    ).dump_and_sign(coolorg.alice.signing_key)
+
+    rep = await coolorg.alice.device_revoke(revoked_device_certificate=certif)
+    assert rep == authenticated_cmds.latest.device_revoke.RepRequireGreaterTimestamp(
+        strictly_greater_than=now
+    )
+
+
+async def test_authenticated_device_revoke_http_common_errors(
+    coolorg: CoolorgRpcClients, authenticated_http_common_errors_tester: HttpCommonErrorsTester
+) -> None:
+    async def do():
+        certif = RevokedDeviceCertificate(
+            author=coolorg.alice.device_id,
+            timestamp=DateTime.now(),
+            user_id=coolorg.bob.user_id,
+            device_id=coolorg.bob.device_id,
+        )
+        await coolorg.alice.device_revoke(
+            revoked_device_certificate=certif.dump_and_sign(coolorg.alice.signing_key)
+        )
+
+    await authenticated_http_common_errors_tester(do)
@@ -153,6 +153,7 @@ async def test_authenticated_events_listen_ok(
                 user_profile_outsider_allowed=True,
                 sse_keepalive_seconds=30,
                 realm_minimum_archiving_period_before_deletion=2592000,
+                pki_crls=None,
             )
         )
 
@@ -193,6 +194,7 @@ async def test_receive_server_config_as_first_event(
                 user_profile_outsider_allowed=False,
                 sse_keepalive_seconds=42,
                 realm_minimum_archiving_period_before_deletion=666,
+                pki_crls=None,
             )
         )
 
@@ -225,6 +227,7 @@ async def test_user_not_receive_event_before_listen(
                 user_profile_outsider_allowed=True,
                 sse_keepalive_seconds=30,
                 realm_minimum_archiving_period_before_deletion=2592000,
+                pki_crls=None,
             )
         )
 
@@ -265,6 +268,7 @@ async def test_conn_closed_on_bad_outcome(
                 user_profile_outsider_allowed=True,
                 sse_keepalive_seconds=30,
                 realm_minimum_archiving_period_before_deletion=2592000,
+                pki_crls=None,
             )
         )
 
@@ -310,6 +314,7 @@ async def test_self_vlob_events_skipped(
                 user_profile_outsider_allowed=True,
                 sse_keepalive_seconds=30,
                 realm_minimum_archiving_period_before_deletion=2592000,
+                pki_crls=None,
             )
         )
 
@@ -359,6 +364,7 @@ async def test_self_certificate_events_provided(
                 user_profile_outsider_allowed=True,
                 sse_keepalive_seconds=30,
                 realm_minimum_archiving_period_before_deletion=2592000,
+                pki_crls=None,
             )
         )
 
@@ -431,6 +437,7 @@ async def test_receive_event_of_newly_shared_realm(
                 user_profile_outsider_allowed=True,
                 sse_keepalive_seconds=30,
                 realm_minimum_archiving_period_before_deletion=2592000,
+                pki_crls=None,
             )
         )
 
@@ -576,6 +583,7 @@ async def test_last_event_id(minimalorg: MinimalorgRpcClients, backend: Backend)
                 user_profile_outsider_allowed=True,
                 sse_keepalive_seconds=30,
                 realm_minimum_archiving_period_before_deletion=2592000,
+                pki_crls=None,
             )
         )
 
@@ -602,6 +610,7 @@ async def test_last_event_id(minimalorg: MinimalorgRpcClients, backend: Backend)
                 user_profile_outsider_allowed=True,
                 sse_keepalive_seconds=30,
                 realm_minimum_archiving_period_before_deletion=2592000,
+                pki_crls=None,
             )
         )
 
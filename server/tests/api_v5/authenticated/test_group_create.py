@@ -0,0 +1,238 @@
+# Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+import pytest
+
+from parsec._parsec import (
+    DateTime,
+    DeviceID,
+    GroupCertificate,
+    GroupID,
+    UserProfile,
+    authenticated_cmds,
+)
+from parsec.events import EventCommonCertificate
+from tests.common import (
+    Backend,
+    CoolorgRpcClients,
+    HttpCommonErrorsTester,
+    bob_becomes_admin_and_changes_alice,
+)
+
+
+async def test_authenticated_group_create_ok(coolorg: CoolorgRpcClients, backend: Backend) -> None:
+    certif = GroupCertificate(
+        author=coolorg.alice.device_id,
+        timestamp=DateTime.now(),
+        group_id=GroupID.new(),
+        name="Engineering",
+    )
+    raw_certif = certif.dump_and_sign(coolorg.alice.signing_key)
+
+    expected_topics = await backend.organization.test_dump_topics(coolorg.organization_id)
+    expected_topics.common = certif.timestamp
+
+    with backend.event_bus.spy() as spy:
+        rep = await coolorg.alice.group_create(group_certificate=raw_certif)
+        assert rep == authenticated_cmds.latest.group_create.RepOk()
+
+        await spy.wait_event_occurred(
+            EventCommonCertificate(
+                organization_id=coolorg.organization_id,
+                timestamp=certif.timestamp,
+            )
+        )
+
+    topics = await backend.organization.test_dump_topics(coolorg.organization_id)
+    assert topics == expected_topics
+
+    # The group certificate is now part of the common topic
+    rep = await coolorg.bob.certificate_get(
+        common_after=None,
+        sequester_after=None,
+        shamir_recovery_after=None,
+        realm_after={},
+    )
+    assert isinstance(rep, authenticated_cmds.latest.certificate_get.RepOk)
+    assert rep.common_certificates[-1] == raw_certif
+
+
+@pytest.mark.parametrize(
+    "kind",
+    (
+        "as_outsider",
+        "as_standard",
+        "no_longer_allowed",
+    ),
+)
+async def test_authenticated_group_create_author_not_allowed(
+    coolorg: CoolorgRpcClients,
+    backend: Backend,
+    kind: str,
+) -> None:
+    match kind:
+        case "as_outsider":
+            author = coolorg.mallory
+
+        case "as_standard":
+            author = coolorg.bob
+
+        case "no_longer_allowed":
+            await bob_becomes_admin_and_changes_alice(
+                coolorg=coolorg, backend=backend, new_alice_profile=UserProfile.STANDARD
+            )
+            author = coolorg.alice
+
+        case unknown:
+            assert False, unknown
+
+    certif = GroupCertificate(
+        author=author.device_id,
+        timestamp=DateTime.now(),
+        group_id=GroupID.new(),
+        name="Engineering",
+    )
+    rep = await author.group_create(group_certificate=certif.dump_and_sign(author.signing_key))
+    assert rep == authenticated_cmds.latest.group_create.RepAuthorNotAllowed()
+
+
+async def test_authenticated_group_create_group_already_exists(
+    coolorg: CoolorgRpcClients, backend: Backend
+) -> None:
+    group_id = GroupID.new()
+    t1 = DateTime.now()
+    certif1 = GroupCertificate(
+        author=coolorg.alice.device_id,
+        timestamp=t1,
+        group_id=group_id,
+        name="Engineering",
+    )
+    outcome = await backend.user.create_group(
+        now=t1,
+        organization_id=coolorg.organization_id,
+        author=coolorg.alice.device_id,
+        author_verify_key=coolorg.alice.signing_key.verify_key,
+        group_certificate=certif1.dump_and_sign(coolorg.alice.signing_key),
+    )
+    assert isinstance(outcome, GroupCertificate)
+
+    certif2 = GroupCertificate(
+        author=coolorg.alice.device_id,
+        timestamp=DateTime.now(),
+        group_id=group_id,
+        name="Marketing",
+    )
+    rep = await coolorg.alice.group_create(
+        group_certificate=certif2.dump_and_sign(coolorg.alice.signing_key)
+    )
+    assert rep == authenticated_cmds.latest.group_create.RepGroupAlreadyExists(
+        last_common_certificate_timestamp=t1
+    )
+
+
+@pytest.mark.parametrize(
+    "kind",
+    (
+        "dummy_certificate",
+        "author_device_mismatch",
+    ),
+)
+async def test_authenticated_group_create_invalid_certificate(
+    coolorg: CoolorgRpcClients,
+    kind: str,
+) -> None:
+    match kind:
+        case "dummy_certificate":
+            certif = b"<dummy>"
+        case "author_device_mismatch":
+            certif = GroupCertificate(
+                author=DeviceID.test_from_nickname("alice@dev2"),
+                timestamp=DateTime.now(),
+                group_id=GroupID.new(),
+                name="Engineering",
+            ).dump_and_sign(coolorg.alice.signing_key)
+        case unknown:
+            assert False, unknown
+
+    rep = await coolorg.alice.group_create(group_certificate=certif)
+    assert rep == authenticated_cmds.latest.group_create.RepInvalidCertificate()
+
+
+async def test_authenticated_group_create_timestamp_out_of_ballpark(
+    coolorg: CoolorgRpcClients,
+) -> None:
+    t0 = DateTime.now().subtract(seconds=3600)
+    certif = GroupCertificate(
+        author=coolorg.alice.device_id,
+        timestamp=t0,
+        group_id=GroupID.new(),
+        name="Engineering",
+    ).dump_and_sign(coolorg.alice.signing_key)
+
+    rep = await coolorg.alice.group_create(group_certificate=certif)
+    assert isinstance(rep, authenticated_cmds.latest.group_create.RepTimestampOutOfBallpark)
+    assert rep.ballpark_client_early_offset == 300.0
+    assert rep.ballpark_client_late_offset == 320.0
+    assert rep.client_timestamp == t0
+
+
+@pytest.mark.parametrize("timestamp_kind", ("same_timestamp", "previous_timestamp"))
+async def test_authenticated_group_create_require_greater_timestamp(
+    coolorg: CoolorgRpcClients,
+    backend: Backend,
+    timestamp_kind: str,
+) -> None:
+    # 1) Create a common certificate with a given timestamp
+
+    now = DateTime.now()
+    match timestamp_kind:
+        case "same_timestamp":
+            group_create_timestamp = now
+        case "previous_timestamp":
+            group_create_timestamp = now.subtract(seconds=1)
+        case unknown:
+            assert False, unknown
+
+    outcome = await backend.user.create_group(
+        now=now,
+        organization_id=coolorg.organization_id,
+        author=coolorg.alice.device_id,
+        author_verify_key=coolorg.alice.signing_key.verify_key,
+        group_certificate=GroupCertificate(
+            author=coolorg.alice.device_id,
+            timestamp=now,
+            group_id=GroupID.new(),
+            name="Engineering",
+        ).dump_and_sign(coolorg.alice.signing_key),
+    )
+    assert isinstance(outcome, GroupCertificate)
+
+    # 2) Try to create another group at a timestamp that would break causality
+
+    certif = GroupCertificate(
+        author=coolorg.alice.device_id,
+        timestamp=group_create_timestamp,
+        group_id=GroupID.new(),
+        name="Marketing",
+    ).dump_and_sign(coolorg.alice.signing_key)
+
+    rep = await coolorg.alice.group_create(group_certificate=certif)
+    assert rep == authenticated_cmds.latest.group_create.RepRequireGreaterTimestamp(
+        strictly_greater_than=now
+    )
+
+
+async def test_authenticated_group_create_http_common_errors(
+    coolorg: CoolorgRpcClients, authenticated_http_common_errors_tester: HttpCommonErrorsTester
+) -> None:
+    async def do():
+        certif = GroupCertificate(
+            author=coolorg.alice.device_id,
+            timestamp=DateTime.now(),
+            group_id=GroupID.new(),
+            name="Engineering",
+        )
+        await coolorg.alice.group_create(
+            group_certificate=certif.dump_and_sign(coolorg.alice.signing_key)
+        )
+
+    await authenticated_http_common_errors_tester(do)
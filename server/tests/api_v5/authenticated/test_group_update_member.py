@@ -0,0 +1,353 @@
+# Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+import pytest
+
+from parsec._parsec import (
+    DateTime,
+    DeviceID,
+    GroupCertificate,
+    GroupID,
+    GroupMemberCertificate,
+    RevokedUserCertificate,
+    UserID,
+    authenticated_cmds,
+)
+from parsec.events import EventCommonCertificate
+from tests.common import (
+    Backend,
+    CoolorgRpcClients,
+    HttpCommonErrorsTester,
+)
+
+
+async def alice_creates_group(
+    coolorg: CoolorgRpcClients, backend: Backend, now: DateTime | None = None
+) -> GroupID:
+    now = now or DateTime.now()
+    group_id = GroupID.new()
+    outcome = await backend.user.create_group(
+        now=now,
+        organization_id=coolorg.organization_id,
+        author=coolorg.alice.device_id,
+        author_verify_key=coolorg.alice.signing_key.verify_key,
+        group_certificate=GroupCertificate(
+            author=coolorg.alice.device_id,
+            timestamp=now,
+            group_id=group_id,
+            name="Engineering",
+        ).dump_and_sign(coolorg.alice.signing_key),
+    )
+    assert isinstance(outcome, GroupCertificate)
+    return group_id
+
+
+async def alice_updates_group_member(
+    coolorg: CoolorgRpcClients,
+    backend: Backend,
+    group_id: GroupID,
+    user_id: UserID,
+    is_member: bool,
+    now: DateTime | None = None,
+) -> None:
+    now = now or DateTime.now()
+    outcome = await backend.user.update_group_member(
+        now=now,
+        organization_id=coolorg.organization_id,
+        author=coolorg.alice.device_id,
+        author_verify_key=coolorg.alice.signing_key.verify_key,
+        group_member_certificate=GroupMemberCertificate(
+            author=coolorg.alice.device_id,
+            timestamp=now,
+            group_id=group_id,
+            user_id=user_id,
+            is_member=is_member,
+        ).dump_and_sign(coolorg.alice.signing_key),
+    )
+    assert isinstance(outcome, GroupMemberCertificate)
+
+
+@pytest.mark.parametrize("kind", ("add_member", "remove_member"))
+async def test_authenticated_group_update_member_ok(
+    coolorg: CoolorgRpcClients, backend: Backend, kind: str
+) -> None:
+    group_id = await alice_creates_group(coolorg, backend)
+    match kind:
+        case "add_member":
+            is_member = True
+        case "remove_member":
+            await alice_updates_group_member(coolorg, backend, group_id, coolorg.bob.user_id, True)
+            is_member = False
+        case unknown:
+            assert False, unknown
+
+    certif = GroupMemberCertificate(
+        author=coolorg.alice.device_id,
+        timestamp=DateTime.now(),
+        group_id=group_id,
+        user_id=coolorg.bob.user_id,
+        is_member=is_member,
+    )
+    raw_certif = certif.dump_and_sign(coolorg.alice.signing_key)
+
+    expected_topics = await backend.organization.test_dump_topics(coolorg.organization_id)
+    expected_topics.common = certif.timestamp
+
+    with backend.event_bus.spy() as spy:
+        rep = await coolorg.alice.group_update_member(group_member_certificate=raw_certif)
+        assert rep == authenticated_cmds.latest.group_update_member.RepOk()
+
+        await spy.wait_event_occurred(
+            EventCommonCertificate(
+                organization_id=coolorg.organization_id,
+                timestamp=certif.timestamp,
+            )
+        )
+
+    topics = await backend.organization.test_dump_topics(coolorg.organization_id)
+    assert topics == expected_topics
+
+
+@pytest.mark.parametrize("kind", ("as_outsider", "as_standard"))
+async def test_authenticated_group_update_member_author_not_allowed(
+    coolorg: CoolorgRpcClients,
+    backend: Backend,
+    kind: str,
+) -> None:
+    group_id = await alice_creates_group(coolorg, backend)
+    match kind:
+        case "as_outsider":
+            author = coolorg.mallory
+        case "as_standard":
+            author = coolorg.bob
+        case unknown:
+            assert False, unknown
+
+    certif = GroupMemberCertificate(
+        author=author.device_id,
+        timestamp=DateTime.now(),
+        group_id=group_id,
+        user_id=coolorg.bob.user_id,
+        is_member=True,
+    )
+    rep = await author.group_update_member(
+        group_member_certificate=certif.dump_and_sign(author.signing_key)
+    )
+    assert rep == authenticated_cmds.latest.group_update_member.RepAuthorNotAllowed()
+
+
+async def test_authenticated_group_update_member_group_not_found(
+    coolorg: CoolorgRpcClients,
+) -> None:
+    certif = GroupMemberCertificate(
+        author=coolorg.alice.device_id,
+        timestamp=DateTime.now(),
+        group_id=GroupID.new(),
+        user_id=coolorg.bob.user_id,
+        is_member=True,
+    )
+    rep = await coolorg.alice.group_update_member(
+        group_member_certificate=certif.dump_and_sign(coolorg.alice.signing_key)
+    )
+    assert rep == authenticated_cmds.latest.group_update_member.RepGroupNotFound()
+
+
+async def test_authenticated_group_update_member_user_not_found(
+    coolorg: CoolorgRpcClients, backend: Backend
+) -> None:
+    group_id = await alice_creates_group(coolorg, backend)
+    certif = GroupMemberCertificate(
+        author=coolorg.alice.device_id,
+        timestamp=DateTime.now(),
+        group_id=group_id,
+        user_id=UserID.new(),
+        is_member=True,
+    )
+    rep = await coolorg.alice.group_update_member(
+        group_member_certificate=certif.dump_and_sign(coolorg.alice.signing_key)
+    )
+    assert rep == authenticated_cmds.latest.group_update_member.RepUserNotFound()
+
+
+@pytest.mark.parametrize("kind", ("add_member", "remove_member"))
+async def test_authenticated_group_update_member_user_revoked(
+    coolorg: CoolorgRpcClients, backend: Backend, kind: str
+) -> None:
+    group_id = await alice_creates_group(coolorg, backend)
+    await alice_updates_group_member(coolorg, backend, group_id, coolorg.mallory.user_id, True)
+    rep = await coolorg.alice.user_revoke(
+        revoked_user_certificate=RevokedUserCertificate(
+            author=coolorg.alice.device_id,
+            timestamp=DateTime.now(),
+            user_id=coolorg.bob.user_id,
+        ).dump_and_sign(coolorg.alice.signing_key)
+    )
+    assert rep == authenticated_cmds.latest.user_revoke.RepOk()
+    rep = await coolorg.alice.user_revoke(
+        revoked_user_certificate=RevokedUserCertificate(
+            author=coolorg.alice.device_id,
+            timestamp=DateTime.now(),
+            user_id=coolorg.mallory.user_id,
+        ).dump_and_sign(coolorg.alice.signing_key)
+    )
+    assert rep == authenticated_cmds.latest.user_revoke.RepOk()
+
+    match kind:
+        case "add_member":
+            user_id = coolorg.bob.user_id
+            is_member = True
+            expected_rep = authenticated_cmds.latest.group_update_member.RepUserRevoked()
+        case "remove_member":
+            # A revoked user can still be removed from a group
+            user_id = coolorg.mallory.user_id
+            is_member = False
+            expected_rep = authenticated_cmds.latest.group_update_member.RepOk()
+        case unknown:
+            assert False, unknown
+
+    certif = GroupMemberCertificate(
+        author=coolorg.alice.device_id,
+        timestamp=DateTime.now(),
+        group_id=group_id,
+        user_id=user_id,
+        is_member=is_member,
+    )
+    rep = await coolorg.alice.group_update_member(
+        group_member_certificate=certif.dump_and_sign(coolorg.alice.signing_key)
+    )
+    assert rep == expected_rep
+
+
+@pytest.mark.parametrize("kind", ("already_member", "never_member"))
+async def test_authenticated_group_update_member_member_already_up_to_date(
+    coolorg: CoolorgRpcClients, backend: Backend, kind: str
+) -> None:
+    group_id = await alice_creates_group(coolorg, backend)
+    match kind:
+        case "already_member":
+            await alice_updates_group_member(coolorg, backend, group_id, coolorg.bob.user_id, True)
+            is_member = True
+        case "never_member":
+            is_member = False
+        case unknown:
+            assert False, unknown
+
+    topics = await backend.organization.test_dump_topics(coolorg.organization_id)
+
+    certif = GroupMemberCertificate(
+        author=coolorg.alice.device_id,
+        timestamp=DateTime.now(),
+        group_id=group_id,
+        user_id=coolorg.bob.user_id,
+        is_member=is_member,
+    )
+    rep = await coolorg.alice.group_update_member(
+        group_member_certificate=certif.dump_and_sign(coolorg.alice.signing_key)
+    )
+    assert rep == authenticated_cmds.latest.group_update_member.RepMemberAlreadyUpToDate(
+        last_common_certificate_timestamp=topics.common
+    )
+
+
+@pytest.mark.parametrize(
+    "kind",
+    (
+        "dummy_certificate",
+        "author_device_mismatch",
+    ),
+)
+async def test_authenticated_group_update_member_invalid_certificate(
+    coolorg: CoolorgRpcClients,
+    backend: Backend,
+    kind: str,
+) -> None:
+    group_id = await alice_creates_group(coolorg, backend)
+    match kind:
+        case "dummy_certificate":
+            certif = b"<dummy>"
+        case "author_device_mismatch":
+            certif = GroupMemberCertificate(
+                author=DeviceID.test_from_nickname("alice@dev2"),
+                timestamp=DateTime.now(),
+                group_id=group_id,
+                user_id=coolorg.bob.user_id,
+                is_member=True,
+            ).dump_and_sign(coolorg.alice.signing_key)
+        case unknown:
+            assert False, unknown
+
+    rep = await coolorg.alice.group_update_member(group_member_certificate=certif)
+    assert rep == authenticated_cmds.latest.group_update_member.RepInvalidCertificate()
+
+
+async def test_authenticated_group_update_member_timestamp_out_of_ballpark(
+    coolorg: CoolorgRpcClients, backend: Backend
+) -> None:
+    group_id = await alice_creates_group(coolorg, backend)
+    t0 = DateTime.now().subtract(seconds=3600)
+    certif = GroupMemberCertificate(
+        author=coolorg.alice.device_id,
+        timestamp=t0,
+        group_id=group_id,
+        user_id=coolorg.bob.user_id,
+        is_member=True,
+    ).dump_and_sign(coolorg.alice.signing_key)
+
+    rep = await coolorg.alice.group_update_member(group_member_certificate=certif)
+    assert isinstance(rep, authenticated_cmds.latest.group_update_member.RepTimestampOutOfBallpark)
+    assert rep.ballpark_client_early_offset == 300.0
+    assert rep.ballpark_client_late_offset == 320.0
+    assert rep.client_timestamp == t0
+
+
+@pytest.mark.parametrize("timestamp_kind", ("same_timestamp", "previous_timestamp"))
+async def test_authenticated_group_update_member_require_greater_timestamp(
+    coolorg: CoolorgRpcClients,
+    backend: Backend,
+    timestamp_kind: str,
+) -> None:
+    now = DateTime.now()
+    match timestamp_kind:
+        case "same_timestamp":
+            update_timestamp = now
+        case "previous_timestamp":
+            update_timestamp = now.subtract(seconds=1)
+        case unknown:
+            assert False, unknown
+
+    # The group creation is the last common certificate
+    group_id = await alice_creates_group(coolorg, backend, now=now)
+
+    certif = GroupMemberCertificate(
+        author=coolorg.alice.device_id,
+        timestamp=update_timestamp,
+        group_id=group_id,
+        user_id=coolorg.bob.user_id,
+        is_member=True,
+    ).dump_and_sign(coolorg.alice.signing_key)
+
+    rep = await coolorg.alice.group_update_member(group_member_certificate=certif)
+    assert rep == authenticated_cmds.latest.group_update_member.RepRequireGreaterTimestamp(
+        strictly_greater_than=now
+    )
+
+
+async def test_authenticated_group_update_member_http_common_errors(
+    coolorg: CoolorgRpcClients,
+    backend: Backend,
+    authenticated_http_common_errors_tester: HttpCommonErrorsTester,
+) -> None:
+    group_id = await alice_creates_group(coolorg, backend)
+
+    async def do():
+        certif = GroupMemberCertificate(
+            author=coolorg.alice.device_id,
+            timestamp=DateTime.now(),
+            group_id=group_id,
+            user_id=coolorg.bob.user_id,
+            is_member=True,
+        )
+        await coolorg.alice.group_update_member(
+            group_member_certificate=certif.dump_and_sign(coolorg.alice.signing_key)
+        )
+
+    await authenticated_http_common_errors_tester(do)
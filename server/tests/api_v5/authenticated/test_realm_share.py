@@ -6,6 +6,7 @@ import pytest
 
 from parsec._parsec import (
     DateTime,
+    GroupID,
     HashAlgorithm,
     RealmKeyRotationCertificate,
     RealmRole,
@@ -224,6 +225,7 @@ async def test_authenticated_realm_share_role_already_granted(
         "role_none",
         "self_share_and_only_owner",
         "self_share_with_other_owners",
+        "unknown_group",
     ),
 )
 async def test_authenticated_realm_share_invalid_certificate(
@@ -251,6 +253,16 @@ async def test_authenticated_realm_share_invalid_certificate(
                 coolorg, user_id=coolorg.alice.user_id, role=RealmRole.CONTRIBUTOR
             ).dump_and_sign(coolorg.alice.signing_key)
 
+        case "unknown_group":
+            certif = RealmRoleCertificate(
+                author=coolorg.alice.device_id,
+                timestamp=DateTime.now(),
+                realm_id=coolorg.wksp1_id,
+                user_id=coolorg.bob.user_id,
+                role=RealmRole.CONTRIBUTOR,
+                group_id=GroupID.new(),
+            ).dump_and_sign(coolorg.alice.signing_key)
+
         case unknown:
             assert False, unknown
 
@@ -0,0 +1,345 @@
+# Parsec Cloud (https://parsec.cloud) Copyright (c) BUSL-1.1 2016-present Scille SAS
+
+import pytest
+
+from parsec._parsec import (
+    DateTime,
+    DeviceID,
+    GroupCertificate,
+    GroupID,
+    RealmGroupRoleCertificate,
+    RealmRole,
+    VlobID,
+    authenticated_cmds,
+)
+from parsec.events import EventRealmCertificate
+from tests.common import (
+    Backend,
+    CoolorgRpcClients,
+    HttpCommonErrorsTester,
+    WorkspaceArchivedOrgRpcClients,
+    wksp1_alice_gives_role,
+)
+
+
+async def alice_creates_group(coolorg: CoolorgRpcClients, backend: Backend) -> GroupID:
+    now = DateTime.now()
+    group_id = GroupID.new()
+    outcome = await backend.user.create_group(
+        now=now,
+        organization_id=coolorg.organization_id,
+        author=coolorg.alice.device_id,
+        author_verify_key=coolorg.alice.signing_key.verify_key,
+        group_certificate=GroupCertificate(
+            author=coolorg.alice.device_id,
+            timestamp=now,
+            group_id=group_id,
+            name="Engineering",
+        ).dump_and_sign(coolorg.alice.signing_key),
+    )
+    assert isinstance(outcome, GroupCertificate)
+    return group_id
+
+
+async def wksp1_alice_gives_group_role(
+    coolorg: CoolorgRpcClients,
+    backend: Backend,
+    group_id: GroupID,
+    role: RealmRole | None,
+    now: DateTime | None = None,
+) -> None:
+    now = now or DateTime.now()
+    outcome = await backend.realm.update_group_role(
+        now=now,
+        organization_id=coolorg.organization_id,
+        author=coolorg.alice.device_id,
+        author_verify_key=coolorg.alice.signing_key.verify_key,
+        realm_group_role_certificate=RealmGroupRoleCertificate(
+            author=coolorg.alice.device_id,
+            timestamp=now,
+            realm_id=coolorg.wksp1_id,
+            group_id=group_id,
+            role=role,
+        ).dump_and_sign(coolorg.alice.signing_key),
+    )
+    assert isinstance(outcome, RealmGroupRoleCertificate)
+
+
+@pytest.mark.parametrize("kind", ("give_role", "change_role", "remove_role"))
+async def test_authenticated_realm_update_group_role_ok(
+    coolorg: CoolorgRpcClients, backend: Backend, kind: str
+) -> None:
+    group_id = await alice_creates_group(coolorg, backend)
+    match kind:
+        case "give_role":
+            role = RealmRole.CONTRIBUTOR
+        case "change_role":
+            await wksp1_alice_gives_group_role(coolorg, backend, group_id, RealmRole.CONTRIBUTOR)
+            role = RealmRole.READER
+        case "remove_role":
+            await wksp1_alice_gives_group_role(coolorg, backend, group_id, RealmRole.CONTRIBUTOR)
+            role = None
+        case unknown:
+            assert False, unknown
+
+    certif = RealmGroupRoleCertificate(
+        author=coolorg.alice.device_id,
+        timestamp=DateTime.now(),
+        realm_id=coolorg.wksp1_id,
+        group_id=group_id,
+        role=role,
+    )
+    raw_certif = certif.dump_and_sign(coolorg.alice.signing_key)
+
+    expected_topics = await backend.organization.test_dump_topics(coolorg.organization_id)
+    expected_topics.realms[coolorg.wksp1_id] = certif.timestamp
+
+    with backend.event_bus.spy() as spy:
+        rep = await coolorg.alice.realm_update_group_role(realm_group_role_certificate=raw_certif)
+        assert rep == authenticated_cmds.latest.realm_update_group_role.RepOk()
+
+        await spy.wait_event_occurred(
+            EventRealmCertificate(
+                organization_id=coolorg.organization_id,
+                timestamp=certif.timestamp,
+                realm_id=coolorg.wksp1_id,
+                user_id=coolorg.alice.user_id,
+                role_removed=False,
+            )
+        )
+
+    topics = await backend.organization.test_dump_topics(coolorg.organization_id)
+    assert topics == expected_topics
+
+
+@pytest.mark.parametrize("kind", ("as_reader", "as_manager", "no_access"))
+async def test_authenticated_realm_update_group_role_author_not_allowed(
+    coolorg: CoolorgRpcClients,
+    backend: Backend,
+    kind: str,
+) -> None:
+    group_id = await alice_creates_group(coolorg, backend)
+    match kind:
+        case "as_reader":
+            # Bob has access to the realm as READER
+            author = coolorg.bob
+        case "as_manager":
+            await wksp1_alice_gives_role(coolorg, backend, coolorg.bob.user_id, RealmRole.MANAGER)
+            author = coolorg.bob
+        case "no_access":
+            # Mallory has no access to the realm !
+            author = coolorg.mallory
+        case unknown:
+            assert False, unknown
+
+    certif = RealmGroupRoleCertificate(
+        author=author.device_id,
+        timestamp=DateTime.now(),
+        realm_id=coolorg.wksp1_id,
+        group_id=group_id,
+        role=RealmRole.READER,
+    )
+    rep = await author.realm_update_group_role(
+        realm_group_role_certificate=certif.dump_and_sign(author.signing_key)
+    )
+    assert rep == authenticated_cmds.latest.realm_update_group_role.RepAuthorNotAllowed()
+
+
+async def test_authenticated_realm_update_group_role_realm_not_found(
+    coolorg: CoolorgRpcClients, backend: Backend
+) -> None:
+    group_id = await alice_creates_group(coolorg, backend)
+    certif = RealmGroupRoleCertificate(
+        author=coolorg.alice.device_id,
+        timestamp=DateTime.now(),
+        realm_id=VlobID.new(),
+        group_id=group_id,
+        role=RealmRole.READER,
+    )
+    rep = await coolorg.alice.realm_update_group_role(
+        realm_group_role_certificate=certif.dump_and_sign(coolorg.alice.signing_key)
+    )
+    assert rep == authenticated_cmds.latest.realm_update_group_role.RepRealmNotFound()
+
+
+async def test_authenticated_realm_update_group_role_realm_deleted(
+    workspace_archived_org: WorkspaceArchivedOrgRpcClients,
+) -> None:
+    certif = RealmGroupRoleCertificate(
+        author=workspace_archived_org.alice.device_id,
+        timestamp=DateTime.now(),
+        realm_id=workspace_archived_org.wksp_deleted_id,
+        group_id=GroupID.new(),
+        role=RealmRole.READER,
+    )
+    rep = await workspace_archived_org.alice.realm_update_group_role(
+        realm_group_role_certificate=certif.dump_and_sign(workspace_archived_org.alice.signing_key)
+    )
+    assert rep == authenticated_cmds.latest.realm_update_group_role.RepRealmDeleted()
+
+
+async def test_authenticated_realm_update_group_role_group_not_found(
+    coolorg: CoolorgRpcClients,
+) -> None:
+    certif = RealmGroupRoleCertificate(
+        author=coolorg.alice.device_id,
+        timestamp=DateTime.now(),
+        realm_id=coolorg.wksp1_id,
+        group_id=GroupID.new(),
+        role=RealmRole.READER,
+    )
+    rep = await coolorg.alice.realm_update_group_role(
+        realm_group_role_certificate=certif.dump_and_sign(coolorg.alice.signing_key)
+    )
+    assert rep == authenticated_cmds.latest.realm_update_group_role.RepGroupNotFound()
+
+
+@pytest.mark.parametrize("kind", ("same_role", "never_had_role"))
+async def test_authenticated_realm_update_group_role_group_role_already_granted(
+    coolorg: CoolorgRpcClients, backend: Backend, kind: str
+) -> None:
+    group_id = await alice_creates_group(coolorg, backend)
+    match kind:
+        case "same_role":
+            await wksp1_alice_gives_group_role(coolorg, backend, group_id, RealmRole.READER)
+            role = RealmRole.READER
+        case "never_had_role":
+            role = None
+        case unknown:
+            assert False, unknown
+
+    topics = await backend.organization.test_dump_topics(coolorg.organization_id)
+
+    certif = RealmGroupRoleCertificate(
+        author=coolorg.alice.device_id,
+        timestamp=DateTime.now(),
+        realm_id=coolorg.wksp1_id,
+        group_id=group_id,
+        role=role,
+    )
+    rep = await coolorg.alice.realm_update_group_role(
+        realm_group_role_certificate=certif.dump_and_sign(coolorg.alice.signing_key)
+    )
+    assert rep == authenticated_cmds.latest.realm_update_group_role.RepGroupRoleAlreadyGranted(
+        last_realm_certificate_timestamp=topics.realms[coolorg.wksp1_id]
+    )
+
+
+@pytest.mark.parametrize(
+    "kind",
+    (
+        "dummy_certificate",
+        "author_device_mismatch",
+        "owner_role",
+        "manager_role",
+    ),
+)
+async def test_authenticated_realm_update_group_role_invalid_certificate(
+    coolorg: CoolorgRpcClients,
+    backend: Backend,
+    kind: str,
+) -> None:
+    group_id = await alice_creates_group(coolorg, backend)
+    match kind:
+        case "dummy_certificate":
+            certif = b"<dummy>"
+        case "author_device_mismatch":
+            certif = RealmGroupRoleCertificate(
+                author=DeviceID.test_from_nickname("alice@dev2"),
+                timestamp=DateTime.now(),
+                realm_id=coolorg.wksp1_id,
+                group_id=group_id,
+                role=RealmRole.READER,
+            ).dump_and_sign(coolorg.alice.signing_key)
+        # A group can only be given READER/CONTRIBUTOR role
+        case "owner_role" | "manager_role":
+            certif = RealmGroupRoleCertificate(
+                author=coolorg.alice.device_id,
+                timestamp=DateTime.now(),
+                realm_id=coolorg.wksp1_id,
+                group_id=group_id,
+                role=RealmRole.OWNER if kind == "owner_role" else RealmRole.MANAGER,
+            ).dump_and_sign(coolorg.alice.signing_key)
+        case unknown:
+            assert False, unknown
+
+    rep = await coolorg.alice.realm_update_group_role(realm_group_role_certificate=certif)
+    assert rep == authenticated_cmds.latest.realm_update_group_role.RepInvalidCertificate()
+
+
+async def test_authenticated_realm_update_group_role_timestamp_out_of_ballpark(
+    coolorg: CoolorgRpcClients, backend: Backend
+) -> None:
+    group_id = await alice_creates_group(coolorg, backend)
+    t0 = DateTime.now().subtract(seconds=3600)
+    certif = RealmGroupRoleCertificate(
+        author=coolorg.alice.device_id,
+        timestamp=t0,
+        realm_id=coolorg.wksp1_id,
+        group_id=group_id,
+        role=RealmRole.READER,
+    ).dump_and_sign(coolorg.alice.signing_key)
+
+    rep = await coolorg.alice.realm_update_group_role(realm_group_role_certificate=certif)
+    assert isinstance(
+        rep, authenticated_cmds.latest.realm_update_group_role.RepTimestampOutOfBallpark
+    )
+    assert rep.ballpark_client_early_offset == 300.0
+    assert rep.ballpark_client_late_offset == 320.0
+    assert rep.client_timestamp == t0
+
+
+@pytest.mark.parametrize("timestamp_kind", ("same_timestamp", "previous_timestamp"))
+async def test_authenticated_realm_update_group_role_require_greater_timestamp(
+    coolorg: CoolorgRpcClients,
+    backend: Backend,
+    timestamp_kind: str,
+) -> None:
+    group_id = await alice_creates_group(coolorg, backend)
+
+    now = DateTime.now()
+    match timestamp_kind:
+        case "same_timestamp":
+            update_timestamp = now
+        case "previous_timestamp":
+            update_timestamp = now.subtract(seconds=1)
+        case unknown:
+            assert False, unknown
+
+    # Just create any certificate in the realm
+    await wksp1_alice_gives_role(coolorg, backend, coolorg.bob.user_id, None, now=now)
+
+    certif = RealmGroupRoleCertificate(
+        author=coolorg.alice.device_id,
+        timestamp=update_timestamp,
+        realm_id=coolorg.wksp1_id,
+        group_id=group_id,
+        role=RealmRole.READER,
+    ).dump_and_sign(coolorg.alice.signing_key)
+
+    rep = await coolorg.alice.realm_update_group_role(realm_group_role_certificate=certif)
+    assert rep == authenticated_cmds.latest.realm_update_group_role.RepRequireGreaterTimestamp(
+        strictly_greater_than=now
+    )
+
+
+async def test_authenticated_realm_update_group_role_http_common_errors(
+    coolorg: CoolorgRpcClients,
+    backend: Backend,
+    authenticated_http_common_errors_tester: HttpCommonErrorsTester,
+) -> None:
+    group_id = await alice_creates_group(coolorg, backend)
+
+    async def do():
+        certif = RealmGroupRoleCertificate(
+            author=coolorg.alice.device_id,
+            timestamp=DateTime.now(),
+            realm_id=coolorg.wksp1_id,
+            group_id=group_id,
+            role=RealmRole.READER,
+        )
+        await coolorg.alice.realm_update_group_role(
+            realm_group_role_certificate=certif.dump_and_sign(coolorg.alice.signing_key)
+        )
+
+    await authenticated_http_common_errors_tester(do)
@@ -286,6 +286,15 @@ class BaseAuthenticatedRpcClient:
         raw_rep = await self._do_request(req.dump(), "authenticated")
         return authenticated_cmds.latest.device_create.Rep.load(raw_rep)
 
+    async def device_revoke(
+        self, revoked_device_certificate: bytes
+    ) -> authenticated_cmds.latest.device_revoke.Rep:
+        req = authenticated_cmds.latest.device_revoke.Req(
+            revoked_device_certificate=revoked_device_certificate
+        )
+        raw_rep = await self._do_request(req.dump(), "authenticated")
+        return authenticated_cmds.latest.device_revoke.Rep.load(raw_rep)
+
     async def events_listen(
         self,
     ) -> authenticated_cmds.latest.events_listen.Rep:
@@ -293,6 +302,22 @@ class BaseAuthenticatedRpcClient:
         raw_rep = await self._do_request(req.dump(), "authenticated")
         return authenticated_cmds.latest.events_listen.Rep.load(raw_rep)
 
+    async def group_create(
+        self, group_certificate: bytes
+    ) -> authenticated_cmds.latest.group_create.Rep:
+        req = authenticated_cmds.latest.group_create.Req(group_certificate=group_certificate)
+        raw_rep = await self._do_request(req.dump(), "authenticated")
+        return authenticated_cmds.latest.group_create.Rep.load(raw_rep)
+
+    async def group_update_member(
+        self, group_member_certificate: bytes
+    ) -> authenticated_cmds.latest.group_update_member.Rep:
+        req = authenticated_cmds.latest.group_update_member.Req(
+            group_member_certificate=group_member_certificate
+        )
+        raw_rep = await self._do_request(req.dump(), "authenticated")
+        return authenticated_cmds.latest.group_update_member.Rep.load(raw_rep)
+
     async def invite_cancel(
         self, token: AccessToken
     ) -> authenticated_cmds.latest.invite_cancel.Rep:
@@ -457,6 +482,15 @@ class BaseAuthenticatedRpcClient:
         raw_rep = await self._do_request(req.dump(), "authenticated")
         return authenticated_cmds.latest.realm_update_archiving.Rep.load(raw_rep)
 
+    async def realm_update_group_role(
+        self, realm_group_role_certificate: bytes
+    ) -> authenticated_cmds.latest.realm_update_group_role.Rep:
+        req = authenticated_cmds.latest.realm_update_group_role.Req(
+            realm_group_role_certificate=realm_group_role_certificate
+        )
+        raw_rep = await self._do_request(req.dump(), "authenticated")
+        return authenticated_cmds.latest.realm_update_group_role.Rep.load(raw_rep)
+
     async def shamir_recovery_delete(
         self, shamir_recovery_deletion_certificate: bytes
     ) -> authenticated_cmds.latest.shamir_recovery_delete.Rep:
@@ -77,6 +77,7 @@ async def test_missed_events(minimalorg: MinimalorgRpcClients, backend: Backend)
                 user_profile_outsider_allowed=True,
                 sse_keepalive_seconds=30,
                 realm_minimum_archiving_period_before_deletion=2592000,
+                pki_crls=None,
             )
         )
 
@@ -116,6 +117,7 @@ async def test_close_on_backpressure(minimalorg: MinimalorgRpcClients, backend:
                 user_profile_outsider_allowed=True,
                 sse_keepalive_seconds=30,
                 realm_minimum_archiving_period_before_deletion=2592000,
+                pki_crls=None,
             )
         )
 
@@ -163,6 +165,7 @@ async def test_empty_last_event_id(minimalorg: MinimalorgRpcClients, backend: Ba
                 user_profile_outsider_allowed=True,
                 sse_keepalive_seconds=30,
                 realm_minimum_archiving_period_before_deletion=2592000,
+                pki_crls=None,
             )
         )
 
@@ -223,6 +226,7 @@ async def test_close_on_user_revoked(coolorg: CoolorgRpcClients, backend: Backen
                 user_profile_outsider_allowed=True,
                 sse_keepalive_seconds=30,
                 realm_minimum_archiving_period_before_deletion=2592000,
+                pki_crls=None,
             )
         )
 
@@ -273,6 +277,7 @@ async def test_close_on_organization_tos_updated(
                 user_profile_outsider_allowed=True,
                 sse_keepalive_seconds=30,
                 realm_minimum_archiving_period_before_deletion=2592000,
+                pki_crls=None,
             )
         )
 